@@ -0,0 +1,91 @@
+use std::collections::{BTreeSet, HashMap};
+
+/// A buddy allocator over a power of two sized range. Every allocation is rounded up to a
+/// power of two and is aligned to its own size, so alignments up to the size of an allocation
+/// are free.
+#[derive(Debug)]
+pub(crate) struct Buddy {
+    size: u64,
+    min_order: u32,
+    /// Free nodes by order, `free[0]` holds the nodes of size `1 << min_order`.
+    free: Vec<BTreeSet<u64>>,
+    /// Order of the node of every live allocation, by offset.
+    allocated: HashMap<u64, u32>,
+}
+
+impl Buddy {
+    pub(crate) const MIN_NODE_SIZE: u64 = 256;
+
+    pub(crate) fn new(size: u64) -> Buddy {
+        assert!(size.is_power_of_two() && size >= Buddy::MIN_NODE_SIZE);
+        let min_order = Buddy::MIN_NODE_SIZE.trailing_zeros();
+        let orders = (size.trailing_zeros() - min_order + 1) as usize;
+        let mut free = vec![BTreeSet::new(); orders];
+        free[orders - 1].insert(0);
+        Buddy {
+            size,
+            min_order,
+            free,
+            allocated: HashMap::new(),
+        }
+    }
+
+    fn node_size(&self, level: usize) -> u64 {
+        1 << (self.min_order + level as u32)
+    }
+
+    pub(crate) fn allocate(&mut self, size: u64, alignment: u64) -> Option<u64> {
+        let node_size = size
+            .max(alignment)
+            .max(Buddy::MIN_NODE_SIZE)
+            .checked_next_power_of_two()?;
+        if node_size > self.size {
+            return None;
+        }
+        let level = (node_size.trailing_zeros() - self.min_order) as usize;
+        let from = (level..self.free.len()).find(|&from| !self.free[from].is_empty())?;
+        let offset = *self.free[from].iter().next().unwrap();
+        self.free[from].remove(&offset);
+        // Split the node, keeping the lower half and freeing the upper one
+        for split in (level..from).rev() {
+            let buddy = offset + self.node_size(split);
+            self.free[split].insert(buddy);
+        }
+        self.allocated.insert(offset, level as u32);
+        Some(offset)
+    }
+
+    pub(crate) fn free(&mut self, offset: u64) {
+        let mut level = self
+            .allocated
+            .remove(&offset)
+            .expect("Freed an offset that was not allocated") as usize;
+        let mut offset = offset;
+        while level + 1 < self.free.len() {
+            let buddy = offset ^ self.node_size(level);
+            if !self.free[level].remove(&buddy) {
+                break;
+            }
+            offset = offset.min(buddy);
+            level += 1;
+        }
+        self.free[level].insert(offset);
+    }
+
+    pub(crate) fn allocation_count(&self) -> usize {
+        self.allocated.len()
+    }
+
+    /// The sizes of the free nodes.
+    pub(crate) fn free_regions(&self) -> Vec<u64> {
+        self.free
+            .iter()
+            .enumerate()
+            .flat_map(|(level, nodes)| {
+                nodes
+                    .iter()
+                    .map(move |_| 1 << (self.min_order + level as u32))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,451 @@
+//! Host memory allocation callbacks backed by a Rust allocator.
+//!
+//! ```no_run
+//! # use ash::allocator::host::HostAllocator;
+//! # use ash::version::DeviceV1_0;
+//! # use ash::vk;
+//! # use std::alloc::System;
+//! # unsafe fn test(device: &ash::Device, info: &vk::BufferCreateInfo) {
+//! let host_allocator = HostAllocator::new(System);
+//! let callbacks = host_allocator.callbacks();
+//! let buffer = device.create_buffer(info, Some(&callbacks)).unwrap();
+//! let usage = host_allocator.stats().scope(vk::SystemAllocationScope::OBJECT);
+//! println!("{} bytes in {} allocations", usage.live_bytes, usage.live_count);
+//! device.destroy_buffer(buffer, Some(&callbacks));
+//! # }
+//! ```
+//!
+//! The callbacks point to the `HostAllocator`, which therefore must not be moved or dropped
+//! while any object created with them is alive. Keep it in a `Box`, an `Arc` or a `static`.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use vk;
+
+const SCOPE_COUNT: usize = 5;
+const INTERNAL_TYPE_COUNT: usize = 1;
+
+/// Stored in front of every allocation, because `vkFreeFunction` is only given the pointer.
+struct Header {
+    size: usize,
+    alignment: usize,
+    scope: vk::SystemAllocationScope,
+}
+
+/// Live and total allocations of one scope or internal allocation type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HostMemoryUsage {
+    pub live_bytes: usize,
+    pub live_count: usize,
+    pub peak_bytes: usize,
+    /// All allocations that were made, including freed ones.
+    pub total_count: usize,
+}
+
+impl HostMemoryUsage {
+    fn add(self, other: HostMemoryUsage) -> HostMemoryUsage {
+        HostMemoryUsage {
+            live_bytes: self.live_bytes + other.live_bytes,
+            live_count: self.live_count + other.live_count,
+            peak_bytes: self.peak_bytes + other.peak_bytes,
+            total_count: self.total_count + other.total_count,
+        }
+    }
+}
+
+/// A snapshot of the host memory that was allocated through a `HostAllocator`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HostMemoryStats {
+    scopes: [HostMemoryUsage; SCOPE_COUNT],
+    internal: [HostMemoryUsage; INTERNAL_TYPE_COUNT],
+}
+
+impl HostMemoryStats {
+    /// Allocations that were made by the callbacks in `scope`.
+    pub fn scope(&self, scope: vk::SystemAllocationScope) -> HostMemoryUsage {
+        self.scopes
+            .get(scope.as_raw() as usize)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Allocations that the implementation made itself and reported with
+    /// `vkInternalAllocationNotification`.
+    pub fn internal(&self, allocation_type: vk::InternalAllocationType) -> HostMemoryUsage {
+        self.internal
+            .get(allocation_type.as_raw() as usize)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The sum of all scopes, without internal allocations. Peaks of the scopes are summed,
+    /// so `peak_bytes` is an upper bound.
+    pub fn total(&self) -> HostMemoryUsage {
+        self.scopes
+            .iter()
+            .fold(HostMemoryUsage::default(), |total, &usage| total.add(usage))
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    live_bytes: AtomicUsize,
+    live_count: AtomicUsize,
+    peak_bytes: AtomicUsize,
+    total_count: AtomicUsize,
+}
+
+impl Counters {
+    fn allocated(&self, size: usize) {
+        let live_bytes = self.live_bytes.fetch_add(size, Ordering::Relaxed) + size;
+        self.live_count.fetch_add(1, Ordering::Relaxed);
+        self.total_count.fetch_add(1, Ordering::Relaxed);
+        let mut peak = self.peak_bytes.load(Ordering::Relaxed);
+        while live_bytes > peak {
+            match self.peak_bytes.compare_exchange_weak(
+                peak,
+                live_bytes,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(current) => peak = current,
+            }
+        }
+    }
+
+    fn freed(&self, size: usize) {
+        self.live_bytes.fetch_sub(size, Ordering::Relaxed);
+        self.live_count.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn usage(&self) -> HostMemoryUsage {
+        HostMemoryUsage {
+            live_bytes: self.live_bytes.load(Ordering::Relaxed),
+            live_count: self.live_count.load(Ordering::Relaxed),
+            peak_bytes: self.peak_bytes.load(Ordering::Relaxed),
+            total_count: self.total_count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A `GlobalAlloc` made of two closures, see `HostAllocator::from_fns`.
+pub struct FnAlloc<A, D> {
+    alloc: A,
+    dealloc: D,
+}
+
+unsafe impl<A, D> GlobalAlloc for FnAlloc<A, D>
+where
+    A: Fn(Layout) -> *mut u8,
+    D: Fn(*mut u8, Layout),
+{
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        (self.alloc)(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        (self.dealloc)(ptr, layout)
+    }
+}
+
+/// Implements `vk::AllocationCallbacks` with a `GlobalAlloc` and counts the allocations per
+/// `vk::SystemAllocationScope` and `vk::InternalAllocationType`.
+pub struct HostAllocator<A: GlobalAlloc = System> {
+    alloc: A,
+    scopes: [Counters; SCOPE_COUNT],
+    internal: [Counters; INTERNAL_TYPE_COUNT],
+}
+
+impl<A, D> HostAllocator<FnAlloc<A, D>>
+where
+    A: Fn(Layout) -> *mut u8,
+    D: Fn(*mut u8, Layout),
+{
+    /// Allocates with `alloc` and frees with `dealloc`, which have the semantics of
+    /// `GlobalAlloc::alloc` and `GlobalAlloc::dealloc`.
+    pub fn from_fns(alloc: A, dealloc: D) -> HostAllocator<FnAlloc<A, D>> {
+        HostAllocator::new(FnAlloc { alloc, dealloc })
+    }
+}
+
+impl<A: GlobalAlloc> HostAllocator<A> {
+    pub fn new(alloc: A) -> HostAllocator<A> {
+        HostAllocator {
+            alloc,
+            scopes: Default::default(),
+            internal: Default::default(),
+        }
+    }
+
+    /// Callbacks that allocate through this allocator. `p_user_data` points to `self`.
+    pub fn callbacks(&self) -> vk::AllocationCallbacks {
+        vk::AllocationCallbacks {
+            p_user_data: self as *const HostAllocator<A> as *mut c_void,
+            pfn_allocation: Some(allocation::<A>),
+            pfn_reallocation: Some(reallocation::<A>),
+            pfn_free: Some(free::<A>),
+            pfn_internal_allocation: Some(internal_allocation::<A>),
+            pfn_internal_free: Some(internal_free::<A>),
+        }
+    }
+
+    pub fn stats(&self) -> HostMemoryStats {
+        let mut stats = HostMemoryStats::default();
+        for (usage, counters) in stats.scopes.iter_mut().zip(&self.scopes) {
+            *usage = counters.usage();
+        }
+        for (usage, counters) in stats.internal.iter_mut().zip(&self.internal) {
+            *usage = counters.usage();
+        }
+        stats
+    }
+
+    fn scope_counters(&self, scope: vk::SystemAllocationScope) -> Option<&Counters> {
+        self.scopes.get(scope.as_raw() as usize)
+    }
+
+    /// The layout of an allocation including its header, and the offset of the allocation.
+    fn layout(size: usize, alignment: usize) -> Option<(Layout, usize)> {
+        let alignment = alignment.max(mem::align_of::<Header>());
+        let offset = (mem::size_of::<Header>() + alignment - 1) & !(alignment - 1);
+        let layout = Layout::from_size_align(offset.checked_add(size)?, alignment).ok()?;
+        Some((layout, offset))
+    }
+
+    unsafe fn allocate(
+        &self,
+        size: usize,
+        alignment: usize,
+        scope: vk::SystemAllocationScope,
+    ) -> *mut u8 {
+        let (layout, offset) = match HostAllocator::<A>::layout(size, alignment) {
+            Some(layout) if size > 0 => layout,
+            _ => return ptr::null_mut(),
+        };
+        let base = self.alloc.alloc(layout);
+        if base.is_null() {
+            return base;
+        }
+        let memory = base.add(offset);
+        ptr::write(
+            header(memory),
+            Header {
+                size,
+                alignment,
+                scope,
+            },
+        );
+        if let Some(counters) = self.scope_counters(scope) {
+            counters.allocated(size);
+        }
+        memory
+    }
+
+    unsafe fn free(&self, memory: *mut u8) {
+        if memory.is_null() {
+            return;
+        }
+        let Header {
+            size,
+            alignment,
+            scope,
+        } = ptr::read(header(memory));
+        let (layout, offset) = HostAllocator::<A>::layout(size, alignment).unwrap();
+        self.alloc.dealloc(memory.sub(offset), layout);
+        if let Some(counters) = self.scope_counters(scope) {
+            counters.freed(size);
+        }
+    }
+}
+
+unsafe fn header(memory: *mut u8) -> *mut Header {
+    (memory as *mut Header).offset(-1)
+}
+
+unsafe fn allocator<'a, A: GlobalAlloc>(p_user_data: *mut c_void) -> &'a HostAllocator<A> {
+    &*(p_user_data as *const HostAllocator<A>)
+}
+
+unsafe extern "system" fn allocation<A: GlobalAlloc>(
+    p_user_data: *mut c_void,
+    size: usize,
+    alignment: usize,
+    allocation_scope: vk::SystemAllocationScope,
+) -> *mut c_void {
+    allocator::<A>(p_user_data).allocate(size, alignment, allocation_scope) as *mut c_void
+}
+
+unsafe extern "system" fn reallocation<A: GlobalAlloc>(
+    p_user_data: *mut c_void,
+    p_original: *mut c_void,
+    size: usize,
+    alignment: usize,
+    allocation_scope: vk::SystemAllocationScope,
+) -> *mut c_void {
+    let allocator = allocator::<A>(p_user_data);
+    let original = p_original as *mut u8;
+    if original.is_null() {
+        return allocator.allocate(size, alignment, allocation_scope) as *mut c_void;
+    }
+    if size == 0 {
+        allocator.free(original);
+        return ptr::null_mut();
+    }
+    // The original allocation is left untouched if the new one fails
+    let memory = allocator.allocate(size, alignment, allocation_scope);
+    if !memory.is_null() {
+        let original_size = (*header(original)).size;
+        ptr::copy_nonoverlapping(original, memory, original_size.min(size));
+        allocator.free(original);
+    }
+    memory as *mut c_void
+}
+
+unsafe extern "system" fn free<A: GlobalAlloc>(
+    p_user_data: *mut c_void,
+    p_memory: *mut c_void,
+) -> c_void {
+    allocator::<A>(p_user_data).free(p_memory as *mut u8);
+    mem::zeroed()
+}
+
+unsafe extern "system" fn internal_allocation<A: GlobalAlloc>(
+    p_user_data: *mut c_void,
+    size: usize,
+    allocation_type: vk::InternalAllocationType,
+    _: vk::SystemAllocationScope,
+) -> c_void {
+    if let Some(counters) = allocator::<A>(p_user_data)
+        .internal
+        .get(allocation_type.as_raw() as usize)
+    {
+        counters.allocated(size);
+    }
+    mem::zeroed()
+}
+
+unsafe extern "system" fn internal_free<A: GlobalAlloc>(
+    p_user_data: *mut c_void,
+    size: usize,
+    allocation_type: vk::InternalAllocationType,
+    _: vk::SystemAllocationScope,
+) -> c_void {
+    if let Some(counters) = allocator::<A>(p_user_data)
+        .internal
+        .get(allocation_type.as_raw() as usize)
+    {
+        counters.freed(size);
+    }
+    mem::zeroed()
+}
+
+lazy_static! {
+    static ref DEFAULT_ALLOCATOR: HostAllocator = HostAllocator::new(System);
+}
+
+/// Allocation callbacks made of associated functions, which are not given any user data.
+#[deprecated(note = "Use `HostAllocator`, which keeps its state in `p_user_data`")]
+pub trait VkAllocation {
+    unsafe extern "system" fn allocation(
+        p_user_data: *mut c_void,
+        size: usize,
+        alignment: usize,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> *mut c_void;
+    unsafe extern "system" fn reallocation(
+        p_user_data: *mut c_void,
+        p_original: *mut c_void,
+        size: usize,
+        alignment: usize,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> *mut c_void;
+    unsafe extern "system" fn free(p_user_data: *mut c_void, p_memory: *mut c_void) -> c_void;
+    unsafe extern "system" fn internal_allocation(
+        p_user_data: *mut c_void,
+        size: usize,
+        allocation_type: vk::InternalAllocationType,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> c_void;
+    unsafe extern "system" fn internal_free(
+        p_user_data: *mut c_void,
+        size: usize,
+        allocation_type: vk::InternalAllocationType,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> c_void;
+    fn create_allocation_callback() -> Option<vk::AllocationCallbacks> {
+        Some(vk::AllocationCallbacks {
+            p_user_data: ptr::null_mut(),
+            pfn_allocation: Some(Self::allocation),
+            pfn_reallocation: Some(Self::reallocation),
+            pfn_free: Some(Self::free),
+            pfn_internal_allocation: Some(Self::internal_allocation),
+            pfn_internal_free: Some(Self::internal_free),
+        })
+    }
+}
+
+/// The allocator of the implementation. `create_allocation_callback` returns `None`, and the
+/// callbacks allocate from a process wide `HostAllocator<System>`.
+#[deprecated(note = "Pass `None` as allocation callbacks, or use `HostAllocator`")]
+pub struct DefaultAllocatorCallback;
+
+#[allow(deprecated)]
+impl VkAllocation for DefaultAllocatorCallback {
+    unsafe extern "system" fn allocation(
+        _: *mut c_void,
+        size: usize,
+        alignment: usize,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> *mut c_void {
+        allocation::<System>(default_allocator(), size, alignment, allocation_scope)
+    }
+
+    unsafe extern "system" fn reallocation(
+        _: *mut c_void,
+        p_original: *mut c_void,
+        size: usize,
+        alignment: usize,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> *mut c_void {
+        reallocation::<System>(
+            default_allocator(),
+            p_original,
+            size,
+            alignment,
+            allocation_scope,
+        )
+    }
+
+    unsafe extern "system" fn free(_: *mut c_void, p_memory: *mut c_void) -> c_void {
+        free::<System>(default_allocator(), p_memory)
+    }
+
+    unsafe extern "system" fn internal_allocation(
+        _: *mut c_void,
+        size: usize,
+        allocation_type: vk::InternalAllocationType,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> c_void {
+        internal_allocation::<System>(default_allocator(), size, allocation_type, allocation_scope)
+    }
+
+    unsafe extern "system" fn internal_free(
+        _: *mut c_void,
+        size: usize,
+        allocation_type: vk::InternalAllocationType,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> c_void {
+        internal_free::<System>(default_allocator(), size, allocation_type, allocation_scope)
+    }
+
+    fn create_allocation_callback() -> Option<vk::AllocationCallbacks> {
+        None
+    }
+}
+
+fn default_allocator() -> *mut c_void {
+    &*DEFAULT_ALLOCATOR as *const HostAllocator as *mut c_void
+}
@@ -0,0 +1,57 @@
+/// A bump allocator. Space is only reclaimed once every allocation of the block has been
+/// freed, which suits transient resources that are freed together, e.g. at the end of a frame.
+#[derive(Debug)]
+pub(crate) struct Linear {
+    size: u64,
+    cursor: u64,
+    allocation_count: usize,
+}
+
+impl Linear {
+    pub(crate) fn new(size: u64) -> Linear {
+        Linear {
+            size,
+            cursor: 0,
+            allocation_count: 0,
+        }
+    }
+
+    pub(crate) fn allocate(&mut self, size: u64, alignment: u64) -> Option<u64> {
+        let offset = align_up(self.cursor, alignment)?;
+        let end = offset.checked_add(size)?;
+        if end > self.size {
+            return None;
+        }
+        self.cursor = end;
+        self.allocation_count += 1;
+        Some(offset)
+    }
+
+    pub(crate) fn free(&mut self) {
+        self.allocation_count = self
+            .allocation_count
+            .checked_sub(1)
+            .expect("Freed more allocations than were made");
+        if self.allocation_count == 0 {
+            self.cursor = 0;
+        }
+    }
+
+    pub(crate) fn allocation_count(&self) -> usize {
+        self.allocation_count
+    }
+
+    /// The size of the range after the cursor, which is the only one that can be reused.
+    pub(crate) fn free_regions(&self) -> Vec<u64> {
+        if self.cursor < self.size {
+            vec![self.size - self.cursor]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+pub(crate) fn align_up(offset: u64, alignment: u64) -> Option<u64> {
+    let mask = alignment.max(1) - 1;
+    offset.checked_add(mask).map(|offset| offset & !mask)
+}
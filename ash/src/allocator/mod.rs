@@ -0,0 +1,703 @@
+//! A device memory sub-allocator.
+//!
+//! Drivers limit the number of live `vkAllocateMemory` allocations to
+//! `max_memory_allocation_count`, which is as low as 4096 on some platforms, so allocating
+//! memory for every buffer and image does not scale. `Allocator` allocates large blocks of
+//! device memory per memory type and hands out ranges of them.
+//!
+//! ```no_run
+//! # use ash::allocator::{AllocationCreateInfo, Allocator, MemoryUsage};
+//! # use ash::version::InstanceV1_0;
+//! # use ash::vk;
+//! # unsafe fn test(instance: &ash::Instance, physical_device: vk::PhysicalDevice, device: &ash::Device) -> Result<(), ash::allocator::AllocatorError> {
+//! let memory_properties = instance.get_physical_device_memory_properties(physical_device);
+//! let limits = instance.get_physical_device_properties(physical_device).limits;
+//! let allocator = Allocator::new(device.clone(), memory_properties, &limits);
+//!
+//! let buffer_info = vk::BufferCreateInfo {
+//!     size: 1024,
+//!     usage: vk::BufferUsageFlags::UNIFORM_BUFFER,
+//!     ..Default::default()
+//! };
+//! let allocation_info = AllocationCreateInfo {
+//!     usage: MemoryUsage::CpuToGpu,
+//!     ..Default::default()
+//! };
+//! let (buffer, mut allocation) = allocator.create_buffer(&buffer_info, &allocation_info)?;
+//! allocation.mapped_slice_mut().unwrap()[..4].copy_from_slice(&[1, 2, 3, 4]);
+//! allocator.destroy_buffer(buffer, allocation);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Blocks of host visible memory types stay mapped for their whole lifetime. If
+//! `buffer_image_granularity` is larger than one, buffers and linear images are kept in other
+//! blocks than optimal images, so they never share a page.
+use self::buddy::Buddy;
+use self::linear::Linear;
+use self::report::{BlockReport, MemoryReport};
+use device::Device;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::os::raw::c_void;
+use std::ptr;
+use std::slice;
+use std::sync::Mutex;
+use version::DeviceV1_0;
+use vk;
+use vk::Handle;
+
+mod buddy;
+pub mod host;
+mod linear;
+pub mod report;
+
+/// How the memory of an allocation is accessed, which decides the memory type it is
+/// allocated from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryUsage {
+    /// Only accessed by the device. Prefers `DEVICE_LOCAL` memory that is not host visible.
+    GpuOnly,
+    /// Written by the host and read by the device, e.g. uniform or staging buffers. Requires
+    /// `HOST_VISIBLE` memory and prefers `DEVICE_LOCAL`.
+    CpuToGpu,
+    /// Written by the device and read by the host. Requires `HOST_VISIBLE` memory and prefers
+    /// `HOST_CACHED`.
+    GpuToCpu,
+    /// Only accessed by the host. Requires `HOST_VISIBLE | HOST_COHERENT` memory and avoids
+    /// `DEVICE_LOCAL`.
+    CpuOnly,
+}
+
+impl MemoryUsage {
+    /// The required, preferred and avoided property flags.
+    fn flags(
+        self,
+    ) -> (
+        vk::MemoryPropertyFlags,
+        vk::MemoryPropertyFlags,
+        vk::MemoryPropertyFlags,
+    ) {
+        use vk::MemoryPropertyFlags as F;
+        match self {
+            MemoryUsage::GpuOnly => (F::empty(), F::DEVICE_LOCAL, F::HOST_VISIBLE),
+            MemoryUsage::CpuToGpu => (F::HOST_VISIBLE, F::DEVICE_LOCAL, F::empty()),
+            MemoryUsage::GpuToCpu => (F::HOST_VISIBLE, F::HOST_CACHED, F::empty()),
+            MemoryUsage::CpuOnly => (
+                F::HOST_VISIBLE | F::HOST_COHERENT,
+                F::empty(),
+                F::DEVICE_LOCAL,
+            ),
+        }
+    }
+}
+
+/// Returns the memory type in `memory_type_bits` that suits `usage` best, or `None` if no
+/// memory type has the flags that `usage` requires.
+pub fn find_memory_type_index(
+    memory_properties: &vk::PhysicalDeviceMemoryProperties,
+    memory_type_bits: u32,
+    usage: MemoryUsage,
+) -> Option<u32> {
+    let (required, preferred, avoided) = usage.flags();
+    let count = memory_properties.memory_type_count as usize;
+    memory_properties.memory_types[..count]
+        .iter()
+        .enumerate()
+        .filter(|&(index, memory_type)| {
+            memory_type_bits & (1 << index) != 0 && memory_type.property_flags.contains(required)
+        })
+        .min_by_key(|&(_, memory_type)| {
+            let flags = memory_type.property_flags;
+            let missing = (preferred.as_raw() & !flags.as_raw()).count_ones();
+            let unwanted = (avoided.as_raw() & flags.as_raw()).count_ones();
+            missing + unwanted
+        })
+        .map(|(index, _)| index as u32)
+}
+
+/// How the ranges of a block are handed out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Strategy {
+    /// Rounds allocations up to a power of two and merges freed neighbours. Suits resources
+    /// with unrelated lifetimes.
+    Buddy,
+    /// Bumps an offset and only reuses a block once all of its allocations have been freed.
+    /// Suits transient resources that are freed together.
+    Linear,
+}
+
+/// The kind of resource that is bound to an allocation, which matters for
+/// `buffer_image_granularity`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    Buffer,
+    LinearImage,
+    OptimalImage,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AllocationCreateInfo {
+    pub usage: MemoryUsage,
+    pub strategy: Strategy,
+    pub resource_type: ResourceType,
+    /// Allocates a `vk::DeviceMemory` for this allocation alone. Allocations larger than half
+    /// a block are always dedicated.
+    pub dedicated: bool,
+}
+
+impl Default for AllocationCreateInfo {
+    fn default() -> AllocationCreateInfo {
+        AllocationCreateInfo {
+            usage: MemoryUsage::GpuOnly,
+            strategy: Strategy::Buddy,
+            resource_type: ResourceType::Buffer,
+            dedicated: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AllocatorError {
+    /// None of the memory types allowed by the memory requirements has the property flags
+    /// required by the `MemoryUsage`.
+    NoCompatibleMemoryType,
+    Vulkan(vk::Result),
+}
+
+impl fmt::Display for AllocatorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AllocatorError::NoCompatibleMemoryType => {
+                write!(f, "No memory type is compatible with the requested usage")
+            }
+            AllocatorError::Vulkan(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for AllocatorError {
+    fn description(&self) -> &str {
+        "AllocatorError"
+    }
+}
+
+impl From<vk::Result> for AllocatorError {
+    fn from(err: vk::Result) -> AllocatorError {
+        AllocatorError::Vulkan(err)
+    }
+}
+
+/// A range of device memory handed out by an `Allocator`. It has to be returned with
+/// `Allocator::free`, otherwise the range stays in use until the allocator is dropped.
+#[derive(Debug)]
+pub struct Allocation {
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    memory_type_index: u32,
+    mapped: *mut u8,
+    location: Location,
+}
+
+// The mapped pointer points into memory that the `Allocator` keeps mapped
+unsafe impl Send for Allocation {}
+unsafe impl Sync for Allocation {}
+
+impl Allocation {
+    pub fn memory(&self) -> vk::DeviceMemory {
+        self.memory
+    }
+
+    pub fn offset(&self) -> vk::DeviceSize {
+        self.offset
+    }
+
+    pub fn size(&self) -> vk::DeviceSize {
+        self.size
+    }
+
+    pub fn memory_type_index(&self) -> u32 {
+        self.memory_type_index
+    }
+
+    pub fn is_dedicated(&self) -> bool {
+        self.location == Location::Dedicated
+    }
+
+    /// The host address of `offset`, if the memory type is host visible.
+    pub fn mapped_ptr(&self) -> Option<*mut c_void> {
+        if self.mapped.is_null() {
+            None
+        } else {
+            Some(self.mapped as *mut c_void)
+        }
+    }
+
+    /// The mapped memory of the allocation. Writes to memory that is not `HOST_COHERENT` have
+    /// to be flushed.
+    pub fn mapped_slice_mut(&mut self) -> Option<&mut [u8]> {
+        self.mapped_ptr()
+            .map(|ptr| unsafe { slice::from_raw_parts_mut(ptr as *mut u8, self.size as usize) })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct PoolKey {
+    memory_type_index: u32,
+    strategy: Strategy,
+    /// Whether the pool holds buffers and linear images, or optimal images. Always true if
+    /// `buffer_image_granularity` is 1.
+    linear_resources: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Location {
+    Dedicated,
+    Block { pool: PoolKey, block: u64 },
+}
+
+#[derive(Debug)]
+enum Suballocator {
+    Buddy(Buddy),
+    Linear(Linear),
+}
+
+#[derive(Debug)]
+struct Block {
+    id: u64,
+    memory: vk::DeviceMemory,
+    size: vk::DeviceSize,
+    mapped: *mut u8,
+    suballocator: Suballocator,
+}
+
+unsafe impl Send for Block {}
+
+impl Block {
+    fn allocate(&mut self, size: u64, alignment: u64) -> Option<u64> {
+        match self.suballocator {
+            Suballocator::Buddy(ref mut buddy) => buddy.allocate(size, alignment),
+            Suballocator::Linear(ref mut linear) => linear.allocate(size, alignment),
+        }
+    }
+
+    fn free(&mut self, offset: u64) {
+        match self.suballocator {
+            Suballocator::Buddy(ref mut buddy) => buddy.free(offset),
+            Suballocator::Linear(ref mut linear) => linear.free(),
+        }
+    }
+
+    fn allocation_count(&self) -> usize {
+        match self.suballocator {
+            Suballocator::Buddy(ref buddy) => buddy.allocation_count(),
+            Suballocator::Linear(ref linear) => linear.allocation_count(),
+        }
+    }
+
+    fn report(&self) -> BlockReport {
+        let free_regions = match self.suballocator {
+            Suballocator::Buddy(ref buddy) => buddy.free_regions(),
+            Suballocator::Linear(ref linear) => linear.free_regions(),
+        };
+        BlockReport {
+            memory: self.memory.as_raw(),
+            size: self.size,
+            dedicated: false,
+            used: self.size - free_regions.iter().sum::<u64>(),
+            allocation_count: self.allocation_count(),
+            free_region_count: free_regions.len(),
+            largest_free_region: free_regions.iter().cloned().max().unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Pools {
+    blocks: HashMap<PoolKey, Vec<Block>>,
+    /// Memory type index and size of dedicated allocations.
+    dedicated: HashMap<vk::DeviceMemory, (u32, vk::DeviceSize)>,
+    /// Tag and size of tagged allocations, by memory and offset.
+    tags: HashMap<(vk::DeviceMemory, vk::DeviceSize), (String, vk::DeviceSize)>,
+    next_block_id: u64,
+}
+
+/// Allocates blocks of device memory per memory type and sub-allocates them. See the
+/// [module documentation](index.html).
+///
+/// Dropping the allocator frees all of its memory, including memory of allocations that were
+/// not freed.
+pub struct Allocator<D: DeviceV1_0 = Device> {
+    device: D,
+    memory_properties: vk::PhysicalDeviceMemoryProperties,
+    buffer_image_granularity: vk::DeviceSize,
+    block_size: Option<vk::DeviceSize>,
+    pools: Mutex<Pools>,
+}
+
+impl<D: DeviceV1_0> Allocator<D> {
+    pub fn new(
+        device: D,
+        memory_properties: vk::PhysicalDeviceMemoryProperties,
+        limits: &vk::PhysicalDeviceLimits,
+    ) -> Allocator<D> {
+        Allocator {
+            device,
+            memory_properties,
+            buffer_image_granularity: limits.buffer_image_granularity,
+            block_size: None,
+            pools: Mutex::new(Pools::default()),
+        }
+    }
+
+    /// Overrides the size of new blocks, which is rounded up to a power of two. By default
+    /// blocks are 256 MiB, or an eighth of the heap for heaps of up to 1 GiB.
+    pub fn block_size(mut self, block_size: vk::DeviceSize) -> Allocator<D> {
+        self.block_size = Some(block_size.max(Buddy::MIN_NODE_SIZE).next_power_of_two());
+        self
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    pub fn memory_properties(&self) -> &vk::PhysicalDeviceMemoryProperties {
+        &self.memory_properties
+    }
+
+    fn block_size_of(&self, memory_type_index: u32) -> vk::DeviceSize {
+        if let Some(block_size) = self.block_size {
+            return block_size;
+        }
+        let memory_type = self.memory_properties.memory_types[memory_type_index as usize];
+        let heap_size = self.memory_properties.memory_heaps[memory_type.heap_index as usize].size;
+        let block_size = if heap_size <= 1 << 30 {
+            heap_size / 8
+        } else {
+            256 << 20
+        };
+        // Rounded down, so that blocks fit into small heaps
+        let block_size = block_size.max(Buddy::MIN_NODE_SIZE);
+        1 << (63 - block_size.leading_zeros())
+    }
+
+    /// Allocates memory for `requirements`. If the best memory type for `info.usage` is out of
+    /// memory, the next best one is tried.
+    pub unsafe fn allocate(
+        &self,
+        requirements: &vk::MemoryRequirements,
+        info: &AllocationCreateInfo,
+    ) -> Result<Allocation, AllocatorError> {
+        let mut memory_type_bits = requirements.memory_type_bits;
+        let mut result = Err(AllocatorError::NoCompatibleMemoryType);
+        while let Some(memory_type_index) =
+            find_memory_type_index(&self.memory_properties, memory_type_bits, info.usage)
+        {
+            result = self.allocate_from_type(memory_type_index, requirements, info);
+            match result {
+                Err(AllocatorError::Vulkan(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY))
+                | Err(AllocatorError::Vulkan(vk::Result::ERROR_OUT_OF_HOST_MEMORY)) => {
+                    memory_type_bits &= !(1 << memory_type_index);
+                }
+                _ => return result,
+            }
+        }
+        result
+    }
+
+    unsafe fn allocate_from_type(
+        &self,
+        memory_type_index: u32,
+        requirements: &vk::MemoryRequirements,
+        info: &AllocationCreateInfo,
+    ) -> Result<Allocation, AllocatorError> {
+        let block_size = self.block_size_of(memory_type_index);
+        let mut pools = self.pools.lock().unwrap();
+        if info.dedicated
+            || requirements.size > block_size / 2
+            || requirements.alignment > block_size
+        {
+            let (memory, mapped) = self.allocate_memory(memory_type_index, requirements.size)?;
+            pools
+                .dedicated
+                .insert(memory, (memory_type_index, requirements.size));
+            return Ok(Allocation {
+                memory,
+                offset: 0,
+                size: requirements.size,
+                memory_type_index,
+                mapped,
+                location: Location::Dedicated,
+            });
+        }
+
+        let pool = PoolKey {
+            memory_type_index,
+            strategy: info.strategy,
+            linear_resources: info.resource_type != ResourceType::OptimalImage
+                || self.buffer_image_granularity <= 1,
+        };
+        let allocation = |block: &mut Block| {
+            let offset = block.allocate(requirements.size, requirements.alignment)?;
+            Some(Allocation {
+                memory: block.memory,
+                offset,
+                size: requirements.size,
+                memory_type_index,
+                mapped: if block.mapped.is_null() {
+                    ptr::null_mut()
+                } else {
+                    block.mapped.offset(offset as isize)
+                },
+                location: Location::Block {
+                    pool,
+                    block: block.id,
+                },
+            })
+        };
+        if let Some(blocks) = pools.blocks.get_mut(&pool) {
+            if let Some(allocation) = blocks.iter_mut().filter_map(allocation).next() {
+                return Ok(allocation);
+            }
+        }
+
+        let (memory, mapped) = self.allocate_memory(memory_type_index, block_size)?;
+        let mut block = Block {
+            id: pools.next_block_id,
+            memory,
+            size: block_size,
+            mapped,
+            suballocator: match info.strategy {
+                Strategy::Buddy => Suballocator::Buddy(Buddy::new(block_size)),
+                Strategy::Linear => Suballocator::Linear(Linear::new(block_size)),
+            },
+        };
+        pools.next_block_id += 1;
+        let allocation = allocation(&mut block).expect("Allocation does not fit an empty block");
+        pools.blocks.entry(pool).or_default().push(block);
+        Ok(allocation)
+    }
+
+    /// Allocates device memory and maps it if it is host visible.
+    unsafe fn allocate_memory(
+        &self,
+        memory_type_index: u32,
+        size: vk::DeviceSize,
+    ) -> Result<(vk::DeviceMemory, *mut u8), AllocatorError> {
+        let allocate_info = vk::MemoryAllocateInfo {
+            allocation_size: size,
+            memory_type_index,
+            ..Default::default()
+        };
+        let memory = self
+            .device
+            .allocate_memory(&allocate_info, None)
+            .map_err(AllocatorError::Vulkan)?;
+        let flags = self.memory_properties.memory_types[memory_type_index as usize].property_flags;
+        if !flags.contains(vk::MemoryPropertyFlags::HOST_VISIBLE) {
+            return Ok((memory, ptr::null_mut()));
+        }
+        match self
+            .device
+            .map_memory(memory, 0, vk::WHOLE_SIZE, vk::MemoryMapFlags::empty())
+        {
+            Ok(mapped) => Ok((memory, mapped as *mut u8)),
+            Err(err) => {
+                self.device.free_memory(memory, None);
+                Err(AllocatorError::Vulkan(err))
+            }
+        }
+    }
+
+    /// Returns the range of `allocation` to its block. Blocks that become empty are freed,
+    /// unless they are the last block of their pool. Panics if `allocation` was not made by
+    /// this allocator.
+    pub unsafe fn free(&self, allocation: Allocation) {
+        let mut pools = self.pools.lock().unwrap();
+        let (pool, block_id) = match allocation.location {
+            Location::Dedicated => {
+                pools
+                    .dedicated
+                    .remove(&allocation.memory)
+                    .expect("Allocation does not belong to this allocator");
+                pools.tags.remove(&(allocation.memory, allocation.offset));
+                self.device.free_memory(allocation.memory, None);
+                return;
+            }
+            Location::Block { pool, block } => (pool, block),
+        };
+        let blocks = pools
+            .blocks
+            .get_mut(&pool)
+            .expect("Allocation does not belong to this allocator");
+        // Another allocator may have a block with the same id
+        let index = blocks
+            .iter()
+            .position(|block| block.id == block_id && block.memory == allocation.memory)
+            .expect("Allocation does not belong to this allocator");
+        blocks[index].free(allocation.offset);
+        if blocks[index].allocation_count() == 0 && blocks.len() > 1 {
+            let block = blocks.swap_remove(index);
+            self.device.free_memory(block.memory, None);
+        }
+        pools.tags.remove(&(allocation.memory, allocation.offset));
+    }
+
+    /// Creates a buffer and binds it to a new allocation.
+    pub unsafe fn create_buffer(
+        &self,
+        create_info: &vk::BufferCreateInfo,
+        info: &AllocationCreateInfo,
+    ) -> Result<(vk::Buffer, Allocation), AllocatorError> {
+        let buffer = self
+            .device
+            .create_buffer(create_info, None)
+            .map_err(AllocatorError::Vulkan)?;
+        let requirements = self.device.get_buffer_memory_requirements(buffer);
+        let info = AllocationCreateInfo {
+            resource_type: ResourceType::Buffer,
+            ..*info
+        };
+        let bound = self.allocate(&requirements, &info).and_then(|allocation| {
+            match self
+                .device
+                .bind_buffer_memory(buffer, allocation.memory, allocation.offset)
+            {
+                Ok(()) => Ok(allocation),
+                Err(err) => {
+                    self.free(allocation);
+                    Err(AllocatorError::Vulkan(err))
+                }
+            }
+        });
+        match bound {
+            Ok(allocation) => Ok((buffer, allocation)),
+            Err(err) => {
+                self.device.destroy_buffer(buffer, None);
+                Err(err)
+            }
+        }
+    }
+
+    /// Creates an image and binds it to a new allocation.
+    pub unsafe fn create_image(
+        &self,
+        create_info: &vk::ImageCreateInfo,
+        info: &AllocationCreateInfo,
+    ) -> Result<(vk::Image, Allocation), AllocatorError> {
+        let image = self
+            .device
+            .create_image(create_info, None)
+            .map_err(AllocatorError::Vulkan)?;
+        let requirements = self.device.get_image_memory_requirements(image);
+        let info = AllocationCreateInfo {
+            resource_type: if create_info.tiling == vk::ImageTiling::OPTIMAL {
+                ResourceType::OptimalImage
+            } else {
+                ResourceType::LinearImage
+            },
+            ..*info
+        };
+        let bound = self.allocate(&requirements, &info).and_then(|allocation| {
+            match self
+                .device
+                .bind_image_memory(image, allocation.memory, allocation.offset)
+            {
+                Ok(()) => Ok(allocation),
+                Err(err) => {
+                    self.free(allocation);
+                    Err(AllocatorError::Vulkan(err))
+                }
+            }
+        });
+        match bound {
+            Ok(allocation) => Ok((image, allocation)),
+            Err(err) => {
+                self.device.destroy_image(image, None);
+                Err(err)
+            }
+        }
+    }
+
+    pub unsafe fn destroy_buffer(&self, buffer: vk::Buffer, allocation: Allocation) {
+        self.device.destroy_buffer(buffer, None);
+        self.free(allocation);
+    }
+
+    pub unsafe fn destroy_image(&self, image: vk::Image, allocation: Allocation) {
+        self.device.destroy_image(image, None);
+        self.free(allocation);
+    }
+
+    /// The number of `vk::DeviceMemory` objects that are currently allocated.
+    pub fn device_memory_count(&self) -> usize {
+        let pools = self.pools.lock().unwrap();
+        pools.dedicated.len() + pools.blocks.values().map(Vec::len).sum::<usize>()
+    }
+
+    /// Counts `allocation` towards the tag `name` in reports, until it is freed.
+    pub fn set_tag(&self, allocation: &Allocation, name: &str) {
+        self.pools.lock().unwrap().tags.insert(
+            (allocation.memory, allocation.offset),
+            (name.to_owned(), allocation.size),
+        );
+    }
+
+    /// A snapshot of the blocks and dedicated allocations of the allocator. See the
+    /// [report module](report/index.html).
+    pub fn report(&self) -> MemoryReport {
+        let pools = self.pools.lock().unwrap();
+        let mut blocks = Vec::new();
+        for (pool, pool_blocks) in &pools.blocks {
+            for block in pool_blocks {
+                blocks.push((pool.memory_type_index, block.report()));
+            }
+        }
+        for (&memory, &(memory_type_index, size)) in &pools.dedicated {
+            let block = BlockReport {
+                memory: memory.as_raw(),
+                size,
+                dedicated: true,
+                used: size,
+                allocation_count: 1,
+                free_region_count: 0,
+                largest_free_region: 0,
+            };
+            blocks.push((memory_type_index, block));
+        }
+        let mut tags = BTreeMap::new();
+        for &(ref name, size) in pools.tags.values() {
+            let total = tags.entry(name.clone()).or_insert((0, 0));
+            total.0 += size;
+            total.1 += 1;
+        }
+        MemoryReport::new(&self.memory_properties, blocks, tags)
+    }
+}
+
+impl<D: DeviceV1_0> Drop for Allocator<D> {
+    fn drop(&mut self) {
+        // A panic in `free` poisons the lock, the memory still has to be freed
+        let pools = self.pools.get_mut().unwrap_or_else(|err| err.into_inner());
+        unsafe {
+            for block in pools.blocks.values().flat_map(|blocks| blocks.iter()) {
+                self.device.free_memory(block.memory, None);
+            }
+            for &memory in pools.dedicated.keys() {
+                self.device.free_memory(memory, None);
+            }
+        }
+    }
+}
+
+impl<D: DeviceV1_0> fmt::Debug for Allocator<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Allocator")
+            .field("device", &self.device.handle())
+            .field("buffer_image_granularity", &self.buffer_image_granularity)
+            .field("block_size", &self.block_size)
+            .finish()
+    }
+}
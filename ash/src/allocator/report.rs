@@ -0,0 +1,627 @@
+//! Device memory reports.
+//!
+//! A `MemoryReport` is a snapshot of the device memory of an `Allocator` or a
+//! `ReportingDevice`, grouped by memory heap and memory type, with the blocks of every memory
+//! type and the totals of user supplied tags:
+//!
+//! ```no_run
+//! # use ash::allocator::report::ReportingDevice;
+//! # use ash::version::{DeviceV1_0, InstanceV1_0};
+//! # use ash::vk;
+//! # unsafe fn test(instance: &ash::Instance, physical_device: vk::PhysicalDevice, device: ash::Device, info: &vk::MemoryAllocateInfo) {
+//! let memory_properties = instance.get_physical_device_memory_properties(physical_device);
+//! let device = ReportingDevice::new(device, memory_properties);
+//! let before = device.report();
+//! let memory = device.allocate_memory(info, None).unwrap();
+//! device.set_tag(memory, "shadow maps");
+//! let after = device.report();
+//! println!("{}", after.to_json());
+//! println!("{}", after.diff(&before).to_json());
+//! # }
+//! ```
+//!
+//! The JSON output is sorted and has one heap, memory type, block or tag per line, so two
+//! reports can also be compared with a text diff.
+use device::Device;
+use prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fmt::Write;
+use std::sync::Mutex;
+use version::{DeviceV1_0, DeviceV1_1};
+use vk;
+use vk::Handle;
+
+/// Memory of a heap or memory type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryTotals {
+    /// Bytes of `vk::DeviceMemory` objects.
+    pub allocated: vk::DeviceSize,
+    /// Bytes of those that are handed out to allocations.
+    pub used: vk::DeviceSize,
+    pub device_memory_count: usize,
+    pub allocation_count: usize,
+}
+
+impl MemoryTotals {
+    fn add(&mut self, block: &BlockReport) {
+        self.allocated += block.size;
+        self.used += block.used;
+        self.device_memory_count += 1;
+        self.allocation_count += block.allocation_count;
+    }
+}
+
+/// One `vk::DeviceMemory`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockReport {
+    /// The raw handle of the memory.
+    pub memory: u64,
+    pub size: vk::DeviceSize,
+    /// Whether the memory holds a single allocation instead of being sub-allocated.
+    pub dedicated: bool,
+    pub used: vk::DeviceSize,
+    pub allocation_count: usize,
+    pub free_region_count: usize,
+    pub largest_free_region: vk::DeviceSize,
+}
+
+impl BlockReport {
+    pub fn free(&self) -> vk::DeviceSize {
+        self.size - self.used
+    }
+
+    /// The share of free memory that is not part of the largest free region, from 0 for no
+    /// fragmentation to almost 1.
+    pub fn fragmentation(&self) -> f64 {
+        fragmentation(self.free(), self.largest_free_region)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeapReport {
+    pub index: u32,
+    pub size: vk::DeviceSize,
+    pub flags: vk::MemoryHeapFlags,
+    pub totals: MemoryTotals,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryTypeReport {
+    pub index: u32,
+    pub heap_index: u32,
+    pub property_flags: vk::MemoryPropertyFlags,
+    pub totals: MemoryTotals,
+    /// Sorted by handle.
+    pub blocks: Vec<BlockReport>,
+}
+
+impl MemoryTypeReport {
+    /// Like `BlockReport::fragmentation`, over the free memory of all blocks.
+    pub fn fragmentation(&self) -> f64 {
+        let largest = self
+            .blocks
+            .iter()
+            .map(|block| block.largest_free_region)
+            .max()
+            .unwrap_or(0);
+        fragmentation(self.totals.allocated - self.totals.used, largest)
+    }
+}
+
+/// The allocations that were tagged with `name`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagReport {
+    pub name: String,
+    pub size: vk::DeviceSize,
+    pub allocation_count: usize,
+}
+
+/// A snapshot of device memory. See the module documentation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryReport {
+    pub heaps: Vec<HeapReport>,
+    pub memory_types: Vec<MemoryTypeReport>,
+    /// Sorted by name.
+    pub tags: Vec<TagReport>,
+}
+
+impl MemoryReport {
+    /// Groups `blocks`, given with their memory type index, by memory type and heap.
+    pub(crate) fn new(
+        memory_properties: &vk::PhysicalDeviceMemoryProperties,
+        blocks: Vec<(u32, BlockReport)>,
+        tags: BTreeMap<String, (vk::DeviceSize, usize)>,
+    ) -> MemoryReport {
+        let heap_count = memory_properties.memory_heap_count as usize;
+        let type_count = memory_properties.memory_type_count as usize;
+        let mut heaps: Vec<HeapReport> = memory_properties.memory_heaps[..heap_count]
+            .iter()
+            .enumerate()
+            .map(|(index, heap)| HeapReport {
+                index: index as u32,
+                size: heap.size,
+                flags: heap.flags,
+                totals: MemoryTotals::default(),
+            })
+            .collect();
+        let mut memory_types: Vec<MemoryTypeReport> = memory_properties.memory_types[..type_count]
+            .iter()
+            .enumerate()
+            .map(|(index, memory_type)| MemoryTypeReport {
+                index: index as u32,
+                heap_index: memory_type.heap_index,
+                property_flags: memory_type.property_flags,
+                totals: MemoryTotals::default(),
+                blocks: Vec::new(),
+            })
+            .collect();
+        for (memory_type_index, block) in blocks {
+            let memory_type = &mut memory_types[memory_type_index as usize];
+            memory_type.totals.add(&block);
+            heaps[memory_type.heap_index as usize].totals.add(&block);
+            memory_type.blocks.push(block);
+        }
+        for memory_type in &mut memory_types {
+            memory_type.blocks.sort_by_key(|block| block.memory);
+        }
+        MemoryReport {
+            heaps,
+            memory_types,
+            tags: tags
+                .into_iter()
+                .map(|(name, (size, allocation_count))| TagReport {
+                    name,
+                    size,
+                    allocation_count,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&TagReport> {
+        self.tags.iter().find(|tag| tag.name == name)
+    }
+
+    /// The totals of all heaps.
+    pub fn total(&self) -> MemoryTotals {
+        let mut total = MemoryTotals::default();
+        for heap in &self.heaps {
+            total.allocated += heap.totals.allocated;
+            total.used += heap.totals.used;
+            total.device_memory_count += heap.totals.device_memory_count;
+            total.allocation_count += heap.totals.allocation_count;
+        }
+        total
+    }
+
+    /// What changed since `earlier`. Heaps, memory types and tags that did not change are
+    /// left out.
+    pub fn diff(&self, earlier: &MemoryReport) -> MemoryReportDiff {
+        let heaps = self
+            .heaps
+            .iter()
+            .map(|heap| {
+                let before = earlier
+                    .heaps
+                    .iter()
+                    .find(|before| before.index == heap.index)
+                    .map(|before| before.totals)
+                    .unwrap_or_default();
+                (heap.index, TotalsDelta::new(&heap.totals, &before))
+            })
+            .filter(|&(_, delta)| !delta.is_zero())
+            .collect();
+        let memory_types = self
+            .memory_types
+            .iter()
+            .map(|memory_type| {
+                let before = earlier
+                    .memory_types
+                    .iter()
+                    .find(|before| before.index == memory_type.index)
+                    .map(|before| before.totals)
+                    .unwrap_or_default();
+                (
+                    memory_type.index,
+                    TotalsDelta::new(&memory_type.totals, &before),
+                )
+            })
+            .filter(|&(_, delta)| !delta.is_zero())
+            .collect();
+
+        let mut tags: BTreeMap<&str, TagDelta> = BTreeMap::new();
+        for tag in &self.tags {
+            let delta = tags.entry(&tag.name).or_default();
+            delta.size += tag.size as i64;
+            delta.allocation_count += tag.allocation_count as i64;
+        }
+        for tag in &earlier.tags {
+            let delta = tags.entry(&tag.name).or_default();
+            delta.size -= tag.size as i64;
+            delta.allocation_count -= tag.allocation_count as i64;
+        }
+
+        let blocks = |report: &MemoryReport| -> BTreeMap<u64, u32> {
+            report
+                .memory_types
+                .iter()
+                .flat_map(|memory_type| {
+                    memory_type
+                        .blocks
+                        .iter()
+                        .map(move |block| (block.memory, memory_type.index))
+                })
+                .collect()
+        };
+        let (blocks, earlier_blocks) = (blocks(self), blocks(earlier));
+        MemoryReportDiff {
+            heaps,
+            memory_types,
+            tags: tags
+                .into_iter()
+                .filter(|&(_, delta)| delta != TagDelta::default())
+                .map(|(name, delta)| (name.to_owned(), delta))
+                .collect(),
+            new_blocks: blocks
+                .iter()
+                .filter(|&(memory, _)| !earlier_blocks.contains_key(memory))
+                .map(|(&memory, &memory_type_index)| (memory, memory_type_index))
+                .collect(),
+            freed_blocks: earlier_blocks
+                .iter()
+                .filter(|&(memory, _)| !blocks.contains_key(memory))
+                .map(|(&memory, &memory_type_index)| (memory, memory_type_index))
+                .collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json).unwrap();
+        json
+    }
+
+    fn write_json(&self, json: &mut String) -> fmt::Result {
+        writeln!(json, "{{")?;
+        writeln!(json, "  \"heaps\": [")?;
+        for (i, heap) in self.heaps.iter().enumerate() {
+            write!(
+                json,
+                "    {{\"index\": {}, \"size\": {}, \"flags\": ",
+                heap.index, heap.size
+            )?;
+            write_string(json, &heap.flags.to_string())?;
+            write!(json, ", ")?;
+            write_totals(json, &heap.totals)?;
+            writeln!(json, "}}{}", separator(i, self.heaps.len()))?;
+        }
+        writeln!(json, "  ],")?;
+        writeln!(json, "  \"memory_types\": [")?;
+        for (i, memory_type) in self.memory_types.iter().enumerate() {
+            write!(
+                json,
+                "    {{\"index\": {}, \"heap_index\": {}, \"property_flags\": ",
+                memory_type.index, memory_type.heap_index
+            )?;
+            write_string(json, &memory_type.property_flags.to_string())?;
+            write!(json, ", ")?;
+            write_totals(json, &memory_type.totals)?;
+            writeln!(
+                json,
+                ", \"fragmentation\": {:.4}, \"blocks\": [",
+                memory_type.fragmentation()
+            )?;
+            for (j, block) in memory_type.blocks.iter().enumerate() {
+                writeln!(
+                    json,
+                    "      {{\"memory\": \"0x{:x}\", \"size\": {}, \"dedicated\": {}, \"used\": {}, \
+                     \"allocation_count\": {}, \"free_region_count\": {}, \
+                     \"largest_free_region\": {}, \"fragmentation\": {:.4}}}{}",
+                    block.memory,
+                    block.size,
+                    block.dedicated,
+                    block.used,
+                    block.allocation_count,
+                    block.free_region_count,
+                    block.largest_free_region,
+                    block.fragmentation(),
+                    separator(j, memory_type.blocks.len())
+                )?;
+            }
+            writeln!(json, "    ]}}{}", separator(i, self.memory_types.len()))?;
+        }
+        writeln!(json, "  ],")?;
+        writeln!(json, "  \"tags\": [")?;
+        for (i, tag) in self.tags.iter().enumerate() {
+            write!(json, "    {{\"name\": ")?;
+            write_string(json, &tag.name)?;
+            writeln!(
+                json,
+                ", \"size\": {}, \"allocation_count\": {}}}{}",
+                tag.size,
+                tag.allocation_count,
+                separator(i, self.tags.len())
+            )?;
+        }
+        writeln!(json, "  ]")?;
+        writeln!(json, "}}")
+    }
+}
+
+/// The change of a `MemoryTotals` between two reports.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TotalsDelta {
+    pub allocated: i64,
+    pub used: i64,
+    pub device_memory_count: i64,
+    pub allocation_count: i64,
+}
+
+impl TotalsDelta {
+    fn new(totals: &MemoryTotals, earlier: &MemoryTotals) -> TotalsDelta {
+        TotalsDelta {
+            allocated: totals.allocated as i64 - earlier.allocated as i64,
+            used: totals.used as i64 - earlier.used as i64,
+            device_memory_count: totals.device_memory_count as i64
+                - earlier.device_memory_count as i64,
+            allocation_count: totals.allocation_count as i64 - earlier.allocation_count as i64,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == TotalsDelta::default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TagDelta {
+    pub size: i64,
+    pub allocation_count: i64,
+}
+
+/// The difference between two `MemoryReport`s, see `MemoryReport::diff`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryReportDiff {
+    /// By heap index.
+    pub heaps: Vec<(u32, TotalsDelta)>,
+    /// By memory type index.
+    pub memory_types: Vec<(u32, TotalsDelta)>,
+    /// By tag name.
+    pub tags: Vec<(String, TagDelta)>,
+    /// Raw handles and memory type indices of blocks that were allocated.
+    pub new_blocks: Vec<(u64, u32)>,
+    /// Raw handles and memory type indices of blocks that were freed.
+    pub freed_blocks: Vec<(u64, u32)>,
+}
+
+impl MemoryReportDiff {
+    pub fn is_empty(&self) -> bool {
+        self.heaps.is_empty()
+            && self.memory_types.is_empty()
+            && self.tags.is_empty()
+            && self.new_blocks.is_empty()
+            && self.freed_blocks.is_empty()
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json).unwrap();
+        json
+    }
+
+    fn write_json(&self, json: &mut String) -> fmt::Result {
+        writeln!(json, "{{")?;
+        for &(key, deltas) in &[("heaps", &self.heaps), ("memory_types", &self.memory_types)] {
+            writeln!(json, "  \"{}\": [", key)?;
+            for (i, &(index, ref delta)) in deltas.iter().enumerate() {
+                writeln!(
+                    json,
+                    "    {{\"index\": {}, \"allocated\": {}, \"used\": {}, \
+                     \"device_memory_count\": {}, \"allocation_count\": {}}}{}",
+                    index,
+                    delta.allocated,
+                    delta.used,
+                    delta.device_memory_count,
+                    delta.allocation_count,
+                    separator(i, deltas.len())
+                )?;
+            }
+            writeln!(json, "  ],")?;
+        }
+        writeln!(json, "  \"tags\": [")?;
+        for (i, &(ref name, delta)) in self.tags.iter().enumerate() {
+            write!(json, "    {{\"name\": ")?;
+            write_string(json, name)?;
+            writeln!(
+                json,
+                ", \"size\": {}, \"allocation_count\": {}}}{}",
+                delta.size,
+                delta.allocation_count,
+                separator(i, self.tags.len())
+            )?;
+        }
+        writeln!(json, "  ],")?;
+        for &(key, blocks, last) in &[
+            ("new_blocks", &self.new_blocks, false),
+            ("freed_blocks", &self.freed_blocks, true),
+        ] {
+            writeln!(json, "  \"{}\": [", key)?;
+            for (i, &(memory, memory_type_index)) in blocks.iter().enumerate() {
+                writeln!(
+                    json,
+                    "    {{\"memory\": \"0x{:x}\", \"memory_type_index\": {}}}{}",
+                    memory,
+                    memory_type_index,
+                    separator(i, blocks.len())
+                )?;
+            }
+            writeln!(json, "  ]{}", if last { "" } else { "," })?;
+        }
+        writeln!(json, "}}")
+    }
+}
+
+fn fragmentation(free: vk::DeviceSize, largest_free_region: vk::DeviceSize) -> f64 {
+    if free == 0 {
+        0.0
+    } else {
+        1.0 - largest_free_region as f64 / free as f64
+    }
+}
+
+fn separator(index: usize, len: usize) -> &'static str {
+    if index + 1 < len {
+        ","
+    } else {
+        ""
+    }
+}
+
+fn write_totals(json: &mut String, totals: &MemoryTotals) -> fmt::Result {
+    write!(
+        json,
+        "\"allocated\": {}, \"used\": {}, \"device_memory_count\": {}, \"allocation_count\": {}",
+        totals.allocated, totals.used, totals.device_memory_count, totals.allocation_count
+    )
+}
+
+fn write_string(json: &mut String, value: &str) -> fmt::Result {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32)?,
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    Ok(())
+}
+
+struct DeviceMemoryInfo {
+    memory_type_index: u32,
+    size: vk::DeviceSize,
+    tag: Option<String>,
+}
+
+/// A device that records the memory allocated with `allocate_memory` and freed with
+/// `free_memory`. Every `vk::DeviceMemory` is reported as a dedicated block. When it is used
+/// by an `Allocator`, the allocator's own `report` also shows how the blocks are
+/// sub-allocated.
+pub struct ReportingDevice<D = Device> {
+    device: D,
+    memory_properties: vk::PhysicalDeviceMemoryProperties,
+    allocations: Mutex<HashMap<vk::DeviceMemory, DeviceMemoryInfo>>,
+}
+
+impl<D: DeviceV1_0> ReportingDevice<D> {
+    pub fn new(device: D, memory_properties: vk::PhysicalDeviceMemoryProperties) -> Self {
+        ReportingDevice {
+            device,
+            memory_properties,
+            allocations: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    pub fn into_inner(self) -> D {
+        self.device
+    }
+
+    /// Counts `memory` towards the tag `name` in reports. Does nothing if `memory` was not
+    /// allocated through this device.
+    pub fn set_tag(&self, memory: vk::DeviceMemory, name: &str) {
+        if let Some(info) = self.allocations.lock().unwrap().get_mut(&memory) {
+            info.tag = Some(name.to_owned());
+        }
+    }
+
+    pub fn report(&self) -> MemoryReport {
+        let allocations = self.allocations.lock().unwrap();
+        let mut tags = BTreeMap::new();
+        let blocks = allocations
+            .iter()
+            .map(|(&memory, info)| {
+                if let Some(ref tag) = info.tag {
+                    let total = tags.entry(tag.clone()).or_insert((0, 0));
+                    total.0 += info.size;
+                    total.1 += 1;
+                }
+                let block = BlockReport {
+                    memory: memory.as_raw(),
+                    size: info.size,
+                    dedicated: true,
+                    used: info.size,
+                    allocation_count: 1,
+                    free_region_count: 0,
+                    largest_free_region: 0,
+                };
+                (info.memory_type_index, block)
+            })
+            .collect();
+        MemoryReport::new(&self.memory_properties, blocks, tags)
+    }
+}
+
+impl<D: DeviceV1_0> DeviceV1_0 for ReportingDevice<D> {
+    fn handle(&self) -> vk::Device {
+        self.device.handle()
+    }
+
+    fn fp_v1_0(&self) -> &vk::DeviceFnV1_0 {
+        self.device.fp_v1_0()
+    }
+
+    unsafe fn allocate_memory(
+        &self,
+        create_info: &vk::MemoryAllocateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<vk::DeviceMemory> {
+        let memory = self
+            .device
+            .allocate_memory(create_info, allocation_callbacks)?;
+        self.allocations.lock().unwrap().insert(
+            memory,
+            DeviceMemoryInfo {
+                memory_type_index: create_info.memory_type_index,
+                size: create_info.allocation_size,
+                tag: None,
+            },
+        );
+        Ok(memory)
+    }
+
+    unsafe fn free_memory(
+        &self,
+        memory: vk::DeviceMemory,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) {
+        self.allocations.lock().unwrap().remove(&memory);
+        self.device.free_memory(memory, allocation_callbacks);
+    }
+}
+
+impl<D: DeviceV1_1> DeviceV1_1 for ReportingDevice<D> {
+    fn fp_v1_1(&self) -> &vk::DeviceFnV1_1 {
+        self.device.fp_v1_1()
+    }
+}
+
+impl<D> fmt::Debug for ReportingDevice<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ReportingDevice")
+            .field(
+                "device_memory_count",
+                &self.allocations.lock().unwrap().len(),
+            )
+            .finish()
+    }
+}
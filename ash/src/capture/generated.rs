@@ -0,0 +1,14374 @@
+use super::{Context, Decode, Encode, Reader, ReplayError, ReplayResult, Writer};
+use std::mem;
+use std::os::raw::*;
+use vk::*;
+codec_raw!(
+    AccelerationStructureMemoryRequirementsTypeNV,
+    AccelerationStructureTypeNV,
+    AccessFlags,
+    AndroidSurfaceCreateFlagsKHR,
+    AttachmentDescriptionFlags,
+    AttachmentLoadOp,
+    AttachmentStoreOp,
+    BlendFactor,
+    BlendOp,
+    BlendOverlapEXT,
+    BorderColor,
+    BufferCreateFlags,
+    BufferUsageFlags,
+    BufferViewCreateFlags,
+    BuildAccelerationStructureFlagsNV,
+    ChromaLocation,
+    CoarseSampleOrderTypeNV,
+    ColorComponentFlags,
+    ColorSpaceKHR,
+    CommandBufferLevel,
+    CommandBufferResetFlags,
+    CommandBufferUsageFlags,
+    CommandPoolCreateFlags,
+    CommandPoolResetFlags,
+    CommandPoolTrimFlags,
+    CompareOp,
+    ComponentSwizzle,
+    CompositeAlphaFlagsKHR,
+    ConditionalRenderingFlagsEXT,
+    ConservativeRasterizationModeEXT,
+    CopyAccelerationStructureModeNV,
+    CoverageModulationModeNV,
+    CullModeFlags,
+    DebugReportFlagsEXT,
+    DebugReportObjectTypeEXT,
+    DebugUtilsMessageSeverityFlagsEXT,
+    DebugUtilsMessageTypeFlagsEXT,
+    DebugUtilsMessengerCallbackDataFlagsEXT,
+    DebugUtilsMessengerCreateFlagsEXT,
+    DependencyFlags,
+    DescriptorBindingFlagsEXT,
+    DescriptorPoolCreateFlags,
+    DescriptorPoolResetFlags,
+    DescriptorSetLayoutCreateFlags,
+    DescriptorType,
+    DescriptorUpdateTemplateCreateFlags,
+    DescriptorUpdateTemplateType,
+    DeviceCreateFlags,
+    DeviceEventTypeEXT,
+    DeviceGroupPresentModeFlagsKHR,
+    DeviceQueueCreateFlags,
+    DiscardRectangleModeEXT,
+    DisplayEventTypeEXT,
+    DisplayModeCreateFlagsKHR,
+    DisplayPlaneAlphaFlagsKHR,
+    DisplayPowerStateEXT,
+    DisplaySurfaceCreateFlagsKHR,
+    DriverIdKHR,
+    DynamicState,
+    EventCreateFlags,
+    ExternalFenceFeatureFlags,
+    ExternalFenceHandleTypeFlags,
+    ExternalMemoryFeatureFlags,
+    ExternalMemoryFeatureFlagsNV,
+    ExternalMemoryHandleTypeFlags,
+    ExternalMemoryHandleTypeFlagsNV,
+    ExternalSemaphoreFeatureFlags,
+    ExternalSemaphoreHandleTypeFlags,
+    FenceCreateFlags,
+    FenceImportFlags,
+    Filter,
+    Format,
+    FormatFeatureFlags,
+    FramebufferCreateFlags,
+    FrontFace,
+    GeometryFlagsNV,
+    GeometryInstanceFlagsNV,
+    GeometryTypeNV,
+    IOSSurfaceCreateFlagsMVK,
+    ImageAspectFlags,
+    ImageCreateFlags,
+    ImageLayout,
+    ImagePipeSurfaceCreateFlagsFUCHSIA,
+    ImageTiling,
+    ImageType,
+    ImageUsageFlags,
+    ImageViewCreateFlags,
+    ImageViewType,
+    IndexType,
+    IndirectCommandsLayoutUsageFlagsNVX,
+    IndirectCommandsTokenTypeNVX,
+    InstanceCreateFlags,
+    InternalAllocationType,
+    LogicOp,
+    MacOSSurfaceCreateFlagsMVK,
+    MemoryAllocateFlags,
+    MemoryHeapFlags,
+    MemoryMapFlags,
+    MemoryOverallocationBehaviorAMD,
+    MemoryPropertyFlags,
+    ObjectEntryTypeNVX,
+    ObjectEntryUsageFlagsNVX,
+    ObjectType,
+    PeerMemoryFeatureFlags,
+    PhysicalDeviceType,
+    PipelineBindPoint,
+    PipelineCacheCreateFlags,
+    PipelineCacheHeaderVersion,
+    PipelineColorBlendStateCreateFlags,
+    PipelineCoverageModulationStateCreateFlagsNV,
+    PipelineCoverageToColorStateCreateFlagsNV,
+    PipelineCreateFlags,
+    PipelineDepthStencilStateCreateFlags,
+    PipelineDiscardRectangleStateCreateFlagsEXT,
+    PipelineDynamicStateCreateFlags,
+    PipelineInputAssemblyStateCreateFlags,
+    PipelineLayoutCreateFlags,
+    PipelineMultisampleStateCreateFlags,
+    PipelineRasterizationConservativeStateCreateFlagsEXT,
+    PipelineRasterizationStateCreateFlags,
+    PipelineRasterizationStateStreamCreateFlagsEXT,
+    PipelineShaderStageCreateFlags,
+    PipelineStageFlags,
+    PipelineTessellationStateCreateFlags,
+    PipelineVertexInputStateCreateFlags,
+    PipelineViewportStateCreateFlags,
+    PipelineViewportSwizzleStateCreateFlagsNV,
+    PointClippingBehavior,
+    PolygonMode,
+    PresentModeKHR,
+    PrimitiveTopology,
+    QueryControlFlags,
+    QueryPipelineStatisticFlags,
+    QueryPoolCreateFlags,
+    QueryResultFlags,
+    QueryType,
+    QueueFlags,
+    QueueGlobalPriorityEXT,
+    RasterizationOrderAMD,
+    RayTracingShaderGroupTypeNV,
+    RenderPassCreateFlags,
+    Result,
+    SampleCountFlags,
+    SamplerAddressMode,
+    SamplerCreateFlags,
+    SamplerMipmapMode,
+    SamplerReductionModeEXT,
+    SamplerYcbcrModelConversion,
+    SamplerYcbcrRange,
+    SemaphoreCreateFlags,
+    SemaphoreImportFlags,
+    ShaderInfoTypeAMD,
+    ShaderModuleCreateFlags,
+    ShaderStageFlags,
+    ShadingRatePaletteEntryNV,
+    SharingMode,
+    SparseImageFormatFlags,
+    SparseMemoryBindFlags,
+    StencilFaceFlags,
+    StencilOp,
+    StructureType,
+    SubgroupFeatureFlags,
+    SubpassContents,
+    SubpassDescriptionFlags,
+    SurfaceCounterFlagsEXT,
+    SurfaceTransformFlagsKHR,
+    SwapchainCreateFlagsKHR,
+    SystemAllocationScope,
+    TessellationDomainOrigin,
+    TimeDomainEXT,
+    ValidationCacheCreateFlagsEXT,
+    ValidationCacheHeaderVersionEXT,
+    ValidationCheckEXT,
+    VendorId,
+    VertexInputRate,
+    ViSurfaceCreateFlagsNN,
+    ViewportCoordinateSwizzleNV,
+    WaylandSurfaceCreateFlagsKHR,
+    Win32SurfaceCreateFlagsKHR,
+    XcbSurfaceCreateFlagsKHR,
+    XlibSurfaceCreateFlagsKHR
+);
+codec_union!(ClearColorValue, ClearValue);
+impl Encode for AccelerationStructureCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.compacted_size.encode(w);
+        self.info.encode(w);
+    }
+}
+impl Decode for AccelerationStructureCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AccelerationStructureCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            compacted_size: Decode::decode(r, ctx)?,
+            info: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AccelerationStructureInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.ty.encode(w);
+        self.flags.encode(w);
+        self.instance_count.encode(w);
+        self.geometry_count.encode(w);
+        w.array(self.p_geometries, self.geometry_count as usize);
+    }
+}
+impl Decode for AccelerationStructureInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AccelerationStructureInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            ty: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            instance_count: Decode::decode(r, ctx)?,
+            geometry_count: Decode::decode(r, ctx)?,
+            p_geometries: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for AccelerationStructureMemoryRequirementsInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.ty.encode(w);
+        self.acceleration_structure.encode(w);
+    }
+}
+impl Decode for AccelerationStructureMemoryRequirementsInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AccelerationStructureMemoryRequirementsInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            ty: Decode::decode(r, ctx)?,
+            acceleration_structure: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AcquireNextImageInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.swapchain.encode(w);
+        self.timeout.encode(w);
+        self.semaphore.encode(w);
+        self.fence.encode(w);
+        self.device_mask.encode(w);
+    }
+}
+impl Decode for AcquireNextImageInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AcquireNextImageInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            swapchain: Decode::decode(r, ctx)?,
+            timeout: Decode::decode(r, ctx)?,
+            semaphore: Decode::decode(r, ctx)?,
+            fence: Decode::decode(r, ctx)?,
+            device_mask: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AllocationCallbacks {
+    unsafe fn encode(&self, _w: &mut Writer) {}
+}
+impl Decode for AllocationCallbacks {
+    unsafe fn decode(_r: &mut Reader, _ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AllocationCallbacks {
+            p_user_data: mem::zeroed(),
+            pfn_allocation: mem::zeroed(),
+            pfn_reallocation: mem::zeroed(),
+            pfn_free: mem::zeroed(),
+            pfn_internal_allocation: mem::zeroed(),
+            pfn_internal_free: mem::zeroed(),
+        })
+    }
+}
+impl Encode for AndroidHardwareBufferFormatPropertiesANDROID {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.format.encode(w);
+        self.external_format.encode(w);
+        self.format_features.encode(w);
+        self.sampler_ycbcr_conversion_components.encode(w);
+        self.suggested_ycbcr_model.encode(w);
+        self.suggested_ycbcr_range.encode(w);
+        self.suggested_x_chroma_offset.encode(w);
+        self.suggested_y_chroma_offset.encode(w);
+    }
+}
+impl Decode for AndroidHardwareBufferFormatPropertiesANDROID {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AndroidHardwareBufferFormatPropertiesANDROID {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            format: Decode::decode(r, ctx)?,
+            external_format: Decode::decode(r, ctx)?,
+            format_features: Decode::decode(r, ctx)?,
+            sampler_ycbcr_conversion_components: Decode::decode(r, ctx)?,
+            suggested_ycbcr_model: Decode::decode(r, ctx)?,
+            suggested_ycbcr_range: Decode::decode(r, ctx)?,
+            suggested_x_chroma_offset: Decode::decode(r, ctx)?,
+            suggested_y_chroma_offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AndroidHardwareBufferPropertiesANDROID {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.allocation_size.encode(w);
+        self.memory_type_bits.encode(w);
+    }
+}
+impl Decode for AndroidHardwareBufferPropertiesANDROID {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AndroidHardwareBufferPropertiesANDROID {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            allocation_size: Decode::decode(r, ctx)?,
+            memory_type_bits: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AndroidHardwareBufferUsageANDROID {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.android_hardware_buffer_usage.encode(w);
+    }
+}
+impl Decode for AndroidHardwareBufferUsageANDROID {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AndroidHardwareBufferUsageANDROID {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            android_hardware_buffer_usage: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AndroidSurfaceCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.window.encode(w);
+    }
+}
+impl Decode for AndroidSurfaceCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AndroidSurfaceCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            window: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ApplicationInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        w.str(self.p_application_name);
+        self.application_version.encode(w);
+        w.str(self.p_engine_name);
+        self.engine_version.encode(w);
+        self.api_version.encode(w);
+    }
+}
+impl Decode for ApplicationInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ApplicationInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            p_application_name: ctx.str(r)?,
+            application_version: Decode::decode(r, ctx)?,
+            p_engine_name: ctx.str(r)?,
+            engine_version: Decode::decode(r, ctx)?,
+            api_version: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AttachmentDescription {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.flags.encode(w);
+        self.format.encode(w);
+        self.samples.encode(w);
+        self.load_op.encode(w);
+        self.store_op.encode(w);
+        self.stencil_load_op.encode(w);
+        self.stencil_store_op.encode(w);
+        self.initial_layout.encode(w);
+        self.final_layout.encode(w);
+    }
+}
+impl Decode for AttachmentDescription {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AttachmentDescription {
+            flags: Decode::decode(r, ctx)?,
+            format: Decode::decode(r, ctx)?,
+            samples: Decode::decode(r, ctx)?,
+            load_op: Decode::decode(r, ctx)?,
+            store_op: Decode::decode(r, ctx)?,
+            stencil_load_op: Decode::decode(r, ctx)?,
+            stencil_store_op: Decode::decode(r, ctx)?,
+            initial_layout: Decode::decode(r, ctx)?,
+            final_layout: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AttachmentDescription2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.format.encode(w);
+        self.samples.encode(w);
+        self.load_op.encode(w);
+        self.store_op.encode(w);
+        self.stencil_load_op.encode(w);
+        self.stencil_store_op.encode(w);
+        self.initial_layout.encode(w);
+        self.final_layout.encode(w);
+    }
+}
+impl Decode for AttachmentDescription2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AttachmentDescription2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            format: Decode::decode(r, ctx)?,
+            samples: Decode::decode(r, ctx)?,
+            load_op: Decode::decode(r, ctx)?,
+            store_op: Decode::decode(r, ctx)?,
+            stencil_load_op: Decode::decode(r, ctx)?,
+            stencil_store_op: Decode::decode(r, ctx)?,
+            initial_layout: Decode::decode(r, ctx)?,
+            final_layout: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AttachmentReference {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.attachment.encode(w);
+        self.layout.encode(w);
+    }
+}
+impl Decode for AttachmentReference {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AttachmentReference {
+            attachment: Decode::decode(r, ctx)?,
+            layout: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AttachmentReference2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.attachment.encode(w);
+        self.layout.encode(w);
+        self.aspect_mask.encode(w);
+    }
+}
+impl Decode for AttachmentReference2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AttachmentReference2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            attachment: Decode::decode(r, ctx)?,
+            layout: Decode::decode(r, ctx)?,
+            aspect_mask: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for AttachmentSampleLocationsEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.attachment_index.encode(w);
+        self.sample_locations_info.encode(w);
+    }
+}
+impl Decode for AttachmentSampleLocationsEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(AttachmentSampleLocationsEXT {
+            attachment_index: Decode::decode(r, ctx)?,
+            sample_locations_info: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BaseInStructure {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+    }
+}
+impl Decode for BaseInStructure {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BaseInStructure {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+        })
+    }
+}
+impl Encode for BaseOutStructure {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+    }
+}
+impl Decode for BaseOutStructure {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BaseOutStructure {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+        })
+    }
+}
+impl Encode for BindAccelerationStructureMemoryInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.acceleration_structure.encode(w);
+        self.memory.encode(w);
+        self.memory_offset.encode(w);
+        self.device_index_count.encode(w);
+        w.array(self.p_device_indices, self.device_index_count as usize);
+    }
+}
+impl Decode for BindAccelerationStructureMemoryInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BindAccelerationStructureMemoryInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            acceleration_structure: Decode::decode(r, ctx)?,
+            memory: Decode::decode(r, ctx)?,
+            memory_offset: Decode::decode(r, ctx)?,
+            device_index_count: Decode::decode(r, ctx)?,
+            p_device_indices: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for BindBufferMemoryDeviceGroupInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.device_index_count.encode(w);
+        w.array(self.p_device_indices, self.device_index_count as usize);
+    }
+}
+impl Decode for BindBufferMemoryDeviceGroupInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BindBufferMemoryDeviceGroupInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            device_index_count: Decode::decode(r, ctx)?,
+            p_device_indices: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for BindBufferMemoryInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.buffer.encode(w);
+        self.memory.encode(w);
+        self.memory_offset.encode(w);
+    }
+}
+impl Decode for BindBufferMemoryInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BindBufferMemoryInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            buffer: Decode::decode(r, ctx)?,
+            memory: Decode::decode(r, ctx)?,
+            memory_offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BindImageMemoryDeviceGroupInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.device_index_count.encode(w);
+        w.array(self.p_device_indices, self.device_index_count as usize);
+        self.split_instance_bind_region_count.encode(w);
+        w.array(
+            self.p_split_instance_bind_regions,
+            self.split_instance_bind_region_count as usize,
+        );
+    }
+}
+impl Decode for BindImageMemoryDeviceGroupInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BindImageMemoryDeviceGroupInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            device_index_count: Decode::decode(r, ctx)?,
+            p_device_indices: ctx.array(r)?,
+            split_instance_bind_region_count: Decode::decode(r, ctx)?,
+            p_split_instance_bind_regions: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for BindImageMemoryInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.image.encode(w);
+        self.memory.encode(w);
+        self.memory_offset.encode(w);
+    }
+}
+impl Decode for BindImageMemoryInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BindImageMemoryInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            image: Decode::decode(r, ctx)?,
+            memory: Decode::decode(r, ctx)?,
+            memory_offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BindImageMemorySwapchainInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.swapchain.encode(w);
+        self.image_index.encode(w);
+    }
+}
+impl Decode for BindImageMemorySwapchainInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BindImageMemorySwapchainInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            swapchain: Decode::decode(r, ctx)?,
+            image_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BindImagePlaneMemoryInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.plane_aspect.encode(w);
+    }
+}
+impl Decode for BindImagePlaneMemoryInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BindImagePlaneMemoryInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            plane_aspect: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BindSparseInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.wait_semaphore_count.encode(w);
+        w.array(self.p_wait_semaphores, self.wait_semaphore_count as usize);
+        self.buffer_bind_count.encode(w);
+        w.array(self.p_buffer_binds, self.buffer_bind_count as usize);
+        self.image_opaque_bind_count.encode(w);
+        w.array(
+            self.p_image_opaque_binds,
+            self.image_opaque_bind_count as usize,
+        );
+        self.image_bind_count.encode(w);
+        w.array(self.p_image_binds, self.image_bind_count as usize);
+        self.signal_semaphore_count.encode(w);
+        w.array(
+            self.p_signal_semaphores,
+            self.signal_semaphore_count as usize,
+        );
+    }
+}
+impl Decode for BindSparseInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BindSparseInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            wait_semaphore_count: Decode::decode(r, ctx)?,
+            p_wait_semaphores: ctx.array(r)?,
+            buffer_bind_count: Decode::decode(r, ctx)?,
+            p_buffer_binds: ctx.array(r)?,
+            image_opaque_bind_count: Decode::decode(r, ctx)?,
+            p_image_opaque_binds: ctx.array(r)?,
+            image_bind_count: Decode::decode(r, ctx)?,
+            p_image_binds: ctx.array(r)?,
+            signal_semaphore_count: Decode::decode(r, ctx)?,
+            p_signal_semaphores: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for BufferCopy {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.src_offset.encode(w);
+        self.dst_offset.encode(w);
+        self.size.encode(w);
+    }
+}
+impl Decode for BufferCopy {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BufferCopy {
+            src_offset: Decode::decode(r, ctx)?,
+            dst_offset: Decode::decode(r, ctx)?,
+            size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BufferCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.size.encode(w);
+        self.usage.encode(w);
+        self.sharing_mode.encode(w);
+        self.queue_family_index_count.encode(w);
+        w.array(
+            self.p_queue_family_indices,
+            self.queue_family_index_count as usize,
+        );
+    }
+}
+impl Decode for BufferCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BufferCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            size: Decode::decode(r, ctx)?,
+            usage: Decode::decode(r, ctx)?,
+            sharing_mode: Decode::decode(r, ctx)?,
+            queue_family_index_count: Decode::decode(r, ctx)?,
+            p_queue_family_indices: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for BufferImageCopy {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.buffer_offset.encode(w);
+        self.buffer_row_length.encode(w);
+        self.buffer_image_height.encode(w);
+        self.image_subresource.encode(w);
+        self.image_offset.encode(w);
+        self.image_extent.encode(w);
+    }
+}
+impl Decode for BufferImageCopy {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BufferImageCopy {
+            buffer_offset: Decode::decode(r, ctx)?,
+            buffer_row_length: Decode::decode(r, ctx)?,
+            buffer_image_height: Decode::decode(r, ctx)?,
+            image_subresource: Decode::decode(r, ctx)?,
+            image_offset: Decode::decode(r, ctx)?,
+            image_extent: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BufferMemoryBarrier {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.src_access_mask.encode(w);
+        self.dst_access_mask.encode(w);
+        self.src_queue_family_index.encode(w);
+        self.dst_queue_family_index.encode(w);
+        self.buffer.encode(w);
+        self.offset.encode(w);
+        self.size.encode(w);
+    }
+}
+impl Decode for BufferMemoryBarrier {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BufferMemoryBarrier {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            src_access_mask: Decode::decode(r, ctx)?,
+            dst_access_mask: Decode::decode(r, ctx)?,
+            src_queue_family_index: Decode::decode(r, ctx)?,
+            dst_queue_family_index: Decode::decode(r, ctx)?,
+            buffer: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BufferMemoryRequirementsInfo2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.buffer.encode(w);
+    }
+}
+impl Decode for BufferMemoryRequirementsInfo2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BufferMemoryRequirementsInfo2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            buffer: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for BufferViewCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.buffer.encode(w);
+        self.format.encode(w);
+        self.offset.encode(w);
+        self.range.encode(w);
+    }
+}
+impl Decode for BufferViewCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(BufferViewCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            buffer: Decode::decode(r, ctx)?,
+            format: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            range: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CalibratedTimestampInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.time_domain.encode(w);
+    }
+}
+impl Decode for CalibratedTimestampInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CalibratedTimestampInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            time_domain: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CheckpointDataNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.stage.encode(w);
+        self.p_checkpoint_marker.encode(w);
+    }
+}
+impl Decode for CheckpointDataNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CheckpointDataNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            stage: Decode::decode(r, ctx)?,
+            p_checkpoint_marker: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ClearAttachment {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.aspect_mask.encode(w);
+        self.color_attachment.encode(w);
+        self.clear_value.encode(w);
+    }
+}
+impl Decode for ClearAttachment {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ClearAttachment {
+            aspect_mask: Decode::decode(r, ctx)?,
+            color_attachment: Decode::decode(r, ctx)?,
+            clear_value: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ClearDepthStencilValue {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.depth.encode(w);
+        self.stencil.encode(w);
+    }
+}
+impl Decode for ClearDepthStencilValue {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ClearDepthStencilValue {
+            depth: Decode::decode(r, ctx)?,
+            stencil: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ClearRect {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.rect.encode(w);
+        self.base_array_layer.encode(w);
+        self.layer_count.encode(w);
+    }
+}
+impl Decode for ClearRect {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ClearRect {
+            rect: Decode::decode(r, ctx)?,
+            base_array_layer: Decode::decode(r, ctx)?,
+            layer_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CmdProcessCommandsInfoNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.object_table.encode(w);
+        self.indirect_commands_layout.encode(w);
+        self.indirect_commands_token_count.encode(w);
+        w.array(
+            self.p_indirect_commands_tokens,
+            self.indirect_commands_token_count as usize,
+        );
+        self.max_sequences_count.encode(w);
+        self.target_command_buffer.encode(w);
+        self.sequences_count_buffer.encode(w);
+        self.sequences_count_offset.encode(w);
+        self.sequences_index_buffer.encode(w);
+        self.sequences_index_offset.encode(w);
+    }
+}
+impl Decode for CmdProcessCommandsInfoNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CmdProcessCommandsInfoNVX {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            object_table: Decode::decode(r, ctx)?,
+            indirect_commands_layout: Decode::decode(r, ctx)?,
+            indirect_commands_token_count: Decode::decode(r, ctx)?,
+            p_indirect_commands_tokens: ctx.array(r)?,
+            max_sequences_count: Decode::decode(r, ctx)?,
+            target_command_buffer: Decode::decode(r, ctx)?,
+            sequences_count_buffer: Decode::decode(r, ctx)?,
+            sequences_count_offset: Decode::decode(r, ctx)?,
+            sequences_index_buffer: Decode::decode(r, ctx)?,
+            sequences_index_offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CmdReserveSpaceForCommandsInfoNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.object_table.encode(w);
+        self.indirect_commands_layout.encode(w);
+        self.max_sequences_count.encode(w);
+    }
+}
+impl Decode for CmdReserveSpaceForCommandsInfoNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CmdReserveSpaceForCommandsInfoNVX {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            object_table: Decode::decode(r, ctx)?,
+            indirect_commands_layout: Decode::decode(r, ctx)?,
+            max_sequences_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CoarseSampleLocationNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.pixel_x.encode(w);
+        self.pixel_y.encode(w);
+        self.sample.encode(w);
+    }
+}
+impl Decode for CoarseSampleLocationNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CoarseSampleLocationNV {
+            pixel_x: Decode::decode(r, ctx)?,
+            pixel_y: Decode::decode(r, ctx)?,
+            sample: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CoarseSampleOrderCustomNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.shading_rate.encode(w);
+        self.sample_count.encode(w);
+        self.sample_location_count.encode(w);
+        w.array(self.p_sample_locations, self.sample_location_count as usize);
+    }
+}
+impl Decode for CoarseSampleOrderCustomNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CoarseSampleOrderCustomNV {
+            shading_rate: Decode::decode(r, ctx)?,
+            sample_count: Decode::decode(r, ctx)?,
+            sample_location_count: Decode::decode(r, ctx)?,
+            p_sample_locations: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for CommandBufferAllocateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.command_pool.encode(w);
+        self.level.encode(w);
+        self.command_buffer_count.encode(w);
+    }
+}
+impl Decode for CommandBufferAllocateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CommandBufferAllocateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            command_pool: Decode::decode(r, ctx)?,
+            level: Decode::decode(r, ctx)?,
+            command_buffer_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CommandBufferBeginInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        w.ptr(self.p_inheritance_info);
+    }
+}
+impl Decode for CommandBufferBeginInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CommandBufferBeginInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            p_inheritance_info: ctx.ptr(r)?,
+        })
+    }
+}
+impl Encode for CommandBufferInheritanceConditionalRenderingInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.conditional_rendering_enable.encode(w);
+    }
+}
+impl Decode for CommandBufferInheritanceConditionalRenderingInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CommandBufferInheritanceConditionalRenderingInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            conditional_rendering_enable: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CommandBufferInheritanceInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.render_pass.encode(w);
+        self.subpass.encode(w);
+        self.framebuffer.encode(w);
+        self.occlusion_query_enable.encode(w);
+        self.query_flags.encode(w);
+        self.pipeline_statistics.encode(w);
+    }
+}
+impl Decode for CommandBufferInheritanceInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CommandBufferInheritanceInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            render_pass: Decode::decode(r, ctx)?,
+            subpass: Decode::decode(r, ctx)?,
+            framebuffer: Decode::decode(r, ctx)?,
+            occlusion_query_enable: Decode::decode(r, ctx)?,
+            query_flags: Decode::decode(r, ctx)?,
+            pipeline_statistics: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CommandPoolCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.queue_family_index.encode(w);
+    }
+}
+impl Decode for CommandPoolCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CommandPoolCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            queue_family_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ComponentMapping {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.r.encode(w);
+        self.g.encode(w);
+        self.b.encode(w);
+        self.a.encode(w);
+    }
+}
+impl Decode for ComponentMapping {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ComponentMapping {
+            r: Decode::decode(r, ctx)?,
+            g: Decode::decode(r, ctx)?,
+            b: Decode::decode(r, ctx)?,
+            a: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ComputePipelineCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.stage.encode(w);
+        self.layout.encode(w);
+        self.base_pipeline_handle.encode(w);
+        self.base_pipeline_index.encode(w);
+    }
+}
+impl Decode for ComputePipelineCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ComputePipelineCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            stage: Decode::decode(r, ctx)?,
+            layout: Decode::decode(r, ctx)?,
+            base_pipeline_handle: Decode::decode(r, ctx)?,
+            base_pipeline_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ConditionalRenderingBeginInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.buffer.encode(w);
+        self.offset.encode(w);
+        self.flags.encode(w);
+    }
+}
+impl Decode for ConditionalRenderingBeginInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ConditionalRenderingBeginInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            buffer: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ConformanceVersionKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.major.encode(w);
+        self.minor.encode(w);
+        self.subminor.encode(w);
+        self.patch.encode(w);
+    }
+}
+impl Decode for ConformanceVersionKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ConformanceVersionKHR {
+            major: Decode::decode(r, ctx)?,
+            minor: Decode::decode(r, ctx)?,
+            subminor: Decode::decode(r, ctx)?,
+            patch: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for CopyDescriptorSet {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.src_set.encode(w);
+        self.src_binding.encode(w);
+        self.src_array_element.encode(w);
+        self.dst_set.encode(w);
+        self.dst_binding.encode(w);
+        self.dst_array_element.encode(w);
+        self.descriptor_count.encode(w);
+    }
+}
+impl Decode for CopyDescriptorSet {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(CopyDescriptorSet {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            src_set: Decode::decode(r, ctx)?,
+            src_binding: Decode::decode(r, ctx)?,
+            src_array_element: Decode::decode(r, ctx)?,
+            dst_set: Decode::decode(r, ctx)?,
+            dst_binding: Decode::decode(r, ctx)?,
+            dst_array_element: Decode::decode(r, ctx)?,
+            descriptor_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for D3D12FenceSubmitInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.wait_semaphore_values_count.encode(w);
+        w.array(
+            self.p_wait_semaphore_values,
+            self.wait_semaphore_values_count as usize,
+        );
+        self.signal_semaphore_values_count.encode(w);
+        w.array(
+            self.p_signal_semaphore_values,
+            self.signal_semaphore_values_count as usize,
+        );
+    }
+}
+impl Decode for D3D12FenceSubmitInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(D3D12FenceSubmitInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            wait_semaphore_values_count: Decode::decode(r, ctx)?,
+            p_wait_semaphore_values: ctx.array(r)?,
+            signal_semaphore_values_count: Decode::decode(r, ctx)?,
+            p_signal_semaphore_values: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DebugMarkerMarkerInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        w.str(self.p_marker_name);
+        self.color.encode(w);
+    }
+}
+impl Decode for DebugMarkerMarkerInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugMarkerMarkerInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            p_marker_name: ctx.str(r)?,
+            color: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DebugMarkerObjectNameInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.object_type.encode(w);
+        self.object.encode(w);
+        w.str(self.p_object_name);
+    }
+}
+impl Decode for DebugMarkerObjectNameInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugMarkerObjectNameInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            object_type: Decode::decode(r, ctx)?,
+            object: Decode::decode(r, ctx)?,
+            p_object_name: ctx.str(r)?,
+        })
+    }
+}
+impl Encode for DebugMarkerObjectTagInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.object_type.encode(w);
+        self.object.encode(w);
+        self.tag_name.encode(w);
+        self.tag_size.encode(w);
+        w.bytes(self.p_tag as *const u8, self.tag_size as usize);
+    }
+}
+impl Decode for DebugMarkerObjectTagInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugMarkerObjectTagInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            object_type: Decode::decode(r, ctx)?,
+            object: Decode::decode(r, ctx)?,
+            tag_name: Decode::decode(r, ctx)?,
+            tag_size: Decode::decode(r, ctx)?,
+            p_tag: ctx.bytes(r)?,
+        })
+    }
+}
+impl Encode for DebugReportCallbackCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+    }
+}
+impl Decode for DebugReportCallbackCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugReportCallbackCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            pfn_callback: mem::zeroed(),
+            p_user_data: mem::zeroed(),
+        })
+    }
+}
+impl Encode for DebugUtilsLabelEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        w.str(self.p_label_name);
+        self.color.encode(w);
+    }
+}
+impl Decode for DebugUtilsLabelEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugUtilsLabelEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            p_label_name: ctx.str(r)?,
+            color: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DebugUtilsMessengerCallbackDataEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        w.str(self.p_message_id_name);
+        self.message_id_number.encode(w);
+        w.str(self.p_message);
+        self.queue_label_count.encode(w);
+        w.array(self.p_queue_labels, self.queue_label_count as usize);
+        self.cmd_buf_label_count.encode(w);
+        w.array(self.p_cmd_buf_labels, self.cmd_buf_label_count as usize);
+        self.object_count.encode(w);
+        w.array(self.p_objects, self.object_count as usize);
+    }
+}
+impl Decode for DebugUtilsMessengerCallbackDataEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugUtilsMessengerCallbackDataEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            p_message_id_name: ctx.str(r)?,
+            message_id_number: Decode::decode(r, ctx)?,
+            p_message: ctx.str(r)?,
+            queue_label_count: Decode::decode(r, ctx)?,
+            p_queue_labels: ctx.array::<DebugUtilsLabelEXT>(r)? as *mut _,
+            cmd_buf_label_count: Decode::decode(r, ctx)?,
+            p_cmd_buf_labels: ctx.array::<DebugUtilsLabelEXT>(r)? as *mut _,
+            object_count: Decode::decode(r, ctx)?,
+            p_objects: ctx.array::<DebugUtilsObjectNameInfoEXT>(r)? as *mut _,
+        })
+    }
+}
+impl Encode for DebugUtilsMessengerCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.message_severity.encode(w);
+        self.message_type.encode(w);
+    }
+}
+impl Decode for DebugUtilsMessengerCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugUtilsMessengerCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            message_severity: Decode::decode(r, ctx)?,
+            message_type: Decode::decode(r, ctx)?,
+            pfn_user_callback: mem::zeroed(),
+            p_user_data: mem::zeroed(),
+        })
+    }
+}
+impl Encode for DebugUtilsObjectNameInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.object_type.encode(w);
+        self.object_handle.encode(w);
+        w.str(self.p_object_name);
+    }
+}
+impl Decode for DebugUtilsObjectNameInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugUtilsObjectNameInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            object_type: Decode::decode(r, ctx)?,
+            object_handle: Decode::decode(r, ctx)?,
+            p_object_name: ctx.str(r)?,
+        })
+    }
+}
+impl Encode for DebugUtilsObjectTagInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.object_type.encode(w);
+        self.object_handle.encode(w);
+        self.tag_name.encode(w);
+        self.tag_size.encode(w);
+        w.bytes(self.p_tag as *const u8, self.tag_size as usize);
+    }
+}
+impl Decode for DebugUtilsObjectTagInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DebugUtilsObjectTagInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            object_type: Decode::decode(r, ctx)?,
+            object_handle: Decode::decode(r, ctx)?,
+            tag_name: Decode::decode(r, ctx)?,
+            tag_size: Decode::decode(r, ctx)?,
+            p_tag: ctx.bytes(r)?,
+        })
+    }
+}
+impl Encode for DedicatedAllocationBufferCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.dedicated_allocation.encode(w);
+    }
+}
+impl Decode for DedicatedAllocationBufferCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DedicatedAllocationBufferCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            dedicated_allocation: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DedicatedAllocationImageCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.dedicated_allocation.encode(w);
+    }
+}
+impl Decode for DedicatedAllocationImageCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DedicatedAllocationImageCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            dedicated_allocation: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DedicatedAllocationMemoryAllocateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.image.encode(w);
+        self.buffer.encode(w);
+    }
+}
+impl Decode for DedicatedAllocationMemoryAllocateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DedicatedAllocationMemoryAllocateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            image: Decode::decode(r, ctx)?,
+            buffer: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DescriptorBufferInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.buffer.encode(w);
+        self.offset.encode(w);
+        self.range.encode(w);
+    }
+}
+impl Decode for DescriptorBufferInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorBufferInfo {
+            buffer: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            range: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DescriptorImageInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.sampler.encode(w);
+        self.image_view.encode(w);
+        self.image_layout.encode(w);
+    }
+}
+impl Decode for DescriptorImageInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorImageInfo {
+            sampler: Decode::decode(r, ctx)?,
+            image_view: Decode::decode(r, ctx)?,
+            image_layout: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DescriptorPoolCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.max_sets.encode(w);
+        self.pool_size_count.encode(w);
+        w.array(self.p_pool_sizes, self.pool_size_count as usize);
+    }
+}
+impl Decode for DescriptorPoolCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorPoolCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            max_sets: Decode::decode(r, ctx)?,
+            pool_size_count: Decode::decode(r, ctx)?,
+            p_pool_sizes: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DescriptorPoolInlineUniformBlockCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_inline_uniform_block_bindings.encode(w);
+    }
+}
+impl Decode for DescriptorPoolInlineUniformBlockCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorPoolInlineUniformBlockCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_inline_uniform_block_bindings: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DescriptorPoolSize {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.ty.encode(w);
+        self.descriptor_count.encode(w);
+    }
+}
+impl Decode for DescriptorPoolSize {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorPoolSize {
+            ty: Decode::decode(r, ctx)?,
+            descriptor_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DescriptorSetAllocateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.descriptor_pool.encode(w);
+        self.descriptor_set_count.encode(w);
+        w.array(self.p_set_layouts, self.descriptor_set_count as usize);
+    }
+}
+impl Decode for DescriptorSetAllocateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorSetAllocateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            descriptor_pool: Decode::decode(r, ctx)?,
+            descriptor_set_count: Decode::decode(r, ctx)?,
+            p_set_layouts: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DescriptorSetLayoutBinding {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.binding.encode(w);
+        self.descriptor_type.encode(w);
+        self.descriptor_count.encode(w);
+        self.stage_flags.encode(w);
+        if [
+            DescriptorType::SAMPLER,
+            DescriptorType::COMBINED_IMAGE_SAMPLER,
+        ]
+        .contains(&self.descriptor_type)
+        {
+            w.array(self.p_immutable_samplers, self.descriptor_count as usize)
+        } else {
+            w.null();
+        }
+    }
+}
+impl Decode for DescriptorSetLayoutBinding {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorSetLayoutBinding {
+            binding: Decode::decode(r, ctx)?,
+            descriptor_type: Decode::decode(r, ctx)?,
+            descriptor_count: Decode::decode(r, ctx)?,
+            stage_flags: Decode::decode(r, ctx)?,
+            p_immutable_samplers: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DescriptorSetLayoutBindingFlagsCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.binding_count.encode(w);
+        w.array(self.p_binding_flags, self.binding_count as usize);
+    }
+}
+impl Decode for DescriptorSetLayoutBindingFlagsCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorSetLayoutBindingFlagsCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            binding_count: Decode::decode(r, ctx)?,
+            p_binding_flags: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DescriptorSetLayoutCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.binding_count.encode(w);
+        w.array(self.p_bindings, self.binding_count as usize);
+    }
+}
+impl Decode for DescriptorSetLayoutCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorSetLayoutCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            binding_count: Decode::decode(r, ctx)?,
+            p_bindings: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DescriptorSetLayoutSupport {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.supported.encode(w);
+    }
+}
+impl Decode for DescriptorSetLayoutSupport {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorSetLayoutSupport {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            supported: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DescriptorSetVariableDescriptorCountAllocateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.descriptor_set_count.encode(w);
+        w.array(self.p_descriptor_counts, self.descriptor_set_count as usize);
+    }
+}
+impl Decode for DescriptorSetVariableDescriptorCountAllocateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorSetVariableDescriptorCountAllocateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            descriptor_set_count: Decode::decode(r, ctx)?,
+            p_descriptor_counts: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DescriptorSetVariableDescriptorCountLayoutSupportEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_variable_descriptor_count.encode(w);
+    }
+}
+impl Decode for DescriptorSetVariableDescriptorCountLayoutSupportEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorSetVariableDescriptorCountLayoutSupportEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_variable_descriptor_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DescriptorUpdateTemplateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.descriptor_update_entry_count.encode(w);
+        w.array(
+            self.p_descriptor_update_entries,
+            self.descriptor_update_entry_count as usize,
+        );
+        self.template_type.encode(w);
+        self.descriptor_set_layout.encode(w);
+        self.pipeline_bind_point.encode(w);
+        self.pipeline_layout.encode(w);
+        self.set.encode(w);
+    }
+}
+impl Decode for DescriptorUpdateTemplateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorUpdateTemplateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            descriptor_update_entry_count: Decode::decode(r, ctx)?,
+            p_descriptor_update_entries: ctx.array(r)?,
+            template_type: Decode::decode(r, ctx)?,
+            descriptor_set_layout: Decode::decode(r, ctx)?,
+            pipeline_bind_point: Decode::decode(r, ctx)?,
+            pipeline_layout: Decode::decode(r, ctx)?,
+            set: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DescriptorUpdateTemplateEntry {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.dst_binding.encode(w);
+        self.dst_array_element.encode(w);
+        self.descriptor_count.encode(w);
+        self.descriptor_type.encode(w);
+        self.offset.encode(w);
+        self.stride.encode(w);
+    }
+}
+impl Decode for DescriptorUpdateTemplateEntry {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DescriptorUpdateTemplateEntry {
+            dst_binding: Decode::decode(r, ctx)?,
+            dst_array_element: Decode::decode(r, ctx)?,
+            descriptor_count: Decode::decode(r, ctx)?,
+            descriptor_type: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            stride: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.queue_create_info_count.encode(w);
+        w.array(
+            self.p_queue_create_infos,
+            self.queue_create_info_count as usize,
+        );
+        self.enabled_layer_count.encode(w);
+        w.str_array(
+            self.pp_enabled_layer_names,
+            self.enabled_layer_count as usize,
+        );
+        self.enabled_extension_count.encode(w);
+        w.str_array(
+            self.pp_enabled_extension_names,
+            self.enabled_extension_count as usize,
+        );
+        w.ptr(self.p_enabled_features);
+    }
+}
+impl Decode for DeviceCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            queue_create_info_count: Decode::decode(r, ctx)?,
+            p_queue_create_infos: ctx.array(r)?,
+            enabled_layer_count: Decode::decode(r, ctx)?,
+            pp_enabled_layer_names: ctx.str_array(r)?,
+            enabled_extension_count: Decode::decode(r, ctx)?,
+            pp_enabled_extension_names: ctx.str_array(r)?,
+            p_enabled_features: ctx.ptr(r)?,
+        })
+    }
+}
+impl Encode for DeviceEventInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.device_event.encode(w);
+    }
+}
+impl Decode for DeviceEventInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceEventInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            device_event: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceGeneratedCommandsFeaturesNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.compute_binding_point_support.encode(w);
+    }
+}
+impl Decode for DeviceGeneratedCommandsFeaturesNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGeneratedCommandsFeaturesNVX {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            compute_binding_point_support: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceGeneratedCommandsLimitsNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_indirect_commands_layout_token_count.encode(w);
+        self.max_object_entry_counts.encode(w);
+        self.min_sequence_count_buffer_offset_alignment.encode(w);
+        self.min_sequence_index_buffer_offset_alignment.encode(w);
+        self.min_commands_token_buffer_offset_alignment.encode(w);
+    }
+}
+impl Decode for DeviceGeneratedCommandsLimitsNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGeneratedCommandsLimitsNVX {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_indirect_commands_layout_token_count: Decode::decode(r, ctx)?,
+            max_object_entry_counts: Decode::decode(r, ctx)?,
+            min_sequence_count_buffer_offset_alignment: Decode::decode(r, ctx)?,
+            min_sequence_index_buffer_offset_alignment: Decode::decode(r, ctx)?,
+            min_commands_token_buffer_offset_alignment: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceGroupBindSparseInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.resource_device_index.encode(w);
+        self.memory_device_index.encode(w);
+    }
+}
+impl Decode for DeviceGroupBindSparseInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGroupBindSparseInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            resource_device_index: Decode::decode(r, ctx)?,
+            memory_device_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceGroupCommandBufferBeginInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.device_mask.encode(w);
+    }
+}
+impl Decode for DeviceGroupCommandBufferBeginInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGroupCommandBufferBeginInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            device_mask: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceGroupDeviceCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.physical_device_count.encode(w);
+        w.array(self.p_physical_devices, self.physical_device_count as usize);
+    }
+}
+impl Decode for DeviceGroupDeviceCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGroupDeviceCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            physical_device_count: Decode::decode(r, ctx)?,
+            p_physical_devices: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DeviceGroupPresentCapabilitiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.present_mask.encode(w);
+        self.modes.encode(w);
+    }
+}
+impl Decode for DeviceGroupPresentCapabilitiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGroupPresentCapabilitiesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            present_mask: Decode::decode(r, ctx)?,
+            modes: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceGroupPresentInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.swapchain_count.encode(w);
+        w.array(self.p_device_masks, self.swapchain_count as usize);
+        self.mode.encode(w);
+    }
+}
+impl Decode for DeviceGroupPresentInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGroupPresentInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            swapchain_count: Decode::decode(r, ctx)?,
+            p_device_masks: ctx.array(r)?,
+            mode: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceGroupRenderPassBeginInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.device_mask.encode(w);
+        self.device_render_area_count.encode(w);
+        w.array(
+            self.p_device_render_areas,
+            self.device_render_area_count as usize,
+        );
+    }
+}
+impl Decode for DeviceGroupRenderPassBeginInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGroupRenderPassBeginInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            device_mask: Decode::decode(r, ctx)?,
+            device_render_area_count: Decode::decode(r, ctx)?,
+            p_device_render_areas: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DeviceGroupSubmitInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.wait_semaphore_count.encode(w);
+        w.array(
+            self.p_wait_semaphore_device_indices,
+            self.wait_semaphore_count as usize,
+        );
+        self.command_buffer_count.encode(w);
+        w.array(
+            self.p_command_buffer_device_masks,
+            self.command_buffer_count as usize,
+        );
+        self.signal_semaphore_count.encode(w);
+        w.array(
+            self.p_signal_semaphore_device_indices,
+            self.signal_semaphore_count as usize,
+        );
+    }
+}
+impl Decode for DeviceGroupSubmitInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGroupSubmitInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            wait_semaphore_count: Decode::decode(r, ctx)?,
+            p_wait_semaphore_device_indices: ctx.array(r)?,
+            command_buffer_count: Decode::decode(r, ctx)?,
+            p_command_buffer_device_masks: ctx.array(r)?,
+            signal_semaphore_count: Decode::decode(r, ctx)?,
+            p_signal_semaphore_device_indices: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DeviceGroupSwapchainCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.modes.encode(w);
+    }
+}
+impl Decode for DeviceGroupSwapchainCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceGroupSwapchainCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            modes: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceMemoryOverallocationCreateInfoAMD {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.overallocation_behavior.encode(w);
+    }
+}
+impl Decode for DeviceMemoryOverallocationCreateInfoAMD {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceMemoryOverallocationCreateInfoAMD {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            overallocation_behavior: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceQueueCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.queue_family_index.encode(w);
+        self.queue_count.encode(w);
+        w.array(self.p_queue_priorities, self.queue_count as usize);
+    }
+}
+impl Decode for DeviceQueueCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceQueueCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            queue_family_index: Decode::decode(r, ctx)?,
+            queue_count: Decode::decode(r, ctx)?,
+            p_queue_priorities: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for DeviceQueueGlobalPriorityCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.global_priority.encode(w);
+    }
+}
+impl Decode for DeviceQueueGlobalPriorityCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceQueueGlobalPriorityCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            global_priority: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DeviceQueueInfo2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.queue_family_index.encode(w);
+        self.queue_index.encode(w);
+    }
+}
+impl Decode for DeviceQueueInfo2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DeviceQueueInfo2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            queue_family_index: Decode::decode(r, ctx)?,
+            queue_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DispatchIndirectCommand {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.x.encode(w);
+        self.y.encode(w);
+        self.z.encode(w);
+    }
+}
+impl Decode for DispatchIndirectCommand {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DispatchIndirectCommand {
+            x: Decode::decode(r, ctx)?,
+            y: Decode::decode(r, ctx)?,
+            z: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayEventInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.display_event.encode(w);
+    }
+}
+impl Decode for DisplayEventInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayEventInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            display_event: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayModeCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.parameters.encode(w);
+    }
+}
+impl Decode for DisplayModeCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayModeCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            parameters: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayModeParametersKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.visible_region.encode(w);
+        self.refresh_rate.encode(w);
+    }
+}
+impl Decode for DisplayModeParametersKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayModeParametersKHR {
+            visible_region: Decode::decode(r, ctx)?,
+            refresh_rate: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayModeProperties2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.display_mode_properties.encode(w);
+    }
+}
+impl Decode for DisplayModeProperties2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayModeProperties2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            display_mode_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayModePropertiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.display_mode.encode(w);
+        self.parameters.encode(w);
+    }
+}
+impl Decode for DisplayModePropertiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayModePropertiesKHR {
+            display_mode: Decode::decode(r, ctx)?,
+            parameters: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayPlaneCapabilities2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.capabilities.encode(w);
+    }
+}
+impl Decode for DisplayPlaneCapabilities2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayPlaneCapabilities2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            capabilities: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayPlaneCapabilitiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.supported_alpha.encode(w);
+        self.min_src_position.encode(w);
+        self.max_src_position.encode(w);
+        self.min_src_extent.encode(w);
+        self.max_src_extent.encode(w);
+        self.min_dst_position.encode(w);
+        self.max_dst_position.encode(w);
+        self.min_dst_extent.encode(w);
+        self.max_dst_extent.encode(w);
+    }
+}
+impl Decode for DisplayPlaneCapabilitiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayPlaneCapabilitiesKHR {
+            supported_alpha: Decode::decode(r, ctx)?,
+            min_src_position: Decode::decode(r, ctx)?,
+            max_src_position: Decode::decode(r, ctx)?,
+            min_src_extent: Decode::decode(r, ctx)?,
+            max_src_extent: Decode::decode(r, ctx)?,
+            min_dst_position: Decode::decode(r, ctx)?,
+            max_dst_position: Decode::decode(r, ctx)?,
+            min_dst_extent: Decode::decode(r, ctx)?,
+            max_dst_extent: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayPlaneInfo2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.mode.encode(w);
+        self.plane_index.encode(w);
+    }
+}
+impl Decode for DisplayPlaneInfo2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayPlaneInfo2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            mode: Decode::decode(r, ctx)?,
+            plane_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayPlaneProperties2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.display_plane_properties.encode(w);
+    }
+}
+impl Decode for DisplayPlaneProperties2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayPlaneProperties2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            display_plane_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayPlanePropertiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.current_display.encode(w);
+        self.current_stack_index.encode(w);
+    }
+}
+impl Decode for DisplayPlanePropertiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayPlanePropertiesKHR {
+            current_display: Decode::decode(r, ctx)?,
+            current_stack_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayPowerInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.power_state.encode(w);
+    }
+}
+impl Decode for DisplayPowerInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayPowerInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            power_state: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayPresentInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.src_rect.encode(w);
+        self.dst_rect.encode(w);
+        self.persistent.encode(w);
+    }
+}
+impl Decode for DisplayPresentInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayPresentInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            src_rect: Decode::decode(r, ctx)?,
+            dst_rect: Decode::decode(r, ctx)?,
+            persistent: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayProperties2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.display_properties.encode(w);
+    }
+}
+impl Decode for DisplayProperties2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayProperties2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            display_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplayPropertiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.display.encode(w);
+        w.str(self.display_name);
+        self.physical_dimensions.encode(w);
+        self.physical_resolution.encode(w);
+        self.supported_transforms.encode(w);
+        self.plane_reorder_possible.encode(w);
+        self.persistent_content.encode(w);
+    }
+}
+impl Decode for DisplayPropertiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplayPropertiesKHR {
+            display: Decode::decode(r, ctx)?,
+            display_name: ctx.str(r)?,
+            physical_dimensions: Decode::decode(r, ctx)?,
+            physical_resolution: Decode::decode(r, ctx)?,
+            supported_transforms: Decode::decode(r, ctx)?,
+            plane_reorder_possible: Decode::decode(r, ctx)?,
+            persistent_content: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DisplaySurfaceCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.display_mode.encode(w);
+        self.plane_index.encode(w);
+        self.plane_stack_index.encode(w);
+        self.transform.encode(w);
+        self.global_alpha.encode(w);
+        self.alpha_mode.encode(w);
+        self.image_extent.encode(w);
+    }
+}
+impl Decode for DisplaySurfaceCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DisplaySurfaceCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            display_mode: Decode::decode(r, ctx)?,
+            plane_index: Decode::decode(r, ctx)?,
+            plane_stack_index: Decode::decode(r, ctx)?,
+            transform: Decode::decode(r, ctx)?,
+            global_alpha: Decode::decode(r, ctx)?,
+            alpha_mode: Decode::decode(r, ctx)?,
+            image_extent: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DrawIndexedIndirectCommand {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.index_count.encode(w);
+        self.instance_count.encode(w);
+        self.first_index.encode(w);
+        self.vertex_offset.encode(w);
+        self.first_instance.encode(w);
+    }
+}
+impl Decode for DrawIndexedIndirectCommand {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DrawIndexedIndirectCommand {
+            index_count: Decode::decode(r, ctx)?,
+            instance_count: Decode::decode(r, ctx)?,
+            first_index: Decode::decode(r, ctx)?,
+            vertex_offset: Decode::decode(r, ctx)?,
+            first_instance: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DrawIndirectCommand {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.vertex_count.encode(w);
+        self.instance_count.encode(w);
+        self.first_vertex.encode(w);
+        self.first_instance.encode(w);
+    }
+}
+impl Decode for DrawIndirectCommand {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DrawIndirectCommand {
+            vertex_count: Decode::decode(r, ctx)?,
+            instance_count: Decode::decode(r, ctx)?,
+            first_vertex: Decode::decode(r, ctx)?,
+            first_instance: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DrawMeshTasksIndirectCommandNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.task_count.encode(w);
+        self.first_task.encode(w);
+    }
+}
+impl Decode for DrawMeshTasksIndirectCommandNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DrawMeshTasksIndirectCommandNV {
+            task_count: Decode::decode(r, ctx)?,
+            first_task: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DrmFormatModifierPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.drm_format_modifier.encode(w);
+        self.drm_format_modifier_plane_count.encode(w);
+        self.drm_format_modifier_tiling_features.encode(w);
+    }
+}
+impl Decode for DrmFormatModifierPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DrmFormatModifierPropertiesEXT {
+            drm_format_modifier: Decode::decode(r, ctx)?,
+            drm_format_modifier_plane_count: Decode::decode(r, ctx)?,
+            drm_format_modifier_tiling_features: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for DrmFormatModifierPropertiesListEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.drm_format_modifier_count.encode(w);
+        w.array(
+            self.p_drm_format_modifier_properties,
+            self.drm_format_modifier_count as usize,
+        );
+    }
+}
+impl Decode for DrmFormatModifierPropertiesListEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(DrmFormatModifierPropertiesListEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            drm_format_modifier_count: Decode::decode(r, ctx)?,
+            p_drm_format_modifier_properties: ctx.array::<DrmFormatModifierPropertiesEXT>(r)?
+                as *mut _,
+        })
+    }
+}
+impl Encode for EventCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+    }
+}
+impl Decode for EventCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(EventCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExportFenceCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_types.encode(w);
+    }
+}
+impl Decode for ExportFenceCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExportFenceCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExportFenceWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.p_attributes.encode(w);
+        self.dw_access.encode(w);
+        self.name.encode(w);
+    }
+}
+impl Decode for ExportFenceWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExportFenceWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            p_attributes: Decode::decode(r, ctx)?,
+            dw_access: Decode::decode(r, ctx)?,
+            name: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExportMemoryAllocateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_types.encode(w);
+    }
+}
+impl Decode for ExportMemoryAllocateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExportMemoryAllocateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExportMemoryAllocateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_types.encode(w);
+    }
+}
+impl Decode for ExportMemoryAllocateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExportMemoryAllocateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExportMemoryWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.p_attributes.encode(w);
+        self.dw_access.encode(w);
+        self.name.encode(w);
+    }
+}
+impl Decode for ExportMemoryWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExportMemoryWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            p_attributes: Decode::decode(r, ctx)?,
+            dw_access: Decode::decode(r, ctx)?,
+            name: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExportMemoryWin32HandleInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.p_attributes.encode(w);
+        self.dw_access.encode(w);
+    }
+}
+impl Decode for ExportMemoryWin32HandleInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExportMemoryWin32HandleInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            p_attributes: Decode::decode(r, ctx)?,
+            dw_access: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExportSemaphoreCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_types.encode(w);
+    }
+}
+impl Decode for ExportSemaphoreCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExportSemaphoreCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExportSemaphoreWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.p_attributes.encode(w);
+        self.dw_access.encode(w);
+        self.name.encode(w);
+    }
+}
+impl Decode for ExportSemaphoreWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExportSemaphoreWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            p_attributes: Decode::decode(r, ctx)?,
+            dw_access: Decode::decode(r, ctx)?,
+            name: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExtensionProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.extension_name.encode(w);
+        self.spec_version.encode(w);
+    }
+}
+impl Decode for ExtensionProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExtensionProperties {
+            extension_name: Decode::decode(r, ctx)?,
+            spec_version: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for Extent2D {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.width.encode(w);
+        self.height.encode(w);
+    }
+}
+impl Decode for Extent2D {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Extent2D {
+            width: Decode::decode(r, ctx)?,
+            height: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for Extent3D {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.width.encode(w);
+        self.height.encode(w);
+        self.depth.encode(w);
+    }
+}
+impl Decode for Extent3D {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Extent3D {
+            width: Decode::decode(r, ctx)?,
+            height: Decode::decode(r, ctx)?,
+            depth: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalBufferProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.external_memory_properties.encode(w);
+    }
+}
+impl Decode for ExternalBufferProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalBufferProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            external_memory_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalFenceProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.export_from_imported_handle_types.encode(w);
+        self.compatible_handle_types.encode(w);
+        self.external_fence_features.encode(w);
+    }
+}
+impl Decode for ExternalFenceProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalFenceProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            export_from_imported_handle_types: Decode::decode(r, ctx)?,
+            compatible_handle_types: Decode::decode(r, ctx)?,
+            external_fence_features: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalFormatANDROID {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.external_format.encode(w);
+    }
+}
+impl Decode for ExternalFormatANDROID {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalFormatANDROID {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            external_format: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalImageFormatProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.external_memory_properties.encode(w);
+    }
+}
+impl Decode for ExternalImageFormatProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalImageFormatProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            external_memory_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalImageFormatPropertiesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.image_format_properties.encode(w);
+        self.external_memory_features.encode(w);
+        self.export_from_imported_handle_types.encode(w);
+        self.compatible_handle_types.encode(w);
+    }
+}
+impl Decode for ExternalImageFormatPropertiesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalImageFormatPropertiesNV {
+            image_format_properties: Decode::decode(r, ctx)?,
+            external_memory_features: Decode::decode(r, ctx)?,
+            export_from_imported_handle_types: Decode::decode(r, ctx)?,
+            compatible_handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalMemoryBufferCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_types.encode(w);
+    }
+}
+impl Decode for ExternalMemoryBufferCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalMemoryBufferCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalMemoryImageCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_types.encode(w);
+    }
+}
+impl Decode for ExternalMemoryImageCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalMemoryImageCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalMemoryImageCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_types.encode(w);
+    }
+}
+impl Decode for ExternalMemoryImageCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalMemoryImageCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalMemoryProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.external_memory_features.encode(w);
+        self.export_from_imported_handle_types.encode(w);
+        self.compatible_handle_types.encode(w);
+    }
+}
+impl Decode for ExternalMemoryProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalMemoryProperties {
+            external_memory_features: Decode::decode(r, ctx)?,
+            export_from_imported_handle_types: Decode::decode(r, ctx)?,
+            compatible_handle_types: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ExternalSemaphoreProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.export_from_imported_handle_types.encode(w);
+        self.compatible_handle_types.encode(w);
+        self.external_semaphore_features.encode(w);
+    }
+}
+impl Decode for ExternalSemaphoreProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ExternalSemaphoreProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            export_from_imported_handle_types: Decode::decode(r, ctx)?,
+            compatible_handle_types: Decode::decode(r, ctx)?,
+            external_semaphore_features: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for FenceCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+    }
+}
+impl Decode for FenceCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(FenceCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for FenceGetFdInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.fence.encode(w);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for FenceGetFdInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(FenceGetFdInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            fence: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for FenceGetWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.fence.encode(w);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for FenceGetWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(FenceGetWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            fence: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for FormatProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.linear_tiling_features.encode(w);
+        self.optimal_tiling_features.encode(w);
+        self.buffer_features.encode(w);
+    }
+}
+impl Decode for FormatProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(FormatProperties {
+            linear_tiling_features: Decode::decode(r, ctx)?,
+            optimal_tiling_features: Decode::decode(r, ctx)?,
+            buffer_features: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for FormatProperties2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.format_properties.encode(w);
+    }
+}
+impl Decode for FormatProperties2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(FormatProperties2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            format_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for FramebufferCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.render_pass.encode(w);
+        self.attachment_count.encode(w);
+        w.array(self.p_attachments, self.attachment_count as usize);
+        self.width.encode(w);
+        self.height.encode(w);
+        self.layers.encode(w);
+    }
+}
+impl Decode for FramebufferCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(FramebufferCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            render_pass: Decode::decode(r, ctx)?,
+            attachment_count: Decode::decode(r, ctx)?,
+            p_attachments: ctx.array(r)?,
+            width: Decode::decode(r, ctx)?,
+            height: Decode::decode(r, ctx)?,
+            layers: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for GeometryAABBNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.aabb_data.encode(w);
+        self.num_aab_bs.encode(w);
+        self.stride.encode(w);
+        self.offset.encode(w);
+    }
+}
+impl Decode for GeometryAABBNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(GeometryAABBNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            aabb_data: Decode::decode(r, ctx)?,
+            num_aab_bs: Decode::decode(r, ctx)?,
+            stride: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for GeometryDataNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.triangles.encode(w);
+        self.aabbs.encode(w);
+    }
+}
+impl Decode for GeometryDataNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(GeometryDataNV {
+            triangles: Decode::decode(r, ctx)?,
+            aabbs: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for GeometryNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.geometry_type.encode(w);
+        self.geometry.encode(w);
+        self.flags.encode(w);
+    }
+}
+impl Decode for GeometryNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(GeometryNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            geometry_type: Decode::decode(r, ctx)?,
+            geometry: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for GeometryTrianglesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.vertex_data.encode(w);
+        self.vertex_offset.encode(w);
+        self.vertex_count.encode(w);
+        self.vertex_stride.encode(w);
+        self.vertex_format.encode(w);
+        self.index_data.encode(w);
+        self.index_offset.encode(w);
+        self.index_count.encode(w);
+        self.index_type.encode(w);
+        self.transform_data.encode(w);
+        self.transform_offset.encode(w);
+    }
+}
+impl Decode for GeometryTrianglesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(GeometryTrianglesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            vertex_data: Decode::decode(r, ctx)?,
+            vertex_offset: Decode::decode(r, ctx)?,
+            vertex_count: Decode::decode(r, ctx)?,
+            vertex_stride: Decode::decode(r, ctx)?,
+            vertex_format: Decode::decode(r, ctx)?,
+            index_data: Decode::decode(r, ctx)?,
+            index_offset: Decode::decode(r, ctx)?,
+            index_count: Decode::decode(r, ctx)?,
+            index_type: Decode::decode(r, ctx)?,
+            transform_data: Decode::decode(r, ctx)?,
+            transform_offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for GraphicsPipelineCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.stage_count.encode(w);
+        w.array(self.p_stages, self.stage_count as usize);
+        w.ptr(self.p_vertex_input_state);
+        w.ptr(self.p_input_assembly_state);
+        w.ptr(self.p_tessellation_state);
+        w.ptr(self.p_viewport_state);
+        w.ptr(self.p_rasterization_state);
+        w.ptr(self.p_multisample_state);
+        w.ptr(self.p_depth_stencil_state);
+        w.ptr(self.p_color_blend_state);
+        w.ptr(self.p_dynamic_state);
+        self.layout.encode(w);
+        self.render_pass.encode(w);
+        self.subpass.encode(w);
+        self.base_pipeline_handle.encode(w);
+        self.base_pipeline_index.encode(w);
+    }
+}
+impl Decode for GraphicsPipelineCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(GraphicsPipelineCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            stage_count: Decode::decode(r, ctx)?,
+            p_stages: ctx.array(r)?,
+            p_vertex_input_state: ctx.ptr(r)?,
+            p_input_assembly_state: ctx.ptr(r)?,
+            p_tessellation_state: ctx.ptr(r)?,
+            p_viewport_state: ctx.ptr(r)?,
+            p_rasterization_state: ctx.ptr(r)?,
+            p_multisample_state: ctx.ptr(r)?,
+            p_depth_stencil_state: ctx.ptr(r)?,
+            p_color_blend_state: ctx.ptr(r)?,
+            p_dynamic_state: ctx.ptr(r)?,
+            layout: Decode::decode(r, ctx)?,
+            render_pass: Decode::decode(r, ctx)?,
+            subpass: Decode::decode(r, ctx)?,
+            base_pipeline_handle: Decode::decode(r, ctx)?,
+            base_pipeline_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for HdrMetadataEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.display_primary_red.encode(w);
+        self.display_primary_green.encode(w);
+        self.display_primary_blue.encode(w);
+        self.white_point.encode(w);
+        self.max_luminance.encode(w);
+        self.min_luminance.encode(w);
+        self.max_content_light_level.encode(w);
+        self.max_frame_average_light_level.encode(w);
+    }
+}
+impl Decode for HdrMetadataEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(HdrMetadataEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            display_primary_red: Decode::decode(r, ctx)?,
+            display_primary_green: Decode::decode(r, ctx)?,
+            display_primary_blue: Decode::decode(r, ctx)?,
+            white_point: Decode::decode(r, ctx)?,
+            max_luminance: Decode::decode(r, ctx)?,
+            min_luminance: Decode::decode(r, ctx)?,
+            max_content_light_level: Decode::decode(r, ctx)?,
+            max_frame_average_light_level: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for IOSSurfaceCreateInfoMVK {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.p_view.encode(w);
+    }
+}
+impl Decode for IOSSurfaceCreateInfoMVK {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(IOSSurfaceCreateInfoMVK {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            p_view: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageBlit {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.src_subresource.encode(w);
+        self.src_offsets.encode(w);
+        self.dst_subresource.encode(w);
+        self.dst_offsets.encode(w);
+    }
+}
+impl Decode for ImageBlit {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageBlit {
+            src_subresource: Decode::decode(r, ctx)?,
+            src_offsets: Decode::decode(r, ctx)?,
+            dst_subresource: Decode::decode(r, ctx)?,
+            dst_offsets: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageCopy {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.src_subresource.encode(w);
+        self.src_offset.encode(w);
+        self.dst_subresource.encode(w);
+        self.dst_offset.encode(w);
+        self.extent.encode(w);
+    }
+}
+impl Decode for ImageCopy {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageCopy {
+            src_subresource: Decode::decode(r, ctx)?,
+            src_offset: Decode::decode(r, ctx)?,
+            dst_subresource: Decode::decode(r, ctx)?,
+            dst_offset: Decode::decode(r, ctx)?,
+            extent: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.image_type.encode(w);
+        self.format.encode(w);
+        self.extent.encode(w);
+        self.mip_levels.encode(w);
+        self.array_layers.encode(w);
+        self.samples.encode(w);
+        self.tiling.encode(w);
+        self.usage.encode(w);
+        self.sharing_mode.encode(w);
+        self.queue_family_index_count.encode(w);
+        w.array(
+            self.p_queue_family_indices,
+            self.queue_family_index_count as usize,
+        );
+        self.initial_layout.encode(w);
+    }
+}
+impl Decode for ImageCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            image_type: Decode::decode(r, ctx)?,
+            format: Decode::decode(r, ctx)?,
+            extent: Decode::decode(r, ctx)?,
+            mip_levels: Decode::decode(r, ctx)?,
+            array_layers: Decode::decode(r, ctx)?,
+            samples: Decode::decode(r, ctx)?,
+            tiling: Decode::decode(r, ctx)?,
+            usage: Decode::decode(r, ctx)?,
+            sharing_mode: Decode::decode(r, ctx)?,
+            queue_family_index_count: Decode::decode(r, ctx)?,
+            p_queue_family_indices: ctx.array(r)?,
+            initial_layout: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageDrmFormatModifierExplicitCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.drm_format_modifier.encode(w);
+        self.drm_format_modifier_plane_count.encode(w);
+        w.array(
+            self.p_plane_layouts,
+            self.drm_format_modifier_plane_count as usize,
+        );
+    }
+}
+impl Decode for ImageDrmFormatModifierExplicitCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageDrmFormatModifierExplicitCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            drm_format_modifier: Decode::decode(r, ctx)?,
+            drm_format_modifier_plane_count: Decode::decode(r, ctx)?,
+            p_plane_layouts: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for ImageDrmFormatModifierListCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.drm_format_modifier_count.encode(w);
+        w.array(
+            self.p_drm_format_modifiers,
+            self.drm_format_modifier_count as usize,
+        );
+    }
+}
+impl Decode for ImageDrmFormatModifierListCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageDrmFormatModifierListCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            drm_format_modifier_count: Decode::decode(r, ctx)?,
+            p_drm_format_modifiers: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for ImageDrmFormatModifierPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.drm_format_modifier.encode(w);
+    }
+}
+impl Decode for ImageDrmFormatModifierPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageDrmFormatModifierPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            drm_format_modifier: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageFormatListCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.view_format_count.encode(w);
+        w.array(self.p_view_formats, self.view_format_count as usize);
+    }
+}
+impl Decode for ImageFormatListCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageFormatListCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            view_format_count: Decode::decode(r, ctx)?,
+            p_view_formats: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for ImageFormatProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.max_extent.encode(w);
+        self.max_mip_levels.encode(w);
+        self.max_array_layers.encode(w);
+        self.sample_counts.encode(w);
+        self.max_resource_size.encode(w);
+    }
+}
+impl Decode for ImageFormatProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageFormatProperties {
+            max_extent: Decode::decode(r, ctx)?,
+            max_mip_levels: Decode::decode(r, ctx)?,
+            max_array_layers: Decode::decode(r, ctx)?,
+            sample_counts: Decode::decode(r, ctx)?,
+            max_resource_size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageFormatProperties2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.image_format_properties.encode(w);
+    }
+}
+impl Decode for ImageFormatProperties2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageFormatProperties2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            image_format_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageMemoryBarrier {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.src_access_mask.encode(w);
+        self.dst_access_mask.encode(w);
+        self.old_layout.encode(w);
+        self.new_layout.encode(w);
+        self.src_queue_family_index.encode(w);
+        self.dst_queue_family_index.encode(w);
+        self.image.encode(w);
+        self.subresource_range.encode(w);
+    }
+}
+impl Decode for ImageMemoryBarrier {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageMemoryBarrier {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            src_access_mask: Decode::decode(r, ctx)?,
+            dst_access_mask: Decode::decode(r, ctx)?,
+            old_layout: Decode::decode(r, ctx)?,
+            new_layout: Decode::decode(r, ctx)?,
+            src_queue_family_index: Decode::decode(r, ctx)?,
+            dst_queue_family_index: Decode::decode(r, ctx)?,
+            image: Decode::decode(r, ctx)?,
+            subresource_range: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageMemoryRequirementsInfo2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.image.encode(w);
+    }
+}
+impl Decode for ImageMemoryRequirementsInfo2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageMemoryRequirementsInfo2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            image: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImagePipeSurfaceCreateInfoFUCHSIA {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.image_pipe_handle.encode(w);
+    }
+}
+impl Decode for ImagePipeSurfaceCreateInfoFUCHSIA {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImagePipeSurfaceCreateInfoFUCHSIA {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            image_pipe_handle: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImagePlaneMemoryRequirementsInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.plane_aspect.encode(w);
+    }
+}
+impl Decode for ImagePlaneMemoryRequirementsInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImagePlaneMemoryRequirementsInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            plane_aspect: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageResolve {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.src_subresource.encode(w);
+        self.src_offset.encode(w);
+        self.dst_subresource.encode(w);
+        self.dst_offset.encode(w);
+        self.extent.encode(w);
+    }
+}
+impl Decode for ImageResolve {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageResolve {
+            src_subresource: Decode::decode(r, ctx)?,
+            src_offset: Decode::decode(r, ctx)?,
+            dst_subresource: Decode::decode(r, ctx)?,
+            dst_offset: Decode::decode(r, ctx)?,
+            extent: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageSparseMemoryRequirementsInfo2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.image.encode(w);
+    }
+}
+impl Decode for ImageSparseMemoryRequirementsInfo2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageSparseMemoryRequirementsInfo2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            image: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageSubresource {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.aspect_mask.encode(w);
+        self.mip_level.encode(w);
+        self.array_layer.encode(w);
+    }
+}
+impl Decode for ImageSubresource {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageSubresource {
+            aspect_mask: Decode::decode(r, ctx)?,
+            mip_level: Decode::decode(r, ctx)?,
+            array_layer: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageSubresourceLayers {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.aspect_mask.encode(w);
+        self.mip_level.encode(w);
+        self.base_array_layer.encode(w);
+        self.layer_count.encode(w);
+    }
+}
+impl Decode for ImageSubresourceLayers {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageSubresourceLayers {
+            aspect_mask: Decode::decode(r, ctx)?,
+            mip_level: Decode::decode(r, ctx)?,
+            base_array_layer: Decode::decode(r, ctx)?,
+            layer_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageSubresourceRange {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.aspect_mask.encode(w);
+        self.base_mip_level.encode(w);
+        self.level_count.encode(w);
+        self.base_array_layer.encode(w);
+        self.layer_count.encode(w);
+    }
+}
+impl Decode for ImageSubresourceRange {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageSubresourceRange {
+            aspect_mask: Decode::decode(r, ctx)?,
+            base_mip_level: Decode::decode(r, ctx)?,
+            level_count: Decode::decode(r, ctx)?,
+            base_array_layer: Decode::decode(r, ctx)?,
+            layer_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageSwapchainCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.swapchain.encode(w);
+    }
+}
+impl Decode for ImageSwapchainCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageSwapchainCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            swapchain: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageViewASTCDecodeModeEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.decode_mode.encode(w);
+    }
+}
+impl Decode for ImageViewASTCDecodeModeEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageViewASTCDecodeModeEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            decode_mode: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageViewCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.image.encode(w);
+        self.view_type.encode(w);
+        self.format.encode(w);
+        self.components.encode(w);
+        self.subresource_range.encode(w);
+    }
+}
+impl Decode for ImageViewCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageViewCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            image: Decode::decode(r, ctx)?,
+            view_type: Decode::decode(r, ctx)?,
+            format: Decode::decode(r, ctx)?,
+            components: Decode::decode(r, ctx)?,
+            subresource_range: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImageViewUsageCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.usage.encode(w);
+    }
+}
+impl Decode for ImageViewUsageCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImageViewUsageCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            usage: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportAndroidHardwareBufferInfoANDROID {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.buffer.encode(w);
+    }
+}
+impl Decode for ImportAndroidHardwareBufferInfoANDROID {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportAndroidHardwareBufferInfoANDROID {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            buffer: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportFenceFdInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.fence.encode(w);
+        self.flags.encode(w);
+        self.handle_type.encode(w);
+        self.fd.encode(w);
+    }
+}
+impl Decode for ImportFenceFdInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportFenceFdInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            fence: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+            fd: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportFenceWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.fence.encode(w);
+        self.flags.encode(w);
+        self.handle_type.encode(w);
+        self.handle.encode(w);
+        self.name.encode(w);
+    }
+}
+impl Decode for ImportFenceWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportFenceWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            fence: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+            handle: Decode::decode(r, ctx)?,
+            name: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportMemoryFdInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_type.encode(w);
+        self.fd.encode(w);
+    }
+}
+impl Decode for ImportMemoryFdInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportMemoryFdInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_type: Decode::decode(r, ctx)?,
+            fd: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportMemoryHostPointerInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_type.encode(w);
+        self.p_host_pointer.encode(w);
+    }
+}
+impl Decode for ImportMemoryHostPointerInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportMemoryHostPointerInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_type: Decode::decode(r, ctx)?,
+            p_host_pointer: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportMemoryWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_type.encode(w);
+        self.handle.encode(w);
+        self.name.encode(w);
+    }
+}
+impl Decode for ImportMemoryWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportMemoryWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_type: Decode::decode(r, ctx)?,
+            handle: Decode::decode(r, ctx)?,
+            name: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportMemoryWin32HandleInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_type.encode(w);
+        self.handle.encode(w);
+    }
+}
+impl Decode for ImportMemoryWin32HandleInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportMemoryWin32HandleInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_type: Decode::decode(r, ctx)?,
+            handle: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportSemaphoreFdInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.semaphore.encode(w);
+        self.flags.encode(w);
+        self.handle_type.encode(w);
+        self.fd.encode(w);
+    }
+}
+impl Decode for ImportSemaphoreFdInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportSemaphoreFdInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            semaphore: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+            fd: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ImportSemaphoreWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.semaphore.encode(w);
+        self.flags.encode(w);
+        self.handle_type.encode(w);
+        self.handle.encode(w);
+        self.name.encode(w);
+    }
+}
+impl Decode for ImportSemaphoreWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ImportSemaphoreWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            semaphore: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+            handle: Decode::decode(r, ctx)?,
+            name: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for IndirectCommandsLayoutCreateInfoNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.pipeline_bind_point.encode(w);
+        self.flags.encode(w);
+        self.token_count.encode(w);
+        w.array(self.p_tokens, self.token_count as usize);
+    }
+}
+impl Decode for IndirectCommandsLayoutCreateInfoNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(IndirectCommandsLayoutCreateInfoNVX {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            pipeline_bind_point: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            token_count: Decode::decode(r, ctx)?,
+            p_tokens: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for IndirectCommandsLayoutTokenNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.token_type.encode(w);
+        self.binding_unit.encode(w);
+        self.dynamic_count.encode(w);
+        self.divisor.encode(w);
+    }
+}
+impl Decode for IndirectCommandsLayoutTokenNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(IndirectCommandsLayoutTokenNVX {
+            token_type: Decode::decode(r, ctx)?,
+            binding_unit: Decode::decode(r, ctx)?,
+            dynamic_count: Decode::decode(r, ctx)?,
+            divisor: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for IndirectCommandsTokenNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.token_type.encode(w);
+        self.buffer.encode(w);
+        self.offset.encode(w);
+    }
+}
+impl Decode for IndirectCommandsTokenNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(IndirectCommandsTokenNVX {
+            token_type: Decode::decode(r, ctx)?,
+            buffer: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for InputAttachmentAspectReference {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.subpass.encode(w);
+        self.input_attachment_index.encode(w);
+        self.aspect_mask.encode(w);
+    }
+}
+impl Decode for InputAttachmentAspectReference {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(InputAttachmentAspectReference {
+            subpass: Decode::decode(r, ctx)?,
+            input_attachment_index: Decode::decode(r, ctx)?,
+            aspect_mask: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for InstanceCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        w.ptr(self.p_application_info);
+        self.enabled_layer_count.encode(w);
+        w.str_array(
+            self.pp_enabled_layer_names,
+            self.enabled_layer_count as usize,
+        );
+        self.enabled_extension_count.encode(w);
+        w.str_array(
+            self.pp_enabled_extension_names,
+            self.enabled_extension_count as usize,
+        );
+    }
+}
+impl Decode for InstanceCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(InstanceCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            p_application_info: ctx.ptr(r)?,
+            enabled_layer_count: Decode::decode(r, ctx)?,
+            pp_enabled_layer_names: ctx.str_array(r)?,
+            enabled_extension_count: Decode::decode(r, ctx)?,
+            pp_enabled_extension_names: ctx.str_array(r)?,
+        })
+    }
+}
+impl Encode for LayerProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.layer_name.encode(w);
+        self.spec_version.encode(w);
+        self.implementation_version.encode(w);
+        self.description.encode(w);
+    }
+}
+impl Decode for LayerProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(LayerProperties {
+            layer_name: Decode::decode(r, ctx)?,
+            spec_version: Decode::decode(r, ctx)?,
+            implementation_version: Decode::decode(r, ctx)?,
+            description: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MacOSSurfaceCreateInfoMVK {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.p_view.encode(w);
+    }
+}
+impl Decode for MacOSSurfaceCreateInfoMVK {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MacOSSurfaceCreateInfoMVK {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            p_view: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MappedMemoryRange {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory.encode(w);
+        self.offset.encode(w);
+        self.size.encode(w);
+    }
+}
+impl Decode for MappedMemoryRange {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MappedMemoryRange {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryAllocateFlagsInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.device_mask.encode(w);
+    }
+}
+impl Decode for MemoryAllocateFlagsInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryAllocateFlagsInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            device_mask: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryAllocateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.allocation_size.encode(w);
+        self.memory_type_index.encode(w);
+    }
+}
+impl Decode for MemoryAllocateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryAllocateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            allocation_size: Decode::decode(r, ctx)?,
+            memory_type_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryBarrier {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.src_access_mask.encode(w);
+        self.dst_access_mask.encode(w);
+    }
+}
+impl Decode for MemoryBarrier {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryBarrier {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            src_access_mask: Decode::decode(r, ctx)?,
+            dst_access_mask: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryDedicatedAllocateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.image.encode(w);
+        self.buffer.encode(w);
+    }
+}
+impl Decode for MemoryDedicatedAllocateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryDedicatedAllocateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            image: Decode::decode(r, ctx)?,
+            buffer: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryDedicatedRequirements {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.prefers_dedicated_allocation.encode(w);
+        self.requires_dedicated_allocation.encode(w);
+    }
+}
+impl Decode for MemoryDedicatedRequirements {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryDedicatedRequirements {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            prefers_dedicated_allocation: Decode::decode(r, ctx)?,
+            requires_dedicated_allocation: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryFdPropertiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory_type_bits.encode(w);
+    }
+}
+impl Decode for MemoryFdPropertiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryFdPropertiesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory_type_bits: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryGetAndroidHardwareBufferInfoANDROID {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory.encode(w);
+    }
+}
+impl Decode for MemoryGetAndroidHardwareBufferInfoANDROID {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryGetAndroidHardwareBufferInfoANDROID {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryGetFdInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory.encode(w);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for MemoryGetFdInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryGetFdInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryGetWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory.encode(w);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for MemoryGetWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryGetWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryHeap {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.size.encode(w);
+        self.flags.encode(w);
+    }
+}
+impl Decode for MemoryHeap {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryHeap {
+            size: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryHostPointerPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory_type_bits.encode(w);
+    }
+}
+impl Decode for MemoryHostPointerPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryHostPointerPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory_type_bits: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryRequirements {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.size.encode(w);
+        self.alignment.encode(w);
+        self.memory_type_bits.encode(w);
+    }
+}
+impl Decode for MemoryRequirements {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryRequirements {
+            size: Decode::decode(r, ctx)?,
+            alignment: Decode::decode(r, ctx)?,
+            memory_type_bits: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryRequirements2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory_requirements.encode(w);
+    }
+}
+impl Decode for MemoryRequirements2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryRequirements2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory_requirements: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryType {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.property_flags.encode(w);
+        self.heap_index.encode(w);
+    }
+}
+impl Decode for MemoryType {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryType {
+            property_flags: Decode::decode(r, ctx)?,
+            heap_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MemoryWin32HandlePropertiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory_type_bits.encode(w);
+    }
+}
+impl Decode for MemoryWin32HandlePropertiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MemoryWin32HandlePropertiesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory_type_bits: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for MultisamplePropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_sample_location_grid_size.encode(w);
+    }
+}
+impl Decode for MultisamplePropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(MultisamplePropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_sample_location_grid_size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for NativeBufferANDROID {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle.encode(w);
+        self.stride.encode(w);
+        self.format.encode(w);
+        self.usage.encode(w);
+    }
+}
+impl Decode for NativeBufferANDROID {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(NativeBufferANDROID {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle: Decode::decode(r, ctx)?,
+            stride: Decode::decode(r, ctx)?,
+            format: Decode::decode(r, ctx)?,
+            usage: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ObjectTableCreateInfoNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.object_count.encode(w);
+        w.array(self.p_object_entry_types, self.object_count as usize);
+        w.array(self.p_object_entry_counts, self.object_count as usize);
+        w.array(self.p_object_entry_usage_flags, self.object_count as usize);
+        self.max_uniform_buffers_per_descriptor.encode(w);
+        self.max_storage_buffers_per_descriptor.encode(w);
+        self.max_storage_images_per_descriptor.encode(w);
+        self.max_sampled_images_per_descriptor.encode(w);
+        self.max_pipeline_layouts.encode(w);
+    }
+}
+impl Decode for ObjectTableCreateInfoNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ObjectTableCreateInfoNVX {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            object_count: Decode::decode(r, ctx)?,
+            p_object_entry_types: ctx.array(r)?,
+            p_object_entry_counts: ctx.array(r)?,
+            p_object_entry_usage_flags: ctx.array(r)?,
+            max_uniform_buffers_per_descriptor: Decode::decode(r, ctx)?,
+            max_storage_buffers_per_descriptor: Decode::decode(r, ctx)?,
+            max_storage_images_per_descriptor: Decode::decode(r, ctx)?,
+            max_sampled_images_per_descriptor: Decode::decode(r, ctx)?,
+            max_pipeline_layouts: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ObjectTableDescriptorSetEntryNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.ty.encode(w);
+        self.flags.encode(w);
+        self.pipeline_layout.encode(w);
+        self.descriptor_set.encode(w);
+    }
+}
+impl Decode for ObjectTableDescriptorSetEntryNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ObjectTableDescriptorSetEntryNVX {
+            ty: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            pipeline_layout: Decode::decode(r, ctx)?,
+            descriptor_set: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ObjectTableEntryNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.ty.encode(w);
+        self.flags.encode(w);
+    }
+}
+impl Decode for ObjectTableEntryNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ObjectTableEntryNVX {
+            ty: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ObjectTableIndexBufferEntryNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.ty.encode(w);
+        self.flags.encode(w);
+        self.buffer.encode(w);
+        self.index_type.encode(w);
+    }
+}
+impl Decode for ObjectTableIndexBufferEntryNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ObjectTableIndexBufferEntryNVX {
+            ty: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            buffer: Decode::decode(r, ctx)?,
+            index_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ObjectTablePipelineEntryNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.ty.encode(w);
+        self.flags.encode(w);
+        self.pipeline.encode(w);
+    }
+}
+impl Decode for ObjectTablePipelineEntryNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ObjectTablePipelineEntryNVX {
+            ty: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            pipeline: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ObjectTablePushConstantEntryNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.ty.encode(w);
+        self.flags.encode(w);
+        self.pipeline_layout.encode(w);
+        self.stage_flags.encode(w);
+    }
+}
+impl Decode for ObjectTablePushConstantEntryNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ObjectTablePushConstantEntryNVX {
+            ty: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            pipeline_layout: Decode::decode(r, ctx)?,
+            stage_flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ObjectTableVertexBufferEntryNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.ty.encode(w);
+        self.flags.encode(w);
+        self.buffer.encode(w);
+    }
+}
+impl Decode for ObjectTableVertexBufferEntryNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ObjectTableVertexBufferEntryNVX {
+            ty: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+            buffer: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for Offset2D {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.x.encode(w);
+        self.y.encode(w);
+    }
+}
+impl Decode for Offset2D {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Offset2D {
+            x: Decode::decode(r, ctx)?,
+            y: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for Offset3D {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.x.encode(w);
+        self.y.encode(w);
+        self.z.encode(w);
+    }
+}
+impl Decode for Offset3D {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Offset3D {
+            x: Decode::decode(r, ctx)?,
+            y: Decode::decode(r, ctx)?,
+            z: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PastPresentationTimingGOOGLE {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.present_id.encode(w);
+        self.desired_present_time.encode(w);
+        self.actual_present_time.encode(w);
+        self.earliest_present_time.encode(w);
+        self.present_margin.encode(w);
+    }
+}
+impl Decode for PastPresentationTimingGOOGLE {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PastPresentationTimingGOOGLE {
+            present_id: Decode::decode(r, ctx)?,
+            desired_present_time: Decode::decode(r, ctx)?,
+            actual_present_time: Decode::decode(r, ctx)?,
+            earliest_present_time: Decode::decode(r, ctx)?,
+            present_margin: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDevice16BitStorageFeatures {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.storage_buffer16_bit_access.encode(w);
+        self.uniform_and_storage_buffer16_bit_access.encode(w);
+        self.storage_push_constant16.encode(w);
+        self.storage_input_output16.encode(w);
+    }
+}
+impl Decode for PhysicalDevice16BitStorageFeatures {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDevice16BitStorageFeatures {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            storage_buffer16_bit_access: Decode::decode(r, ctx)?,
+            uniform_and_storage_buffer16_bit_access: Decode::decode(r, ctx)?,
+            storage_push_constant16: Decode::decode(r, ctx)?,
+            storage_input_output16: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDevice8BitStorageFeaturesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.storage_buffer8_bit_access.encode(w);
+        self.uniform_and_storage_buffer8_bit_access.encode(w);
+        self.storage_push_constant8.encode(w);
+    }
+}
+impl Decode for PhysicalDevice8BitStorageFeaturesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDevice8BitStorageFeaturesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            storage_buffer8_bit_access: Decode::decode(r, ctx)?,
+            uniform_and_storage_buffer8_bit_access: Decode::decode(r, ctx)?,
+            storage_push_constant8: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceASTCDecodeFeaturesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.decode_mode_shared_exponent.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceASTCDecodeFeaturesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceASTCDecodeFeaturesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            decode_mode_shared_exponent: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceBlendOperationAdvancedFeaturesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.advanced_blend_coherent_operations.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceBlendOperationAdvancedFeaturesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceBlendOperationAdvancedFeaturesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            advanced_blend_coherent_operations: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceBlendOperationAdvancedPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.advanced_blend_max_color_attachments.encode(w);
+        self.advanced_blend_independent_blend.encode(w);
+        self.advanced_blend_non_premultiplied_src_color.encode(w);
+        self.advanced_blend_non_premultiplied_dst_color.encode(w);
+        self.advanced_blend_correlated_overlap.encode(w);
+        self.advanced_blend_all_operations.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceBlendOperationAdvancedPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceBlendOperationAdvancedPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            advanced_blend_max_color_attachments: Decode::decode(r, ctx)?,
+            advanced_blend_independent_blend: Decode::decode(r, ctx)?,
+            advanced_blend_non_premultiplied_src_color: Decode::decode(r, ctx)?,
+            advanced_blend_non_premultiplied_dst_color: Decode::decode(r, ctx)?,
+            advanced_blend_correlated_overlap: Decode::decode(r, ctx)?,
+            advanced_blend_all_operations: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceComputeShaderDerivativesFeaturesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.compute_derivative_group_quads.encode(w);
+        self.compute_derivative_group_linear.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceComputeShaderDerivativesFeaturesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceComputeShaderDerivativesFeaturesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            compute_derivative_group_quads: Decode::decode(r, ctx)?,
+            compute_derivative_group_linear: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceConditionalRenderingFeaturesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.conditional_rendering.encode(w);
+        self.inherited_conditional_rendering.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceConditionalRenderingFeaturesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceConditionalRenderingFeaturesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            conditional_rendering: Decode::decode(r, ctx)?,
+            inherited_conditional_rendering: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceConservativeRasterizationPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.primitive_overestimation_size.encode(w);
+        self.max_extra_primitive_overestimation_size.encode(w);
+        self.extra_primitive_overestimation_size_granularity
+            .encode(w);
+        self.primitive_underestimation.encode(w);
+        self.conservative_point_and_line_rasterization.encode(w);
+        self.degenerate_triangles_rasterized.encode(w);
+        self.degenerate_lines_rasterized.encode(w);
+        self.fully_covered_fragment_shader_input_variable.encode(w);
+        self.conservative_rasterization_post_depth_coverage
+            .encode(w);
+    }
+}
+impl Decode for PhysicalDeviceConservativeRasterizationPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceConservativeRasterizationPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            primitive_overestimation_size: Decode::decode(r, ctx)?,
+            max_extra_primitive_overestimation_size: Decode::decode(r, ctx)?,
+            extra_primitive_overestimation_size_granularity: Decode::decode(r, ctx)?,
+            primitive_underestimation: Decode::decode(r, ctx)?,
+            conservative_point_and_line_rasterization: Decode::decode(r, ctx)?,
+            degenerate_triangles_rasterized: Decode::decode(r, ctx)?,
+            degenerate_lines_rasterized: Decode::decode(r, ctx)?,
+            fully_covered_fragment_shader_input_variable: Decode::decode(r, ctx)?,
+            conservative_rasterization_post_depth_coverage: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceCornerSampledImageFeaturesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.corner_sampled_image.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceCornerSampledImageFeaturesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceCornerSampledImageFeaturesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            corner_sampled_image: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceDescriptorIndexingFeaturesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shader_input_attachment_array_dynamic_indexing
+            .encode(w);
+        self.shader_uniform_texel_buffer_array_dynamic_indexing
+            .encode(w);
+        self.shader_storage_texel_buffer_array_dynamic_indexing
+            .encode(w);
+        self.shader_uniform_buffer_array_non_uniform_indexing
+            .encode(w);
+        self.shader_sampled_image_array_non_uniform_indexing
+            .encode(w);
+        self.shader_storage_buffer_array_non_uniform_indexing
+            .encode(w);
+        self.shader_storage_image_array_non_uniform_indexing
+            .encode(w);
+        self.shader_input_attachment_array_non_uniform_indexing
+            .encode(w);
+        self.shader_uniform_texel_buffer_array_non_uniform_indexing
+            .encode(w);
+        self.shader_storage_texel_buffer_array_non_uniform_indexing
+            .encode(w);
+        self.descriptor_binding_uniform_buffer_update_after_bind
+            .encode(w);
+        self.descriptor_binding_sampled_image_update_after_bind
+            .encode(w);
+        self.descriptor_binding_storage_image_update_after_bind
+            .encode(w);
+        self.descriptor_binding_storage_buffer_update_after_bind
+            .encode(w);
+        self.descriptor_binding_uniform_texel_buffer_update_after_bind
+            .encode(w);
+        self.descriptor_binding_storage_texel_buffer_update_after_bind
+            .encode(w);
+        self.descriptor_binding_update_unused_while_pending
+            .encode(w);
+        self.descriptor_binding_partially_bound.encode(w);
+        self.descriptor_binding_variable_descriptor_count.encode(w);
+        self.runtime_descriptor_array.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceDescriptorIndexingFeaturesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceDescriptorIndexingFeaturesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shader_input_attachment_array_dynamic_indexing: Decode::decode(r, ctx)?,
+            shader_uniform_texel_buffer_array_dynamic_indexing: Decode::decode(r, ctx)?,
+            shader_storage_texel_buffer_array_dynamic_indexing: Decode::decode(r, ctx)?,
+            shader_uniform_buffer_array_non_uniform_indexing: Decode::decode(r, ctx)?,
+            shader_sampled_image_array_non_uniform_indexing: Decode::decode(r, ctx)?,
+            shader_storage_buffer_array_non_uniform_indexing: Decode::decode(r, ctx)?,
+            shader_storage_image_array_non_uniform_indexing: Decode::decode(r, ctx)?,
+            shader_input_attachment_array_non_uniform_indexing: Decode::decode(r, ctx)?,
+            shader_uniform_texel_buffer_array_non_uniform_indexing: Decode::decode(r, ctx)?,
+            shader_storage_texel_buffer_array_non_uniform_indexing: Decode::decode(r, ctx)?,
+            descriptor_binding_uniform_buffer_update_after_bind: Decode::decode(r, ctx)?,
+            descriptor_binding_sampled_image_update_after_bind: Decode::decode(r, ctx)?,
+            descriptor_binding_storage_image_update_after_bind: Decode::decode(r, ctx)?,
+            descriptor_binding_storage_buffer_update_after_bind: Decode::decode(r, ctx)?,
+            descriptor_binding_uniform_texel_buffer_update_after_bind: Decode::decode(r, ctx)?,
+            descriptor_binding_storage_texel_buffer_update_after_bind: Decode::decode(r, ctx)?,
+            descriptor_binding_update_unused_while_pending: Decode::decode(r, ctx)?,
+            descriptor_binding_partially_bound: Decode::decode(r, ctx)?,
+            descriptor_binding_variable_descriptor_count: Decode::decode(r, ctx)?,
+            runtime_descriptor_array: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceDescriptorIndexingPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_update_after_bind_descriptors_in_all_pools
+            .encode(w);
+        self.shader_uniform_buffer_array_non_uniform_indexing_native
+            .encode(w);
+        self.shader_sampled_image_array_non_uniform_indexing_native
+            .encode(w);
+        self.shader_storage_buffer_array_non_uniform_indexing_native
+            .encode(w);
+        self.shader_storage_image_array_non_uniform_indexing_native
+            .encode(w);
+        self.shader_input_attachment_array_non_uniform_indexing_native
+            .encode(w);
+        self.robust_buffer_access_update_after_bind.encode(w);
+        self.quad_divergent_implicit_lod.encode(w);
+        self.max_per_stage_descriptor_update_after_bind_samplers
+            .encode(w);
+        self.max_per_stage_descriptor_update_after_bind_uniform_buffers
+            .encode(w);
+        self.max_per_stage_descriptor_update_after_bind_storage_buffers
+            .encode(w);
+        self.max_per_stage_descriptor_update_after_bind_sampled_images
+            .encode(w);
+        self.max_per_stage_descriptor_update_after_bind_storage_images
+            .encode(w);
+        self.max_per_stage_descriptor_update_after_bind_input_attachments
+            .encode(w);
+        self.max_per_stage_update_after_bind_resources.encode(w);
+        self.max_descriptor_set_update_after_bind_samplers.encode(w);
+        self.max_descriptor_set_update_after_bind_uniform_buffers
+            .encode(w);
+        self.max_descriptor_set_update_after_bind_uniform_buffers_dynamic
+            .encode(w);
+        self.max_descriptor_set_update_after_bind_storage_buffers
+            .encode(w);
+        self.max_descriptor_set_update_after_bind_storage_buffers_dynamic
+            .encode(w);
+        self.max_descriptor_set_update_after_bind_sampled_images
+            .encode(w);
+        self.max_descriptor_set_update_after_bind_storage_images
+            .encode(w);
+        self.max_descriptor_set_update_after_bind_input_attachments
+            .encode(w);
+    }
+}
+impl Decode for PhysicalDeviceDescriptorIndexingPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceDescriptorIndexingPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_update_after_bind_descriptors_in_all_pools: Decode::decode(r, ctx)?,
+            shader_uniform_buffer_array_non_uniform_indexing_native: Decode::decode(r, ctx)?,
+            shader_sampled_image_array_non_uniform_indexing_native: Decode::decode(r, ctx)?,
+            shader_storage_buffer_array_non_uniform_indexing_native: Decode::decode(r, ctx)?,
+            shader_storage_image_array_non_uniform_indexing_native: Decode::decode(r, ctx)?,
+            shader_input_attachment_array_non_uniform_indexing_native: Decode::decode(r, ctx)?,
+            robust_buffer_access_update_after_bind: Decode::decode(r, ctx)?,
+            quad_divergent_implicit_lod: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_update_after_bind_samplers: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_update_after_bind_uniform_buffers: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_update_after_bind_storage_buffers: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_update_after_bind_sampled_images: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_update_after_bind_storage_images: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_update_after_bind_input_attachments: Decode::decode(r, ctx)?,
+            max_per_stage_update_after_bind_resources: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_samplers: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_uniform_buffers: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_uniform_buffers_dynamic: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_storage_buffers: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_storage_buffers_dynamic: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_sampled_images: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_storage_images: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_input_attachments: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceDiscardRectanglePropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_discard_rectangles.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceDiscardRectanglePropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceDiscardRectanglePropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_discard_rectangles: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceDriverPropertiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.driver_id.encode(w);
+        self.driver_name.encode(w);
+        self.driver_info.encode(w);
+        self.conformance_version.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceDriverPropertiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceDriverPropertiesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            driver_id: Decode::decode(r, ctx)?,
+            driver_name: Decode::decode(r, ctx)?,
+            driver_info: Decode::decode(r, ctx)?,
+            conformance_version: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceExclusiveScissorFeaturesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.exclusive_scissor.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceExclusiveScissorFeaturesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceExclusiveScissorFeaturesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            exclusive_scissor: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceExternalBufferInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.usage.encode(w);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceExternalBufferInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceExternalBufferInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            usage: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceExternalFenceInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceExternalFenceInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceExternalFenceInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceExternalImageFormatInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceExternalImageFormatInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceExternalImageFormatInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceExternalMemoryHostPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.min_imported_host_pointer_alignment.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceExternalMemoryHostPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceExternalMemoryHostPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            min_imported_host_pointer_alignment: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceExternalSemaphoreInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceExternalSemaphoreInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceExternalSemaphoreInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceFeatures {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.robust_buffer_access.encode(w);
+        self.full_draw_index_uint32.encode(w);
+        self.image_cube_array.encode(w);
+        self.independent_blend.encode(w);
+        self.geometry_shader.encode(w);
+        self.tessellation_shader.encode(w);
+        self.sample_rate_shading.encode(w);
+        self.dual_src_blend.encode(w);
+        self.logic_op.encode(w);
+        self.multi_draw_indirect.encode(w);
+        self.draw_indirect_first_instance.encode(w);
+        self.depth_clamp.encode(w);
+        self.depth_bias_clamp.encode(w);
+        self.fill_mode_non_solid.encode(w);
+        self.depth_bounds.encode(w);
+        self.wide_lines.encode(w);
+        self.large_points.encode(w);
+        self.alpha_to_one.encode(w);
+        self.multi_viewport.encode(w);
+        self.sampler_anisotropy.encode(w);
+        self.texture_compression_etc2.encode(w);
+        self.texture_compression_astc_ldr.encode(w);
+        self.texture_compression_bc.encode(w);
+        self.occlusion_query_precise.encode(w);
+        self.pipeline_statistics_query.encode(w);
+        self.vertex_pipeline_stores_and_atomics.encode(w);
+        self.fragment_stores_and_atomics.encode(w);
+        self.shader_tessellation_and_geometry_point_size.encode(w);
+        self.shader_image_gather_extended.encode(w);
+        self.shader_storage_image_extended_formats.encode(w);
+        self.shader_storage_image_multisample.encode(w);
+        self.shader_storage_image_read_without_format.encode(w);
+        self.shader_storage_image_write_without_format.encode(w);
+        self.shader_uniform_buffer_array_dynamic_indexing.encode(w);
+        self.shader_sampled_image_array_dynamic_indexing.encode(w);
+        self.shader_storage_buffer_array_dynamic_indexing.encode(w);
+        self.shader_storage_image_array_dynamic_indexing.encode(w);
+        self.shader_clip_distance.encode(w);
+        self.shader_cull_distance.encode(w);
+        self.shader_float64.encode(w);
+        self.shader_int64.encode(w);
+        self.shader_int16.encode(w);
+        self.shader_resource_residency.encode(w);
+        self.shader_resource_min_lod.encode(w);
+        self.sparse_binding.encode(w);
+        self.sparse_residency_buffer.encode(w);
+        self.sparse_residency_image2_d.encode(w);
+        self.sparse_residency_image3_d.encode(w);
+        self.sparse_residency2_samples.encode(w);
+        self.sparse_residency4_samples.encode(w);
+        self.sparse_residency8_samples.encode(w);
+        self.sparse_residency16_samples.encode(w);
+        self.sparse_residency_aliased.encode(w);
+        self.variable_multisample_rate.encode(w);
+        self.inherited_queries.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceFeatures {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceFeatures {
+            robust_buffer_access: Decode::decode(r, ctx)?,
+            full_draw_index_uint32: Decode::decode(r, ctx)?,
+            image_cube_array: Decode::decode(r, ctx)?,
+            independent_blend: Decode::decode(r, ctx)?,
+            geometry_shader: Decode::decode(r, ctx)?,
+            tessellation_shader: Decode::decode(r, ctx)?,
+            sample_rate_shading: Decode::decode(r, ctx)?,
+            dual_src_blend: Decode::decode(r, ctx)?,
+            logic_op: Decode::decode(r, ctx)?,
+            multi_draw_indirect: Decode::decode(r, ctx)?,
+            draw_indirect_first_instance: Decode::decode(r, ctx)?,
+            depth_clamp: Decode::decode(r, ctx)?,
+            depth_bias_clamp: Decode::decode(r, ctx)?,
+            fill_mode_non_solid: Decode::decode(r, ctx)?,
+            depth_bounds: Decode::decode(r, ctx)?,
+            wide_lines: Decode::decode(r, ctx)?,
+            large_points: Decode::decode(r, ctx)?,
+            alpha_to_one: Decode::decode(r, ctx)?,
+            multi_viewport: Decode::decode(r, ctx)?,
+            sampler_anisotropy: Decode::decode(r, ctx)?,
+            texture_compression_etc2: Decode::decode(r, ctx)?,
+            texture_compression_astc_ldr: Decode::decode(r, ctx)?,
+            texture_compression_bc: Decode::decode(r, ctx)?,
+            occlusion_query_precise: Decode::decode(r, ctx)?,
+            pipeline_statistics_query: Decode::decode(r, ctx)?,
+            vertex_pipeline_stores_and_atomics: Decode::decode(r, ctx)?,
+            fragment_stores_and_atomics: Decode::decode(r, ctx)?,
+            shader_tessellation_and_geometry_point_size: Decode::decode(r, ctx)?,
+            shader_image_gather_extended: Decode::decode(r, ctx)?,
+            shader_storage_image_extended_formats: Decode::decode(r, ctx)?,
+            shader_storage_image_multisample: Decode::decode(r, ctx)?,
+            shader_storage_image_read_without_format: Decode::decode(r, ctx)?,
+            shader_storage_image_write_without_format: Decode::decode(r, ctx)?,
+            shader_uniform_buffer_array_dynamic_indexing: Decode::decode(r, ctx)?,
+            shader_sampled_image_array_dynamic_indexing: Decode::decode(r, ctx)?,
+            shader_storage_buffer_array_dynamic_indexing: Decode::decode(r, ctx)?,
+            shader_storage_image_array_dynamic_indexing: Decode::decode(r, ctx)?,
+            shader_clip_distance: Decode::decode(r, ctx)?,
+            shader_cull_distance: Decode::decode(r, ctx)?,
+            shader_float64: Decode::decode(r, ctx)?,
+            shader_int64: Decode::decode(r, ctx)?,
+            shader_int16: Decode::decode(r, ctx)?,
+            shader_resource_residency: Decode::decode(r, ctx)?,
+            shader_resource_min_lod: Decode::decode(r, ctx)?,
+            sparse_binding: Decode::decode(r, ctx)?,
+            sparse_residency_buffer: Decode::decode(r, ctx)?,
+            sparse_residency_image2_d: Decode::decode(r, ctx)?,
+            sparse_residency_image3_d: Decode::decode(r, ctx)?,
+            sparse_residency2_samples: Decode::decode(r, ctx)?,
+            sparse_residency4_samples: Decode::decode(r, ctx)?,
+            sparse_residency8_samples: Decode::decode(r, ctx)?,
+            sparse_residency16_samples: Decode::decode(r, ctx)?,
+            sparse_residency_aliased: Decode::decode(r, ctx)?,
+            variable_multisample_rate: Decode::decode(r, ctx)?,
+            inherited_queries: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceFeatures2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.features.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceFeatures2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceFeatures2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            features: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceFragmentShaderBarycentricFeaturesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.fragment_shader_barycentric.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceFragmentShaderBarycentricFeaturesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceFragmentShaderBarycentricFeaturesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            fragment_shader_barycentric: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceGroupProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.physical_device_count.encode(w);
+        self.physical_devices.encode(w);
+        self.subset_allocation.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceGroupProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceGroupProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            physical_device_count: Decode::decode(r, ctx)?,
+            physical_devices: Decode::decode(r, ctx)?,
+            subset_allocation: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceIDProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.device_uuid.encode(w);
+        self.driver_uuid.encode(w);
+        self.device_luid.encode(w);
+        self.device_node_mask.encode(w);
+        self.device_luid_valid.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceIDProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceIDProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            device_uuid: Decode::decode(r, ctx)?,
+            driver_uuid: Decode::decode(r, ctx)?,
+            device_luid: Decode::decode(r, ctx)?,
+            device_node_mask: Decode::decode(r, ctx)?,
+            device_luid_valid: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceImageDrmFormatModifierInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.drm_format_modifier.encode(w);
+        self.sharing_mode.encode(w);
+        self.queue_family_index_count.encode(w);
+        w.array(
+            self.p_queue_family_indices,
+            self.queue_family_index_count as usize,
+        );
+    }
+}
+impl Decode for PhysicalDeviceImageDrmFormatModifierInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceImageDrmFormatModifierInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            drm_format_modifier: Decode::decode(r, ctx)?,
+            sharing_mode: Decode::decode(r, ctx)?,
+            queue_family_index_count: Decode::decode(r, ctx)?,
+            p_queue_family_indices: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceImageFormatInfo2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.format.encode(w);
+        self.ty.encode(w);
+        self.tiling.encode(w);
+        self.usage.encode(w);
+        self.flags.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceImageFormatInfo2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceImageFormatInfo2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            format: Decode::decode(r, ctx)?,
+            ty: Decode::decode(r, ctx)?,
+            tiling: Decode::decode(r, ctx)?,
+            usage: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceInlineUniformBlockFeaturesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.inline_uniform_block.encode(w);
+        self.descriptor_binding_inline_uniform_block_update_after_bind
+            .encode(w);
+    }
+}
+impl Decode for PhysicalDeviceInlineUniformBlockFeaturesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceInlineUniformBlockFeaturesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            inline_uniform_block: Decode::decode(r, ctx)?,
+            descriptor_binding_inline_uniform_block_update_after_bind: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceInlineUniformBlockPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_inline_uniform_block_size.encode(w);
+        self.max_per_stage_descriptor_inline_uniform_blocks
+            .encode(w);
+        self.max_per_stage_descriptor_update_after_bind_inline_uniform_blocks
+            .encode(w);
+        self.max_descriptor_set_inline_uniform_blocks.encode(w);
+        self.max_descriptor_set_update_after_bind_inline_uniform_blocks
+            .encode(w);
+    }
+}
+impl Decode for PhysicalDeviceInlineUniformBlockPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceInlineUniformBlockPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_inline_uniform_block_size: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_inline_uniform_blocks: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: Decode::decode(
+                r, ctx,
+            )?,
+            max_descriptor_set_inline_uniform_blocks: Decode::decode(r, ctx)?,
+            max_descriptor_set_update_after_bind_inline_uniform_blocks: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceLimits {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.max_image_dimension1_d.encode(w);
+        self.max_image_dimension2_d.encode(w);
+        self.max_image_dimension3_d.encode(w);
+        self.max_image_dimension_cube.encode(w);
+        self.max_image_array_layers.encode(w);
+        self.max_texel_buffer_elements.encode(w);
+        self.max_uniform_buffer_range.encode(w);
+        self.max_storage_buffer_range.encode(w);
+        self.max_push_constants_size.encode(w);
+        self.max_memory_allocation_count.encode(w);
+        self.max_sampler_allocation_count.encode(w);
+        self.buffer_image_granularity.encode(w);
+        self.sparse_address_space_size.encode(w);
+        self.max_bound_descriptor_sets.encode(w);
+        self.max_per_stage_descriptor_samplers.encode(w);
+        self.max_per_stage_descriptor_uniform_buffers.encode(w);
+        self.max_per_stage_descriptor_storage_buffers.encode(w);
+        self.max_per_stage_descriptor_sampled_images.encode(w);
+        self.max_per_stage_descriptor_storage_images.encode(w);
+        self.max_per_stage_descriptor_input_attachments.encode(w);
+        self.max_per_stage_resources.encode(w);
+        self.max_descriptor_set_samplers.encode(w);
+        self.max_descriptor_set_uniform_buffers.encode(w);
+        self.max_descriptor_set_uniform_buffers_dynamic.encode(w);
+        self.max_descriptor_set_storage_buffers.encode(w);
+        self.max_descriptor_set_storage_buffers_dynamic.encode(w);
+        self.max_descriptor_set_sampled_images.encode(w);
+        self.max_descriptor_set_storage_images.encode(w);
+        self.max_descriptor_set_input_attachments.encode(w);
+        self.max_vertex_input_attributes.encode(w);
+        self.max_vertex_input_bindings.encode(w);
+        self.max_vertex_input_attribute_offset.encode(w);
+        self.max_vertex_input_binding_stride.encode(w);
+        self.max_vertex_output_components.encode(w);
+        self.max_tessellation_generation_level.encode(w);
+        self.max_tessellation_patch_size.encode(w);
+        self.max_tessellation_control_per_vertex_input_components
+            .encode(w);
+        self.max_tessellation_control_per_vertex_output_components
+            .encode(w);
+        self.max_tessellation_control_per_patch_output_components
+            .encode(w);
+        self.max_tessellation_control_total_output_components
+            .encode(w);
+        self.max_tessellation_evaluation_input_components.encode(w);
+        self.max_tessellation_evaluation_output_components.encode(w);
+        self.max_geometry_shader_invocations.encode(w);
+        self.max_geometry_input_components.encode(w);
+        self.max_geometry_output_components.encode(w);
+        self.max_geometry_output_vertices.encode(w);
+        self.max_geometry_total_output_components.encode(w);
+        self.max_fragment_input_components.encode(w);
+        self.max_fragment_output_attachments.encode(w);
+        self.max_fragment_dual_src_attachments.encode(w);
+        self.max_fragment_combined_output_resources.encode(w);
+        self.max_compute_shared_memory_size.encode(w);
+        self.max_compute_work_group_count.encode(w);
+        self.max_compute_work_group_invocations.encode(w);
+        self.max_compute_work_group_size.encode(w);
+        self.sub_pixel_precision_bits.encode(w);
+        self.sub_texel_precision_bits.encode(w);
+        self.mipmap_precision_bits.encode(w);
+        self.max_draw_indexed_index_value.encode(w);
+        self.max_draw_indirect_count.encode(w);
+        self.max_sampler_lod_bias.encode(w);
+        self.max_sampler_anisotropy.encode(w);
+        self.max_viewports.encode(w);
+        self.max_viewport_dimensions.encode(w);
+        self.viewport_bounds_range.encode(w);
+        self.viewport_sub_pixel_bits.encode(w);
+        self.min_memory_map_alignment.encode(w);
+        self.min_texel_buffer_offset_alignment.encode(w);
+        self.min_uniform_buffer_offset_alignment.encode(w);
+        self.min_storage_buffer_offset_alignment.encode(w);
+        self.min_texel_offset.encode(w);
+        self.max_texel_offset.encode(w);
+        self.min_texel_gather_offset.encode(w);
+        self.max_texel_gather_offset.encode(w);
+        self.min_interpolation_offset.encode(w);
+        self.max_interpolation_offset.encode(w);
+        self.sub_pixel_interpolation_offset_bits.encode(w);
+        self.max_framebuffer_width.encode(w);
+        self.max_framebuffer_height.encode(w);
+        self.max_framebuffer_layers.encode(w);
+        self.framebuffer_color_sample_counts.encode(w);
+        self.framebuffer_depth_sample_counts.encode(w);
+        self.framebuffer_stencil_sample_counts.encode(w);
+        self.framebuffer_no_attachments_sample_counts.encode(w);
+        self.max_color_attachments.encode(w);
+        self.sampled_image_color_sample_counts.encode(w);
+        self.sampled_image_integer_sample_counts.encode(w);
+        self.sampled_image_depth_sample_counts.encode(w);
+        self.sampled_image_stencil_sample_counts.encode(w);
+        self.storage_image_sample_counts.encode(w);
+        self.max_sample_mask_words.encode(w);
+        self.timestamp_compute_and_graphics.encode(w);
+        self.timestamp_period.encode(w);
+        self.max_clip_distances.encode(w);
+        self.max_cull_distances.encode(w);
+        self.max_combined_clip_and_cull_distances.encode(w);
+        self.discrete_queue_priorities.encode(w);
+        self.point_size_range.encode(w);
+        self.line_width_range.encode(w);
+        self.point_size_granularity.encode(w);
+        self.line_width_granularity.encode(w);
+        self.strict_lines.encode(w);
+        self.standard_sample_locations.encode(w);
+        self.optimal_buffer_copy_offset_alignment.encode(w);
+        self.optimal_buffer_copy_row_pitch_alignment.encode(w);
+        self.non_coherent_atom_size.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceLimits {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceLimits {
+            max_image_dimension1_d: Decode::decode(r, ctx)?,
+            max_image_dimension2_d: Decode::decode(r, ctx)?,
+            max_image_dimension3_d: Decode::decode(r, ctx)?,
+            max_image_dimension_cube: Decode::decode(r, ctx)?,
+            max_image_array_layers: Decode::decode(r, ctx)?,
+            max_texel_buffer_elements: Decode::decode(r, ctx)?,
+            max_uniform_buffer_range: Decode::decode(r, ctx)?,
+            max_storage_buffer_range: Decode::decode(r, ctx)?,
+            max_push_constants_size: Decode::decode(r, ctx)?,
+            max_memory_allocation_count: Decode::decode(r, ctx)?,
+            max_sampler_allocation_count: Decode::decode(r, ctx)?,
+            buffer_image_granularity: Decode::decode(r, ctx)?,
+            sparse_address_space_size: Decode::decode(r, ctx)?,
+            max_bound_descriptor_sets: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_samplers: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_uniform_buffers: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_storage_buffers: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_sampled_images: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_storage_images: Decode::decode(r, ctx)?,
+            max_per_stage_descriptor_input_attachments: Decode::decode(r, ctx)?,
+            max_per_stage_resources: Decode::decode(r, ctx)?,
+            max_descriptor_set_samplers: Decode::decode(r, ctx)?,
+            max_descriptor_set_uniform_buffers: Decode::decode(r, ctx)?,
+            max_descriptor_set_uniform_buffers_dynamic: Decode::decode(r, ctx)?,
+            max_descriptor_set_storage_buffers: Decode::decode(r, ctx)?,
+            max_descriptor_set_storage_buffers_dynamic: Decode::decode(r, ctx)?,
+            max_descriptor_set_sampled_images: Decode::decode(r, ctx)?,
+            max_descriptor_set_storage_images: Decode::decode(r, ctx)?,
+            max_descriptor_set_input_attachments: Decode::decode(r, ctx)?,
+            max_vertex_input_attributes: Decode::decode(r, ctx)?,
+            max_vertex_input_bindings: Decode::decode(r, ctx)?,
+            max_vertex_input_attribute_offset: Decode::decode(r, ctx)?,
+            max_vertex_input_binding_stride: Decode::decode(r, ctx)?,
+            max_vertex_output_components: Decode::decode(r, ctx)?,
+            max_tessellation_generation_level: Decode::decode(r, ctx)?,
+            max_tessellation_patch_size: Decode::decode(r, ctx)?,
+            max_tessellation_control_per_vertex_input_components: Decode::decode(r, ctx)?,
+            max_tessellation_control_per_vertex_output_components: Decode::decode(r, ctx)?,
+            max_tessellation_control_per_patch_output_components: Decode::decode(r, ctx)?,
+            max_tessellation_control_total_output_components: Decode::decode(r, ctx)?,
+            max_tessellation_evaluation_input_components: Decode::decode(r, ctx)?,
+            max_tessellation_evaluation_output_components: Decode::decode(r, ctx)?,
+            max_geometry_shader_invocations: Decode::decode(r, ctx)?,
+            max_geometry_input_components: Decode::decode(r, ctx)?,
+            max_geometry_output_components: Decode::decode(r, ctx)?,
+            max_geometry_output_vertices: Decode::decode(r, ctx)?,
+            max_geometry_total_output_components: Decode::decode(r, ctx)?,
+            max_fragment_input_components: Decode::decode(r, ctx)?,
+            max_fragment_output_attachments: Decode::decode(r, ctx)?,
+            max_fragment_dual_src_attachments: Decode::decode(r, ctx)?,
+            max_fragment_combined_output_resources: Decode::decode(r, ctx)?,
+            max_compute_shared_memory_size: Decode::decode(r, ctx)?,
+            max_compute_work_group_count: Decode::decode(r, ctx)?,
+            max_compute_work_group_invocations: Decode::decode(r, ctx)?,
+            max_compute_work_group_size: Decode::decode(r, ctx)?,
+            sub_pixel_precision_bits: Decode::decode(r, ctx)?,
+            sub_texel_precision_bits: Decode::decode(r, ctx)?,
+            mipmap_precision_bits: Decode::decode(r, ctx)?,
+            max_draw_indexed_index_value: Decode::decode(r, ctx)?,
+            max_draw_indirect_count: Decode::decode(r, ctx)?,
+            max_sampler_lod_bias: Decode::decode(r, ctx)?,
+            max_sampler_anisotropy: Decode::decode(r, ctx)?,
+            max_viewports: Decode::decode(r, ctx)?,
+            max_viewport_dimensions: Decode::decode(r, ctx)?,
+            viewport_bounds_range: Decode::decode(r, ctx)?,
+            viewport_sub_pixel_bits: Decode::decode(r, ctx)?,
+            min_memory_map_alignment: Decode::decode(r, ctx)?,
+            min_texel_buffer_offset_alignment: Decode::decode(r, ctx)?,
+            min_uniform_buffer_offset_alignment: Decode::decode(r, ctx)?,
+            min_storage_buffer_offset_alignment: Decode::decode(r, ctx)?,
+            min_texel_offset: Decode::decode(r, ctx)?,
+            max_texel_offset: Decode::decode(r, ctx)?,
+            min_texel_gather_offset: Decode::decode(r, ctx)?,
+            max_texel_gather_offset: Decode::decode(r, ctx)?,
+            min_interpolation_offset: Decode::decode(r, ctx)?,
+            max_interpolation_offset: Decode::decode(r, ctx)?,
+            sub_pixel_interpolation_offset_bits: Decode::decode(r, ctx)?,
+            max_framebuffer_width: Decode::decode(r, ctx)?,
+            max_framebuffer_height: Decode::decode(r, ctx)?,
+            max_framebuffer_layers: Decode::decode(r, ctx)?,
+            framebuffer_color_sample_counts: Decode::decode(r, ctx)?,
+            framebuffer_depth_sample_counts: Decode::decode(r, ctx)?,
+            framebuffer_stencil_sample_counts: Decode::decode(r, ctx)?,
+            framebuffer_no_attachments_sample_counts: Decode::decode(r, ctx)?,
+            max_color_attachments: Decode::decode(r, ctx)?,
+            sampled_image_color_sample_counts: Decode::decode(r, ctx)?,
+            sampled_image_integer_sample_counts: Decode::decode(r, ctx)?,
+            sampled_image_depth_sample_counts: Decode::decode(r, ctx)?,
+            sampled_image_stencil_sample_counts: Decode::decode(r, ctx)?,
+            storage_image_sample_counts: Decode::decode(r, ctx)?,
+            max_sample_mask_words: Decode::decode(r, ctx)?,
+            timestamp_compute_and_graphics: Decode::decode(r, ctx)?,
+            timestamp_period: Decode::decode(r, ctx)?,
+            max_clip_distances: Decode::decode(r, ctx)?,
+            max_cull_distances: Decode::decode(r, ctx)?,
+            max_combined_clip_and_cull_distances: Decode::decode(r, ctx)?,
+            discrete_queue_priorities: Decode::decode(r, ctx)?,
+            point_size_range: Decode::decode(r, ctx)?,
+            line_width_range: Decode::decode(r, ctx)?,
+            point_size_granularity: Decode::decode(r, ctx)?,
+            line_width_granularity: Decode::decode(r, ctx)?,
+            strict_lines: Decode::decode(r, ctx)?,
+            standard_sample_locations: Decode::decode(r, ctx)?,
+            optimal_buffer_copy_offset_alignment: Decode::decode(r, ctx)?,
+            optimal_buffer_copy_row_pitch_alignment: Decode::decode(r, ctx)?,
+            non_coherent_atom_size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceMaintenance3Properties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_per_set_descriptors.encode(w);
+        self.max_memory_allocation_size.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceMaintenance3Properties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceMaintenance3Properties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_per_set_descriptors: Decode::decode(r, ctx)?,
+            max_memory_allocation_size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceMemoryProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.memory_type_count.encode(w);
+        self.memory_types.encode(w);
+        self.memory_heap_count.encode(w);
+        self.memory_heaps.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceMemoryProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceMemoryProperties {
+            memory_type_count: Decode::decode(r, ctx)?,
+            memory_types: Decode::decode(r, ctx)?,
+            memory_heap_count: Decode::decode(r, ctx)?,
+            memory_heaps: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceMemoryProperties2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory_properties.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceMemoryProperties2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceMemoryProperties2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceMeshShaderFeaturesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.task_shader.encode(w);
+        self.mesh_shader.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceMeshShaderFeaturesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceMeshShaderFeaturesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            task_shader: Decode::decode(r, ctx)?,
+            mesh_shader: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceMeshShaderPropertiesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_draw_mesh_tasks_count.encode(w);
+        self.max_task_work_group_invocations.encode(w);
+        self.max_task_work_group_size.encode(w);
+        self.max_task_total_memory_size.encode(w);
+        self.max_task_output_count.encode(w);
+        self.max_mesh_work_group_invocations.encode(w);
+        self.max_mesh_work_group_size.encode(w);
+        self.max_mesh_total_memory_size.encode(w);
+        self.max_mesh_output_vertices.encode(w);
+        self.max_mesh_output_primitives.encode(w);
+        self.max_mesh_multiview_view_count.encode(w);
+        self.mesh_output_per_vertex_granularity.encode(w);
+        self.mesh_output_per_primitive_granularity.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceMeshShaderPropertiesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceMeshShaderPropertiesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_draw_mesh_tasks_count: Decode::decode(r, ctx)?,
+            max_task_work_group_invocations: Decode::decode(r, ctx)?,
+            max_task_work_group_size: Decode::decode(r, ctx)?,
+            max_task_total_memory_size: Decode::decode(r, ctx)?,
+            max_task_output_count: Decode::decode(r, ctx)?,
+            max_mesh_work_group_invocations: Decode::decode(r, ctx)?,
+            max_mesh_work_group_size: Decode::decode(r, ctx)?,
+            max_mesh_total_memory_size: Decode::decode(r, ctx)?,
+            max_mesh_output_vertices: Decode::decode(r, ctx)?,
+            max_mesh_output_primitives: Decode::decode(r, ctx)?,
+            max_mesh_multiview_view_count: Decode::decode(r, ctx)?,
+            mesh_output_per_vertex_granularity: Decode::decode(r, ctx)?,
+            mesh_output_per_primitive_granularity: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceMultiviewFeatures {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.multiview.encode(w);
+        self.multiview_geometry_shader.encode(w);
+        self.multiview_tessellation_shader.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceMultiviewFeatures {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceMultiviewFeatures {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            multiview: Decode::decode(r, ctx)?,
+            multiview_geometry_shader: Decode::decode(r, ctx)?,
+            multiview_tessellation_shader: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.per_view_position_all_components.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            per_view_position_all_components: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceMultiviewProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_multiview_view_count.encode(w);
+        self.max_multiview_instance_index.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceMultiviewProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceMultiviewProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_multiview_view_count: Decode::decode(r, ctx)?,
+            max_multiview_instance_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDevicePCIBusInfoPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.pci_domain.encode(w);
+        self.pci_bus.encode(w);
+        self.pci_device.encode(w);
+        self.pci_function.encode(w);
+    }
+}
+impl Decode for PhysicalDevicePCIBusInfoPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDevicePCIBusInfoPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            pci_domain: Decode::decode(r, ctx)?,
+            pci_bus: Decode::decode(r, ctx)?,
+            pci_device: Decode::decode(r, ctx)?,
+            pci_function: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDevicePointClippingProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.point_clipping_behavior.encode(w);
+    }
+}
+impl Decode for PhysicalDevicePointClippingProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDevicePointClippingProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            point_clipping_behavior: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.api_version.encode(w);
+        self.driver_version.encode(w);
+        self.vendor_id.encode(w);
+        self.device_id.encode(w);
+        self.device_type.encode(w);
+        self.device_name.encode(w);
+        self.pipeline_cache_uuid.encode(w);
+        self.limits.encode(w);
+        self.sparse_properties.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceProperties {
+            api_version: Decode::decode(r, ctx)?,
+            driver_version: Decode::decode(r, ctx)?,
+            vendor_id: Decode::decode(r, ctx)?,
+            device_id: Decode::decode(r, ctx)?,
+            device_type: Decode::decode(r, ctx)?,
+            device_name: Decode::decode(r, ctx)?,
+            pipeline_cache_uuid: Decode::decode(r, ctx)?,
+            limits: Decode::decode(r, ctx)?,
+            sparse_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceProperties2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.properties.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceProperties2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceProperties2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceProtectedMemoryFeatures {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.protected_memory.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceProtectedMemoryFeatures {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceProtectedMemoryFeatures {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            protected_memory: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceProtectedMemoryProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.protected_no_fault.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceProtectedMemoryProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceProtectedMemoryProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            protected_no_fault: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDevicePushDescriptorPropertiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_push_descriptors.encode(w);
+    }
+}
+impl Decode for PhysicalDevicePushDescriptorPropertiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDevicePushDescriptorPropertiesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_push_descriptors: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceRayTracingPropertiesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shader_group_handle_size.encode(w);
+        self.max_recursion_depth.encode(w);
+        self.max_shader_group_stride.encode(w);
+        self.shader_group_base_alignment.encode(w);
+        self.max_geometry_count.encode(w);
+        self.max_instance_count.encode(w);
+        self.max_triangle_count.encode(w);
+        self.max_descriptor_set_acceleration_structures.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceRayTracingPropertiesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceRayTracingPropertiesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shader_group_handle_size: Decode::decode(r, ctx)?,
+            max_recursion_depth: Decode::decode(r, ctx)?,
+            max_shader_group_stride: Decode::decode(r, ctx)?,
+            shader_group_base_alignment: Decode::decode(r, ctx)?,
+            max_geometry_count: Decode::decode(r, ctx)?,
+            max_instance_count: Decode::decode(r, ctx)?,
+            max_triangle_count: Decode::decode(r, ctx)?,
+            max_descriptor_set_acceleration_structures: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceRepresentativeFragmentTestFeaturesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.representative_fragment_test.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceRepresentativeFragmentTestFeaturesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceRepresentativeFragmentTestFeaturesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            representative_fragment_test: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceSampleLocationsPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.sample_location_sample_counts.encode(w);
+        self.max_sample_location_grid_size.encode(w);
+        self.sample_location_coordinate_range.encode(w);
+        self.sample_location_sub_pixel_bits.encode(w);
+        self.variable_sample_locations.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceSampleLocationsPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceSampleLocationsPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            sample_location_sample_counts: Decode::decode(r, ctx)?,
+            max_sample_location_grid_size: Decode::decode(r, ctx)?,
+            sample_location_coordinate_range: Decode::decode(r, ctx)?,
+            sample_location_sub_pixel_bits: Decode::decode(r, ctx)?,
+            variable_sample_locations: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceSamplerFilterMinmaxPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.filter_minmax_single_component_formats.encode(w);
+        self.filter_minmax_image_component_mapping.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceSamplerFilterMinmaxPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceSamplerFilterMinmaxPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            filter_minmax_single_component_formats: Decode::decode(r, ctx)?,
+            filter_minmax_image_component_mapping: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceSamplerYcbcrConversionFeatures {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.sampler_ycbcr_conversion.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceSamplerYcbcrConversionFeatures {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceSamplerYcbcrConversionFeatures {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            sampler_ycbcr_conversion: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceShaderAtomicInt64FeaturesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shader_buffer_int64_atomics.encode(w);
+        self.shader_shared_int64_atomics.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceShaderAtomicInt64FeaturesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceShaderAtomicInt64FeaturesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shader_buffer_int64_atomics: Decode::decode(r, ctx)?,
+            shader_shared_int64_atomics: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceShaderCorePropertiesAMD {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shader_engine_count.encode(w);
+        self.shader_arrays_per_engine_count.encode(w);
+        self.compute_units_per_shader_array.encode(w);
+        self.simd_per_compute_unit.encode(w);
+        self.wavefronts_per_simd.encode(w);
+        self.wavefront_size.encode(w);
+        self.sgprs_per_simd.encode(w);
+        self.min_sgpr_allocation.encode(w);
+        self.max_sgpr_allocation.encode(w);
+        self.sgpr_allocation_granularity.encode(w);
+        self.vgprs_per_simd.encode(w);
+        self.min_vgpr_allocation.encode(w);
+        self.max_vgpr_allocation.encode(w);
+        self.vgpr_allocation_granularity.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceShaderCorePropertiesAMD {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceShaderCorePropertiesAMD {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shader_engine_count: Decode::decode(r, ctx)?,
+            shader_arrays_per_engine_count: Decode::decode(r, ctx)?,
+            compute_units_per_shader_array: Decode::decode(r, ctx)?,
+            simd_per_compute_unit: Decode::decode(r, ctx)?,
+            wavefronts_per_simd: Decode::decode(r, ctx)?,
+            wavefront_size: Decode::decode(r, ctx)?,
+            sgprs_per_simd: Decode::decode(r, ctx)?,
+            min_sgpr_allocation: Decode::decode(r, ctx)?,
+            max_sgpr_allocation: Decode::decode(r, ctx)?,
+            sgpr_allocation_granularity: Decode::decode(r, ctx)?,
+            vgprs_per_simd: Decode::decode(r, ctx)?,
+            min_vgpr_allocation: Decode::decode(r, ctx)?,
+            max_vgpr_allocation: Decode::decode(r, ctx)?,
+            vgpr_allocation_granularity: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceShaderDrawParameterFeatures {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shader_draw_parameters.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceShaderDrawParameterFeatures {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceShaderDrawParameterFeatures {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shader_draw_parameters: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceShaderImageFootprintFeaturesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.image_footprint.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceShaderImageFootprintFeaturesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceShaderImageFootprintFeaturesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            image_footprint: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceShadingRateImageFeaturesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shading_rate_image.encode(w);
+        self.shading_rate_coarse_sample_order.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceShadingRateImageFeaturesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceShadingRateImageFeaturesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shading_rate_image: Decode::decode(r, ctx)?,
+            shading_rate_coarse_sample_order: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceShadingRateImagePropertiesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shading_rate_texel_size.encode(w);
+        self.shading_rate_palette_size.encode(w);
+        self.shading_rate_max_coarse_samples.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceShadingRateImagePropertiesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceShadingRateImagePropertiesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shading_rate_texel_size: Decode::decode(r, ctx)?,
+            shading_rate_palette_size: Decode::decode(r, ctx)?,
+            shading_rate_max_coarse_samples: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceSparseImageFormatInfo2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.format.encode(w);
+        self.ty.encode(w);
+        self.samples.encode(w);
+        self.usage.encode(w);
+        self.tiling.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceSparseImageFormatInfo2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceSparseImageFormatInfo2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            format: Decode::decode(r, ctx)?,
+            ty: Decode::decode(r, ctx)?,
+            samples: Decode::decode(r, ctx)?,
+            usage: Decode::decode(r, ctx)?,
+            tiling: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceSparseProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.residency_standard2_d_block_shape.encode(w);
+        self.residency_standard2_d_multisample_block_shape.encode(w);
+        self.residency_standard3_d_block_shape.encode(w);
+        self.residency_aligned_mip_size.encode(w);
+        self.residency_non_resident_strict.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceSparseProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceSparseProperties {
+            residency_standard2_d_block_shape: Decode::decode(r, ctx)?,
+            residency_standard2_d_multisample_block_shape: Decode::decode(r, ctx)?,
+            residency_standard3_d_block_shape: Decode::decode(r, ctx)?,
+            residency_aligned_mip_size: Decode::decode(r, ctx)?,
+            residency_non_resident_strict: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceSubgroupProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.subgroup_size.encode(w);
+        self.supported_stages.encode(w);
+        self.supported_operations.encode(w);
+        self.quad_operations_in_all_stages.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceSubgroupProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceSubgroupProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            subgroup_size: Decode::decode(r, ctx)?,
+            supported_stages: Decode::decode(r, ctx)?,
+            supported_operations: Decode::decode(r, ctx)?,
+            quad_operations_in_all_stages: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceSurfaceInfo2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.surface.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceSurfaceInfo2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceSurfaceInfo2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            surface: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceTransformFeedbackFeaturesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.transform_feedback.encode(w);
+        self.geometry_streams.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceTransformFeedbackFeaturesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceTransformFeedbackFeaturesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            transform_feedback: Decode::decode(r, ctx)?,
+            geometry_streams: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceTransformFeedbackPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_transform_feedback_streams.encode(w);
+        self.max_transform_feedback_buffers.encode(w);
+        self.max_transform_feedback_buffer_size.encode(w);
+        self.max_transform_feedback_stream_data_size.encode(w);
+        self.max_transform_feedback_buffer_data_size.encode(w);
+        self.max_transform_feedback_buffer_data_stride.encode(w);
+        self.transform_feedback_queries.encode(w);
+        self.transform_feedback_streams_lines_triangles.encode(w);
+        self.transform_feedback_rasterization_stream_select
+            .encode(w);
+        self.transform_feedback_draw.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceTransformFeedbackPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceTransformFeedbackPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_transform_feedback_streams: Decode::decode(r, ctx)?,
+            max_transform_feedback_buffers: Decode::decode(r, ctx)?,
+            max_transform_feedback_buffer_size: Decode::decode(r, ctx)?,
+            max_transform_feedback_stream_data_size: Decode::decode(r, ctx)?,
+            max_transform_feedback_buffer_data_size: Decode::decode(r, ctx)?,
+            max_transform_feedback_buffer_data_stride: Decode::decode(r, ctx)?,
+            transform_feedback_queries: Decode::decode(r, ctx)?,
+            transform_feedback_streams_lines_triangles: Decode::decode(r, ctx)?,
+            transform_feedback_rasterization_stream_select: Decode::decode(r, ctx)?,
+            transform_feedback_draw: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceVariablePointerFeatures {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.variable_pointers_storage_buffer.encode(w);
+        self.variable_pointers.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceVariablePointerFeatures {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceVariablePointerFeatures {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            variable_pointers_storage_buffer: Decode::decode(r, ctx)?,
+            variable_pointers: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceVertexAttributeDivisorFeaturesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.vertex_attribute_instance_rate_divisor.encode(w);
+        self.vertex_attribute_instance_rate_zero_divisor.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceVertexAttributeDivisorFeaturesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceVertexAttributeDivisorFeaturesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            vertex_attribute_instance_rate_divisor: Decode::decode(r, ctx)?,
+            vertex_attribute_instance_rate_zero_divisor: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceVertexAttributeDivisorPropertiesEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.max_vertex_attrib_divisor.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceVertexAttributeDivisorPropertiesEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceVertexAttributeDivisorPropertiesEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            max_vertex_attrib_divisor: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PhysicalDeviceVulkanMemoryModelFeaturesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.vulkan_memory_model.encode(w);
+        self.vulkan_memory_model_device_scope.encode(w);
+    }
+}
+impl Decode for PhysicalDeviceVulkanMemoryModelFeaturesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PhysicalDeviceVulkanMemoryModelFeaturesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            vulkan_memory_model: Decode::decode(r, ctx)?,
+            vulkan_memory_model_device_scope: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineCacheCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.initial_data_size.encode(w);
+        w.bytes(
+            self.p_initial_data as *const u8,
+            self.initial_data_size as usize,
+        );
+    }
+}
+impl Decode for PipelineCacheCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineCacheCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            initial_data_size: Decode::decode(r, ctx)?,
+            p_initial_data: ctx.bytes(r)?,
+        })
+    }
+}
+impl Encode for PipelineColorBlendAdvancedStateCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.src_premultiplied.encode(w);
+        self.dst_premultiplied.encode(w);
+        self.blend_overlap.encode(w);
+    }
+}
+impl Decode for PipelineColorBlendAdvancedStateCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineColorBlendAdvancedStateCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            src_premultiplied: Decode::decode(r, ctx)?,
+            dst_premultiplied: Decode::decode(r, ctx)?,
+            blend_overlap: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineColorBlendAttachmentState {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.blend_enable.encode(w);
+        self.src_color_blend_factor.encode(w);
+        self.dst_color_blend_factor.encode(w);
+        self.color_blend_op.encode(w);
+        self.src_alpha_blend_factor.encode(w);
+        self.dst_alpha_blend_factor.encode(w);
+        self.alpha_blend_op.encode(w);
+        self.color_write_mask.encode(w);
+    }
+}
+impl Decode for PipelineColorBlendAttachmentState {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineColorBlendAttachmentState {
+            blend_enable: Decode::decode(r, ctx)?,
+            src_color_blend_factor: Decode::decode(r, ctx)?,
+            dst_color_blend_factor: Decode::decode(r, ctx)?,
+            color_blend_op: Decode::decode(r, ctx)?,
+            src_alpha_blend_factor: Decode::decode(r, ctx)?,
+            dst_alpha_blend_factor: Decode::decode(r, ctx)?,
+            alpha_blend_op: Decode::decode(r, ctx)?,
+            color_write_mask: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineColorBlendStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.logic_op_enable.encode(w);
+        self.logic_op.encode(w);
+        self.attachment_count.encode(w);
+        w.array(self.p_attachments, self.attachment_count as usize);
+        self.blend_constants.encode(w);
+    }
+}
+impl Decode for PipelineColorBlendStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineColorBlendStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            logic_op_enable: Decode::decode(r, ctx)?,
+            logic_op: Decode::decode(r, ctx)?,
+            attachment_count: Decode::decode(r, ctx)?,
+            p_attachments: ctx.array(r)?,
+            blend_constants: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineCoverageModulationStateCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.coverage_modulation_mode.encode(w);
+        self.coverage_modulation_table_enable.encode(w);
+        self.coverage_modulation_table_count.encode(w);
+        w.array(
+            self.p_coverage_modulation_table,
+            self.coverage_modulation_table_count as usize,
+        );
+    }
+}
+impl Decode for PipelineCoverageModulationStateCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineCoverageModulationStateCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            coverage_modulation_mode: Decode::decode(r, ctx)?,
+            coverage_modulation_table_enable: Decode::decode(r, ctx)?,
+            coverage_modulation_table_count: Decode::decode(r, ctx)?,
+            p_coverage_modulation_table: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineCoverageToColorStateCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.coverage_to_color_enable.encode(w);
+        self.coverage_to_color_location.encode(w);
+    }
+}
+impl Decode for PipelineCoverageToColorStateCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineCoverageToColorStateCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            coverage_to_color_enable: Decode::decode(r, ctx)?,
+            coverage_to_color_location: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineDepthStencilStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.depth_test_enable.encode(w);
+        self.depth_write_enable.encode(w);
+        self.depth_compare_op.encode(w);
+        self.depth_bounds_test_enable.encode(w);
+        self.stencil_test_enable.encode(w);
+        self.front.encode(w);
+        self.back.encode(w);
+        self.min_depth_bounds.encode(w);
+        self.max_depth_bounds.encode(w);
+    }
+}
+impl Decode for PipelineDepthStencilStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineDepthStencilStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            depth_test_enable: Decode::decode(r, ctx)?,
+            depth_write_enable: Decode::decode(r, ctx)?,
+            depth_compare_op: Decode::decode(r, ctx)?,
+            depth_bounds_test_enable: Decode::decode(r, ctx)?,
+            stencil_test_enable: Decode::decode(r, ctx)?,
+            front: Decode::decode(r, ctx)?,
+            back: Decode::decode(r, ctx)?,
+            min_depth_bounds: Decode::decode(r, ctx)?,
+            max_depth_bounds: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineDiscardRectangleStateCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.discard_rectangle_mode.encode(w);
+        self.discard_rectangle_count.encode(w);
+        w.array(
+            self.p_discard_rectangles,
+            self.discard_rectangle_count as usize,
+        );
+    }
+}
+impl Decode for PipelineDiscardRectangleStateCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineDiscardRectangleStateCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            discard_rectangle_mode: Decode::decode(r, ctx)?,
+            discard_rectangle_count: Decode::decode(r, ctx)?,
+            p_discard_rectangles: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineDynamicStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.dynamic_state_count.encode(w);
+        w.array(self.p_dynamic_states, self.dynamic_state_count as usize);
+    }
+}
+impl Decode for PipelineDynamicStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineDynamicStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            dynamic_state_count: Decode::decode(r, ctx)?,
+            p_dynamic_states: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineInputAssemblyStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.topology.encode(w);
+        self.primitive_restart_enable.encode(w);
+    }
+}
+impl Decode for PipelineInputAssemblyStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineInputAssemblyStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            topology: Decode::decode(r, ctx)?,
+            primitive_restart_enable: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineLayoutCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.set_layout_count.encode(w);
+        w.array(self.p_set_layouts, self.set_layout_count as usize);
+        self.push_constant_range_count.encode(w);
+        w.array(
+            self.p_push_constant_ranges,
+            self.push_constant_range_count as usize,
+        );
+    }
+}
+impl Decode for PipelineLayoutCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineLayoutCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            set_layout_count: Decode::decode(r, ctx)?,
+            p_set_layouts: ctx.array(r)?,
+            push_constant_range_count: Decode::decode(r, ctx)?,
+            p_push_constant_ranges: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineMultisampleStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.rasterization_samples.encode(w);
+        self.sample_shading_enable.encode(w);
+        self.min_sample_shading.encode(w);
+        w.array(
+            self.p_sample_mask,
+            (self.rasterization_samples.as_raw() as usize + 31) / 32,
+        );
+        self.alpha_to_coverage_enable.encode(w);
+        self.alpha_to_one_enable.encode(w);
+    }
+}
+impl Decode for PipelineMultisampleStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineMultisampleStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            rasterization_samples: Decode::decode(r, ctx)?,
+            sample_shading_enable: Decode::decode(r, ctx)?,
+            min_sample_shading: Decode::decode(r, ctx)?,
+            p_sample_mask: ctx.array(r)?,
+            alpha_to_coverage_enable: Decode::decode(r, ctx)?,
+            alpha_to_one_enable: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineRasterizationConservativeStateCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.conservative_rasterization_mode.encode(w);
+        self.extra_primitive_overestimation_size.encode(w);
+    }
+}
+impl Decode for PipelineRasterizationConservativeStateCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineRasterizationConservativeStateCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            conservative_rasterization_mode: Decode::decode(r, ctx)?,
+            extra_primitive_overestimation_size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineRasterizationStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.depth_clamp_enable.encode(w);
+        self.rasterizer_discard_enable.encode(w);
+        self.polygon_mode.encode(w);
+        self.cull_mode.encode(w);
+        self.front_face.encode(w);
+        self.depth_bias_enable.encode(w);
+        self.depth_bias_constant_factor.encode(w);
+        self.depth_bias_clamp.encode(w);
+        self.depth_bias_slope_factor.encode(w);
+        self.line_width.encode(w);
+    }
+}
+impl Decode for PipelineRasterizationStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineRasterizationStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            depth_clamp_enable: Decode::decode(r, ctx)?,
+            rasterizer_discard_enable: Decode::decode(r, ctx)?,
+            polygon_mode: Decode::decode(r, ctx)?,
+            cull_mode: Decode::decode(r, ctx)?,
+            front_face: Decode::decode(r, ctx)?,
+            depth_bias_enable: Decode::decode(r, ctx)?,
+            depth_bias_constant_factor: Decode::decode(r, ctx)?,
+            depth_bias_clamp: Decode::decode(r, ctx)?,
+            depth_bias_slope_factor: Decode::decode(r, ctx)?,
+            line_width: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineRasterizationStateRasterizationOrderAMD {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.rasterization_order.encode(w);
+    }
+}
+impl Decode for PipelineRasterizationStateRasterizationOrderAMD {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineRasterizationStateRasterizationOrderAMD {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            rasterization_order: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineRasterizationStateStreamCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.rasterization_stream.encode(w);
+    }
+}
+impl Decode for PipelineRasterizationStateStreamCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineRasterizationStateStreamCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            rasterization_stream: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineRepresentativeFragmentTestStateCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.representative_fragment_test_enable.encode(w);
+    }
+}
+impl Decode for PipelineRepresentativeFragmentTestStateCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineRepresentativeFragmentTestStateCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            representative_fragment_test_enable: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineSampleLocationsStateCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.sample_locations_enable.encode(w);
+        self.sample_locations_info.encode(w);
+    }
+}
+impl Decode for PipelineSampleLocationsStateCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineSampleLocationsStateCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            sample_locations_enable: Decode::decode(r, ctx)?,
+            sample_locations_info: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineShaderStageCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.stage.encode(w);
+        self.module.encode(w);
+        w.str(self.p_name);
+        w.ptr(self.p_specialization_info);
+    }
+}
+impl Decode for PipelineShaderStageCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineShaderStageCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            stage: Decode::decode(r, ctx)?,
+            module: Decode::decode(r, ctx)?,
+            p_name: ctx.str(r)?,
+            p_specialization_info: ctx.ptr(r)?,
+        })
+    }
+}
+impl Encode for PipelineTessellationDomainOriginStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.domain_origin.encode(w);
+    }
+}
+impl Decode for PipelineTessellationDomainOriginStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineTessellationDomainOriginStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            domain_origin: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineTessellationStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.patch_control_points.encode(w);
+    }
+}
+impl Decode for PipelineTessellationStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineTessellationStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            patch_control_points: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PipelineVertexInputDivisorStateCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.vertex_binding_divisor_count.encode(w);
+        w.array(
+            self.p_vertex_binding_divisors,
+            self.vertex_binding_divisor_count as usize,
+        );
+    }
+}
+impl Decode for PipelineVertexInputDivisorStateCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineVertexInputDivisorStateCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            vertex_binding_divisor_count: Decode::decode(r, ctx)?,
+            p_vertex_binding_divisors: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineVertexInputStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.vertex_binding_description_count.encode(w);
+        w.array(
+            self.p_vertex_binding_descriptions,
+            self.vertex_binding_description_count as usize,
+        );
+        self.vertex_attribute_description_count.encode(w);
+        w.array(
+            self.p_vertex_attribute_descriptions,
+            self.vertex_attribute_description_count as usize,
+        );
+    }
+}
+impl Decode for PipelineVertexInputStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineVertexInputStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            vertex_binding_description_count: Decode::decode(r, ctx)?,
+            p_vertex_binding_descriptions: ctx.array(r)?,
+            vertex_attribute_description_count: Decode::decode(r, ctx)?,
+            p_vertex_attribute_descriptions: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineViewportCoarseSampleOrderStateCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.sample_order_type.encode(w);
+        self.custom_sample_order_count.encode(w);
+        w.array(
+            self.p_custom_sample_orders,
+            self.custom_sample_order_count as usize,
+        );
+    }
+}
+impl Decode for PipelineViewportCoarseSampleOrderStateCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineViewportCoarseSampleOrderStateCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            sample_order_type: Decode::decode(r, ctx)?,
+            custom_sample_order_count: Decode::decode(r, ctx)?,
+            p_custom_sample_orders: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineViewportExclusiveScissorStateCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.exclusive_scissor_count.encode(w);
+        w.array(
+            self.p_exclusive_scissors,
+            self.exclusive_scissor_count as usize,
+        );
+    }
+}
+impl Decode for PipelineViewportExclusiveScissorStateCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineViewportExclusiveScissorStateCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            exclusive_scissor_count: Decode::decode(r, ctx)?,
+            p_exclusive_scissors: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineViewportShadingRateImageStateCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shading_rate_image_enable.encode(w);
+        self.viewport_count.encode(w);
+        w.array(self.p_shading_rate_palettes, self.viewport_count as usize);
+    }
+}
+impl Decode for PipelineViewportShadingRateImageStateCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineViewportShadingRateImageStateCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shading_rate_image_enable: Decode::decode(r, ctx)?,
+            viewport_count: Decode::decode(r, ctx)?,
+            p_shading_rate_palettes: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineViewportStateCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.viewport_count.encode(w);
+        w.array(self.p_viewports, self.viewport_count as usize);
+        self.scissor_count.encode(w);
+        w.array(self.p_scissors, self.scissor_count as usize);
+    }
+}
+impl Decode for PipelineViewportStateCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineViewportStateCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            viewport_count: Decode::decode(r, ctx)?,
+            p_viewports: ctx.array(r)?,
+            scissor_count: Decode::decode(r, ctx)?,
+            p_scissors: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineViewportSwizzleStateCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.viewport_count.encode(w);
+        w.array(self.p_viewport_swizzles, self.viewport_count as usize);
+    }
+}
+impl Decode for PipelineViewportSwizzleStateCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineViewportSwizzleStateCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            viewport_count: Decode::decode(r, ctx)?,
+            p_viewport_swizzles: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PipelineViewportWScalingStateCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.viewport_w_scaling_enable.encode(w);
+        self.viewport_count.encode(w);
+        w.array(self.p_viewport_w_scalings, self.viewport_count as usize);
+    }
+}
+impl Decode for PipelineViewportWScalingStateCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PipelineViewportWScalingStateCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            viewport_w_scaling_enable: Decode::decode(r, ctx)?,
+            viewport_count: Decode::decode(r, ctx)?,
+            p_viewport_w_scalings: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PresentInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.wait_semaphore_count.encode(w);
+        w.array(self.p_wait_semaphores, self.wait_semaphore_count as usize);
+        self.swapchain_count.encode(w);
+        w.array(self.p_swapchains, self.swapchain_count as usize);
+        w.array(self.p_image_indices, self.swapchain_count as usize);
+        w.array(self.p_results, self.swapchain_count as usize);
+    }
+}
+impl Decode for PresentInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PresentInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            wait_semaphore_count: Decode::decode(r, ctx)?,
+            p_wait_semaphores: ctx.array(r)?,
+            swapchain_count: Decode::decode(r, ctx)?,
+            p_swapchains: ctx.array(r)?,
+            p_image_indices: ctx.array(r)?,
+            p_results: ctx.array::<Result>(r)? as *mut _,
+        })
+    }
+}
+impl Encode for PresentRegionKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.rectangle_count.encode(w);
+        w.array(self.p_rectangles, self.rectangle_count as usize);
+    }
+}
+impl Decode for PresentRegionKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PresentRegionKHR {
+            rectangle_count: Decode::decode(r, ctx)?,
+            p_rectangles: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PresentRegionsKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.swapchain_count.encode(w);
+        w.array(self.p_regions, self.swapchain_count as usize);
+    }
+}
+impl Decode for PresentRegionsKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PresentRegionsKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            swapchain_count: Decode::decode(r, ctx)?,
+            p_regions: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for PresentTimeGOOGLE {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.present_id.encode(w);
+        self.desired_present_time.encode(w);
+    }
+}
+impl Decode for PresentTimeGOOGLE {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PresentTimeGOOGLE {
+            present_id: Decode::decode(r, ctx)?,
+            desired_present_time: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PresentTimesInfoGOOGLE {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.swapchain_count.encode(w);
+        w.array(self.p_times, self.swapchain_count as usize);
+    }
+}
+impl Decode for PresentTimesInfoGOOGLE {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PresentTimesInfoGOOGLE {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            swapchain_count: Decode::decode(r, ctx)?,
+            p_times: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for ProtectedSubmitInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.protected_submit.encode(w);
+    }
+}
+impl Decode for ProtectedSubmitInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ProtectedSubmitInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            protected_submit: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for PushConstantRange {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.stage_flags.encode(w);
+        self.offset.encode(w);
+        self.size.encode(w);
+    }
+}
+impl Decode for PushConstantRange {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(PushConstantRange {
+            stage_flags: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for QueryPoolCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.query_type.encode(w);
+        self.query_count.encode(w);
+        self.pipeline_statistics.encode(w);
+    }
+}
+impl Decode for QueryPoolCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(QueryPoolCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            query_type: Decode::decode(r, ctx)?,
+            query_count: Decode::decode(r, ctx)?,
+            pipeline_statistics: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for QueueFamilyCheckpointPropertiesNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.checkpoint_execution_stage_mask.encode(w);
+    }
+}
+impl Decode for QueueFamilyCheckpointPropertiesNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(QueueFamilyCheckpointPropertiesNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            checkpoint_execution_stage_mask: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for QueueFamilyProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.queue_flags.encode(w);
+        self.queue_count.encode(w);
+        self.timestamp_valid_bits.encode(w);
+        self.min_image_transfer_granularity.encode(w);
+    }
+}
+impl Decode for QueueFamilyProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(QueueFamilyProperties {
+            queue_flags: Decode::decode(r, ctx)?,
+            queue_count: Decode::decode(r, ctx)?,
+            timestamp_valid_bits: Decode::decode(r, ctx)?,
+            min_image_transfer_granularity: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for QueueFamilyProperties2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.queue_family_properties.encode(w);
+    }
+}
+impl Decode for QueueFamilyProperties2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(QueueFamilyProperties2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            queue_family_properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for RayTracingPipelineCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.stage_count.encode(w);
+        w.array(self.p_stages, self.stage_count as usize);
+        self.group_count.encode(w);
+        w.array(self.p_groups, self.group_count as usize);
+        self.max_recursion_depth.encode(w);
+        self.layout.encode(w);
+        self.base_pipeline_handle.encode(w);
+        self.base_pipeline_index.encode(w);
+    }
+}
+impl Decode for RayTracingPipelineCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RayTracingPipelineCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            stage_count: Decode::decode(r, ctx)?,
+            p_stages: ctx.array(r)?,
+            group_count: Decode::decode(r, ctx)?,
+            p_groups: ctx.array(r)?,
+            max_recursion_depth: Decode::decode(r, ctx)?,
+            layout: Decode::decode(r, ctx)?,
+            base_pipeline_handle: Decode::decode(r, ctx)?,
+            base_pipeline_index: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for RayTracingShaderGroupCreateInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.ty.encode(w);
+        self.general_shader.encode(w);
+        self.closest_hit_shader.encode(w);
+        self.any_hit_shader.encode(w);
+        self.intersection_shader.encode(w);
+    }
+}
+impl Decode for RayTracingShaderGroupCreateInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RayTracingShaderGroupCreateInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            ty: Decode::decode(r, ctx)?,
+            general_shader: Decode::decode(r, ctx)?,
+            closest_hit_shader: Decode::decode(r, ctx)?,
+            any_hit_shader: Decode::decode(r, ctx)?,
+            intersection_shader: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for Rect2D {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.offset.encode(w);
+        self.extent.encode(w);
+    }
+}
+impl Decode for Rect2D {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Rect2D {
+            offset: Decode::decode(r, ctx)?,
+            extent: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for RectLayerKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.offset.encode(w);
+        self.extent.encode(w);
+        self.layer.encode(w);
+    }
+}
+impl Decode for RectLayerKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RectLayerKHR {
+            offset: Decode::decode(r, ctx)?,
+            extent: Decode::decode(r, ctx)?,
+            layer: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for RefreshCycleDurationGOOGLE {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.refresh_duration.encode(w);
+    }
+}
+impl Decode for RefreshCycleDurationGOOGLE {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RefreshCycleDurationGOOGLE {
+            refresh_duration: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for RenderPassBeginInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.render_pass.encode(w);
+        self.framebuffer.encode(w);
+        self.render_area.encode(w);
+        self.clear_value_count.encode(w);
+        w.array(self.p_clear_values, self.clear_value_count as usize);
+    }
+}
+impl Decode for RenderPassBeginInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RenderPassBeginInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            render_pass: Decode::decode(r, ctx)?,
+            framebuffer: Decode::decode(r, ctx)?,
+            render_area: Decode::decode(r, ctx)?,
+            clear_value_count: Decode::decode(r, ctx)?,
+            p_clear_values: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for RenderPassCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.attachment_count.encode(w);
+        w.array(self.p_attachments, self.attachment_count as usize);
+        self.subpass_count.encode(w);
+        w.array(self.p_subpasses, self.subpass_count as usize);
+        self.dependency_count.encode(w);
+        w.array(self.p_dependencies, self.dependency_count as usize);
+    }
+}
+impl Decode for RenderPassCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RenderPassCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            attachment_count: Decode::decode(r, ctx)?,
+            p_attachments: ctx.array(r)?,
+            subpass_count: Decode::decode(r, ctx)?,
+            p_subpasses: ctx.array(r)?,
+            dependency_count: Decode::decode(r, ctx)?,
+            p_dependencies: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for RenderPassCreateInfo2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.attachment_count.encode(w);
+        w.array(self.p_attachments, self.attachment_count as usize);
+        self.subpass_count.encode(w);
+        w.array(self.p_subpasses, self.subpass_count as usize);
+        self.dependency_count.encode(w);
+        w.array(self.p_dependencies, self.dependency_count as usize);
+        self.correlated_view_mask_count.encode(w);
+        w.array(
+            self.p_correlated_view_masks,
+            self.correlated_view_mask_count as usize,
+        );
+    }
+}
+impl Decode for RenderPassCreateInfo2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RenderPassCreateInfo2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            attachment_count: Decode::decode(r, ctx)?,
+            p_attachments: ctx.array(r)?,
+            subpass_count: Decode::decode(r, ctx)?,
+            p_subpasses: ctx.array(r)?,
+            dependency_count: Decode::decode(r, ctx)?,
+            p_dependencies: ctx.array(r)?,
+            correlated_view_mask_count: Decode::decode(r, ctx)?,
+            p_correlated_view_masks: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for RenderPassInputAttachmentAspectCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.aspect_reference_count.encode(w);
+        w.array(
+            self.p_aspect_references,
+            self.aspect_reference_count as usize,
+        );
+    }
+}
+impl Decode for RenderPassInputAttachmentAspectCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RenderPassInputAttachmentAspectCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            aspect_reference_count: Decode::decode(r, ctx)?,
+            p_aspect_references: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for RenderPassMultiviewCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.subpass_count.encode(w);
+        w.array(self.p_view_masks, self.subpass_count as usize);
+        self.dependency_count.encode(w);
+        w.array(self.p_view_offsets, self.dependency_count as usize);
+        self.correlation_mask_count.encode(w);
+        w.array(
+            self.p_correlation_masks,
+            self.correlation_mask_count as usize,
+        );
+    }
+}
+impl Decode for RenderPassMultiviewCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RenderPassMultiviewCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            subpass_count: Decode::decode(r, ctx)?,
+            p_view_masks: ctx.array(r)?,
+            dependency_count: Decode::decode(r, ctx)?,
+            p_view_offsets: ctx.array(r)?,
+            correlation_mask_count: Decode::decode(r, ctx)?,
+            p_correlation_masks: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for RenderPassSampleLocationsBeginInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.attachment_initial_sample_locations_count.encode(w);
+        w.array(
+            self.p_attachment_initial_sample_locations,
+            self.attachment_initial_sample_locations_count as usize,
+        );
+        self.post_subpass_sample_locations_count.encode(w);
+        w.array(
+            self.p_post_subpass_sample_locations,
+            self.post_subpass_sample_locations_count as usize,
+        );
+    }
+}
+impl Decode for RenderPassSampleLocationsBeginInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(RenderPassSampleLocationsBeginInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            attachment_initial_sample_locations_count: Decode::decode(r, ctx)?,
+            p_attachment_initial_sample_locations: ctx.array(r)?,
+            post_subpass_sample_locations_count: Decode::decode(r, ctx)?,
+            p_post_subpass_sample_locations: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SampleLocationEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.x.encode(w);
+        self.y.encode(w);
+    }
+}
+impl Decode for SampleLocationEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SampleLocationEXT {
+            x: Decode::decode(r, ctx)?,
+            y: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SampleLocationsInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.sample_locations_per_pixel.encode(w);
+        self.sample_location_grid_size.encode(w);
+        self.sample_locations_count.encode(w);
+        w.array(
+            self.p_sample_locations,
+            self.sample_locations_count as usize,
+        );
+    }
+}
+impl Decode for SampleLocationsInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SampleLocationsInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            sample_locations_per_pixel: Decode::decode(r, ctx)?,
+            sample_location_grid_size: Decode::decode(r, ctx)?,
+            sample_locations_count: Decode::decode(r, ctx)?,
+            p_sample_locations: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SamplerCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.mag_filter.encode(w);
+        self.min_filter.encode(w);
+        self.mipmap_mode.encode(w);
+        self.address_mode_u.encode(w);
+        self.address_mode_v.encode(w);
+        self.address_mode_w.encode(w);
+        self.mip_lod_bias.encode(w);
+        self.anisotropy_enable.encode(w);
+        self.max_anisotropy.encode(w);
+        self.compare_enable.encode(w);
+        self.compare_op.encode(w);
+        self.min_lod.encode(w);
+        self.max_lod.encode(w);
+        self.border_color.encode(w);
+        self.unnormalized_coordinates.encode(w);
+    }
+}
+impl Decode for SamplerCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SamplerCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            mag_filter: Decode::decode(r, ctx)?,
+            min_filter: Decode::decode(r, ctx)?,
+            mipmap_mode: Decode::decode(r, ctx)?,
+            address_mode_u: Decode::decode(r, ctx)?,
+            address_mode_v: Decode::decode(r, ctx)?,
+            address_mode_w: Decode::decode(r, ctx)?,
+            mip_lod_bias: Decode::decode(r, ctx)?,
+            anisotropy_enable: Decode::decode(r, ctx)?,
+            max_anisotropy: Decode::decode(r, ctx)?,
+            compare_enable: Decode::decode(r, ctx)?,
+            compare_op: Decode::decode(r, ctx)?,
+            min_lod: Decode::decode(r, ctx)?,
+            max_lod: Decode::decode(r, ctx)?,
+            border_color: Decode::decode(r, ctx)?,
+            unnormalized_coordinates: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SamplerReductionModeCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.reduction_mode.encode(w);
+    }
+}
+impl Decode for SamplerReductionModeCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SamplerReductionModeCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            reduction_mode: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SamplerYcbcrConversionCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.format.encode(w);
+        self.ycbcr_model.encode(w);
+        self.ycbcr_range.encode(w);
+        self.components.encode(w);
+        self.x_chroma_offset.encode(w);
+        self.y_chroma_offset.encode(w);
+        self.chroma_filter.encode(w);
+        self.force_explicit_reconstruction.encode(w);
+    }
+}
+impl Decode for SamplerYcbcrConversionCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SamplerYcbcrConversionCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            format: Decode::decode(r, ctx)?,
+            ycbcr_model: Decode::decode(r, ctx)?,
+            ycbcr_range: Decode::decode(r, ctx)?,
+            components: Decode::decode(r, ctx)?,
+            x_chroma_offset: Decode::decode(r, ctx)?,
+            y_chroma_offset: Decode::decode(r, ctx)?,
+            chroma_filter: Decode::decode(r, ctx)?,
+            force_explicit_reconstruction: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SamplerYcbcrConversionImageFormatProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.combined_image_sampler_descriptor_count.encode(w);
+    }
+}
+impl Decode for SamplerYcbcrConversionImageFormatProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SamplerYcbcrConversionImageFormatProperties {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            combined_image_sampler_descriptor_count: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SamplerYcbcrConversionInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.conversion.encode(w);
+    }
+}
+impl Decode for SamplerYcbcrConversionInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SamplerYcbcrConversionInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            conversion: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SemaphoreCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+    }
+}
+impl Decode for SemaphoreCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SemaphoreCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SemaphoreGetFdInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.semaphore.encode(w);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for SemaphoreGetFdInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SemaphoreGetFdInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            semaphore: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SemaphoreGetWin32HandleInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.semaphore.encode(w);
+        self.handle_type.encode(w);
+    }
+}
+impl Decode for SemaphoreGetWin32HandleInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SemaphoreGetWin32HandleInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            semaphore: Decode::decode(r, ctx)?,
+            handle_type: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ShaderModuleCreateInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.code_size.encode(w);
+        w.array(self.p_code, self.code_size / 4);
+    }
+}
+impl Decode for ShaderModuleCreateInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ShaderModuleCreateInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            code_size: Decode::decode(r, ctx)?,
+            p_code: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for ShaderModuleValidationCacheCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.validation_cache.encode(w);
+    }
+}
+impl Decode for ShaderModuleValidationCacheCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ShaderModuleValidationCacheCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            validation_cache: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ShaderResourceUsageAMD {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.num_used_vgprs.encode(w);
+        self.num_used_sgprs.encode(w);
+        self.lds_size_per_local_work_group.encode(w);
+        self.lds_usage_size_in_bytes.encode(w);
+        self.scratch_mem_usage_in_bytes.encode(w);
+    }
+}
+impl Decode for ShaderResourceUsageAMD {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ShaderResourceUsageAMD {
+            num_used_vgprs: Decode::decode(r, ctx)?,
+            num_used_sgprs: Decode::decode(r, ctx)?,
+            lds_size_per_local_work_group: Decode::decode(r, ctx)?,
+            lds_usage_size_in_bytes: Decode::decode(r, ctx)?,
+            scratch_mem_usage_in_bytes: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ShaderStatisticsInfoAMD {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.shader_stage_mask.encode(w);
+        self.resource_usage.encode(w);
+        self.num_physical_vgprs.encode(w);
+        self.num_physical_sgprs.encode(w);
+        self.num_available_vgprs.encode(w);
+        self.num_available_sgprs.encode(w);
+        self.compute_work_group_size.encode(w);
+    }
+}
+impl Decode for ShaderStatisticsInfoAMD {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ShaderStatisticsInfoAMD {
+            shader_stage_mask: Decode::decode(r, ctx)?,
+            resource_usage: Decode::decode(r, ctx)?,
+            num_physical_vgprs: Decode::decode(r, ctx)?,
+            num_physical_sgprs: Decode::decode(r, ctx)?,
+            num_available_vgprs: Decode::decode(r, ctx)?,
+            num_available_sgprs: Decode::decode(r, ctx)?,
+            compute_work_group_size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ShadingRatePaletteNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.shading_rate_palette_entry_count.encode(w);
+        w.array(
+            self.p_shading_rate_palette_entries,
+            self.shading_rate_palette_entry_count as usize,
+        );
+    }
+}
+impl Decode for ShadingRatePaletteNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ShadingRatePaletteNV {
+            shading_rate_palette_entry_count: Decode::decode(r, ctx)?,
+            p_shading_rate_palette_entries: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SharedPresentSurfaceCapabilitiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.shared_present_supported_usage_flags.encode(w);
+    }
+}
+impl Decode for SharedPresentSurfaceCapabilitiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SharedPresentSurfaceCapabilitiesKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            shared_present_supported_usage_flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SparseBufferMemoryBindInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.buffer.encode(w);
+        self.bind_count.encode(w);
+        w.array(self.p_binds, self.bind_count as usize);
+    }
+}
+impl Decode for SparseBufferMemoryBindInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseBufferMemoryBindInfo {
+            buffer: Decode::decode(r, ctx)?,
+            bind_count: Decode::decode(r, ctx)?,
+            p_binds: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SparseImageFormatProperties {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.aspect_mask.encode(w);
+        self.image_granularity.encode(w);
+        self.flags.encode(w);
+    }
+}
+impl Decode for SparseImageFormatProperties {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseImageFormatProperties {
+            aspect_mask: Decode::decode(r, ctx)?,
+            image_granularity: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SparseImageFormatProperties2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.properties.encode(w);
+    }
+}
+impl Decode for SparseImageFormatProperties2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseImageFormatProperties2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            properties: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SparseImageMemoryBind {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.subresource.encode(w);
+        self.offset.encode(w);
+        self.extent.encode(w);
+        self.memory.encode(w);
+        self.memory_offset.encode(w);
+        self.flags.encode(w);
+    }
+}
+impl Decode for SparseImageMemoryBind {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseImageMemoryBind {
+            subresource: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            extent: Decode::decode(r, ctx)?,
+            memory: Decode::decode(r, ctx)?,
+            memory_offset: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SparseImageMemoryBindInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.image.encode(w);
+        self.bind_count.encode(w);
+        w.array(self.p_binds, self.bind_count as usize);
+    }
+}
+impl Decode for SparseImageMemoryBindInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseImageMemoryBindInfo {
+            image: Decode::decode(r, ctx)?,
+            bind_count: Decode::decode(r, ctx)?,
+            p_binds: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SparseImageMemoryRequirements {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.format_properties.encode(w);
+        self.image_mip_tail_first_lod.encode(w);
+        self.image_mip_tail_size.encode(w);
+        self.image_mip_tail_offset.encode(w);
+        self.image_mip_tail_stride.encode(w);
+    }
+}
+impl Decode for SparseImageMemoryRequirements {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseImageMemoryRequirements {
+            format_properties: Decode::decode(r, ctx)?,
+            image_mip_tail_first_lod: Decode::decode(r, ctx)?,
+            image_mip_tail_size: Decode::decode(r, ctx)?,
+            image_mip_tail_offset: Decode::decode(r, ctx)?,
+            image_mip_tail_stride: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SparseImageMemoryRequirements2 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.memory_requirements.encode(w);
+    }
+}
+impl Decode for SparseImageMemoryRequirements2 {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseImageMemoryRequirements2 {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            memory_requirements: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SparseImageOpaqueMemoryBindInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.image.encode(w);
+        self.bind_count.encode(w);
+        w.array(self.p_binds, self.bind_count as usize);
+    }
+}
+impl Decode for SparseImageOpaqueMemoryBindInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseImageOpaqueMemoryBindInfo {
+            image: Decode::decode(r, ctx)?,
+            bind_count: Decode::decode(r, ctx)?,
+            p_binds: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SparseMemoryBind {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.resource_offset.encode(w);
+        self.size.encode(w);
+        self.memory.encode(w);
+        self.memory_offset.encode(w);
+        self.flags.encode(w);
+    }
+}
+impl Decode for SparseMemoryBind {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SparseMemoryBind {
+            resource_offset: Decode::decode(r, ctx)?,
+            size: Decode::decode(r, ctx)?,
+            memory: Decode::decode(r, ctx)?,
+            memory_offset: Decode::decode(r, ctx)?,
+            flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SpecializationInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.map_entry_count.encode(w);
+        w.array(self.p_map_entries, self.map_entry_count as usize);
+        self.data_size.encode(w);
+        w.bytes(self.p_data as *const u8, self.data_size as usize);
+    }
+}
+impl Decode for SpecializationInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SpecializationInfo {
+            map_entry_count: Decode::decode(r, ctx)?,
+            p_map_entries: ctx.array(r)?,
+            data_size: Decode::decode(r, ctx)?,
+            p_data: ctx.bytes(r)?,
+        })
+    }
+}
+impl Encode for SpecializationMapEntry {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.constant_id.encode(w);
+        self.offset.encode(w);
+        self.size.encode(w);
+    }
+}
+impl Decode for SpecializationMapEntry {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SpecializationMapEntry {
+            constant_id: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+            size: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for StencilOpState {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.fail_op.encode(w);
+        self.pass_op.encode(w);
+        self.depth_fail_op.encode(w);
+        self.compare_op.encode(w);
+        self.compare_mask.encode(w);
+        self.write_mask.encode(w);
+        self.reference.encode(w);
+    }
+}
+impl Decode for StencilOpState {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(StencilOpState {
+            fail_op: Decode::decode(r, ctx)?,
+            pass_op: Decode::decode(r, ctx)?,
+            depth_fail_op: Decode::decode(r, ctx)?,
+            compare_op: Decode::decode(r, ctx)?,
+            compare_mask: Decode::decode(r, ctx)?,
+            write_mask: Decode::decode(r, ctx)?,
+            reference: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SubmitInfo {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.wait_semaphore_count.encode(w);
+        w.array(self.p_wait_semaphores, self.wait_semaphore_count as usize);
+        w.array(
+            self.p_wait_dst_stage_mask,
+            self.wait_semaphore_count as usize,
+        );
+        self.command_buffer_count.encode(w);
+        w.array(self.p_command_buffers, self.command_buffer_count as usize);
+        self.signal_semaphore_count.encode(w);
+        w.array(
+            self.p_signal_semaphores,
+            self.signal_semaphore_count as usize,
+        );
+    }
+}
+impl Decode for SubmitInfo {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubmitInfo {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            wait_semaphore_count: Decode::decode(r, ctx)?,
+            p_wait_semaphores: ctx.array(r)?,
+            p_wait_dst_stage_mask: ctx.array(r)?,
+            command_buffer_count: Decode::decode(r, ctx)?,
+            p_command_buffers: ctx.array(r)?,
+            signal_semaphore_count: Decode::decode(r, ctx)?,
+            p_signal_semaphores: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SubpassBeginInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.contents.encode(w);
+    }
+}
+impl Decode for SubpassBeginInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubpassBeginInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            contents: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SubpassDependency {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.src_subpass.encode(w);
+        self.dst_subpass.encode(w);
+        self.src_stage_mask.encode(w);
+        self.dst_stage_mask.encode(w);
+        self.src_access_mask.encode(w);
+        self.dst_access_mask.encode(w);
+        self.dependency_flags.encode(w);
+    }
+}
+impl Decode for SubpassDependency {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubpassDependency {
+            src_subpass: Decode::decode(r, ctx)?,
+            dst_subpass: Decode::decode(r, ctx)?,
+            src_stage_mask: Decode::decode(r, ctx)?,
+            dst_stage_mask: Decode::decode(r, ctx)?,
+            src_access_mask: Decode::decode(r, ctx)?,
+            dst_access_mask: Decode::decode(r, ctx)?,
+            dependency_flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SubpassDependency2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.src_subpass.encode(w);
+        self.dst_subpass.encode(w);
+        self.src_stage_mask.encode(w);
+        self.dst_stage_mask.encode(w);
+        self.src_access_mask.encode(w);
+        self.dst_access_mask.encode(w);
+        self.dependency_flags.encode(w);
+        self.view_offset.encode(w);
+    }
+}
+impl Decode for SubpassDependency2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubpassDependency2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            src_subpass: Decode::decode(r, ctx)?,
+            dst_subpass: Decode::decode(r, ctx)?,
+            src_stage_mask: Decode::decode(r, ctx)?,
+            dst_stage_mask: Decode::decode(r, ctx)?,
+            src_access_mask: Decode::decode(r, ctx)?,
+            dst_access_mask: Decode::decode(r, ctx)?,
+            dependency_flags: Decode::decode(r, ctx)?,
+            view_offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SubpassDescription {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.flags.encode(w);
+        self.pipeline_bind_point.encode(w);
+        self.input_attachment_count.encode(w);
+        w.array(
+            self.p_input_attachments,
+            self.input_attachment_count as usize,
+        );
+        self.color_attachment_count.encode(w);
+        w.array(
+            self.p_color_attachments,
+            self.color_attachment_count as usize,
+        );
+        w.array(
+            self.p_resolve_attachments,
+            self.color_attachment_count as usize,
+        );
+        w.ptr(self.p_depth_stencil_attachment);
+        self.preserve_attachment_count.encode(w);
+        w.array(
+            self.p_preserve_attachments,
+            self.preserve_attachment_count as usize,
+        );
+    }
+}
+impl Decode for SubpassDescription {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubpassDescription {
+            flags: Decode::decode(r, ctx)?,
+            pipeline_bind_point: Decode::decode(r, ctx)?,
+            input_attachment_count: Decode::decode(r, ctx)?,
+            p_input_attachments: ctx.array(r)?,
+            color_attachment_count: Decode::decode(r, ctx)?,
+            p_color_attachments: ctx.array(r)?,
+            p_resolve_attachments: ctx.array(r)?,
+            p_depth_stencil_attachment: ctx.ptr(r)?,
+            preserve_attachment_count: Decode::decode(r, ctx)?,
+            p_preserve_attachments: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SubpassDescription2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.pipeline_bind_point.encode(w);
+        self.view_mask.encode(w);
+        self.input_attachment_count.encode(w);
+        w.array(
+            self.p_input_attachments,
+            self.input_attachment_count as usize,
+        );
+        self.color_attachment_count.encode(w);
+        w.array(
+            self.p_color_attachments,
+            self.color_attachment_count as usize,
+        );
+        w.array(
+            self.p_resolve_attachments,
+            self.color_attachment_count as usize,
+        );
+        w.ptr(self.p_depth_stencil_attachment);
+        self.preserve_attachment_count.encode(w);
+        w.array(
+            self.p_preserve_attachments,
+            self.preserve_attachment_count as usize,
+        );
+    }
+}
+impl Decode for SubpassDescription2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubpassDescription2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            pipeline_bind_point: Decode::decode(r, ctx)?,
+            view_mask: Decode::decode(r, ctx)?,
+            input_attachment_count: Decode::decode(r, ctx)?,
+            p_input_attachments: ctx.array(r)?,
+            color_attachment_count: Decode::decode(r, ctx)?,
+            p_color_attachments: ctx.array(r)?,
+            p_resolve_attachments: ctx.array(r)?,
+            p_depth_stencil_attachment: ctx.ptr(r)?,
+            preserve_attachment_count: Decode::decode(r, ctx)?,
+            p_preserve_attachments: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for SubpassEndInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+    }
+}
+impl Decode for SubpassEndInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubpassEndInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+        })
+    }
+}
+impl Encode for SubpassSampleLocationsEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.subpass_index.encode(w);
+        self.sample_locations_info.encode(w);
+    }
+}
+impl Decode for SubpassSampleLocationsEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubpassSampleLocationsEXT {
+            subpass_index: Decode::decode(r, ctx)?,
+            sample_locations_info: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SubresourceLayout {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.offset.encode(w);
+        self.size.encode(w);
+        self.row_pitch.encode(w);
+        self.array_pitch.encode(w);
+        self.depth_pitch.encode(w);
+    }
+}
+impl Decode for SubresourceLayout {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SubresourceLayout {
+            offset: Decode::decode(r, ctx)?,
+            size: Decode::decode(r, ctx)?,
+            row_pitch: Decode::decode(r, ctx)?,
+            array_pitch: Decode::decode(r, ctx)?,
+            depth_pitch: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SurfaceCapabilities2EXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.min_image_count.encode(w);
+        self.max_image_count.encode(w);
+        self.current_extent.encode(w);
+        self.min_image_extent.encode(w);
+        self.max_image_extent.encode(w);
+        self.max_image_array_layers.encode(w);
+        self.supported_transforms.encode(w);
+        self.current_transform.encode(w);
+        self.supported_composite_alpha.encode(w);
+        self.supported_usage_flags.encode(w);
+        self.supported_surface_counters.encode(w);
+    }
+}
+impl Decode for SurfaceCapabilities2EXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SurfaceCapabilities2EXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            min_image_count: Decode::decode(r, ctx)?,
+            max_image_count: Decode::decode(r, ctx)?,
+            current_extent: Decode::decode(r, ctx)?,
+            min_image_extent: Decode::decode(r, ctx)?,
+            max_image_extent: Decode::decode(r, ctx)?,
+            max_image_array_layers: Decode::decode(r, ctx)?,
+            supported_transforms: Decode::decode(r, ctx)?,
+            current_transform: Decode::decode(r, ctx)?,
+            supported_composite_alpha: Decode::decode(r, ctx)?,
+            supported_usage_flags: Decode::decode(r, ctx)?,
+            supported_surface_counters: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SurfaceCapabilities2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.surface_capabilities.encode(w);
+    }
+}
+impl Decode for SurfaceCapabilities2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SurfaceCapabilities2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            surface_capabilities: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SurfaceCapabilitiesKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.min_image_count.encode(w);
+        self.max_image_count.encode(w);
+        self.current_extent.encode(w);
+        self.min_image_extent.encode(w);
+        self.max_image_extent.encode(w);
+        self.max_image_array_layers.encode(w);
+        self.supported_transforms.encode(w);
+        self.current_transform.encode(w);
+        self.supported_composite_alpha.encode(w);
+        self.supported_usage_flags.encode(w);
+    }
+}
+impl Decode for SurfaceCapabilitiesKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SurfaceCapabilitiesKHR {
+            min_image_count: Decode::decode(r, ctx)?,
+            max_image_count: Decode::decode(r, ctx)?,
+            current_extent: Decode::decode(r, ctx)?,
+            min_image_extent: Decode::decode(r, ctx)?,
+            max_image_extent: Decode::decode(r, ctx)?,
+            max_image_array_layers: Decode::decode(r, ctx)?,
+            supported_transforms: Decode::decode(r, ctx)?,
+            current_transform: Decode::decode(r, ctx)?,
+            supported_composite_alpha: Decode::decode(r, ctx)?,
+            supported_usage_flags: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SurfaceFormat2KHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.surface_format.encode(w);
+    }
+}
+impl Decode for SurfaceFormat2KHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SurfaceFormat2KHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            surface_format: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SurfaceFormatKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.format.encode(w);
+        self.color_space.encode(w);
+    }
+}
+impl Decode for SurfaceFormatKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SurfaceFormatKHR {
+            format: Decode::decode(r, ctx)?,
+            color_space: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SwapchainCounterCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.surface_counters.encode(w);
+    }
+}
+impl Decode for SwapchainCounterCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SwapchainCounterCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            surface_counters: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for SwapchainCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.surface.encode(w);
+        self.min_image_count.encode(w);
+        self.image_format.encode(w);
+        self.image_color_space.encode(w);
+        self.image_extent.encode(w);
+        self.image_array_layers.encode(w);
+        self.image_usage.encode(w);
+        self.image_sharing_mode.encode(w);
+        self.queue_family_index_count.encode(w);
+        w.array(
+            self.p_queue_family_indices,
+            self.queue_family_index_count as usize,
+        );
+        self.pre_transform.encode(w);
+        self.composite_alpha.encode(w);
+        self.present_mode.encode(w);
+        self.clipped.encode(w);
+        self.old_swapchain.encode(w);
+    }
+}
+impl Decode for SwapchainCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(SwapchainCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            surface: Decode::decode(r, ctx)?,
+            min_image_count: Decode::decode(r, ctx)?,
+            image_format: Decode::decode(r, ctx)?,
+            image_color_space: Decode::decode(r, ctx)?,
+            image_extent: Decode::decode(r, ctx)?,
+            image_array_layers: Decode::decode(r, ctx)?,
+            image_usage: Decode::decode(r, ctx)?,
+            image_sharing_mode: Decode::decode(r, ctx)?,
+            queue_family_index_count: Decode::decode(r, ctx)?,
+            p_queue_family_indices: ctx.array(r)?,
+            pre_transform: Decode::decode(r, ctx)?,
+            composite_alpha: Decode::decode(r, ctx)?,
+            present_mode: Decode::decode(r, ctx)?,
+            clipped: Decode::decode(r, ctx)?,
+            old_swapchain: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for TextureLODGatherFormatPropertiesAMD {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.supports_texture_gather_lod_bias_amd.encode(w);
+    }
+}
+impl Decode for TextureLODGatherFormatPropertiesAMD {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(TextureLODGatherFormatPropertiesAMD {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            supports_texture_gather_lod_bias_amd: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ValidationCacheCreateInfoEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.initial_data_size.encode(w);
+        w.bytes(
+            self.p_initial_data as *const u8,
+            self.initial_data_size as usize,
+        );
+    }
+}
+impl Decode for ValidationCacheCreateInfoEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ValidationCacheCreateInfoEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            initial_data_size: Decode::decode(r, ctx)?,
+            p_initial_data: ctx.bytes(r)?,
+        })
+    }
+}
+impl Encode for ValidationFlagsEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.disabled_validation_check_count.encode(w);
+        w.array(
+            self.p_disabled_validation_checks,
+            self.disabled_validation_check_count as usize,
+        );
+    }
+}
+impl Decode for ValidationFlagsEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ValidationFlagsEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            disabled_validation_check_count: Decode::decode(r, ctx)?,
+            p_disabled_validation_checks: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for VertexInputAttributeDescription {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.location.encode(w);
+        self.binding.encode(w);
+        self.format.encode(w);
+        self.offset.encode(w);
+    }
+}
+impl Decode for VertexInputAttributeDescription {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(VertexInputAttributeDescription {
+            location: Decode::decode(r, ctx)?,
+            binding: Decode::decode(r, ctx)?,
+            format: Decode::decode(r, ctx)?,
+            offset: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for VertexInputBindingDescription {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.binding.encode(w);
+        self.stride.encode(w);
+        self.input_rate.encode(w);
+    }
+}
+impl Decode for VertexInputBindingDescription {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(VertexInputBindingDescription {
+            binding: Decode::decode(r, ctx)?,
+            stride: Decode::decode(r, ctx)?,
+            input_rate: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for VertexInputBindingDivisorDescriptionEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.binding.encode(w);
+        self.divisor.encode(w);
+    }
+}
+impl Decode for VertexInputBindingDivisorDescriptionEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(VertexInputBindingDivisorDescriptionEXT {
+            binding: Decode::decode(r, ctx)?,
+            divisor: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ViSurfaceCreateInfoNN {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.window.encode(w);
+    }
+}
+impl Decode for ViSurfaceCreateInfoNN {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ViSurfaceCreateInfoNN {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            window: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for Viewport {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.x.encode(w);
+        self.y.encode(w);
+        self.width.encode(w);
+        self.height.encode(w);
+        self.min_depth.encode(w);
+        self.max_depth.encode(w);
+    }
+}
+impl Decode for Viewport {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Viewport {
+            x: Decode::decode(r, ctx)?,
+            y: Decode::decode(r, ctx)?,
+            width: Decode::decode(r, ctx)?,
+            height: Decode::decode(r, ctx)?,
+            min_depth: Decode::decode(r, ctx)?,
+            max_depth: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ViewportSwizzleNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.x.encode(w);
+        self.y.encode(w);
+        self.z.encode(w);
+        self.w.encode(w);
+    }
+}
+impl Decode for ViewportSwizzleNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ViewportSwizzleNV {
+            x: Decode::decode(r, ctx)?,
+            y: Decode::decode(r, ctx)?,
+            z: Decode::decode(r, ctx)?,
+            w: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for ViewportWScalingNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.xcoeff.encode(w);
+        self.ycoeff.encode(w);
+    }
+}
+impl Decode for ViewportWScalingNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(ViewportWScalingNV {
+            xcoeff: Decode::decode(r, ctx)?,
+            ycoeff: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for WaylandSurfaceCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.display.encode(w);
+        self.surface.encode(w);
+    }
+}
+impl Decode for WaylandSurfaceCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(WaylandSurfaceCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            display: Decode::decode(r, ctx)?,
+            surface: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for Win32KeyedMutexAcquireReleaseInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.acquire_count.encode(w);
+        w.array(self.p_acquire_syncs, self.acquire_count as usize);
+        w.array(self.p_acquire_keys, self.acquire_count as usize);
+        w.array(self.p_acquire_timeouts, self.acquire_count as usize);
+        self.release_count.encode(w);
+        w.array(self.p_release_syncs, self.release_count as usize);
+        w.array(self.p_release_keys, self.release_count as usize);
+    }
+}
+impl Decode for Win32KeyedMutexAcquireReleaseInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Win32KeyedMutexAcquireReleaseInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            acquire_count: Decode::decode(r, ctx)?,
+            p_acquire_syncs: ctx.array(r)?,
+            p_acquire_keys: ctx.array(r)?,
+            p_acquire_timeouts: ctx.array(r)?,
+            release_count: Decode::decode(r, ctx)?,
+            p_release_syncs: ctx.array(r)?,
+            p_release_keys: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for Win32KeyedMutexAcquireReleaseInfoNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.acquire_count.encode(w);
+        w.array(self.p_acquire_syncs, self.acquire_count as usize);
+        w.array(self.p_acquire_keys, self.acquire_count as usize);
+        w.array(
+            self.p_acquire_timeout_milliseconds,
+            self.acquire_count as usize,
+        );
+        self.release_count.encode(w);
+        w.array(self.p_release_syncs, self.release_count as usize);
+        w.array(self.p_release_keys, self.release_count as usize);
+    }
+}
+impl Decode for Win32KeyedMutexAcquireReleaseInfoNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Win32KeyedMutexAcquireReleaseInfoNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            acquire_count: Decode::decode(r, ctx)?,
+            p_acquire_syncs: ctx.array(r)?,
+            p_acquire_keys: ctx.array(r)?,
+            p_acquire_timeout_milliseconds: ctx.array(r)?,
+            release_count: Decode::decode(r, ctx)?,
+            p_release_syncs: ctx.array(r)?,
+            p_release_keys: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for Win32SurfaceCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.hinstance.encode(w);
+        self.hwnd.encode(w);
+    }
+}
+impl Decode for Win32SurfaceCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(Win32SurfaceCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            hinstance: Decode::decode(r, ctx)?,
+            hwnd: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for WriteDescriptorSet {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.dst_set.encode(w);
+        self.dst_binding.encode(w);
+        self.dst_array_element.encode(w);
+        self.descriptor_count.encode(w);
+        self.descriptor_type.encode(w);
+        if [
+            DescriptorType::SAMPLER,
+            DescriptorType::COMBINED_IMAGE_SAMPLER,
+            DescriptorType::SAMPLED_IMAGE,
+            DescriptorType::STORAGE_IMAGE,
+            DescriptorType::INPUT_ATTACHMENT,
+        ]
+        .contains(&self.descriptor_type)
+        {
+            w.array(self.p_image_info, self.descriptor_count as usize)
+        } else {
+            w.null();
+        }
+        if [
+            DescriptorType::UNIFORM_BUFFER,
+            DescriptorType::STORAGE_BUFFER,
+            DescriptorType::UNIFORM_BUFFER_DYNAMIC,
+            DescriptorType::STORAGE_BUFFER_DYNAMIC,
+        ]
+        .contains(&self.descriptor_type)
+        {
+            w.array(self.p_buffer_info, self.descriptor_count as usize)
+        } else {
+            w.null();
+        }
+        if [
+            DescriptorType::UNIFORM_TEXEL_BUFFER,
+            DescriptorType::STORAGE_TEXEL_BUFFER,
+        ]
+        .contains(&self.descriptor_type)
+        {
+            w.array(self.p_texel_buffer_view, self.descriptor_count as usize)
+        } else {
+            w.null();
+        }
+    }
+}
+impl Decode for WriteDescriptorSet {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(WriteDescriptorSet {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            dst_set: Decode::decode(r, ctx)?,
+            dst_binding: Decode::decode(r, ctx)?,
+            dst_array_element: Decode::decode(r, ctx)?,
+            descriptor_count: Decode::decode(r, ctx)?,
+            descriptor_type: Decode::decode(r, ctx)?,
+            p_image_info: ctx.array(r)?,
+            p_buffer_info: ctx.array(r)?,
+            p_texel_buffer_view: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for WriteDescriptorSetAccelerationStructureNV {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.acceleration_structure_count.encode(w);
+        w.array(
+            self.p_acceleration_structures,
+            self.acceleration_structure_count as usize,
+        );
+    }
+}
+impl Decode for WriteDescriptorSetAccelerationStructureNV {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(WriteDescriptorSetAccelerationStructureNV {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            acceleration_structure_count: Decode::decode(r, ctx)?,
+            p_acceleration_structures: ctx.array(r)?,
+        })
+    }
+}
+impl Encode for WriteDescriptorSetInlineUniformBlockEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.data_size.encode(w);
+        w.bytes(self.p_data as *const u8, self.data_size as usize);
+    }
+}
+impl Decode for WriteDescriptorSetInlineUniformBlockEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(WriteDescriptorSetInlineUniformBlockEXT {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            data_size: Decode::decode(r, ctx)?,
+            p_data: ctx.bytes(r)?,
+        })
+    }
+}
+impl Encode for XYColorEXT {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.x.encode(w);
+        self.y.encode(w);
+    }
+}
+impl Decode for XYColorEXT {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(XYColorEXT {
+            x: Decode::decode(r, ctx)?,
+            y: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for XcbSurfaceCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.connection.encode(w);
+        self.window.encode(w);
+    }
+}
+impl Decode for XcbSurfaceCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(XcbSurfaceCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            connection: Decode::decode(r, ctx)?,
+            window: Decode::decode(r, ctx)?,
+        })
+    }
+}
+impl Encode for XlibSurfaceCreateInfoKHR {
+    unsafe fn encode(&self, w: &mut Writer) {
+        self.s_type.encode(w);
+        w.p_next(self.p_next as *const c_void);
+        self.flags.encode(w);
+        self.dpy.encode(w);
+        self.window.encode(w);
+    }
+}
+impl Decode for XlibSurfaceCreateInfoKHR {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ReplayResult<Self> {
+        Ok(XlibSurfaceCreateInfoKHR {
+            s_type: Decode::decode(r, ctx)?,
+            p_next: ctx.p_next(r)? as _,
+            flags: Decode::decode(r, ctx)?,
+            dpy: Decode::decode(r, ctx)?,
+            window: Decode::decode(r, ctx)?,
+        })
+    }
+}
+pub(crate) unsafe fn encode_structure(p: *const c_void, w: &mut Writer) -> bool {
+    match (*(p as *const BaseInStructure)).s_type {
+        StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_NV => {
+            (*(p as *const AccelerationStructureCreateInfoNV)).encode(w)
+        }
+        StructureType::ACCELERATION_STRUCTURE_INFO_NV => {
+            (*(p as *const AccelerationStructureInfoNV)).encode(w)
+        }
+        StructureType::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV => {
+            (*(p as *const AccelerationStructureMemoryRequirementsInfoNV)).encode(w)
+        }
+        StructureType::ACQUIRE_NEXT_IMAGE_INFO_KHR => {
+            (*(p as *const AcquireNextImageInfoKHR)).encode(w)
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID => {
+            (*(p as *const AndroidHardwareBufferFormatPropertiesANDROID)).encode(w)
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID => {
+            (*(p as *const AndroidHardwareBufferPropertiesANDROID)).encode(w)
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID => {
+            (*(p as *const AndroidHardwareBufferUsageANDROID)).encode(w)
+        }
+        StructureType::ANDROID_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const AndroidSurfaceCreateInfoKHR)).encode(w)
+        }
+        StructureType::APPLICATION_INFO => (*(p as *const ApplicationInfo)).encode(w),
+        StructureType::ATTACHMENT_DESCRIPTION_2_KHR => {
+            (*(p as *const AttachmentDescription2KHR)).encode(w)
+        }
+        StructureType::ATTACHMENT_REFERENCE_2_KHR => {
+            (*(p as *const AttachmentReference2KHR)).encode(w)
+        }
+        StructureType::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV => {
+            (*(p as *const BindAccelerationStructureMemoryInfoNV)).encode(w)
+        }
+        StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO => {
+            (*(p as *const BindBufferMemoryDeviceGroupInfo)).encode(w)
+        }
+        StructureType::BIND_BUFFER_MEMORY_INFO => (*(p as *const BindBufferMemoryInfo)).encode(w),
+        StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO => {
+            (*(p as *const BindImageMemoryDeviceGroupInfo)).encode(w)
+        }
+        StructureType::BIND_IMAGE_MEMORY_INFO => (*(p as *const BindImageMemoryInfo)).encode(w),
+        StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR => {
+            (*(p as *const BindImageMemorySwapchainInfoKHR)).encode(w)
+        }
+        StructureType::BIND_IMAGE_PLANE_MEMORY_INFO => {
+            (*(p as *const BindImagePlaneMemoryInfo)).encode(w)
+        }
+        StructureType::BIND_SPARSE_INFO => (*(p as *const BindSparseInfo)).encode(w),
+        StructureType::BUFFER_CREATE_INFO => (*(p as *const BufferCreateInfo)).encode(w),
+        StructureType::BUFFER_MEMORY_BARRIER => (*(p as *const BufferMemoryBarrier)).encode(w),
+        StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2 => {
+            (*(p as *const BufferMemoryRequirementsInfo2)).encode(w)
+        }
+        StructureType::BUFFER_VIEW_CREATE_INFO => (*(p as *const BufferViewCreateInfo)).encode(w),
+        StructureType::CALIBRATED_TIMESTAMP_INFO_EXT => {
+            (*(p as *const CalibratedTimestampInfoEXT)).encode(w)
+        }
+        StructureType::CHECKPOINT_DATA_NV => (*(p as *const CheckpointDataNV)).encode(w),
+        StructureType::CMD_PROCESS_COMMANDS_INFO_NVX => {
+            (*(p as *const CmdProcessCommandsInfoNVX)).encode(w)
+        }
+        StructureType::CMD_RESERVE_SPACE_FOR_COMMANDS_INFO_NVX => {
+            (*(p as *const CmdReserveSpaceForCommandsInfoNVX)).encode(w)
+        }
+        StructureType::COMMAND_BUFFER_ALLOCATE_INFO => {
+            (*(p as *const CommandBufferAllocateInfo)).encode(w)
+        }
+        StructureType::COMMAND_BUFFER_BEGIN_INFO => {
+            (*(p as *const CommandBufferBeginInfo)).encode(w)
+        }
+        StructureType::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT => {
+            (*(p as *const CommandBufferInheritanceConditionalRenderingInfoEXT)).encode(w)
+        }
+        StructureType::COMMAND_BUFFER_INHERITANCE_INFO => {
+            (*(p as *const CommandBufferInheritanceInfo)).encode(w)
+        }
+        StructureType::COMMAND_POOL_CREATE_INFO => (*(p as *const CommandPoolCreateInfo)).encode(w),
+        StructureType::COMPUTE_PIPELINE_CREATE_INFO => {
+            (*(p as *const ComputePipelineCreateInfo)).encode(w)
+        }
+        StructureType::CONDITIONAL_RENDERING_BEGIN_INFO_EXT => {
+            (*(p as *const ConditionalRenderingBeginInfoEXT)).encode(w)
+        }
+        StructureType::COPY_DESCRIPTOR_SET => (*(p as *const CopyDescriptorSet)).encode(w),
+        StructureType::D3D12_FENCE_SUBMIT_INFO_KHR => {
+            (*(p as *const D3D12FenceSubmitInfoKHR)).encode(w)
+        }
+        StructureType::DEBUG_MARKER_MARKER_INFO_EXT => {
+            (*(p as *const DebugMarkerMarkerInfoEXT)).encode(w)
+        }
+        StructureType::DEBUG_MARKER_OBJECT_NAME_INFO_EXT => {
+            (*(p as *const DebugMarkerObjectNameInfoEXT)).encode(w)
+        }
+        StructureType::DEBUG_MARKER_OBJECT_TAG_INFO_EXT => {
+            (*(p as *const DebugMarkerObjectTagInfoEXT)).encode(w)
+        }
+        StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT => {
+            (*(p as *const DebugReportCallbackCreateInfoEXT)).encode(w)
+        }
+        StructureType::DEBUG_UTILS_LABEL_EXT => (*(p as *const DebugUtilsLabelEXT)).encode(w),
+        StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT => {
+            (*(p as *const DebugUtilsMessengerCallbackDataEXT)).encode(w)
+        }
+        StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT => {
+            (*(p as *const DebugUtilsMessengerCreateInfoEXT)).encode(w)
+        }
+        StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT => {
+            (*(p as *const DebugUtilsObjectNameInfoEXT)).encode(w)
+        }
+        StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT => {
+            (*(p as *const DebugUtilsObjectTagInfoEXT)).encode(w)
+        }
+        StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV => {
+            (*(p as *const DedicatedAllocationBufferCreateInfoNV)).encode(w)
+        }
+        StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV => {
+            (*(p as *const DedicatedAllocationImageCreateInfoNV)).encode(w)
+        }
+        StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV => {
+            (*(p as *const DedicatedAllocationMemoryAllocateInfoNV)).encode(w)
+        }
+        StructureType::DESCRIPTOR_POOL_CREATE_INFO => {
+            (*(p as *const DescriptorPoolCreateInfo)).encode(w)
+        }
+        StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT => {
+            (*(p as *const DescriptorPoolInlineUniformBlockCreateInfoEXT)).encode(w)
+        }
+        StructureType::DESCRIPTOR_SET_ALLOCATE_INFO => {
+            (*(p as *const DescriptorSetAllocateInfo)).encode(w)
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT => {
+            (*(p as *const DescriptorSetLayoutBindingFlagsCreateInfoEXT)).encode(w)
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO => {
+            (*(p as *const DescriptorSetLayoutCreateInfo)).encode(w)
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT => {
+            (*(p as *const DescriptorSetLayoutSupport)).encode(w)
+        }
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT => {
+            (*(p as *const DescriptorSetVariableDescriptorCountAllocateInfoEXT)).encode(w)
+        }
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT => {
+            (*(p as *const DescriptorSetVariableDescriptorCountLayoutSupportEXT)).encode(w)
+        }
+        StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO => {
+            (*(p as *const DescriptorUpdateTemplateCreateInfo)).encode(w)
+        }
+        StructureType::DEVICE_CREATE_INFO => (*(p as *const DeviceCreateInfo)).encode(w),
+        StructureType::DEVICE_EVENT_INFO_EXT => (*(p as *const DeviceEventInfoEXT)).encode(w),
+        StructureType::DEVICE_GENERATED_COMMANDS_FEATURES_NVX => {
+            (*(p as *const DeviceGeneratedCommandsFeaturesNVX)).encode(w)
+        }
+        StructureType::DEVICE_GENERATED_COMMANDS_LIMITS_NVX => {
+            (*(p as *const DeviceGeneratedCommandsLimitsNVX)).encode(w)
+        }
+        StructureType::DEVICE_GROUP_BIND_SPARSE_INFO => {
+            (*(p as *const DeviceGroupBindSparseInfo)).encode(w)
+        }
+        StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO => {
+            (*(p as *const DeviceGroupCommandBufferBeginInfo)).encode(w)
+        }
+        StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO => {
+            (*(p as *const DeviceGroupDeviceCreateInfo)).encode(w)
+        }
+        StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR => {
+            (*(p as *const DeviceGroupPresentCapabilitiesKHR)).encode(w)
+        }
+        StructureType::DEVICE_GROUP_PRESENT_INFO_KHR => {
+            (*(p as *const DeviceGroupPresentInfoKHR)).encode(w)
+        }
+        StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO => {
+            (*(p as *const DeviceGroupRenderPassBeginInfo)).encode(w)
+        }
+        StructureType::DEVICE_GROUP_SUBMIT_INFO => (*(p as *const DeviceGroupSubmitInfo)).encode(w),
+        StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR => {
+            (*(p as *const DeviceGroupSwapchainCreateInfoKHR)).encode(w)
+        }
+        StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD => {
+            (*(p as *const DeviceMemoryOverallocationCreateInfoAMD)).encode(w)
+        }
+        StructureType::DEVICE_QUEUE_CREATE_INFO => (*(p as *const DeviceQueueCreateInfo)).encode(w),
+        StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT => {
+            (*(p as *const DeviceQueueGlobalPriorityCreateInfoEXT)).encode(w)
+        }
+        StructureType::DEVICE_QUEUE_INFO_2 => (*(p as *const DeviceQueueInfo2)).encode(w),
+        StructureType::DISPLAY_EVENT_INFO_EXT => (*(p as *const DisplayEventInfoEXT)).encode(w),
+        StructureType::DISPLAY_MODE_CREATE_INFO_KHR => {
+            (*(p as *const DisplayModeCreateInfoKHR)).encode(w)
+        }
+        StructureType::DISPLAY_MODE_PROPERTIES_2_KHR => {
+            (*(p as *const DisplayModeProperties2KHR)).encode(w)
+        }
+        StructureType::DISPLAY_PLANE_CAPABILITIES_2_KHR => {
+            (*(p as *const DisplayPlaneCapabilities2KHR)).encode(w)
+        }
+        StructureType::DISPLAY_PLANE_INFO_2_KHR => (*(p as *const DisplayPlaneInfo2KHR)).encode(w),
+        StructureType::DISPLAY_PLANE_PROPERTIES_2_KHR => {
+            (*(p as *const DisplayPlaneProperties2KHR)).encode(w)
+        }
+        StructureType::DISPLAY_POWER_INFO_EXT => (*(p as *const DisplayPowerInfoEXT)).encode(w),
+        StructureType::DISPLAY_PRESENT_INFO_KHR => (*(p as *const DisplayPresentInfoKHR)).encode(w),
+        StructureType::DISPLAY_PROPERTIES_2_KHR => (*(p as *const DisplayProperties2KHR)).encode(w),
+        StructureType::DISPLAY_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const DisplaySurfaceCreateInfoKHR)).encode(w)
+        }
+        StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT => {
+            (*(p as *const DrmFormatModifierPropertiesListEXT)).encode(w)
+        }
+        StructureType::EVENT_CREATE_INFO => (*(p as *const EventCreateInfo)).encode(w),
+        StructureType::EXPORT_FENCE_CREATE_INFO => (*(p as *const ExportFenceCreateInfo)).encode(w),
+        StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ExportFenceWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::EXPORT_MEMORY_ALLOCATE_INFO => {
+            (*(p as *const ExportMemoryAllocateInfo)).encode(w)
+        }
+        StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV => {
+            (*(p as *const ExportMemoryAllocateInfoNV)).encode(w)
+        }
+        StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ExportMemoryWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV => {
+            (*(p as *const ExportMemoryWin32HandleInfoNV)).encode(w)
+        }
+        StructureType::EXPORT_SEMAPHORE_CREATE_INFO => {
+            (*(p as *const ExportSemaphoreCreateInfo)).encode(w)
+        }
+        StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ExportSemaphoreWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::EXTERNAL_BUFFER_PROPERTIES => {
+            (*(p as *const ExternalBufferProperties)).encode(w)
+        }
+        StructureType::EXTERNAL_FENCE_PROPERTIES => {
+            (*(p as *const ExternalFenceProperties)).encode(w)
+        }
+        StructureType::EXTERNAL_FORMAT_ANDROID => (*(p as *const ExternalFormatANDROID)).encode(w),
+        StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES => {
+            (*(p as *const ExternalImageFormatProperties)).encode(w)
+        }
+        StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO => {
+            (*(p as *const ExternalMemoryBufferCreateInfo)).encode(w)
+        }
+        StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO => {
+            (*(p as *const ExternalMemoryImageCreateInfo)).encode(w)
+        }
+        StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV => {
+            (*(p as *const ExternalMemoryImageCreateInfoNV)).encode(w)
+        }
+        StructureType::EXTERNAL_SEMAPHORE_PROPERTIES => {
+            (*(p as *const ExternalSemaphoreProperties)).encode(w)
+        }
+        StructureType::FENCE_CREATE_INFO => (*(p as *const FenceCreateInfo)).encode(w),
+        StructureType::FENCE_GET_FD_INFO_KHR => (*(p as *const FenceGetFdInfoKHR)).encode(w),
+        StructureType::FENCE_GET_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const FenceGetWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::FORMAT_PROPERTIES_2 => (*(p as *const FormatProperties2)).encode(w),
+        StructureType::FRAMEBUFFER_CREATE_INFO => (*(p as *const FramebufferCreateInfo)).encode(w),
+        StructureType::GEOMETRY_AABB_NV => (*(p as *const GeometryAABBNV)).encode(w),
+        StructureType::GEOMETRY_NV => (*(p as *const GeometryNV)).encode(w),
+        StructureType::GEOMETRY_TRIANGLES_NV => (*(p as *const GeometryTrianglesNV)).encode(w),
+        StructureType::GRAPHICS_PIPELINE_CREATE_INFO => {
+            (*(p as *const GraphicsPipelineCreateInfo)).encode(w)
+        }
+        StructureType::HDR_METADATA_EXT => (*(p as *const HdrMetadataEXT)).encode(w),
+        StructureType::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA => {
+            (*(p as *const ImagePipeSurfaceCreateInfoFUCHSIA)).encode(w)
+        }
+        StructureType::IMAGE_CREATE_INFO => (*(p as *const ImageCreateInfo)).encode(w),
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT => {
+            (*(p as *const ImageDrmFormatModifierExplicitCreateInfoEXT)).encode(w)
+        }
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT => {
+            (*(p as *const ImageDrmFormatModifierListCreateInfoEXT)).encode(w)
+        }
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT => {
+            (*(p as *const ImageDrmFormatModifierPropertiesEXT)).encode(w)
+        }
+        StructureType::IMAGE_FORMAT_LIST_CREATE_INFO_KHR => {
+            (*(p as *const ImageFormatListCreateInfoKHR)).encode(w)
+        }
+        StructureType::IMAGE_FORMAT_PROPERTIES_2 => {
+            (*(p as *const ImageFormatProperties2)).encode(w)
+        }
+        StructureType::IMAGE_MEMORY_BARRIER => (*(p as *const ImageMemoryBarrier)).encode(w),
+        StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2 => {
+            (*(p as *const ImageMemoryRequirementsInfo2)).encode(w)
+        }
+        StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO => {
+            (*(p as *const ImagePlaneMemoryRequirementsInfo)).encode(w)
+        }
+        StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2 => {
+            (*(p as *const ImageSparseMemoryRequirementsInfo2)).encode(w)
+        }
+        StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR => {
+            (*(p as *const ImageSwapchainCreateInfoKHR)).encode(w)
+        }
+        StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT => {
+            (*(p as *const ImageViewASTCDecodeModeEXT)).encode(w)
+        }
+        StructureType::IMAGE_VIEW_CREATE_INFO => (*(p as *const ImageViewCreateInfo)).encode(w),
+        StructureType::IMAGE_VIEW_USAGE_CREATE_INFO => {
+            (*(p as *const ImageViewUsageCreateInfo)).encode(w)
+        }
+        StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID => {
+            (*(p as *const ImportAndroidHardwareBufferInfoANDROID)).encode(w)
+        }
+        StructureType::IMPORT_FENCE_FD_INFO_KHR => (*(p as *const ImportFenceFdInfoKHR)).encode(w),
+        StructureType::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ImportFenceWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::IMPORT_MEMORY_FD_INFO_KHR => {
+            (*(p as *const ImportMemoryFdInfoKHR)).encode(w)
+        }
+        StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT => {
+            (*(p as *const ImportMemoryHostPointerInfoEXT)).encode(w)
+        }
+        StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ImportMemoryWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV => {
+            (*(p as *const ImportMemoryWin32HandleInfoNV)).encode(w)
+        }
+        StructureType::IMPORT_SEMAPHORE_FD_INFO_KHR => {
+            (*(p as *const ImportSemaphoreFdInfoKHR)).encode(w)
+        }
+        StructureType::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ImportSemaphoreWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NVX => {
+            (*(p as *const IndirectCommandsLayoutCreateInfoNVX)).encode(w)
+        }
+        StructureType::INSTANCE_CREATE_INFO => (*(p as *const InstanceCreateInfo)).encode(w),
+        StructureType::IOS_SURFACE_CREATE_INFO_M => {
+            (*(p as *const IOSSurfaceCreateInfoMVK)).encode(w)
+        }
+        StructureType::MACOS_SURFACE_CREATE_INFO_M => {
+            (*(p as *const MacOSSurfaceCreateInfoMVK)).encode(w)
+        }
+        StructureType::MAPPED_MEMORY_RANGE => (*(p as *const MappedMemoryRange)).encode(w),
+        StructureType::MEMORY_ALLOCATE_FLAGS_INFO => {
+            (*(p as *const MemoryAllocateFlagsInfo)).encode(w)
+        }
+        StructureType::MEMORY_ALLOCATE_INFO => (*(p as *const MemoryAllocateInfo)).encode(w),
+        StructureType::MEMORY_BARRIER => (*(p as *const MemoryBarrier)).encode(w),
+        StructureType::MEMORY_DEDICATED_ALLOCATE_INFO => {
+            (*(p as *const MemoryDedicatedAllocateInfo)).encode(w)
+        }
+        StructureType::MEMORY_DEDICATED_REQUIREMENTS => {
+            (*(p as *const MemoryDedicatedRequirements)).encode(w)
+        }
+        StructureType::MEMORY_FD_PROPERTIES_KHR => (*(p as *const MemoryFdPropertiesKHR)).encode(w),
+        StructureType::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID => {
+            (*(p as *const MemoryGetAndroidHardwareBufferInfoANDROID)).encode(w)
+        }
+        StructureType::MEMORY_GET_FD_INFO_KHR => (*(p as *const MemoryGetFdInfoKHR)).encode(w),
+        StructureType::MEMORY_GET_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const MemoryGetWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::MEMORY_HOST_POINTER_PROPERTIES_EXT => {
+            (*(p as *const MemoryHostPointerPropertiesEXT)).encode(w)
+        }
+        StructureType::MEMORY_REQUIREMENTS_2 => (*(p as *const MemoryRequirements2)).encode(w),
+        StructureType::MEMORY_WIN32_HANDLE_PROPERTIES_KHR => {
+            (*(p as *const MemoryWin32HandlePropertiesKHR)).encode(w)
+        }
+        StructureType::MULTISAMPLE_PROPERTIES_EXT => {
+            (*(p as *const MultisamplePropertiesEXT)).encode(w)
+        }
+        StructureType::NATIVE_BUFFER_ANDROID => (*(p as *const NativeBufferANDROID)).encode(w),
+        StructureType::OBJECT_TABLE_CREATE_INFO_NVX => {
+            (*(p as *const ObjectTableCreateInfoNVX)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES => {
+            (*(p as *const PhysicalDevice16BitStorageFeatures)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR => {
+            (*(p as *const PhysicalDevice8BitStorageFeaturesKHR)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceASTCDecodeFeaturesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceBlendOperationAdvancedFeaturesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceBlendOperationAdvancedPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceComputeShaderDerivativesFeaturesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceConditionalRenderingFeaturesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceConservativeRasterizationPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceCornerSampledImageFeaturesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceDescriptorIndexingFeaturesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceDescriptorIndexingPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceDiscardRectanglePropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR => {
+            (*(p as *const PhysicalDeviceDriverPropertiesKHR)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceExclusiveScissorFeaturesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO => {
+            (*(p as *const PhysicalDeviceExternalBufferInfo)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO => {
+            (*(p as *const PhysicalDeviceExternalFenceInfo)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO => {
+            (*(p as *const PhysicalDeviceExternalImageFormatInfo)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceExternalMemoryHostPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO => {
+            (*(p as *const PhysicalDeviceExternalSemaphoreInfo)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_FEATURES_2 => {
+            (*(p as *const PhysicalDeviceFeatures2)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceFragmentShaderBarycentricFeaturesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES => {
+            (*(p as *const PhysicalDeviceGroupProperties)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_ID_PROPERTIES => {
+            (*(p as *const PhysicalDeviceIDProperties)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT => {
+            (*(p as *const PhysicalDeviceImageDrmFormatModifierInfoEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2 => {
+            (*(p as *const PhysicalDeviceImageFormatInfo2)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceInlineUniformBlockFeaturesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceInlineUniformBlockPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES => {
+            (*(p as *const PhysicalDeviceMaintenance3Properties)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2 => {
+            (*(p as *const PhysicalDeviceMemoryProperties2)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceMeshShaderFeaturesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV => {
+            (*(p as *const PhysicalDeviceMeshShaderPropertiesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES => {
+            (*(p as *const PhysicalDeviceMultiviewFeatures)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX => {
+            (*(p as *const PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES => {
+            (*(p as *const PhysicalDeviceMultiviewProperties)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDevicePCIBusInfoPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES => {
+            (*(p as *const PhysicalDevicePointClippingProperties)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_PROPERTIES_2 => {
+            (*(p as *const PhysicalDeviceProperties2)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES => {
+            (*(p as *const PhysicalDeviceProtectedMemoryFeatures)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES => {
+            (*(p as *const PhysicalDeviceProtectedMemoryProperties)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR => {
+            (*(p as *const PhysicalDevicePushDescriptorPropertiesKHR)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV => {
+            (*(p as *const PhysicalDeviceRayTracingPropertiesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceRepresentativeFragmentTestFeaturesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceSamplerFilterMinmaxPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES => {
+            (*(p as *const PhysicalDeviceSamplerYcbcrConversionFeatures)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceSampleLocationsPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR => {
+            (*(p as *const PhysicalDeviceShaderAtomicInt64FeaturesKHR)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD => {
+            (*(p as *const PhysicalDeviceShaderCorePropertiesAMD)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES => {
+            (*(p as *const PhysicalDeviceShaderDrawParameterFeatures)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceShaderImageFootprintFeaturesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceShadingRateImageFeaturesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV => {
+            (*(p as *const PhysicalDeviceShadingRateImagePropertiesNV)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2 => {
+            (*(p as *const PhysicalDeviceSparseImageFormatInfo2)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES => {
+            (*(p as *const PhysicalDeviceSubgroupProperties)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR => {
+            (*(p as *const PhysicalDeviceSurfaceInfo2KHR)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceTransformFeedbackFeaturesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceTransformFeedbackPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES => {
+            (*(p as *const PhysicalDeviceVariablePointerFeatures)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceVertexAttributeDivisorFeaturesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceVertexAttributeDivisorPropertiesEXT)).encode(w)
+        }
+        StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR => {
+            (*(p as *const PhysicalDeviceVulkanMemoryModelFeaturesKHR)).encode(w)
+        }
+        StructureType::PIPELINE_CACHE_CREATE_INFO => {
+            (*(p as *const PipelineCacheCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineColorBlendAdvancedStateCreateInfoEXT)).encode(w)
+        }
+        StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO => {
+            (*(p as *const PipelineColorBlendStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineCoverageModulationStateCreateInfoNV)).encode(w)
+        }
+        StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineCoverageToColorStateCreateInfoNV)).encode(w)
+        }
+        StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO => {
+            (*(p as *const PipelineDepthStencilStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineDiscardRectangleStateCreateInfoEXT)).encode(w)
+        }
+        StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO => {
+            (*(p as *const PipelineDynamicStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO => {
+            (*(p as *const PipelineInputAssemblyStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_LAYOUT_CREATE_INFO => {
+            (*(p as *const PipelineLayoutCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO => {
+            (*(p as *const PipelineMultisampleStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineRasterizationConservativeStateCreateInfoEXT)).encode(w)
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO => {
+            (*(p as *const PipelineRasterizationStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD => {
+            (*(p as *const PipelineRasterizationStateRasterizationOrderAMD)).encode(w)
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT => {
+            (*(p as *const PipelineRasterizationStateStreamCreateInfoEXT)).encode(w)
+        }
+        StructureType::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineRepresentativeFragmentTestStateCreateInfoNV)).encode(w)
+        }
+        StructureType::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineSampleLocationsStateCreateInfoEXT)).encode(w)
+        }
+        StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO => {
+            (*(p as *const PipelineShaderStageCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO => {
+            (*(p as *const PipelineTessellationDomainOriginStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO => {
+            (*(p as *const PipelineTessellationStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineVertexInputDivisorStateCreateInfoEXT)).encode(w)
+        }
+        StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO => {
+            (*(p as *const PipelineVertexInputStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportCoarseSampleOrderStateCreateInfoNV)).encode(w)
+        }
+        StructureType::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportExclusiveScissorStateCreateInfoNV)).encode(w)
+        }
+        StructureType::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportShadingRateImageStateCreateInfoNV)).encode(w)
+        }
+        StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO => {
+            (*(p as *const PipelineViewportStateCreateInfo)).encode(w)
+        }
+        StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportSwizzleStateCreateInfoNV)).encode(w)
+        }
+        StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportWScalingStateCreateInfoNV)).encode(w)
+        }
+        StructureType::PRESENT_INFO_KHR => (*(p as *const PresentInfoKHR)).encode(w),
+        StructureType::PRESENT_REGIONS_KHR => (*(p as *const PresentRegionsKHR)).encode(w),
+        StructureType::PRESENT_TIMES_INFO_GOOGLE => {
+            (*(p as *const PresentTimesInfoGOOGLE)).encode(w)
+        }
+        StructureType::PROTECTED_SUBMIT_INFO => (*(p as *const ProtectedSubmitInfo)).encode(w),
+        StructureType::QUERY_POOL_CREATE_INFO => (*(p as *const QueryPoolCreateInfo)).encode(w),
+        StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV => {
+            (*(p as *const QueueFamilyCheckpointPropertiesNV)).encode(w)
+        }
+        StructureType::QUEUE_FAMILY_PROPERTIES_2 => {
+            (*(p as *const QueueFamilyProperties2)).encode(w)
+        }
+        StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_NV => {
+            (*(p as *const RayTracingPipelineCreateInfoNV)).encode(w)
+        }
+        StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV => {
+            (*(p as *const RayTracingShaderGroupCreateInfoNV)).encode(w)
+        }
+        StructureType::RENDER_PASS_BEGIN_INFO => (*(p as *const RenderPassBeginInfo)).encode(w),
+        StructureType::RENDER_PASS_CREATE_INFO => (*(p as *const RenderPassCreateInfo)).encode(w),
+        StructureType::RENDER_PASS_CREATE_INFO_2_KHR => {
+            (*(p as *const RenderPassCreateInfo2KHR)).encode(w)
+        }
+        StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO => {
+            (*(p as *const RenderPassInputAttachmentAspectCreateInfo)).encode(w)
+        }
+        StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO => {
+            (*(p as *const RenderPassMultiviewCreateInfo)).encode(w)
+        }
+        StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT => {
+            (*(p as *const RenderPassSampleLocationsBeginInfoEXT)).encode(w)
+        }
+        StructureType::SAMPLER_CREATE_INFO => (*(p as *const SamplerCreateInfo)).encode(w),
+        StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT => {
+            (*(p as *const SamplerReductionModeCreateInfoEXT)).encode(w)
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO => {
+            (*(p as *const SamplerYcbcrConversionCreateInfo)).encode(w)
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES => {
+            (*(p as *const SamplerYcbcrConversionImageFormatProperties)).encode(w)
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_INFO => {
+            (*(p as *const SamplerYcbcrConversionInfo)).encode(w)
+        }
+        StructureType::SAMPLE_LOCATIONS_INFO_EXT => {
+            (*(p as *const SampleLocationsInfoEXT)).encode(w)
+        }
+        StructureType::SEMAPHORE_CREATE_INFO => (*(p as *const SemaphoreCreateInfo)).encode(w),
+        StructureType::SEMAPHORE_GET_FD_INFO_KHR => {
+            (*(p as *const SemaphoreGetFdInfoKHR)).encode(w)
+        }
+        StructureType::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const SemaphoreGetWin32HandleInfoKHR)).encode(w)
+        }
+        StructureType::SHADER_MODULE_CREATE_INFO => {
+            (*(p as *const ShaderModuleCreateInfo)).encode(w)
+        }
+        StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT => {
+            (*(p as *const ShaderModuleValidationCacheCreateInfoEXT)).encode(w)
+        }
+        StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR => {
+            (*(p as *const SharedPresentSurfaceCapabilitiesKHR)).encode(w)
+        }
+        StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2 => {
+            (*(p as *const SparseImageFormatProperties2)).encode(w)
+        }
+        StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2 => {
+            (*(p as *const SparseImageMemoryRequirements2)).encode(w)
+        }
+        StructureType::SUBMIT_INFO => (*(p as *const SubmitInfo)).encode(w),
+        StructureType::SUBPASS_BEGIN_INFO_KHR => (*(p as *const SubpassBeginInfoKHR)).encode(w),
+        StructureType::SUBPASS_DEPENDENCY_2_KHR => (*(p as *const SubpassDependency2KHR)).encode(w),
+        StructureType::SUBPASS_DESCRIPTION_2_KHR => {
+            (*(p as *const SubpassDescription2KHR)).encode(w)
+        }
+        StructureType::SUBPASS_END_INFO_KHR => (*(p as *const SubpassEndInfoKHR)).encode(w),
+        StructureType::SURFACE_CAPABILITIES_2_EXT => {
+            (*(p as *const SurfaceCapabilities2EXT)).encode(w)
+        }
+        StructureType::SURFACE_CAPABILITIES_2_KHR => {
+            (*(p as *const SurfaceCapabilities2KHR)).encode(w)
+        }
+        StructureType::SURFACE_FORMAT_2_KHR => (*(p as *const SurfaceFormat2KHR)).encode(w),
+        StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT => {
+            (*(p as *const SwapchainCounterCreateInfoEXT)).encode(w)
+        }
+        StructureType::SWAPCHAIN_CREATE_INFO_KHR => {
+            (*(p as *const SwapchainCreateInfoKHR)).encode(w)
+        }
+        StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD => {
+            (*(p as *const TextureLODGatherFormatPropertiesAMD)).encode(w)
+        }
+        StructureType::VALIDATION_CACHE_CREATE_INFO_EXT => {
+            (*(p as *const ValidationCacheCreateInfoEXT)).encode(w)
+        }
+        StructureType::VALIDATION_FLAGS_EXT => (*(p as *const ValidationFlagsEXT)).encode(w),
+        StructureType::VI_SURFACE_CREATE_INFO_NN => {
+            (*(p as *const ViSurfaceCreateInfoNN)).encode(w)
+        }
+        StructureType::WAYLAND_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const WaylandSurfaceCreateInfoKHR)).encode(w)
+        }
+        StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR => {
+            (*(p as *const Win32KeyedMutexAcquireReleaseInfoKHR)).encode(w)
+        }
+        StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV => {
+            (*(p as *const Win32KeyedMutexAcquireReleaseInfoNV)).encode(w)
+        }
+        StructureType::WIN32_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const Win32SurfaceCreateInfoKHR)).encode(w)
+        }
+        StructureType::WRITE_DESCRIPTOR_SET => (*(p as *const WriteDescriptorSet)).encode(w),
+        StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV => {
+            (*(p as *const WriteDescriptorSetAccelerationStructureNV)).encode(w)
+        }
+        StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT => {
+            (*(p as *const WriteDescriptorSetInlineUniformBlockEXT)).encode(w)
+        }
+        StructureType::XCB_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const XcbSurfaceCreateInfoKHR)).encode(w)
+        }
+        StructureType::XLIB_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const XlibSurfaceCreateInfoKHR)).encode(w)
+        }
+        _ => return false,
+    }
+    true
+}
+pub(crate) unsafe fn decode_structure(
+    s_type: StructureType,
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<*const c_void> {
+    Ok(match s_type {
+        StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_NV => {
+            ctx.boxed::<AccelerationStructureCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::ACCELERATION_STRUCTURE_INFO_NV => {
+            ctx.boxed::<AccelerationStructureInfoNV>(r)? as *const c_void
+        }
+        StructureType::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV => {
+            ctx.boxed::<AccelerationStructureMemoryRequirementsInfoNV>(r)? as *const c_void
+        }
+        StructureType::ACQUIRE_NEXT_IMAGE_INFO_KHR => {
+            ctx.boxed::<AcquireNextImageInfoKHR>(r)? as *const c_void
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID => {
+            ctx.boxed::<AndroidHardwareBufferFormatPropertiesANDROID>(r)? as *const c_void
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID => {
+            ctx.boxed::<AndroidHardwareBufferPropertiesANDROID>(r)? as *const c_void
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID => {
+            ctx.boxed::<AndroidHardwareBufferUsageANDROID>(r)? as *const c_void
+        }
+        StructureType::ANDROID_SURFACE_CREATE_INFO_KHR => {
+            ctx.boxed::<AndroidSurfaceCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::APPLICATION_INFO => ctx.boxed::<ApplicationInfo>(r)? as *const c_void,
+        StructureType::ATTACHMENT_DESCRIPTION_2_KHR => {
+            ctx.boxed::<AttachmentDescription2KHR>(r)? as *const c_void
+        }
+        StructureType::ATTACHMENT_REFERENCE_2_KHR => {
+            ctx.boxed::<AttachmentReference2KHR>(r)? as *const c_void
+        }
+        StructureType::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV => {
+            ctx.boxed::<BindAccelerationStructureMemoryInfoNV>(r)? as *const c_void
+        }
+        StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO => {
+            ctx.boxed::<BindBufferMemoryDeviceGroupInfo>(r)? as *const c_void
+        }
+        StructureType::BIND_BUFFER_MEMORY_INFO => {
+            ctx.boxed::<BindBufferMemoryInfo>(r)? as *const c_void
+        }
+        StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO => {
+            ctx.boxed::<BindImageMemoryDeviceGroupInfo>(r)? as *const c_void
+        }
+        StructureType::BIND_IMAGE_MEMORY_INFO => {
+            ctx.boxed::<BindImageMemoryInfo>(r)? as *const c_void
+        }
+        StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR => {
+            ctx.boxed::<BindImageMemorySwapchainInfoKHR>(r)? as *const c_void
+        }
+        StructureType::BIND_IMAGE_PLANE_MEMORY_INFO => {
+            ctx.boxed::<BindImagePlaneMemoryInfo>(r)? as *const c_void
+        }
+        StructureType::BIND_SPARSE_INFO => ctx.boxed::<BindSparseInfo>(r)? as *const c_void,
+        StructureType::BUFFER_CREATE_INFO => ctx.boxed::<BufferCreateInfo>(r)? as *const c_void,
+        StructureType::BUFFER_MEMORY_BARRIER => {
+            ctx.boxed::<BufferMemoryBarrier>(r)? as *const c_void
+        }
+        StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2 => {
+            ctx.boxed::<BufferMemoryRequirementsInfo2>(r)? as *const c_void
+        }
+        StructureType::BUFFER_VIEW_CREATE_INFO => {
+            ctx.boxed::<BufferViewCreateInfo>(r)? as *const c_void
+        }
+        StructureType::CALIBRATED_TIMESTAMP_INFO_EXT => {
+            ctx.boxed::<CalibratedTimestampInfoEXT>(r)? as *const c_void
+        }
+        StructureType::CHECKPOINT_DATA_NV => ctx.boxed::<CheckpointDataNV>(r)? as *const c_void,
+        StructureType::CMD_PROCESS_COMMANDS_INFO_NVX => {
+            ctx.boxed::<CmdProcessCommandsInfoNVX>(r)? as *const c_void
+        }
+        StructureType::CMD_RESERVE_SPACE_FOR_COMMANDS_INFO_NVX => {
+            ctx.boxed::<CmdReserveSpaceForCommandsInfoNVX>(r)? as *const c_void
+        }
+        StructureType::COMMAND_BUFFER_ALLOCATE_INFO => {
+            ctx.boxed::<CommandBufferAllocateInfo>(r)? as *const c_void
+        }
+        StructureType::COMMAND_BUFFER_BEGIN_INFO => {
+            ctx.boxed::<CommandBufferBeginInfo>(r)? as *const c_void
+        }
+        StructureType::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT => {
+            ctx.boxed::<CommandBufferInheritanceConditionalRenderingInfoEXT>(r)? as *const c_void
+        }
+        StructureType::COMMAND_BUFFER_INHERITANCE_INFO => {
+            ctx.boxed::<CommandBufferInheritanceInfo>(r)? as *const c_void
+        }
+        StructureType::COMMAND_POOL_CREATE_INFO => {
+            ctx.boxed::<CommandPoolCreateInfo>(r)? as *const c_void
+        }
+        StructureType::COMPUTE_PIPELINE_CREATE_INFO => {
+            ctx.boxed::<ComputePipelineCreateInfo>(r)? as *const c_void
+        }
+        StructureType::CONDITIONAL_RENDERING_BEGIN_INFO_EXT => {
+            ctx.boxed::<ConditionalRenderingBeginInfoEXT>(r)? as *const c_void
+        }
+        StructureType::COPY_DESCRIPTOR_SET => ctx.boxed::<CopyDescriptorSet>(r)? as *const c_void,
+        StructureType::D3D12_FENCE_SUBMIT_INFO_KHR => {
+            ctx.boxed::<D3D12FenceSubmitInfoKHR>(r)? as *const c_void
+        }
+        StructureType::DEBUG_MARKER_MARKER_INFO_EXT => {
+            ctx.boxed::<DebugMarkerMarkerInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DEBUG_MARKER_OBJECT_NAME_INFO_EXT => {
+            ctx.boxed::<DebugMarkerObjectNameInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DEBUG_MARKER_OBJECT_TAG_INFO_EXT => {
+            ctx.boxed::<DebugMarkerObjectTagInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT => {
+            let value: DebugReportCallbackCreateInfoEXT = Decode::decode(r, ctx)?;
+            value.p_next
+        }
+        StructureType::DEBUG_UTILS_LABEL_EXT => {
+            ctx.boxed::<DebugUtilsLabelEXT>(r)? as *const c_void
+        }
+        StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT => {
+            ctx.boxed::<DebugUtilsMessengerCallbackDataEXT>(r)? as *const c_void
+        }
+        StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT => {
+            let value: DebugUtilsMessengerCreateInfoEXT = Decode::decode(r, ctx)?;
+            value.p_next
+        }
+        StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT => {
+            ctx.boxed::<DebugUtilsObjectNameInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT => {
+            ctx.boxed::<DebugUtilsObjectTagInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV => {
+            ctx.boxed::<DedicatedAllocationBufferCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV => {
+            ctx.boxed::<DedicatedAllocationImageCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV => {
+            ctx.boxed::<DedicatedAllocationMemoryAllocateInfoNV>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_POOL_CREATE_INFO => {
+            ctx.boxed::<DescriptorPoolCreateInfo>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT => {
+            ctx.boxed::<DescriptorPoolInlineUniformBlockCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_SET_ALLOCATE_INFO => {
+            ctx.boxed::<DescriptorSetAllocateInfo>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT => {
+            ctx.boxed::<DescriptorSetLayoutBindingFlagsCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO => {
+            ctx.boxed::<DescriptorSetLayoutCreateInfo>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT => {
+            ctx.boxed::<DescriptorSetLayoutSupport>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT => {
+            ctx.boxed::<DescriptorSetVariableDescriptorCountAllocateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT => {
+            ctx.boxed::<DescriptorSetVariableDescriptorCountLayoutSupportEXT>(r)? as *const c_void
+        }
+        StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO => {
+            ctx.boxed::<DescriptorUpdateTemplateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::DEVICE_CREATE_INFO => ctx.boxed::<DeviceCreateInfo>(r)? as *const c_void,
+        StructureType::DEVICE_EVENT_INFO_EXT => {
+            ctx.boxed::<DeviceEventInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GENERATED_COMMANDS_FEATURES_NVX => {
+            ctx.boxed::<DeviceGeneratedCommandsFeaturesNVX>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GENERATED_COMMANDS_LIMITS_NVX => {
+            ctx.boxed::<DeviceGeneratedCommandsLimitsNVX>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GROUP_BIND_SPARSE_INFO => {
+            ctx.boxed::<DeviceGroupBindSparseInfo>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO => {
+            ctx.boxed::<DeviceGroupCommandBufferBeginInfo>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO => {
+            ctx.boxed::<DeviceGroupDeviceCreateInfo>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR => {
+            ctx.boxed::<DeviceGroupPresentCapabilitiesKHR>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GROUP_PRESENT_INFO_KHR => {
+            ctx.boxed::<DeviceGroupPresentInfoKHR>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO => {
+            ctx.boxed::<DeviceGroupRenderPassBeginInfo>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GROUP_SUBMIT_INFO => {
+            ctx.boxed::<DeviceGroupSubmitInfo>(r)? as *const c_void
+        }
+        StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR => {
+            ctx.boxed::<DeviceGroupSwapchainCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD => {
+            ctx.boxed::<DeviceMemoryOverallocationCreateInfoAMD>(r)? as *const c_void
+        }
+        StructureType::DEVICE_QUEUE_CREATE_INFO => {
+            ctx.boxed::<DeviceQueueCreateInfo>(r)? as *const c_void
+        }
+        StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT => {
+            ctx.boxed::<DeviceQueueGlobalPriorityCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DEVICE_QUEUE_INFO_2 => ctx.boxed::<DeviceQueueInfo2>(r)? as *const c_void,
+        StructureType::DISPLAY_EVENT_INFO_EXT => {
+            ctx.boxed::<DisplayEventInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_MODE_CREATE_INFO_KHR => {
+            ctx.boxed::<DisplayModeCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_MODE_PROPERTIES_2_KHR => {
+            ctx.boxed::<DisplayModeProperties2KHR>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_PLANE_CAPABILITIES_2_KHR => {
+            ctx.boxed::<DisplayPlaneCapabilities2KHR>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_PLANE_INFO_2_KHR => {
+            ctx.boxed::<DisplayPlaneInfo2KHR>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_PLANE_PROPERTIES_2_KHR => {
+            ctx.boxed::<DisplayPlaneProperties2KHR>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_POWER_INFO_EXT => {
+            ctx.boxed::<DisplayPowerInfoEXT>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_PRESENT_INFO_KHR => {
+            ctx.boxed::<DisplayPresentInfoKHR>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_PROPERTIES_2_KHR => {
+            ctx.boxed::<DisplayProperties2KHR>(r)? as *const c_void
+        }
+        StructureType::DISPLAY_SURFACE_CREATE_INFO_KHR => {
+            ctx.boxed::<DisplaySurfaceCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT => {
+            ctx.boxed::<DrmFormatModifierPropertiesListEXT>(r)? as *const c_void
+        }
+        StructureType::EVENT_CREATE_INFO => ctx.boxed::<EventCreateInfo>(r)? as *const c_void,
+        StructureType::EXPORT_FENCE_CREATE_INFO => {
+            ctx.boxed::<ExportFenceCreateInfo>(r)? as *const c_void
+        }
+        StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<ExportFenceWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::EXPORT_MEMORY_ALLOCATE_INFO => {
+            ctx.boxed::<ExportMemoryAllocateInfo>(r)? as *const c_void
+        }
+        StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV => {
+            ctx.boxed::<ExportMemoryAllocateInfoNV>(r)? as *const c_void
+        }
+        StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<ExportMemoryWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV => {
+            ctx.boxed::<ExportMemoryWin32HandleInfoNV>(r)? as *const c_void
+        }
+        StructureType::EXPORT_SEMAPHORE_CREATE_INFO => {
+            ctx.boxed::<ExportSemaphoreCreateInfo>(r)? as *const c_void
+        }
+        StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<ExportSemaphoreWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::EXTERNAL_BUFFER_PROPERTIES => {
+            ctx.boxed::<ExternalBufferProperties>(r)? as *const c_void
+        }
+        StructureType::EXTERNAL_FENCE_PROPERTIES => {
+            ctx.boxed::<ExternalFenceProperties>(r)? as *const c_void
+        }
+        StructureType::EXTERNAL_FORMAT_ANDROID => {
+            ctx.boxed::<ExternalFormatANDROID>(r)? as *const c_void
+        }
+        StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES => {
+            ctx.boxed::<ExternalImageFormatProperties>(r)? as *const c_void
+        }
+        StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO => {
+            ctx.boxed::<ExternalMemoryBufferCreateInfo>(r)? as *const c_void
+        }
+        StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO => {
+            ctx.boxed::<ExternalMemoryImageCreateInfo>(r)? as *const c_void
+        }
+        StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV => {
+            ctx.boxed::<ExternalMemoryImageCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::EXTERNAL_SEMAPHORE_PROPERTIES => {
+            ctx.boxed::<ExternalSemaphoreProperties>(r)? as *const c_void
+        }
+        StructureType::FENCE_CREATE_INFO => ctx.boxed::<FenceCreateInfo>(r)? as *const c_void,
+        StructureType::FENCE_GET_FD_INFO_KHR => ctx.boxed::<FenceGetFdInfoKHR>(r)? as *const c_void,
+        StructureType::FENCE_GET_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<FenceGetWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::FORMAT_PROPERTIES_2 => ctx.boxed::<FormatProperties2>(r)? as *const c_void,
+        StructureType::FRAMEBUFFER_CREATE_INFO => {
+            ctx.boxed::<FramebufferCreateInfo>(r)? as *const c_void
+        }
+        StructureType::GEOMETRY_AABB_NV => ctx.boxed::<GeometryAABBNV>(r)? as *const c_void,
+        StructureType::GEOMETRY_NV => ctx.boxed::<GeometryNV>(r)? as *const c_void,
+        StructureType::GEOMETRY_TRIANGLES_NV => {
+            ctx.boxed::<GeometryTrianglesNV>(r)? as *const c_void
+        }
+        StructureType::GRAPHICS_PIPELINE_CREATE_INFO => {
+            ctx.boxed::<GraphicsPipelineCreateInfo>(r)? as *const c_void
+        }
+        StructureType::HDR_METADATA_EXT => ctx.boxed::<HdrMetadataEXT>(r)? as *const c_void,
+        StructureType::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA => {
+            ctx.boxed::<ImagePipeSurfaceCreateInfoFUCHSIA>(r)? as *const c_void
+        }
+        StructureType::IMAGE_CREATE_INFO => ctx.boxed::<ImageCreateInfo>(r)? as *const c_void,
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT => {
+            ctx.boxed::<ImageDrmFormatModifierExplicitCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT => {
+            ctx.boxed::<ImageDrmFormatModifierListCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT => {
+            ctx.boxed::<ImageDrmFormatModifierPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::IMAGE_FORMAT_LIST_CREATE_INFO_KHR => {
+            ctx.boxed::<ImageFormatListCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::IMAGE_FORMAT_PROPERTIES_2 => {
+            ctx.boxed::<ImageFormatProperties2>(r)? as *const c_void
+        }
+        StructureType::IMAGE_MEMORY_BARRIER => ctx.boxed::<ImageMemoryBarrier>(r)? as *const c_void,
+        StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2 => {
+            ctx.boxed::<ImageMemoryRequirementsInfo2>(r)? as *const c_void
+        }
+        StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO => {
+            ctx.boxed::<ImagePlaneMemoryRequirementsInfo>(r)? as *const c_void
+        }
+        StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2 => {
+            ctx.boxed::<ImageSparseMemoryRequirementsInfo2>(r)? as *const c_void
+        }
+        StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR => {
+            ctx.boxed::<ImageSwapchainCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT => {
+            ctx.boxed::<ImageViewASTCDecodeModeEXT>(r)? as *const c_void
+        }
+        StructureType::IMAGE_VIEW_CREATE_INFO => {
+            ctx.boxed::<ImageViewCreateInfo>(r)? as *const c_void
+        }
+        StructureType::IMAGE_VIEW_USAGE_CREATE_INFO => {
+            ctx.boxed::<ImageViewUsageCreateInfo>(r)? as *const c_void
+        }
+        StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID => {
+            ctx.boxed::<ImportAndroidHardwareBufferInfoANDROID>(r)? as *const c_void
+        }
+        StructureType::IMPORT_FENCE_FD_INFO_KHR => {
+            ctx.boxed::<ImportFenceFdInfoKHR>(r)? as *const c_void
+        }
+        StructureType::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<ImportFenceWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::IMPORT_MEMORY_FD_INFO_KHR => {
+            ctx.boxed::<ImportMemoryFdInfoKHR>(r)? as *const c_void
+        }
+        StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT => {
+            ctx.boxed::<ImportMemoryHostPointerInfoEXT>(r)? as *const c_void
+        }
+        StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<ImportMemoryWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV => {
+            ctx.boxed::<ImportMemoryWin32HandleInfoNV>(r)? as *const c_void
+        }
+        StructureType::IMPORT_SEMAPHORE_FD_INFO_KHR => {
+            ctx.boxed::<ImportSemaphoreFdInfoKHR>(r)? as *const c_void
+        }
+        StructureType::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<ImportSemaphoreWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NVX => {
+            ctx.boxed::<IndirectCommandsLayoutCreateInfoNVX>(r)? as *const c_void
+        }
+        StructureType::INSTANCE_CREATE_INFO => ctx.boxed::<InstanceCreateInfo>(r)? as *const c_void,
+        StructureType::IOS_SURFACE_CREATE_INFO_M => {
+            ctx.boxed::<IOSSurfaceCreateInfoMVK>(r)? as *const c_void
+        }
+        StructureType::MACOS_SURFACE_CREATE_INFO_M => {
+            ctx.boxed::<MacOSSurfaceCreateInfoMVK>(r)? as *const c_void
+        }
+        StructureType::MAPPED_MEMORY_RANGE => ctx.boxed::<MappedMemoryRange>(r)? as *const c_void,
+        StructureType::MEMORY_ALLOCATE_FLAGS_INFO => {
+            ctx.boxed::<MemoryAllocateFlagsInfo>(r)? as *const c_void
+        }
+        StructureType::MEMORY_ALLOCATE_INFO => ctx.boxed::<MemoryAllocateInfo>(r)? as *const c_void,
+        StructureType::MEMORY_BARRIER => ctx.boxed::<MemoryBarrier>(r)? as *const c_void,
+        StructureType::MEMORY_DEDICATED_ALLOCATE_INFO => {
+            ctx.boxed::<MemoryDedicatedAllocateInfo>(r)? as *const c_void
+        }
+        StructureType::MEMORY_DEDICATED_REQUIREMENTS => {
+            ctx.boxed::<MemoryDedicatedRequirements>(r)? as *const c_void
+        }
+        StructureType::MEMORY_FD_PROPERTIES_KHR => {
+            ctx.boxed::<MemoryFdPropertiesKHR>(r)? as *const c_void
+        }
+        StructureType::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID => {
+            ctx.boxed::<MemoryGetAndroidHardwareBufferInfoANDROID>(r)? as *const c_void
+        }
+        StructureType::MEMORY_GET_FD_INFO_KHR => {
+            ctx.boxed::<MemoryGetFdInfoKHR>(r)? as *const c_void
+        }
+        StructureType::MEMORY_GET_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<MemoryGetWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::MEMORY_HOST_POINTER_PROPERTIES_EXT => {
+            ctx.boxed::<MemoryHostPointerPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::MEMORY_REQUIREMENTS_2 => {
+            ctx.boxed::<MemoryRequirements2>(r)? as *const c_void
+        }
+        StructureType::MEMORY_WIN32_HANDLE_PROPERTIES_KHR => {
+            ctx.boxed::<MemoryWin32HandlePropertiesKHR>(r)? as *const c_void
+        }
+        StructureType::MULTISAMPLE_PROPERTIES_EXT => {
+            ctx.boxed::<MultisamplePropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::NATIVE_BUFFER_ANDROID => {
+            ctx.boxed::<NativeBufferANDROID>(r)? as *const c_void
+        }
+        StructureType::OBJECT_TABLE_CREATE_INFO_NVX => {
+            ctx.boxed::<ObjectTableCreateInfoNVX>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES => {
+            ctx.boxed::<PhysicalDevice16BitStorageFeatures>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR => {
+            ctx.boxed::<PhysicalDevice8BitStorageFeaturesKHR>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT => {
+            ctx.boxed::<PhysicalDeviceASTCDecodeFeaturesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT => {
+            ctx.boxed::<PhysicalDeviceBlendOperationAdvancedFeaturesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceBlendOperationAdvancedPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV => {
+            ctx.boxed::<PhysicalDeviceComputeShaderDerivativesFeaturesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT => {
+            ctx.boxed::<PhysicalDeviceConditionalRenderingFeaturesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceConservativeRasterizationPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV => {
+            ctx.boxed::<PhysicalDeviceCornerSampledImageFeaturesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT => {
+            ctx.boxed::<PhysicalDeviceDescriptorIndexingFeaturesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceDescriptorIndexingPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceDiscardRectanglePropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR => {
+            ctx.boxed::<PhysicalDeviceDriverPropertiesKHR>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV => {
+            ctx.boxed::<PhysicalDeviceExclusiveScissorFeaturesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO => {
+            ctx.boxed::<PhysicalDeviceExternalBufferInfo>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO => {
+            ctx.boxed::<PhysicalDeviceExternalFenceInfo>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO => {
+            ctx.boxed::<PhysicalDeviceExternalImageFormatInfo>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceExternalMemoryHostPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO => {
+            ctx.boxed::<PhysicalDeviceExternalSemaphoreInfo>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_FEATURES_2 => {
+            ctx.boxed::<PhysicalDeviceFeatures2>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV => {
+            ctx.boxed::<PhysicalDeviceFragmentShaderBarycentricFeaturesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES => {
+            ctx.boxed::<PhysicalDeviceGroupProperties>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_ID_PROPERTIES => {
+            ctx.boxed::<PhysicalDeviceIDProperties>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT => {
+            ctx.boxed::<PhysicalDeviceImageDrmFormatModifierInfoEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2 => {
+            ctx.boxed::<PhysicalDeviceImageFormatInfo2>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT => {
+            ctx.boxed::<PhysicalDeviceInlineUniformBlockFeaturesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceInlineUniformBlockPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES => {
+            ctx.boxed::<PhysicalDeviceMaintenance3Properties>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2 => {
+            ctx.boxed::<PhysicalDeviceMemoryProperties2>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV => {
+            ctx.boxed::<PhysicalDeviceMeshShaderFeaturesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV => {
+            ctx.boxed::<PhysicalDeviceMeshShaderPropertiesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES => {
+            ctx.boxed::<PhysicalDeviceMultiviewFeatures>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX => {
+            ctx.boxed::<PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES => {
+            ctx.boxed::<PhysicalDeviceMultiviewProperties>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDevicePCIBusInfoPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES => {
+            ctx.boxed::<PhysicalDevicePointClippingProperties>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_PROPERTIES_2 => {
+            ctx.boxed::<PhysicalDeviceProperties2>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES => {
+            ctx.boxed::<PhysicalDeviceProtectedMemoryFeatures>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES => {
+            ctx.boxed::<PhysicalDeviceProtectedMemoryProperties>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR => {
+            ctx.boxed::<PhysicalDevicePushDescriptorPropertiesKHR>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV => {
+            ctx.boxed::<PhysicalDeviceRayTracingPropertiesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV => {
+            ctx.boxed::<PhysicalDeviceRepresentativeFragmentTestFeaturesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceSamplerFilterMinmaxPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES => {
+            ctx.boxed::<PhysicalDeviceSamplerYcbcrConversionFeatures>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceSampleLocationsPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR => {
+            ctx.boxed::<PhysicalDeviceShaderAtomicInt64FeaturesKHR>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD => {
+            ctx.boxed::<PhysicalDeviceShaderCorePropertiesAMD>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES => {
+            ctx.boxed::<PhysicalDeviceShaderDrawParameterFeatures>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV => {
+            ctx.boxed::<PhysicalDeviceShaderImageFootprintFeaturesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV => {
+            ctx.boxed::<PhysicalDeviceShadingRateImageFeaturesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV => {
+            ctx.boxed::<PhysicalDeviceShadingRateImagePropertiesNV>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2 => {
+            ctx.boxed::<PhysicalDeviceSparseImageFormatInfo2>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES => {
+            ctx.boxed::<PhysicalDeviceSubgroupProperties>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR => {
+            ctx.boxed::<PhysicalDeviceSurfaceInfo2KHR>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT => {
+            ctx.boxed::<PhysicalDeviceTransformFeedbackFeaturesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceTransformFeedbackPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES => {
+            ctx.boxed::<PhysicalDeviceVariablePointerFeatures>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT => {
+            ctx.boxed::<PhysicalDeviceVertexAttributeDivisorFeaturesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT => {
+            ctx.boxed::<PhysicalDeviceVertexAttributeDivisorPropertiesEXT>(r)? as *const c_void
+        }
+        StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR => {
+            ctx.boxed::<PhysicalDeviceVulkanMemoryModelFeaturesKHR>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_CACHE_CREATE_INFO => {
+            ctx.boxed::<PipelineCacheCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT => {
+            ctx.boxed::<PipelineColorBlendAdvancedStateCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineColorBlendStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV => {
+            ctx.boxed::<PipelineCoverageModulationStateCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV => {
+            ctx.boxed::<PipelineCoverageToColorStateCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineDepthStencilStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT => {
+            ctx.boxed::<PipelineDiscardRectangleStateCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineDynamicStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineInputAssemblyStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_LAYOUT_CREATE_INFO => {
+            ctx.boxed::<PipelineLayoutCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineMultisampleStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT => {
+            ctx.boxed::<PipelineRasterizationConservativeStateCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineRasterizationStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD => {
+            ctx.boxed::<PipelineRasterizationStateRasterizationOrderAMD>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT => {
+            ctx.boxed::<PipelineRasterizationStateStreamCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV => {
+            ctx.boxed::<PipelineRepresentativeFragmentTestStateCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT => {
+            ctx.boxed::<PipelineSampleLocationsStateCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO => {
+            ctx.boxed::<PipelineShaderStageCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineTessellationDomainOriginStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineTessellationStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT => {
+            ctx.boxed::<PipelineVertexInputDivisorStateCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineVertexInputStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV => {
+            ctx.boxed::<PipelineViewportCoarseSampleOrderStateCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV => {
+            ctx.boxed::<PipelineViewportExclusiveScissorStateCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV => {
+            ctx.boxed::<PipelineViewportShadingRateImageStateCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO => {
+            ctx.boxed::<PipelineViewportStateCreateInfo>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV => {
+            ctx.boxed::<PipelineViewportSwizzleStateCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV => {
+            ctx.boxed::<PipelineViewportWScalingStateCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::PRESENT_INFO_KHR => ctx.boxed::<PresentInfoKHR>(r)? as *const c_void,
+        StructureType::PRESENT_REGIONS_KHR => ctx.boxed::<PresentRegionsKHR>(r)? as *const c_void,
+        StructureType::PRESENT_TIMES_INFO_GOOGLE => {
+            ctx.boxed::<PresentTimesInfoGOOGLE>(r)? as *const c_void
+        }
+        StructureType::PROTECTED_SUBMIT_INFO => {
+            ctx.boxed::<ProtectedSubmitInfo>(r)? as *const c_void
+        }
+        StructureType::QUERY_POOL_CREATE_INFO => {
+            ctx.boxed::<QueryPoolCreateInfo>(r)? as *const c_void
+        }
+        StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV => {
+            ctx.boxed::<QueueFamilyCheckpointPropertiesNV>(r)? as *const c_void
+        }
+        StructureType::QUEUE_FAMILY_PROPERTIES_2 => {
+            ctx.boxed::<QueueFamilyProperties2>(r)? as *const c_void
+        }
+        StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_NV => {
+            ctx.boxed::<RayTracingPipelineCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV => {
+            ctx.boxed::<RayTracingShaderGroupCreateInfoNV>(r)? as *const c_void
+        }
+        StructureType::RENDER_PASS_BEGIN_INFO => {
+            ctx.boxed::<RenderPassBeginInfo>(r)? as *const c_void
+        }
+        StructureType::RENDER_PASS_CREATE_INFO => {
+            ctx.boxed::<RenderPassCreateInfo>(r)? as *const c_void
+        }
+        StructureType::RENDER_PASS_CREATE_INFO_2_KHR => {
+            ctx.boxed::<RenderPassCreateInfo2KHR>(r)? as *const c_void
+        }
+        StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO => {
+            ctx.boxed::<RenderPassInputAttachmentAspectCreateInfo>(r)? as *const c_void
+        }
+        StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO => {
+            ctx.boxed::<RenderPassMultiviewCreateInfo>(r)? as *const c_void
+        }
+        StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT => {
+            ctx.boxed::<RenderPassSampleLocationsBeginInfoEXT>(r)? as *const c_void
+        }
+        StructureType::SAMPLER_CREATE_INFO => ctx.boxed::<SamplerCreateInfo>(r)? as *const c_void,
+        StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT => {
+            ctx.boxed::<SamplerReductionModeCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO => {
+            ctx.boxed::<SamplerYcbcrConversionCreateInfo>(r)? as *const c_void
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES => {
+            ctx.boxed::<SamplerYcbcrConversionImageFormatProperties>(r)? as *const c_void
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_INFO => {
+            ctx.boxed::<SamplerYcbcrConversionInfo>(r)? as *const c_void
+        }
+        StructureType::SAMPLE_LOCATIONS_INFO_EXT => {
+            ctx.boxed::<SampleLocationsInfoEXT>(r)? as *const c_void
+        }
+        StructureType::SEMAPHORE_CREATE_INFO => {
+            ctx.boxed::<SemaphoreCreateInfo>(r)? as *const c_void
+        }
+        StructureType::SEMAPHORE_GET_FD_INFO_KHR => {
+            ctx.boxed::<SemaphoreGetFdInfoKHR>(r)? as *const c_void
+        }
+        StructureType::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR => {
+            ctx.boxed::<SemaphoreGetWin32HandleInfoKHR>(r)? as *const c_void
+        }
+        StructureType::SHADER_MODULE_CREATE_INFO => {
+            ctx.boxed::<ShaderModuleCreateInfo>(r)? as *const c_void
+        }
+        StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT => {
+            ctx.boxed::<ShaderModuleValidationCacheCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR => {
+            ctx.boxed::<SharedPresentSurfaceCapabilitiesKHR>(r)? as *const c_void
+        }
+        StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2 => {
+            ctx.boxed::<SparseImageFormatProperties2>(r)? as *const c_void
+        }
+        StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2 => {
+            ctx.boxed::<SparseImageMemoryRequirements2>(r)? as *const c_void
+        }
+        StructureType::SUBMIT_INFO => ctx.boxed::<SubmitInfo>(r)? as *const c_void,
+        StructureType::SUBPASS_BEGIN_INFO_KHR => {
+            ctx.boxed::<SubpassBeginInfoKHR>(r)? as *const c_void
+        }
+        StructureType::SUBPASS_DEPENDENCY_2_KHR => {
+            ctx.boxed::<SubpassDependency2KHR>(r)? as *const c_void
+        }
+        StructureType::SUBPASS_DESCRIPTION_2_KHR => {
+            ctx.boxed::<SubpassDescription2KHR>(r)? as *const c_void
+        }
+        StructureType::SUBPASS_END_INFO_KHR => ctx.boxed::<SubpassEndInfoKHR>(r)? as *const c_void,
+        StructureType::SURFACE_CAPABILITIES_2_EXT => {
+            ctx.boxed::<SurfaceCapabilities2EXT>(r)? as *const c_void
+        }
+        StructureType::SURFACE_CAPABILITIES_2_KHR => {
+            ctx.boxed::<SurfaceCapabilities2KHR>(r)? as *const c_void
+        }
+        StructureType::SURFACE_FORMAT_2_KHR => ctx.boxed::<SurfaceFormat2KHR>(r)? as *const c_void,
+        StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT => {
+            ctx.boxed::<SwapchainCounterCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::SWAPCHAIN_CREATE_INFO_KHR => {
+            ctx.boxed::<SwapchainCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD => {
+            ctx.boxed::<TextureLODGatherFormatPropertiesAMD>(r)? as *const c_void
+        }
+        StructureType::VALIDATION_CACHE_CREATE_INFO_EXT => {
+            ctx.boxed::<ValidationCacheCreateInfoEXT>(r)? as *const c_void
+        }
+        StructureType::VALIDATION_FLAGS_EXT => ctx.boxed::<ValidationFlagsEXT>(r)? as *const c_void,
+        StructureType::VI_SURFACE_CREATE_INFO_NN => {
+            ctx.boxed::<ViSurfaceCreateInfoNN>(r)? as *const c_void
+        }
+        StructureType::WAYLAND_SURFACE_CREATE_INFO_KHR => {
+            ctx.boxed::<WaylandSurfaceCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR => {
+            ctx.boxed::<Win32KeyedMutexAcquireReleaseInfoKHR>(r)? as *const c_void
+        }
+        StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV => {
+            ctx.boxed::<Win32KeyedMutexAcquireReleaseInfoNV>(r)? as *const c_void
+        }
+        StructureType::WIN32_SURFACE_CREATE_INFO_KHR => {
+            ctx.boxed::<Win32SurfaceCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::WRITE_DESCRIPTOR_SET => ctx.boxed::<WriteDescriptorSet>(r)? as *const c_void,
+        StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV => {
+            ctx.boxed::<WriteDescriptorSetAccelerationStructureNV>(r)? as *const c_void
+        }
+        StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT => {
+            ctx.boxed::<WriteDescriptorSetInlineUniformBlockEXT>(r)? as *const c_void
+        }
+        StructureType::XCB_SURFACE_CREATE_INFO_KHR => {
+            ctx.boxed::<XcbSurfaceCreateInfoKHR>(r)? as *const c_void
+        }
+        StructureType::XLIB_SURFACE_CREATE_INFO_KHR => {
+            ctx.boxed::<XlibSurfaceCreateInfoKHR>(r)? as *const c_void
+        }
+        _ => return Err(ReplayError::Invalid("unknown structure type")),
+    })
+}
+unsafe fn acquire_image_android(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let native_fence_fd = Decode::decode(r, ctx)?;
+    let semaphore = Decode::decode(r, ctx)?;
+    let fence = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkAcquireImageANDROID = ctx.fp("vkAcquireImageANDROID")?;
+    let result = fp(device, image, native_fence_fd, semaphore, fence);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn acquire_next_image2_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_acquire_info = ctx.ptr(r)?;
+    let p_image_index = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkAcquireNextImage2KHR = ctx.fp("vkAcquireNextImage2KHR")?;
+    let result = fp(device, p_acquire_info, p_image_index);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn acquire_next_image_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain = Decode::decode(r, ctx)?;
+    let timeout = Decode::decode(r, ctx)?;
+    let semaphore = Decode::decode(r, ctx)?;
+    let fence = Decode::decode(r, ctx)?;
+    let p_image_index = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkAcquireNextImageKHR = ctx.fp("vkAcquireNextImageKHR")?;
+    let result = fp(device, swapchain, timeout, semaphore, fence, p_image_index);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn allocate_command_buffers(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_allocate_info = ctx.ptr(r)?;
+    let p_command_buffers = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkAllocateCommandBuffers = ctx.fp("vkAllocateCommandBuffers")?;
+    let result = fp(device, p_allocate_info, p_command_buffers);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn allocate_descriptor_sets(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_allocate_info = ctx.ptr(r)?;
+    let p_descriptor_sets = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkAllocateDescriptorSets = ctx.fp("vkAllocateDescriptorSets")?;
+    let result = fp(device, p_allocate_info, p_descriptor_sets);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn allocate_memory(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_allocate_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_memory = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkAllocateMemory = ctx.fp("vkAllocateMemory")?;
+    let result = fp(device, p_allocate_info, p_allocator, p_memory);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn begin_command_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_begin_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkBeginCommandBuffer = ctx.fp("vkBeginCommandBuffer")?;
+    let result = fp(command_buffer, p_begin_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn bind_acceleration_structure_memory_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let bind_info_count = Decode::decode(r, ctx)?;
+    let p_bind_infos = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkBindAccelerationStructureMemoryNV =
+        ctx.fp("vkBindAccelerationStructureMemoryNV")?;
+    let result = fp(device, bind_info_count, p_bind_infos);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn bind_buffer_memory(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let memory = Decode::decode(r, ctx)?;
+    let memory_offset = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkBindBufferMemory = ctx.fp("vkBindBufferMemory")?;
+    let result = fp(device, buffer, memory, memory_offset);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn bind_buffer_memory2(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let bind_info_count = Decode::decode(r, ctx)?;
+    let p_bind_infos = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkBindBufferMemory2 = ctx.fp("vkBindBufferMemory2")?;
+    let result = fp(device, bind_info_count, p_bind_infos);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn bind_image_memory(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let memory = Decode::decode(r, ctx)?;
+    let memory_offset = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkBindImageMemory = ctx.fp("vkBindImageMemory")?;
+    let result = fp(device, image, memory, memory_offset);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn bind_image_memory2(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let bind_info_count = Decode::decode(r, ctx)?;
+    let p_bind_infos = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkBindImageMemory2 = ctx.fp("vkBindImageMemory2")?;
+    let result = fp(device, bind_info_count, p_bind_infos);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn cmd_begin_conditional_rendering_ext(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_conditional_rendering_begin = ctx.ptr(r)?;
+    let fp: PFN_vkCmdBeginConditionalRenderingEXT = ctx.fp("vkCmdBeginConditionalRenderingEXT")?;
+    fp(command_buffer, p_conditional_rendering_begin);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_begin_debug_utils_label_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_label_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdBeginDebugUtilsLabelEXT = ctx.fp("vkCmdBeginDebugUtilsLabelEXT")?;
+    fp(command_buffer, p_label_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_begin_query(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let query = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdBeginQuery = ctx.fp("vkCmdBeginQuery")?;
+    fp(command_buffer, query_pool, query, flags);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_begin_query_indexed_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let query = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let index = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdBeginQueryIndexedEXT = ctx.fp("vkCmdBeginQueryIndexedEXT")?;
+    fp(command_buffer, query_pool, query, flags, index);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_begin_render_pass(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_render_pass_begin = ctx.ptr(r)?;
+    let contents = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdBeginRenderPass = ctx.fp("vkCmdBeginRenderPass")?;
+    fp(command_buffer, p_render_pass_begin, contents);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_begin_render_pass2_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_render_pass_begin = ctx.ptr(r)?;
+    let p_subpass_begin_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdBeginRenderPass2KHR = ctx.fp("vkCmdBeginRenderPass2KHR")?;
+    fp(command_buffer, p_render_pass_begin, p_subpass_begin_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_begin_transform_feedback_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_counter_buffer = Decode::decode(r, ctx)?;
+    let counter_buffer_count = Decode::decode(r, ctx)?;
+    let p_counter_buffers = ctx.array(r)?;
+    let p_counter_buffer_offsets = ctx.array(r)?;
+    let fp: PFN_vkCmdBeginTransformFeedbackEXT = ctx.fp("vkCmdBeginTransformFeedbackEXT")?;
+    fp(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_bind_descriptor_sets(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let pipeline_bind_point = Decode::decode(r, ctx)?;
+    let layout = Decode::decode(r, ctx)?;
+    let first_set = Decode::decode(r, ctx)?;
+    let descriptor_set_count = Decode::decode(r, ctx)?;
+    let p_descriptor_sets = ctx.array(r)?;
+    let dynamic_offset_count = Decode::decode(r, ctx)?;
+    let p_dynamic_offsets = ctx.array(r)?;
+    let fp: PFN_vkCmdBindDescriptorSets = ctx.fp("vkCmdBindDescriptorSets")?;
+    fp(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        descriptor_set_count,
+        p_descriptor_sets,
+        dynamic_offset_count,
+        p_dynamic_offsets,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_bind_index_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let index_type = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdBindIndexBuffer = ctx.fp("vkCmdBindIndexBuffer")?;
+    fp(command_buffer, buffer, offset, index_type);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_bind_pipeline(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let pipeline_bind_point = Decode::decode(r, ctx)?;
+    let pipeline = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdBindPipeline = ctx.fp("vkCmdBindPipeline")?;
+    fp(command_buffer, pipeline_bind_point, pipeline);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_bind_shading_rate_image_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let image_view = Decode::decode(r, ctx)?;
+    let image_layout = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdBindShadingRateImageNV = ctx.fp("vkCmdBindShadingRateImageNV")?;
+    fp(command_buffer, image_view, image_layout);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_bind_transform_feedback_buffers_ext(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_binding = Decode::decode(r, ctx)?;
+    let binding_count = Decode::decode(r, ctx)?;
+    let p_buffers = ctx.array(r)?;
+    let p_offsets = ctx.array(r)?;
+    let p_sizes = ctx.array(r)?;
+    let fp: PFN_vkCmdBindTransformFeedbackBuffersEXT =
+        ctx.fp("vkCmdBindTransformFeedbackBuffersEXT")?;
+    fp(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_bind_vertex_buffers(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_binding = Decode::decode(r, ctx)?;
+    let binding_count = Decode::decode(r, ctx)?;
+    let p_buffers = ctx.array(r)?;
+    let p_offsets = ctx.array(r)?;
+    let fp: PFN_vkCmdBindVertexBuffers = ctx.fp("vkCmdBindVertexBuffers")?;
+    fp(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_blit_image(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let src_image = Decode::decode(r, ctx)?;
+    let src_image_layout = Decode::decode(r, ctx)?;
+    let dst_image = Decode::decode(r, ctx)?;
+    let dst_image_layout = Decode::decode(r, ctx)?;
+    let region_count = Decode::decode(r, ctx)?;
+    let p_regions = ctx.array(r)?;
+    let filter = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdBlitImage = ctx.fp("vkCmdBlitImage")?;
+    fp(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+        filter,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_build_acceleration_structure_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_info = ctx.ptr(r)?;
+    let instance_data = Decode::decode(r, ctx)?;
+    let instance_offset = Decode::decode(r, ctx)?;
+    let update = Decode::decode(r, ctx)?;
+    let dst = Decode::decode(r, ctx)?;
+    let src = Decode::decode(r, ctx)?;
+    let scratch = Decode::decode(r, ctx)?;
+    let scratch_offset = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdBuildAccelerationStructureNV = ctx.fp("vkCmdBuildAccelerationStructureNV")?;
+    fp(
+        command_buffer,
+        p_info,
+        instance_data,
+        instance_offset,
+        update,
+        dst,
+        src,
+        scratch,
+        scratch_offset,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_clear_attachments(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let attachment_count = Decode::decode(r, ctx)?;
+    let p_attachments = ctx.array(r)?;
+    let rect_count = Decode::decode(r, ctx)?;
+    let p_rects = ctx.array(r)?;
+    let fp: PFN_vkCmdClearAttachments = ctx.fp("vkCmdClearAttachments")?;
+    fp(
+        command_buffer,
+        attachment_count,
+        p_attachments,
+        rect_count,
+        p_rects,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_clear_color_image(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let image_layout = Decode::decode(r, ctx)?;
+    let p_color = ctx.ptr(r)?;
+    let range_count = Decode::decode(r, ctx)?;
+    let p_ranges = ctx.array(r)?;
+    let fp: PFN_vkCmdClearColorImage = ctx.fp("vkCmdClearColorImage")?;
+    fp(
+        command_buffer,
+        image,
+        image_layout,
+        p_color,
+        range_count,
+        p_ranges,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_clear_depth_stencil_image(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let image_layout = Decode::decode(r, ctx)?;
+    let p_depth_stencil = ctx.ptr(r)?;
+    let range_count = Decode::decode(r, ctx)?;
+    let p_ranges = ctx.array(r)?;
+    let fp: PFN_vkCmdClearDepthStencilImage = ctx.fp("vkCmdClearDepthStencilImage")?;
+    fp(
+        command_buffer,
+        image,
+        image_layout,
+        p_depth_stencil,
+        range_count,
+        p_ranges,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_copy_acceleration_structure_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let dst = Decode::decode(r, ctx)?;
+    let src = Decode::decode(r, ctx)?;
+    let mode = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdCopyAccelerationStructureNV = ctx.fp("vkCmdCopyAccelerationStructureNV")?;
+    fp(command_buffer, dst, src, mode);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_copy_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let src_buffer = Decode::decode(r, ctx)?;
+    let dst_buffer = Decode::decode(r, ctx)?;
+    let region_count = Decode::decode(r, ctx)?;
+    let p_regions = ctx.array(r)?;
+    let fp: PFN_vkCmdCopyBuffer = ctx.fp("vkCmdCopyBuffer")?;
+    fp(
+        command_buffer,
+        src_buffer,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_copy_buffer_to_image(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let src_buffer = Decode::decode(r, ctx)?;
+    let dst_image = Decode::decode(r, ctx)?;
+    let dst_image_layout = Decode::decode(r, ctx)?;
+    let region_count = Decode::decode(r, ctx)?;
+    let p_regions = ctx.array(r)?;
+    let fp: PFN_vkCmdCopyBufferToImage = ctx.fp("vkCmdCopyBufferToImage")?;
+    fp(
+        command_buffer,
+        src_buffer,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_copy_image(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let src_image = Decode::decode(r, ctx)?;
+    let src_image_layout = Decode::decode(r, ctx)?;
+    let dst_image = Decode::decode(r, ctx)?;
+    let dst_image_layout = Decode::decode(r, ctx)?;
+    let region_count = Decode::decode(r, ctx)?;
+    let p_regions = ctx.array(r)?;
+    let fp: PFN_vkCmdCopyImage = ctx.fp("vkCmdCopyImage")?;
+    fp(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_copy_image_to_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let src_image = Decode::decode(r, ctx)?;
+    let src_image_layout = Decode::decode(r, ctx)?;
+    let dst_buffer = Decode::decode(r, ctx)?;
+    let region_count = Decode::decode(r, ctx)?;
+    let p_regions = ctx.array(r)?;
+    let fp: PFN_vkCmdCopyImageToBuffer = ctx.fp("vkCmdCopyImageToBuffer")?;
+    fp(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_copy_query_pool_results(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let first_query = Decode::decode(r, ctx)?;
+    let query_count = Decode::decode(r, ctx)?;
+    let dst_buffer = Decode::decode(r, ctx)?;
+    let dst_offset = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdCopyQueryPoolResults = ctx.fp("vkCmdCopyQueryPoolResults")?;
+    fp(
+        command_buffer,
+        query_pool,
+        first_query,
+        query_count,
+        dst_buffer,
+        dst_offset,
+        stride,
+        flags,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_debug_marker_begin_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_marker_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdDebugMarkerBeginEXT = ctx.fp("vkCmdDebugMarkerBeginEXT")?;
+    fp(command_buffer, p_marker_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_debug_marker_end_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDebugMarkerEndEXT = ctx.fp("vkCmdDebugMarkerEndEXT")?;
+    fp(command_buffer);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_debug_marker_insert_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_marker_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdDebugMarkerInsertEXT = ctx.fp("vkCmdDebugMarkerInsertEXT")?;
+    fp(command_buffer, p_marker_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_dispatch(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let group_count_x = Decode::decode(r, ctx)?;
+    let group_count_y = Decode::decode(r, ctx)?;
+    let group_count_z = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDispatch = ctx.fp("vkCmdDispatch")?;
+    fp(command_buffer, group_count_x, group_count_y, group_count_z);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_dispatch_base(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let base_group_x = Decode::decode(r, ctx)?;
+    let base_group_y = Decode::decode(r, ctx)?;
+    let base_group_z = Decode::decode(r, ctx)?;
+    let group_count_x = Decode::decode(r, ctx)?;
+    let group_count_y = Decode::decode(r, ctx)?;
+    let group_count_z = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDispatchBase = ctx.fp("vkCmdDispatchBase")?;
+    fp(
+        command_buffer,
+        base_group_x,
+        base_group_y,
+        base_group_z,
+        group_count_x,
+        group_count_y,
+        group_count_z,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_dispatch_indirect(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDispatchIndirect = ctx.fp("vkCmdDispatchIndirect")?;
+    fp(command_buffer, buffer, offset);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let vertex_count = Decode::decode(r, ctx)?;
+    let instance_count = Decode::decode(r, ctx)?;
+    let first_vertex = Decode::decode(r, ctx)?;
+    let first_instance = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDraw = ctx.fp("vkCmdDraw")?;
+    fp(
+        command_buffer,
+        vertex_count,
+        instance_count,
+        first_vertex,
+        first_instance,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_indexed(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let index_count = Decode::decode(r, ctx)?;
+    let instance_count = Decode::decode(r, ctx)?;
+    let first_index = Decode::decode(r, ctx)?;
+    let vertex_offset = Decode::decode(r, ctx)?;
+    let first_instance = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawIndexed = ctx.fp("vkCmdDrawIndexed")?;
+    fp(
+        command_buffer,
+        index_count,
+        instance_count,
+        first_index,
+        vertex_offset,
+        first_instance,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_indexed_indirect(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let draw_count = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawIndexedIndirect = ctx.fp("vkCmdDrawIndexedIndirect")?;
+    fp(command_buffer, buffer, offset, draw_count, stride);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_indexed_indirect_count_amd(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let count_buffer = Decode::decode(r, ctx)?;
+    let count_buffer_offset = Decode::decode(r, ctx)?;
+    let max_draw_count = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawIndexedIndirectCountAMD = ctx.fp("vkCmdDrawIndexedIndirectCountAMD")?;
+    fp(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_indexed_indirect_count_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let count_buffer = Decode::decode(r, ctx)?;
+    let count_buffer_offset = Decode::decode(r, ctx)?;
+    let max_draw_count = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawIndexedIndirectCountKHR = ctx.fp("vkCmdDrawIndexedIndirectCountKHR")?;
+    fp(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_indirect(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let draw_count = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawIndirect = ctx.fp("vkCmdDrawIndirect")?;
+    fp(command_buffer, buffer, offset, draw_count, stride);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_indirect_byte_count_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let instance_count = Decode::decode(r, ctx)?;
+    let first_instance = Decode::decode(r, ctx)?;
+    let counter_buffer = Decode::decode(r, ctx)?;
+    let counter_buffer_offset = Decode::decode(r, ctx)?;
+    let counter_offset = Decode::decode(r, ctx)?;
+    let vertex_stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawIndirectByteCountEXT = ctx.fp("vkCmdDrawIndirectByteCountEXT")?;
+    fp(
+        command_buffer,
+        instance_count,
+        first_instance,
+        counter_buffer,
+        counter_buffer_offset,
+        counter_offset,
+        vertex_stride,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_indirect_count_amd(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let count_buffer = Decode::decode(r, ctx)?;
+    let count_buffer_offset = Decode::decode(r, ctx)?;
+    let max_draw_count = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawIndirectCountAMD = ctx.fp("vkCmdDrawIndirectCountAMD")?;
+    fp(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_indirect_count_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let count_buffer = Decode::decode(r, ctx)?;
+    let count_buffer_offset = Decode::decode(r, ctx)?;
+    let max_draw_count = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawIndirectCountKHR = ctx.fp("vkCmdDrawIndirectCountKHR")?;
+    fp(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_mesh_tasks_indirect_count_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let count_buffer = Decode::decode(r, ctx)?;
+    let count_buffer_offset = Decode::decode(r, ctx)?;
+    let max_draw_count = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawMeshTasksIndirectCountNV = ctx.fp("vkCmdDrawMeshTasksIndirectCountNV")?;
+    fp(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_mesh_tasks_indirect_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let draw_count = Decode::decode(r, ctx)?;
+    let stride = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawMeshTasksIndirectNV = ctx.fp("vkCmdDrawMeshTasksIndirectNV")?;
+    fp(command_buffer, buffer, offset, draw_count, stride);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_draw_mesh_tasks_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let task_count = Decode::decode(r, ctx)?;
+    let first_task = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdDrawMeshTasksNV = ctx.fp("vkCmdDrawMeshTasksNV")?;
+    fp(command_buffer, task_count, first_task);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_end_conditional_rendering_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdEndConditionalRenderingEXT = ctx.fp("vkCmdEndConditionalRenderingEXT")?;
+    fp(command_buffer);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_end_debug_utils_label_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdEndDebugUtilsLabelEXT = ctx.fp("vkCmdEndDebugUtilsLabelEXT")?;
+    fp(command_buffer);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_end_query(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let query = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdEndQuery = ctx.fp("vkCmdEndQuery")?;
+    fp(command_buffer, query_pool, query);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_end_query_indexed_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let query = Decode::decode(r, ctx)?;
+    let index = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdEndQueryIndexedEXT = ctx.fp("vkCmdEndQueryIndexedEXT")?;
+    fp(command_buffer, query_pool, query, index);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_end_render_pass(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdEndRenderPass = ctx.fp("vkCmdEndRenderPass")?;
+    fp(command_buffer);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_end_render_pass2_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_subpass_end_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdEndRenderPass2KHR = ctx.fp("vkCmdEndRenderPass2KHR")?;
+    fp(command_buffer, p_subpass_end_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_end_transform_feedback_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_counter_buffer = Decode::decode(r, ctx)?;
+    let counter_buffer_count = Decode::decode(r, ctx)?;
+    let p_counter_buffers = ctx.array(r)?;
+    let p_counter_buffer_offsets = ctx.array(r)?;
+    let fp: PFN_vkCmdEndTransformFeedbackEXT = ctx.fp("vkCmdEndTransformFeedbackEXT")?;
+    fp(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_execute_commands(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let command_buffer_count = Decode::decode(r, ctx)?;
+    let p_command_buffers = ctx.array(r)?;
+    let fp: PFN_vkCmdExecuteCommands = ctx.fp("vkCmdExecuteCommands")?;
+    fp(command_buffer, command_buffer_count, p_command_buffers);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_fill_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let dst_buffer = Decode::decode(r, ctx)?;
+    let dst_offset = Decode::decode(r, ctx)?;
+    let size = Decode::decode(r, ctx)?;
+    let data = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdFillBuffer = ctx.fp("vkCmdFillBuffer")?;
+    fp(command_buffer, dst_buffer, dst_offset, size, data);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_insert_debug_utils_label_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_label_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdInsertDebugUtilsLabelEXT = ctx.fp("vkCmdInsertDebugUtilsLabelEXT")?;
+    fp(command_buffer, p_label_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_next_subpass(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let contents = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdNextSubpass = ctx.fp("vkCmdNextSubpass")?;
+    fp(command_buffer, contents);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_next_subpass2_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_subpass_begin_info = ctx.ptr(r)?;
+    let p_subpass_end_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdNextSubpass2KHR = ctx.fp("vkCmdNextSubpass2KHR")?;
+    fp(command_buffer, p_subpass_begin_info, p_subpass_end_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_pipeline_barrier(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let src_stage_mask = Decode::decode(r, ctx)?;
+    let dst_stage_mask = Decode::decode(r, ctx)?;
+    let dependency_flags = Decode::decode(r, ctx)?;
+    let memory_barrier_count = Decode::decode(r, ctx)?;
+    let p_memory_barriers = ctx.array(r)?;
+    let buffer_memory_barrier_count = Decode::decode(r, ctx)?;
+    let p_buffer_memory_barriers = ctx.array(r)?;
+    let image_memory_barrier_count = Decode::decode(r, ctx)?;
+    let p_image_memory_barriers = ctx.array(r)?;
+    let fp: PFN_vkCmdPipelineBarrier = ctx.fp("vkCmdPipelineBarrier")?;
+    fp(
+        command_buffer,
+        src_stage_mask,
+        dst_stage_mask,
+        dependency_flags,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_process_commands_nvx(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_process_commands_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdProcessCommandsNVX = ctx.fp("vkCmdProcessCommandsNVX")?;
+    fp(command_buffer, p_process_commands_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_push_constants(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let layout = Decode::decode(r, ctx)?;
+    let stage_flags = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let size = Decode::decode(r, ctx)?;
+    let p_values = ctx.bytes(r)?;
+    let fp: PFN_vkCmdPushConstants = ctx.fp("vkCmdPushConstants")?;
+    fp(command_buffer, layout, stage_flags, offset, size, p_values);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_push_descriptor_set_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let pipeline_bind_point = Decode::decode(r, ctx)?;
+    let layout = Decode::decode(r, ctx)?;
+    let set = Decode::decode(r, ctx)?;
+    let descriptor_write_count = Decode::decode(r, ctx)?;
+    let p_descriptor_writes = ctx.array(r)?;
+    let fp: PFN_vkCmdPushDescriptorSetKHR = ctx.fp("vkCmdPushDescriptorSetKHR")?;
+    fp(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        set,
+        descriptor_write_count,
+        p_descriptor_writes,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_reserve_space_for_commands_nvx(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_reserve_space_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdReserveSpaceForCommandsNVX = ctx.fp("vkCmdReserveSpaceForCommandsNVX")?;
+    fp(command_buffer, p_reserve_space_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_reset_event(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let event = Decode::decode(r, ctx)?;
+    let stage_mask = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdResetEvent = ctx.fp("vkCmdResetEvent")?;
+    fp(command_buffer, event, stage_mask);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_reset_query_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let first_query = Decode::decode(r, ctx)?;
+    let query_count = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdResetQueryPool = ctx.fp("vkCmdResetQueryPool")?;
+    fp(command_buffer, query_pool, first_query, query_count);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_resolve_image(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let src_image = Decode::decode(r, ctx)?;
+    let src_image_layout = Decode::decode(r, ctx)?;
+    let dst_image = Decode::decode(r, ctx)?;
+    let dst_image_layout = Decode::decode(r, ctx)?;
+    let region_count = Decode::decode(r, ctx)?;
+    let p_regions = ctx.array(r)?;
+    let fp: PFN_vkCmdResolveImage = ctx.fp("vkCmdResolveImage")?;
+    fp(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_blend_constants(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let blend_constants = ctx.ptr(r)?;
+    let fp: PFN_vkCmdSetBlendConstants = ctx.fp("vkCmdSetBlendConstants")?;
+    fp(command_buffer, blend_constants);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_checkpoint_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_checkpoint_marker = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetCheckpointNV = ctx.fp("vkCmdSetCheckpointNV")?;
+    fp(command_buffer, p_checkpoint_marker);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_coarse_sample_order_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let sample_order_type = Decode::decode(r, ctx)?;
+    let custom_sample_order_count = Decode::decode(r, ctx)?;
+    let p_custom_sample_orders = ctx.array(r)?;
+    let fp: PFN_vkCmdSetCoarseSampleOrderNV = ctx.fp("vkCmdSetCoarseSampleOrderNV")?;
+    fp(
+        command_buffer,
+        sample_order_type,
+        custom_sample_order_count,
+        p_custom_sample_orders,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_depth_bias(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let depth_bias_constant_factor = Decode::decode(r, ctx)?;
+    let depth_bias_clamp = Decode::decode(r, ctx)?;
+    let depth_bias_slope_factor = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetDepthBias = ctx.fp("vkCmdSetDepthBias")?;
+    fp(
+        command_buffer,
+        depth_bias_constant_factor,
+        depth_bias_clamp,
+        depth_bias_slope_factor,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_depth_bounds(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let min_depth_bounds = Decode::decode(r, ctx)?;
+    let max_depth_bounds = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetDepthBounds = ctx.fp("vkCmdSetDepthBounds")?;
+    fp(command_buffer, min_depth_bounds, max_depth_bounds);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_device_mask(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let device_mask = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetDeviceMask = ctx.fp("vkCmdSetDeviceMask")?;
+    fp(command_buffer, device_mask);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_discard_rectangle_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_discard_rectangle = Decode::decode(r, ctx)?;
+    let discard_rectangle_count = Decode::decode(r, ctx)?;
+    let p_discard_rectangles = ctx.array(r)?;
+    let fp: PFN_vkCmdSetDiscardRectangleEXT = ctx.fp("vkCmdSetDiscardRectangleEXT")?;
+    fp(
+        command_buffer,
+        first_discard_rectangle,
+        discard_rectangle_count,
+        p_discard_rectangles,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_event(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let event = Decode::decode(r, ctx)?;
+    let stage_mask = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetEvent = ctx.fp("vkCmdSetEvent")?;
+    fp(command_buffer, event, stage_mask);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_exclusive_scissor_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_exclusive_scissor = Decode::decode(r, ctx)?;
+    let exclusive_scissor_count = Decode::decode(r, ctx)?;
+    let p_exclusive_scissors = ctx.array(r)?;
+    let fp: PFN_vkCmdSetExclusiveScissorNV = ctx.fp("vkCmdSetExclusiveScissorNV")?;
+    fp(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissors,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_line_width(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let line_width = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetLineWidth = ctx.fp("vkCmdSetLineWidth")?;
+    fp(command_buffer, line_width);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_sample_locations_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let p_sample_locations_info = ctx.ptr(r)?;
+    let fp: PFN_vkCmdSetSampleLocationsEXT = ctx.fp("vkCmdSetSampleLocationsEXT")?;
+    fp(command_buffer, p_sample_locations_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_scissor(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_scissor = Decode::decode(r, ctx)?;
+    let scissor_count = Decode::decode(r, ctx)?;
+    let p_scissors = ctx.array(r)?;
+    let fp: PFN_vkCmdSetScissor = ctx.fp("vkCmdSetScissor")?;
+    fp(command_buffer, first_scissor, scissor_count, p_scissors);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_stencil_compare_mask(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let face_mask = Decode::decode(r, ctx)?;
+    let compare_mask = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetStencilCompareMask = ctx.fp("vkCmdSetStencilCompareMask")?;
+    fp(command_buffer, face_mask, compare_mask);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_stencil_reference(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let face_mask = Decode::decode(r, ctx)?;
+    let reference = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetStencilReference = ctx.fp("vkCmdSetStencilReference")?;
+    fp(command_buffer, face_mask, reference);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_stencil_write_mask(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let face_mask = Decode::decode(r, ctx)?;
+    let write_mask = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdSetStencilWriteMask = ctx.fp("vkCmdSetStencilWriteMask")?;
+    fp(command_buffer, face_mask, write_mask);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_viewport(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_viewport = Decode::decode(r, ctx)?;
+    let viewport_count = Decode::decode(r, ctx)?;
+    let p_viewports = ctx.array(r)?;
+    let fp: PFN_vkCmdSetViewport = ctx.fp("vkCmdSetViewport")?;
+    fp(command_buffer, first_viewport, viewport_count, p_viewports);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_viewport_shading_rate_palette_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_viewport = Decode::decode(r, ctx)?;
+    let viewport_count = Decode::decode(r, ctx)?;
+    let p_shading_rate_palettes = ctx.array(r)?;
+    let fp: PFN_vkCmdSetViewportShadingRatePaletteNV =
+        ctx.fp("vkCmdSetViewportShadingRatePaletteNV")?;
+    fp(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_shading_rate_palettes,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_set_viewport_w_scaling_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let first_viewport = Decode::decode(r, ctx)?;
+    let viewport_count = Decode::decode(r, ctx)?;
+    let p_viewport_w_scalings = ctx.array(r)?;
+    let fp: PFN_vkCmdSetViewportWScalingNV = ctx.fp("vkCmdSetViewportWScalingNV")?;
+    fp(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_w_scalings,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_trace_rays_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let raygen_shader_binding_table_buffer = Decode::decode(r, ctx)?;
+    let raygen_shader_binding_offset = Decode::decode(r, ctx)?;
+    let miss_shader_binding_table_buffer = Decode::decode(r, ctx)?;
+    let miss_shader_binding_offset = Decode::decode(r, ctx)?;
+    let miss_shader_binding_stride = Decode::decode(r, ctx)?;
+    let hit_shader_binding_table_buffer = Decode::decode(r, ctx)?;
+    let hit_shader_binding_offset = Decode::decode(r, ctx)?;
+    let hit_shader_binding_stride = Decode::decode(r, ctx)?;
+    let callable_shader_binding_table_buffer = Decode::decode(r, ctx)?;
+    let callable_shader_binding_offset = Decode::decode(r, ctx)?;
+    let callable_shader_binding_stride = Decode::decode(r, ctx)?;
+    let width = Decode::decode(r, ctx)?;
+    let height = Decode::decode(r, ctx)?;
+    let depth = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdTraceRaysNV = ctx.fp("vkCmdTraceRaysNV")?;
+    fp(
+        command_buffer,
+        raygen_shader_binding_table_buffer,
+        raygen_shader_binding_offset,
+        miss_shader_binding_table_buffer,
+        miss_shader_binding_offset,
+        miss_shader_binding_stride,
+        hit_shader_binding_table_buffer,
+        hit_shader_binding_offset,
+        hit_shader_binding_stride,
+        callable_shader_binding_table_buffer,
+        callable_shader_binding_offset,
+        callable_shader_binding_stride,
+        width,
+        height,
+        depth,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_update_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let dst_buffer = Decode::decode(r, ctx)?;
+    let dst_offset = Decode::decode(r, ctx)?;
+    let data_size = Decode::decode(r, ctx)?;
+    let p_data = ctx.bytes(r)?;
+    let fp: PFN_vkCmdUpdateBuffer = ctx.fp("vkCmdUpdateBuffer")?;
+    fp(command_buffer, dst_buffer, dst_offset, data_size, p_data);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_wait_events(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let event_count = Decode::decode(r, ctx)?;
+    let p_events = ctx.array(r)?;
+    let src_stage_mask = Decode::decode(r, ctx)?;
+    let dst_stage_mask = Decode::decode(r, ctx)?;
+    let memory_barrier_count = Decode::decode(r, ctx)?;
+    let p_memory_barriers = ctx.array(r)?;
+    let buffer_memory_barrier_count = Decode::decode(r, ctx)?;
+    let p_buffer_memory_barriers = ctx.array(r)?;
+    let image_memory_barrier_count = Decode::decode(r, ctx)?;
+    let p_image_memory_barriers = ctx.array(r)?;
+    let fp: PFN_vkCmdWaitEvents = ctx.fp("vkCmdWaitEvents")?;
+    fp(
+        command_buffer,
+        event_count,
+        p_events,
+        src_stage_mask,
+        dst_stage_mask,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_write_acceleration_structures_properties_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let acceleration_structure_count = Decode::decode(r, ctx)?;
+    let p_acceleration_structures = ctx.array(r)?;
+    let query_type = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let first_query = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdWriteAccelerationStructuresPropertiesNV =
+        ctx.fp("vkCmdWriteAccelerationStructuresPropertiesNV")?;
+    fp(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_write_buffer_marker_amd(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let pipeline_stage = Decode::decode(r, ctx)?;
+    let dst_buffer = Decode::decode(r, ctx)?;
+    let dst_offset = Decode::decode(r, ctx)?;
+    let marker = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdWriteBufferMarkerAMD = ctx.fp("vkCmdWriteBufferMarkerAMD")?;
+    fp(
+        command_buffer,
+        pipeline_stage,
+        dst_buffer,
+        dst_offset,
+        marker,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn cmd_write_timestamp(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let pipeline_stage = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let query = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCmdWriteTimestamp = ctx.fp("vkCmdWriteTimestamp")?;
+    fp(command_buffer, pipeline_stage, query_pool, query);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn compile_deferred_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline = Decode::decode(r, ctx)?;
+    let shader = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCompileDeferredNV = ctx.fp("vkCompileDeferredNV")?;
+    let result = fp(device, pipeline, shader);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_acceleration_structure_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_acceleration_structure = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateAccelerationStructureNV = ctx.fp("vkCreateAccelerationStructureNV")?;
+    let result = fp(device, p_create_info, p_allocator, p_acceleration_structure);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_buffer = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateBuffer = ctx.fp("vkCreateBuffer")?;
+    let result = fp(device, p_create_info, p_allocator, p_buffer);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_buffer_view(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_view = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateBufferView = ctx.fp("vkCreateBufferView")?;
+    let result = fp(device, p_create_info, p_allocator, p_view);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_command_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_command_pool = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateCommandPool = ctx.fp("vkCreateCommandPool")?;
+    let result = fp(device, p_create_info, p_allocator, p_command_pool);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_compute_pipelines(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline_cache = Decode::decode(r, ctx)?;
+    let create_info_count = Decode::decode(r, ctx)?;
+    let p_create_infos = ctx.array(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_pipelines = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateComputePipelines = ctx.fp("vkCreateComputePipelines")?;
+    let result = fp(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_descriptor_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_descriptor_pool = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateDescriptorPool = ctx.fp("vkCreateDescriptorPool")?;
+    let result = fp(device, p_create_info, p_allocator, p_descriptor_pool);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_descriptor_set_layout(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_set_layout = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateDescriptorSetLayout = ctx.fp("vkCreateDescriptorSetLayout")?;
+    let result = fp(device, p_create_info, p_allocator, p_set_layout);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_descriptor_update_template(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_descriptor_update_template = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateDescriptorUpdateTemplate = ctx.fp("vkCreateDescriptorUpdateTemplate")?;
+    let result = fp(
+        device,
+        p_create_info,
+        p_allocator,
+        p_descriptor_update_template,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_device(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_device = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateDevice = ctx.fp("vkCreateDevice")?;
+    let result = fp(physical_device, p_create_info, p_allocator, p_device);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_display_mode_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let display = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_mode = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateDisplayModeKHR = ctx.fp("vkCreateDisplayModeKHR")?;
+    let result = fp(physical_device, display, p_create_info, p_allocator, p_mode);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_display_plane_surface_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_surface = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateDisplayPlaneSurfaceKHR = ctx.fp("vkCreateDisplayPlaneSurfaceKHR")?;
+    let result = fp(instance, p_create_info, p_allocator, p_surface);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_event(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_event = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateEvent = ctx.fp("vkCreateEvent")?;
+    let result = fp(device, p_create_info, p_allocator, p_event);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_fence(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_fence = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateFence = ctx.fp("vkCreateFence")?;
+    let result = fp(device, p_create_info, p_allocator, p_fence);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_framebuffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_framebuffer = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateFramebuffer = ctx.fp("vkCreateFramebuffer")?;
+    let result = fp(device, p_create_info, p_allocator, p_framebuffer);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_graphics_pipelines(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline_cache = Decode::decode(r, ctx)?;
+    let create_info_count = Decode::decode(r, ctx)?;
+    let p_create_infos = ctx.array(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_pipelines = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateGraphicsPipelines = ctx.fp("vkCreateGraphicsPipelines")?;
+    let result = fp(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_image(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_image = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateImage = ctx.fp("vkCreateImage")?;
+    let result = fp(device, p_create_info, p_allocator, p_image);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_image_view(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_view = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateImageView = ctx.fp("vkCreateImageView")?;
+    let result = fp(device, p_create_info, p_allocator, p_view);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_indirect_commands_layout_nvx(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_indirect_commands_layout = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateIndirectCommandsLayoutNVX = ctx.fp("vkCreateIndirectCommandsLayoutNVX")?;
+    let result = fp(
+        device,
+        p_create_info,
+        p_allocator,
+        p_indirect_commands_layout,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_instance(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_instance = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateInstance = ctx.fp("vkCreateInstance")?;
+    let result = fp(p_create_info, p_allocator, p_instance);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_object_table_nvx(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_object_table = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateObjectTableNVX = ctx.fp("vkCreateObjectTableNVX")?;
+    let result = fp(device, p_create_info, p_allocator, p_object_table);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_pipeline_cache(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_pipeline_cache = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreatePipelineCache = ctx.fp("vkCreatePipelineCache")?;
+    let result = fp(device, p_create_info, p_allocator, p_pipeline_cache);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_pipeline_layout(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_pipeline_layout = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreatePipelineLayout = ctx.fp("vkCreatePipelineLayout")?;
+    let result = fp(device, p_create_info, p_allocator, p_pipeline_layout);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_query_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_query_pool = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateQueryPool = ctx.fp("vkCreateQueryPool")?;
+    let result = fp(device, p_create_info, p_allocator, p_query_pool);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_ray_tracing_pipelines_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline_cache = Decode::decode(r, ctx)?;
+    let create_info_count = Decode::decode(r, ctx)?;
+    let p_create_infos = ctx.array(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_pipelines = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateRayTracingPipelinesNV = ctx.fp("vkCreateRayTracingPipelinesNV")?;
+    let result = fp(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_render_pass(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_render_pass = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateRenderPass = ctx.fp("vkCreateRenderPass")?;
+    let result = fp(device, p_create_info, p_allocator, p_render_pass);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_render_pass2_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_render_pass = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateRenderPass2KHR = ctx.fp("vkCreateRenderPass2KHR")?;
+    let result = fp(device, p_create_info, p_allocator, p_render_pass);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_sampler(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_sampler = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateSampler = ctx.fp("vkCreateSampler")?;
+    let result = fp(device, p_create_info, p_allocator, p_sampler);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_sampler_ycbcr_conversion(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_ycbcr_conversion = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateSamplerYcbcrConversion = ctx.fp("vkCreateSamplerYcbcrConversion")?;
+    let result = fp(device, p_create_info, p_allocator, p_ycbcr_conversion);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_semaphore(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_semaphore = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateSemaphore = ctx.fp("vkCreateSemaphore")?;
+    let result = fp(device, p_create_info, p_allocator, p_semaphore);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_shader_module(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_shader_module = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateShaderModule = ctx.fp("vkCreateShaderModule")?;
+    let result = fp(device, p_create_info, p_allocator, p_shader_module);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_shared_swapchains_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain_count = Decode::decode(r, ctx)?;
+    let p_create_infos = ctx.array(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_swapchains = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateSharedSwapchainsKHR = ctx.fp("vkCreateSharedSwapchainsKHR")?;
+    let result = fp(
+        device,
+        swapchain_count,
+        p_create_infos,
+        p_allocator,
+        p_swapchains,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_swapchain_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_swapchain = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateSwapchainKHR = ctx.fp("vkCreateSwapchainKHR")?;
+    let result = fp(device, p_create_info, p_allocator, p_swapchain);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_validation_cache_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_validation_cache = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateValidationCacheEXT = ctx.fp("vkCreateValidationCacheEXT")?;
+    let result = fp(device, p_create_info, p_allocator, p_validation_cache);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn create_vi_surface_nn(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_surface = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkCreateViSurfaceNN = ctx.fp("vkCreateViSurfaceNN")?;
+    let result = fp(instance, p_create_info, p_allocator, p_surface);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn debug_marker_set_object_name_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_name_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkDebugMarkerSetObjectNameEXT = ctx.fp("vkDebugMarkerSetObjectNameEXT")?;
+    let result = fp(device, p_name_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn debug_marker_set_object_tag_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_tag_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkDebugMarkerSetObjectTagEXT = ctx.fp("vkDebugMarkerSetObjectTagEXT")?;
+    let result = fp(device, p_tag_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn debug_report_message_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let object_type = Decode::decode(r, ctx)?;
+    let object = Decode::decode(r, ctx)?;
+    let location = Decode::decode(r, ctx)?;
+    let message_code = Decode::decode(r, ctx)?;
+    let p_layer_prefix = ctx.str(r)?;
+    let p_message = ctx.str(r)?;
+    let fp: PFN_vkDebugReportMessageEXT = ctx.fp("vkDebugReportMessageEXT")?;
+    fp(
+        instance,
+        flags,
+        object_type,
+        object,
+        location,
+        message_code,
+        p_layer_prefix,
+        p_message,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_acceleration_structure_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let acceleration_structure = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyAccelerationStructureNV = ctx.fp("vkDestroyAccelerationStructureNV")?;
+    fp(device, acceleration_structure, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyBuffer = ctx.fp("vkDestroyBuffer")?;
+    fp(device, buffer, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_buffer_view(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let buffer_view = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyBufferView = ctx.fp("vkDestroyBufferView")?;
+    fp(device, buffer_view, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_command_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let command_pool = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyCommandPool = ctx.fp("vkDestroyCommandPool")?;
+    fp(device, command_pool, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_debug_report_callback_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let callback = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyDebugReportCallbackEXT = ctx.fp("vkDestroyDebugReportCallbackEXT")?;
+    fp(instance, callback, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_debug_utils_messenger_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let messenger = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyDebugUtilsMessengerEXT = ctx.fp("vkDestroyDebugUtilsMessengerEXT")?;
+    fp(instance, messenger, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_descriptor_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let descriptor_pool = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyDescriptorPool = ctx.fp("vkDestroyDescriptorPool")?;
+    fp(device, descriptor_pool, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_descriptor_set_layout(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let descriptor_set_layout = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyDescriptorSetLayout = ctx.fp("vkDestroyDescriptorSetLayout")?;
+    fp(device, descriptor_set_layout, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_descriptor_update_template(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let descriptor_update_template = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyDescriptorUpdateTemplate = ctx.fp("vkDestroyDescriptorUpdateTemplate")?;
+    fp(device, descriptor_update_template, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_device(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyDevice = ctx.fp("vkDestroyDevice")?;
+    fp(device, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_event(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let event = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyEvent = ctx.fp("vkDestroyEvent")?;
+    fp(device, event, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_fence(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let fence = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyFence = ctx.fp("vkDestroyFence")?;
+    fp(device, fence, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_framebuffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let framebuffer = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyFramebuffer = ctx.fp("vkDestroyFramebuffer")?;
+    fp(device, framebuffer, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_image(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyImage = ctx.fp("vkDestroyImage")?;
+    fp(device, image, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_image_view(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let image_view = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyImageView = ctx.fp("vkDestroyImageView")?;
+    fp(device, image_view, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_indirect_commands_layout_nvx(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let indirect_commands_layout = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyIndirectCommandsLayoutNVX =
+        ctx.fp("vkDestroyIndirectCommandsLayoutNVX")?;
+    fp(device, indirect_commands_layout, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_instance(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyInstance = ctx.fp("vkDestroyInstance")?;
+    fp(instance, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_object_table_nvx(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let object_table = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyObjectTableNVX = ctx.fp("vkDestroyObjectTableNVX")?;
+    fp(device, object_table, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_pipeline(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyPipeline = ctx.fp("vkDestroyPipeline")?;
+    fp(device, pipeline, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_pipeline_cache(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline_cache = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyPipelineCache = ctx.fp("vkDestroyPipelineCache")?;
+    fp(device, pipeline_cache, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_pipeline_layout(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline_layout = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyPipelineLayout = ctx.fp("vkDestroyPipelineLayout")?;
+    fp(device, pipeline_layout, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_query_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyQueryPool = ctx.fp("vkDestroyQueryPool")?;
+    fp(device, query_pool, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_render_pass(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let render_pass = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyRenderPass = ctx.fp("vkDestroyRenderPass")?;
+    fp(device, render_pass, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_sampler(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let sampler = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroySampler = ctx.fp("vkDestroySampler")?;
+    fp(device, sampler, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_sampler_ycbcr_conversion(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let ycbcr_conversion = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroySamplerYcbcrConversion = ctx.fp("vkDestroySamplerYcbcrConversion")?;
+    fp(device, ycbcr_conversion, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_semaphore(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let semaphore = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroySemaphore = ctx.fp("vkDestroySemaphore")?;
+    fp(device, semaphore, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_shader_module(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let shader_module = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyShaderModule = ctx.fp("vkDestroyShaderModule")?;
+    fp(device, shader_module, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_surface_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let surface = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroySurfaceKHR = ctx.fp("vkDestroySurfaceKHR")?;
+    fp(instance, surface, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_swapchain_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroySwapchainKHR = ctx.fp("vkDestroySwapchainKHR")?;
+    fp(device, swapchain, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn destroy_validation_cache_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let validation_cache = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkDestroyValidationCacheEXT = ctx.fp("vkDestroyValidationCacheEXT")?;
+    fp(device, validation_cache, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn device_wait_idle(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkDeviceWaitIdle = ctx.fp("vkDeviceWaitIdle")?;
+    let result = fp(device);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn display_power_control_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let display = Decode::decode(r, ctx)?;
+    let p_display_power_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkDisplayPowerControlEXT = ctx.fp("vkDisplayPowerControlEXT")?;
+    let result = fp(device, display, p_display_power_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn end_command_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkEndCommandBuffer = ctx.fp("vkEndCommandBuffer")?;
+    let result = fp(command_buffer);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn enumerate_device_extension_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_layer_name = ctx.str(r)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkEnumerateDeviceExtensionProperties =
+        ctx.fp("vkEnumerateDeviceExtensionProperties")?;
+    let result = fp(
+        physical_device,
+        p_layer_name,
+        p_property_count,
+        p_properties,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn enumerate_device_layer_properties(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkEnumerateDeviceLayerProperties = ctx.fp("vkEnumerateDeviceLayerProperties")?;
+    let result = fp(physical_device, p_property_count, p_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn enumerate_physical_device_groups(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let p_physical_device_group_count = ctx.out(r)?;
+    let p_physical_device_group_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkEnumeratePhysicalDeviceGroups = ctx.fp("vkEnumeratePhysicalDeviceGroups")?;
+    let result = fp(
+        instance,
+        p_physical_device_group_count,
+        p_physical_device_group_properties,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn enumerate_physical_devices(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let p_physical_device_count = ctx.out(r)?;
+    let p_physical_devices = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkEnumeratePhysicalDevices = ctx.fp("vkEnumeratePhysicalDevices")?;
+    let result = fp(instance, p_physical_device_count, p_physical_devices);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn flush_mapped_memory_ranges(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let memory_range_count = Decode::decode(r, ctx)?;
+    let p_memory_ranges = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkFlushMappedMemoryRanges = ctx.fp("vkFlushMappedMemoryRanges")?;
+    let result = fp(device, memory_range_count, p_memory_ranges);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn free_command_buffers(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let command_pool = Decode::decode(r, ctx)?;
+    let command_buffer_count = Decode::decode(r, ctx)?;
+    let p_command_buffers = ctx.array(r)?;
+    let fp: PFN_vkFreeCommandBuffers = ctx.fp("vkFreeCommandBuffers")?;
+    fp(
+        device,
+        command_pool,
+        command_buffer_count,
+        p_command_buffers,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn free_descriptor_sets(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let descriptor_pool = Decode::decode(r, ctx)?;
+    let descriptor_set_count = Decode::decode(r, ctx)?;
+    let p_descriptor_sets = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkFreeDescriptorSets = ctx.fp("vkFreeDescriptorSets")?;
+    let result = fp(
+        device,
+        descriptor_pool,
+        descriptor_set_count,
+        p_descriptor_sets,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn free_memory(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let memory = Decode::decode(r, ctx)?;
+    let p_allocator = ctx.allocator(r)?;
+    let fp: PFN_vkFreeMemory = ctx.fp("vkFreeMemory")?;
+    fp(device, memory, p_allocator);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_acceleration_structure_handle_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let acceleration_structure = Decode::decode(r, ctx)?;
+    let data_size = Decode::decode(r, ctx)?;
+    let p_data = ctx.out_bytes(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetAccelerationStructureHandleNV =
+        ctx.fp("vkGetAccelerationStructureHandleNV")?;
+    let result = fp(device, acceleration_structure, data_size, p_data);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_acceleration_structure_memory_requirements_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_info = ctx.ptr(r)?;
+    let p_memory_requirements = ctx.out(r)?;
+    let fp: PFN_vkGetAccelerationStructureMemoryRequirementsNV =
+        ctx.fp("vkGetAccelerationStructureMemoryRequirementsNV")?;
+    fp(device, p_info, p_memory_requirements);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_buffer_memory_requirements(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let buffer = Decode::decode(r, ctx)?;
+    let p_memory_requirements = ctx.out(r)?;
+    let fp: PFN_vkGetBufferMemoryRequirements = ctx.fp("vkGetBufferMemoryRequirements")?;
+    fp(device, buffer, p_memory_requirements);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_buffer_memory_requirements2(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_info = ctx.ptr(r)?;
+    let p_memory_requirements = ctx.out(r)?;
+    let fp: PFN_vkGetBufferMemoryRequirements2 = ctx.fp("vkGetBufferMemoryRequirements2")?;
+    fp(device, p_info, p_memory_requirements);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_calibrated_timestamps_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let timestamp_count = Decode::decode(r, ctx)?;
+    let p_timestamp_infos = ctx.array(r)?;
+    let p_timestamps = ctx.out_array(r)?;
+    let p_max_deviation = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetCalibratedTimestampsEXT = ctx.fp("vkGetCalibratedTimestampsEXT")?;
+    let result = fp(
+        device,
+        timestamp_count,
+        p_timestamp_infos,
+        p_timestamps,
+        p_max_deviation,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_descriptor_set_layout_support(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_create_info = ctx.ptr(r)?;
+    let p_support = ctx.out(r)?;
+    let fp: PFN_vkGetDescriptorSetLayoutSupport = ctx.fp("vkGetDescriptorSetLayoutSupport")?;
+    fp(device, p_create_info, p_support);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_device_group_peer_memory_features(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let heap_index = Decode::decode(r, ctx)?;
+    let local_device_index = Decode::decode(r, ctx)?;
+    let remote_device_index = Decode::decode(r, ctx)?;
+    let p_peer_memory_features = ctx.out(r)?;
+    let fp: PFN_vkGetDeviceGroupPeerMemoryFeatures =
+        ctx.fp("vkGetDeviceGroupPeerMemoryFeatures")?;
+    fp(
+        device,
+        heap_index,
+        local_device_index,
+        remote_device_index,
+        p_peer_memory_features,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_device_group_present_capabilities_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_device_group_present_capabilities = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetDeviceGroupPresentCapabilitiesKHR =
+        ctx.fp("vkGetDeviceGroupPresentCapabilitiesKHR")?;
+    let result = fp(device, p_device_group_present_capabilities);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_device_group_surface_present_modes_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let surface = Decode::decode(r, ctx)?;
+    let p_modes = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetDeviceGroupSurfacePresentModesKHR =
+        ctx.fp("vkGetDeviceGroupSurfacePresentModesKHR")?;
+    let result = fp(device, surface, p_modes);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_device_memory_commitment(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let memory = Decode::decode(r, ctx)?;
+    let p_committed_memory_in_bytes = ctx.out(r)?;
+    let fp: PFN_vkGetDeviceMemoryCommitment = ctx.fp("vkGetDeviceMemoryCommitment")?;
+    fp(device, memory, p_committed_memory_in_bytes);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_device_queue(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let queue_family_index = Decode::decode(r, ctx)?;
+    let queue_index = Decode::decode(r, ctx)?;
+    let p_queue = ctx.out(r)?;
+    let fp: PFN_vkGetDeviceQueue = ctx.fp("vkGetDeviceQueue")?;
+    fp(device, queue_family_index, queue_index, p_queue);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_device_queue2(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_queue_info = ctx.ptr(r)?;
+    let p_queue = ctx.out(r)?;
+    let fp: PFN_vkGetDeviceQueue2 = ctx.fp("vkGetDeviceQueue2")?;
+    fp(device, p_queue_info, p_queue);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_display_mode_properties2_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let display = Decode::decode(r, ctx)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetDisplayModeProperties2KHR = ctx.fp("vkGetDisplayModeProperties2KHR")?;
+    let result = fp(physical_device, display, p_property_count, p_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_display_mode_properties_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let display = Decode::decode(r, ctx)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetDisplayModePropertiesKHR = ctx.fp("vkGetDisplayModePropertiesKHR")?;
+    let result = fp(physical_device, display, p_property_count, p_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_display_plane_capabilities2_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_display_plane_info = ctx.ptr(r)?;
+    let p_capabilities = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetDisplayPlaneCapabilities2KHR = ctx.fp("vkGetDisplayPlaneCapabilities2KHR")?;
+    let result = fp(physical_device, p_display_plane_info, p_capabilities);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_display_plane_capabilities_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let mode = Decode::decode(r, ctx)?;
+    let plane_index = Decode::decode(r, ctx)?;
+    let p_capabilities = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetDisplayPlaneCapabilitiesKHR = ctx.fp("vkGetDisplayPlaneCapabilitiesKHR")?;
+    let result = fp(physical_device, mode, plane_index, p_capabilities);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_display_plane_supported_displays_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let plane_index = Decode::decode(r, ctx)?;
+    let p_display_count = ctx.out(r)?;
+    let p_displays = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetDisplayPlaneSupportedDisplaysKHR =
+        ctx.fp("vkGetDisplayPlaneSupportedDisplaysKHR")?;
+    let result = fp(physical_device, plane_index, p_display_count, p_displays);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_event_status(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let event = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetEventStatus = ctx.fp("vkGetEventStatus")?;
+    let result = fp(device, event);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_fence_fd_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_get_fd_info = ctx.ptr(r)?;
+    let p_fd = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetFenceFdKHR = ctx.fp("vkGetFenceFdKHR")?;
+    let result = fp(device, p_get_fd_info, p_fd);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_fence_status(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let fence = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetFenceStatus = ctx.fp("vkGetFenceStatus")?;
+    let result = fp(device, fence);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_fence_win32_handle_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_get_win32_handle_info = ctx.ptr(r)?;
+    let p_handle = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetFenceWin32HandleKHR = ctx.fp("vkGetFenceWin32HandleKHR")?;
+    let result = fp(device, p_get_win32_handle_info, p_handle);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_image_drm_format_modifier_properties_ext(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let p_properties = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetImageDrmFormatModifierPropertiesEXT =
+        ctx.fp("vkGetImageDrmFormatModifierPropertiesEXT")?;
+    let result = fp(device, image, p_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_image_memory_requirements(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let p_memory_requirements = ctx.out(r)?;
+    let fp: PFN_vkGetImageMemoryRequirements = ctx.fp("vkGetImageMemoryRequirements")?;
+    fp(device, image, p_memory_requirements);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_image_memory_requirements2(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_info = ctx.ptr(r)?;
+    let p_memory_requirements = ctx.out(r)?;
+    let fp: PFN_vkGetImageMemoryRequirements2 = ctx.fp("vkGetImageMemoryRequirements2")?;
+    fp(device, p_info, p_memory_requirements);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_image_sparse_memory_requirements(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let p_sparse_memory_requirement_count = ctx.out(r)?;
+    let p_sparse_memory_requirements = ctx.out_array(r)?;
+    let fp: PFN_vkGetImageSparseMemoryRequirements =
+        ctx.fp("vkGetImageSparseMemoryRequirements")?;
+    fp(
+        device,
+        image,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_image_sparse_memory_requirements2(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_info = ctx.ptr(r)?;
+    let p_sparse_memory_requirement_count = ctx.out(r)?;
+    let p_sparse_memory_requirements = ctx.out_array(r)?;
+    let fp: PFN_vkGetImageSparseMemoryRequirements2 =
+        ctx.fp("vkGetImageSparseMemoryRequirements2")?;
+    fp(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_image_subresource_layout(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let image = Decode::decode(r, ctx)?;
+    let p_subresource = ctx.ptr(r)?;
+    let p_layout = ctx.out(r)?;
+    let fp: PFN_vkGetImageSubresourceLayout = ctx.fp("vkGetImageSubresourceLayout")?;
+    fp(device, image, p_subresource, p_layout);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_memory_android_hardware_buffer_android(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_info = ctx.ptr(r)?;
+    let p_buffer = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetMemoryAndroidHardwareBufferANDROID =
+        ctx.fp("vkGetMemoryAndroidHardwareBufferANDROID")?;
+    let result = fp(device, p_info, p_buffer);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_memory_fd_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_get_fd_info = ctx.ptr(r)?;
+    let p_fd = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetMemoryFdKHR = ctx.fp("vkGetMemoryFdKHR")?;
+    let result = fp(device, p_get_fd_info, p_fd);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_memory_fd_properties_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let handle_type = Decode::decode(r, ctx)?;
+    let fd = Decode::decode(r, ctx)?;
+    let p_memory_fd_properties = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetMemoryFdPropertiesKHR = ctx.fp("vkGetMemoryFdPropertiesKHR")?;
+    let result = fp(device, handle_type, fd, p_memory_fd_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_memory_win32_handle_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_get_win32_handle_info = ctx.ptr(r)?;
+    let p_handle = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetMemoryWin32HandleKHR = ctx.fp("vkGetMemoryWin32HandleKHR")?;
+    let result = fp(device, p_get_win32_handle_info, p_handle);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_memory_win32_handle_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let memory = Decode::decode(r, ctx)?;
+    let handle_type = Decode::decode(r, ctx)?;
+    let p_handle = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetMemoryWin32HandleNV = ctx.fp("vkGetMemoryWin32HandleNV")?;
+    let result = fp(device, memory, handle_type, p_handle);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_memory_win32_handle_properties_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let handle_type = Decode::decode(r, ctx)?;
+    let handle = Decode::decode(r, ctx)?;
+    let p_memory_win32_handle_properties = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetMemoryWin32HandlePropertiesKHR =
+        ctx.fp("vkGetMemoryWin32HandlePropertiesKHR")?;
+    let result = fp(
+        device,
+        handle_type,
+        handle,
+        p_memory_win32_handle_properties,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_past_presentation_timing_google(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain = Decode::decode(r, ctx)?;
+    let p_presentation_timing_count = ctx.out(r)?;
+    let p_presentation_timings = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPastPresentationTimingGOOGLE = ctx.fp("vkGetPastPresentationTimingGOOGLE")?;
+    let result = fp(
+        device,
+        swapchain,
+        p_presentation_timing_count,
+        p_presentation_timings,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_calibrateable_time_domains_ext(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_time_domain_count = ctx.out(r)?;
+    let p_time_domains = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT =
+        ctx.fp("vkGetPhysicalDeviceCalibrateableTimeDomainsEXT")?;
+    let result = fp(physical_device, p_time_domain_count, p_time_domains);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_display_plane_properties2_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceDisplayPlaneProperties2KHR =
+        ctx.fp("vkGetPhysicalDeviceDisplayPlaneProperties2KHR")?;
+    let result = fp(physical_device, p_property_count, p_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_display_plane_properties_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR =
+        ctx.fp("vkGetPhysicalDeviceDisplayPlanePropertiesKHR")?;
+    let result = fp(physical_device, p_property_count, p_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_display_properties2_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceDisplayProperties2KHR =
+        ctx.fp("vkGetPhysicalDeviceDisplayProperties2KHR")?;
+    let result = fp(physical_device, p_property_count, p_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_display_properties_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceDisplayPropertiesKHR =
+        ctx.fp("vkGetPhysicalDeviceDisplayPropertiesKHR")?;
+    let result = fp(physical_device, p_property_count, p_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_external_buffer_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_external_buffer_info = ctx.ptr(r)?;
+    let p_external_buffer_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceExternalBufferProperties =
+        ctx.fp("vkGetPhysicalDeviceExternalBufferProperties")?;
+    fp(
+        physical_device,
+        p_external_buffer_info,
+        p_external_buffer_properties,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_external_fence_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_external_fence_info = ctx.ptr(r)?;
+    let p_external_fence_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceExternalFenceProperties =
+        ctx.fp("vkGetPhysicalDeviceExternalFenceProperties")?;
+    fp(
+        physical_device,
+        p_external_fence_info,
+        p_external_fence_properties,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_external_image_format_properties_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let format = Decode::decode(r, ctx)?;
+    let ty = Decode::decode(r, ctx)?;
+    let tiling = Decode::decode(r, ctx)?;
+    let usage = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let external_handle_type = Decode::decode(r, ctx)?;
+    let p_external_image_format_properties = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV =
+        ctx.fp("vkGetPhysicalDeviceExternalImageFormatPropertiesNV")?;
+    let result = fp(
+        physical_device,
+        format,
+        ty,
+        tiling,
+        usage,
+        flags,
+        external_handle_type,
+        p_external_image_format_properties,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_external_semaphore_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_external_semaphore_info = ctx.ptr(r)?;
+    let p_external_semaphore_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceExternalSemaphoreProperties =
+        ctx.fp("vkGetPhysicalDeviceExternalSemaphoreProperties")?;
+    fp(
+        physical_device,
+        p_external_semaphore_info,
+        p_external_semaphore_properties,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_features(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_features = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceFeatures = ctx.fp("vkGetPhysicalDeviceFeatures")?;
+    fp(physical_device, p_features);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_features2(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_features = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceFeatures2 = ctx.fp("vkGetPhysicalDeviceFeatures2")?;
+    fp(physical_device, p_features);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_format_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let format = Decode::decode(r, ctx)?;
+    let p_format_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceFormatProperties =
+        ctx.fp("vkGetPhysicalDeviceFormatProperties")?;
+    fp(physical_device, format, p_format_properties);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_format_properties2(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let format = Decode::decode(r, ctx)?;
+    let p_format_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceFormatProperties2 =
+        ctx.fp("vkGetPhysicalDeviceFormatProperties2")?;
+    fp(physical_device, format, p_format_properties);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_generated_commands_properties_nvx(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_features = ctx.out(r)?;
+    let p_limits = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX =
+        ctx.fp("vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX")?;
+    fp(physical_device, p_features, p_limits);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_image_format_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let format = Decode::decode(r, ctx)?;
+    let ty = Decode::decode(r, ctx)?;
+    let tiling = Decode::decode(r, ctx)?;
+    let usage = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let p_image_format_properties = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceImageFormatProperties =
+        ctx.fp("vkGetPhysicalDeviceImageFormatProperties")?;
+    let result = fp(
+        physical_device,
+        format,
+        ty,
+        tiling,
+        usage,
+        flags,
+        p_image_format_properties,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_image_format_properties2(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_image_format_info = ctx.ptr(r)?;
+    let p_image_format_properties = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceImageFormatProperties2 =
+        ctx.fp("vkGetPhysicalDeviceImageFormatProperties2")?;
+    let result = fp(
+        physical_device,
+        p_image_format_info,
+        p_image_format_properties,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_memory_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_memory_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceMemoryProperties =
+        ctx.fp("vkGetPhysicalDeviceMemoryProperties")?;
+    fp(physical_device, p_memory_properties);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_memory_properties2(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_memory_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceMemoryProperties2 =
+        ctx.fp("vkGetPhysicalDeviceMemoryProperties2")?;
+    fp(physical_device, p_memory_properties);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_multisample_properties_ext(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let samples = Decode::decode(r, ctx)?;
+    let p_multisample_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceMultisamplePropertiesEXT =
+        ctx.fp("vkGetPhysicalDeviceMultisamplePropertiesEXT")?;
+    fp(physical_device, samples, p_multisample_properties);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_present_rectangles_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let surface = Decode::decode(r, ctx)?;
+    let p_rect_count = ctx.out(r)?;
+    let p_rects = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDevicePresentRectanglesKHR =
+        ctx.fp("vkGetPhysicalDevicePresentRectanglesKHR")?;
+    let result = fp(physical_device, surface, p_rect_count, p_rects);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_properties(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceProperties = ctx.fp("vkGetPhysicalDeviceProperties")?;
+    fp(physical_device, p_properties);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_properties2(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_properties = ctx.out(r)?;
+    let fp: PFN_vkGetPhysicalDeviceProperties2 = ctx.fp("vkGetPhysicalDeviceProperties2")?;
+    fp(physical_device, p_properties);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_queue_family_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_queue_family_property_count = ctx.out(r)?;
+    let p_queue_family_properties = ctx.out_array(r)?;
+    let fp: PFN_vkGetPhysicalDeviceQueueFamilyProperties =
+        ctx.fp("vkGetPhysicalDeviceQueueFamilyProperties")?;
+    fp(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_queue_family_properties2(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_queue_family_property_count = ctx.out(r)?;
+    let p_queue_family_properties = ctx.out_array(r)?;
+    let fp: PFN_vkGetPhysicalDeviceQueueFamilyProperties2 =
+        ctx.fp("vkGetPhysicalDeviceQueueFamilyProperties2")?;
+    fp(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_sparse_image_format_properties(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let format = Decode::decode(r, ctx)?;
+    let ty = Decode::decode(r, ctx)?;
+    let samples = Decode::decode(r, ctx)?;
+    let usage = Decode::decode(r, ctx)?;
+    let tiling = Decode::decode(r, ctx)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let fp: PFN_vkGetPhysicalDeviceSparseImageFormatProperties =
+        ctx.fp("vkGetPhysicalDeviceSparseImageFormatProperties")?;
+    fp(
+        physical_device,
+        format,
+        ty,
+        samples,
+        usage,
+        tiling,
+        p_property_count,
+        p_properties,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_sparse_image_format_properties2(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_format_info = ctx.ptr(r)?;
+    let p_property_count = ctx.out(r)?;
+    let p_properties = ctx.out_array(r)?;
+    let fp: PFN_vkGetPhysicalDeviceSparseImageFormatProperties2 =
+        ctx.fp("vkGetPhysicalDeviceSparseImageFormatProperties2")?;
+    fp(
+        physical_device,
+        p_format_info,
+        p_property_count,
+        p_properties,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_physical_device_surface_capabilities2_ext(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let surface = Decode::decode(r, ctx)?;
+    let p_surface_capabilities = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT =
+        ctx.fp("vkGetPhysicalDeviceSurfaceCapabilities2EXT")?;
+    let result = fp(physical_device, surface, p_surface_capabilities);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_surface_capabilities2_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_surface_info = ctx.ptr(r)?;
+    let p_surface_capabilities = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR =
+        ctx.fp("vkGetPhysicalDeviceSurfaceCapabilities2KHR")?;
+    let result = fp(physical_device, p_surface_info, p_surface_capabilities);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_surface_capabilities_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let surface = Decode::decode(r, ctx)?;
+    let p_surface_capabilities = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR =
+        ctx.fp("vkGetPhysicalDeviceSurfaceCapabilitiesKHR")?;
+    let result = fp(physical_device, surface, p_surface_capabilities);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_surface_formats2_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let p_surface_info = ctx.ptr(r)?;
+    let p_surface_format_count = ctx.out(r)?;
+    let p_surface_formats = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceSurfaceFormats2KHR =
+        ctx.fp("vkGetPhysicalDeviceSurfaceFormats2KHR")?;
+    let result = fp(
+        physical_device,
+        p_surface_info,
+        p_surface_format_count,
+        p_surface_formats,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_surface_formats_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let surface = Decode::decode(r, ctx)?;
+    let p_surface_format_count = ctx.out(r)?;
+    let p_surface_formats = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceSurfaceFormatsKHR =
+        ctx.fp("vkGetPhysicalDeviceSurfaceFormatsKHR")?;
+    let result = fp(
+        physical_device,
+        surface,
+        p_surface_format_count,
+        p_surface_formats,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_surface_present_modes_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let surface = Decode::decode(r, ctx)?;
+    let p_present_mode_count = ctx.out(r)?;
+    let p_present_modes = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceSurfacePresentModesKHR =
+        ctx.fp("vkGetPhysicalDeviceSurfacePresentModesKHR")?;
+    let result = fp(
+        physical_device,
+        surface,
+        p_present_mode_count,
+        p_present_modes,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_surface_support_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let queue_family_index = Decode::decode(r, ctx)?;
+    let surface = Decode::decode(r, ctx)?;
+    let p_supported = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceSurfaceSupportKHR =
+        ctx.fp("vkGetPhysicalDeviceSurfaceSupportKHR")?;
+    let result = fp(physical_device, queue_family_index, surface, p_supported);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_physical_device_win32_presentation_support_khr(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let queue_family_index = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR =
+        ctx.fp("vkGetPhysicalDeviceWin32PresentationSupportKHR")?;
+    let result = fp(physical_device, queue_family_index);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_pipeline_cache_data(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline_cache = Decode::decode(r, ctx)?;
+    let p_data_size = ctx.out(r)?;
+    let p_data = ctx.out_bytes(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetPipelineCacheData = ctx.fp("vkGetPipelineCacheData")?;
+    let result = fp(device, pipeline_cache, p_data_size, p_data);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_query_pool_results(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let query_pool = Decode::decode(r, ctx)?;
+    let first_query = Decode::decode(r, ctx)?;
+    let query_count = Decode::decode(r, ctx)?;
+    let data_size = Decode::decode(r, ctx)?;
+    let p_data = ctx.out_bytes(r)?;
+    let stride = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetQueryPoolResults = ctx.fp("vkGetQueryPoolResults")?;
+    let result = fp(
+        device,
+        query_pool,
+        first_query,
+        query_count,
+        data_size,
+        p_data,
+        stride,
+        flags,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_queue_checkpoint_data_nv(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let p_checkpoint_data_count = ctx.out(r)?;
+    let p_checkpoint_data = ctx.out_array(r)?;
+    let fp: PFN_vkGetQueueCheckpointDataNV = ctx.fp("vkGetQueueCheckpointDataNV")?;
+    fp(queue, p_checkpoint_data_count, p_checkpoint_data);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_ray_tracing_shader_group_handles_nv(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline = Decode::decode(r, ctx)?;
+    let first_group = Decode::decode(r, ctx)?;
+    let group_count = Decode::decode(r, ctx)?;
+    let data_size = Decode::decode(r, ctx)?;
+    let p_data = ctx.out_bytes(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetRayTracingShaderGroupHandlesNV =
+        ctx.fp("vkGetRayTracingShaderGroupHandlesNV")?;
+    let result = fp(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_refresh_cycle_duration_google(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain = Decode::decode(r, ctx)?;
+    let p_display_timing_properties = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetRefreshCycleDurationGOOGLE = ctx.fp("vkGetRefreshCycleDurationGOOGLE")?;
+    let result = fp(device, swapchain, p_display_timing_properties);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_render_area_granularity(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let render_pass = Decode::decode(r, ctx)?;
+    let p_granularity = ctx.out(r)?;
+    let fp: PFN_vkGetRenderAreaGranularity = ctx.fp("vkGetRenderAreaGranularity")?;
+    fp(device, render_pass, p_granularity);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn get_semaphore_fd_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_get_fd_info = ctx.ptr(r)?;
+    let p_fd = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetSemaphoreFdKHR = ctx.fp("vkGetSemaphoreFdKHR")?;
+    let result = fp(device, p_get_fd_info, p_fd);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_semaphore_win32_handle_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_get_win32_handle_info = ctx.ptr(r)?;
+    let p_handle = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetSemaphoreWin32HandleKHR = ctx.fp("vkGetSemaphoreWin32HandleKHR")?;
+    let result = fp(device, p_get_win32_handle_info, p_handle);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_shader_info_amd(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let pipeline = Decode::decode(r, ctx)?;
+    let shader_stage = Decode::decode(r, ctx)?;
+    let info_type = Decode::decode(r, ctx)?;
+    let p_info_size = ctx.out(r)?;
+    let p_info = ctx.out_bytes(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetShaderInfoAMD = ctx.fp("vkGetShaderInfoAMD")?;
+    let result = fp(
+        device,
+        pipeline,
+        shader_stage,
+        info_type,
+        p_info_size,
+        p_info,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_swapchain_counter_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain = Decode::decode(r, ctx)?;
+    let counter = Decode::decode(r, ctx)?;
+    let p_counter_value = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetSwapchainCounterEXT = ctx.fp("vkGetSwapchainCounterEXT")?;
+    let result = fp(device, swapchain, counter, p_counter_value);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_swapchain_gralloc_usage_android(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let format = Decode::decode(r, ctx)?;
+    let image_usage = Decode::decode(r, ctx)?;
+    let gralloc_usage = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetSwapchainGrallocUsageANDROID = ctx.fp("vkGetSwapchainGrallocUsageANDROID")?;
+    let result = fp(device, format, image_usage, gralloc_usage);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_swapchain_images_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain = Decode::decode(r, ctx)?;
+    let p_swapchain_image_count = ctx.out(r)?;
+    let p_swapchain_images = ctx.out_array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetSwapchainImagesKHR = ctx.fp("vkGetSwapchainImagesKHR")?;
+    let result = fp(
+        device,
+        swapchain,
+        p_swapchain_image_count,
+        p_swapchain_images,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_swapchain_status_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetSwapchainStatusKHR = ctx.fp("vkGetSwapchainStatusKHR")?;
+    let result = fp(device, swapchain);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn get_validation_cache_data_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let validation_cache = Decode::decode(r, ctx)?;
+    let p_data_size = ctx.out(r)?;
+    let p_data = ctx.out_bytes(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkGetValidationCacheDataEXT = ctx.fp("vkGetValidationCacheDataEXT")?;
+    let result = fp(device, validation_cache, p_data_size, p_data);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn import_fence_fd_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_import_fence_fd_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkImportFenceFdKHR = ctx.fp("vkImportFenceFdKHR")?;
+    let result = fp(device, p_import_fence_fd_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn import_semaphore_fd_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_import_semaphore_fd_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkImportSemaphoreFdKHR = ctx.fp("vkImportSemaphoreFdKHR")?;
+    let result = fp(device, p_import_semaphore_fd_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn invalidate_mapped_memory_ranges(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let memory_range_count = Decode::decode(r, ctx)?;
+    let p_memory_ranges = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkInvalidateMappedMemoryRanges = ctx.fp("vkInvalidateMappedMemoryRanges")?;
+    let result = fp(device, memory_range_count, p_memory_ranges);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn merge_pipeline_caches(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let dst_cache = Decode::decode(r, ctx)?;
+    let src_cache_count = Decode::decode(r, ctx)?;
+    let p_src_caches = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkMergePipelineCaches = ctx.fp("vkMergePipelineCaches")?;
+    let result = fp(device, dst_cache, src_cache_count, p_src_caches);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn merge_validation_caches_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let dst_cache = Decode::decode(r, ctx)?;
+    let src_cache_count = Decode::decode(r, ctx)?;
+    let p_src_caches = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkMergeValidationCachesEXT = ctx.fp("vkMergeValidationCachesEXT")?;
+    let result = fp(device, dst_cache, src_cache_count, p_src_caches);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn queue_begin_debug_utils_label_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let p_label_info = ctx.ptr(r)?;
+    let fp: PFN_vkQueueBeginDebugUtilsLabelEXT = ctx.fp("vkQueueBeginDebugUtilsLabelEXT")?;
+    fp(queue, p_label_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn queue_bind_sparse(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let bind_info_count = Decode::decode(r, ctx)?;
+    let p_bind_info = ctx.array(r)?;
+    let fence = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkQueueBindSparse = ctx.fp("vkQueueBindSparse")?;
+    let result = fp(queue, bind_info_count, p_bind_info, fence);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn queue_end_debug_utils_label_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let fp: PFN_vkQueueEndDebugUtilsLabelEXT = ctx.fp("vkQueueEndDebugUtilsLabelEXT")?;
+    fp(queue);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn queue_insert_debug_utils_label_ext(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let p_label_info = ctx.ptr(r)?;
+    let fp: PFN_vkQueueInsertDebugUtilsLabelEXT = ctx.fp("vkQueueInsertDebugUtilsLabelEXT")?;
+    fp(queue, p_label_info);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn queue_present_khr(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let p_present_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkQueuePresentKHR = ctx.fp("vkQueuePresentKHR")?;
+    let result = fp(queue, p_present_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn queue_signal_release_image_android(
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let wait_semaphore_count = Decode::decode(r, ctx)?;
+    let p_wait_semaphores = ctx.array(r)?;
+    let image = Decode::decode(r, ctx)?;
+    let p_native_fence_fd = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkQueueSignalReleaseImageANDROID = ctx.fp("vkQueueSignalReleaseImageANDROID")?;
+    let result = fp(
+        queue,
+        wait_semaphore_count,
+        p_wait_semaphores,
+        image,
+        p_native_fence_fd,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn queue_submit(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let submit_count = Decode::decode(r, ctx)?;
+    let p_submits = ctx.array(r)?;
+    let fence = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkQueueSubmit = ctx.fp("vkQueueSubmit")?;
+    let result = fp(queue, submit_count, p_submits, fence);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn queue_wait_idle(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let queue = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkQueueWaitIdle = ctx.fp("vkQueueWaitIdle")?;
+    let result = fp(queue);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn register_device_event_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_device_event_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_fence = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkRegisterDeviceEventEXT = ctx.fp("vkRegisterDeviceEventEXT")?;
+    let result = fp(device, p_device_event_info, p_allocator, p_fence);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn register_display_event_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let display = Decode::decode(r, ctx)?;
+    let p_display_event_info = ctx.ptr(r)?;
+    let p_allocator = ctx.allocator(r)?;
+    let p_fence = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkRegisterDisplayEventEXT = ctx.fp("vkRegisterDisplayEventEXT")?;
+    let result = fp(device, display, p_display_event_info, p_allocator, p_fence);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn register_objects_nvx(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let object_table = Decode::decode(r, ctx)?;
+    let object_count = Decode::decode(r, ctx)?;
+    let pp_object_table_entries = ctx.array(r)?;
+    let p_object_indices = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkRegisterObjectsNVX = ctx.fp("vkRegisterObjectsNVX")?;
+    let result = fp(
+        device,
+        object_table,
+        object_count,
+        pp_object_table_entries,
+        p_object_indices,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn release_display_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let physical_device = Decode::decode(r, ctx)?;
+    let display = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkReleaseDisplayEXT = ctx.fp("vkReleaseDisplayEXT")?;
+    let result = fp(physical_device, display);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn reset_command_buffer(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let command_buffer = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkResetCommandBuffer = ctx.fp("vkResetCommandBuffer")?;
+    let result = fp(command_buffer, flags);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn reset_command_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let command_pool = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkResetCommandPool = ctx.fp("vkResetCommandPool")?;
+    let result = fp(device, command_pool, flags);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn reset_descriptor_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let descriptor_pool = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkResetDescriptorPool = ctx.fp("vkResetDescriptorPool")?;
+    let result = fp(device, descriptor_pool, flags);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn reset_event(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let event = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkResetEvent = ctx.fp("vkResetEvent")?;
+    let result = fp(device, event);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn reset_fences(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let fence_count = Decode::decode(r, ctx)?;
+    let p_fences = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkResetFences = ctx.fp("vkResetFences")?;
+    let result = fp(device, fence_count, p_fences);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn set_debug_utils_object_name_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_name_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkSetDebugUtilsObjectNameEXT = ctx.fp("vkSetDebugUtilsObjectNameEXT")?;
+    let result = fp(device, p_name_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn set_debug_utils_object_tag_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let p_tag_info = ctx.ptr(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkSetDebugUtilsObjectTagEXT = ctx.fp("vkSetDebugUtilsObjectTagEXT")?;
+    let result = fp(device, p_tag_info);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn set_event(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let event = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkSetEvent = ctx.fp("vkSetEvent")?;
+    let result = fp(device, event);
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn set_hdr_metadata_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let swapchain_count = Decode::decode(r, ctx)?;
+    let p_swapchains = ctx.array(r)?;
+    let p_metadata = ctx.array(r)?;
+    let fp: PFN_vkSetHdrMetadataEXT = ctx.fp("vkSetHdrMetadataEXT")?;
+    fp(device, swapchain_count, p_swapchains, p_metadata);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn submit_debug_utils_message_ext(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let instance = Decode::decode(r, ctx)?;
+    let message_severity = Decode::decode(r, ctx)?;
+    let message_types = Decode::decode(r, ctx)?;
+    let p_callback_data = ctx.ptr(r)?;
+    let fp: PFN_vkSubmitDebugUtilsMessageEXT = ctx.fp("vkSubmitDebugUtilsMessageEXT")?;
+    fp(instance, message_severity, message_types, p_callback_data);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn trim_command_pool(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let command_pool = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let fp: PFN_vkTrimCommandPool = ctx.fp("vkTrimCommandPool")?;
+    fp(device, command_pool, flags);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn unmap_memory(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let memory = Decode::decode(r, ctx)?;
+    let fp: PFN_vkUnmapMemory = ctx.fp("vkUnmapMemory")?;
+    fp(device, memory);
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn unregister_objects_nvx(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let object_table = Decode::decode(r, ctx)?;
+    let object_count = Decode::decode(r, ctx)?;
+    let p_object_entry_types = ctx.array(r)?;
+    let p_object_indices = ctx.array(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkUnregisterObjectsNVX = ctx.fp("vkUnregisterObjectsNVX")?;
+    let result = fp(
+        device,
+        object_table,
+        object_count,
+        p_object_entry_types,
+        p_object_indices,
+    );
+    ctx.finish(result, captured);
+    Ok(())
+}
+unsafe fn update_descriptor_sets(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let descriptor_write_count = Decode::decode(r, ctx)?;
+    let p_descriptor_writes = ctx.array(r)?;
+    let descriptor_copy_count = Decode::decode(r, ctx)?;
+    let p_descriptor_copies = ctx.array(r)?;
+    let fp: PFN_vkUpdateDescriptorSets = ctx.fp("vkUpdateDescriptorSets")?;
+    fp(
+        device,
+        descriptor_write_count,
+        p_descriptor_writes,
+        descriptor_copy_count,
+        p_descriptor_copies,
+    );
+    ctx.finish((), ());
+    Ok(())
+}
+unsafe fn wait_for_fences(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+    let device = Decode::decode(r, ctx)?;
+    let fence_count = Decode::decode(r, ctx)?;
+    let p_fences = ctx.array(r)?;
+    let wait_all = Decode::decode(r, ctx)?;
+    let timeout = Decode::decode(r, ctx)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: PFN_vkWaitForFences = ctx.fp("vkWaitForFences")?;
+    let result = fp(device, fence_count, p_fences, wait_all, timeout);
+    ctx.finish(result, captured);
+    Ok(())
+}
+pub(crate) unsafe fn replay_command(
+    name: &str,
+    r: &mut Reader,
+    ctx: &mut Context,
+) -> Option<ReplayResult<()>> {
+    Some(match name {
+        "vkAcquireImageANDROID" => acquire_image_android(r, ctx),
+        "vkAcquireNextImage2KHR" => acquire_next_image2_khr(r, ctx),
+        "vkAcquireNextImageKHR" => acquire_next_image_khr(r, ctx),
+        "vkAcquireXlibDisplayEXT" => Err(ReplayError::Unsupported("vkAcquireXlibDisplayEXT")),
+        "vkAllocateCommandBuffers" => allocate_command_buffers(r, ctx),
+        "vkAllocateDescriptorSets" => allocate_descriptor_sets(r, ctx),
+        "vkAllocateMemory" => allocate_memory(r, ctx),
+        "vkBeginCommandBuffer" => begin_command_buffer(r, ctx),
+        "vkBindAccelerationStructureMemoryNV" => bind_acceleration_structure_memory_nv(r, ctx),
+        "vkBindBufferMemory" => bind_buffer_memory(r, ctx),
+        "vkBindBufferMemory2" => bind_buffer_memory2(r, ctx),
+        "vkBindImageMemory" => bind_image_memory(r, ctx),
+        "vkBindImageMemory2" => bind_image_memory2(r, ctx),
+        "vkCmdBeginConditionalRenderingEXT" => cmd_begin_conditional_rendering_ext(r, ctx),
+        "vkCmdBeginDebugUtilsLabelEXT" => cmd_begin_debug_utils_label_ext(r, ctx),
+        "vkCmdBeginQuery" => cmd_begin_query(r, ctx),
+        "vkCmdBeginQueryIndexedEXT" => cmd_begin_query_indexed_ext(r, ctx),
+        "vkCmdBeginRenderPass" => cmd_begin_render_pass(r, ctx),
+        "vkCmdBeginRenderPass2KHR" => cmd_begin_render_pass2_khr(r, ctx),
+        "vkCmdBeginTransformFeedbackEXT" => cmd_begin_transform_feedback_ext(r, ctx),
+        "vkCmdBindDescriptorSets" => cmd_bind_descriptor_sets(r, ctx),
+        "vkCmdBindIndexBuffer" => cmd_bind_index_buffer(r, ctx),
+        "vkCmdBindPipeline" => cmd_bind_pipeline(r, ctx),
+        "vkCmdBindShadingRateImageNV" => cmd_bind_shading_rate_image_nv(r, ctx),
+        "vkCmdBindTransformFeedbackBuffersEXT" => cmd_bind_transform_feedback_buffers_ext(r, ctx),
+        "vkCmdBindVertexBuffers" => cmd_bind_vertex_buffers(r, ctx),
+        "vkCmdBlitImage" => cmd_blit_image(r, ctx),
+        "vkCmdBuildAccelerationStructureNV" => cmd_build_acceleration_structure_nv(r, ctx),
+        "vkCmdClearAttachments" => cmd_clear_attachments(r, ctx),
+        "vkCmdClearColorImage" => cmd_clear_color_image(r, ctx),
+        "vkCmdClearDepthStencilImage" => cmd_clear_depth_stencil_image(r, ctx),
+        "vkCmdCopyAccelerationStructureNV" => cmd_copy_acceleration_structure_nv(r, ctx),
+        "vkCmdCopyBuffer" => cmd_copy_buffer(r, ctx),
+        "vkCmdCopyBufferToImage" => cmd_copy_buffer_to_image(r, ctx),
+        "vkCmdCopyImage" => cmd_copy_image(r, ctx),
+        "vkCmdCopyImageToBuffer" => cmd_copy_image_to_buffer(r, ctx),
+        "vkCmdCopyQueryPoolResults" => cmd_copy_query_pool_results(r, ctx),
+        "vkCmdDebugMarkerBeginEXT" => cmd_debug_marker_begin_ext(r, ctx),
+        "vkCmdDebugMarkerEndEXT" => cmd_debug_marker_end_ext(r, ctx),
+        "vkCmdDebugMarkerInsertEXT" => cmd_debug_marker_insert_ext(r, ctx),
+        "vkCmdDispatch" => cmd_dispatch(r, ctx),
+        "vkCmdDispatchBase" => cmd_dispatch_base(r, ctx),
+        "vkCmdDispatchIndirect" => cmd_dispatch_indirect(r, ctx),
+        "vkCmdDraw" => cmd_draw(r, ctx),
+        "vkCmdDrawIndexed" => cmd_draw_indexed(r, ctx),
+        "vkCmdDrawIndexedIndirect" => cmd_draw_indexed_indirect(r, ctx),
+        "vkCmdDrawIndexedIndirectCountAMD" => cmd_draw_indexed_indirect_count_amd(r, ctx),
+        "vkCmdDrawIndexedIndirectCountKHR" => cmd_draw_indexed_indirect_count_khr(r, ctx),
+        "vkCmdDrawIndirect" => cmd_draw_indirect(r, ctx),
+        "vkCmdDrawIndirectByteCountEXT" => cmd_draw_indirect_byte_count_ext(r, ctx),
+        "vkCmdDrawIndirectCountAMD" => cmd_draw_indirect_count_amd(r, ctx),
+        "vkCmdDrawIndirectCountKHR" => cmd_draw_indirect_count_khr(r, ctx),
+        "vkCmdDrawMeshTasksIndirectCountNV" => cmd_draw_mesh_tasks_indirect_count_nv(r, ctx),
+        "vkCmdDrawMeshTasksIndirectNV" => cmd_draw_mesh_tasks_indirect_nv(r, ctx),
+        "vkCmdDrawMeshTasksNV" => cmd_draw_mesh_tasks_nv(r, ctx),
+        "vkCmdEndConditionalRenderingEXT" => cmd_end_conditional_rendering_ext(r, ctx),
+        "vkCmdEndDebugUtilsLabelEXT" => cmd_end_debug_utils_label_ext(r, ctx),
+        "vkCmdEndQuery" => cmd_end_query(r, ctx),
+        "vkCmdEndQueryIndexedEXT" => cmd_end_query_indexed_ext(r, ctx),
+        "vkCmdEndRenderPass" => cmd_end_render_pass(r, ctx),
+        "vkCmdEndRenderPass2KHR" => cmd_end_render_pass2_khr(r, ctx),
+        "vkCmdEndTransformFeedbackEXT" => cmd_end_transform_feedback_ext(r, ctx),
+        "vkCmdExecuteCommands" => cmd_execute_commands(r, ctx),
+        "vkCmdFillBuffer" => cmd_fill_buffer(r, ctx),
+        "vkCmdInsertDebugUtilsLabelEXT" => cmd_insert_debug_utils_label_ext(r, ctx),
+        "vkCmdNextSubpass" => cmd_next_subpass(r, ctx),
+        "vkCmdNextSubpass2KHR" => cmd_next_subpass2_khr(r, ctx),
+        "vkCmdPipelineBarrier" => cmd_pipeline_barrier(r, ctx),
+        "vkCmdProcessCommandsNVX" => cmd_process_commands_nvx(r, ctx),
+        "vkCmdPushConstants" => cmd_push_constants(r, ctx),
+        "vkCmdPushDescriptorSetKHR" => cmd_push_descriptor_set_khr(r, ctx),
+        "vkCmdPushDescriptorSetWithTemplateKHR" => Err(ReplayError::Unsupported(
+            "vkCmdPushDescriptorSetWithTemplateKHR",
+        )),
+        "vkCmdReserveSpaceForCommandsNVX" => cmd_reserve_space_for_commands_nvx(r, ctx),
+        "vkCmdResetEvent" => cmd_reset_event(r, ctx),
+        "vkCmdResetQueryPool" => cmd_reset_query_pool(r, ctx),
+        "vkCmdResolveImage" => cmd_resolve_image(r, ctx),
+        "vkCmdSetBlendConstants" => cmd_set_blend_constants(r, ctx),
+        "vkCmdSetCheckpointNV" => cmd_set_checkpoint_nv(r, ctx),
+        "vkCmdSetCoarseSampleOrderNV" => cmd_set_coarse_sample_order_nv(r, ctx),
+        "vkCmdSetDepthBias" => cmd_set_depth_bias(r, ctx),
+        "vkCmdSetDepthBounds" => cmd_set_depth_bounds(r, ctx),
+        "vkCmdSetDeviceMask" => cmd_set_device_mask(r, ctx),
+        "vkCmdSetDiscardRectangleEXT" => cmd_set_discard_rectangle_ext(r, ctx),
+        "vkCmdSetEvent" => cmd_set_event(r, ctx),
+        "vkCmdSetExclusiveScissorNV" => cmd_set_exclusive_scissor_nv(r, ctx),
+        "vkCmdSetLineWidth" => cmd_set_line_width(r, ctx),
+        "vkCmdSetSampleLocationsEXT" => cmd_set_sample_locations_ext(r, ctx),
+        "vkCmdSetScissor" => cmd_set_scissor(r, ctx),
+        "vkCmdSetStencilCompareMask" => cmd_set_stencil_compare_mask(r, ctx),
+        "vkCmdSetStencilReference" => cmd_set_stencil_reference(r, ctx),
+        "vkCmdSetStencilWriteMask" => cmd_set_stencil_write_mask(r, ctx),
+        "vkCmdSetViewport" => cmd_set_viewport(r, ctx),
+        "vkCmdSetViewportShadingRatePaletteNV" => cmd_set_viewport_shading_rate_palette_nv(r, ctx),
+        "vkCmdSetViewportWScalingNV" => cmd_set_viewport_w_scaling_nv(r, ctx),
+        "vkCmdTraceRaysNV" => cmd_trace_rays_nv(r, ctx),
+        "vkCmdUpdateBuffer" => cmd_update_buffer(r, ctx),
+        "vkCmdWaitEvents" => cmd_wait_events(r, ctx),
+        "vkCmdWriteAccelerationStructuresPropertiesNV" => {
+            cmd_write_acceleration_structures_properties_nv(r, ctx)
+        }
+        "vkCmdWriteBufferMarkerAMD" => cmd_write_buffer_marker_amd(r, ctx),
+        "vkCmdWriteTimestamp" => cmd_write_timestamp(r, ctx),
+        "vkCompileDeferredNV" => compile_deferred_nv(r, ctx),
+        "vkCreateAccelerationStructureNV" => create_acceleration_structure_nv(r, ctx),
+        "vkCreateAndroidSurfaceKHR" => Err(ReplayError::Unsupported("vkCreateAndroidSurfaceKHR")),
+        "vkCreateBuffer" => create_buffer(r, ctx),
+        "vkCreateBufferView" => create_buffer_view(r, ctx),
+        "vkCreateCommandPool" => create_command_pool(r, ctx),
+        "vkCreateComputePipelines" => create_compute_pipelines(r, ctx),
+        "vkCreateDebugReportCallbackEXT" => {
+            Err(ReplayError::Unsupported("vkCreateDebugReportCallbackEXT"))
+        }
+        "vkCreateDebugUtilsMessengerEXT" => {
+            Err(ReplayError::Unsupported("vkCreateDebugUtilsMessengerEXT"))
+        }
+        "vkCreateDescriptorPool" => create_descriptor_pool(r, ctx),
+        "vkCreateDescriptorSetLayout" => create_descriptor_set_layout(r, ctx),
+        "vkCreateDescriptorUpdateTemplate" => create_descriptor_update_template(r, ctx),
+        "vkCreateDevice" => create_device(r, ctx),
+        "vkCreateDisplayModeKHR" => create_display_mode_khr(r, ctx),
+        "vkCreateDisplayPlaneSurfaceKHR" => create_display_plane_surface_khr(r, ctx),
+        "vkCreateEvent" => create_event(r, ctx),
+        "vkCreateFence" => create_fence(r, ctx),
+        "vkCreateFramebuffer" => create_framebuffer(r, ctx),
+        "vkCreateGraphicsPipelines" => create_graphics_pipelines(r, ctx),
+        "vkCreateIOSSurfaceMVK" => Err(ReplayError::Unsupported("vkCreateIOSSurfaceMVK")),
+        "vkCreateImage" => create_image(r, ctx),
+        "vkCreateImagePipeSurfaceFUCHSIA" => {
+            Err(ReplayError::Unsupported("vkCreateImagePipeSurfaceFUCHSIA"))
+        }
+        "vkCreateImageView" => create_image_view(r, ctx),
+        "vkCreateIndirectCommandsLayoutNVX" => create_indirect_commands_layout_nvx(r, ctx),
+        "vkCreateInstance" => create_instance(r, ctx),
+        "vkCreateMacOSSurfaceMVK" => Err(ReplayError::Unsupported("vkCreateMacOSSurfaceMVK")),
+        "vkCreateObjectTableNVX" => create_object_table_nvx(r, ctx),
+        "vkCreatePipelineCache" => create_pipeline_cache(r, ctx),
+        "vkCreatePipelineLayout" => create_pipeline_layout(r, ctx),
+        "vkCreateQueryPool" => create_query_pool(r, ctx),
+        "vkCreateRayTracingPipelinesNV" => create_ray_tracing_pipelines_nv(r, ctx),
+        "vkCreateRenderPass" => create_render_pass(r, ctx),
+        "vkCreateRenderPass2KHR" => create_render_pass2_khr(r, ctx),
+        "vkCreateSampler" => create_sampler(r, ctx),
+        "vkCreateSamplerYcbcrConversion" => create_sampler_ycbcr_conversion(r, ctx),
+        "vkCreateSemaphore" => create_semaphore(r, ctx),
+        "vkCreateShaderModule" => create_shader_module(r, ctx),
+        "vkCreateSharedSwapchainsKHR" => create_shared_swapchains_khr(r, ctx),
+        "vkCreateSwapchainKHR" => create_swapchain_khr(r, ctx),
+        "vkCreateValidationCacheEXT" => create_validation_cache_ext(r, ctx),
+        "vkCreateViSurfaceNN" => create_vi_surface_nn(r, ctx),
+        "vkCreateWaylandSurfaceKHR" => Err(ReplayError::Unsupported("vkCreateWaylandSurfaceKHR")),
+        "vkCreateWin32SurfaceKHR" => Err(ReplayError::Unsupported("vkCreateWin32SurfaceKHR")),
+        "vkCreateXcbSurfaceKHR" => Err(ReplayError::Unsupported("vkCreateXcbSurfaceKHR")),
+        "vkCreateXlibSurfaceKHR" => Err(ReplayError::Unsupported("vkCreateXlibSurfaceKHR")),
+        "vkDebugMarkerSetObjectNameEXT" => debug_marker_set_object_name_ext(r, ctx),
+        "vkDebugMarkerSetObjectTagEXT" => debug_marker_set_object_tag_ext(r, ctx),
+        "vkDebugReportMessageEXT" => debug_report_message_ext(r, ctx),
+        "vkDestroyAccelerationStructureNV" => destroy_acceleration_structure_nv(r, ctx),
+        "vkDestroyBuffer" => destroy_buffer(r, ctx),
+        "vkDestroyBufferView" => destroy_buffer_view(r, ctx),
+        "vkDestroyCommandPool" => destroy_command_pool(r, ctx),
+        "vkDestroyDebugReportCallbackEXT" => destroy_debug_report_callback_ext(r, ctx),
+        "vkDestroyDebugUtilsMessengerEXT" => destroy_debug_utils_messenger_ext(r, ctx),
+        "vkDestroyDescriptorPool" => destroy_descriptor_pool(r, ctx),
+        "vkDestroyDescriptorSetLayout" => destroy_descriptor_set_layout(r, ctx),
+        "vkDestroyDescriptorUpdateTemplate" => destroy_descriptor_update_template(r, ctx),
+        "vkDestroyDevice" => destroy_device(r, ctx),
+        "vkDestroyEvent" => destroy_event(r, ctx),
+        "vkDestroyFence" => destroy_fence(r, ctx),
+        "vkDestroyFramebuffer" => destroy_framebuffer(r, ctx),
+        "vkDestroyImage" => destroy_image(r, ctx),
+        "vkDestroyImageView" => destroy_image_view(r, ctx),
+        "vkDestroyIndirectCommandsLayoutNVX" => destroy_indirect_commands_layout_nvx(r, ctx),
+        "vkDestroyInstance" => destroy_instance(r, ctx),
+        "vkDestroyObjectTableNVX" => destroy_object_table_nvx(r, ctx),
+        "vkDestroyPipeline" => destroy_pipeline(r, ctx),
+        "vkDestroyPipelineCache" => destroy_pipeline_cache(r, ctx),
+        "vkDestroyPipelineLayout" => destroy_pipeline_layout(r, ctx),
+        "vkDestroyQueryPool" => destroy_query_pool(r, ctx),
+        "vkDestroyRenderPass" => destroy_render_pass(r, ctx),
+        "vkDestroySampler" => destroy_sampler(r, ctx),
+        "vkDestroySamplerYcbcrConversion" => destroy_sampler_ycbcr_conversion(r, ctx),
+        "vkDestroySemaphore" => destroy_semaphore(r, ctx),
+        "vkDestroyShaderModule" => destroy_shader_module(r, ctx),
+        "vkDestroySurfaceKHR" => destroy_surface_khr(r, ctx),
+        "vkDestroySwapchainKHR" => destroy_swapchain_khr(r, ctx),
+        "vkDestroyValidationCacheEXT" => destroy_validation_cache_ext(r, ctx),
+        "vkDeviceWaitIdle" => device_wait_idle(r, ctx),
+        "vkDisplayPowerControlEXT" => display_power_control_ext(r, ctx),
+        "vkEndCommandBuffer" => end_command_buffer(r, ctx),
+        "vkEnumerateDeviceExtensionProperties" => enumerate_device_extension_properties(r, ctx),
+        "vkEnumerateDeviceLayerProperties" => enumerate_device_layer_properties(r, ctx),
+        "vkEnumeratePhysicalDeviceGroups" => enumerate_physical_device_groups(r, ctx),
+        "vkEnumeratePhysicalDevices" => enumerate_physical_devices(r, ctx),
+        "vkFlushMappedMemoryRanges" => flush_mapped_memory_ranges(r, ctx),
+        "vkFreeCommandBuffers" => free_command_buffers(r, ctx),
+        "vkFreeDescriptorSets" => free_descriptor_sets(r, ctx),
+        "vkFreeMemory" => free_memory(r, ctx),
+        "vkGetAccelerationStructureHandleNV" => get_acceleration_structure_handle_nv(r, ctx),
+        "vkGetAccelerationStructureMemoryRequirementsNV" => {
+            get_acceleration_structure_memory_requirements_nv(r, ctx)
+        }
+        "vkGetAndroidHardwareBufferPropertiesANDROID" => Err(ReplayError::Unsupported(
+            "vkGetAndroidHardwareBufferPropertiesANDROID",
+        )),
+        "vkGetBufferMemoryRequirements" => get_buffer_memory_requirements(r, ctx),
+        "vkGetBufferMemoryRequirements2" => get_buffer_memory_requirements2(r, ctx),
+        "vkGetCalibratedTimestampsEXT" => get_calibrated_timestamps_ext(r, ctx),
+        "vkGetDescriptorSetLayoutSupport" => get_descriptor_set_layout_support(r, ctx),
+        "vkGetDeviceGroupPeerMemoryFeatures" => get_device_group_peer_memory_features(r, ctx),
+        "vkGetDeviceGroupPresentCapabilitiesKHR" => {
+            get_device_group_present_capabilities_khr(r, ctx)
+        }
+        "vkGetDeviceGroupSurfacePresentModesKHR" => {
+            get_device_group_surface_present_modes_khr(r, ctx)
+        }
+        "vkGetDeviceMemoryCommitment" => get_device_memory_commitment(r, ctx),
+        "vkGetDeviceQueue" => get_device_queue(r, ctx),
+        "vkGetDeviceQueue2" => get_device_queue2(r, ctx),
+        "vkGetDisplayModeProperties2KHR" => get_display_mode_properties2_khr(r, ctx),
+        "vkGetDisplayModePropertiesKHR" => get_display_mode_properties_khr(r, ctx),
+        "vkGetDisplayPlaneCapabilities2KHR" => get_display_plane_capabilities2_khr(r, ctx),
+        "vkGetDisplayPlaneCapabilitiesKHR" => get_display_plane_capabilities_khr(r, ctx),
+        "vkGetDisplayPlaneSupportedDisplaysKHR" => get_display_plane_supported_displays_khr(r, ctx),
+        "vkGetEventStatus" => get_event_status(r, ctx),
+        "vkGetFenceFdKHR" => get_fence_fd_khr(r, ctx),
+        "vkGetFenceStatus" => get_fence_status(r, ctx),
+        "vkGetFenceWin32HandleKHR" => get_fence_win32_handle_khr(r, ctx),
+        "vkGetImageDrmFormatModifierPropertiesEXT" => {
+            get_image_drm_format_modifier_properties_ext(r, ctx)
+        }
+        "vkGetImageMemoryRequirements" => get_image_memory_requirements(r, ctx),
+        "vkGetImageMemoryRequirements2" => get_image_memory_requirements2(r, ctx),
+        "vkGetImageSparseMemoryRequirements" => get_image_sparse_memory_requirements(r, ctx),
+        "vkGetImageSparseMemoryRequirements2" => get_image_sparse_memory_requirements2(r, ctx),
+        "vkGetImageSubresourceLayout" => get_image_subresource_layout(r, ctx),
+        "vkGetMemoryAndroidHardwareBufferANDROID" => {
+            get_memory_android_hardware_buffer_android(r, ctx)
+        }
+        "vkGetMemoryFdKHR" => get_memory_fd_khr(r, ctx),
+        "vkGetMemoryFdPropertiesKHR" => get_memory_fd_properties_khr(r, ctx),
+        "vkGetMemoryHostPointerPropertiesEXT" => Err(ReplayError::Unsupported(
+            "vkGetMemoryHostPointerPropertiesEXT",
+        )),
+        "vkGetMemoryWin32HandleKHR" => get_memory_win32_handle_khr(r, ctx),
+        "vkGetMemoryWin32HandleNV" => get_memory_win32_handle_nv(r, ctx),
+        "vkGetMemoryWin32HandlePropertiesKHR" => get_memory_win32_handle_properties_khr(r, ctx),
+        "vkGetPastPresentationTimingGOOGLE" => get_past_presentation_timing_google(r, ctx),
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT" => {
+            get_physical_device_calibrateable_time_domains_ext(r, ctx)
+        }
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR" => {
+            get_physical_device_display_plane_properties2_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR" => {
+            get_physical_device_display_plane_properties_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceDisplayProperties2KHR" => {
+            get_physical_device_display_properties2_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceDisplayPropertiesKHR" => {
+            get_physical_device_display_properties_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceExternalBufferProperties" => {
+            get_physical_device_external_buffer_properties(r, ctx)
+        }
+        "vkGetPhysicalDeviceExternalFenceProperties" => {
+            get_physical_device_external_fence_properties(r, ctx)
+        }
+        "vkGetPhysicalDeviceExternalImageFormatPropertiesNV" => {
+            get_physical_device_external_image_format_properties_nv(r, ctx)
+        }
+        "vkGetPhysicalDeviceExternalSemaphoreProperties" => {
+            get_physical_device_external_semaphore_properties(r, ctx)
+        }
+        "vkGetPhysicalDeviceFeatures" => get_physical_device_features(r, ctx),
+        "vkGetPhysicalDeviceFeatures2" => get_physical_device_features2(r, ctx),
+        "vkGetPhysicalDeviceFormatProperties" => get_physical_device_format_properties(r, ctx),
+        "vkGetPhysicalDeviceFormatProperties2" => get_physical_device_format_properties2(r, ctx),
+        "vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX" => {
+            get_physical_device_generated_commands_properties_nvx(r, ctx)
+        }
+        "vkGetPhysicalDeviceImageFormatProperties" => {
+            get_physical_device_image_format_properties(r, ctx)
+        }
+        "vkGetPhysicalDeviceImageFormatProperties2" => {
+            get_physical_device_image_format_properties2(r, ctx)
+        }
+        "vkGetPhysicalDeviceMemoryProperties" => get_physical_device_memory_properties(r, ctx),
+        "vkGetPhysicalDeviceMemoryProperties2" => get_physical_device_memory_properties2(r, ctx),
+        "vkGetPhysicalDeviceMultisamplePropertiesEXT" => {
+            get_physical_device_multisample_properties_ext(r, ctx)
+        }
+        "vkGetPhysicalDevicePresentRectanglesKHR" => {
+            get_physical_device_present_rectangles_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceProperties" => get_physical_device_properties(r, ctx),
+        "vkGetPhysicalDeviceProperties2" => get_physical_device_properties2(r, ctx),
+        "vkGetPhysicalDeviceQueueFamilyProperties" => {
+            get_physical_device_queue_family_properties(r, ctx)
+        }
+        "vkGetPhysicalDeviceQueueFamilyProperties2" => {
+            get_physical_device_queue_family_properties2(r, ctx)
+        }
+        "vkGetPhysicalDeviceSparseImageFormatProperties" => {
+            get_physical_device_sparse_image_format_properties(r, ctx)
+        }
+        "vkGetPhysicalDeviceSparseImageFormatProperties2" => {
+            get_physical_device_sparse_image_format_properties2(r, ctx)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilities2EXT" => {
+            get_physical_device_surface_capabilities2_ext(r, ctx)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilities2KHR" => {
+            get_physical_device_surface_capabilities2_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => {
+            get_physical_device_surface_capabilities_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceSurfaceFormats2KHR" => get_physical_device_surface_formats2_khr(r, ctx),
+        "vkGetPhysicalDeviceSurfaceFormatsKHR" => get_physical_device_surface_formats_khr(r, ctx),
+        "vkGetPhysicalDeviceSurfacePresentModesKHR" => {
+            get_physical_device_surface_present_modes_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceSurfaceSupportKHR" => get_physical_device_surface_support_khr(r, ctx),
+        "vkGetPhysicalDeviceWaylandPresentationSupportKHR" => Err(ReplayError::Unsupported(
+            "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+        )),
+        "vkGetPhysicalDeviceWin32PresentationSupportKHR" => {
+            get_physical_device_win32_presentation_support_khr(r, ctx)
+        }
+        "vkGetPhysicalDeviceXcbPresentationSupportKHR" => Err(ReplayError::Unsupported(
+            "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+        )),
+        "vkGetPhysicalDeviceXlibPresentationSupportKHR" => Err(ReplayError::Unsupported(
+            "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+        )),
+        "vkGetPipelineCacheData" => get_pipeline_cache_data(r, ctx),
+        "vkGetQueryPoolResults" => get_query_pool_results(r, ctx),
+        "vkGetQueueCheckpointDataNV" => get_queue_checkpoint_data_nv(r, ctx),
+        "vkGetRandROutputDisplayEXT" => Err(ReplayError::Unsupported("vkGetRandROutputDisplayEXT")),
+        "vkGetRayTracingShaderGroupHandlesNV" => get_ray_tracing_shader_group_handles_nv(r, ctx),
+        "vkGetRefreshCycleDurationGOOGLE" => get_refresh_cycle_duration_google(r, ctx),
+        "vkGetRenderAreaGranularity" => get_render_area_granularity(r, ctx),
+        "vkGetSemaphoreFdKHR" => get_semaphore_fd_khr(r, ctx),
+        "vkGetSemaphoreWin32HandleKHR" => get_semaphore_win32_handle_khr(r, ctx),
+        "vkGetShaderInfoAMD" => get_shader_info_amd(r, ctx),
+        "vkGetSwapchainCounterEXT" => get_swapchain_counter_ext(r, ctx),
+        "vkGetSwapchainGrallocUsageANDROID" => get_swapchain_gralloc_usage_android(r, ctx),
+        "vkGetSwapchainImagesKHR" => get_swapchain_images_khr(r, ctx),
+        "vkGetSwapchainStatusKHR" => get_swapchain_status_khr(r, ctx),
+        "vkGetValidationCacheDataEXT" => get_validation_cache_data_ext(r, ctx),
+        "vkImportFenceFdKHR" => import_fence_fd_khr(r, ctx),
+        "vkImportFenceWin32HandleKHR" => {
+            Err(ReplayError::Unsupported("vkImportFenceWin32HandleKHR"))
+        }
+        "vkImportSemaphoreFdKHR" => import_semaphore_fd_khr(r, ctx),
+        "vkImportSemaphoreWin32HandleKHR" => {
+            Err(ReplayError::Unsupported("vkImportSemaphoreWin32HandleKHR"))
+        }
+        "vkInvalidateMappedMemoryRanges" => invalidate_mapped_memory_ranges(r, ctx),
+        "vkMergePipelineCaches" => merge_pipeline_caches(r, ctx),
+        "vkMergeValidationCachesEXT" => merge_validation_caches_ext(r, ctx),
+        "vkQueueBeginDebugUtilsLabelEXT" => queue_begin_debug_utils_label_ext(r, ctx),
+        "vkQueueBindSparse" => queue_bind_sparse(r, ctx),
+        "vkQueueEndDebugUtilsLabelEXT" => queue_end_debug_utils_label_ext(r, ctx),
+        "vkQueueInsertDebugUtilsLabelEXT" => queue_insert_debug_utils_label_ext(r, ctx),
+        "vkQueuePresentKHR" => queue_present_khr(r, ctx),
+        "vkQueueSignalReleaseImageANDROID" => queue_signal_release_image_android(r, ctx),
+        "vkQueueSubmit" => queue_submit(r, ctx),
+        "vkQueueWaitIdle" => queue_wait_idle(r, ctx),
+        "vkRegisterDeviceEventEXT" => register_device_event_ext(r, ctx),
+        "vkRegisterDisplayEventEXT" => register_display_event_ext(r, ctx),
+        "vkRegisterObjectsNVX" => register_objects_nvx(r, ctx),
+        "vkReleaseDisplayEXT" => release_display_ext(r, ctx),
+        "vkResetCommandBuffer" => reset_command_buffer(r, ctx),
+        "vkResetCommandPool" => reset_command_pool(r, ctx),
+        "vkResetDescriptorPool" => reset_descriptor_pool(r, ctx),
+        "vkResetEvent" => reset_event(r, ctx),
+        "vkResetFences" => reset_fences(r, ctx),
+        "vkSetDebugUtilsObjectNameEXT" => set_debug_utils_object_name_ext(r, ctx),
+        "vkSetDebugUtilsObjectTagEXT" => set_debug_utils_object_tag_ext(r, ctx),
+        "vkSetEvent" => set_event(r, ctx),
+        "vkSetHdrMetadataEXT" => set_hdr_metadata_ext(r, ctx),
+        "vkSubmitDebugUtilsMessageEXT" => submit_debug_utils_message_ext(r, ctx),
+        "vkTrimCommandPool" => trim_command_pool(r, ctx),
+        "vkUnmapMemory" => unmap_memory(r, ctx),
+        "vkUnregisterObjectsNVX" => unregister_objects_nvx(r, ctx),
+        "vkUpdateDescriptorSetWithTemplate" => Err(ReplayError::Unsupported(
+            "vkUpdateDescriptorSetWithTemplate",
+        )),
+        "vkUpdateDescriptorSets" => update_descriptor_sets(r, ctx),
+        "vkWaitForFences" => wait_for_fences(r, ctx),
+        _ => return None,
+    })
+}
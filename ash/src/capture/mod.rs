@@ -0,0 +1,1296 @@
+//! Capture of Vulkan command streams into a compact binary file, and their replay.
+//!
+//! `CaptureSink` is a `TraceSink` that serializes every traced call, including the structs its
+//! parameters point to and their pNext chains. Host writes to mapped memory are captured as well,
+//! as the difference to what the memory held when it was captured last, before every queue
+//! submission. Memory that is not host coherent is only compared in the ranges that were passed
+//! to `vkFlushMappedMemoryRanges`, the application has to call
+//! `vkGetPhysicalDeviceMemoryProperties` for the capture to know which memory that is.
+//! `Replayer` issues the calls of a capture again, against any driver, and maps the handles that
+//! the capture recorded to the ones the driver hands out.
+//!
+//! ```no_run
+//! # use ash::capture::{CaptureSink, Replayer};
+//! # use ash::trace::TracingEntry;
+//! # use ash::version::*;
+//! # use ash::{vk, Entry};
+//! # use std::fs::{self, File};
+//! # use std::io::BufWriter;
+//! # unsafe fn test(entry: &Entry, create_info: &vk::InstanceCreateInfo) {
+//! let file = BufWriter::new(File::create("hang.capture").unwrap());
+//! let capture = CaptureSink::new(file).unwrap();
+//! let instance = TracingEntry::new(entry, capture.clone())
+//!     .create_instance(create_info, None)
+//!     .unwrap();
+//! // Render as usual
+//! capture.flush().unwrap();
+//!
+//! // Later, possibly on another machine
+//! let mut replayer = Replayer::new(entry.static_fn());
+//! let stats = replayer.replay(&fs::read("hang.capture").unwrap()).unwrap();
+//! # }
+//! ```
+//!
+//! Commands that depend on the window system or on callbacks, such as `vkCreateXlibSurfaceKHR`,
+//! are captured but can not be replayed. The replayer either fails on them, or skips them when
+//! `skip_unsupported` is set, along with every later call that uses a handle they created.
+//! Handles that the replay has to provide itself, such as a surface, can be mapped with
+//! `Replayer::map_handle`.
+use std::any::Any;
+use std::collections::HashMap;
+use std::error::Error;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::io::{self, Write};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+use std::sync::{Arc, Mutex};
+use trace::{
+    self, Array, Bytes, Call, Count, Out, OutArray, OutBytes, Ptr, Str, TraceSink, UnionPtr,
+};
+use vk::{self, Handle};
+
+macro_rules! codec_raw {
+    ($($name: ident),*) => {
+        $(
+            impl Encode for $name {
+                unsafe fn encode(&self, w: &mut Writer) {
+                    self.as_raw().encode(w)
+                }
+            }
+
+            impl Decode for $name {
+                unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> ::capture::ReplayResult<Self> {
+                    Ok($name::from_raw(Decode::decode(r, ctx)?))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! codec_union {
+    ($($name: ident),*) => {
+        $(
+            impl Encode for $name {
+                unsafe fn encode(&self, w: &mut Writer) {
+                    let bytes = self as *const $name as *const u8;
+                    w.raw(::std::slice::from_raw_parts(bytes, ::std::mem::size_of::<$name>()))
+                }
+            }
+
+            impl Decode for $name {
+                unsafe fn decode(r: &mut Reader, _: &mut Context) -> ::capture::ReplayResult<Self> {
+                    let bytes = r.raw(::std::mem::size_of::<$name>())?;
+                    Ok(::std::ptr::read_unaligned(bytes.as_ptr() as *const $name))
+                }
+            }
+        )*
+    };
+}
+
+mod generated;
+
+pub(crate) type ReplayResult<T> = Result<T, ReplayError>;
+
+const MAGIC: &[u8] = b"ASHCAPT1";
+
+// Record tags
+const NAME: u64 = 0;
+const CALL: u64 = 1;
+const MEMORY: u64 = 2;
+
+// Pointer tags
+const NULL: u64 = 0;
+const PRESENT: u64 = 1;
+const WITH_OUTPUT: u64 = 2;
+
+/// Serializes values into a capture.
+pub struct Writer {
+    buf: Vec<u8>,
+    // Whether the driver wrote the output parameters, they are garbage if the call failed
+    outputs: bool,
+    handles: Option<Vec<(vk::ObjectType, u64)>>,
+}
+
+impl Writer {
+    fn new(outputs: bool) -> Self {
+        Writer {
+            buf: Vec::new(),
+            outputs,
+            handles: None,
+        }
+    }
+
+    /// A writer that records the handles it is given, in order.
+    fn collecting() -> Self {
+        Writer {
+            buf: Vec::new(),
+            outputs: true,
+            handles: Some(Vec::new()),
+        }
+    }
+
+    pub(crate) fn u64(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    pub(crate) fn i64(&mut self, value: i64) {
+        self.u64(((value << 1) ^ (value >> 63)) as u64)
+    }
+
+    pub(crate) fn raw(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes)
+    }
+
+    pub(crate) fn null(&mut self) {
+        self.u64(NULL)
+    }
+
+    pub(crate) fn handle(&mut self, object_type: vk::ObjectType, raw: u64) {
+        if let Some(ref mut handles) = self.handles {
+            handles.push((object_type, raw));
+        }
+        self.u64(raw)
+    }
+
+    pub(crate) unsafe fn ptr<T: Encode>(&mut self, p: *const T) {
+        match p.as_ref() {
+            Some(value) => {
+                self.u64(PRESENT);
+                value.encode(self)
+            }
+            None => self.null(),
+        }
+    }
+
+    pub(crate) unsafe fn array<T: Encode>(&mut self, p: *const T, len: usize) {
+        if p.is_null() {
+            return self.null();
+        }
+        self.u64(PRESENT);
+        self.u64(len as u64);
+        for value in slice::from_raw_parts(p, len) {
+            value.encode(self)
+        }
+    }
+
+    pub(crate) unsafe fn str(&mut self, p: *const c_char) {
+        if p.is_null() {
+            return self.null();
+        }
+        let bytes = CStr::from_ptr(p).to_bytes();
+        self.u64(PRESENT);
+        self.u64(bytes.len() as u64);
+        self.raw(bytes)
+    }
+
+    pub(crate) unsafe fn str_array(&mut self, p: *const *const c_char, len: usize) {
+        if p.is_null() {
+            return self.null();
+        }
+        self.u64(PRESENT);
+        self.u64(len as u64);
+        for &s in slice::from_raw_parts(p, len) {
+            self.str(s)
+        }
+    }
+
+    pub(crate) unsafe fn bytes(&mut self, p: *const u8, len: usize) {
+        if p.is_null() {
+            return self.null();
+        }
+        self.u64(PRESENT);
+        self.u64(len as u64);
+        self.raw(slice::from_raw_parts(p, len))
+    }
+
+    /// Writes the known structs of a pNext chain. Unknown structs are left out.
+    pub(crate) unsafe fn p_next(&mut self, mut p: *const c_void) {
+        while !p.is_null() {
+            let base = &*(p as *const vk::BaseInStructure);
+            let start = self.buf.len();
+            self.u64(PRESENT);
+            base.s_type.encode(self);
+            if generated::encode_structure(p, self) {
+                return;
+            }
+            self.buf.truncate(start);
+            p = base.p_next as *const c_void;
+        }
+        self.null()
+    }
+
+    unsafe fn out<T: Encode>(&mut self, p: *const T) {
+        if p.is_null() {
+            self.null()
+        } else if self.outputs {
+            self.u64(WITH_OUTPUT);
+            (*p).encode(self)
+        } else {
+            self.u64(PRESENT)
+        }
+    }
+
+    unsafe fn out_array<T: Encode>(&mut self, p: *const T, len: usize) {
+        if p.is_null() {
+            return self.null();
+        }
+        self.u64(if self.outputs { WITH_OUTPUT } else { PRESENT });
+        self.u64(len as u64);
+        if self.outputs {
+            for value in slice::from_raw_parts(p, len) {
+                value.encode(self)
+            }
+        }
+    }
+}
+
+/// A value that can be written to a capture. Implemented for every Vulkan type.
+pub trait Encode {
+    /// Pointers in `self` must be valid.
+    unsafe fn encode(&self, writer: &mut Writer);
+}
+
+pub(crate) trait Decode: Sized {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> Result<Self, ReplayError>;
+}
+
+#[derive(Clone)]
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Reader { data }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, ReplayError> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.raw(1)?.first().unwrap();
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ReplayError::Invalid("integer too long"))
+    }
+
+    pub(crate) fn i64(&mut self) -> Result<i64, ReplayError> {
+        let value = self.u64()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    pub(crate) fn raw(&mut self, len: usize) -> Result<&'a [u8], ReplayError> {
+        if len > self.data.len() {
+            return Err(ReplayError::Invalid("unexpected end of capture"));
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn len(&mut self) -> Result<usize, ReplayError> {
+        let len = self.u64()? as usize;
+        // Every element takes at least a byte
+        if len > self.data.len() {
+            return Err(ReplayError::Invalid("length exceeds the capture"));
+        }
+        Ok(len)
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], ReplayError> {
+        let len = self.len()?;
+        self.raw(len)
+    }
+}
+
+macro_rules! codec_unsigned {
+    ($($ty: ident),*) => {
+        $(
+            impl Encode for $ty {
+                unsafe fn encode(&self, w: &mut Writer) {
+                    w.u64(*self as u64)
+                }
+            }
+
+            impl Decode for $ty {
+                unsafe fn decode(r: &mut Reader, _: &mut Context) -> Result<Self, ReplayError> {
+                    Ok(r.u64()? as $ty)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! codec_signed {
+    ($($ty: ident),*) => {
+        $(
+            impl Encode for $ty {
+                unsafe fn encode(&self, w: &mut Writer) {
+                    w.i64(*self as i64)
+                }
+            }
+
+            impl Decode for $ty {
+                unsafe fn decode(r: &mut Reader, _: &mut Context) -> Result<Self, ReplayError> {
+                    Ok(r.i64()? as $ty)
+                }
+            }
+        )*
+    };
+}
+
+codec_unsigned!(u8, u16, u32, u64, usize);
+codec_signed!(i8, i16, i32, i64, isize);
+
+impl Encode for f32 {
+    unsafe fn encode(&self, w: &mut Writer) {
+        let bits = self.to_bits();
+        w.raw(&[
+            bits as u8,
+            (bits >> 8) as u8,
+            (bits >> 16) as u8,
+            (bits >> 24) as u8,
+        ])
+    }
+}
+
+impl Decode for f32 {
+    unsafe fn decode(r: &mut Reader, _: &mut Context) -> Result<Self, ReplayError> {
+        let bytes = r.raw(4)?;
+        let bits = bytes
+            .iter()
+            .rev()
+            .fold(0u32, |bits, &byte| bits << 8 | u32::from(byte));
+        Ok(f32::from_bits(bits))
+    }
+}
+
+impl<H: Handle + Copy> Encode for H {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.handle(H::TYPE, self.as_raw())
+    }
+}
+
+impl<H: Handle> Decode for H {
+    unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> Result<Self, ReplayError> {
+        let raw = r.u64()?;
+        if raw == 0 || !ctx.remap {
+            return Ok(H::from_raw(raw));
+        }
+        match ctx.replayer.handles.get(&(H::TYPE, raw)) {
+            Some(&raw) => Ok(H::from_raw(raw)),
+            None => Err(ReplayError::UnknownHandle(H::TYPE, raw)),
+        }
+    }
+}
+
+// Pointers that are not followed, such as window system handles, are captured as addresses
+impl<T> Encode for *const T {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.u64(*self as usize as u64)
+    }
+}
+
+impl<T> Decode for *const T {
+    unsafe fn decode(r: &mut Reader, _: &mut Context) -> Result<Self, ReplayError> {
+        Ok(r.u64()? as usize as *const T)
+    }
+}
+
+impl<T> Encode for *mut T {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.u64(*self as usize as u64)
+    }
+}
+
+impl<T> Decode for *mut T {
+    unsafe fn decode(r: &mut Reader, _: &mut Context) -> Result<Self, ReplayError> {
+        Ok(r.u64()? as usize as *mut T)
+    }
+}
+
+macro_rules! codec_array {
+    ($($len: expr),*) => {
+        $(
+            impl<T: Encode> Encode for [T; $len] {
+                unsafe fn encode(&self, w: &mut Writer) {
+                    for value in self.iter() {
+                        value.encode(w)
+                    }
+                }
+            }
+
+            impl<T: Decode> Decode for [T; $len] {
+                unsafe fn decode(r: &mut Reader, ctx: &mut Context) -> Result<Self, ReplayError> {
+                    let mut array: [T; $len] = mem::zeroed();
+                    for value in array.iter_mut() {
+                        ptr::write(value, Decode::decode(r, ctx)?);
+                    }
+                    Ok(array)
+                }
+            }
+        )*
+    };
+}
+
+codec_array!(2, 3, 4, 8, 16, 32, 256);
+
+impl<T: Encode> Encode for Ptr<T> {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.ptr(self.0)
+    }
+}
+
+impl<T: Encode> Encode for UnionPtr<T> {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.ptr(self.0)
+    }
+}
+
+impl<T: Encode> Encode for Array<T> {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.array(self.0, self.1)
+    }
+}
+
+impl Encode for Bytes {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.bytes(self.0, self.1)
+    }
+}
+
+impl Encode for Str {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.str(self.0)
+    }
+}
+
+impl<T: Encode> Encode for Out<T> {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.out(self.0)
+    }
+}
+
+impl<T: Encode, C: Count> Encode for OutArray<T, C> {
+    unsafe fn encode(&self, w: &mut Writer) {
+        w.out_array(self.0, self.len())
+    }
+}
+
+// The contents are not captured, the replay only needs room for them
+impl Encode for OutBytes {
+    unsafe fn encode(&self, w: &mut Writer) {
+        if self.0.is_null() {
+            return w.null();
+        }
+        w.u64(PRESENT);
+        w.u64(self.len() as u64)
+    }
+}
+
+/// Writes a capture of every call it receives into `W`. Clones write to the same capture, so
+/// one can be given to `TracingEntry::new` and another kept to `flush` the capture.
+pub struct CaptureSink<W> {
+    state: Arc<Mutex<CaptureState<W>>>,
+}
+
+impl<W> Clone for CaptureSink<W> {
+    fn clone(&self) -> Self {
+        CaptureSink {
+            state: self.state.clone(),
+        }
+    }
+}
+
+struct CaptureState<W> {
+    writer: W,
+    names: HashMap<&'static str, u64>,
+    // The first error, reported by `flush`. The application keeps running without a capture.
+    error: Option<io::Error>,
+    // Physical devices to the memory types that are host coherent, as a mask of their indices
+    coherent_memory_types: HashMap<u64, u32>,
+    // Devices to their physical devices
+    physical_devices: HashMap<u64, u64>,
+    allocations: HashMap<u64, Allocation>,
+    mappings: HashMap<u64, Mapping>,
+}
+
+struct Allocation {
+    size: u64,
+    coherent: bool,
+}
+
+struct Mapping {
+    ptr: usize,
+    // The offset of the mapping in the allocation
+    offset: u64,
+    // Writes to host coherent memory may be anywhere in the mapping, the others are announced
+    // by `vkFlushMappedMemoryRanges`.
+    coherent: bool,
+    // Ranges of the mapping that were flushed since they were captured last
+    dirty: Vec<(usize, usize)>,
+    // What the memory held when it was captured last
+    shadow: Vec<u8>,
+}
+
+impl Mapping {
+    /// Adds the flushed `range` of the allocation to the dirty ranges.
+    fn flushed(&mut self, range: &vk::MappedMemoryRange) {
+        let len = self.shadow.len() as u64;
+        let start = range.offset.saturating_sub(self.offset).min(len);
+        let end = match range.size {
+            vk::WHOLE_SIZE => len,
+            size => (range.offset + size).saturating_sub(self.offset).min(len),
+        };
+        if start < end {
+            self.dirty.push((start as usize, end as usize));
+        }
+    }
+
+    /// Writes the changes in `start..end` since the last capture into `w`.
+    unsafe fn capture(&mut self, memory: u64, start: usize, end: usize, w: &mut Writer) {
+        let current = slice::from_raw_parts(self.ptr as *const u8, self.shadow.len());
+        let mut offset = start;
+        while let Some(start) = (offset..end).find(|&i| current[i] != self.shadow[i]) {
+            let mut changed_end = start + 1;
+            let mut unchanged = 0;
+            while changed_end + unchanged < end && unchanged <= MAX_GAP {
+                if current[changed_end + unchanged] != self.shadow[changed_end + unchanged] {
+                    changed_end += unchanged + 1;
+                    unchanged = 0;
+                } else {
+                    unchanged += 1;
+                }
+            }
+            w.u64(MEMORY);
+            w.u64(memory);
+            w.u64(start as u64);
+            w.bytes(current[start..changed_end].as_ptr(), changed_end - start);
+            self.shadow[start..changed_end].copy_from_slice(&current[start..changed_end]);
+            offset = changed_end;
+        }
+    }
+}
+
+// Unchanged bytes between two changed ones that are still captured as one write
+const MAX_GAP: usize = 32;
+
+impl<W: Write> CaptureState<W> {
+    fn write(&mut self, record: &[u8]) {
+        if self.error.is_none() {
+            if let Err(err) = self.writer.write_all(record) {
+                self.error = Some(err);
+            }
+        }
+    }
+
+    /// Captures the host writes to mapped memory since the last time. Only the flushed ranges
+    /// of memory that is not host coherent are compared to what they held before.
+    unsafe fn capture_memory(&mut self) {
+        let mut w = Writer::new(false);
+        for (&memory, mapping) in self.mappings.iter_mut() {
+            let dirty = if mapping.coherent {
+                vec![(0, mapping.shadow.len())]
+            } else {
+                mem::replace(&mut mapping.dirty, Vec::new())
+            };
+            for (start, end) in dirty {
+                mapping.capture(memory, start, end, &mut w);
+            }
+        }
+        let record = w.buf;
+        self.write(&record);
+    }
+
+    unsafe fn flush_memory(&mut self, ranges: &Array<vk::MappedMemoryRange>) {
+        if ranges.0.is_null() {
+            return;
+        }
+        for range in slice::from_raw_parts(ranges.0, ranges.1) {
+            if let Some(mapping) = self.mappings.get_mut(&range.memory.as_raw()) {
+                mapping.flushed(range);
+            }
+        }
+    }
+
+    unsafe fn track_memory(&mut self, call: &Call) {
+        match call.command {
+            "vkGetPhysicalDeviceMemoryProperties" | "vkGetPhysicalDeviceMemoryProperties2" => {
+                let properties = match (
+                    arg::<Out<vk::PhysicalDeviceMemoryProperties>>(call, 1),
+                    arg::<Out<vk::PhysicalDeviceMemoryProperties2>>(call, 1),
+                ) {
+                    (Some(properties), _) => &*properties.0,
+                    (_, Some(properties)) => &(*properties.0).memory_properties,
+                    _ => return,
+                };
+                if let Some(&physical_device) = arg::<vk::PhysicalDevice>(call, 0) {
+                    let memory_types = &properties.memory_types
+                        [..(properties.memory_type_count as usize).min(vk::MAX_MEMORY_TYPES)];
+                    let coherent = memory_types
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, memory_type)| {
+                            memory_type
+                                .property_flags
+                                .contains(vk::MemoryPropertyFlags::HOST_COHERENT)
+                        })
+                        .fold(0, |mask, (index, _)| mask | 1 << index);
+                    self.coherent_memory_types
+                        .insert(physical_device.as_raw(), coherent);
+                }
+            }
+            "vkCreateDevice" => {
+                if let (Some(&physical_device), Some(device)) = (
+                    arg::<vk::PhysicalDevice>(call, 0),
+                    arg::<Out<vk::Device>>(call, 3),
+                ) {
+                    self.physical_devices
+                        .insert((*device.0).as_raw(), physical_device.as_raw());
+                }
+            }
+            "vkDestroyDevice" => {
+                if let Some(&device) = arg::<vk::Device>(call, 0) {
+                    self.physical_devices.remove(&device.as_raw());
+                }
+            }
+            "vkAllocateMemory" => {
+                if let (Some(&device), Some(info), Some(memory)) = (
+                    arg::<vk::Device>(call, 0),
+                    arg::<Ptr<vk::MemoryAllocateInfo>>(call, 1),
+                    arg::<Out<vk::DeviceMemory>>(call, 3),
+                ) {
+                    // Without the memory properties, every write has to be looked for
+                    let coherent = self
+                        .physical_devices
+                        .get(&device.as_raw())
+                        .and_then(|physical_device| self.coherent_memory_types.get(physical_device))
+                        .map_or(true, |&coherent| {
+                            coherent & 1u32.wrapping_shl((*info.0).memory_type_index) != 0
+                        });
+                    self.allocations.insert(
+                        (*memory.0).as_raw(),
+                        Allocation {
+                            size: (*info.0).allocation_size,
+                            coherent,
+                        },
+                    );
+                }
+            }
+            "vkMapMemory" => {
+                if let (Some(&memory), Some(&offset), Some(&size), Some(data)) = (
+                    arg::<vk::DeviceMemory>(call, 1),
+                    arg::<vk::DeviceSize>(call, 2),
+                    arg::<vk::DeviceSize>(call, 3),
+                    arg::<Out<*mut c_void>>(call, 5),
+                ) {
+                    let allocation = match self.allocations.get(&memory.as_raw()) {
+                        Some(allocation) => allocation,
+                        None => return,
+                    };
+                    let size = match size {
+                        vk::WHOLE_SIZE => allocation.size - offset,
+                        size => size,
+                    };
+                    let ptr = *data.0 as *const u8;
+                    let mapping = Mapping {
+                        ptr: ptr as usize,
+                        offset,
+                        coherent: allocation.coherent,
+                        dirty: Vec::new(),
+                        shadow: slice::from_raw_parts(ptr, size as usize).to_vec(),
+                    };
+                    self.mappings.insert(memory.as_raw(), mapping);
+                }
+            }
+            "vkUnmapMemory" | "vkFreeMemory" => {
+                if let Some(&memory) = arg::<vk::DeviceMemory>(call, 1) {
+                    self.mappings.remove(&memory.as_raw());
+                    if call.command == "vkFreeMemory" {
+                        self.allocations.remove(&memory.as_raw());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn arg<'a, T: 'static>(call: &'a Call, index: usize) -> Option<&'a T> {
+    call.args
+        .get(index)
+        .and_then(|&(_, value)| value.as_any().downcast_ref::<T>())
+}
+
+impl<W: Write + Send> CaptureSink<W> {
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(MAGIC)?;
+        Ok(CaptureSink {
+            state: Arc::new(Mutex::new(CaptureState {
+                writer,
+                names: HashMap::new(),
+                error: None,
+                coherent_memory_types: HashMap::new(),
+                physical_devices: HashMap::new(),
+                allocations: HashMap::new(),
+                mappings: HashMap::new(),
+            })),
+        })
+    }
+
+    /// Flushes the writer. Returns the first error that occurred while capturing.
+    pub fn flush(&self) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(err) = state.error.take() {
+            return Err(err);
+        }
+        state.writer.flush()
+    }
+}
+
+impl<W: Write + Send> TraceSink for CaptureSink<W> {
+    fn before_call(&self, command: &'static str, args: &[(&'static str, &dyn trace::Value)]) {
+        match command {
+            "vkQueueSubmit" | "vkFlushMappedMemoryRanges" | "vkUnmapMemory" => {
+                let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+                unsafe {
+                    if command == "vkFlushMappedMemoryRanges" {
+                        if let Some(ranges) = args
+                            .get(2)
+                            .and_then(|&(_, value)| value.as_any().downcast_ref())
+                        {
+                            state.flush_memory(ranges);
+                        }
+                    }
+                    state.capture_memory()
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn call(&self, call: &Call) {
+        let outputs = match call.result {
+            Some(result) => match result.as_any().downcast_ref::<vk::Result>() {
+                Some(result) => result.as_raw() >= 0,
+                None => true,
+            },
+            None => true,
+        };
+        let mut params = Writer::new(outputs);
+        unsafe {
+            for &(_, value) in call.args {
+                value.encode(&mut params);
+            }
+            if let Some(result) = call.result {
+                result.encode(&mut params);
+            }
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        let mut w = Writer::new(outputs);
+        let next_id = state.names.len() as u64;
+        let id = *state.names.entry(call.command).or_insert(next_id);
+        if id == next_id {
+            w.u64(NAME);
+            w.u64(call.command.len() as u64);
+            w.raw(call.command.as_bytes());
+        }
+        w.u64(CALL);
+        w.u64(id);
+        w.u64(params.buf.len() as u64);
+        w.raw(&params.buf);
+        state.write(&w.buf);
+        if outputs {
+            unsafe { state.track_memory(call) }
+        }
+    }
+}
+
+/// Counts of a replay.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReplayStats {
+    pub calls: usize,
+    /// Calls that were not replayed, see `Replayer::skip_unsupported`.
+    pub skipped: usize,
+    /// Calls that returned something else than in the capture.
+    pub result_mismatches: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The capture is malformed or truncated.
+    Invalid(&'static str),
+    /// The capture holds a command this version of ash does not know.
+    UnknownCommand(String),
+    /// The command depends on the window system or on callbacks.
+    Unsupported(&'static str),
+    /// The driver does not provide the command.
+    MissingCommand(&'static str),
+    /// A handle that the capture did not create, and that was not mapped.
+    UnknownHandle(vk::ObjectType, u64),
+    /// The capture writes to memory that was not mapped during the replay.
+    NotMapped(u64),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReplayError::Invalid(reason) => write!(f, "Invalid capture: {}", reason),
+            ReplayError::UnknownCommand(ref name) => write!(f, "Unknown command {}", name),
+            ReplayError::Unsupported(name) => write!(f, "{} can not be replayed", name),
+            ReplayError::MissingCommand(name) => write!(f, "The driver does not provide {}", name),
+            ReplayError::UnknownHandle(object_type, raw) => {
+                write!(f, "Unknown {:?} 0x{:x}", object_type, raw)
+            }
+            ReplayError::NotMapped(memory) => write!(f, "Memory 0x{:x} is not mapped", memory),
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn description(&self) -> &str {
+        "ReplayError"
+    }
+}
+
+/// Replays captures made by `CaptureSink`.
+pub struct Replayer {
+    static_fn: vk::StaticFn,
+    instance: vk::Instance,
+    fns: HashMap<&'static str, usize>,
+    handles: HashMap<(vk::ObjectType, u64), u64>,
+    // Captured memory handles to the pointers they are mapped at during the replay
+    mapped: HashMap<u64, *mut u8>,
+    skip_unsupported: bool,
+}
+
+impl Replayer {
+    /// `static_fn` is used to load every command. The entry it belongs to must outlive the
+    /// replayer.
+    pub fn new(static_fn: &vk::StaticFn) -> Self {
+        Replayer {
+            static_fn: static_fn.clone(),
+            instance: vk::Instance::null(),
+            fns: HashMap::new(),
+            handles: HashMap::new(),
+            mapped: HashMap::new(),
+            skip_unsupported: false,
+        }
+    }
+
+    /// Skips calls that can not be replayed, and calls that use the handles they created,
+    /// instead of failing.
+    pub fn skip_unsupported(mut self, skip_unsupported: bool) -> Self {
+        self.skip_unsupported = skip_unsupported;
+        self
+    }
+
+    /// Replays `captured` as `replayed`. Mapping an instance makes it the one commands are
+    /// loaded from.
+    pub fn map_handle<H: Handle>(&mut self, captured: H, replayed: H) {
+        let (captured, replayed) = (captured.as_raw(), replayed.as_raw());
+        self.insert_handle(H::TYPE, captured, replayed);
+    }
+
+    /// The handle the replay uses in place of `captured`.
+    pub fn handle<H: Handle>(&self, captured: H) -> Option<H> {
+        self.handles
+            .get(&(H::TYPE, captured.as_raw()))
+            .map(|&raw| H::from_raw(raw))
+    }
+
+    fn insert_handle(&mut self, object_type: vk::ObjectType, captured: u64, replayed: u64) {
+        if object_type == vk::ObjectType::INSTANCE {
+            self.instance = vk::Instance::from_raw(replayed);
+            self.fns.clear();
+        }
+        self.handles.insert((object_type, captured), replayed);
+    }
+
+    /// Issues every call of `capture`. Objects the calls create are not destroyed, unless
+    /// the capture destroys them.
+    pub unsafe fn replay(&mut self, capture: &[u8]) -> Result<ReplayStats, ReplayError> {
+        if !capture.starts_with(MAGIC) {
+            return Err(ReplayError::Invalid("not a capture"));
+        }
+        let mut r = Reader::new(&capture[MAGIC.len()..]);
+        let mut names = Vec::new();
+        let mut stats = ReplayStats::default();
+        while !r.is_empty() {
+            match r.u64()? {
+                NAME => {
+                    let name = String::from_utf8_lossy(r.bytes()?).into_owned();
+                    names.push(name);
+                }
+                CALL => {
+                    let name = names
+                        .get(r.u64()? as usize)
+                        .ok_or(ReplayError::Invalid("undefined command name"))?;
+                    let mut call = Reader::new(r.bytes()?);
+                    self.replay_call(name, &mut call, &mut stats)?;
+                }
+                MEMORY => {
+                    let memory = r.u64()?;
+                    let offset = r.u64()? as usize;
+                    if r.u64()? != PRESENT {
+                        return Err(ReplayError::Invalid("memory write without data"));
+                    }
+                    let data = r.bytes()?;
+                    match self.mapped.get(&memory) {
+                        Some(&ptr) => {
+                            ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(offset), data.len())
+                        }
+                        None if self.skip_unsupported => {}
+                        None => return Err(ReplayError::NotMapped(memory)),
+                    }
+                }
+                _ => return Err(ReplayError::Invalid("unknown record")),
+            }
+        }
+        Ok(stats)
+    }
+
+    unsafe fn replay_call(
+        &mut self,
+        name: &str,
+        r: &mut Reader,
+        stats: &mut ReplayStats,
+    ) -> Result<(), ReplayError> {
+        // The memory parameter follows the device
+        let unmapped = match name {
+            "vkUnmapMemory" | "vkFreeMemory" => {
+                let mut params = r.clone();
+                params.u64()?;
+                Some(params.u64()?)
+            }
+            _ => None,
+        };
+        let result = {
+            let mut ctx = Context::new(self, stats);
+            if name == "vkMapMemory" {
+                map_memory(r, &mut ctx)
+            } else {
+                generated::replay_command(name, r, &mut ctx)
+                    .unwrap_or_else(|| Err(ReplayError::UnknownCommand(name.to_owned())))
+            }
+        };
+        match result {
+            Ok(()) => {
+                stats.calls += 1;
+                if let Some(memory) = unmapped {
+                    self.mapped.remove(&memory);
+                }
+                Ok(())
+            }
+            Err(ReplayError::Unsupported(_))
+            | Err(ReplayError::UnknownCommand(_))
+            | Err(ReplayError::UnknownHandle(..))
+                if self.skip_unsupported =>
+            {
+                stats.skipped += 1;
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+unsafe fn map_memory(r: &mut Reader, ctx: &mut Context) -> Result<(), ReplayError> {
+    let device = Decode::decode(r, ctx)?;
+    let captured_memory = r.clone().u64()?;
+    let memory = Decode::decode(r, ctx)?;
+    let offset = Decode::decode(r, ctx)?;
+    let size = Decode::decode(r, ctx)?;
+    let flags = Decode::decode(r, ctx)?;
+    let pp_data: *mut *mut c_void = ctx.out(r)?;
+    let captured = Decode::decode(r, ctx)?;
+    let fp: vk::PFN_vkMapMemory = ctx.fp("vkMapMemory")?;
+    let result = fp(device, memory, offset, size, flags, pp_data);
+    ctx.finish(result, captured);
+    if result == vk::Result::SUCCESS && !pp_data.is_null() {
+        ctx.replayer
+            .mapped
+            .insert(captured_memory, *pp_data as *mut u8);
+    }
+    Ok(())
+}
+
+/// The return types of commands.
+pub(crate) trait CommandResult: PartialEq {
+    fn succeeded(&self) -> bool;
+}
+
+impl CommandResult for () {
+    fn succeeded(&self) -> bool {
+        true
+    }
+}
+
+impl CommandResult for vk::Result {
+    fn succeeded(&self) -> bool {
+        self.as_raw() >= 0
+    }
+}
+
+impl CommandResult for vk::Bool32 {
+    fn succeeded(&self) -> bool {
+        true
+    }
+}
+
+/// The state of one replayed call. Everything its parameters point to lives in `arena`.
+pub(crate) struct Context<'a> {
+    replayer: &'a mut Replayer,
+    stats: &'a mut ReplayStats,
+    arena: Vec<Box<dyn Any>>,
+    // Whether handles are translated. Output parameters keep the captured handles.
+    remap: bool,
+    // Writes the captured and the replayed value of each output parameter
+    outputs: Vec<Box<dyn Fn(&mut Writer, &mut Writer)>>,
+}
+
+impl<'a> Context<'a> {
+    fn new(replayer: &'a mut Replayer, stats: &'a mut ReplayStats) -> Self {
+        Context {
+            replayer,
+            stats,
+            arena: Vec::new(),
+            remap: true,
+            outputs: Vec::new(),
+        }
+    }
+
+    fn keep<T: 'static>(&mut self, mut value: Box<T>) -> *mut T {
+        let p: *mut T = &mut *value;
+        self.arena.push(value);
+        p
+    }
+
+    pub(crate) unsafe fn fp<F: Copy>(&mut self, name: &'static str) -> Result<F, ReplayError> {
+        assert_eq!(mem::size_of::<F>(), mem::size_of::<usize>());
+        if let Some(fp) = self.replayer.fns.get(name) {
+            return Ok(mem::transmute_copy(fp));
+        }
+        let c_name = CString::new(name).unwrap();
+        let fp = (self.replayer.static_fn.get_instance_proc_addr)(
+            self.replayer.instance,
+            c_name.as_ptr(),
+        )
+        .ok_or(ReplayError::MissingCommand(name))? as usize;
+        self.replayer.fns.insert(name, fp);
+        Ok(mem::transmute_copy(&fp))
+    }
+
+    pub(crate) unsafe fn boxed<T: Decode + 'static>(
+        &mut self,
+        r: &mut Reader,
+    ) -> Result<*mut T, ReplayError> {
+        let value = T::decode(r, self)?;
+        Ok(self.keep(Box::new(value)))
+    }
+
+    pub(crate) unsafe fn ptr<T: Decode + 'static>(
+        &mut self,
+        r: &mut Reader,
+    ) -> Result<*const T, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null()),
+            _ => Ok(self.boxed(r)?),
+        }
+    }
+
+    /// Allocation callbacks are not captured, the replay uses the default allocator.
+    pub(crate) unsafe fn allocator(
+        &mut self,
+        r: &mut Reader,
+    ) -> Result<*const vk::AllocationCallbacks, ReplayError> {
+        self.ptr::<vk::AllocationCallbacks>(r)?;
+        Ok(ptr::null())
+    }
+
+    unsafe fn vec<T: Decode>(&mut self, r: &mut Reader) -> Result<Vec<T>, ReplayError> {
+        let len = r.len()?;
+        let mut values = Vec::with_capacity(len);
+        for _ in 0..len {
+            values.push(T::decode(r, self)?);
+        }
+        Ok(values)
+    }
+
+    pub(crate) unsafe fn array<T: Decode + 'static>(
+        &mut self,
+        r: &mut Reader,
+    ) -> Result<*const T, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null()),
+            _ => {
+                let values = self.vec(r)?;
+                Ok((*self.keep(Box::new(values))).as_ptr())
+            }
+        }
+    }
+
+    pub(crate) unsafe fn str(&mut self, r: &mut Reader) -> Result<*const c_char, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null()),
+            _ => {
+                let s = CString::new(r.bytes()?)
+                    .map_err(|_| ReplayError::Invalid("string with a nul byte"))?;
+                Ok((*self.keep(Box::new(s))).as_ptr())
+            }
+        }
+    }
+
+    pub(crate) unsafe fn str_array(
+        &mut self,
+        r: &mut Reader,
+    ) -> Result<*const *const c_char, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null()),
+            _ => {
+                let len = r.len()?;
+                let mut strs = Vec::with_capacity(len);
+                for _ in 0..len {
+                    strs.push(self.str(r)?);
+                }
+                Ok((*self.keep(Box::new(strs))).as_ptr())
+            }
+        }
+    }
+
+    pub(crate) unsafe fn bytes(&mut self, r: &mut Reader) -> Result<*const c_void, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null()),
+            _ => {
+                let bytes = r.bytes()?.to_vec();
+                Ok((*self.keep(Box::new(bytes))).as_ptr() as *const c_void)
+            }
+        }
+    }
+
+    pub(crate) unsafe fn p_next(&mut self, r: &mut Reader) -> Result<*const c_void, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null()),
+            _ => {
+                let s_type = Decode::decode(r, self)?;
+                generated::decode_structure(s_type, r, self)
+            }
+        }
+    }
+
+    /// Decodes the captured value of an output parameter twice, once to compare with, and
+    /// once as the storage for the driver, which may depend on it as with `sType`.
+    unsafe fn decode_twice<T, F>(
+        &mut self,
+        r: &mut Reader,
+        decode: F,
+    ) -> Result<(T, T), ReplayError>
+    where
+        F: Fn(&mut Self, &mut Reader) -> Result<T, ReplayError>,
+    {
+        let mut captured_reader = r.clone();
+        self.remap = false;
+        let captured = decode(self, &mut captured_reader);
+        let replayed = decode(self, r);
+        self.remap = true;
+        Ok((captured?, replayed?))
+    }
+
+    pub(crate) unsafe fn out<T: Decode + Encode + 'static>(
+        &mut self,
+        r: &mut Reader,
+    ) -> Result<*mut T, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null_mut()),
+            WITH_OUTPUT => {
+                let (captured, replayed) = self.decode_twice(r, |ctx, r| T::decode(r, ctx))?;
+                let captured = self.keep(Box::new(captured));
+                let replayed = self.keep(Box::new(replayed));
+                self.outputs
+                    .push(Box::new(move |c: &mut Writer, w: &mut Writer| unsafe {
+                        (*captured).encode(c);
+                        (*replayed).encode(w);
+                    }));
+                Ok(replayed)
+            }
+            _ => Ok(self.keep(Box::new(mem::zeroed()))),
+        }
+    }
+
+    pub(crate) unsafe fn out_array<T: Decode + Encode + 'static>(
+        &mut self,
+        r: &mut Reader,
+    ) -> Result<*mut T, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null_mut()),
+            WITH_OUTPUT => {
+                let (captured, replayed) = self.decode_twice(r, |ctx, r| ctx.vec::<T>(r))?;
+                let captured = self.keep(Box::new(captured));
+                let replayed = self.keep(Box::new(replayed));
+                self.outputs
+                    .push(Box::new(move |c: &mut Writer, w: &mut Writer| unsafe {
+                        for value in (*captured).iter() {
+                            value.encode(c);
+                        }
+                        for value in (*replayed).iter() {
+                            value.encode(w);
+                        }
+                    }));
+                Ok((*replayed).as_mut_ptr())
+            }
+            _ => {
+                let len = r.u64()? as usize;
+                let values = (0..len).map(|_| mem::zeroed()).collect::<Vec<T>>();
+                Ok((*self.keep(Box::new(values))).as_mut_ptr())
+            }
+        }
+    }
+
+    pub(crate) unsafe fn out_bytes(&mut self, r: &mut Reader) -> Result<*mut c_void, ReplayError> {
+        match r.u64()? {
+            NULL => Ok(ptr::null_mut()),
+            _ => {
+                let len = r.u64()? as usize;
+                let mut bytes = Box::new(vec![0u8; len]);
+                let p = bytes.as_mut_ptr() as *mut c_void;
+                self.arena.push(bytes);
+                Ok(p)
+            }
+        }
+    }
+
+    /// Records the handles the call created, if both the capture and the replay succeeded.
+    pub(crate) fn finish<R: CommandResult>(&mut self, replayed: R, captured: R) {
+        if replayed != captured {
+            self.stats.result_mismatches += 1;
+        }
+        if !replayed.succeeded() || !captured.succeeded() {
+            return;
+        }
+        for output in &self.outputs {
+            let (mut c, mut w) = (Writer::collecting(), Writer::collecting());
+            output(&mut c, &mut w);
+            let captured = c.handles.unwrap_or_default();
+            let replayed = w.handles.unwrap_or_default();
+            for (&(object_type, captured), &(_, replayed)) in captured.iter().zip(&replayed) {
+                if captured != 0 {
+                    self.replayer.insert_handle(object_type, captured, replayed);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,774 @@
+//! A command buffer recorder that tracks the state of the command buffer in its type.
+//!
+//! The `cmd_*` functions of `DeviceV1_0` accept any `vk::CommandBuffer`. `CommandBuffer` only
+//! exposes the commands that are valid in its current state:
+//!
+//! * `Initial`: can be begun.
+//! * `Recording`: commands outside of a render pass, e.g. `cmd_copy_buffer` and `cmd_dispatch`.
+//! * `InRenderPassInline`: commands inside of a subpass with inline contents, e.g. `cmd_draw`.
+//! * `InRenderPassSecondary`: a subpass whose contents are recorded in secondary command
+//!   buffers, so only `cmd_execute_commands` is available.
+//! * `Executable`: can be submitted with `handle`, or reset.
+//!
+//! Commands that are valid both inside and outside of a render pass, such as
+//! `cmd_bind_pipeline`, are available in both states.
+//!
+//! ```no_run
+//! # use ash::command_buffer::CommandBuffer;
+//! # use ash::vk;
+//! # unsafe fn test(
+//! #     device: &ash::Device,
+//! #     command_buffer: vk::CommandBuffer,
+//! #     begin_info: &vk::CommandBufferBeginInfo,
+//! #     render_pass_begin_info: &vk::RenderPassBeginInfo,
+//! #     pipeline: vk::Pipeline,
+//! # ) -> ash::prelude::VkResult<()> {
+//! let recording = CommandBuffer::new(device, command_buffer).begin(begin_info)?;
+//! let render_pass = recording.begin_render_pass_inline(render_pass_begin_info);
+//! render_pass.cmd_bind_pipeline(vk::PipelineBindPoint::GRAPHICS, pipeline);
+//! render_pass.cmd_draw(3, 1, 0, 0);
+//! let executable = render_pass.end_render_pass().end()?;
+//! // `executable.handle()` can now be submitted
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Recording a draw outside of a render pass does not compile:
+//!
+//! ```compile_fail
+//! # use ash::command_buffer::CommandBuffer;
+//! # use ash::vk;
+//! # unsafe fn test(device: &ash::Device, command_buffer: vk::CommandBuffer) {
+//! let recording = CommandBuffer::new(device, command_buffer)
+//!     .begin(&Default::default())
+//!     .unwrap();
+//! recording.cmd_draw(3, 1, 0, 0);
+//! # }
+//! ```
+//!
+//! Neither can a subpass whose contents are recorded in secondary command buffers draw:
+//!
+//! ```compile_fail
+//! # use ash::command_buffer::CommandBuffer;
+//! # use ash::vk;
+//! # unsafe fn test(device: &ash::Device, command_buffer: vk::CommandBuffer, render_pass_begin_info: &vk::RenderPassBeginInfo) {
+//! let render_pass = CommandBuffer::new(device, command_buffer)
+//!     .begin(&Default::default())
+//!     .unwrap()
+//!     .begin_render_pass_secondary(render_pass_begin_info);
+//! render_pass.cmd_draw(3, 1, 0, 0);
+//! # }
+//! ```
+//!
+//! Nor can a secondary command buffer that continues a render pass execute other command
+//! buffers:
+//!
+//! ```compile_fail
+//! # use ash::command_buffer::CommandBuffer;
+//! # use ash::vk;
+//! # unsafe fn test(device: &ash::Device, command_buffer: vk::CommandBuffer, secondary: vk::CommandBuffer) {
+//! let inherited = CommandBuffer::new(device, command_buffer)
+//!     .begin_render_pass_continue(&Default::default())
+//!     .unwrap();
+//! inherited.cmd_execute_commands(&[secondary]);
+//! # }
+//! ```
+//!
+//! The recorder only tracks the state of the command buffer on the host. It is up to the
+//! application to not reset or free the command buffer while it is in use, and to not use the
+//! raw handle to bypass the state tracking.
+use prelude::*;
+use std::fmt;
+use std::marker::PhantomData;
+use version::{DeviceV1_0, DeviceV1_1};
+use vk;
+
+/// The command buffer has been allocated or reset.
+#[derive(Debug)]
+pub enum Initial {}
+/// Between `begin` and `end`, outside of a render pass.
+#[derive(Debug)]
+pub enum Recording {}
+/// In a subpass with `SubpassContents::INLINE`, between `begin_render_pass_inline` or
+/// `next_subpass_inline` and the next subpass or `end_render_pass`.
+#[derive(Debug)]
+pub enum InRenderPassInline {}
+/// In a subpass with `SubpassContents::SECONDARY_COMMAND_BUFFERS`, between
+/// `begin_render_pass_secondary` or `next_subpass_secondary` and the next subpass or
+/// `end_render_pass`.
+#[derive(Debug)]
+pub enum InRenderPassSecondary {}
+/// A secondary command buffer that was begun with
+/// `CommandBufferUsageFlags::RENDER_PASS_CONTINUE`, and is entirely inside a render pass.
+#[derive(Debug)]
+pub enum InheritedRenderPass {}
+/// Recording has ended, the command buffer can be submitted.
+#[derive(Debug)]
+pub enum Executable {}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Recording {}
+    impl Sealed for super::InRenderPassInline {}
+    impl Sealed for super::InRenderPassSecondary {}
+    impl Sealed for super::InheritedRenderPass {}
+}
+
+/// States in which commands can be recorded.
+pub trait RecordingState: sealed::Sealed {}
+impl RecordingState for Recording {}
+impl RecordingState for InRenderPassInline {}
+impl RecordingState for InheritedRenderPass {}
+
+/// States that are inside of a render pass instance, and can draw.
+pub trait RenderPassState: RecordingState {}
+impl RenderPassState for InRenderPassInline {}
+impl RenderPassState for InheritedRenderPass {}
+
+/// The subpass states of a render pass instance that was begun in this command buffer.
+pub trait SubpassState: sealed::Sealed {}
+impl SubpassState for InRenderPassInline {}
+impl SubpassState for InRenderPassSecondary {}
+
+/// States in which secondary command buffers can be executed. Secondary command buffers
+/// cannot execute other secondary command buffers, so `InheritedRenderPass` is excluded, and
+/// neither can inline subpasses.
+pub trait ExecuteCommandsState: sealed::Sealed {}
+impl ExecuteCommandsState for Recording {}
+impl ExecuteCommandsState for InRenderPassSecondary {}
+
+/// A `vk::CommandBuffer` in state `S`. See the module documentation.
+pub struct CommandBuffer<'d, D: DeviceV1_0 + 'd, S> {
+    device: &'d D,
+    handle: vk::CommandBuffer,
+    _state: PhantomData<S>,
+}
+
+impl<'d, D: DeviceV1_0, S> CommandBuffer<'d, D, S> {
+    fn transition<T>(self) -> CommandBuffer<'d, D, T> {
+        CommandBuffer {
+            device: self.device,
+            handle: self.handle,
+            _state: PhantomData,
+        }
+    }
+
+    pub fn device(&self) -> &'d D {
+        self.device
+    }
+
+    /// Returns the raw handle regardless of the state. Recording into it directly bypasses the
+    /// state tracking.
+    pub unsafe fn raw_handle(&self) -> vk::CommandBuffer {
+        self.handle
+    }
+}
+
+impl<'d, D: DeviceV1_0, S> fmt::Debug for CommandBuffer<'d, D, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CommandBuffer")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<'d, D: DeviceV1_0> CommandBuffer<'d, D, Initial> {
+    /// `command_buffer` must have been allocated from `device` and be in the initial state,
+    /// i.e. freshly allocated or reset.
+    pub unsafe fn new(device: &'d D, command_buffer: vk::CommandBuffer) -> Self {
+        CommandBuffer {
+            device,
+            handle: command_buffer,
+            _state: PhantomData,
+        }
+    }
+
+    pub unsafe fn begin(
+        self,
+        begin_info: &vk::CommandBufferBeginInfo,
+    ) -> VkResult<CommandBuffer<'d, D, Recording>> {
+        self.device.begin_command_buffer(self.handle, begin_info)?;
+        Ok(self.transition())
+    }
+
+    /// Begins a secondary command buffer that is executed entirely inside of a render pass.
+    /// `begin_info` must contain `CommandBufferUsageFlags::RENDER_PASS_CONTINUE` and inheritance
+    /// info.
+    pub unsafe fn begin_render_pass_continue(
+        self,
+        begin_info: &vk::CommandBufferBeginInfo,
+    ) -> VkResult<CommandBuffer<'d, D, InheritedRenderPass>> {
+        debug_assert!(begin_info
+            .flags
+            .contains(vk::CommandBufferUsageFlags::RENDER_PASS_CONTINUE));
+        self.device.begin_command_buffer(self.handle, begin_info)?;
+        Ok(self.transition())
+    }
+}
+
+impl<'d, D: DeviceV1_0> CommandBuffer<'d, D, Recording> {
+    /// Begins a render pass whose first subpass is recorded inline.
+    pub unsafe fn begin_render_pass_inline(
+        self,
+        begin_info: &vk::RenderPassBeginInfo,
+    ) -> CommandBuffer<'d, D, InRenderPassInline> {
+        self.device
+            .cmd_begin_render_pass(self.handle, begin_info, vk::SubpassContents::INLINE);
+        self.transition()
+    }
+
+    /// Begins a render pass whose first subpass is recorded in secondary command buffers.
+    pub unsafe fn begin_render_pass_secondary(
+        self,
+        begin_info: &vk::RenderPassBeginInfo,
+    ) -> CommandBuffer<'d, D, InRenderPassSecondary> {
+        self.device.cmd_begin_render_pass(
+            self.handle,
+            begin_info,
+            vk::SubpassContents::SECONDARY_COMMAND_BUFFERS,
+        );
+        self.transition()
+    }
+
+    pub unsafe fn end(self) -> VkResult<CommandBuffer<'d, D, Executable>> {
+        self.device.end_command_buffer(self.handle)?;
+        Ok(self.transition())
+    }
+
+    pub unsafe fn cmd_copy_buffer(
+        &self,
+        src_buffer: vk::Buffer,
+        dst_buffer: vk::Buffer,
+        regions: &[vk::BufferCopy],
+    ) {
+        self.device
+            .cmd_copy_buffer(self.handle, src_buffer, dst_buffer, regions);
+    }
+
+    pub unsafe fn cmd_copy_image(
+        &self,
+        src_image: vk::Image,
+        src_image_layout: vk::ImageLayout,
+        dst_image: vk::Image,
+        dst_image_layout: vk::ImageLayout,
+        regions: &[vk::ImageCopy],
+    ) {
+        self.device.cmd_copy_image(
+            self.handle,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            regions,
+        );
+    }
+
+    pub unsafe fn cmd_copy_buffer_to_image(
+        &self,
+        src_buffer: vk::Buffer,
+        dst_image: vk::Image,
+        dst_image_layout: vk::ImageLayout,
+        regions: &[vk::BufferImageCopy],
+    ) {
+        self.device.cmd_copy_buffer_to_image(
+            self.handle,
+            src_buffer,
+            dst_image,
+            dst_image_layout,
+            regions,
+        );
+    }
+
+    pub unsafe fn cmd_copy_image_to_buffer(
+        &self,
+        src_image: vk::Image,
+        src_image_layout: vk::ImageLayout,
+        dst_buffer: vk::Buffer,
+        regions: &[vk::BufferImageCopy],
+    ) {
+        self.device.cmd_copy_image_to_buffer(
+            self.handle,
+            src_image,
+            src_image_layout,
+            dst_buffer,
+            regions,
+        );
+    }
+
+    pub unsafe fn cmd_blit_image(
+        &self,
+        src_image: vk::Image,
+        src_image_layout: vk::ImageLayout,
+        dst_image: vk::Image,
+        dst_image_layout: vk::ImageLayout,
+        regions: &[vk::ImageBlit],
+        filter: vk::Filter,
+    ) {
+        self.device.cmd_blit_image(
+            self.handle,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            regions,
+            filter,
+        );
+    }
+
+    pub unsafe fn cmd_resolve_image(
+        &self,
+        src_image: vk::Image,
+        src_image_layout: vk::ImageLayout,
+        dst_image: vk::Image,
+        dst_image_layout: vk::ImageLayout,
+        regions: &[vk::ImageResolve],
+    ) {
+        self.device.cmd_resolve_image(
+            self.handle,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            regions,
+        );
+    }
+
+    pub unsafe fn cmd_fill_buffer(
+        &self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+        data: u32,
+    ) {
+        self.device
+            .cmd_fill_buffer(self.handle, buffer, offset, size, data);
+    }
+
+    pub unsafe fn cmd_update_buffer(
+        &self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        data: &[u8],
+    ) {
+        self.device
+            .cmd_update_buffer(self.handle, buffer, offset, data);
+    }
+
+    pub unsafe fn cmd_clear_color_image(
+        &self,
+        image: vk::Image,
+        image_layout: vk::ImageLayout,
+        clear_color_value: &vk::ClearColorValue,
+        ranges: &[vk::ImageSubresourceRange],
+    ) {
+        self.device.cmd_clear_color_image(
+            self.handle,
+            image,
+            image_layout,
+            clear_color_value,
+            ranges,
+        );
+    }
+
+    pub unsafe fn cmd_clear_depth_stencil_image(
+        &self,
+        image: vk::Image,
+        image_layout: vk::ImageLayout,
+        clear_depth_stencil_value: &vk::ClearDepthStencilValue,
+        ranges: &[vk::ImageSubresourceRange],
+    ) {
+        self.device.cmd_clear_depth_stencil_image(
+            self.handle,
+            image,
+            image_layout,
+            clear_depth_stencil_value,
+            ranges,
+        );
+    }
+
+    pub unsafe fn cmd_dispatch(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
+        self.device
+            .cmd_dispatch(self.handle, group_count_x, group_count_y, group_count_z);
+    }
+
+    pub unsafe fn cmd_dispatch_indirect(&self, buffer: vk::Buffer, offset: vk::DeviceSize) {
+        self.device
+            .cmd_dispatch_indirect(self.handle, buffer, offset);
+    }
+
+    pub unsafe fn cmd_reset_query_pool(
+        &self,
+        pool: vk::QueryPool,
+        first_query: u32,
+        query_count: u32,
+    ) {
+        self.device
+            .cmd_reset_query_pool(self.handle, pool, first_query, query_count);
+    }
+
+    pub unsafe fn cmd_copy_query_pool_results(
+        &self,
+        query_pool: vk::QueryPool,
+        first_query: u32,
+        query_count: u32,
+        dst_buffer: vk::Buffer,
+        dst_offset: vk::DeviceSize,
+        stride: vk::DeviceSize,
+        flags: vk::QueryResultFlags,
+    ) {
+        self.device.cmd_copy_query_pool_results(
+            self.handle,
+            query_pool,
+            first_query,
+            query_count,
+            dst_buffer,
+            dst_offset,
+            stride,
+            flags,
+        );
+    }
+
+    pub unsafe fn cmd_set_event(&self, event: vk::Event, stage_mask: vk::PipelineStageFlags) {
+        self.device.cmd_set_event(self.handle, event, stage_mask);
+    }
+
+    pub unsafe fn cmd_reset_event(&self, event: vk::Event, stage_mask: vk::PipelineStageFlags) {
+        self.device.cmd_reset_event(self.handle, event, stage_mask);
+    }
+}
+
+impl<'d, D: DeviceV1_1> CommandBuffer<'d, D, Recording> {
+    pub unsafe fn cmd_dispatch_base(
+        &self,
+        base_group_x: u32,
+        base_group_y: u32,
+        base_group_z: u32,
+        group_count_x: u32,
+        group_count_y: u32,
+        group_count_z: u32,
+    ) {
+        self.device.cmd_dispatch_base(
+            self.handle,
+            base_group_x,
+            base_group_y,
+            base_group_z,
+            group_count_x,
+            group_count_y,
+            group_count_z,
+        );
+    }
+}
+
+impl<'d, D: DeviceV1_0, S: RecordingState> CommandBuffer<'d, D, S> {
+    pub unsafe fn cmd_bind_pipeline(
+        &self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        pipeline: vk::Pipeline,
+    ) {
+        self.device
+            .cmd_bind_pipeline(self.handle, pipeline_bind_point, pipeline);
+    }
+
+    pub unsafe fn cmd_bind_descriptor_sets(
+        &self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        layout: vk::PipelineLayout,
+        first_set: u32,
+        descriptor_sets: &[vk::DescriptorSet],
+        dynamic_offsets: &[u32],
+    ) {
+        self.device.cmd_bind_descriptor_sets(
+            self.handle,
+            pipeline_bind_point,
+            layout,
+            first_set,
+            descriptor_sets,
+            dynamic_offsets,
+        );
+    }
+
+    pub unsafe fn cmd_bind_vertex_buffers(
+        &self,
+        first_binding: u32,
+        buffers: &[vk::Buffer],
+        offsets: &[vk::DeviceSize],
+    ) {
+        self.device
+            .cmd_bind_vertex_buffers(self.handle, first_binding, buffers, offsets);
+    }
+
+    pub unsafe fn cmd_bind_index_buffer(
+        &self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        index_type: vk::IndexType,
+    ) {
+        self.device
+            .cmd_bind_index_buffer(self.handle, buffer, offset, index_type);
+    }
+
+    pub unsafe fn cmd_push_constants(
+        &self,
+        layout: vk::PipelineLayout,
+        stage_flags: vk::ShaderStageFlags,
+        offset: u32,
+        constants: &[u8],
+    ) {
+        self.device
+            .cmd_push_constants(self.handle, layout, stage_flags, offset, constants);
+    }
+
+    pub unsafe fn cmd_set_viewport(&self, first_viewport: u32, viewports: &[vk::Viewport]) {
+        self.device
+            .cmd_set_viewport(self.handle, first_viewport, viewports);
+    }
+
+    pub unsafe fn cmd_set_scissor(&self, first_scissor: u32, scissors: &[vk::Rect2D]) {
+        self.device
+            .cmd_set_scissor(self.handle, first_scissor, scissors);
+    }
+
+    pub unsafe fn cmd_set_line_width(&self, line_width: f32) {
+        self.device.cmd_set_line_width(self.handle, line_width);
+    }
+
+    pub unsafe fn cmd_set_depth_bias(&self, constant_factor: f32, clamp: f32, slope_factor: f32) {
+        self.device
+            .cmd_set_depth_bias(self.handle, constant_factor, clamp, slope_factor);
+    }
+
+    pub unsafe fn cmd_set_blend_constants(&self, blend_constants: [f32; 4]) {
+        self.device
+            .cmd_set_blend_constants(self.handle, blend_constants);
+    }
+
+    pub unsafe fn cmd_set_depth_bounds(&self, min_depth_bounds: f32, max_depth_bounds: f32) {
+        self.device
+            .cmd_set_depth_bounds(self.handle, min_depth_bounds, max_depth_bounds);
+    }
+
+    pub unsafe fn cmd_set_stencil_compare_mask(
+        &self,
+        face_mask: vk::StencilFaceFlags,
+        compare_mask: u32,
+    ) {
+        self.device
+            .cmd_set_stencil_compare_mask(self.handle, face_mask, compare_mask);
+    }
+
+    pub unsafe fn cmd_set_stencil_write_mask(
+        &self,
+        face_mask: vk::StencilFaceFlags,
+        write_mask: u32,
+    ) {
+        self.device
+            .cmd_set_stencil_write_mask(self.handle, face_mask, write_mask);
+    }
+
+    pub unsafe fn cmd_set_stencil_reference(
+        &self,
+        face_mask: vk::StencilFaceFlags,
+        reference: u32,
+    ) {
+        self.device
+            .cmd_set_stencil_reference(self.handle, face_mask, reference);
+    }
+
+    pub unsafe fn cmd_pipeline_barrier(
+        &self,
+        src_stage_mask: vk::PipelineStageFlags,
+        dst_stage_mask: vk::PipelineStageFlags,
+        dependency_flags: vk::DependencyFlags,
+        memory_barriers: &[vk::MemoryBarrier],
+        buffer_memory_barriers: &[vk::BufferMemoryBarrier],
+        image_memory_barriers: &[vk::ImageMemoryBarrier],
+    ) {
+        self.device.cmd_pipeline_barrier(
+            self.handle,
+            src_stage_mask,
+            dst_stage_mask,
+            dependency_flags,
+            memory_barriers,
+            buffer_memory_barriers,
+            image_memory_barriers,
+        );
+    }
+
+    pub unsafe fn cmd_wait_events(
+        &self,
+        events: &[vk::Event],
+        src_stage_mask: vk::PipelineStageFlags,
+        dst_stage_mask: vk::PipelineStageFlags,
+        memory_barriers: &[vk::MemoryBarrier],
+        buffer_memory_barriers: &[vk::BufferMemoryBarrier],
+        image_memory_barriers: &[vk::ImageMemoryBarrier],
+    ) {
+        self.device.cmd_wait_events(
+            self.handle,
+            events,
+            src_stage_mask,
+            dst_stage_mask,
+            memory_barriers,
+            buffer_memory_barriers,
+            image_memory_barriers,
+        );
+    }
+
+    pub unsafe fn cmd_begin_query(
+        &self,
+        query_pool: vk::QueryPool,
+        query: u32,
+        flags: vk::QueryControlFlags,
+    ) {
+        self.device
+            .cmd_begin_query(self.handle, query_pool, query, flags);
+    }
+
+    pub unsafe fn cmd_end_query(&self, query_pool: vk::QueryPool, query: u32) {
+        self.device.cmd_end_query(self.handle, query_pool, query);
+    }
+
+    pub unsafe fn cmd_write_timestamp(
+        &self,
+        pipeline_stage: vk::PipelineStageFlags,
+        query_pool: vk::QueryPool,
+        query: u32,
+    ) {
+        self.device
+            .cmd_write_timestamp(self.handle, pipeline_stage, query_pool, query);
+    }
+}
+
+impl<'d, D: DeviceV1_0, S: ExecuteCommandsState> CommandBuffer<'d, D, S> {
+    pub unsafe fn cmd_execute_commands(&self, secondary_command_buffers: &[vk::CommandBuffer]) {
+        self.device
+            .cmd_execute_commands(self.handle, secondary_command_buffers);
+    }
+}
+
+impl<'d, D: DeviceV1_1, S: RecordingState> CommandBuffer<'d, D, S> {
+    pub unsafe fn cmd_set_device_mask(&self, device_mask: u32) {
+        self.device.cmd_set_device_mask(self.handle, device_mask);
+    }
+}
+
+impl<'d, D: DeviceV1_0, S: RenderPassState> CommandBuffer<'d, D, S> {
+    pub unsafe fn cmd_draw(
+        &self,
+        vertex_count: u32,
+        instance_count: u32,
+        first_vertex: u32,
+        first_instance: u32,
+    ) {
+        self.device.cmd_draw(
+            self.handle,
+            vertex_count,
+            instance_count,
+            first_vertex,
+            first_instance,
+        );
+    }
+
+    pub unsafe fn cmd_draw_indexed(
+        &self,
+        index_count: u32,
+        instance_count: u32,
+        first_index: u32,
+        vertex_offset: i32,
+        first_instance: u32,
+    ) {
+        self.device.cmd_draw_indexed(
+            self.handle,
+            index_count,
+            instance_count,
+            first_index,
+            vertex_offset,
+            first_instance,
+        );
+    }
+
+    pub unsafe fn cmd_draw_indirect(
+        &self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        self.device
+            .cmd_draw_indirect(self.handle, buffer, offset, draw_count, stride);
+    }
+
+    pub unsafe fn cmd_draw_indexed_indirect(
+        &self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        self.device
+            .cmd_draw_indexed_indirect(self.handle, buffer, offset, draw_count, stride);
+    }
+
+    pub unsafe fn cmd_clear_attachments(
+        &self,
+        attachments: &[vk::ClearAttachment],
+        rects: &[vk::ClearRect],
+    ) {
+        self.device
+            .cmd_clear_attachments(self.handle, attachments, rects);
+    }
+}
+
+impl<'d, D: DeviceV1_0, S: SubpassState> CommandBuffer<'d, D, S> {
+    pub unsafe fn next_subpass_inline(self) -> CommandBuffer<'d, D, InRenderPassInline> {
+        self.device
+            .cmd_next_subpass(self.handle, vk::SubpassContents::INLINE);
+        self.transition()
+    }
+
+    pub unsafe fn next_subpass_secondary(self) -> CommandBuffer<'d, D, InRenderPassSecondary> {
+        self.device
+            .cmd_next_subpass(self.handle, vk::SubpassContents::SECONDARY_COMMAND_BUFFERS);
+        self.transition()
+    }
+
+    pub unsafe fn end_render_pass(self) -> CommandBuffer<'d, D, Recording> {
+        self.device.cmd_end_render_pass(self.handle);
+        self.transition()
+    }
+}
+
+impl<'d, D: DeviceV1_0> CommandBuffer<'d, D, InheritedRenderPass> {
+    pub unsafe fn end(self) -> VkResult<CommandBuffer<'d, D, Executable>> {
+        self.device.end_command_buffer(self.handle)?;
+        Ok(self.transition())
+    }
+}
+
+impl<'d, D: DeviceV1_0> CommandBuffer<'d, D, Executable> {
+    /// The handle to pass to `queue_submit` or `cmd_execute_commands`.
+    pub fn handle(&self) -> vk::CommandBuffer {
+        self.handle
+    }
+
+    /// Resets the command buffer. The command pool must have been created with
+    /// `CommandPoolCreateFlags::RESET_COMMAND_BUFFER`, and the command buffer must not be in use
+    /// by the device.
+    pub unsafe fn reset(
+        self,
+        flags: vk::CommandBufferResetFlags,
+    ) -> VkResult<CommandBuffer<'d, D, Initial>> {
+        self.device.reset_command_buffer(self.handle, flags)?;
+        Ok(self.transition())
+    }
+
+    /// Records the command buffer again. Recording implicitly resets it, which has the same
+    /// requirements as `reset`.
+    pub unsafe fn begin(
+        self,
+        begin_info: &vk::CommandBufferBeginInfo,
+    ) -> VkResult<CommandBuffer<'d, D, Recording>> {
+        self.device.begin_command_buffer(self.handle, begin_info)?;
+        Ok(self.transition())
+    }
+}
@@ -0,0 +1,228 @@
+//! Deferred destruction of handles that may still be in use by the GPU.
+//!
+//! Destroying a handle that is still referenced by a command buffer in flight is undefined
+//! behavior. A `DestructionQueue` keeps such handles until the fence of the last submission
+//! that used them is signaled, or until the frame that last used them has completed.
+use device::Device;
+use owned::{AllocationCallbacks, DeviceChild, Owned};
+use prelude::*;
+use std::sync::Arc;
+use version::DeviceV1_1;
+use vk;
+
+/// When a handle in a `DestructionQueue` may be destroyed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DestroyAfter {
+    /// Once the fence is signaled.
+    Fence(vk::Fence),
+    /// Once `DestructionQueue::frame_completed` was called with this or a later frame index.
+    Frame(u64),
+}
+
+type Destroy<D> = Box<dyn FnMut(&D, u64, Option<&vk::AllocationCallbacks>) + Send>;
+
+struct PendingDestruction<D> {
+    after: DestroyAfter,
+    object_type: vk::ObjectType,
+    raw: u64,
+    allocation_callbacks: AllocationCallbacks,
+    destroy: Destroy<D>,
+}
+
+/// Destroys handles once the GPU is done with them.
+///
+/// Instead of calling `device_wait_idle` before destroying a handle, push it together with the
+/// fence of the last submission that uses it, or with the index of the frame that last used
+/// it. Handles are destroyed in the order in which they were pushed.
+///
+/// ```no_run
+/// # use ash::vk;
+/// # use ash::destruction::{DestroyAfter, DestructionQueue};
+/// # use ash::extensions::khr::Swapchain;
+/// # use std::sync::Arc;
+/// # unsafe fn test(device: Arc<ash::Device>, swapchain_loader: &Swapchain, buffer: vk::Buffer, swapchain: vk::SwapchainKHR, fence: vk::Fence) {
+/// let mut queue = DestructionQueue::new(device);
+/// queue.push(buffer, DestroyAfter::Fence(fence), None);
+/// // Handles that are not destroyed by the device itself need a destroy function
+/// let swapchain_loader = swapchain_loader.clone();
+/// queue.push_with(swapchain, DestroyAfter::Fence(fence), None, move |_, swapchain, allocation_callbacks| {
+///     swapchain_loader.destroy_swapchain(swapchain, allocation_callbacks)
+/// });
+/// // Once per frame
+/// queue.collect().unwrap();
+/// // On shutdown
+/// queue.flush_all().unwrap();
+/// # }
+/// ```
+pub struct DestructionQueue<D: DeviceV1_1 = Device> {
+    device: Arc<D>,
+    pending: Vec<PendingDestruction<D>>,
+}
+
+impl<D: DeviceV1_1> DestructionQueue<D> {
+    pub fn new(device: Arc<D>) -> Self {
+        DestructionQueue {
+            device,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn device(&self) -> &Arc<D> {
+        &self.device
+    }
+
+    /// Number of handles that have not been destroyed yet.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Queues `handle` for destruction. `handle` must have been created from the device of this
+    /// queue with `allocation_callbacks`, which must be safe to call from any thread.
+    pub unsafe fn push<T: DeviceChild>(
+        &mut self,
+        handle: T,
+        after: DestroyAfter,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) {
+        self.push_with(
+            handle,
+            after,
+            allocation_callbacks,
+            |device, handle: T, allocation_callbacks| unsafe {
+                handle.destroy(device, allocation_callbacks)
+            },
+        );
+    }
+
+    /// Queues any handle for destruction, which is destroyed by calling `destroy` with the
+    /// device of this queue, the handle and `allocation_callbacks`.
+    ///
+    /// Use this for handles that the device cannot destroy on its own, e.g. a
+    /// `vk::SwapchainKHR` that is destroyed with the extension loader, or descriptor sets that
+    /// are freed to their pool. `allocation_callbacks` must be safe to call from any thread.
+    pub unsafe fn push_with<H, F>(
+        &mut self,
+        handle: H,
+        after: DestroyAfter,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+        destroy: F,
+    ) where
+        H: vk::Handle,
+        F: FnOnce(&D, H, Option<&vk::AllocationCallbacks>) + Send + 'static,
+    {
+        let mut destroy = Some(destroy);
+        self.pending.push(PendingDestruction {
+            after,
+            object_type: H::TYPE,
+            raw: handle.as_raw(),
+            allocation_callbacks: AllocationCallbacks::new(allocation_callbacks),
+            destroy: Box::new(move |device, raw, allocation_callbacks| {
+                if let Some(destroy) = destroy.take() {
+                    destroy(device, H::from_raw(raw), allocation_callbacks);
+                }
+            }),
+        });
+    }
+
+    /// Queues an owned handle for destruction. The handle must belong to the device of this
+    /// queue.
+    pub fn push_owned<T: DeviceChild>(&mut self, owned: Owned<T, D>, after: DestroyAfter) {
+        assert_eq!(
+            owned.device().handle(),
+            self.device.handle(),
+            "The handle belongs to a different device"
+        );
+        unsafe {
+            let allocation_callbacks = AllocationCallbacks::new(owned.allocation_callbacks());
+            self.push(owned.into_raw(), after, allocation_callbacks.as_ref());
+        }
+    }
+
+    /// Destroys all handles whose fence is signaled. Returns the number of destroyed handles.
+    pub unsafe fn collect(&mut self) -> VkResult<usize> {
+        let mut signaled = Vec::new();
+        let mut unsignaled = Vec::new();
+        for pending in &self.pending {
+            if let DestroyAfter::Fence(fence) = pending.after {
+                if signaled.contains(&fence) || unsignaled.contains(&fence) {
+                    continue;
+                }
+                match self.device.get_fence_status(fence)? {
+                    FenceStatus::Signaled => signaled.push(fence),
+                    FenceStatus::NotReady => unsignaled.push(fence),
+                }
+            }
+        }
+        Ok(self.destroy_where(|after| match after {
+            DestroyAfter::Fence(fence) => signaled.contains(&fence),
+            DestroyAfter::Frame(_) => false,
+        }))
+    }
+
+    /// Destroys all handles that were queued with `DestroyAfter::Frame(index)` where `index` is
+    /// less or equal to `frame_index`. Returns the number of destroyed handles.
+    pub unsafe fn frame_completed(&mut self, frame_index: u64) -> usize {
+        self.destroy_where(|after| match after {
+            DestroyAfter::Frame(index) => index <= frame_index,
+            DestroyAfter::Fence(_) => false,
+        })
+    }
+
+    /// Waits until the device is idle and destroys every queued handle. Should be called
+    /// before the device is destroyed.
+    pub unsafe fn flush_all(&mut self) -> VkResult<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        self.device.device_wait_idle()?;
+        self.destroy_where(|_| true);
+        Ok(())
+    }
+
+    /// Returns how many handles of each object type are still queued.
+    pub fn pending_counts(&self) -> Vec<(vk::ObjectType, usize)> {
+        let mut counts: Vec<(vk::ObjectType, usize)> = Vec::new();
+        for pending in &self.pending {
+            match counts.iter().position(|&(ty, _)| ty == pending.object_type) {
+                Some(index) => counts[index].1 += 1,
+                None => counts.push((pending.object_type, 1)),
+            }
+        }
+        counts
+    }
+
+    unsafe fn destroy_where<F: Fn(DestroyAfter) -> bool>(&mut self, f: F) -> usize {
+        let mut destroyed = 0;
+        let mut index = 0;
+        // Handles are destroyed in the order in which they were pushed
+        while index < self.pending.len() {
+            if f(self.pending[index].after) {
+                let mut pending = self.pending.remove(index);
+                (pending.destroy)(
+                    &*self.device,
+                    pending.raw,
+                    pending.allocation_callbacks.as_ref(),
+                );
+                destroyed += 1;
+            } else {
+                index += 1;
+            }
+        }
+        destroyed
+    }
+}
+
+impl<D: DeviceV1_1> Drop for DestructionQueue<D> {
+    fn drop(&mut self) {
+        unsafe {
+            // If the device is lost everything can be destroyed right away
+            if self.flush_all().is_err() {
+                self.destroy_where(|_| true);
+            }
+        }
+    }
+}
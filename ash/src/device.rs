@@ -11,6 +11,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
     fn fp_v1_1(&self) -> &vk::DeviceFnV1_1;
 
     unsafe fn bind_buffer_memory2(&self, bind_infos: &[vk::BindBufferMemoryInfo]) -> VkResult<()> {
+        validate!("vkBindBufferMemory2", |v| {
+            v.slice(bind_infos);
+        });
         let err_code = self.fp_v1_1().bind_buffer_memory2(
             self.handle(),
             bind_infos.len() as _,
@@ -23,6 +26,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
     }
 
     unsafe fn bind_image_memory2(&self, bind_infos: &[vk::BindImageMemoryInfo]) -> VkResult<()> {
+        validate!("vkBindImageMemory2", |v| {
+            v.slice(bind_infos);
+        });
         let err_code = self.fp_v1_1().bind_image_memory2(
             self.handle(),
             bind_infos.len() as _,
@@ -52,6 +58,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
     }
 
     unsafe fn cmd_set_device_mask(&self, command_buffer: vk::CommandBuffer, device_mask: u32) {
+        validate!("vkCmdSetDeviceMask", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_1()
             .cmd_set_device_mask(command_buffer, device_mask);
     }
@@ -66,6 +75,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        validate!("vkCmdDispatchBase", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_1().cmd_dispatch_base(
             command_buffer,
             base_group_x,
@@ -82,6 +94,10 @@ pub trait DeviceV1_1: DeviceV1_0 {
         info: &vk::ImageMemoryRequirementsInfo2,
         out: &mut vk::MemoryRequirements2,
     ) {
+        validate!("vkGetImageMemoryRequirements2", |v| {
+            v.structure(info);
+            v.structure(out);
+        });
         self.fp_v1_1()
             .get_image_memory_requirements2(self.handle(), info, out);
     }
@@ -91,6 +107,10 @@ pub trait DeviceV1_1: DeviceV1_0 {
         info: &vk::BufferMemoryRequirementsInfo2,
         out: &mut vk::MemoryRequirements2,
     ) {
+        validate!("vkGetBufferMemoryRequirements2", |v| {
+            v.structure(info);
+            v.structure(out);
+        });
         self.fp_v1_1()
             .get_buffer_memory_requirements2(self.handle(), info, out);
     }
@@ -99,6 +119,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
         &self,
         info: &vk::ImageSparseMemoryRequirementsInfo2,
     ) -> usize {
+        validate!("vkGetImageSparseMemoryRequirements2", |v| {
+            v.structure(info);
+        });
         let mut count = mem::uninitialized();
         self.fp_v1_1().get_image_sparse_memory_requirements2(
             self.handle(),
@@ -114,6 +137,10 @@ pub trait DeviceV1_1: DeviceV1_0 {
         info: &vk::ImageSparseMemoryRequirementsInfo2,
         out: &mut [vk::SparseImageMemoryRequirements2],
     ) {
+        validate!("vkGetImageSparseMemoryRequirements2", |v| {
+            v.structure(info);
+            v.slice(out);
+        });
         let mut count = out.len() as u32;
         self.fp_v1_1().get_image_sparse_memory_requirements2(
             self.handle(),
@@ -128,6 +155,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
         command_pool: vk::CommandPool,
         flags: vk::CommandPoolTrimFlags,
     ) {
+        validate!("vkTrimCommandPool", |v| {
+            v.handle("command_pool", command_pool);
+        });
         self.fp_v1_1()
             .trim_command_pool(self.handle(), command_pool, flags);
     }
@@ -137,6 +167,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
         create_info: &vk::SamplerYcbcrConversionCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SamplerYcbcrConversion> {
+        validate!("vkCreateSamplerYcbcrConversion", |v| {
+            v.structure(create_info);
+        });
         let mut ycbcr_conversion = mem::uninitialized();
         let err_code = self.fp_v1_1().create_sampler_ycbcr_conversion(
             self.handle(),
@@ -167,6 +200,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
         create_info: &vk::DescriptorUpdateTemplateCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DescriptorUpdateTemplate> {
+        validate!("vkCreateDescriptorUpdateTemplate", |v| {
+            v.structure(create_info);
+        });
         let mut descriptor_update_template = mem::uninitialized();
         let err_code = self.fp_v1_1().create_descriptor_update_template(
             self.handle(),
@@ -198,6 +234,10 @@ pub trait DeviceV1_1: DeviceV1_0 {
         descriptor_update_template: vk::DescriptorUpdateTemplate,
         data: *const c_void,
     ) {
+        validate!("vkUpdateDescriptorSetWithTemplate", |v| {
+            v.handle("descriptor_set", descriptor_set);
+            v.handle("descriptor_update_template", descriptor_update_template);
+        });
         self.fp_v1_1().update_descriptor_set_with_template(
             self.handle(),
             descriptor_set,
@@ -211,6 +251,10 @@ pub trait DeviceV1_1: DeviceV1_0 {
         create_info: &vk::DescriptorSetLayoutCreateInfo,
         out: &mut vk::DescriptorSetLayoutSupport,
     ) {
+        validate!("vkGetDescriptorSetLayoutSupport", |v| {
+            v.structure(create_info);
+            v.structure(out);
+        });
         self.fp_v1_1()
             .get_descriptor_set_layout_support(self.handle(), create_info, out);
     }
@@ -248,6 +292,12 @@ pub trait DeviceV1_0 {
         command_pool: vk::CommandPool,
         command_buffers: &[vk::CommandBuffer],
     ) {
+        validate!("vkFreeCommandBuffers", |v| {
+            v.handle("command_pool", command_pool);
+            for &command_buffer in command_buffers {
+                v.dispatchable("command_buffers", command_buffer, self.handle());
+            }
+        });
         self.fp_v1_0().free_command_buffers(
             self.handle(),
             command_pool,
@@ -261,6 +311,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::EventCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Event> {
+        validate!("vkCreateEvent", |v| {
+            v.structure(create_info);
+        });
         let mut event = mem::uninitialized();
         let err_code = self.fp_v1_0().create_event(
             self.handle(),
@@ -274,18 +327,22 @@ pub trait DeviceV1_0 {
         }
     }
 
-    /// Returns true if the event was set, and false if the event was reset, otherwise it will
-    /// return the error code.
-    unsafe fn get_event_status(&self, event: vk::Event) -> VkResult<bool> {
+    unsafe fn get_event_status(&self, event: vk::Event) -> VkResult<EventStatus> {
+        validate!("vkGetEventStatus", |v| {
+            v.handle("event", event);
+        });
         let err_code = self.fp_v1_0().get_event_status(self.handle(), event);
         match err_code {
-            vk::Result::EVENT_SET => Ok(true),
-            vk::Result::EVENT_RESET => Ok(false),
+            vk::Result::EVENT_SET => Ok(EventStatus::Set),
+            vk::Result::EVENT_RESET => Ok(EventStatus::Reset),
             _ => Err(err_code),
         }
     }
 
     unsafe fn set_event(&self, event: vk::Event) -> VkResult<()> {
+        validate!("vkSetEvent", |v| {
+            v.handle("event", event);
+        });
         let err_code = self.fp_v1_0().set_event(self.handle(), event);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
@@ -294,6 +351,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn reset_event(&self, event: vk::Event) -> VkResult<()> {
+        validate!("vkResetEvent", |v| {
+            v.handle("event", event);
+        });
         let err_code = self.fp_v1_0().reset_event(self.handle(), event);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
@@ -306,6 +366,10 @@ pub trait DeviceV1_0 {
         event: vk::Event,
         stage_mask: vk::PipelineStageFlags,
     ) {
+        validate!("vkCmdSetEvent", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("event", event);
+        });
         self.fp_v1_0()
             .cmd_set_event(command_buffer, event, stage_mask);
     }
@@ -315,6 +379,10 @@ pub trait DeviceV1_0 {
         event: vk::Event,
         stage_mask: vk::PipelineStageFlags,
     ) {
+        validate!("vkCmdResetEvent", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("event", event);
+        });
         self.fp_v1_0()
             .cmd_reset_event(command_buffer, event, stage_mask);
     }
@@ -329,6 +397,13 @@ pub trait DeviceV1_0 {
         buffer_memory_barriers: &[vk::BufferMemoryBarrier],
         image_memory_barriers: &[vk::ImageMemoryBarrier],
     ) {
+        validate!("vkCmdWaitEvents", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handles("events", events);
+            v.slice(memory_barriers);
+            v.slice(buffer_memory_barriers);
+            v.slice(image_memory_barriers);
+        });
         self.fp_v1_0().cmd_wait_events(
             command_buffer,
             events.len() as _,
@@ -520,6 +595,9 @@ pub trait DeviceV1_0 {
         pool: vk::DescriptorPool,
         descriptor_sets: &[vk::DescriptorSet],
     ) {
+        validate!("vkFreeDescriptorSets", |v| {
+            v.handle("pool", pool);
+        });
         self.fp_v1_0().free_descriptor_sets(
             self.handle(),
             pool,
@@ -533,6 +611,10 @@ pub trait DeviceV1_0 {
         descriptor_writes: &[vk::WriteDescriptorSet],
         descriptor_copies: &[vk::CopyDescriptorSet],
     ) {
+        validate!("vkUpdateDescriptorSets", |v| {
+            v.slice(descriptor_writes);
+            v.slice(descriptor_copies);
+        });
         self.fp_v1_0().update_descriptor_sets(
             self.handle(),
             descriptor_writes.len() as u32,
@@ -547,6 +629,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::SamplerCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Sampler> {
+        validate!("vkCreateSampler", |v| {
+            v.structure(create_info);
+        });
         let mut sampler = mem::uninitialized();
         let err_code = self.fp_v1_0().create_sampler(
             self.handle(),
@@ -570,6 +655,11 @@ pub trait DeviceV1_0 {
         regions: &[vk::ImageBlit],
         filter: vk::Filter,
     ) {
+        validate!("vkCmdBlitImage", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("src_image", src_image);
+            v.handle("dst_image", dst_image);
+        });
         self.fp_v1_0().cmd_blit_image(
             command_buffer,
             src_image,
@@ -591,6 +681,11 @@ pub trait DeviceV1_0 {
         dst_image_layout: vk::ImageLayout,
         regions: &[vk::ImageResolve],
     ) {
+        validate!("vkCmdResolveImage", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("src_image", src_image);
+            v.handle("dst_image", dst_image);
+        });
         self.fp_v1_0().cmd_resolve_image(
             command_buffer,
             src_image,
@@ -610,6 +705,10 @@ pub trait DeviceV1_0 {
         size: vk::DeviceSize,
         data: u32,
     ) {
+        validate!("vkCmdFillBuffer", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("buffer", buffer);
+        });
         self.fp_v1_0()
             .cmd_fill_buffer(command_buffer, buffer, offset, size, data);
     }
@@ -621,6 +720,10 @@ pub trait DeviceV1_0 {
         offset: vk::DeviceSize,
         data: &[u8],
     ) {
+        validate!("vkCmdUpdateBuffer", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("buffer", buffer);
+        });
         self.fp_v1_0().cmd_update_buffer(
             command_buffer,
             buffer,
@@ -637,6 +740,11 @@ pub trait DeviceV1_0 {
         dst_buffer: vk::Buffer,
         regions: &[vk::BufferCopy],
     ) {
+        validate!("vkCmdCopyBuffer", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("src_buffer", src_buffer);
+            v.handle("dst_buffer", dst_buffer);
+        });
         self.fp_v1_0().cmd_copy_buffer(
             command_buffer,
             src_buffer,
@@ -654,6 +762,11 @@ pub trait DeviceV1_0 {
         dst_buffer: vk::Buffer,
         regions: &[vk::BufferImageCopy],
     ) {
+        validate!("vkCmdCopyImageToBuffer", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("src_image", src_image);
+            v.handle("dst_buffer", dst_buffer);
+        });
         self.fp_v1_0().cmd_copy_image_to_buffer(
             command_buffer,
             src_image,
@@ -672,6 +785,11 @@ pub trait DeviceV1_0 {
         dst_image_layout: vk::ImageLayout,
         regions: &[vk::BufferImageCopy],
     ) {
+        validate!("vkCmdCopyBufferToImage", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("src_buffer", src_buffer);
+            v.handle("dst_image", dst_image);
+        });
         self.fp_v1_0().cmd_copy_buffer_to_image(
             command_buffer,
             src_buffer,
@@ -691,6 +809,11 @@ pub trait DeviceV1_0 {
         dst_image_layout: vk::ImageLayout,
         regions: &[vk::ImageCopy],
     ) {
+        validate!("vkCmdCopyImage", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("src_image", src_image);
+            v.handle("dst_image", dst_image);
+        });
         self.fp_v1_0().cmd_copy_image(
             command_buffer,
             src_image,
@@ -706,6 +829,10 @@ pub trait DeviceV1_0 {
         &self,
         create_info: &vk::DescriptorSetAllocateInfo,
     ) -> VkResult<Vec<vk::DescriptorSet>> {
+        validate!("vkAllocateDescriptorSets", |v| {
+            v.structure(create_info);
+            v.handle("create_info.descriptor_pool", create_info.descriptor_pool);
+        });
         let mut desc_set = Vec::with_capacity(create_info.descriptor_set_count as usize);
         let err_code = self.fp_v1_0().allocate_descriptor_sets(
             self.handle(),
@@ -725,6 +852,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::DescriptorSetLayoutCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DescriptorSetLayout> {
+        validate!("vkCreateDescriptorSetLayout", |v| {
+            v.structure(create_info);
+        });
         let mut layout = mem::uninitialized();
         let err_code = self.fp_v1_0().create_descriptor_set_layout(
             self.handle(),
@@ -751,6 +881,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::DescriptorPoolCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DescriptorPool> {
+        validate!("vkCreateDescriptorPool", |v| {
+            v.structure(create_info);
+        });
         let mut pool = mem::uninitialized();
         let err_code = self.fp_v1_0().create_descriptor_pool(
             self.handle(),
@@ -769,6 +902,9 @@ pub trait DeviceV1_0 {
         pool: vk::DescriptorPool,
         flags: vk::DescriptorPoolResetFlags,
     ) -> VkResult<()> {
+        validate!("vkResetDescriptorPool", |v| {
+            v.handle("pool", pool);
+        });
         let err_code = self
             .fp_v1_0()
             .reset_descriptor_pool(self.handle(), pool, flags);
@@ -783,6 +919,9 @@ pub trait DeviceV1_0 {
         command_pool: vk::CommandPool,
         flags: vk::CommandPoolResetFlags,
     ) -> VkResult<()> {
+        validate!("vkResetCommandPool", |v| {
+            v.handle("command_pool", command_pool);
+        });
         let err_code = self
             .fp_v1_0()
             .reset_command_pool(self.handle(), command_pool, flags);
@@ -797,6 +936,9 @@ pub trait DeviceV1_0 {
         command_buffer: vk::CommandBuffer,
         flags: vk::CommandBufferResetFlags,
     ) -> VkResult<()> {
+        validate!("vkResetCommandBuffer", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         let err_code = self.fp_v1_0().reset_command_buffer(command_buffer, flags);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
@@ -805,6 +947,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn reset_fences(&self, fences: &[vk::Fence]) -> VkResult<()> {
+        validate!("vkResetFences", |v| {
+            v.handles("fences", fences);
+        });
         let err_code =
             self.fp_v1_0()
                 .reset_fences(self.handle(), fences.len() as u32, fences.as_ptr());
@@ -821,6 +966,10 @@ pub trait DeviceV1_0 {
         offset: vk::DeviceSize,
         index_type: vk::IndexType,
     ) {
+        validate!("vkCmdBindIndexBuffer", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("buffer", buffer);
+        });
         self.fp_v1_0()
             .cmd_bind_index_buffer(command_buffer, buffer, offset, index_type);
     }
@@ -833,6 +982,10 @@ pub trait DeviceV1_0 {
         clear_color_value: &vk::ClearColorValue,
         ranges: &[vk::ImageSubresourceRange],
     ) {
+        validate!("vkCmdClearColorImage", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("image", image);
+        });
         self.fp_v1_0().cmd_clear_color_image(
             command_buffer,
             image,
@@ -851,6 +1004,10 @@ pub trait DeviceV1_0 {
         clear_depth_stencil_value: &vk::ClearDepthStencilValue,
         ranges: &[vk::ImageSubresourceRange],
     ) {
+        validate!("vkCmdClearDepthStencilImage", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("image", image);
+        });
         self.fp_v1_0().cmd_clear_depth_stencil_image(
             command_buffer,
             image,
@@ -867,6 +1024,9 @@ pub trait DeviceV1_0 {
         attachments: &[vk::ClearAttachment],
         rects: &[vk::ClearRect],
     ) {
+        validate!("vkCmdClearAttachments", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0().cmd_clear_attachments(
             command_buffer,
             attachments.len() as u32,
@@ -885,6 +1045,9 @@ pub trait DeviceV1_0 {
         vertex_offset: i32,
         first_instance: u32,
     ) {
+        validate!("vkCmdDrawIndexed", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0().cmd_draw_indexed(
             command_buffer,
             index_count,
@@ -903,6 +1066,10 @@ pub trait DeviceV1_0 {
         draw_count: u32,
         stride: u32,
     ) {
+        validate!("vkCmdDrawIndexedIndirect", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("buffer", buffer);
+        });
         self.fp_v1_0().cmd_draw_indexed_indirect(
             command_buffer,
             buffer,
@@ -917,6 +1084,16 @@ pub trait DeviceV1_0 {
         primary_command_buffer: vk::CommandBuffer,
         secondary_command_buffers: &[vk::CommandBuffer],
     ) {
+        validate!("vkCmdExecuteCommands", |v| {
+            v.dispatchable(
+                "primary_command_buffer",
+                primary_command_buffer,
+                self.handle(),
+            );
+            for &command_buffer in secondary_command_buffers {
+                v.dispatchable("secondary_command_buffers", command_buffer, self.handle());
+            }
+        });
         self.fp_v1_0().cmd_execute_commands(
             primary_command_buffer,
             secondary_command_buffers.len() as u32,
@@ -933,6 +1110,11 @@ pub trait DeviceV1_0 {
         descriptor_sets: &[vk::DescriptorSet],
         dynamic_offsets: &[u32],
     ) {
+        validate!("vkCmdBindDescriptorSets", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("layout", layout);
+            v.handles("descriptor_sets", descriptor_sets);
+        });
         self.fp_v1_0().cmd_bind_descriptor_sets(
             command_buffer,
             pipeline_bind_point,
@@ -956,6 +1138,11 @@ pub trait DeviceV1_0 {
         stride: vk::DeviceSize,
         flags: vk::QueryResultFlags,
     ) {
+        validate!("vkCmdCopyQueryPoolResults", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("query_pool", query_pool);
+            v.handle("dst_buffer", dst_buffer);
+        });
         self.fp_v1_0().cmd_copy_query_pool_results(
             command_buffer,
             query_pool,
@@ -976,6 +1163,10 @@ pub trait DeviceV1_0 {
         offset: u32,
         constants: &[u8],
     ) {
+        validate!("vkCmdPushConstants", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("layout", layout);
+        });
         self.fp_v1_0().cmd_push_constants(
             command_buffer,
             layout,
@@ -992,6 +1183,12 @@ pub trait DeviceV1_0 {
         create_info: &vk::RenderPassBeginInfo,
         contents: vk::SubpassContents,
     ) {
+        validate!("vkCmdBeginRenderPass", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.structure(create_info);
+            v.handle("create_info.render_pass", create_info.render_pass);
+            v.handle("create_info.framebuffer", create_info.framebuffer);
+        });
         self.fp_v1_0()
             .cmd_begin_render_pass(command_buffer, create_info, contents);
     }
@@ -1001,6 +1198,9 @@ pub trait DeviceV1_0 {
         command_buffer: vk::CommandBuffer,
         contents: vk::SubpassContents,
     ) {
+        validate!("vkCmdNextSubpass", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0().cmd_next_subpass(command_buffer, contents);
     }
 
@@ -1010,6 +1210,10 @@ pub trait DeviceV1_0 {
         pipeline_bind_point: vk::PipelineBindPoint,
         pipeline: vk::Pipeline,
     ) {
+        validate!("vkCmdBindPipeline", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("pipeline", pipeline);
+        });
         self.fp_v1_0()
             .cmd_bind_pipeline(command_buffer, pipeline_bind_point, pipeline);
     }
@@ -1020,6 +1224,9 @@ pub trait DeviceV1_0 {
         first_scissor: u32,
         scissors: &[vk::Rect2D],
     ) {
+        validate!("vkCmdSetScissor", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0().cmd_set_scissor(
             command_buffer,
             first_scissor,
@@ -1029,6 +1236,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn cmd_set_line_width(&self, command_buffer: vk::CommandBuffer, line_width: f32) {
+        validate!("vkCmdSetLineWidth", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0()
             .cmd_set_line_width(command_buffer, line_width);
     }
@@ -1040,6 +1250,10 @@ pub trait DeviceV1_0 {
         buffers: &[vk::Buffer],
         offsets: &[vk::DeviceSize],
     ) {
+        validate!("vkCmdBindVertexBuffers", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handles("buffers", buffers);
+        });
         debug_assert_eq!(buffers.len(), offsets.len());
         self.fp_v1_0().cmd_bind_vertex_buffers(
             command_buffer,
@@ -1051,6 +1265,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn cmd_end_render_pass(&self, command_buffer: vk::CommandBuffer) {
+        validate!("vkCmdEndRenderPass", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0().cmd_end_render_pass(command_buffer);
     }
 
@@ -1062,6 +1279,9 @@ pub trait DeviceV1_0 {
         first_vertex: u32,
         first_instance: u32,
     ) {
+        validate!("vkCmdDraw", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0().cmd_draw(
             command_buffer,
             vertex_count,
@@ -1079,6 +1299,10 @@ pub trait DeviceV1_0 {
         draw_count: u32,
         stride: u32,
     ) {
+        validate!("vkCmdDrawIndirect", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("buffer", buffer);
+        });
         self.fp_v1_0()
             .cmd_draw_indirect(command_buffer, buffer, offset, draw_count, stride);
     }
@@ -1090,6 +1314,9 @@ pub trait DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        validate!("vkCmdDispatch", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0()
             .cmd_dispatch(command_buffer, group_count_x, group_count_y, group_count_z);
     }
@@ -1100,6 +1327,10 @@ pub trait DeviceV1_0 {
         buffer: vk::Buffer,
         offset: vk::DeviceSize,
     ) {
+        validate!("vkCmdDispatchIndirect", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("buffer", buffer);
+        });
         self.fp_v1_0()
             .cmd_dispatch_indirect(command_buffer, buffer, offset);
     }
@@ -1110,6 +1341,9 @@ pub trait DeviceV1_0 {
         first_viewport: u32,
         viewports: &[vk::Viewport],
     ) {
+        validate!("vkCmdSetViewport", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0().cmd_set_viewport(
             command_buffer,
             first_viewport,
@@ -1125,6 +1359,9 @@ pub trait DeviceV1_0 {
         clamp: f32,
         slope_factor: f32,
     ) {
+        validate!("vkCmdSetDepthBias", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0()
             .cmd_set_depth_bias(command_buffer, constant_factor, clamp, slope_factor);
     }
@@ -1134,8 +1371,11 @@ pub trait DeviceV1_0 {
         command_buffer: vk::CommandBuffer,
         blend_constants: [f32; 4],
     ) {
+        validate!("vkCmdSetBlendConstants", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0()
-            .cmd_set_blend_constants(command_buffer, blend_constants);
+            .cmd_set_blend_constants(command_buffer, &blend_constants);
     }
 
     unsafe fn cmd_set_depth_bounds(
@@ -1144,6 +1384,9 @@ pub trait DeviceV1_0 {
         min_depth_bounds: f32,
         max_depth_bounds: f32,
     ) {
+        validate!("vkCmdSetDepthBounds", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0()
             .cmd_set_depth_bounds(command_buffer, min_depth_bounds, max_depth_bounds);
     }
@@ -1154,6 +1397,9 @@ pub trait DeviceV1_0 {
         face_mask: vk::StencilFaceFlags,
         compare_mask: u32,
     ) {
+        validate!("vkCmdSetStencilCompareMask", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0()
             .cmd_set_stencil_compare_mask(command_buffer, face_mask, compare_mask);
     }
@@ -1164,6 +1410,9 @@ pub trait DeviceV1_0 {
         face_mask: vk::StencilFaceFlags,
         write_mask: u32,
     ) {
+        validate!("vkCmdSetStencilWriteMask", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0()
             .cmd_set_stencil_write_mask(command_buffer, face_mask, write_mask);
     }
@@ -1174,6 +1423,9 @@ pub trait DeviceV1_0 {
         face_mask: vk::StencilFaceFlags,
         reference: u32,
     ) {
+        validate!("vkCmdSetStencilReference", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         self.fp_v1_0()
             .cmd_set_stencil_reference(command_buffer, face_mask, reference);
     }
@@ -1185,7 +1437,10 @@ pub trait DeviceV1_0 {
         query_count: u32,
         data: &mut [T],
         flags: vk::QueryResultFlags,
-    ) -> VkResult<()> {
+    ) -> VkResult<QueryResultStatus> {
+        validate!("vkGetQueryPoolResults", |v| {
+            v.handle("query_pool", query_pool);
+        });
         let data_length = query_count as usize;
         assert!(
             mem::size_of::<T>() <= mem::size_of::<u64>(),
@@ -1208,7 +1463,8 @@ pub trait DeviceV1_0 {
         );
 
         match err_code {
-            vk::Result::SUCCESS => Ok(()),
+            vk::Result::SUCCESS => Ok(QueryResultStatus::Ready),
+            vk::Result::NOT_READY => Ok(QueryResultStatus::NotReady),
             _ => Err(err_code),
         }
     }
@@ -1220,6 +1476,10 @@ pub trait DeviceV1_0 {
         query: u32,
         flags: vk::QueryControlFlags,
     ) {
+        validate!("vkCmdBeginQuery", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("query_pool", query_pool);
+        });
         self.fp_v1_0()
             .cmd_begin_query(command_buffer, query_pool, query, flags);
     }
@@ -1230,6 +1490,10 @@ pub trait DeviceV1_0 {
         query_pool: vk::QueryPool,
         query: u32,
     ) {
+        validate!("vkCmdEndQuery", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("query_pool", query_pool);
+        });
         self.fp_v1_0()
             .cmd_end_query(command_buffer, query_pool, query);
     }
@@ -1241,6 +1505,10 @@ pub trait DeviceV1_0 {
         first_query: u32,
         query_count: u32,
     ) {
+        validate!("vkCmdResetQueryPool", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("pool", pool);
+        });
         self.fp_v1_0()
             .cmd_reset_query_pool(command_buffer, pool, first_query, query_count);
     }
@@ -1252,6 +1520,10 @@ pub trait DeviceV1_0 {
         query_pool: vk::QueryPool,
         query: u32,
     ) {
+        validate!("vkCmdWriteTimestamp", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.handle("query_pool", query_pool);
+        });
         self.fp_v1_0()
             .cmd_write_timestamp(command_buffer, pipeline_stage, query_pool, query);
     }
@@ -1261,6 +1533,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::SemaphoreCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Semaphore> {
+        validate!("vkCreateSemaphore", |v| {
+            v.structure(create_info);
+        });
         let mut semaphore = mem::uninitialized();
         let err_code = self.fp_v1_0().create_semaphore(
             self.handle(),
@@ -1280,6 +1555,13 @@ pub trait DeviceV1_0 {
         create_infos: &[vk::GraphicsPipelineCreateInfo],
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
+        validate!("vkCreateGraphicsPipelines", |v| {
+            v.slice(create_infos);
+            for create_info in create_infos {
+                v.handle("create_info.layout", create_info.layout);
+                v.handle("create_info.render_pass", create_info.render_pass);
+            }
+        });
         let mut pipelines = Vec::with_capacity(create_infos.len());
         let err_code = self.fp_v1_0().create_graphics_pipelines(
             self.handle(),
@@ -1302,6 +1584,13 @@ pub trait DeviceV1_0 {
         create_infos: &[vk::ComputePipelineCreateInfo],
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
+        validate!("vkCreateComputePipelines", |v| {
+            v.slice(create_infos);
+            for create_info in create_infos {
+                v.handle("create_info.layout", create_info.layout);
+                v.handle("create_info.stage.module", create_info.stage.module);
+            }
+        });
         let mut pipelines = Vec::with_capacity(create_infos.len());
         let err_code = self.fp_v1_0().create_compute_pipelines(
             self.handle(),
@@ -1323,6 +1612,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::BufferCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Buffer> {
+        validate!("vkCreateBuffer", |v| {
+            v.structure(create_info);
+        });
         let mut buffer = mem::uninitialized();
         let err_code = self.fp_v1_0().create_buffer(
             self.handle(),
@@ -1341,6 +1633,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::PipelineLayoutCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::PipelineLayout> {
+        validate!("vkCreatePipelineLayout", |v| {
+            v.structure(create_info);
+        });
         let mut pipeline_layout = mem::uninitialized();
         let err_code = self.fp_v1_0().create_pipeline_layout(
             self.handle(),
@@ -1359,6 +1654,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::PipelineCacheCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::PipelineCache> {
+        validate!("vkCreatePipelineCache", |v| {
+            v.structure(create_info);
+        });
         let mut pipeline_cache = mem::uninitialized();
         let err_code = self.fp_v1_0().create_pipeline_cache(
             self.handle(),
@@ -1377,27 +1675,36 @@ pub trait DeviceV1_0 {
         &self,
         pipeline_cache: vk::PipelineCache,
     ) -> VkResult<Vec<u8>> {
-        let mut data_size: usize = 0;
-        let err_code = self.fp_v1_0().get_pipeline_cache_data(
-            self.handle(),
-            pipeline_cache,
-            &mut data_size,
-            ptr::null_mut(),
-        );
-        if err_code != vk::Result::SUCCESS {
-            return Err(err_code);
-        };
-        let mut data: Vec<u8> = Vec::with_capacity(data_size);
-        let err_code = self.fp_v1_0().get_pipeline_cache_data(
-            self.handle(),
-            pipeline_cache,
-            &mut data_size,
-            data.as_mut_ptr() as _,
-        );
-        data.set_len(data_size);
-        match err_code {
-            vk::Result::SUCCESS => Ok(data),
-            _ => Err(err_code),
+        validate!("vkGetPipelineCacheData", |v| {
+            v.handle("pipeline_cache", pipeline_cache);
+        });
+        loop {
+            let mut data_size: usize = 0;
+            let err_code = self.fp_v1_0().get_pipeline_cache_data(
+                self.handle(),
+                pipeline_cache,
+                &mut data_size,
+                ptr::null_mut(),
+            );
+            if err_code != vk::Result::SUCCESS {
+                return Err(err_code);
+            };
+            let mut data: Vec<u8> = Vec::with_capacity(data_size);
+            let err_code = self.fp_v1_0().get_pipeline_cache_data(
+                self.handle(),
+                pipeline_cache,
+                &mut data_size,
+                data.as_mut_ptr() as _,
+            );
+            match err_code {
+                vk::Result::SUCCESS => {
+                    data.set_len(data_size);
+                    return Ok(data);
+                }
+                // The cache grew between the two calls
+                vk::Result::INCOMPLETE => continue,
+                _ => return Err(err_code),
+            }
         }
     }
 
@@ -1408,6 +1715,9 @@ pub trait DeviceV1_0 {
         size: vk::DeviceSize,
         flags: vk::MemoryMapFlags,
     ) -> VkResult<*mut c_void> {
+        validate!("vkMapMemory", |v| {
+            v.handle("memory", memory);
+        });
         let mut data: *mut c_void = mem::uninitialized();
         let err_code =
             self.fp_v1_0()
@@ -1419,6 +1729,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn unmap_memory(&self, memory: vk::DeviceMemory) {
+        validate!("vkUnmapMemory", |v| {
+            v.handle("memory", memory);
+        });
         self.fp_v1_0().unmap_memory(self.handle(), memory);
     }
 
@@ -1426,6 +1739,9 @@ pub trait DeviceV1_0 {
         &self,
         ranges: &[vk::MappedMemoryRange],
     ) -> VkResult<()> {
+        validate!("vkInvalidateMappedMemoryRanges", |v| {
+            v.slice(ranges);
+        });
         let err_code = self.fp_v1_0().invalidate_mapped_memory_ranges(
             self.handle(),
             ranges.len() as u32,
@@ -1438,6 +1754,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn flush_mapped_memory_ranges(&self, ranges: &[vk::MappedMemoryRange]) -> VkResult<()> {
+        validate!("vkFlushMappedMemoryRanges", |v| {
+            v.slice(ranges);
+        });
         let err_code = self.fp_v1_0().flush_mapped_memory_ranges(
             self.handle(),
             ranges.len() as u32,
@@ -1454,6 +1773,10 @@ pub trait DeviceV1_0 {
         create_info: &vk::FramebufferCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Framebuffer> {
+        validate!("vkCreateFramebuffer", |v| {
+            v.structure(create_info);
+            v.handle("create_info.render_pass", create_info.render_pass);
+        });
         let mut framebuffer = mem::uninitialized();
         let err_code = self.fp_v1_0().create_framebuffer(
             self.handle(),
@@ -1484,6 +1807,12 @@ pub trait DeviceV1_0 {
         buffer_memory_barriers: &[vk::BufferMemoryBarrier],
         image_memory_barriers: &[vk::ImageMemoryBarrier],
     ) {
+        validate!("vkCmdPipelineBarrier", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.slice(memory_barriers);
+            v.slice(buffer_memory_barriers);
+            v.slice(image_memory_barriers);
+        });
         self.fp_v1_0().cmd_pipeline_barrier(
             command_buffer,
             src_stage_mask,
@@ -1503,6 +1832,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::RenderPassCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::RenderPass> {
+        validate!("vkCreateRenderPass", |v| {
+            v.structure(create_info);
+        });
         let mut renderpass = mem::uninitialized();
         let err_code = self.fp_v1_0().create_render_pass(
             self.handle(),
@@ -1521,6 +1853,10 @@ pub trait DeviceV1_0 {
         command_buffer: vk::CommandBuffer,
         begin_info: &vk::CommandBufferBeginInfo,
     ) -> VkResult<()> {
+        validate!("vkBeginCommandBuffer", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+            v.structure(begin_info);
+        });
         let err_code = self
             .fp_v1_0()
             .begin_command_buffer(command_buffer, begin_info);
@@ -1531,6 +1867,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn end_command_buffer(&self, command_buffer: vk::CommandBuffer) -> VkResult<()> {
+        validate!("vkEndCommandBuffer", |v| {
+            v.dispatchable("command_buffer", command_buffer, self.handle());
+        });
         let err_code = self.fp_v1_0().end_command_buffer(command_buffer);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
@@ -1543,7 +1882,10 @@ pub trait DeviceV1_0 {
         fences: &[vk::Fence],
         wait_all: bool,
         timeout: u64,
-    ) -> VkResult<()> {
+    ) -> VkResult<WaitResult> {
+        validate!("vkWaitForFences", |v| {
+            v.handles("fences", fences);
+        });
         let err_code = self.fp_v1_0().wait_for_fences(
             self.handle(),
             fences.len() as u32,
@@ -1552,20 +1894,28 @@ pub trait DeviceV1_0 {
             timeout,
         );
         match err_code {
-            vk::Result::SUCCESS => Ok(()),
+            vk::Result::SUCCESS => Ok(WaitResult::Success),
+            vk::Result::TIMEOUT => Ok(WaitResult::TimedOut),
             _ => Err(err_code),
         }
     }
 
-    unsafe fn get_fence_status(&self, fence: vk::Fence) -> VkResult<()> {
+    unsafe fn get_fence_status(&self, fence: vk::Fence) -> VkResult<FenceStatus> {
+        validate!("vkGetFenceStatus", |v| {
+            v.handle("fence", fence);
+        });
         let err_code = self.fp_v1_0().get_fence_status(self.handle(), fence);
         match err_code {
-            vk::Result::SUCCESS => Ok(()),
+            vk::Result::SUCCESS => Ok(FenceStatus::Signaled),
+            vk::Result::NOT_READY => Ok(FenceStatus::NotReady),
             _ => Err(err_code),
         }
     }
 
     unsafe fn queue_wait_idle(&self, queue: vk::Queue) -> VkResult<()> {
+        validate!("vkQueueWaitIdle", |v| {
+            v.dispatchable("queue", queue, self.handle());
+        });
         let err_code = self.fp_v1_0().queue_wait_idle(queue);
         match err_code {
             vk::Result::SUCCESS => Ok(()),
@@ -1579,6 +1929,10 @@ pub trait DeviceV1_0 {
         submits: &[vk::SubmitInfo],
         fence: vk::Fence,
     ) -> VkResult<()> {
+        validate!("vkQueueSubmit", |v| {
+            v.dispatchable("queue", queue, self.handle());
+            v.slice(submits);
+        });
         let err_code =
             self.fp_v1_0()
                 .queue_submit(queue, submits.len() as u32, submits.as_ptr(), fence);
@@ -1593,6 +1947,10 @@ pub trait DeviceV1_0 {
         create_info: &vk::BufferViewCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::BufferView> {
+        validate!("vkCreateBufferView", |v| {
+            v.structure(create_info);
+            v.handle("create_info.buffer", create_info.buffer);
+        });
         let mut buffer_view = mem::uninitialized();
         let err_code = self.fp_v1_0().create_buffer_view(
             self.handle(),
@@ -1623,6 +1981,10 @@ pub trait DeviceV1_0 {
         create_info: &vk::ImageViewCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::ImageView> {
+        validate!("vkCreateImageView", |v| {
+            v.structure(create_info);
+            v.handle("create_info.image", create_info.image);
+        });
         let mut image_view = mem::uninitialized();
         let err_code = self.fp_v1_0().create_image_view(
             self.handle(),
@@ -1640,6 +2002,10 @@ pub trait DeviceV1_0 {
         &self,
         create_info: &vk::CommandBufferAllocateInfo,
     ) -> VkResult<Vec<vk::CommandBuffer>> {
+        validate!("vkAllocateCommandBuffers", |v| {
+            v.structure(create_info);
+            v.handle("create_info.command_pool", create_info.command_pool);
+        });
         let mut buffers = Vec::with_capacity(create_info.command_buffer_count as usize);
         let err_code = self.fp_v1_0().allocate_command_buffers(
             self.handle(),
@@ -1658,6 +2024,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::CommandPoolCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::CommandPool> {
+        validate!("vkCreateCommandPool", |v| {
+            v.structure(create_info);
+        });
         let mut pool = mem::uninitialized();
         let err_code = self.fp_v1_0().create_command_pool(
             self.handle(),
@@ -1676,6 +2045,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::QueryPoolCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::QueryPool> {
+        validate!("vkCreateQueryPool", |v| {
+            v.structure(create_info);
+        });
         let mut pool = mem::uninitialized();
         let err_code = self.fp_v1_0().create_query_pool(
             self.handle(),
@@ -1694,6 +2066,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::ImageCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Image> {
+        validate!("vkCreateImage", |v| {
+            v.structure(create_info);
+        });
         let mut image = mem::uninitialized();
         let err_code = self.fp_v1_0().create_image(
             self.handle(),
@@ -1712,6 +2087,9 @@ pub trait DeviceV1_0 {
         image: vk::Image,
         subresource: vk::ImageSubresource,
     ) -> vk::SubresourceLayout {
+        validate!("vkGetImageSubresourceLayout", |v| {
+            v.handle("image", image);
+        });
         let mut layout = mem::uninitialized();
         self.fp_v1_0().get_image_subresource_layout(
             self.handle(),
@@ -1723,6 +2101,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn get_image_memory_requirements(&self, image: vk::Image) -> vk::MemoryRequirements {
+        validate!("vkGetImageMemoryRequirements", |v| {
+            v.handle("image", image);
+        });
         let mut mem_req = mem::uninitialized();
         self.fp_v1_0()
             .get_image_memory_requirements(self.handle(), image, &mut mem_req);
@@ -1730,6 +2111,9 @@ pub trait DeviceV1_0 {
     }
 
     unsafe fn get_buffer_memory_requirements(&self, buffer: vk::Buffer) -> vk::MemoryRequirements {
+        validate!("vkGetBufferMemoryRequirements", |v| {
+            v.handle("buffer", buffer);
+        });
         let mut mem_req = mem::uninitialized();
         self.fp_v1_0()
             .get_buffer_memory_requirements(self.handle(), buffer, &mut mem_req);
@@ -1741,6 +2125,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::MemoryAllocateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DeviceMemory> {
+        validate!("vkAllocateMemory", |v| {
+            v.structure(create_info);
+        });
         let mut memory = mem::uninitialized();
         let err_code = self.fp_v1_0().allocate_memory(
             self.handle(),
@@ -1759,6 +2146,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::ShaderModuleCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::ShaderModule> {
+        validate!("vkCreateShaderModule", |v| {
+            v.structure(create_info);
+        });
         let mut shader = mem::uninitialized();
         let err_code = self.fp_v1_0().create_shader_module(
             self.handle(),
@@ -1777,6 +2167,9 @@ pub trait DeviceV1_0 {
         create_info: &vk::FenceCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::Fence> {
+        validate!("vkCreateFence", |v| {
+            v.structure(create_info);
+        });
         let mut fence = mem::uninitialized();
         let err_code = self.fp_v1_0().create_fence(
             self.handle(),
@@ -1796,6 +2189,10 @@ pub trait DeviceV1_0 {
         device_memory: vk::DeviceMemory,
         offset: vk::DeviceSize,
     ) -> VkResult<()> {
+        validate!("vkBindBufferMemory", |v| {
+            v.handle("buffer", buffer);
+            v.handle("device_memory", device_memory);
+        });
         let err_code =
             self.fp_v1_0()
                 .bind_buffer_memory(self.handle(), buffer, device_memory, offset);
@@ -1811,6 +2208,10 @@ pub trait DeviceV1_0 {
         device_memory: vk::DeviceMemory,
         offset: vk::DeviceSize,
     ) -> VkResult<()> {
+        validate!("vkBindImageMemory", |v| {
+            v.handle("image", image);
+            v.handle("device_memory", device_memory);
+        });
         let err_code =
             self.fp_v1_0()
                 .bind_image_memory(self.handle(), image, device_memory, offset);
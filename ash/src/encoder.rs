@@ -0,0 +1,552 @@
+//! Elimination of redundant state changes while recording a command buffer.
+//!
+//! `CachingEncoder` wraps a command buffer that is being recorded and remembers the state that
+//! is currently bound. Binds that would not change the state are skipped and counted in
+//! `EncoderStats`.
+//!
+//! ```no_run
+//! # use ash::encoder::CachingEncoder;
+//! # use ash::vk;
+//! # unsafe fn test(device: &ash::Device, command_buffer: vk::CommandBuffer, pipeline: vk::Pipeline) {
+//! let mut encoder = CachingEncoder::new(device, command_buffer);
+//! for _ in 0..100 {
+//!     encoder.cmd_bind_pipeline(vk::PipelineBindPoint::GRAPHICS, pipeline);
+//!     // ...
+//! }
+//! assert_eq!(encoder.stats().pipelines.elided, 99);
+//! # }
+//! ```
+//!
+//! The cache only knows about state that was set through the encoder. Call `invalidate` after
+//! state was changed behind its back, e.g. with `cmd_execute_commands`, which leaves the bound
+//! state undefined.
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use version::DeviceV1_0;
+use vk;
+
+/// Number of binds that were passed to the device and that were skipped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BindCounter {
+    pub issued: u64,
+    pub elided: u64,
+}
+
+impl BindCounter {
+    fn count(&mut self, issued: bool) {
+        if issued {
+            self.issued += 1;
+        } else {
+            self.elided += 1;
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EncoderStats {
+    pub pipelines: BindCounter,
+    pub descriptor_sets: BindCounter,
+    pub vertex_buffers: BindCounter,
+    pub index_buffers: BindCounter,
+    pub viewports: BindCounter,
+    pub scissors: BindCounter,
+    pub push_constants: BindCounter,
+}
+
+impl EncoderStats {
+    pub fn total(&self) -> BindCounter {
+        let counters = [
+            self.pipelines,
+            self.descriptor_sets,
+            self.vertex_buffers,
+            self.index_buffers,
+            self.viewports,
+            self.scissors,
+            self.push_constants,
+        ];
+        counters
+            .iter()
+            .fold(BindCounter::default(), |acc, counter| BindCounter {
+                issued: acc.issued + counter.issued,
+                elided: acc.elided + counter.elided,
+            })
+    }
+}
+
+impl fmt::Display for EncoderStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows = [
+            ("pipelines", self.pipelines),
+            ("descriptor sets", self.descriptor_sets),
+            ("vertex buffers", self.vertex_buffers),
+            ("index buffers", self.index_buffers),
+            ("viewports", self.viewports),
+            ("scissors", self.scissors),
+            ("push constants", self.push_constants),
+        ];
+        for &(name, counter) in &rows {
+            writeln!(
+                f,
+                "{}: {} issued, {} elided",
+                name, counter.issued, counter.elided
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct BindPointState {
+    pipeline: Option<vk::Pipeline>,
+    layout: Option<vk::PipelineLayout>,
+    // Indexed by set number: (descriptor set, dynamic offsets)
+    descriptor_sets: Vec<Option<(vk::DescriptorSet, Vec<u32>)>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PushConstantRange {
+    stage_flags: vk::ShaderStageFlags,
+    offset: u32,
+    data: Vec<u8>,
+}
+
+/// The state that is currently bound to one command buffer.
+///
+/// Every function updates the cache and returns which part of the bind still has to be passed
+/// to the device. It does not record anything itself, `CachingEncoder` does.
+#[derive(Debug, Clone, Default)]
+pub struct BindingCache {
+    // Every bind point has its own pipeline and descriptor sets
+    bind_points: HashMap<vk::PipelineBindPoint, BindPointState>,
+    vertex_buffers: Vec<Option<(vk::Buffer, vk::DeviceSize)>>,
+    index_buffer: Option<(vk::Buffer, vk::DeviceSize, vk::IndexType)>,
+    viewports: Vec<Option<vk::Viewport>>,
+    scissors: Vec<Option<vk::Rect2D>>,
+    push_constant_layout: Option<vk::PipelineLayout>,
+    push_constants: Vec<PushConstantRange>,
+}
+
+impl BindingCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets everything that is bound.
+    pub fn invalidate(&mut self) {
+        *self = Self::default();
+    }
+
+    fn bind_point(&mut self, bind_point: vk::PipelineBindPoint) -> &mut BindPointState {
+        self.bind_points.entry(bind_point).or_default()
+    }
+
+    /// Returns true if the pipeline has to be bound. Binding a different graphics pipeline
+    /// overwrites the viewport and scissor state, unless they are listed in `dynamic_states`.
+    pub fn bind_pipeline(
+        &mut self,
+        bind_point: vk::PipelineBindPoint,
+        pipeline: vk::Pipeline,
+        dynamic_states: &[vk::DynamicState],
+    ) -> bool {
+        if self.bind_point(bind_point).pipeline == Some(pipeline) {
+            return false;
+        }
+        self.bind_point(bind_point).pipeline = Some(pipeline);
+        if bind_point == vk::PipelineBindPoint::GRAPHICS {
+            if !dynamic_states.contains(&vk::DynamicState::VIEWPORT) {
+                self.viewports.clear();
+            }
+            if !dynamic_states.contains(&vk::DynamicState::SCISSOR) {
+                self.scissors.clear();
+            }
+        }
+        true
+    }
+
+    /// Returns the range of `descriptor_sets` that has to be bound, which is either all or none
+    /// of them. Sets with dynamic offsets are only skipped if the offsets are the same as well.
+    pub fn bind_descriptor_sets(
+        &mut self,
+        bind_point: vk::PipelineBindPoint,
+        layout: vk::PipelineLayout,
+        first_set: u32,
+        descriptor_sets: &[vk::DescriptorSet],
+        dynamic_offsets: &[u32],
+    ) -> Option<Range<usize>> {
+        let state = self.bind_point(bind_point);
+        if state.layout != Some(layout) {
+            // Compatibility between layouts is not tracked, assume everything was disturbed
+            state.layout = Some(layout);
+            state.descriptor_sets.clear();
+        }
+        // Dynamic offsets can't be split between sets without knowing how many dynamic
+        // descriptors each set has, so the whole call is recorded if any set changed.
+        let end = first_set as usize + descriptor_sets.len();
+        if state.descriptor_sets.len() < end {
+            state.descriptor_sets.resize(end, None);
+        }
+        let mut changed = false;
+        for (i, &set) in descriptor_sets.iter().enumerate() {
+            let slot = &mut state.descriptor_sets[first_set as usize + i];
+            let new = (set, dynamic_offsets.to_vec());
+            if slot.as_ref() != Some(&new) {
+                changed = true;
+                *slot = Some(new);
+            }
+        }
+        if changed {
+            Some(0..descriptor_sets.len())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the range of `buffers`/`offsets` that has to be bound, starting at
+    /// `first_binding`.
+    pub fn bind_vertex_buffers(
+        &mut self,
+        first_binding: u32,
+        buffers: &[vk::Buffer],
+        offsets: &[vk::DeviceSize],
+    ) -> Option<Range<usize>> {
+        let bindings = buffers.iter().cloned().zip(offsets.iter().cloned());
+        update_range(&mut self.vertex_buffers, first_binding, bindings)
+    }
+
+    /// Returns true if the index buffer has to be bound.
+    pub fn bind_index_buffer(
+        &mut self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        index_type: vk::IndexType,
+    ) -> bool {
+        let new = Some((buffer, offset, index_type));
+        if self.index_buffer == new {
+            return false;
+        }
+        self.index_buffer = new;
+        true
+    }
+
+    /// Returns the range of `viewports` that has to be set, starting at `first_viewport`.
+    pub fn set_viewports(
+        &mut self,
+        first_viewport: u32,
+        viewports: &[vk::Viewport],
+    ) -> Option<Range<usize>> {
+        update_range_by(
+            &mut self.viewports,
+            first_viewport,
+            viewports.iter().cloned(),
+            viewport_eq,
+        )
+    }
+
+    /// Returns the range of `scissors` that has to be set, starting at `first_scissor`.
+    pub fn set_scissors(
+        &mut self,
+        first_scissor: u32,
+        scissors: &[vk::Rect2D],
+    ) -> Option<Range<usize>> {
+        update_range_by(
+            &mut self.scissors,
+            first_scissor,
+            scissors.iter().cloned(),
+            rect_eq,
+        )
+    }
+
+    /// Returns true if the push constants have to be pushed. Only pushes that are identical to
+    /// an earlier push with the same layout, stages and offset are skipped.
+    pub fn push_constants(
+        &mut self,
+        layout: vk::PipelineLayout,
+        stage_flags: vk::ShaderStageFlags,
+        offset: u32,
+        constants: &[u8],
+    ) -> bool {
+        if self.push_constant_layout != Some(layout) {
+            self.push_constant_layout = Some(layout);
+            self.push_constants.clear();
+        }
+        let start = offset;
+        let end = offset + constants.len() as u32;
+        let is_same = |range: &PushConstantRange| {
+            range.stage_flags == stage_flags && range.offset == offset && range.data == constants
+        };
+        if self.push_constants.iter().any(|range| is_same(range)) {
+            return false;
+        }
+        // Drop every range that overlaps with the new one
+        self.push_constants.retain(|range| {
+            let range_end = range.offset + range.data.len() as u32;
+            !(range.stage_flags.intersects(stage_flags) && range.offset < end && start < range_end)
+        });
+        self.push_constants.push(PushConstantRange {
+            stage_flags,
+            offset,
+            data: constants.to_vec(),
+        });
+        true
+    }
+}
+
+fn viewport_eq(a: &vk::Viewport, b: &vk::Viewport) -> bool {
+    a.x == b.x
+        && a.y == b.y
+        && a.width == b.width
+        && a.height == b.height
+        && a.min_depth == b.min_depth
+        && a.max_depth == b.max_depth
+}
+
+fn rect_eq(a: &vk::Rect2D, b: &vk::Rect2D) -> bool {
+    a.offset.x == b.offset.x
+        && a.offset.y == b.offset.y
+        && a.extent.width == b.extent.width
+        && a.extent.height == b.extent.height
+}
+
+fn update_range<T: PartialEq, I: Iterator<Item = T>>(
+    cache: &mut Vec<Option<T>>,
+    first: u32,
+    values: I,
+) -> Option<Range<usize>> {
+    update_range_by(cache, first, values, |a, b| a == b)
+}
+
+/// Stores `values` at `first..` and returns the smallest range of `values` that differs from
+/// what was cached.
+fn update_range_by<T, I, F>(
+    cache: &mut Vec<Option<T>>,
+    first: u32,
+    values: I,
+    eq: F,
+) -> Option<Range<usize>>
+where
+    I: Iterator<Item = T>,
+    F: Fn(&T, &T) -> bool,
+{
+    let first = first as usize;
+    let mut changed: Option<Range<usize>> = None;
+    for (i, value) in values.enumerate() {
+        if cache.len() <= first + i {
+            cache.push(None);
+        }
+        let slot = &mut cache[first + i];
+        let is_same = match *slot {
+            Some(ref cached) => eq(cached, &value),
+            None => false,
+        };
+        if !is_same {
+            *slot = Some(value);
+            changed = Some(match changed {
+                Some(range) => range.start..i + 1,
+                None => i..i + 1,
+            });
+        }
+    }
+    changed
+}
+
+/// Records binds into a command buffer and skips the ones that would not change the bound
+/// state. See the module documentation.
+pub struct CachingEncoder<'d, D: DeviceV1_0 + 'd> {
+    device: &'d D,
+    command_buffer: vk::CommandBuffer,
+    cache: BindingCache,
+    stats: EncoderStats,
+}
+
+impl<'d, D: DeviceV1_0> CachingEncoder<'d, D> {
+    /// `command_buffer` must be in the recording state, and nothing must have been bound yet.
+    pub fn new(device: &'d D, command_buffer: vk::CommandBuffer) -> Self {
+        CachingEncoder {
+            device,
+            command_buffer,
+            cache: BindingCache::new(),
+            stats: EncoderStats::default(),
+        }
+    }
+
+    pub fn device(&self) -> &'d D {
+        self.device
+    }
+
+    /// The command buffer, for recording commands that the encoder does not cache.
+    pub fn command_buffer(&self) -> vk::CommandBuffer {
+        self.command_buffer
+    }
+
+    pub fn stats(&self) -> &EncoderStats {
+        &self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = EncoderStats::default();
+    }
+
+    /// Forgets the cached state, the next bind of every kind is recorded.
+    pub fn invalidate(&mut self) {
+        self.cache.invalidate();
+    }
+
+    /// Binding a different graphics pipeline forgets the cached viewports and scissors,
+    /// because a pipeline with static viewport or scissor state overwrites them. Use
+    /// `cmd_bind_pipeline_with_dynamic_state` for pipelines that have them as dynamic state.
+    pub unsafe fn cmd_bind_pipeline(
+        &mut self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        pipeline: vk::Pipeline,
+    ) {
+        self.cmd_bind_pipeline_with_dynamic_state(pipeline_bind_point, pipeline, &[]);
+    }
+
+    /// `dynamic_states` must be the dynamic states `pipeline` was created with.
+    pub unsafe fn cmd_bind_pipeline_with_dynamic_state(
+        &mut self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        pipeline: vk::Pipeline,
+        dynamic_states: &[vk::DynamicState],
+    ) {
+        let issue = self
+            .cache
+            .bind_pipeline(pipeline_bind_point, pipeline, dynamic_states);
+        if issue {
+            self.device
+                .cmd_bind_pipeline(self.command_buffer, pipeline_bind_point, pipeline);
+        }
+        self.stats.pipelines.count(issue);
+    }
+
+    pub unsafe fn cmd_bind_descriptor_sets(
+        &mut self,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        layout: vk::PipelineLayout,
+        first_set: u32,
+        descriptor_sets: &[vk::DescriptorSet],
+        dynamic_offsets: &[u32],
+    ) {
+        let range = self.cache.bind_descriptor_sets(
+            pipeline_bind_point,
+            layout,
+            first_set,
+            descriptor_sets,
+            dynamic_offsets,
+        );
+        if range.is_some() {
+            self.device.cmd_bind_descriptor_sets(
+                self.command_buffer,
+                pipeline_bind_point,
+                layout,
+                first_set,
+                descriptor_sets,
+                dynamic_offsets,
+            );
+        }
+        self.stats.descriptor_sets.count(range.is_some());
+    }
+
+    /// Only the bindings that changed are recorded.
+    pub unsafe fn cmd_bind_vertex_buffers(
+        &mut self,
+        first_binding: u32,
+        buffers: &[vk::Buffer],
+        offsets: &[vk::DeviceSize],
+    ) {
+        assert_eq!(buffers.len(), offsets.len());
+        let range = self
+            .cache
+            .bind_vertex_buffers(first_binding, buffers, offsets);
+        if let Some(ref range) = range {
+            self.device.cmd_bind_vertex_buffers(
+                self.command_buffer,
+                first_binding + range.start as u32,
+                &buffers[range.clone()],
+                &offsets[range.clone()],
+            );
+        }
+        self.stats.vertex_buffers.count(range.is_some());
+    }
+
+    pub unsafe fn cmd_bind_index_buffer(
+        &mut self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        index_type: vk::IndexType,
+    ) {
+        let issue = self.cache.bind_index_buffer(buffer, offset, index_type);
+        if issue {
+            self.device
+                .cmd_bind_index_buffer(self.command_buffer, buffer, offset, index_type);
+        }
+        self.stats.index_buffers.count(issue);
+    }
+
+    /// Only the viewports that changed are recorded.
+    pub unsafe fn cmd_set_viewport(&mut self, first_viewport: u32, viewports: &[vk::Viewport]) {
+        let range = self.cache.set_viewports(first_viewport, viewports);
+        if let Some(ref range) = range {
+            self.device.cmd_set_viewport(
+                self.command_buffer,
+                first_viewport + range.start as u32,
+                &viewports[range.clone()],
+            );
+        }
+        self.stats.viewports.count(range.is_some());
+    }
+
+    /// Only the scissors that changed are recorded.
+    pub unsafe fn cmd_set_scissor(&mut self, first_scissor: u32, scissors: &[vk::Rect2D]) {
+        let range = self.cache.set_scissors(first_scissor, scissors);
+        if let Some(ref range) = range {
+            self.device.cmd_set_scissor(
+                self.command_buffer,
+                first_scissor + range.start as u32,
+                &scissors[range.clone()],
+            );
+        }
+        self.stats.scissors.count(range.is_some());
+    }
+
+    pub unsafe fn cmd_push_constants(
+        &mut self,
+        layout: vk::PipelineLayout,
+        stage_flags: vk::ShaderStageFlags,
+        offset: u32,
+        constants: &[u8],
+    ) {
+        let issue = self
+            .cache
+            .push_constants(layout, stage_flags, offset, constants);
+        if issue {
+            self.device.cmd_push_constants(
+                self.command_buffer,
+                layout,
+                stage_flags,
+                offset,
+                constants,
+            );
+        }
+        self.stats.push_constants.count(issue);
+    }
+
+    /// Executes secondary command buffers. The bound state is undefined afterwards, so the
+    /// cache is invalidated.
+    pub unsafe fn cmd_execute_commands(&mut self, secondary_command_buffers: &[vk::CommandBuffer]) {
+        self.device
+            .cmd_execute_commands(self.command_buffer, secondary_command_buffers);
+        self.cache.invalidate();
+    }
+}
+
+impl<'d, D: DeviceV1_0> fmt::Debug for CachingEncoder<'d, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CachingEncoder")
+            .field("command_buffer", &self.command_buffer)
+            .field("cache", &self.cache)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
@@ -83,40 +83,18 @@ pub trait EntryV1_0 {
     ) -> Result<Self::Instance, InstanceError>;
     fn enumerate_instance_layer_properties(&self) -> VkResult<Vec<vk::LayerProperties>> {
         unsafe {
-            let mut num = 0;
-            self.fp_v1_0()
-                .enumerate_instance_layer_properties(&mut num, ptr::null_mut());
-
-            let mut v = Vec::with_capacity(num as usize);
-            let err_code = self
-                .fp_v1_0()
-                .enumerate_instance_layer_properties(&mut num, v.as_mut_ptr());
-            v.set_len(num as usize);
-            match err_code {
-                vk::Result::SUCCESS => Ok(v),
-                _ => Err(err_code),
-            }
+            read_into_vec(|count, data| {
+                self.fp_v1_0()
+                    .enumerate_instance_layer_properties(count, data)
+            })
         }
     }
     fn enumerate_instance_extension_properties(&self) -> VkResult<Vec<vk::ExtensionProperties>> {
         unsafe {
-            let mut num = 0;
-            self.fp_v1_0().enumerate_instance_extension_properties(
-                ptr::null(),
-                &mut num,
-                ptr::null_mut(),
-            );
-            let mut data = Vec::with_capacity(num as usize);
-            let err_code = self.fp_v1_0().enumerate_instance_extension_properties(
-                ptr::null(),
-                &mut num,
-                data.as_mut_ptr(),
-            );
-            data.set_len(num as usize);
-            match err_code {
-                vk::Result::SUCCESS => Ok(data),
-                _ => Err(err_code),
-            }
+            read_into_vec(|count, data| {
+                self.fp_v1_0()
+                    .enumerate_instance_extension_properties(ptr::null(), count, data)
+            })
         }
     }
 
@@ -0,0 +1,442 @@
+command_error! {
+    #[doc = "Error codes of `vkAcquireNextImage2KHR`."]
+    AcquireNextImage2Error {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+        OutOfDate = ERROR_OUT_OF_DATE_KHR,
+        SurfaceLost = ERROR_SURFACE_LOST_KHR,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkAcquireNextImageKHR`."]
+    AcquireNextImageError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+        OutOfDate = ERROR_OUT_OF_DATE_KHR,
+        SurfaceLost = ERROR_SURFACE_LOST_KHR,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkAllocateCommandBuffers`."]
+    AllocateCommandBuffersError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkAllocateDescriptorSets`."]
+    AllocateDescriptorSetsError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        FragmentedPool = ERROR_FRAGMENTED_POOL,
+        OutOfPoolMemory = ERROR_OUT_OF_POOL_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkAllocateMemory`."]
+    AllocateMemoryError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        TooManyObjects = ERROR_TOO_MANY_OBJECTS,
+        InvalidExternalHandle = ERROR_INVALID_EXTERNAL_HANDLE,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkBeginCommandBuffer`."]
+    BeginCommandBufferError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkBindBufferMemory`."]
+    BindBufferMemoryError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkBindImageMemory`."]
+    BindImageMemoryError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateBuffer`."]
+    CreateBufferError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateBufferView`."]
+    CreateBufferViewError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateCommandPool`."]
+    CreateCommandPoolError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateComputePipelines`."]
+    CreateComputePipelinesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        InvalidShader = ERROR_INVALID_SHADER_NV,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateDescriptorPool`."]
+    CreateDescriptorPoolError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        Fragmentation = ERROR_FRAGMENTATION_EXT,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateDescriptorSetLayout`."]
+    CreateDescriptorSetLayoutError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateEvent`."]
+    CreateEventError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateFence`."]
+    CreateFenceError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateFramebuffer`."]
+    CreateFramebufferError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateGraphicsPipelines`."]
+    CreateGraphicsPipelinesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        InvalidShader = ERROR_INVALID_SHADER_NV,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateImage`."]
+    CreateImageError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateImageView`."]
+    CreateImageViewError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreatePipelineCache`."]
+    CreatePipelineCacheError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreatePipelineLayout`."]
+    CreatePipelineLayoutError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateQueryPool`."]
+    CreateQueryPoolError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateRenderPass`."]
+    CreateRenderPassError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateSampler`."]
+    CreateSamplerError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateSemaphore`."]
+    CreateSemaphoreError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateShaderModule`."]
+    CreateShaderModuleError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        InvalidShader = ERROR_INVALID_SHADER_NV,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkCreateSwapchainKHR`."]
+    CreateSwapchainError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+        SurfaceLost = ERROR_SURFACE_LOST_KHR,
+        NativeWindowInUse = ERROR_NATIVE_WINDOW_IN_USE_KHR,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkDeviceWaitIdle`."]
+    DeviceWaitIdleError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkEndCommandBuffer`."]
+    EndCommandBufferError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkFlushMappedMemoryRanges`."]
+    FlushMappedMemoryRangesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkGetDeviceGroupPresentCapabilitiesKHR`."]
+    GetDeviceGroupPresentCapabilitiesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkGetDeviceGroupSurfacePresentModesKHR`."]
+    GetDeviceGroupSurfacePresentModesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        SurfaceLost = ERROR_SURFACE_LOST_KHR,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkGetEventStatus`."]
+    GetEventStatusError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkGetFenceStatus`."]
+    GetFenceStatusError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkGetPhysicalDevicePresentRectanglesKHR`."]
+    GetPhysicalDevicePresentRectanglesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkGetPipelineCacheData`."]
+    GetPipelineCacheDataError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkGetQueryPoolResults`."]
+    GetQueryPoolResultsError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkGetSwapchainImagesKHR`."]
+    GetSwapchainImagesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkInvalidateMappedMemoryRanges`."]
+    InvalidateMappedMemoryRangesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkMapMemory`."]
+    MapMemoryError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        MemoryMapFailed = ERROR_MEMORY_MAP_FAILED,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkMergePipelineCaches`."]
+    MergePipelineCachesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkQueueBindSparse`."]
+    QueueBindSparseError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkQueuePresentKHR`."]
+    QueuePresentError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+        OutOfDate = ERROR_OUT_OF_DATE_KHR,
+        SurfaceLost = ERROR_SURFACE_LOST_KHR,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkQueueSubmit`."]
+    QueueSubmitError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkQueueWaitIdle`."]
+    QueueWaitIdleError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkResetCommandBuffer`."]
+    ResetCommandBufferError {
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkResetCommandPool`."]
+    ResetCommandPoolError {
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkResetEvent`."]
+    ResetEventError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkResetFences`."]
+    ResetFencesError {
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkSetEvent`."]
+    SetEventError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+    }
+}
+
+command_error! {
+    #[doc = "Error codes of `vkWaitForFences`."]
+    WaitForFencesError {
+        OutOfHostMemory = ERROR_OUT_OF_HOST_MEMORY,
+        OutOfDeviceMemory = ERROR_OUT_OF_DEVICE_MEMORY,
+        DeviceLost = ERROR_DEVICE_LOST,
+    }
+}
@@ -0,0 +1,72 @@
+//! Per-command error types, generated from the `errorcodes` that vk.xml lists for every
+//! command of `DeviceV1_0` and `VK_KHR_swapchain`. Requires the `typed_errors` feature.
+//!
+//! The wrappers of `DeviceV1_0` and `extensions::khr::Swapchain` keep returning `vk::Result`.
+//! `TypedErrors::typed` gives access to variants of the fallible ones that return an enum with
+//! a variant for each code the command may return, so matching on it is exhaustive:
+//!
+//! ```ignore
+//! use ash::error::{AcquireNextImageError, TypedErrors};
+//!
+//! match swapchain_loader.typed().acquire_next_image(swapchain, !0, semaphore, vk::Fence::null()) {
+//!     Ok(result) => ...,
+//!     Err(AcquireNextImageError::OutOfDate) => recreate_swapchain(),
+//!     Err(err) => return Err(err.into()),
+//! }
+//! ```
+//!
+//! `Other` holds codes that the specification does not allow for the command, which layers
+//! and broken drivers may return anyway. Every type converts into `vk::Result`, so `?` works in
+//! functions returning `VkResult`.
+macro_rules! command_error {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident = $code:ident,)* }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            Other(::vk::Result),
+        }
+
+        impl From<::vk::Result> for $name {
+            fn from(result: ::vk::Result) -> Self {
+                match result {
+                    $(::vk::Result::$code => $name::$variant,)*
+                    _ => $name::Other(result),
+                }
+            }
+        }
+
+        impl From<$name> for ::vk::Result {
+            fn from(err: $name) -> Self {
+                match err {
+                    $($name::$variant => ::vk::Result::$code,)*
+                    $name::Other(result) => result,
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&::vk::Result::from(*self), f)
+            }
+        }
+
+        impl ::std::error::Error for $name {
+            #[allow(deprecated)]
+            fn description(&self) -> &str {
+                match *self {
+                    $($name::$variant => {
+                        static CODE: ::vk::Result = ::vk::Result::$code;
+                        CODE.description()
+                    })*
+                    $name::Other(ref result) => result.description(),
+                }
+            }
+        }
+    };
+}
+
+mod generated;
+mod typed;
+pub use self::generated::*;
+pub use self::typed::{Typed, TypedErrors};
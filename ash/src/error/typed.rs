@@ -0,0 +1,460 @@
+use extensions::khr::Swapchain;
+use prelude::*;
+use std::os::raw::c_void;
+use version::DeviceV1_0;
+use vk;
+
+use super::*;
+
+/// Gives access to the wrappers of a device or extension loader that return per-command error
+/// types instead of `vk::Result`.
+pub trait TypedErrors {
+    fn typed(&self) -> Typed<'_, Self>;
+}
+
+impl<D: DeviceV1_0> TypedErrors for D {
+    fn typed(&self) -> Typed<'_, Self> {
+        Typed(self)
+    }
+}
+
+impl TypedErrors for Swapchain {
+    fn typed(&self) -> Typed<'_, Self> {
+        Typed(self)
+    }
+}
+
+/// The wrappers of `T` with per-command error types, returned by `TypedErrors::typed`. They
+/// call the wrappers of `T` and convert the error codes.
+#[derive(Clone, Copy, Debug)]
+pub struct Typed<'a, T: 'a + ?Sized>(&'a T);
+
+impl<'a, D: DeviceV1_0 + ?Sized> Typed<'a, D> {
+    pub unsafe fn create_event(
+        &self,
+        create_info: &vk::EventCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::Event, CreateEventError> {
+        self.0
+            .create_event(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn get_event_status(
+        &self,
+        event: vk::Event,
+    ) -> Result<EventStatus, GetEventStatusError> {
+        self.0.get_event_status(event).map_err(Into::into)
+    }
+
+    pub unsafe fn set_event(&self, event: vk::Event) -> Result<(), SetEventError> {
+        self.0.set_event(event).map_err(Into::into)
+    }
+
+    pub unsafe fn reset_event(&self, event: vk::Event) -> Result<(), ResetEventError> {
+        self.0.reset_event(event).map_err(Into::into)
+    }
+
+    pub unsafe fn create_sampler(
+        &self,
+        create_info: &vk::SamplerCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::Sampler, CreateSamplerError> {
+        self.0
+            .create_sampler(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn allocate_descriptor_sets(
+        &self,
+        create_info: &vk::DescriptorSetAllocateInfo,
+    ) -> Result<Vec<vk::DescriptorSet>, AllocateDescriptorSetsError> {
+        self.0
+            .allocate_descriptor_sets(create_info)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_descriptor_set_layout(
+        &self,
+        create_info: &vk::DescriptorSetLayoutCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::DescriptorSetLayout, CreateDescriptorSetLayoutError> {
+        self.0
+            .create_descriptor_set_layout(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn device_wait_idle(&self) -> Result<(), DeviceWaitIdleError> {
+        self.0.device_wait_idle().map_err(Into::into)
+    }
+
+    pub unsafe fn create_descriptor_pool(
+        &self,
+        create_info: &vk::DescriptorPoolCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::DescriptorPool, CreateDescriptorPoolError> {
+        self.0
+            .create_descriptor_pool(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn reset_command_pool(
+        &self,
+        command_pool: vk::CommandPool,
+        flags: vk::CommandPoolResetFlags,
+    ) -> Result<(), ResetCommandPoolError> {
+        self.0
+            .reset_command_pool(command_pool, flags)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn reset_command_buffer(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        flags: vk::CommandBufferResetFlags,
+    ) -> Result<(), ResetCommandBufferError> {
+        self.0
+            .reset_command_buffer(command_buffer, flags)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn reset_fences(&self, fences: &[vk::Fence]) -> Result<(), ResetFencesError> {
+        self.0.reset_fences(fences).map_err(Into::into)
+    }
+
+    pub unsafe fn get_query_pool_results<T>(
+        &self,
+        query_pool: vk::QueryPool,
+        first_query: u32,
+        query_count: u32,
+        data: &mut [T],
+        flags: vk::QueryResultFlags,
+    ) -> Result<QueryResultStatus, GetQueryPoolResultsError> {
+        self.0
+            .get_query_pool_results(query_pool, first_query, query_count, data, flags)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_semaphore(
+        &self,
+        create_info: &vk::SemaphoreCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::Semaphore, CreateSemaphoreError> {
+        self.0
+            .create_semaphore(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_graphics_pipelines(
+        &self,
+        pipeline_cache: vk::PipelineCache,
+        create_infos: &[vk::GraphicsPipelineCreateInfo],
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, CreateGraphicsPipelinesError)> {
+        self.0
+            .create_graphics_pipelines(pipeline_cache, create_infos, allocation_callbacks)
+            .map_err(|(pipelines, err)| (pipelines, err.into()))
+    }
+
+    pub unsafe fn create_compute_pipelines(
+        &self,
+        pipeline_cache: vk::PipelineCache,
+        create_infos: &[vk::ComputePipelineCreateInfo],
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, CreateComputePipelinesError)> {
+        self.0
+            .create_compute_pipelines(pipeline_cache, create_infos, allocation_callbacks)
+            .map_err(|(pipelines, err)| (pipelines, err.into()))
+    }
+
+    pub unsafe fn create_buffer(
+        &self,
+        create_info: &vk::BufferCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::Buffer, CreateBufferError> {
+        self.0
+            .create_buffer(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_pipeline_layout(
+        &self,
+        create_info: &vk::PipelineLayoutCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::PipelineLayout, CreatePipelineLayoutError> {
+        self.0
+            .create_pipeline_layout(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_pipeline_cache(
+        &self,
+        create_info: &vk::PipelineCacheCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::PipelineCache, CreatePipelineCacheError> {
+        self.0
+            .create_pipeline_cache(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn get_pipeline_cache_data(
+        &self,
+        pipeline_cache: vk::PipelineCache,
+    ) -> Result<Vec<u8>, GetPipelineCacheDataError> {
+        self.0
+            .get_pipeline_cache_data(pipeline_cache)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn map_memory(
+        &self,
+        memory: vk::DeviceMemory,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+        flags: vk::MemoryMapFlags,
+    ) -> Result<*mut c_void, MapMemoryError> {
+        self.0
+            .map_memory(memory, offset, size, flags)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn invalidate_mapped_memory_ranges(
+        &self,
+        ranges: &[vk::MappedMemoryRange],
+    ) -> Result<(), InvalidateMappedMemoryRangesError> {
+        self.0
+            .invalidate_mapped_memory_ranges(ranges)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn flush_mapped_memory_ranges(
+        &self,
+        ranges: &[vk::MappedMemoryRange],
+    ) -> Result<(), FlushMappedMemoryRangesError> {
+        self.0
+            .flush_mapped_memory_ranges(ranges)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_framebuffer(
+        &self,
+        create_info: &vk::FramebufferCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::Framebuffer, CreateFramebufferError> {
+        self.0
+            .create_framebuffer(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_render_pass(
+        &self,
+        create_info: &vk::RenderPassCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::RenderPass, CreateRenderPassError> {
+        self.0
+            .create_render_pass(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn begin_command_buffer(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        begin_info: &vk::CommandBufferBeginInfo,
+    ) -> Result<(), BeginCommandBufferError> {
+        self.0
+            .begin_command_buffer(command_buffer, begin_info)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn end_command_buffer(
+        &self,
+        command_buffer: vk::CommandBuffer,
+    ) -> Result<(), EndCommandBufferError> {
+        self.0
+            .end_command_buffer(command_buffer)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn wait_for_fences(
+        &self,
+        fences: &[vk::Fence],
+        wait_all: bool,
+        timeout: u64,
+    ) -> Result<WaitResult, WaitForFencesError> {
+        self.0
+            .wait_for_fences(fences, wait_all, timeout)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn get_fence_status(
+        &self,
+        fence: vk::Fence,
+    ) -> Result<FenceStatus, GetFenceStatusError> {
+        self.0.get_fence_status(fence).map_err(Into::into)
+    }
+
+    pub unsafe fn queue_wait_idle(&self, queue: vk::Queue) -> Result<(), QueueWaitIdleError> {
+        self.0.queue_wait_idle(queue).map_err(Into::into)
+    }
+
+    pub unsafe fn queue_submit(
+        &self,
+        queue: vk::Queue,
+        submits: &[vk::SubmitInfo],
+        fence: vk::Fence,
+    ) -> Result<(), QueueSubmitError> {
+        self.0
+            .queue_submit(queue, submits, fence)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_buffer_view(
+        &self,
+        create_info: &vk::BufferViewCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::BufferView, CreateBufferViewError> {
+        self.0
+            .create_buffer_view(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_image_view(
+        &self,
+        create_info: &vk::ImageViewCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::ImageView, CreateImageViewError> {
+        self.0
+            .create_image_view(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn allocate_command_buffers(
+        &self,
+        create_info: &vk::CommandBufferAllocateInfo,
+    ) -> Result<Vec<vk::CommandBuffer>, AllocateCommandBuffersError> {
+        self.0
+            .allocate_command_buffers(create_info)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_command_pool(
+        &self,
+        create_info: &vk::CommandPoolCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::CommandPool, CreateCommandPoolError> {
+        self.0
+            .create_command_pool(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_query_pool(
+        &self,
+        create_info: &vk::QueryPoolCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::QueryPool, CreateQueryPoolError> {
+        self.0
+            .create_query_pool(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_image(
+        &self,
+        create_info: &vk::ImageCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::Image, CreateImageError> {
+        self.0
+            .create_image(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn allocate_memory(
+        &self,
+        create_info: &vk::MemoryAllocateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::DeviceMemory, AllocateMemoryError> {
+        self.0
+            .allocate_memory(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_shader_module(
+        &self,
+        create_info: &vk::ShaderModuleCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::ShaderModule, CreateShaderModuleError> {
+        self.0
+            .create_shader_module(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_fence(
+        &self,
+        create_info: &vk::FenceCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::Fence, CreateFenceError> {
+        self.0
+            .create_fence(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn bind_buffer_memory(
+        &self,
+        buffer: vk::Buffer,
+        device_memory: vk::DeviceMemory,
+        offset: vk::DeviceSize,
+    ) -> Result<(), BindBufferMemoryError> {
+        self.0
+            .bind_buffer_memory(buffer, device_memory, offset)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn bind_image_memory(
+        &self,
+        image: vk::Image,
+        device_memory: vk::DeviceMemory,
+        offset: vk::DeviceSize,
+    ) -> Result<(), BindImageMemoryError> {
+        self.0
+            .bind_image_memory(image, device_memory, offset)
+            .map_err(Into::into)
+    }
+}
+
+impl<'a> Typed<'a, Swapchain> {
+    pub unsafe fn acquire_next_image(
+        &self,
+        swapchain: vk::SwapchainKHR,
+        timeout: u64,
+        semaphore: vk::Semaphore,
+        fence: vk::Fence,
+    ) -> Result<AcquireResult, AcquireNextImageError> {
+        self.0
+            .acquire_next_image(swapchain, timeout, semaphore, fence)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn create_swapchain(
+        &self,
+        create_info: &vk::SwapchainCreateInfoKHR,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::SwapchainKHR, CreateSwapchainError> {
+        self.0
+            .create_swapchain(create_info, allocation_callbacks)
+            .map_err(Into::into)
+    }
+
+    pub unsafe fn queue_present(
+        &self,
+        queue: vk::Queue,
+        create_info: &vk::PresentInfoKHR,
+    ) -> Result<PresentResult, QueuePresentError> {
+        self.0.queue_present(queue, create_info).map_err(Into::into)
+    }
+
+    pub unsafe fn get_swapchain_images(
+        &self,
+        swapchain: vk::SwapchainKHR,
+    ) -> Result<Vec<vk::Image>, GetSwapchainImagesError> {
+        self.0.get_swapchain_images(swapchain).map_err(Into::into)
+    }
+}
@@ -29,6 +29,10 @@ impl DebugMarker {
         device: vk::Device,
         name_info: &vk::DebugMarkerObjectNameInfoEXT,
     ) -> VkResult<()> {
+        validate!("vkDebugMarkerSetObjectNameEXT", |v| {
+            v.handle("device", device);
+            v.structure(name_info);
+        });
         let err_code = self
             .debug_marker_fn
             .debug_marker_set_object_name_ext(device, name_info);
@@ -43,11 +47,18 @@ impl DebugMarker {
         command_buffer: vk::CommandBuffer,
         marker_info: &vk::DebugMarkerMarkerInfoEXT,
     ) {
+        validate!("vkCmdDebugMarkerBeginEXT", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+            v.structure(marker_info);
+        });
         self.debug_marker_fn
             .cmd_debug_marker_begin_ext(command_buffer, marker_info);
     }
 
     pub unsafe fn cmd_debug_marker_end(&self, command_buffer: vk::CommandBuffer) {
+        validate!("vkCmdDebugMarkerEndEXT", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+        });
         self.debug_marker_fn
             .cmd_debug_marker_end_ext(command_buffer);
     }
@@ -57,6 +68,10 @@ impl DebugMarker {
         command_buffer: vk::CommandBuffer,
         marker_info: &vk::DebugMarkerMarkerInfoEXT,
     ) {
+        validate!("vkCmdDebugMarkerInsertEXT", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+            v.structure(marker_info);
+        });
         self.debug_marker_fn
             .cmd_debug_marker_insert_ext(command_buffer, marker_info);
     }
@@ -44,6 +44,9 @@ impl DebugReport {
         create_info: &vk::DebugReportCallbackCreateInfoEXT,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DebugReportCallbackEXT> {
+        validate!("vkCreateDebugReportCallbackEXT", |v| {
+            v.structure(create_info);
+        });
         let mut debug_cb = mem::uninitialized();
         let err_code = self.debug_report_fn.create_debug_report_callback_ext(
             self.handle,
@@ -31,6 +31,10 @@ impl DebugUtils {
         device: vk::Device,
         name_info: &vk::DebugUtilsObjectNameInfoEXT,
     ) -> VkResult<()> {
+        validate!("vkSetDebugUtilsObjectNameEXT", |v| {
+            v.handle("device", device);
+            v.structure(name_info);
+        });
         let err_code = self
             .debug_utils_fn
             .set_debug_utils_object_name_ext(device, name_info);
@@ -45,6 +49,10 @@ impl DebugUtils {
         device: vk::Device,
         tag_info: &vk::DebugUtilsObjectTagInfoEXT,
     ) -> VkResult<()> {
+        validate!("vkSetDebugUtilsObjectTagEXT", |v| {
+            v.handle("device", device);
+            v.structure(tag_info);
+        });
         let err_code = self
             .debug_utils_fn
             .set_debug_utils_object_tag_ext(device, tag_info);
@@ -59,11 +67,18 @@ impl DebugUtils {
         command_buffer: vk::CommandBuffer,
         label: &vk::DebugUtilsLabelEXT,
     ) {
+        validate!("vkCmdBeginDebugUtilsLabelEXT", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+            v.structure(label);
+        });
         self.debug_utils_fn
             .cmd_begin_debug_utils_label_ext(command_buffer, label);
     }
 
     pub unsafe fn cmd_end_debug_utils_label(&self, command_buffer: vk::CommandBuffer) {
+        validate!("vkCmdEndDebugUtilsLabelEXT", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+        });
         self.debug_utils_fn
             .cmd_end_debug_utils_label_ext(command_buffer);
     }
@@ -73,6 +88,10 @@ impl DebugUtils {
         command_buffer: vk::CommandBuffer,
         label: &vk::DebugUtilsLabelEXT,
     ) {
+        validate!("vkCmdInsertDebugUtilsLabelEXT", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+            v.structure(label);
+        });
         self.debug_utils_fn
             .cmd_insert_debug_utils_label_ext(command_buffer, label);
     }
@@ -82,11 +101,18 @@ impl DebugUtils {
         queue: vk::Queue,
         label: &vk::DebugUtilsLabelEXT,
     ) {
+        validate!("vkQueueBeginDebugUtilsLabelEXT", |v| {
+            v.dispatchable("queue", queue, vk::Device::null());
+            v.structure(label);
+        });
         self.debug_utils_fn
             .queue_begin_debug_utils_label_ext(queue, label);
     }
 
     pub unsafe fn queue_end_debug_utils_label(&self, queue: vk::Queue) {
+        validate!("vkQueueEndDebugUtilsLabelEXT", |v| {
+            v.dispatchable("queue", queue, vk::Device::null());
+        });
         self.debug_utils_fn.queue_end_debug_utils_label_ext(queue);
     }
 
@@ -95,6 +121,10 @@ impl DebugUtils {
         queue: vk::Queue,
         label: &vk::DebugUtilsLabelEXT,
     ) {
+        validate!("vkQueueInsertDebugUtilsLabelEXT", |v| {
+            v.dispatchable("queue", queue, vk::Device::null());
+            v.structure(label);
+        });
         self.debug_utils_fn
             .queue_insert_debug_utils_label_ext(queue, label);
     }
@@ -104,6 +134,9 @@ impl DebugUtils {
         create_info: &vk::DebugUtilsMessengerCreateInfoEXT,
         allocator: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::DebugUtilsMessengerEXT> {
+        validate!("vkCreateDebugUtilsMessengerEXT", |v| {
+            v.structure(create_info);
+        });
         let mut messenger = mem::uninitialized();
         let err_code = self.debug_utils_fn.create_debug_utils_messenger_ext(
             self.handle,
@@ -136,6 +169,10 @@ impl DebugUtils {
         message_types: vk::DebugUtilsMessageTypeFlagsEXT,
         callback_data: &vk::DebugUtilsMessengerCallbackDataEXT,
     ) {
+        validate!("vkSubmitDebugUtilsMessageEXT", |v| {
+            v.handle("instance", instance);
+            v.structure(callback_data);
+        });
         self.debug_utils_fn.submit_debug_utils_message_ext(
             instance,
             message_severity,
@@ -32,6 +32,9 @@ impl AndroidSurface {
         create_info: &vk::AndroidSurfaceCreateInfoKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
+        validate!("vkCreateAndroidSurfaceKHR", |v| {
+            v.structure(create_info);
+        });
         let mut surface = mem::uninitialized();
         let err_code = self.android_surface_fn.create_android_surface_khr(
             self.handle,
@@ -32,6 +32,9 @@ impl DisplaySwapchain {
         create_infos: &[vk::SwapchainCreateInfoKHR],
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<Vec<vk::SwapchainKHR>> {
+        validate!("vkCreateSharedSwapchainsKHR", |v| {
+            v.slice(create_infos);
+        });
         let mut swapchains = Vec::with_capacity(create_infos.len());
         let err_code = self.swapchain_fn.create_shared_swapchains_khr(
             self.handle,
@@ -2,7 +2,6 @@
 use prelude::*;
 use std::ffi::CStr;
 use std::mem;
-use std::ptr;
 use version::{EntryV1_0, InstanceV1_0};
 use vk;
 use RawPtr;
@@ -34,6 +33,10 @@ impl Surface {
         queue_index: u32,
         surface: vk::SurfaceKHR,
     ) -> bool {
+        validate!("vkGetPhysicalDeviceSurfaceSupportKHR", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle);
+            v.handle("surface", surface);
+        });
         let mut b = mem::uninitialized();
         self.surface_fn.get_physical_device_surface_support_khr(
             physical_device,
@@ -49,28 +52,19 @@ impl Surface {
         physical_device: vk::PhysicalDevice,
         surface: vk::SurfaceKHR,
     ) -> VkResult<Vec<vk::PresentModeKHR>> {
-        let mut count = 0;
-        self.surface_fn
-            .get_physical_device_surface_present_modes_khr(
-                physical_device,
-                surface,
-                &mut count,
-                ptr::null_mut(),
-            );
-        let mut v = Vec::with_capacity(count as usize);
-        let err_code = self
-            .surface_fn
-            .get_physical_device_surface_present_modes_khr(
-                physical_device,
-                surface,
-                &mut count,
-                v.as_mut_ptr(),
-            );
-        v.set_len(count as usize);
-        match err_code {
-            vk::Result::SUCCESS => Ok(v),
-            _ => Err(err_code),
-        }
+        validate!("vkGetPhysicalDeviceSurfacePresentModesKHR", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle);
+            v.handle("surface", surface);
+        });
+        read_into_vec(|count, data| {
+            self.surface_fn
+                .get_physical_device_surface_present_modes_khr(
+                    physical_device,
+                    surface,
+                    count,
+                    data,
+                )
+        })
     }
 
     pub unsafe fn get_physical_device_surface_capabilities(
@@ -78,6 +72,10 @@ impl Surface {
         physical_device: vk::PhysicalDevice,
         surface: vk::SurfaceKHR,
     ) -> VkResult<vk::SurfaceCapabilitiesKHR> {
+        validate!("vkGetPhysicalDeviceSurfaceCapabilitiesKHR", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle);
+            v.handle("surface", surface);
+        });
         let mut surface_capabilities = mem::uninitialized();
         let err_code = self
             .surface_fn
@@ -97,25 +95,18 @@ impl Surface {
         physical_device: vk::PhysicalDevice,
         surface: vk::SurfaceKHR,
     ) -> VkResult<Vec<vk::SurfaceFormatKHR>> {
-        let mut count = 0;
-        self.surface_fn.get_physical_device_surface_formats_khr(
-            physical_device,
-            surface,
-            &mut count,
-            ptr::null_mut(),
-        );
-        let mut v = Vec::with_capacity(count as usize);
-        let err_code = self.surface_fn.get_physical_device_surface_formats_khr(
-            physical_device,
-            surface,
-            &mut count,
-            v.as_mut_ptr(),
-        );
-        v.set_len(count as usize);
-        match err_code {
-            vk::Result::SUCCESS => Ok(v),
-            _ => Err(err_code),
-        }
+        validate!("vkGetPhysicalDeviceSurfaceFormatsKHR", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle);
+            v.handle("surface", surface);
+        });
+        read_into_vec(|count, data| {
+            self.surface_fn.get_physical_device_surface_formats_khr(
+                physical_device,
+                surface,
+                count,
+                data,
+            )
+        })
     }
 
     pub unsafe fn destroy_surface(
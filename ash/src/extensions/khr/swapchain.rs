@@ -2,7 +2,6 @@
 use prelude::*;
 use std::ffi::CStr;
 use std::mem;
-use std::ptr;
 use version::{DeviceV1_0, InstanceV1_0};
 use vk;
 use RawPtr;
@@ -40,14 +39,18 @@ impl Swapchain {
         );
     }
 
-    /// On success, returns the next image's index and whether the swapchain is suboptimal for the surface.
+    /// On success, returns the next image's index and whether the swapchain is suboptimal for the
+    /// surface, or that no image was available before the timeout.
     pub unsafe fn acquire_next_image(
         &self,
         swapchain: vk::SwapchainKHR,
         timeout: u64,
         semaphore: vk::Semaphore,
         fence: vk::Fence,
-    ) -> VkResult<(u32, bool)> {
+    ) -> VkResult<AcquireResult> {
+        validate!("vkAcquireNextImageKHR", |v| {
+            v.handle("swapchain", swapchain);
+        });
         let mut index = mem::uninitialized();
         let err_code = self.swapchain_fn.acquire_next_image_khr(
             self.handle,
@@ -58,8 +61,10 @@ impl Swapchain {
             &mut index,
         );
         match err_code {
-            vk::Result::SUCCESS => Ok((index, false)),
-            vk::Result::SUBOPTIMAL_KHR => Ok((index, true)),
+            vk::Result::SUCCESS => Ok(AcquireResult::Success(index)),
+            vk::Result::SUBOPTIMAL_KHR => Ok(AcquireResult::Suboptimal(index)),
+            vk::Result::TIMEOUT => Ok(AcquireResult::TimedOut),
+            vk::Result::NOT_READY => Ok(AcquireResult::NotReady),
             _ => Err(err_code),
         }
     }
@@ -69,6 +74,10 @@ impl Swapchain {
         create_info: &vk::SwapchainCreateInfoKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SwapchainKHR> {
+        validate!("vkCreateSwapchainKHR", |v| {
+            v.structure(create_info);
+            v.handle("create_info.surface", create_info.surface);
+        });
         let mut swapchain = mem::uninitialized();
         let err_code = self.swapchain_fn.create_swapchain_khr(
             self.handle,
@@ -87,11 +96,15 @@ impl Swapchain {
         &self,
         queue: vk::Queue,
         create_info: &vk::PresentInfoKHR,
-    ) -> VkResult<bool> {
+    ) -> VkResult<PresentResult> {
+        validate!("vkQueuePresentKHR", |v| {
+            v.dispatchable("queue", queue, self.handle);
+            v.structure(create_info);
+        });
         let err_code = self.swapchain_fn.queue_present_khr(queue, create_info);
         match err_code {
-            vk::Result::SUCCESS => Ok(false),
-            vk::Result::SUBOPTIMAL_KHR => Ok(true),
+            vk::Result::SUCCESS => Ok(PresentResult::Success),
+            vk::Result::SUBOPTIMAL_KHR => Ok(PresentResult::Suboptimal),
             _ => Err(err_code),
         }
     }
@@ -100,25 +113,12 @@ impl Swapchain {
         &self,
         swapchain: vk::SwapchainKHR,
     ) -> VkResult<Vec<vk::Image>> {
-        let mut count = 0;
-        self.swapchain_fn.get_swapchain_images_khr(
-            self.handle,
-            swapchain,
-            &mut count,
-            ptr::null_mut(),
-        );
-
-        let mut v = Vec::with_capacity(count as usize);
-        let err_code = self.swapchain_fn.get_swapchain_images_khr(
-            self.handle,
-            swapchain,
-            &mut count,
-            v.as_mut_ptr(),
-        );
-        v.set_len(count as usize);
-        match err_code {
-            vk::Result::SUCCESS => Ok(v),
-            _ => Err(err_code),
-        }
+        validate!("vkGetSwapchainImagesKHR", |v| {
+            v.handle("swapchain", swapchain);
+        });
+        read_into_vec(|count, data| {
+            self.swapchain_fn
+                .get_swapchain_images_khr(self.handle, swapchain, count, data)
+        })
     }
 }
@@ -32,6 +32,9 @@ impl WaylandSurface {
         create_info: &vk::WaylandSurfaceCreateInfoKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
+        validate!("vkCreateWaylandSurfaceKHR", |v| {
+            v.structure(create_info);
+        });
         let mut surface = mem::uninitialized();
         let err_code = self.wayland_surface_fn.create_wayland_surface_khr(
             self.handle,
@@ -32,6 +32,9 @@ impl Win32Surface {
         create_info: &vk::Win32SurfaceCreateInfoKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
+        validate!("vkCreateWin32SurfaceKHR", |v| {
+            v.structure(create_info);
+        });
         let mut surface = mem::uninitialized();
         let err_code = self.win32_surface_fn.create_win32_surface_khr(
             self.handle,
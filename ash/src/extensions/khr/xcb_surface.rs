@@ -32,6 +32,9 @@ impl XcbSurface {
         create_info: &vk::XcbSurfaceCreateInfoKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
+        validate!("vkCreateXcbSurfaceKHR", |v| {
+            v.structure(create_info);
+        });
         let mut surface = mem::uninitialized();
         let err_code = self.xcb_surface_fn.create_xcb_surface_khr(
             self.handle,
@@ -32,6 +32,9 @@ impl XlibSurface {
         create_info: &vk::XlibSurfaceCreateInfoKHR,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
+        validate!("vkCreateXlibSurfaceKHR", |v| {
+            v.structure(create_info);
+        });
         let mut surface = mem::uninitialized();
         let err_code = self.xlib_surface_fn.create_xlib_surface_khr(
             self.handle,
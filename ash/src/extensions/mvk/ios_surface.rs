@@ -32,6 +32,9 @@ impl IOSSurface {
         create_info: &vk::IOSSurfaceCreateInfoMVK,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
+        validate!("vkCreateIOSSurfaceMVK", |v| {
+            v.structure(create_info);
+        });
         let mut surface = mem::uninitialized();
         let err_code = self.ios_surface_fn.create_ios_surface_mvk(
             self.handle,
@@ -32,6 +32,9 @@ impl MacOSSurface {
         create_info: &vk::MacOSSurfaceCreateInfoMVK,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<vk::SurfaceKHR> {
+        validate!("vkCreateMacOSSurfaceMVK", |v| {
+            v.structure(create_info);
+        });
         let mut surface = mem::uninitialized();
         let err_code = self.macos_surface_fn.create_mac_os_surface_mvk(
             self.handle,
@@ -22,6 +22,9 @@ impl MeshShader {
         task_count: u32,
         first_task: u32,
     ) {
+        validate!("vkCmdDrawMeshTasksNV", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+        });
         self.mesh_shader_fn
             .cmd_draw_mesh_tasks_nv(command_buffer, task_count, first_task);
     }
@@ -33,6 +36,10 @@ impl MeshShader {
         draw_count: u32,
         stride: u32,
     ) {
+        validate!("vkCmdDrawMeshTasksIndirectNV", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+            v.handle("buffer", buffer);
+        });
         self.mesh_shader_fn.cmd_draw_mesh_tasks_indirect_nv(
             command_buffer,
             buffer,
@@ -51,6 +58,11 @@ impl MeshShader {
         max_draw_count: u32,
         stride: u32,
     ) {
+        validate!("vkCmdDrawMeshTasksIndirectCountNV", |v| {
+            v.dispatchable("command_buffer", command_buffer, vk::Device::null());
+            v.handle("buffer", buffer);
+            v.handle("count_buffer", count_buffer);
+        });
         self.mesh_shader_fn.cmd_draw_mesh_tasks_indirect_count_nv(
             command_buffer,
             buffer,
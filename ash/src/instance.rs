@@ -38,6 +38,10 @@ impl InstanceV1_0 for Instance {
         create_info: &vk::DeviceCreateInfo,
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Self::Device, vk::Result> {
+        validate!("vkCreateDevice", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(create_info);
+        });
         let mut device: vk::Device = mem::uninitialized();
         let err_code = self.fp_v1_0().create_device(
             physical_device,
@@ -82,7 +86,7 @@ pub trait InstanceV1_1: InstanceV1_0 {
     fn enumerate_physical_device_groups(
         &self,
         out: &mut [vk::PhysicalDeviceGroupProperties],
-    ) -> VkResult<()> {
+    ) -> VkResult<EnumerateResult> {
         unsafe {
             let mut group_count = out.len() as u32;
             let err_code = self.fp_v1_1().enumerate_physical_device_groups(
@@ -90,10 +94,10 @@ pub trait InstanceV1_1: InstanceV1_0 {
                 &mut group_count,
                 out.as_mut_ptr(),
             );
-            if err_code == vk::Result::SUCCESS {
-                Ok(())
-            } else {
-                Err(err_code)
+            match err_code {
+                vk::Result::SUCCESS => Ok(EnumerateResult::Complete),
+                vk::Result::INCOMPLETE => Ok(EnumerateResult::Incomplete),
+                _ => Err(err_code),
             }
         }
     }
@@ -103,6 +107,10 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         prop: &mut vk::PhysicalDeviceProperties2,
     ) {
+        validate!("vkGetPhysicalDeviceProperties2", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(prop);
+        });
         self.fp_v1_1()
             .get_physical_device_properties2(physical_device, prop);
     }
@@ -113,6 +121,10 @@ pub trait InstanceV1_1: InstanceV1_0 {
         format: vk::Format,
         out: &mut vk::FormatProperties2,
     ) {
+        validate!("vkGetPhysicalDeviceFormatProperties2", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(out);
+        });
         self.fp_v1_1()
             .get_physical_device_format_properties2(physical_device, format, out);
     }
@@ -123,6 +135,11 @@ pub trait InstanceV1_1: InstanceV1_0 {
         format_info: &vk::PhysicalDeviceImageFormatInfo2,
         image_format_prop: &mut vk::ImageFormatProperties2,
     ) -> VkResult<()> {
+        validate!("vkGetPhysicalDeviceImageFormatProperties2", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(format_info);
+            v.structure(image_format_prop);
+        });
         let err_code = self.fp_v1_1().get_physical_device_image_format_properties2(
             physical_device,
             format_info,
@@ -139,6 +156,9 @@ pub trait InstanceV1_1: InstanceV1_0 {
         &self,
         physical_device: vk::PhysicalDevice,
     ) -> usize {
+        validate!("vkGetPhysicalDeviceQueueFamilyProperties2", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+        });
         let mut queue_count = 0;
         self.fp_v1_1().get_physical_device_queue_family_properties2(
             physical_device,
@@ -153,6 +173,10 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         queue_family_props: &mut [vk::QueueFamilyProperties2],
     ) {
+        validate!("vkGetPhysicalDeviceQueueFamilyProperties2", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.slice(queue_family_props);
+        });
         let mut queue_count = queue_family_props.len() as u32;
         self.fp_v1_1().get_physical_device_queue_family_properties2(
             physical_device,
@@ -166,6 +190,10 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         out: &mut vk::PhysicalDeviceMemoryProperties2,
     ) {
+        validate!("vkGetPhysicalDeviceMemoryProperties2", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(out);
+        });
         self.fp_v1_1()
             .get_physical_device_memory_properties2(physical_device, out);
     }
@@ -175,6 +203,10 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         format_info: &vk::PhysicalDeviceSparseImageFormatInfo2,
     ) -> usize {
+        validate!("vkGetPhysicalDeviceSparseImageFormatProperties2", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(format_info);
+        });
         let mut format_count = 0;
         self.fp_v1_1()
             .get_physical_device_sparse_image_format_properties2(
@@ -192,6 +224,11 @@ pub trait InstanceV1_1: InstanceV1_0 {
         format_info: &vk::PhysicalDeviceSparseImageFormatInfo2,
         out: &mut [vk::SparseImageFormatProperties2],
     ) {
+        validate!("vkGetPhysicalDeviceSparseImageFormatProperties2", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(format_info);
+            v.slice(out);
+        });
         let mut format_count = out.len() as u32;
         self.fp_v1_1()
             .get_physical_device_sparse_image_format_properties2(
@@ -208,6 +245,11 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_buffer_info: &vk::PhysicalDeviceExternalBufferInfo,
         out: &mut vk::ExternalBufferProperties,
     ) {
+        validate!("vkGetPhysicalDeviceExternalBufferProperties", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(external_buffer_info);
+            v.structure(out);
+        });
         self.fp_v1_1()
             .get_physical_device_external_buffer_properties(
                 physical_device,
@@ -222,6 +264,11 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_fence_info: &vk::PhysicalDeviceExternalFenceInfo,
         out: &mut vk::ExternalFenceProperties,
     ) {
+        validate!("vkGetPhysicalDeviceExternalFenceProperties", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(external_fence_info);
+            v.structure(out);
+        });
         self.fp_v1_1()
             .get_physical_device_external_fence_properties(
                 physical_device,
@@ -236,6 +283,11 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_semaphore_info: &vk::PhysicalDeviceExternalSemaphoreInfo,
         out: &mut vk::ExternalSemaphoreProperties,
     ) {
+        validate!("vkGetPhysicalDeviceExternalSemaphoreProperties", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+            v.structure(external_semaphore_info);
+            v.structure(out);
+        });
         self.fp_v1_1()
             .get_physical_device_external_semaphore_properties(
                 physical_device,
@@ -275,6 +327,9 @@ pub trait InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         format: vk::Format,
     ) -> vk::FormatProperties {
+        validate!("vkGetPhysicalDeviceFormatProperties", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+        });
         let mut format_prop = mem::uninitialized();
         self.fp_v1_0().get_physical_device_format_properties(
             physical_device,
@@ -293,6 +348,9 @@ pub trait InstanceV1_0 {
         usage: vk::ImageUsageFlags,
         flags: vk::ImageCreateFlags,
     ) -> VkResult<vk::ImageFormatProperties> {
+        validate!("vkGetPhysicalDeviceImageFormatProperties", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+        });
         let mut image_format_prop = mem::uninitialized();
         let err_code = self.fp_v1_0().get_physical_device_image_format_properties(
             physical_device,
@@ -314,6 +372,9 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: vk::PhysicalDevice,
     ) -> vk::PhysicalDeviceMemoryProperties {
+        validate!("vkGetPhysicalDeviceMemoryProperties", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+        });
         let mut memory_prop = mem::uninitialized();
         self.fp_v1_0()
             .get_physical_device_memory_properties(physical_device, &mut memory_prop);
@@ -324,6 +385,9 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: vk::PhysicalDevice,
     ) -> vk::PhysicalDeviceProperties {
+        validate!("vkGetPhysicalDeviceProperties", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+        });
         let mut prop = mem::uninitialized();
         self.fp_v1_0()
             .get_physical_device_properties(physical_device, &mut prop);
@@ -334,6 +398,9 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: vk::PhysicalDevice,
     ) -> Vec<vk::QueueFamilyProperties> {
+        validate!("vkGetPhysicalDeviceQueueFamilyProperties", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+        });
         let mut queue_count = 0;
         self.fp_v1_0().get_physical_device_queue_family_properties(
             physical_device,
@@ -354,6 +421,9 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: vk::PhysicalDevice,
     ) -> vk::PhysicalDeviceFeatures {
+        validate!("vkGetPhysicalDeviceFeatures", |v| {
+            v.dispatchable("physical_device", physical_device, self.handle());
+        });
         let mut prop = mem::uninitialized();
         self.fp_v1_0()
             .get_physical_device_features(physical_device, &mut prop);
@@ -361,44 +431,22 @@ pub trait InstanceV1_0 {
     }
 
     unsafe fn enumerate_physical_devices(&self) -> VkResult<Vec<vk::PhysicalDevice>> {
-        let mut num = mem::uninitialized();
-        self.fp_v1_0()
-            .enumerate_physical_devices(self.handle(), &mut num, ptr::null_mut());
-        let mut physical_devices = Vec::<vk::PhysicalDevice>::with_capacity(num as usize);
-        let err_code = self.fp_v1_0().enumerate_physical_devices(
-            self.handle(),
-            &mut num,
-            physical_devices.as_mut_ptr(),
-        );
-        physical_devices.set_len(num as usize);
-        match err_code {
-            vk::Result::SUCCESS => Ok(physical_devices),
-            _ => Err(err_code),
-        }
+        read_into_vec(|count, data| {
+            self.fp_v1_0()
+                .enumerate_physical_devices(self.handle(), count, data)
+        })
     }
 
     unsafe fn enumerate_device_extension_properties(
         &self,
         device: vk::PhysicalDevice,
     ) -> Result<Vec<vk::ExtensionProperties>, vk::Result> {
-        let mut num = 0;
-        self.fp_v1_0().enumerate_device_extension_properties(
-            device,
-            ptr::null(),
-            &mut num,
-            ptr::null_mut(),
-        );
-        let mut data = Vec::with_capacity(num as usize);
-        let err_code = self.fp_v1_0().enumerate_device_extension_properties(
-            device,
-            ptr::null(),
-            &mut num,
-            data.as_mut_ptr(),
-        );
-        data.set_len(num as usize);
-        match err_code {
-            vk::Result::SUCCESS => Ok(data),
-            _ => Err(err_code),
-        }
+        validate!("vkEnumerateDeviceExtensionProperties", |v| {
+            v.dispatchable("device", device, self.handle());
+        });
+        read_into_vec(|count, data| {
+            self.fp_v1_0()
+                .enumerate_device_extension_properties(device, ptr::null(), count, data)
+        })
     }
 }
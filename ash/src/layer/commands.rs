@@ -0,0 +1,12081 @@
+use super::{
+    catch, device_data, instance_data, DeviceDispatch, InstanceDispatch, Layer, ShimReturn,
+};
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::*;
+use vk::*;
+pub trait Commands: Sized + Send + Sync + 'static {
+    unsafe fn destroy_instance(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0().destroy_instance(instance, p_allocator);
+    }
+    unsafe fn enumerate_physical_devices(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_physical_device_count: *mut u32,
+        p_physical_devices: *mut PhysicalDevice,
+    ) -> Result {
+        next.fp_v1_0().enumerate_physical_devices(
+            instance,
+            p_physical_device_count,
+            p_physical_devices,
+        )
+    }
+    unsafe fn get_physical_device_features(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_features: *mut PhysicalDeviceFeatures,
+    ) {
+        next.fp_v1_0()
+            .get_physical_device_features(physical_device, p_features);
+    }
+    unsafe fn get_physical_device_format_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        format: Format,
+        p_format_properties: *mut FormatProperties,
+    ) {
+        next.fp_v1_0().get_physical_device_format_properties(
+            physical_device,
+            format,
+            p_format_properties,
+        );
+    }
+    unsafe fn get_physical_device_image_format_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        format: Format,
+        ty: ImageType,
+        tiling: ImageTiling,
+        usage: ImageUsageFlags,
+        flags: ImageCreateFlags,
+        p_image_format_properties: *mut ImageFormatProperties,
+    ) -> Result {
+        next.fp_v1_0().get_physical_device_image_format_properties(
+            physical_device,
+            format,
+            ty,
+            tiling,
+            usage,
+            flags,
+            p_image_format_properties,
+        )
+    }
+    unsafe fn get_physical_device_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_properties: *mut PhysicalDeviceProperties,
+    ) {
+        next.fp_v1_0()
+            .get_physical_device_properties(physical_device, p_properties);
+    }
+    unsafe fn get_physical_device_queue_family_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_queue_family_property_count: *mut u32,
+        p_queue_family_properties: *mut QueueFamilyProperties,
+    ) {
+        next.fp_v1_0().get_physical_device_queue_family_properties(
+            physical_device,
+            p_queue_family_property_count,
+            p_queue_family_properties,
+        );
+    }
+    unsafe fn get_physical_device_memory_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_memory_properties: *mut PhysicalDeviceMemoryProperties,
+    ) {
+        next.fp_v1_0()
+            .get_physical_device_memory_properties(physical_device, p_memory_properties);
+    }
+    unsafe fn create_device(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_create_info: *const DeviceCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_device: *mut Device,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_device(physical_device, p_create_info, p_allocator, p_device)
+    }
+    unsafe fn enumerate_device_extension_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_layer_name: *const c_char,
+        p_property_count: *mut u32,
+        p_properties: *mut ExtensionProperties,
+    ) -> Result {
+        next.fp_v1_0().enumerate_device_extension_properties(
+            physical_device,
+            p_layer_name,
+            p_property_count,
+            p_properties,
+        )
+    }
+    unsafe fn enumerate_device_layer_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut LayerProperties,
+    ) -> Result {
+        next.fp_v1_0().enumerate_device_layer_properties(
+            physical_device,
+            p_property_count,
+            p_properties,
+        )
+    }
+    unsafe fn get_physical_device_sparse_image_format_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        format: Format,
+        ty: ImageType,
+        samples: SampleCountFlags,
+        usage: ImageUsageFlags,
+        tiling: ImageTiling,
+        p_property_count: *mut u32,
+        p_properties: *mut SparseImageFormatProperties,
+    ) {
+        next.fp_v1_0()
+            .get_physical_device_sparse_image_format_properties(
+                physical_device,
+                format,
+                ty,
+                samples,
+                usage,
+                tiling,
+                p_property_count,
+                p_properties,
+            );
+    }
+    unsafe fn destroy_device(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0().destroy_device(device, p_allocator);
+    }
+    unsafe fn get_device_queue(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        queue_family_index: u32,
+        queue_index: u32,
+        p_queue: *mut Queue,
+    ) {
+        next.fp_v1_0()
+            .get_device_queue(device, queue_family_index, queue_index, p_queue);
+    }
+    unsafe fn queue_submit(
+        &self,
+        next: &DeviceDispatch,
+        queue: Queue,
+        submit_count: u32,
+        p_submits: *const SubmitInfo,
+        fence: Fence,
+    ) -> Result {
+        next.fp_v1_0()
+            .queue_submit(queue, submit_count, p_submits, fence)
+    }
+    unsafe fn queue_wait_idle(&self, next: &DeviceDispatch, queue: Queue) -> Result {
+        next.fp_v1_0().queue_wait_idle(queue)
+    }
+    unsafe fn device_wait_idle(&self, next: &DeviceDispatch, device: Device) -> Result {
+        next.fp_v1_0().device_wait_idle(device)
+    }
+    unsafe fn allocate_memory(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_allocate_info: *const MemoryAllocateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_memory: *mut DeviceMemory,
+    ) -> Result {
+        next.fp_v1_0()
+            .allocate_memory(device, p_allocate_info, p_allocator, p_memory)
+    }
+    unsafe fn free_memory(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        memory: DeviceMemory,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0().free_memory(device, memory, p_allocator);
+    }
+    unsafe fn map_memory(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        memory: DeviceMemory,
+        offset: DeviceSize,
+        size: DeviceSize,
+        flags: MemoryMapFlags,
+        pp_data: *mut *mut c_void,
+    ) -> Result {
+        next.fp_v1_0()
+            .map_memory(device, memory, offset, size, flags, pp_data)
+    }
+    unsafe fn unmap_memory(&self, next: &DeviceDispatch, device: Device, memory: DeviceMemory) {
+        next.fp_v1_0().unmap_memory(device, memory);
+    }
+    unsafe fn flush_mapped_memory_ranges(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        memory_range_count: u32,
+        p_memory_ranges: *const MappedMemoryRange,
+    ) -> Result {
+        next.fp_v1_0()
+            .flush_mapped_memory_ranges(device, memory_range_count, p_memory_ranges)
+    }
+    unsafe fn invalidate_mapped_memory_ranges(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        memory_range_count: u32,
+        p_memory_ranges: *const MappedMemoryRange,
+    ) -> Result {
+        next.fp_v1_0()
+            .invalidate_mapped_memory_ranges(device, memory_range_count, p_memory_ranges)
+    }
+    unsafe fn get_device_memory_commitment(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        memory: DeviceMemory,
+        p_committed_memory_in_bytes: *mut DeviceSize,
+    ) {
+        next.fp_v1_0()
+            .get_device_memory_commitment(device, memory, p_committed_memory_in_bytes);
+    }
+    unsafe fn bind_buffer_memory(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        buffer: Buffer,
+        memory: DeviceMemory,
+        memory_offset: DeviceSize,
+    ) -> Result {
+        next.fp_v1_0()
+            .bind_buffer_memory(device, buffer, memory, memory_offset)
+    }
+    unsafe fn bind_image_memory(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        image: Image,
+        memory: DeviceMemory,
+        memory_offset: DeviceSize,
+    ) -> Result {
+        next.fp_v1_0()
+            .bind_image_memory(device, image, memory, memory_offset)
+    }
+    unsafe fn get_buffer_memory_requirements(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        buffer: Buffer,
+        p_memory_requirements: *mut MemoryRequirements,
+    ) {
+        next.fp_v1_0()
+            .get_buffer_memory_requirements(device, buffer, p_memory_requirements);
+    }
+    unsafe fn get_image_memory_requirements(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        image: Image,
+        p_memory_requirements: *mut MemoryRequirements,
+    ) {
+        next.fp_v1_0()
+            .get_image_memory_requirements(device, image, p_memory_requirements);
+    }
+    unsafe fn get_image_sparse_memory_requirements(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        image: Image,
+        p_sparse_memory_requirement_count: *mut u32,
+        p_sparse_memory_requirements: *mut SparseImageMemoryRequirements,
+    ) {
+        next.fp_v1_0().get_image_sparse_memory_requirements(
+            device,
+            image,
+            p_sparse_memory_requirement_count,
+            p_sparse_memory_requirements,
+        );
+    }
+    unsafe fn queue_bind_sparse(
+        &self,
+        next: &DeviceDispatch,
+        queue: Queue,
+        bind_info_count: u32,
+        p_bind_info: *const BindSparseInfo,
+        fence: Fence,
+    ) -> Result {
+        next.fp_v1_0()
+            .queue_bind_sparse(queue, bind_info_count, p_bind_info, fence)
+    }
+    unsafe fn create_fence(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const FenceCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_fence: *mut Fence,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_fence(device, p_create_info, p_allocator, p_fence)
+    }
+    unsafe fn destroy_fence(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        fence: Fence,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0().destroy_fence(device, fence, p_allocator);
+    }
+    unsafe fn reset_fences(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        fence_count: u32,
+        p_fences: *const Fence,
+    ) -> Result {
+        next.fp_v1_0().reset_fences(device, fence_count, p_fences)
+    }
+    unsafe fn get_fence_status(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        fence: Fence,
+    ) -> Result {
+        next.fp_v1_0().get_fence_status(device, fence)
+    }
+    unsafe fn wait_for_fences(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        fence_count: u32,
+        p_fences: *const Fence,
+        wait_all: Bool32,
+        timeout: u64,
+    ) -> Result {
+        next.fp_v1_0()
+            .wait_for_fences(device, fence_count, p_fences, wait_all, timeout)
+    }
+    unsafe fn create_semaphore(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const SemaphoreCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_semaphore: *mut Semaphore,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_semaphore(device, p_create_info, p_allocator, p_semaphore)
+    }
+    unsafe fn destroy_semaphore(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        semaphore: Semaphore,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_semaphore(device, semaphore, p_allocator);
+    }
+    unsafe fn create_event(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const EventCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_event: *mut Event,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_event(device, p_create_info, p_allocator, p_event)
+    }
+    unsafe fn destroy_event(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        event: Event,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0().destroy_event(device, event, p_allocator);
+    }
+    unsafe fn get_event_status(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        event: Event,
+    ) -> Result {
+        next.fp_v1_0().get_event_status(device, event)
+    }
+    unsafe fn set_event(&self, next: &DeviceDispatch, device: Device, event: Event) -> Result {
+        next.fp_v1_0().set_event(device, event)
+    }
+    unsafe fn reset_event(&self, next: &DeviceDispatch, device: Device, event: Event) -> Result {
+        next.fp_v1_0().reset_event(device, event)
+    }
+    unsafe fn create_query_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const QueryPoolCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_query_pool: *mut QueryPool,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_query_pool(device, p_create_info, p_allocator, p_query_pool)
+    }
+    unsafe fn destroy_query_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        query_pool: QueryPool,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_query_pool(device, query_pool, p_allocator);
+    }
+    unsafe fn get_query_pool_results(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        query_pool: QueryPool,
+        first_query: u32,
+        query_count: u32,
+        data_size: usize,
+        p_data: *mut c_void,
+        stride: DeviceSize,
+        flags: QueryResultFlags,
+    ) -> Result {
+        next.fp_v1_0().get_query_pool_results(
+            device,
+            query_pool,
+            first_query,
+            query_count,
+            data_size,
+            p_data,
+            stride,
+            flags,
+        )
+    }
+    unsafe fn create_buffer(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const BufferCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_buffer: *mut Buffer,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_buffer(device, p_create_info, p_allocator, p_buffer)
+    }
+    unsafe fn destroy_buffer(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        buffer: Buffer,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0().destroy_buffer(device, buffer, p_allocator);
+    }
+    unsafe fn create_buffer_view(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const BufferViewCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_view: *mut BufferView,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_buffer_view(device, p_create_info, p_allocator, p_view)
+    }
+    unsafe fn destroy_buffer_view(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        buffer_view: BufferView,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_buffer_view(device, buffer_view, p_allocator);
+    }
+    unsafe fn create_image(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const ImageCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_image: *mut Image,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_image(device, p_create_info, p_allocator, p_image)
+    }
+    unsafe fn destroy_image(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        image: Image,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0().destroy_image(device, image, p_allocator);
+    }
+    unsafe fn get_image_subresource_layout(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        image: Image,
+        p_subresource: *const ImageSubresource,
+        p_layout: *mut SubresourceLayout,
+    ) {
+        next.fp_v1_0()
+            .get_image_subresource_layout(device, image, p_subresource, p_layout);
+    }
+    unsafe fn create_image_view(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const ImageViewCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_view: *mut ImageView,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_image_view(device, p_create_info, p_allocator, p_view)
+    }
+    unsafe fn destroy_image_view(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        image_view: ImageView,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_image_view(device, image_view, p_allocator);
+    }
+    unsafe fn create_shader_module(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const ShaderModuleCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_shader_module: *mut ShaderModule,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_shader_module(device, p_create_info, p_allocator, p_shader_module)
+    }
+    unsafe fn destroy_shader_module(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        shader_module: ShaderModule,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_shader_module(device, shader_module, p_allocator);
+    }
+    unsafe fn create_pipeline_cache(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const PipelineCacheCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_pipeline_cache: *mut PipelineCache,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_pipeline_cache(device, p_create_info, p_allocator, p_pipeline_cache)
+    }
+    unsafe fn destroy_pipeline_cache(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline_cache: PipelineCache,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_pipeline_cache(device, pipeline_cache, p_allocator);
+    }
+    unsafe fn get_pipeline_cache_data(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline_cache: PipelineCache,
+        p_data_size: *mut usize,
+        p_data: *mut c_void,
+    ) -> Result {
+        next.fp_v1_0()
+            .get_pipeline_cache_data(device, pipeline_cache, p_data_size, p_data)
+    }
+    unsafe fn merge_pipeline_caches(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        dst_cache: PipelineCache,
+        src_cache_count: u32,
+        p_src_caches: *const PipelineCache,
+    ) -> Result {
+        next.fp_v1_0()
+            .merge_pipeline_caches(device, dst_cache, src_cache_count, p_src_caches)
+    }
+    unsafe fn create_graphics_pipelines(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline_cache: PipelineCache,
+        create_info_count: u32,
+        p_create_infos: *const GraphicsPipelineCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_pipelines: *mut Pipeline,
+    ) -> Result {
+        next.fp_v1_0().create_graphics_pipelines(
+            device,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_pipelines,
+        )
+    }
+    unsafe fn create_compute_pipelines(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline_cache: PipelineCache,
+        create_info_count: u32,
+        p_create_infos: *const ComputePipelineCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_pipelines: *mut Pipeline,
+    ) -> Result {
+        next.fp_v1_0().create_compute_pipelines(
+            device,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_pipelines,
+        )
+    }
+    unsafe fn destroy_pipeline(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline: Pipeline,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_pipeline(device, pipeline, p_allocator);
+    }
+    unsafe fn create_pipeline_layout(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const PipelineLayoutCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_pipeline_layout: *mut PipelineLayout,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_pipeline_layout(device, p_create_info, p_allocator, p_pipeline_layout)
+    }
+    unsafe fn destroy_pipeline_layout(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline_layout: PipelineLayout,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_pipeline_layout(device, pipeline_layout, p_allocator);
+    }
+    unsafe fn create_sampler(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const SamplerCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_sampler: *mut Sampler,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_sampler(device, p_create_info, p_allocator, p_sampler)
+    }
+    unsafe fn destroy_sampler(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        sampler: Sampler,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0().destroy_sampler(device, sampler, p_allocator);
+    }
+    unsafe fn create_descriptor_set_layout(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const DescriptorSetLayoutCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_set_layout: *mut DescriptorSetLayout,
+    ) -> Result {
+        next.fp_v1_0().create_descriptor_set_layout(
+            device,
+            p_create_info,
+            p_allocator,
+            p_set_layout,
+        )
+    }
+    unsafe fn destroy_descriptor_set_layout(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        descriptor_set_layout: DescriptorSetLayout,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_descriptor_set_layout(device, descriptor_set_layout, p_allocator);
+    }
+    unsafe fn create_descriptor_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const DescriptorPoolCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_descriptor_pool: *mut DescriptorPool,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_descriptor_pool(device, p_create_info, p_allocator, p_descriptor_pool)
+    }
+    unsafe fn destroy_descriptor_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        descriptor_pool: DescriptorPool,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_descriptor_pool(device, descriptor_pool, p_allocator);
+    }
+    unsafe fn reset_descriptor_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        descriptor_pool: DescriptorPool,
+        flags: DescriptorPoolResetFlags,
+    ) -> Result {
+        next.fp_v1_0()
+            .reset_descriptor_pool(device, descriptor_pool, flags)
+    }
+    unsafe fn allocate_descriptor_sets(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_allocate_info: *const DescriptorSetAllocateInfo,
+        p_descriptor_sets: *mut DescriptorSet,
+    ) -> Result {
+        next.fp_v1_0()
+            .allocate_descriptor_sets(device, p_allocate_info, p_descriptor_sets)
+    }
+    unsafe fn free_descriptor_sets(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        descriptor_pool: DescriptorPool,
+        descriptor_set_count: u32,
+        p_descriptor_sets: *const DescriptorSet,
+    ) -> Result {
+        next.fp_v1_0().free_descriptor_sets(
+            device,
+            descriptor_pool,
+            descriptor_set_count,
+            p_descriptor_sets,
+        )
+    }
+    unsafe fn update_descriptor_sets(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        descriptor_write_count: u32,
+        p_descriptor_writes: *const WriteDescriptorSet,
+        descriptor_copy_count: u32,
+        p_descriptor_copies: *const CopyDescriptorSet,
+    ) {
+        next.fp_v1_0().update_descriptor_sets(
+            device,
+            descriptor_write_count,
+            p_descriptor_writes,
+            descriptor_copy_count,
+            p_descriptor_copies,
+        );
+    }
+    unsafe fn create_framebuffer(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const FramebufferCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_framebuffer: *mut Framebuffer,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_framebuffer(device, p_create_info, p_allocator, p_framebuffer)
+    }
+    unsafe fn destroy_framebuffer(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        framebuffer: Framebuffer,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_framebuffer(device, framebuffer, p_allocator);
+    }
+    unsafe fn create_render_pass(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const RenderPassCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_render_pass: *mut RenderPass,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_render_pass(device, p_create_info, p_allocator, p_render_pass)
+    }
+    unsafe fn destroy_render_pass(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        render_pass: RenderPass,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_render_pass(device, render_pass, p_allocator);
+    }
+    unsafe fn get_render_area_granularity(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        render_pass: RenderPass,
+        p_granularity: *mut Extent2D,
+    ) {
+        next.fp_v1_0()
+            .get_render_area_granularity(device, render_pass, p_granularity);
+    }
+    unsafe fn create_command_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const CommandPoolCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_command_pool: *mut CommandPool,
+    ) -> Result {
+        next.fp_v1_0()
+            .create_command_pool(device, p_create_info, p_allocator, p_command_pool)
+    }
+    unsafe fn destroy_command_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        command_pool: CommandPool,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        next.fp_v1_0()
+            .destroy_command_pool(device, command_pool, p_allocator);
+    }
+    unsafe fn reset_command_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        command_pool: CommandPool,
+        flags: CommandPoolResetFlags,
+    ) -> Result {
+        next.fp_v1_0()
+            .reset_command_pool(device, command_pool, flags)
+    }
+    unsafe fn allocate_command_buffers(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_allocate_info: *const CommandBufferAllocateInfo,
+        p_command_buffers: *mut CommandBuffer,
+    ) -> Result {
+        next.fp_v1_0()
+            .allocate_command_buffers(device, p_allocate_info, p_command_buffers)
+    }
+    unsafe fn free_command_buffers(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        command_pool: CommandPool,
+        command_buffer_count: u32,
+        p_command_buffers: *const CommandBuffer,
+    ) {
+        next.fp_v1_0().free_command_buffers(
+            device,
+            command_pool,
+            command_buffer_count,
+            p_command_buffers,
+        );
+    }
+    unsafe fn begin_command_buffer(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_begin_info: *const CommandBufferBeginInfo,
+    ) -> Result {
+        next.fp_v1_0()
+            .begin_command_buffer(command_buffer, p_begin_info)
+    }
+    unsafe fn end_command_buffer(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+    ) -> Result {
+        next.fp_v1_0().end_command_buffer(command_buffer)
+    }
+    unsafe fn reset_command_buffer(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        flags: CommandBufferResetFlags,
+    ) -> Result {
+        next.fp_v1_0().reset_command_buffer(command_buffer, flags)
+    }
+    unsafe fn cmd_bind_pipeline(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        pipeline_bind_point: PipelineBindPoint,
+        pipeline: Pipeline,
+    ) {
+        next.fp_v1_0()
+            .cmd_bind_pipeline(command_buffer, pipeline_bind_point, pipeline);
+    }
+    unsafe fn cmd_set_viewport(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_viewport: u32,
+        viewport_count: u32,
+        p_viewports: *const Viewport,
+    ) {
+        next.fp_v1_0().cmd_set_viewport(
+            command_buffer,
+            first_viewport,
+            viewport_count,
+            p_viewports,
+        );
+    }
+    unsafe fn cmd_set_scissor(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_scissor: u32,
+        scissor_count: u32,
+        p_scissors: *const Rect2D,
+    ) {
+        next.fp_v1_0()
+            .cmd_set_scissor(command_buffer, first_scissor, scissor_count, p_scissors);
+    }
+    unsafe fn cmd_set_line_width(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        line_width: f32,
+    ) {
+        next.fp_v1_0()
+            .cmd_set_line_width(command_buffer, line_width);
+    }
+    unsafe fn cmd_set_depth_bias(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        depth_bias_constant_factor: f32,
+        depth_bias_clamp: f32,
+        depth_bias_slope_factor: f32,
+    ) {
+        next.fp_v1_0().cmd_set_depth_bias(
+            command_buffer,
+            depth_bias_constant_factor,
+            depth_bias_clamp,
+            depth_bias_slope_factor,
+        );
+    }
+    unsafe fn cmd_set_blend_constants(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        blend_constants: *const [f32; 4],
+    ) {
+        next.fp_v1_0()
+            .cmd_set_blend_constants(command_buffer, blend_constants);
+    }
+    unsafe fn cmd_set_depth_bounds(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        min_depth_bounds: f32,
+        max_depth_bounds: f32,
+    ) {
+        next.fp_v1_0()
+            .cmd_set_depth_bounds(command_buffer, min_depth_bounds, max_depth_bounds);
+    }
+    unsafe fn cmd_set_stencil_compare_mask(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        face_mask: StencilFaceFlags,
+        compare_mask: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_set_stencil_compare_mask(command_buffer, face_mask, compare_mask);
+    }
+    unsafe fn cmd_set_stencil_write_mask(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        face_mask: StencilFaceFlags,
+        write_mask: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_set_stencil_write_mask(command_buffer, face_mask, write_mask);
+    }
+    unsafe fn cmd_set_stencil_reference(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        face_mask: StencilFaceFlags,
+        reference: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_set_stencil_reference(command_buffer, face_mask, reference);
+    }
+    unsafe fn cmd_bind_descriptor_sets(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        pipeline_bind_point: PipelineBindPoint,
+        layout: PipelineLayout,
+        first_set: u32,
+        descriptor_set_count: u32,
+        p_descriptor_sets: *const DescriptorSet,
+        dynamic_offset_count: u32,
+        p_dynamic_offsets: *const u32,
+    ) {
+        next.fp_v1_0().cmd_bind_descriptor_sets(
+            command_buffer,
+            pipeline_bind_point,
+            layout,
+            first_set,
+            descriptor_set_count,
+            p_descriptor_sets,
+            dynamic_offset_count,
+            p_dynamic_offsets,
+        );
+    }
+    unsafe fn cmd_bind_index_buffer(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        index_type: IndexType,
+    ) {
+        next.fp_v1_0()
+            .cmd_bind_index_buffer(command_buffer, buffer, offset, index_type);
+    }
+    unsafe fn cmd_bind_vertex_buffers(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_binding: u32,
+        binding_count: u32,
+        p_buffers: *const Buffer,
+        p_offsets: *const DeviceSize,
+    ) {
+        next.fp_v1_0().cmd_bind_vertex_buffers(
+            command_buffer,
+            first_binding,
+            binding_count,
+            p_buffers,
+            p_offsets,
+        );
+    }
+    unsafe fn cmd_draw(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        vertex_count: u32,
+        instance_count: u32,
+        first_vertex: u32,
+        first_instance: u32,
+    ) {
+        next.fp_v1_0().cmd_draw(
+            command_buffer,
+            vertex_count,
+            instance_count,
+            first_vertex,
+            first_instance,
+        );
+    }
+    unsafe fn cmd_draw_indexed(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        index_count: u32,
+        instance_count: u32,
+        first_index: u32,
+        vertex_offset: i32,
+        first_instance: u32,
+    ) {
+        next.fp_v1_0().cmd_draw_indexed(
+            command_buffer,
+            index_count,
+            instance_count,
+            first_index,
+            vertex_offset,
+            first_instance,
+        );
+    }
+    unsafe fn cmd_draw_indirect(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_draw_indirect(command_buffer, buffer, offset, draw_count, stride);
+    }
+    unsafe fn cmd_draw_indexed_indirect(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        next.fp_v1_0().cmd_draw_indexed_indirect(
+            command_buffer,
+            buffer,
+            offset,
+            draw_count,
+            stride,
+        );
+    }
+    unsafe fn cmd_dispatch(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        group_count_x: u32,
+        group_count_y: u32,
+        group_count_z: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_dispatch(command_buffer, group_count_x, group_count_y, group_count_z);
+    }
+    unsafe fn cmd_dispatch_indirect(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+    ) {
+        next.fp_v1_0()
+            .cmd_dispatch_indirect(command_buffer, buffer, offset);
+    }
+    unsafe fn cmd_copy_buffer(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        src_buffer: Buffer,
+        dst_buffer: Buffer,
+        region_count: u32,
+        p_regions: *const BufferCopy,
+    ) {
+        next.fp_v1_0().cmd_copy_buffer(
+            command_buffer,
+            src_buffer,
+            dst_buffer,
+            region_count,
+            p_regions,
+        );
+    }
+    unsafe fn cmd_copy_image(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        src_image: Image,
+        src_image_layout: ImageLayout,
+        dst_image: Image,
+        dst_image_layout: ImageLayout,
+        region_count: u32,
+        p_regions: *const ImageCopy,
+    ) {
+        next.fp_v1_0().cmd_copy_image(
+            command_buffer,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            region_count,
+            p_regions,
+        );
+    }
+    unsafe fn cmd_blit_image(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        src_image: Image,
+        src_image_layout: ImageLayout,
+        dst_image: Image,
+        dst_image_layout: ImageLayout,
+        region_count: u32,
+        p_regions: *const ImageBlit,
+        filter: Filter,
+    ) {
+        next.fp_v1_0().cmd_blit_image(
+            command_buffer,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            region_count,
+            p_regions,
+            filter,
+        );
+    }
+    unsafe fn cmd_copy_buffer_to_image(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        src_buffer: Buffer,
+        dst_image: Image,
+        dst_image_layout: ImageLayout,
+        region_count: u32,
+        p_regions: *const BufferImageCopy,
+    ) {
+        next.fp_v1_0().cmd_copy_buffer_to_image(
+            command_buffer,
+            src_buffer,
+            dst_image,
+            dst_image_layout,
+            region_count,
+            p_regions,
+        );
+    }
+    unsafe fn cmd_copy_image_to_buffer(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        src_image: Image,
+        src_image_layout: ImageLayout,
+        dst_buffer: Buffer,
+        region_count: u32,
+        p_regions: *const BufferImageCopy,
+    ) {
+        next.fp_v1_0().cmd_copy_image_to_buffer(
+            command_buffer,
+            src_image,
+            src_image_layout,
+            dst_buffer,
+            region_count,
+            p_regions,
+        );
+    }
+    unsafe fn cmd_update_buffer(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        dst_buffer: Buffer,
+        dst_offset: DeviceSize,
+        data_size: DeviceSize,
+        p_data: *const c_void,
+    ) {
+        next.fp_v1_0()
+            .cmd_update_buffer(command_buffer, dst_buffer, dst_offset, data_size, p_data);
+    }
+    unsafe fn cmd_fill_buffer(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        dst_buffer: Buffer,
+        dst_offset: DeviceSize,
+        size: DeviceSize,
+        data: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_fill_buffer(command_buffer, dst_buffer, dst_offset, size, data);
+    }
+    unsafe fn cmd_clear_color_image(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        image: Image,
+        image_layout: ImageLayout,
+        p_color: *const ClearColorValue,
+        range_count: u32,
+        p_ranges: *const ImageSubresourceRange,
+    ) {
+        next.fp_v1_0().cmd_clear_color_image(
+            command_buffer,
+            image,
+            image_layout,
+            p_color,
+            range_count,
+            p_ranges,
+        );
+    }
+    unsafe fn cmd_clear_depth_stencil_image(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        image: Image,
+        image_layout: ImageLayout,
+        p_depth_stencil: *const ClearDepthStencilValue,
+        range_count: u32,
+        p_ranges: *const ImageSubresourceRange,
+    ) {
+        next.fp_v1_0().cmd_clear_depth_stencil_image(
+            command_buffer,
+            image,
+            image_layout,
+            p_depth_stencil,
+            range_count,
+            p_ranges,
+        );
+    }
+    unsafe fn cmd_clear_attachments(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        attachment_count: u32,
+        p_attachments: *const ClearAttachment,
+        rect_count: u32,
+        p_rects: *const ClearRect,
+    ) {
+        next.fp_v1_0().cmd_clear_attachments(
+            command_buffer,
+            attachment_count,
+            p_attachments,
+            rect_count,
+            p_rects,
+        );
+    }
+    unsafe fn cmd_resolve_image(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        src_image: Image,
+        src_image_layout: ImageLayout,
+        dst_image: Image,
+        dst_image_layout: ImageLayout,
+        region_count: u32,
+        p_regions: *const ImageResolve,
+    ) {
+        next.fp_v1_0().cmd_resolve_image(
+            command_buffer,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            region_count,
+            p_regions,
+        );
+    }
+    unsafe fn cmd_set_event(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        event: Event,
+        stage_mask: PipelineStageFlags,
+    ) {
+        next.fp_v1_0()
+            .cmd_set_event(command_buffer, event, stage_mask);
+    }
+    unsafe fn cmd_reset_event(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        event: Event,
+        stage_mask: PipelineStageFlags,
+    ) {
+        next.fp_v1_0()
+            .cmd_reset_event(command_buffer, event, stage_mask);
+    }
+    unsafe fn cmd_wait_events(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        event_count: u32,
+        p_events: *const Event,
+        src_stage_mask: PipelineStageFlags,
+        dst_stage_mask: PipelineStageFlags,
+        memory_barrier_count: u32,
+        p_memory_barriers: *const MemoryBarrier,
+        buffer_memory_barrier_count: u32,
+        p_buffer_memory_barriers: *const BufferMemoryBarrier,
+        image_memory_barrier_count: u32,
+        p_image_memory_barriers: *const ImageMemoryBarrier,
+    ) {
+        next.fp_v1_0().cmd_wait_events(
+            command_buffer,
+            event_count,
+            p_events,
+            src_stage_mask,
+            dst_stage_mask,
+            memory_barrier_count,
+            p_memory_barriers,
+            buffer_memory_barrier_count,
+            p_buffer_memory_barriers,
+            image_memory_barrier_count,
+            p_image_memory_barriers,
+        );
+    }
+    unsafe fn cmd_pipeline_barrier(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        src_stage_mask: PipelineStageFlags,
+        dst_stage_mask: PipelineStageFlags,
+        dependency_flags: DependencyFlags,
+        memory_barrier_count: u32,
+        p_memory_barriers: *const MemoryBarrier,
+        buffer_memory_barrier_count: u32,
+        p_buffer_memory_barriers: *const BufferMemoryBarrier,
+        image_memory_barrier_count: u32,
+        p_image_memory_barriers: *const ImageMemoryBarrier,
+    ) {
+        next.fp_v1_0().cmd_pipeline_barrier(
+            command_buffer,
+            src_stage_mask,
+            dst_stage_mask,
+            dependency_flags,
+            memory_barrier_count,
+            p_memory_barriers,
+            buffer_memory_barrier_count,
+            p_buffer_memory_barriers,
+            image_memory_barrier_count,
+            p_image_memory_barriers,
+        );
+    }
+    unsafe fn cmd_begin_query(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        query_pool: QueryPool,
+        query: u32,
+        flags: QueryControlFlags,
+    ) {
+        next.fp_v1_0()
+            .cmd_begin_query(command_buffer, query_pool, query, flags);
+    }
+    unsafe fn cmd_end_query(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        query_pool: QueryPool,
+        query: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_end_query(command_buffer, query_pool, query);
+    }
+    unsafe fn cmd_reset_query_pool(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        query_pool: QueryPool,
+        first_query: u32,
+        query_count: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_reset_query_pool(command_buffer, query_pool, first_query, query_count);
+    }
+    unsafe fn cmd_write_timestamp(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        pipeline_stage: PipelineStageFlags,
+        query_pool: QueryPool,
+        query: u32,
+    ) {
+        next.fp_v1_0()
+            .cmd_write_timestamp(command_buffer, pipeline_stage, query_pool, query);
+    }
+    unsafe fn cmd_copy_query_pool_results(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        query_pool: QueryPool,
+        first_query: u32,
+        query_count: u32,
+        dst_buffer: Buffer,
+        dst_offset: DeviceSize,
+        stride: DeviceSize,
+        flags: QueryResultFlags,
+    ) {
+        next.fp_v1_0().cmd_copy_query_pool_results(
+            command_buffer,
+            query_pool,
+            first_query,
+            query_count,
+            dst_buffer,
+            dst_offset,
+            stride,
+            flags,
+        );
+    }
+    unsafe fn cmd_push_constants(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        layout: PipelineLayout,
+        stage_flags: ShaderStageFlags,
+        offset: u32,
+        size: u32,
+        p_values: *const c_void,
+    ) {
+        next.fp_v1_0().cmd_push_constants(
+            command_buffer,
+            layout,
+            stage_flags,
+            offset,
+            size,
+            p_values,
+        );
+    }
+    unsafe fn cmd_begin_render_pass(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_render_pass_begin: *const RenderPassBeginInfo,
+        contents: SubpassContents,
+    ) {
+        next.fp_v1_0()
+            .cmd_begin_render_pass(command_buffer, p_render_pass_begin, contents);
+    }
+    unsafe fn cmd_next_subpass(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        contents: SubpassContents,
+    ) {
+        next.fp_v1_0().cmd_next_subpass(command_buffer, contents);
+    }
+    unsafe fn cmd_end_render_pass(&self, next: &DeviceDispatch, command_buffer: CommandBuffer) {
+        next.fp_v1_0().cmd_end_render_pass(command_buffer);
+    }
+    unsafe fn cmd_execute_commands(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        command_buffer_count: u32,
+        p_command_buffers: *const CommandBuffer,
+    ) {
+        next.fp_v1_0().cmd_execute_commands(
+            command_buffer,
+            command_buffer_count,
+            p_command_buffers,
+        );
+    }
+    unsafe fn acquire_image_android(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        image: Image,
+        native_fence_fd: c_int,
+        semaphore: Semaphore,
+        fence: Fence,
+    ) -> Result {
+        match next.fp_extensions().acquire_image_android {
+            Some(fp) => fp(device, image, native_fence_fd, semaphore, fence),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn acquire_next_image2_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_acquire_info: *const AcquireNextImageInfoKHR,
+        p_image_index: *mut u32,
+    ) -> Result {
+        match next.fp_extensions().acquire_next_image2_khr {
+            Some(fp) => fp(device, p_acquire_info, p_image_index),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn acquire_next_image_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain: SwapchainKHR,
+        timeout: u64,
+        semaphore: Semaphore,
+        fence: Fence,
+        p_image_index: *mut u32,
+    ) -> Result {
+        match next.fp_extensions().acquire_next_image_khr {
+            Some(fp) => fp(device, swapchain, timeout, semaphore, fence, p_image_index),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn acquire_xlib_display_ext(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        dpy: *mut Display,
+        display: DisplayKHR,
+    ) -> Result {
+        match next.fp_extensions().acquire_xlib_display_ext {
+            Some(fp) => fp(physical_device, dpy, display),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn bind_acceleration_structure_memory_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        bind_info_count: u32,
+        p_bind_infos: *const BindAccelerationStructureMemoryInfoNV,
+    ) -> Result {
+        match next.fp_extensions().bind_acceleration_structure_memory_nv {
+            Some(fp) => fp(device, bind_info_count, p_bind_infos),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn bind_buffer_memory2(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        bind_info_count: u32,
+        p_bind_infos: *const BindBufferMemoryInfo,
+    ) -> Result {
+        match next.fp_extensions().bind_buffer_memory2 {
+            Some(fp) => fp(device, bind_info_count, p_bind_infos),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn bind_image_memory2(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        bind_info_count: u32,
+        p_bind_infos: *const BindImageMemoryInfo,
+    ) -> Result {
+        match next.fp_extensions().bind_image_memory2 {
+            Some(fp) => fp(device, bind_info_count, p_bind_infos),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn cmd_begin_conditional_rendering_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_conditional_rendering_begin: *const ConditionalRenderingBeginInfoEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_begin_conditional_rendering_ext {
+            fp(command_buffer, p_conditional_rendering_begin);
+        }
+    }
+    unsafe fn cmd_begin_debug_utils_label_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_label_info: *const DebugUtilsLabelEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_begin_debug_utils_label_ext {
+            fp(command_buffer, p_label_info);
+        }
+    }
+    unsafe fn cmd_begin_query_indexed_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        query_pool: QueryPool,
+        query: u32,
+        flags: QueryControlFlags,
+        index: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_begin_query_indexed_ext {
+            fp(command_buffer, query_pool, query, flags, index);
+        }
+    }
+    unsafe fn cmd_begin_render_pass2_khr(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_render_pass_begin: *const RenderPassBeginInfo,
+        p_subpass_begin_info: *const SubpassBeginInfoKHR,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_begin_render_pass2_khr {
+            fp(command_buffer, p_render_pass_begin, p_subpass_begin_info);
+        }
+    }
+    unsafe fn cmd_begin_transform_feedback_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_counter_buffer: u32,
+        counter_buffer_count: u32,
+        p_counter_buffers: *const Buffer,
+        p_counter_buffer_offsets: *const DeviceSize,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_begin_transform_feedback_ext {
+            fp(
+                command_buffer,
+                first_counter_buffer,
+                counter_buffer_count,
+                p_counter_buffers,
+                p_counter_buffer_offsets,
+            );
+        }
+    }
+    unsafe fn cmd_bind_shading_rate_image_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        image_view: ImageView,
+        image_layout: ImageLayout,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_bind_shading_rate_image_nv {
+            fp(command_buffer, image_view, image_layout);
+        }
+    }
+    unsafe fn cmd_bind_transform_feedback_buffers_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_binding: u32,
+        binding_count: u32,
+        p_buffers: *const Buffer,
+        p_offsets: *const DeviceSize,
+        p_sizes: *const DeviceSize,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_bind_transform_feedback_buffers_ext {
+            fp(
+                command_buffer,
+                first_binding,
+                binding_count,
+                p_buffers,
+                p_offsets,
+                p_sizes,
+            );
+        }
+    }
+    unsafe fn cmd_build_acceleration_structure_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_info: *const AccelerationStructureInfoNV,
+        instance_data: Buffer,
+        instance_offset: DeviceSize,
+        update: Bool32,
+        dst: AccelerationStructureNV,
+        src: AccelerationStructureNV,
+        scratch: Buffer,
+        scratch_offset: DeviceSize,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_build_acceleration_structure_nv {
+            fp(
+                command_buffer,
+                p_info,
+                instance_data,
+                instance_offset,
+                update,
+                dst,
+                src,
+                scratch,
+                scratch_offset,
+            );
+        }
+    }
+    unsafe fn cmd_copy_acceleration_structure_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        dst: AccelerationStructureNV,
+        src: AccelerationStructureNV,
+        mode: CopyAccelerationStructureModeNV,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_copy_acceleration_structure_nv {
+            fp(command_buffer, dst, src, mode);
+        }
+    }
+    unsafe fn cmd_debug_marker_begin_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_marker_info: *const DebugMarkerMarkerInfoEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_debug_marker_begin_ext {
+            fp(command_buffer, p_marker_info);
+        }
+    }
+    unsafe fn cmd_debug_marker_end_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_debug_marker_end_ext {
+            fp(command_buffer);
+        }
+    }
+    unsafe fn cmd_debug_marker_insert_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_marker_info: *const DebugMarkerMarkerInfoEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_debug_marker_insert_ext {
+            fp(command_buffer, p_marker_info);
+        }
+    }
+    unsafe fn cmd_dispatch_base(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        base_group_x: u32,
+        base_group_y: u32,
+        base_group_z: u32,
+        group_count_x: u32,
+        group_count_y: u32,
+        group_count_z: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_dispatch_base {
+            fp(
+                command_buffer,
+                base_group_x,
+                base_group_y,
+                base_group_z,
+                group_count_x,
+                group_count_y,
+                group_count_z,
+            );
+        }
+    }
+    unsafe fn cmd_draw_indexed_indirect_count_amd(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        count_buffer: Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_draw_indexed_indirect_count_amd {
+            fp(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            );
+        }
+    }
+    unsafe fn cmd_draw_indexed_indirect_count_khr(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        count_buffer: Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_draw_indexed_indirect_count_khr {
+            fp(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            );
+        }
+    }
+    unsafe fn cmd_draw_indirect_byte_count_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        instance_count: u32,
+        first_instance: u32,
+        counter_buffer: Buffer,
+        counter_buffer_offset: DeviceSize,
+        counter_offset: u32,
+        vertex_stride: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_draw_indirect_byte_count_ext {
+            fp(
+                command_buffer,
+                instance_count,
+                first_instance,
+                counter_buffer,
+                counter_buffer_offset,
+                counter_offset,
+                vertex_stride,
+            );
+        }
+    }
+    unsafe fn cmd_draw_indirect_count_amd(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        count_buffer: Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_draw_indirect_count_amd {
+            fp(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            );
+        }
+    }
+    unsafe fn cmd_draw_indirect_count_khr(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        count_buffer: Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_draw_indirect_count_khr {
+            fp(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            );
+        }
+    }
+    unsafe fn cmd_draw_mesh_tasks_indirect_count_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        count_buffer: Buffer,
+        count_buffer_offset: DeviceSize,
+        max_draw_count: u32,
+        stride: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_draw_mesh_tasks_indirect_count_nv {
+            fp(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            );
+        }
+    }
+    unsafe fn cmd_draw_mesh_tasks_indirect_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        buffer: Buffer,
+        offset: DeviceSize,
+        draw_count: u32,
+        stride: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_draw_mesh_tasks_indirect_nv {
+            fp(command_buffer, buffer, offset, draw_count, stride);
+        }
+    }
+    unsafe fn cmd_draw_mesh_tasks_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        task_count: u32,
+        first_task: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_draw_mesh_tasks_nv {
+            fp(command_buffer, task_count, first_task);
+        }
+    }
+    unsafe fn cmd_end_conditional_rendering_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_end_conditional_rendering_ext {
+            fp(command_buffer);
+        }
+    }
+    unsafe fn cmd_end_debug_utils_label_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_end_debug_utils_label_ext {
+            fp(command_buffer);
+        }
+    }
+    unsafe fn cmd_end_query_indexed_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        query_pool: QueryPool,
+        query: u32,
+        index: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_end_query_indexed_ext {
+            fp(command_buffer, query_pool, query, index);
+        }
+    }
+    unsafe fn cmd_end_render_pass2_khr(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_subpass_end_info: *const SubpassEndInfoKHR,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_end_render_pass2_khr {
+            fp(command_buffer, p_subpass_end_info);
+        }
+    }
+    unsafe fn cmd_end_transform_feedback_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_counter_buffer: u32,
+        counter_buffer_count: u32,
+        p_counter_buffers: *const Buffer,
+        p_counter_buffer_offsets: *const DeviceSize,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_end_transform_feedback_ext {
+            fp(
+                command_buffer,
+                first_counter_buffer,
+                counter_buffer_count,
+                p_counter_buffers,
+                p_counter_buffer_offsets,
+            );
+        }
+    }
+    unsafe fn cmd_insert_debug_utils_label_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_label_info: *const DebugUtilsLabelEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_insert_debug_utils_label_ext {
+            fp(command_buffer, p_label_info);
+        }
+    }
+    unsafe fn cmd_next_subpass2_khr(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_subpass_begin_info: *const SubpassBeginInfoKHR,
+        p_subpass_end_info: *const SubpassEndInfoKHR,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_next_subpass2_khr {
+            fp(command_buffer, p_subpass_begin_info, p_subpass_end_info);
+        }
+    }
+    unsafe fn cmd_process_commands_nvx(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_process_commands_info: *const CmdProcessCommandsInfoNVX,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_process_commands_nvx {
+            fp(command_buffer, p_process_commands_info);
+        }
+    }
+    unsafe fn cmd_push_descriptor_set_khr(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        pipeline_bind_point: PipelineBindPoint,
+        layout: PipelineLayout,
+        set: u32,
+        descriptor_write_count: u32,
+        p_descriptor_writes: *const WriteDescriptorSet,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_push_descriptor_set_khr {
+            fp(
+                command_buffer,
+                pipeline_bind_point,
+                layout,
+                set,
+                descriptor_write_count,
+                p_descriptor_writes,
+            );
+        }
+    }
+    unsafe fn cmd_push_descriptor_set_with_template_khr(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        descriptor_update_template: DescriptorUpdateTemplate,
+        layout: PipelineLayout,
+        set: u32,
+        p_data: *const c_void,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .cmd_push_descriptor_set_with_template_khr
+        {
+            fp(
+                command_buffer,
+                descriptor_update_template,
+                layout,
+                set,
+                p_data,
+            );
+        }
+    }
+    unsafe fn cmd_reserve_space_for_commands_nvx(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_reserve_space_info: *const CmdReserveSpaceForCommandsInfoNVX,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_reserve_space_for_commands_nvx {
+            fp(command_buffer, p_reserve_space_info);
+        }
+    }
+    unsafe fn cmd_set_checkpoint_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_checkpoint_marker: *const c_void,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_set_checkpoint_nv {
+            fp(command_buffer, p_checkpoint_marker);
+        }
+    }
+    unsafe fn cmd_set_coarse_sample_order_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        sample_order_type: CoarseSampleOrderTypeNV,
+        custom_sample_order_count: u32,
+        p_custom_sample_orders: *const CoarseSampleOrderCustomNV,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_set_coarse_sample_order_nv {
+            fp(
+                command_buffer,
+                sample_order_type,
+                custom_sample_order_count,
+                p_custom_sample_orders,
+            );
+        }
+    }
+    unsafe fn cmd_set_device_mask(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        device_mask: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_set_device_mask {
+            fp(command_buffer, device_mask);
+        }
+    }
+    unsafe fn cmd_set_discard_rectangle_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_discard_rectangle: u32,
+        discard_rectangle_count: u32,
+        p_discard_rectangles: *const Rect2D,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_set_discard_rectangle_ext {
+            fp(
+                command_buffer,
+                first_discard_rectangle,
+                discard_rectangle_count,
+                p_discard_rectangles,
+            );
+        }
+    }
+    unsafe fn cmd_set_exclusive_scissor_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_exclusive_scissor: u32,
+        exclusive_scissor_count: u32,
+        p_exclusive_scissors: *const Rect2D,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_set_exclusive_scissor_nv {
+            fp(
+                command_buffer,
+                first_exclusive_scissor,
+                exclusive_scissor_count,
+                p_exclusive_scissors,
+            );
+        }
+    }
+    unsafe fn cmd_set_sample_locations_ext(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        p_sample_locations_info: *const SampleLocationsInfoEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_set_sample_locations_ext {
+            fp(command_buffer, p_sample_locations_info);
+        }
+    }
+    unsafe fn cmd_set_viewport_shading_rate_palette_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_viewport: u32,
+        viewport_count: u32,
+        p_shading_rate_palettes: *const ShadingRatePaletteNV,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .cmd_set_viewport_shading_rate_palette_nv
+        {
+            fp(
+                command_buffer,
+                first_viewport,
+                viewport_count,
+                p_shading_rate_palettes,
+            );
+        }
+    }
+    unsafe fn cmd_set_viewport_w_scaling_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        first_viewport: u32,
+        viewport_count: u32,
+        p_viewport_w_scalings: *const ViewportWScalingNV,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_set_viewport_w_scaling_nv {
+            fp(
+                command_buffer,
+                first_viewport,
+                viewport_count,
+                p_viewport_w_scalings,
+            );
+        }
+    }
+    unsafe fn cmd_trace_rays_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        raygen_shader_binding_table_buffer: Buffer,
+        raygen_shader_binding_offset: DeviceSize,
+        miss_shader_binding_table_buffer: Buffer,
+        miss_shader_binding_offset: DeviceSize,
+        miss_shader_binding_stride: DeviceSize,
+        hit_shader_binding_table_buffer: Buffer,
+        hit_shader_binding_offset: DeviceSize,
+        hit_shader_binding_stride: DeviceSize,
+        callable_shader_binding_table_buffer: Buffer,
+        callable_shader_binding_offset: DeviceSize,
+        callable_shader_binding_stride: DeviceSize,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_trace_rays_nv {
+            fp(
+                command_buffer,
+                raygen_shader_binding_table_buffer,
+                raygen_shader_binding_offset,
+                miss_shader_binding_table_buffer,
+                miss_shader_binding_offset,
+                miss_shader_binding_stride,
+                hit_shader_binding_table_buffer,
+                hit_shader_binding_offset,
+                hit_shader_binding_stride,
+                callable_shader_binding_table_buffer,
+                callable_shader_binding_offset,
+                callable_shader_binding_stride,
+                width,
+                height,
+                depth,
+            );
+        }
+    }
+    unsafe fn cmd_write_acceleration_structures_properties_nv(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        acceleration_structure_count: u32,
+        p_acceleration_structures: *const AccelerationStructureNV,
+        query_type: QueryType,
+        query_pool: QueryPool,
+        first_query: u32,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .cmd_write_acceleration_structures_properties_nv
+        {
+            fp(
+                command_buffer,
+                acceleration_structure_count,
+                p_acceleration_structures,
+                query_type,
+                query_pool,
+                first_query,
+            );
+        }
+    }
+    unsafe fn cmd_write_buffer_marker_amd(
+        &self,
+        next: &DeviceDispatch,
+        command_buffer: CommandBuffer,
+        pipeline_stage: PipelineStageFlags,
+        dst_buffer: Buffer,
+        dst_offset: DeviceSize,
+        marker: u32,
+    ) {
+        if let Some(fp) = next.fp_extensions().cmd_write_buffer_marker_amd {
+            fp(
+                command_buffer,
+                pipeline_stage,
+                dst_buffer,
+                dst_offset,
+                marker,
+            );
+        }
+    }
+    unsafe fn compile_deferred_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline: Pipeline,
+        shader: u32,
+    ) -> Result {
+        match next.fp_extensions().compile_deferred_nv {
+            Some(fp) => fp(device, pipeline, shader),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_acceleration_structure_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const AccelerationStructureCreateInfoNV,
+        p_allocator: *const AllocationCallbacks,
+        p_acceleration_structure: *mut AccelerationStructureNV,
+    ) -> Result {
+        match next.fp_extensions().create_acceleration_structure_nv {
+            Some(fp) => fp(device, p_create_info, p_allocator, p_acceleration_structure),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_android_surface_khr(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const AndroidSurfaceCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_android_surface_khr {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_debug_report_callback_ext(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const DebugReportCallbackCreateInfoEXT,
+        p_allocator: *const AllocationCallbacks,
+        p_callback: *mut DebugReportCallbackEXT,
+    ) -> Result {
+        match next.fp_extensions().create_debug_report_callback_ext {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_callback),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_debug_utils_messenger_ext(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const DebugUtilsMessengerCreateInfoEXT,
+        p_allocator: *const AllocationCallbacks,
+        p_messenger: *mut DebugUtilsMessengerEXT,
+    ) -> Result {
+        match next.fp_extensions().create_debug_utils_messenger_ext {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_messenger),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_descriptor_update_template(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const DescriptorUpdateTemplateCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_descriptor_update_template: *mut DescriptorUpdateTemplate,
+    ) -> Result {
+        match next.fp_extensions().create_descriptor_update_template {
+            Some(fp) => fp(
+                device,
+                p_create_info,
+                p_allocator,
+                p_descriptor_update_template,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_display_mode_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        display: DisplayKHR,
+        p_create_info: *const DisplayModeCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_mode: *mut DisplayModeKHR,
+    ) -> Result {
+        match next.fp_extensions().create_display_mode_khr {
+            Some(fp) => fp(physical_device, display, p_create_info, p_allocator, p_mode),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_display_plane_surface_khr(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const DisplaySurfaceCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_display_plane_surface_khr {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_ios_surface_mvk(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const IOSSurfaceCreateInfoMVK,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_ios_surface_mvk {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_image_pipe_surface_fuchsia(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const ImagePipeSurfaceCreateInfoFUCHSIA,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_image_pipe_surface_fuchsia {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_indirect_commands_layout_nvx(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const IndirectCommandsLayoutCreateInfoNVX,
+        p_allocator: *const AllocationCallbacks,
+        p_indirect_commands_layout: *mut IndirectCommandsLayoutNVX,
+    ) -> Result {
+        match next.fp_extensions().create_indirect_commands_layout_nvx {
+            Some(fp) => fp(
+                device,
+                p_create_info,
+                p_allocator,
+                p_indirect_commands_layout,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_mac_os_surface_mvk(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const MacOSSurfaceCreateInfoMVK,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_mac_os_surface_mvk {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_object_table_nvx(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const ObjectTableCreateInfoNVX,
+        p_allocator: *const AllocationCallbacks,
+        p_object_table: *mut ObjectTableNVX,
+    ) -> Result {
+        match next.fp_extensions().create_object_table_nvx {
+            Some(fp) => fp(device, p_create_info, p_allocator, p_object_table),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_ray_tracing_pipelines_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline_cache: PipelineCache,
+        create_info_count: u32,
+        p_create_infos: *const RayTracingPipelineCreateInfoNV,
+        p_allocator: *const AllocationCallbacks,
+        p_pipelines: *mut Pipeline,
+    ) -> Result {
+        match next.fp_extensions().create_ray_tracing_pipelines_nv {
+            Some(fp) => fp(
+                device,
+                pipeline_cache,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_pipelines,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_render_pass2_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const RenderPassCreateInfo2KHR,
+        p_allocator: *const AllocationCallbacks,
+        p_render_pass: *mut RenderPass,
+    ) -> Result {
+        match next.fp_extensions().create_render_pass2_khr {
+            Some(fp) => fp(device, p_create_info, p_allocator, p_render_pass),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_sampler_ycbcr_conversion(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const SamplerYcbcrConversionCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_ycbcr_conversion: *mut SamplerYcbcrConversion,
+    ) -> Result {
+        match next.fp_extensions().create_sampler_ycbcr_conversion {
+            Some(fp) => fp(device, p_create_info, p_allocator, p_ycbcr_conversion),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_shared_swapchains_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain_count: u32,
+        p_create_infos: *const SwapchainCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_swapchains: *mut SwapchainKHR,
+    ) -> Result {
+        match next.fp_extensions().create_shared_swapchains_khr {
+            Some(fp) => fp(
+                device,
+                swapchain_count,
+                p_create_infos,
+                p_allocator,
+                p_swapchains,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_swapchain_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const SwapchainCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_swapchain: *mut SwapchainKHR,
+    ) -> Result {
+        match next.fp_extensions().create_swapchain_khr {
+            Some(fp) => fp(device, p_create_info, p_allocator, p_swapchain),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_validation_cache_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const ValidationCacheCreateInfoEXT,
+        p_allocator: *const AllocationCallbacks,
+        p_validation_cache: *mut ValidationCacheEXT,
+    ) -> Result {
+        match next.fp_extensions().create_validation_cache_ext {
+            Some(fp) => fp(device, p_create_info, p_allocator, p_validation_cache),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_vi_surface_nn(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const ViSurfaceCreateInfoNN,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_vi_surface_nn {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_wayland_surface_khr(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const WaylandSurfaceCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_wayland_surface_khr {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_win32_surface_khr(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const Win32SurfaceCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_win32_surface_khr {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_xcb_surface_khr(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const XcbSurfaceCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_xcb_surface_khr {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn create_xlib_surface_khr(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_create_info: *const XlibSurfaceCreateInfoKHR,
+        p_allocator: *const AllocationCallbacks,
+        p_surface: *mut SurfaceKHR,
+    ) -> Result {
+        match next.fp_extensions().create_xlib_surface_khr {
+            Some(fp) => fp(instance, p_create_info, p_allocator, p_surface),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn debug_marker_set_object_name_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_name_info: *const DebugMarkerObjectNameInfoEXT,
+    ) -> Result {
+        match next.fp_extensions().debug_marker_set_object_name_ext {
+            Some(fp) => fp(device, p_name_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn debug_marker_set_object_tag_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_tag_info: *const DebugMarkerObjectTagInfoEXT,
+    ) -> Result {
+        match next.fp_extensions().debug_marker_set_object_tag_ext {
+            Some(fp) => fp(device, p_tag_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn debug_report_message_ext(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        flags: DebugReportFlagsEXT,
+        object_type: DebugReportObjectTypeEXT,
+        object: u64,
+        location: usize,
+        message_code: i32,
+        p_layer_prefix: *const c_char,
+        p_message: *const c_char,
+    ) {
+        if let Some(fp) = next.fp_extensions().debug_report_message_ext {
+            fp(
+                instance,
+                flags,
+                object_type,
+                object,
+                location,
+                message_code,
+                p_layer_prefix,
+                p_message,
+            );
+        }
+    }
+    unsafe fn destroy_acceleration_structure_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        acceleration_structure: AccelerationStructureNV,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_acceleration_structure_nv {
+            fp(device, acceleration_structure, p_allocator);
+        }
+    }
+    unsafe fn destroy_debug_report_callback_ext(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        callback: DebugReportCallbackEXT,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_debug_report_callback_ext {
+            fp(instance, callback, p_allocator);
+        }
+    }
+    unsafe fn destroy_debug_utils_messenger_ext(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        messenger: DebugUtilsMessengerEXT,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_debug_utils_messenger_ext {
+            fp(instance, messenger, p_allocator);
+        }
+    }
+    unsafe fn destroy_descriptor_update_template(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        descriptor_update_template: DescriptorUpdateTemplate,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_descriptor_update_template {
+            fp(device, descriptor_update_template, p_allocator);
+        }
+    }
+    unsafe fn destroy_indirect_commands_layout_nvx(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        indirect_commands_layout: IndirectCommandsLayoutNVX,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_indirect_commands_layout_nvx {
+            fp(device, indirect_commands_layout, p_allocator);
+        }
+    }
+    unsafe fn destroy_object_table_nvx(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        object_table: ObjectTableNVX,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_object_table_nvx {
+            fp(device, object_table, p_allocator);
+        }
+    }
+    unsafe fn destroy_sampler_ycbcr_conversion(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        ycbcr_conversion: SamplerYcbcrConversion,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_sampler_ycbcr_conversion {
+            fp(device, ycbcr_conversion, p_allocator);
+        }
+    }
+    unsafe fn destroy_surface_khr(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        surface: SurfaceKHR,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_surface_khr {
+            fp(instance, surface, p_allocator);
+        }
+    }
+    unsafe fn destroy_swapchain_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain: SwapchainKHR,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_swapchain_khr {
+            fp(device, swapchain, p_allocator);
+        }
+    }
+    unsafe fn destroy_validation_cache_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        validation_cache: ValidationCacheEXT,
+        p_allocator: *const AllocationCallbacks,
+    ) {
+        if let Some(fp) = next.fp_extensions().destroy_validation_cache_ext {
+            fp(device, validation_cache, p_allocator);
+        }
+    }
+    unsafe fn display_power_control_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        display: DisplayKHR,
+        p_display_power_info: *const DisplayPowerInfoEXT,
+    ) -> Result {
+        match next.fp_extensions().display_power_control_ext {
+            Some(fp) => fp(device, display, p_display_power_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn enumerate_physical_device_groups(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        p_physical_device_group_count: *mut u32,
+        p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+    ) -> Result {
+        match next.fp_extensions().enumerate_physical_device_groups {
+            Some(fp) => fp(
+                instance,
+                p_physical_device_group_count,
+                p_physical_device_group_properties,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_acceleration_structure_handle_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        acceleration_structure: AccelerationStructureNV,
+        data_size: usize,
+        p_data: *mut c_void,
+    ) -> Result {
+        match next.fp_extensions().get_acceleration_structure_handle_nv {
+            Some(fp) => fp(device, acceleration_structure, data_size, p_data),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_acceleration_structure_memory_requirements_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_info: *const AccelerationStructureMemoryRequirementsInfoNV,
+        p_memory_requirements: *mut MemoryRequirements2KHR,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .get_acceleration_structure_memory_requirements_nv
+        {
+            fp(device, p_info, p_memory_requirements);
+        }
+    }
+    unsafe fn get_android_hardware_buffer_properties_android(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        buffer: *const AHardwareBuffer,
+        p_properties: *mut AndroidHardwareBufferPropertiesANDROID,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_android_hardware_buffer_properties_android
+        {
+            Some(fp) => fp(device, buffer, p_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_buffer_memory_requirements2(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_info: *const BufferMemoryRequirementsInfo2,
+        p_memory_requirements: *mut MemoryRequirements2,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_buffer_memory_requirements2 {
+            fp(device, p_info, p_memory_requirements);
+        }
+    }
+    unsafe fn get_calibrated_timestamps_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        timestamp_count: u32,
+        p_timestamp_infos: *const CalibratedTimestampInfoEXT,
+        p_timestamps: *mut u64,
+        p_max_deviation: *mut u64,
+    ) -> Result {
+        match next.fp_extensions().get_calibrated_timestamps_ext {
+            Some(fp) => fp(
+                device,
+                timestamp_count,
+                p_timestamp_infos,
+                p_timestamps,
+                p_max_deviation,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_descriptor_set_layout_support(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_create_info: *const DescriptorSetLayoutCreateInfo,
+        p_support: *mut DescriptorSetLayoutSupport,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_descriptor_set_layout_support {
+            fp(device, p_create_info, p_support);
+        }
+    }
+    unsafe fn get_device_group_peer_memory_features(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        heap_index: u32,
+        local_device_index: u32,
+        remote_device_index: u32,
+        p_peer_memory_features: *mut PeerMemoryFeatureFlags,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_device_group_peer_memory_features {
+            fp(
+                device,
+                heap_index,
+                local_device_index,
+                remote_device_index,
+                p_peer_memory_features,
+            );
+        }
+    }
+    unsafe fn get_device_group_present_capabilities_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_device_group_present_capabilities: *mut DeviceGroupPresentCapabilitiesKHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_device_group_present_capabilities_khr
+        {
+            Some(fp) => fp(device, p_device_group_present_capabilities),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_device_group_surface_present_modes_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        surface: SurfaceKHR,
+        p_modes: *mut DeviceGroupPresentModeFlagsKHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_device_group_surface_present_modes_khr
+        {
+            Some(fp) => fp(device, surface, p_modes),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_device_queue2(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_queue_info: *const DeviceQueueInfo2,
+        p_queue: *mut Queue,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_device_queue2 {
+            fp(device, p_queue_info, p_queue);
+        }
+    }
+    unsafe fn get_display_mode_properties2_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        display: DisplayKHR,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayModeProperties2KHR,
+    ) -> Result {
+        match next.fp_extensions().get_display_mode_properties2_khr {
+            Some(fp) => fp(physical_device, display, p_property_count, p_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_display_mode_properties_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        display: DisplayKHR,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayModePropertiesKHR,
+    ) -> Result {
+        match next.fp_extensions().get_display_mode_properties_khr {
+            Some(fp) => fp(physical_device, display, p_property_count, p_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_display_plane_capabilities2_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_display_plane_info: *const DisplayPlaneInfo2KHR,
+        p_capabilities: *mut DisplayPlaneCapabilities2KHR,
+    ) -> Result {
+        match next.fp_extensions().get_display_plane_capabilities2_khr {
+            Some(fp) => fp(physical_device, p_display_plane_info, p_capabilities),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_display_plane_capabilities_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        mode: DisplayModeKHR,
+        plane_index: u32,
+        p_capabilities: *mut DisplayPlaneCapabilitiesKHR,
+    ) -> Result {
+        match next.fp_extensions().get_display_plane_capabilities_khr {
+            Some(fp) => fp(physical_device, mode, plane_index, p_capabilities),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_display_plane_supported_displays_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        plane_index: u32,
+        p_display_count: *mut u32,
+        p_displays: *mut DisplayKHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_display_plane_supported_displays_khr
+        {
+            Some(fp) => fp(physical_device, plane_index, p_display_count, p_displays),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_fence_fd_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_get_fd_info: *const FenceGetFdInfoKHR,
+        p_fd: *mut c_int,
+    ) -> Result {
+        match next.fp_extensions().get_fence_fd_khr {
+            Some(fp) => fp(device, p_get_fd_info, p_fd),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_fence_win32_handle_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_get_win32_handle_info: *const FenceGetWin32HandleInfoKHR,
+        p_handle: *mut HANDLE,
+    ) -> Result {
+        match next.fp_extensions().get_fence_win32_handle_khr {
+            Some(fp) => fp(device, p_get_win32_handle_info, p_handle),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_image_drm_format_modifier_properties_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        image: Image,
+        p_properties: *mut ImageDrmFormatModifierPropertiesEXT,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_image_drm_format_modifier_properties_ext
+        {
+            Some(fp) => fp(device, image, p_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_image_memory_requirements2(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_info: *const ImageMemoryRequirementsInfo2,
+        p_memory_requirements: *mut MemoryRequirements2,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_image_memory_requirements2 {
+            fp(device, p_info, p_memory_requirements);
+        }
+    }
+    unsafe fn get_image_sparse_memory_requirements2(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_info: *const ImageSparseMemoryRequirementsInfo2,
+        p_sparse_memory_requirement_count: *mut u32,
+        p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_image_sparse_memory_requirements2 {
+            fp(
+                device,
+                p_info,
+                p_sparse_memory_requirement_count,
+                p_sparse_memory_requirements,
+            );
+        }
+    }
+    unsafe fn get_memory_android_hardware_buffer_android(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_info: *const MemoryGetAndroidHardwareBufferInfoANDROID,
+        p_buffer: *mut *mut AHardwareBuffer,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_memory_android_hardware_buffer_android
+        {
+            Some(fp) => fp(device, p_info, p_buffer),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_memory_fd_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_get_fd_info: *const MemoryGetFdInfoKHR,
+        p_fd: *mut c_int,
+    ) -> Result {
+        match next.fp_extensions().get_memory_fd_khr {
+            Some(fp) => fp(device, p_get_fd_info, p_fd),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_memory_fd_properties_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        handle_type: ExternalMemoryHandleTypeFlags,
+        fd: c_int,
+        p_memory_fd_properties: *mut MemoryFdPropertiesKHR,
+    ) -> Result {
+        match next.fp_extensions().get_memory_fd_properties_khr {
+            Some(fp) => fp(device, handle_type, fd, p_memory_fd_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_memory_host_pointer_properties_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        handle_type: ExternalMemoryHandleTypeFlags,
+        p_host_pointer: *const c_void,
+        p_memory_host_pointer_properties: *mut MemoryHostPointerPropertiesEXT,
+    ) -> Result {
+        match next.fp_extensions().get_memory_host_pointer_properties_ext {
+            Some(fp) => fp(
+                device,
+                handle_type,
+                p_host_pointer,
+                p_memory_host_pointer_properties,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_memory_win32_handle_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_get_win32_handle_info: *const MemoryGetWin32HandleInfoKHR,
+        p_handle: *mut HANDLE,
+    ) -> Result {
+        match next.fp_extensions().get_memory_win32_handle_khr {
+            Some(fp) => fp(device, p_get_win32_handle_info, p_handle),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_memory_win32_handle_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        memory: DeviceMemory,
+        handle_type: ExternalMemoryHandleTypeFlagsNV,
+        p_handle: *mut HANDLE,
+    ) -> Result {
+        match next.fp_extensions().get_memory_win32_handle_nv {
+            Some(fp) => fp(device, memory, handle_type, p_handle),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_memory_win32_handle_properties_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        handle_type: ExternalMemoryHandleTypeFlags,
+        handle: HANDLE,
+        p_memory_win32_handle_properties: *mut MemoryWin32HandlePropertiesKHR,
+    ) -> Result {
+        match next.fp_extensions().get_memory_win32_handle_properties_khr {
+            Some(fp) => fp(
+                device,
+                handle_type,
+                handle,
+                p_memory_win32_handle_properties,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_past_presentation_timing_google(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain: SwapchainKHR,
+        p_presentation_timing_count: *mut u32,
+        p_presentation_timings: *mut PastPresentationTimingGOOGLE,
+    ) -> Result {
+        match next.fp_extensions().get_past_presentation_timing_google {
+            Some(fp) => fp(
+                device,
+                swapchain,
+                p_presentation_timing_count,
+                p_presentation_timings,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_calibrateable_time_domains_ext(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_time_domain_count: *mut u32,
+        p_time_domains: *mut TimeDomainEXT,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_calibrateable_time_domains_ext
+        {
+            Some(fp) => fp(physical_device, p_time_domain_count, p_time_domains),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_display_plane_properties2_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayPlaneProperties2KHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_display_plane_properties2_khr
+        {
+            Some(fp) => fp(physical_device, p_property_count, p_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_display_plane_properties_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayPlanePropertiesKHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_display_plane_properties_khr
+        {
+            Some(fp) => fp(physical_device, p_property_count, p_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_display_properties2_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayProperties2KHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_display_properties2_khr
+        {
+            Some(fp) => fp(physical_device, p_property_count, p_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_display_properties_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayPropertiesKHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_display_properties_khr
+        {
+            Some(fp) => fp(physical_device, p_property_count, p_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_external_buffer_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+        p_external_buffer_properties: *mut ExternalBufferProperties,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .get_physical_device_external_buffer_properties
+        {
+            fp(
+                physical_device,
+                p_external_buffer_info,
+                p_external_buffer_properties,
+            );
+        }
+    }
+    unsafe fn get_physical_device_external_fence_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+        p_external_fence_properties: *mut ExternalFenceProperties,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .get_physical_device_external_fence_properties
+        {
+            fp(
+                physical_device,
+                p_external_fence_info,
+                p_external_fence_properties,
+            );
+        }
+    }
+    unsafe fn get_physical_device_external_image_format_properties_nv(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        format: Format,
+        ty: ImageType,
+        tiling: ImageTiling,
+        usage: ImageUsageFlags,
+        flags: ImageCreateFlags,
+        external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+        p_external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_external_image_format_properties_nv
+        {
+            Some(fp) => fp(
+                physical_device,
+                format,
+                ty,
+                tiling,
+                usage,
+                flags,
+                external_handle_type,
+                p_external_image_format_properties,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_external_semaphore_properties(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+        p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .get_physical_device_external_semaphore_properties
+        {
+            fp(
+                physical_device,
+                p_external_semaphore_info,
+                p_external_semaphore_properties,
+            );
+        }
+    }
+    unsafe fn get_physical_device_features2(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_features: *mut PhysicalDeviceFeatures2,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_physical_device_features2 {
+            fp(physical_device, p_features);
+        }
+    }
+    unsafe fn get_physical_device_format_properties2(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        format: Format,
+        p_format_properties: *mut FormatProperties2,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_physical_device_format_properties2 {
+            fp(physical_device, format, p_format_properties);
+        }
+    }
+    unsafe fn get_physical_device_generated_commands_properties_nvx(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_features: *mut DeviceGeneratedCommandsFeaturesNVX,
+        p_limits: *mut DeviceGeneratedCommandsLimitsNVX,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .get_physical_device_generated_commands_properties_nvx
+        {
+            fp(physical_device, p_features, p_limits);
+        }
+    }
+    unsafe fn get_physical_device_image_format_properties2(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+        p_image_format_properties: *mut ImageFormatProperties2,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_image_format_properties2
+        {
+            Some(fp) => fp(
+                physical_device,
+                p_image_format_info,
+                p_image_format_properties,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_memory_properties2(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_physical_device_memory_properties2 {
+            fp(physical_device, p_memory_properties);
+        }
+    }
+    unsafe fn get_physical_device_multisample_properties_ext(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        samples: SampleCountFlags,
+        p_multisample_properties: *mut MultisamplePropertiesEXT,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .get_physical_device_multisample_properties_ext
+        {
+            fp(physical_device, samples, p_multisample_properties);
+        }
+    }
+    unsafe fn get_physical_device_present_rectangles_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_rect_count: *mut u32,
+        p_rects: *mut Rect2D,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_present_rectangles_khr
+        {
+            Some(fp) => fp(physical_device, surface, p_rect_count, p_rects),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_properties2(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_properties: *mut PhysicalDeviceProperties2,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_physical_device_properties2 {
+            fp(physical_device, p_properties);
+        }
+    }
+    unsafe fn get_physical_device_queue_family_properties2(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_queue_family_property_count: *mut u32,
+        p_queue_family_properties: *mut QueueFamilyProperties2,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .get_physical_device_queue_family_properties2
+        {
+            fp(
+                physical_device,
+                p_queue_family_property_count,
+                p_queue_family_properties,
+            );
+        }
+    }
+    unsafe fn get_physical_device_sparse_image_format_properties2(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+        p_property_count: *mut u32,
+        p_properties: *mut SparseImageFormatProperties2,
+    ) {
+        if let Some(fp) = next
+            .fp_extensions()
+            .get_physical_device_sparse_image_format_properties2
+        {
+            fp(
+                physical_device,
+                p_format_info,
+                p_property_count,
+                p_properties,
+            );
+        }
+    }
+    unsafe fn get_physical_device_surface_capabilities2_ext(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_surface_capabilities: *mut SurfaceCapabilities2EXT,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_surface_capabilities2_ext
+        {
+            Some(fp) => fp(physical_device, surface, p_surface_capabilities),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_surface_capabilities2_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+        p_surface_capabilities: *mut SurfaceCapabilities2KHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_surface_capabilities2_khr
+        {
+            Some(fp) => fp(physical_device, p_surface_info, p_surface_capabilities),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_surface_capabilities_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_surface_capabilities: *mut SurfaceCapabilitiesKHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_surface_capabilities_khr
+        {
+            Some(fp) => fp(physical_device, surface, p_surface_capabilities),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_surface_formats2_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+        p_surface_format_count: *mut u32,
+        p_surface_formats: *mut SurfaceFormat2KHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_surface_formats2_khr
+        {
+            Some(fp) => fp(
+                physical_device,
+                p_surface_info,
+                p_surface_format_count,
+                p_surface_formats,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_surface_formats_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_surface_format_count: *mut u32,
+        p_surface_formats: *mut SurfaceFormatKHR,
+    ) -> Result {
+        match next.fp_extensions().get_physical_device_surface_formats_khr {
+            Some(fp) => fp(
+                physical_device,
+                surface,
+                p_surface_format_count,
+                p_surface_formats,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_surface_present_modes_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_present_mode_count: *mut u32,
+        p_present_modes: *mut PresentModeKHR,
+    ) -> Result {
+        match next
+            .fp_extensions()
+            .get_physical_device_surface_present_modes_khr
+        {
+            Some(fp) => fp(
+                physical_device,
+                surface,
+                p_present_mode_count,
+                p_present_modes,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_surface_support_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+        surface: SurfaceKHR,
+        p_supported: *mut Bool32,
+    ) -> Result {
+        match next.fp_extensions().get_physical_device_surface_support_khr {
+            Some(fp) => fp(physical_device, queue_family_index, surface, p_supported),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_wayland_presentation_support_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+        display: *mut wl_display,
+    ) -> Bool32 {
+        match next
+            .fp_extensions()
+            .get_physical_device_wayland_presentation_support_khr
+        {
+            Some(fp) => fp(physical_device, queue_family_index, display),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_win32_presentation_support_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+    ) -> Bool32 {
+        match next
+            .fp_extensions()
+            .get_physical_device_win32_presentation_support_khr
+        {
+            Some(fp) => fp(physical_device, queue_family_index),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_xcb_presentation_support_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+        connection: *mut xcb_connection_t,
+        visual_id: xcb_visualid_t,
+    ) -> Bool32 {
+        match next
+            .fp_extensions()
+            .get_physical_device_xcb_presentation_support_khr
+        {
+            Some(fp) => fp(physical_device, queue_family_index, connection, visual_id),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_physical_device_xlib_presentation_support_khr(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+        dpy: *mut Display,
+        visual_id: VisualID,
+    ) -> Bool32 {
+        match next
+            .fp_extensions()
+            .get_physical_device_xlib_presentation_support_khr
+        {
+            Some(fp) => fp(physical_device, queue_family_index, dpy, visual_id),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_queue_checkpoint_data_nv(
+        &self,
+        next: &DeviceDispatch,
+        queue: Queue,
+        p_checkpoint_data_count: *mut u32,
+        p_checkpoint_data: *mut CheckpointDataNV,
+    ) {
+        if let Some(fp) = next.fp_extensions().get_queue_checkpoint_data_nv {
+            fp(queue, p_checkpoint_data_count, p_checkpoint_data);
+        }
+    }
+    unsafe fn get_rand_r_output_display_ext(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        dpy: *mut Display,
+        rr_output: RROutput,
+        p_display: *mut DisplayKHR,
+    ) -> Result {
+        match next.fp_extensions().get_rand_r_output_display_ext {
+            Some(fp) => fp(physical_device, dpy, rr_output, p_display),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_ray_tracing_shader_group_handles_nv(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline: Pipeline,
+        first_group: u32,
+        group_count: u32,
+        data_size: usize,
+        p_data: *mut c_void,
+    ) -> Result {
+        match next.fp_extensions().get_ray_tracing_shader_group_handles_nv {
+            Some(fp) => fp(
+                device,
+                pipeline,
+                first_group,
+                group_count,
+                data_size,
+                p_data,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_refresh_cycle_duration_google(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain: SwapchainKHR,
+        p_display_timing_properties: *mut RefreshCycleDurationGOOGLE,
+    ) -> Result {
+        match next.fp_extensions().get_refresh_cycle_duration_google {
+            Some(fp) => fp(device, swapchain, p_display_timing_properties),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_semaphore_fd_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_get_fd_info: *const SemaphoreGetFdInfoKHR,
+        p_fd: *mut c_int,
+    ) -> Result {
+        match next.fp_extensions().get_semaphore_fd_khr {
+            Some(fp) => fp(device, p_get_fd_info, p_fd),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_semaphore_win32_handle_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_get_win32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
+        p_handle: *mut HANDLE,
+    ) -> Result {
+        match next.fp_extensions().get_semaphore_win32_handle_khr {
+            Some(fp) => fp(device, p_get_win32_handle_info, p_handle),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_shader_info_amd(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        pipeline: Pipeline,
+        shader_stage: ShaderStageFlags,
+        info_type: ShaderInfoTypeAMD,
+        p_info_size: *mut usize,
+        p_info: *mut c_void,
+    ) -> Result {
+        match next.fp_extensions().get_shader_info_amd {
+            Some(fp) => fp(
+                device,
+                pipeline,
+                shader_stage,
+                info_type,
+                p_info_size,
+                p_info,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_swapchain_counter_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain: SwapchainKHR,
+        counter: SurfaceCounterFlagsEXT,
+        p_counter_value: *mut u64,
+    ) -> Result {
+        match next.fp_extensions().get_swapchain_counter_ext {
+            Some(fp) => fp(device, swapchain, counter, p_counter_value),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_swapchain_gralloc_usage_android(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        format: Format,
+        image_usage: ImageUsageFlags,
+        gralloc_usage: *mut c_int,
+    ) -> Result {
+        match next.fp_extensions().get_swapchain_gralloc_usage_android {
+            Some(fp) => fp(device, format, image_usage, gralloc_usage),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_swapchain_images_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain: SwapchainKHR,
+        p_swapchain_image_count: *mut u32,
+        p_swapchain_images: *mut Image,
+    ) -> Result {
+        match next.fp_extensions().get_swapchain_images_khr {
+            Some(fp) => fp(
+                device,
+                swapchain,
+                p_swapchain_image_count,
+                p_swapchain_images,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_swapchain_status_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain: SwapchainKHR,
+    ) -> Result {
+        match next.fp_extensions().get_swapchain_status_khr {
+            Some(fp) => fp(device, swapchain),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn get_validation_cache_data_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        validation_cache: ValidationCacheEXT,
+        p_data_size: *mut usize,
+        p_data: *mut c_void,
+    ) -> Result {
+        match next.fp_extensions().get_validation_cache_data_ext {
+            Some(fp) => fp(device, validation_cache, p_data_size, p_data),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn import_fence_fd_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_import_fence_fd_info: *const ImportFenceFdInfoKHR,
+    ) -> Result {
+        match next.fp_extensions().import_fence_fd_khr {
+            Some(fp) => fp(device, p_import_fence_fd_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn import_fence_win32_handle_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_import_fence_win32_handle_info: *const ImportFenceWin32HandleInfoKHR,
+    ) -> Result {
+        match next.fp_extensions().import_fence_win32_handle_khr {
+            Some(fp) => fp(device, p_import_fence_win32_handle_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn import_semaphore_fd_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_import_semaphore_fd_info: *const ImportSemaphoreFdInfoKHR,
+    ) -> Result {
+        match next.fp_extensions().import_semaphore_fd_khr {
+            Some(fp) => fp(device, p_import_semaphore_fd_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn import_semaphore_win32_handle_khr(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_import_semaphore_win32_handle_info: *const ImportSemaphoreWin32HandleInfoKHR,
+    ) -> Result {
+        match next.fp_extensions().import_semaphore_win32_handle_khr {
+            Some(fp) => fp(device, p_import_semaphore_win32_handle_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn merge_validation_caches_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        dst_cache: ValidationCacheEXT,
+        src_cache_count: u32,
+        p_src_caches: *const ValidationCacheEXT,
+    ) -> Result {
+        match next.fp_extensions().merge_validation_caches_ext {
+            Some(fp) => fp(device, dst_cache, src_cache_count, p_src_caches),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn queue_begin_debug_utils_label_ext(
+        &self,
+        next: &DeviceDispatch,
+        queue: Queue,
+        p_label_info: *const DebugUtilsLabelEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().queue_begin_debug_utils_label_ext {
+            fp(queue, p_label_info);
+        }
+    }
+    unsafe fn queue_end_debug_utils_label_ext(&self, next: &DeviceDispatch, queue: Queue) {
+        if let Some(fp) = next.fp_extensions().queue_end_debug_utils_label_ext {
+            fp(queue);
+        }
+    }
+    unsafe fn queue_insert_debug_utils_label_ext(
+        &self,
+        next: &DeviceDispatch,
+        queue: Queue,
+        p_label_info: *const DebugUtilsLabelEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().queue_insert_debug_utils_label_ext {
+            fp(queue, p_label_info);
+        }
+    }
+    unsafe fn queue_present_khr(
+        &self,
+        next: &DeviceDispatch,
+        queue: Queue,
+        p_present_info: *const PresentInfoKHR,
+    ) -> Result {
+        match next.fp_extensions().queue_present_khr {
+            Some(fp) => fp(queue, p_present_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn queue_signal_release_image_android(
+        &self,
+        next: &DeviceDispatch,
+        queue: Queue,
+        wait_semaphore_count: u32,
+        p_wait_semaphores: *const Semaphore,
+        image: Image,
+        p_native_fence_fd: *mut c_int,
+    ) -> Result {
+        match next.fp_extensions().queue_signal_release_image_android {
+            Some(fp) => fp(
+                queue,
+                wait_semaphore_count,
+                p_wait_semaphores,
+                image,
+                p_native_fence_fd,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn register_device_event_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_device_event_info: *const DeviceEventInfoEXT,
+        p_allocator: *const AllocationCallbacks,
+        p_fence: *mut Fence,
+    ) -> Result {
+        match next.fp_extensions().register_device_event_ext {
+            Some(fp) => fp(device, p_device_event_info, p_allocator, p_fence),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn register_display_event_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        display: DisplayKHR,
+        p_display_event_info: *const DisplayEventInfoEXT,
+        p_allocator: *const AllocationCallbacks,
+        p_fence: *mut Fence,
+    ) -> Result {
+        match next.fp_extensions().register_display_event_ext {
+            Some(fp) => fp(device, display, p_display_event_info, p_allocator, p_fence),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn register_objects_nvx(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        object_table: ObjectTableNVX,
+        object_count: u32,
+        pp_object_table_entries: *const *const ObjectTableEntryNVX,
+        p_object_indices: *const u32,
+    ) -> Result {
+        match next.fp_extensions().register_objects_nvx {
+            Some(fp) => fp(
+                device,
+                object_table,
+                object_count,
+                pp_object_table_entries,
+                p_object_indices,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn release_display_ext(
+        &self,
+        next: &InstanceDispatch,
+        physical_device: PhysicalDevice,
+        display: DisplayKHR,
+    ) -> Result {
+        match next.fp_extensions().release_display_ext {
+            Some(fp) => fp(physical_device, display),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn set_debug_utils_object_name_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_name_info: *const DebugUtilsObjectNameInfoEXT,
+    ) -> Result {
+        match next.fp_extensions().set_debug_utils_object_name_ext {
+            Some(fp) => fp(device, p_name_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn set_debug_utils_object_tag_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        p_tag_info: *const DebugUtilsObjectTagInfoEXT,
+    ) -> Result {
+        match next.fp_extensions().set_debug_utils_object_tag_ext {
+            Some(fp) => fp(device, p_tag_info),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn set_hdr_metadata_ext(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        swapchain_count: u32,
+        p_swapchains: *const SwapchainKHR,
+        p_metadata: *const HdrMetadataEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().set_hdr_metadata_ext {
+            fp(device, swapchain_count, p_swapchains, p_metadata);
+        }
+    }
+    unsafe fn submit_debug_utils_message_ext(
+        &self,
+        next: &InstanceDispatch,
+        instance: Instance,
+        message_severity: DebugUtilsMessageSeverityFlagsEXT,
+        message_types: DebugUtilsMessageTypeFlagsEXT,
+        p_callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+    ) {
+        if let Some(fp) = next.fp_extensions().submit_debug_utils_message_ext {
+            fp(instance, message_severity, message_types, p_callback_data);
+        }
+    }
+    unsafe fn trim_command_pool(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        command_pool: CommandPool,
+        flags: CommandPoolTrimFlags,
+    ) {
+        if let Some(fp) = next.fp_extensions().trim_command_pool {
+            fp(device, command_pool, flags);
+        }
+    }
+    unsafe fn unregister_objects_nvx(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        object_table: ObjectTableNVX,
+        object_count: u32,
+        p_object_entry_types: *const ObjectEntryTypeNVX,
+        p_object_indices: *const u32,
+    ) -> Result {
+        match next.fp_extensions().unregister_objects_nvx {
+            Some(fp) => fp(
+                device,
+                object_table,
+                object_count,
+                p_object_entry_types,
+                p_object_indices,
+            ),
+            None => ShimReturn::unforwarded(),
+        }
+    }
+    unsafe fn update_descriptor_set_with_template(
+        &self,
+        next: &DeviceDispatch,
+        device: Device,
+        descriptor_set: DescriptorSet,
+        descriptor_update_template: DescriptorUpdateTemplate,
+        p_data: *const c_void,
+    ) {
+        if let Some(fp) = next.fp_extensions().update_descriptor_set_with_template {
+            fp(device, descriptor_set, descriptor_update_template, p_data);
+        }
+    }
+}
+extern "system" fn enumerate_physical_devices<L: Layer>(
+    instance: Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut PhysicalDevice,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.enumerate_physical_devices(
+                &this.dispatch,
+                instance,
+                p_physical_device_count,
+                p_physical_devices,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_features<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_features: *mut PhysicalDeviceFeatures,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_features(&this.dispatch, physical_device, p_features)
+        })
+    }
+}
+extern "system" fn get_physical_device_format_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    format: Format,
+    p_format_properties: *mut FormatProperties,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_format_properties(
+                &this.dispatch,
+                physical_device,
+                format,
+                p_format_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_image_format_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    tiling: ImageTiling,
+    usage: ImageUsageFlags,
+    flags: ImageCreateFlags,
+    p_image_format_properties: *mut ImageFormatProperties,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_image_format_properties(
+                &this.dispatch,
+                physical_device,
+                format,
+                ty,
+                tiling,
+                usage,
+                flags,
+                p_image_format_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_properties: *mut PhysicalDeviceProperties,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_properties(&this.dispatch, physical_device, p_properties)
+        })
+    }
+}
+extern "system" fn get_physical_device_queue_family_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut QueueFamilyProperties,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_queue_family_properties(
+                &this.dispatch,
+                physical_device,
+                p_queue_family_property_count,
+                p_queue_family_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_memory_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_memory_properties: *mut PhysicalDeviceMemoryProperties,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_memory_properties(
+                &this.dispatch,
+                physical_device,
+                p_memory_properties,
+            )
+        })
+    }
+}
+extern "system" fn enumerate_device_extension_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut ExtensionProperties,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.enumerate_device_extension_properties(
+                &this.dispatch,
+                physical_device,
+                p_layer_name,
+                p_property_count,
+                p_properties,
+            )
+        })
+    }
+}
+extern "system" fn enumerate_device_layer_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut LayerProperties,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.enumerate_device_layer_properties(
+                &this.dispatch,
+                physical_device,
+                p_property_count,
+                p_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_sparse_image_format_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    samples: SampleCountFlags,
+    usage: ImageUsageFlags,
+    tiling: ImageTiling,
+    p_property_count: *mut u32,
+    p_properties: *mut SparseImageFormatProperties,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_sparse_image_format_properties(
+                    &this.dispatch,
+                    physical_device,
+                    format,
+                    ty,
+                    samples,
+                    usage,
+                    tiling,
+                    p_property_count,
+                    p_properties,
+                )
+        })
+    }
+}
+extern "system" fn get_device_queue<L: Layer>(
+    device: Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut Queue,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_device_queue(
+                &this.dispatch,
+                device,
+                queue_family_index,
+                queue_index,
+                p_queue,
+            )
+        })
+    }
+}
+extern "system" fn queue_submit<L: Layer>(
+    queue: Queue,
+    submit_count: u32,
+    p_submits: *const SubmitInfo,
+    fence: Fence,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .queue_submit(&this.dispatch, queue, submit_count, p_submits, fence)
+        })
+    }
+}
+extern "system" fn queue_wait_idle<L: Layer>(queue: Queue) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| this.instance.layer.queue_wait_idle(&this.dispatch, queue))
+    }
+}
+extern "system" fn device_wait_idle<L: Layer>(device: Device) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| this.instance.layer.device_wait_idle(&this.dispatch, device))
+    }
+}
+extern "system" fn allocate_memory<L: Layer>(
+    device: Device,
+    p_allocate_info: *const MemoryAllocateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_memory: *mut DeviceMemory,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.allocate_memory(
+                &this.dispatch,
+                device,
+                p_allocate_info,
+                p_allocator,
+                p_memory,
+            )
+        })
+    }
+}
+extern "system" fn free_memory<L: Layer>(
+    device: Device,
+    memory: DeviceMemory,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .free_memory(&this.dispatch, device, memory, p_allocator)
+        })
+    }
+}
+extern "system" fn map_memory<L: Layer>(
+    device: Device,
+    memory: DeviceMemory,
+    offset: DeviceSize,
+    size: DeviceSize,
+    flags: MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.map_memory(
+                &this.dispatch,
+                device,
+                memory,
+                offset,
+                size,
+                flags,
+                pp_data,
+            )
+        })
+    }
+}
+extern "system" fn unmap_memory<L: Layer>(device: Device, memory: DeviceMemory) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .unmap_memory(&this.dispatch, device, memory)
+        })
+    }
+}
+extern "system" fn flush_mapped_memory_ranges<L: Layer>(
+    device: Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const MappedMemoryRange,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.flush_mapped_memory_ranges(
+                &this.dispatch,
+                device,
+                memory_range_count,
+                p_memory_ranges,
+            )
+        })
+    }
+}
+extern "system" fn invalidate_mapped_memory_ranges<L: Layer>(
+    device: Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const MappedMemoryRange,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.invalidate_mapped_memory_ranges(
+                &this.dispatch,
+                device,
+                memory_range_count,
+                p_memory_ranges,
+            )
+        })
+    }
+}
+extern "system" fn get_device_memory_commitment<L: Layer>(
+    device: Device,
+    memory: DeviceMemory,
+    p_committed_memory_in_bytes: *mut DeviceSize,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_device_memory_commitment(
+                &this.dispatch,
+                device,
+                memory,
+                p_committed_memory_in_bytes,
+            )
+        })
+    }
+}
+extern "system" fn bind_buffer_memory<L: Layer>(
+    device: Device,
+    buffer: Buffer,
+    memory: DeviceMemory,
+    memory_offset: DeviceSize,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.bind_buffer_memory(
+                &this.dispatch,
+                device,
+                buffer,
+                memory,
+                memory_offset,
+            )
+        })
+    }
+}
+extern "system" fn bind_image_memory<L: Layer>(
+    device: Device,
+    image: Image,
+    memory: DeviceMemory,
+    memory_offset: DeviceSize,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.bind_image_memory(
+                &this.dispatch,
+                device,
+                image,
+                memory,
+                memory_offset,
+            )
+        })
+    }
+}
+extern "system" fn get_buffer_memory_requirements<L: Layer>(
+    device: Device,
+    buffer: Buffer,
+    p_memory_requirements: *mut MemoryRequirements,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_buffer_memory_requirements(
+                &this.dispatch,
+                device,
+                buffer,
+                p_memory_requirements,
+            )
+        })
+    }
+}
+extern "system" fn get_image_memory_requirements<L: Layer>(
+    device: Device,
+    image: Image,
+    p_memory_requirements: *mut MemoryRequirements,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_image_memory_requirements(
+                &this.dispatch,
+                device,
+                image,
+                p_memory_requirements,
+            )
+        })
+    }
+}
+extern "system" fn get_image_sparse_memory_requirements<L: Layer>(
+    device: Device,
+    image: Image,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut SparseImageMemoryRequirements,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_image_sparse_memory_requirements(
+                &this.dispatch,
+                device,
+                image,
+                p_sparse_memory_requirement_count,
+                p_sparse_memory_requirements,
+            )
+        })
+    }
+}
+extern "system" fn queue_bind_sparse<L: Layer>(
+    queue: Queue,
+    bind_info_count: u32,
+    p_bind_info: *const BindSparseInfo,
+    fence: Fence,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.queue_bind_sparse(
+                &this.dispatch,
+                queue,
+                bind_info_count,
+                p_bind_info,
+                fence,
+            )
+        })
+    }
+}
+extern "system" fn create_fence<L: Layer>(
+    device: Device,
+    p_create_info: *const FenceCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_fence(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_fence,
+            )
+        })
+    }
+}
+extern "system" fn destroy_fence<L: Layer>(
+    device: Device,
+    fence: Fence,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_fence(&this.dispatch, device, fence, p_allocator)
+        })
+    }
+}
+extern "system" fn reset_fences<L: Layer>(
+    device: Device,
+    fence_count: u32,
+    p_fences: *const Fence,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .reset_fences(&this.dispatch, device, fence_count, p_fences)
+        })
+    }
+}
+extern "system" fn get_fence_status<L: Layer>(device: Device, fence: Fence) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_fence_status(&this.dispatch, device, fence)
+        })
+    }
+}
+extern "system" fn wait_for_fences<L: Layer>(
+    device: Device,
+    fence_count: u32,
+    p_fences: *const Fence,
+    wait_all: Bool32,
+    timeout: u64,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.wait_for_fences(
+                &this.dispatch,
+                device,
+                fence_count,
+                p_fences,
+                wait_all,
+                timeout,
+            )
+        })
+    }
+}
+extern "system" fn create_semaphore<L: Layer>(
+    device: Device,
+    p_create_info: *const SemaphoreCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_semaphore: *mut Semaphore,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_semaphore(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_semaphore,
+            )
+        })
+    }
+}
+extern "system" fn destroy_semaphore<L: Layer>(
+    device: Device,
+    semaphore: Semaphore,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_semaphore(&this.dispatch, device, semaphore, p_allocator)
+        })
+    }
+}
+extern "system" fn create_event<L: Layer>(
+    device: Device,
+    p_create_info: *const EventCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_event: *mut Event,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_event(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_event,
+            )
+        })
+    }
+}
+extern "system" fn destroy_event<L: Layer>(
+    device: Device,
+    event: Event,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_event(&this.dispatch, device, event, p_allocator)
+        })
+    }
+}
+extern "system" fn get_event_status<L: Layer>(device: Device, event: Event) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_event_status(&this.dispatch, device, event)
+        })
+    }
+}
+extern "system" fn set_event<L: Layer>(device: Device, event: Event) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| this.instance.layer.set_event(&this.dispatch, device, event))
+    }
+}
+extern "system" fn reset_event<L: Layer>(device: Device, event: Event) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .reset_event(&this.dispatch, device, event)
+        })
+    }
+}
+extern "system" fn create_query_pool<L: Layer>(
+    device: Device,
+    p_create_info: *const QueryPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_query_pool: *mut QueryPool,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_query_pool(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_query_pool,
+            )
+        })
+    }
+}
+extern "system" fn destroy_query_pool<L: Layer>(
+    device: Device,
+    query_pool: QueryPool,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_query_pool(&this.dispatch, device, query_pool, p_allocator)
+        })
+    }
+}
+extern "system" fn get_query_pool_results<L: Layer>(
+    device: Device,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: DeviceSize,
+    flags: QueryResultFlags,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_query_pool_results(
+                &this.dispatch,
+                device,
+                query_pool,
+                first_query,
+                query_count,
+                data_size,
+                p_data,
+                stride,
+                flags,
+            )
+        })
+    }
+}
+extern "system" fn create_buffer<L: Layer>(
+    device: Device,
+    p_create_info: *const BufferCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_buffer: *mut Buffer,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_buffer(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_buffer,
+            )
+        })
+    }
+}
+extern "system" fn destroy_buffer<L: Layer>(
+    device: Device,
+    buffer: Buffer,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_buffer(&this.dispatch, device, buffer, p_allocator)
+        })
+    }
+}
+extern "system" fn create_buffer_view<L: Layer>(
+    device: Device,
+    p_create_info: *const BufferViewCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_view: *mut BufferView,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_buffer_view(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_view,
+            )
+        })
+    }
+}
+extern "system" fn destroy_buffer_view<L: Layer>(
+    device: Device,
+    buffer_view: BufferView,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_buffer_view(
+                &this.dispatch,
+                device,
+                buffer_view,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn create_image<L: Layer>(
+    device: Device,
+    p_create_info: *const ImageCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_image: *mut Image,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_image(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_image,
+            )
+        })
+    }
+}
+extern "system" fn destroy_image<L: Layer>(
+    device: Device,
+    image: Image,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_image(&this.dispatch, device, image, p_allocator)
+        })
+    }
+}
+extern "system" fn get_image_subresource_layout<L: Layer>(
+    device: Device,
+    image: Image,
+    p_subresource: *const ImageSubresource,
+    p_layout: *mut SubresourceLayout,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_image_subresource_layout(
+                &this.dispatch,
+                device,
+                image,
+                p_subresource,
+                p_layout,
+            )
+        })
+    }
+}
+extern "system" fn create_image_view<L: Layer>(
+    device: Device,
+    p_create_info: *const ImageViewCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_view: *mut ImageView,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_image_view(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_view,
+            )
+        })
+    }
+}
+extern "system" fn destroy_image_view<L: Layer>(
+    device: Device,
+    image_view: ImageView,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_image_view(&this.dispatch, device, image_view, p_allocator)
+        })
+    }
+}
+extern "system" fn create_shader_module<L: Layer>(
+    device: Device,
+    p_create_info: *const ShaderModuleCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_shader_module: *mut ShaderModule,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_shader_module(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_shader_module,
+            )
+        })
+    }
+}
+extern "system" fn destroy_shader_module<L: Layer>(
+    device: Device,
+    shader_module: ShaderModule,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_shader_module(
+                &this.dispatch,
+                device,
+                shader_module,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn create_pipeline_cache<L: Layer>(
+    device: Device,
+    p_create_info: *const PipelineCacheCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipeline_cache: *mut PipelineCache,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_pipeline_cache(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_pipeline_cache,
+            )
+        })
+    }
+}
+extern "system" fn destroy_pipeline_cache<L: Layer>(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_pipeline_cache(
+                &this.dispatch,
+                device,
+                pipeline_cache,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn get_pipeline_cache_data<L: Layer>(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_pipeline_cache_data(
+                &this.dispatch,
+                device,
+                pipeline_cache,
+                p_data_size,
+                p_data,
+            )
+        })
+    }
+}
+extern "system" fn merge_pipeline_caches<L: Layer>(
+    device: Device,
+    dst_cache: PipelineCache,
+    src_cache_count: u32,
+    p_src_caches: *const PipelineCache,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.merge_pipeline_caches(
+                &this.dispatch,
+                device,
+                dst_cache,
+                src_cache_count,
+                p_src_caches,
+            )
+        })
+    }
+}
+extern "system" fn create_graphics_pipelines<L: Layer>(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const GraphicsPipelineCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_graphics_pipelines(
+                &this.dispatch,
+                device,
+                pipeline_cache,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_pipelines,
+            )
+        })
+    }
+}
+extern "system" fn create_compute_pipelines<L: Layer>(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const ComputePipelineCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_compute_pipelines(
+                &this.dispatch,
+                device,
+                pipeline_cache,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_pipelines,
+            )
+        })
+    }
+}
+extern "system" fn destroy_pipeline<L: Layer>(
+    device: Device,
+    pipeline: Pipeline,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_pipeline(&this.dispatch, device, pipeline, p_allocator)
+        })
+    }
+}
+extern "system" fn create_pipeline_layout<L: Layer>(
+    device: Device,
+    p_create_info: *const PipelineLayoutCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipeline_layout: *mut PipelineLayout,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_pipeline_layout(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_pipeline_layout,
+            )
+        })
+    }
+}
+extern "system" fn destroy_pipeline_layout<L: Layer>(
+    device: Device,
+    pipeline_layout: PipelineLayout,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_pipeline_layout(
+                &this.dispatch,
+                device,
+                pipeline_layout,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn create_sampler<L: Layer>(
+    device: Device,
+    p_create_info: *const SamplerCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_sampler: *mut Sampler,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_sampler(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_sampler,
+            )
+        })
+    }
+}
+extern "system" fn destroy_sampler<L: Layer>(
+    device: Device,
+    sampler: Sampler,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .destroy_sampler(&this.dispatch, device, sampler, p_allocator)
+        })
+    }
+}
+extern "system" fn create_descriptor_set_layout<L: Layer>(
+    device: Device,
+    p_create_info: *const DescriptorSetLayoutCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_set_layout: *mut DescriptorSetLayout,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_descriptor_set_layout(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_set_layout,
+            )
+        })
+    }
+}
+extern "system" fn destroy_descriptor_set_layout<L: Layer>(
+    device: Device,
+    descriptor_set_layout: DescriptorSetLayout,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_descriptor_set_layout(
+                &this.dispatch,
+                device,
+                descriptor_set_layout,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn create_descriptor_pool<L: Layer>(
+    device: Device,
+    p_create_info: *const DescriptorPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_descriptor_pool: *mut DescriptorPool,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_descriptor_pool(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_descriptor_pool,
+            )
+        })
+    }
+}
+extern "system" fn destroy_descriptor_pool<L: Layer>(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_descriptor_pool(
+                &this.dispatch,
+                device,
+                descriptor_pool,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn reset_descriptor_pool<L: Layer>(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    flags: DescriptorPoolResetFlags,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.reset_descriptor_pool(
+                &this.dispatch,
+                device,
+                descriptor_pool,
+                flags,
+            )
+        })
+    }
+}
+extern "system" fn allocate_descriptor_sets<L: Layer>(
+    device: Device,
+    p_allocate_info: *const DescriptorSetAllocateInfo,
+    p_descriptor_sets: *mut DescriptorSet,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.allocate_descriptor_sets(
+                &this.dispatch,
+                device,
+                p_allocate_info,
+                p_descriptor_sets,
+            )
+        })
+    }
+}
+extern "system" fn free_descriptor_sets<L: Layer>(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const DescriptorSet,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.free_descriptor_sets(
+                &this.dispatch,
+                device,
+                descriptor_pool,
+                descriptor_set_count,
+                p_descriptor_sets,
+            )
+        })
+    }
+}
+extern "system" fn update_descriptor_sets<L: Layer>(
+    device: Device,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const WriteDescriptorSet,
+    descriptor_copy_count: u32,
+    p_descriptor_copies: *const CopyDescriptorSet,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.update_descriptor_sets(
+                &this.dispatch,
+                device,
+                descriptor_write_count,
+                p_descriptor_writes,
+                descriptor_copy_count,
+                p_descriptor_copies,
+            )
+        })
+    }
+}
+extern "system" fn create_framebuffer<L: Layer>(
+    device: Device,
+    p_create_info: *const FramebufferCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_framebuffer: *mut Framebuffer,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_framebuffer(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_framebuffer,
+            )
+        })
+    }
+}
+extern "system" fn destroy_framebuffer<L: Layer>(
+    device: Device,
+    framebuffer: Framebuffer,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_framebuffer(
+                &this.dispatch,
+                device,
+                framebuffer,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn create_render_pass<L: Layer>(
+    device: Device,
+    p_create_info: *const RenderPassCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_render_pass: *mut RenderPass,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_render_pass(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_render_pass,
+            )
+        })
+    }
+}
+extern "system" fn destroy_render_pass<L: Layer>(
+    device: Device,
+    render_pass: RenderPass,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_render_pass(
+                &this.dispatch,
+                device,
+                render_pass,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn get_render_area_granularity<L: Layer>(
+    device: Device,
+    render_pass: RenderPass,
+    p_granularity: *mut Extent2D,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_render_area_granularity(
+                &this.dispatch,
+                device,
+                render_pass,
+                p_granularity,
+            )
+        })
+    }
+}
+extern "system" fn create_command_pool<L: Layer>(
+    device: Device,
+    p_create_info: *const CommandPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_command_pool: *mut CommandPool,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_command_pool(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_command_pool,
+            )
+        })
+    }
+}
+extern "system" fn destroy_command_pool<L: Layer>(
+    device: Device,
+    command_pool: CommandPool,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_command_pool(
+                &this.dispatch,
+                device,
+                command_pool,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn reset_command_pool<L: Layer>(
+    device: Device,
+    command_pool: CommandPool,
+    flags: CommandPoolResetFlags,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .reset_command_pool(&this.dispatch, device, command_pool, flags)
+        })
+    }
+}
+extern "system" fn allocate_command_buffers<L: Layer>(
+    device: Device,
+    p_allocate_info: *const CommandBufferAllocateInfo,
+    p_command_buffers: *mut CommandBuffer,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.allocate_command_buffers(
+                &this.dispatch,
+                device,
+                p_allocate_info,
+                p_command_buffers,
+            )
+        })
+    }
+}
+extern "system" fn free_command_buffers<L: Layer>(
+    device: Device,
+    command_pool: CommandPool,
+    command_buffer_count: u32,
+    p_command_buffers: *const CommandBuffer,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.free_command_buffers(
+                &this.dispatch,
+                device,
+                command_pool,
+                command_buffer_count,
+                p_command_buffers,
+            )
+        })
+    }
+}
+extern "system" fn begin_command_buffer<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_begin_info: *const CommandBufferBeginInfo,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .begin_command_buffer(&this.dispatch, command_buffer, p_begin_info)
+        })
+    }
+}
+extern "system" fn end_command_buffer<L: Layer>(command_buffer: CommandBuffer) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .end_command_buffer(&this.dispatch, command_buffer)
+        })
+    }
+}
+extern "system" fn reset_command_buffer<L: Layer>(
+    command_buffer: CommandBuffer,
+    flags: CommandBufferResetFlags,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .reset_command_buffer(&this.dispatch, command_buffer, flags)
+        })
+    }
+}
+extern "system" fn cmd_bind_pipeline<L: Layer>(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    pipeline: Pipeline,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_bind_pipeline(
+                &this.dispatch,
+                command_buffer,
+                pipeline_bind_point,
+                pipeline,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_viewport<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewports: *const Viewport,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_viewport(
+                &this.dispatch,
+                command_buffer,
+                first_viewport,
+                viewport_count,
+                p_viewports,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_scissor<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_scissor: u32,
+    scissor_count: u32,
+    p_scissors: *const Rect2D,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_scissor(
+                &this.dispatch,
+                command_buffer,
+                first_scissor,
+                scissor_count,
+                p_scissors,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_line_width<L: Layer>(command_buffer: CommandBuffer, line_width: f32) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_set_line_width(&this.dispatch, command_buffer, line_width)
+        })
+    }
+}
+extern "system" fn cmd_set_depth_bias<L: Layer>(
+    command_buffer: CommandBuffer,
+    depth_bias_constant_factor: f32,
+    depth_bias_clamp: f32,
+    depth_bias_slope_factor: f32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_depth_bias(
+                &this.dispatch,
+                command_buffer,
+                depth_bias_constant_factor,
+                depth_bias_clamp,
+                depth_bias_slope_factor,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_blend_constants<L: Layer>(
+    command_buffer: CommandBuffer,
+    blend_constants: *const [f32; 4],
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_blend_constants(
+                &this.dispatch,
+                command_buffer,
+                blend_constants,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_depth_bounds<L: Layer>(
+    command_buffer: CommandBuffer,
+    min_depth_bounds: f32,
+    max_depth_bounds: f32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_depth_bounds(
+                &this.dispatch,
+                command_buffer,
+                min_depth_bounds,
+                max_depth_bounds,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_stencil_compare_mask<L: Layer>(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    compare_mask: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_stencil_compare_mask(
+                &this.dispatch,
+                command_buffer,
+                face_mask,
+                compare_mask,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_stencil_write_mask<L: Layer>(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    write_mask: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_stencil_write_mask(
+                &this.dispatch,
+                command_buffer,
+                face_mask,
+                write_mask,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_stencil_reference<L: Layer>(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    reference: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_stencil_reference(
+                &this.dispatch,
+                command_buffer,
+                face_mask,
+                reference,
+            )
+        })
+    }
+}
+extern "system" fn cmd_bind_descriptor_sets<L: Layer>(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    layout: PipelineLayout,
+    first_set: u32,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const DescriptorSet,
+    dynamic_offset_count: u32,
+    p_dynamic_offsets: *const u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_bind_descriptor_sets(
+                &this.dispatch,
+                command_buffer,
+                pipeline_bind_point,
+                layout,
+                first_set,
+                descriptor_set_count,
+                p_descriptor_sets,
+                dynamic_offset_count,
+                p_dynamic_offsets,
+            )
+        })
+    }
+}
+extern "system" fn cmd_bind_index_buffer<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    index_type: IndexType,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_bind_index_buffer(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                index_type,
+            )
+        })
+    }
+}
+extern "system" fn cmd_bind_vertex_buffers<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const Buffer,
+    p_offsets: *const DeviceSize,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_bind_vertex_buffers(
+                &this.dispatch,
+                command_buffer,
+                first_binding,
+                binding_count,
+                p_buffers,
+                p_offsets,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw<L: Layer>(
+    command_buffer: CommandBuffer,
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw(
+                &this.dispatch,
+                command_buffer,
+                vertex_count,
+                instance_count,
+                first_vertex,
+                first_instance,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_indexed<L: Layer>(
+    command_buffer: CommandBuffer,
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    vertex_offset: i32,
+    first_instance: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_indexed(
+                &this.dispatch,
+                command_buffer,
+                index_count,
+                instance_count,
+                first_index,
+                vertex_offset,
+                first_instance,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_indirect<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_indirect(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                draw_count,
+                stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_indexed_indirect<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_indexed_indirect(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                draw_count,
+                stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_dispatch<L: Layer>(
+    command_buffer: CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_dispatch(
+                &this.dispatch,
+                command_buffer,
+                group_count_x,
+                group_count_y,
+                group_count_z,
+            )
+        })
+    }
+}
+extern "system" fn cmd_dispatch_indirect<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_dispatch_indirect(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+            )
+        })
+    }
+}
+extern "system" fn cmd_copy_buffer<L: Layer>(
+    command_buffer: CommandBuffer,
+    src_buffer: Buffer,
+    dst_buffer: Buffer,
+    region_count: u32,
+    p_regions: *const BufferCopy,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_copy_buffer(
+                &this.dispatch,
+                command_buffer,
+                src_buffer,
+                dst_buffer,
+                region_count,
+                p_regions,
+            )
+        })
+    }
+}
+extern "system" fn cmd_copy_image<L: Layer>(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageCopy,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_copy_image(
+                &this.dispatch,
+                command_buffer,
+                src_image,
+                src_image_layout,
+                dst_image,
+                dst_image_layout,
+                region_count,
+                p_regions,
+            )
+        })
+    }
+}
+extern "system" fn cmd_blit_image<L: Layer>(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageBlit,
+    filter: Filter,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_blit_image(
+                &this.dispatch,
+                command_buffer,
+                src_image,
+                src_image_layout,
+                dst_image,
+                dst_image_layout,
+                region_count,
+                p_regions,
+                filter,
+            )
+        })
+    }
+}
+extern "system" fn cmd_copy_buffer_to_image<L: Layer>(
+    command_buffer: CommandBuffer,
+    src_buffer: Buffer,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const BufferImageCopy,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_copy_buffer_to_image(
+                &this.dispatch,
+                command_buffer,
+                src_buffer,
+                dst_image,
+                dst_image_layout,
+                region_count,
+                p_regions,
+            )
+        })
+    }
+}
+extern "system" fn cmd_copy_image_to_buffer<L: Layer>(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_buffer: Buffer,
+    region_count: u32,
+    p_regions: *const BufferImageCopy,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_copy_image_to_buffer(
+                &this.dispatch,
+                command_buffer,
+                src_image,
+                src_image_layout,
+                dst_buffer,
+                region_count,
+                p_regions,
+            )
+        })
+    }
+}
+extern "system" fn cmd_update_buffer<L: Layer>(
+    command_buffer: CommandBuffer,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    data_size: DeviceSize,
+    p_data: *const c_void,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_update_buffer(
+                &this.dispatch,
+                command_buffer,
+                dst_buffer,
+                dst_offset,
+                data_size,
+                p_data,
+            )
+        })
+    }
+}
+extern "system" fn cmd_fill_buffer<L: Layer>(
+    command_buffer: CommandBuffer,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    size: DeviceSize,
+    data: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_fill_buffer(
+                &this.dispatch,
+                command_buffer,
+                dst_buffer,
+                dst_offset,
+                size,
+                data,
+            )
+        })
+    }
+}
+extern "system" fn cmd_clear_color_image<L: Layer>(
+    command_buffer: CommandBuffer,
+    image: Image,
+    image_layout: ImageLayout,
+    p_color: *const ClearColorValue,
+    range_count: u32,
+    p_ranges: *const ImageSubresourceRange,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_clear_color_image(
+                &this.dispatch,
+                command_buffer,
+                image,
+                image_layout,
+                p_color,
+                range_count,
+                p_ranges,
+            )
+        })
+    }
+}
+extern "system" fn cmd_clear_depth_stencil_image<L: Layer>(
+    command_buffer: CommandBuffer,
+    image: Image,
+    image_layout: ImageLayout,
+    p_depth_stencil: *const ClearDepthStencilValue,
+    range_count: u32,
+    p_ranges: *const ImageSubresourceRange,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_clear_depth_stencil_image(
+                &this.dispatch,
+                command_buffer,
+                image,
+                image_layout,
+                p_depth_stencil,
+                range_count,
+                p_ranges,
+            )
+        })
+    }
+}
+extern "system" fn cmd_clear_attachments<L: Layer>(
+    command_buffer: CommandBuffer,
+    attachment_count: u32,
+    p_attachments: *const ClearAttachment,
+    rect_count: u32,
+    p_rects: *const ClearRect,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_clear_attachments(
+                &this.dispatch,
+                command_buffer,
+                attachment_count,
+                p_attachments,
+                rect_count,
+                p_rects,
+            )
+        })
+    }
+}
+extern "system" fn cmd_resolve_image<L: Layer>(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageResolve,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_resolve_image(
+                &this.dispatch,
+                command_buffer,
+                src_image,
+                src_image_layout,
+                dst_image,
+                dst_image_layout,
+                region_count,
+                p_regions,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_event<L: Layer>(
+    command_buffer: CommandBuffer,
+    event: Event,
+    stage_mask: PipelineStageFlags,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_set_event(&this.dispatch, command_buffer, event, stage_mask)
+        })
+    }
+}
+extern "system" fn cmd_reset_event<L: Layer>(
+    command_buffer: CommandBuffer,
+    event: Event,
+    stage_mask: PipelineStageFlags,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_reset_event(&this.dispatch, command_buffer, event, stage_mask)
+        })
+    }
+}
+extern "system" fn cmd_wait_events<L: Layer>(
+    command_buffer: CommandBuffer,
+    event_count: u32,
+    p_events: *const Event,
+    src_stage_mask: PipelineStageFlags,
+    dst_stage_mask: PipelineStageFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const MemoryBarrier,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const BufferMemoryBarrier,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const ImageMemoryBarrier,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_wait_events(
+                &this.dispatch,
+                command_buffer,
+                event_count,
+                p_events,
+                src_stage_mask,
+                dst_stage_mask,
+                memory_barrier_count,
+                p_memory_barriers,
+                buffer_memory_barrier_count,
+                p_buffer_memory_barriers,
+                image_memory_barrier_count,
+                p_image_memory_barriers,
+            )
+        })
+    }
+}
+extern "system" fn cmd_pipeline_barrier<L: Layer>(
+    command_buffer: CommandBuffer,
+    src_stage_mask: PipelineStageFlags,
+    dst_stage_mask: PipelineStageFlags,
+    dependency_flags: DependencyFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const MemoryBarrier,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const BufferMemoryBarrier,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const ImageMemoryBarrier,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_pipeline_barrier(
+                &this.dispatch,
+                command_buffer,
+                src_stage_mask,
+                dst_stage_mask,
+                dependency_flags,
+                memory_barrier_count,
+                p_memory_barriers,
+                buffer_memory_barrier_count,
+                p_buffer_memory_barriers,
+                image_memory_barrier_count,
+                p_image_memory_barriers,
+            )
+        })
+    }
+}
+extern "system" fn cmd_begin_query<L: Layer>(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    flags: QueryControlFlags,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_begin_query(
+                &this.dispatch,
+                command_buffer,
+                query_pool,
+                query,
+                flags,
+            )
+        })
+    }
+}
+extern "system" fn cmd_end_query<L: Layer>(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_end_query(&this.dispatch, command_buffer, query_pool, query)
+        })
+    }
+}
+extern "system" fn cmd_reset_query_pool<L: Layer>(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_reset_query_pool(
+                &this.dispatch,
+                command_buffer,
+                query_pool,
+                first_query,
+                query_count,
+            )
+        })
+    }
+}
+extern "system" fn cmd_write_timestamp<L: Layer>(
+    command_buffer: CommandBuffer,
+    pipeline_stage: PipelineStageFlags,
+    query_pool: QueryPool,
+    query: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_write_timestamp(
+                &this.dispatch,
+                command_buffer,
+                pipeline_stage,
+                query_pool,
+                query,
+            )
+        })
+    }
+}
+extern "system" fn cmd_copy_query_pool_results<L: Layer>(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    stride: DeviceSize,
+    flags: QueryResultFlags,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_copy_query_pool_results(
+                &this.dispatch,
+                command_buffer,
+                query_pool,
+                first_query,
+                query_count,
+                dst_buffer,
+                dst_offset,
+                stride,
+                flags,
+            )
+        })
+    }
+}
+extern "system" fn cmd_push_constants<L: Layer>(
+    command_buffer: CommandBuffer,
+    layout: PipelineLayout,
+    stage_flags: ShaderStageFlags,
+    offset: u32,
+    size: u32,
+    p_values: *const c_void,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_push_constants(
+                &this.dispatch,
+                command_buffer,
+                layout,
+                stage_flags,
+                offset,
+                size,
+                p_values,
+            )
+        })
+    }
+}
+extern "system" fn cmd_begin_render_pass<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_render_pass_begin: *const RenderPassBeginInfo,
+    contents: SubpassContents,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_begin_render_pass(
+                &this.dispatch,
+                command_buffer,
+                p_render_pass_begin,
+                contents,
+            )
+        })
+    }
+}
+extern "system" fn cmd_next_subpass<L: Layer>(
+    command_buffer: CommandBuffer,
+    contents: SubpassContents,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_next_subpass(&this.dispatch, command_buffer, contents)
+        })
+    }
+}
+extern "system" fn cmd_end_render_pass<L: Layer>(command_buffer: CommandBuffer) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_end_render_pass(&this.dispatch, command_buffer)
+        })
+    }
+}
+extern "system" fn cmd_execute_commands<L: Layer>(
+    command_buffer: CommandBuffer,
+    command_buffer_count: u32,
+    p_command_buffers: *const CommandBuffer,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_execute_commands(
+                &this.dispatch,
+                command_buffer,
+                command_buffer_count,
+                p_command_buffers,
+            )
+        })
+    }
+}
+extern "system" fn acquire_image_android<L: Layer>(
+    device: Device,
+    image: Image,
+    native_fence_fd: c_int,
+    semaphore: Semaphore,
+    fence: Fence,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.acquire_image_android(
+                &this.dispatch,
+                device,
+                image,
+                native_fence_fd,
+                semaphore,
+                fence,
+            )
+        })
+    }
+}
+extern "system" fn acquire_next_image2_khr<L: Layer>(
+    device: Device,
+    p_acquire_info: *const AcquireNextImageInfoKHR,
+    p_image_index: *mut u32,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.acquire_next_image2_khr(
+                &this.dispatch,
+                device,
+                p_acquire_info,
+                p_image_index,
+            )
+        })
+    }
+}
+extern "system" fn acquire_next_image_khr<L: Layer>(
+    device: Device,
+    swapchain: SwapchainKHR,
+    timeout: u64,
+    semaphore: Semaphore,
+    fence: Fence,
+    p_image_index: *mut u32,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.acquire_next_image_khr(
+                &this.dispatch,
+                device,
+                swapchain,
+                timeout,
+                semaphore,
+                fence,
+                p_image_index,
+            )
+        })
+    }
+}
+extern "system" fn acquire_xlib_display_ext<L: Layer>(
+    physical_device: PhysicalDevice,
+    dpy: *mut Display,
+    display: DisplayKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .acquire_xlib_display_ext(&this.dispatch, physical_device, dpy, display)
+        })
+    }
+}
+extern "system" fn bind_acceleration_structure_memory_nv<L: Layer>(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindAccelerationStructureMemoryInfoNV,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.bind_acceleration_structure_memory_nv(
+                &this.dispatch,
+                device,
+                bind_info_count,
+                p_bind_infos,
+            )
+        })
+    }
+}
+extern "system" fn bind_buffer_memory2<L: Layer>(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindBufferMemoryInfo,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.bind_buffer_memory2(
+                &this.dispatch,
+                device,
+                bind_info_count,
+                p_bind_infos,
+            )
+        })
+    }
+}
+extern "system" fn bind_image_memory2<L: Layer>(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindImageMemoryInfo,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.bind_image_memory2(
+                &this.dispatch,
+                device,
+                bind_info_count,
+                p_bind_infos,
+            )
+        })
+    }
+}
+extern "system" fn cmd_begin_conditional_rendering_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_conditional_rendering_begin: *const ConditionalRenderingBeginInfoEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_begin_conditional_rendering_ext(
+                &this.dispatch,
+                command_buffer,
+                p_conditional_rendering_begin,
+            )
+        })
+    }
+}
+extern "system" fn cmd_begin_debug_utils_label_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_label_info: *const DebugUtilsLabelEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_begin_debug_utils_label_ext(
+                &this.dispatch,
+                command_buffer,
+                p_label_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_begin_query_indexed_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    flags: QueryControlFlags,
+    index: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_begin_query_indexed_ext(
+                &this.dispatch,
+                command_buffer,
+                query_pool,
+                query,
+                flags,
+                index,
+            )
+        })
+    }
+}
+extern "system" fn cmd_begin_render_pass2_khr<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_render_pass_begin: *const RenderPassBeginInfo,
+    p_subpass_begin_info: *const SubpassBeginInfoKHR,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_begin_render_pass2_khr(
+                &this.dispatch,
+                command_buffer,
+                p_render_pass_begin,
+                p_subpass_begin_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_begin_transform_feedback_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const Buffer,
+    p_counter_buffer_offsets: *const DeviceSize,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_begin_transform_feedback_ext(
+                &this.dispatch,
+                command_buffer,
+                first_counter_buffer,
+                counter_buffer_count,
+                p_counter_buffers,
+                p_counter_buffer_offsets,
+            )
+        })
+    }
+}
+extern "system" fn cmd_bind_shading_rate_image_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    image_view: ImageView,
+    image_layout: ImageLayout,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_bind_shading_rate_image_nv(
+                &this.dispatch,
+                command_buffer,
+                image_view,
+                image_layout,
+            )
+        })
+    }
+}
+extern "system" fn cmd_bind_transform_feedback_buffers_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const Buffer,
+    p_offsets: *const DeviceSize,
+    p_sizes: *const DeviceSize,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_bind_transform_feedback_buffers_ext(
+                &this.dispatch,
+                command_buffer,
+                first_binding,
+                binding_count,
+                p_buffers,
+                p_offsets,
+                p_sizes,
+            )
+        })
+    }
+}
+extern "system" fn cmd_build_acceleration_structure_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_info: *const AccelerationStructureInfoNV,
+    instance_data: Buffer,
+    instance_offset: DeviceSize,
+    update: Bool32,
+    dst: AccelerationStructureNV,
+    src: AccelerationStructureNV,
+    scratch: Buffer,
+    scratch_offset: DeviceSize,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_build_acceleration_structure_nv(
+                &this.dispatch,
+                command_buffer,
+                p_info,
+                instance_data,
+                instance_offset,
+                update,
+                dst,
+                src,
+                scratch,
+                scratch_offset,
+            )
+        })
+    }
+}
+extern "system" fn cmd_copy_acceleration_structure_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    dst: AccelerationStructureNV,
+    src: AccelerationStructureNV,
+    mode: CopyAccelerationStructureModeNV,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_copy_acceleration_structure_nv(
+                &this.dispatch,
+                command_buffer,
+                dst,
+                src,
+                mode,
+            )
+        })
+    }
+}
+extern "system" fn cmd_debug_marker_begin_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_marker_info: *const DebugMarkerMarkerInfoEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_debug_marker_begin_ext(
+                &this.dispatch,
+                command_buffer,
+                p_marker_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_debug_marker_end_ext<L: Layer>(command_buffer: CommandBuffer) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_debug_marker_end_ext(&this.dispatch, command_buffer)
+        })
+    }
+}
+extern "system" fn cmd_debug_marker_insert_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_marker_info: *const DebugMarkerMarkerInfoEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_debug_marker_insert_ext(
+                &this.dispatch,
+                command_buffer,
+                p_marker_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_dispatch_base<L: Layer>(
+    command_buffer: CommandBuffer,
+    base_group_x: u32,
+    base_group_y: u32,
+    base_group_z: u32,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_dispatch_base(
+                &this.dispatch,
+                command_buffer,
+                base_group_x,
+                base_group_y,
+                base_group_z,
+                group_count_x,
+                group_count_y,
+                group_count_z,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_indexed_indirect_count_amd<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_indexed_indirect_count_amd(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_indexed_indirect_count_khr<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_indexed_indirect_count_khr(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_indirect_byte_count_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    instance_count: u32,
+    first_instance: u32,
+    counter_buffer: Buffer,
+    counter_buffer_offset: DeviceSize,
+    counter_offset: u32,
+    vertex_stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_indirect_byte_count_ext(
+                &this.dispatch,
+                command_buffer,
+                instance_count,
+                first_instance,
+                counter_buffer,
+                counter_buffer_offset,
+                counter_offset,
+                vertex_stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_indirect_count_amd<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_indirect_count_amd(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_indirect_count_khr<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_indirect_count_khr(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_mesh_tasks_indirect_count_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_mesh_tasks_indirect_count_nv(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_mesh_tasks_indirect_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_mesh_tasks_indirect_nv(
+                &this.dispatch,
+                command_buffer,
+                buffer,
+                offset,
+                draw_count,
+                stride,
+            )
+        })
+    }
+}
+extern "system" fn cmd_draw_mesh_tasks_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    task_count: u32,
+    first_task: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_draw_mesh_tasks_nv(
+                &this.dispatch,
+                command_buffer,
+                task_count,
+                first_task,
+            )
+        })
+    }
+}
+extern "system" fn cmd_end_conditional_rendering_ext<L: Layer>(command_buffer: CommandBuffer) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_end_conditional_rendering_ext(&this.dispatch, command_buffer)
+        })
+    }
+}
+extern "system" fn cmd_end_debug_utils_label_ext<L: Layer>(command_buffer: CommandBuffer) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_end_debug_utils_label_ext(&this.dispatch, command_buffer)
+        })
+    }
+}
+extern "system" fn cmd_end_query_indexed_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    index: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_end_query_indexed_ext(
+                &this.dispatch,
+                command_buffer,
+                query_pool,
+                query,
+                index,
+            )
+        })
+    }
+}
+extern "system" fn cmd_end_render_pass2_khr<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_subpass_end_info: *const SubpassEndInfoKHR,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_end_render_pass2_khr(
+                &this.dispatch,
+                command_buffer,
+                p_subpass_end_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_end_transform_feedback_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const Buffer,
+    p_counter_buffer_offsets: *const DeviceSize,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_end_transform_feedback_ext(
+                &this.dispatch,
+                command_buffer,
+                first_counter_buffer,
+                counter_buffer_count,
+                p_counter_buffers,
+                p_counter_buffer_offsets,
+            )
+        })
+    }
+}
+extern "system" fn cmd_insert_debug_utils_label_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_label_info: *const DebugUtilsLabelEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_insert_debug_utils_label_ext(
+                &this.dispatch,
+                command_buffer,
+                p_label_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_next_subpass2_khr<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_subpass_begin_info: *const SubpassBeginInfoKHR,
+    p_subpass_end_info: *const SubpassEndInfoKHR,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_next_subpass2_khr(
+                &this.dispatch,
+                command_buffer,
+                p_subpass_begin_info,
+                p_subpass_end_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_process_commands_nvx<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_process_commands_info: *const CmdProcessCommandsInfoNVX,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_process_commands_nvx(
+                &this.dispatch,
+                command_buffer,
+                p_process_commands_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_push_descriptor_set_khr<L: Layer>(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    layout: PipelineLayout,
+    set: u32,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const WriteDescriptorSet,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_push_descriptor_set_khr(
+                &this.dispatch,
+                command_buffer,
+                pipeline_bind_point,
+                layout,
+                set,
+                descriptor_write_count,
+                p_descriptor_writes,
+            )
+        })
+    }
+}
+extern "system" fn cmd_push_descriptor_set_with_template_khr<L: Layer>(
+    command_buffer: CommandBuffer,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    layout: PipelineLayout,
+    set: u32,
+    p_data: *const c_void,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_push_descriptor_set_with_template_khr(
+                    &this.dispatch,
+                    command_buffer,
+                    descriptor_update_template,
+                    layout,
+                    set,
+                    p_data,
+                )
+        })
+    }
+}
+extern "system" fn cmd_reserve_space_for_commands_nvx<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_reserve_space_info: *const CmdReserveSpaceForCommandsInfoNVX,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_reserve_space_for_commands_nvx(
+                &this.dispatch,
+                command_buffer,
+                p_reserve_space_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_checkpoint_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_checkpoint_marker: *const c_void,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_checkpoint_nv(
+                &this.dispatch,
+                command_buffer,
+                p_checkpoint_marker,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_coarse_sample_order_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    sample_order_type: CoarseSampleOrderTypeNV,
+    custom_sample_order_count: u32,
+    p_custom_sample_orders: *const CoarseSampleOrderCustomNV,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_coarse_sample_order_nv(
+                &this.dispatch,
+                command_buffer,
+                sample_order_type,
+                custom_sample_order_count,
+                p_custom_sample_orders,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_device_mask<L: Layer>(command_buffer: CommandBuffer, device_mask: u32) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_set_device_mask(&this.dispatch, command_buffer, device_mask)
+        })
+    }
+}
+extern "system" fn cmd_set_discard_rectangle_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_discard_rectangle: u32,
+    discard_rectangle_count: u32,
+    p_discard_rectangles: *const Rect2D,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_discard_rectangle_ext(
+                &this.dispatch,
+                command_buffer,
+                first_discard_rectangle,
+                discard_rectangle_count,
+                p_discard_rectangles,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_exclusive_scissor_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissors: *const Rect2D,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_exclusive_scissor_nv(
+                &this.dispatch,
+                command_buffer,
+                first_exclusive_scissor,
+                exclusive_scissor_count,
+                p_exclusive_scissors,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_sample_locations_ext<L: Layer>(
+    command_buffer: CommandBuffer,
+    p_sample_locations_info: *const SampleLocationsInfoEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_sample_locations_ext(
+                &this.dispatch,
+                command_buffer,
+                p_sample_locations_info,
+            )
+        })
+    }
+}
+extern "system" fn cmd_set_viewport_shading_rate_palette_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_shading_rate_palettes: *const ShadingRatePaletteNV,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_set_viewport_shading_rate_palette_nv(
+                    &this.dispatch,
+                    command_buffer,
+                    first_viewport,
+                    viewport_count,
+                    p_shading_rate_palettes,
+                )
+        })
+    }
+}
+extern "system" fn cmd_set_viewport_w_scaling_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_w_scalings: *const ViewportWScalingNV,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_set_viewport_w_scaling_nv(
+                &this.dispatch,
+                command_buffer,
+                first_viewport,
+                viewport_count,
+                p_viewport_w_scalings,
+            )
+        })
+    }
+}
+extern "system" fn cmd_trace_rays_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    raygen_shader_binding_table_buffer: Buffer,
+    raygen_shader_binding_offset: DeviceSize,
+    miss_shader_binding_table_buffer: Buffer,
+    miss_shader_binding_offset: DeviceSize,
+    miss_shader_binding_stride: DeviceSize,
+    hit_shader_binding_table_buffer: Buffer,
+    hit_shader_binding_offset: DeviceSize,
+    hit_shader_binding_stride: DeviceSize,
+    callable_shader_binding_table_buffer: Buffer,
+    callable_shader_binding_offset: DeviceSize,
+    callable_shader_binding_stride: DeviceSize,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_trace_rays_nv(
+                &this.dispatch,
+                command_buffer,
+                raygen_shader_binding_table_buffer,
+                raygen_shader_binding_offset,
+                miss_shader_binding_table_buffer,
+                miss_shader_binding_offset,
+                miss_shader_binding_stride,
+                hit_shader_binding_table_buffer,
+                hit_shader_binding_offset,
+                hit_shader_binding_stride,
+                callable_shader_binding_table_buffer,
+                callable_shader_binding_offset,
+                callable_shader_binding_stride,
+                width,
+                height,
+                depth,
+            )
+        })
+    }
+}
+extern "system" fn cmd_write_acceleration_structures_properties_nv<L: Layer>(
+    command_buffer: CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const AccelerationStructureNV,
+    query_type: QueryType,
+    query_pool: QueryPool,
+    first_query: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .cmd_write_acceleration_structures_properties_nv(
+                    &this.dispatch,
+                    command_buffer,
+                    acceleration_structure_count,
+                    p_acceleration_structures,
+                    query_type,
+                    query_pool,
+                    first_query,
+                )
+        })
+    }
+}
+extern "system" fn cmd_write_buffer_marker_amd<L: Layer>(
+    command_buffer: CommandBuffer,
+    pipeline_stage: PipelineStageFlags,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    marker: u32,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(command_buffer) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.cmd_write_buffer_marker_amd(
+                &this.dispatch,
+                command_buffer,
+                pipeline_stage,
+                dst_buffer,
+                dst_offset,
+                marker,
+            )
+        })
+    }
+}
+extern "system" fn compile_deferred_nv<L: Layer>(
+    device: Device,
+    pipeline: Pipeline,
+    shader: u32,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .compile_deferred_nv(&this.dispatch, device, pipeline, shader)
+        })
+    }
+}
+extern "system" fn create_acceleration_structure_nv<L: Layer>(
+    device: Device,
+    p_create_info: *const AccelerationStructureCreateInfoNV,
+    p_allocator: *const AllocationCallbacks,
+    p_acceleration_structure: *mut AccelerationStructureNV,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_acceleration_structure_nv(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_acceleration_structure,
+            )
+        })
+    }
+}
+extern "system" fn create_android_surface_khr<L: Layer>(
+    instance: Instance,
+    p_create_info: *const AndroidSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_android_surface_khr(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_debug_report_callback_ext<L: Layer>(
+    instance: Instance,
+    p_create_info: *const DebugReportCallbackCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_callback: *mut DebugReportCallbackEXT,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_debug_report_callback_ext(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_callback,
+            )
+        })
+    }
+}
+extern "system" fn create_debug_utils_messenger_ext<L: Layer>(
+    instance: Instance,
+    p_create_info: *const DebugUtilsMessengerCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_messenger: *mut DebugUtilsMessengerEXT,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_debug_utils_messenger_ext(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_messenger,
+            )
+        })
+    }
+}
+extern "system" fn create_descriptor_update_template<L: Layer>(
+    device: Device,
+    p_create_info: *const DescriptorUpdateTemplateCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_descriptor_update_template: *mut DescriptorUpdateTemplate,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_descriptor_update_template(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_descriptor_update_template,
+            )
+        })
+    }
+}
+extern "system" fn create_display_mode_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_create_info: *const DisplayModeCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_mode: *mut DisplayModeKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_display_mode_khr(
+                &this.dispatch,
+                physical_device,
+                display,
+                p_create_info,
+                p_allocator,
+                p_mode,
+            )
+        })
+    }
+}
+extern "system" fn create_display_plane_surface_khr<L: Layer>(
+    instance: Instance,
+    p_create_info: *const DisplaySurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_display_plane_surface_khr(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_ios_surface_mvk<L: Layer>(
+    instance: Instance,
+    p_create_info: *const IOSSurfaceCreateInfoMVK,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_ios_surface_mvk(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_image_pipe_surface_fuchsia<L: Layer>(
+    instance: Instance,
+    p_create_info: *const ImagePipeSurfaceCreateInfoFUCHSIA,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_image_pipe_surface_fuchsia(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_indirect_commands_layout_nvx<L: Layer>(
+    device: Device,
+    p_create_info: *const IndirectCommandsLayoutCreateInfoNVX,
+    p_allocator: *const AllocationCallbacks,
+    p_indirect_commands_layout: *mut IndirectCommandsLayoutNVX,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_indirect_commands_layout_nvx(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_indirect_commands_layout,
+            )
+        })
+    }
+}
+extern "system" fn create_mac_os_surface_mvk<L: Layer>(
+    instance: Instance,
+    p_create_info: *const MacOSSurfaceCreateInfoMVK,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_mac_os_surface_mvk(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_object_table_nvx<L: Layer>(
+    device: Device,
+    p_create_info: *const ObjectTableCreateInfoNVX,
+    p_allocator: *const AllocationCallbacks,
+    p_object_table: *mut ObjectTableNVX,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_object_table_nvx(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_object_table,
+            )
+        })
+    }
+}
+extern "system" fn create_ray_tracing_pipelines_nv<L: Layer>(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const RayTracingPipelineCreateInfoNV,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_ray_tracing_pipelines_nv(
+                &this.dispatch,
+                device,
+                pipeline_cache,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_pipelines,
+            )
+        })
+    }
+}
+extern "system" fn create_render_pass2_khr<L: Layer>(
+    device: Device,
+    p_create_info: *const RenderPassCreateInfo2KHR,
+    p_allocator: *const AllocationCallbacks,
+    p_render_pass: *mut RenderPass,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_render_pass2_khr(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_render_pass,
+            )
+        })
+    }
+}
+extern "system" fn create_sampler_ycbcr_conversion<L: Layer>(
+    device: Device,
+    p_create_info: *const SamplerYcbcrConversionCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_ycbcr_conversion: *mut SamplerYcbcrConversion,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_sampler_ycbcr_conversion(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_ycbcr_conversion,
+            )
+        })
+    }
+}
+extern "system" fn create_shared_swapchains_khr<L: Layer>(
+    device: Device,
+    swapchain_count: u32,
+    p_create_infos: *const SwapchainCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_swapchains: *mut SwapchainKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_shared_swapchains_khr(
+                &this.dispatch,
+                device,
+                swapchain_count,
+                p_create_infos,
+                p_allocator,
+                p_swapchains,
+            )
+        })
+    }
+}
+extern "system" fn create_swapchain_khr<L: Layer>(
+    device: Device,
+    p_create_info: *const SwapchainCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_swapchain: *mut SwapchainKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_swapchain_khr(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_swapchain,
+            )
+        })
+    }
+}
+extern "system" fn create_validation_cache_ext<L: Layer>(
+    device: Device,
+    p_create_info: *const ValidationCacheCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_validation_cache: *mut ValidationCacheEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.create_validation_cache_ext(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_allocator,
+                p_validation_cache,
+            )
+        })
+    }
+}
+extern "system" fn create_vi_surface_nn<L: Layer>(
+    instance: Instance,
+    p_create_info: *const ViSurfaceCreateInfoNN,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_vi_surface_nn(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_wayland_surface_khr<L: Layer>(
+    instance: Instance,
+    p_create_info: *const WaylandSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_wayland_surface_khr(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_win32_surface_khr<L: Layer>(
+    instance: Instance,
+    p_create_info: *const Win32SurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_win32_surface_khr(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_xcb_surface_khr<L: Layer>(
+    instance: Instance,
+    p_create_info: *const XcbSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_xcb_surface_khr(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn create_xlib_surface_khr<L: Layer>(
+    instance: Instance,
+    p_create_info: *const XlibSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.create_xlib_surface_khr(
+                &this.dispatch,
+                instance,
+                p_create_info,
+                p_allocator,
+                p_surface,
+            )
+        })
+    }
+}
+extern "system" fn debug_marker_set_object_name_ext<L: Layer>(
+    device: Device,
+    p_name_info: *const DebugMarkerObjectNameInfoEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.debug_marker_set_object_name_ext(
+                &this.dispatch,
+                device,
+                p_name_info,
+            )
+        })
+    }
+}
+extern "system" fn debug_marker_set_object_tag_ext<L: Layer>(
+    device: Device,
+    p_tag_info: *const DebugMarkerObjectTagInfoEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .debug_marker_set_object_tag_ext(&this.dispatch, device, p_tag_info)
+        })
+    }
+}
+extern "system" fn debug_report_message_ext<L: Layer>(
+    instance: Instance,
+    flags: DebugReportFlagsEXT,
+    object_type: DebugReportObjectTypeEXT,
+    object: u64,
+    location: usize,
+    message_code: i32,
+    p_layer_prefix: *const c_char,
+    p_message: *const c_char,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.debug_report_message_ext(
+                &this.dispatch,
+                instance,
+                flags,
+                object_type,
+                object,
+                location,
+                message_code,
+                p_layer_prefix,
+                p_message,
+            )
+        })
+    }
+}
+extern "system" fn destroy_acceleration_structure_nv<L: Layer>(
+    device: Device,
+    acceleration_structure: AccelerationStructureNV,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_acceleration_structure_nv(
+                &this.dispatch,
+                device,
+                acceleration_structure,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn destroy_debug_report_callback_ext<L: Layer>(
+    instance: Instance,
+    callback: DebugReportCallbackEXT,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.destroy_debug_report_callback_ext(
+                &this.dispatch,
+                instance,
+                callback,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn destroy_debug_utils_messenger_ext<L: Layer>(
+    instance: Instance,
+    messenger: DebugUtilsMessengerEXT,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.destroy_debug_utils_messenger_ext(
+                &this.dispatch,
+                instance,
+                messenger,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn destroy_descriptor_update_template<L: Layer>(
+    device: Device,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_descriptor_update_template(
+                &this.dispatch,
+                device,
+                descriptor_update_template,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn destroy_indirect_commands_layout_nvx<L: Layer>(
+    device: Device,
+    indirect_commands_layout: IndirectCommandsLayoutNVX,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_indirect_commands_layout_nvx(
+                &this.dispatch,
+                device,
+                indirect_commands_layout,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn destroy_object_table_nvx<L: Layer>(
+    device: Device,
+    object_table: ObjectTableNVX,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_object_table_nvx(
+                &this.dispatch,
+                device,
+                object_table,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn destroy_sampler_ycbcr_conversion<L: Layer>(
+    device: Device,
+    ycbcr_conversion: SamplerYcbcrConversion,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_sampler_ycbcr_conversion(
+                &this.dispatch,
+                device,
+                ycbcr_conversion,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn destroy_surface_khr<L: Layer>(
+    instance: Instance,
+    surface: SurfaceKHR,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .destroy_surface_khr(&this.dispatch, instance, surface, p_allocator)
+        })
+    }
+}
+extern "system" fn destroy_swapchain_khr<L: Layer>(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_swapchain_khr(
+                &this.dispatch,
+                device,
+                swapchain,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn destroy_validation_cache_ext<L: Layer>(
+    device: Device,
+    validation_cache: ValidationCacheEXT,
+    p_allocator: *const AllocationCallbacks,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.destroy_validation_cache_ext(
+                &this.dispatch,
+                device,
+                validation_cache,
+                p_allocator,
+            )
+        })
+    }
+}
+extern "system" fn display_power_control_ext<L: Layer>(
+    device: Device,
+    display: DisplayKHR,
+    p_display_power_info: *const DisplayPowerInfoEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.display_power_control_ext(
+                &this.dispatch,
+                device,
+                display,
+                p_display_power_info,
+            )
+        })
+    }
+}
+extern "system" fn enumerate_physical_device_groups<L: Layer>(
+    instance: Instance,
+    p_physical_device_group_count: *mut u32,
+    p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.enumerate_physical_device_groups(
+                &this.dispatch,
+                instance,
+                p_physical_device_group_count,
+                p_physical_device_group_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_acceleration_structure_handle_nv<L: Layer>(
+    device: Device,
+    acceleration_structure: AccelerationStructureNV,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_acceleration_structure_handle_nv(
+                &this.dispatch,
+                device,
+                acceleration_structure,
+                data_size,
+                p_data,
+            )
+        })
+    }
+}
+extern "system" fn get_acceleration_structure_memory_requirements_nv<L: Layer>(
+    device: Device,
+    p_info: *const AccelerationStructureMemoryRequirementsInfoNV,
+    p_memory_requirements: *mut MemoryRequirements2KHR,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_acceleration_structure_memory_requirements_nv(
+                    &this.dispatch,
+                    device,
+                    p_info,
+                    p_memory_requirements,
+                )
+        })
+    }
+}
+extern "system" fn get_android_hardware_buffer_properties_android<L: Layer>(
+    device: Device,
+    buffer: *const AHardwareBuffer,
+    p_properties: *mut AndroidHardwareBufferPropertiesANDROID,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_android_hardware_buffer_properties_android(
+                    &this.dispatch,
+                    device,
+                    buffer,
+                    p_properties,
+                )
+        })
+    }
+}
+extern "system" fn get_buffer_memory_requirements2<L: Layer>(
+    device: Device,
+    p_info: *const BufferMemoryRequirementsInfo2,
+    p_memory_requirements: *mut MemoryRequirements2,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_buffer_memory_requirements2(
+                &this.dispatch,
+                device,
+                p_info,
+                p_memory_requirements,
+            )
+        })
+    }
+}
+extern "system" fn get_calibrated_timestamps_ext<L: Layer>(
+    device: Device,
+    timestamp_count: u32,
+    p_timestamp_infos: *const CalibratedTimestampInfoEXT,
+    p_timestamps: *mut u64,
+    p_max_deviation: *mut u64,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_calibrated_timestamps_ext(
+                &this.dispatch,
+                device,
+                timestamp_count,
+                p_timestamp_infos,
+                p_timestamps,
+                p_max_deviation,
+            )
+        })
+    }
+}
+extern "system" fn get_descriptor_set_layout_support<L: Layer>(
+    device: Device,
+    p_create_info: *const DescriptorSetLayoutCreateInfo,
+    p_support: *mut DescriptorSetLayoutSupport,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_descriptor_set_layout_support(
+                &this.dispatch,
+                device,
+                p_create_info,
+                p_support,
+            )
+        })
+    }
+}
+extern "system" fn get_device_group_peer_memory_features<L: Layer>(
+    device: Device,
+    heap_index: u32,
+    local_device_index: u32,
+    remote_device_index: u32,
+    p_peer_memory_features: *mut PeerMemoryFeatureFlags,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_device_group_peer_memory_features(
+                &this.dispatch,
+                device,
+                heap_index,
+                local_device_index,
+                remote_device_index,
+                p_peer_memory_features,
+            )
+        })
+    }
+}
+extern "system" fn get_device_group_present_capabilities_khr<L: Layer>(
+    device: Device,
+    p_device_group_present_capabilities: *mut DeviceGroupPresentCapabilitiesKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_device_group_present_capabilities_khr(
+                    &this.dispatch,
+                    device,
+                    p_device_group_present_capabilities,
+                )
+        })
+    }
+}
+extern "system" fn get_device_group_surface_present_modes_khr<L: Layer>(
+    device: Device,
+    surface: SurfaceKHR,
+    p_modes: *mut DeviceGroupPresentModeFlagsKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_device_group_surface_present_modes_khr(
+                    &this.dispatch,
+                    device,
+                    surface,
+                    p_modes,
+                )
+        })
+    }
+}
+extern "system" fn get_device_queue2<L: Layer>(
+    device: Device,
+    p_queue_info: *const DeviceQueueInfo2,
+    p_queue: *mut Queue,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_device_queue2(&this.dispatch, device, p_queue_info, p_queue)
+        })
+    }
+}
+extern "system" fn get_display_mode_properties2_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayModeProperties2KHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_display_mode_properties2_khr(
+                &this.dispatch,
+                physical_device,
+                display,
+                p_property_count,
+                p_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_display_mode_properties_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayModePropertiesKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_display_mode_properties_khr(
+                &this.dispatch,
+                physical_device,
+                display,
+                p_property_count,
+                p_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_display_plane_capabilities2_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_display_plane_info: *const DisplayPlaneInfo2KHR,
+    p_capabilities: *mut DisplayPlaneCapabilities2KHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_display_plane_capabilities2_khr(
+                &this.dispatch,
+                physical_device,
+                p_display_plane_info,
+                p_capabilities,
+            )
+        })
+    }
+}
+extern "system" fn get_display_plane_capabilities_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    mode: DisplayModeKHR,
+    plane_index: u32,
+    p_capabilities: *mut DisplayPlaneCapabilitiesKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_display_plane_capabilities_khr(
+                &this.dispatch,
+                physical_device,
+                mode,
+                plane_index,
+                p_capabilities,
+            )
+        })
+    }
+}
+extern "system" fn get_display_plane_supported_displays_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    plane_index: u32,
+    p_display_count: *mut u32,
+    p_displays: *mut DisplayKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_display_plane_supported_displays_khr(
+                &this.dispatch,
+                physical_device,
+                plane_index,
+                p_display_count,
+                p_displays,
+            )
+        })
+    }
+}
+extern "system" fn get_fence_fd_khr<L: Layer>(
+    device: Device,
+    p_get_fd_info: *const FenceGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_fence_fd_khr(&this.dispatch, device, p_get_fd_info, p_fd)
+        })
+    }
+}
+extern "system" fn get_fence_win32_handle_khr<L: Layer>(
+    device: Device,
+    p_get_win32_handle_info: *const FenceGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_fence_win32_handle_khr(
+                &this.dispatch,
+                device,
+                p_get_win32_handle_info,
+                p_handle,
+            )
+        })
+    }
+}
+extern "system" fn get_image_drm_format_modifier_properties_ext<L: Layer>(
+    device: Device,
+    image: Image,
+    p_properties: *mut ImageDrmFormatModifierPropertiesEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_image_drm_format_modifier_properties_ext(
+                    &this.dispatch,
+                    device,
+                    image,
+                    p_properties,
+                )
+        })
+    }
+}
+extern "system" fn get_image_memory_requirements2<L: Layer>(
+    device: Device,
+    p_info: *const ImageMemoryRequirementsInfo2,
+    p_memory_requirements: *mut MemoryRequirements2,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_image_memory_requirements2(
+                &this.dispatch,
+                device,
+                p_info,
+                p_memory_requirements,
+            )
+        })
+    }
+}
+extern "system" fn get_image_sparse_memory_requirements2<L: Layer>(
+    device: Device,
+    p_info: *const ImageSparseMemoryRequirementsInfo2,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_image_sparse_memory_requirements2(
+                &this.dispatch,
+                device,
+                p_info,
+                p_sparse_memory_requirement_count,
+                p_sparse_memory_requirements,
+            )
+        })
+    }
+}
+extern "system" fn get_memory_android_hardware_buffer_android<L: Layer>(
+    device: Device,
+    p_info: *const MemoryGetAndroidHardwareBufferInfoANDROID,
+    p_buffer: *mut *mut AHardwareBuffer,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_memory_android_hardware_buffer_android(
+                    &this.dispatch,
+                    device,
+                    p_info,
+                    p_buffer,
+                )
+        })
+    }
+}
+extern "system" fn get_memory_fd_khr<L: Layer>(
+    device: Device,
+    p_get_fd_info: *const MemoryGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_memory_fd_khr(&this.dispatch, device, p_get_fd_info, p_fd)
+        })
+    }
+}
+extern "system" fn get_memory_fd_properties_khr<L: Layer>(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    fd: c_int,
+    p_memory_fd_properties: *mut MemoryFdPropertiesKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_memory_fd_properties_khr(
+                &this.dispatch,
+                device,
+                handle_type,
+                fd,
+                p_memory_fd_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_memory_host_pointer_properties_ext<L: Layer>(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    p_host_pointer: *const c_void,
+    p_memory_host_pointer_properties: *mut MemoryHostPointerPropertiesEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_memory_host_pointer_properties_ext(
+                &this.dispatch,
+                device,
+                handle_type,
+                p_host_pointer,
+                p_memory_host_pointer_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_memory_win32_handle_khr<L: Layer>(
+    device: Device,
+    p_get_win32_handle_info: *const MemoryGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_memory_win32_handle_khr(
+                &this.dispatch,
+                device,
+                p_get_win32_handle_info,
+                p_handle,
+            )
+        })
+    }
+}
+extern "system" fn get_memory_win32_handle_nv<L: Layer>(
+    device: Device,
+    memory: DeviceMemory,
+    handle_type: ExternalMemoryHandleTypeFlagsNV,
+    p_handle: *mut HANDLE,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_memory_win32_handle_nv(
+                &this.dispatch,
+                device,
+                memory,
+                handle_type,
+                p_handle,
+            )
+        })
+    }
+}
+extern "system" fn get_memory_win32_handle_properties_khr<L: Layer>(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    handle: HANDLE,
+    p_memory_win32_handle_properties: *mut MemoryWin32HandlePropertiesKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_memory_win32_handle_properties_khr(
+                &this.dispatch,
+                device,
+                handle_type,
+                handle,
+                p_memory_win32_handle_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_past_presentation_timing_google<L: Layer>(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_presentation_timing_count: *mut u32,
+    p_presentation_timings: *mut PastPresentationTimingGOOGLE,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_past_presentation_timing_google(
+                &this.dispatch,
+                device,
+                swapchain,
+                p_presentation_timing_count,
+                p_presentation_timings,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_calibrateable_time_domains_ext<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_time_domain_count: *mut u32,
+    p_time_domains: *mut TimeDomainEXT,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_calibrateable_time_domains_ext(
+                    &this.dispatch,
+                    physical_device,
+                    p_time_domain_count,
+                    p_time_domains,
+                )
+        })
+    }
+}
+extern "system" fn get_physical_device_display_plane_properties2_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPlaneProperties2KHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_display_plane_properties2_khr(
+                    &this.dispatch,
+                    physical_device,
+                    p_property_count,
+                    p_properties,
+                )
+        })
+    }
+}
+extern "system" fn get_physical_device_display_plane_properties_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPlanePropertiesKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_display_plane_properties_khr(
+                &this.dispatch,
+                physical_device,
+                p_property_count,
+                p_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_display_properties2_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayProperties2KHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_display_properties2_khr(
+                &this.dispatch,
+                physical_device,
+                p_property_count,
+                p_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_display_properties_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPropertiesKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_display_properties_khr(
+                &this.dispatch,
+                physical_device,
+                p_property_count,
+                p_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_external_buffer_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+    p_external_buffer_properties: *mut ExternalBufferProperties,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_external_buffer_properties(
+                &this.dispatch,
+                physical_device,
+                p_external_buffer_info,
+                p_external_buffer_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_external_fence_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+    p_external_fence_properties: *mut ExternalFenceProperties,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_external_fence_properties(
+                &this.dispatch,
+                physical_device,
+                p_external_fence_info,
+                p_external_fence_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_external_image_format_properties_nv<L: Layer>(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    tiling: ImageTiling,
+    usage: ImageUsageFlags,
+    flags: ImageCreateFlags,
+    external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+    p_external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_external_image_format_properties_nv(
+                    &this.dispatch,
+                    physical_device,
+                    format,
+                    ty,
+                    tiling,
+                    usage,
+                    flags,
+                    external_handle_type,
+                    p_external_image_format_properties,
+                )
+        })
+    }
+}
+extern "system" fn get_physical_device_external_semaphore_properties<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+    p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_external_semaphore_properties(
+                    &this.dispatch,
+                    physical_device,
+                    p_external_semaphore_info,
+                    p_external_semaphore_properties,
+                )
+        })
+    }
+}
+extern "system" fn get_physical_device_features2<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_features: *mut PhysicalDeviceFeatures2,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_features2(&this.dispatch, physical_device, p_features)
+        })
+    }
+}
+extern "system" fn get_physical_device_format_properties2<L: Layer>(
+    physical_device: PhysicalDevice,
+    format: Format,
+    p_format_properties: *mut FormatProperties2,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_format_properties2(
+                &this.dispatch,
+                physical_device,
+                format,
+                p_format_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_generated_commands_properties_nvx<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_features: *mut DeviceGeneratedCommandsFeaturesNVX,
+    p_limits: *mut DeviceGeneratedCommandsLimitsNVX,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_generated_commands_properties_nvx(
+                    &this.dispatch,
+                    physical_device,
+                    p_features,
+                    p_limits,
+                )
+        })
+    }
+}
+extern "system" fn get_physical_device_image_format_properties2<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+    p_image_format_properties: *mut ImageFormatProperties2,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_image_format_properties2(
+                &this.dispatch,
+                physical_device,
+                p_image_format_info,
+                p_image_format_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_memory_properties2<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_memory_properties2(
+                &this.dispatch,
+                physical_device,
+                p_memory_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_multisample_properties_ext<L: Layer>(
+    physical_device: PhysicalDevice,
+    samples: SampleCountFlags,
+    p_multisample_properties: *mut MultisamplePropertiesEXT,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_multisample_properties_ext(
+                &this.dispatch,
+                physical_device,
+                samples,
+                p_multisample_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_present_rectangles_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_rect_count: *mut u32,
+    p_rects: *mut Rect2D,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_present_rectangles_khr(
+                &this.dispatch,
+                physical_device,
+                surface,
+                p_rect_count,
+                p_rects,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_properties2<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_properties: *mut PhysicalDeviceProperties2,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_properties2(
+                &this.dispatch,
+                physical_device,
+                p_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_queue_family_properties2<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut QueueFamilyProperties2,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_queue_family_properties2(
+                &this.dispatch,
+                physical_device,
+                p_queue_family_property_count,
+                p_queue_family_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_sparse_image_format_properties2<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+    p_property_count: *mut u32,
+    p_properties: *mut SparseImageFormatProperties2,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_sparse_image_format_properties2(
+                    &this.dispatch,
+                    physical_device,
+                    p_format_info,
+                    p_property_count,
+                    p_properties,
+                )
+        })
+    }
+}
+extern "system" fn get_physical_device_surface_capabilities2_ext<L: Layer>(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_capabilities: *mut SurfaceCapabilities2EXT,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_surface_capabilities2_ext(
+                &this.dispatch,
+                physical_device,
+                surface,
+                p_surface_capabilities,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_surface_capabilities2_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    p_surface_capabilities: *mut SurfaceCapabilities2KHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_surface_capabilities2_khr(
+                &this.dispatch,
+                physical_device,
+                p_surface_info,
+                p_surface_capabilities,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_surface_capabilities_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_capabilities: *mut SurfaceCapabilitiesKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_surface_capabilities_khr(
+                &this.dispatch,
+                physical_device,
+                surface,
+                p_surface_capabilities,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_surface_formats2_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut SurfaceFormat2KHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_surface_formats2_khr(
+                &this.dispatch,
+                physical_device,
+                p_surface_info,
+                p_surface_format_count,
+                p_surface_formats,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_surface_formats_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut SurfaceFormatKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_surface_formats_khr(
+                &this.dispatch,
+                physical_device,
+                surface,
+                p_surface_format_count,
+                p_surface_formats,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_surface_present_modes_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut PresentModeKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_surface_present_modes_khr(
+                &this.dispatch,
+                physical_device,
+                surface,
+                p_present_mode_count,
+                p_present_modes,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_surface_support_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    surface: SurfaceKHR,
+    p_supported: *mut Bool32,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_surface_support_khr(
+                &this.dispatch,
+                physical_device,
+                queue_family_index,
+                surface,
+                p_supported,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_wayland_presentation_support_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    display: *mut wl_display,
+) -> Bool32 {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_wayland_presentation_support_khr(
+                    &this.dispatch,
+                    physical_device,
+                    queue_family_index,
+                    display,
+                )
+        })
+    }
+}
+extern "system" fn get_physical_device_win32_presentation_support_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+) -> Bool32 {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_win32_presentation_support_khr(
+                    &this.dispatch,
+                    physical_device,
+                    queue_family_index,
+                )
+        })
+    }
+}
+extern "system" fn get_physical_device_xcb_presentation_support_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    connection: *mut xcb_connection_t,
+    visual_id: xcb_visualid_t,
+) -> Bool32 {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_physical_device_xcb_presentation_support_khr(
+                &this.dispatch,
+                physical_device,
+                queue_family_index,
+                connection,
+                visual_id,
+            )
+        })
+    }
+}
+extern "system" fn get_physical_device_xlib_presentation_support_khr<L: Layer>(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    dpy: *mut Display,
+    visual_id: VisualID,
+) -> Bool32 {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .get_physical_device_xlib_presentation_support_khr(
+                    &this.dispatch,
+                    physical_device,
+                    queue_family_index,
+                    dpy,
+                    visual_id,
+                )
+        })
+    }
+}
+extern "system" fn get_queue_checkpoint_data_nv<L: Layer>(
+    queue: Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut CheckpointDataNV,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_queue_checkpoint_data_nv(
+                &this.dispatch,
+                queue,
+                p_checkpoint_data_count,
+                p_checkpoint_data,
+            )
+        })
+    }
+}
+extern "system" fn get_rand_r_output_display_ext<L: Layer>(
+    physical_device: PhysicalDevice,
+    dpy: *mut Display,
+    rr_output: RROutput,
+    p_display: *mut DisplayKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.get_rand_r_output_display_ext(
+                &this.dispatch,
+                physical_device,
+                dpy,
+                rr_output,
+                p_display,
+            )
+        })
+    }
+}
+extern "system" fn get_ray_tracing_shader_group_handles_nv<L: Layer>(
+    device: Device,
+    pipeline: Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_ray_tracing_shader_group_handles_nv(
+                &this.dispatch,
+                device,
+                pipeline,
+                first_group,
+                group_count,
+                data_size,
+                p_data,
+            )
+        })
+    }
+}
+extern "system" fn get_refresh_cycle_duration_google<L: Layer>(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_display_timing_properties: *mut RefreshCycleDurationGOOGLE,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_refresh_cycle_duration_google(
+                &this.dispatch,
+                device,
+                swapchain,
+                p_display_timing_properties,
+            )
+        })
+    }
+}
+extern "system" fn get_semaphore_fd_khr<L: Layer>(
+    device: Device,
+    p_get_fd_info: *const SemaphoreGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_semaphore_fd_khr(&this.dispatch, device, p_get_fd_info, p_fd)
+        })
+    }
+}
+extern "system" fn get_semaphore_win32_handle_khr<L: Layer>(
+    device: Device,
+    p_get_win32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_semaphore_win32_handle_khr(
+                &this.dispatch,
+                device,
+                p_get_win32_handle_info,
+                p_handle,
+            )
+        })
+    }
+}
+extern "system" fn get_shader_info_amd<L: Layer>(
+    device: Device,
+    pipeline: Pipeline,
+    shader_stage: ShaderStageFlags,
+    info_type: ShaderInfoTypeAMD,
+    p_info_size: *mut usize,
+    p_info: *mut c_void,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_shader_info_amd(
+                &this.dispatch,
+                device,
+                pipeline,
+                shader_stage,
+                info_type,
+                p_info_size,
+                p_info,
+            )
+        })
+    }
+}
+extern "system" fn get_swapchain_counter_ext<L: Layer>(
+    device: Device,
+    swapchain: SwapchainKHR,
+    counter: SurfaceCounterFlagsEXT,
+    p_counter_value: *mut u64,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_swapchain_counter_ext(
+                &this.dispatch,
+                device,
+                swapchain,
+                counter,
+                p_counter_value,
+            )
+        })
+    }
+}
+extern "system" fn get_swapchain_gralloc_usage_android<L: Layer>(
+    device: Device,
+    format: Format,
+    image_usage: ImageUsageFlags,
+    gralloc_usage: *mut c_int,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_swapchain_gralloc_usage_android(
+                &this.dispatch,
+                device,
+                format,
+                image_usage,
+                gralloc_usage,
+            )
+        })
+    }
+}
+extern "system" fn get_swapchain_images_khr<L: Layer>(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_swapchain_image_count: *mut u32,
+    p_swapchain_images: *mut Image,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_swapchain_images_khr(
+                &this.dispatch,
+                device,
+                swapchain,
+                p_swapchain_image_count,
+                p_swapchain_images,
+            )
+        })
+    }
+}
+extern "system" fn get_swapchain_status_khr<L: Layer>(
+    device: Device,
+    swapchain: SwapchainKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .get_swapchain_status_khr(&this.dispatch, device, swapchain)
+        })
+    }
+}
+extern "system" fn get_validation_cache_data_ext<L: Layer>(
+    device: Device,
+    validation_cache: ValidationCacheEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.get_validation_cache_data_ext(
+                &this.dispatch,
+                device,
+                validation_cache,
+                p_data_size,
+                p_data,
+            )
+        })
+    }
+}
+extern "system" fn import_fence_fd_khr<L: Layer>(
+    device: Device,
+    p_import_fence_fd_info: *const ImportFenceFdInfoKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .import_fence_fd_khr(&this.dispatch, device, p_import_fence_fd_info)
+        })
+    }
+}
+extern "system" fn import_fence_win32_handle_khr<L: Layer>(
+    device: Device,
+    p_import_fence_win32_handle_info: *const ImportFenceWin32HandleInfoKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.import_fence_win32_handle_khr(
+                &this.dispatch,
+                device,
+                p_import_fence_win32_handle_info,
+            )
+        })
+    }
+}
+extern "system" fn import_semaphore_fd_khr<L: Layer>(
+    device: Device,
+    p_import_semaphore_fd_info: *const ImportSemaphoreFdInfoKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.import_semaphore_fd_khr(
+                &this.dispatch,
+                device,
+                p_import_semaphore_fd_info,
+            )
+        })
+    }
+}
+extern "system" fn import_semaphore_win32_handle_khr<L: Layer>(
+    device: Device,
+    p_import_semaphore_win32_handle_info: *const ImportSemaphoreWin32HandleInfoKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.import_semaphore_win32_handle_khr(
+                &this.dispatch,
+                device,
+                p_import_semaphore_win32_handle_info,
+            )
+        })
+    }
+}
+extern "system" fn merge_validation_caches_ext<L: Layer>(
+    device: Device,
+    dst_cache: ValidationCacheEXT,
+    src_cache_count: u32,
+    p_src_caches: *const ValidationCacheEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.merge_validation_caches_ext(
+                &this.dispatch,
+                device,
+                dst_cache,
+                src_cache_count,
+                p_src_caches,
+            )
+        })
+    }
+}
+extern "system" fn queue_begin_debug_utils_label_ext<L: Layer>(
+    queue: Queue,
+    p_label_info: *const DebugUtilsLabelEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.queue_begin_debug_utils_label_ext(
+                &this.dispatch,
+                queue,
+                p_label_info,
+            )
+        })
+    }
+}
+extern "system" fn queue_end_debug_utils_label_ext<L: Layer>(queue: Queue) {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .queue_end_debug_utils_label_ext(&this.dispatch, queue)
+        })
+    }
+}
+extern "system" fn queue_insert_debug_utils_label_ext<L: Layer>(
+    queue: Queue,
+    p_label_info: *const DebugUtilsLabelEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.queue_insert_debug_utils_label_ext(
+                &this.dispatch,
+                queue,
+                p_label_info,
+            )
+        })
+    }
+}
+extern "system" fn queue_present_khr<L: Layer>(
+    queue: Queue,
+    p_present_info: *const PresentInfoKHR,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .queue_present_khr(&this.dispatch, queue, p_present_info)
+        })
+    }
+}
+extern "system" fn queue_signal_release_image_android<L: Layer>(
+    queue: Queue,
+    wait_semaphore_count: u32,
+    p_wait_semaphores: *const Semaphore,
+    image: Image,
+    p_native_fence_fd: *mut c_int,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(queue) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.queue_signal_release_image_android(
+                &this.dispatch,
+                queue,
+                wait_semaphore_count,
+                p_wait_semaphores,
+                image,
+                p_native_fence_fd,
+            )
+        })
+    }
+}
+extern "system" fn register_device_event_ext<L: Layer>(
+    device: Device,
+    p_device_event_info: *const DeviceEventInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.register_device_event_ext(
+                &this.dispatch,
+                device,
+                p_device_event_info,
+                p_allocator,
+                p_fence,
+            )
+        })
+    }
+}
+extern "system" fn register_display_event_ext<L: Layer>(
+    device: Device,
+    display: DisplayKHR,
+    p_display_event_info: *const DisplayEventInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.register_display_event_ext(
+                &this.dispatch,
+                device,
+                display,
+                p_display_event_info,
+                p_allocator,
+                p_fence,
+            )
+        })
+    }
+}
+extern "system" fn register_objects_nvx<L: Layer>(
+    device: Device,
+    object_table: ObjectTableNVX,
+    object_count: u32,
+    pp_object_table_entries: *const *const ObjectTableEntryNVX,
+    p_object_indices: *const u32,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.register_objects_nvx(
+                &this.dispatch,
+                device,
+                object_table,
+                object_count,
+                pp_object_table_entries,
+                p_object_indices,
+            )
+        })
+    }
+}
+extern "system" fn release_display_ext<L: Layer>(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+) -> Result {
+    unsafe {
+        let this = match instance_data::<L, _>(physical_device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer
+                .release_display_ext(&this.dispatch, physical_device, display)
+        })
+    }
+}
+extern "system" fn set_debug_utils_object_name_ext<L: Layer>(
+    device: Device,
+    p_name_info: *const DebugUtilsObjectNameInfoEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .set_debug_utils_object_name_ext(&this.dispatch, device, p_name_info)
+        })
+    }
+}
+extern "system" fn set_debug_utils_object_tag_ext<L: Layer>(
+    device: Device,
+    p_tag_info: *const DebugUtilsObjectTagInfoEXT,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .set_debug_utils_object_tag_ext(&this.dispatch, device, p_tag_info)
+        })
+    }
+}
+extern "system" fn set_hdr_metadata_ext<L: Layer>(
+    device: Device,
+    swapchain_count: u32,
+    p_swapchains: *const SwapchainKHR,
+    p_metadata: *const HdrMetadataEXT,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.set_hdr_metadata_ext(
+                &this.dispatch,
+                device,
+                swapchain_count,
+                p_swapchains,
+                p_metadata,
+            )
+        })
+    }
+}
+extern "system" fn submit_debug_utils_message_ext<L: Layer>(
+    instance: Instance,
+    message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    message_types: DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+) {
+    unsafe {
+        let this = match instance_data::<L, _>(instance) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.layer.submit_debug_utils_message_ext(
+                &this.dispatch,
+                instance,
+                message_severity,
+                message_types,
+                p_callback_data,
+            )
+        })
+    }
+}
+extern "system" fn trim_command_pool<L: Layer>(
+    device: Device,
+    command_pool: CommandPool,
+    flags: CommandPoolTrimFlags,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance
+                .layer
+                .trim_command_pool(&this.dispatch, device, command_pool, flags)
+        })
+    }
+}
+extern "system" fn unregister_objects_nvx<L: Layer>(
+    device: Device,
+    object_table: ObjectTableNVX,
+    object_count: u32,
+    p_object_entry_types: *const ObjectEntryTypeNVX,
+    p_object_indices: *const u32,
+) -> Result {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.unregister_objects_nvx(
+                &this.dispatch,
+                device,
+                object_table,
+                object_count,
+                p_object_entry_types,
+                p_object_indices,
+            )
+        })
+    }
+}
+extern "system" fn update_descriptor_set_with_template<L: Layer>(
+    device: Device,
+    descriptor_set: DescriptorSet,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    p_data: *const c_void,
+) {
+    unsafe {
+        let this = match device_data::<L, _>(device) {
+            Some(this) => this,
+            None => return ShimReturn::unforwarded(),
+        };
+        catch(|| {
+            this.instance.layer.update_descriptor_set_with_template(
+                &this.dispatch,
+                device,
+                descriptor_set,
+                descriptor_update_template,
+                p_data,
+            )
+        })
+    }
+}
+pub(crate) fn instance_shim<L: Layer>(name: &str) -> Option<*const c_void> {
+    match name {
+        "vkEnumeratePhysicalDevices" => Some(enumerate_physical_devices::<L> as *const c_void),
+        "vkGetPhysicalDeviceFeatures" => Some(get_physical_device_features::<L> as *const c_void),
+        "vkGetPhysicalDeviceFormatProperties" => {
+            Some(get_physical_device_format_properties::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceImageFormatProperties" => {
+            Some(get_physical_device_image_format_properties::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceProperties" => {
+            Some(get_physical_device_properties::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceQueueFamilyProperties" => {
+            Some(get_physical_device_queue_family_properties::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceMemoryProperties" => {
+            Some(get_physical_device_memory_properties::<L> as *const c_void)
+        }
+        "vkEnumerateDeviceExtensionProperties" => {
+            Some(enumerate_device_extension_properties::<L> as *const c_void)
+        }
+        "vkEnumerateDeviceLayerProperties" => {
+            Some(enumerate_device_layer_properties::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSparseImageFormatProperties" => {
+            Some(get_physical_device_sparse_image_format_properties::<L> as *const c_void)
+        }
+        _ => None,
+    }
+}
+pub(crate) fn device_shim<L: Layer>(name: &str) -> Option<*const c_void> {
+    match name {
+        "vkGetDeviceQueue" => Some(get_device_queue::<L> as *const c_void),
+        "vkQueueSubmit" => Some(queue_submit::<L> as *const c_void),
+        "vkQueueWaitIdle" => Some(queue_wait_idle::<L> as *const c_void),
+        "vkDeviceWaitIdle" => Some(device_wait_idle::<L> as *const c_void),
+        "vkAllocateMemory" => Some(allocate_memory::<L> as *const c_void),
+        "vkFreeMemory" => Some(free_memory::<L> as *const c_void),
+        "vkMapMemory" => Some(map_memory::<L> as *const c_void),
+        "vkUnmapMemory" => Some(unmap_memory::<L> as *const c_void),
+        "vkFlushMappedMemoryRanges" => Some(flush_mapped_memory_ranges::<L> as *const c_void),
+        "vkInvalidateMappedMemoryRanges" => {
+            Some(invalidate_mapped_memory_ranges::<L> as *const c_void)
+        }
+        "vkGetDeviceMemoryCommitment" => Some(get_device_memory_commitment::<L> as *const c_void),
+        "vkBindBufferMemory" => Some(bind_buffer_memory::<L> as *const c_void),
+        "vkBindImageMemory" => Some(bind_image_memory::<L> as *const c_void),
+        "vkGetBufferMemoryRequirements" => {
+            Some(get_buffer_memory_requirements::<L> as *const c_void)
+        }
+        "vkGetImageMemoryRequirements" => Some(get_image_memory_requirements::<L> as *const c_void),
+        "vkGetImageSparseMemoryRequirements" => {
+            Some(get_image_sparse_memory_requirements::<L> as *const c_void)
+        }
+        "vkQueueBindSparse" => Some(queue_bind_sparse::<L> as *const c_void),
+        "vkCreateFence" => Some(create_fence::<L> as *const c_void),
+        "vkDestroyFence" => Some(destroy_fence::<L> as *const c_void),
+        "vkResetFences" => Some(reset_fences::<L> as *const c_void),
+        "vkGetFenceStatus" => Some(get_fence_status::<L> as *const c_void),
+        "vkWaitForFences" => Some(wait_for_fences::<L> as *const c_void),
+        "vkCreateSemaphore" => Some(create_semaphore::<L> as *const c_void),
+        "vkDestroySemaphore" => Some(destroy_semaphore::<L> as *const c_void),
+        "vkCreateEvent" => Some(create_event::<L> as *const c_void),
+        "vkDestroyEvent" => Some(destroy_event::<L> as *const c_void),
+        "vkGetEventStatus" => Some(get_event_status::<L> as *const c_void),
+        "vkSetEvent" => Some(set_event::<L> as *const c_void),
+        "vkResetEvent" => Some(reset_event::<L> as *const c_void),
+        "vkCreateQueryPool" => Some(create_query_pool::<L> as *const c_void),
+        "vkDestroyQueryPool" => Some(destroy_query_pool::<L> as *const c_void),
+        "vkGetQueryPoolResults" => Some(get_query_pool_results::<L> as *const c_void),
+        "vkCreateBuffer" => Some(create_buffer::<L> as *const c_void),
+        "vkDestroyBuffer" => Some(destroy_buffer::<L> as *const c_void),
+        "vkCreateBufferView" => Some(create_buffer_view::<L> as *const c_void),
+        "vkDestroyBufferView" => Some(destroy_buffer_view::<L> as *const c_void),
+        "vkCreateImage" => Some(create_image::<L> as *const c_void),
+        "vkDestroyImage" => Some(destroy_image::<L> as *const c_void),
+        "vkGetImageSubresourceLayout" => Some(get_image_subresource_layout::<L> as *const c_void),
+        "vkCreateImageView" => Some(create_image_view::<L> as *const c_void),
+        "vkDestroyImageView" => Some(destroy_image_view::<L> as *const c_void),
+        "vkCreateShaderModule" => Some(create_shader_module::<L> as *const c_void),
+        "vkDestroyShaderModule" => Some(destroy_shader_module::<L> as *const c_void),
+        "vkCreatePipelineCache" => Some(create_pipeline_cache::<L> as *const c_void),
+        "vkDestroyPipelineCache" => Some(destroy_pipeline_cache::<L> as *const c_void),
+        "vkGetPipelineCacheData" => Some(get_pipeline_cache_data::<L> as *const c_void),
+        "vkMergePipelineCaches" => Some(merge_pipeline_caches::<L> as *const c_void),
+        "vkCreateGraphicsPipelines" => Some(create_graphics_pipelines::<L> as *const c_void),
+        "vkCreateComputePipelines" => Some(create_compute_pipelines::<L> as *const c_void),
+        "vkDestroyPipeline" => Some(destroy_pipeline::<L> as *const c_void),
+        "vkCreatePipelineLayout" => Some(create_pipeline_layout::<L> as *const c_void),
+        "vkDestroyPipelineLayout" => Some(destroy_pipeline_layout::<L> as *const c_void),
+        "vkCreateSampler" => Some(create_sampler::<L> as *const c_void),
+        "vkDestroySampler" => Some(destroy_sampler::<L> as *const c_void),
+        "vkCreateDescriptorSetLayout" => Some(create_descriptor_set_layout::<L> as *const c_void),
+        "vkDestroyDescriptorSetLayout" => Some(destroy_descriptor_set_layout::<L> as *const c_void),
+        "vkCreateDescriptorPool" => Some(create_descriptor_pool::<L> as *const c_void),
+        "vkDestroyDescriptorPool" => Some(destroy_descriptor_pool::<L> as *const c_void),
+        "vkResetDescriptorPool" => Some(reset_descriptor_pool::<L> as *const c_void),
+        "vkAllocateDescriptorSets" => Some(allocate_descriptor_sets::<L> as *const c_void),
+        "vkFreeDescriptorSets" => Some(free_descriptor_sets::<L> as *const c_void),
+        "vkUpdateDescriptorSets" => Some(update_descriptor_sets::<L> as *const c_void),
+        "vkCreateFramebuffer" => Some(create_framebuffer::<L> as *const c_void),
+        "vkDestroyFramebuffer" => Some(destroy_framebuffer::<L> as *const c_void),
+        "vkCreateRenderPass" => Some(create_render_pass::<L> as *const c_void),
+        "vkDestroyRenderPass" => Some(destroy_render_pass::<L> as *const c_void),
+        "vkGetRenderAreaGranularity" => Some(get_render_area_granularity::<L> as *const c_void),
+        "vkCreateCommandPool" => Some(create_command_pool::<L> as *const c_void),
+        "vkDestroyCommandPool" => Some(destroy_command_pool::<L> as *const c_void),
+        "vkResetCommandPool" => Some(reset_command_pool::<L> as *const c_void),
+        "vkAllocateCommandBuffers" => Some(allocate_command_buffers::<L> as *const c_void),
+        "vkFreeCommandBuffers" => Some(free_command_buffers::<L> as *const c_void),
+        "vkBeginCommandBuffer" => Some(begin_command_buffer::<L> as *const c_void),
+        "vkEndCommandBuffer" => Some(end_command_buffer::<L> as *const c_void),
+        "vkResetCommandBuffer" => Some(reset_command_buffer::<L> as *const c_void),
+        "vkCmdBindPipeline" => Some(cmd_bind_pipeline::<L> as *const c_void),
+        "vkCmdSetViewport" => Some(cmd_set_viewport::<L> as *const c_void),
+        "vkCmdSetScissor" => Some(cmd_set_scissor::<L> as *const c_void),
+        "vkCmdSetLineWidth" => Some(cmd_set_line_width::<L> as *const c_void),
+        "vkCmdSetDepthBias" => Some(cmd_set_depth_bias::<L> as *const c_void),
+        "vkCmdSetBlendConstants" => Some(cmd_set_blend_constants::<L> as *const c_void),
+        "vkCmdSetDepthBounds" => Some(cmd_set_depth_bounds::<L> as *const c_void),
+        "vkCmdSetStencilCompareMask" => Some(cmd_set_stencil_compare_mask::<L> as *const c_void),
+        "vkCmdSetStencilWriteMask" => Some(cmd_set_stencil_write_mask::<L> as *const c_void),
+        "vkCmdSetStencilReference" => Some(cmd_set_stencil_reference::<L> as *const c_void),
+        "vkCmdBindDescriptorSets" => Some(cmd_bind_descriptor_sets::<L> as *const c_void),
+        "vkCmdBindIndexBuffer" => Some(cmd_bind_index_buffer::<L> as *const c_void),
+        "vkCmdBindVertexBuffers" => Some(cmd_bind_vertex_buffers::<L> as *const c_void),
+        "vkCmdDraw" => Some(cmd_draw::<L> as *const c_void),
+        "vkCmdDrawIndexed" => Some(cmd_draw_indexed::<L> as *const c_void),
+        "vkCmdDrawIndirect" => Some(cmd_draw_indirect::<L> as *const c_void),
+        "vkCmdDrawIndexedIndirect" => Some(cmd_draw_indexed_indirect::<L> as *const c_void),
+        "vkCmdDispatch" => Some(cmd_dispatch::<L> as *const c_void),
+        "vkCmdDispatchIndirect" => Some(cmd_dispatch_indirect::<L> as *const c_void),
+        "vkCmdCopyBuffer" => Some(cmd_copy_buffer::<L> as *const c_void),
+        "vkCmdCopyImage" => Some(cmd_copy_image::<L> as *const c_void),
+        "vkCmdBlitImage" => Some(cmd_blit_image::<L> as *const c_void),
+        "vkCmdCopyBufferToImage" => Some(cmd_copy_buffer_to_image::<L> as *const c_void),
+        "vkCmdCopyImageToBuffer" => Some(cmd_copy_image_to_buffer::<L> as *const c_void),
+        "vkCmdUpdateBuffer" => Some(cmd_update_buffer::<L> as *const c_void),
+        "vkCmdFillBuffer" => Some(cmd_fill_buffer::<L> as *const c_void),
+        "vkCmdClearColorImage" => Some(cmd_clear_color_image::<L> as *const c_void),
+        "vkCmdClearDepthStencilImage" => Some(cmd_clear_depth_stencil_image::<L> as *const c_void),
+        "vkCmdClearAttachments" => Some(cmd_clear_attachments::<L> as *const c_void),
+        "vkCmdResolveImage" => Some(cmd_resolve_image::<L> as *const c_void),
+        "vkCmdSetEvent" => Some(cmd_set_event::<L> as *const c_void),
+        "vkCmdResetEvent" => Some(cmd_reset_event::<L> as *const c_void),
+        "vkCmdWaitEvents" => Some(cmd_wait_events::<L> as *const c_void),
+        "vkCmdPipelineBarrier" => Some(cmd_pipeline_barrier::<L> as *const c_void),
+        "vkCmdBeginQuery" => Some(cmd_begin_query::<L> as *const c_void),
+        "vkCmdEndQuery" => Some(cmd_end_query::<L> as *const c_void),
+        "vkCmdResetQueryPool" => Some(cmd_reset_query_pool::<L> as *const c_void),
+        "vkCmdWriteTimestamp" => Some(cmd_write_timestamp::<L> as *const c_void),
+        "vkCmdCopyQueryPoolResults" => Some(cmd_copy_query_pool_results::<L> as *const c_void),
+        "vkCmdPushConstants" => Some(cmd_push_constants::<L> as *const c_void),
+        "vkCmdBeginRenderPass" => Some(cmd_begin_render_pass::<L> as *const c_void),
+        "vkCmdNextSubpass" => Some(cmd_next_subpass::<L> as *const c_void),
+        "vkCmdEndRenderPass" => Some(cmd_end_render_pass::<L> as *const c_void),
+        "vkCmdExecuteCommands" => Some(cmd_execute_commands::<L> as *const c_void),
+        _ => None,
+    }
+}
+pub(crate) fn instance_extension_shim<L: Layer>(name: &str) -> Option<*const c_void> {
+    match name {
+        "vkAcquireXlibDisplayEXT" => Some(acquire_xlib_display_ext::<L> as *const c_void),
+        "vkCreateAndroidSurfaceKHR" => Some(create_android_surface_khr::<L> as *const c_void),
+        "vkCreateDebugReportCallbackEXT" => {
+            Some(create_debug_report_callback_ext::<L> as *const c_void)
+        }
+        "vkCreateDebugUtilsMessengerEXT" => {
+            Some(create_debug_utils_messenger_ext::<L> as *const c_void)
+        }
+        "vkCreateDisplayModeKHR" => Some(create_display_mode_khr::<L> as *const c_void),
+        "vkCreateDisplayPlaneSurfaceKHR" => {
+            Some(create_display_plane_surface_khr::<L> as *const c_void)
+        }
+        "vkCreateIOSSurfaceMVK" => Some(create_ios_surface_mvk::<L> as *const c_void),
+        "vkCreateImagePipeSurfaceFUCHSIA" => {
+            Some(create_image_pipe_surface_fuchsia::<L> as *const c_void)
+        }
+        "vkCreateMacOSSurfaceMVK" => Some(create_mac_os_surface_mvk::<L> as *const c_void),
+        "vkCreateViSurfaceNN" => Some(create_vi_surface_nn::<L> as *const c_void),
+        "vkCreateWaylandSurfaceKHR" => Some(create_wayland_surface_khr::<L> as *const c_void),
+        "vkCreateWin32SurfaceKHR" => Some(create_win32_surface_khr::<L> as *const c_void),
+        "vkCreateXcbSurfaceKHR" => Some(create_xcb_surface_khr::<L> as *const c_void),
+        "vkCreateXlibSurfaceKHR" => Some(create_xlib_surface_khr::<L> as *const c_void),
+        "vkDebugReportMessageEXT" => Some(debug_report_message_ext::<L> as *const c_void),
+        "vkDestroyDebugReportCallbackEXT" => {
+            Some(destroy_debug_report_callback_ext::<L> as *const c_void)
+        }
+        "vkDestroyDebugUtilsMessengerEXT" => {
+            Some(destroy_debug_utils_messenger_ext::<L> as *const c_void)
+        }
+        "vkDestroySurfaceKHR" => Some(destroy_surface_khr::<L> as *const c_void),
+        "vkEnumeratePhysicalDeviceGroups" => {
+            Some(enumerate_physical_device_groups::<L> as *const c_void)
+        }
+        "vkGetDisplayModeProperties2KHR" => {
+            Some(get_display_mode_properties2_khr::<L> as *const c_void)
+        }
+        "vkGetDisplayModePropertiesKHR" => {
+            Some(get_display_mode_properties_khr::<L> as *const c_void)
+        }
+        "vkGetDisplayPlaneCapabilities2KHR" => {
+            Some(get_display_plane_capabilities2_khr::<L> as *const c_void)
+        }
+        "vkGetDisplayPlaneCapabilitiesKHR" => {
+            Some(get_display_plane_capabilities_khr::<L> as *const c_void)
+        }
+        "vkGetDisplayPlaneSupportedDisplaysKHR" => {
+            Some(get_display_plane_supported_displays_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT" => {
+            Some(get_physical_device_calibrateable_time_domains_ext::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR" => {
+            Some(get_physical_device_display_plane_properties2_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR" => {
+            Some(get_physical_device_display_plane_properties_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceDisplayProperties2KHR" => {
+            Some(get_physical_device_display_properties2_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceDisplayPropertiesKHR" => {
+            Some(get_physical_device_display_properties_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceExternalBufferProperties" => {
+            Some(get_physical_device_external_buffer_properties::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceExternalFenceProperties" => {
+            Some(get_physical_device_external_fence_properties::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceExternalImageFormatPropertiesNV" => {
+            Some(get_physical_device_external_image_format_properties_nv::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceExternalSemaphoreProperties" => {
+            Some(get_physical_device_external_semaphore_properties::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceFeatures2" => Some(get_physical_device_features2::<L> as *const c_void),
+        "vkGetPhysicalDeviceFormatProperties2" => {
+            Some(get_physical_device_format_properties2::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX" => {
+            Some(get_physical_device_generated_commands_properties_nvx::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceImageFormatProperties2" => {
+            Some(get_physical_device_image_format_properties2::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceMemoryProperties2" => {
+            Some(get_physical_device_memory_properties2::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceMultisamplePropertiesEXT" => {
+            Some(get_physical_device_multisample_properties_ext::<L> as *const c_void)
+        }
+        "vkGetPhysicalDevicePresentRectanglesKHR" => {
+            Some(get_physical_device_present_rectangles_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceProperties2" => {
+            Some(get_physical_device_properties2::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceQueueFamilyProperties2" => {
+            Some(get_physical_device_queue_family_properties2::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSparseImageFormatProperties2" => {
+            Some(get_physical_device_sparse_image_format_properties2::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilities2EXT" => {
+            Some(get_physical_device_surface_capabilities2_ext::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilities2KHR" => {
+            Some(get_physical_device_surface_capabilities2_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => {
+            Some(get_physical_device_surface_capabilities_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceFormats2KHR" => {
+            Some(get_physical_device_surface_formats2_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceFormatsKHR" => {
+            Some(get_physical_device_surface_formats_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfacePresentModesKHR" => {
+            Some(get_physical_device_surface_present_modes_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceSupportKHR" => {
+            Some(get_physical_device_surface_support_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceWaylandPresentationSupportKHR" => {
+            Some(get_physical_device_wayland_presentation_support_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceWin32PresentationSupportKHR" => {
+            Some(get_physical_device_win32_presentation_support_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceXcbPresentationSupportKHR" => {
+            Some(get_physical_device_xcb_presentation_support_khr::<L> as *const c_void)
+        }
+        "vkGetPhysicalDeviceXlibPresentationSupportKHR" => {
+            Some(get_physical_device_xlib_presentation_support_khr::<L> as *const c_void)
+        }
+        "vkGetRandROutputDisplayEXT" => Some(get_rand_r_output_display_ext::<L> as *const c_void),
+        "vkReleaseDisplayEXT" => Some(release_display_ext::<L> as *const c_void),
+        "vkSubmitDebugUtilsMessageEXT" => {
+            Some(submit_debug_utils_message_ext::<L> as *const c_void)
+        }
+        _ => None,
+    }
+}
+pub(crate) fn device_extension_shim<L: Layer>(name: &str) -> Option<*const c_void> {
+    match name {
+        "vkAcquireImageANDROID" => Some(acquire_image_android::<L> as *const c_void),
+        "vkAcquireNextImage2KHR" => Some(acquire_next_image2_khr::<L> as *const c_void),
+        "vkAcquireNextImageKHR" => Some(acquire_next_image_khr::<L> as *const c_void),
+        "vkBindAccelerationStructureMemoryNV" => {
+            Some(bind_acceleration_structure_memory_nv::<L> as *const c_void)
+        }
+        "vkBindBufferMemory2" => Some(bind_buffer_memory2::<L> as *const c_void),
+        "vkBindImageMemory2" => Some(bind_image_memory2::<L> as *const c_void),
+        "vkCmdBeginConditionalRenderingEXT" => {
+            Some(cmd_begin_conditional_rendering_ext::<L> as *const c_void)
+        }
+        "vkCmdBeginDebugUtilsLabelEXT" => {
+            Some(cmd_begin_debug_utils_label_ext::<L> as *const c_void)
+        }
+        "vkCmdBeginQueryIndexedEXT" => Some(cmd_begin_query_indexed_ext::<L> as *const c_void),
+        "vkCmdBeginRenderPass2KHR" => Some(cmd_begin_render_pass2_khr::<L> as *const c_void),
+        "vkCmdBeginTransformFeedbackEXT" => {
+            Some(cmd_begin_transform_feedback_ext::<L> as *const c_void)
+        }
+        "vkCmdBindShadingRateImageNV" => Some(cmd_bind_shading_rate_image_nv::<L> as *const c_void),
+        "vkCmdBindTransformFeedbackBuffersEXT" => {
+            Some(cmd_bind_transform_feedback_buffers_ext::<L> as *const c_void)
+        }
+        "vkCmdBuildAccelerationStructureNV" => {
+            Some(cmd_build_acceleration_structure_nv::<L> as *const c_void)
+        }
+        "vkCmdCopyAccelerationStructureNV" => {
+            Some(cmd_copy_acceleration_structure_nv::<L> as *const c_void)
+        }
+        "vkCmdDebugMarkerBeginEXT" => Some(cmd_debug_marker_begin_ext::<L> as *const c_void),
+        "vkCmdDebugMarkerEndEXT" => Some(cmd_debug_marker_end_ext::<L> as *const c_void),
+        "vkCmdDebugMarkerInsertEXT" => Some(cmd_debug_marker_insert_ext::<L> as *const c_void),
+        "vkCmdDispatchBase" => Some(cmd_dispatch_base::<L> as *const c_void),
+        "vkCmdDrawIndexedIndirectCountAMD" => {
+            Some(cmd_draw_indexed_indirect_count_amd::<L> as *const c_void)
+        }
+        "vkCmdDrawIndexedIndirectCountKHR" => {
+            Some(cmd_draw_indexed_indirect_count_khr::<L> as *const c_void)
+        }
+        "vkCmdDrawIndirectByteCountEXT" => {
+            Some(cmd_draw_indirect_byte_count_ext::<L> as *const c_void)
+        }
+        "vkCmdDrawIndirectCountAMD" => Some(cmd_draw_indirect_count_amd::<L> as *const c_void),
+        "vkCmdDrawIndirectCountKHR" => Some(cmd_draw_indirect_count_khr::<L> as *const c_void),
+        "vkCmdDrawMeshTasksIndirectCountNV" => {
+            Some(cmd_draw_mesh_tasks_indirect_count_nv::<L> as *const c_void)
+        }
+        "vkCmdDrawMeshTasksIndirectNV" => {
+            Some(cmd_draw_mesh_tasks_indirect_nv::<L> as *const c_void)
+        }
+        "vkCmdDrawMeshTasksNV" => Some(cmd_draw_mesh_tasks_nv::<L> as *const c_void),
+        "vkCmdEndConditionalRenderingEXT" => {
+            Some(cmd_end_conditional_rendering_ext::<L> as *const c_void)
+        }
+        "vkCmdEndDebugUtilsLabelEXT" => Some(cmd_end_debug_utils_label_ext::<L> as *const c_void),
+        "vkCmdEndQueryIndexedEXT" => Some(cmd_end_query_indexed_ext::<L> as *const c_void),
+        "vkCmdEndRenderPass2KHR" => Some(cmd_end_render_pass2_khr::<L> as *const c_void),
+        "vkCmdEndTransformFeedbackEXT" => {
+            Some(cmd_end_transform_feedback_ext::<L> as *const c_void)
+        }
+        "vkCmdInsertDebugUtilsLabelEXT" => {
+            Some(cmd_insert_debug_utils_label_ext::<L> as *const c_void)
+        }
+        "vkCmdNextSubpass2KHR" => Some(cmd_next_subpass2_khr::<L> as *const c_void),
+        "vkCmdProcessCommandsNVX" => Some(cmd_process_commands_nvx::<L> as *const c_void),
+        "vkCmdPushDescriptorSetKHR" => Some(cmd_push_descriptor_set_khr::<L> as *const c_void),
+        "vkCmdPushDescriptorSetWithTemplateKHR" => {
+            Some(cmd_push_descriptor_set_with_template_khr::<L> as *const c_void)
+        }
+        "vkCmdReserveSpaceForCommandsNVX" => {
+            Some(cmd_reserve_space_for_commands_nvx::<L> as *const c_void)
+        }
+        "vkCmdSetCheckpointNV" => Some(cmd_set_checkpoint_nv::<L> as *const c_void),
+        "vkCmdSetCoarseSampleOrderNV" => Some(cmd_set_coarse_sample_order_nv::<L> as *const c_void),
+        "vkCmdSetDeviceMask" => Some(cmd_set_device_mask::<L> as *const c_void),
+        "vkCmdSetDiscardRectangleEXT" => Some(cmd_set_discard_rectangle_ext::<L> as *const c_void),
+        "vkCmdSetExclusiveScissorNV" => Some(cmd_set_exclusive_scissor_nv::<L> as *const c_void),
+        "vkCmdSetSampleLocationsEXT" => Some(cmd_set_sample_locations_ext::<L> as *const c_void),
+        "vkCmdSetViewportShadingRatePaletteNV" => {
+            Some(cmd_set_viewport_shading_rate_palette_nv::<L> as *const c_void)
+        }
+        "vkCmdSetViewportWScalingNV" => Some(cmd_set_viewport_w_scaling_nv::<L> as *const c_void),
+        "vkCmdTraceRaysNV" => Some(cmd_trace_rays_nv::<L> as *const c_void),
+        "vkCmdWriteAccelerationStructuresPropertiesNV" => {
+            Some(cmd_write_acceleration_structures_properties_nv::<L> as *const c_void)
+        }
+        "vkCmdWriteBufferMarkerAMD" => Some(cmd_write_buffer_marker_amd::<L> as *const c_void),
+        "vkCompileDeferredNV" => Some(compile_deferred_nv::<L> as *const c_void),
+        "vkCreateAccelerationStructureNV" => {
+            Some(create_acceleration_structure_nv::<L> as *const c_void)
+        }
+        "vkCreateDescriptorUpdateTemplate" => {
+            Some(create_descriptor_update_template::<L> as *const c_void)
+        }
+        "vkCreateIndirectCommandsLayoutNVX" => {
+            Some(create_indirect_commands_layout_nvx::<L> as *const c_void)
+        }
+        "vkCreateObjectTableNVX" => Some(create_object_table_nvx::<L> as *const c_void),
+        "vkCreateRayTracingPipelinesNV" => {
+            Some(create_ray_tracing_pipelines_nv::<L> as *const c_void)
+        }
+        "vkCreateRenderPass2KHR" => Some(create_render_pass2_khr::<L> as *const c_void),
+        "vkCreateSamplerYcbcrConversion" => {
+            Some(create_sampler_ycbcr_conversion::<L> as *const c_void)
+        }
+        "vkCreateSharedSwapchainsKHR" => Some(create_shared_swapchains_khr::<L> as *const c_void),
+        "vkCreateSwapchainKHR" => Some(create_swapchain_khr::<L> as *const c_void),
+        "vkCreateValidationCacheEXT" => Some(create_validation_cache_ext::<L> as *const c_void),
+        "vkDebugMarkerSetObjectNameEXT" => {
+            Some(debug_marker_set_object_name_ext::<L> as *const c_void)
+        }
+        "vkDebugMarkerSetObjectTagEXT" => {
+            Some(debug_marker_set_object_tag_ext::<L> as *const c_void)
+        }
+        "vkDestroyAccelerationStructureNV" => {
+            Some(destroy_acceleration_structure_nv::<L> as *const c_void)
+        }
+        "vkDestroyDescriptorUpdateTemplate" => {
+            Some(destroy_descriptor_update_template::<L> as *const c_void)
+        }
+        "vkDestroyIndirectCommandsLayoutNVX" => {
+            Some(destroy_indirect_commands_layout_nvx::<L> as *const c_void)
+        }
+        "vkDestroyObjectTableNVX" => Some(destroy_object_table_nvx::<L> as *const c_void),
+        "vkDestroySamplerYcbcrConversion" => {
+            Some(destroy_sampler_ycbcr_conversion::<L> as *const c_void)
+        }
+        "vkDestroySwapchainKHR" => Some(destroy_swapchain_khr::<L> as *const c_void),
+        "vkDestroyValidationCacheEXT" => Some(destroy_validation_cache_ext::<L> as *const c_void),
+        "vkDisplayPowerControlEXT" => Some(display_power_control_ext::<L> as *const c_void),
+        "vkGetAccelerationStructureHandleNV" => {
+            Some(get_acceleration_structure_handle_nv::<L> as *const c_void)
+        }
+        "vkGetAccelerationStructureMemoryRequirementsNV" => {
+            Some(get_acceleration_structure_memory_requirements_nv::<L> as *const c_void)
+        }
+        "vkGetAndroidHardwareBufferPropertiesANDROID" => {
+            Some(get_android_hardware_buffer_properties_android::<L> as *const c_void)
+        }
+        "vkGetBufferMemoryRequirements2" => {
+            Some(get_buffer_memory_requirements2::<L> as *const c_void)
+        }
+        "vkGetCalibratedTimestampsEXT" => Some(get_calibrated_timestamps_ext::<L> as *const c_void),
+        "vkGetDescriptorSetLayoutSupport" => {
+            Some(get_descriptor_set_layout_support::<L> as *const c_void)
+        }
+        "vkGetDeviceGroupPeerMemoryFeatures" => {
+            Some(get_device_group_peer_memory_features::<L> as *const c_void)
+        }
+        "vkGetDeviceGroupPresentCapabilitiesKHR" => {
+            Some(get_device_group_present_capabilities_khr::<L> as *const c_void)
+        }
+        "vkGetDeviceGroupSurfacePresentModesKHR" => {
+            Some(get_device_group_surface_present_modes_khr::<L> as *const c_void)
+        }
+        "vkGetDeviceQueue2" => Some(get_device_queue2::<L> as *const c_void),
+        "vkGetFenceFdKHR" => Some(get_fence_fd_khr::<L> as *const c_void),
+        "vkGetFenceWin32HandleKHR" => Some(get_fence_win32_handle_khr::<L> as *const c_void),
+        "vkGetImageDrmFormatModifierPropertiesEXT" => {
+            Some(get_image_drm_format_modifier_properties_ext::<L> as *const c_void)
+        }
+        "vkGetImageMemoryRequirements2" => {
+            Some(get_image_memory_requirements2::<L> as *const c_void)
+        }
+        "vkGetImageSparseMemoryRequirements2" => {
+            Some(get_image_sparse_memory_requirements2::<L> as *const c_void)
+        }
+        "vkGetMemoryAndroidHardwareBufferANDROID" => {
+            Some(get_memory_android_hardware_buffer_android::<L> as *const c_void)
+        }
+        "vkGetMemoryFdKHR" => Some(get_memory_fd_khr::<L> as *const c_void),
+        "vkGetMemoryFdPropertiesKHR" => Some(get_memory_fd_properties_khr::<L> as *const c_void),
+        "vkGetMemoryHostPointerPropertiesEXT" => {
+            Some(get_memory_host_pointer_properties_ext::<L> as *const c_void)
+        }
+        "vkGetMemoryWin32HandleKHR" => Some(get_memory_win32_handle_khr::<L> as *const c_void),
+        "vkGetMemoryWin32HandleNV" => Some(get_memory_win32_handle_nv::<L> as *const c_void),
+        "vkGetMemoryWin32HandlePropertiesKHR" => {
+            Some(get_memory_win32_handle_properties_khr::<L> as *const c_void)
+        }
+        "vkGetPastPresentationTimingGOOGLE" => {
+            Some(get_past_presentation_timing_google::<L> as *const c_void)
+        }
+        "vkGetQueueCheckpointDataNV" => Some(get_queue_checkpoint_data_nv::<L> as *const c_void),
+        "vkGetRayTracingShaderGroupHandlesNV" => {
+            Some(get_ray_tracing_shader_group_handles_nv::<L> as *const c_void)
+        }
+        "vkGetRefreshCycleDurationGOOGLE" => {
+            Some(get_refresh_cycle_duration_google::<L> as *const c_void)
+        }
+        "vkGetSemaphoreFdKHR" => Some(get_semaphore_fd_khr::<L> as *const c_void),
+        "vkGetSemaphoreWin32HandleKHR" => {
+            Some(get_semaphore_win32_handle_khr::<L> as *const c_void)
+        }
+        "vkGetShaderInfoAMD" => Some(get_shader_info_amd::<L> as *const c_void),
+        "vkGetSwapchainCounterEXT" => Some(get_swapchain_counter_ext::<L> as *const c_void),
+        "vkGetSwapchainGrallocUsageANDROID" => {
+            Some(get_swapchain_gralloc_usage_android::<L> as *const c_void)
+        }
+        "vkGetSwapchainImagesKHR" => Some(get_swapchain_images_khr::<L> as *const c_void),
+        "vkGetSwapchainStatusKHR" => Some(get_swapchain_status_khr::<L> as *const c_void),
+        "vkGetValidationCacheDataEXT" => Some(get_validation_cache_data_ext::<L> as *const c_void),
+        "vkImportFenceFdKHR" => Some(import_fence_fd_khr::<L> as *const c_void),
+        "vkImportFenceWin32HandleKHR" => Some(import_fence_win32_handle_khr::<L> as *const c_void),
+        "vkImportSemaphoreFdKHR" => Some(import_semaphore_fd_khr::<L> as *const c_void),
+        "vkImportSemaphoreWin32HandleKHR" => {
+            Some(import_semaphore_win32_handle_khr::<L> as *const c_void)
+        }
+        "vkMergeValidationCachesEXT" => Some(merge_validation_caches_ext::<L> as *const c_void),
+        "vkQueueBeginDebugUtilsLabelEXT" => {
+            Some(queue_begin_debug_utils_label_ext::<L> as *const c_void)
+        }
+        "vkQueueEndDebugUtilsLabelEXT" => {
+            Some(queue_end_debug_utils_label_ext::<L> as *const c_void)
+        }
+        "vkQueueInsertDebugUtilsLabelEXT" => {
+            Some(queue_insert_debug_utils_label_ext::<L> as *const c_void)
+        }
+        "vkQueuePresentKHR" => Some(queue_present_khr::<L> as *const c_void),
+        "vkQueueSignalReleaseImageANDROID" => {
+            Some(queue_signal_release_image_android::<L> as *const c_void)
+        }
+        "vkRegisterDeviceEventEXT" => Some(register_device_event_ext::<L> as *const c_void),
+        "vkRegisterDisplayEventEXT" => Some(register_display_event_ext::<L> as *const c_void),
+        "vkRegisterObjectsNVX" => Some(register_objects_nvx::<L> as *const c_void),
+        "vkSetDebugUtilsObjectNameEXT" => {
+            Some(set_debug_utils_object_name_ext::<L> as *const c_void)
+        }
+        "vkSetDebugUtilsObjectTagEXT" => Some(set_debug_utils_object_tag_ext::<L> as *const c_void),
+        "vkSetHdrMetadataEXT" => Some(set_hdr_metadata_ext::<L> as *const c_void),
+        "vkTrimCommandPool" => Some(trim_command_pool::<L> as *const c_void),
+        "vkUnregisterObjectsNVX" => Some(unregister_objects_nvx::<L> as *const c_void),
+        "vkUpdateDescriptorSetWithTemplate" => {
+            Some(update_descriptor_set_with_template::<L> as *const c_void)
+        }
+        _ => None,
+    }
+}
+#[doc = "The instance commands of the next layer that are not part of Vulkan 1.0, `None` if the next layer does not provide them, e.g. because their extension is not enabled."]
+pub struct InstanceExtensionFn {
+    pub acquire_xlib_display_ext: Option<PFN_vkAcquireXlibDisplayEXT>,
+    pub create_android_surface_khr: Option<PFN_vkCreateAndroidSurfaceKHR>,
+    pub create_debug_report_callback_ext: Option<PFN_vkCreateDebugReportCallbackEXT>,
+    pub create_debug_utils_messenger_ext: Option<PFN_vkCreateDebugUtilsMessengerEXT>,
+    pub create_display_mode_khr: Option<PFN_vkCreateDisplayModeKHR>,
+    pub create_display_plane_surface_khr: Option<PFN_vkCreateDisplayPlaneSurfaceKHR>,
+    pub create_ios_surface_mvk: Option<PFN_vkCreateIOSSurfaceMVK>,
+    pub create_image_pipe_surface_fuchsia: Option<PFN_vkCreateImagePipeSurfaceFUCHSIA>,
+    pub create_mac_os_surface_mvk: Option<PFN_vkCreateMacOSSurfaceMVK>,
+    pub create_vi_surface_nn: Option<PFN_vkCreateViSurfaceNN>,
+    pub create_wayland_surface_khr: Option<PFN_vkCreateWaylandSurfaceKHR>,
+    pub create_win32_surface_khr: Option<PFN_vkCreateWin32SurfaceKHR>,
+    pub create_xcb_surface_khr: Option<PFN_vkCreateXcbSurfaceKHR>,
+    pub create_xlib_surface_khr: Option<PFN_vkCreateXlibSurfaceKHR>,
+    pub debug_report_message_ext: Option<PFN_vkDebugReportMessageEXT>,
+    pub destroy_debug_report_callback_ext: Option<PFN_vkDestroyDebugReportCallbackEXT>,
+    pub destroy_debug_utils_messenger_ext: Option<PFN_vkDestroyDebugUtilsMessengerEXT>,
+    pub destroy_surface_khr: Option<PFN_vkDestroySurfaceKHR>,
+    pub enumerate_physical_device_groups: Option<PFN_vkEnumeratePhysicalDeviceGroups>,
+    pub get_display_mode_properties2_khr: Option<PFN_vkGetDisplayModeProperties2KHR>,
+    pub get_display_mode_properties_khr: Option<PFN_vkGetDisplayModePropertiesKHR>,
+    pub get_display_plane_capabilities2_khr: Option<PFN_vkGetDisplayPlaneCapabilities2KHR>,
+    pub get_display_plane_capabilities_khr: Option<PFN_vkGetDisplayPlaneCapabilitiesKHR>,
+    pub get_display_plane_supported_displays_khr: Option<PFN_vkGetDisplayPlaneSupportedDisplaysKHR>,
+    pub get_physical_device_calibrateable_time_domains_ext:
+        Option<PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT>,
+    pub get_physical_device_display_plane_properties2_khr:
+        Option<PFN_vkGetPhysicalDeviceDisplayPlaneProperties2KHR>,
+    pub get_physical_device_display_plane_properties_khr:
+        Option<PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR>,
+    pub get_physical_device_display_properties2_khr:
+        Option<PFN_vkGetPhysicalDeviceDisplayProperties2KHR>,
+    pub get_physical_device_display_properties_khr:
+        Option<PFN_vkGetPhysicalDeviceDisplayPropertiesKHR>,
+    pub get_physical_device_external_buffer_properties:
+        Option<PFN_vkGetPhysicalDeviceExternalBufferProperties>,
+    pub get_physical_device_external_fence_properties:
+        Option<PFN_vkGetPhysicalDeviceExternalFenceProperties>,
+    pub get_physical_device_external_image_format_properties_nv:
+        Option<PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV>,
+    pub get_physical_device_external_semaphore_properties:
+        Option<PFN_vkGetPhysicalDeviceExternalSemaphoreProperties>,
+    pub get_physical_device_features2: Option<PFN_vkGetPhysicalDeviceFeatures2>,
+    pub get_physical_device_format_properties2: Option<PFN_vkGetPhysicalDeviceFormatProperties2>,
+    pub get_physical_device_generated_commands_properties_nvx:
+        Option<PFN_vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX>,
+    pub get_physical_device_image_format_properties2:
+        Option<PFN_vkGetPhysicalDeviceImageFormatProperties2>,
+    pub get_physical_device_memory_properties2: Option<PFN_vkGetPhysicalDeviceMemoryProperties2>,
+    pub get_physical_device_multisample_properties_ext:
+        Option<PFN_vkGetPhysicalDeviceMultisamplePropertiesEXT>,
+    pub get_physical_device_present_rectangles_khr:
+        Option<PFN_vkGetPhysicalDevicePresentRectanglesKHR>,
+    pub get_physical_device_properties2: Option<PFN_vkGetPhysicalDeviceProperties2>,
+    pub get_physical_device_queue_family_properties2:
+        Option<PFN_vkGetPhysicalDeviceQueueFamilyProperties2>,
+    pub get_physical_device_sparse_image_format_properties2:
+        Option<PFN_vkGetPhysicalDeviceSparseImageFormatProperties2>,
+    pub get_physical_device_surface_capabilities2_ext:
+        Option<PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT>,
+    pub get_physical_device_surface_capabilities2_khr:
+        Option<PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR>,
+    pub get_physical_device_surface_capabilities_khr:
+        Option<PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR>,
+    pub get_physical_device_surface_formats2_khr: Option<PFN_vkGetPhysicalDeviceSurfaceFormats2KHR>,
+    pub get_physical_device_surface_formats_khr: Option<PFN_vkGetPhysicalDeviceSurfaceFormatsKHR>,
+    pub get_physical_device_surface_present_modes_khr:
+        Option<PFN_vkGetPhysicalDeviceSurfacePresentModesKHR>,
+    pub get_physical_device_surface_support_khr: Option<PFN_vkGetPhysicalDeviceSurfaceSupportKHR>,
+    pub get_physical_device_wayland_presentation_support_khr:
+        Option<PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR>,
+    pub get_physical_device_win32_presentation_support_khr:
+        Option<PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR>,
+    pub get_physical_device_xcb_presentation_support_khr:
+        Option<PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR>,
+    pub get_physical_device_xlib_presentation_support_khr:
+        Option<PFN_vkGetPhysicalDeviceXlibPresentationSupportKHR>,
+    pub get_rand_r_output_display_ext: Option<PFN_vkGetRandROutputDisplayEXT>,
+    pub release_display_ext: Option<PFN_vkReleaseDisplayEXT>,
+    pub submit_debug_utils_message_ext: Option<PFN_vkSubmitDebugUtilsMessageEXT>,
+}
+impl InstanceExtensionFn {
+    pub(crate) unsafe fn load<F: FnMut(&CStr) -> *const c_void>(mut f: F) -> Self {
+        InstanceExtensionFn {
+            acquire_xlib_display_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkAcquireXlibDisplayEXT\0",
+            ))),
+            create_android_surface_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateAndroidSurfaceKHR\0",
+            ))),
+            create_debug_report_callback_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateDebugReportCallbackEXT\0"),
+            )),
+            create_debug_utils_messenger_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateDebugUtilsMessengerEXT\0"),
+            )),
+            create_display_mode_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateDisplayModeKHR\0",
+            ))),
+            create_display_plane_surface_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateDisplayPlaneSurfaceKHR\0"),
+            )),
+            create_ios_surface_mvk: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateIOSSurfaceMVK\0",
+            ))),
+            create_image_pipe_surface_fuchsia: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateImagePipeSurfaceFUCHSIA\0"),
+            )),
+            create_mac_os_surface_mvk: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateMacOSSurfaceMVK\0",
+            ))),
+            create_vi_surface_nn: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateViSurfaceNN\0",
+            ))),
+            create_wayland_surface_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateWaylandSurfaceKHR\0",
+            ))),
+            create_win32_surface_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateWin32SurfaceKHR\0",
+            ))),
+            create_xcb_surface_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateXcbSurfaceKHR\0",
+            ))),
+            create_xlib_surface_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateXlibSurfaceKHR\0",
+            ))),
+            debug_report_message_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkDebugReportMessageEXT\0",
+            ))),
+            destroy_debug_report_callback_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkDestroyDebugReportCallbackEXT\0"),
+            )),
+            destroy_debug_utils_messenger_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkDestroyDebugUtilsMessengerEXT\0"),
+            )),
+            destroy_surface_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkDestroySurfaceKHR\0",
+            ))),
+            enumerate_physical_device_groups: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkEnumeratePhysicalDeviceGroups\0"),
+            )),
+            get_display_mode_properties2_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayModeProperties2KHR\0"),
+            )),
+            get_display_mode_properties_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayModePropertiesKHR\0"),
+            )),
+            get_display_plane_capabilities2_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneCapabilities2KHR\0"),
+            )),
+            get_display_plane_capabilities_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneCapabilitiesKHR\0"),
+            )),
+            get_display_plane_supported_displays_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneSupportedDisplaysKHR\0"),
+            )),
+            get_physical_device_calibrateable_time_domains_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT\0",
+                ),
+            )),
+            get_physical_device_display_plane_properties2_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceDisplayPlaneProperties2KHR\0",
+                ),
+            )),
+            get_physical_device_display_plane_properties_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR\0",
+                ),
+            )),
+            get_physical_device_display_properties2_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDisplayProperties2KHR\0"),
+            )),
+            get_physical_device_display_properties_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDisplayPropertiesKHR\0"),
+            )),
+            get_physical_device_external_buffer_properties: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalBufferProperties\0",
+                ),
+            )),
+            get_physical_device_external_fence_properties: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalFenceProperties\0",
+                ),
+            )),
+            get_physical_device_external_image_format_properties_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalImageFormatPropertiesNV\0",
+                ),
+            )),
+            get_physical_device_external_semaphore_properties: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalSemaphoreProperties\0",
+                ),
+            )),
+            get_physical_device_features2: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetPhysicalDeviceFeatures2\0",
+            ))),
+            get_physical_device_format_properties2: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFormatProperties2\0"),
+            )),
+            get_physical_device_generated_commands_properties_nvx: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX\0",
+                ),
+            )),
+            get_physical_device_image_format_properties2: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceImageFormatProperties2\0"),
+            )),
+            get_physical_device_memory_properties2: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceMemoryProperties2\0"),
+            )),
+            get_physical_device_multisample_properties_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceMultisamplePropertiesEXT\0",
+                ),
+            )),
+            get_physical_device_present_rectangles_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDevicePresentRectanglesKHR\0"),
+            )),
+            get_physical_device_properties2: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceProperties2\0"),
+            )),
+            get_physical_device_queue_family_properties2: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceQueueFamilyProperties2\0"),
+            )),
+            get_physical_device_sparse_image_format_properties2: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceSparseImageFormatProperties2\0",
+                ),
+            )),
+            get_physical_device_surface_capabilities2_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceSurfaceCapabilities2EXT\0",
+                ),
+            )),
+            get_physical_device_surface_capabilities2_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceSurfaceCapabilities2KHR\0",
+                ),
+            )),
+            get_physical_device_surface_capabilities_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR\0"),
+            )),
+            get_physical_device_surface_formats2_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceFormats2KHR\0"),
+            )),
+            get_physical_device_surface_formats_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceFormatsKHR\0"),
+            )),
+            get_physical_device_surface_present_modes_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfacePresentModesKHR\0"),
+            )),
+            get_physical_device_surface_support_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceSupportKHR\0"),
+            )),
+            get_physical_device_wayland_presentation_support_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceWaylandPresentationSupportKHR\0",
+                ),
+            )),
+            get_physical_device_win32_presentation_support_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceWin32PresentationSupportKHR\0",
+                ),
+            )),
+            get_physical_device_xcb_presentation_support_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceXcbPresentationSupportKHR\0",
+                ),
+            )),
+            get_physical_device_xlib_presentation_support_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceXlibPresentationSupportKHR\0",
+                ),
+            )),
+            get_rand_r_output_display_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetRandROutputDisplayEXT\0",
+            ))),
+            release_display_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkReleaseDisplayEXT\0",
+            ))),
+            submit_debug_utils_message_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkSubmitDebugUtilsMessageEXT\0",
+            ))),
+        }
+    }
+}
+#[doc = "The device commands of the next layer that are not part of Vulkan 1.0, `None` if the next layer does not provide them, e.g. because their extension is not enabled."]
+pub struct DeviceExtensionFn {
+    pub acquire_image_android: Option<PFN_vkAcquireImageANDROID>,
+    pub acquire_next_image2_khr: Option<PFN_vkAcquireNextImage2KHR>,
+    pub acquire_next_image_khr: Option<PFN_vkAcquireNextImageKHR>,
+    pub bind_acceleration_structure_memory_nv: Option<PFN_vkBindAccelerationStructureMemoryNV>,
+    pub bind_buffer_memory2: Option<PFN_vkBindBufferMemory2>,
+    pub bind_image_memory2: Option<PFN_vkBindImageMemory2>,
+    pub cmd_begin_conditional_rendering_ext: Option<PFN_vkCmdBeginConditionalRenderingEXT>,
+    pub cmd_begin_debug_utils_label_ext: Option<PFN_vkCmdBeginDebugUtilsLabelEXT>,
+    pub cmd_begin_query_indexed_ext: Option<PFN_vkCmdBeginQueryIndexedEXT>,
+    pub cmd_begin_render_pass2_khr: Option<PFN_vkCmdBeginRenderPass2KHR>,
+    pub cmd_begin_transform_feedback_ext: Option<PFN_vkCmdBeginTransformFeedbackEXT>,
+    pub cmd_bind_shading_rate_image_nv: Option<PFN_vkCmdBindShadingRateImageNV>,
+    pub cmd_bind_transform_feedback_buffers_ext: Option<PFN_vkCmdBindTransformFeedbackBuffersEXT>,
+    pub cmd_build_acceleration_structure_nv: Option<PFN_vkCmdBuildAccelerationStructureNV>,
+    pub cmd_copy_acceleration_structure_nv: Option<PFN_vkCmdCopyAccelerationStructureNV>,
+    pub cmd_debug_marker_begin_ext: Option<PFN_vkCmdDebugMarkerBeginEXT>,
+    pub cmd_debug_marker_end_ext: Option<PFN_vkCmdDebugMarkerEndEXT>,
+    pub cmd_debug_marker_insert_ext: Option<PFN_vkCmdDebugMarkerInsertEXT>,
+    pub cmd_dispatch_base: Option<PFN_vkCmdDispatchBase>,
+    pub cmd_draw_indexed_indirect_count_amd: Option<PFN_vkCmdDrawIndexedIndirectCountAMD>,
+    pub cmd_draw_indexed_indirect_count_khr: Option<PFN_vkCmdDrawIndexedIndirectCountKHR>,
+    pub cmd_draw_indirect_byte_count_ext: Option<PFN_vkCmdDrawIndirectByteCountEXT>,
+    pub cmd_draw_indirect_count_amd: Option<PFN_vkCmdDrawIndirectCountAMD>,
+    pub cmd_draw_indirect_count_khr: Option<PFN_vkCmdDrawIndirectCountKHR>,
+    pub cmd_draw_mesh_tasks_indirect_count_nv: Option<PFN_vkCmdDrawMeshTasksIndirectCountNV>,
+    pub cmd_draw_mesh_tasks_indirect_nv: Option<PFN_vkCmdDrawMeshTasksIndirectNV>,
+    pub cmd_draw_mesh_tasks_nv: Option<PFN_vkCmdDrawMeshTasksNV>,
+    pub cmd_end_conditional_rendering_ext: Option<PFN_vkCmdEndConditionalRenderingEXT>,
+    pub cmd_end_debug_utils_label_ext: Option<PFN_vkCmdEndDebugUtilsLabelEXT>,
+    pub cmd_end_query_indexed_ext: Option<PFN_vkCmdEndQueryIndexedEXT>,
+    pub cmd_end_render_pass2_khr: Option<PFN_vkCmdEndRenderPass2KHR>,
+    pub cmd_end_transform_feedback_ext: Option<PFN_vkCmdEndTransformFeedbackEXT>,
+    pub cmd_insert_debug_utils_label_ext: Option<PFN_vkCmdInsertDebugUtilsLabelEXT>,
+    pub cmd_next_subpass2_khr: Option<PFN_vkCmdNextSubpass2KHR>,
+    pub cmd_process_commands_nvx: Option<PFN_vkCmdProcessCommandsNVX>,
+    pub cmd_push_descriptor_set_khr: Option<PFN_vkCmdPushDescriptorSetKHR>,
+    pub cmd_push_descriptor_set_with_template_khr:
+        Option<PFN_vkCmdPushDescriptorSetWithTemplateKHR>,
+    pub cmd_reserve_space_for_commands_nvx: Option<PFN_vkCmdReserveSpaceForCommandsNVX>,
+    pub cmd_set_checkpoint_nv: Option<PFN_vkCmdSetCheckpointNV>,
+    pub cmd_set_coarse_sample_order_nv: Option<PFN_vkCmdSetCoarseSampleOrderNV>,
+    pub cmd_set_device_mask: Option<PFN_vkCmdSetDeviceMask>,
+    pub cmd_set_discard_rectangle_ext: Option<PFN_vkCmdSetDiscardRectangleEXT>,
+    pub cmd_set_exclusive_scissor_nv: Option<PFN_vkCmdSetExclusiveScissorNV>,
+    pub cmd_set_sample_locations_ext: Option<PFN_vkCmdSetSampleLocationsEXT>,
+    pub cmd_set_viewport_shading_rate_palette_nv: Option<PFN_vkCmdSetViewportShadingRatePaletteNV>,
+    pub cmd_set_viewport_w_scaling_nv: Option<PFN_vkCmdSetViewportWScalingNV>,
+    pub cmd_trace_rays_nv: Option<PFN_vkCmdTraceRaysNV>,
+    pub cmd_write_acceleration_structures_properties_nv:
+        Option<PFN_vkCmdWriteAccelerationStructuresPropertiesNV>,
+    pub cmd_write_buffer_marker_amd: Option<PFN_vkCmdWriteBufferMarkerAMD>,
+    pub compile_deferred_nv: Option<PFN_vkCompileDeferredNV>,
+    pub create_acceleration_structure_nv: Option<PFN_vkCreateAccelerationStructureNV>,
+    pub create_descriptor_update_template: Option<PFN_vkCreateDescriptorUpdateTemplate>,
+    pub create_indirect_commands_layout_nvx: Option<PFN_vkCreateIndirectCommandsLayoutNVX>,
+    pub create_object_table_nvx: Option<PFN_vkCreateObjectTableNVX>,
+    pub create_ray_tracing_pipelines_nv: Option<PFN_vkCreateRayTracingPipelinesNV>,
+    pub create_render_pass2_khr: Option<PFN_vkCreateRenderPass2KHR>,
+    pub create_sampler_ycbcr_conversion: Option<PFN_vkCreateSamplerYcbcrConversion>,
+    pub create_shared_swapchains_khr: Option<PFN_vkCreateSharedSwapchainsKHR>,
+    pub create_swapchain_khr: Option<PFN_vkCreateSwapchainKHR>,
+    pub create_validation_cache_ext: Option<PFN_vkCreateValidationCacheEXT>,
+    pub debug_marker_set_object_name_ext: Option<PFN_vkDebugMarkerSetObjectNameEXT>,
+    pub debug_marker_set_object_tag_ext: Option<PFN_vkDebugMarkerSetObjectTagEXT>,
+    pub destroy_acceleration_structure_nv: Option<PFN_vkDestroyAccelerationStructureNV>,
+    pub destroy_descriptor_update_template: Option<PFN_vkDestroyDescriptorUpdateTemplate>,
+    pub destroy_indirect_commands_layout_nvx: Option<PFN_vkDestroyIndirectCommandsLayoutNVX>,
+    pub destroy_object_table_nvx: Option<PFN_vkDestroyObjectTableNVX>,
+    pub destroy_sampler_ycbcr_conversion: Option<PFN_vkDestroySamplerYcbcrConversion>,
+    pub destroy_swapchain_khr: Option<PFN_vkDestroySwapchainKHR>,
+    pub destroy_validation_cache_ext: Option<PFN_vkDestroyValidationCacheEXT>,
+    pub display_power_control_ext: Option<PFN_vkDisplayPowerControlEXT>,
+    pub get_acceleration_structure_handle_nv: Option<PFN_vkGetAccelerationStructureHandleNV>,
+    pub get_acceleration_structure_memory_requirements_nv:
+        Option<PFN_vkGetAccelerationStructureMemoryRequirementsNV>,
+    pub get_android_hardware_buffer_properties_android:
+        Option<PFN_vkGetAndroidHardwareBufferPropertiesANDROID>,
+    pub get_buffer_memory_requirements2: Option<PFN_vkGetBufferMemoryRequirements2>,
+    pub get_calibrated_timestamps_ext: Option<PFN_vkGetCalibratedTimestampsEXT>,
+    pub get_descriptor_set_layout_support: Option<PFN_vkGetDescriptorSetLayoutSupport>,
+    pub get_device_group_peer_memory_features: Option<PFN_vkGetDeviceGroupPeerMemoryFeatures>,
+    pub get_device_group_present_capabilities_khr:
+        Option<PFN_vkGetDeviceGroupPresentCapabilitiesKHR>,
+    pub get_device_group_surface_present_modes_khr:
+        Option<PFN_vkGetDeviceGroupSurfacePresentModesKHR>,
+    pub get_device_queue2: Option<PFN_vkGetDeviceQueue2>,
+    pub get_fence_fd_khr: Option<PFN_vkGetFenceFdKHR>,
+    pub get_fence_win32_handle_khr: Option<PFN_vkGetFenceWin32HandleKHR>,
+    pub get_image_drm_format_modifier_properties_ext:
+        Option<PFN_vkGetImageDrmFormatModifierPropertiesEXT>,
+    pub get_image_memory_requirements2: Option<PFN_vkGetImageMemoryRequirements2>,
+    pub get_image_sparse_memory_requirements2: Option<PFN_vkGetImageSparseMemoryRequirements2>,
+    pub get_memory_android_hardware_buffer_android:
+        Option<PFN_vkGetMemoryAndroidHardwareBufferANDROID>,
+    pub get_memory_fd_khr: Option<PFN_vkGetMemoryFdKHR>,
+    pub get_memory_fd_properties_khr: Option<PFN_vkGetMemoryFdPropertiesKHR>,
+    pub get_memory_host_pointer_properties_ext: Option<PFN_vkGetMemoryHostPointerPropertiesEXT>,
+    pub get_memory_win32_handle_khr: Option<PFN_vkGetMemoryWin32HandleKHR>,
+    pub get_memory_win32_handle_nv: Option<PFN_vkGetMemoryWin32HandleNV>,
+    pub get_memory_win32_handle_properties_khr: Option<PFN_vkGetMemoryWin32HandlePropertiesKHR>,
+    pub get_past_presentation_timing_google: Option<PFN_vkGetPastPresentationTimingGOOGLE>,
+    pub get_queue_checkpoint_data_nv: Option<PFN_vkGetQueueCheckpointDataNV>,
+    pub get_ray_tracing_shader_group_handles_nv: Option<PFN_vkGetRayTracingShaderGroupHandlesNV>,
+    pub get_refresh_cycle_duration_google: Option<PFN_vkGetRefreshCycleDurationGOOGLE>,
+    pub get_semaphore_fd_khr: Option<PFN_vkGetSemaphoreFdKHR>,
+    pub get_semaphore_win32_handle_khr: Option<PFN_vkGetSemaphoreWin32HandleKHR>,
+    pub get_shader_info_amd: Option<PFN_vkGetShaderInfoAMD>,
+    pub get_swapchain_counter_ext: Option<PFN_vkGetSwapchainCounterEXT>,
+    pub get_swapchain_gralloc_usage_android: Option<PFN_vkGetSwapchainGrallocUsageANDROID>,
+    pub get_swapchain_images_khr: Option<PFN_vkGetSwapchainImagesKHR>,
+    pub get_swapchain_status_khr: Option<PFN_vkGetSwapchainStatusKHR>,
+    pub get_validation_cache_data_ext: Option<PFN_vkGetValidationCacheDataEXT>,
+    pub import_fence_fd_khr: Option<PFN_vkImportFenceFdKHR>,
+    pub import_fence_win32_handle_khr: Option<PFN_vkImportFenceWin32HandleKHR>,
+    pub import_semaphore_fd_khr: Option<PFN_vkImportSemaphoreFdKHR>,
+    pub import_semaphore_win32_handle_khr: Option<PFN_vkImportSemaphoreWin32HandleKHR>,
+    pub merge_validation_caches_ext: Option<PFN_vkMergeValidationCachesEXT>,
+    pub queue_begin_debug_utils_label_ext: Option<PFN_vkQueueBeginDebugUtilsLabelEXT>,
+    pub queue_end_debug_utils_label_ext: Option<PFN_vkQueueEndDebugUtilsLabelEXT>,
+    pub queue_insert_debug_utils_label_ext: Option<PFN_vkQueueInsertDebugUtilsLabelEXT>,
+    pub queue_present_khr: Option<PFN_vkQueuePresentKHR>,
+    pub queue_signal_release_image_android: Option<PFN_vkQueueSignalReleaseImageANDROID>,
+    pub register_device_event_ext: Option<PFN_vkRegisterDeviceEventEXT>,
+    pub register_display_event_ext: Option<PFN_vkRegisterDisplayEventEXT>,
+    pub register_objects_nvx: Option<PFN_vkRegisterObjectsNVX>,
+    pub set_debug_utils_object_name_ext: Option<PFN_vkSetDebugUtilsObjectNameEXT>,
+    pub set_debug_utils_object_tag_ext: Option<PFN_vkSetDebugUtilsObjectTagEXT>,
+    pub set_hdr_metadata_ext: Option<PFN_vkSetHdrMetadataEXT>,
+    pub trim_command_pool: Option<PFN_vkTrimCommandPool>,
+    pub unregister_objects_nvx: Option<PFN_vkUnregisterObjectsNVX>,
+    pub update_descriptor_set_with_template: Option<PFN_vkUpdateDescriptorSetWithTemplate>,
+}
+impl DeviceExtensionFn {
+    pub(crate) unsafe fn load<F: FnMut(&CStr) -> *const c_void>(mut f: F) -> Self {
+        DeviceExtensionFn {
+            acquire_image_android: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkAcquireImageANDROID\0",
+            ))),
+            acquire_next_image2_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkAcquireNextImage2KHR\0",
+            ))),
+            acquire_next_image_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkAcquireNextImageKHR\0",
+            ))),
+            bind_acceleration_structure_memory_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkBindAccelerationStructureMemoryNV\0"),
+            )),
+            bind_buffer_memory2: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkBindBufferMemory2\0",
+            ))),
+            bind_image_memory2: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkBindImageMemory2\0",
+            ))),
+            cmd_begin_conditional_rendering_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginConditionalRenderingEXT\0"),
+            )),
+            cmd_begin_debug_utils_label_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginDebugUtilsLabelEXT\0"),
+            )),
+            cmd_begin_query_indexed_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdBeginQueryIndexedEXT\0",
+            ))),
+            cmd_begin_render_pass2_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdBeginRenderPass2KHR\0",
+            ))),
+            cmd_begin_transform_feedback_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginTransformFeedbackEXT\0"),
+            )),
+            cmd_bind_shading_rate_image_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdBindShadingRateImageNV\0",
+            ))),
+            cmd_bind_transform_feedback_buffers_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdBindTransformFeedbackBuffersEXT\0"),
+            )),
+            cmd_build_acceleration_structure_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildAccelerationStructureNV\0"),
+            )),
+            cmd_copy_acceleration_structure_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyAccelerationStructureNV\0"),
+            )),
+            cmd_debug_marker_begin_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdDebugMarkerBeginEXT\0",
+            ))),
+            cmd_debug_marker_end_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdDebugMarkerEndEXT\0",
+            ))),
+            cmd_debug_marker_insert_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdDebugMarkerInsertEXT\0",
+            ))),
+            cmd_dispatch_base: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdDispatchBase\0",
+            ))),
+            cmd_draw_indexed_indirect_count_amd: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirectCountAMD\0"),
+            )),
+            cmd_draw_indexed_indirect_count_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirectCountKHR\0"),
+            )),
+            cmd_draw_indirect_byte_count_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectByteCountEXT\0"),
+            )),
+            cmd_draw_indirect_count_amd: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdDrawIndirectCountAMD\0",
+            ))),
+            cmd_draw_indirect_count_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdDrawIndirectCountKHR\0",
+            ))),
+            cmd_draw_mesh_tasks_indirect_count_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectCountNV\0"),
+            )),
+            cmd_draw_mesh_tasks_indirect_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectNV\0"),
+            )),
+            cmd_draw_mesh_tasks_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdDrawMeshTasksNV\0",
+            ))),
+            cmd_end_conditional_rendering_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdEndConditionalRenderingEXT\0"),
+            )),
+            cmd_end_debug_utils_label_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdEndDebugUtilsLabelEXT\0",
+            ))),
+            cmd_end_query_indexed_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdEndQueryIndexedEXT\0",
+            ))),
+            cmd_end_render_pass2_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdEndRenderPass2KHR\0",
+            ))),
+            cmd_end_transform_feedback_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdEndTransformFeedbackEXT\0",
+            ))),
+            cmd_insert_debug_utils_label_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdInsertDebugUtilsLabelEXT\0"),
+            )),
+            cmd_next_subpass2_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdNextSubpass2KHR\0",
+            ))),
+            cmd_process_commands_nvx: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdProcessCommandsNVX\0",
+            ))),
+            cmd_push_descriptor_set_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdPushDescriptorSetKHR\0",
+            ))),
+            cmd_push_descriptor_set_with_template_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdPushDescriptorSetWithTemplateKHR\0"),
+            )),
+            cmd_reserve_space_for_commands_nvx: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdReserveSpaceForCommandsNVX\0"),
+            )),
+            cmd_set_checkpoint_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdSetCheckpointNV\0",
+            ))),
+            cmd_set_coarse_sample_order_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdSetCoarseSampleOrderNV\0",
+            ))),
+            cmd_set_device_mask: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdSetDeviceMask\0",
+            ))),
+            cmd_set_discard_rectangle_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdSetDiscardRectangleEXT\0",
+            ))),
+            cmd_set_exclusive_scissor_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdSetExclusiveScissorNV\0",
+            ))),
+            cmd_set_sample_locations_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdSetSampleLocationsEXT\0",
+            ))),
+            cmd_set_viewport_shading_rate_palette_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportShadingRatePaletteNV\0"),
+            )),
+            cmd_set_viewport_w_scaling_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdSetViewportWScalingNV\0",
+            ))),
+            cmd_trace_rays_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdTraceRaysNV\0",
+            ))),
+            cmd_write_acceleration_structures_properties_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkCmdWriteAccelerationStructuresPropertiesNV\0",
+                ),
+            )),
+            cmd_write_buffer_marker_amd: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCmdWriteBufferMarkerAMD\0",
+            ))),
+            compile_deferred_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCompileDeferredNV\0",
+            ))),
+            create_acceleration_structure_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateAccelerationStructureNV\0"),
+            )),
+            create_descriptor_update_template: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorUpdateTemplate\0"),
+            )),
+            create_indirect_commands_layout_nvx: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateIndirectCommandsLayoutNVX\0"),
+            )),
+            create_object_table_nvx: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateObjectTableNVX\0",
+            ))),
+            create_ray_tracing_pipelines_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateRayTracingPipelinesNV\0"),
+            )),
+            create_render_pass2_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateRenderPass2KHR\0",
+            ))),
+            create_sampler_ycbcr_conversion: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkCreateSamplerYcbcrConversion\0"),
+            )),
+            create_shared_swapchains_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateSharedSwapchainsKHR\0",
+            ))),
+            create_swapchain_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateSwapchainKHR\0",
+            ))),
+            create_validation_cache_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkCreateValidationCacheEXT\0",
+            ))),
+            debug_marker_set_object_name_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkDebugMarkerSetObjectNameEXT\0"),
+            )),
+            debug_marker_set_object_tag_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkDebugMarkerSetObjectTagEXT\0"),
+            )),
+            destroy_acceleration_structure_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkDestroyAccelerationStructureNV\0"),
+            )),
+            destroy_descriptor_update_template: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorUpdateTemplate\0"),
+            )),
+            destroy_indirect_commands_layout_nvx: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkDestroyIndirectCommandsLayoutNVX\0"),
+            )),
+            destroy_object_table_nvx: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkDestroyObjectTableNVX\0",
+            ))),
+            destroy_sampler_ycbcr_conversion: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkDestroySamplerYcbcrConversion\0"),
+            )),
+            destroy_swapchain_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkDestroySwapchainKHR\0",
+            ))),
+            destroy_validation_cache_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkDestroyValidationCacheEXT\0",
+            ))),
+            display_power_control_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkDisplayPowerControlEXT\0",
+            ))),
+            get_acceleration_structure_handle_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetAccelerationStructureHandleNV\0"),
+            )),
+            get_acceleration_structure_memory_requirements_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetAccelerationStructureMemoryRequirementsNV\0",
+                ),
+            )),
+            get_android_hardware_buffer_properties_android: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetAndroidHardwareBufferPropertiesANDROID\0",
+                ),
+            )),
+            get_buffer_memory_requirements2: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetBufferMemoryRequirements2\0"),
+            )),
+            get_calibrated_timestamps_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetCalibratedTimestampsEXT\0",
+            ))),
+            get_descriptor_set_layout_support: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutSupport\0"),
+            )),
+            get_device_group_peer_memory_features: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupPeerMemoryFeatures\0"),
+            )),
+            get_device_group_present_capabilities_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupPresentCapabilitiesKHR\0"),
+            )),
+            get_device_group_surface_present_modes_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupSurfacePresentModesKHR\0"),
+            )),
+            get_device_queue2: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetDeviceQueue2\0",
+            ))),
+            get_fence_fd_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetFenceFdKHR\0",
+            ))),
+            get_fence_win32_handle_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetFenceWin32HandleKHR\0",
+            ))),
+            get_image_drm_format_modifier_properties_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetImageDrmFormatModifierPropertiesEXT\0"),
+            )),
+            get_image_memory_requirements2: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetImageMemoryRequirements2\0",
+            ))),
+            get_image_sparse_memory_requirements2: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetImageSparseMemoryRequirements2\0"),
+            )),
+            get_memory_android_hardware_buffer_android: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryAndroidHardwareBufferANDROID\0"),
+            )),
+            get_memory_fd_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetMemoryFdKHR\0",
+            ))),
+            get_memory_fd_properties_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetMemoryFdPropertiesKHR\0",
+            ))),
+            get_memory_host_pointer_properties_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryHostPointerPropertiesEXT\0"),
+            )),
+            get_memory_win32_handle_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetMemoryWin32HandleKHR\0",
+            ))),
+            get_memory_win32_handle_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetMemoryWin32HandleNV\0",
+            ))),
+            get_memory_win32_handle_properties_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandlePropertiesKHR\0"),
+            )),
+            get_past_presentation_timing_google: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPastPresentationTimingGOOGLE\0"),
+            )),
+            get_queue_checkpoint_data_nv: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetQueueCheckpointDataNV\0",
+            ))),
+            get_ray_tracing_shader_group_handles_nv: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetRayTracingShaderGroupHandlesNV\0"),
+            )),
+            get_refresh_cycle_duration_google: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetRefreshCycleDurationGOOGLE\0"),
+            )),
+            get_semaphore_fd_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetSemaphoreFdKHR\0",
+            ))),
+            get_semaphore_win32_handle_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetSemaphoreWin32HandleKHR\0",
+            ))),
+            get_shader_info_amd: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetShaderInfoAMD\0",
+            ))),
+            get_swapchain_counter_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetSwapchainCounterEXT\0",
+            ))),
+            get_swapchain_gralloc_usage_android: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainGrallocUsageANDROID\0"),
+            )),
+            get_swapchain_images_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetSwapchainImagesKHR\0",
+            ))),
+            get_swapchain_status_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetSwapchainStatusKHR\0",
+            ))),
+            get_validation_cache_data_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkGetValidationCacheDataEXT\0",
+            ))),
+            import_fence_fd_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkImportFenceFdKHR\0",
+            ))),
+            import_fence_win32_handle_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkImportFenceWin32HandleKHR\0",
+            ))),
+            import_semaphore_fd_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkImportSemaphoreFdKHR\0",
+            ))),
+            import_semaphore_win32_handle_khr: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkImportSemaphoreWin32HandleKHR\0"),
+            )),
+            merge_validation_caches_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkMergeValidationCachesEXT\0",
+            ))),
+            queue_begin_debug_utils_label_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkQueueBeginDebugUtilsLabelEXT\0"),
+            )),
+            queue_end_debug_utils_label_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkQueueEndDebugUtilsLabelEXT\0"),
+            )),
+            queue_insert_debug_utils_label_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkQueueInsertDebugUtilsLabelEXT\0"),
+            )),
+            queue_present_khr: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkQueuePresentKHR\0",
+            ))),
+            queue_signal_release_image_android: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkQueueSignalReleaseImageANDROID\0"),
+            )),
+            register_device_event_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkRegisterDeviceEventEXT\0",
+            ))),
+            register_display_event_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkRegisterDisplayEventEXT\0",
+            ))),
+            register_objects_nvx: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkRegisterObjectsNVX\0",
+            ))),
+            set_debug_utils_object_name_ext: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkSetDebugUtilsObjectNameEXT\0"),
+            )),
+            set_debug_utils_object_tag_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkSetDebugUtilsObjectTagEXT\0",
+            ))),
+            set_hdr_metadata_ext: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkSetHdrMetadataEXT\0",
+            ))),
+            trim_command_pool: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkTrimCommandPool\0",
+            ))),
+            unregister_objects_nvx: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(
+                b"vkUnregisterObjectsNVX\0",
+            ))),
+            update_descriptor_set_with_template: mem::transmute(f(
+                CStr::from_bytes_with_nul_unchecked(b"vkUpdateDescriptorSetWithTemplate\0"),
+            )),
+        }
+    }
+}
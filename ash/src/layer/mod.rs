@@ -0,0 +1,587 @@
+//! A framework for Vulkan layers written in Rust.
+//!
+//! A layer is a `cdylib` that implements `Layer` and `Commands`, and exports the entry points
+//! of the loader's layer interface with `export_layer!`. The overrides of `Commands` are
+//! called instead of the next layer in the chain; their default implementations forward to
+//! the next layer.
+//!
+//! ```ignore
+//! #[macro_use]
+//! extern crate ash;
+//! use ash::layer::{Commands, DeviceDispatch, InstanceDispatch, Layer};
+//! use ash::vk;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! struct SubmitCounter {
+//!     submits: AtomicUsize,
+//! }
+//!
+//! impl Layer for SubmitCounter {
+//!     fn new(_: &vk::InstanceCreateInfo, _: &InstanceDispatch) -> Self {
+//!         SubmitCounter {
+//!             submits: AtomicUsize::new(0),
+//!         }
+//!     }
+//! }
+//!
+//! impl Commands for SubmitCounter {
+//!     unsafe fn queue_submit(
+//!         &self,
+//!         next: &DeviceDispatch,
+//!         queue: vk::Queue,
+//!         submit_count: u32,
+//!         p_submits: *const vk::SubmitInfo,
+//!         fence: vk::Fence,
+//!     ) -> vk::Result {
+//!         self.submits.fetch_add(submit_count as usize, Ordering::Relaxed);
+//!         next.fp_v1_0().queue_submit(queue, submit_count, p_submits, fence)
+//!     }
+//! }
+//!
+//! export_layer!(SubmitCounter);
+//! ```
+//!
+//! The loader finds the layer through a JSON manifest with `"api_version"` and
+//! `"implementation_version"`, and a `"file_format_version"` of `"1.1.0"` or later, as only
+//! `vkNegotiateLoaderLayerInterfaceVersion` and the two `Get*ProcAddr` functions are exported.
+//!
+//! Every instance has its own `Layer`. The commands of its physical devices, devices, queues
+//! and command buffers are called on the `Layer` of the instance, and find it through the
+//! dispatch table pointer that the loader stores at the start of every dispatchable handle.
+//! Core and extension commands can be overridden. The commands that are not part of Vulkan 1.0
+//! are only intercepted if the next layer provides them, and their default implementations
+//! forward through `fp_extensions`. Commands that are called with a handle the layer did not
+//! see being created do nothing, and return `ERROR_INITIALIZATION_FAILED` if they return a
+//! `vk::Result`. So do commands whose override or `Layer::new` panics, as the panic must not
+//! unwind into the application.
+use std::any::Any;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::{Arc, RwLock};
+use vk;
+
+mod commands;
+
+pub use self::commands::{Commands, DeviceExtensionFn, InstanceExtensionFn};
+
+/// The state of a layer for one instance.
+pub trait Layer: Commands {
+    /// Called once the rest of the chain has created the instance.
+    fn new(create_info: &vk::InstanceCreateInfo, next: &InstanceDispatch) -> Self;
+}
+
+/// The instance commands of the next layer.
+pub struct InstanceDispatch {
+    handle: vk::Instance,
+    get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    instance_fn_1_0: vk::InstanceFnV1_0,
+    extension_fn: InstanceExtensionFn,
+}
+
+impl InstanceDispatch {
+    unsafe fn load(
+        instance: vk::Instance,
+        get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    ) -> Self {
+        InstanceDispatch {
+            handle: instance,
+            get_instance_proc_addr,
+            instance_fn_1_0: vk::InstanceFnV1_0::load(|name| {
+                mem::transmute(get_instance_proc_addr(instance, name.as_ptr()))
+            }),
+            extension_fn: InstanceExtensionFn::load(|name| {
+                mem::transmute(get_instance_proc_addr(instance, name.as_ptr()))
+            }),
+        }
+    }
+
+    pub fn handle(&self) -> vk::Instance {
+        self.handle
+    }
+
+    pub fn fp_v1_0(&self) -> &vk::InstanceFnV1_0 {
+        &self.instance_fn_1_0
+    }
+
+    pub fn fp_extensions(&self) -> &InstanceExtensionFn {
+        &self.extension_fn
+    }
+
+    /// Loads a command of the next layer that is not part of `fp_v1_0`.
+    pub unsafe fn get_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        (self.get_instance_proc_addr)(self.handle, name.as_ptr())
+    }
+}
+
+/// The device commands of the next layer.
+pub struct DeviceDispatch {
+    handle: vk::Device,
+    get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    device_fn_1_0: vk::DeviceFnV1_0,
+    extension_fn: DeviceExtensionFn,
+}
+
+impl DeviceDispatch {
+    unsafe fn load(device: vk::Device, get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr) -> Self {
+        DeviceDispatch {
+            handle: device,
+            get_device_proc_addr,
+            device_fn_1_0: vk::DeviceFnV1_0::load(|name| {
+                mem::transmute(get_device_proc_addr(device, name.as_ptr()))
+            }),
+            extension_fn: DeviceExtensionFn::load(|name| {
+                mem::transmute(get_device_proc_addr(device, name.as_ptr()))
+            }),
+        }
+    }
+
+    pub fn handle(&self) -> vk::Device {
+        self.handle
+    }
+
+    pub fn fp_v1_0(&self) -> &vk::DeviceFnV1_0 {
+        &self.device_fn_1_0
+    }
+
+    pub fn fp_extensions(&self) -> &DeviceExtensionFn {
+        &self.extension_fn
+    }
+
+    /// Loads a command of the next layer that is not part of `fp_v1_0`.
+    pub unsafe fn get_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        (self.get_device_proc_addr)(self.handle, name.as_ptr())
+    }
+}
+
+struct InstanceData<L> {
+    layer: L,
+    dispatch: InstanceDispatch,
+}
+
+struct DeviceData<L> {
+    instance: Arc<InstanceData<L>>,
+    dispatch: DeviceDispatch,
+}
+
+lazy_static! {
+    // The data of every instance and device, by dispatch key. The values are `InstanceData`
+    // and `DeviceData` of the exported layer, which can not be named in a static.
+    static ref INSTANCES: RwLock<HashMap<usize, Arc<dyn Any + Send + Sync>>> =
+        RwLock::new(HashMap::new());
+    static ref DEVICES: RwLock<HashMap<usize, Arc<dyn Any + Send + Sync>>> =
+        RwLock::new(HashMap::new());
+}
+
+unsafe fn dispatch_key<H: vk::Handle>(handle: H) -> Option<usize> {
+    let handle = handle.as_raw() as usize as *const usize;
+    if handle.is_null() {
+        None
+    } else {
+        Some(*handle)
+    }
+}
+
+/// `None` if `handle` was not created through this layer.
+unsafe fn lookup<H: vk::Handle + Copy, T: Any + Send + Sync>(
+    map: &RwLock<HashMap<usize, Arc<dyn Any + Send + Sync>>>,
+    handle: H,
+) -> Option<Arc<T>> {
+    let key = dispatch_key(handle)?;
+    let map = map.read().unwrap_or_else(|err| err.into_inner());
+    map.get(&key).cloned()?.downcast::<T>().ok()
+}
+
+unsafe fn instance_data<L: Layer, H: vk::Handle + Copy>(handle: H) -> Option<Arc<InstanceData<L>>> {
+    lookup(&INSTANCES, handle)
+}
+
+unsafe fn device_data<L: Layer, H: vk::Handle + Copy>(handle: H) -> Option<Arc<DeviceData<L>>> {
+    lookup(&DEVICES, handle)
+}
+
+unsafe fn insert<H: vk::Handle>(
+    map: &RwLock<HashMap<usize, Arc<dyn Any + Send + Sync>>>,
+    handle: H,
+    data: Arc<dyn Any + Send + Sync>,
+) {
+    if let Some(key) = dispatch_key(handle) {
+        map.write()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(key, data);
+    }
+}
+
+unsafe fn remove<H: vk::Handle>(
+    map: &RwLock<HashMap<usize, Arc<dyn Any + Send + Sync>>>,
+    handle: H,
+) {
+    if let Some(key) = dispatch_key(handle) {
+        map.write()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(&key);
+    }
+}
+
+/// The return types of the shims.
+///
+/// The shims are called by the loader through the Vulkan ABI, so they must not unwind. If
+/// their handle was not created through this layer, the next layer does not provide the
+/// command or the layer panics, `unforwarded()` is returned instead, e.g.
+/// `ERROR_INITIALIZATION_FAILED`.
+trait ShimReturn {
+    fn unforwarded() -> Self;
+}
+
+impl ShimReturn for () {
+    fn unforwarded() -> Self {}
+}
+
+impl ShimReturn for vk::Result {
+    fn unforwarded() -> Self {
+        vk::Result::ERROR_INITIALIZATION_FAILED
+    }
+}
+
+impl ShimReturn for vk::Bool32 {
+    fn unforwarded() -> Self {
+        vk::FALSE
+    }
+}
+
+/// Calls into the layer, and returns `unforwarded()` if it panics.
+fn catch<R: ShimReturn, F: FnOnce() -> R>(f: F) -> R {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| R::unforwarded())
+}
+
+/// `VkNegotiateLayerStructType` of the loader's layer interface.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NegotiateLayerStructType(pub i32);
+
+impl NegotiateLayerStructType {
+    pub const UNINITIALIZED: Self = NegotiateLayerStructType(0);
+    pub const INTERFACE_STRUCT: Self = NegotiateLayerStructType(1);
+}
+
+/// `VkNegotiateLayerInterface`, which the loader passes to
+/// `vkNegotiateLoaderLayerInterfaceVersion`.
+#[repr(C)]
+pub struct NegotiateLayerInterface {
+    pub s_type: NegotiateLayerStructType,
+    pub p_next: *mut c_void,
+    pub loader_layer_interface_version: u32,
+    pub pfn_get_instance_proc_addr: Option<vk::PFN_vkGetInstanceProcAddr>,
+    pub pfn_get_device_proc_addr: Option<vk::PFN_vkGetDeviceProcAddr>,
+    pub pfn_get_physical_device_proc_addr: Option<vk::PFN_vkGetInstanceProcAddr>,
+}
+
+/// The version of the loader's layer interface that is implemented.
+pub const LAYER_INTERFACE_VERSION: u32 = 2;
+
+// `VkLayerFunction` of the loader's create infos
+const LAYER_LINK_INFO: i32 = 0;
+
+#[repr(C)]
+struct LayerInstanceLink {
+    p_next: *mut LayerInstanceLink,
+    pfn_next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    pfn_next_get_physical_device_proc_addr: vk::PFN_vkVoidFunction,
+}
+
+#[repr(C)]
+struct LayerDeviceLink {
+    p_next: *mut LayerDeviceLink,
+    pfn_next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    pfn_next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+}
+
+/// `VkLayerInstanceCreateInfo` and `VkLayerDeviceCreateInfo`, which only differ in the type of
+/// the link.
+#[repr(C)]
+struct LayerCreateInfo<Link> {
+    s_type: vk::StructureType,
+    p_next: *const c_void,
+    function: i32,
+    p_layer_info: *mut Link,
+}
+
+/// Finds the link of this layer in the pNext chain of a create info, and moves the chain on to
+/// the next layer.
+unsafe fn next_link<Link>(mut p_next: *const c_void, s_type: vk::StructureType) -> *mut Link {
+    while !p_next.is_null() {
+        let info = p_next as *mut LayerCreateInfo<Link>;
+        if (*info).s_type == s_type && (*info).function == LAYER_LINK_INFO {
+            let link = (*info).p_layer_info;
+            if !link.is_null() {
+                // Links start with the pointer to the next link
+                (*info).p_layer_info = *(link as *const *mut Link);
+            }
+            return link;
+        }
+        p_next = (*info).p_next;
+    }
+    ptr::null_mut()
+}
+
+/// The implementation of `vkNegotiateLoaderLayerInterfaceVersion`, see `export_layer!`.
+pub unsafe fn negotiate_loader_layer_interface_version<L: Layer>(
+    p_version_struct: *mut NegotiateLayerInterface,
+) -> vk::Result {
+    let version = match p_version_struct.as_mut() {
+        Some(version) if version.s_type == NegotiateLayerStructType::INTERFACE_STRUCT => version,
+        _ => return vk::Result::ERROR_INITIALIZATION_FAILED,
+    };
+    if version.loader_layer_interface_version < LAYER_INTERFACE_VERSION {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    version.loader_layer_interface_version = LAYER_INTERFACE_VERSION;
+    version.pfn_get_instance_proc_addr = Some(instance_proc_addr_shim::<L>);
+    version.pfn_get_device_proc_addr = Some(device_proc_addr_shim::<L>);
+    version.pfn_get_physical_device_proc_addr = None;
+    vk::Result::SUCCESS
+}
+
+/// Returns the shim of this layer for `name`, if there is one.
+fn shim<L: Layer>(name: &str) -> Option<*const c_void> {
+    match name {
+        "vkGetInstanceProcAddr" => Some(instance_proc_addr_shim::<L> as *const c_void),
+        "vkGetDeviceProcAddr" => Some(device_proc_addr_shim::<L> as *const c_void),
+        "vkCreateInstance" => Some(create_instance::<L> as *const c_void),
+        "vkDestroyInstance" => Some(destroy_instance::<L> as *const c_void),
+        "vkCreateDevice" => Some(create_device::<L> as *const c_void),
+        "vkDestroyDevice" => Some(destroy_device::<L> as *const c_void),
+        _ => commands::instance_shim::<L>(name).or_else(|| commands::device_shim::<L>(name)),
+    }
+}
+
+/// The implementation of the exported `vkGetInstanceProcAddr`, see `export_layer!`.
+///
+/// `p_name` must be null or point to a null terminated string.
+pub unsafe extern "system" fn get_instance_proc_addr<L: Layer>(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    if p_name.is_null() {
+        return None;
+    }
+    let name = CStr::from_ptr(p_name);
+    let name_str = name.to_str().unwrap_or("");
+    if let Some(shim) = shim::<L>(name_str) {
+        return Some(mem::transmute(shim));
+    }
+    if instance == vk::Instance::null() {
+        return None;
+    }
+    let next = instance_data::<L, _>(instance)?
+        .dispatch
+        .get_proc_addr(name);
+    // Commands that are not part of Vulkan 1.0 are only intercepted if the next layer has them
+    let shim = commands::instance_extension_shim::<L>(name_str)
+        .or_else(|| commands::device_extension_shim::<L>(name_str));
+    match shim {
+        Some(shim) if next.is_some() => Some(mem::transmute(shim)),
+        _ => next,
+    }
+}
+
+/// The implementation of the exported `vkGetDeviceProcAddr`, see `export_layer!`.
+///
+/// `p_name` must be null or point to a null terminated string.
+pub unsafe extern "system" fn get_device_proc_addr<L: Layer>(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    if p_name.is_null() {
+        return None;
+    }
+    let name = CStr::from_ptr(p_name);
+    let name_str = name.to_str().unwrap_or("");
+    let shim = match name_str {
+        "vkGetDeviceProcAddr" => Some(device_proc_addr_shim::<L> as *const c_void),
+        "vkDestroyDevice" => Some(destroy_device::<L> as *const c_void),
+        _ => commands::device_shim::<L>(name_str),
+    };
+    if let Some(shim) = shim {
+        return Some(mem::transmute(shim));
+    }
+    let next = device_data::<L, _>(device)?.dispatch.get_proc_addr(name);
+    match commands::device_extension_shim::<L>(name_str) {
+        Some(shim) if next.is_some() => Some(mem::transmute(shim)),
+        _ => next,
+    }
+}
+
+// The loader calls the `Get*ProcAddr` functions through safe function pointers
+
+extern "system" fn instance_proc_addr_shim<L: Layer>(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe { get_instance_proc_addr::<L>(instance, p_name) }
+}
+
+extern "system" fn device_proc_addr_shim<L: Layer>(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe { get_device_proc_addr::<L>(device, p_name) }
+}
+
+extern "system" fn create_instance<L: Layer>(
+    p_create_info: *const vk::InstanceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    unsafe {
+        let link: *mut LayerInstanceLink = next_link(
+            (*p_create_info).p_next,
+            vk::StructureType::LOADER_INSTANCE_CREATE_INFO,
+        );
+        if link.is_null() {
+            return vk::Result::ERROR_INITIALIZATION_FAILED;
+        }
+        let get_instance_proc_addr = (*link).pfn_next_get_instance_proc_addr;
+        let create = get_instance_proc_addr(
+            vk::Instance::null(),
+            b"vkCreateInstance\0".as_ptr() as *const c_char,
+        );
+        let create: vk::PFN_vkCreateInstance = match create {
+            Some(create) => mem::transmute(create),
+            None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+        let result = create(p_create_info, p_allocator, p_instance);
+        if result != vk::Result::SUCCESS {
+            return result;
+        }
+        let dispatch = InstanceDispatch::load(*p_instance, get_instance_proc_addr);
+        let layer =
+            match panic::catch_unwind(AssertUnwindSafe(|| L::new(&*p_create_info, &dispatch))) {
+                Ok(layer) => layer,
+                Err(_) => {
+                    dispatch
+                        .fp_v1_0()
+                        .destroy_instance(*p_instance, p_allocator);
+                    *p_instance = vk::Instance::null();
+                    return vk::Result::ERROR_INITIALIZATION_FAILED;
+                }
+            };
+        insert(
+            &INSTANCES,
+            *p_instance,
+            Arc::new(InstanceData { layer, dispatch }),
+        );
+        result
+    }
+}
+
+extern "system" fn destroy_instance<L: Layer>(
+    instance: vk::Instance,
+    p_allocator: *const vk::AllocationCallbacks,
+) {
+    unsafe {
+        let data = match instance_data::<L, _>(instance) {
+            Some(data) => data,
+            None => return,
+        };
+        catch(|| {
+            data.layer
+                .destroy_instance(&data.dispatch, instance, p_allocator)
+        });
+        remove(&INSTANCES, instance);
+    }
+}
+
+extern "system" fn create_device<L: Layer>(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    unsafe {
+        let link: *mut LayerDeviceLink = next_link(
+            (*p_create_info).p_next,
+            vk::StructureType::LOADER_DEVICE_CREATE_INFO,
+        );
+        if link.is_null() {
+            return vk::Result::ERROR_INITIALIZATION_FAILED;
+        }
+        let get_device_proc_addr = (*link).pfn_next_get_device_proc_addr;
+        let instance = match instance_data::<L, _>(physical_device) {
+            Some(instance) => instance,
+            None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+        // The chain has moved on, so the next layer's vkCreateDevice sees its own link
+        let result = catch(|| {
+            instance.layer.create_device(
+                &instance.dispatch,
+                physical_device,
+                p_create_info,
+                p_allocator,
+                p_device,
+            )
+        });
+        if result != vk::Result::SUCCESS {
+            return result;
+        }
+        let dispatch = DeviceDispatch::load(*p_device, get_device_proc_addr);
+        insert(
+            &DEVICES,
+            *p_device,
+            Arc::new(DeviceData { instance, dispatch }),
+        );
+        result
+    }
+}
+
+extern "system" fn destroy_device<L: Layer>(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks,
+) {
+    unsafe {
+        let data = match device_data::<L, _>(device) {
+            Some(data) => data,
+            None => return,
+        };
+        catch(|| {
+            data.instance
+                .layer
+                .destroy_device(&data.dispatch, device, p_allocator)
+        });
+        remove(&DEVICES, device);
+    }
+}
+
+/// Exports `vkNegotiateLoaderLayerInterfaceVersion`, `vkGetInstanceProcAddr` and
+/// `vkGetDeviceProcAddr` for the `Layer` type `$layer`. Must be used once, in the root of a
+/// `cdylib`.
+#[macro_export]
+macro_rules! export_layer {
+    ($layer:ty) => {
+        #[no_mangle]
+        pub unsafe extern "system" fn vkNegotiateLoaderLayerInterfaceVersion(
+            p_version_struct: *mut $crate::layer::NegotiateLayerInterface,
+        ) -> $crate::vk::Result {
+            $crate::layer::negotiate_loader_layer_interface_version::<$layer>(p_version_struct)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn vkGetInstanceProcAddr(
+            instance: $crate::vk::Instance,
+            p_name: *const ::std::os::raw::c_char,
+        ) -> $crate::vk::PFN_vkVoidFunction {
+            $crate::layer::get_instance_proc_addr::<$layer>(instance, p_name)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn vkGetDeviceProcAddr(
+            device: $crate::vk::Device,
+            p_name: *const ::std::os::raw::c_char,
+        ) -> $crate::vk::PFN_vkVoidFunction {
+            $crate::layer::get_device_proc_addr::<$layer>(device, p_name)
+        }
+    };
+}
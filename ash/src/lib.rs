@@ -6,12 +6,42 @@ pub use device::Device;
 pub use entry::{Entry, InstanceError, LoadingError};
 pub use instance::Instance;
 
+/// Runs the checks of `validation` on the arguments of a wrapper, if the `validation` feature
+/// is enabled. Expands to nothing otherwise.
+#[cfg(feature = "validation")]
+macro_rules! validate {
+    ($command:expr, $checks:expr) => {
+        ::validation::check($command, $checks)
+    };
+}
+
+#[cfg(not(feature = "validation"))]
+macro_rules! validate {
+    ($command:expr, $checks:expr) => {};
+}
+
+pub mod allocator;
+pub mod capture;
+pub mod command_buffer;
+pub mod destruction;
 mod device;
+pub mod encoder;
 mod entry;
+#[cfg(feature = "typed_errors")]
+pub mod error;
 pub mod extensions;
 mod instance;
+pub mod layer;
+pub mod limits;
+pub mod managed;
+pub mod owned;
 pub mod prelude;
+pub mod sync;
+pub mod trace;
+pub mod tracking;
 pub mod util;
+#[cfg(feature = "validation")]
+pub mod validation;
 pub mod version;
 pub mod vk;
 pub trait RawPtr<T> {
@@ -0,0 +1,1157 @@
+//! Checks of create-infos against the limits, the enabled features and the enabled extensions
+//! of a device.
+//!
+//! Many drivers crash instead of failing when a create-info exceeds a limit or uses a feature
+//! that was not enabled. `LimitChecker` finds these mistakes before `create_*` is called, and
+//! describes them in a `LimitError`.
+//!
+//! ```no_run
+//! # use ash::limits::LimitChecker;
+//! # use ash::version::{DeviceV1_0, InstanceV1_0};
+//! # use ash::vk;
+//! # unsafe fn test(instance: &ash::Instance, physical_device: vk::PhysicalDevice, device: &ash::Device, device_create_info: &vk::DeviceCreateInfo) {
+//! let properties = instance.get_physical_device_properties(physical_device);
+//! let checker = LimitChecker::from_create_info(&properties, device_create_info);
+//! let create_info = vk::SamplerCreateInfo {
+//!     anisotropy_enable: vk::TRUE,
+//!     max_anisotropy: 16.0,
+//!     ..Default::default()
+//! };
+//! match checker.check_sampler(&create_info) {
+//!     Ok(()) => {
+//!         device.create_sampler(&create_info, None).unwrap();
+//!     }
+//!     // e.g. "SamplerCreateInfo.anisotropy_enable requires the sampler_anisotropy feature,
+//!     // which is not enabled"
+//!     Err(err) => eprintln!("{}", err),
+//! }
+//! # }
+//! ```
+//!
+//! Only the limits that can be checked from the create-info alone are checked. Limits that
+//! depend on the properties of a format, or on the shaders of a pipeline, are not.
+use std::collections::HashSet;
+use std::error::Error;
+use std::ffi::CStr;
+use std::fmt;
+use std::slice;
+use vk;
+
+const VERSION_1_1: u32 = (1 << 22) | (1 << 12);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LimitError {
+    /// `what` is larger than the limit `limit` of the device.
+    Exceeded {
+        what: String,
+        value: f64,
+        limit: &'static str,
+        max: f64,
+    },
+    /// `what` is smaller than the limit `limit` of the device.
+    BelowMinimum {
+        what: String,
+        value: f64,
+        limit: &'static str,
+        min: f64,
+    },
+    /// `what` is not one of the sample counts in `limit`.
+    UnsupportedSampleCount {
+        what: String,
+        samples: vk::SampleCountFlags,
+        limit: &'static str,
+    },
+    /// `what` requires a `PhysicalDeviceFeatures` member that was not enabled.
+    FeatureNotEnabled { what: String, feature: &'static str },
+    /// `what` requires an extension that was not enabled, and is not part of the API version
+    /// of the device.
+    ExtensionNotEnabled {
+        what: String,
+        extension: &'static str,
+    },
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LimitError::Exceeded {
+                ref what,
+                value,
+                limit,
+                max,
+            } => write!(
+                f,
+                "{} is {}, but the {} of the device is {}",
+                what, value, limit, max
+            ),
+            LimitError::BelowMinimum {
+                ref what,
+                value,
+                limit,
+                min,
+            } => write!(
+                f,
+                "{} is {}, but the {} of the device is {}",
+                what, value, limit, min
+            ),
+            LimitError::UnsupportedSampleCount {
+                ref what,
+                samples,
+                limit,
+            } => write!(
+                f,
+                "{} is {:?}, which is not in the {} of the device",
+                what, samples, limit
+            ),
+            LimitError::FeatureNotEnabled { ref what, feature } => write!(
+                f,
+                "{} requires the {} feature, which is not enabled",
+                what, feature
+            ),
+            LimitError::ExtensionNotEnabled {
+                ref what,
+                extension,
+            } => write!(f, "{} requires {}, which is not enabled", what, extension),
+        }
+    }
+}
+
+impl Error for LimitError {
+    fn description(&self) -> &str {
+        "LimitError"
+    }
+}
+
+/// The descriptors of the types in `types` that a pipeline layout may hold, in total and for
+/// each shader stage.
+struct DescriptorLimit {
+    types: &'static [vk::DescriptorType],
+    per_set: NamedLimit,
+    per_stage: Option<NamedLimit>,
+}
+
+type NamedLimit = (&'static str, fn(&vk::PhysicalDeviceLimits) -> u32);
+
+const DESCRIPTOR_LIMITS: &[DescriptorLimit] = &[
+    DescriptorLimit {
+        types: &[
+            vk::DescriptorType::SAMPLER,
+            vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
+        ],
+        per_set: ("max_descriptor_set_samplers", |l| {
+            l.max_descriptor_set_samplers
+        }),
+        per_stage: Some(("max_per_stage_descriptor_samplers", |l| {
+            l.max_per_stage_descriptor_samplers
+        })),
+    },
+    DescriptorLimit {
+        types: &[
+            vk::DescriptorType::UNIFORM_BUFFER,
+            vk::DescriptorType::UNIFORM_BUFFER_DYNAMIC,
+        ],
+        per_set: ("max_descriptor_set_uniform_buffers", |l| {
+            l.max_descriptor_set_uniform_buffers
+        }),
+        per_stage: Some(("max_per_stage_descriptor_uniform_buffers", |l| {
+            l.max_per_stage_descriptor_uniform_buffers
+        })),
+    },
+    DescriptorLimit {
+        types: &[vk::DescriptorType::UNIFORM_BUFFER_DYNAMIC],
+        per_set: ("max_descriptor_set_uniform_buffers_dynamic", |l| {
+            l.max_descriptor_set_uniform_buffers_dynamic
+        }),
+        per_stage: None,
+    },
+    DescriptorLimit {
+        types: &[
+            vk::DescriptorType::STORAGE_BUFFER,
+            vk::DescriptorType::STORAGE_BUFFER_DYNAMIC,
+        ],
+        per_set: ("max_descriptor_set_storage_buffers", |l| {
+            l.max_descriptor_set_storage_buffers
+        }),
+        per_stage: Some(("max_per_stage_descriptor_storage_buffers", |l| {
+            l.max_per_stage_descriptor_storage_buffers
+        })),
+    },
+    DescriptorLimit {
+        types: &[vk::DescriptorType::STORAGE_BUFFER_DYNAMIC],
+        per_set: ("max_descriptor_set_storage_buffers_dynamic", |l| {
+            l.max_descriptor_set_storage_buffers_dynamic
+        }),
+        per_stage: None,
+    },
+    DescriptorLimit {
+        types: &[
+            vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
+            vk::DescriptorType::SAMPLED_IMAGE,
+            vk::DescriptorType::UNIFORM_TEXEL_BUFFER,
+        ],
+        per_set: ("max_descriptor_set_sampled_images", |l| {
+            l.max_descriptor_set_sampled_images
+        }),
+        per_stage: Some(("max_per_stage_descriptor_sampled_images", |l| {
+            l.max_per_stage_descriptor_sampled_images
+        })),
+    },
+    DescriptorLimit {
+        types: &[
+            vk::DescriptorType::STORAGE_IMAGE,
+            vk::DescriptorType::STORAGE_TEXEL_BUFFER,
+        ],
+        per_set: ("max_descriptor_set_storage_images", |l| {
+            l.max_descriptor_set_storage_images
+        }),
+        per_stage: Some(("max_per_stage_descriptor_storage_images", |l| {
+            l.max_per_stage_descriptor_storage_images
+        })),
+    },
+    DescriptorLimit {
+        types: &[vk::DescriptorType::INPUT_ATTACHMENT],
+        per_set: ("max_descriptor_set_input_attachments", |l| {
+            l.max_descriptor_set_input_attachments
+        }),
+        per_stage: Some(("max_per_stage_descriptor_input_attachments", |l| {
+            l.max_per_stage_descriptor_input_attachments
+        })),
+    },
+];
+
+const SHADER_STAGES: &[vk::ShaderStageFlags] = &[
+    vk::ShaderStageFlags::VERTEX,
+    vk::ShaderStageFlags::TESSELLATION_CONTROL,
+    vk::ShaderStageFlags::TESSELLATION_EVALUATION,
+    vk::ShaderStageFlags::GEOMETRY,
+    vk::ShaderStageFlags::FRAGMENT,
+    vk::ShaderStageFlags::COMPUTE,
+];
+
+fn at_most<T: Into<f64>>(
+    what: &str,
+    value: T,
+    limit: &'static str,
+    max: T,
+) -> Result<(), LimitError> {
+    let (value, max) = (value.into(), max.into());
+    if value > max {
+        return Err(LimitError::Exceeded {
+            what: what.to_owned(),
+            value,
+            limit,
+            max,
+        });
+    }
+    Ok(())
+}
+
+fn at_least<T: Into<f64>>(
+    what: &str,
+    value: T,
+    limit: &'static str,
+    min: T,
+) -> Result<(), LimitError> {
+    let (value, min) = (value.into(), min.into());
+    if value < min {
+        return Err(LimitError::BelowMinimum {
+            what: what.to_owned(),
+            value,
+            limit,
+            min,
+        });
+    }
+    Ok(())
+}
+
+fn sample_count(
+    what: &str,
+    samples: vk::SampleCountFlags,
+    limit: &'static str,
+    supported: vk::SampleCountFlags,
+) -> Result<(), LimitError> {
+    if !supported.contains(samples) {
+        return Err(LimitError::UnsupportedSampleCount {
+            what: what.to_owned(),
+            samples,
+            limit,
+        });
+    }
+    Ok(())
+}
+
+unsafe fn slice_from<'a, T>(ptr: *const T, len: u32) -> &'a [T] {
+    if ptr.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+fn is_dual_source(factor: vk::BlendFactor) -> bool {
+    match factor {
+        vk::BlendFactor::SRC1_COLOR
+        | vk::BlendFactor::ONE_MINUS_SRC1_COLOR
+        | vk::BlendFactor::SRC1_ALPHA
+        | vk::BlendFactor::ONE_MINUS_SRC1_ALPHA => true,
+        _ => false,
+    }
+}
+
+fn same_blend(
+    a: &vk::PipelineColorBlendAttachmentState,
+    b: &vk::PipelineColorBlendAttachmentState,
+) -> bool {
+    a.blend_enable == b.blend_enable
+        && a.src_color_blend_factor == b.src_color_blend_factor
+        && a.dst_color_blend_factor == b.dst_color_blend_factor
+        && a.color_blend_op == b.color_blend_op
+        && a.src_alpha_blend_factor == b.src_alpha_blend_factor
+        && a.dst_alpha_blend_factor == b.dst_alpha_blend_factor
+        && a.alpha_blend_op == b.alpha_blend_op
+        && a.color_write_mask == b.color_write_mask
+}
+
+fn has_depth(format: vk::Format) -> bool {
+    match format {
+        vk::Format::D16_UNORM
+        | vk::Format::X8_D24_UNORM_PACK32
+        | vk::Format::D32_SFLOAT
+        | vk::Format::D16_UNORM_S8_UINT
+        | vk::Format::D24_UNORM_S8_UINT
+        | vk::Format::D32_SFLOAT_S8_UINT => true,
+        _ => false,
+    }
+}
+
+fn has_stencil(format: vk::Format) -> bool {
+    match format {
+        vk::Format::S8_UINT
+        | vk::Format::D16_UNORM_S8_UINT
+        | vk::Format::D24_UNORM_S8_UINT
+        | vk::Format::D32_SFLOAT_S8_UINT => true,
+        _ => false,
+    }
+}
+
+/// Checks create-infos against the limits, features and extensions of a device.
+#[derive(Clone, Debug)]
+pub struct LimitChecker {
+    api_version: u32,
+    limits: vk::PhysicalDeviceLimits,
+    features: vk::PhysicalDeviceFeatures,
+    extensions: HashSet<String>,
+}
+
+impl LimitChecker {
+    /// `features` and `extensions` are the ones the device was created with, not the ones the
+    /// physical device supports.
+    pub fn new(
+        properties: &vk::PhysicalDeviceProperties,
+        features: vk::PhysicalDeviceFeatures,
+        extensions: &[&CStr],
+    ) -> Self {
+        LimitChecker {
+            api_version: properties.api_version,
+            limits: properties.limits,
+            features,
+            extensions: extensions
+                .iter()
+                .map(|name| name.to_string_lossy().into_owned())
+                .collect(),
+        }
+    }
+
+    /// Takes the features and extensions from the create-info the device was created with.
+    pub unsafe fn from_create_info(
+        properties: &vk::PhysicalDeviceProperties,
+        create_info: &vk::DeviceCreateInfo,
+    ) -> Self {
+        let features = if create_info.p_enabled_features.is_null() {
+            vk::PhysicalDeviceFeatures::default()
+        } else {
+            *create_info.p_enabled_features
+        };
+        let extensions: Vec<&CStr> = slice_from(
+            create_info.pp_enabled_extension_names,
+            create_info.enabled_extension_count,
+        )
+        .iter()
+        .map(|&name| CStr::from_ptr(name))
+        .collect();
+        LimitChecker::new(properties, features, &extensions)
+    }
+
+    /// The API version of the device, which decides whether extensions that were promoted to
+    /// core need to be enabled. Defaults to the `api_version` of the physical device, but the
+    /// application may use a lower one.
+    pub fn api_version(mut self, api_version: u32) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    pub fn limits(&self) -> &vk::PhysicalDeviceLimits {
+        &self.limits
+    }
+
+    pub fn features(&self) -> &vk::PhysicalDeviceFeatures {
+        &self.features
+    }
+
+    pub fn has_extension(&self, name: &CStr) -> bool {
+        self.extensions.contains(&*name.to_string_lossy())
+    }
+
+    fn feature(
+        &self,
+        what: &str,
+        enabled: vk::Bool32,
+        feature: &'static str,
+    ) -> Result<(), LimitError> {
+        if enabled == vk::FALSE {
+            return Err(LimitError::FeatureNotEnabled {
+                what: what.to_owned(),
+                feature,
+            });
+        }
+        Ok(())
+    }
+
+    /// `core` is the API version the extension was promoted in.
+    fn extension(
+        &self,
+        what: &str,
+        extension: &'static str,
+        core: Option<u32>,
+    ) -> Result<(), LimitError> {
+        let promoted = core.map_or(false, |core| self.api_version >= core);
+        if !promoted && !self.extensions.contains(extension) {
+            return Err(LimitError::ExtensionNotEnabled {
+                what: what.to_owned(),
+                extension,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn check_image(&self, create_info: &vk::ImageCreateInfo) -> Result<(), LimitError> {
+        let limits = &self.limits;
+        let extent = &create_info.extent;
+        match create_info.image_type {
+            vk::ImageType::TYPE_1D => {
+                at_most(
+                    "ImageCreateInfo.extent.width",
+                    extent.width,
+                    "max_image_dimension1_d",
+                    limits.max_image_dimension1_d,
+                )?;
+            }
+            vk::ImageType::TYPE_2D => {
+                let (limit, max) = if create_info
+                    .flags
+                    .contains(vk::ImageCreateFlags::CUBE_COMPATIBLE)
+                {
+                    ("max_image_dimension_cube", limits.max_image_dimension_cube)
+                } else {
+                    ("max_image_dimension2_d", limits.max_image_dimension2_d)
+                };
+                at_most("ImageCreateInfo.extent.width", extent.width, limit, max)?;
+                at_most("ImageCreateInfo.extent.height", extent.height, limit, max)?;
+            }
+            vk::ImageType::TYPE_3D => {
+                let max = limits.max_image_dimension3_d;
+                at_most(
+                    "ImageCreateInfo.extent.width",
+                    extent.width,
+                    "max_image_dimension3_d",
+                    max,
+                )?;
+                at_most(
+                    "ImageCreateInfo.extent.height",
+                    extent.height,
+                    "max_image_dimension3_d",
+                    max,
+                )?;
+                at_most(
+                    "ImageCreateInfo.extent.depth",
+                    extent.depth,
+                    "max_image_dimension3_d",
+                    max,
+                )?;
+            }
+            _ => (),
+        }
+        at_most(
+            "ImageCreateInfo.array_layers",
+            create_info.array_layers,
+            "max_image_array_layers",
+            limits.max_image_array_layers,
+        )?;
+
+        let samples = create_info.samples;
+        let usage = create_info.usage;
+        if usage.contains(vk::ImageUsageFlags::COLOR_ATTACHMENT) {
+            sample_count(
+                "ImageCreateInfo.samples",
+                samples,
+                "framebuffer_color_sample_counts",
+                limits.framebuffer_color_sample_counts,
+            )?;
+        }
+        if usage.contains(vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT) {
+            if has_depth(create_info.format) {
+                sample_count(
+                    "ImageCreateInfo.samples",
+                    samples,
+                    "framebuffer_depth_sample_counts",
+                    limits.framebuffer_depth_sample_counts,
+                )?;
+            }
+            if has_stencil(create_info.format) {
+                sample_count(
+                    "ImageCreateInfo.samples",
+                    samples,
+                    "framebuffer_stencil_sample_counts",
+                    limits.framebuffer_stencil_sample_counts,
+                )?;
+            }
+        }
+        if usage.contains(vk::ImageUsageFlags::SAMPLED) {
+            if has_depth(create_info.format) {
+                sample_count(
+                    "ImageCreateInfo.samples",
+                    samples,
+                    "sampled_image_depth_sample_counts",
+                    limits.sampled_image_depth_sample_counts,
+                )?;
+            }
+            if has_stencil(create_info.format) {
+                sample_count(
+                    "ImageCreateInfo.samples",
+                    samples,
+                    "sampled_image_stencil_sample_counts",
+                    limits.sampled_image_stencil_sample_counts,
+                )?;
+            }
+            // Without the numeric type of the format, integer and float formats can not be
+            // told apart
+            if !has_depth(create_info.format) && !has_stencil(create_info.format) {
+                sample_count(
+                    "ImageCreateInfo.samples",
+                    samples,
+                    "sampled_image_color_sample_counts",
+                    limits.sampled_image_color_sample_counts
+                        | limits.sampled_image_integer_sample_counts,
+                )?;
+            }
+        }
+        if usage.contains(vk::ImageUsageFlags::STORAGE) && samples != vk::SampleCountFlags::TYPE_1 {
+            self.feature(
+                "ImageCreateInfo.samples of a storage image",
+                self.features.shader_storage_image_multisample,
+                "shader_storage_image_multisample",
+            )?;
+            sample_count(
+                "ImageCreateInfo.samples",
+                samples,
+                "storage_image_sample_counts",
+                limits.storage_image_sample_counts,
+            )?;
+        }
+
+        let flags = create_info.flags;
+        if flags.contains(vk::ImageCreateFlags::SPARSE_BINDING) {
+            self.feature(
+                "ImageCreateFlags::SPARSE_BINDING",
+                self.features.sparse_binding,
+                "sparse_binding",
+            )?;
+        }
+        if flags.contains(vk::ImageCreateFlags::SPARSE_RESIDENCY) {
+            match create_info.image_type {
+                vk::ImageType::TYPE_2D => self.feature(
+                    "ImageCreateFlags::SPARSE_RESIDENCY of a 2D image",
+                    self.features.sparse_residency_image2_d,
+                    "sparse_residency_image2_d",
+                )?,
+                vk::ImageType::TYPE_3D => self.feature(
+                    "ImageCreateFlags::SPARSE_RESIDENCY of a 3D image",
+                    self.features.sparse_residency_image3_d,
+                    "sparse_residency_image3_d",
+                )?,
+                _ => (),
+            }
+        }
+        if flags.contains(vk::ImageCreateFlags::SPARSE_ALIASED) {
+            self.feature(
+                "ImageCreateFlags::SPARSE_ALIASED",
+                self.features.sparse_residency_aliased,
+                "sparse_residency_aliased",
+            )?;
+        }
+        let extension_flags = [
+            (
+                vk::ImageCreateFlags::TYPE_2D_ARRAY_COMPATIBLE,
+                "ImageCreateFlags::TYPE_2D_ARRAY_COMPATIBLE",
+                "VK_KHR_maintenance1",
+                Some(VERSION_1_1),
+            ),
+            (
+                vk::ImageCreateFlags::BLOCK_TEXEL_VIEW_COMPATIBLE,
+                "ImageCreateFlags::BLOCK_TEXEL_VIEW_COMPATIBLE",
+                "VK_KHR_maintenance2",
+                Some(VERSION_1_1),
+            ),
+            (
+                vk::ImageCreateFlags::EXTENDED_USAGE,
+                "ImageCreateFlags::EXTENDED_USAGE",
+                "VK_KHR_maintenance2",
+                Some(VERSION_1_1),
+            ),
+            (
+                vk::ImageCreateFlags::SPLIT_INSTANCE_BIND_REGIONS,
+                "ImageCreateFlags::SPLIT_INSTANCE_BIND_REGIONS",
+                "VK_KHR_device_group",
+                Some(VERSION_1_1),
+            ),
+            (
+                vk::ImageCreateFlags::ALIAS,
+                "ImageCreateFlags::ALIAS",
+                "VK_KHR_bind_memory2",
+                Some(VERSION_1_1),
+            ),
+            (
+                vk::ImageCreateFlags::DISJOINT,
+                "ImageCreateFlags::DISJOINT",
+                "VK_KHR_sampler_ycbcr_conversion",
+                Some(VERSION_1_1),
+            ),
+            (
+                vk::ImageCreateFlags::CORNER_SAMPLED_NV,
+                "ImageCreateFlags::CORNER_SAMPLED_NV",
+                "VK_NV_corner_sampled_image",
+                None,
+            ),
+            (
+                vk::ImageCreateFlags::SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_EXT,
+                "ImageCreateFlags::SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_EXT",
+                "VK_EXT_sample_locations",
+                None,
+            ),
+        ];
+        for &(flag, what, extension, core) in &extension_flags {
+            if flags.contains(flag) {
+                self.extension(what, extension, core)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn check_buffer(&self, create_info: &vk::BufferCreateInfo) -> Result<(), LimitError> {
+        let flags = create_info.flags;
+        if flags.contains(vk::BufferCreateFlags::SPARSE_BINDING) {
+            self.feature(
+                "BufferCreateFlags::SPARSE_BINDING",
+                self.features.sparse_binding,
+                "sparse_binding",
+            )?;
+        }
+        if flags.contains(vk::BufferCreateFlags::SPARSE_RESIDENCY) {
+            self.feature(
+                "BufferCreateFlags::SPARSE_RESIDENCY",
+                self.features.sparse_residency_buffer,
+                "sparse_residency_buffer",
+            )?;
+        }
+        if flags.contains(vk::BufferCreateFlags::SPARSE_ALIASED) {
+            self.feature(
+                "BufferCreateFlags::SPARSE_ALIASED",
+                self.features.sparse_residency_aliased,
+                "sparse_residency_aliased",
+            )?;
+        }
+        let extension_usages = [
+            (
+                vk::BufferUsageFlags::TRANSFORM_FEEDBACK_BUFFER_EXT,
+                "BufferUsageFlags::TRANSFORM_FEEDBACK_BUFFER_EXT",
+                "VK_EXT_transform_feedback",
+            ),
+            (
+                vk::BufferUsageFlags::CONDITIONAL_RENDERING_EXT,
+                "BufferUsageFlags::CONDITIONAL_RENDERING_EXT",
+                "VK_EXT_conditional_rendering",
+            ),
+            (
+                vk::BufferUsageFlags::RAY_TRACING_NV,
+                "BufferUsageFlags::RAY_TRACING_NV",
+                "VK_NV_ray_tracing",
+            ),
+        ];
+        for &(usage, what, extension) in &extension_usages {
+            if create_info.usage.contains(usage) {
+                self.extension(what, extension, None)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn check_sampler(&self, create_info: &vk::SamplerCreateInfo) -> Result<(), LimitError> {
+        if create_info.anisotropy_enable != vk::FALSE {
+            self.feature(
+                "SamplerCreateInfo.anisotropy_enable",
+                self.features.sampler_anisotropy,
+                "sampler_anisotropy",
+            )?;
+            at_least(
+                "SamplerCreateInfo.max_anisotropy",
+                create_info.max_anisotropy,
+                "minimum anisotropy",
+                1.0,
+            )?;
+            at_most(
+                "SamplerCreateInfo.max_anisotropy",
+                create_info.max_anisotropy,
+                "max_sampler_anisotropy",
+                self.limits.max_sampler_anisotropy,
+            )?;
+        }
+        at_most(
+            "The magnitude of SamplerCreateInfo.mip_lod_bias",
+            create_info.mip_lod_bias.abs(),
+            "max_sampler_lod_bias",
+            self.limits.max_sampler_lod_bias,
+        )?;
+        let address_modes = [
+            (
+                "SamplerCreateInfo.address_mode_u",
+                create_info.address_mode_u,
+            ),
+            (
+                "SamplerCreateInfo.address_mode_v",
+                create_info.address_mode_v,
+            ),
+            (
+                "SamplerCreateInfo.address_mode_w",
+                create_info.address_mode_w,
+            ),
+        ];
+        for &(what, address_mode) in &address_modes {
+            // MIRROR_CLAMP_TO_EDGE, which `vk` does not define as it was a core enum before
+            // it moved to an extension
+            if address_mode.as_raw() == 4 {
+                self.extension(what, "VK_KHR_sampler_mirror_clamp_to_edge", None)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the fixed function state of a graphics pipeline. State that is dynamic is not
+    /// checked.
+    pub unsafe fn check_graphics_pipeline(
+        &self,
+        create_info: &vk::GraphicsPipelineCreateInfo,
+    ) -> Result<(), LimitError> {
+        let limits = &self.limits;
+        let features = &self.features;
+        let dynamic_states = create_info
+            .p_dynamic_state
+            .as_ref()
+            .map_or(&[][..], |state| {
+                slice_from(state.p_dynamic_states, state.dynamic_state_count)
+            });
+        let is_dynamic = |state| dynamic_states.contains(&state);
+
+        for stage in slice_from(create_info.p_stages, create_info.stage_count) {
+            let what = format!("The {:?} stage", stage.stage);
+            if stage.stage.contains(vk::ShaderStageFlags::GEOMETRY) {
+                self.feature(&what, features.geometry_shader, "geometry_shader")?;
+            }
+            if stage.stage.intersects(
+                vk::ShaderStageFlags::TESSELLATION_CONTROL
+                    | vk::ShaderStageFlags::TESSELLATION_EVALUATION,
+            ) {
+                self.feature(&what, features.tessellation_shader, "tessellation_shader")?;
+            }
+            if stage
+                .stage
+                .intersects(vk::ShaderStageFlags::TASK_NV | vk::ShaderStageFlags::MESH_NV)
+            {
+                self.extension(&what, "VK_NV_mesh_shader", None)?;
+            }
+        }
+
+        if let Some(state) = create_info.p_vertex_input_state.as_ref() {
+            at_most(
+                "PipelineVertexInputStateCreateInfo.vertex_binding_description_count",
+                state.vertex_binding_description_count,
+                "max_vertex_input_bindings",
+                limits.max_vertex_input_bindings,
+            )?;
+            at_most(
+                "PipelineVertexInputStateCreateInfo.vertex_attribute_description_count",
+                state.vertex_attribute_description_count,
+                "max_vertex_input_attributes",
+                limits.max_vertex_input_attributes,
+            )?;
+            let bindings = slice_from(
+                state.p_vertex_binding_descriptions,
+                state.vertex_binding_description_count,
+            );
+            for (i, binding) in bindings.iter().enumerate() {
+                at_most(
+                    &format!("VertexInputBindingDescription[{}].binding", i),
+                    binding.binding,
+                    "max_vertex_input_bindings",
+                    limits.max_vertex_input_bindings.saturating_sub(1),
+                )?;
+                at_most(
+                    &format!("VertexInputBindingDescription[{}].stride", i),
+                    binding.stride,
+                    "max_vertex_input_binding_stride",
+                    limits.max_vertex_input_binding_stride,
+                )?;
+            }
+            let attributes = slice_from(
+                state.p_vertex_attribute_descriptions,
+                state.vertex_attribute_description_count,
+            );
+            for (i, attribute) in attributes.iter().enumerate() {
+                at_most(
+                    &format!("VertexInputAttributeDescription[{}].location", i),
+                    attribute.location,
+                    "max_vertex_input_attributes",
+                    limits.max_vertex_input_attributes.saturating_sub(1),
+                )?;
+                at_most(
+                    &format!("VertexInputAttributeDescription[{}].offset", i),
+                    attribute.offset,
+                    "max_vertex_input_attribute_offset",
+                    limits.max_vertex_input_attribute_offset,
+                )?;
+            }
+        }
+
+        if let Some(state) = create_info.p_tessellation_state.as_ref() {
+            at_most(
+                "PipelineTessellationStateCreateInfo.patch_control_points",
+                state.patch_control_points,
+                "max_tessellation_patch_size",
+                limits.max_tessellation_patch_size,
+            )?;
+        }
+
+        if let Some(state) = create_info.p_viewport_state.as_ref() {
+            if state.viewport_count > 1 {
+                self.feature(
+                    "PipelineViewportStateCreateInfo.viewport_count above 1",
+                    features.multi_viewport,
+                    "multi_viewport",
+                )?;
+            }
+            if state.scissor_count > 1 {
+                self.feature(
+                    "PipelineViewportStateCreateInfo.scissor_count above 1",
+                    features.multi_viewport,
+                    "multi_viewport",
+                )?;
+            }
+            at_most(
+                "PipelineViewportStateCreateInfo.viewport_count",
+                state.viewport_count,
+                "max_viewports",
+                limits.max_viewports,
+            )?;
+            at_most(
+                "PipelineViewportStateCreateInfo.scissor_count",
+                state.scissor_count,
+                "max_viewports",
+                limits.max_viewports,
+            )?;
+            if !is_dynamic(vk::DynamicState::VIEWPORT) {
+                let viewports = slice_from(state.p_viewports, state.viewport_count);
+                let [min, max] = limits.viewport_bounds_range;
+                for (i, viewport) in viewports.iter().enumerate() {
+                    let what = |member| format!("Viewport[{}].{}", i, member);
+                    at_most(
+                        &what("width"),
+                        viewport.width,
+                        "max_viewport_dimensions",
+                        limits.max_viewport_dimensions[0] as f32,
+                    )?;
+                    at_most(
+                        &what("height"),
+                        viewport.height.abs(),
+                        "max_viewport_dimensions",
+                        limits.max_viewport_dimensions[1] as f32,
+                    )?;
+                    at_least(&what("x"), viewport.x, "viewport_bounds_range", min)?;
+                    at_least(&what("y"), viewport.y, "viewport_bounds_range", min)?;
+                    at_most(
+                        &what("x + width"),
+                        viewport.x + viewport.width,
+                        "viewport_bounds_range",
+                        max,
+                    )?;
+                    at_most(
+                        &what("y + height"),
+                        viewport.y + viewport.height,
+                        "viewport_bounds_range",
+                        max,
+                    )?;
+                }
+            }
+        }
+
+        if let Some(state) = create_info.p_rasterization_state.as_ref() {
+            if state.depth_clamp_enable != vk::FALSE {
+                self.feature(
+                    "PipelineRasterizationStateCreateInfo.depth_clamp_enable",
+                    features.depth_clamp,
+                    "depth_clamp",
+                )?;
+            }
+            if state.polygon_mode == vk::PolygonMode::LINE
+                || state.polygon_mode == vk::PolygonMode::POINT
+            {
+                self.feature(
+                    "PipelineRasterizationStateCreateInfo.polygon_mode",
+                    features.fill_mode_non_solid,
+                    "fill_mode_non_solid",
+                )?;
+            }
+            if state.depth_bias_enable != vk::FALSE
+                && state.depth_bias_clamp != 0.0
+                && !is_dynamic(vk::DynamicState::DEPTH_BIAS)
+            {
+                self.feature(
+                    "PipelineRasterizationStateCreateInfo.depth_bias_clamp",
+                    features.depth_bias_clamp,
+                    "depth_bias_clamp",
+                )?;
+            }
+            if !is_dynamic(vk::DynamicState::LINE_WIDTH) && state.line_width != 1.0 {
+                self.feature(
+                    "PipelineRasterizationStateCreateInfo.line_width other than 1.0",
+                    features.wide_lines,
+                    "wide_lines",
+                )?;
+                let [min, max] = limits.line_width_range;
+                at_least(
+                    "PipelineRasterizationStateCreateInfo.line_width",
+                    state.line_width,
+                    "line_width_range",
+                    min,
+                )?;
+                at_most(
+                    "PipelineRasterizationStateCreateInfo.line_width",
+                    state.line_width,
+                    "line_width_range",
+                    max,
+                )?;
+            }
+        }
+
+        if let Some(state) = create_info.p_multisample_state.as_ref() {
+            if state.sample_shading_enable != vk::FALSE {
+                self.feature(
+                    "PipelineMultisampleStateCreateInfo.sample_shading_enable",
+                    features.sample_rate_shading,
+                    "sample_rate_shading",
+                )?;
+            }
+            if state.alpha_to_one_enable != vk::FALSE {
+                self.feature(
+                    "PipelineMultisampleStateCreateInfo.alpha_to_one_enable",
+                    features.alpha_to_one,
+                    "alpha_to_one",
+                )?;
+            }
+        }
+
+        if let Some(state) = create_info.p_depth_stencil_state.as_ref() {
+            if state.depth_bounds_test_enable != vk::FALSE {
+                self.feature(
+                    "PipelineDepthStencilStateCreateInfo.depth_bounds_test_enable",
+                    features.depth_bounds,
+                    "depth_bounds",
+                )?;
+            }
+        }
+
+        if let Some(state) = create_info.p_color_blend_state.as_ref() {
+            if state.logic_op_enable != vk::FALSE {
+                self.feature(
+                    "PipelineColorBlendStateCreateInfo.logic_op_enable",
+                    features.logic_op,
+                    "logic_op",
+                )?;
+            }
+            at_most(
+                "PipelineColorBlendStateCreateInfo.attachment_count",
+                state.attachment_count,
+                "max_color_attachments",
+                limits.max_color_attachments,
+            )?;
+            let attachments = slice_from(state.p_attachments, state.attachment_count);
+            for (i, attachment) in attachments.iter().enumerate() {
+                let factors = [
+                    attachment.src_color_blend_factor,
+                    attachment.dst_color_blend_factor,
+                    attachment.src_alpha_blend_factor,
+                    attachment.dst_alpha_blend_factor,
+                ];
+                if attachment.blend_enable != vk::FALSE
+                    && factors.iter().any(|&factor| is_dual_source(factor))
+                {
+                    self.feature(
+                        &format!(
+                            "The SRC1 blend factor of PipelineColorBlendAttachmentState[{}]",
+                            i
+                        ),
+                        features.dual_src_blend,
+                        "dual_src_blend",
+                    )?;
+                }
+                if !same_blend(attachment, &attachments[0]) {
+                    self.feature(
+                        &format!(
+                            "PipelineColorBlendAttachmentState[{}], which differs from the first",
+                            i
+                        ),
+                        features.independent_blend,
+                        "independent_blend",
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the descriptor types of a pool, which may come from extensions. `p_pool_sizes`
+    /// must point to `pool_size_count` pool sizes.
+    pub unsafe fn check_descriptor_pool(
+        &self,
+        create_info: &vk::DescriptorPoolCreateInfo,
+    ) -> Result<(), LimitError> {
+        let pool_sizes = slice_from(create_info.p_pool_sizes, create_info.pool_size_count);
+        for (i, pool_size) in pool_sizes.iter().enumerate() {
+            let extension = match pool_size.ty {
+                vk::DescriptorType::INLINE_UNIFORM_BLOCK_EXT => "VK_EXT_inline_uniform_block",
+                vk::DescriptorType::ACCELERATION_STRUCTURE_NV => "VK_NV_ray_tracing",
+                _ => continue,
+            };
+            self.extension(&format!("DescriptorPoolSize[{}].ty", i), extension, None)?;
+        }
+        Ok(())
+    }
+
+    /// Checks the descriptors and push constants of a pipeline layout that is created from
+    /// descriptor set layouts with the bindings in `set_layouts`.
+    pub unsafe fn check_pipeline_layout(
+        &self,
+        set_layouts: &[&vk::DescriptorSetLayoutCreateInfo],
+        create_info: &vk::PipelineLayoutCreateInfo,
+    ) -> Result<(), LimitError> {
+        let limits = &self.limits;
+        at_most(
+            "PipelineLayoutCreateInfo.set_layout_count",
+            create_info.set_layout_count,
+            "max_bound_descriptor_sets",
+            limits.max_bound_descriptor_sets,
+        )?;
+        let ranges = slice_from(
+            create_info.p_push_constant_ranges,
+            create_info.push_constant_range_count,
+        );
+        for (i, range) in ranges.iter().enumerate() {
+            at_most(
+                &format!("The end of PushConstantRange[{}]", i),
+                range.offset.saturating_add(range.size),
+                "max_push_constants_size",
+                limits.max_push_constants_size,
+            )?;
+        }
+
+        let bindings: Vec<&vk::DescriptorSetLayoutBinding> = set_layouts
+            .iter()
+            .flat_map(|layout| slice_from(layout.p_bindings, layout.binding_count))
+            .collect();
+        for descriptor_limit in DESCRIPTOR_LIMITS {
+            let count = |stages: vk::ShaderStageFlags| -> u32 {
+                bindings
+                    .iter()
+                    .filter(|binding| {
+                        descriptor_limit.types.contains(&binding.descriptor_type)
+                            && binding.stage_flags.intersects(stages)
+                    })
+                    .fold(0u32, |count, binding| {
+                        count.saturating_add(binding.descriptor_count)
+                    })
+            };
+            let (limit, max) = descriptor_limit.per_set;
+            at_most(
+                &format!("The number of {:?} descriptors", descriptor_limit.types),
+                count(vk::ShaderStageFlags::ALL),
+                limit,
+                max(limits),
+            )?;
+            if let Some((limit, max)) = descriptor_limit.per_stage {
+                for &stage in SHADER_STAGES {
+                    at_most(
+                        &format!(
+                            "The number of {:?} descriptors in the {:?} stage",
+                            descriptor_limit.types, stage
+                        ),
+                        count(stage),
+                        limit,
+                        max(limits),
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the group counts of `cmd_dispatch`.
+    pub fn check_dispatch(
+        &self,
+        group_count_x: u32,
+        group_count_y: u32,
+        group_count_z: u32,
+    ) -> Result<(), LimitError> {
+        let max = self.limits.max_compute_work_group_count;
+        at_most(
+            "group_count_x",
+            group_count_x,
+            "max_compute_work_group_count[0]",
+            max[0],
+        )?;
+        at_most(
+            "group_count_y",
+            group_count_y,
+            "max_compute_work_group_count[1]",
+            max[1],
+        )?;
+        at_most(
+            "group_count_z",
+            group_count_z,
+            "max_compute_work_group_count[2]",
+            max[2],
+        )?;
+        Ok(())
+    }
+
+    /// Checks the `local_size` of a compute shader.
+    pub fn check_work_group_size(&self, x: u32, y: u32, z: u32) -> Result<(), LimitError> {
+        let max = self.limits.max_compute_work_group_size;
+        at_most("local_size_x", x, "max_compute_work_group_size[0]", max[0])?;
+        at_most("local_size_y", y, "max_compute_work_group_size[1]", max[1])?;
+        at_most("local_size_z", z, "max_compute_work_group_size[2]", max[2])?;
+        at_most(
+            "The number of invocations of a work group",
+            f64::from(x) * f64::from(y) * f64::from(z),
+            "max_compute_work_group_invocations",
+            f64::from(self.limits.max_compute_work_group_invocations),
+        )
+    }
+}
@@ -0,0 +1,290 @@
+//! Reference counted `Instance` and `Device` that are destroyed automatically.
+//!
+//! `Instance` and `Device` are plain function pointer tables, it is up to the application to
+//! destroy them in the right order. In managed mode every child holds an `Arc` to its parent:
+//!
+//! * `ManagedInstance` keeps the `Entry`, and therefore the Vulkan library, alive.
+//! * `ManagedDevice` keeps its `ManagedInstance` alive.
+//! * `InstanceExtension` and `DeviceExtension` keep the parent they were loaded from alive.
+//!
+//! `destroy_device` and `destroy_instance` are called when the last reference is dropped,
+//! children are always destroyed before their parents.
+//!
+//! ```no_run
+//! # use ash::extensions::khr::Swapchain;
+//! # use ash::managed::{ManagedDevice, ManagedInstance};
+//! # use ash::{vk, Entry};
+//! # unsafe fn test(
+//! #     entry: &Entry,
+//! #     instance_info: &vk::InstanceCreateInfo,
+//! #     physical_device: vk::PhysicalDevice,
+//! #     device_info: &vk::DeviceCreateInfo,
+//! # ) {
+//! let instance = ManagedInstance::new(entry, instance_info, None).unwrap();
+//! let device = ManagedDevice::new(&instance, physical_device, device_info, None).unwrap();
+//! let swapchain_loader = ManagedDevice::extension(&device, Swapchain::new);
+//! drop(instance);
+//! drop(device);
+//! // The device and the instance are destroyed once `swapchain_loader` is dropped.
+//! # }
+//! ```
+//!
+//! The managed types dereference to the `Instance` and `Device` they own, so all of their
+//! functions are available. `ManagedDevice` also implements `DeviceV1_0` and `DeviceV1_1` by
+//! forwarding to its `Device`, so it can be passed to generic code, e.g. `Owned`. Its
+//! `destroy_device` does nothing, the device is only destroyed with the last reference.
+//! Calling `destroy_instance` or `destroy_device` on the dereferenced `Instance` or `Device` is
+//! undefined behavior.
+//!
+//! The allocation callbacks passed to the managed types are called from whichever thread drops
+//! the last reference.
+use device::Device;
+use entry::{Entry, InstanceError};
+use instance::Instance;
+use owned::AllocationCallbacks;
+use prelude::*;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+use version::{DeviceV1_0, DeviceV1_1, EntryV1_0, InstanceV1_0};
+use vk;
+
+/// An `Instance` that is destroyed when the last reference to it is dropped.
+pub struct ManagedInstance {
+    instance: Instance,
+    allocation_callbacks: AllocationCallbacks,
+    entry: Entry,
+}
+
+impl ManagedInstance {
+    pub unsafe fn new(
+        entry: &Entry,
+        create_info: &vk::InstanceCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<Arc<Self>, InstanceError> {
+        let instance = entry.create_instance(create_info, allocation_callbacks)?;
+        Ok(Self::from_instance(entry, instance, allocation_callbacks))
+    }
+
+    /// Takes ownership of an `Instance` that was created from `entry` with
+    /// `allocation_callbacks`, which must be safe to call from any thread.
+    pub unsafe fn from_instance(
+        entry: &Entry,
+        instance: Instance,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Arc<Self> {
+        Arc::new(ManagedInstance {
+            instance,
+            allocation_callbacks: AllocationCallbacks::new(allocation_callbacks),
+            entry: entry.clone(),
+        })
+    }
+
+    pub fn entry(&self) -> &Entry {
+        &self.entry
+    }
+
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    /// Loads an instance level extension, e.g. `ManagedInstance::extension(&instance,
+    /// Surface::new)`. The returned wrapper keeps the instance alive.
+    pub fn extension<E, F>(this: &Arc<Self>, load: F) -> InstanceExtension<E>
+    where
+        F: FnOnce(&Entry, &Instance) -> E,
+    {
+        InstanceExtension {
+            extension: load(&this.entry, &this.instance),
+            instance: this.clone(),
+        }
+    }
+}
+
+impl Deref for ManagedInstance {
+    type Target = Instance;
+    fn deref(&self) -> &Instance {
+        &self.instance
+    }
+}
+
+impl fmt::Debug for ManagedInstance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ManagedInstance")
+            .field("handle", &self.handle())
+            .finish()
+    }
+}
+
+impl Drop for ManagedInstance {
+    fn drop(&mut self) {
+        unsafe {
+            self.instance
+                .destroy_instance(self.allocation_callbacks.as_ref());
+        }
+    }
+}
+
+/// A `Device` that is destroyed when the last reference to it is dropped. Holds a reference to
+/// the `ManagedInstance` it was created from.
+pub struct ManagedDevice {
+    device: Device,
+    allocation_callbacks: AllocationCallbacks,
+    instance: Arc<ManagedInstance>,
+}
+
+impl ManagedDevice {
+    pub unsafe fn new(
+        instance: &Arc<ManagedInstance>,
+        physical_device: vk::PhysicalDevice,
+        create_info: &vk::DeviceCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<Arc<Self>> {
+        let device = instance.create_device(physical_device, create_info, allocation_callbacks)?;
+        Ok(Self::from_device(instance, device, allocation_callbacks))
+    }
+
+    /// Takes ownership of a `Device` that was created from `instance` with
+    /// `allocation_callbacks`, which must be safe to call from any thread.
+    pub unsafe fn from_device(
+        instance: &Arc<ManagedInstance>,
+        device: Device,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Arc<Self> {
+        Arc::new(ManagedDevice {
+            device,
+            allocation_callbacks: AllocationCallbacks::new(allocation_callbacks),
+            instance: instance.clone(),
+        })
+    }
+
+    pub fn instance(&self) -> &Arc<ManagedInstance> {
+        &self.instance
+    }
+
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Loads a device level extension, e.g. `ManagedDevice::extension(&device,
+    /// Swapchain::new)`. The returned wrapper keeps the device alive.
+    pub fn extension<E, F>(this: &Arc<Self>, load: F) -> DeviceExtension<E>
+    where
+        F: FnOnce(&Instance, &Device) -> E,
+    {
+        DeviceExtension {
+            extension: load(&this.instance.instance, &this.device),
+            device: this.clone(),
+        }
+    }
+}
+
+impl Deref for ManagedDevice {
+    type Target = Device;
+    fn deref(&self) -> &Device {
+        &self.device
+    }
+}
+
+impl DeviceV1_0 for ManagedDevice {
+    fn handle(&self) -> vk::Device {
+        self.device.handle()
+    }
+
+    fn fp_v1_0(&self) -> &vk::DeviceFnV1_0 {
+        self.device.fp_v1_0()
+    }
+
+    /// Does nothing, the device is destroyed when the last reference to it is dropped.
+    unsafe fn destroy_device(&self, _allocation_callbacks: Option<&vk::AllocationCallbacks>) {}
+}
+
+impl DeviceV1_1 for ManagedDevice {
+    fn fp_v1_1(&self) -> &vk::DeviceFnV1_1 {
+        self.device.fp_v1_1()
+    }
+}
+
+impl fmt::Debug for ManagedDevice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ManagedDevice")
+            .field("handle", &self.handle())
+            .field("instance", &self.instance)
+            .finish()
+    }
+}
+
+impl Drop for ManagedDevice {
+    fn drop(&mut self) {
+        unsafe {
+            self.device
+                .destroy_device(self.allocation_callbacks.as_ref());
+        }
+    }
+}
+
+/// An instance level extension loader, such as `khr::Surface`, that keeps its instance alive.
+pub struct InstanceExtension<E> {
+    extension: E,
+    instance: Arc<ManagedInstance>,
+}
+
+impl<E> InstanceExtension<E> {
+    pub fn instance(&self) -> &Arc<ManagedInstance> {
+        &self.instance
+    }
+
+    /// Returns the extension loader and the instance it depends on.
+    pub fn into_inner(self) -> (E, Arc<ManagedInstance>) {
+        (self.extension, self.instance)
+    }
+}
+
+impl<E> Deref for InstanceExtension<E> {
+    type Target = E;
+    fn deref(&self) -> &E {
+        &self.extension
+    }
+}
+
+impl<E: Clone> Clone for InstanceExtension<E> {
+    fn clone(&self) -> Self {
+        InstanceExtension {
+            extension: self.extension.clone(),
+            instance: self.instance.clone(),
+        }
+    }
+}
+
+/// A device level extension loader, such as `khr::Swapchain`, that keeps its device alive.
+pub struct DeviceExtension<E> {
+    extension: E,
+    device: Arc<ManagedDevice>,
+}
+
+impl<E> DeviceExtension<E> {
+    pub fn device(&self) -> &Arc<ManagedDevice> {
+        &self.device
+    }
+
+    /// Returns the extension loader and the device it depends on.
+    pub fn into_inner(self) -> (E, Arc<ManagedDevice>) {
+        (self.extension, self.device)
+    }
+}
+
+impl<E> Deref for DeviceExtension<E> {
+    type Target = E;
+    fn deref(&self) -> &E {
+        &self.extension
+    }
+}
+
+impl<E: Clone> Clone for DeviceExtension<E> {
+    fn clone(&self) -> Self {
+        DeviceExtension {
+            extension: self.extension.clone(),
+            device: self.device.clone(),
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! Owning wrappers around device level handles.
+//!
+//! The handles in `vk` are plain `Copy` types that have to be destroyed manually with the
+//! matching `destroy_*` call. `Owned<T>` keeps the `Device` that created the handle alive and
+//! destroys the handle when it is dropped.
+//!
+//! ```no_run
+//! # use ash::vk;
+//! # use ash::owned::{self, OwnedBuffer};
+//! # use std::sync::Arc;
+//! # unsafe fn test(device: Arc<ash::Device>, info: &vk::BufferCreateInfo) -> ash::prelude::VkResult<()> {
+//! let buffer: OwnedBuffer = owned::create_buffer(&device, info, None)?;
+//! // `*buffer` is a `vk::Buffer` and can be passed to any `DeviceV1_0` function.
+//! drop(buffer); // calls `destroy_buffer`
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Nothing prevents dropping a handle that is still in use by the GPU. Use
+//! `Owned::destroy_after` to keep the handle alive until a fence signals, or a
+//! `destruction::DestructionQueue` to destroy many handles once the frame that used them has
+//! completed.
+//!
+//! Handles are destroyed on the thread that drops their owner, with the allocation callbacks
+//! they were created with. Those callbacks must be safe to call from any thread.
+use device::Device;
+use prelude::*;
+use std::fmt;
+use std::mem;
+use std::ops::Deref;
+use std::ptr;
+use std::sync::Arc;
+use version::DeviceV1_1;
+use vk;
+
+/// A handle that is owned by a `vk::Device` and destroyed with a `vkDestroy*` or `vkFree*`
+/// command.
+pub trait DeviceChild: vk::Handle + Copy {
+    /// Destroys the handle with the matching `destroy_*` call.
+    unsafe fn destroy<D: DeviceV1_1>(
+        self,
+        device: &D,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    );
+}
+
+macro_rules! device_child {
+    ($($name: ident => $destroy: ident),* $(,)*) => {
+        $(
+            impl DeviceChild for vk::$name {
+                unsafe fn destroy<D: DeviceV1_1>(
+                    self,
+                    device: &D,
+                    allocation_callbacks: Option<&vk::AllocationCallbacks>,
+                ) {
+                    device.$destroy(self, allocation_callbacks);
+                }
+            }
+        )*
+    };
+}
+
+device_child! {
+    Buffer => destroy_buffer,
+    BufferView => destroy_buffer_view,
+    CommandPool => destroy_command_pool,
+    DescriptorPool => destroy_descriptor_pool,
+    DescriptorSetLayout => destroy_descriptor_set_layout,
+    DescriptorUpdateTemplate => destroy_descriptor_update_template,
+    DeviceMemory => free_memory,
+    Event => destroy_event,
+    Fence => destroy_fence,
+    Framebuffer => destroy_framebuffer,
+    Image => destroy_image,
+    ImageView => destroy_image_view,
+    Pipeline => destroy_pipeline,
+    PipelineCache => destroy_pipeline_cache,
+    PipelineLayout => destroy_pipeline_layout,
+    QueryPool => destroy_query_pool,
+    RenderPass => destroy_render_pass,
+    Sampler => destroy_sampler,
+    SamplerYcbcrConversion => destroy_sampler_ycbcr_conversion,
+    Semaphore => destroy_semaphore,
+    ShaderModule => destroy_shader_module,
+}
+
+/// Owns a device level handle and destroys it on `Drop`.
+///
+/// `D` is the device the handle was created from. It defaults to `Device`, but any
+/// `DeviceV1_1` implementation works, e.g. `tracking::TrackingDevice`.
+pub struct Owned<T: DeviceChild, D: DeviceV1_1 = Device> {
+    handle: T,
+    device: Arc<D>,
+    allocation_callbacks: AllocationCallbacks,
+}
+
+/// `vk::AllocationCallbacks` that may be moved to and called from any thread.
+///
+/// `vk::AllocationCallbacks` contains raw pointers and therefore is neither `Send` nor `Sync`.
+/// The owners that store them destroy their handle on whichever thread drops them, so the
+/// callbacks must be callable from there, which `new` requires.
+#[derive(Clone, Copy)]
+pub(crate) struct AllocationCallbacks(Option<vk::AllocationCallbacks>);
+
+unsafe impl Send for AllocationCallbacks {}
+unsafe impl Sync for AllocationCallbacks {}
+
+impl AllocationCallbacks {
+    /// # Safety
+    ///
+    /// The callbacks and their user data must be safe to call from any thread.
+    pub(crate) unsafe fn new(allocation_callbacks: Option<&vk::AllocationCallbacks>) -> Self {
+        AllocationCallbacks(allocation_callbacks.cloned())
+    }
+
+    pub(crate) fn as_ref(&self) -> Option<&vk::AllocationCallbacks> {
+        self.0.as_ref()
+    }
+}
+
+pub type OwnedBuffer<D = Device> = Owned<vk::Buffer, D>;
+pub type OwnedBufferView<D = Device> = Owned<vk::BufferView, D>;
+pub type OwnedCommandPool<D = Device> = Owned<vk::CommandPool, D>;
+pub type OwnedDescriptorPool<D = Device> = Owned<vk::DescriptorPool, D>;
+pub type OwnedDescriptorSetLayout<D = Device> = Owned<vk::DescriptorSetLayout, D>;
+pub type OwnedDescriptorUpdateTemplate<D = Device> = Owned<vk::DescriptorUpdateTemplate, D>;
+pub type OwnedDeviceMemory<D = Device> = Owned<vk::DeviceMemory, D>;
+pub type OwnedEvent<D = Device> = Owned<vk::Event, D>;
+pub type OwnedFence<D = Device> = Owned<vk::Fence, D>;
+pub type OwnedFramebuffer<D = Device> = Owned<vk::Framebuffer, D>;
+pub type OwnedImage<D = Device> = Owned<vk::Image, D>;
+pub type OwnedImageView<D = Device> = Owned<vk::ImageView, D>;
+pub type OwnedPipeline<D = Device> = Owned<vk::Pipeline, D>;
+pub type OwnedPipelineCache<D = Device> = Owned<vk::PipelineCache, D>;
+pub type OwnedPipelineLayout<D = Device> = Owned<vk::PipelineLayout, D>;
+pub type OwnedQueryPool<D = Device> = Owned<vk::QueryPool, D>;
+pub type OwnedRenderPass<D = Device> = Owned<vk::RenderPass, D>;
+pub type OwnedSampler<D = Device> = Owned<vk::Sampler, D>;
+pub type OwnedSamplerYcbcrConversion<D = Device> = Owned<vk::SamplerYcbcrConversion, D>;
+pub type OwnedSemaphore<D = Device> = Owned<vk::Semaphore, D>;
+pub type OwnedShaderModule<D = Device> = Owned<vk::ShaderModule, D>;
+
+impl<T: DeviceChild, D: DeviceV1_1> Owned<T, D> {
+    /// Takes ownership of `handle`. `handle` must have been created from `device` with
+    /// `allocation_callbacks`, and must not be destroyed by anyone else. `allocation_callbacks`
+    /// must be safe to call from any thread.
+    pub unsafe fn from_raw(
+        device: Arc<D>,
+        handle: T,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Self {
+        Owned {
+            handle,
+            device,
+            allocation_callbacks: AllocationCallbacks::new(allocation_callbacks),
+        }
+    }
+
+    /// Releases ownership of the handle without destroying it.
+    pub fn into_raw(self) -> T {
+        let handle = self.handle;
+        unsafe {
+            // Releases the device without running `Drop for Owned`
+            let device = ptr::read(&self.device);
+            mem::forget(self);
+            drop(device);
+        }
+        handle
+    }
+
+    pub fn handle(&self) -> T {
+        self.handle
+    }
+
+    pub fn device(&self) -> &Arc<D> {
+        &self.device
+    }
+
+    pub fn allocation_callbacks(&self) -> Option<&vk::AllocationCallbacks> {
+        self.allocation_callbacks.as_ref()
+    }
+
+    /// Defers the destruction of the handle until `fence` is signaled. See `FenceDeferred`.
+    pub fn destroy_after(self, fence: vk::Fence) -> FenceDeferred<T, D> {
+        FenceDeferred {
+            owned: Some(self),
+            fence,
+        }
+    }
+}
+
+impl<T: DeviceChild, D: DeviceV1_1> Deref for Owned<T, D> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.handle
+    }
+}
+
+impl<T: DeviceChild + fmt::Debug, D: DeviceV1_1> fmt::Debug for Owned<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Owned")
+            .field("handle", &self.handle)
+            .field("device", &self.device.handle())
+            .finish()
+    }
+}
+
+impl<T: DeviceChild, D: DeviceV1_1> Drop for Owned<T, D> {
+    fn drop(&mut self) {
+        unsafe {
+            self.handle
+                .destroy(&*self.device, self.allocation_callbacks.as_ref());
+        }
+    }
+}
+
+/// A handle whose destruction is deferred until a fence is signaled, for example the fence of
+/// the last submission that uses the handle.
+///
+/// `poll` destroys the handle if the fence is signaled, and `wait` waits up to a timeout for it.
+/// The fence itself is not owned and must outlive this object.
+///
+/// # Blocking on drop
+///
+/// Dropping a `FenceDeferred` whose handle has not been destroyed yet **blocks the thread
+/// without a timeout** until the fence is signaled, which never happens if the fence is not
+/// submitted. Call `poll` or `wait` before dropping it, or take the handle back with `cancel`.
+pub struct FenceDeferred<T: DeviceChild, D: DeviceV1_1 = Device> {
+    owned: Option<Owned<T, D>>,
+    fence: vk::Fence,
+}
+
+impl<T: DeviceChild, D: DeviceV1_1> FenceDeferred<T, D> {
+    pub fn fence(&self) -> vk::Fence {
+        self.fence
+    }
+
+    /// Destroys the handle if the fence is signaled. Returns `Ok(true)` if the handle has been
+    /// destroyed, now or by an earlier call.
+    pub unsafe fn poll(&mut self) -> VkResult<bool> {
+        let status = match self.owned {
+            Some(ref owned) => owned.device.get_fence_status(self.fence)?,
+            None => return Ok(true),
+        };
+        match status {
+            FenceStatus::Signaled => {
+                self.owned = None;
+                Ok(true)
+            }
+            FenceStatus::NotReady => Ok(false),
+        }
+    }
+
+    /// Waits up to `timeout` nanoseconds for the fence and destroys the handle if it is signaled.
+    /// Returns `WaitResult::Success` if the handle has been destroyed, now or earlier.
+    pub unsafe fn wait(&mut self, timeout: u64) -> VkResult<WaitResult> {
+        let result = match self.owned {
+            Some(ref owned) => owned.device.wait_for_fences(&[self.fence], true, timeout)?,
+            None => return Ok(WaitResult::Success),
+        };
+        if result == WaitResult::Success {
+            self.owned = None;
+        }
+        Ok(result)
+    }
+
+    /// Takes back ownership of the handle without waiting for the fence.
+    pub fn cancel(mut self) -> Option<Owned<T, D>> {
+        self.owned.take()
+    }
+}
+
+impl<T: DeviceChild, D: DeviceV1_1> Drop for FenceDeferred<T, D> {
+    fn drop(&mut self) {
+        if let Some(owned) = self.owned.take() {
+            unsafe {
+                // Blocks forever if the fence is never signaled. If the device is lost the
+                // handle can be destroyed right away.
+                let _ = owned
+                    .device
+                    .wait_for_fences(&[self.fence], true, u64::max_value());
+            }
+            drop(owned);
+        }
+    }
+}
+
+macro_rules! create_fns {
+    ($($fn_name: ident($info: ty) -> $name: ident;)*) => {
+        $(
+            pub unsafe fn $fn_name<D: DeviceV1_1>(
+                device: &Arc<D>,
+                create_info: &$info,
+                allocation_callbacks: Option<&vk::AllocationCallbacks>,
+            ) -> VkResult<Owned<vk::$name, D>> {
+                let handle = device.$fn_name(create_info, allocation_callbacks)?;
+                Ok(Owned::from_raw(device.clone(), handle, allocation_callbacks))
+            }
+        )*
+    };
+}
+
+create_fns! {
+    create_buffer(vk::BufferCreateInfo) -> Buffer;
+    create_buffer_view(vk::BufferViewCreateInfo) -> BufferView;
+    create_command_pool(vk::CommandPoolCreateInfo) -> CommandPool;
+    create_descriptor_pool(vk::DescriptorPoolCreateInfo) -> DescriptorPool;
+    create_descriptor_set_layout(vk::DescriptorSetLayoutCreateInfo) -> DescriptorSetLayout;
+    create_descriptor_update_template(vk::DescriptorUpdateTemplateCreateInfo) -> DescriptorUpdateTemplate;
+    create_event(vk::EventCreateInfo) -> Event;
+    create_fence(vk::FenceCreateInfo) -> Fence;
+    create_framebuffer(vk::FramebufferCreateInfo) -> Framebuffer;
+    create_image(vk::ImageCreateInfo) -> Image;
+    create_image_view(vk::ImageViewCreateInfo) -> ImageView;
+    create_pipeline_cache(vk::PipelineCacheCreateInfo) -> PipelineCache;
+    create_pipeline_layout(vk::PipelineLayoutCreateInfo) -> PipelineLayout;
+    create_query_pool(vk::QueryPoolCreateInfo) -> QueryPool;
+    create_render_pass(vk::RenderPassCreateInfo) -> RenderPass;
+    create_sampler(vk::SamplerCreateInfo) -> Sampler;
+    create_sampler_ycbcr_conversion(vk::SamplerYcbcrConversionCreateInfo) -> SamplerYcbcrConversion;
+    create_semaphore(vk::SemaphoreCreateInfo) -> Semaphore;
+    create_shader_module(vk::ShaderModuleCreateInfo) -> ShaderModule;
+    allocate_memory(vk::MemoryAllocateInfo) -> DeviceMemory;
+}
+
+/// Pipelines that were created before an error occurred are destroyed.
+pub unsafe fn create_graphics_pipelines<D: DeviceV1_1>(
+    device: &Arc<D>,
+    pipeline_cache: vk::PipelineCache,
+    create_infos: &[vk::GraphicsPipelineCreateInfo],
+    allocation_callbacks: Option<&vk::AllocationCallbacks>,
+) -> VkResult<Vec<OwnedPipeline<D>>> {
+    let pipelines =
+        device.create_graphics_pipelines(pipeline_cache, create_infos, allocation_callbacks);
+    own_pipelines(device, pipelines, allocation_callbacks)
+}
+
+/// Pipelines that were created before an error occurred are destroyed.
+pub unsafe fn create_compute_pipelines<D: DeviceV1_1>(
+    device: &Arc<D>,
+    pipeline_cache: vk::PipelineCache,
+    create_infos: &[vk::ComputePipelineCreateInfo],
+    allocation_callbacks: Option<&vk::AllocationCallbacks>,
+) -> VkResult<Vec<OwnedPipeline<D>>> {
+    let pipelines =
+        device.create_compute_pipelines(pipeline_cache, create_infos, allocation_callbacks);
+    own_pipelines(device, pipelines, allocation_callbacks)
+}
+
+unsafe fn own_pipelines<D: DeviceV1_1>(
+    device: &Arc<D>,
+    pipelines: Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)>,
+    allocation_callbacks: Option<&vk::AllocationCallbacks>,
+) -> VkResult<Vec<OwnedPipeline<D>>> {
+    let (pipelines, err_code) = match pipelines {
+        Ok(pipelines) => (pipelines, vk::Result::SUCCESS),
+        Err((pipelines, err_code)) => (pipelines, err_code),
+    };
+    let owned: Vec<_> = pipelines
+        .into_iter()
+        .filter(|&pipeline| pipeline != vk::Pipeline::null())
+        .map(|pipeline| Owned::from_raw(device.clone(), pipeline, allocation_callbacks))
+        .collect();
+    match err_code {
+        vk::Result::SUCCESS => Ok(owned),
+        _ => Err(err_code),
+    }
+}
@@ -1,2 +1,121 @@
 use vk;
 pub type VkResult<T> = Result<T, vk::Result>;
+
+/// Non-error result of `vkGetFenceStatus`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FenceStatus {
+    /// `VK_SUCCESS`, the fence is signaled.
+    Signaled,
+    /// `VK_NOT_READY`, the fence is unsignaled.
+    NotReady,
+}
+
+/// Non-error result of `vkWaitForFences`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WaitResult {
+    /// `VK_SUCCESS`, the wait condition was satisfied.
+    Success,
+    /// `VK_TIMEOUT`, the wait condition was not satisfied within the timeout.
+    TimedOut,
+}
+
+/// Non-error result of `vkGetEventStatus`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EventStatus {
+    /// `VK_EVENT_SET`
+    Set,
+    /// `VK_EVENT_RESET`
+    Reset,
+}
+
+/// Non-error result of `vkGetQueryPoolResults`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum QueryResultStatus {
+    /// `VK_SUCCESS`, all requested results were written.
+    Ready,
+    /// `VK_NOT_READY`, some of the queries are unavailable. Only possible if
+    /// `QueryResultFlags::WAIT` was not set.
+    NotReady,
+}
+
+/// Non-error result of `vkAcquireNextImageKHR`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AcquireResult {
+    /// `VK_SUCCESS`, contains the index of the acquired image.
+    Success(u32),
+    /// `VK_SUBOPTIMAL_KHR`, an image was acquired but the swapchain no longer matches the
+    /// surface properties exactly.
+    Suboptimal(u32),
+    /// `VK_TIMEOUT`, no image became available within a non-zero timeout.
+    TimedOut,
+    /// `VK_NOT_READY`, no image was available and the timeout was zero.
+    NotReady,
+}
+
+impl AcquireResult {
+    /// Returns the index of the acquired image, if any.
+    pub fn image_index(&self) -> Option<u32> {
+        match *self {
+            AcquireResult::Success(index) | AcquireResult::Suboptimal(index) => Some(index),
+            AcquireResult::TimedOut | AcquireResult::NotReady => None,
+        }
+    }
+
+    pub fn is_suboptimal(&self) -> bool {
+        match *self {
+            AcquireResult::Suboptimal(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Non-error result of `vkQueuePresentKHR`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PresentResult {
+    /// `VK_SUCCESS`
+    Success,
+    /// `VK_SUBOPTIMAL_KHR`, the image was presented but the swapchain no longer matches the
+    /// surface properties exactly.
+    Suboptimal,
+}
+
+impl PresentResult {
+    pub fn is_suboptimal(&self) -> bool {
+        *self == PresentResult::Suboptimal
+    }
+}
+
+/// Non-error result of enumerations that write into a caller provided slice.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EnumerateResult {
+    /// `VK_SUCCESS`, all available values were written.
+    Complete,
+    /// `VK_INCOMPLETE`, the slice was too small to hold all available values.
+    Incomplete,
+}
+
+/// Runs the two-call idiom of the `vkEnumerate*`/`vkGet*` commands that return arrays. The
+/// query is repeated while the implementation reports `VK_INCOMPLETE`, which happens if the
+/// number of elements changed between the two calls.
+pub(crate) unsafe fn read_into_vec<T, F>(mut f: F) -> VkResult<Vec<T>>
+where
+    F: FnMut(&mut u32, *mut T) -> vk::Result,
+{
+    loop {
+        let mut count = 0;
+        let err_code = f(&mut count, ::std::ptr::null_mut());
+        if err_code != vk::Result::SUCCESS {
+            return Err(err_code);
+        }
+        let mut data = Vec::with_capacity(count as usize);
+        let err_code = f(&mut count, data.as_mut_ptr());
+        match err_code {
+            vk::Result::SUCCESS => {
+                data.set_len(count as usize);
+                return Ok(data);
+            }
+            vk::Result::INCOMPLETE => continue,
+            _ => return Err(err_code),
+        }
+    }
+}
@@ -0,0 +1,800 @@
+use super::ExternSync;
+pub(crate) static EXTERN_SYNC: &[ExternSync] = &[
+    ExternSync {
+        command: "vkAcquireNextImageKHR",
+        params: &["swapchain", "semaphore", "fence"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkAllocateCommandBuffers",
+        params: &["pAllocateInfo->commandPool"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkAllocateDescriptorSets",
+        params: &["pAllocateInfo->descriptorPool"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkBeginCommandBuffer",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkBindBufferMemory",
+        params: &["buffer"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkBindImageMemory",
+        params: &["image"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkCmdBeginConditionalRenderingEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBeginDebugUtilsLabelEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBeginQuery",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBeginQueryIndexedEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBeginRenderPass",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBeginRenderPass2KHR",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBeginTransformFeedbackEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBindDescriptorSets",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBindIndexBuffer",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBindPipeline",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBindShadingRateImageNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBindTransformFeedbackBuffersEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBindVertexBuffers",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBlitImage",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdBuildAccelerationStructureNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdClearAttachments",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdClearColorImage",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdClearDepthStencilImage",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdCopyAccelerationStructureNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdCopyBuffer",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdCopyBufferToImage",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdCopyImage",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdCopyImageToBuffer",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdCopyQueryPoolResults",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDebugMarkerBeginEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDebugMarkerEndEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDebugMarkerInsertEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDispatch",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDispatchBase",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDispatchIndirect",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDraw",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawIndexed",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawIndexedIndirect",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawIndexedIndirectCountAMD",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawIndexedIndirectCountKHR",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawIndirect",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawIndirectByteCountEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawIndirectCountAMD",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawIndirectCountKHR",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawMeshTasksIndirectCountNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawMeshTasksIndirectNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdDrawMeshTasksNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdEndConditionalRenderingEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdEndDebugUtilsLabelEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdEndQuery",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdEndQueryIndexedEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdEndRenderPass",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdEndRenderPass2KHR",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdEndTransformFeedbackEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdExecuteCommands",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdFillBuffer",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdInsertDebugUtilsLabelEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdNextSubpass",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdNextSubpass2KHR",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdPipelineBarrier",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdProcessCommandsNVX",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdPushConstants",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdPushDescriptorSetKHR",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdPushDescriptorSetWithTemplateKHR",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdReserveSpaceForCommandsNVX",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdResetEvent",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdResetQueryPool",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdResolveImage",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetBlendConstants",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetCheckpointNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetCoarseSampleOrderNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetDepthBias",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetDepthBounds",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetDeviceMask",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetDiscardRectangleEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetEvent",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetExclusiveScissorNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetLineWidth",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetSampleLocationsEXT",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetScissor",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetStencilCompareMask",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetStencilReference",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetStencilWriteMask",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetViewport",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetViewportShadingRatePaletteNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdSetViewportWScalingNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdTraceRaysNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdUpdateBuffer",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdWaitEvents",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdWriteAccelerationStructuresPropertiesNV",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdWriteBufferMarkerAMD",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCmdWriteTimestamp",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkCreateSharedSwapchainsKHR",
+        params: &["pCreateInfos[].surface", "pCreateInfos[].oldSwapchain"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkCreateSwapchainKHR",
+        params: &["pCreateInfo->surface", "pCreateInfo->oldSwapchain"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDebugMarkerSetObjectNameEXT",
+        params: &["pNameInfo->object"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDebugMarkerSetObjectTagEXT",
+        params: &["pTagInfo->object"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyAccelerationStructureNV",
+        params: &["accelerationStructure"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyBuffer",
+        params: &["buffer"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyBufferView",
+        params: &["bufferView"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyCommandPool",
+        params: &["commandPool"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyDebugReportCallbackEXT",
+        params: &["callback"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyDebugUtilsMessengerEXT",
+        params: &["messenger"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyDescriptorPool",
+        params: &["descriptorPool"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyDescriptorSetLayout",
+        params: &["descriptorSetLayout"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyDescriptorUpdateTemplate",
+        params: &["descriptorUpdateTemplate"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyDevice",
+        params: &["device"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyEvent",
+        params: &["event"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyFence",
+        params: &["fence"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyFramebuffer",
+        params: &["framebuffer"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyImage",
+        params: &["image"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyImageView",
+        params: &["imageView"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyIndirectCommandsLayoutNVX",
+        params: &["indirectCommandsLayout"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyInstance",
+        params: &["instance"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyObjectTableNVX",
+        params: &["objectTable"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyPipeline",
+        params: &["pipeline"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyPipelineCache",
+        params: &["pipelineCache"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyPipelineLayout",
+        params: &["pipelineLayout"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyQueryPool",
+        params: &["queryPool"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyRenderPass",
+        params: &["renderPass"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroySampler",
+        params: &["sampler"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroySamplerYcbcrConversion",
+        params: &["ycbcrConversion"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroySemaphore",
+        params: &["semaphore"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyShaderModule",
+        params: &["shaderModule"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroySurfaceKHR",
+        params: &["surface"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroySwapchainKHR",
+        params: &["swapchain"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkDestroyValidationCacheEXT",
+        params: &["validationCache"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkEndCommandBuffer",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkFreeCommandBuffers",
+        params: &["commandPool", "pCommandBuffers[]"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkFreeDescriptorSets",
+        params: &["descriptorPool", "pDescriptorSets[]"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkFreeMemory",
+        params: &["memory"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkGetPastPresentationTimingGOOGLE",
+        params: &["swapchain"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkGetRefreshCycleDurationGOOGLE",
+        params: &["swapchain"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkGetSwapchainStatusKHR",
+        params: &["swapchain"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkMapMemory",
+        params: &["memory"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkMergePipelineCaches",
+        params: &["dstCache"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkQueueBeginDebugUtilsLabelEXT",
+        params: &["queue"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkQueueBindSparse",
+        params: &[
+            "queue",
+            "fence",
+            "pBindInfo[].pWaitSemaphores[]",
+            "pBindInfo[].pSignalSemaphores[]",
+            "pBindInfo[].pBufferBinds[].buffer",
+            "pBindInfo[].pImageOpaqueBinds[].image",
+            "pBindInfo[].pImageBinds[].image",
+        ],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkQueueEndDebugUtilsLabelEXT",
+        params: &["queue"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkQueueInsertDebugUtilsLabelEXT",
+        params: &["queue"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkQueuePresentKHR",
+        params: &[
+            "queue",
+            "pPresentInfo->pWaitSemaphores[]",
+            "pPresentInfo->pSwapchains[]",
+        ],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkQueueSubmit",
+        params: &["queue", "fence"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkQueueWaitIdle",
+        params: &["queue"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkRegisterObjectsNVX",
+        params: &["objectTable"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkResetCommandBuffer",
+        params: &["commandBuffer"],
+        implicit: &["the VkCommandPool that commandBuffer was allocated from"],
+    },
+    ExternSync {
+        command: "vkResetCommandPool",
+        params: &["commandPool"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkResetDescriptorPool",
+        params: &["descriptorPool"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkResetEvent",
+        params: &["event"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkResetFences",
+        params: &["pFences[]"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkSetDebugUtilsObjectNameEXT",
+        params: &["pNameInfo->objectHandle"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkSetDebugUtilsObjectTagEXT",
+        params: &["pTagInfo->objectHandle"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkSetEvent",
+        params: &["event"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkTrimCommandPool",
+        params: &["commandPool"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkUnmapMemory",
+        params: &["memory"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkUnregisterObjectsNVX",
+        params: &["objectTable"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkUpdateDescriptorSetWithTemplate",
+        params: &["descriptorSet"],
+        implicit: &[],
+    },
+    ExternSync {
+        command: "vkUpdateDescriptorSets",
+        params: &["pDescriptorWrites[].dstSet", "pDescriptorCopies[].dstSet"],
+        implicit: &[],
+    },
+];
@@ -0,0 +1,637 @@
+//! External synchronization of Vulkan objects.
+//!
+//! vk.xml marks parameters that the application has to synchronize as `externsync`, e.g. the
+//! `queue` of `vkQueueSubmit`, the `descriptorPool` of `vkFreeDescriptorSets` or the command
+//! pool a command buffer was allocated from while it is recorded. `extern_sync` returns that
+//! metadata for every command.
+//!
+//! `SyncDevice` wraps a device and takes a lock on these objects for the duration of each call.
+//! To serialize recording command buffers of the same pool on different threads,
+//! `begin_recording` returns a `Recording` guard that keeps the command buffer and its command
+//! pool locked until it is ended or dropped. The locks are reentrant, a thread may record
+//! several command buffers of a pool at once.
+//!
+//! ```no_run
+//! # use ash::sync::{Misuse, SyncDevice};
+//! # use ash::version::DeviceV1_0;
+//! # use ash::vk;
+//! # unsafe fn test(device: ash::Device, queue: vk::Queue, submits: &[vk::SubmitInfo], command_buffer: vk::CommandBuffer) {
+//! let device = if cfg!(debug_assertions) {
+//!     SyncDevice::detect_misuse(device, |misuse: &Misuse| eprintln!("{}", misuse))
+//! } else {
+//!     SyncDevice::new(device)
+//! };
+//! // Safe to call from several threads with the same queue
+//! device.queue_submit(queue, submits, vk::Fence::null()).unwrap();
+//!
+//! let recording = device
+//!     .begin_recording(command_buffer, &vk::CommandBufferBeginInfo::default())
+//!     .unwrap();
+//! device.cmd_draw(recording.command_buffer(), 3, 1, 0, 0);
+//! recording.end().unwrap();
+//! # }
+//! ```
+//!
+//! Only calls made through the wrapper take locks. Commands of extension loaders and the
+//! `cmd_*` commands, which are covered by the lock of a `Recording`, do not.
+use device::Device;
+use prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+use std::sync::{Condvar, Mutex};
+use std::thread::{self, ThreadId};
+use version::{DeviceV1_0, DeviceV1_1};
+use vk;
+use vk::Handle;
+
+mod generated;
+
+/// The parameters of a command that have to be externally synchronized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExternSync {
+    /// e.g. `vkFreeDescriptorSets`.
+    pub command: &'static str,
+    /// The parameters as they are named in vk.xml. Members of parameters use the notation of
+    /// vk.xml, e.g. `pAllocateInfo->commandPool` or `pDescriptorWrites[].dstSet`.
+    pub params: &'static [&'static str],
+    /// Objects that are synchronized without being a parameter, as described by vk.xml without
+    /// its asciidoc markup, e.g. the command pool of a command buffer.
+    pub implicit: &'static [&'static str],
+}
+
+/// Returns the externally synchronized parameters of `command`, or `None` if it has none.
+pub fn extern_sync(command: &str) -> Option<&'static ExternSync> {
+    generated::EXTERN_SYNC
+        .binary_search_by(|sync| sync.command.cmp(command))
+        .ok()
+        .map(|index| &generated::EXTERN_SYNC[index])
+}
+
+/// All commands with externally synchronized parameters.
+pub fn extern_sync_commands() -> &'static [ExternSync] {
+    generated::EXTERN_SYNC
+}
+
+type Key = (vk::ObjectType, u64);
+
+struct Holder {
+    thread: ThreadId,
+    count: usize,
+    command: &'static str,
+}
+
+/// An externally synchronized object that a call used while another thread held it, see
+/// `SyncDevice::detect_misuse`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misuse {
+    /// The command that was called, e.g. `vkBeginCommandBuffer`.
+    pub command: &'static str,
+    /// The parameter as it is named in vk.xml, e.g. `commandPool`.
+    pub param: &'static str,
+    pub object_type: vk::ObjectType,
+    pub handle: u64,
+    /// The command that uses the object on the other thread.
+    pub held_by: &'static str,
+}
+
+impl fmt::Display for Misuse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} ({} 0x{:x}) is externally synchronized, but {} uses it on another thread",
+            self.command, self.param, self.object_type, self.handle, self.held_by
+        )
+    }
+}
+
+type MisuseCallback = Box<dyn Fn(&Misuse) + Send + Sync>;
+
+/// Objects that are in use, and the thread that uses them.
+struct ObjectLocks {
+    held: Mutex<HashMap<Key, Holder>>,
+    released: Condvar,
+    misuse_callback: Option<MisuseCallback>,
+}
+
+impl ObjectLocks {
+    /// Waits until no other thread holds any of `objects`. The first object that is held by
+    /// another thread is reported to the misuse callback. Null handles are ignored.
+    fn acquire(&self, command: &'static str, objects: &[(&'static str, Key)]) {
+        let thread = thread::current().id();
+        let mut held = self.held.lock().unwrap();
+        let mut reported = false;
+        loop {
+            let busy = objects.iter().find(|&&(_, key)| {
+                key.1 != 0
+                    && held
+                        .get(&key)
+                        .map_or(false, |holder| holder.thread != thread)
+            });
+            let (param, key) = match busy {
+                Some(&object) => object,
+                None => break,
+            };
+            if let (Some(callback), false) = (self.misuse_callback.as_ref(), reported) {
+                let misuse = Misuse {
+                    command,
+                    param,
+                    object_type: key.0,
+                    handle: key.1,
+                    held_by: held[&key].command,
+                };
+                // The callback may call the device, and the object may be released meanwhile
+                drop(held);
+                callback(&misuse);
+                reported = true;
+                held = self.held.lock().unwrap();
+                continue;
+            }
+            held = self.released.wait(held).unwrap();
+        }
+        for &(_, key) in objects.iter().filter(|&&(_, key)| key.1 != 0) {
+            held.entry(key)
+                .or_insert(Holder {
+                    thread,
+                    count: 0,
+                    command,
+                })
+                .count += 1;
+        }
+    }
+
+    fn release<I: IntoIterator<Item = Key>>(&self, keys: I) {
+        let mut held = self.held.lock().unwrap();
+        for key in keys {
+            let released = match held.get_mut(&key) {
+                Some(holder) => {
+                    holder.count -= 1;
+                    holder.count == 0
+                }
+                None => false,
+            };
+            if released {
+                held.remove(&key);
+            }
+        }
+        self.released.notify_all();
+    }
+
+    fn lock<'a>(&'a self, command: &'static str, objects: &[(&'static str, Key)]) -> Locked<'a> {
+        self.acquire(command, objects);
+        Locked {
+            locks: self,
+            keys: objects.iter().map(|&(_, key)| key).collect(),
+        }
+    }
+}
+
+/// Releases the objects of a call when it returns.
+struct Locked<'a> {
+    locks: &'a ObjectLocks,
+    keys: Vec<Key>,
+}
+
+impl<'a> Drop for Locked<'a> {
+    fn drop(&mut self) {
+        self.locks.release(self.keys.drain(..));
+    }
+}
+
+fn object<H: Handle>(name: &'static str, handle: H) -> (&'static str, Key) {
+    (name, (H::TYPE, handle.as_raw()))
+}
+
+/// A device that synchronizes the objects that vk.xml marks as `externsync`. See the module
+/// documentation.
+pub struct SyncDevice<D = Device> {
+    device: D,
+    locks: ObjectLocks,
+    // The pools of the command buffers that were allocated through the wrapper
+    command_pools: Mutex<HashMap<vk::CommandBuffer, vk::CommandPool>>,
+}
+
+impl<D: DeviceV1_1> SyncDevice<D> {
+    /// Calls wait until no other thread uses their externally synchronized objects.
+    pub fn new(device: D) -> Self {
+        SyncDevice::with_callback(device, None)
+    }
+
+    /// Calls report to `callback` if another thread uses one of their externally synchronized
+    /// objects, and then wait as usual. Meant for debug builds, to find the missing
+    /// synchronization.
+    pub fn detect_misuse<F: Fn(&Misuse) + Send + Sync + 'static>(device: D, callback: F) -> Self {
+        SyncDevice::with_callback(device, Some(Box::new(callback)))
+    }
+
+    fn with_callback(device: D, misuse_callback: Option<MisuseCallback>) -> Self {
+        SyncDevice {
+            device,
+            locks: ObjectLocks {
+                held: Mutex::new(HashMap::new()),
+                released: Condvar::new(),
+                misuse_callback,
+            },
+            command_pools: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    pub fn into_inner(self) -> D {
+        self.device
+    }
+
+    /// Runs `f` while `handle` is locked, e.g. to call commands of extension loaders.
+    pub fn with_lock<H: Handle, R, F: FnOnce() -> R>(&self, handle: H, f: F) -> R {
+        let _locked = self.locks.lock("with_lock", &[object("handle", handle)]);
+        f()
+    }
+
+    fn command_pool(&self, command_buffer: vk::CommandBuffer) -> vk::CommandPool {
+        self.command_pools
+            .lock()
+            .unwrap()
+            .get(&command_buffer)
+            .cloned()
+            .unwrap_or_else(vk::CommandPool::null)
+    }
+
+    /// Begins recording `command_buffer`, which stays locked together with its command pool
+    /// until the returned guard is ended or dropped. The `cmd_*` commands that record into it
+    /// are synchronized by that lock.
+    pub unsafe fn begin_recording(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        begin_info: &vk::CommandBufferBeginInfo,
+    ) -> VkResult<Recording<'_, D>> {
+        let locked = self.locks.lock(
+            "vkBeginCommandBuffer",
+            &self.recording_objects(command_buffer),
+        );
+        self.device
+            .begin_command_buffer(command_buffer, begin_info)?;
+        Ok(Recording {
+            device: self,
+            command_buffer,
+            _locked: locked,
+            _not_send: PhantomData,
+        })
+    }
+
+    fn recording_objects(&self, command_buffer: vk::CommandBuffer) -> [(&'static str, Key); 2] {
+        [
+            object("commandBuffer", command_buffer),
+            object("commandPool", self.command_pool(command_buffer)),
+        ]
+    }
+}
+
+/// A command buffer that is recorded through `SyncDevice::begin_recording`. It and its command
+/// pool stay locked until the guard is ended or dropped.
+pub struct Recording<'a, D: 'a> {
+    device: &'a SyncDevice<D>,
+    command_buffer: vk::CommandBuffer,
+    _locked: Locked<'a>,
+    // The locks belong to the thread that took them
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<'a, D: DeviceV1_1> Recording<'a, D> {
+    pub fn command_buffer(&self) -> vk::CommandBuffer {
+        self.command_buffer
+    }
+
+    /// Ends the recording and releases the locks.
+    pub unsafe fn end(self) -> VkResult<()> {
+        self.device.device.end_command_buffer(self.command_buffer)
+    }
+}
+
+macro_rules! synced_destroy {
+    ($($name: ident($command: expr, $param: expr, vk::$ty: ident);)*) => {
+        $(
+            unsafe fn $name(
+                &self,
+                handle: vk::$ty,
+                allocation_callbacks: Option<&vk::AllocationCallbacks>,
+            ) {
+                let _locked = self.locks.lock($command, &[object($param, handle)]);
+                self.device.$name(handle, allocation_callbacks);
+            }
+        )*
+    };
+}
+
+impl<D: DeviceV1_1> DeviceV1_0 for SyncDevice<D> {
+    fn handle(&self) -> vk::Device {
+        self.device.handle()
+    }
+
+    fn fp_v1_0(&self) -> &vk::DeviceFnV1_0 {
+        self.device.fp_v1_0()
+    }
+
+    synced_destroy! {
+        free_memory("vkFreeMemory", "memory", vk::DeviceMemory);
+        destroy_buffer("vkDestroyBuffer", "buffer", vk::Buffer);
+        destroy_buffer_view("vkDestroyBufferView", "bufferView", vk::BufferView);
+        destroy_descriptor_set_layout("vkDestroyDescriptorSetLayout", "descriptorSetLayout", vk::DescriptorSetLayout);
+        destroy_event("vkDestroyEvent", "event", vk::Event);
+        destroy_fence("vkDestroyFence", "fence", vk::Fence);
+        destroy_framebuffer("vkDestroyFramebuffer", "framebuffer", vk::Framebuffer);
+        destroy_image("vkDestroyImage", "image", vk::Image);
+        destroy_image_view("vkDestroyImageView", "imageView", vk::ImageView);
+        destroy_pipeline("vkDestroyPipeline", "pipeline", vk::Pipeline);
+        destroy_pipeline_cache("vkDestroyPipelineCache", "pipelineCache", vk::PipelineCache);
+        destroy_pipeline_layout("vkDestroyPipelineLayout", "pipelineLayout", vk::PipelineLayout);
+        destroy_query_pool("vkDestroyQueryPool", "queryPool", vk::QueryPool);
+        destroy_render_pass("vkDestroyRenderPass", "renderPass", vk::RenderPass);
+        destroy_sampler("vkDestroySampler", "sampler", vk::Sampler);
+        destroy_semaphore("vkDestroySemaphore", "semaphore", vk::Semaphore);
+        destroy_shader_module("vkDestroyShaderModule", "shaderModule", vk::ShaderModule);
+        destroy_descriptor_pool("vkDestroyDescriptorPool", "descriptorPool", vk::DescriptorPool);
+    }
+
+    unsafe fn destroy_command_pool(
+        &self,
+        pool: vk::CommandPool,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) {
+        let _locked = self
+            .locks
+            .lock("vkDestroyCommandPool", &[object("commandPool", pool)]);
+        self.command_pools
+            .lock()
+            .unwrap()
+            .retain(|_, &mut command_pool| command_pool != pool);
+        self.device.destroy_command_pool(pool, allocation_callbacks);
+    }
+
+    unsafe fn queue_submit(
+        &self,
+        queue: vk::Queue,
+        submits: &[vk::SubmitInfo],
+        fence: vk::Fence,
+    ) -> VkResult<()> {
+        let _locked = self.locks.lock(
+            "vkQueueSubmit",
+            &[object("queue", queue), object("fence", fence)],
+        );
+        self.device.queue_submit(queue, submits, fence)
+    }
+
+    unsafe fn queue_wait_idle(&self, queue: vk::Queue) -> VkResult<()> {
+        let _locked = self
+            .locks
+            .lock("vkQueueWaitIdle", &[object("queue", queue)]);
+        self.device.queue_wait_idle(queue)
+    }
+
+    unsafe fn map_memory(
+        &self,
+        memory: vk::DeviceMemory,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+        flags: vk::MemoryMapFlags,
+    ) -> VkResult<*mut c_void> {
+        let _locked = self.locks.lock("vkMapMemory", &[object("memory", memory)]);
+        self.device.map_memory(memory, offset, size, flags)
+    }
+
+    unsafe fn unmap_memory(&self, memory: vk::DeviceMemory) {
+        let _locked = self
+            .locks
+            .lock("vkUnmapMemory", &[object("memory", memory)]);
+        self.device.unmap_memory(memory)
+    }
+
+    unsafe fn bind_buffer_memory(
+        &self,
+        buffer: vk::Buffer,
+        device_memory: vk::DeviceMemory,
+        offset: vk::DeviceSize,
+    ) -> VkResult<()> {
+        let _locked = self
+            .locks
+            .lock("vkBindBufferMemory", &[object("buffer", buffer)]);
+        self.device
+            .bind_buffer_memory(buffer, device_memory, offset)
+    }
+
+    unsafe fn bind_image_memory(
+        &self,
+        image: vk::Image,
+        device_memory: vk::DeviceMemory,
+        offset: vk::DeviceSize,
+    ) -> VkResult<()> {
+        let _locked = self
+            .locks
+            .lock("vkBindImageMemory", &[object("image", image)]);
+        self.device.bind_image_memory(image, device_memory, offset)
+    }
+
+    unsafe fn reset_fences(&self, fences: &[vk::Fence]) -> VkResult<()> {
+        let objects: Vec<_> = fences
+            .iter()
+            .map(|&fence| object("pFences[]", fence))
+            .collect();
+        let _locked = self.locks.lock("vkResetFences", &objects);
+        self.device.reset_fences(fences)
+    }
+
+    unsafe fn set_event(&self, event: vk::Event) -> VkResult<()> {
+        let _locked = self.locks.lock("vkSetEvent", &[object("event", event)]);
+        self.device.set_event(event)
+    }
+
+    unsafe fn reset_event(&self, event: vk::Event) -> VkResult<()> {
+        let _locked = self.locks.lock("vkResetEvent", &[object("event", event)]);
+        self.device.reset_event(event)
+    }
+
+    unsafe fn allocate_descriptor_sets(
+        &self,
+        create_info: &vk::DescriptorSetAllocateInfo,
+    ) -> VkResult<Vec<vk::DescriptorSet>> {
+        let _locked = self.locks.lock(
+            "vkAllocateDescriptorSets",
+            &[object(
+                "pAllocateInfo->descriptorPool",
+                create_info.descriptor_pool,
+            )],
+        );
+        self.device.allocate_descriptor_sets(create_info)
+    }
+
+    unsafe fn free_descriptor_sets(
+        &self,
+        pool: vk::DescriptorPool,
+        descriptor_sets: &[vk::DescriptorSet],
+    ) {
+        let mut objects = vec![object("descriptorPool", pool)];
+        objects.extend(
+            descriptor_sets
+                .iter()
+                .map(|&descriptor_set| object("pDescriptorSets[]", descriptor_set)),
+        );
+        let _locked = self.locks.lock("vkFreeDescriptorSets", &objects);
+        self.device.free_descriptor_sets(pool, descriptor_sets)
+    }
+
+    unsafe fn reset_descriptor_pool(
+        &self,
+        pool: vk::DescriptorPool,
+        flags: vk::DescriptorPoolResetFlags,
+    ) -> VkResult<()> {
+        let _locked = self
+            .locks
+            .lock("vkResetDescriptorPool", &[object("descriptorPool", pool)]);
+        self.device.reset_descriptor_pool(pool, flags)
+    }
+
+    unsafe fn update_descriptor_sets(
+        &self,
+        descriptor_writes: &[vk::WriteDescriptorSet],
+        descriptor_copies: &[vk::CopyDescriptorSet],
+    ) {
+        let writes = descriptor_writes
+            .iter()
+            .map(|write| object("pDescriptorWrites[].dstSet", write.dst_set));
+        let copies = descriptor_copies
+            .iter()
+            .map(|copy| object("pDescriptorCopies[].dstSet", copy.dst_set));
+        let objects: Vec<_> = writes.chain(copies).collect();
+        let _locked = self.locks.lock("vkUpdateDescriptorSets", &objects);
+        self.device
+            .update_descriptor_sets(descriptor_writes, descriptor_copies)
+    }
+
+    unsafe fn allocate_command_buffers(
+        &self,
+        create_info: &vk::CommandBufferAllocateInfo,
+    ) -> VkResult<Vec<vk::CommandBuffer>> {
+        let _locked = self.locks.lock(
+            "vkAllocateCommandBuffers",
+            &[object(
+                "pAllocateInfo->commandPool",
+                create_info.command_pool,
+            )],
+        );
+        let command_buffers = self.device.allocate_command_buffers(create_info)?;
+        let mut command_pools = self.command_pools.lock().unwrap();
+        for &command_buffer in &command_buffers {
+            command_pools.insert(command_buffer, create_info.command_pool);
+        }
+        Ok(command_buffers)
+    }
+
+    unsafe fn free_command_buffers(
+        &self,
+        command_pool: vk::CommandPool,
+        command_buffers: &[vk::CommandBuffer],
+    ) {
+        let mut objects = vec![object("commandPool", command_pool)];
+        objects.extend(
+            command_buffers
+                .iter()
+                .map(|&command_buffer| object("pCommandBuffers[]", command_buffer)),
+        );
+        let _locked = self.locks.lock("vkFreeCommandBuffers", &objects);
+        {
+            let mut command_pools = self.command_pools.lock().unwrap();
+            for command_buffer in command_buffers {
+                command_pools.remove(command_buffer);
+            }
+        }
+        self.device
+            .free_command_buffers(command_pool, command_buffers)
+    }
+
+    unsafe fn reset_command_pool(
+        &self,
+        command_pool: vk::CommandPool,
+        flags: vk::CommandPoolResetFlags,
+    ) -> VkResult<()> {
+        let _locked = self
+            .locks
+            .lock("vkResetCommandPool", &[object("commandPool", command_pool)]);
+        self.device.reset_command_pool(command_pool, flags)
+    }
+
+    /// Only locks the command buffer and its command pool for the duration of the call, use
+    /// `SyncDevice::begin_recording` to keep them locked while recording.
+    unsafe fn begin_command_buffer(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        begin_info: &vk::CommandBufferBeginInfo,
+    ) -> VkResult<()> {
+        let _locked = self.locks.lock(
+            "vkBeginCommandBuffer",
+            &self.recording_objects(command_buffer),
+        );
+        self.device.begin_command_buffer(command_buffer, begin_info)
+    }
+
+    unsafe fn end_command_buffer(&self, command_buffer: vk::CommandBuffer) -> VkResult<()> {
+        let _locked = self.locks.lock(
+            "vkEndCommandBuffer",
+            &self.recording_objects(command_buffer),
+        );
+        self.device.end_command_buffer(command_buffer)
+    }
+
+    unsafe fn reset_command_buffer(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        flags: vk::CommandBufferResetFlags,
+    ) -> VkResult<()> {
+        let _locked = self.locks.lock(
+            "vkResetCommandBuffer",
+            &self.recording_objects(command_buffer),
+        );
+        self.device.reset_command_buffer(command_buffer, flags)
+    }
+}
+
+impl<D: DeviceV1_1> DeviceV1_1 for SyncDevice<D> {
+    fn fp_v1_1(&self) -> &vk::DeviceFnV1_1 {
+        self.device.fp_v1_1()
+    }
+
+    synced_destroy! {
+        destroy_descriptor_update_template("vkDestroyDescriptorUpdateTemplate", "descriptorUpdateTemplate", vk::DescriptorUpdateTemplate);
+        destroy_sampler_ycbcr_conversion("vkDestroySamplerYcbcrConversion", "ycbcrConversion", vk::SamplerYcbcrConversion);
+    }
+
+    unsafe fn trim_command_pool(
+        &self,
+        command_pool: vk::CommandPool,
+        flags: vk::CommandPoolTrimFlags,
+    ) {
+        let _locked = self
+            .locks
+            .lock("vkTrimCommandPool", &[object("commandPool", command_pool)]);
+        self.device.trim_command_pool(command_pool, flags)
+    }
+
+    unsafe fn update_descriptor_set_with_template(
+        &self,
+        descriptor_set: vk::DescriptorSet,
+        descriptor_update_template: vk::DescriptorUpdateTemplate,
+        data: *const c_void,
+    ) {
+        let _locked = self.locks.lock(
+            "vkUpdateDescriptorSetWithTemplate",
+            &[object("descriptorSet", descriptor_set)],
+        );
+        self.device.update_descriptor_set_with_template(
+            descriptor_set,
+            descriptor_update_template,
+            data,
+        )
+    }
+}
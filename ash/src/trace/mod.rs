@@ -0,0 +1,627 @@
+//! Tracing of every Vulkan call, without a layer.
+//!
+//! `TracingEntry` wraps an `Entry` and hands out instances whose function pointers are shims
+//! generated from vk.xml. Every shim forwards to the driver and reports the call, with its
+//! decoded arguments, its return code and its duration, to a `TraceSink`.
+//!
+//! Devices created from a traced instance are traced as well, and so are extension loaders
+//! that are created from a `TracingEntry` and a traced instance or device.
+//!
+//! ```no_run
+//! # use ash::trace::{TracingEntry, WriteSink};
+//! # use ash::version::*;
+//! # use ash::{vk, Entry};
+//! # use std::fs::File;
+//! # unsafe fn test(entry: &Entry, create_info: &vk::InstanceCreateInfo) {
+//! let file = File::create("vulkan.trace").unwrap();
+//! let entry = TracingEntry::new(entry, WriteSink::new(file));
+//! let instance = entry.create_instance(create_info, None).unwrap();
+//! // Writes "vkEnumeratePhysicalDevices(instance: 0x..., ...) -> Command completed
+//! // successfully (...)"
+//! let physical_devices = instance.enumerate_physical_devices().unwrap();
+//! # }
+//! ```
+//!
+//! Forwarding to the `log` crate only needs a closure:
+//!
+//! ```ignore
+//! let entry = TracingEntry::new(&entry, |call: &Call| trace!("{}", call));
+//! ```
+//!
+//! The shims find the driver's function pointers through the dispatch table pointer that the
+//! Vulkan loader stores at the start of every dispatchable handle, which is shared by an
+//! instance and its physical devices, and by a device and its queues and command buffers.
+//! Handles that were not created through the loader can not be traced. Calls through them, or
+//! through a handle that was not created by a traced instance or device, do not reach the
+//! driver: they return `ERROR_INITIALIZATION_FAILED`, or nothing if the command returns
+//! `void`.
+use capture::{Encode, Writer};
+use entry::{Entry, EntryV1_0, InstanceError};
+use instance::Instance;
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fmt;
+use std::io::Write;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use vk;
+use RawPtr;
+
+mod shims;
+
+/// A Vulkan call that was traced.
+pub struct Call<'a> {
+    /// The name of the command, e.g. `vkCreateBuffer`.
+    pub command: &'static str,
+    /// The parameters and their values. Pointers to structs are followed.
+    pub args: &'a [(&'static str, &'a dyn Value)],
+    /// `None` if the command returns `void`.
+    pub result: Option<&'a dyn Value>,
+    pub duration: Duration,
+}
+
+impl<'a> fmt::Display for Call<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}(", self.command)?;
+        for (i, &(name, value)) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {:?}", name, value)?;
+        }
+        write!(f, ")")?;
+        if let Some(result) = self.result {
+            // The derived Debug impl of `vk::Result` only shows the raw value
+            match result.as_any().downcast_ref::<vk::Result>() {
+                Some(result) => write!(f, " -> {}", result)?,
+                None => write!(f, " -> {:?}", result)?,
+            }
+        }
+        write!(f, " ({:?})", self.duration)
+    }
+}
+
+/// A parameter or the return value of a traced call.
+pub trait Value: fmt::Debug {
+    fn as_any(&self) -> &dyn Any;
+    /// Appends the value, and everything it points to, to a capture. Only valid while the
+    /// call is being reported.
+    unsafe fn encode(&self, writer: &mut Writer);
+}
+
+impl<T: fmt::Debug + Encode + Any> Value for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    unsafe fn encode(&self, writer: &mut Writer) {
+        Encode::encode(self, writer)
+    }
+}
+
+/// Receives every traced call. Implemented for closures.
+pub trait TraceSink: Send + Sync {
+    /// Called before the driver is, with the arguments of the call. Output parameters are not
+    /// written yet, and Debug formats them as `<pending>`.
+    fn before_call(&self, _command: &'static str, _args: &[(&'static str, &dyn Value)]) {}
+
+    fn call(&self, call: &Call);
+}
+
+impl<F: Fn(&Call) + Send + Sync> TraceSink for F {
+    fn call(&self, call: &Call) {
+        self(call)
+    }
+}
+
+/// Writes one line per call, e.g. into a file.
+pub struct WriteSink<W> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> WriteSink<W> {
+    pub fn new(writer: W) -> Self {
+        WriteSink {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+            .into_inner()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<W: Write + Send> TraceSink for WriteSink<W> {
+    fn call(&self, call: &Call) {
+        let mut writer = self.writer.lock().unwrap_or_else(|err| err.into_inner());
+        // A trace is best effort, failing to write it must not fail the application
+        let _ = writeln!(writer, "{}", call);
+    }
+}
+
+/// The driver's function pointers for one dispatch key.
+struct Dispatch {
+    sink: Arc<dyn TraceSink>,
+    fns: RwLock<HashMap<String, usize>>,
+    // A device falls back to the function pointers of its instance
+    parent: Option<Arc<Dispatch>>,
+}
+
+impl Dispatch {
+    fn get(&self, name: &str) -> Option<usize> {
+        let fns = self.fns.read().unwrap_or_else(|err| err.into_inner());
+        fns.get(name)
+            .cloned()
+            .or_else(|| self.parent.as_ref().and_then(|parent| parent.get(name)))
+    }
+
+    fn insert(&self, name: &str, fp: usize) {
+        let mut fns = self.fns.write().unwrap_or_else(|err| err.into_inner());
+        fns.insert(name.to_owned(), fp);
+    }
+}
+
+lazy_static! {
+    static ref DISPATCH: RwLock<HashMap<usize, Arc<Dispatch>>> = RwLock::new(HashMap::new());
+}
+
+unsafe fn dispatch_key<H: vk::Handle>(handle: H) -> Option<usize> {
+    let handle = handle.as_raw() as usize as *const usize;
+    if handle.is_null() {
+        None
+    } else {
+        Some(*handle)
+    }
+}
+
+/// `None` if `handle` was not created by a traced instance or device.
+unsafe fn dispatch<H: vk::Handle + Copy>(handle: H) -> Option<Arc<Dispatch>> {
+    let key = dispatch_key(handle)?;
+    let dispatch = DISPATCH.read().unwrap_or_else(|err| err.into_inner());
+    dispatch.get(&key).cloned()
+}
+
+unsafe fn register<H: vk::Handle + Copy>(handle: H, dispatch: Dispatch) -> Arc<Dispatch> {
+    let dispatch = Arc::new(dispatch);
+    if let Some(key) = dispatch_key(handle) {
+        DISPATCH
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(key, dispatch.clone());
+    }
+    dispatch
+}
+
+trait TraceReturn {
+    fn as_value(&self) -> Option<&dyn Value>;
+    /// Returned when the driver's function can not be found.
+    fn untraced() -> Self;
+}
+
+impl TraceReturn for c_void {
+    fn as_value(&self) -> Option<&dyn Value> {
+        None
+    }
+
+    fn untraced() -> Self {
+        unsafe { mem::zeroed() }
+    }
+}
+
+impl TraceReturn for vk::Result {
+    fn as_value(&self) -> Option<&dyn Value> {
+        Some(self)
+    }
+
+    fn untraced() -> Self {
+        vk::Result::ERROR_INITIALIZATION_FAILED
+    }
+}
+
+impl TraceReturn for vk::Bool32 {
+    fn as_value(&self) -> Option<&dyn Value> {
+        Some(self)
+    }
+
+    fn untraced() -> Self {
+        vk::FALSE
+    }
+}
+
+/// Calls the driver's `name` for the dispatch key of `handle` and reports the call.
+///
+/// The shims are called by the application through the Vulkan ABI, so they must not unwind.
+/// If `handle` was not created by a traced instance or device, or `name` was not loaded
+/// through one, nothing is called and `R::untraced()` is returned instead, e.g.
+/// `ERROR_INITIALIZATION_FAILED`.
+unsafe fn trace_call<H, F, R, C>(
+    handle: H,
+    name: &'static str,
+    args: &[(&'static str, &dyn Value)],
+    call: C,
+) -> R
+where
+    H: vk::Handle + Copy,
+    F: Copy,
+    R: TraceReturn,
+    C: FnOnce(F) -> R,
+{
+    assert_eq!(mem::size_of::<F>(), mem::size_of::<usize>());
+    let (key, dispatch) = match (dispatch_key(handle), dispatch(handle)) {
+        (Some(key), Some(dispatch)) => (key, dispatch),
+        _ => return R::untraced(),
+    };
+    let fp = match dispatch.get(name) {
+        Some(fp) => fp,
+        None => return R::untraced(),
+    };
+    before_call(&*dispatch.sink, name, args);
+    let start = Instant::now();
+    let result = call(mem::transmute_copy(&fp));
+    let duration = start.elapsed();
+    // The handle is gone, and the loader may hand out its dispatch key again
+    if name == "vkDestroyInstance" || name == "vkDestroyDevice" {
+        DISPATCH
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(&key);
+    }
+    dispatch.sink.call(&Call {
+        command: name,
+        args,
+        result: result.as_value(),
+        duration,
+    });
+    result
+}
+
+/// Replaces the driver's `real` function pointer with the shim for `p_name`, if there is one.
+unsafe fn intercept(
+    dispatch: &Dispatch,
+    p_name: *const c_char,
+    real: vk::PFN_vkVoidFunction,
+) -> vk::PFN_vkVoidFunction {
+    let real = real?;
+    let name = match CStr::from_ptr(p_name).to_str() {
+        Ok(name) => name,
+        Err(_) => return Some(real),
+    };
+    let shim = match name {
+        "vkGetInstanceProcAddr" => Some(get_instance_proc_addr as *const c_void),
+        "vkGetDeviceProcAddr" => Some(get_device_proc_addr as *const c_void),
+        "vkCreateDevice" => Some(create_device as *const c_void),
+        _ => shims::shim(name),
+    };
+    match shim {
+        Some(shim) => {
+            dispatch.insert(name, real as usize);
+            Some(mem::transmute(shim))
+        }
+        None => Some(real),
+    }
+}
+
+extern "system" fn get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        let dispatch = dispatch(instance)?;
+        let real = dispatch.get("vkGetInstanceProcAddr")?;
+        let real: vk::PFN_vkGetInstanceProcAddr = mem::transmute(real);
+        intercept(&dispatch, p_name, real(instance, p_name))
+    }
+}
+
+extern "system" fn get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        let dispatch = dispatch(device)?;
+        let real = dispatch.get("vkGetDeviceProcAddr")?;
+        let real: vk::PFN_vkGetDeviceProcAddr = mem::transmute(real);
+        intercept(&dispatch, p_name, real(device, p_name))
+    }
+}
+
+extern "system" fn create_device(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    unsafe {
+        let result = trace_call(
+            physical_device,
+            "vkCreateDevice",
+            &[
+                ("physical_device", &physical_device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_device", &Out(p_device)),
+            ],
+            |fp: vk::PFN_vkCreateDevice| fp(physical_device, p_create_info, p_allocator, p_device),
+        );
+        if result != vk::Result::SUCCESS {
+            return result;
+        }
+        if let Some(instance) = dispatch(physical_device) {
+            register(
+                *p_device,
+                Dispatch {
+                    sink: instance.sink.clone(),
+                    fns: RwLock::new(HashMap::new()),
+                    parent: Some(instance),
+                },
+            );
+        }
+        result
+    }
+}
+
+/// Loads `instance` with shims that report every call to `sink`. `static_fn` must be the
+/// table `instance` was created with.
+pub unsafe fn trace_instance<S: TraceSink + 'static>(
+    static_fn: &vk::StaticFn,
+    instance: vk::Instance,
+    sink: S,
+) -> Instance {
+    trace_instance_with(static_fn, instance, Arc::new(sink))
+}
+
+unsafe fn trace_instance_with(
+    static_fn: &vk::StaticFn,
+    instance: vk::Instance,
+    sink: Arc<dyn TraceSink>,
+) -> Instance {
+    let mut fns = HashMap::new();
+    fns.insert(
+        "vkGetInstanceProcAddr".to_owned(),
+        static_fn.get_instance_proc_addr as usize,
+    );
+    register(
+        instance,
+        Dispatch {
+            sink,
+            fns: RwLock::new(fns),
+            parent: None,
+        },
+    );
+    let static_fn = vk::StaticFn {
+        get_instance_proc_addr,
+    };
+    Instance::load(&static_fn, instance)
+}
+
+/// An `Entry` that creates traced instances. Instance level extension loaders, such as
+/// `khr::Surface::new(&tracing_entry, &instance)`, are traced as well.
+#[derive(Clone)]
+pub struct TracingEntry {
+    entry: Entry,
+    static_fn: vk::StaticFn,
+    sink: Arc<dyn TraceSink>,
+}
+
+impl TracingEntry {
+    pub fn new<S: TraceSink + 'static>(entry: &Entry, sink: S) -> Self {
+        TracingEntry {
+            entry: entry.clone(),
+            static_fn: vk::StaticFn {
+                get_instance_proc_addr,
+            },
+            sink: Arc::new(sink),
+        }
+    }
+
+    pub fn entry(&self) -> &Entry {
+        &self.entry
+    }
+
+    /// Traces an instance that was created with the untraced `Entry`.
+    pub unsafe fn trace_instance(&self, instance: vk::Instance) -> Instance {
+        trace_instance_with(self.entry.static_fn(), instance, self.sink.clone())
+    }
+}
+
+impl EntryV1_0 for TracingEntry {
+    type Instance = Instance;
+    unsafe fn create_instance(
+        &self,
+        create_info: &vk::InstanceCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<Self::Instance, InstanceError> {
+        let mut instance = vk::Instance::null();
+        let p_instance: *mut vk::Instance = &mut instance;
+        let args: &[(&'static str, &dyn Value)] = &[
+            ("p_create_info", &Ptr(create_info)),
+            ("p_allocator", &Ptr(allocation_callbacks.as_raw_ptr())),
+            ("p_instance", &Out(p_instance)),
+        ];
+        before_call(&*self.sink, "vkCreateInstance", args);
+        let start = Instant::now();
+        let err_code = self.entry.fp_v1_0().create_instance(
+            create_info,
+            allocation_callbacks.as_raw_ptr(),
+            p_instance,
+        );
+        self.sink.call(&Call {
+            command: "vkCreateInstance",
+            args,
+            result: err_code.as_value(),
+            duration: start.elapsed(),
+        });
+        if err_code != vk::Result::SUCCESS {
+            return Err(InstanceError::VkError(err_code));
+        }
+        Ok(self.trace_instance(instance))
+    }
+    fn fp_v1_0(&self) -> &vk::EntryFnV1_0 {
+        self.entry.fp_v1_0()
+    }
+    fn static_fn(&self) -> &vk::StaticFn {
+        &self.static_fn
+    }
+}
+
+thread_local! {
+    // Set while `TraceSink::before_call` runs, when the outputs are not written yet
+    static PENDING: Cell<bool> = Cell::new(false);
+}
+
+fn before_call(sink: &dyn TraceSink, name: &'static str, args: &[(&'static str, &dyn Value)]) {
+    let pending = PENDING.with(|pending| pending.replace(true));
+    sink.before_call(name, args);
+    PENDING.with(|p| p.set(pending));
+}
+
+fn is_pending() -> bool {
+    PENDING.with(|pending| pending.get())
+}
+
+/// Debug formats the value a pointer parameter points to.
+pub(crate) struct Ptr<T>(pub(crate) *const T);
+
+impl<T: fmt::Debug> fmt::Debug for Ptr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match unsafe { self.0.as_ref() } {
+            Some(value) => value.fmt(f),
+            None => write!(f, "null"),
+        }
+    }
+}
+
+/// A pointer to a union, which has no Debug impl. Debug formats the address.
+pub(crate) struct UnionPtr<T>(pub(crate) *const T);
+
+impl<T> fmt::Debug for UnionPtr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Debug formats an array parameter with a separate length parameter.
+pub(crate) struct Array<T>(pub(crate) *const T, pub(crate) usize);
+
+impl<T: fmt::Debug> fmt::Debug for Array<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_null() || self.1 == 0 {
+            return write!(f, "[]");
+        }
+        unsafe { slice::from_raw_parts(self.0, self.1) }.fmt(f)
+    }
+}
+
+/// An untyped buffer parameter with a separate size parameter, such as the data of
+/// `vkCmdUpdateBuffer`.
+pub(crate) struct Bytes(pub(crate) *const u8, pub(crate) usize);
+
+impl fmt::Debug for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{} bytes at {:?}>", self.1, self.0)
+    }
+}
+
+/// Debug formats a null terminated string parameter.
+pub(crate) struct Str(pub(crate) *const c_char);
+
+impl fmt::Debug for Str {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0 == ptr::null() {
+            return write!(f, "null");
+        }
+        unsafe { CStr::from_ptr(self.0) }.fmt(f)
+    }
+}
+
+/// A value the driver writes through a pointer parameter. Formatted as `<pending>` before
+/// the call.
+pub(crate) struct Out<T>(pub(crate) *mut T);
+
+impl<T: fmt::Debug> fmt::Debug for Out<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if is_pending() {
+            return write!(f, "<pending>");
+        }
+        match unsafe { self.0.as_ref() } {
+            Some(value) => value.fmt(f),
+            None => write!(f, "null"),
+        }
+    }
+}
+
+/// The type of the length of an output array.
+pub(crate) trait Count: Copy {
+    fn to_usize(self) -> usize;
+}
+
+impl Count for u32 {
+    fn to_usize(self) -> usize {
+        self as usize
+    }
+}
+
+impl Count for usize {
+    fn to_usize(self) -> usize {
+        self
+    }
+}
+
+/// An array the driver writes, whose length is read after the call. The length is often
+/// another output parameter, as in `vkEnumeratePhysicalDevices`.
+pub(crate) struct OutArray<T, C>(pub(crate) *mut T, pub(crate) *const C);
+
+impl<T, C: Count> OutArray<T, C> {
+    pub(crate) unsafe fn len(&self) -> usize {
+        if self.0.is_null() || self.1.is_null() {
+            0
+        } else {
+            (*self.1).to_usize()
+        }
+    }
+}
+
+impl<T: fmt::Debug, C: Count> fmt::Debug for OutArray<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_null() {
+            return write!(f, "null");
+        }
+        if is_pending() {
+            return write!(f, "<pending>");
+        }
+        unsafe { slice::from_raw_parts(self.0, self.len()) }.fmt(f)
+    }
+}
+
+/// An untyped buffer the driver writes, such as the results of `vkGetQueryPoolResults`.
+pub(crate) struct OutBytes(pub(crate) *mut c_void, pub(crate) *const usize);
+
+impl OutBytes {
+    pub(crate) unsafe fn len(&self) -> usize {
+        if self.0.is_null() || self.1.is_null() {
+            0
+        } else {
+            *self.1
+        }
+    }
+}
+
+impl fmt::Debug for OutBytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_null() {
+            return write!(f, "null");
+        }
+        if is_pending() {
+            return write!(f, "<pending>");
+        }
+        write!(f, "<{} bytes>", unsafe { self.len() })
+    }
+}
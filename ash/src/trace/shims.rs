@@ -0,0 +1,7518 @@
+use super::{trace_call, Array, Bytes, Out, OutArray, OutBytes, Ptr, Str, UnionPtr};
+use std::os::raw::*;
+use vk::*;
+extern "system" fn acquire_image_android(
+    device: Device,
+    image: Image,
+    native_fence_fd: c_int,
+    semaphore: Semaphore,
+    fence: Fence,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkAcquireImageANDROID",
+            &[
+                ("device", &device),
+                ("image", &image),
+                ("native_fence_fd", &native_fence_fd),
+                ("semaphore", &semaphore),
+                ("fence", &fence),
+            ],
+            |fp: PFN_vkAcquireImageANDROID| fp(device, image, native_fence_fd, semaphore, fence),
+        )
+    }
+}
+extern "system" fn acquire_next_image2_khr(
+    device: Device,
+    p_acquire_info: *const AcquireNextImageInfoKHR,
+    p_image_index: *mut u32,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkAcquireNextImage2KHR",
+            &[
+                ("device", &device),
+                ("p_acquire_info", &Ptr(p_acquire_info)),
+                ("p_image_index", &Out(p_image_index)),
+            ],
+            |fp: PFN_vkAcquireNextImage2KHR| fp(device, p_acquire_info, p_image_index),
+        )
+    }
+}
+extern "system" fn acquire_next_image_khr(
+    device: Device,
+    swapchain: SwapchainKHR,
+    timeout: u64,
+    semaphore: Semaphore,
+    fence: Fence,
+    p_image_index: *mut u32,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkAcquireNextImageKHR",
+            &[
+                ("device", &device),
+                ("swapchain", &swapchain),
+                ("timeout", &timeout),
+                ("semaphore", &semaphore),
+                ("fence", &fence),
+                ("p_image_index", &Out(p_image_index)),
+            ],
+            |fp: PFN_vkAcquireNextImageKHR| {
+                fp(device, swapchain, timeout, semaphore, fence, p_image_index)
+            },
+        )
+    }
+}
+extern "system" fn acquire_xlib_display_ext(
+    physical_device: PhysicalDevice,
+    dpy: *mut Display,
+    display: DisplayKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkAcquireXlibDisplayEXT",
+            &[
+                ("physical_device", &physical_device),
+                ("dpy", &dpy),
+                ("display", &display),
+            ],
+            |fp: PFN_vkAcquireXlibDisplayEXT| fp(physical_device, dpy, display),
+        )
+    }
+}
+extern "system" fn allocate_command_buffers(
+    device: Device,
+    p_allocate_info: *const CommandBufferAllocateInfo,
+    p_command_buffers: *mut CommandBuffer,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkAllocateCommandBuffers",
+            &[
+                ("device", &device),
+                ("p_allocate_info", &Ptr(p_allocate_info)),
+                (
+                    "p_command_buffers",
+                    &OutArray(p_command_buffers, &(*p_allocate_info).command_buffer_count),
+                ),
+            ],
+            |fp: PFN_vkAllocateCommandBuffers| fp(device, p_allocate_info, p_command_buffers),
+        )
+    }
+}
+extern "system" fn allocate_descriptor_sets(
+    device: Device,
+    p_allocate_info: *const DescriptorSetAllocateInfo,
+    p_descriptor_sets: *mut DescriptorSet,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkAllocateDescriptorSets",
+            &[
+                ("device", &device),
+                ("p_allocate_info", &Ptr(p_allocate_info)),
+                (
+                    "p_descriptor_sets",
+                    &OutArray(p_descriptor_sets, &(*p_allocate_info).descriptor_set_count),
+                ),
+            ],
+            |fp: PFN_vkAllocateDescriptorSets| fp(device, p_allocate_info, p_descriptor_sets),
+        )
+    }
+}
+extern "system" fn allocate_memory(
+    device: Device,
+    p_allocate_info: *const MemoryAllocateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_memory: *mut DeviceMemory,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkAllocateMemory",
+            &[
+                ("device", &device),
+                ("p_allocate_info", &Ptr(p_allocate_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_memory", &Out(p_memory)),
+            ],
+            |fp: PFN_vkAllocateMemory| fp(device, p_allocate_info, p_allocator, p_memory),
+        )
+    }
+}
+extern "system" fn begin_command_buffer(
+    command_buffer: CommandBuffer,
+    p_begin_info: *const CommandBufferBeginInfo,
+) -> Result {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkBeginCommandBuffer",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_begin_info", &Ptr(p_begin_info)),
+            ],
+            |fp: PFN_vkBeginCommandBuffer| fp(command_buffer, p_begin_info),
+        )
+    }
+}
+extern "system" fn bind_acceleration_structure_memory_nv(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindAccelerationStructureMemoryInfoNV,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkBindAccelerationStructureMemoryNV",
+            &[
+                ("device", &device),
+                ("bind_info_count", &bind_info_count),
+                (
+                    "p_bind_infos",
+                    &Array(p_bind_infos, bind_info_count as usize),
+                ),
+            ],
+            |fp: PFN_vkBindAccelerationStructureMemoryNV| fp(device, bind_info_count, p_bind_infos),
+        )
+    }
+}
+extern "system" fn bind_buffer_memory(
+    device: Device,
+    buffer: Buffer,
+    memory: DeviceMemory,
+    memory_offset: DeviceSize,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkBindBufferMemory",
+            &[
+                ("device", &device),
+                ("buffer", &buffer),
+                ("memory", &memory),
+                ("memory_offset", &memory_offset),
+            ],
+            |fp: PFN_vkBindBufferMemory| fp(device, buffer, memory, memory_offset),
+        )
+    }
+}
+extern "system" fn bind_buffer_memory2(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindBufferMemoryInfo,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkBindBufferMemory2",
+            &[
+                ("device", &device),
+                ("bind_info_count", &bind_info_count),
+                (
+                    "p_bind_infos",
+                    &Array(p_bind_infos, bind_info_count as usize),
+                ),
+            ],
+            |fp: PFN_vkBindBufferMemory2| fp(device, bind_info_count, p_bind_infos),
+        )
+    }
+}
+extern "system" fn bind_image_memory(
+    device: Device,
+    image: Image,
+    memory: DeviceMemory,
+    memory_offset: DeviceSize,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkBindImageMemory",
+            &[
+                ("device", &device),
+                ("image", &image),
+                ("memory", &memory),
+                ("memory_offset", &memory_offset),
+            ],
+            |fp: PFN_vkBindImageMemory| fp(device, image, memory, memory_offset),
+        )
+    }
+}
+extern "system" fn bind_image_memory2(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindImageMemoryInfo,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkBindImageMemory2",
+            &[
+                ("device", &device),
+                ("bind_info_count", &bind_info_count),
+                (
+                    "p_bind_infos",
+                    &Array(p_bind_infos, bind_info_count as usize),
+                ),
+            ],
+            |fp: PFN_vkBindImageMemory2| fp(device, bind_info_count, p_bind_infos),
+        )
+    }
+}
+extern "system" fn cmd_begin_conditional_rendering_ext(
+    command_buffer: CommandBuffer,
+    p_conditional_rendering_begin: *const ConditionalRenderingBeginInfoEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBeginConditionalRenderingEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                (
+                    "p_conditional_rendering_begin",
+                    &Ptr(p_conditional_rendering_begin),
+                ),
+            ],
+            |fp: PFN_vkCmdBeginConditionalRenderingEXT| {
+                fp(command_buffer, p_conditional_rendering_begin)
+            },
+        )
+    }
+}
+extern "system" fn cmd_begin_debug_utils_label_ext(
+    command_buffer: CommandBuffer,
+    p_label_info: *const DebugUtilsLabelEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBeginDebugUtilsLabelEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_label_info", &Ptr(p_label_info)),
+            ],
+            |fp: PFN_vkCmdBeginDebugUtilsLabelEXT| fp(command_buffer, p_label_info),
+        )
+    }
+}
+extern "system" fn cmd_begin_query(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    flags: QueryControlFlags,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBeginQuery",
+            &[
+                ("command_buffer", &command_buffer),
+                ("query_pool", &query_pool),
+                ("query", &query),
+                ("flags", &flags),
+            ],
+            |fp: PFN_vkCmdBeginQuery| fp(command_buffer, query_pool, query, flags),
+        )
+    }
+}
+extern "system" fn cmd_begin_query_indexed_ext(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    flags: QueryControlFlags,
+    index: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBeginQueryIndexedEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("query_pool", &query_pool),
+                ("query", &query),
+                ("flags", &flags),
+                ("index", &index),
+            ],
+            |fp: PFN_vkCmdBeginQueryIndexedEXT| fp(command_buffer, query_pool, query, flags, index),
+        )
+    }
+}
+extern "system" fn cmd_begin_render_pass(
+    command_buffer: CommandBuffer,
+    p_render_pass_begin: *const RenderPassBeginInfo,
+    contents: SubpassContents,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBeginRenderPass",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_render_pass_begin", &Ptr(p_render_pass_begin)),
+                ("contents", &contents),
+            ],
+            |fp: PFN_vkCmdBeginRenderPass| fp(command_buffer, p_render_pass_begin, contents),
+        )
+    }
+}
+extern "system" fn cmd_begin_render_pass2_khr(
+    command_buffer: CommandBuffer,
+    p_render_pass_begin: *const RenderPassBeginInfo,
+    p_subpass_begin_info: *const SubpassBeginInfoKHR,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBeginRenderPass2KHR",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_render_pass_begin", &Ptr(p_render_pass_begin)),
+                ("p_subpass_begin_info", &Ptr(p_subpass_begin_info)),
+            ],
+            |fp: PFN_vkCmdBeginRenderPass2KHR| {
+                fp(command_buffer, p_render_pass_begin, p_subpass_begin_info)
+            },
+        )
+    }
+}
+extern "system" fn cmd_begin_transform_feedback_ext(
+    command_buffer: CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const Buffer,
+    p_counter_buffer_offsets: *const DeviceSize,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBeginTransformFeedbackEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_counter_buffer", &first_counter_buffer),
+                ("counter_buffer_count", &counter_buffer_count),
+                (
+                    "p_counter_buffers",
+                    &Array(p_counter_buffers, counter_buffer_count as usize),
+                ),
+                (
+                    "p_counter_buffer_offsets",
+                    &Array(p_counter_buffer_offsets, counter_buffer_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdBeginTransformFeedbackEXT| {
+                fp(
+                    command_buffer,
+                    first_counter_buffer,
+                    counter_buffer_count,
+                    p_counter_buffers,
+                    p_counter_buffer_offsets,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_bind_descriptor_sets(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    layout: PipelineLayout,
+    first_set: u32,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const DescriptorSet,
+    dynamic_offset_count: u32,
+    p_dynamic_offsets: *const u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBindDescriptorSets",
+            &[
+                ("command_buffer", &command_buffer),
+                ("pipeline_bind_point", &pipeline_bind_point),
+                ("layout", &layout),
+                ("first_set", &first_set),
+                ("descriptor_set_count", &descriptor_set_count),
+                (
+                    "p_descriptor_sets",
+                    &Array(p_descriptor_sets, descriptor_set_count as usize),
+                ),
+                ("dynamic_offset_count", &dynamic_offset_count),
+                (
+                    "p_dynamic_offsets",
+                    &Array(p_dynamic_offsets, dynamic_offset_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdBindDescriptorSets| {
+                fp(
+                    command_buffer,
+                    pipeline_bind_point,
+                    layout,
+                    first_set,
+                    descriptor_set_count,
+                    p_descriptor_sets,
+                    dynamic_offset_count,
+                    p_dynamic_offsets,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_bind_index_buffer(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    index_type: IndexType,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBindIndexBuffer",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("index_type", &index_type),
+            ],
+            |fp: PFN_vkCmdBindIndexBuffer| fp(command_buffer, buffer, offset, index_type),
+        )
+    }
+}
+extern "system" fn cmd_bind_pipeline(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    pipeline: Pipeline,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBindPipeline",
+            &[
+                ("command_buffer", &command_buffer),
+                ("pipeline_bind_point", &pipeline_bind_point),
+                ("pipeline", &pipeline),
+            ],
+            |fp: PFN_vkCmdBindPipeline| fp(command_buffer, pipeline_bind_point, pipeline),
+        )
+    }
+}
+extern "system" fn cmd_bind_shading_rate_image_nv(
+    command_buffer: CommandBuffer,
+    image_view: ImageView,
+    image_layout: ImageLayout,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBindShadingRateImageNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("image_view", &image_view),
+                ("image_layout", &image_layout),
+            ],
+            |fp: PFN_vkCmdBindShadingRateImageNV| fp(command_buffer, image_view, image_layout),
+        )
+    }
+}
+extern "system" fn cmd_bind_transform_feedback_buffers_ext(
+    command_buffer: CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const Buffer,
+    p_offsets: *const DeviceSize,
+    p_sizes: *const DeviceSize,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBindTransformFeedbackBuffersEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_binding", &first_binding),
+                ("binding_count", &binding_count),
+                ("p_buffers", &Array(p_buffers, binding_count as usize)),
+                ("p_offsets", &Array(p_offsets, binding_count as usize)),
+                ("p_sizes", &Array(p_sizes, binding_count as usize)),
+            ],
+            |fp: PFN_vkCmdBindTransformFeedbackBuffersEXT| {
+                fp(
+                    command_buffer,
+                    first_binding,
+                    binding_count,
+                    p_buffers,
+                    p_offsets,
+                    p_sizes,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_bind_vertex_buffers(
+    command_buffer: CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const Buffer,
+    p_offsets: *const DeviceSize,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBindVertexBuffers",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_binding", &first_binding),
+                ("binding_count", &binding_count),
+                ("p_buffers", &Array(p_buffers, binding_count as usize)),
+                ("p_offsets", &Array(p_offsets, binding_count as usize)),
+            ],
+            |fp: PFN_vkCmdBindVertexBuffers| {
+                fp(
+                    command_buffer,
+                    first_binding,
+                    binding_count,
+                    p_buffers,
+                    p_offsets,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_blit_image(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageBlit,
+    filter: Filter,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBlitImage",
+            &[
+                ("command_buffer", &command_buffer),
+                ("src_image", &src_image),
+                ("src_image_layout", &src_image_layout),
+                ("dst_image", &dst_image),
+                ("dst_image_layout", &dst_image_layout),
+                ("region_count", &region_count),
+                ("p_regions", &Array(p_regions, region_count as usize)),
+                ("filter", &filter),
+            ],
+            |fp: PFN_vkCmdBlitImage| {
+                fp(
+                    command_buffer,
+                    src_image,
+                    src_image_layout,
+                    dst_image,
+                    dst_image_layout,
+                    region_count,
+                    p_regions,
+                    filter,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_build_acceleration_structure_nv(
+    command_buffer: CommandBuffer,
+    p_info: *const AccelerationStructureInfoNV,
+    instance_data: Buffer,
+    instance_offset: DeviceSize,
+    update: Bool32,
+    dst: AccelerationStructureNV,
+    src: AccelerationStructureNV,
+    scratch: Buffer,
+    scratch_offset: DeviceSize,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdBuildAccelerationStructureNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_info", &Ptr(p_info)),
+                ("instance_data", &instance_data),
+                ("instance_offset", &instance_offset),
+                ("update", &update),
+                ("dst", &dst),
+                ("src", &src),
+                ("scratch", &scratch),
+                ("scratch_offset", &scratch_offset),
+            ],
+            |fp: PFN_vkCmdBuildAccelerationStructureNV| {
+                fp(
+                    command_buffer,
+                    p_info,
+                    instance_data,
+                    instance_offset,
+                    update,
+                    dst,
+                    src,
+                    scratch,
+                    scratch_offset,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_clear_attachments(
+    command_buffer: CommandBuffer,
+    attachment_count: u32,
+    p_attachments: *const ClearAttachment,
+    rect_count: u32,
+    p_rects: *const ClearRect,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdClearAttachments",
+            &[
+                ("command_buffer", &command_buffer),
+                ("attachment_count", &attachment_count),
+                (
+                    "p_attachments",
+                    &Array(p_attachments, attachment_count as usize),
+                ),
+                ("rect_count", &rect_count),
+                ("p_rects", &Array(p_rects, rect_count as usize)),
+            ],
+            |fp: PFN_vkCmdClearAttachments| {
+                fp(
+                    command_buffer,
+                    attachment_count,
+                    p_attachments,
+                    rect_count,
+                    p_rects,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_clear_color_image(
+    command_buffer: CommandBuffer,
+    image: Image,
+    image_layout: ImageLayout,
+    p_color: *const ClearColorValue,
+    range_count: u32,
+    p_ranges: *const ImageSubresourceRange,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdClearColorImage",
+            &[
+                ("command_buffer", &command_buffer),
+                ("image", &image),
+                ("image_layout", &image_layout),
+                ("p_color", &UnionPtr(p_color)),
+                ("range_count", &range_count),
+                ("p_ranges", &Array(p_ranges, range_count as usize)),
+            ],
+            |fp: PFN_vkCmdClearColorImage| {
+                fp(
+                    command_buffer,
+                    image,
+                    image_layout,
+                    p_color,
+                    range_count,
+                    p_ranges,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_clear_depth_stencil_image(
+    command_buffer: CommandBuffer,
+    image: Image,
+    image_layout: ImageLayout,
+    p_depth_stencil: *const ClearDepthStencilValue,
+    range_count: u32,
+    p_ranges: *const ImageSubresourceRange,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdClearDepthStencilImage",
+            &[
+                ("command_buffer", &command_buffer),
+                ("image", &image),
+                ("image_layout", &image_layout),
+                ("p_depth_stencil", &Ptr(p_depth_stencil)),
+                ("range_count", &range_count),
+                ("p_ranges", &Array(p_ranges, range_count as usize)),
+            ],
+            |fp: PFN_vkCmdClearDepthStencilImage| {
+                fp(
+                    command_buffer,
+                    image,
+                    image_layout,
+                    p_depth_stencil,
+                    range_count,
+                    p_ranges,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_copy_acceleration_structure_nv(
+    command_buffer: CommandBuffer,
+    dst: AccelerationStructureNV,
+    src: AccelerationStructureNV,
+    mode: CopyAccelerationStructureModeNV,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdCopyAccelerationStructureNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("dst", &dst),
+                ("src", &src),
+                ("mode", &mode),
+            ],
+            |fp: PFN_vkCmdCopyAccelerationStructureNV| fp(command_buffer, dst, src, mode),
+        )
+    }
+}
+extern "system" fn cmd_copy_buffer(
+    command_buffer: CommandBuffer,
+    src_buffer: Buffer,
+    dst_buffer: Buffer,
+    region_count: u32,
+    p_regions: *const BufferCopy,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdCopyBuffer",
+            &[
+                ("command_buffer", &command_buffer),
+                ("src_buffer", &src_buffer),
+                ("dst_buffer", &dst_buffer),
+                ("region_count", &region_count),
+                ("p_regions", &Array(p_regions, region_count as usize)),
+            ],
+            |fp: PFN_vkCmdCopyBuffer| {
+                fp(
+                    command_buffer,
+                    src_buffer,
+                    dst_buffer,
+                    region_count,
+                    p_regions,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_copy_buffer_to_image(
+    command_buffer: CommandBuffer,
+    src_buffer: Buffer,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const BufferImageCopy,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdCopyBufferToImage",
+            &[
+                ("command_buffer", &command_buffer),
+                ("src_buffer", &src_buffer),
+                ("dst_image", &dst_image),
+                ("dst_image_layout", &dst_image_layout),
+                ("region_count", &region_count),
+                ("p_regions", &Array(p_regions, region_count as usize)),
+            ],
+            |fp: PFN_vkCmdCopyBufferToImage| {
+                fp(
+                    command_buffer,
+                    src_buffer,
+                    dst_image,
+                    dst_image_layout,
+                    region_count,
+                    p_regions,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_copy_image(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageCopy,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdCopyImage",
+            &[
+                ("command_buffer", &command_buffer),
+                ("src_image", &src_image),
+                ("src_image_layout", &src_image_layout),
+                ("dst_image", &dst_image),
+                ("dst_image_layout", &dst_image_layout),
+                ("region_count", &region_count),
+                ("p_regions", &Array(p_regions, region_count as usize)),
+            ],
+            |fp: PFN_vkCmdCopyImage| {
+                fp(
+                    command_buffer,
+                    src_image,
+                    src_image_layout,
+                    dst_image,
+                    dst_image_layout,
+                    region_count,
+                    p_regions,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_copy_image_to_buffer(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_buffer: Buffer,
+    region_count: u32,
+    p_regions: *const BufferImageCopy,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdCopyImageToBuffer",
+            &[
+                ("command_buffer", &command_buffer),
+                ("src_image", &src_image),
+                ("src_image_layout", &src_image_layout),
+                ("dst_buffer", &dst_buffer),
+                ("region_count", &region_count),
+                ("p_regions", &Array(p_regions, region_count as usize)),
+            ],
+            |fp: PFN_vkCmdCopyImageToBuffer| {
+                fp(
+                    command_buffer,
+                    src_image,
+                    src_image_layout,
+                    dst_buffer,
+                    region_count,
+                    p_regions,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_copy_query_pool_results(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    stride: DeviceSize,
+    flags: QueryResultFlags,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdCopyQueryPoolResults",
+            &[
+                ("command_buffer", &command_buffer),
+                ("query_pool", &query_pool),
+                ("first_query", &first_query),
+                ("query_count", &query_count),
+                ("dst_buffer", &dst_buffer),
+                ("dst_offset", &dst_offset),
+                ("stride", &stride),
+                ("flags", &flags),
+            ],
+            |fp: PFN_vkCmdCopyQueryPoolResults| {
+                fp(
+                    command_buffer,
+                    query_pool,
+                    first_query,
+                    query_count,
+                    dst_buffer,
+                    dst_offset,
+                    stride,
+                    flags,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_debug_marker_begin_ext(
+    command_buffer: CommandBuffer,
+    p_marker_info: *const DebugMarkerMarkerInfoEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDebugMarkerBeginEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_marker_info", &Ptr(p_marker_info)),
+            ],
+            |fp: PFN_vkCmdDebugMarkerBeginEXT| fp(command_buffer, p_marker_info),
+        )
+    }
+}
+extern "system" fn cmd_debug_marker_end_ext(command_buffer: CommandBuffer) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDebugMarkerEndEXT",
+            &[("command_buffer", &command_buffer)],
+            |fp: PFN_vkCmdDebugMarkerEndEXT| fp(command_buffer),
+        )
+    }
+}
+extern "system" fn cmd_debug_marker_insert_ext(
+    command_buffer: CommandBuffer,
+    p_marker_info: *const DebugMarkerMarkerInfoEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDebugMarkerInsertEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_marker_info", &Ptr(p_marker_info)),
+            ],
+            |fp: PFN_vkCmdDebugMarkerInsertEXT| fp(command_buffer, p_marker_info),
+        )
+    }
+}
+extern "system" fn cmd_dispatch(
+    command_buffer: CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDispatch",
+            &[
+                ("command_buffer", &command_buffer),
+                ("group_count_x", &group_count_x),
+                ("group_count_y", &group_count_y),
+                ("group_count_z", &group_count_z),
+            ],
+            |fp: PFN_vkCmdDispatch| fp(command_buffer, group_count_x, group_count_y, group_count_z),
+        )
+    }
+}
+extern "system" fn cmd_dispatch_base(
+    command_buffer: CommandBuffer,
+    base_group_x: u32,
+    base_group_y: u32,
+    base_group_z: u32,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDispatchBase",
+            &[
+                ("command_buffer", &command_buffer),
+                ("base_group_x", &base_group_x),
+                ("base_group_y", &base_group_y),
+                ("base_group_z", &base_group_z),
+                ("group_count_x", &group_count_x),
+                ("group_count_y", &group_count_y),
+                ("group_count_z", &group_count_z),
+            ],
+            |fp: PFN_vkCmdDispatchBase| {
+                fp(
+                    command_buffer,
+                    base_group_x,
+                    base_group_y,
+                    base_group_z,
+                    group_count_x,
+                    group_count_y,
+                    group_count_z,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_dispatch_indirect(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDispatchIndirect",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+            ],
+            |fp: PFN_vkCmdDispatchIndirect| fp(command_buffer, buffer, offset),
+        )
+    }
+}
+extern "system" fn cmd_draw(
+    command_buffer: CommandBuffer,
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDraw",
+            &[
+                ("command_buffer", &command_buffer),
+                ("vertex_count", &vertex_count),
+                ("instance_count", &instance_count),
+                ("first_vertex", &first_vertex),
+                ("first_instance", &first_instance),
+            ],
+            |fp: PFN_vkCmdDraw| {
+                fp(
+                    command_buffer,
+                    vertex_count,
+                    instance_count,
+                    first_vertex,
+                    first_instance,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_indexed(
+    command_buffer: CommandBuffer,
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    vertex_offset: i32,
+    first_instance: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawIndexed",
+            &[
+                ("command_buffer", &command_buffer),
+                ("index_count", &index_count),
+                ("instance_count", &instance_count),
+                ("first_index", &first_index),
+                ("vertex_offset", &vertex_offset),
+                ("first_instance", &first_instance),
+            ],
+            |fp: PFN_vkCmdDrawIndexed| {
+                fp(
+                    command_buffer,
+                    index_count,
+                    instance_count,
+                    first_index,
+                    vertex_offset,
+                    first_instance,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_indexed_indirect(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawIndexedIndirect",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("draw_count", &draw_count),
+                ("stride", &stride),
+            ],
+            |fp: PFN_vkCmdDrawIndexedIndirect| {
+                fp(command_buffer, buffer, offset, draw_count, stride)
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_indexed_indirect_count_amd(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawIndexedIndirectCountAMD",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("count_buffer", &count_buffer),
+                ("count_buffer_offset", &count_buffer_offset),
+                ("max_draw_count", &max_draw_count),
+                ("stride", &stride),
+            ],
+            |fp: PFN_vkCmdDrawIndexedIndirectCountAMD| {
+                fp(
+                    command_buffer,
+                    buffer,
+                    offset,
+                    count_buffer,
+                    count_buffer_offset,
+                    max_draw_count,
+                    stride,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_indexed_indirect_count_khr(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawIndexedIndirectCountKHR",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("count_buffer", &count_buffer),
+                ("count_buffer_offset", &count_buffer_offset),
+                ("max_draw_count", &max_draw_count),
+                ("stride", &stride),
+            ],
+            |fp: PFN_vkCmdDrawIndexedIndirectCountKHR| {
+                fp(
+                    command_buffer,
+                    buffer,
+                    offset,
+                    count_buffer,
+                    count_buffer_offset,
+                    max_draw_count,
+                    stride,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_indirect(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawIndirect",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("draw_count", &draw_count),
+                ("stride", &stride),
+            ],
+            |fp: PFN_vkCmdDrawIndirect| fp(command_buffer, buffer, offset, draw_count, stride),
+        )
+    }
+}
+extern "system" fn cmd_draw_indirect_byte_count_ext(
+    command_buffer: CommandBuffer,
+    instance_count: u32,
+    first_instance: u32,
+    counter_buffer: Buffer,
+    counter_buffer_offset: DeviceSize,
+    counter_offset: u32,
+    vertex_stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawIndirectByteCountEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("instance_count", &instance_count),
+                ("first_instance", &first_instance),
+                ("counter_buffer", &counter_buffer),
+                ("counter_buffer_offset", &counter_buffer_offset),
+                ("counter_offset", &counter_offset),
+                ("vertex_stride", &vertex_stride),
+            ],
+            |fp: PFN_vkCmdDrawIndirectByteCountEXT| {
+                fp(
+                    command_buffer,
+                    instance_count,
+                    first_instance,
+                    counter_buffer,
+                    counter_buffer_offset,
+                    counter_offset,
+                    vertex_stride,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_indirect_count_amd(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawIndirectCountAMD",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("count_buffer", &count_buffer),
+                ("count_buffer_offset", &count_buffer_offset),
+                ("max_draw_count", &max_draw_count),
+                ("stride", &stride),
+            ],
+            |fp: PFN_vkCmdDrawIndirectCountAMD| {
+                fp(
+                    command_buffer,
+                    buffer,
+                    offset,
+                    count_buffer,
+                    count_buffer_offset,
+                    max_draw_count,
+                    stride,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_indirect_count_khr(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawIndirectCountKHR",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("count_buffer", &count_buffer),
+                ("count_buffer_offset", &count_buffer_offset),
+                ("max_draw_count", &max_draw_count),
+                ("stride", &stride),
+            ],
+            |fp: PFN_vkCmdDrawIndirectCountKHR| {
+                fp(
+                    command_buffer,
+                    buffer,
+                    offset,
+                    count_buffer,
+                    count_buffer_offset,
+                    max_draw_count,
+                    stride,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_mesh_tasks_indirect_count_nv(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawMeshTasksIndirectCountNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("count_buffer", &count_buffer),
+                ("count_buffer_offset", &count_buffer_offset),
+                ("max_draw_count", &max_draw_count),
+                ("stride", &stride),
+            ],
+            |fp: PFN_vkCmdDrawMeshTasksIndirectCountNV| {
+                fp(
+                    command_buffer,
+                    buffer,
+                    offset,
+                    count_buffer,
+                    count_buffer_offset,
+                    max_draw_count,
+                    stride,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_mesh_tasks_indirect_nv(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawMeshTasksIndirectNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("buffer", &buffer),
+                ("offset", &offset),
+                ("draw_count", &draw_count),
+                ("stride", &stride),
+            ],
+            |fp: PFN_vkCmdDrawMeshTasksIndirectNV| {
+                fp(command_buffer, buffer, offset, draw_count, stride)
+            },
+        )
+    }
+}
+extern "system" fn cmd_draw_mesh_tasks_nv(
+    command_buffer: CommandBuffer,
+    task_count: u32,
+    first_task: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdDrawMeshTasksNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("task_count", &task_count),
+                ("first_task", &first_task),
+            ],
+            |fp: PFN_vkCmdDrawMeshTasksNV| fp(command_buffer, task_count, first_task),
+        )
+    }
+}
+extern "system" fn cmd_end_conditional_rendering_ext(command_buffer: CommandBuffer) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdEndConditionalRenderingEXT",
+            &[("command_buffer", &command_buffer)],
+            |fp: PFN_vkCmdEndConditionalRenderingEXT| fp(command_buffer),
+        )
+    }
+}
+extern "system" fn cmd_end_debug_utils_label_ext(command_buffer: CommandBuffer) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdEndDebugUtilsLabelEXT",
+            &[("command_buffer", &command_buffer)],
+            |fp: PFN_vkCmdEndDebugUtilsLabelEXT| fp(command_buffer),
+        )
+    }
+}
+extern "system" fn cmd_end_query(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdEndQuery",
+            &[
+                ("command_buffer", &command_buffer),
+                ("query_pool", &query_pool),
+                ("query", &query),
+            ],
+            |fp: PFN_vkCmdEndQuery| fp(command_buffer, query_pool, query),
+        )
+    }
+}
+extern "system" fn cmd_end_query_indexed_ext(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    index: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdEndQueryIndexedEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("query_pool", &query_pool),
+                ("query", &query),
+                ("index", &index),
+            ],
+            |fp: PFN_vkCmdEndQueryIndexedEXT| fp(command_buffer, query_pool, query, index),
+        )
+    }
+}
+extern "system" fn cmd_end_render_pass(command_buffer: CommandBuffer) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdEndRenderPass",
+            &[("command_buffer", &command_buffer)],
+            |fp: PFN_vkCmdEndRenderPass| fp(command_buffer),
+        )
+    }
+}
+extern "system" fn cmd_end_render_pass2_khr(
+    command_buffer: CommandBuffer,
+    p_subpass_end_info: *const SubpassEndInfoKHR,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdEndRenderPass2KHR",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_subpass_end_info", &Ptr(p_subpass_end_info)),
+            ],
+            |fp: PFN_vkCmdEndRenderPass2KHR| fp(command_buffer, p_subpass_end_info),
+        )
+    }
+}
+extern "system" fn cmd_end_transform_feedback_ext(
+    command_buffer: CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const Buffer,
+    p_counter_buffer_offsets: *const DeviceSize,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdEndTransformFeedbackEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_counter_buffer", &first_counter_buffer),
+                ("counter_buffer_count", &counter_buffer_count),
+                (
+                    "p_counter_buffers",
+                    &Array(p_counter_buffers, counter_buffer_count as usize),
+                ),
+                (
+                    "p_counter_buffer_offsets",
+                    &Array(p_counter_buffer_offsets, counter_buffer_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdEndTransformFeedbackEXT| {
+                fp(
+                    command_buffer,
+                    first_counter_buffer,
+                    counter_buffer_count,
+                    p_counter_buffers,
+                    p_counter_buffer_offsets,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_execute_commands(
+    command_buffer: CommandBuffer,
+    command_buffer_count: u32,
+    p_command_buffers: *const CommandBuffer,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdExecuteCommands",
+            &[
+                ("command_buffer", &command_buffer),
+                ("command_buffer_count", &command_buffer_count),
+                (
+                    "p_command_buffers",
+                    &Array(p_command_buffers, command_buffer_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdExecuteCommands| {
+                fp(command_buffer, command_buffer_count, p_command_buffers)
+            },
+        )
+    }
+}
+extern "system" fn cmd_fill_buffer(
+    command_buffer: CommandBuffer,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    size: DeviceSize,
+    data: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdFillBuffer",
+            &[
+                ("command_buffer", &command_buffer),
+                ("dst_buffer", &dst_buffer),
+                ("dst_offset", &dst_offset),
+                ("size", &size),
+                ("data", &data),
+            ],
+            |fp: PFN_vkCmdFillBuffer| fp(command_buffer, dst_buffer, dst_offset, size, data),
+        )
+    }
+}
+extern "system" fn cmd_insert_debug_utils_label_ext(
+    command_buffer: CommandBuffer,
+    p_label_info: *const DebugUtilsLabelEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdInsertDebugUtilsLabelEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_label_info", &Ptr(p_label_info)),
+            ],
+            |fp: PFN_vkCmdInsertDebugUtilsLabelEXT| fp(command_buffer, p_label_info),
+        )
+    }
+}
+extern "system" fn cmd_next_subpass(
+    command_buffer: CommandBuffer,
+    contents: SubpassContents,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdNextSubpass",
+            &[("command_buffer", &command_buffer), ("contents", &contents)],
+            |fp: PFN_vkCmdNextSubpass| fp(command_buffer, contents),
+        )
+    }
+}
+extern "system" fn cmd_next_subpass2_khr(
+    command_buffer: CommandBuffer,
+    p_subpass_begin_info: *const SubpassBeginInfoKHR,
+    p_subpass_end_info: *const SubpassEndInfoKHR,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdNextSubpass2KHR",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_subpass_begin_info", &Ptr(p_subpass_begin_info)),
+                ("p_subpass_end_info", &Ptr(p_subpass_end_info)),
+            ],
+            |fp: PFN_vkCmdNextSubpass2KHR| {
+                fp(command_buffer, p_subpass_begin_info, p_subpass_end_info)
+            },
+        )
+    }
+}
+extern "system" fn cmd_pipeline_barrier(
+    command_buffer: CommandBuffer,
+    src_stage_mask: PipelineStageFlags,
+    dst_stage_mask: PipelineStageFlags,
+    dependency_flags: DependencyFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const MemoryBarrier,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const BufferMemoryBarrier,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const ImageMemoryBarrier,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdPipelineBarrier",
+            &[
+                ("command_buffer", &command_buffer),
+                ("src_stage_mask", &src_stage_mask),
+                ("dst_stage_mask", &dst_stage_mask),
+                ("dependency_flags", &dependency_flags),
+                ("memory_barrier_count", &memory_barrier_count),
+                (
+                    "p_memory_barriers",
+                    &Array(p_memory_barriers, memory_barrier_count as usize),
+                ),
+                ("buffer_memory_barrier_count", &buffer_memory_barrier_count),
+                (
+                    "p_buffer_memory_barriers",
+                    &Array(
+                        p_buffer_memory_barriers,
+                        buffer_memory_barrier_count as usize,
+                    ),
+                ),
+                ("image_memory_barrier_count", &image_memory_barrier_count),
+                (
+                    "p_image_memory_barriers",
+                    &Array(p_image_memory_barriers, image_memory_barrier_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdPipelineBarrier| {
+                fp(
+                    command_buffer,
+                    src_stage_mask,
+                    dst_stage_mask,
+                    dependency_flags,
+                    memory_barrier_count,
+                    p_memory_barriers,
+                    buffer_memory_barrier_count,
+                    p_buffer_memory_barriers,
+                    image_memory_barrier_count,
+                    p_image_memory_barriers,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_process_commands_nvx(
+    command_buffer: CommandBuffer,
+    p_process_commands_info: *const CmdProcessCommandsInfoNVX,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdProcessCommandsNVX",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_process_commands_info", &Ptr(p_process_commands_info)),
+            ],
+            |fp: PFN_vkCmdProcessCommandsNVX| fp(command_buffer, p_process_commands_info),
+        )
+    }
+}
+extern "system" fn cmd_push_constants(
+    command_buffer: CommandBuffer,
+    layout: PipelineLayout,
+    stage_flags: ShaderStageFlags,
+    offset: u32,
+    size: u32,
+    p_values: *const c_void,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdPushConstants",
+            &[
+                ("command_buffer", &command_buffer),
+                ("layout", &layout),
+                ("stage_flags", &stage_flags),
+                ("offset", &offset),
+                ("size", &size),
+                ("p_values", &Bytes(p_values as *const u8, size as usize)),
+            ],
+            |fp: PFN_vkCmdPushConstants| {
+                fp(command_buffer, layout, stage_flags, offset, size, p_values)
+            },
+        )
+    }
+}
+extern "system" fn cmd_push_descriptor_set_khr(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    layout: PipelineLayout,
+    set: u32,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const WriteDescriptorSet,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdPushDescriptorSetKHR",
+            &[
+                ("command_buffer", &command_buffer),
+                ("pipeline_bind_point", &pipeline_bind_point),
+                ("layout", &layout),
+                ("set", &set),
+                ("descriptor_write_count", &descriptor_write_count),
+                (
+                    "p_descriptor_writes",
+                    &Array(p_descriptor_writes, descriptor_write_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdPushDescriptorSetKHR| {
+                fp(
+                    command_buffer,
+                    pipeline_bind_point,
+                    layout,
+                    set,
+                    descriptor_write_count,
+                    p_descriptor_writes,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_push_descriptor_set_with_template_khr(
+    command_buffer: CommandBuffer,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    layout: PipelineLayout,
+    set: u32,
+    p_data: *const c_void,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdPushDescriptorSetWithTemplateKHR",
+            &[
+                ("command_buffer", &command_buffer),
+                ("descriptor_update_template", &descriptor_update_template),
+                ("layout", &layout),
+                ("set", &set),
+                ("p_data", &p_data),
+            ],
+            |fp: PFN_vkCmdPushDescriptorSetWithTemplateKHR| {
+                fp(
+                    command_buffer,
+                    descriptor_update_template,
+                    layout,
+                    set,
+                    p_data,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_reserve_space_for_commands_nvx(
+    command_buffer: CommandBuffer,
+    p_reserve_space_info: *const CmdReserveSpaceForCommandsInfoNVX,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdReserveSpaceForCommandsNVX",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_reserve_space_info", &Ptr(p_reserve_space_info)),
+            ],
+            |fp: PFN_vkCmdReserveSpaceForCommandsNVX| fp(command_buffer, p_reserve_space_info),
+        )
+    }
+}
+extern "system" fn cmd_reset_event(
+    command_buffer: CommandBuffer,
+    event: Event,
+    stage_mask: PipelineStageFlags,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdResetEvent",
+            &[
+                ("command_buffer", &command_buffer),
+                ("event", &event),
+                ("stage_mask", &stage_mask),
+            ],
+            |fp: PFN_vkCmdResetEvent| fp(command_buffer, event, stage_mask),
+        )
+    }
+}
+extern "system" fn cmd_reset_query_pool(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdResetQueryPool",
+            &[
+                ("command_buffer", &command_buffer),
+                ("query_pool", &query_pool),
+                ("first_query", &first_query),
+                ("query_count", &query_count),
+            ],
+            |fp: PFN_vkCmdResetQueryPool| fp(command_buffer, query_pool, first_query, query_count),
+        )
+    }
+}
+extern "system" fn cmd_resolve_image(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageResolve,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdResolveImage",
+            &[
+                ("command_buffer", &command_buffer),
+                ("src_image", &src_image),
+                ("src_image_layout", &src_image_layout),
+                ("dst_image", &dst_image),
+                ("dst_image_layout", &dst_image_layout),
+                ("region_count", &region_count),
+                ("p_regions", &Array(p_regions, region_count as usize)),
+            ],
+            |fp: PFN_vkCmdResolveImage| {
+                fp(
+                    command_buffer,
+                    src_image,
+                    src_image_layout,
+                    dst_image,
+                    dst_image_layout,
+                    region_count,
+                    p_regions,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_set_blend_constants(
+    command_buffer: CommandBuffer,
+    blend_constants: *const [f32; 4],
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetBlendConstants",
+            &[
+                ("command_buffer", &command_buffer),
+                ("blend_constants", &Ptr(blend_constants)),
+            ],
+            |fp: PFN_vkCmdSetBlendConstants| fp(command_buffer, blend_constants),
+        )
+    }
+}
+extern "system" fn cmd_set_checkpoint_nv(
+    command_buffer: CommandBuffer,
+    p_checkpoint_marker: *const c_void,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetCheckpointNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_checkpoint_marker", &p_checkpoint_marker),
+            ],
+            |fp: PFN_vkCmdSetCheckpointNV| fp(command_buffer, p_checkpoint_marker),
+        )
+    }
+}
+extern "system" fn cmd_set_coarse_sample_order_nv(
+    command_buffer: CommandBuffer,
+    sample_order_type: CoarseSampleOrderTypeNV,
+    custom_sample_order_count: u32,
+    p_custom_sample_orders: *const CoarseSampleOrderCustomNV,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetCoarseSampleOrderNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("sample_order_type", &sample_order_type),
+                ("custom_sample_order_count", &custom_sample_order_count),
+                (
+                    "p_custom_sample_orders",
+                    &Array(p_custom_sample_orders, custom_sample_order_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdSetCoarseSampleOrderNV| {
+                fp(
+                    command_buffer,
+                    sample_order_type,
+                    custom_sample_order_count,
+                    p_custom_sample_orders,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_set_depth_bias(
+    command_buffer: CommandBuffer,
+    depth_bias_constant_factor: f32,
+    depth_bias_clamp: f32,
+    depth_bias_slope_factor: f32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetDepthBias",
+            &[
+                ("command_buffer", &command_buffer),
+                ("depth_bias_constant_factor", &depth_bias_constant_factor),
+                ("depth_bias_clamp", &depth_bias_clamp),
+                ("depth_bias_slope_factor", &depth_bias_slope_factor),
+            ],
+            |fp: PFN_vkCmdSetDepthBias| {
+                fp(
+                    command_buffer,
+                    depth_bias_constant_factor,
+                    depth_bias_clamp,
+                    depth_bias_slope_factor,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_set_depth_bounds(
+    command_buffer: CommandBuffer,
+    min_depth_bounds: f32,
+    max_depth_bounds: f32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetDepthBounds",
+            &[
+                ("command_buffer", &command_buffer),
+                ("min_depth_bounds", &min_depth_bounds),
+                ("max_depth_bounds", &max_depth_bounds),
+            ],
+            |fp: PFN_vkCmdSetDepthBounds| fp(command_buffer, min_depth_bounds, max_depth_bounds),
+        )
+    }
+}
+extern "system" fn cmd_set_device_mask(command_buffer: CommandBuffer, device_mask: u32) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetDeviceMask",
+            &[
+                ("command_buffer", &command_buffer),
+                ("device_mask", &device_mask),
+            ],
+            |fp: PFN_vkCmdSetDeviceMask| fp(command_buffer, device_mask),
+        )
+    }
+}
+extern "system" fn cmd_set_discard_rectangle_ext(
+    command_buffer: CommandBuffer,
+    first_discard_rectangle: u32,
+    discard_rectangle_count: u32,
+    p_discard_rectangles: *const Rect2D,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetDiscardRectangleEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_discard_rectangle", &first_discard_rectangle),
+                ("discard_rectangle_count", &discard_rectangle_count),
+                (
+                    "p_discard_rectangles",
+                    &Array(p_discard_rectangles, discard_rectangle_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdSetDiscardRectangleEXT| {
+                fp(
+                    command_buffer,
+                    first_discard_rectangle,
+                    discard_rectangle_count,
+                    p_discard_rectangles,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_set_event(
+    command_buffer: CommandBuffer,
+    event: Event,
+    stage_mask: PipelineStageFlags,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetEvent",
+            &[
+                ("command_buffer", &command_buffer),
+                ("event", &event),
+                ("stage_mask", &stage_mask),
+            ],
+            |fp: PFN_vkCmdSetEvent| fp(command_buffer, event, stage_mask),
+        )
+    }
+}
+extern "system" fn cmd_set_exclusive_scissor_nv(
+    command_buffer: CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissors: *const Rect2D,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetExclusiveScissorNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_exclusive_scissor", &first_exclusive_scissor),
+                ("exclusive_scissor_count", &exclusive_scissor_count),
+                (
+                    "p_exclusive_scissors",
+                    &Array(p_exclusive_scissors, exclusive_scissor_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdSetExclusiveScissorNV| {
+                fp(
+                    command_buffer,
+                    first_exclusive_scissor,
+                    exclusive_scissor_count,
+                    p_exclusive_scissors,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_set_line_width(command_buffer: CommandBuffer, line_width: f32) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetLineWidth",
+            &[
+                ("command_buffer", &command_buffer),
+                ("line_width", &line_width),
+            ],
+            |fp: PFN_vkCmdSetLineWidth| fp(command_buffer, line_width),
+        )
+    }
+}
+extern "system" fn cmd_set_sample_locations_ext(
+    command_buffer: CommandBuffer,
+    p_sample_locations_info: *const SampleLocationsInfoEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetSampleLocationsEXT",
+            &[
+                ("command_buffer", &command_buffer),
+                ("p_sample_locations_info", &Ptr(p_sample_locations_info)),
+            ],
+            |fp: PFN_vkCmdSetSampleLocationsEXT| fp(command_buffer, p_sample_locations_info),
+        )
+    }
+}
+extern "system" fn cmd_set_scissor(
+    command_buffer: CommandBuffer,
+    first_scissor: u32,
+    scissor_count: u32,
+    p_scissors: *const Rect2D,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetScissor",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_scissor", &first_scissor),
+                ("scissor_count", &scissor_count),
+                ("p_scissors", &Array(p_scissors, scissor_count as usize)),
+            ],
+            |fp: PFN_vkCmdSetScissor| fp(command_buffer, first_scissor, scissor_count, p_scissors),
+        )
+    }
+}
+extern "system" fn cmd_set_stencil_compare_mask(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    compare_mask: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetStencilCompareMask",
+            &[
+                ("command_buffer", &command_buffer),
+                ("face_mask", &face_mask),
+                ("compare_mask", &compare_mask),
+            ],
+            |fp: PFN_vkCmdSetStencilCompareMask| fp(command_buffer, face_mask, compare_mask),
+        )
+    }
+}
+extern "system" fn cmd_set_stencil_reference(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    reference: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetStencilReference",
+            &[
+                ("command_buffer", &command_buffer),
+                ("face_mask", &face_mask),
+                ("reference", &reference),
+            ],
+            |fp: PFN_vkCmdSetStencilReference| fp(command_buffer, face_mask, reference),
+        )
+    }
+}
+extern "system" fn cmd_set_stencil_write_mask(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    write_mask: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetStencilWriteMask",
+            &[
+                ("command_buffer", &command_buffer),
+                ("face_mask", &face_mask),
+                ("write_mask", &write_mask),
+            ],
+            |fp: PFN_vkCmdSetStencilWriteMask| fp(command_buffer, face_mask, write_mask),
+        )
+    }
+}
+extern "system" fn cmd_set_viewport(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewports: *const Viewport,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetViewport",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_viewport", &first_viewport),
+                ("viewport_count", &viewport_count),
+                ("p_viewports", &Array(p_viewports, viewport_count as usize)),
+            ],
+            |fp: PFN_vkCmdSetViewport| {
+                fp(command_buffer, first_viewport, viewport_count, p_viewports)
+            },
+        )
+    }
+}
+extern "system" fn cmd_set_viewport_shading_rate_palette_nv(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_shading_rate_palettes: *const ShadingRatePaletteNV,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetViewportShadingRatePaletteNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_viewport", &first_viewport),
+                ("viewport_count", &viewport_count),
+                (
+                    "p_shading_rate_palettes",
+                    &Array(p_shading_rate_palettes, viewport_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdSetViewportShadingRatePaletteNV| {
+                fp(
+                    command_buffer,
+                    first_viewport,
+                    viewport_count,
+                    p_shading_rate_palettes,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_set_viewport_w_scaling_nv(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_w_scalings: *const ViewportWScalingNV,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdSetViewportWScalingNV",
+            &[
+                ("command_buffer", &command_buffer),
+                ("first_viewport", &first_viewport),
+                ("viewport_count", &viewport_count),
+                (
+                    "p_viewport_w_scalings",
+                    &Array(p_viewport_w_scalings, viewport_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdSetViewportWScalingNV| {
+                fp(
+                    command_buffer,
+                    first_viewport,
+                    viewport_count,
+                    p_viewport_w_scalings,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_trace_rays_nv(
+    command_buffer: CommandBuffer,
+    raygen_shader_binding_table_buffer: Buffer,
+    raygen_shader_binding_offset: DeviceSize,
+    miss_shader_binding_table_buffer: Buffer,
+    miss_shader_binding_offset: DeviceSize,
+    miss_shader_binding_stride: DeviceSize,
+    hit_shader_binding_table_buffer: Buffer,
+    hit_shader_binding_offset: DeviceSize,
+    hit_shader_binding_stride: DeviceSize,
+    callable_shader_binding_table_buffer: Buffer,
+    callable_shader_binding_offset: DeviceSize,
+    callable_shader_binding_stride: DeviceSize,
+    width: u32,
+    height: u32,
+    depth: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdTraceRaysNV",
+            &[
+                ("command_buffer", &command_buffer),
+                (
+                    "raygen_shader_binding_table_buffer",
+                    &raygen_shader_binding_table_buffer,
+                ),
+                (
+                    "raygen_shader_binding_offset",
+                    &raygen_shader_binding_offset,
+                ),
+                (
+                    "miss_shader_binding_table_buffer",
+                    &miss_shader_binding_table_buffer,
+                ),
+                ("miss_shader_binding_offset", &miss_shader_binding_offset),
+                ("miss_shader_binding_stride", &miss_shader_binding_stride),
+                (
+                    "hit_shader_binding_table_buffer",
+                    &hit_shader_binding_table_buffer,
+                ),
+                ("hit_shader_binding_offset", &hit_shader_binding_offset),
+                ("hit_shader_binding_stride", &hit_shader_binding_stride),
+                (
+                    "callable_shader_binding_table_buffer",
+                    &callable_shader_binding_table_buffer,
+                ),
+                (
+                    "callable_shader_binding_offset",
+                    &callable_shader_binding_offset,
+                ),
+                (
+                    "callable_shader_binding_stride",
+                    &callable_shader_binding_stride,
+                ),
+                ("width", &width),
+                ("height", &height),
+                ("depth", &depth),
+            ],
+            |fp: PFN_vkCmdTraceRaysNV| {
+                fp(
+                    command_buffer,
+                    raygen_shader_binding_table_buffer,
+                    raygen_shader_binding_offset,
+                    miss_shader_binding_table_buffer,
+                    miss_shader_binding_offset,
+                    miss_shader_binding_stride,
+                    hit_shader_binding_table_buffer,
+                    hit_shader_binding_offset,
+                    hit_shader_binding_stride,
+                    callable_shader_binding_table_buffer,
+                    callable_shader_binding_offset,
+                    callable_shader_binding_stride,
+                    width,
+                    height,
+                    depth,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_update_buffer(
+    command_buffer: CommandBuffer,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    data_size: DeviceSize,
+    p_data: *const c_void,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdUpdateBuffer",
+            &[
+                ("command_buffer", &command_buffer),
+                ("dst_buffer", &dst_buffer),
+                ("dst_offset", &dst_offset),
+                ("data_size", &data_size),
+                ("p_data", &Bytes(p_data as *const u8, data_size as usize)),
+            ],
+            |fp: PFN_vkCmdUpdateBuffer| {
+                fp(command_buffer, dst_buffer, dst_offset, data_size, p_data)
+            },
+        )
+    }
+}
+extern "system" fn cmd_wait_events(
+    command_buffer: CommandBuffer,
+    event_count: u32,
+    p_events: *const Event,
+    src_stage_mask: PipelineStageFlags,
+    dst_stage_mask: PipelineStageFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const MemoryBarrier,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const BufferMemoryBarrier,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const ImageMemoryBarrier,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdWaitEvents",
+            &[
+                ("command_buffer", &command_buffer),
+                ("event_count", &event_count),
+                ("p_events", &Array(p_events, event_count as usize)),
+                ("src_stage_mask", &src_stage_mask),
+                ("dst_stage_mask", &dst_stage_mask),
+                ("memory_barrier_count", &memory_barrier_count),
+                (
+                    "p_memory_barriers",
+                    &Array(p_memory_barriers, memory_barrier_count as usize),
+                ),
+                ("buffer_memory_barrier_count", &buffer_memory_barrier_count),
+                (
+                    "p_buffer_memory_barriers",
+                    &Array(
+                        p_buffer_memory_barriers,
+                        buffer_memory_barrier_count as usize,
+                    ),
+                ),
+                ("image_memory_barrier_count", &image_memory_barrier_count),
+                (
+                    "p_image_memory_barriers",
+                    &Array(p_image_memory_barriers, image_memory_barrier_count as usize),
+                ),
+            ],
+            |fp: PFN_vkCmdWaitEvents| {
+                fp(
+                    command_buffer,
+                    event_count,
+                    p_events,
+                    src_stage_mask,
+                    dst_stage_mask,
+                    memory_barrier_count,
+                    p_memory_barriers,
+                    buffer_memory_barrier_count,
+                    p_buffer_memory_barriers,
+                    image_memory_barrier_count,
+                    p_image_memory_barriers,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_write_acceleration_structures_properties_nv(
+    command_buffer: CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const AccelerationStructureNV,
+    query_type: QueryType,
+    query_pool: QueryPool,
+    first_query: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdWriteAccelerationStructuresPropertiesNV",
+            &[
+                ("command_buffer", &command_buffer),
+                (
+                    "acceleration_structure_count",
+                    &acceleration_structure_count,
+                ),
+                (
+                    "p_acceleration_structures",
+                    &Array(
+                        p_acceleration_structures,
+                        acceleration_structure_count as usize,
+                    ),
+                ),
+                ("query_type", &query_type),
+                ("query_pool", &query_pool),
+                ("first_query", &first_query),
+            ],
+            |fp: PFN_vkCmdWriteAccelerationStructuresPropertiesNV| {
+                fp(
+                    command_buffer,
+                    acceleration_structure_count,
+                    p_acceleration_structures,
+                    query_type,
+                    query_pool,
+                    first_query,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_write_buffer_marker_amd(
+    command_buffer: CommandBuffer,
+    pipeline_stage: PipelineStageFlags,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    marker: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdWriteBufferMarkerAMD",
+            &[
+                ("command_buffer", &command_buffer),
+                ("pipeline_stage", &pipeline_stage),
+                ("dst_buffer", &dst_buffer),
+                ("dst_offset", &dst_offset),
+                ("marker", &marker),
+            ],
+            |fp: PFN_vkCmdWriteBufferMarkerAMD| {
+                fp(
+                    command_buffer,
+                    pipeline_stage,
+                    dst_buffer,
+                    dst_offset,
+                    marker,
+                )
+            },
+        )
+    }
+}
+extern "system" fn cmd_write_timestamp(
+    command_buffer: CommandBuffer,
+    pipeline_stage: PipelineStageFlags,
+    query_pool: QueryPool,
+    query: u32,
+) -> c_void {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkCmdWriteTimestamp",
+            &[
+                ("command_buffer", &command_buffer),
+                ("pipeline_stage", &pipeline_stage),
+                ("query_pool", &query_pool),
+                ("query", &query),
+            ],
+            |fp: PFN_vkCmdWriteTimestamp| fp(command_buffer, pipeline_stage, query_pool, query),
+        )
+    }
+}
+extern "system" fn compile_deferred_nv(device: Device, pipeline: Pipeline, shader: u32) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCompileDeferredNV",
+            &[
+                ("device", &device),
+                ("pipeline", &pipeline),
+                ("shader", &shader),
+            ],
+            |fp: PFN_vkCompileDeferredNV| fp(device, pipeline, shader),
+        )
+    }
+}
+extern "system" fn create_acceleration_structure_nv(
+    device: Device,
+    p_create_info: *const AccelerationStructureCreateInfoNV,
+    p_allocator: *const AllocationCallbacks,
+    p_acceleration_structure: *mut AccelerationStructureNV,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateAccelerationStructureNV",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_acceleration_structure", &Out(p_acceleration_structure)),
+            ],
+            |fp: PFN_vkCreateAccelerationStructureNV| {
+                fp(device, p_create_info, p_allocator, p_acceleration_structure)
+            },
+        )
+    }
+}
+extern "system" fn create_android_surface_khr(
+    instance: Instance,
+    p_create_info: *const AndroidSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateAndroidSurfaceKHR",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateAndroidSurfaceKHR| fp(instance, p_create_info, p_allocator, p_surface),
+        )
+    }
+}
+extern "system" fn create_buffer(
+    device: Device,
+    p_create_info: *const BufferCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_buffer: *mut Buffer,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateBuffer",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_buffer", &Out(p_buffer)),
+            ],
+            |fp: PFN_vkCreateBuffer| fp(device, p_create_info, p_allocator, p_buffer),
+        )
+    }
+}
+extern "system" fn create_buffer_view(
+    device: Device,
+    p_create_info: *const BufferViewCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_view: *mut BufferView,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateBufferView",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_view", &Out(p_view)),
+            ],
+            |fp: PFN_vkCreateBufferView| fp(device, p_create_info, p_allocator, p_view),
+        )
+    }
+}
+extern "system" fn create_command_pool(
+    device: Device,
+    p_create_info: *const CommandPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_command_pool: *mut CommandPool,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateCommandPool",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_command_pool", &Out(p_command_pool)),
+            ],
+            |fp: PFN_vkCreateCommandPool| fp(device, p_create_info, p_allocator, p_command_pool),
+        )
+    }
+}
+extern "system" fn create_compute_pipelines(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const ComputePipelineCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateComputePipelines",
+            &[
+                ("device", &device),
+                ("pipeline_cache", &pipeline_cache),
+                ("create_info_count", &create_info_count),
+                (
+                    "p_create_infos",
+                    &Array(p_create_infos, create_info_count as usize),
+                ),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_pipelines", &OutArray(p_pipelines, &create_info_count)),
+            ],
+            |fp: PFN_vkCreateComputePipelines| {
+                fp(
+                    device,
+                    pipeline_cache,
+                    create_info_count,
+                    p_create_infos,
+                    p_allocator,
+                    p_pipelines,
+                )
+            },
+        )
+    }
+}
+extern "system" fn create_debug_report_callback_ext(
+    instance: Instance,
+    p_create_info: *const DebugReportCallbackCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_callback: *mut DebugReportCallbackEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateDebugReportCallbackEXT",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_callback", &Out(p_callback)),
+            ],
+            |fp: PFN_vkCreateDebugReportCallbackEXT| {
+                fp(instance, p_create_info, p_allocator, p_callback)
+            },
+        )
+    }
+}
+extern "system" fn create_debug_utils_messenger_ext(
+    instance: Instance,
+    p_create_info: *const DebugUtilsMessengerCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_messenger: *mut DebugUtilsMessengerEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateDebugUtilsMessengerEXT",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_messenger", &Out(p_messenger)),
+            ],
+            |fp: PFN_vkCreateDebugUtilsMessengerEXT| {
+                fp(instance, p_create_info, p_allocator, p_messenger)
+            },
+        )
+    }
+}
+extern "system" fn create_descriptor_pool(
+    device: Device,
+    p_create_info: *const DescriptorPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_descriptor_pool: *mut DescriptorPool,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateDescriptorPool",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_descriptor_pool", &Out(p_descriptor_pool)),
+            ],
+            |fp: PFN_vkCreateDescriptorPool| {
+                fp(device, p_create_info, p_allocator, p_descriptor_pool)
+            },
+        )
+    }
+}
+extern "system" fn create_descriptor_set_layout(
+    device: Device,
+    p_create_info: *const DescriptorSetLayoutCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_set_layout: *mut DescriptorSetLayout,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateDescriptorSetLayout",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_set_layout", &Out(p_set_layout)),
+            ],
+            |fp: PFN_vkCreateDescriptorSetLayout| {
+                fp(device, p_create_info, p_allocator, p_set_layout)
+            },
+        )
+    }
+}
+extern "system" fn create_descriptor_update_template(
+    device: Device,
+    p_create_info: *const DescriptorUpdateTemplateCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_descriptor_update_template: *mut DescriptorUpdateTemplate,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateDescriptorUpdateTemplate",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                (
+                    "p_descriptor_update_template",
+                    &Out(p_descriptor_update_template),
+                ),
+            ],
+            |fp: PFN_vkCreateDescriptorUpdateTemplate| {
+                fp(
+                    device,
+                    p_create_info,
+                    p_allocator,
+                    p_descriptor_update_template,
+                )
+            },
+        )
+    }
+}
+extern "system" fn create_display_mode_khr(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_create_info: *const DisplayModeCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_mode: *mut DisplayModeKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkCreateDisplayModeKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("display", &display),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_mode", &Out(p_mode)),
+            ],
+            |fp: PFN_vkCreateDisplayModeKHR| {
+                fp(physical_device, display, p_create_info, p_allocator, p_mode)
+            },
+        )
+    }
+}
+extern "system" fn create_display_plane_surface_khr(
+    instance: Instance,
+    p_create_info: *const DisplaySurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateDisplayPlaneSurfaceKHR",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateDisplayPlaneSurfaceKHR| {
+                fp(instance, p_create_info, p_allocator, p_surface)
+            },
+        )
+    }
+}
+extern "system" fn create_event(
+    device: Device,
+    p_create_info: *const EventCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_event: *mut Event,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateEvent",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_event", &Out(p_event)),
+            ],
+            |fp: PFN_vkCreateEvent| fp(device, p_create_info, p_allocator, p_event),
+        )
+    }
+}
+extern "system" fn create_fence(
+    device: Device,
+    p_create_info: *const FenceCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateFence",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_fence", &Out(p_fence)),
+            ],
+            |fp: PFN_vkCreateFence| fp(device, p_create_info, p_allocator, p_fence),
+        )
+    }
+}
+extern "system" fn create_framebuffer(
+    device: Device,
+    p_create_info: *const FramebufferCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_framebuffer: *mut Framebuffer,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateFramebuffer",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_framebuffer", &Out(p_framebuffer)),
+            ],
+            |fp: PFN_vkCreateFramebuffer| fp(device, p_create_info, p_allocator, p_framebuffer),
+        )
+    }
+}
+extern "system" fn create_graphics_pipelines(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const GraphicsPipelineCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateGraphicsPipelines",
+            &[
+                ("device", &device),
+                ("pipeline_cache", &pipeline_cache),
+                ("create_info_count", &create_info_count),
+                (
+                    "p_create_infos",
+                    &Array(p_create_infos, create_info_count as usize),
+                ),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_pipelines", &OutArray(p_pipelines, &create_info_count)),
+            ],
+            |fp: PFN_vkCreateGraphicsPipelines| {
+                fp(
+                    device,
+                    pipeline_cache,
+                    create_info_count,
+                    p_create_infos,
+                    p_allocator,
+                    p_pipelines,
+                )
+            },
+        )
+    }
+}
+extern "system" fn create_ios_surface_mvk(
+    instance: Instance,
+    p_create_info: *const IOSSurfaceCreateInfoMVK,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateIOSSurfaceMVK",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateIOSSurfaceMVK| fp(instance, p_create_info, p_allocator, p_surface),
+        )
+    }
+}
+extern "system" fn create_image(
+    device: Device,
+    p_create_info: *const ImageCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_image: *mut Image,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateImage",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_image", &Out(p_image)),
+            ],
+            |fp: PFN_vkCreateImage| fp(device, p_create_info, p_allocator, p_image),
+        )
+    }
+}
+extern "system" fn create_image_pipe_surface_fuchsia(
+    instance: Instance,
+    p_create_info: *const ImagePipeSurfaceCreateInfoFUCHSIA,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateImagePipeSurfaceFUCHSIA",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateImagePipeSurfaceFUCHSIA| {
+                fp(instance, p_create_info, p_allocator, p_surface)
+            },
+        )
+    }
+}
+extern "system" fn create_image_view(
+    device: Device,
+    p_create_info: *const ImageViewCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_view: *mut ImageView,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateImageView",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_view", &Out(p_view)),
+            ],
+            |fp: PFN_vkCreateImageView| fp(device, p_create_info, p_allocator, p_view),
+        )
+    }
+}
+extern "system" fn create_indirect_commands_layout_nvx(
+    device: Device,
+    p_create_info: *const IndirectCommandsLayoutCreateInfoNVX,
+    p_allocator: *const AllocationCallbacks,
+    p_indirect_commands_layout: *mut IndirectCommandsLayoutNVX,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateIndirectCommandsLayoutNVX",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                (
+                    "p_indirect_commands_layout",
+                    &Out(p_indirect_commands_layout),
+                ),
+            ],
+            |fp: PFN_vkCreateIndirectCommandsLayoutNVX| {
+                fp(
+                    device,
+                    p_create_info,
+                    p_allocator,
+                    p_indirect_commands_layout,
+                )
+            },
+        )
+    }
+}
+extern "system" fn create_mac_os_surface_mvk(
+    instance: Instance,
+    p_create_info: *const MacOSSurfaceCreateInfoMVK,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateMacOSSurfaceMVK",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateMacOSSurfaceMVK| fp(instance, p_create_info, p_allocator, p_surface),
+        )
+    }
+}
+extern "system" fn create_object_table_nvx(
+    device: Device,
+    p_create_info: *const ObjectTableCreateInfoNVX,
+    p_allocator: *const AllocationCallbacks,
+    p_object_table: *mut ObjectTableNVX,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateObjectTableNVX",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_object_table", &Out(p_object_table)),
+            ],
+            |fp: PFN_vkCreateObjectTableNVX| fp(device, p_create_info, p_allocator, p_object_table),
+        )
+    }
+}
+extern "system" fn create_pipeline_cache(
+    device: Device,
+    p_create_info: *const PipelineCacheCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipeline_cache: *mut PipelineCache,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreatePipelineCache",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_pipeline_cache", &Out(p_pipeline_cache)),
+            ],
+            |fp: PFN_vkCreatePipelineCache| {
+                fp(device, p_create_info, p_allocator, p_pipeline_cache)
+            },
+        )
+    }
+}
+extern "system" fn create_pipeline_layout(
+    device: Device,
+    p_create_info: *const PipelineLayoutCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipeline_layout: *mut PipelineLayout,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreatePipelineLayout",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_pipeline_layout", &Out(p_pipeline_layout)),
+            ],
+            |fp: PFN_vkCreatePipelineLayout| {
+                fp(device, p_create_info, p_allocator, p_pipeline_layout)
+            },
+        )
+    }
+}
+extern "system" fn create_query_pool(
+    device: Device,
+    p_create_info: *const QueryPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_query_pool: *mut QueryPool,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateQueryPool",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_query_pool", &Out(p_query_pool)),
+            ],
+            |fp: PFN_vkCreateQueryPool| fp(device, p_create_info, p_allocator, p_query_pool),
+        )
+    }
+}
+extern "system" fn create_ray_tracing_pipelines_nv(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const RayTracingPipelineCreateInfoNV,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateRayTracingPipelinesNV",
+            &[
+                ("device", &device),
+                ("pipeline_cache", &pipeline_cache),
+                ("create_info_count", &create_info_count),
+                (
+                    "p_create_infos",
+                    &Array(p_create_infos, create_info_count as usize),
+                ),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_pipelines", &OutArray(p_pipelines, &create_info_count)),
+            ],
+            |fp: PFN_vkCreateRayTracingPipelinesNV| {
+                fp(
+                    device,
+                    pipeline_cache,
+                    create_info_count,
+                    p_create_infos,
+                    p_allocator,
+                    p_pipelines,
+                )
+            },
+        )
+    }
+}
+extern "system" fn create_render_pass(
+    device: Device,
+    p_create_info: *const RenderPassCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_render_pass: *mut RenderPass,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateRenderPass",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_render_pass", &Out(p_render_pass)),
+            ],
+            |fp: PFN_vkCreateRenderPass| fp(device, p_create_info, p_allocator, p_render_pass),
+        )
+    }
+}
+extern "system" fn create_render_pass2_khr(
+    device: Device,
+    p_create_info: *const RenderPassCreateInfo2KHR,
+    p_allocator: *const AllocationCallbacks,
+    p_render_pass: *mut RenderPass,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateRenderPass2KHR",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_render_pass", &Out(p_render_pass)),
+            ],
+            |fp: PFN_vkCreateRenderPass2KHR| fp(device, p_create_info, p_allocator, p_render_pass),
+        )
+    }
+}
+extern "system" fn create_sampler(
+    device: Device,
+    p_create_info: *const SamplerCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_sampler: *mut Sampler,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateSampler",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_sampler", &Out(p_sampler)),
+            ],
+            |fp: PFN_vkCreateSampler| fp(device, p_create_info, p_allocator, p_sampler),
+        )
+    }
+}
+extern "system" fn create_sampler_ycbcr_conversion(
+    device: Device,
+    p_create_info: *const SamplerYcbcrConversionCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_ycbcr_conversion: *mut SamplerYcbcrConversion,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateSamplerYcbcrConversion",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_ycbcr_conversion", &Out(p_ycbcr_conversion)),
+            ],
+            |fp: PFN_vkCreateSamplerYcbcrConversion| {
+                fp(device, p_create_info, p_allocator, p_ycbcr_conversion)
+            },
+        )
+    }
+}
+extern "system" fn create_semaphore(
+    device: Device,
+    p_create_info: *const SemaphoreCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_semaphore: *mut Semaphore,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateSemaphore",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_semaphore", &Out(p_semaphore)),
+            ],
+            |fp: PFN_vkCreateSemaphore| fp(device, p_create_info, p_allocator, p_semaphore),
+        )
+    }
+}
+extern "system" fn create_shader_module(
+    device: Device,
+    p_create_info: *const ShaderModuleCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_shader_module: *mut ShaderModule,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateShaderModule",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_shader_module", &Out(p_shader_module)),
+            ],
+            |fp: PFN_vkCreateShaderModule| fp(device, p_create_info, p_allocator, p_shader_module),
+        )
+    }
+}
+extern "system" fn create_shared_swapchains_khr(
+    device: Device,
+    swapchain_count: u32,
+    p_create_infos: *const SwapchainCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_swapchains: *mut SwapchainKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateSharedSwapchainsKHR",
+            &[
+                ("device", &device),
+                ("swapchain_count", &swapchain_count),
+                (
+                    "p_create_infos",
+                    &Array(p_create_infos, swapchain_count as usize),
+                ),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_swapchains", &OutArray(p_swapchains, &swapchain_count)),
+            ],
+            |fp: PFN_vkCreateSharedSwapchainsKHR| {
+                fp(
+                    device,
+                    swapchain_count,
+                    p_create_infos,
+                    p_allocator,
+                    p_swapchains,
+                )
+            },
+        )
+    }
+}
+extern "system" fn create_swapchain_khr(
+    device: Device,
+    p_create_info: *const SwapchainCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_swapchain: *mut SwapchainKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateSwapchainKHR",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_swapchain", &Out(p_swapchain)),
+            ],
+            |fp: PFN_vkCreateSwapchainKHR| fp(device, p_create_info, p_allocator, p_swapchain),
+        )
+    }
+}
+extern "system" fn create_validation_cache_ext(
+    device: Device,
+    p_create_info: *const ValidationCacheCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_validation_cache: *mut ValidationCacheEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkCreateValidationCacheEXT",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_validation_cache", &Out(p_validation_cache)),
+            ],
+            |fp: PFN_vkCreateValidationCacheEXT| {
+                fp(device, p_create_info, p_allocator, p_validation_cache)
+            },
+        )
+    }
+}
+extern "system" fn create_vi_surface_nn(
+    instance: Instance,
+    p_create_info: *const ViSurfaceCreateInfoNN,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateViSurfaceNN",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateViSurfaceNN| fp(instance, p_create_info, p_allocator, p_surface),
+        )
+    }
+}
+extern "system" fn create_wayland_surface_khr(
+    instance: Instance,
+    p_create_info: *const WaylandSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateWaylandSurfaceKHR",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateWaylandSurfaceKHR| fp(instance, p_create_info, p_allocator, p_surface),
+        )
+    }
+}
+extern "system" fn create_win32_surface_khr(
+    instance: Instance,
+    p_create_info: *const Win32SurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateWin32SurfaceKHR",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateWin32SurfaceKHR| fp(instance, p_create_info, p_allocator, p_surface),
+        )
+    }
+}
+extern "system" fn create_xcb_surface_khr(
+    instance: Instance,
+    p_create_info: *const XcbSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateXcbSurfaceKHR",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateXcbSurfaceKHR| fp(instance, p_create_info, p_allocator, p_surface),
+        )
+    }
+}
+extern "system" fn create_xlib_surface_khr(
+    instance: Instance,
+    p_create_info: *const XlibSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkCreateXlibSurfaceKHR",
+            &[
+                ("instance", &instance),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_surface", &Out(p_surface)),
+            ],
+            |fp: PFN_vkCreateXlibSurfaceKHR| fp(instance, p_create_info, p_allocator, p_surface),
+        )
+    }
+}
+extern "system" fn debug_marker_set_object_name_ext(
+    device: Device,
+    p_name_info: *const DebugMarkerObjectNameInfoEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkDebugMarkerSetObjectNameEXT",
+            &[("device", &device), ("p_name_info", &Ptr(p_name_info))],
+            |fp: PFN_vkDebugMarkerSetObjectNameEXT| fp(device, p_name_info),
+        )
+    }
+}
+extern "system" fn debug_marker_set_object_tag_ext(
+    device: Device,
+    p_tag_info: *const DebugMarkerObjectTagInfoEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkDebugMarkerSetObjectTagEXT",
+            &[("device", &device), ("p_tag_info", &Ptr(p_tag_info))],
+            |fp: PFN_vkDebugMarkerSetObjectTagEXT| fp(device, p_tag_info),
+        )
+    }
+}
+extern "system" fn debug_report_message_ext(
+    instance: Instance,
+    flags: DebugReportFlagsEXT,
+    object_type: DebugReportObjectTypeEXT,
+    object: u64,
+    location: usize,
+    message_code: i32,
+    p_layer_prefix: *const c_char,
+    p_message: *const c_char,
+) -> c_void {
+    unsafe {
+        trace_call(
+            instance,
+            "vkDebugReportMessageEXT",
+            &[
+                ("instance", &instance),
+                ("flags", &flags),
+                ("object_type", &object_type),
+                ("object", &object),
+                ("location", &location),
+                ("message_code", &message_code),
+                ("p_layer_prefix", &Str(p_layer_prefix)),
+                ("p_message", &Str(p_message)),
+            ],
+            |fp: PFN_vkDebugReportMessageEXT| {
+                fp(
+                    instance,
+                    flags,
+                    object_type,
+                    object,
+                    location,
+                    message_code,
+                    p_layer_prefix,
+                    p_message,
+                )
+            },
+        )
+    }
+}
+extern "system" fn destroy_acceleration_structure_nv(
+    device: Device,
+    acceleration_structure: AccelerationStructureNV,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyAccelerationStructureNV",
+            &[
+                ("device", &device),
+                ("acceleration_structure", &acceleration_structure),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyAccelerationStructureNV| {
+                fp(device, acceleration_structure, p_allocator)
+            },
+        )
+    }
+}
+extern "system" fn destroy_buffer(
+    device: Device,
+    buffer: Buffer,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyBuffer",
+            &[
+                ("device", &device),
+                ("buffer", &buffer),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyBuffer| fp(device, buffer, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_buffer_view(
+    device: Device,
+    buffer_view: BufferView,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyBufferView",
+            &[
+                ("device", &device),
+                ("buffer_view", &buffer_view),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyBufferView| fp(device, buffer_view, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_command_pool(
+    device: Device,
+    command_pool: CommandPool,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyCommandPool",
+            &[
+                ("device", &device),
+                ("command_pool", &command_pool),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyCommandPool| fp(device, command_pool, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_debug_report_callback_ext(
+    instance: Instance,
+    callback: DebugReportCallbackEXT,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            instance,
+            "vkDestroyDebugReportCallbackEXT",
+            &[
+                ("instance", &instance),
+                ("callback", &callback),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyDebugReportCallbackEXT| fp(instance, callback, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_debug_utils_messenger_ext(
+    instance: Instance,
+    messenger: DebugUtilsMessengerEXT,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            instance,
+            "vkDestroyDebugUtilsMessengerEXT",
+            &[
+                ("instance", &instance),
+                ("messenger", &messenger),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyDebugUtilsMessengerEXT| fp(instance, messenger, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_descriptor_pool(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyDescriptorPool",
+            &[
+                ("device", &device),
+                ("descriptor_pool", &descriptor_pool),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyDescriptorPool| fp(device, descriptor_pool, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_descriptor_set_layout(
+    device: Device,
+    descriptor_set_layout: DescriptorSetLayout,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyDescriptorSetLayout",
+            &[
+                ("device", &device),
+                ("descriptor_set_layout", &descriptor_set_layout),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyDescriptorSetLayout| fp(device, descriptor_set_layout, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_descriptor_update_template(
+    device: Device,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyDescriptorUpdateTemplate",
+            &[
+                ("device", &device),
+                ("descriptor_update_template", &descriptor_update_template),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyDescriptorUpdateTemplate| {
+                fp(device, descriptor_update_template, p_allocator)
+            },
+        )
+    }
+}
+extern "system" fn destroy_device(
+    device: Device,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyDevice",
+            &[("device", &device), ("p_allocator", &Ptr(p_allocator))],
+            |fp: PFN_vkDestroyDevice| fp(device, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_event(
+    device: Device,
+    event: Event,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyEvent",
+            &[
+                ("device", &device),
+                ("event", &event),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyEvent| fp(device, event, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_fence(
+    device: Device,
+    fence: Fence,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyFence",
+            &[
+                ("device", &device),
+                ("fence", &fence),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyFence| fp(device, fence, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_framebuffer(
+    device: Device,
+    framebuffer: Framebuffer,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyFramebuffer",
+            &[
+                ("device", &device),
+                ("framebuffer", &framebuffer),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyFramebuffer| fp(device, framebuffer, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_image(
+    device: Device,
+    image: Image,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyImage",
+            &[
+                ("device", &device),
+                ("image", &image),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyImage| fp(device, image, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_image_view(
+    device: Device,
+    image_view: ImageView,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyImageView",
+            &[
+                ("device", &device),
+                ("image_view", &image_view),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyImageView| fp(device, image_view, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_indirect_commands_layout_nvx(
+    device: Device,
+    indirect_commands_layout: IndirectCommandsLayoutNVX,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyIndirectCommandsLayoutNVX",
+            &[
+                ("device", &device),
+                ("indirect_commands_layout", &indirect_commands_layout),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyIndirectCommandsLayoutNVX| {
+                fp(device, indirect_commands_layout, p_allocator)
+            },
+        )
+    }
+}
+extern "system" fn destroy_instance(
+    instance: Instance,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            instance,
+            "vkDestroyInstance",
+            &[("instance", &instance), ("p_allocator", &Ptr(p_allocator))],
+            |fp: PFN_vkDestroyInstance| fp(instance, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_object_table_nvx(
+    device: Device,
+    object_table: ObjectTableNVX,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyObjectTableNVX",
+            &[
+                ("device", &device),
+                ("object_table", &object_table),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyObjectTableNVX| fp(device, object_table, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_pipeline(
+    device: Device,
+    pipeline: Pipeline,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyPipeline",
+            &[
+                ("device", &device),
+                ("pipeline", &pipeline),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyPipeline| fp(device, pipeline, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_pipeline_cache(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyPipelineCache",
+            &[
+                ("device", &device),
+                ("pipeline_cache", &pipeline_cache),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyPipelineCache| fp(device, pipeline_cache, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_pipeline_layout(
+    device: Device,
+    pipeline_layout: PipelineLayout,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyPipelineLayout",
+            &[
+                ("device", &device),
+                ("pipeline_layout", &pipeline_layout),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyPipelineLayout| fp(device, pipeline_layout, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_query_pool(
+    device: Device,
+    query_pool: QueryPool,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyQueryPool",
+            &[
+                ("device", &device),
+                ("query_pool", &query_pool),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyQueryPool| fp(device, query_pool, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_render_pass(
+    device: Device,
+    render_pass: RenderPass,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyRenderPass",
+            &[
+                ("device", &device),
+                ("render_pass", &render_pass),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyRenderPass| fp(device, render_pass, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_sampler(
+    device: Device,
+    sampler: Sampler,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroySampler",
+            &[
+                ("device", &device),
+                ("sampler", &sampler),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroySampler| fp(device, sampler, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_sampler_ycbcr_conversion(
+    device: Device,
+    ycbcr_conversion: SamplerYcbcrConversion,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroySamplerYcbcrConversion",
+            &[
+                ("device", &device),
+                ("ycbcr_conversion", &ycbcr_conversion),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroySamplerYcbcrConversion| fp(device, ycbcr_conversion, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_semaphore(
+    device: Device,
+    semaphore: Semaphore,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroySemaphore",
+            &[
+                ("device", &device),
+                ("semaphore", &semaphore),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroySemaphore| fp(device, semaphore, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_shader_module(
+    device: Device,
+    shader_module: ShaderModule,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyShaderModule",
+            &[
+                ("device", &device),
+                ("shader_module", &shader_module),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyShaderModule| fp(device, shader_module, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_surface_khr(
+    instance: Instance,
+    surface: SurfaceKHR,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            instance,
+            "vkDestroySurfaceKHR",
+            &[
+                ("instance", &instance),
+                ("surface", &surface),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroySurfaceKHR| fp(instance, surface, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_swapchain_khr(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroySwapchainKHR",
+            &[
+                ("device", &device),
+                ("swapchain", &swapchain),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroySwapchainKHR| fp(device, swapchain, p_allocator),
+        )
+    }
+}
+extern "system" fn destroy_validation_cache_ext(
+    device: Device,
+    validation_cache: ValidationCacheEXT,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkDestroyValidationCacheEXT",
+            &[
+                ("device", &device),
+                ("validation_cache", &validation_cache),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkDestroyValidationCacheEXT| fp(device, validation_cache, p_allocator),
+        )
+    }
+}
+extern "system" fn device_wait_idle(device: Device) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkDeviceWaitIdle",
+            &[("device", &device)],
+            |fp: PFN_vkDeviceWaitIdle| fp(device),
+        )
+    }
+}
+extern "system" fn display_power_control_ext(
+    device: Device,
+    display: DisplayKHR,
+    p_display_power_info: *const DisplayPowerInfoEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkDisplayPowerControlEXT",
+            &[
+                ("device", &device),
+                ("display", &display),
+                ("p_display_power_info", &Ptr(p_display_power_info)),
+            ],
+            |fp: PFN_vkDisplayPowerControlEXT| fp(device, display, p_display_power_info),
+        )
+    }
+}
+extern "system" fn end_command_buffer(command_buffer: CommandBuffer) -> Result {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkEndCommandBuffer",
+            &[("command_buffer", &command_buffer)],
+            |fp: PFN_vkEndCommandBuffer| fp(command_buffer),
+        )
+    }
+}
+extern "system" fn enumerate_device_extension_properties(
+    physical_device: PhysicalDevice,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut ExtensionProperties,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkEnumerateDeviceExtensionProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("p_layer_name", &Str(p_layer_name)),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkEnumerateDeviceExtensionProperties| {
+                fp(
+                    physical_device,
+                    p_layer_name,
+                    p_property_count,
+                    p_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn enumerate_device_layer_properties(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut LayerProperties,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkEnumerateDeviceLayerProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkEnumerateDeviceLayerProperties| {
+                fp(physical_device, p_property_count, p_properties)
+            },
+        )
+    }
+}
+extern "system" fn enumerate_physical_device_groups(
+    instance: Instance,
+    p_physical_device_group_count: *mut u32,
+    p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkEnumeratePhysicalDeviceGroups",
+            &[
+                ("instance", &instance),
+                (
+                    "p_physical_device_group_count",
+                    &Out(p_physical_device_group_count),
+                ),
+                (
+                    "p_physical_device_group_properties",
+                    &OutArray(
+                        p_physical_device_group_properties,
+                        p_physical_device_group_count,
+                    ),
+                ),
+            ],
+            |fp: PFN_vkEnumeratePhysicalDeviceGroups| {
+                fp(
+                    instance,
+                    p_physical_device_group_count,
+                    p_physical_device_group_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn enumerate_physical_devices(
+    instance: Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut PhysicalDevice,
+) -> Result {
+    unsafe {
+        trace_call(
+            instance,
+            "vkEnumeratePhysicalDevices",
+            &[
+                ("instance", &instance),
+                ("p_physical_device_count", &Out(p_physical_device_count)),
+                (
+                    "p_physical_devices",
+                    &OutArray(p_physical_devices, p_physical_device_count),
+                ),
+            ],
+            |fp: PFN_vkEnumeratePhysicalDevices| {
+                fp(instance, p_physical_device_count, p_physical_devices)
+            },
+        )
+    }
+}
+extern "system" fn flush_mapped_memory_ranges(
+    device: Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const MappedMemoryRange,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkFlushMappedMemoryRanges",
+            &[
+                ("device", &device),
+                ("memory_range_count", &memory_range_count),
+                (
+                    "p_memory_ranges",
+                    &Array(p_memory_ranges, memory_range_count as usize),
+                ),
+            ],
+            |fp: PFN_vkFlushMappedMemoryRanges| fp(device, memory_range_count, p_memory_ranges),
+        )
+    }
+}
+extern "system" fn free_command_buffers(
+    device: Device,
+    command_pool: CommandPool,
+    command_buffer_count: u32,
+    p_command_buffers: *const CommandBuffer,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkFreeCommandBuffers",
+            &[
+                ("device", &device),
+                ("command_pool", &command_pool),
+                ("command_buffer_count", &command_buffer_count),
+                (
+                    "p_command_buffers",
+                    &Array(p_command_buffers, command_buffer_count as usize),
+                ),
+            ],
+            |fp: PFN_vkFreeCommandBuffers| {
+                fp(
+                    device,
+                    command_pool,
+                    command_buffer_count,
+                    p_command_buffers,
+                )
+            },
+        )
+    }
+}
+extern "system" fn free_descriptor_sets(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const DescriptorSet,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkFreeDescriptorSets",
+            &[
+                ("device", &device),
+                ("descriptor_pool", &descriptor_pool),
+                ("descriptor_set_count", &descriptor_set_count),
+                (
+                    "p_descriptor_sets",
+                    &Array(p_descriptor_sets, descriptor_set_count as usize),
+                ),
+            ],
+            |fp: PFN_vkFreeDescriptorSets| {
+                fp(
+                    device,
+                    descriptor_pool,
+                    descriptor_set_count,
+                    p_descriptor_sets,
+                )
+            },
+        )
+    }
+}
+extern "system" fn free_memory(
+    device: Device,
+    memory: DeviceMemory,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkFreeMemory",
+            &[
+                ("device", &device),
+                ("memory", &memory),
+                ("p_allocator", &Ptr(p_allocator)),
+            ],
+            |fp: PFN_vkFreeMemory| fp(device, memory, p_allocator),
+        )
+    }
+}
+extern "system" fn get_acceleration_structure_handle_nv(
+    device: Device,
+    acceleration_structure: AccelerationStructureNV,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetAccelerationStructureHandleNV",
+            &[
+                ("device", &device),
+                ("acceleration_structure", &acceleration_structure),
+                ("data_size", &data_size),
+                ("p_data", &OutBytes(p_data, &data_size)),
+            ],
+            |fp: PFN_vkGetAccelerationStructureHandleNV| {
+                fp(device, acceleration_structure, data_size, p_data)
+            },
+        )
+    }
+}
+extern "system" fn get_acceleration_structure_memory_requirements_nv(
+    device: Device,
+    p_info: *const AccelerationStructureMemoryRequirementsInfoNV,
+    p_memory_requirements: *mut MemoryRequirements2KHR,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetAccelerationStructureMemoryRequirementsNV",
+            &[
+                ("device", &device),
+                ("p_info", &Ptr(p_info)),
+                ("p_memory_requirements", &Out(p_memory_requirements)),
+            ],
+            |fp: PFN_vkGetAccelerationStructureMemoryRequirementsNV| {
+                fp(device, p_info, p_memory_requirements)
+            },
+        )
+    }
+}
+extern "system" fn get_android_hardware_buffer_properties_android(
+    device: Device,
+    buffer: *const AHardwareBuffer,
+    p_properties: *mut AndroidHardwareBufferPropertiesANDROID,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetAndroidHardwareBufferPropertiesANDROID",
+            &[
+                ("device", &device),
+                ("buffer", &buffer),
+                ("p_properties", &Out(p_properties)),
+            ],
+            |fp: PFN_vkGetAndroidHardwareBufferPropertiesANDROID| fp(device, buffer, p_properties),
+        )
+    }
+}
+extern "system" fn get_buffer_memory_requirements(
+    device: Device,
+    buffer: Buffer,
+    p_memory_requirements: *mut MemoryRequirements,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetBufferMemoryRequirements",
+            &[
+                ("device", &device),
+                ("buffer", &buffer),
+                ("p_memory_requirements", &Out(p_memory_requirements)),
+            ],
+            |fp: PFN_vkGetBufferMemoryRequirements| fp(device, buffer, p_memory_requirements),
+        )
+    }
+}
+extern "system" fn get_buffer_memory_requirements2(
+    device: Device,
+    p_info: *const BufferMemoryRequirementsInfo2,
+    p_memory_requirements: *mut MemoryRequirements2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetBufferMemoryRequirements2",
+            &[
+                ("device", &device),
+                ("p_info", &Ptr(p_info)),
+                ("p_memory_requirements", &Out(p_memory_requirements)),
+            ],
+            |fp: PFN_vkGetBufferMemoryRequirements2| fp(device, p_info, p_memory_requirements),
+        )
+    }
+}
+extern "system" fn get_calibrated_timestamps_ext(
+    device: Device,
+    timestamp_count: u32,
+    p_timestamp_infos: *const CalibratedTimestampInfoEXT,
+    p_timestamps: *mut u64,
+    p_max_deviation: *mut u64,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetCalibratedTimestampsEXT",
+            &[
+                ("device", &device),
+                ("timestamp_count", &timestamp_count),
+                (
+                    "p_timestamp_infos",
+                    &Array(p_timestamp_infos, timestamp_count as usize),
+                ),
+                ("p_timestamps", &OutArray(p_timestamps, &timestamp_count)),
+                ("p_max_deviation", &Out(p_max_deviation)),
+            ],
+            |fp: PFN_vkGetCalibratedTimestampsEXT| {
+                fp(
+                    device,
+                    timestamp_count,
+                    p_timestamp_infos,
+                    p_timestamps,
+                    p_max_deviation,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_descriptor_set_layout_support(
+    device: Device,
+    p_create_info: *const DescriptorSetLayoutCreateInfo,
+    p_support: *mut DescriptorSetLayoutSupport,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetDescriptorSetLayoutSupport",
+            &[
+                ("device", &device),
+                ("p_create_info", &Ptr(p_create_info)),
+                ("p_support", &Out(p_support)),
+            ],
+            |fp: PFN_vkGetDescriptorSetLayoutSupport| fp(device, p_create_info, p_support),
+        )
+    }
+}
+extern "system" fn get_device_group_peer_memory_features(
+    device: Device,
+    heap_index: u32,
+    local_device_index: u32,
+    remote_device_index: u32,
+    p_peer_memory_features: *mut PeerMemoryFeatureFlags,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetDeviceGroupPeerMemoryFeatures",
+            &[
+                ("device", &device),
+                ("heap_index", &heap_index),
+                ("local_device_index", &local_device_index),
+                ("remote_device_index", &remote_device_index),
+                ("p_peer_memory_features", &Out(p_peer_memory_features)),
+            ],
+            |fp: PFN_vkGetDeviceGroupPeerMemoryFeatures| {
+                fp(
+                    device,
+                    heap_index,
+                    local_device_index,
+                    remote_device_index,
+                    p_peer_memory_features,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_device_group_present_capabilities_khr(
+    device: Device,
+    p_device_group_present_capabilities: *mut DeviceGroupPresentCapabilitiesKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetDeviceGroupPresentCapabilitiesKHR",
+            &[
+                ("device", &device),
+                (
+                    "p_device_group_present_capabilities",
+                    &Out(p_device_group_present_capabilities),
+                ),
+            ],
+            |fp: PFN_vkGetDeviceGroupPresentCapabilitiesKHR| {
+                fp(device, p_device_group_present_capabilities)
+            },
+        )
+    }
+}
+extern "system" fn get_device_group_surface_present_modes_khr(
+    device: Device,
+    surface: SurfaceKHR,
+    p_modes: *mut DeviceGroupPresentModeFlagsKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetDeviceGroupSurfacePresentModesKHR",
+            &[
+                ("device", &device),
+                ("surface", &surface),
+                ("p_modes", &Out(p_modes)),
+            ],
+            |fp: PFN_vkGetDeviceGroupSurfacePresentModesKHR| fp(device, surface, p_modes),
+        )
+    }
+}
+extern "system" fn get_device_memory_commitment(
+    device: Device,
+    memory: DeviceMemory,
+    p_committed_memory_in_bytes: *mut DeviceSize,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetDeviceMemoryCommitment",
+            &[
+                ("device", &device),
+                ("memory", &memory),
+                (
+                    "p_committed_memory_in_bytes",
+                    &Out(p_committed_memory_in_bytes),
+                ),
+            ],
+            |fp: PFN_vkGetDeviceMemoryCommitment| fp(device, memory, p_committed_memory_in_bytes),
+        )
+    }
+}
+extern "system" fn get_device_queue(
+    device: Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut Queue,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetDeviceQueue",
+            &[
+                ("device", &device),
+                ("queue_family_index", &queue_family_index),
+                ("queue_index", &queue_index),
+                ("p_queue", &Out(p_queue)),
+            ],
+            |fp: PFN_vkGetDeviceQueue| fp(device, queue_family_index, queue_index, p_queue),
+        )
+    }
+}
+extern "system" fn get_device_queue2(
+    device: Device,
+    p_queue_info: *const DeviceQueueInfo2,
+    p_queue: *mut Queue,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetDeviceQueue2",
+            &[
+                ("device", &device),
+                ("p_queue_info", &Ptr(p_queue_info)),
+                ("p_queue", &Out(p_queue)),
+            ],
+            |fp: PFN_vkGetDeviceQueue2| fp(device, p_queue_info, p_queue),
+        )
+    }
+}
+extern "system" fn get_display_mode_properties2_khr(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayModeProperties2KHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetDisplayModeProperties2KHR",
+            &[
+                ("physical_device", &physical_device),
+                ("display", &display),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkGetDisplayModeProperties2KHR| {
+                fp(physical_device, display, p_property_count, p_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_display_mode_properties_khr(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayModePropertiesKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetDisplayModePropertiesKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("display", &display),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkGetDisplayModePropertiesKHR| {
+                fp(physical_device, display, p_property_count, p_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_display_plane_capabilities2_khr(
+    physical_device: PhysicalDevice,
+    p_display_plane_info: *const DisplayPlaneInfo2KHR,
+    p_capabilities: *mut DisplayPlaneCapabilities2KHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetDisplayPlaneCapabilities2KHR",
+            &[
+                ("physical_device", &physical_device),
+                ("p_display_plane_info", &Ptr(p_display_plane_info)),
+                ("p_capabilities", &Out(p_capabilities)),
+            ],
+            |fp: PFN_vkGetDisplayPlaneCapabilities2KHR| {
+                fp(physical_device, p_display_plane_info, p_capabilities)
+            },
+        )
+    }
+}
+extern "system" fn get_display_plane_capabilities_khr(
+    physical_device: PhysicalDevice,
+    mode: DisplayModeKHR,
+    plane_index: u32,
+    p_capabilities: *mut DisplayPlaneCapabilitiesKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetDisplayPlaneCapabilitiesKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("mode", &mode),
+                ("plane_index", &plane_index),
+                ("p_capabilities", &Out(p_capabilities)),
+            ],
+            |fp: PFN_vkGetDisplayPlaneCapabilitiesKHR| {
+                fp(physical_device, mode, plane_index, p_capabilities)
+            },
+        )
+    }
+}
+extern "system" fn get_display_plane_supported_displays_khr(
+    physical_device: PhysicalDevice,
+    plane_index: u32,
+    p_display_count: *mut u32,
+    p_displays: *mut DisplayKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetDisplayPlaneSupportedDisplaysKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("plane_index", &plane_index),
+                ("p_display_count", &Out(p_display_count)),
+                ("p_displays", &OutArray(p_displays, p_display_count)),
+            ],
+            |fp: PFN_vkGetDisplayPlaneSupportedDisplaysKHR| {
+                fp(physical_device, plane_index, p_display_count, p_displays)
+            },
+        )
+    }
+}
+extern "system" fn get_event_status(device: Device, event: Event) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetEventStatus",
+            &[("device", &device), ("event", &event)],
+            |fp: PFN_vkGetEventStatus| fp(device, event),
+        )
+    }
+}
+extern "system" fn get_fence_fd_khr(
+    device: Device,
+    p_get_fd_info: *const FenceGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetFenceFdKHR",
+            &[
+                ("device", &device),
+                ("p_get_fd_info", &Ptr(p_get_fd_info)),
+                ("p_fd", &Out(p_fd)),
+            ],
+            |fp: PFN_vkGetFenceFdKHR| fp(device, p_get_fd_info, p_fd),
+        )
+    }
+}
+extern "system" fn get_fence_status(device: Device, fence: Fence) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetFenceStatus",
+            &[("device", &device), ("fence", &fence)],
+            |fp: PFN_vkGetFenceStatus| fp(device, fence),
+        )
+    }
+}
+extern "system" fn get_fence_win32_handle_khr(
+    device: Device,
+    p_get_win32_handle_info: *const FenceGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetFenceWin32HandleKHR",
+            &[
+                ("device", &device),
+                ("p_get_win32_handle_info", &Ptr(p_get_win32_handle_info)),
+                ("p_handle", &Out(p_handle)),
+            ],
+            |fp: PFN_vkGetFenceWin32HandleKHR| fp(device, p_get_win32_handle_info, p_handle),
+        )
+    }
+}
+extern "system" fn get_image_drm_format_modifier_properties_ext(
+    device: Device,
+    image: Image,
+    p_properties: *mut ImageDrmFormatModifierPropertiesEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetImageDrmFormatModifierPropertiesEXT",
+            &[
+                ("device", &device),
+                ("image", &image),
+                ("p_properties", &Out(p_properties)),
+            ],
+            |fp: PFN_vkGetImageDrmFormatModifierPropertiesEXT| fp(device, image, p_properties),
+        )
+    }
+}
+extern "system" fn get_image_memory_requirements(
+    device: Device,
+    image: Image,
+    p_memory_requirements: *mut MemoryRequirements,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetImageMemoryRequirements",
+            &[
+                ("device", &device),
+                ("image", &image),
+                ("p_memory_requirements", &Out(p_memory_requirements)),
+            ],
+            |fp: PFN_vkGetImageMemoryRequirements| fp(device, image, p_memory_requirements),
+        )
+    }
+}
+extern "system" fn get_image_memory_requirements2(
+    device: Device,
+    p_info: *const ImageMemoryRequirementsInfo2,
+    p_memory_requirements: *mut MemoryRequirements2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetImageMemoryRequirements2",
+            &[
+                ("device", &device),
+                ("p_info", &Ptr(p_info)),
+                ("p_memory_requirements", &Out(p_memory_requirements)),
+            ],
+            |fp: PFN_vkGetImageMemoryRequirements2| fp(device, p_info, p_memory_requirements),
+        )
+    }
+}
+extern "system" fn get_image_sparse_memory_requirements(
+    device: Device,
+    image: Image,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut SparseImageMemoryRequirements,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetImageSparseMemoryRequirements",
+            &[
+                ("device", &device),
+                ("image", &image),
+                (
+                    "p_sparse_memory_requirement_count",
+                    &Out(p_sparse_memory_requirement_count),
+                ),
+                (
+                    "p_sparse_memory_requirements",
+                    &OutArray(
+                        p_sparse_memory_requirements,
+                        p_sparse_memory_requirement_count,
+                    ),
+                ),
+            ],
+            |fp: PFN_vkGetImageSparseMemoryRequirements| {
+                fp(
+                    device,
+                    image,
+                    p_sparse_memory_requirement_count,
+                    p_sparse_memory_requirements,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_image_sparse_memory_requirements2(
+    device: Device,
+    p_info: *const ImageSparseMemoryRequirementsInfo2,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetImageSparseMemoryRequirements2",
+            &[
+                ("device", &device),
+                ("p_info", &Ptr(p_info)),
+                (
+                    "p_sparse_memory_requirement_count",
+                    &Out(p_sparse_memory_requirement_count),
+                ),
+                (
+                    "p_sparse_memory_requirements",
+                    &OutArray(
+                        p_sparse_memory_requirements,
+                        p_sparse_memory_requirement_count,
+                    ),
+                ),
+            ],
+            |fp: PFN_vkGetImageSparseMemoryRequirements2| {
+                fp(
+                    device,
+                    p_info,
+                    p_sparse_memory_requirement_count,
+                    p_sparse_memory_requirements,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_image_subresource_layout(
+    device: Device,
+    image: Image,
+    p_subresource: *const ImageSubresource,
+    p_layout: *mut SubresourceLayout,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetImageSubresourceLayout",
+            &[
+                ("device", &device),
+                ("image", &image),
+                ("p_subresource", &Ptr(p_subresource)),
+                ("p_layout", &Out(p_layout)),
+            ],
+            |fp: PFN_vkGetImageSubresourceLayout| fp(device, image, p_subresource, p_layout),
+        )
+    }
+}
+extern "system" fn get_memory_android_hardware_buffer_android(
+    device: Device,
+    p_info: *const MemoryGetAndroidHardwareBufferInfoANDROID,
+    p_buffer: *mut *mut AHardwareBuffer,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetMemoryAndroidHardwareBufferANDROID",
+            &[
+                ("device", &device),
+                ("p_info", &Ptr(p_info)),
+                ("p_buffer", &Out(p_buffer)),
+            ],
+            |fp: PFN_vkGetMemoryAndroidHardwareBufferANDROID| fp(device, p_info, p_buffer),
+        )
+    }
+}
+extern "system" fn get_memory_fd_khr(
+    device: Device,
+    p_get_fd_info: *const MemoryGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetMemoryFdKHR",
+            &[
+                ("device", &device),
+                ("p_get_fd_info", &Ptr(p_get_fd_info)),
+                ("p_fd", &Out(p_fd)),
+            ],
+            |fp: PFN_vkGetMemoryFdKHR| fp(device, p_get_fd_info, p_fd),
+        )
+    }
+}
+extern "system" fn get_memory_fd_properties_khr(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    fd: c_int,
+    p_memory_fd_properties: *mut MemoryFdPropertiesKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetMemoryFdPropertiesKHR",
+            &[
+                ("device", &device),
+                ("handle_type", &handle_type),
+                ("fd", &fd),
+                ("p_memory_fd_properties", &Out(p_memory_fd_properties)),
+            ],
+            |fp: PFN_vkGetMemoryFdPropertiesKHR| {
+                fp(device, handle_type, fd, p_memory_fd_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_memory_host_pointer_properties_ext(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    p_host_pointer: *const c_void,
+    p_memory_host_pointer_properties: *mut MemoryHostPointerPropertiesEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetMemoryHostPointerPropertiesEXT",
+            &[
+                ("device", &device),
+                ("handle_type", &handle_type),
+                ("p_host_pointer", &p_host_pointer),
+                (
+                    "p_memory_host_pointer_properties",
+                    &Out(p_memory_host_pointer_properties),
+                ),
+            ],
+            |fp: PFN_vkGetMemoryHostPointerPropertiesEXT| {
+                fp(
+                    device,
+                    handle_type,
+                    p_host_pointer,
+                    p_memory_host_pointer_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_memory_win32_handle_khr(
+    device: Device,
+    p_get_win32_handle_info: *const MemoryGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetMemoryWin32HandleKHR",
+            &[
+                ("device", &device),
+                ("p_get_win32_handle_info", &Ptr(p_get_win32_handle_info)),
+                ("p_handle", &Out(p_handle)),
+            ],
+            |fp: PFN_vkGetMemoryWin32HandleKHR| fp(device, p_get_win32_handle_info, p_handle),
+        )
+    }
+}
+extern "system" fn get_memory_win32_handle_nv(
+    device: Device,
+    memory: DeviceMemory,
+    handle_type: ExternalMemoryHandleTypeFlagsNV,
+    p_handle: *mut HANDLE,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetMemoryWin32HandleNV",
+            &[
+                ("device", &device),
+                ("memory", &memory),
+                ("handle_type", &handle_type),
+                ("p_handle", &Out(p_handle)),
+            ],
+            |fp: PFN_vkGetMemoryWin32HandleNV| fp(device, memory, handle_type, p_handle),
+        )
+    }
+}
+extern "system" fn get_memory_win32_handle_properties_khr(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    handle: HANDLE,
+    p_memory_win32_handle_properties: *mut MemoryWin32HandlePropertiesKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetMemoryWin32HandlePropertiesKHR",
+            &[
+                ("device", &device),
+                ("handle_type", &handle_type),
+                ("handle", &handle),
+                (
+                    "p_memory_win32_handle_properties",
+                    &Out(p_memory_win32_handle_properties),
+                ),
+            ],
+            |fp: PFN_vkGetMemoryWin32HandlePropertiesKHR| {
+                fp(
+                    device,
+                    handle_type,
+                    handle,
+                    p_memory_win32_handle_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_past_presentation_timing_google(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_presentation_timing_count: *mut u32,
+    p_presentation_timings: *mut PastPresentationTimingGOOGLE,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetPastPresentationTimingGOOGLE",
+            &[
+                ("device", &device),
+                ("swapchain", &swapchain),
+                (
+                    "p_presentation_timing_count",
+                    &Out(p_presentation_timing_count),
+                ),
+                (
+                    "p_presentation_timings",
+                    &OutArray(p_presentation_timings, p_presentation_timing_count),
+                ),
+            ],
+            |fp: PFN_vkGetPastPresentationTimingGOOGLE| {
+                fp(
+                    device,
+                    swapchain,
+                    p_presentation_timing_count,
+                    p_presentation_timings,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_calibrateable_time_domains_ext(
+    physical_device: PhysicalDevice,
+    p_time_domain_count: *mut u32,
+    p_time_domains: *mut TimeDomainEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+            &[
+                ("physical_device", &physical_device),
+                ("p_time_domain_count", &Out(p_time_domain_count)),
+                (
+                    "p_time_domains",
+                    &OutArray(p_time_domains, p_time_domain_count),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT| {
+                fp(physical_device, p_time_domain_count, p_time_domains)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_display_plane_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPlaneProperties2KHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+            &[
+                ("physical_device", &physical_device),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceDisplayPlaneProperties2KHR| {
+                fp(physical_device, p_property_count, p_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_display_plane_properties_khr(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPlanePropertiesKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR| {
+                fp(physical_device, p_property_count, p_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_display_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayProperties2KHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceDisplayProperties2KHR",
+            &[
+                ("physical_device", &physical_device),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceDisplayProperties2KHR| {
+                fp(physical_device, p_property_count, p_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_display_properties_khr(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPropertiesKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceDisplayPropertiesKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceDisplayPropertiesKHR| {
+                fp(physical_device, p_property_count, p_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_external_buffer_properties(
+    physical_device: PhysicalDevice,
+    p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+    p_external_buffer_properties: *mut ExternalBufferProperties,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceExternalBufferProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("p_external_buffer_info", &Ptr(p_external_buffer_info)),
+                (
+                    "p_external_buffer_properties",
+                    &Out(p_external_buffer_properties),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceExternalBufferProperties| {
+                fp(
+                    physical_device,
+                    p_external_buffer_info,
+                    p_external_buffer_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_external_fence_properties(
+    physical_device: PhysicalDevice,
+    p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+    p_external_fence_properties: *mut ExternalFenceProperties,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceExternalFenceProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("p_external_fence_info", &Ptr(p_external_fence_info)),
+                (
+                    "p_external_fence_properties",
+                    &Out(p_external_fence_properties),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceExternalFenceProperties| {
+                fp(
+                    physical_device,
+                    p_external_fence_info,
+                    p_external_fence_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_external_image_format_properties_nv(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    tiling: ImageTiling,
+    usage: ImageUsageFlags,
+    flags: ImageCreateFlags,
+    external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+    p_external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+            &[
+                ("physical_device", &physical_device),
+                ("format", &format),
+                ("ty", &ty),
+                ("tiling", &tiling),
+                ("usage", &usage),
+                ("flags", &flags),
+                ("external_handle_type", &external_handle_type),
+                (
+                    "p_external_image_format_properties",
+                    &Out(p_external_image_format_properties),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV| {
+                fp(
+                    physical_device,
+                    format,
+                    ty,
+                    tiling,
+                    usage,
+                    flags,
+                    external_handle_type,
+                    p_external_image_format_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_external_semaphore_properties(
+    physical_device: PhysicalDevice,
+    p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+    p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceExternalSemaphoreProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("p_external_semaphore_info", &Ptr(p_external_semaphore_info)),
+                (
+                    "p_external_semaphore_properties",
+                    &Out(p_external_semaphore_properties),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceExternalSemaphoreProperties| {
+                fp(
+                    physical_device,
+                    p_external_semaphore_info,
+                    p_external_semaphore_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_features(
+    physical_device: PhysicalDevice,
+    p_features: *mut PhysicalDeviceFeatures,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceFeatures",
+            &[
+                ("physical_device", &physical_device),
+                ("p_features", &Out(p_features)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceFeatures| fp(physical_device, p_features),
+        )
+    }
+}
+extern "system" fn get_physical_device_features2(
+    physical_device: PhysicalDevice,
+    p_features: *mut PhysicalDeviceFeatures2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceFeatures2",
+            &[
+                ("physical_device", &physical_device),
+                ("p_features", &Out(p_features)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceFeatures2| fp(physical_device, p_features),
+        )
+    }
+}
+extern "system" fn get_physical_device_format_properties(
+    physical_device: PhysicalDevice,
+    format: Format,
+    p_format_properties: *mut FormatProperties,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceFormatProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("format", &format),
+                ("p_format_properties", &Out(p_format_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceFormatProperties| {
+                fp(physical_device, format, p_format_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_format_properties2(
+    physical_device: PhysicalDevice,
+    format: Format,
+    p_format_properties: *mut FormatProperties2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceFormatProperties2",
+            &[
+                ("physical_device", &physical_device),
+                ("format", &format),
+                ("p_format_properties", &Out(p_format_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceFormatProperties2| {
+                fp(physical_device, format, p_format_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_generated_commands_properties_nvx(
+    physical_device: PhysicalDevice,
+    p_features: *mut DeviceGeneratedCommandsFeaturesNVX,
+    p_limits: *mut DeviceGeneratedCommandsLimitsNVX,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX",
+            &[
+                ("physical_device", &physical_device),
+                ("p_features", &Out(p_features)),
+                ("p_limits", &Out(p_limits)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX| {
+                fp(physical_device, p_features, p_limits)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_image_format_properties(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    tiling: ImageTiling,
+    usage: ImageUsageFlags,
+    flags: ImageCreateFlags,
+    p_image_format_properties: *mut ImageFormatProperties,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceImageFormatProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("format", &format),
+                ("ty", &ty),
+                ("tiling", &tiling),
+                ("usage", &usage),
+                ("flags", &flags),
+                ("p_image_format_properties", &Out(p_image_format_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceImageFormatProperties| {
+                fp(
+                    physical_device,
+                    format,
+                    ty,
+                    tiling,
+                    usage,
+                    flags,
+                    p_image_format_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_image_format_properties2(
+    physical_device: PhysicalDevice,
+    p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+    p_image_format_properties: *mut ImageFormatProperties2,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceImageFormatProperties2",
+            &[
+                ("physical_device", &physical_device),
+                ("p_image_format_info", &Ptr(p_image_format_info)),
+                ("p_image_format_properties", &Out(p_image_format_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceImageFormatProperties2| {
+                fp(
+                    physical_device,
+                    p_image_format_info,
+                    p_image_format_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_memory_properties(
+    physical_device: PhysicalDevice,
+    p_memory_properties: *mut PhysicalDeviceMemoryProperties,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceMemoryProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("p_memory_properties", &Out(p_memory_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceMemoryProperties| fp(physical_device, p_memory_properties),
+        )
+    }
+}
+extern "system" fn get_physical_device_memory_properties2(
+    physical_device: PhysicalDevice,
+    p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceMemoryProperties2",
+            &[
+                ("physical_device", &physical_device),
+                ("p_memory_properties", &Out(p_memory_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceMemoryProperties2| fp(physical_device, p_memory_properties),
+        )
+    }
+}
+extern "system" fn get_physical_device_multisample_properties_ext(
+    physical_device: PhysicalDevice,
+    samples: SampleCountFlags,
+    p_multisample_properties: *mut MultisamplePropertiesEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+            &[
+                ("physical_device", &physical_device),
+                ("samples", &samples),
+                ("p_multisample_properties", &Out(p_multisample_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceMultisamplePropertiesEXT| {
+                fp(physical_device, samples, p_multisample_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_present_rectangles_khr(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_rect_count: *mut u32,
+    p_rects: *mut Rect2D,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDevicePresentRectanglesKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("surface", &surface),
+                ("p_rect_count", &Out(p_rect_count)),
+                ("p_rects", &OutArray(p_rects, p_rect_count)),
+            ],
+            |fp: PFN_vkGetPhysicalDevicePresentRectanglesKHR| {
+                fp(physical_device, surface, p_rect_count, p_rects)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_properties(
+    physical_device: PhysicalDevice,
+    p_properties: *mut PhysicalDeviceProperties,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("p_properties", &Out(p_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceProperties| fp(physical_device, p_properties),
+        )
+    }
+}
+extern "system" fn get_physical_device_properties2(
+    physical_device: PhysicalDevice,
+    p_properties: *mut PhysicalDeviceProperties2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceProperties2",
+            &[
+                ("physical_device", &physical_device),
+                ("p_properties", &Out(p_properties)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceProperties2| fp(physical_device, p_properties),
+        )
+    }
+}
+extern "system" fn get_physical_device_queue_family_properties(
+    physical_device: PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut QueueFamilyProperties,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceQueueFamilyProperties",
+            &[
+                ("physical_device", &physical_device),
+                (
+                    "p_queue_family_property_count",
+                    &Out(p_queue_family_property_count),
+                ),
+                (
+                    "p_queue_family_properties",
+                    &OutArray(p_queue_family_properties, p_queue_family_property_count),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceQueueFamilyProperties| {
+                fp(
+                    physical_device,
+                    p_queue_family_property_count,
+                    p_queue_family_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_queue_family_properties2(
+    physical_device: PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut QueueFamilyProperties2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceQueueFamilyProperties2",
+            &[
+                ("physical_device", &physical_device),
+                (
+                    "p_queue_family_property_count",
+                    &Out(p_queue_family_property_count),
+                ),
+                (
+                    "p_queue_family_properties",
+                    &OutArray(p_queue_family_properties, p_queue_family_property_count),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceQueueFamilyProperties2| {
+                fp(
+                    physical_device,
+                    p_queue_family_property_count,
+                    p_queue_family_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_sparse_image_format_properties(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    samples: SampleCountFlags,
+    usage: ImageUsageFlags,
+    tiling: ImageTiling,
+    p_property_count: *mut u32,
+    p_properties: *mut SparseImageFormatProperties,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSparseImageFormatProperties",
+            &[
+                ("physical_device", &physical_device),
+                ("format", &format),
+                ("ty", &ty),
+                ("samples", &samples),
+                ("usage", &usage),
+                ("tiling", &tiling),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSparseImageFormatProperties| {
+                fp(
+                    physical_device,
+                    format,
+                    ty,
+                    samples,
+                    usage,
+                    tiling,
+                    p_property_count,
+                    p_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_sparse_image_format_properties2(
+    physical_device: PhysicalDevice,
+    p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+    p_property_count: *mut u32,
+    p_properties: *mut SparseImageFormatProperties2,
+) -> c_void {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSparseImageFormatProperties2",
+            &[
+                ("physical_device", &physical_device),
+                ("p_format_info", &Ptr(p_format_info)),
+                ("p_property_count", &Out(p_property_count)),
+                ("p_properties", &OutArray(p_properties, p_property_count)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSparseImageFormatProperties2| {
+                fp(
+                    physical_device,
+                    p_format_info,
+                    p_property_count,
+                    p_properties,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_surface_capabilities2_ext(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_capabilities: *mut SurfaceCapabilities2EXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+            &[
+                ("physical_device", &physical_device),
+                ("surface", &surface),
+                ("p_surface_capabilities", &Out(p_surface_capabilities)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT| {
+                fp(physical_device, surface, p_surface_capabilities)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_surface_capabilities2_khr(
+    physical_device: PhysicalDevice,
+    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    p_surface_capabilities: *mut SurfaceCapabilities2KHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+            &[
+                ("physical_device", &physical_device),
+                ("p_surface_info", &Ptr(p_surface_info)),
+                ("p_surface_capabilities", &Out(p_surface_capabilities)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR| {
+                fp(physical_device, p_surface_info, p_surface_capabilities)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_surface_capabilities_khr(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_capabilities: *mut SurfaceCapabilitiesKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("surface", &surface),
+                ("p_surface_capabilities", &Out(p_surface_capabilities)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR| {
+                fp(physical_device, surface, p_surface_capabilities)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_surface_formats2_khr(
+    physical_device: PhysicalDevice,
+    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut SurfaceFormat2KHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSurfaceFormats2KHR",
+            &[
+                ("physical_device", &physical_device),
+                ("p_surface_info", &Ptr(p_surface_info)),
+                ("p_surface_format_count", &Out(p_surface_format_count)),
+                (
+                    "p_surface_formats",
+                    &OutArray(p_surface_formats, p_surface_format_count),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSurfaceFormats2KHR| {
+                fp(
+                    physical_device,
+                    p_surface_info,
+                    p_surface_format_count,
+                    p_surface_formats,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_surface_formats_khr(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut SurfaceFormatKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSurfaceFormatsKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("surface", &surface),
+                ("p_surface_format_count", &Out(p_surface_format_count)),
+                (
+                    "p_surface_formats",
+                    &OutArray(p_surface_formats, p_surface_format_count),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSurfaceFormatsKHR| {
+                fp(
+                    physical_device,
+                    surface,
+                    p_surface_format_count,
+                    p_surface_formats,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_surface_present_modes_khr(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut PresentModeKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSurfacePresentModesKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("surface", &surface),
+                ("p_present_mode_count", &Out(p_present_mode_count)),
+                (
+                    "p_present_modes",
+                    &OutArray(p_present_modes, p_present_mode_count),
+                ),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSurfacePresentModesKHR| {
+                fp(
+                    physical_device,
+                    surface,
+                    p_present_mode_count,
+                    p_present_modes,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_surface_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    surface: SurfaceKHR,
+    p_supported: *mut Bool32,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceSurfaceSupportKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("queue_family_index", &queue_family_index),
+                ("surface", &surface),
+                ("p_supported", &Out(p_supported)),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceSurfaceSupportKHR| {
+                fp(physical_device, queue_family_index, surface, p_supported)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_wayland_presentation_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    display: *mut wl_display,
+) -> Bool32 {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("queue_family_index", &queue_family_index),
+                ("display", &display),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR| {
+                fp(physical_device, queue_family_index, display)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_win32_presentation_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+) -> Bool32 {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("queue_family_index", &queue_family_index),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR| {
+                fp(physical_device, queue_family_index)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_xcb_presentation_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    connection: *mut xcb_connection_t,
+    visual_id: xcb_visualid_t,
+) -> Bool32 {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("queue_family_index", &queue_family_index),
+                ("connection", &connection),
+                ("visual_id", &visual_id),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR| {
+                fp(physical_device, queue_family_index, connection, visual_id)
+            },
+        )
+    }
+}
+extern "system" fn get_physical_device_xlib_presentation_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    dpy: *mut Display,
+    visual_id: VisualID,
+) -> Bool32 {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+            &[
+                ("physical_device", &physical_device),
+                ("queue_family_index", &queue_family_index),
+                ("dpy", &dpy),
+                ("visual_id", &visual_id),
+            ],
+            |fp: PFN_vkGetPhysicalDeviceXlibPresentationSupportKHR| {
+                fp(physical_device, queue_family_index, dpy, visual_id)
+            },
+        )
+    }
+}
+extern "system" fn get_pipeline_cache_data(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetPipelineCacheData",
+            &[
+                ("device", &device),
+                ("pipeline_cache", &pipeline_cache),
+                ("p_data_size", &Out(p_data_size)),
+                ("p_data", &OutBytes(p_data, p_data_size)),
+            ],
+            |fp: PFN_vkGetPipelineCacheData| fp(device, pipeline_cache, p_data_size, p_data),
+        )
+    }
+}
+extern "system" fn get_query_pool_results(
+    device: Device,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: DeviceSize,
+    flags: QueryResultFlags,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetQueryPoolResults",
+            &[
+                ("device", &device),
+                ("query_pool", &query_pool),
+                ("first_query", &first_query),
+                ("query_count", &query_count),
+                ("data_size", &data_size),
+                ("p_data", &OutBytes(p_data, &data_size)),
+                ("stride", &stride),
+                ("flags", &flags),
+            ],
+            |fp: PFN_vkGetQueryPoolResults| {
+                fp(
+                    device,
+                    query_pool,
+                    first_query,
+                    query_count,
+                    data_size,
+                    p_data,
+                    stride,
+                    flags,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_queue_checkpoint_data_nv(
+    queue: Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut CheckpointDataNV,
+) -> c_void {
+    unsafe {
+        trace_call(
+            queue,
+            "vkGetQueueCheckpointDataNV",
+            &[
+                ("queue", &queue),
+                ("p_checkpoint_data_count", &Out(p_checkpoint_data_count)),
+                (
+                    "p_checkpoint_data",
+                    &OutArray(p_checkpoint_data, p_checkpoint_data_count),
+                ),
+            ],
+            |fp: PFN_vkGetQueueCheckpointDataNV| {
+                fp(queue, p_checkpoint_data_count, p_checkpoint_data)
+            },
+        )
+    }
+}
+extern "system" fn get_rand_r_output_display_ext(
+    physical_device: PhysicalDevice,
+    dpy: *mut Display,
+    rr_output: RROutput,
+    p_display: *mut DisplayKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkGetRandROutputDisplayEXT",
+            &[
+                ("physical_device", &physical_device),
+                ("dpy", &dpy),
+                ("rr_output", &rr_output),
+                ("p_display", &Out(p_display)),
+            ],
+            |fp: PFN_vkGetRandROutputDisplayEXT| fp(physical_device, dpy, rr_output, p_display),
+        )
+    }
+}
+extern "system" fn get_ray_tracing_shader_group_handles_nv(
+    device: Device,
+    pipeline: Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetRayTracingShaderGroupHandlesNV",
+            &[
+                ("device", &device),
+                ("pipeline", &pipeline),
+                ("first_group", &first_group),
+                ("group_count", &group_count),
+                ("data_size", &data_size),
+                ("p_data", &OutBytes(p_data, &data_size)),
+            ],
+            |fp: PFN_vkGetRayTracingShaderGroupHandlesNV| {
+                fp(
+                    device,
+                    pipeline,
+                    first_group,
+                    group_count,
+                    data_size,
+                    p_data,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_refresh_cycle_duration_google(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_display_timing_properties: *mut RefreshCycleDurationGOOGLE,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetRefreshCycleDurationGOOGLE",
+            &[
+                ("device", &device),
+                ("swapchain", &swapchain),
+                (
+                    "p_display_timing_properties",
+                    &Out(p_display_timing_properties),
+                ),
+            ],
+            |fp: PFN_vkGetRefreshCycleDurationGOOGLE| {
+                fp(device, swapchain, p_display_timing_properties)
+            },
+        )
+    }
+}
+extern "system" fn get_render_area_granularity(
+    device: Device,
+    render_pass: RenderPass,
+    p_granularity: *mut Extent2D,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetRenderAreaGranularity",
+            &[
+                ("device", &device),
+                ("render_pass", &render_pass),
+                ("p_granularity", &Out(p_granularity)),
+            ],
+            |fp: PFN_vkGetRenderAreaGranularity| fp(device, render_pass, p_granularity),
+        )
+    }
+}
+extern "system" fn get_semaphore_fd_khr(
+    device: Device,
+    p_get_fd_info: *const SemaphoreGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetSemaphoreFdKHR",
+            &[
+                ("device", &device),
+                ("p_get_fd_info", &Ptr(p_get_fd_info)),
+                ("p_fd", &Out(p_fd)),
+            ],
+            |fp: PFN_vkGetSemaphoreFdKHR| fp(device, p_get_fd_info, p_fd),
+        )
+    }
+}
+extern "system" fn get_semaphore_win32_handle_khr(
+    device: Device,
+    p_get_win32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetSemaphoreWin32HandleKHR",
+            &[
+                ("device", &device),
+                ("p_get_win32_handle_info", &Ptr(p_get_win32_handle_info)),
+                ("p_handle", &Out(p_handle)),
+            ],
+            |fp: PFN_vkGetSemaphoreWin32HandleKHR| fp(device, p_get_win32_handle_info, p_handle),
+        )
+    }
+}
+extern "system" fn get_shader_info_amd(
+    device: Device,
+    pipeline: Pipeline,
+    shader_stage: ShaderStageFlags,
+    info_type: ShaderInfoTypeAMD,
+    p_info_size: *mut usize,
+    p_info: *mut c_void,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetShaderInfoAMD",
+            &[
+                ("device", &device),
+                ("pipeline", &pipeline),
+                ("shader_stage", &shader_stage),
+                ("info_type", &info_type),
+                ("p_info_size", &Out(p_info_size)),
+                ("p_info", &OutBytes(p_info, p_info_size)),
+            ],
+            |fp: PFN_vkGetShaderInfoAMD| {
+                fp(
+                    device,
+                    pipeline,
+                    shader_stage,
+                    info_type,
+                    p_info_size,
+                    p_info,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_swapchain_counter_ext(
+    device: Device,
+    swapchain: SwapchainKHR,
+    counter: SurfaceCounterFlagsEXT,
+    p_counter_value: *mut u64,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetSwapchainCounterEXT",
+            &[
+                ("device", &device),
+                ("swapchain", &swapchain),
+                ("counter", &counter),
+                ("p_counter_value", &Out(p_counter_value)),
+            ],
+            |fp: PFN_vkGetSwapchainCounterEXT| fp(device, swapchain, counter, p_counter_value),
+        )
+    }
+}
+extern "system" fn get_swapchain_gralloc_usage_android(
+    device: Device,
+    format: Format,
+    image_usage: ImageUsageFlags,
+    gralloc_usage: *mut c_int,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetSwapchainGrallocUsageANDROID",
+            &[
+                ("device", &device),
+                ("format", &format),
+                ("image_usage", &image_usage),
+                ("gralloc_usage", &Out(gralloc_usage)),
+            ],
+            |fp: PFN_vkGetSwapchainGrallocUsageANDROID| {
+                fp(device, format, image_usage, gralloc_usage)
+            },
+        )
+    }
+}
+extern "system" fn get_swapchain_images_khr(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_swapchain_image_count: *mut u32,
+    p_swapchain_images: *mut Image,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetSwapchainImagesKHR",
+            &[
+                ("device", &device),
+                ("swapchain", &swapchain),
+                ("p_swapchain_image_count", &Out(p_swapchain_image_count)),
+                (
+                    "p_swapchain_images",
+                    &OutArray(p_swapchain_images, p_swapchain_image_count),
+                ),
+            ],
+            |fp: PFN_vkGetSwapchainImagesKHR| {
+                fp(
+                    device,
+                    swapchain,
+                    p_swapchain_image_count,
+                    p_swapchain_images,
+                )
+            },
+        )
+    }
+}
+extern "system" fn get_swapchain_status_khr(device: Device, swapchain: SwapchainKHR) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetSwapchainStatusKHR",
+            &[("device", &device), ("swapchain", &swapchain)],
+            |fp: PFN_vkGetSwapchainStatusKHR| fp(device, swapchain),
+        )
+    }
+}
+extern "system" fn get_validation_cache_data_ext(
+    device: Device,
+    validation_cache: ValidationCacheEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkGetValidationCacheDataEXT",
+            &[
+                ("device", &device),
+                ("validation_cache", &validation_cache),
+                ("p_data_size", &Out(p_data_size)),
+                ("p_data", &OutBytes(p_data, p_data_size)),
+            ],
+            |fp: PFN_vkGetValidationCacheDataEXT| fp(device, validation_cache, p_data_size, p_data),
+        )
+    }
+}
+extern "system" fn import_fence_fd_khr(
+    device: Device,
+    p_import_fence_fd_info: *const ImportFenceFdInfoKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkImportFenceFdKHR",
+            &[
+                ("device", &device),
+                ("p_import_fence_fd_info", &Ptr(p_import_fence_fd_info)),
+            ],
+            |fp: PFN_vkImportFenceFdKHR| fp(device, p_import_fence_fd_info),
+        )
+    }
+}
+extern "system" fn import_fence_win32_handle_khr(
+    device: Device,
+    p_import_fence_win32_handle_info: *const ImportFenceWin32HandleInfoKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkImportFenceWin32HandleKHR",
+            &[
+                ("device", &device),
+                (
+                    "p_import_fence_win32_handle_info",
+                    &Ptr(p_import_fence_win32_handle_info),
+                ),
+            ],
+            |fp: PFN_vkImportFenceWin32HandleKHR| fp(device, p_import_fence_win32_handle_info),
+        )
+    }
+}
+extern "system" fn import_semaphore_fd_khr(
+    device: Device,
+    p_import_semaphore_fd_info: *const ImportSemaphoreFdInfoKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkImportSemaphoreFdKHR",
+            &[
+                ("device", &device),
+                (
+                    "p_import_semaphore_fd_info",
+                    &Ptr(p_import_semaphore_fd_info),
+                ),
+            ],
+            |fp: PFN_vkImportSemaphoreFdKHR| fp(device, p_import_semaphore_fd_info),
+        )
+    }
+}
+extern "system" fn import_semaphore_win32_handle_khr(
+    device: Device,
+    p_import_semaphore_win32_handle_info: *const ImportSemaphoreWin32HandleInfoKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkImportSemaphoreWin32HandleKHR",
+            &[
+                ("device", &device),
+                (
+                    "p_import_semaphore_win32_handle_info",
+                    &Ptr(p_import_semaphore_win32_handle_info),
+                ),
+            ],
+            |fp: PFN_vkImportSemaphoreWin32HandleKHR| {
+                fp(device, p_import_semaphore_win32_handle_info)
+            },
+        )
+    }
+}
+extern "system" fn invalidate_mapped_memory_ranges(
+    device: Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const MappedMemoryRange,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkInvalidateMappedMemoryRanges",
+            &[
+                ("device", &device),
+                ("memory_range_count", &memory_range_count),
+                (
+                    "p_memory_ranges",
+                    &Array(p_memory_ranges, memory_range_count as usize),
+                ),
+            ],
+            |fp: PFN_vkInvalidateMappedMemoryRanges| {
+                fp(device, memory_range_count, p_memory_ranges)
+            },
+        )
+    }
+}
+extern "system" fn map_memory(
+    device: Device,
+    memory: DeviceMemory,
+    offset: DeviceSize,
+    size: DeviceSize,
+    flags: MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkMapMemory",
+            &[
+                ("device", &device),
+                ("memory", &memory),
+                ("offset", &offset),
+                ("size", &size),
+                ("flags", &flags),
+                ("pp_data", &Out(pp_data)),
+            ],
+            |fp: PFN_vkMapMemory| fp(device, memory, offset, size, flags, pp_data),
+        )
+    }
+}
+extern "system" fn merge_pipeline_caches(
+    device: Device,
+    dst_cache: PipelineCache,
+    src_cache_count: u32,
+    p_src_caches: *const PipelineCache,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkMergePipelineCaches",
+            &[
+                ("device", &device),
+                ("dst_cache", &dst_cache),
+                ("src_cache_count", &src_cache_count),
+                (
+                    "p_src_caches",
+                    &Array(p_src_caches, src_cache_count as usize),
+                ),
+            ],
+            |fp: PFN_vkMergePipelineCaches| fp(device, dst_cache, src_cache_count, p_src_caches),
+        )
+    }
+}
+extern "system" fn merge_validation_caches_ext(
+    device: Device,
+    dst_cache: ValidationCacheEXT,
+    src_cache_count: u32,
+    p_src_caches: *const ValidationCacheEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkMergeValidationCachesEXT",
+            &[
+                ("device", &device),
+                ("dst_cache", &dst_cache),
+                ("src_cache_count", &src_cache_count),
+                (
+                    "p_src_caches",
+                    &Array(p_src_caches, src_cache_count as usize),
+                ),
+            ],
+            |fp: PFN_vkMergeValidationCachesEXT| {
+                fp(device, dst_cache, src_cache_count, p_src_caches)
+            },
+        )
+    }
+}
+extern "system" fn queue_begin_debug_utils_label_ext(
+    queue: Queue,
+    p_label_info: *const DebugUtilsLabelEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            queue,
+            "vkQueueBeginDebugUtilsLabelEXT",
+            &[("queue", &queue), ("p_label_info", &Ptr(p_label_info))],
+            |fp: PFN_vkQueueBeginDebugUtilsLabelEXT| fp(queue, p_label_info),
+        )
+    }
+}
+extern "system" fn queue_bind_sparse(
+    queue: Queue,
+    bind_info_count: u32,
+    p_bind_info: *const BindSparseInfo,
+    fence: Fence,
+) -> Result {
+    unsafe {
+        trace_call(
+            queue,
+            "vkQueueBindSparse",
+            &[
+                ("queue", &queue),
+                ("bind_info_count", &bind_info_count),
+                ("p_bind_info", &Array(p_bind_info, bind_info_count as usize)),
+                ("fence", &fence),
+            ],
+            |fp: PFN_vkQueueBindSparse| fp(queue, bind_info_count, p_bind_info, fence),
+        )
+    }
+}
+extern "system" fn queue_end_debug_utils_label_ext(queue: Queue) -> c_void {
+    unsafe {
+        trace_call(
+            queue,
+            "vkQueueEndDebugUtilsLabelEXT",
+            &[("queue", &queue)],
+            |fp: PFN_vkQueueEndDebugUtilsLabelEXT| fp(queue),
+        )
+    }
+}
+extern "system" fn queue_insert_debug_utils_label_ext(
+    queue: Queue,
+    p_label_info: *const DebugUtilsLabelEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            queue,
+            "vkQueueInsertDebugUtilsLabelEXT",
+            &[("queue", &queue), ("p_label_info", &Ptr(p_label_info))],
+            |fp: PFN_vkQueueInsertDebugUtilsLabelEXT| fp(queue, p_label_info),
+        )
+    }
+}
+extern "system" fn queue_present_khr(
+    queue: Queue,
+    p_present_info: *const PresentInfoKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            queue,
+            "vkQueuePresentKHR",
+            &[("queue", &queue), ("p_present_info", &Ptr(p_present_info))],
+            |fp: PFN_vkQueuePresentKHR| fp(queue, p_present_info),
+        )
+    }
+}
+extern "system" fn queue_signal_release_image_android(
+    queue: Queue,
+    wait_semaphore_count: u32,
+    p_wait_semaphores: *const Semaphore,
+    image: Image,
+    p_native_fence_fd: *mut c_int,
+) -> Result {
+    unsafe {
+        trace_call(
+            queue,
+            "vkQueueSignalReleaseImageANDROID",
+            &[
+                ("queue", &queue),
+                ("wait_semaphore_count", &wait_semaphore_count),
+                (
+                    "p_wait_semaphores",
+                    &Array(p_wait_semaphores, wait_semaphore_count as usize),
+                ),
+                ("image", &image),
+                ("p_native_fence_fd", &Out(p_native_fence_fd)),
+            ],
+            |fp: PFN_vkQueueSignalReleaseImageANDROID| {
+                fp(
+                    queue,
+                    wait_semaphore_count,
+                    p_wait_semaphores,
+                    image,
+                    p_native_fence_fd,
+                )
+            },
+        )
+    }
+}
+extern "system" fn queue_submit(
+    queue: Queue,
+    submit_count: u32,
+    p_submits: *const SubmitInfo,
+    fence: Fence,
+) -> Result {
+    unsafe {
+        trace_call(
+            queue,
+            "vkQueueSubmit",
+            &[
+                ("queue", &queue),
+                ("submit_count", &submit_count),
+                ("p_submits", &Array(p_submits, submit_count as usize)),
+                ("fence", &fence),
+            ],
+            |fp: PFN_vkQueueSubmit| fp(queue, submit_count, p_submits, fence),
+        )
+    }
+}
+extern "system" fn queue_wait_idle(queue: Queue) -> Result {
+    unsafe {
+        trace_call(
+            queue,
+            "vkQueueWaitIdle",
+            &[("queue", &queue)],
+            |fp: PFN_vkQueueWaitIdle| fp(queue),
+        )
+    }
+}
+extern "system" fn register_device_event_ext(
+    device: Device,
+    p_device_event_info: *const DeviceEventInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkRegisterDeviceEventEXT",
+            &[
+                ("device", &device),
+                ("p_device_event_info", &Ptr(p_device_event_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_fence", &Out(p_fence)),
+            ],
+            |fp: PFN_vkRegisterDeviceEventEXT| {
+                fp(device, p_device_event_info, p_allocator, p_fence)
+            },
+        )
+    }
+}
+extern "system" fn register_display_event_ext(
+    device: Device,
+    display: DisplayKHR,
+    p_display_event_info: *const DisplayEventInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkRegisterDisplayEventEXT",
+            &[
+                ("device", &device),
+                ("display", &display),
+                ("p_display_event_info", &Ptr(p_display_event_info)),
+                ("p_allocator", &Ptr(p_allocator)),
+                ("p_fence", &Out(p_fence)),
+            ],
+            |fp: PFN_vkRegisterDisplayEventEXT| {
+                fp(device, display, p_display_event_info, p_allocator, p_fence)
+            },
+        )
+    }
+}
+extern "system" fn register_objects_nvx(
+    device: Device,
+    object_table: ObjectTableNVX,
+    object_count: u32,
+    pp_object_table_entries: *const *const ObjectTableEntryNVX,
+    p_object_indices: *const u32,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkRegisterObjectsNVX",
+            &[
+                ("device", &device),
+                ("object_table", &object_table),
+                ("object_count", &object_count),
+                (
+                    "pp_object_table_entries",
+                    &Array(pp_object_table_entries, object_count as usize),
+                ),
+                (
+                    "p_object_indices",
+                    &Array(p_object_indices, object_count as usize),
+                ),
+            ],
+            |fp: PFN_vkRegisterObjectsNVX| {
+                fp(
+                    device,
+                    object_table,
+                    object_count,
+                    pp_object_table_entries,
+                    p_object_indices,
+                )
+            },
+        )
+    }
+}
+extern "system" fn release_display_ext(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+) -> Result {
+    unsafe {
+        trace_call(
+            physical_device,
+            "vkReleaseDisplayEXT",
+            &[("physical_device", &physical_device), ("display", &display)],
+            |fp: PFN_vkReleaseDisplayEXT| fp(physical_device, display),
+        )
+    }
+}
+extern "system" fn reset_command_buffer(
+    command_buffer: CommandBuffer,
+    flags: CommandBufferResetFlags,
+) -> Result {
+    unsafe {
+        trace_call(
+            command_buffer,
+            "vkResetCommandBuffer",
+            &[("command_buffer", &command_buffer), ("flags", &flags)],
+            |fp: PFN_vkResetCommandBuffer| fp(command_buffer, flags),
+        )
+    }
+}
+extern "system" fn reset_command_pool(
+    device: Device,
+    command_pool: CommandPool,
+    flags: CommandPoolResetFlags,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkResetCommandPool",
+            &[
+                ("device", &device),
+                ("command_pool", &command_pool),
+                ("flags", &flags),
+            ],
+            |fp: PFN_vkResetCommandPool| fp(device, command_pool, flags),
+        )
+    }
+}
+extern "system" fn reset_descriptor_pool(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    flags: DescriptorPoolResetFlags,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkResetDescriptorPool",
+            &[
+                ("device", &device),
+                ("descriptor_pool", &descriptor_pool),
+                ("flags", &flags),
+            ],
+            |fp: PFN_vkResetDescriptorPool| fp(device, descriptor_pool, flags),
+        )
+    }
+}
+extern "system" fn reset_event(device: Device, event: Event) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkResetEvent",
+            &[("device", &device), ("event", &event)],
+            |fp: PFN_vkResetEvent| fp(device, event),
+        )
+    }
+}
+extern "system" fn reset_fences(
+    device: Device,
+    fence_count: u32,
+    p_fences: *const Fence,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkResetFences",
+            &[
+                ("device", &device),
+                ("fence_count", &fence_count),
+                ("p_fences", &Array(p_fences, fence_count as usize)),
+            ],
+            |fp: PFN_vkResetFences| fp(device, fence_count, p_fences),
+        )
+    }
+}
+extern "system" fn set_debug_utils_object_name_ext(
+    device: Device,
+    p_name_info: *const DebugUtilsObjectNameInfoEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkSetDebugUtilsObjectNameEXT",
+            &[("device", &device), ("p_name_info", &Ptr(p_name_info))],
+            |fp: PFN_vkSetDebugUtilsObjectNameEXT| fp(device, p_name_info),
+        )
+    }
+}
+extern "system" fn set_debug_utils_object_tag_ext(
+    device: Device,
+    p_tag_info: *const DebugUtilsObjectTagInfoEXT,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkSetDebugUtilsObjectTagEXT",
+            &[("device", &device), ("p_tag_info", &Ptr(p_tag_info))],
+            |fp: PFN_vkSetDebugUtilsObjectTagEXT| fp(device, p_tag_info),
+        )
+    }
+}
+extern "system" fn set_event(device: Device, event: Event) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkSetEvent",
+            &[("device", &device), ("event", &event)],
+            |fp: PFN_vkSetEvent| fp(device, event),
+        )
+    }
+}
+extern "system" fn set_hdr_metadata_ext(
+    device: Device,
+    swapchain_count: u32,
+    p_swapchains: *const SwapchainKHR,
+    p_metadata: *const HdrMetadataEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkSetHdrMetadataEXT",
+            &[
+                ("device", &device),
+                ("swapchain_count", &swapchain_count),
+                (
+                    "p_swapchains",
+                    &Array(p_swapchains, swapchain_count as usize),
+                ),
+                ("p_metadata", &Array(p_metadata, swapchain_count as usize)),
+            ],
+            |fp: PFN_vkSetHdrMetadataEXT| fp(device, swapchain_count, p_swapchains, p_metadata),
+        )
+    }
+}
+extern "system" fn submit_debug_utils_message_ext(
+    instance: Instance,
+    message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    message_types: DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+) -> c_void {
+    unsafe {
+        trace_call(
+            instance,
+            "vkSubmitDebugUtilsMessageEXT",
+            &[
+                ("instance", &instance),
+                ("message_severity", &message_severity),
+                ("message_types", &message_types),
+                ("p_callback_data", &Ptr(p_callback_data)),
+            ],
+            |fp: PFN_vkSubmitDebugUtilsMessageEXT| {
+                fp(instance, message_severity, message_types, p_callback_data)
+            },
+        )
+    }
+}
+extern "system" fn trim_command_pool(
+    device: Device,
+    command_pool: CommandPool,
+    flags: CommandPoolTrimFlags,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkTrimCommandPool",
+            &[
+                ("device", &device),
+                ("command_pool", &command_pool),
+                ("flags", &flags),
+            ],
+            |fp: PFN_vkTrimCommandPool| fp(device, command_pool, flags),
+        )
+    }
+}
+extern "system" fn unmap_memory(device: Device, memory: DeviceMemory) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkUnmapMemory",
+            &[("device", &device), ("memory", &memory)],
+            |fp: PFN_vkUnmapMemory| fp(device, memory),
+        )
+    }
+}
+extern "system" fn unregister_objects_nvx(
+    device: Device,
+    object_table: ObjectTableNVX,
+    object_count: u32,
+    p_object_entry_types: *const ObjectEntryTypeNVX,
+    p_object_indices: *const u32,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkUnregisterObjectsNVX",
+            &[
+                ("device", &device),
+                ("object_table", &object_table),
+                ("object_count", &object_count),
+                (
+                    "p_object_entry_types",
+                    &Array(p_object_entry_types, object_count as usize),
+                ),
+                (
+                    "p_object_indices",
+                    &Array(p_object_indices, object_count as usize),
+                ),
+            ],
+            |fp: PFN_vkUnregisterObjectsNVX| {
+                fp(
+                    device,
+                    object_table,
+                    object_count,
+                    p_object_entry_types,
+                    p_object_indices,
+                )
+            },
+        )
+    }
+}
+extern "system" fn update_descriptor_set_with_template(
+    device: Device,
+    descriptor_set: DescriptorSet,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    p_data: *const c_void,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkUpdateDescriptorSetWithTemplate",
+            &[
+                ("device", &device),
+                ("descriptor_set", &descriptor_set),
+                ("descriptor_update_template", &descriptor_update_template),
+                ("p_data", &p_data),
+            ],
+            |fp: PFN_vkUpdateDescriptorSetWithTemplate| {
+                fp(device, descriptor_set, descriptor_update_template, p_data)
+            },
+        )
+    }
+}
+extern "system" fn update_descriptor_sets(
+    device: Device,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const WriteDescriptorSet,
+    descriptor_copy_count: u32,
+    p_descriptor_copies: *const CopyDescriptorSet,
+) -> c_void {
+    unsafe {
+        trace_call(
+            device,
+            "vkUpdateDescriptorSets",
+            &[
+                ("device", &device),
+                ("descriptor_write_count", &descriptor_write_count),
+                (
+                    "p_descriptor_writes",
+                    &Array(p_descriptor_writes, descriptor_write_count as usize),
+                ),
+                ("descriptor_copy_count", &descriptor_copy_count),
+                (
+                    "p_descriptor_copies",
+                    &Array(p_descriptor_copies, descriptor_copy_count as usize),
+                ),
+            ],
+            |fp: PFN_vkUpdateDescriptorSets| {
+                fp(
+                    device,
+                    descriptor_write_count,
+                    p_descriptor_writes,
+                    descriptor_copy_count,
+                    p_descriptor_copies,
+                )
+            },
+        )
+    }
+}
+extern "system" fn wait_for_fences(
+    device: Device,
+    fence_count: u32,
+    p_fences: *const Fence,
+    wait_all: Bool32,
+    timeout: u64,
+) -> Result {
+    unsafe {
+        trace_call(
+            device,
+            "vkWaitForFences",
+            &[
+                ("device", &device),
+                ("fence_count", &fence_count),
+                ("p_fences", &Array(p_fences, fence_count as usize)),
+                ("wait_all", &wait_all),
+                ("timeout", &timeout),
+            ],
+            |fp: PFN_vkWaitForFences| fp(device, fence_count, p_fences, wait_all, timeout),
+        )
+    }
+}
+pub fn shim(name: &str) -> Option<*const c_void> {
+    match name {
+        "vkAcquireImageANDROID" => Some(acquire_image_android as *const c_void),
+        "vkAcquireNextImage2KHR" => Some(acquire_next_image2_khr as *const c_void),
+        "vkAcquireNextImageKHR" => Some(acquire_next_image_khr as *const c_void),
+        "vkAcquireXlibDisplayEXT" => Some(acquire_xlib_display_ext as *const c_void),
+        "vkAllocateCommandBuffers" => Some(allocate_command_buffers as *const c_void),
+        "vkAllocateDescriptorSets" => Some(allocate_descriptor_sets as *const c_void),
+        "vkAllocateMemory" => Some(allocate_memory as *const c_void),
+        "vkBeginCommandBuffer" => Some(begin_command_buffer as *const c_void),
+        "vkBindAccelerationStructureMemoryNV" => {
+            Some(bind_acceleration_structure_memory_nv as *const c_void)
+        }
+        "vkBindBufferMemory" => Some(bind_buffer_memory as *const c_void),
+        "vkBindBufferMemory2" => Some(bind_buffer_memory2 as *const c_void),
+        "vkBindImageMemory" => Some(bind_image_memory as *const c_void),
+        "vkBindImageMemory2" => Some(bind_image_memory2 as *const c_void),
+        "vkCmdBeginConditionalRenderingEXT" => {
+            Some(cmd_begin_conditional_rendering_ext as *const c_void)
+        }
+        "vkCmdBeginDebugUtilsLabelEXT" => Some(cmd_begin_debug_utils_label_ext as *const c_void),
+        "vkCmdBeginQuery" => Some(cmd_begin_query as *const c_void),
+        "vkCmdBeginQueryIndexedEXT" => Some(cmd_begin_query_indexed_ext as *const c_void),
+        "vkCmdBeginRenderPass" => Some(cmd_begin_render_pass as *const c_void),
+        "vkCmdBeginRenderPass2KHR" => Some(cmd_begin_render_pass2_khr as *const c_void),
+        "vkCmdBeginTransformFeedbackEXT" => Some(cmd_begin_transform_feedback_ext as *const c_void),
+        "vkCmdBindDescriptorSets" => Some(cmd_bind_descriptor_sets as *const c_void),
+        "vkCmdBindIndexBuffer" => Some(cmd_bind_index_buffer as *const c_void),
+        "vkCmdBindPipeline" => Some(cmd_bind_pipeline as *const c_void),
+        "vkCmdBindShadingRateImageNV" => Some(cmd_bind_shading_rate_image_nv as *const c_void),
+        "vkCmdBindTransformFeedbackBuffersEXT" => {
+            Some(cmd_bind_transform_feedback_buffers_ext as *const c_void)
+        }
+        "vkCmdBindVertexBuffers" => Some(cmd_bind_vertex_buffers as *const c_void),
+        "vkCmdBlitImage" => Some(cmd_blit_image as *const c_void),
+        "vkCmdBuildAccelerationStructureNV" => {
+            Some(cmd_build_acceleration_structure_nv as *const c_void)
+        }
+        "vkCmdClearAttachments" => Some(cmd_clear_attachments as *const c_void),
+        "vkCmdClearColorImage" => Some(cmd_clear_color_image as *const c_void),
+        "vkCmdClearDepthStencilImage" => Some(cmd_clear_depth_stencil_image as *const c_void),
+        "vkCmdCopyAccelerationStructureNV" => {
+            Some(cmd_copy_acceleration_structure_nv as *const c_void)
+        }
+        "vkCmdCopyBuffer" => Some(cmd_copy_buffer as *const c_void),
+        "vkCmdCopyBufferToImage" => Some(cmd_copy_buffer_to_image as *const c_void),
+        "vkCmdCopyImage" => Some(cmd_copy_image as *const c_void),
+        "vkCmdCopyImageToBuffer" => Some(cmd_copy_image_to_buffer as *const c_void),
+        "vkCmdCopyQueryPoolResults" => Some(cmd_copy_query_pool_results as *const c_void),
+        "vkCmdDebugMarkerBeginEXT" => Some(cmd_debug_marker_begin_ext as *const c_void),
+        "vkCmdDebugMarkerEndEXT" => Some(cmd_debug_marker_end_ext as *const c_void),
+        "vkCmdDebugMarkerInsertEXT" => Some(cmd_debug_marker_insert_ext as *const c_void),
+        "vkCmdDispatch" => Some(cmd_dispatch as *const c_void),
+        "vkCmdDispatchBase" => Some(cmd_dispatch_base as *const c_void),
+        "vkCmdDispatchIndirect" => Some(cmd_dispatch_indirect as *const c_void),
+        "vkCmdDraw" => Some(cmd_draw as *const c_void),
+        "vkCmdDrawIndexed" => Some(cmd_draw_indexed as *const c_void),
+        "vkCmdDrawIndexedIndirect" => Some(cmd_draw_indexed_indirect as *const c_void),
+        "vkCmdDrawIndexedIndirectCountAMD" => {
+            Some(cmd_draw_indexed_indirect_count_amd as *const c_void)
+        }
+        "vkCmdDrawIndexedIndirectCountKHR" => {
+            Some(cmd_draw_indexed_indirect_count_khr as *const c_void)
+        }
+        "vkCmdDrawIndirect" => Some(cmd_draw_indirect as *const c_void),
+        "vkCmdDrawIndirectByteCountEXT" => Some(cmd_draw_indirect_byte_count_ext as *const c_void),
+        "vkCmdDrawIndirectCountAMD" => Some(cmd_draw_indirect_count_amd as *const c_void),
+        "vkCmdDrawIndirectCountKHR" => Some(cmd_draw_indirect_count_khr as *const c_void),
+        "vkCmdDrawMeshTasksIndirectCountNV" => {
+            Some(cmd_draw_mesh_tasks_indirect_count_nv as *const c_void)
+        }
+        "vkCmdDrawMeshTasksIndirectNV" => Some(cmd_draw_mesh_tasks_indirect_nv as *const c_void),
+        "vkCmdDrawMeshTasksNV" => Some(cmd_draw_mesh_tasks_nv as *const c_void),
+        "vkCmdEndConditionalRenderingEXT" => {
+            Some(cmd_end_conditional_rendering_ext as *const c_void)
+        }
+        "vkCmdEndDebugUtilsLabelEXT" => Some(cmd_end_debug_utils_label_ext as *const c_void),
+        "vkCmdEndQuery" => Some(cmd_end_query as *const c_void),
+        "vkCmdEndQueryIndexedEXT" => Some(cmd_end_query_indexed_ext as *const c_void),
+        "vkCmdEndRenderPass" => Some(cmd_end_render_pass as *const c_void),
+        "vkCmdEndRenderPass2KHR" => Some(cmd_end_render_pass2_khr as *const c_void),
+        "vkCmdEndTransformFeedbackEXT" => Some(cmd_end_transform_feedback_ext as *const c_void),
+        "vkCmdExecuteCommands" => Some(cmd_execute_commands as *const c_void),
+        "vkCmdFillBuffer" => Some(cmd_fill_buffer as *const c_void),
+        "vkCmdInsertDebugUtilsLabelEXT" => Some(cmd_insert_debug_utils_label_ext as *const c_void),
+        "vkCmdNextSubpass" => Some(cmd_next_subpass as *const c_void),
+        "vkCmdNextSubpass2KHR" => Some(cmd_next_subpass2_khr as *const c_void),
+        "vkCmdPipelineBarrier" => Some(cmd_pipeline_barrier as *const c_void),
+        "vkCmdProcessCommandsNVX" => Some(cmd_process_commands_nvx as *const c_void),
+        "vkCmdPushConstants" => Some(cmd_push_constants as *const c_void),
+        "vkCmdPushDescriptorSetKHR" => Some(cmd_push_descriptor_set_khr as *const c_void),
+        "vkCmdPushDescriptorSetWithTemplateKHR" => {
+            Some(cmd_push_descriptor_set_with_template_khr as *const c_void)
+        }
+        "vkCmdReserveSpaceForCommandsNVX" => {
+            Some(cmd_reserve_space_for_commands_nvx as *const c_void)
+        }
+        "vkCmdResetEvent" => Some(cmd_reset_event as *const c_void),
+        "vkCmdResetQueryPool" => Some(cmd_reset_query_pool as *const c_void),
+        "vkCmdResolveImage" => Some(cmd_resolve_image as *const c_void),
+        "vkCmdSetBlendConstants" => Some(cmd_set_blend_constants as *const c_void),
+        "vkCmdSetCheckpointNV" => Some(cmd_set_checkpoint_nv as *const c_void),
+        "vkCmdSetCoarseSampleOrderNV" => Some(cmd_set_coarse_sample_order_nv as *const c_void),
+        "vkCmdSetDepthBias" => Some(cmd_set_depth_bias as *const c_void),
+        "vkCmdSetDepthBounds" => Some(cmd_set_depth_bounds as *const c_void),
+        "vkCmdSetDeviceMask" => Some(cmd_set_device_mask as *const c_void),
+        "vkCmdSetDiscardRectangleEXT" => Some(cmd_set_discard_rectangle_ext as *const c_void),
+        "vkCmdSetEvent" => Some(cmd_set_event as *const c_void),
+        "vkCmdSetExclusiveScissorNV" => Some(cmd_set_exclusive_scissor_nv as *const c_void),
+        "vkCmdSetLineWidth" => Some(cmd_set_line_width as *const c_void),
+        "vkCmdSetSampleLocationsEXT" => Some(cmd_set_sample_locations_ext as *const c_void),
+        "vkCmdSetScissor" => Some(cmd_set_scissor as *const c_void),
+        "vkCmdSetStencilCompareMask" => Some(cmd_set_stencil_compare_mask as *const c_void),
+        "vkCmdSetStencilReference" => Some(cmd_set_stencil_reference as *const c_void),
+        "vkCmdSetStencilWriteMask" => Some(cmd_set_stencil_write_mask as *const c_void),
+        "vkCmdSetViewport" => Some(cmd_set_viewport as *const c_void),
+        "vkCmdSetViewportShadingRatePaletteNV" => {
+            Some(cmd_set_viewport_shading_rate_palette_nv as *const c_void)
+        }
+        "vkCmdSetViewportWScalingNV" => Some(cmd_set_viewport_w_scaling_nv as *const c_void),
+        "vkCmdTraceRaysNV" => Some(cmd_trace_rays_nv as *const c_void),
+        "vkCmdUpdateBuffer" => Some(cmd_update_buffer as *const c_void),
+        "vkCmdWaitEvents" => Some(cmd_wait_events as *const c_void),
+        "vkCmdWriteAccelerationStructuresPropertiesNV" => {
+            Some(cmd_write_acceleration_structures_properties_nv as *const c_void)
+        }
+        "vkCmdWriteBufferMarkerAMD" => Some(cmd_write_buffer_marker_amd as *const c_void),
+        "vkCmdWriteTimestamp" => Some(cmd_write_timestamp as *const c_void),
+        "vkCompileDeferredNV" => Some(compile_deferred_nv as *const c_void),
+        "vkCreateAccelerationStructureNV" => {
+            Some(create_acceleration_structure_nv as *const c_void)
+        }
+        "vkCreateAndroidSurfaceKHR" => Some(create_android_surface_khr as *const c_void),
+        "vkCreateBuffer" => Some(create_buffer as *const c_void),
+        "vkCreateBufferView" => Some(create_buffer_view as *const c_void),
+        "vkCreateCommandPool" => Some(create_command_pool as *const c_void),
+        "vkCreateComputePipelines" => Some(create_compute_pipelines as *const c_void),
+        "vkCreateDebugReportCallbackEXT" => Some(create_debug_report_callback_ext as *const c_void),
+        "vkCreateDebugUtilsMessengerEXT" => Some(create_debug_utils_messenger_ext as *const c_void),
+        "vkCreateDescriptorPool" => Some(create_descriptor_pool as *const c_void),
+        "vkCreateDescriptorSetLayout" => Some(create_descriptor_set_layout as *const c_void),
+        "vkCreateDescriptorUpdateTemplate" => {
+            Some(create_descriptor_update_template as *const c_void)
+        }
+        "vkCreateDisplayModeKHR" => Some(create_display_mode_khr as *const c_void),
+        "vkCreateDisplayPlaneSurfaceKHR" => Some(create_display_plane_surface_khr as *const c_void),
+        "vkCreateEvent" => Some(create_event as *const c_void),
+        "vkCreateFence" => Some(create_fence as *const c_void),
+        "vkCreateFramebuffer" => Some(create_framebuffer as *const c_void),
+        "vkCreateGraphicsPipelines" => Some(create_graphics_pipelines as *const c_void),
+        "vkCreateIOSSurfaceMVK" => Some(create_ios_surface_mvk as *const c_void),
+        "vkCreateImage" => Some(create_image as *const c_void),
+        "vkCreateImagePipeSurfaceFUCHSIA" => {
+            Some(create_image_pipe_surface_fuchsia as *const c_void)
+        }
+        "vkCreateImageView" => Some(create_image_view as *const c_void),
+        "vkCreateIndirectCommandsLayoutNVX" => {
+            Some(create_indirect_commands_layout_nvx as *const c_void)
+        }
+        "vkCreateMacOSSurfaceMVK" => Some(create_mac_os_surface_mvk as *const c_void),
+        "vkCreateObjectTableNVX" => Some(create_object_table_nvx as *const c_void),
+        "vkCreatePipelineCache" => Some(create_pipeline_cache as *const c_void),
+        "vkCreatePipelineLayout" => Some(create_pipeline_layout as *const c_void),
+        "vkCreateQueryPool" => Some(create_query_pool as *const c_void),
+        "vkCreateRayTracingPipelinesNV" => Some(create_ray_tracing_pipelines_nv as *const c_void),
+        "vkCreateRenderPass" => Some(create_render_pass as *const c_void),
+        "vkCreateRenderPass2KHR" => Some(create_render_pass2_khr as *const c_void),
+        "vkCreateSampler" => Some(create_sampler as *const c_void),
+        "vkCreateSamplerYcbcrConversion" => Some(create_sampler_ycbcr_conversion as *const c_void),
+        "vkCreateSemaphore" => Some(create_semaphore as *const c_void),
+        "vkCreateShaderModule" => Some(create_shader_module as *const c_void),
+        "vkCreateSharedSwapchainsKHR" => Some(create_shared_swapchains_khr as *const c_void),
+        "vkCreateSwapchainKHR" => Some(create_swapchain_khr as *const c_void),
+        "vkCreateValidationCacheEXT" => Some(create_validation_cache_ext as *const c_void),
+        "vkCreateViSurfaceNN" => Some(create_vi_surface_nn as *const c_void),
+        "vkCreateWaylandSurfaceKHR" => Some(create_wayland_surface_khr as *const c_void),
+        "vkCreateWin32SurfaceKHR" => Some(create_win32_surface_khr as *const c_void),
+        "vkCreateXcbSurfaceKHR" => Some(create_xcb_surface_khr as *const c_void),
+        "vkCreateXlibSurfaceKHR" => Some(create_xlib_surface_khr as *const c_void),
+        "vkDebugMarkerSetObjectNameEXT" => Some(debug_marker_set_object_name_ext as *const c_void),
+        "vkDebugMarkerSetObjectTagEXT" => Some(debug_marker_set_object_tag_ext as *const c_void),
+        "vkDebugReportMessageEXT" => Some(debug_report_message_ext as *const c_void),
+        "vkDestroyAccelerationStructureNV" => {
+            Some(destroy_acceleration_structure_nv as *const c_void)
+        }
+        "vkDestroyBuffer" => Some(destroy_buffer as *const c_void),
+        "vkDestroyBufferView" => Some(destroy_buffer_view as *const c_void),
+        "vkDestroyCommandPool" => Some(destroy_command_pool as *const c_void),
+        "vkDestroyDebugReportCallbackEXT" => {
+            Some(destroy_debug_report_callback_ext as *const c_void)
+        }
+        "vkDestroyDebugUtilsMessengerEXT" => {
+            Some(destroy_debug_utils_messenger_ext as *const c_void)
+        }
+        "vkDestroyDescriptorPool" => Some(destroy_descriptor_pool as *const c_void),
+        "vkDestroyDescriptorSetLayout" => Some(destroy_descriptor_set_layout as *const c_void),
+        "vkDestroyDescriptorUpdateTemplate" => {
+            Some(destroy_descriptor_update_template as *const c_void)
+        }
+        "vkDestroyDevice" => Some(destroy_device as *const c_void),
+        "vkDestroyEvent" => Some(destroy_event as *const c_void),
+        "vkDestroyFence" => Some(destroy_fence as *const c_void),
+        "vkDestroyFramebuffer" => Some(destroy_framebuffer as *const c_void),
+        "vkDestroyImage" => Some(destroy_image as *const c_void),
+        "vkDestroyImageView" => Some(destroy_image_view as *const c_void),
+        "vkDestroyIndirectCommandsLayoutNVX" => {
+            Some(destroy_indirect_commands_layout_nvx as *const c_void)
+        }
+        "vkDestroyInstance" => Some(destroy_instance as *const c_void),
+        "vkDestroyObjectTableNVX" => Some(destroy_object_table_nvx as *const c_void),
+        "vkDestroyPipeline" => Some(destroy_pipeline as *const c_void),
+        "vkDestroyPipelineCache" => Some(destroy_pipeline_cache as *const c_void),
+        "vkDestroyPipelineLayout" => Some(destroy_pipeline_layout as *const c_void),
+        "vkDestroyQueryPool" => Some(destroy_query_pool as *const c_void),
+        "vkDestroyRenderPass" => Some(destroy_render_pass as *const c_void),
+        "vkDestroySampler" => Some(destroy_sampler as *const c_void),
+        "vkDestroySamplerYcbcrConversion" => {
+            Some(destroy_sampler_ycbcr_conversion as *const c_void)
+        }
+        "vkDestroySemaphore" => Some(destroy_semaphore as *const c_void),
+        "vkDestroyShaderModule" => Some(destroy_shader_module as *const c_void),
+        "vkDestroySurfaceKHR" => Some(destroy_surface_khr as *const c_void),
+        "vkDestroySwapchainKHR" => Some(destroy_swapchain_khr as *const c_void),
+        "vkDestroyValidationCacheEXT" => Some(destroy_validation_cache_ext as *const c_void),
+        "vkDeviceWaitIdle" => Some(device_wait_idle as *const c_void),
+        "vkDisplayPowerControlEXT" => Some(display_power_control_ext as *const c_void),
+        "vkEndCommandBuffer" => Some(end_command_buffer as *const c_void),
+        "vkEnumerateDeviceExtensionProperties" => {
+            Some(enumerate_device_extension_properties as *const c_void)
+        }
+        "vkEnumerateDeviceLayerProperties" => {
+            Some(enumerate_device_layer_properties as *const c_void)
+        }
+        "vkEnumeratePhysicalDeviceGroups" => {
+            Some(enumerate_physical_device_groups as *const c_void)
+        }
+        "vkEnumeratePhysicalDevices" => Some(enumerate_physical_devices as *const c_void),
+        "vkFlushMappedMemoryRanges" => Some(flush_mapped_memory_ranges as *const c_void),
+        "vkFreeCommandBuffers" => Some(free_command_buffers as *const c_void),
+        "vkFreeDescriptorSets" => Some(free_descriptor_sets as *const c_void),
+        "vkFreeMemory" => Some(free_memory as *const c_void),
+        "vkGetAccelerationStructureHandleNV" => {
+            Some(get_acceleration_structure_handle_nv as *const c_void)
+        }
+        "vkGetAccelerationStructureMemoryRequirementsNV" => {
+            Some(get_acceleration_structure_memory_requirements_nv as *const c_void)
+        }
+        "vkGetAndroidHardwareBufferPropertiesANDROID" => {
+            Some(get_android_hardware_buffer_properties_android as *const c_void)
+        }
+        "vkGetBufferMemoryRequirements" => Some(get_buffer_memory_requirements as *const c_void),
+        "vkGetBufferMemoryRequirements2" => Some(get_buffer_memory_requirements2 as *const c_void),
+        "vkGetCalibratedTimestampsEXT" => Some(get_calibrated_timestamps_ext as *const c_void),
+        "vkGetDescriptorSetLayoutSupport" => {
+            Some(get_descriptor_set_layout_support as *const c_void)
+        }
+        "vkGetDeviceGroupPeerMemoryFeatures" => {
+            Some(get_device_group_peer_memory_features as *const c_void)
+        }
+        "vkGetDeviceGroupPresentCapabilitiesKHR" => {
+            Some(get_device_group_present_capabilities_khr as *const c_void)
+        }
+        "vkGetDeviceGroupSurfacePresentModesKHR" => {
+            Some(get_device_group_surface_present_modes_khr as *const c_void)
+        }
+        "vkGetDeviceMemoryCommitment" => Some(get_device_memory_commitment as *const c_void),
+        "vkGetDeviceQueue" => Some(get_device_queue as *const c_void),
+        "vkGetDeviceQueue2" => Some(get_device_queue2 as *const c_void),
+        "vkGetDisplayModeProperties2KHR" => Some(get_display_mode_properties2_khr as *const c_void),
+        "vkGetDisplayModePropertiesKHR" => Some(get_display_mode_properties_khr as *const c_void),
+        "vkGetDisplayPlaneCapabilities2KHR" => {
+            Some(get_display_plane_capabilities2_khr as *const c_void)
+        }
+        "vkGetDisplayPlaneCapabilitiesKHR" => {
+            Some(get_display_plane_capabilities_khr as *const c_void)
+        }
+        "vkGetDisplayPlaneSupportedDisplaysKHR" => {
+            Some(get_display_plane_supported_displays_khr as *const c_void)
+        }
+        "vkGetEventStatus" => Some(get_event_status as *const c_void),
+        "vkGetFenceFdKHR" => Some(get_fence_fd_khr as *const c_void),
+        "vkGetFenceStatus" => Some(get_fence_status as *const c_void),
+        "vkGetFenceWin32HandleKHR" => Some(get_fence_win32_handle_khr as *const c_void),
+        "vkGetImageDrmFormatModifierPropertiesEXT" => {
+            Some(get_image_drm_format_modifier_properties_ext as *const c_void)
+        }
+        "vkGetImageMemoryRequirements" => Some(get_image_memory_requirements as *const c_void),
+        "vkGetImageMemoryRequirements2" => Some(get_image_memory_requirements2 as *const c_void),
+        "vkGetImageSparseMemoryRequirements" => {
+            Some(get_image_sparse_memory_requirements as *const c_void)
+        }
+        "vkGetImageSparseMemoryRequirements2" => {
+            Some(get_image_sparse_memory_requirements2 as *const c_void)
+        }
+        "vkGetImageSubresourceLayout" => Some(get_image_subresource_layout as *const c_void),
+        "vkGetMemoryAndroidHardwareBufferANDROID" => {
+            Some(get_memory_android_hardware_buffer_android as *const c_void)
+        }
+        "vkGetMemoryFdKHR" => Some(get_memory_fd_khr as *const c_void),
+        "vkGetMemoryFdPropertiesKHR" => Some(get_memory_fd_properties_khr as *const c_void),
+        "vkGetMemoryHostPointerPropertiesEXT" => {
+            Some(get_memory_host_pointer_properties_ext as *const c_void)
+        }
+        "vkGetMemoryWin32HandleKHR" => Some(get_memory_win32_handle_khr as *const c_void),
+        "vkGetMemoryWin32HandleNV" => Some(get_memory_win32_handle_nv as *const c_void),
+        "vkGetMemoryWin32HandlePropertiesKHR" => {
+            Some(get_memory_win32_handle_properties_khr as *const c_void)
+        }
+        "vkGetPastPresentationTimingGOOGLE" => {
+            Some(get_past_presentation_timing_google as *const c_void)
+        }
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT" => {
+            Some(get_physical_device_calibrateable_time_domains_ext as *const c_void)
+        }
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR" => {
+            Some(get_physical_device_display_plane_properties2_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR" => {
+            Some(get_physical_device_display_plane_properties_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceDisplayProperties2KHR" => {
+            Some(get_physical_device_display_properties2_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceDisplayPropertiesKHR" => {
+            Some(get_physical_device_display_properties_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceExternalBufferProperties" => {
+            Some(get_physical_device_external_buffer_properties as *const c_void)
+        }
+        "vkGetPhysicalDeviceExternalFenceProperties" => {
+            Some(get_physical_device_external_fence_properties as *const c_void)
+        }
+        "vkGetPhysicalDeviceExternalImageFormatPropertiesNV" => {
+            Some(get_physical_device_external_image_format_properties_nv as *const c_void)
+        }
+        "vkGetPhysicalDeviceExternalSemaphoreProperties" => {
+            Some(get_physical_device_external_semaphore_properties as *const c_void)
+        }
+        "vkGetPhysicalDeviceFeatures" => Some(get_physical_device_features as *const c_void),
+        "vkGetPhysicalDeviceFeatures2" => Some(get_physical_device_features2 as *const c_void),
+        "vkGetPhysicalDeviceFormatProperties" => {
+            Some(get_physical_device_format_properties as *const c_void)
+        }
+        "vkGetPhysicalDeviceFormatProperties2" => {
+            Some(get_physical_device_format_properties2 as *const c_void)
+        }
+        "vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX" => {
+            Some(get_physical_device_generated_commands_properties_nvx as *const c_void)
+        }
+        "vkGetPhysicalDeviceImageFormatProperties" => {
+            Some(get_physical_device_image_format_properties as *const c_void)
+        }
+        "vkGetPhysicalDeviceImageFormatProperties2" => {
+            Some(get_physical_device_image_format_properties2 as *const c_void)
+        }
+        "vkGetPhysicalDeviceMemoryProperties" => {
+            Some(get_physical_device_memory_properties as *const c_void)
+        }
+        "vkGetPhysicalDeviceMemoryProperties2" => {
+            Some(get_physical_device_memory_properties2 as *const c_void)
+        }
+        "vkGetPhysicalDeviceMultisamplePropertiesEXT" => {
+            Some(get_physical_device_multisample_properties_ext as *const c_void)
+        }
+        "vkGetPhysicalDevicePresentRectanglesKHR" => {
+            Some(get_physical_device_present_rectangles_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceProperties" => Some(get_physical_device_properties as *const c_void),
+        "vkGetPhysicalDeviceProperties2" => Some(get_physical_device_properties2 as *const c_void),
+        "vkGetPhysicalDeviceQueueFamilyProperties" => {
+            Some(get_physical_device_queue_family_properties as *const c_void)
+        }
+        "vkGetPhysicalDeviceQueueFamilyProperties2" => {
+            Some(get_physical_device_queue_family_properties2 as *const c_void)
+        }
+        "vkGetPhysicalDeviceSparseImageFormatProperties" => {
+            Some(get_physical_device_sparse_image_format_properties as *const c_void)
+        }
+        "vkGetPhysicalDeviceSparseImageFormatProperties2" => {
+            Some(get_physical_device_sparse_image_format_properties2 as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilities2EXT" => {
+            Some(get_physical_device_surface_capabilities2_ext as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilities2KHR" => {
+            Some(get_physical_device_surface_capabilities2_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => {
+            Some(get_physical_device_surface_capabilities_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceFormats2KHR" => {
+            Some(get_physical_device_surface_formats2_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceFormatsKHR" => {
+            Some(get_physical_device_surface_formats_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfacePresentModesKHR" => {
+            Some(get_physical_device_surface_present_modes_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceSurfaceSupportKHR" => {
+            Some(get_physical_device_surface_support_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceWaylandPresentationSupportKHR" => {
+            Some(get_physical_device_wayland_presentation_support_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceWin32PresentationSupportKHR" => {
+            Some(get_physical_device_win32_presentation_support_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceXcbPresentationSupportKHR" => {
+            Some(get_physical_device_xcb_presentation_support_khr as *const c_void)
+        }
+        "vkGetPhysicalDeviceXlibPresentationSupportKHR" => {
+            Some(get_physical_device_xlib_presentation_support_khr as *const c_void)
+        }
+        "vkGetPipelineCacheData" => Some(get_pipeline_cache_data as *const c_void),
+        "vkGetQueryPoolResults" => Some(get_query_pool_results as *const c_void),
+        "vkGetQueueCheckpointDataNV" => Some(get_queue_checkpoint_data_nv as *const c_void),
+        "vkGetRandROutputDisplayEXT" => Some(get_rand_r_output_display_ext as *const c_void),
+        "vkGetRayTracingShaderGroupHandlesNV" => {
+            Some(get_ray_tracing_shader_group_handles_nv as *const c_void)
+        }
+        "vkGetRefreshCycleDurationGOOGLE" => {
+            Some(get_refresh_cycle_duration_google as *const c_void)
+        }
+        "vkGetRenderAreaGranularity" => Some(get_render_area_granularity as *const c_void),
+        "vkGetSemaphoreFdKHR" => Some(get_semaphore_fd_khr as *const c_void),
+        "vkGetSemaphoreWin32HandleKHR" => Some(get_semaphore_win32_handle_khr as *const c_void),
+        "vkGetShaderInfoAMD" => Some(get_shader_info_amd as *const c_void),
+        "vkGetSwapchainCounterEXT" => Some(get_swapchain_counter_ext as *const c_void),
+        "vkGetSwapchainGrallocUsageANDROID" => {
+            Some(get_swapchain_gralloc_usage_android as *const c_void)
+        }
+        "vkGetSwapchainImagesKHR" => Some(get_swapchain_images_khr as *const c_void),
+        "vkGetSwapchainStatusKHR" => Some(get_swapchain_status_khr as *const c_void),
+        "vkGetValidationCacheDataEXT" => Some(get_validation_cache_data_ext as *const c_void),
+        "vkImportFenceFdKHR" => Some(import_fence_fd_khr as *const c_void),
+        "vkImportFenceWin32HandleKHR" => Some(import_fence_win32_handle_khr as *const c_void),
+        "vkImportSemaphoreFdKHR" => Some(import_semaphore_fd_khr as *const c_void),
+        "vkImportSemaphoreWin32HandleKHR" => {
+            Some(import_semaphore_win32_handle_khr as *const c_void)
+        }
+        "vkInvalidateMappedMemoryRanges" => Some(invalidate_mapped_memory_ranges as *const c_void),
+        "vkMapMemory" => Some(map_memory as *const c_void),
+        "vkMergePipelineCaches" => Some(merge_pipeline_caches as *const c_void),
+        "vkMergeValidationCachesEXT" => Some(merge_validation_caches_ext as *const c_void),
+        "vkQueueBeginDebugUtilsLabelEXT" => {
+            Some(queue_begin_debug_utils_label_ext as *const c_void)
+        }
+        "vkQueueBindSparse" => Some(queue_bind_sparse as *const c_void),
+        "vkQueueEndDebugUtilsLabelEXT" => Some(queue_end_debug_utils_label_ext as *const c_void),
+        "vkQueueInsertDebugUtilsLabelEXT" => {
+            Some(queue_insert_debug_utils_label_ext as *const c_void)
+        }
+        "vkQueuePresentKHR" => Some(queue_present_khr as *const c_void),
+        "vkQueueSignalReleaseImageANDROID" => {
+            Some(queue_signal_release_image_android as *const c_void)
+        }
+        "vkQueueSubmit" => Some(queue_submit as *const c_void),
+        "vkQueueWaitIdle" => Some(queue_wait_idle as *const c_void),
+        "vkRegisterDeviceEventEXT" => Some(register_device_event_ext as *const c_void),
+        "vkRegisterDisplayEventEXT" => Some(register_display_event_ext as *const c_void),
+        "vkRegisterObjectsNVX" => Some(register_objects_nvx as *const c_void),
+        "vkReleaseDisplayEXT" => Some(release_display_ext as *const c_void),
+        "vkResetCommandBuffer" => Some(reset_command_buffer as *const c_void),
+        "vkResetCommandPool" => Some(reset_command_pool as *const c_void),
+        "vkResetDescriptorPool" => Some(reset_descriptor_pool as *const c_void),
+        "vkResetEvent" => Some(reset_event as *const c_void),
+        "vkResetFences" => Some(reset_fences as *const c_void),
+        "vkSetDebugUtilsObjectNameEXT" => Some(set_debug_utils_object_name_ext as *const c_void),
+        "vkSetDebugUtilsObjectTagEXT" => Some(set_debug_utils_object_tag_ext as *const c_void),
+        "vkSetEvent" => Some(set_event as *const c_void),
+        "vkSetHdrMetadataEXT" => Some(set_hdr_metadata_ext as *const c_void),
+        "vkSubmitDebugUtilsMessageEXT" => Some(submit_debug_utils_message_ext as *const c_void),
+        "vkTrimCommandPool" => Some(trim_command_pool as *const c_void),
+        "vkUnmapMemory" => Some(unmap_memory as *const c_void),
+        "vkUnregisterObjectsNVX" => Some(unregister_objects_nvx as *const c_void),
+        "vkUpdateDescriptorSetWithTemplate" => {
+            Some(update_descriptor_set_with_template as *const c_void)
+        }
+        "vkUpdateDescriptorSets" => Some(update_descriptor_sets as *const c_void),
+        "vkWaitForFences" => Some(wait_for_fences as *const c_void),
+        _ => None,
+    }
+}
@@ -0,0 +1,813 @@
+//! Handle leak tracking for `Device` and `Instance`.
+//!
+//! `TrackingDevice` wraps a device and records every handle returned by the `create_*` and
+//! `allocate_*` functions of `DeviceV1_0` and `DeviceV1_1`. Handles are removed again by the
+//! matching `destroy_*`/`free_*` call. Whatever is left when `destroy_device` is called is
+//! reported as a `LeakReport`, grouped by `vk::ObjectType`.
+//!
+//! ```no_run
+//! # use ash::extensions::ext::DebugUtils;
+//! # use ash::tracking::TrackingDevice;
+//! # use ash::version::DeviceV1_0;
+//! # use ash::vk;
+//! # unsafe fn test(device: ash::Device, debug_utils: DebugUtils, info: &vk::ImageViewCreateInfo) {
+//! let mut device = TrackingDevice::new(device);
+//! device.set_debug_utils(debug_utils);
+//! let view = device.create_image_view(info, None).unwrap();
+//! // Shown in the leak report, and passed on to `VK_EXT_debug_utils`
+//! device.set_object_name(view, "swapchain view 0").unwrap();
+//! let report = device.destroy_device_and_report(None);
+//! assert_eq!(report.count(vk::ObjectType::IMAGE_VIEW), 1);
+//! # }
+//! ```
+//!
+//! Nothing is printed when handles leak. Either pass a callback to `set_leak_callback`, which
+//! is called by `destroy_device`/`destroy_instance`, or use `destroy_device_and_report`/
+//! `destroy_instance_and_report`, which return the report.
+//!
+//! Handles created through extension loaders are not seen by the `DeviceV1_0` and
+//! `InstanceV1_0` implementations. Create and destroy them with the helpers of the same name
+//! on `TrackingDevice` and `TrackingInstance`, which take the loader as their first argument,
+//! or use `track`/`untrack` for everything else.
+//!
+//! With the `backtraces` feature, `with_backtraces` also records where each handle was
+//! created. The feature requires Rust 1.65.
+use device::Device;
+use extensions::ext::{DebugReport, DebugUtils};
+use extensions::khr::{
+    AndroidSurface, DisplaySwapchain, Surface, Swapchain, WaylandSurface, Win32Surface, XcbSurface,
+    XlibSurface,
+};
+use extensions::mvk::{IOSSurface, MacOSSurface};
+use instance::Instance;
+use prelude::*;
+#[cfg(feature = "backtraces")]
+use std::backtrace::Backtrace;
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use version::{DeviceV1_0, DeviceV1_1, InstanceV1_0, InstanceV1_1};
+use vk;
+use vk::Handle;
+
+struct TrackedObject {
+    parent: Option<u64>,
+    name: Option<String>,
+    #[cfg(feature = "backtraces")]
+    backtrace: Option<Backtrace>,
+}
+
+impl TrackedObject {
+    #[cfg(feature = "backtraces")]
+    fn backtrace(&self) -> Option<String> {
+        self.backtrace.as_ref().map(|bt| bt.to_string())
+    }
+
+    #[cfg(not(feature = "backtraces"))]
+    fn backtrace(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Records live handles. Shared between a `TrackingInstance` and the `TrackingDevice`s that
+/// were created from it.
+pub struct HandleTracker {
+    objects: Mutex<HashMap<(vk::ObjectType, u64), TrackedObject>>,
+    capture_backtraces: bool,
+    leak_callback: Mutex<Option<Arc<dyn Fn(&LeakReport) + Send + Sync>>>,
+}
+
+impl HandleTracker {
+    pub fn new() -> Self {
+        HandleTracker {
+            objects: Mutex::new(HashMap::new()),
+            capture_backtraces: false,
+            leak_callback: Mutex::new(None),
+        }
+    }
+
+    /// Like `new`, but also records where each handle was created.
+    #[cfg(feature = "backtraces")]
+    pub fn with_backtraces() -> Self {
+        HandleTracker {
+            capture_backtraces: true,
+            ..HandleTracker::new()
+        }
+    }
+
+    /// Sets the function that is called with the leaked objects when the parent object is
+    /// destroyed. Without a callback leaks are not reported. Devices that are created from a
+    /// `TrackingInstance` afterwards share the callback of its tracker.
+    pub fn set_leak_callback<F: Fn(&LeakReport) + Send + Sync + 'static>(&self, f: F) {
+        *self.leak_callback.lock().unwrap() = Some(Arc::new(f));
+    }
+
+    pub fn track<H: Handle>(&self, handle: H) {
+        self.insert(H::TYPE, handle.as_raw(), None);
+    }
+
+    /// Tracks a handle that is implicitly freed together with `parent`, such as a command
+    /// buffer that belongs to a command pool.
+    pub fn track_child<H: Handle, P: Handle>(&self, handle: H, parent: P) {
+        self.insert(H::TYPE, handle.as_raw(), Some(parent.as_raw()));
+    }
+
+    /// Returns false if the handle was not tracked.
+    pub fn untrack<H: Handle>(&self, handle: H) -> bool {
+        let raw = handle.as_raw();
+        raw == 0
+            || self
+                .objects
+                .lock()
+                .unwrap()
+                .remove(&(H::TYPE, raw))
+                .is_some()
+    }
+
+    /// Untracks all handles of type `H` that were tracked with `parent`.
+    pub fn untrack_children<H: Handle, P: Handle>(&self, parent: P) {
+        let parent = Some(parent.as_raw());
+        self.objects
+            .lock()
+            .unwrap()
+            .retain(|&(ty, _), object| ty != H::TYPE || object.parent != parent);
+    }
+
+    /// Attaches a debug name to a tracked handle. The name is shown in the `LeakReport`.
+    pub fn set_object_name<H: Handle>(&self, handle: H, name: &str) {
+        if let Some(object) = self
+            .objects
+            .lock()
+            .unwrap()
+            .get_mut(&(H::TYPE, handle.as_raw()))
+        {
+            object.name = Some(name.to_owned());
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.objects.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns all handles that are currently alive.
+    pub fn leak_report(&self) -> LeakReport {
+        let objects = self.objects.lock().unwrap();
+        let mut groups: Vec<(vk::ObjectType, Vec<LeakedObject>)> = Vec::new();
+        for (&(object_type, handle), object) in objects.iter() {
+            let leaked = LeakedObject {
+                handle,
+                name: object.name.clone(),
+                backtrace: object.backtrace(),
+            };
+            match groups.iter().position(|&(ty, _)| ty == object_type) {
+                Some(index) => groups[index].1.push(leaked),
+                None => groups.push((object_type, vec![leaked])),
+            }
+        }
+        groups.sort_by_key(|&(ty, _)| ty);
+        for &mut (_, ref mut leaked) in &mut groups {
+            leaked.sort_by_key(|object| object.handle);
+        }
+        LeakReport { groups }
+    }
+
+    fn insert(&self, object_type: vk::ObjectType, raw: u64, parent: Option<u64>) {
+        self.objects.lock().unwrap().insert(
+            (object_type, raw),
+            TrackedObject {
+                parent,
+                name: None,
+                #[cfg(feature = "backtraces")]
+                backtrace: if self.capture_backtraces {
+                    Some(Backtrace::force_capture())
+                } else {
+                    None
+                },
+            },
+        );
+    }
+
+    /// Passes the leaked objects to the leak callback, if there are any, and returns them.
+    fn report_leaks(&self) -> LeakReport {
+        let report = self.leak_report();
+        if !report.is_empty() {
+            // Not called under the lock, so that the callback can replace itself
+            let callback = self.leak_callback.lock().unwrap().clone();
+            if let Some(callback) = callback {
+                callback(&report);
+            }
+        }
+        report
+    }
+}
+
+impl Default for HandleTracker {
+    fn default() -> Self {
+        HandleTracker::new()
+    }
+}
+
+impl fmt::Debug for HandleTracker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HandleTracker")
+            .field("len", &self.len())
+            .field("capture_backtraces", &self.capture_backtraces)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeakedObject {
+    pub handle: u64,
+    pub name: Option<String>,
+    pub backtrace: Option<String>,
+}
+
+/// Handles that were still alive, grouped by object type.
+#[derive(Debug, Clone)]
+pub struct LeakReport {
+    pub groups: Vec<(vk::ObjectType, Vec<LeakedObject>)>,
+}
+
+impl LeakReport {
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Total number of leaked handles.
+    pub fn len(&self) -> usize {
+        self.groups
+            .iter()
+            .map(|&(_, ref objects)| objects.len())
+            .sum()
+    }
+
+    pub fn count(&self, object_type: vk::ObjectType) -> usize {
+        self.groups
+            .iter()
+            .find(|&&(ty, _)| ty == object_type)
+            .map(|&(_, ref objects)| objects.len())
+            .unwrap_or(0)
+    }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} leaked Vulkan object(s)", self.len())?;
+        for &(object_type, ref objects) in &self.groups {
+            writeln!(f, "{}: {}", object_type, objects.len())?;
+            for object in objects {
+                write!(f, "    0x{:x}", object.handle)?;
+                if let Some(ref name) = object.name {
+                    write!(f, " {:?}", name)?;
+                }
+                writeln!(f)?;
+                if let Some(ref backtrace) = object.backtrace {
+                    for line in backtrace.lines() {
+                        writeln!(f, "        {}", line)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A device that tracks the handles created through it. See the module documentation.
+pub struct TrackingDevice<D = Device> {
+    device: D,
+    tracker: Arc<HandleTracker>,
+    instance_tracker: Option<Arc<HandleTracker>>,
+    debug_utils: Option<DebugUtils>,
+}
+
+impl<D: DeviceV1_1> TrackingDevice<D> {
+    pub fn new(device: D) -> Self {
+        TrackingDevice {
+            device,
+            tracker: Arc::new(HandleTracker::new()),
+            instance_tracker: None,
+            debug_utils: None,
+        }
+    }
+
+    /// Like `new`, but also records where each handle was created.
+    #[cfg(feature = "backtraces")]
+    pub fn with_backtraces(device: D) -> Self {
+        TrackingDevice {
+            device,
+            tracker: Arc::new(HandleTracker::with_backtraces()),
+            instance_tracker: None,
+            debug_utils: None,
+        }
+    }
+
+    /// Passes the names given to `set_object_name` on to `VK_EXT_debug_utils`, so that they
+    /// show up in validation messages and graphics debuggers.
+    pub fn set_debug_utils(&mut self, debug_utils: DebugUtils) {
+        self.debug_utils = Some(debug_utils);
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    pub fn tracker(&self) -> &Arc<HandleTracker> {
+        &self.tracker
+    }
+
+    pub fn into_inner(self) -> D {
+        self.device
+    }
+
+    pub fn track<H: Handle>(&self, handle: H) {
+        self.tracker.track(handle)
+    }
+
+    pub fn untrack<H: Handle>(&self, handle: H) -> bool {
+        self.tracker.untrack(handle)
+    }
+
+    /// Attaches a debug name to a tracked handle, which is also set with
+    /// `vkSetDebugUtilsObjectNameEXT` if `set_debug_utils` was called. Names are cut at the
+    /// first nul byte.
+    pub unsafe fn set_object_name<H: Handle>(&self, handle: H, name: &str) -> VkResult<()> {
+        let raw = handle.as_raw();
+        self.tracker.set_object_name(H::from_raw(raw), name);
+        if let Some(ref debug_utils) = self.debug_utils {
+            let name = CString::new(name.split('\0').next().unwrap_or("")).unwrap();
+            let name_info = vk::DebugUtilsObjectNameInfoEXT::builder()
+                .object_type(H::TYPE)
+                .object_handle(raw)
+                .object_name(&name);
+            debug_utils.debug_utils_set_object_name(self.device.handle(), &name_info)?;
+        }
+        Ok(())
+    }
+
+    pub fn set_leak_callback<F: Fn(&LeakReport) + Send + Sync + 'static>(&self, f: F) {
+        self.tracker.set_leak_callback(f)
+    }
+
+    pub fn leak_report(&self) -> LeakReport {
+        self.tracker.leak_report()
+    }
+
+    /// Like `destroy_device`, but also returns the handles that were still alive.
+    pub unsafe fn destroy_device_and_report(
+        &self,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> LeakReport {
+        let report = self.tracker.report_leaks();
+        if let Some(ref instance_tracker) = self.instance_tracker {
+            instance_tracker.untrack(self.device.handle());
+        }
+        self.device.destroy_device(allocation_callbacks);
+        report
+    }
+
+    pub unsafe fn create_swapchain(
+        &self,
+        swapchain_loader: &Swapchain,
+        create_info: &vk::SwapchainCreateInfoKHR,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<vk::SwapchainKHR> {
+        let swapchain = swapchain_loader.create_swapchain(create_info, allocation_callbacks)?;
+        self.tracker.track(swapchain);
+        Ok(swapchain)
+    }
+
+    pub unsafe fn destroy_swapchain(
+        &self,
+        swapchain_loader: &Swapchain,
+        swapchain: vk::SwapchainKHR,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) {
+        self.tracker.untrack(swapchain);
+        swapchain_loader.destroy_swapchain(swapchain, allocation_callbacks);
+    }
+
+    pub unsafe fn create_shared_swapchains(
+        &self,
+        swapchain_loader: &DisplaySwapchain,
+        create_infos: &[vk::SwapchainCreateInfoKHR],
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<Vec<vk::SwapchainKHR>> {
+        let swapchains =
+            swapchain_loader.create_shared_swapchains(create_infos, allocation_callbacks)?;
+        for &swapchain in &swapchains {
+            self.tracker.track(swapchain);
+        }
+        Ok(swapchains)
+    }
+}
+
+macro_rules! tracked_extension_create {
+    ($($name: ident($loader: ident, $info: ty) -> $handle: ty;)*) => {
+        $(
+            pub unsafe fn $name(
+                &self,
+                loader: &$loader,
+                create_info: &$info,
+                allocation_callbacks: Option<&vk::AllocationCallbacks>,
+            ) -> VkResult<$handle> {
+                let handle = loader.$name(create_info, allocation_callbacks)?;
+                self.tracker.track(handle);
+                Ok(handle)
+            }
+        )*
+    };
+}
+
+macro_rules! tracked_extension_destroy {
+    ($($name: ident($loader: ident, $handle: ty);)*) => {
+        $(
+            pub unsafe fn $name(
+                &self,
+                loader: &$loader,
+                handle: $handle,
+                allocation_callbacks: Option<&vk::AllocationCallbacks>,
+            ) {
+                self.tracker.untrack(handle);
+                loader.$name(handle, allocation_callbacks);
+            }
+        )*
+    };
+}
+
+macro_rules! tracked_create {
+    ($($name: ident($info: ty) -> $ty: ident;)*) => {
+        $(
+            unsafe fn $name(
+                &self,
+                create_info: &$info,
+                allocation_callbacks: Option<&vk::AllocationCallbacks>,
+            ) -> VkResult<vk::$ty> {
+                let handle = self.device.$name(create_info, allocation_callbacks)?;
+                self.tracker.track(handle);
+                Ok(handle)
+            }
+        )*
+    };
+}
+
+macro_rules! tracked_destroy {
+    ($($name: ident(vk::$ty: ident);)*) => {
+        $(
+            unsafe fn $name(
+                &self,
+                handle: vk::$ty,
+                allocation_callbacks: Option<&vk::AllocationCallbacks>,
+            ) {
+                self.tracker.untrack(handle);
+                self.device.$name(handle, allocation_callbacks);
+            }
+        )*
+    };
+}
+
+impl<D: DeviceV1_1> DeviceV1_0 for TrackingDevice<D> {
+    fn handle(&self) -> vk::Device {
+        self.device.handle()
+    }
+
+    fn fp_v1_0(&self) -> &vk::DeviceFnV1_0 {
+        self.device.fp_v1_0()
+    }
+
+    /// Reports all handles that are still alive to the leak callback before the device is
+    /// destroyed.
+    unsafe fn destroy_device(&self, allocation_callbacks: Option<&vk::AllocationCallbacks>) {
+        self.destroy_device_and_report(allocation_callbacks);
+    }
+
+    tracked_create! {
+        allocate_memory(vk::MemoryAllocateInfo) -> DeviceMemory;
+        create_buffer(vk::BufferCreateInfo) -> Buffer;
+        create_buffer_view(vk::BufferViewCreateInfo) -> BufferView;
+        create_command_pool(vk::CommandPoolCreateInfo) -> CommandPool;
+        create_descriptor_pool(vk::DescriptorPoolCreateInfo) -> DescriptorPool;
+        create_descriptor_set_layout(vk::DescriptorSetLayoutCreateInfo) -> DescriptorSetLayout;
+        create_event(vk::EventCreateInfo) -> Event;
+        create_fence(vk::FenceCreateInfo) -> Fence;
+        create_framebuffer(vk::FramebufferCreateInfo) -> Framebuffer;
+        create_image(vk::ImageCreateInfo) -> Image;
+        create_image_view(vk::ImageViewCreateInfo) -> ImageView;
+        create_pipeline_cache(vk::PipelineCacheCreateInfo) -> PipelineCache;
+        create_pipeline_layout(vk::PipelineLayoutCreateInfo) -> PipelineLayout;
+        create_query_pool(vk::QueryPoolCreateInfo) -> QueryPool;
+        create_render_pass(vk::RenderPassCreateInfo) -> RenderPass;
+        create_sampler(vk::SamplerCreateInfo) -> Sampler;
+        create_semaphore(vk::SemaphoreCreateInfo) -> Semaphore;
+        create_shader_module(vk::ShaderModuleCreateInfo) -> ShaderModule;
+    }
+
+    tracked_destroy! {
+        free_memory(vk::DeviceMemory);
+        destroy_buffer(vk::Buffer);
+        destroy_buffer_view(vk::BufferView);
+        destroy_descriptor_set_layout(vk::DescriptorSetLayout);
+        destroy_event(vk::Event);
+        destroy_fence(vk::Fence);
+        destroy_framebuffer(vk::Framebuffer);
+        destroy_image(vk::Image);
+        destroy_image_view(vk::ImageView);
+        destroy_pipeline(vk::Pipeline);
+        destroy_pipeline_cache(vk::PipelineCache);
+        destroy_pipeline_layout(vk::PipelineLayout);
+        destroy_query_pool(vk::QueryPool);
+        destroy_render_pass(vk::RenderPass);
+        destroy_sampler(vk::Sampler);
+        destroy_semaphore(vk::Semaphore);
+        destroy_shader_module(vk::ShaderModule);
+    }
+
+    unsafe fn create_graphics_pipelines(
+        &self,
+        pipeline_cache: vk::PipelineCache,
+        create_infos: &[vk::GraphicsPipelineCreateInfo],
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
+        let result = self.device.create_graphics_pipelines(
+            pipeline_cache,
+            create_infos,
+            allocation_callbacks,
+        );
+        self.track_pipelines(&result);
+        result
+    }
+
+    unsafe fn create_compute_pipelines(
+        &self,
+        pipeline_cache: vk::PipelineCache,
+        create_infos: &[vk::ComputePipelineCreateInfo],
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
+        let result = self.device.create_compute_pipelines(
+            pipeline_cache,
+            create_infos,
+            allocation_callbacks,
+        );
+        self.track_pipelines(&result);
+        result
+    }
+
+    unsafe fn allocate_command_buffers(
+        &self,
+        create_info: &vk::CommandBufferAllocateInfo,
+    ) -> VkResult<Vec<vk::CommandBuffer>> {
+        let command_buffers = self.device.allocate_command_buffers(create_info)?;
+        for &command_buffer in &command_buffers {
+            self.tracker
+                .track_child(command_buffer, create_info.command_pool);
+        }
+        Ok(command_buffers)
+    }
+
+    unsafe fn free_command_buffers(
+        &self,
+        command_pool: vk::CommandPool,
+        command_buffers: &[vk::CommandBuffer],
+    ) {
+        for &command_buffer in command_buffers {
+            self.tracker.untrack(command_buffer);
+        }
+        self.device
+            .free_command_buffers(command_pool, command_buffers);
+    }
+
+    unsafe fn destroy_command_pool(
+        &self,
+        pool: vk::CommandPool,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) {
+        self.tracker.untrack_children::<vk::CommandBuffer, _>(pool);
+        self.tracker.untrack(pool);
+        self.device.destroy_command_pool(pool, allocation_callbacks);
+    }
+
+    unsafe fn allocate_descriptor_sets(
+        &self,
+        create_info: &vk::DescriptorSetAllocateInfo,
+    ) -> VkResult<Vec<vk::DescriptorSet>> {
+        let descriptor_sets = self.device.allocate_descriptor_sets(create_info)?;
+        for &descriptor_set in &descriptor_sets {
+            self.tracker
+                .track_child(descriptor_set, create_info.descriptor_pool);
+        }
+        Ok(descriptor_sets)
+    }
+
+    unsafe fn free_descriptor_sets(
+        &self,
+        pool: vk::DescriptorPool,
+        descriptor_sets: &[vk::DescriptorSet],
+    ) {
+        for &descriptor_set in descriptor_sets {
+            self.tracker.untrack(descriptor_set);
+        }
+        self.device.free_descriptor_sets(pool, descriptor_sets);
+    }
+
+    unsafe fn reset_descriptor_pool(
+        &self,
+        pool: vk::DescriptorPool,
+        flags: vk::DescriptorPoolResetFlags,
+    ) -> VkResult<()> {
+        self.tracker.untrack_children::<vk::DescriptorSet, _>(pool);
+        self.device.reset_descriptor_pool(pool, flags)
+    }
+
+    unsafe fn destroy_descriptor_pool(
+        &self,
+        pool: vk::DescriptorPool,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) {
+        self.tracker.untrack_children::<vk::DescriptorSet, _>(pool);
+        self.tracker.untrack(pool);
+        self.device
+            .destroy_descriptor_pool(pool, allocation_callbacks);
+    }
+}
+
+impl<D: DeviceV1_1> DeviceV1_1 for TrackingDevice<D> {
+    fn fp_v1_1(&self) -> &vk::DeviceFnV1_1 {
+        self.device.fp_v1_1()
+    }
+
+    tracked_create! {
+        create_descriptor_update_template(vk::DescriptorUpdateTemplateCreateInfo) -> DescriptorUpdateTemplate;
+        create_sampler_ycbcr_conversion(vk::SamplerYcbcrConversionCreateInfo) -> SamplerYcbcrConversion;
+    }
+
+    tracked_destroy! {
+        destroy_descriptor_update_template(vk::DescriptorUpdateTemplate);
+        destroy_sampler_ycbcr_conversion(vk::SamplerYcbcrConversion);
+    }
+}
+
+impl<D: DeviceV1_1> TrackingDevice<D> {
+    fn track_pipelines(&self, result: &Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)>) {
+        let pipelines = match *result {
+            Ok(ref pipelines) | Err((ref pipelines, _)) => pipelines,
+        };
+        for &pipeline in pipelines {
+            if pipeline != vk::Pipeline::null() {
+                self.tracker.track(pipeline);
+            }
+        }
+    }
+}
+
+impl<D> fmt::Debug for TrackingDevice<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TrackingDevice")
+            .field("tracker", &self.tracker)
+            .finish()
+    }
+}
+
+/// An instance that tracks the devices created through it, and any handle passed to `track`
+/// such as surfaces and debug messengers. Devices created from it are `TrackingDevice`s.
+pub struct TrackingInstance<I = Instance> {
+    instance: I,
+    tracker: Arc<HandleTracker>,
+}
+
+impl<I: InstanceV1_0<Device = Device>> TrackingInstance<I> {
+    pub fn new(instance: I) -> Self {
+        TrackingInstance {
+            instance,
+            tracker: Arc::new(HandleTracker::new()),
+        }
+    }
+
+    /// Like `new`, but also records where each handle was created. Devices created from this
+    /// instance record backtraces as well.
+    #[cfg(feature = "backtraces")]
+    pub fn with_backtraces(instance: I) -> Self {
+        TrackingInstance {
+            instance,
+            tracker: Arc::new(HandleTracker::with_backtraces()),
+        }
+    }
+
+    pub fn instance(&self) -> &I {
+        &self.instance
+    }
+
+    pub fn tracker(&self) -> &Arc<HandleTracker> {
+        &self.tracker
+    }
+
+    pub fn into_inner(self) -> I {
+        self.instance
+    }
+
+    pub fn track<H: Handle>(&self, handle: H) {
+        self.tracker.track(handle)
+    }
+
+    pub fn untrack<H: Handle>(&self, handle: H) -> bool {
+        self.tracker.untrack(handle)
+    }
+
+    pub fn set_object_name<H: Handle>(&self, handle: H, name: &str) {
+        self.tracker.set_object_name(handle, name)
+    }
+
+    pub fn set_leak_callback<F: Fn(&LeakReport) + Send + Sync + 'static>(&self, f: F) {
+        self.tracker.set_leak_callback(f)
+    }
+
+    pub fn leak_report(&self) -> LeakReport {
+        self.tracker.leak_report()
+    }
+
+    /// Like `destroy_instance`, but also returns the handles that were still alive.
+    pub unsafe fn destroy_instance_and_report(
+        &self,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> LeakReport {
+        let report = self.tracker.report_leaks();
+        self.instance.destroy_instance(allocation_callbacks);
+        report
+    }
+
+    tracked_extension_create! {
+        create_android_surface(AndroidSurface, vk::AndroidSurfaceCreateInfoKHR) -> vk::SurfaceKHR;
+        create_wayland_surface(WaylandSurface, vk::WaylandSurfaceCreateInfoKHR) -> vk::SurfaceKHR;
+        create_win32_surface(Win32Surface, vk::Win32SurfaceCreateInfoKHR) -> vk::SurfaceKHR;
+        create_xcb_surface(XcbSurface, vk::XcbSurfaceCreateInfoKHR) -> vk::SurfaceKHR;
+        create_xlib_surface(XlibSurface, vk::XlibSurfaceCreateInfoKHR) -> vk::SurfaceKHR;
+        create_ios_surface_mvk(IOSSurface, vk::IOSSurfaceCreateInfoMVK) -> vk::SurfaceKHR;
+        create_mac_os_surface_mvk(MacOSSurface, vk::MacOSSurfaceCreateInfoMVK) -> vk::SurfaceKHR;
+        create_debug_report_callback(DebugReport, vk::DebugReportCallbackCreateInfoEXT) -> vk::DebugReportCallbackEXT;
+        create_debug_utils_messenger(DebugUtils, vk::DebugUtilsMessengerCreateInfoEXT) -> vk::DebugUtilsMessengerEXT;
+    }
+
+    tracked_extension_destroy! {
+        destroy_surface(Surface, vk::SurfaceKHR);
+        destroy_debug_report_callback(DebugReport, vk::DebugReportCallbackEXT);
+        destroy_debug_utils_messenger(DebugUtils, vk::DebugUtilsMessengerEXT);
+    }
+}
+
+impl<I: InstanceV1_0<Device = Device>> InstanceV1_0 for TrackingInstance<I> {
+    type Device = TrackingDevice<Device>;
+
+    fn handle(&self) -> vk::Instance {
+        self.instance.handle()
+    }
+
+    fn fp_v1_0(&self) -> &vk::InstanceFnV1_0 {
+        self.instance.fp_v1_0()
+    }
+
+    unsafe fn create_device(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        create_info: &vk::DeviceCreateInfo,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<Self::Device, vk::Result> {
+        let device =
+            self.instance
+                .create_device(physical_device, create_info, allocation_callbacks)?;
+        self.tracker.track(device.handle());
+        Ok(TrackingDevice {
+            device,
+            tracker: Arc::new(HandleTracker {
+                capture_backtraces: self.tracker.capture_backtraces,
+                leak_callback: Mutex::new(self.tracker.leak_callback.lock().unwrap().clone()),
+                ..HandleTracker::new()
+            }),
+            instance_tracker: Some(self.tracker.clone()),
+            debug_utils: None,
+        })
+    }
+
+    /// Reports all devices and tracked handles that are still alive to the leak callback
+    /// before the instance is destroyed.
+    unsafe fn destroy_instance(&self, allocation_callbacks: Option<&vk::AllocationCallbacks>) {
+        self.destroy_instance_and_report(allocation_callbacks);
+    }
+}
+
+impl<I: InstanceV1_1<Device = Device>> InstanceV1_1 for TrackingInstance<I> {
+    fn fp_v1_1(&self) -> &vk::InstanceFnV1_1 {
+        self.instance.fp_v1_1()
+    }
+}
+
+impl<I> fmt::Debug for TrackingInstance<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TrackingInstance")
+            .field("tracker", &self.tracker)
+            .finish()
+    }
+}
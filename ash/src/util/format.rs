@@ -0,0 +1,210 @@
+use instance::Instance;
+use prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use version::{InstanceV1_0, InstanceV1_1};
+use vk;
+
+/// Depth formats in order of preference: precision first, then size.
+const DEPTH_FORMATS: [vk::Format; 4] = [
+    vk::Format::D32_SFLOAT,
+    vk::Format::D32_SFLOAT_S8_UINT,
+    vk::Format::D24_UNORM_S8_UINT,
+    vk::Format::D16_UNORM,
+];
+
+/// The parameters of `get_physical_device_image_format_properties`.
+type ImageFormatKey = (
+    vk::Format,
+    vk::ImageType,
+    vk::ImageTiling,
+    vk::ImageUsageFlags,
+    vk::ImageCreateFlags,
+);
+
+/// Answers which formats a physical device supports, and caches the answers of
+/// `get_physical_device_format_properties` and `get_physical_device_image_format_properties`
+/// so that repeated queries do not call into the driver.
+///
+/// ```no_run
+/// # use ash::util::FormatTable;
+/// # use ash::vk;
+/// # fn test(instance: ash::Instance, physical_device: vk::PhysicalDevice) {
+/// let formats = unsafe { FormatTable::new(instance, physical_device) };
+/// let depth_format = formats.depth_format(false).expect("No depth format");
+/// let hdr_format = formats.find_supported(
+///     &[vk::Format::B10G11R11_UFLOAT_PACK32, vk::Format::R16G16B16A16_SFLOAT],
+///     vk::ImageTiling::OPTIMAL,
+///     vk::FormatFeatureFlags::COLOR_ATTACHMENT | vk::FormatFeatureFlags::SAMPLED_IMAGE,
+/// );
+/// # }
+/// ```
+pub struct FormatTable<I: InstanceV1_0 = Instance> {
+    instance: I,
+    physical_device: vk::PhysicalDevice,
+    formats: Mutex<HashMap<vk::Format, vk::FormatProperties>>,
+    image_formats: Mutex<HashMap<ImageFormatKey, VkResult<vk::ImageFormatProperties>>>,
+}
+
+impl<I: InstanceV1_0> FormatTable<I> {
+    /// `physical_device` must have been enumerated from `instance`, which must not be destroyed
+    /// while the table is used. The queries of the other methods rely on it.
+    pub unsafe fn new(instance: I, physical_device: vk::PhysicalDevice) -> FormatTable<I> {
+        FormatTable {
+            instance,
+            physical_device,
+            formats: Mutex::new(HashMap::new()),
+            image_formats: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn instance(&self) -> &I {
+        &self.instance
+    }
+
+    pub fn physical_device(&self) -> vk::PhysicalDevice {
+        self.physical_device
+    }
+
+    pub fn format_properties(&self, format: vk::Format) -> vk::FormatProperties {
+        let mut formats = self.formats.lock().unwrap();
+        *formats.entry(format).or_insert_with(|| unsafe {
+            self.instance
+                .get_physical_device_format_properties(self.physical_device, format)
+        })
+    }
+
+    /// The features of `format` for images with `tiling`.
+    pub fn features(&self, format: vk::Format, tiling: vk::ImageTiling) -> vk::FormatFeatureFlags {
+        let properties = self.format_properties(format);
+        match tiling {
+            vk::ImageTiling::LINEAR => properties.linear_tiling_features,
+            vk::ImageTiling::OPTIMAL => properties.optimal_tiling_features,
+            _ => vk::FormatFeatureFlags::empty(),
+        }
+    }
+
+    /// Whether images of `format` with `tiling` support all of `features`.
+    pub fn supports(
+        &self,
+        format: vk::Format,
+        tiling: vk::ImageTiling,
+        features: vk::FormatFeatureFlags,
+    ) -> bool {
+        self.features(format, tiling).contains(features)
+    }
+
+    /// Whether buffers of `format` support all of `features`, e.g. `VERTEX_BUFFER`.
+    pub fn supports_buffer(&self, format: vk::Format, features: vk::FormatFeatureFlags) -> bool {
+        self.format_properties(format)
+            .buffer_features
+            .contains(features)
+    }
+
+    /// The first of `candidates` that supports all of `features` with `tiling`.
+    pub fn find_supported(
+        &self,
+        candidates: &[vk::Format],
+        tiling: vk::ImageTiling,
+        features: vk::FormatFeatureFlags,
+    ) -> Option<vk::Format> {
+        candidates
+            .iter()
+            .cloned()
+            .find(|&format| self.supports(format, tiling, features))
+    }
+
+    /// An optimally tiled depth attachment format, with a stencil aspect if `stencil` is set.
+    /// Prefers 32 bit floats, then 24 and then 16 bit depth.
+    pub fn depth_format(&self, stencil: bool) -> Option<vk::Format> {
+        let candidates: Vec<vk::Format> = DEPTH_FORMATS
+            .iter()
+            .cloned()
+            .filter(|&format| !stencil || format.info().map_or(false, has_stencil))
+            .collect();
+        self.find_supported(
+            &candidates,
+            vk::ImageTiling::OPTIMAL,
+            vk::FormatFeatureFlags::DEPTH_STENCIL_ATTACHMENT,
+        )
+    }
+
+    /// The limits of images that are created like `create_info`. Fails with
+    /// `ERROR_FORMAT_NOT_SUPPORTED` if the combination of format, type, tiling, usage and
+    /// flags is not supported at all.
+    pub fn image_format_properties(
+        &self,
+        create_info: &vk::ImageCreateInfo,
+    ) -> VkResult<vk::ImageFormatProperties> {
+        let key = (
+            create_info.format,
+            create_info.image_type,
+            create_info.tiling,
+            create_info.usage,
+            create_info.flags,
+        );
+        let mut image_formats = self.image_formats.lock().unwrap();
+        *image_formats.entry(key).or_insert_with(|| unsafe {
+            self.instance.get_physical_device_image_format_properties(
+                self.physical_device,
+                create_info.format,
+                create_info.image_type,
+                create_info.tiling,
+                create_info.usage,
+                create_info.flags,
+            )
+        })
+    }
+
+    /// Whether an image can be created with `create_info`: the format supports it, and its
+    /// extent, mip levels, array layers and samples are within the limits of
+    /// `image_format_properties`.
+    pub fn supports_image(&self, create_info: &vk::ImageCreateInfo) -> VkResult<bool> {
+        let properties = match self.image_format_properties(create_info) {
+            Ok(properties) => properties,
+            Err(vk::Result::ERROR_FORMAT_NOT_SUPPORTED) => return Ok(false),
+            Err(err) => return Err(err),
+        };
+        let extent = create_info.extent;
+        let max_extent = properties.max_extent;
+        Ok(extent.width <= max_extent.width
+            && extent.height <= max_extent.height
+            && extent.depth <= max_extent.depth
+            && create_info.mip_levels <= properties.max_mip_levels
+            && create_info.array_layers <= properties.max_array_layers
+            && properties.sample_counts.contains(create_info.samples))
+    }
+}
+
+impl<I: InstanceV1_1> FormatTable<I> {
+    /// Queries `get_physical_device_image_format_properties2`, e.g. with
+    /// `SamplerYcbcrConversionImageFormatProperties` chained to `out`. These queries are not
+    /// cached, because the chains may differ. The pNext chains of `format_info` and `out` must
+    /// be valid.
+    pub unsafe fn image_format_properties2(
+        &self,
+        format_info: &vk::PhysicalDeviceImageFormatInfo2,
+        out: &mut vk::ImageFormatProperties2,
+    ) -> VkResult<()> {
+        self.instance.get_physical_device_image_format_properties2(
+            self.physical_device,
+            format_info,
+            out,
+        )
+    }
+}
+
+impl<I: InstanceV1_0> fmt::Debug for FormatTable<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FormatTable")
+            .field("physical_device", &self.physical_device)
+            .field("formats", &self.formats.lock().unwrap().len())
+            .field("image_formats", &self.image_formats.lock().unwrap().len())
+            .finish()
+    }
+}
+
+fn has_stencil(info: &vk::formats::FormatInfo) -> bool {
+    info.aspect_mask.contains(vk::ImageAspectFlags::STENCIL)
+}
@@ -0,0 +1,252 @@
+use device::Device;
+use prelude::*;
+use std::mem;
+use std::os::raw::c_void;
+use std::slice;
+use version::DeviceV1_0;
+use vk;
+
+/// A mapped range of a `vk::DeviceMemory` that knows whether its memory type is
+/// `HOST_COHERENT`.
+///
+/// For memory that is not coherent, `map` invalidates the range so that device writes become
+/// visible, and `commit` flushes the bytes that were written through the range. Both round the
+/// flushed and invalidated ranges to `non_coherent_atom_size`. Dropping the range commits it
+/// and unmaps the memory. Errors while committing on drop are ignored, so call `commit` to
+/// handle them.
+///
+/// ```no_run
+/// # use ash::util::MappedRange;
+/// # use ash::vk;
+/// # unsafe fn test(device: &ash::Device, memory: vk::DeviceMemory, memory_type: vk::MemoryType, limits: &vk::PhysicalDeviceLimits) -> ash::prelude::VkResult<()> {
+/// let mut mapped = MappedRange::map(
+///     device,
+///     memory,
+///     1024,
+///     memory_type.property_flags,
+///     limits.non_coherent_atom_size,
+///     256,
+///     vk::WHOLE_SIZE,
+/// )?;
+/// mapped.write(16, &[1.0f32, 2.0, 3.0]);
+/// // Flushes 256..320 if the memory is not coherent and the atom size is 64
+/// mapped.commit()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct MappedRange<'a, D: 'a + DeviceV1_0 = Device> {
+    device: &'a D,
+    memory: vk::DeviceMemory,
+    memory_size: vk::DeviceSize,
+    coherent: bool,
+    non_coherent_atom_size: vk::DeviceSize,
+    offset: vk::DeviceSize,
+    size: usize,
+    ptr: *mut u8,
+    /// Bytes written since the last commit, relative to `offset`.
+    dirty: Option<(usize, usize)>,
+}
+
+impl<'a, D: DeviceV1_0> MappedRange<'a, D> {
+    /// Maps `size` bytes of `memory` from `offset`, or the rest of the memory if `size` is
+    /// `vk::WHOLE_SIZE`. `memory_size` is the `allocation_size` the memory was allocated with
+    /// and `memory_flags` are the property flags of its memory type. The memory must not be
+    /// mapped already.
+    pub unsafe fn map(
+        device: &'a D,
+        memory: vk::DeviceMemory,
+        memory_size: vk::DeviceSize,
+        memory_flags: vk::MemoryPropertyFlags,
+        non_coherent_atom_size: vk::DeviceSize,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+    ) -> VkResult<MappedRange<'a, D>> {
+        assert!(
+            memory_flags.contains(vk::MemoryPropertyFlags::HOST_VISIBLE),
+            "Memory is not host visible"
+        );
+        let size = if size == vk::WHOLE_SIZE {
+            memory_size - offset
+        } else {
+            size
+        };
+        assert!(offset + size <= memory_size, "Range exceeds the memory");
+        let coherent = memory_flags.contains(vk::MemoryPropertyFlags::HOST_COHERENT);
+        let atom_size = if coherent {
+            1
+        } else {
+            non_coherent_atom_size.max(1)
+        };
+        // Map whole atoms, so that the flushed ranges lie within the mapping
+        let (map_offset, map_size) = aligned_range(offset, offset + size, atom_size, memory_size);
+        let base = device.map_memory(memory, map_offset, map_size, vk::MemoryMapFlags::empty())?;
+        let mapped = MappedRange {
+            device,
+            memory,
+            memory_size,
+            coherent,
+            non_coherent_atom_size: atom_size,
+            offset,
+            size: size as usize,
+            ptr: (base as *mut u8).offset((offset - map_offset) as isize),
+            dirty: None,
+        };
+        if let Err(err) = mapped.invalidate() {
+            // Unmaps the memory
+            drop(mapped);
+            return Err(err);
+        }
+        Ok(mapped)
+    }
+
+    pub fn memory(&self) -> vk::DeviceMemory {
+        self.memory
+    }
+
+    /// The offset of the range in the memory.
+    pub fn offset(&self) -> vk::DeviceSize {
+        self.offset
+    }
+
+    /// The size of the range in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_coherent(&self) -> bool {
+        self.coherent
+    }
+
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.ptr as *mut c_void
+    }
+
+    /// The range as a slice of `T`. Trailing bytes that do not fill a `T` are left out.
+    ///
+    /// Panics if the range is not aligned for `T`.
+    pub fn as_slice<T: Copy>(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.typed_ptr::<T>(), self.size / mem::size_of::<T>()) }
+    }
+
+    /// Like `as_slice`, but marks the whole range as written.
+    pub fn as_mut_slice<T: Copy>(&mut self) -> &mut [T] {
+        let len = self.size / mem::size_of::<T>();
+        self.mark_written(0, len * mem::size_of::<T>());
+        unsafe { slice::from_raw_parts_mut(self.typed_ptr::<T>(), len) }
+    }
+
+    /// Copies `data` to `offset` bytes into the range and marks those bytes as written.
+    ///
+    /// Panics if `data` does not fit into the range.
+    pub fn write<T: Copy>(&mut self, offset: usize, data: &[T]) {
+        let len = mem::size_of_val(data);
+        assert!(offset + len <= self.size, "Write exceeds the mapped range");
+        unsafe {
+            let dst = self.ptr.add(offset);
+            dst.copy_from_nonoverlapping(data.as_ptr() as *const u8, len);
+        }
+        self.mark_written(offset, offset + len);
+    }
+
+    /// Copies `offset` bytes into the range to `data`.
+    ///
+    /// Panics if the range does not hold `data.len()` values from `offset`.
+    pub fn read<T: Copy>(&self, offset: usize, data: &mut [T]) {
+        let len = mem::size_of_val(data);
+        assert!(offset + len <= self.size, "Read exceeds the mapped range");
+        unsafe {
+            let src = self.ptr.add(offset);
+            src.copy_to_nonoverlapping(data.as_mut_ptr() as *mut u8, len);
+        }
+    }
+
+    /// Marks `start..end`, in bytes from the start of the range, as written through
+    /// `as_ptr`.
+    pub fn mark_written(&mut self, start: usize, end: usize) {
+        assert!(start <= end && end <= self.size);
+        if start == end {
+            return;
+        }
+        self.dirty = Some(match self.dirty {
+            Some((dirty_start, dirty_end)) => (dirty_start.min(start), dirty_end.max(end)),
+            None => (start, end),
+        });
+    }
+
+    /// Flushes the bytes that were written since the last commit, unless the memory is
+    /// coherent.
+    pub fn commit(&mut self) -> VkResult<()> {
+        let (start, end) = match self.dirty.take() {
+            Some(dirty) => dirty,
+            None => return Ok(()),
+        };
+        if self.coherent {
+            return Ok(());
+        }
+        let range = self.memory_range(start, end);
+        let result = unsafe { self.device.flush_mapped_memory_ranges(&[range]) };
+        if result.is_err() {
+            self.dirty = Some((start, end));
+        }
+        result
+    }
+
+    /// Makes device writes to the whole range visible to the host, unless the memory is
+    /// coherent.
+    pub fn invalidate(&self) -> VkResult<()> {
+        if self.coherent {
+            return Ok(());
+        }
+        let range = self.memory_range(0, self.size);
+        unsafe { self.device.invalidate_mapped_memory_ranges(&[range]) }
+    }
+
+    fn memory_range(&self, start: usize, end: usize) -> vk::MappedMemoryRange {
+        let (offset, size) = aligned_range(
+            self.offset + start as vk::DeviceSize,
+            self.offset + end as vk::DeviceSize,
+            self.non_coherent_atom_size,
+            self.memory_size,
+        );
+        vk::MappedMemoryRange {
+            memory: self.memory,
+            offset,
+            size,
+            ..Default::default()
+        }
+    }
+
+    fn typed_ptr<T>(&self) -> *mut T {
+        assert_eq!(
+            self.ptr as usize % mem::align_of::<T>(),
+            0,
+            "Mapped range is not aligned for the type"
+        );
+        self.ptr as *mut T
+    }
+}
+
+impl<'a, D: DeviceV1_0> Drop for MappedRange<'a, D> {
+    fn drop(&mut self) {
+        let _ = self.commit();
+        unsafe {
+            self.device.unmap_memory(self.memory);
+        }
+    }
+}
+
+/// Rounds `start..end` out to multiples of `atom_size`. The end is clamped to the end of the
+/// memory, which is allowed to be unaligned.
+fn aligned_range(
+    start: vk::DeviceSize,
+    end: vk::DeviceSize,
+    atom_size: vk::DeviceSize,
+    memory_size: vk::DeviceSize,
+) -> (vk::DeviceSize, vk::DeviceSize) {
+    let start = start / atom_size * atom_size;
+    let end = end
+        .checked_add(atom_size - 1)
+        .map_or(memory_size, |end| end / atom_size * atom_size)
+        .min(memory_size);
+    (start, end - start)
+}
@@ -0,0 +1,360 @@
+use std::error::Error;
+use std::fmt;
+use std::iter::Iterator;
+use std::marker::PhantomData;
+use std::mem::{align_of, size_of};
+use std::ops::Range;
+use std::os::raw::c_void;
+use std::{io, slice};
+use vk;
+
+mod format;
+mod mapped;
+mod readback;
+mod ring;
+mod upload;
+
+pub use self::format::FormatTable;
+pub use self::mapped::MappedRange;
+pub use self::readback::{depth_stencil_texel_size, read_linear_image, Readback, ReadbackRegion};
+pub use self::ring::{RingBuffer, RingBufferError};
+pub use self::upload::{ImageRegion, UploadBatch, UploadToken, Uploader};
+
+/// `Align` handles dynamic alignment. The is useful for dynamic uniform buffers where
+/// the alignment might be different. For example a 4x4 f32 matrix has a size of 64 bytes
+/// but the min alignment for a dynamic uniform buffer might be 256 bytes. A slice of `&[Mat4x4<f32>]`
+/// has a memory layout of `[[64 bytes], [64 bytes], [64 bytes]]`, but it might need to have a memory
+/// layout of `[[256 bytes], [256 bytes], [256 bytes]]`.
+/// `Align::copy_from_slice` will copy a slice of `&[T]` directly into the host memory without
+/// an additional allocation and with the correct alignment.
+///
+/// `Align::from_slice` checks the alignment and borrows the memory, e.g. of a `MappedRange`:
+///
+/// ```no_run
+/// # use ash::util::{Align, MappedRange};
+/// # fn test(mapped: &mut MappedRange, limits: &ash::vk::PhysicalDeviceLimits) {
+/// let alignment = limits.min_uniform_buffer_offset_alignment;
+/// let mut uniforms = Align::<[f32; 16]>::from_slice(mapped.as_mut_slice(), alignment).unwrap();
+/// uniforms.copy_from_slice(&[[0.0; 16]; 8]);
+/// let last = uniforms.get(uniforms.len() - 1);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Align<'a, T> {
+    ptr: *mut c_void,
+    elem_size: vk::DeviceSize,
+    len: usize,
+    _m: PhantomData<&'a mut T>,
+}
+
+#[derive(Debug)]
+pub struct AlignIter<'a, T: 'a> {
+    ptr: *mut u8,
+    elem_size: vk::DeviceSize,
+    remaining: usize,
+    _m: PhantomData<&'a mut T>,
+}
+
+/// Iterates over the elements of an `Align` by reference, see `Align::iter`.
+#[derive(Debug)]
+pub struct AlignRefIter<'a, T: 'a> {
+    ptr: *const u8,
+    elem_size: vk::DeviceSize,
+    remaining: usize,
+    _m: PhantomData<&'a T>,
+}
+
+/// Why memory can not be viewed as an `Align`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlignError {
+    /// The alignment is zero, or elements padded to it would not be aligned for `T`.
+    InvalidAlignment,
+    /// The pointer is not a multiple of the alignment or of the alignment of `T`.
+    MisalignedPointer,
+    /// The size is not a multiple of the alignment.
+    UnalignedSize,
+}
+
+impl fmt::Display for AlignError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match *self {
+            AlignError::InvalidAlignment => "alignment is invalid for the element type",
+            AlignError::MisalignedPointer => "pointer is not aligned",
+            AlignError::UnalignedSize => "size must be aligned",
+        };
+        f.write_str(message)
+    }
+}
+
+impl Error for AlignError {
+    fn description(&self) -> &str {
+        "AlignError"
+    }
+}
+
+impl<'a, T: Copy> Align<'a, T> {
+    /// Copies `slice` into the first `slice.len()` elements. Elements that do not fit are
+    /// left out.
+    pub fn copy_from_slice(&mut self, slice: &[T]) {
+        use std::slice::from_raw_parts_mut;
+        if self.elem_size == size_of::<T>() as u64 {
+            let len = slice.len().min(self.len);
+            unsafe {
+                let mapped_slice = from_raw_parts_mut(self.ptr as *mut T, len);
+                mapped_slice.copy_from_slice(&slice[..len]);
+            }
+        } else {
+            for (val, &src) in self.iter_mut().zip(slice) {
+                *val = src;
+            }
+        }
+    }
+
+    pub fn copy_to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+fn calc_padding(adr: vk::DeviceSize, align: vk::DeviceSize) -> vk::DeviceSize {
+    (align - adr % align) % align
+}
+
+fn elem_count(size: vk::DeviceSize, elem_size: vk::DeviceSize) -> usize {
+    size.checked_div(elem_size).unwrap_or(0) as usize
+}
+
+impl<'a, T> Align<'a, T> {
+    /// Panics if `size` is not a multiple of `alignment`. The pointer is not checked, use
+    /// `try_new` for that.
+    pub unsafe fn new(ptr: *mut c_void, alignment: vk::DeviceSize, size: vk::DeviceSize) -> Self {
+        let padding = calc_padding(size_of::<T>() as vk::DeviceSize, alignment);
+        let elem_size = size_of::<T>() as vk::DeviceSize + padding;
+        assert!(calc_padding(size, alignment) == 0, "size must be aligned");
+        Align {
+            ptr,
+            elem_size,
+            len: elem_count(size, elem_size),
+            _m: PhantomData,
+        }
+    }
+
+    /// Views `size` bytes from `ptr` as elements of `T` that are `alignment` apart. Fails if
+    /// `ptr` or `size` are not multiples of `alignment`, or if the elements would not be
+    /// aligned for `T`.
+    pub unsafe fn try_new(
+        ptr: *mut c_void,
+        alignment: vk::DeviceSize,
+        size: vk::DeviceSize,
+    ) -> Result<Self, AlignError> {
+        if alignment == 0 {
+            return Err(AlignError::InvalidAlignment);
+        }
+        let padding = calc_padding(size_of::<T>() as vk::DeviceSize, alignment);
+        let elem_size = size_of::<T>() as vk::DeviceSize + padding;
+        if elem_size % align_of::<T>() as vk::DeviceSize != 0 {
+            return Err(AlignError::InvalidAlignment);
+        }
+        let address = ptr as usize as vk::DeviceSize;
+        if address % alignment != 0 || address % align_of::<T>() as vk::DeviceSize != 0 {
+            return Err(AlignError::MisalignedPointer);
+        }
+        if calc_padding(size, alignment) != 0 {
+            return Err(AlignError::UnalignedSize);
+        }
+        Ok(Align {
+            ptr,
+            elem_size,
+            len: elem_count(size, elem_size),
+            _m: PhantomData,
+        })
+    }
+
+    /// Like `try_new`, for the memory of `bytes`, which stay borrowed while the view lives:
+    ///
+    /// ```compile_fail
+    /// # use ash::util::Align;
+    /// let mut bytes = [0u8; 16];
+    /// let mut align = Align::<u8>::from_slice(&mut bytes, 1).unwrap();
+    /// bytes[0] = 1;
+    /// align.copy_from_slice(&[2]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use ash::util::Align;
+    /// let align = {
+    ///     let mut bytes = [0u8; 16];
+    ///     Align::<u8>::from_slice(&mut bytes, 1).unwrap()
+    /// };
+    /// align.get(0);
+    /// ```
+    pub fn from_slice(bytes: &'a mut [u8], alignment: vk::DeviceSize) -> Result<Self, AlignError> {
+        unsafe {
+            Align::try_new(
+                bytes.as_mut_ptr() as *mut c_void,
+                alignment,
+                bytes.len() as vk::DeviceSize,
+            )
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The distance between two elements in bytes.
+    pub fn elem_size(&self) -> vk::DeviceSize {
+        self.elem_size
+    }
+
+    fn elem_ptr(&self, index: usize) -> *mut T {
+        unsafe { (self.ptr as *mut u8).add(index * self.elem_size as usize) as *mut T }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            unsafe { Some(&*self.elem_ptr(index)) }
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            unsafe { Some(&mut *self.elem_ptr(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// The elements in `range`, which also start at a multiple of the alignment.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn sub_range(&mut self, range: Range<usize>) -> Align<'_, T> {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "Range out of bounds"
+        );
+        Align {
+            ptr: self.elem_ptr(range.start) as *mut c_void,
+            elem_size: self.elem_size,
+            len: range.end - range.start,
+            _m: PhantomData,
+        }
+    }
+
+    pub fn iter(&self) -> AlignRefIter<'_, T> {
+        AlignRefIter {
+            ptr: self.ptr as *const u8,
+            elem_size: self.elem_size,
+            remaining: self.len,
+            _m: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> AlignIter<'_, T> {
+        AlignIter {
+            ptr: self.ptr as *mut u8,
+            elem_size: self.elem_size,
+            remaining: self.len,
+            _m: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Iterator for AlignIter<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        unsafe {
+            let ptr = self.ptr as *mut T;
+            self.ptr = self.ptr.add(self.elem_size as usize);
+            self.remaining -= 1;
+            Some(&mut *ptr)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: 'a> Iterator for AlignRefIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        unsafe {
+            let ptr = self.ptr as *const T;
+            self.ptr = self.ptr.add(self.elem_size as usize);
+            self.remaining -= 1;
+            Some(&*ptr)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Decode SPIR-V from bytes.
+///
+/// This function handles SPIR-V of arbitrary endianness gracefully, and returns correctly aligned
+/// storage.
+///
+/// # Examples
+/// ```no_run
+/// // Decode SPIR-V from a file
+/// let mut file = std::fs::File::open("/path/to/shader.spv").unwrap();
+/// let words = ash::util::read_spv(&mut file).unwrap();
+/// ```
+/// ```
+/// // Decode SPIR-V from memory
+/// const SPIRV: &[u8] = &[
+///     // ...
+/// #   0x03, 0x02, 0x23, 0x07,
+/// ];
+/// let words = ash::util::read_spv(&mut std::io::Cursor::new(&SPIRV[..])).unwrap();
+/// ```
+pub fn read_spv<R: io::Read + io::Seek>(x: &mut R) -> io::Result<Vec<u32>> {
+    let size = x.seek(io::SeekFrom::End(0))?;
+    if size % 4 != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "input length not divisible by 4",
+        ));
+    }
+    if size > usize::max_value() as u64 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "input too long"));
+    }
+    let words = (size / 4) as usize;
+    let mut result = Vec::<u32>::with_capacity(words);
+    x.seek(io::SeekFrom::Start(0))?;
+    unsafe {
+        x.read_exact(slice::from_raw_parts_mut(
+            result.as_mut_ptr() as *mut u8,
+            words * 4,
+        ))?;
+        result.set_len(words);
+    }
+    const MAGIC_NUMBER: u32 = 0x07230203;
+    if result.len() > 0 && result[0] == MAGIC_NUMBER.swap_bytes() {
+        for word in &mut result {
+            *word = word.swap_bytes();
+        }
+    }
+    if result.len() == 0 || result[0] != MAGIC_NUMBER {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "input missing SPIR-V magic number",
+        ));
+    }
+    Ok(result)
+}
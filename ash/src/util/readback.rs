@@ -0,0 +1,373 @@
+use super::upload::HostBuffer;
+use allocator::MemoryUsage;
+use device::Device;
+use prelude::*;
+use std::fmt;
+use std::os::raw::c_void;
+use std::ptr;
+use version::DeviceV1_0;
+use vk;
+
+/// The mip level and array layers of an image that `Readback::read_image` copies.
+#[derive(Clone, Copy, Debug)]
+pub struct ReadbackRegion {
+    /// A single aspect, e.g. `DEPTH` or `STENCIL` of a depth/stencil image.
+    pub aspect_mask: vk::ImageAspectFlags,
+    pub mip_level: u32,
+    pub base_array_layer: u32,
+    pub layer_count: u32,
+    /// The extent of the mip level.
+    pub extent: vk::Extent3D,
+    /// The size of a texel of the aspect in buffer copies, see `depth_stencil_texel_size`.
+    pub texel_size: u32,
+}
+
+impl ReadbackRegion {
+    fn len(&self) -> usize {
+        let extent = self.extent;
+        extent.width as usize
+            * extent.height as usize
+            * extent.depth as usize
+            * self.layer_count as usize
+            * self.texel_size as usize
+    }
+}
+
+/// The size of a texel of the depth or stencil aspect of `format` in copies between images
+/// and buffers, which differs from the size of the format. The depth of `D24_UNORM_S8_UINT` is
+/// copied as 4 bytes and its stencil as 1 byte, for example.
+pub fn depth_stencil_texel_size(format: vk::Format, aspect: vk::ImageAspectFlags) -> Option<u32> {
+    let (depth, stencil) = match format {
+        vk::Format::D16_UNORM => (Some(2), None),
+        vk::Format::X8_D24_UNORM_PACK32 | vk::Format::D32_SFLOAT => (Some(4), None),
+        vk::Format::S8_UINT => (None, Some(1)),
+        vk::Format::D16_UNORM_S8_UINT => (Some(2), Some(1)),
+        vk::Format::D24_UNORM_S8_UINT | vk::Format::D32_SFLOAT_S8_UINT => (Some(4), Some(1)),
+        _ => (None, None),
+    };
+    if aspect == vk::ImageAspectFlags::DEPTH {
+        depth
+    } else if aspect == vk::ImageAspectFlags::STENCIL {
+        stencil
+    } else {
+        None
+    }
+}
+
+/// Copies images and buffers back to the host and waits for the copy, e.g. for screenshots or
+/// to compare rendered images in tests.
+///
+/// The resources have to be owned by the queue family of the queue, and the work that writes
+/// them has to be submitted before.
+///
+/// ```no_run
+/// # use ash::util::{Readback, ReadbackRegion};
+/// # use ash::vk;
+/// # unsafe fn test(device: ash::Device, memory_properties: vk::PhysicalDeviceMemoryProperties, queue: vk::Queue, queue_family_index: u32, image: vk::Image) -> ash::prelude::VkResult<()> {
+/// let readback = Readback::new(device, memory_properties, queue, queue_family_index)?;
+/// let region = ReadbackRegion {
+///     aspect_mask: vk::ImageAspectFlags::COLOR,
+///     mip_level: 0,
+///     base_array_layer: 0,
+///     layer_count: 1,
+///     extent: vk::Extent3D { width: 1920, height: 1080, depth: 1 },
+///     texel_size: 4,
+/// };
+/// let pixels = readback.read_image(image, vk::ImageLayout::PRESENT_SRC_KHR, &region)?;
+/// assert_eq!(pixels.len(), 1920 * 1080 * 4);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Readback<D: DeviceV1_0 = Device> {
+    device: D,
+    memory_properties: vk::PhysicalDeviceMemoryProperties,
+    queue: vk::Queue,
+    command_pool: vk::CommandPool,
+}
+
+impl<D: DeviceV1_0> Readback<D> {
+    /// Creates a command pool for `queue`, which belongs to `queue_family_index`.
+    pub unsafe fn new(
+        device: D,
+        memory_properties: vk::PhysicalDeviceMemoryProperties,
+        queue: vk::Queue,
+        queue_family_index: u32,
+    ) -> VkResult<Readback<D>> {
+        let pool_info = vk::CommandPoolCreateInfo {
+            flags: vk::CommandPoolCreateFlags::TRANSIENT,
+            queue_family_index,
+            ..Default::default()
+        };
+        let command_pool = device.create_command_pool(&pool_info, None)?;
+        Ok(Readback {
+            device,
+            memory_properties,
+            queue,
+            command_pool,
+        })
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    /// Copies `region` of `image`, which needs `TRANSFER_SRC` usage and is in `layout`, and
+    /// returns its texels tightly packed, row by row, slice by slice and layer by layer.
+    ///
+    /// The image is transitioned to `TRANSFER_SRC_OPTIMAL` for the copy and back to `layout`
+    /// afterwards. Images in `GENERAL` or `TRANSFER_SRC_OPTIMAL` are copied in that layout, and
+    /// images in `UNDEFINED` or `PREINITIALIZED` are left in `TRANSFER_SRC_OPTIMAL`.
+    pub unsafe fn read_image(
+        &self,
+        image: vk::Image,
+        layout: vk::ImageLayout,
+        region: &ReadbackRegion,
+    ) -> VkResult<Vec<u8>> {
+        let len = region.len();
+        let copy_layout = match layout {
+            vk::ImageLayout::GENERAL | vk::ImageLayout::TRANSFER_SRC_OPTIMAL => layout,
+            _ => vk::ImageLayout::TRANSFER_SRC_OPTIMAL,
+        };
+        let final_layout = match layout {
+            vk::ImageLayout::UNDEFINED | vk::ImageLayout::PREINITIALIZED => copy_layout,
+            _ => layout,
+        };
+        let subresource_range = vk::ImageSubresourceRange {
+            aspect_mask: region.aspect_mask,
+            base_mip_level: region.mip_level,
+            level_count: 1,
+            base_array_layer: region.base_array_layer,
+            layer_count: region.layer_count,
+        };
+        self.read(len, |device, command_buffer, staging| {
+            let to_transfer = vk::ImageMemoryBarrier {
+                src_access_mask: vk::AccessFlags::MEMORY_WRITE,
+                dst_access_mask: vk::AccessFlags::TRANSFER_READ,
+                old_layout: layout,
+                new_layout: copy_layout,
+                src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                image,
+                subresource_range,
+                ..Default::default()
+            };
+            device.cmd_pipeline_barrier(
+                command_buffer,
+                vk::PipelineStageFlags::ALL_COMMANDS,
+                vk::PipelineStageFlags::TRANSFER,
+                vk::DependencyFlags::empty(),
+                &[],
+                &[],
+                &[to_transfer],
+            );
+            // The rows of the buffer are exactly as long as the rows of the image
+            let copy = vk::BufferImageCopy {
+                buffer_offset: 0,
+                buffer_row_length: region.extent.width,
+                buffer_image_height: region.extent.height,
+                image_subresource: vk::ImageSubresourceLayers {
+                    aspect_mask: region.aspect_mask,
+                    mip_level: region.mip_level,
+                    base_array_layer: region.base_array_layer,
+                    layer_count: region.layer_count,
+                },
+                image_offset: vk::Offset3D::default(),
+                image_extent: region.extent,
+            };
+            device.cmd_copy_image_to_buffer(command_buffer, image, copy_layout, staging, &[copy]);
+            let image_barriers = if final_layout != copy_layout {
+                vec![vk::ImageMemoryBarrier {
+                    src_access_mask: vk::AccessFlags::empty(),
+                    dst_access_mask: vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE,
+                    old_layout: copy_layout,
+                    new_layout: final_layout,
+                    src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                    dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                    image,
+                    subresource_range,
+                    ..Default::default()
+                }]
+            } else {
+                Vec::new()
+            };
+            device.cmd_pipeline_barrier(
+                command_buffer,
+                vk::PipelineStageFlags::TRANSFER,
+                vk::PipelineStageFlags::HOST | vk::PipelineStageFlags::ALL_COMMANDS,
+                vk::DependencyFlags::empty(),
+                &[],
+                &[host_read_barrier(staging, len)],
+                &image_barriers,
+            );
+        })
+    }
+
+    /// Copies `size` bytes from `offset` of `buffer`, which needs `TRANSFER_SRC` usage.
+    pub unsafe fn read_buffer(
+        &self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+    ) -> VkResult<Vec<u8>> {
+        self.read(size as usize, |device, command_buffer, staging| {
+            let barrier = vk::BufferMemoryBarrier {
+                src_access_mask: vk::AccessFlags::MEMORY_WRITE,
+                dst_access_mask: vk::AccessFlags::TRANSFER_READ,
+                src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                buffer,
+                offset,
+                size,
+                ..Default::default()
+            };
+            device.cmd_pipeline_barrier(
+                command_buffer,
+                vk::PipelineStageFlags::ALL_COMMANDS,
+                vk::PipelineStageFlags::TRANSFER,
+                vk::DependencyFlags::empty(),
+                &[],
+                &[barrier],
+                &[],
+            );
+            let copy = vk::BufferCopy {
+                src_offset: offset,
+                dst_offset: 0,
+                size,
+            };
+            device.cmd_copy_buffer(command_buffer, buffer, staging, &[copy]);
+            device.cmd_pipeline_barrier(
+                command_buffer,
+                vk::PipelineStageFlags::TRANSFER,
+                vk::PipelineStageFlags::HOST,
+                vk::DependencyFlags::empty(),
+                &[],
+                &[host_read_barrier(staging, size as usize)],
+                &[],
+            );
+        })
+    }
+
+    /// Records the copy of `len` bytes to a staging buffer with `record`, submits it, waits
+    /// for it and returns the bytes.
+    unsafe fn read<F>(&self, len: usize, record: F) -> VkResult<Vec<u8>>
+    where
+        F: FnOnce(&D, vk::CommandBuffer, vk::Buffer),
+    {
+        let staging = HostBuffer::new(
+            &self.device,
+            &self.memory_properties,
+            (len as vk::DeviceSize).max(1),
+            vk::BufferUsageFlags::TRANSFER_DST,
+            MemoryUsage::GpuToCpu,
+        )?;
+        let allocate_info = vk::CommandBufferAllocateInfo {
+            command_pool: self.command_pool,
+            level: vk::CommandBufferLevel::PRIMARY,
+            command_buffer_count: 1,
+            ..Default::default()
+        };
+        let command_buffer = match self.device.allocate_command_buffers(&allocate_info) {
+            Ok(command_buffers) => command_buffers[0],
+            Err(err) => {
+                staging.destroy(&self.device);
+                return Err(err);
+            }
+        };
+        let result = self.execute(command_buffer, |command_buffer| {
+            record(&self.device, command_buffer, staging.buffer)
+        });
+        let result = result.and_then(|_| staging.read(&self.device, 0, len));
+        self.device
+            .free_command_buffers(self.command_pool, &[command_buffer]);
+        staging.destroy(&self.device);
+        result
+    }
+
+    unsafe fn execute<F: FnOnce(vk::CommandBuffer)>(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        record: F,
+    ) -> VkResult<()> {
+        let begin_info = vk::CommandBufferBeginInfo {
+            flags: vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT,
+            ..Default::default()
+        };
+        self.device
+            .begin_command_buffer(command_buffer, &begin_info)?;
+        record(command_buffer);
+        self.device.end_command_buffer(command_buffer)?;
+        let fence = self
+            .device
+            .create_fence(&vk::FenceCreateInfo::default(), None)?;
+        let submit_info = vk::SubmitInfo {
+            command_buffer_count: 1,
+            p_command_buffers: &command_buffer,
+            ..Default::default()
+        };
+        let result = self
+            .device
+            .queue_submit(self.queue, &[submit_info], fence)
+            .and_then(|_| self.device.wait_for_fences(&[fence], true, !0));
+        self.device.destroy_fence(fence, None);
+        result.map(|_| ())
+    }
+}
+
+impl<D: DeviceV1_0> Drop for Readback<D> {
+    fn drop(&mut self) {
+        unsafe {
+            self.device.destroy_command_pool(self.command_pool, None);
+        }
+    }
+}
+
+impl<D: DeviceV1_0> fmt::Debug for Readback<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Readback")
+            .field("queue", &self.queue)
+            .field("command_pool", &self.command_pool)
+            .finish()
+    }
+}
+
+fn host_read_barrier(buffer: vk::Buffer, len: usize) -> vk::BufferMemoryBarrier {
+    vk::BufferMemoryBarrier {
+        src_access_mask: vk::AccessFlags::TRANSFER_WRITE,
+        dst_access_mask: vk::AccessFlags::HOST_READ,
+        src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+        dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+        buffer,
+        offset: 0,
+        size: len as vk::DeviceSize,
+        ..Default::default()
+    }
+}
+
+/// Copies the texels of `subresource` of a `LINEAR` image from its mapped memory and returns
+/// them tightly packed. The rows, depth slices and array layer are located with
+/// `get_image_subresource_layout`, whose pitches may be larger than the texels.
+///
+/// `mapped` is the host address of the memory at the offset the image is bound to. Device
+/// writes to the image have to be complete and visible to the host.
+pub unsafe fn read_linear_image<D: DeviceV1_0>(
+    device: &D,
+    image: vk::Image,
+    subresource: vk::ImageSubresource,
+    extent: vk::Extent3D,
+    texel_size: u32,
+    mapped: *const c_void,
+) -> Vec<u8> {
+    let layout = device.get_image_subresource_layout(image, subresource);
+    let row_len = extent.width as usize * texel_size as usize;
+    let mut texels = Vec::with_capacity(row_len * extent.height as usize * extent.depth as usize);
+    let base = (mapped as *const u8).add(layout.offset as usize);
+    for z in 0..extent.depth as usize {
+        for y in 0..extent.height as usize {
+            let row = base.add(z * layout.depth_pitch as usize + y * layout.row_pitch as usize);
+            let start = texels.len();
+            texels.resize(start + row_len, 0);
+            ptr::copy_nonoverlapping(row, texels[start..].as_mut_ptr(), row_len);
+        }
+    }
+    texels
+}
@@ -0,0 +1,234 @@
+use device::Device;
+use prelude::*;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::os::raw::c_void;
+use std::slice;
+use version::DeviceV1_0;
+use vk;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RingBufferError {
+    /// The allocation does not fit into the space that is not used by frames in flight.
+    OutOfSpace {
+        size: vk::DeviceSize,
+        available: vk::DeviceSize,
+    },
+    Vulkan(vk::Result),
+}
+
+impl fmt::Display for RingBufferError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RingBufferError::OutOfSpace { size, available } => write!(
+                f,
+                "Ring buffer overflow: {} bytes requested, {} bytes available",
+                size, available
+            ),
+            RingBufferError::Vulkan(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for RingBufferError {
+    fn description(&self) -> &str {
+        "RingBufferError"
+    }
+}
+
+impl From<vk::Result> for RingBufferError {
+    fn from(err: vk::Result) -> RingBufferError {
+        RingBufferError::Vulkan(err)
+    }
+}
+
+/// A linear allocator over a persistently mapped buffer for data that is written once per
+/// frame, such as uniforms or dynamic vertices.
+///
+/// Allocations are handed out one after another and wrap around at the end of the buffer.
+/// `end_frame` associates the allocations since the previous `end_frame` with the fence of
+/// the frame's submission, and their space is reused once that fence is signaled. If all of
+/// the space is still in use by frames in flight, `allocate` fails instead of overwriting it.
+///
+/// ```no_run
+/// # use ash::util::RingBuffer;
+/// # use ash::vk;
+/// # unsafe fn test(device: ash::Device, buffer: vk::Buffer, mapped: *mut std::os::raw::c_void, limits: &vk::PhysicalDeviceLimits, fence: vk::Fence) -> Result<(), ash::util::RingBufferError> {
+/// let mut ring = RingBuffer::new(device, buffer, mapped, 4 << 20, limits);
+/// // Every frame
+/// let (buffer, offset, data) = ring.allocate_uniform(64)?;
+/// data.copy_from_slice(&[0; 64]);
+/// // Bind `buffer` with the dynamic offset `offset` and submit the frame with `fence`
+/// ring.end_frame(fence);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The memory has to be `HOST_COHERENT`, or written ranges have to be flushed before the
+/// submission.
+pub struct RingBuffer<D: DeviceV1_0 = Device> {
+    device: D,
+    buffer: vk::Buffer,
+    mapped: *mut u8,
+    size: vk::DeviceSize,
+    min_uniform_buffer_offset_alignment: vk::DeviceSize,
+    min_storage_buffer_offset_alignment: vk::DeviceSize,
+    /// Total number of bytes handed out, including padding. The position in the buffer is
+    /// `head % size`.
+    head: u64,
+    /// The value of `head` before the first byte that is still in use.
+    tail: u64,
+    /// Frames in flight with the fence of their submission and the value of `head` at
+    /// their end.
+    frames: VecDeque<(vk::Fence, u64)>,
+}
+
+// The mapped pointer is only accessed through `&mut self`
+unsafe impl<D: DeviceV1_0 + Send> Send for RingBuffer<D> {}
+
+impl<D: DeviceV1_0> RingBuffer<D> {
+    /// `mapped` is the host address of the first `size` bytes of `buffer`, and has to stay
+    /// mapped while the ring buffer is used.
+    pub unsafe fn new(
+        device: D,
+        buffer: vk::Buffer,
+        mapped: *mut c_void,
+        size: vk::DeviceSize,
+        limits: &vk::PhysicalDeviceLimits,
+    ) -> RingBuffer<D> {
+        RingBuffer {
+            device,
+            buffer,
+            mapped: mapped as *mut u8,
+            size,
+            min_uniform_buffer_offset_alignment: limits.min_uniform_buffer_offset_alignment,
+            min_storage_buffer_offset_alignment: limits.min_storage_buffer_offset_alignment,
+            head: 0,
+            tail: 0,
+            frames: VecDeque::new(),
+        }
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    pub fn buffer(&self) -> vk::Buffer {
+        self.buffer
+    }
+
+    pub fn size(&self) -> vk::DeviceSize {
+        self.size
+    }
+
+    /// Bytes in use by the current frame and frames in flight, including padding.
+    pub fn used(&self) -> vk::DeviceSize {
+        self.head - self.tail
+    }
+
+    /// The number of frames whose fence has not been seen signaled yet.
+    pub fn frames_in_flight(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Allocates `size` bytes at an offset that is a multiple of `alignment`. If the space is
+    /// used up, the fences of the frames in flight are checked first.
+    pub fn allocate(
+        &mut self,
+        size: vk::DeviceSize,
+        alignment: vk::DeviceSize,
+    ) -> Result<(vk::Buffer, vk::DeviceSize, &mut [u8]), RingBufferError> {
+        let (head, offset) = match self.fit(size, alignment) {
+            Some(fit) => fit,
+            None => {
+                unsafe {
+                    self.reclaim()?;
+                }
+                self.fit(size, alignment)
+                    .ok_or(RingBufferError::OutOfSpace {
+                        size,
+                        available: self.size - self.used(),
+                    })?
+            }
+        };
+        self.head = head;
+        let data =
+            unsafe { slice::from_raw_parts_mut(self.mapped.add(offset as usize), size as usize) };
+        Ok((self.buffer, offset, data))
+    }
+
+    /// Allocates at a multiple of `min_uniform_buffer_offset_alignment`, for a dynamic
+    /// uniform buffer offset.
+    pub fn allocate_uniform(
+        &mut self,
+        size: vk::DeviceSize,
+    ) -> Result<(vk::Buffer, vk::DeviceSize, &mut [u8]), RingBufferError> {
+        let alignment = self.min_uniform_buffer_offset_alignment;
+        self.allocate(size, alignment)
+    }
+
+    /// Allocates at a multiple of `min_storage_buffer_offset_alignment`, for a dynamic
+    /// storage buffer offset.
+    pub fn allocate_storage(
+        &mut self,
+        size: vk::DeviceSize,
+    ) -> Result<(vk::Buffer, vk::DeviceSize, &mut [u8]), RingBufferError> {
+        let alignment = self.min_storage_buffer_offset_alignment;
+        self.allocate(size, alignment)
+    }
+
+    /// Returns the new head and the offset of an allocation, if it fits.
+    fn fit(&self, size: vk::DeviceSize, alignment: vk::DeviceSize) -> Option<(u64, u64)> {
+        let position = self.head % self.size;
+        let mask = alignment.max(1) - 1;
+        let mut offset = (position + mask) & !mask;
+        if offset + size > self.size {
+            // The rest of the buffer is skipped
+            offset = 0;
+        }
+        let padding = if offset >= position {
+            offset - position
+        } else {
+            self.size - position
+        };
+        let head = self.head + padding + size;
+        if head - self.tail > self.size {
+            return None;
+        }
+        Some((head, offset))
+    }
+
+    /// Ends the current frame. Its allocations are reused once `fence` is signaled.
+    pub fn end_frame(&mut self, fence: vk::Fence) {
+        self.frames.push_back((fence, self.head));
+    }
+
+    /// Reuses the space of the frames whose fence is signaled, in submission order. Returns
+    /// the number of completed frames.
+    pub unsafe fn reclaim(&mut self) -> VkResult<usize> {
+        let mut completed = 0;
+        while let Some(&(fence, end)) = self.frames.front() {
+            match self.device.get_fence_status(fence)? {
+                FenceStatus::Signaled => {
+                    self.frames.pop_front();
+                    self.tail = end;
+                    completed += 1;
+                }
+                FenceStatus::NotReady => break,
+            }
+        }
+        Ok(completed)
+    }
+}
+
+impl<D: DeviceV1_0> fmt::Debug for RingBuffer<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RingBuffer")
+            .field("buffer", &self.buffer)
+            .field("size", &self.size)
+            .field("used", &self.used())
+            .field("frames_in_flight", &self.frames.len())
+            .finish()
+    }
+}
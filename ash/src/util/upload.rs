@@ -0,0 +1,725 @@
+use allocator::{find_memory_type_index, MemoryUsage};
+use device::Device;
+use prelude::*;
+use std::fmt;
+use std::mem;
+use std::ptr;
+use std::slice;
+use version::DeviceV1_0;
+use vk;
+
+/// Staging offsets of copies to images must be multiples of 4 and of the texel block size,
+/// which divides 96 for every format with a block size of up to 32 bytes.
+const STAGING_ALIGNMENT: vk::DeviceSize = 96;
+
+/// Tightly packed texels of one mip level of one or more array layers.
+#[derive(Clone, Copy, Debug)]
+pub struct ImageRegion<'a> {
+    /// A single aspect, e.g. `DEPTH` or `STENCIL` of a depth/stencil image.
+    pub aspect_mask: vk::ImageAspectFlags,
+    pub mip_level: u32,
+    pub base_array_layer: u32,
+    pub layer_count: u32,
+    /// The extent of the mip level.
+    pub extent: vk::Extent3D,
+    pub data: &'a [u8],
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BufferUpload<'a> {
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    data: &'a [u8],
+}
+
+#[derive(Clone, Debug)]
+struct ImageUpload<'a> {
+    image: vk::Image,
+    aspect_mask: vk::ImageAspectFlags,
+    old_layout: vk::ImageLayout,
+    new_layout: vk::ImageLayout,
+    regions: Vec<ImageRegion<'a>>,
+}
+
+impl<'a> ImageUpload<'a> {
+    /// The mip level and array layers of each region, once for the regions of the aspects of a
+    /// depth/stencil image. The regions of an aspect must not overlap, so neither do the ranges.
+    fn subresource_ranges(&self) -> Vec<vk::ImageSubresourceRange> {
+        let mut ranges: Vec<vk::ImageSubresourceRange> = Vec::new();
+        for region in &self.regions {
+            let range = vk::ImageSubresourceRange {
+                aspect_mask: self.aspect_mask,
+                base_mip_level: region.mip_level,
+                level_count: 1,
+                base_array_layer: region.base_array_layer,
+                layer_count: region.layer_count,
+            };
+            let covered = ranges.iter().any(|other| {
+                other.base_mip_level == range.base_mip_level
+                    && other.base_array_layer == range.base_array_layer
+                    && other.layer_count == range.layer_count
+            });
+            if !covered {
+                ranges.push(range);
+            }
+        }
+        ranges
+    }
+}
+
+/// Buffer and image uploads that are submitted together by `Uploader::submit`.
+#[derive(Clone, Debug, Default)]
+pub struct UploadBatch<'a> {
+    buffers: Vec<BufferUpload<'a>>,
+    images: Vec<ImageUpload<'a>>,
+}
+
+impl<'a> UploadBatch<'a> {
+    pub fn new() -> UploadBatch<'a> {
+        UploadBatch::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty() && self.images.is_empty()
+    }
+
+    /// Copies `data` to `offset` bytes into `buffer`, which needs `TRANSFER_DST` usage. Empty
+    /// uploads are skipped.
+    pub fn buffer<T: Copy>(
+        &mut self,
+        buffer: vk::Buffer,
+        offset: vk::DeviceSize,
+        data: &'a [T],
+    ) -> &mut UploadBatch<'a> {
+        let data =
+            unsafe { slice::from_raw_parts(data.as_ptr() as *const u8, mem::size_of_val(data)) };
+        // Copies and barriers of zero bytes are invalid
+        if data.is_empty() {
+            return self;
+        }
+        self.buffers.push(BufferUpload {
+            buffer,
+            offset,
+            data,
+        });
+        self
+    }
+
+    /// Copies `regions` to `image`, which needs `TRANSFER_DST` usage, and transitions the
+    /// `aspect_mask` aspects of the mip levels and array layers they cover from `old_layout` to
+    /// `new_layout`. `UNDEFINED` discards their previous contents. Depth/stencil images are
+    /// transitioned with both aspects, but each region copies only one of them.
+    pub fn image(
+        &mut self,
+        image: vk::Image,
+        aspect_mask: vk::ImageAspectFlags,
+        old_layout: vk::ImageLayout,
+        new_layout: vk::ImageLayout,
+        regions: &[ImageRegion<'a>],
+    ) -> &mut UploadBatch<'a> {
+        self.images.push(ImageUpload {
+            image,
+            aspect_mask,
+            old_layout,
+            new_layout,
+            regions: regions.to_vec(),
+        });
+        self
+    }
+
+    fn staging_size(&self) -> vk::DeviceSize {
+        let buffers = self.buffers.iter().map(|upload| upload.data);
+        let regions = self
+            .images
+            .iter()
+            .flat_map(|upload| upload.regions.iter().map(|region| region.data));
+        buffers
+            .chain(regions)
+            .map(|data| align_up(data.len() as vk::DeviceSize))
+            .sum()
+    }
+}
+
+/// Identifies a submission of `Uploader::submit`.
+///
+/// If the resources were released to another queue family, the barriers of
+/// `buffer_acquire_barriers` and `image_acquire_barriers` have to be recorded on a queue of
+/// that family before the resources are used, after waiting for the fence or the semaphore
+/// that was passed to `submit`. Their `dst_access_mask` is `MEMORY_READ` and can be narrowed.
+#[derive(Debug)]
+pub struct UploadToken {
+    id: u64,
+    fence: vk::Fence,
+    buffer_acquire_barriers: Vec<vk::BufferMemoryBarrier>,
+    image_acquire_barriers: Vec<vk::ImageMemoryBarrier>,
+}
+
+impl UploadToken {
+    /// Signaled once the copies have completed.
+    pub fn fence(&self) -> vk::Fence {
+        self.fence
+    }
+
+    pub fn buffer_acquire_barriers(&self) -> &[vk::BufferMemoryBarrier] {
+        &self.buffer_acquire_barriers
+    }
+
+    pub fn image_acquire_barriers(&self) -> &[vk::ImageMemoryBarrier] {
+        &self.image_acquire_barriers
+    }
+}
+
+#[derive(Debug)]
+struct Submission {
+    id: u64,
+    fence: vk::Fence,
+    command_buffer: vk::CommandBuffer,
+    staging: HostBuffer,
+}
+
+/// Uploads buffer and image data through a staging buffer on a transfer queue.
+///
+/// All uploads of an `UploadBatch` share one staging buffer and one command buffer, which
+/// copies the data and transitions the images to their final layout. Staging buffers are
+/// freed once their submission has completed, by `wait`, `collect` or on drop.
+///
+/// ```no_run
+/// # use ash::util::{ImageRegion, UploadBatch, Uploader};
+/// # use ash::version::DeviceV1_0;
+/// # use ash::vk;
+/// # unsafe fn test(device: ash::Device, memory_properties: vk::PhysicalDeviceMemoryProperties, transfer_queue: vk::Queue, transfer_family: u32, graphics_family: u32, vertex_buffer: vk::Buffer, vertices: &[f32], image: vk::Image, mips: &[Vec<u8>]) -> ash::prelude::VkResult<()> {
+/// let mut uploader = Uploader::new(device, memory_properties, transfer_queue, transfer_family)?;
+/// let regions: Vec<ImageRegion> = mips
+///     .iter()
+///     .enumerate()
+///     .map(|(level, data)| ImageRegion {
+///         aspect_mask: vk::ImageAspectFlags::COLOR,
+///         mip_level: level as u32,
+///         base_array_layer: 0,
+///         layer_count: 1,
+///         extent: vk::Extent3D { width: 256 >> level, height: 256 >> level, depth: 1 },
+///         data,
+///     })
+///     .collect();
+/// let mut batch = UploadBatch::new();
+/// batch.buffer(vertex_buffer, 0, vertices).image(
+///     image,
+///     vk::ImageAspectFlags::COLOR,
+///     vk::ImageLayout::UNDEFINED,
+///     vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+///     &regions,
+/// );
+/// let token = uploader.submit(&batch, Some(graphics_family), None)?;
+/// // Record `token.image_acquire_barriers()` on the graphics queue, then
+/// uploader.wait(token)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Uploader<D: DeviceV1_0 = Device> {
+    device: D,
+    memory_properties: vk::PhysicalDeviceMemoryProperties,
+    queue: vk::Queue,
+    queue_family_index: u32,
+    command_pool: vk::CommandPool,
+    submissions: Vec<Submission>,
+    next_id: u64,
+}
+
+impl<D: DeviceV1_0> Uploader<D> {
+    /// Creates a command pool for `queue`, which belongs to `queue_family_index`.
+    pub unsafe fn new(
+        device: D,
+        memory_properties: vk::PhysicalDeviceMemoryProperties,
+        queue: vk::Queue,
+        queue_family_index: u32,
+    ) -> VkResult<Uploader<D>> {
+        let pool_info = vk::CommandPoolCreateInfo {
+            flags: vk::CommandPoolCreateFlags::TRANSIENT,
+            queue_family_index,
+            ..Default::default()
+        };
+        let command_pool = device.create_command_pool(&pool_info, None)?;
+        Ok(Uploader {
+            device,
+            memory_properties,
+            queue,
+            queue_family_index,
+            command_pool,
+            submissions: Vec::new(),
+            next_id: 0,
+        })
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    /// Records and submits the uploads of `batch`. If `dst_queue_family_index` is another
+    /// family than the one of the transfer queue, the buffers and images are released to it.
+    /// `signal_semaphore` is signaled together with the fence of the token.
+    pub unsafe fn submit(
+        &mut self,
+        batch: &UploadBatch,
+        dst_queue_family_index: Option<u32>,
+        signal_semaphore: Option<vk::Semaphore>,
+    ) -> VkResult<UploadToken> {
+        let staging = HostBuffer::new(
+            &self.device,
+            &self.memory_properties,
+            batch.staging_size().max(1),
+            vk::BufferUsageFlags::TRANSFER_SRC,
+            MemoryUsage::CpuOnly,
+        )?;
+        let command_buffer = match self.record(batch, &staging, dst_queue_family_index) {
+            Ok(command_buffer) => command_buffer,
+            Err(err) => {
+                staging.destroy(&self.device);
+                return Err(err);
+            }
+        };
+        let (fence, barriers) = match self.execute(command_buffer, signal_semaphore) {
+            Ok(fence) => (fence, self.acquire_barriers(batch, dst_queue_family_index)),
+            Err(err) => {
+                self.device
+                    .free_command_buffers(self.command_pool, &[command_buffer]);
+                staging.destroy(&self.device);
+                return Err(err);
+            }
+        };
+        let id = self.next_id;
+        self.next_id += 1;
+        self.submissions.push(Submission {
+            id,
+            fence,
+            command_buffer,
+            staging,
+        });
+        Ok(UploadToken {
+            id,
+            fence,
+            buffer_acquire_barriers: barriers.0,
+            image_acquire_barriers: barriers.1,
+        })
+    }
+
+    /// The queue family that resources are released to, if any.
+    fn release_to(&self, dst_queue_family_index: Option<u32>) -> Option<u32> {
+        dst_queue_family_index.filter(|&family| family != self.queue_family_index)
+    }
+
+    unsafe fn record(
+        &self,
+        batch: &UploadBatch,
+        staging: &HostBuffer,
+        dst_queue_family_index: Option<u32>,
+    ) -> VkResult<vk::CommandBuffer> {
+        let allocate_info = vk::CommandBufferAllocateInfo {
+            command_pool: self.command_pool,
+            level: vk::CommandBufferLevel::PRIMARY,
+            command_buffer_count: 1,
+            ..Default::default()
+        };
+        let command_buffer = self.device.allocate_command_buffers(&allocate_info)?[0];
+        let begin_info = vk::CommandBufferBeginInfo {
+            flags: vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT,
+            ..Default::default()
+        };
+        if let Err(err) = self
+            .device
+            .begin_command_buffer(command_buffer, &begin_info)
+        {
+            self.device
+                .free_command_buffers(self.command_pool, &[command_buffer]);
+            return Err(err);
+        }
+
+        let to_transfer: Vec<vk::ImageMemoryBarrier> = batch
+            .images
+            .iter()
+            .flat_map(|upload| {
+                upload
+                    .subresource_ranges()
+                    .into_iter()
+                    .map(move |subresource_range| vk::ImageMemoryBarrier {
+                        src_access_mask: vk::AccessFlags::empty(),
+                        dst_access_mask: vk::AccessFlags::TRANSFER_WRITE,
+                        old_layout: upload.old_layout,
+                        new_layout: vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                        src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                        dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                        image: upload.image,
+                        subresource_range,
+                        ..Default::default()
+                    })
+            })
+            .collect();
+        if !to_transfer.is_empty() {
+            self.device.cmd_pipeline_barrier(
+                command_buffer,
+                vk::PipelineStageFlags::TOP_OF_PIPE,
+                vk::PipelineStageFlags::TRANSFER,
+                vk::DependencyFlags::empty(),
+                &[],
+                &[],
+                &to_transfer,
+            );
+        }
+
+        let mut staging_offset = 0;
+        for upload in &batch.buffers {
+            staging.write(staging_offset, upload.data);
+            let region = vk::BufferCopy {
+                src_offset: staging_offset,
+                dst_offset: upload.offset,
+                size: upload.data.len() as vk::DeviceSize,
+            };
+            self.device
+                .cmd_copy_buffer(command_buffer, staging.buffer, upload.buffer, &[region]);
+            staging_offset += align_up(upload.data.len() as vk::DeviceSize);
+        }
+        for upload in &batch.images {
+            let mut regions = Vec::with_capacity(upload.regions.len());
+            for region in &upload.regions {
+                staging.write(staging_offset, region.data);
+                regions.push(vk::BufferImageCopy {
+                    buffer_offset: staging_offset,
+                    buffer_row_length: 0,
+                    buffer_image_height: 0,
+                    image_subresource: vk::ImageSubresourceLayers {
+                        aspect_mask: region.aspect_mask,
+                        mip_level: region.mip_level,
+                        base_array_layer: region.base_array_layer,
+                        layer_count: region.layer_count,
+                    },
+                    image_offset: vk::Offset3D::default(),
+                    image_extent: region.extent,
+                });
+                staging_offset += align_up(region.data.len() as vk::DeviceSize);
+            }
+            self.device.cmd_copy_buffer_to_image(
+                command_buffer,
+                staging.buffer,
+                upload.image,
+                vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                &regions,
+            );
+        }
+
+        // Either releases the resources to the destination queue family, or makes the copies
+        // visible to any later read on this queue
+        let release_to = self.release_to(dst_queue_family_index);
+        let (dst_stage, dst_access, src_family, dst_family) = match release_to {
+            Some(family) => (
+                vk::PipelineStageFlags::BOTTOM_OF_PIPE,
+                vk::AccessFlags::empty(),
+                self.queue_family_index,
+                family,
+            ),
+            None => (
+                vk::PipelineStageFlags::ALL_COMMANDS,
+                vk::AccessFlags::MEMORY_READ,
+                vk::QUEUE_FAMILY_IGNORED,
+                vk::QUEUE_FAMILY_IGNORED,
+            ),
+        };
+        let buffer_barriers: Vec<vk::BufferMemoryBarrier> = batch
+            .buffers
+            .iter()
+            .map(|upload| vk::BufferMemoryBarrier {
+                src_access_mask: vk::AccessFlags::TRANSFER_WRITE,
+                dst_access_mask: dst_access,
+                src_queue_family_index: src_family,
+                dst_queue_family_index: dst_family,
+                buffer: upload.buffer,
+                offset: upload.offset,
+                size: upload.data.len() as vk::DeviceSize,
+                ..Default::default()
+            })
+            .collect();
+        let image_barriers: Vec<vk::ImageMemoryBarrier> = batch
+            .images
+            .iter()
+            .flat_map(|upload| {
+                upload
+                    .subresource_ranges()
+                    .into_iter()
+                    .map(move |subresource_range| vk::ImageMemoryBarrier {
+                        src_access_mask: vk::AccessFlags::TRANSFER_WRITE,
+                        dst_access_mask: dst_access,
+                        old_layout: vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                        new_layout: upload.new_layout,
+                        src_queue_family_index: src_family,
+                        dst_queue_family_index: dst_family,
+                        image: upload.image,
+                        subresource_range,
+                        ..Default::default()
+                    })
+            })
+            .collect();
+        if !buffer_barriers.is_empty() || !image_barriers.is_empty() {
+            self.device.cmd_pipeline_barrier(
+                command_buffer,
+                vk::PipelineStageFlags::TRANSFER,
+                dst_stage,
+                vk::DependencyFlags::empty(),
+                &[],
+                &buffer_barriers,
+                &image_barriers,
+            );
+        }
+
+        if let Err(err) = self.device.end_command_buffer(command_buffer) {
+            self.device
+                .free_command_buffers(self.command_pool, &[command_buffer]);
+            return Err(err);
+        }
+        Ok(command_buffer)
+    }
+
+    unsafe fn execute(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        signal_semaphore: Option<vk::Semaphore>,
+    ) -> VkResult<vk::Fence> {
+        let fence = self
+            .device
+            .create_fence(&vk::FenceCreateInfo::default(), None)?;
+        let signal_semaphores: Vec<vk::Semaphore> = signal_semaphore.into_iter().collect();
+        let submit_info = vk::SubmitInfo {
+            command_buffer_count: 1,
+            p_command_buffers: &command_buffer,
+            signal_semaphore_count: signal_semaphores.len() as u32,
+            p_signal_semaphores: signal_semaphores.as_ptr(),
+            ..Default::default()
+        };
+        if let Err(err) = self.device.queue_submit(self.queue, &[submit_info], fence) {
+            self.device.destroy_fence(fence, None);
+            return Err(err);
+        }
+        Ok(fence)
+    }
+
+    /// The barriers that acquire the resources of `batch` on the destination queue family.
+    fn acquire_barriers(
+        &self,
+        batch: &UploadBatch,
+        dst_queue_family_index: Option<u32>,
+    ) -> (Vec<vk::BufferMemoryBarrier>, Vec<vk::ImageMemoryBarrier>) {
+        let family = match self.release_to(dst_queue_family_index) {
+            Some(family) => family,
+            None => return (Vec::new(), Vec::new()),
+        };
+        let buffers = batch
+            .buffers
+            .iter()
+            .map(|upload| vk::BufferMemoryBarrier {
+                src_access_mask: vk::AccessFlags::empty(),
+                dst_access_mask: vk::AccessFlags::MEMORY_READ,
+                src_queue_family_index: self.queue_family_index,
+                dst_queue_family_index: family,
+                buffer: upload.buffer,
+                offset: upload.offset,
+                size: upload.data.len() as vk::DeviceSize,
+                ..Default::default()
+            })
+            .collect();
+        let images = batch
+            .images
+            .iter()
+            .flat_map(|upload| {
+                upload
+                    .subresource_ranges()
+                    .into_iter()
+                    .map(move |subresource_range| vk::ImageMemoryBarrier {
+                        src_access_mask: vk::AccessFlags::empty(),
+                        dst_access_mask: vk::AccessFlags::MEMORY_READ,
+                        old_layout: vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                        new_layout: upload.new_layout,
+                        src_queue_family_index: self.queue_family_index,
+                        dst_queue_family_index: family,
+                        image: upload.image,
+                        subresource_range,
+                        ..Default::default()
+                    })
+            })
+            .collect();
+        (buffers, images)
+    }
+
+    /// Whether the submission of `token` has completed.
+    pub unsafe fn is_complete(&self, token: &UploadToken) -> VkResult<bool> {
+        match self.submissions.iter().find(|s| s.id == token.id) {
+            Some(submission) => {
+                Ok(self.device.get_fence_status(submission.fence)? == FenceStatus::Signaled)
+            }
+            None => Ok(true),
+        }
+    }
+
+    /// Waits until the submission of `token` has completed and frees its staging buffer.
+    pub unsafe fn wait(&mut self, token: UploadToken) -> VkResult<()> {
+        if let Some(index) = self.submissions.iter().position(|s| s.id == token.id) {
+            self.device
+                .wait_for_fences(&[self.submissions[index].fence], true, !0)?;
+            let submission = self.submissions.swap_remove(index);
+            self.free(submission);
+        }
+        Ok(())
+    }
+
+    /// Frees the staging buffers of all completed submissions. Returns how many submissions
+    /// are still in flight.
+    pub unsafe fn collect(&mut self) -> VkResult<usize> {
+        let mut index = 0;
+        while index < self.submissions.len() {
+            match self
+                .device
+                .get_fence_status(self.submissions[index].fence)?
+            {
+                FenceStatus::Signaled => {
+                    let submission = self.submissions.swap_remove(index);
+                    self.free(submission);
+                }
+                FenceStatus::NotReady => index += 1,
+            }
+        }
+        Ok(self.submissions.len())
+    }
+
+    unsafe fn free(&self, submission: Submission) {
+        self.device.destroy_fence(submission.fence, None);
+        self.device
+            .free_command_buffers(self.command_pool, &[submission.command_buffer]);
+        submission.staging.destroy(&self.device);
+    }
+}
+
+impl<D: DeviceV1_0> Drop for Uploader<D> {
+    fn drop(&mut self) {
+        unsafe {
+            let fences: Vec<vk::Fence> = self.submissions.iter().map(|s| s.fence).collect();
+            if !fences.is_empty() {
+                let _ = self.device.wait_for_fences(&fences, true, !0);
+            }
+            while let Some(submission) = self.submissions.pop() {
+                self.free(submission);
+            }
+            self.device.destroy_command_pool(self.command_pool, None);
+        }
+    }
+}
+
+impl<D: DeviceV1_0> fmt::Debug for Uploader<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Uploader")
+            .field("queue", &self.queue)
+            .field("queue_family_index", &self.queue_family_index)
+            .field("submissions", &self.submissions.len())
+            .finish()
+    }
+}
+
+fn align_up(size: vk::DeviceSize) -> vk::DeviceSize {
+    (size + STAGING_ALIGNMENT - 1) / STAGING_ALIGNMENT * STAGING_ALIGNMENT
+}
+
+/// A buffer with its own host visible memory, which stays mapped.
+#[derive(Debug)]
+pub(super) struct HostBuffer {
+    pub(super) buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    coherent: bool,
+    mapped: *mut u8,
+    size: vk::DeviceSize,
+}
+
+impl HostBuffer {
+    pub(super) unsafe fn new<D: DeviceV1_0>(
+        device: &D,
+        memory_properties: &vk::PhysicalDeviceMemoryProperties,
+        size: vk::DeviceSize,
+        usage: vk::BufferUsageFlags,
+        memory_usage: MemoryUsage,
+    ) -> VkResult<HostBuffer> {
+        let buffer_info = vk::BufferCreateInfo {
+            size,
+            usage,
+            sharing_mode: vk::SharingMode::EXCLUSIVE,
+            ..Default::default()
+        };
+        let buffer = device.create_buffer(&buffer_info, None)?;
+        let mut host_buffer = HostBuffer {
+            buffer,
+            memory: vk::DeviceMemory::null(),
+            coherent: false,
+            mapped: ptr::null_mut(),
+            size,
+        };
+        if let Err(err) = host_buffer.bind(device, memory_properties, memory_usage) {
+            host_buffer.destroy(device);
+            return Err(err);
+        }
+        Ok(host_buffer)
+    }
+
+    unsafe fn bind<D: DeviceV1_0>(
+        &mut self,
+        device: &D,
+        memory_properties: &vk::PhysicalDeviceMemoryProperties,
+        memory_usage: MemoryUsage,
+    ) -> VkResult<()> {
+        let requirements = device.get_buffer_memory_requirements(self.buffer);
+        let memory_type_index = find_memory_type_index(
+            memory_properties,
+            requirements.memory_type_bits,
+            memory_usage,
+        )
+        .ok_or(vk::Result::ERROR_FEATURE_NOT_PRESENT)?;
+        let allocate_info = vk::MemoryAllocateInfo {
+            allocation_size: requirements.size,
+            memory_type_index,
+            ..Default::default()
+        };
+        self.memory = device.allocate_memory(&allocate_info, None)?;
+        self.coherent = memory_properties.memory_types[memory_type_index as usize]
+            .property_flags
+            .contains(vk::MemoryPropertyFlags::HOST_COHERENT);
+        device.bind_buffer_memory(self.buffer, self.memory, 0)?;
+        let mapped =
+            device.map_memory(self.memory, 0, vk::WHOLE_SIZE, vk::MemoryMapFlags::empty())?;
+        self.mapped = mapped as *mut u8;
+        Ok(())
+    }
+
+    pub(super) unsafe fn write(&self, offset: vk::DeviceSize, data: &[u8]) {
+        debug_assert!(offset + data.len() as vk::DeviceSize <= self.size);
+        ptr::copy_nonoverlapping(data.as_ptr(), self.mapped.add(offset as usize), data.len());
+    }
+
+    /// Copies `len` bytes from `offset` after making device writes to the memory visible.
+    pub(super) unsafe fn read<D: DeviceV1_0>(
+        &self,
+        device: &D,
+        offset: vk::DeviceSize,
+        len: usize,
+    ) -> VkResult<Vec<u8>> {
+        debug_assert!(offset + len as vk::DeviceSize <= self.size);
+        if !self.coherent {
+            let range = vk::MappedMemoryRange {
+                memory: self.memory,
+                offset: 0,
+                size: vk::WHOLE_SIZE,
+                ..Default::default()
+            };
+            device.invalidate_mapped_memory_ranges(&[range])?;
+        }
+        Ok(slice::from_raw_parts(self.mapped.add(offset as usize), len).to_vec())
+    }
+
+    pub(super) unsafe fn destroy<D: DeviceV1_0>(&self, device: &D) {
+        device.destroy_buffer(self.buffer, None);
+        if self.memory != vk::DeviceMemory::null() {
+            device.free_memory(self.memory, None);
+        }
+    }
+}
@@ -0,0 +1,6371 @@
+use super::{Validate, Validator};
+use std::os::raw::*;
+use vk::*;
+impl Validate for AccelerationStructureCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AccelerationStructureCreateInfoNV",
+            self.s_type,
+            StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        self.info.validate(v);
+    }
+}
+impl Validate for AccelerationStructureInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AccelerationStructureInfoNV",
+            self.s_type,
+            StructureType::ACCELERATION_STRUCTURE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::ACCELERATION_STRUCTURE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "AccelerationStructureInfoNV",
+            "p_geometries",
+            self.p_geometries,
+            self.geometry_count as usize,
+        );
+        v.structures(self.p_geometries, self.geometry_count as usize);
+    }
+}
+impl Validate for AccelerationStructureMemoryRequirementsInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AccelerationStructureMemoryRequirementsInfoNV",
+            self.s_type,
+            StructureType::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV,
+        );
+        v.p_next(
+            StructureType::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for AcquireNextImageInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AcquireNextImageInfoKHR",
+            self.s_type,
+            StructureType::ACQUIRE_NEXT_IMAGE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::ACQUIRE_NEXT_IMAGE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for AndroidHardwareBufferFormatPropertiesANDROID {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AndroidHardwareBufferFormatPropertiesANDROID",
+            self.s_type,
+            StructureType::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID,
+        );
+        v.p_next(
+            StructureType::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for AndroidHardwareBufferPropertiesANDROID {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AndroidHardwareBufferPropertiesANDROID",
+            self.s_type,
+            StructureType::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID,
+        );
+        v.p_next(
+            StructureType::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for AndroidHardwareBufferUsageANDROID {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AndroidHardwareBufferUsageANDROID",
+            self.s_type,
+            StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID,
+        );
+        v.p_next(
+            StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for AndroidSurfaceCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AndroidSurfaceCreateInfoKHR",
+            self.s_type,
+            StructureType::ANDROID_SURFACE_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::ANDROID_SURFACE_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ApplicationInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ApplicationInfo",
+            self.s_type,
+            StructureType::APPLICATION_INFO,
+        );
+        v.p_next(
+            StructureType::APPLICATION_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for AttachmentDescription2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AttachmentDescription2KHR",
+            self.s_type,
+            StructureType::ATTACHMENT_DESCRIPTION_2_KHR,
+        );
+        v.p_next(
+            StructureType::ATTACHMENT_DESCRIPTION_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for AttachmentReference2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "AttachmentReference2KHR",
+            self.s_type,
+            StructureType::ATTACHMENT_REFERENCE_2_KHR,
+        );
+        v.p_next(
+            StructureType::ATTACHMENT_REFERENCE_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for AttachmentSampleLocationsEXT {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        self.sample_locations_info.validate(v);
+    }
+}
+impl Validate for BindAccelerationStructureMemoryInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BindAccelerationStructureMemoryInfoNV",
+            self.s_type,
+            StructureType::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV,
+        );
+        v.p_next(
+            StructureType::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "BindAccelerationStructureMemoryInfoNV",
+            "p_device_indices",
+            self.p_device_indices,
+            self.device_index_count as usize,
+        );
+    }
+}
+impl Validate for BindBufferMemoryDeviceGroupInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BindBufferMemoryDeviceGroupInfo",
+            self.s_type,
+            StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO,
+        );
+        v.p_next(
+            StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "BindBufferMemoryDeviceGroupInfo",
+            "p_device_indices",
+            self.p_device_indices,
+            self.device_index_count as usize,
+        );
+    }
+}
+impl Validate for BindBufferMemoryInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BindBufferMemoryInfo",
+            self.s_type,
+            StructureType::BIND_BUFFER_MEMORY_INFO,
+        );
+        v.p_next(
+            StructureType::BIND_BUFFER_MEMORY_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for BindImageMemoryDeviceGroupInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BindImageMemoryDeviceGroupInfo",
+            self.s_type,
+            StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO,
+        );
+        v.p_next(
+            StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "BindImageMemoryDeviceGroupInfo",
+            "p_device_indices",
+            self.p_device_indices,
+            self.device_index_count as usize,
+        );
+        v.array(
+            "BindImageMemoryDeviceGroupInfo",
+            "p_split_instance_bind_regions",
+            self.p_split_instance_bind_regions,
+            self.split_instance_bind_region_count as usize,
+        );
+    }
+}
+impl Validate for BindImageMemoryInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BindImageMemoryInfo",
+            self.s_type,
+            StructureType::BIND_IMAGE_MEMORY_INFO,
+        );
+        v.p_next(
+            StructureType::BIND_IMAGE_MEMORY_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for BindImageMemorySwapchainInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BindImageMemorySwapchainInfoKHR",
+            self.s_type,
+            StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for BindImagePlaneMemoryInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BindImagePlaneMemoryInfo",
+            self.s_type,
+            StructureType::BIND_IMAGE_PLANE_MEMORY_INFO,
+        );
+        v.p_next(
+            StructureType::BIND_IMAGE_PLANE_MEMORY_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for BindSparseInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BindSparseInfo",
+            self.s_type,
+            StructureType::BIND_SPARSE_INFO,
+        );
+        v.p_next(
+            StructureType::BIND_SPARSE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "BindSparseInfo",
+            "p_wait_semaphores",
+            self.p_wait_semaphores,
+            self.wait_semaphore_count as usize,
+        );
+        v.array(
+            "BindSparseInfo",
+            "p_buffer_binds",
+            self.p_buffer_binds,
+            self.buffer_bind_count as usize,
+        );
+        v.structures(self.p_buffer_binds, self.buffer_bind_count as usize);
+        v.array(
+            "BindSparseInfo",
+            "p_image_opaque_binds",
+            self.p_image_opaque_binds,
+            self.image_opaque_bind_count as usize,
+        );
+        v.structures(
+            self.p_image_opaque_binds,
+            self.image_opaque_bind_count as usize,
+        );
+        v.array(
+            "BindSparseInfo",
+            "p_image_binds",
+            self.p_image_binds,
+            self.image_bind_count as usize,
+        );
+        v.structures(self.p_image_binds, self.image_bind_count as usize);
+        v.array(
+            "BindSparseInfo",
+            "p_signal_semaphores",
+            self.p_signal_semaphores,
+            self.signal_semaphore_count as usize,
+        );
+    }
+}
+impl Validate for BufferCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BufferCreateInfo",
+            self.s_type,
+            StructureType::BUFFER_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::BUFFER_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for BufferMemoryBarrier {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BufferMemoryBarrier",
+            self.s_type,
+            StructureType::BUFFER_MEMORY_BARRIER,
+        );
+        v.p_next(
+            StructureType::BUFFER_MEMORY_BARRIER,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for BufferMemoryRequirementsInfo2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BufferMemoryRequirementsInfo2",
+            self.s_type,
+            StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2,
+        );
+        v.p_next(
+            StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for BufferViewCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "BufferViewCreateInfo",
+            self.s_type,
+            StructureType::BUFFER_VIEW_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::BUFFER_VIEW_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for CalibratedTimestampInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CalibratedTimestampInfoEXT",
+            self.s_type,
+            StructureType::CALIBRATED_TIMESTAMP_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::CALIBRATED_TIMESTAMP_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for CheckpointDataNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CheckpointDataNV",
+            self.s_type,
+            StructureType::CHECKPOINT_DATA_NV,
+        );
+        v.p_next(
+            StructureType::CHECKPOINT_DATA_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for CmdProcessCommandsInfoNVX {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CmdProcessCommandsInfoNVX",
+            self.s_type,
+            StructureType::CMD_PROCESS_COMMANDS_INFO_NVX,
+        );
+        v.p_next(
+            StructureType::CMD_PROCESS_COMMANDS_INFO_NVX,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "CmdProcessCommandsInfoNVX",
+            "p_indirect_commands_tokens",
+            self.p_indirect_commands_tokens,
+            self.indirect_commands_token_count as usize,
+        );
+    }
+}
+impl Validate for CmdReserveSpaceForCommandsInfoNVX {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CmdReserveSpaceForCommandsInfoNVX",
+            self.s_type,
+            StructureType::CMD_RESERVE_SPACE_FOR_COMMANDS_INFO_NVX,
+        );
+        v.p_next(
+            StructureType::CMD_RESERVE_SPACE_FOR_COMMANDS_INFO_NVX,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for CoarseSampleOrderCustomNV {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "CoarseSampleOrderCustomNV",
+            "p_sample_locations",
+            self.p_sample_locations,
+            self.sample_location_count as usize,
+        );
+    }
+}
+impl Validate for CommandBufferAllocateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CommandBufferAllocateInfo",
+            self.s_type,
+            StructureType::COMMAND_BUFFER_ALLOCATE_INFO,
+        );
+        v.p_next(
+            StructureType::COMMAND_BUFFER_ALLOCATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for CommandBufferBeginInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CommandBufferBeginInfo",
+            self.s_type,
+            StructureType::COMMAND_BUFFER_BEGIN_INFO,
+        );
+        v.p_next(
+            StructureType::COMMAND_BUFFER_BEGIN_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.structures(self.p_inheritance_info, 1);
+    }
+}
+impl Validate for CommandBufferInheritanceConditionalRenderingInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CommandBufferInheritanceConditionalRenderingInfoEXT",
+            self.s_type,
+            StructureType::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for CommandBufferInheritanceInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CommandBufferInheritanceInfo",
+            self.s_type,
+            StructureType::COMMAND_BUFFER_INHERITANCE_INFO,
+        );
+        v.p_next(
+            StructureType::COMMAND_BUFFER_INHERITANCE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for CommandPoolCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CommandPoolCreateInfo",
+            self.s_type,
+            StructureType::COMMAND_POOL_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::COMMAND_POOL_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ComputePipelineCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ComputePipelineCreateInfo",
+            self.s_type,
+            StructureType::COMPUTE_PIPELINE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::COMPUTE_PIPELINE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        self.stage.validate(v);
+    }
+}
+impl Validate for ConditionalRenderingBeginInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ConditionalRenderingBeginInfoEXT",
+            self.s_type,
+            StructureType::CONDITIONAL_RENDERING_BEGIN_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::CONDITIONAL_RENDERING_BEGIN_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for CopyDescriptorSet {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "CopyDescriptorSet",
+            self.s_type,
+            StructureType::COPY_DESCRIPTOR_SET,
+        );
+        v.p_next(
+            StructureType::COPY_DESCRIPTOR_SET,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for D3D12FenceSubmitInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "D3D12FenceSubmitInfoKHR",
+            self.s_type,
+            StructureType::D3D12_FENCE_SUBMIT_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::D3D12_FENCE_SUBMIT_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DebugMarkerMarkerInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugMarkerMarkerInfoEXT",
+            self.s_type,
+            StructureType::DEBUG_MARKER_MARKER_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_MARKER_MARKER_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DebugMarkerObjectNameInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugMarkerObjectNameInfoEXT",
+            self.s_type,
+            StructureType::DEBUG_MARKER_OBJECT_NAME_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_MARKER_OBJECT_NAME_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DebugMarkerObjectTagInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugMarkerObjectTagInfoEXT",
+            self.s_type,
+            StructureType::DEBUG_MARKER_OBJECT_TAG_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_MARKER_OBJECT_TAG_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DebugMarkerObjectTagInfoEXT",
+            "p_tag",
+            self.p_tag,
+            self.tag_size as usize,
+        );
+    }
+}
+impl Validate for DebugReportCallbackCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugReportCallbackCreateInfoEXT",
+            self.s_type,
+            StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DebugUtilsLabelEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugUtilsLabelEXT",
+            self.s_type,
+            StructureType::DEBUG_UTILS_LABEL_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_UTILS_LABEL_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DebugUtilsMessengerCallbackDataEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugUtilsMessengerCallbackDataEXT",
+            self.s_type,
+            StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.structures(self.p_queue_labels, 1);
+        v.structures(self.p_cmd_buf_labels, 1);
+        v.structures(self.p_objects, 1);
+    }
+}
+impl Validate for DebugUtilsMessengerCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugUtilsMessengerCreateInfoEXT",
+            self.s_type,
+            StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DebugUtilsObjectNameInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugUtilsObjectNameInfoEXT",
+            self.s_type,
+            StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DebugUtilsObjectTagInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DebugUtilsObjectTagInfoEXT",
+            self.s_type,
+            StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DebugUtilsObjectTagInfoEXT",
+            "p_tag",
+            self.p_tag,
+            self.tag_size as usize,
+        );
+    }
+}
+impl Validate for DedicatedAllocationBufferCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DedicatedAllocationBufferCreateInfoNV",
+            self.s_type,
+            StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DedicatedAllocationImageCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DedicatedAllocationImageCreateInfoNV",
+            self.s_type,
+            StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DedicatedAllocationMemoryAllocateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DedicatedAllocationMemoryAllocateInfoNV",
+            self.s_type,
+            StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DescriptorPoolCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorPoolCreateInfo",
+            self.s_type,
+            StructureType::DESCRIPTOR_POOL_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_POOL_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DescriptorPoolCreateInfo",
+            "p_pool_sizes",
+            self.p_pool_sizes,
+            self.pool_size_count as usize,
+        );
+    }
+}
+impl Validate for DescriptorPoolInlineUniformBlockCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorPoolInlineUniformBlockCreateInfoEXT",
+            self.s_type,
+            StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DescriptorSetAllocateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorSetAllocateInfo",
+            self.s_type,
+            StructureType::DESCRIPTOR_SET_ALLOCATE_INFO,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_SET_ALLOCATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DescriptorSetAllocateInfo",
+            "p_set_layouts",
+            self.p_set_layouts,
+            self.descriptor_set_count as usize,
+        );
+    }
+}
+impl Validate for DescriptorSetLayoutBinding {
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DescriptorSetLayoutBindingFlagsCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorSetLayoutBindingFlagsCreateInfoEXT",
+            self.s_type,
+            StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DescriptorSetLayoutBindingFlagsCreateInfoEXT",
+            "p_binding_flags",
+            self.p_binding_flags,
+            self.binding_count as usize,
+        );
+    }
+}
+impl Validate for DescriptorSetLayoutCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorSetLayoutCreateInfo",
+            self.s_type,
+            StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DescriptorSetLayoutCreateInfo",
+            "p_bindings",
+            self.p_bindings,
+            self.binding_count as usize,
+        );
+        v.structures(self.p_bindings, self.binding_count as usize);
+    }
+}
+impl Validate for DescriptorSetLayoutSupport {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorSetLayoutSupport",
+            self.s_type,
+            StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DescriptorSetVariableDescriptorCountAllocateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorSetVariableDescriptorCountAllocateInfoEXT",
+            self.s_type,
+            StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DescriptorSetVariableDescriptorCountAllocateInfoEXT",
+            "p_descriptor_counts",
+            self.p_descriptor_counts,
+            self.descriptor_set_count as usize,
+        );
+    }
+}
+impl Validate for DescriptorSetVariableDescriptorCountLayoutSupportEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorSetVariableDescriptorCountLayoutSupportEXT",
+            self.s_type,
+            StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DescriptorUpdateTemplateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DescriptorUpdateTemplateCreateInfo",
+            self.s_type,
+            StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DescriptorUpdateTemplateCreateInfo",
+            "p_descriptor_update_entries",
+            self.p_descriptor_update_entries,
+            self.descriptor_update_entry_count as usize,
+        );
+    }
+}
+impl Validate for DeviceCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceCreateInfo",
+            self.s_type,
+            StructureType::DEVICE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::DEVICE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DeviceCreateInfo",
+            "p_queue_create_infos",
+            self.p_queue_create_infos,
+            self.queue_create_info_count as usize,
+        );
+        v.structures(
+            self.p_queue_create_infos,
+            self.queue_create_info_count as usize,
+        );
+        v.array(
+            "DeviceCreateInfo",
+            "pp_enabled_layer_names",
+            self.pp_enabled_layer_names,
+            self.enabled_layer_count as usize,
+        );
+        v.array(
+            "DeviceCreateInfo",
+            "pp_enabled_extension_names",
+            self.pp_enabled_extension_names,
+            self.enabled_extension_count as usize,
+        );
+    }
+}
+impl Validate for DeviceEventInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceEventInfoEXT",
+            self.s_type,
+            StructureType::DEVICE_EVENT_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEVICE_EVENT_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceGeneratedCommandsFeaturesNVX {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGeneratedCommandsFeaturesNVX",
+            self.s_type,
+            StructureType::DEVICE_GENERATED_COMMANDS_FEATURES_NVX,
+        );
+        v.p_next(
+            StructureType::DEVICE_GENERATED_COMMANDS_FEATURES_NVX,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceGeneratedCommandsLimitsNVX {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGeneratedCommandsLimitsNVX",
+            self.s_type,
+            StructureType::DEVICE_GENERATED_COMMANDS_LIMITS_NVX,
+        );
+        v.p_next(
+            StructureType::DEVICE_GENERATED_COMMANDS_LIMITS_NVX,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceGroupBindSparseInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGroupBindSparseInfo",
+            self.s_type,
+            StructureType::DEVICE_GROUP_BIND_SPARSE_INFO,
+        );
+        v.p_next(
+            StructureType::DEVICE_GROUP_BIND_SPARSE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceGroupCommandBufferBeginInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGroupCommandBufferBeginInfo",
+            self.s_type,
+            StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO,
+        );
+        v.p_next(
+            StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceGroupDeviceCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGroupDeviceCreateInfo",
+            self.s_type,
+            StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DeviceGroupDeviceCreateInfo",
+            "p_physical_devices",
+            self.p_physical_devices,
+            self.physical_device_count as usize,
+        );
+    }
+}
+impl Validate for DeviceGroupPresentCapabilitiesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGroupPresentCapabilitiesKHR",
+            self.s_type,
+            StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR,
+        );
+        v.p_next(
+            StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceGroupPresentInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGroupPresentInfoKHR",
+            self.s_type,
+            StructureType::DEVICE_GROUP_PRESENT_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::DEVICE_GROUP_PRESENT_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DeviceGroupPresentInfoKHR",
+            "p_device_masks",
+            self.p_device_masks,
+            self.swapchain_count as usize,
+        );
+    }
+}
+impl Validate for DeviceGroupRenderPassBeginInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGroupRenderPassBeginInfo",
+            self.s_type,
+            StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO,
+        );
+        v.p_next(
+            StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DeviceGroupRenderPassBeginInfo",
+            "p_device_render_areas",
+            self.p_device_render_areas,
+            self.device_render_area_count as usize,
+        );
+    }
+}
+impl Validate for DeviceGroupSubmitInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGroupSubmitInfo",
+            self.s_type,
+            StructureType::DEVICE_GROUP_SUBMIT_INFO,
+        );
+        v.p_next(
+            StructureType::DEVICE_GROUP_SUBMIT_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DeviceGroupSubmitInfo",
+            "p_wait_semaphore_device_indices",
+            self.p_wait_semaphore_device_indices,
+            self.wait_semaphore_count as usize,
+        );
+        v.array(
+            "DeviceGroupSubmitInfo",
+            "p_command_buffer_device_masks",
+            self.p_command_buffer_device_masks,
+            self.command_buffer_count as usize,
+        );
+        v.array(
+            "DeviceGroupSubmitInfo",
+            "p_signal_semaphore_device_indices",
+            self.p_signal_semaphore_device_indices,
+            self.signal_semaphore_count as usize,
+        );
+    }
+}
+impl Validate for DeviceGroupSwapchainCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceGroupSwapchainCreateInfoKHR",
+            self.s_type,
+            StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceMemoryOverallocationCreateInfoAMD {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceMemoryOverallocationCreateInfoAMD",
+            self.s_type,
+            StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD,
+        );
+        v.p_next(
+            StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceQueueCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceQueueCreateInfo",
+            self.s_type,
+            StructureType::DEVICE_QUEUE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::DEVICE_QUEUE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "DeviceQueueCreateInfo",
+            "p_queue_priorities",
+            self.p_queue_priorities,
+            self.queue_count as usize,
+        );
+    }
+}
+impl Validate for DeviceQueueGlobalPriorityCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceQueueGlobalPriorityCreateInfoEXT",
+            self.s_type,
+            StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DeviceQueueInfo2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DeviceQueueInfo2",
+            self.s_type,
+            StructureType::DEVICE_QUEUE_INFO_2,
+        );
+        v.p_next(
+            StructureType::DEVICE_QUEUE_INFO_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayEventInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayEventInfoEXT",
+            self.s_type,
+            StructureType::DISPLAY_EVENT_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DISPLAY_EVENT_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayModeCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayModeCreateInfoKHR",
+            self.s_type,
+            StructureType::DISPLAY_MODE_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::DISPLAY_MODE_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayModeProperties2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayModeProperties2KHR",
+            self.s_type,
+            StructureType::DISPLAY_MODE_PROPERTIES_2_KHR,
+        );
+        v.p_next(
+            StructureType::DISPLAY_MODE_PROPERTIES_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayPlaneCapabilities2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayPlaneCapabilities2KHR",
+            self.s_type,
+            StructureType::DISPLAY_PLANE_CAPABILITIES_2_KHR,
+        );
+        v.p_next(
+            StructureType::DISPLAY_PLANE_CAPABILITIES_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayPlaneInfo2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayPlaneInfo2KHR",
+            self.s_type,
+            StructureType::DISPLAY_PLANE_INFO_2_KHR,
+        );
+        v.p_next(
+            StructureType::DISPLAY_PLANE_INFO_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayPlaneProperties2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayPlaneProperties2KHR",
+            self.s_type,
+            StructureType::DISPLAY_PLANE_PROPERTIES_2_KHR,
+        );
+        v.p_next(
+            StructureType::DISPLAY_PLANE_PROPERTIES_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayPowerInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayPowerInfoEXT",
+            self.s_type,
+            StructureType::DISPLAY_POWER_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::DISPLAY_POWER_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayPresentInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayPresentInfoKHR",
+            self.s_type,
+            StructureType::DISPLAY_PRESENT_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::DISPLAY_PRESENT_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplayProperties2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplayProperties2KHR",
+            self.s_type,
+            StructureType::DISPLAY_PROPERTIES_2_KHR,
+        );
+        v.p_next(
+            StructureType::DISPLAY_PROPERTIES_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DisplaySurfaceCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DisplaySurfaceCreateInfoKHR",
+            self.s_type,
+            StructureType::DISPLAY_SURFACE_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::DISPLAY_SURFACE_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for DrmFormatModifierPropertiesListEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "DrmFormatModifierPropertiesListEXT",
+            self.s_type,
+            StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT,
+        );
+        v.p_next(
+            StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for EventCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "EventCreateInfo",
+            self.s_type,
+            StructureType::EVENT_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::EVENT_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExportFenceCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExportFenceCreateInfo",
+            self.s_type,
+            StructureType::EXPORT_FENCE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::EXPORT_FENCE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExportFenceWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExportFenceWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExportMemoryAllocateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExportMemoryAllocateInfo",
+            self.s_type,
+            StructureType::EXPORT_MEMORY_ALLOCATE_INFO,
+        );
+        v.p_next(
+            StructureType::EXPORT_MEMORY_ALLOCATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExportMemoryAllocateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExportMemoryAllocateInfoNV",
+            self.s_type,
+            StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExportMemoryWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExportMemoryWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExportMemoryWin32HandleInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExportMemoryWin32HandleInfoNV",
+            self.s_type,
+            StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExportSemaphoreCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExportSemaphoreCreateInfo",
+            self.s_type,
+            StructureType::EXPORT_SEMAPHORE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::EXPORT_SEMAPHORE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExportSemaphoreWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExportSemaphoreWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExternalBufferProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExternalBufferProperties",
+            self.s_type,
+            StructureType::EXTERNAL_BUFFER_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::EXTERNAL_BUFFER_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExternalFenceProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExternalFenceProperties",
+            self.s_type,
+            StructureType::EXTERNAL_FENCE_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::EXTERNAL_FENCE_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExternalFormatANDROID {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExternalFormatANDROID",
+            self.s_type,
+            StructureType::EXTERNAL_FORMAT_ANDROID,
+        );
+        v.p_next(
+            StructureType::EXTERNAL_FORMAT_ANDROID,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExternalImageFormatProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExternalImageFormatProperties",
+            self.s_type,
+            StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExternalMemoryBufferCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExternalMemoryBufferCreateInfo",
+            self.s_type,
+            StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExternalMemoryImageCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExternalMemoryImageCreateInfo",
+            self.s_type,
+            StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExternalMemoryImageCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExternalMemoryImageCreateInfoNV",
+            self.s_type,
+            StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ExternalSemaphoreProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ExternalSemaphoreProperties",
+            self.s_type,
+            StructureType::EXTERNAL_SEMAPHORE_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::EXTERNAL_SEMAPHORE_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for FenceCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "FenceCreateInfo",
+            self.s_type,
+            StructureType::FENCE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::FENCE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for FenceGetFdInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "FenceGetFdInfoKHR",
+            self.s_type,
+            StructureType::FENCE_GET_FD_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::FENCE_GET_FD_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for FenceGetWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "FenceGetWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::FENCE_GET_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::FENCE_GET_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for FormatProperties2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "FormatProperties2",
+            self.s_type,
+            StructureType::FORMAT_PROPERTIES_2,
+        );
+        v.p_next(
+            StructureType::FORMAT_PROPERTIES_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for FramebufferCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "FramebufferCreateInfo",
+            self.s_type,
+            StructureType::FRAMEBUFFER_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::FRAMEBUFFER_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "FramebufferCreateInfo",
+            "p_attachments",
+            self.p_attachments,
+            self.attachment_count as usize,
+        );
+    }
+}
+impl Validate for GeometryAABBNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "GeometryAABBNV",
+            self.s_type,
+            StructureType::GEOMETRY_AABB_NV,
+        );
+        v.p_next(
+            StructureType::GEOMETRY_AABB_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for GeometryDataNV {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        self.triangles.validate(v);
+        self.aabbs.validate(v);
+    }
+}
+impl Validate for GeometryNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type("GeometryNV", self.s_type, StructureType::GEOMETRY_NV);
+        v.p_next(StructureType::GEOMETRY_NV, self.p_next as *const c_void);
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        self.geometry.validate(v);
+    }
+}
+impl Validate for GeometryTrianglesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "GeometryTrianglesNV",
+            self.s_type,
+            StructureType::GEOMETRY_TRIANGLES_NV,
+        );
+        v.p_next(
+            StructureType::GEOMETRY_TRIANGLES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for GraphicsPipelineCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "GraphicsPipelineCreateInfo",
+            self.s_type,
+            StructureType::GRAPHICS_PIPELINE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::GRAPHICS_PIPELINE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "GraphicsPipelineCreateInfo",
+            "p_stages",
+            self.p_stages,
+            self.stage_count as usize,
+        );
+        v.structures(self.p_stages, self.stage_count as usize);
+        v.structures(self.p_vertex_input_state, 1);
+        v.structures(self.p_input_assembly_state, 1);
+        v.structures(self.p_tessellation_state, 1);
+        v.structures(self.p_viewport_state, 1);
+        v.structures(self.p_rasterization_state, 1);
+        v.structures(self.p_multisample_state, 1);
+        v.structures(self.p_depth_stencil_state, 1);
+        v.structures(self.p_color_blend_state, 1);
+        v.structures(self.p_dynamic_state, 1);
+    }
+}
+impl Validate for HdrMetadataEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "HdrMetadataEXT",
+            self.s_type,
+            StructureType::HDR_METADATA_EXT,
+        );
+        v.p_next(
+            StructureType::HDR_METADATA_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for IOSSurfaceCreateInfoMVK {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "IOSSurfaceCreateInfoMVK",
+            self.s_type,
+            StructureType::IOS_SURFACE_CREATE_INFO_M,
+        );
+        v.p_next(
+            StructureType::IOS_SURFACE_CREATE_INFO_M,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageCreateInfo",
+            self.s_type,
+            StructureType::IMAGE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::IMAGE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageDrmFormatModifierExplicitCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageDrmFormatModifierExplicitCreateInfoEXT",
+            self.s_type,
+            StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "ImageDrmFormatModifierExplicitCreateInfoEXT",
+            "p_plane_layouts",
+            self.p_plane_layouts,
+            self.drm_format_modifier_plane_count as usize,
+        );
+    }
+}
+impl Validate for ImageDrmFormatModifierListCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageDrmFormatModifierListCreateInfoEXT",
+            self.s_type,
+            StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "ImageDrmFormatModifierListCreateInfoEXT",
+            "p_drm_format_modifiers",
+            self.p_drm_format_modifiers,
+            self.drm_format_modifier_count as usize,
+        );
+    }
+}
+impl Validate for ImageDrmFormatModifierPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageDrmFormatModifierPropertiesEXT",
+            self.s_type,
+            StructureType::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageFormatListCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageFormatListCreateInfoKHR",
+            self.s_type,
+            StructureType::IMAGE_FORMAT_LIST_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::IMAGE_FORMAT_LIST_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "ImageFormatListCreateInfoKHR",
+            "p_view_formats",
+            self.p_view_formats,
+            self.view_format_count as usize,
+        );
+    }
+}
+impl Validate for ImageFormatProperties2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageFormatProperties2",
+            self.s_type,
+            StructureType::IMAGE_FORMAT_PROPERTIES_2,
+        );
+        v.p_next(
+            StructureType::IMAGE_FORMAT_PROPERTIES_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageMemoryBarrier {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageMemoryBarrier",
+            self.s_type,
+            StructureType::IMAGE_MEMORY_BARRIER,
+        );
+        v.p_next(
+            StructureType::IMAGE_MEMORY_BARRIER,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageMemoryRequirementsInfo2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageMemoryRequirementsInfo2",
+            self.s_type,
+            StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2,
+        );
+        v.p_next(
+            StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImagePipeSurfaceCreateInfoFUCHSIA {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImagePipeSurfaceCreateInfoFUCHSIA",
+            self.s_type,
+            StructureType::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA,
+        );
+        v.p_next(
+            StructureType::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImagePlaneMemoryRequirementsInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImagePlaneMemoryRequirementsInfo",
+            self.s_type,
+            StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO,
+        );
+        v.p_next(
+            StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageSparseMemoryRequirementsInfo2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageSparseMemoryRequirementsInfo2",
+            self.s_type,
+            StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2,
+        );
+        v.p_next(
+            StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageSwapchainCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageSwapchainCreateInfoKHR",
+            self.s_type,
+            StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageViewASTCDecodeModeEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageViewASTCDecodeModeEXT",
+            self.s_type,
+            StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT,
+        );
+        v.p_next(
+            StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageViewCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageViewCreateInfo",
+            self.s_type,
+            StructureType::IMAGE_VIEW_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::IMAGE_VIEW_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImageViewUsageCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImageViewUsageCreateInfo",
+            self.s_type,
+            StructureType::IMAGE_VIEW_USAGE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::IMAGE_VIEW_USAGE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportAndroidHardwareBufferInfoANDROID {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportAndroidHardwareBufferInfoANDROID",
+            self.s_type,
+            StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+        );
+        v.p_next(
+            StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportFenceFdInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportFenceFdInfoKHR",
+            self.s_type,
+            StructureType::IMPORT_FENCE_FD_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::IMPORT_FENCE_FD_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportFenceWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportFenceWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportMemoryFdInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportMemoryFdInfoKHR",
+            self.s_type,
+            StructureType::IMPORT_MEMORY_FD_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::IMPORT_MEMORY_FD_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportMemoryHostPointerInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportMemoryHostPointerInfoEXT",
+            self.s_type,
+            StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportMemoryWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportMemoryWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportMemoryWin32HandleInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportMemoryWin32HandleInfoNV",
+            self.s_type,
+            StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportSemaphoreFdInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportSemaphoreFdInfoKHR",
+            self.s_type,
+            StructureType::IMPORT_SEMAPHORE_FD_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::IMPORT_SEMAPHORE_FD_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ImportSemaphoreWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ImportSemaphoreWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for IndirectCommandsLayoutCreateInfoNVX {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "IndirectCommandsLayoutCreateInfoNVX",
+            self.s_type,
+            StructureType::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NVX,
+        );
+        v.p_next(
+            StructureType::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NVX,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "IndirectCommandsLayoutCreateInfoNVX",
+            "p_tokens",
+            self.p_tokens,
+            self.token_count as usize,
+        );
+    }
+}
+impl Validate for InstanceCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "InstanceCreateInfo",
+            self.s_type,
+            StructureType::INSTANCE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::INSTANCE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.structures(self.p_application_info, 1);
+        v.array(
+            "InstanceCreateInfo",
+            "pp_enabled_layer_names",
+            self.pp_enabled_layer_names,
+            self.enabled_layer_count as usize,
+        );
+        v.array(
+            "InstanceCreateInfo",
+            "pp_enabled_extension_names",
+            self.pp_enabled_extension_names,
+            self.enabled_extension_count as usize,
+        );
+    }
+}
+impl Validate for MacOSSurfaceCreateInfoMVK {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MacOSSurfaceCreateInfoMVK",
+            self.s_type,
+            StructureType::MACOS_SURFACE_CREATE_INFO_M,
+        );
+        v.p_next(
+            StructureType::MACOS_SURFACE_CREATE_INFO_M,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MappedMemoryRange {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MappedMemoryRange",
+            self.s_type,
+            StructureType::MAPPED_MEMORY_RANGE,
+        );
+        v.p_next(
+            StructureType::MAPPED_MEMORY_RANGE,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryAllocateFlagsInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryAllocateFlagsInfo",
+            self.s_type,
+            StructureType::MEMORY_ALLOCATE_FLAGS_INFO,
+        );
+        v.p_next(
+            StructureType::MEMORY_ALLOCATE_FLAGS_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryAllocateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryAllocateInfo",
+            self.s_type,
+            StructureType::MEMORY_ALLOCATE_INFO,
+        );
+        v.p_next(
+            StructureType::MEMORY_ALLOCATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryBarrier {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type("MemoryBarrier", self.s_type, StructureType::MEMORY_BARRIER);
+        v.p_next(StructureType::MEMORY_BARRIER, self.p_next as *const c_void);
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryDedicatedAllocateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryDedicatedAllocateInfo",
+            self.s_type,
+            StructureType::MEMORY_DEDICATED_ALLOCATE_INFO,
+        );
+        v.p_next(
+            StructureType::MEMORY_DEDICATED_ALLOCATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryDedicatedRequirements {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryDedicatedRequirements",
+            self.s_type,
+            StructureType::MEMORY_DEDICATED_REQUIREMENTS,
+        );
+        v.p_next(
+            StructureType::MEMORY_DEDICATED_REQUIREMENTS,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryFdPropertiesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryFdPropertiesKHR",
+            self.s_type,
+            StructureType::MEMORY_FD_PROPERTIES_KHR,
+        );
+        v.p_next(
+            StructureType::MEMORY_FD_PROPERTIES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryGetAndroidHardwareBufferInfoANDROID {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryGetAndroidHardwareBufferInfoANDROID",
+            self.s_type,
+            StructureType::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+        );
+        v.p_next(
+            StructureType::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryGetFdInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryGetFdInfoKHR",
+            self.s_type,
+            StructureType::MEMORY_GET_FD_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::MEMORY_GET_FD_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryGetWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryGetWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::MEMORY_GET_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::MEMORY_GET_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryHostPointerPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryHostPointerPropertiesEXT",
+            self.s_type,
+            StructureType::MEMORY_HOST_POINTER_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::MEMORY_HOST_POINTER_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryRequirements2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryRequirements2",
+            self.s_type,
+            StructureType::MEMORY_REQUIREMENTS_2,
+        );
+        v.p_next(
+            StructureType::MEMORY_REQUIREMENTS_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MemoryWin32HandlePropertiesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MemoryWin32HandlePropertiesKHR",
+            self.s_type,
+            StructureType::MEMORY_WIN32_HANDLE_PROPERTIES_KHR,
+        );
+        v.p_next(
+            StructureType::MEMORY_WIN32_HANDLE_PROPERTIES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for MultisamplePropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "MultisamplePropertiesEXT",
+            self.s_type,
+            StructureType::MULTISAMPLE_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::MULTISAMPLE_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for NativeBufferANDROID {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "NativeBufferANDROID",
+            self.s_type,
+            StructureType::NATIVE_BUFFER_ANDROID,
+        );
+        v.p_next(
+            StructureType::NATIVE_BUFFER_ANDROID,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ObjectTableCreateInfoNVX {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ObjectTableCreateInfoNVX",
+            self.s_type,
+            StructureType::OBJECT_TABLE_CREATE_INFO_NVX,
+        );
+        v.p_next(
+            StructureType::OBJECT_TABLE_CREATE_INFO_NVX,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "ObjectTableCreateInfoNVX",
+            "p_object_entry_types",
+            self.p_object_entry_types,
+            self.object_count as usize,
+        );
+        v.array(
+            "ObjectTableCreateInfoNVX",
+            "p_object_entry_counts",
+            self.p_object_entry_counts,
+            self.object_count as usize,
+        );
+        v.array(
+            "ObjectTableCreateInfoNVX",
+            "p_object_entry_usage_flags",
+            self.p_object_entry_usage_flags,
+            self.object_count as usize,
+        );
+    }
+}
+impl Validate for PhysicalDevice16BitStorageFeatures {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDevice16BitStorageFeatures",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDevice8BitStorageFeaturesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDevice8BitStorageFeaturesKHR",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceASTCDecodeFeaturesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceASTCDecodeFeaturesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceBlendOperationAdvancedFeaturesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceBlendOperationAdvancedFeaturesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceBlendOperationAdvancedPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceBlendOperationAdvancedPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceComputeShaderDerivativesFeaturesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceComputeShaderDerivativesFeaturesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceConditionalRenderingFeaturesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceConditionalRenderingFeaturesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceConservativeRasterizationPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceConservativeRasterizationPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceCornerSampledImageFeaturesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceCornerSampledImageFeaturesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceDescriptorIndexingFeaturesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceDescriptorIndexingFeaturesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceDescriptorIndexingPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceDescriptorIndexingPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceDiscardRectanglePropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceDiscardRectanglePropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceDriverPropertiesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceDriverPropertiesKHR",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceExclusiveScissorFeaturesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceExclusiveScissorFeaturesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceExternalBufferInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceExternalBufferInfo",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceExternalFenceInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceExternalFenceInfo",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceExternalImageFormatInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceExternalImageFormatInfo",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceExternalMemoryHostPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceExternalMemoryHostPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceExternalSemaphoreInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceExternalSemaphoreInfo",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceFeatures2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceFeatures2",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_FEATURES_2,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceFragmentShaderBarycentricFeaturesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceFragmentShaderBarycentricFeaturesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceGroupProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceGroupProperties",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceIDProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceIDProperties",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_ID_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_ID_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceImageDrmFormatModifierInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceImageDrmFormatModifierInfoEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceImageFormatInfo2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceImageFormatInfo2",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceInlineUniformBlockFeaturesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceInlineUniformBlockFeaturesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceInlineUniformBlockPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceInlineUniformBlockPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceMaintenance3Properties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceMaintenance3Properties",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceMemoryProperties2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceMemoryProperties2",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceMeshShaderFeaturesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceMeshShaderFeaturesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceMeshShaderPropertiesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceMeshShaderPropertiesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceMultiviewFeatures {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceMultiviewFeatures",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceMultiviewProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceMultiviewProperties",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDevicePCIBusInfoPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDevicePCIBusInfoPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDevicePointClippingProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDevicePointClippingProperties",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceProperties2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceProperties2",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceProtectedMemoryFeatures {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceProtectedMemoryFeatures",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceProtectedMemoryProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceProtectedMemoryProperties",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDevicePushDescriptorPropertiesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDevicePushDescriptorPropertiesKHR",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceRayTracingPropertiesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceRayTracingPropertiesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceRepresentativeFragmentTestFeaturesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceRepresentativeFragmentTestFeaturesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceSampleLocationsPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceSampleLocationsPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceSamplerFilterMinmaxPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceSamplerFilterMinmaxPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceSamplerYcbcrConversionFeatures {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceSamplerYcbcrConversionFeatures",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceShaderAtomicInt64FeaturesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceShaderAtomicInt64FeaturesKHR",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceShaderCorePropertiesAMD {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceShaderCorePropertiesAMD",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceShaderDrawParameterFeatures {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceShaderDrawParameterFeatures",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceShaderImageFootprintFeaturesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceShaderImageFootprintFeaturesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceShadingRateImageFeaturesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceShadingRateImageFeaturesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceShadingRateImagePropertiesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceShadingRateImagePropertiesNV",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceSparseImageFormatInfo2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceSparseImageFormatInfo2",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceSubgroupProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceSubgroupProperties",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceSurfaceInfo2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceSurfaceInfo2KHR",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceTransformFeedbackFeaturesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceTransformFeedbackFeaturesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceTransformFeedbackPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceTransformFeedbackPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceVariablePointerFeatures {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceVariablePointerFeatures",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceVertexAttributeDivisorFeaturesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceVertexAttributeDivisorFeaturesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceVertexAttributeDivisorPropertiesEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceVertexAttributeDivisorPropertiesEXT",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PhysicalDeviceVulkanMemoryModelFeaturesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PhysicalDeviceVulkanMemoryModelFeaturesKHR",
+            self.s_type,
+            StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR,
+        );
+        v.p_next(
+            StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineCacheCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineCacheCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_CACHE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_CACHE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PipelineCacheCreateInfo",
+            "p_initial_data",
+            self.p_initial_data,
+            self.initial_data_size as usize,
+        );
+    }
+}
+impl Validate for PipelineColorBlendAdvancedStateCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineColorBlendAdvancedStateCreateInfoEXT",
+            self.s_type,
+            StructureType::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineColorBlendStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineColorBlendStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PipelineColorBlendStateCreateInfo",
+            "p_attachments",
+            self.p_attachments,
+            self.attachment_count as usize,
+        );
+    }
+}
+impl Validate for PipelineCoverageModulationStateCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineCoverageModulationStateCreateInfoNV",
+            self.s_type,
+            StructureType::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineCoverageToColorStateCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineCoverageToColorStateCreateInfoNV",
+            self.s_type,
+            StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineDepthStencilStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineDepthStencilStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineDiscardRectangleStateCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineDiscardRectangleStateCreateInfoEXT",
+            self.s_type,
+            StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineDynamicStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineDynamicStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PipelineDynamicStateCreateInfo",
+            "p_dynamic_states",
+            self.p_dynamic_states,
+            self.dynamic_state_count as usize,
+        );
+    }
+}
+impl Validate for PipelineInputAssemblyStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineInputAssemblyStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineLayoutCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineLayoutCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_LAYOUT_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_LAYOUT_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PipelineLayoutCreateInfo",
+            "p_set_layouts",
+            self.p_set_layouts,
+            self.set_layout_count as usize,
+        );
+        v.array(
+            "PipelineLayoutCreateInfo",
+            "p_push_constant_ranges",
+            self.p_push_constant_ranges,
+            self.push_constant_range_count as usize,
+        );
+    }
+}
+impl Validate for PipelineMultisampleStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineMultisampleStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineRasterizationConservativeStateCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineRasterizationConservativeStateCreateInfoEXT",
+            self.s_type,
+            StructureType::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineRasterizationStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineRasterizationStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineRasterizationStateRasterizationOrderAMD {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineRasterizationStateRasterizationOrderAMD",
+            self.s_type,
+            StructureType::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD,
+        );
+        v.p_next(
+            StructureType::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineRasterizationStateStreamCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineRasterizationStateStreamCreateInfoEXT",
+            self.s_type,
+            StructureType::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineRepresentativeFragmentTestStateCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineRepresentativeFragmentTestStateCreateInfoNV",
+            self.s_type,
+            StructureType::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineSampleLocationsStateCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineSampleLocationsStateCreateInfoEXT",
+            self.s_type,
+            StructureType::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        self.sample_locations_info.validate(v);
+    }
+}
+impl Validate for PipelineShaderStageCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineShaderStageCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.structures(self.p_specialization_info, 1);
+    }
+}
+impl Validate for PipelineTessellationDomainOriginStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineTessellationDomainOriginStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineTessellationStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineTessellationStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineVertexInputDivisorStateCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineVertexInputDivisorStateCreateInfoEXT",
+            self.s_type,
+            StructureType::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PipelineVertexInputDivisorStateCreateInfoEXT",
+            "p_vertex_binding_divisors",
+            self.p_vertex_binding_divisors,
+            self.vertex_binding_divisor_count as usize,
+        );
+    }
+}
+impl Validate for PipelineVertexInputStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineVertexInputStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PipelineVertexInputStateCreateInfo",
+            "p_vertex_binding_descriptions",
+            self.p_vertex_binding_descriptions,
+            self.vertex_binding_description_count as usize,
+        );
+        v.array(
+            "PipelineVertexInputStateCreateInfo",
+            "p_vertex_attribute_descriptions",
+            self.p_vertex_attribute_descriptions,
+            self.vertex_attribute_description_count as usize,
+        );
+    }
+}
+impl Validate for PipelineViewportCoarseSampleOrderStateCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineViewportCoarseSampleOrderStateCreateInfoNV",
+            self.s_type,
+            StructureType::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PipelineViewportCoarseSampleOrderStateCreateInfoNV",
+            "p_custom_sample_orders",
+            self.p_custom_sample_orders,
+            self.custom_sample_order_count as usize,
+        );
+        v.structures(
+            self.p_custom_sample_orders,
+            self.custom_sample_order_count as usize,
+        );
+    }
+}
+impl Validate for PipelineViewportExclusiveScissorStateCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineViewportExclusiveScissorStateCreateInfoNV",
+            self.s_type,
+            StructureType::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineViewportShadingRateImageStateCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineViewportShadingRateImageStateCreateInfoNV",
+            self.s_type,
+            StructureType::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.structures(self.p_shading_rate_palettes, self.viewport_count as usize);
+    }
+}
+impl Validate for PipelineViewportStateCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineViewportStateCreateInfo",
+            self.s_type,
+            StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PipelineViewportSwizzleStateCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineViewportSwizzleStateCreateInfoNV",
+            self.s_type,
+            StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PipelineViewportSwizzleStateCreateInfoNV",
+            "p_viewport_swizzles",
+            self.p_viewport_swizzles,
+            self.viewport_count as usize,
+        );
+    }
+}
+impl Validate for PipelineViewportWScalingStateCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PipelineViewportWScalingStateCreateInfoNV",
+            self.s_type,
+            StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PresentInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PresentInfoKHR",
+            self.s_type,
+            StructureType::PRESENT_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::PRESENT_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "PresentInfoKHR",
+            "p_wait_semaphores",
+            self.p_wait_semaphores,
+            self.wait_semaphore_count as usize,
+        );
+        v.array(
+            "PresentInfoKHR",
+            "p_swapchains",
+            self.p_swapchains,
+            self.swapchain_count as usize,
+        );
+        v.array(
+            "PresentInfoKHR",
+            "p_image_indices",
+            self.p_image_indices,
+            self.swapchain_count as usize,
+        );
+    }
+}
+impl Validate for PresentRegionKHR {
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for PresentRegionsKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PresentRegionsKHR",
+            self.s_type,
+            StructureType::PRESENT_REGIONS_KHR,
+        );
+        v.p_next(
+            StructureType::PRESENT_REGIONS_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.structures(self.p_regions, self.swapchain_count as usize);
+    }
+}
+impl Validate for PresentTimesInfoGOOGLE {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "PresentTimesInfoGOOGLE",
+            self.s_type,
+            StructureType::PRESENT_TIMES_INFO_GOOGLE,
+        );
+        v.p_next(
+            StructureType::PRESENT_TIMES_INFO_GOOGLE,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ProtectedSubmitInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ProtectedSubmitInfo",
+            self.s_type,
+            StructureType::PROTECTED_SUBMIT_INFO,
+        );
+        v.p_next(
+            StructureType::PROTECTED_SUBMIT_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for QueryPoolCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "QueryPoolCreateInfo",
+            self.s_type,
+            StructureType::QUERY_POOL_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::QUERY_POOL_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for QueueFamilyCheckpointPropertiesNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "QueueFamilyCheckpointPropertiesNV",
+            self.s_type,
+            StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV,
+        );
+        v.p_next(
+            StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for QueueFamilyProperties2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "QueueFamilyProperties2",
+            self.s_type,
+            StructureType::QUEUE_FAMILY_PROPERTIES_2,
+        );
+        v.p_next(
+            StructureType::QUEUE_FAMILY_PROPERTIES_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for RayTracingPipelineCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "RayTracingPipelineCreateInfoNV",
+            self.s_type,
+            StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "RayTracingPipelineCreateInfoNV",
+            "p_stages",
+            self.p_stages,
+            self.stage_count as usize,
+        );
+        v.structures(self.p_stages, self.stage_count as usize);
+        v.array(
+            "RayTracingPipelineCreateInfoNV",
+            "p_groups",
+            self.p_groups,
+            self.group_count as usize,
+        );
+        v.structures(self.p_groups, self.group_count as usize);
+    }
+}
+impl Validate for RayTracingShaderGroupCreateInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "RayTracingShaderGroupCreateInfoNV",
+            self.s_type,
+            StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for RenderPassBeginInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "RenderPassBeginInfo",
+            self.s_type,
+            StructureType::RENDER_PASS_BEGIN_INFO,
+        );
+        v.p_next(
+            StructureType::RENDER_PASS_BEGIN_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "RenderPassBeginInfo",
+            "p_clear_values",
+            self.p_clear_values,
+            self.clear_value_count as usize,
+        );
+    }
+}
+impl Validate for RenderPassCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "RenderPassCreateInfo",
+            self.s_type,
+            StructureType::RENDER_PASS_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::RENDER_PASS_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "RenderPassCreateInfo",
+            "p_attachments",
+            self.p_attachments,
+            self.attachment_count as usize,
+        );
+        v.array(
+            "RenderPassCreateInfo",
+            "p_subpasses",
+            self.p_subpasses,
+            self.subpass_count as usize,
+        );
+        v.structures(self.p_subpasses, self.subpass_count as usize);
+        v.array(
+            "RenderPassCreateInfo",
+            "p_dependencies",
+            self.p_dependencies,
+            self.dependency_count as usize,
+        );
+    }
+}
+impl Validate for RenderPassCreateInfo2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "RenderPassCreateInfo2KHR",
+            self.s_type,
+            StructureType::RENDER_PASS_CREATE_INFO_2_KHR,
+        );
+        v.p_next(
+            StructureType::RENDER_PASS_CREATE_INFO_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "RenderPassCreateInfo2KHR",
+            "p_attachments",
+            self.p_attachments,
+            self.attachment_count as usize,
+        );
+        v.structures(self.p_attachments, self.attachment_count as usize);
+        v.array(
+            "RenderPassCreateInfo2KHR",
+            "p_subpasses",
+            self.p_subpasses,
+            self.subpass_count as usize,
+        );
+        v.structures(self.p_subpasses, self.subpass_count as usize);
+        v.array(
+            "RenderPassCreateInfo2KHR",
+            "p_dependencies",
+            self.p_dependencies,
+            self.dependency_count as usize,
+        );
+        v.structures(self.p_dependencies, self.dependency_count as usize);
+        v.array(
+            "RenderPassCreateInfo2KHR",
+            "p_correlated_view_masks",
+            self.p_correlated_view_masks,
+            self.correlated_view_mask_count as usize,
+        );
+    }
+}
+impl Validate for RenderPassInputAttachmentAspectCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "RenderPassInputAttachmentAspectCreateInfo",
+            self.s_type,
+            StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "RenderPassInputAttachmentAspectCreateInfo",
+            "p_aspect_references",
+            self.p_aspect_references,
+            self.aspect_reference_count as usize,
+        );
+    }
+}
+impl Validate for RenderPassMultiviewCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "RenderPassMultiviewCreateInfo",
+            self.s_type,
+            StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "RenderPassMultiviewCreateInfo",
+            "p_view_masks",
+            self.p_view_masks,
+            self.subpass_count as usize,
+        );
+        v.array(
+            "RenderPassMultiviewCreateInfo",
+            "p_view_offsets",
+            self.p_view_offsets,
+            self.dependency_count as usize,
+        );
+        v.array(
+            "RenderPassMultiviewCreateInfo",
+            "p_correlation_masks",
+            self.p_correlation_masks,
+            self.correlation_mask_count as usize,
+        );
+    }
+}
+impl Validate for RenderPassSampleLocationsBeginInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "RenderPassSampleLocationsBeginInfoEXT",
+            self.s_type,
+            StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "RenderPassSampleLocationsBeginInfoEXT",
+            "p_attachment_initial_sample_locations",
+            self.p_attachment_initial_sample_locations,
+            self.attachment_initial_sample_locations_count as usize,
+        );
+        v.structures(
+            self.p_attachment_initial_sample_locations,
+            self.attachment_initial_sample_locations_count as usize,
+        );
+        v.array(
+            "RenderPassSampleLocationsBeginInfoEXT",
+            "p_post_subpass_sample_locations",
+            self.p_post_subpass_sample_locations,
+            self.post_subpass_sample_locations_count as usize,
+        );
+        v.structures(
+            self.p_post_subpass_sample_locations,
+            self.post_subpass_sample_locations_count as usize,
+        );
+    }
+}
+impl Validate for SampleLocationsInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SampleLocationsInfoEXT",
+            self.s_type,
+            StructureType::SAMPLE_LOCATIONS_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::SAMPLE_LOCATIONS_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "SampleLocationsInfoEXT",
+            "p_sample_locations",
+            self.p_sample_locations,
+            self.sample_locations_count as usize,
+        );
+    }
+}
+impl Validate for SamplerCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SamplerCreateInfo",
+            self.s_type,
+            StructureType::SAMPLER_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::SAMPLER_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SamplerReductionModeCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SamplerReductionModeCreateInfoEXT",
+            self.s_type,
+            StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SamplerYcbcrConversionCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SamplerYcbcrConversionCreateInfo",
+            self.s_type,
+            StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SamplerYcbcrConversionImageFormatProperties {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SamplerYcbcrConversionImageFormatProperties",
+            self.s_type,
+            StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES,
+        );
+        v.p_next(
+            StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SamplerYcbcrConversionInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SamplerYcbcrConversionInfo",
+            self.s_type,
+            StructureType::SAMPLER_YCBCR_CONVERSION_INFO,
+        );
+        v.p_next(
+            StructureType::SAMPLER_YCBCR_CONVERSION_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SemaphoreCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SemaphoreCreateInfo",
+            self.s_type,
+            StructureType::SEMAPHORE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::SEMAPHORE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SemaphoreGetFdInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SemaphoreGetFdInfoKHR",
+            self.s_type,
+            StructureType::SEMAPHORE_GET_FD_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::SEMAPHORE_GET_FD_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SemaphoreGetWin32HandleInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SemaphoreGetWin32HandleInfoKHR",
+            self.s_type,
+            StructureType::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ShaderModuleCreateInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ShaderModuleCreateInfo",
+            self.s_type,
+            StructureType::SHADER_MODULE_CREATE_INFO,
+        );
+        v.p_next(
+            StructureType::SHADER_MODULE_CREATE_INFO,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "ShaderModuleCreateInfo",
+            "p_code",
+            self.p_code,
+            self.code_size / 4,
+        );
+    }
+}
+impl Validate for ShaderModuleValidationCacheCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ShaderModuleValidationCacheCreateInfoEXT",
+            self.s_type,
+            StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ShadingRatePaletteNV {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "ShadingRatePaletteNV",
+            "p_shading_rate_palette_entries",
+            self.p_shading_rate_palette_entries,
+            self.shading_rate_palette_entry_count as usize,
+        );
+    }
+}
+impl Validate for SharedPresentSurfaceCapabilitiesKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SharedPresentSurfaceCapabilitiesKHR",
+            self.s_type,
+            StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR,
+        );
+        v.p_next(
+            StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SparseBufferMemoryBindInfo {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "SparseBufferMemoryBindInfo",
+            "p_binds",
+            self.p_binds,
+            self.bind_count as usize,
+        );
+    }
+}
+impl Validate for SparseImageFormatProperties2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SparseImageFormatProperties2",
+            self.s_type,
+            StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2,
+        );
+        v.p_next(
+            StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SparseImageMemoryBindInfo {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "SparseImageMemoryBindInfo",
+            "p_binds",
+            self.p_binds,
+            self.bind_count as usize,
+        );
+    }
+}
+impl Validate for SparseImageMemoryRequirements2 {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SparseImageMemoryRequirements2",
+            self.s_type,
+            StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2,
+        );
+        v.p_next(
+            StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SparseImageOpaqueMemoryBindInfo {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "SparseImageOpaqueMemoryBindInfo",
+            "p_binds",
+            self.p_binds,
+            self.bind_count as usize,
+        );
+    }
+}
+impl Validate for SpecializationInfo {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "SpecializationInfo",
+            "p_map_entries",
+            self.p_map_entries,
+            self.map_entry_count as usize,
+        );
+        v.array(
+            "SpecializationInfo",
+            "p_data",
+            self.p_data,
+            self.data_size as usize,
+        );
+    }
+}
+impl Validate for SubmitInfo {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type("SubmitInfo", self.s_type, StructureType::SUBMIT_INFO);
+        v.p_next(StructureType::SUBMIT_INFO, self.p_next as *const c_void);
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "SubmitInfo",
+            "p_wait_semaphores",
+            self.p_wait_semaphores,
+            self.wait_semaphore_count as usize,
+        );
+        v.array(
+            "SubmitInfo",
+            "p_wait_dst_stage_mask",
+            self.p_wait_dst_stage_mask,
+            self.wait_semaphore_count as usize,
+        );
+        v.array(
+            "SubmitInfo",
+            "p_command_buffers",
+            self.p_command_buffers,
+            self.command_buffer_count as usize,
+        );
+        v.array(
+            "SubmitInfo",
+            "p_signal_semaphores",
+            self.p_signal_semaphores,
+            self.signal_semaphore_count as usize,
+        );
+    }
+}
+impl Validate for SubpassBeginInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SubpassBeginInfoKHR",
+            self.s_type,
+            StructureType::SUBPASS_BEGIN_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::SUBPASS_BEGIN_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SubpassDependency2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SubpassDependency2KHR",
+            self.s_type,
+            StructureType::SUBPASS_DEPENDENCY_2_KHR,
+        );
+        v.p_next(
+            StructureType::SUBPASS_DEPENDENCY_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SubpassDescription {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "SubpassDescription",
+            "p_input_attachments",
+            self.p_input_attachments,
+            self.input_attachment_count as usize,
+        );
+        v.array(
+            "SubpassDescription",
+            "p_color_attachments",
+            self.p_color_attachments,
+            self.color_attachment_count as usize,
+        );
+        v.array(
+            "SubpassDescription",
+            "p_preserve_attachments",
+            self.p_preserve_attachments,
+            self.preserve_attachment_count as usize,
+        );
+    }
+}
+impl Validate for SubpassDescription2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SubpassDescription2KHR",
+            self.s_type,
+            StructureType::SUBPASS_DESCRIPTION_2_KHR,
+        );
+        v.p_next(
+            StructureType::SUBPASS_DESCRIPTION_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "SubpassDescription2KHR",
+            "p_input_attachments",
+            self.p_input_attachments,
+            self.input_attachment_count as usize,
+        );
+        v.structures(
+            self.p_input_attachments,
+            self.input_attachment_count as usize,
+        );
+        v.array(
+            "SubpassDescription2KHR",
+            "p_color_attachments",
+            self.p_color_attachments,
+            self.color_attachment_count as usize,
+        );
+        v.structures(
+            self.p_color_attachments,
+            self.color_attachment_count as usize,
+        );
+        v.structures(
+            self.p_resolve_attachments,
+            self.color_attachment_count as usize,
+        );
+        v.structures(self.p_depth_stencil_attachment, 1);
+        v.array(
+            "SubpassDescription2KHR",
+            "p_preserve_attachments",
+            self.p_preserve_attachments,
+            self.preserve_attachment_count as usize,
+        );
+    }
+}
+impl Validate for SubpassEndInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SubpassEndInfoKHR",
+            self.s_type,
+            StructureType::SUBPASS_END_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::SUBPASS_END_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SubpassSampleLocationsEXT {
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        self.sample_locations_info.validate(v);
+    }
+}
+impl Validate for SurfaceCapabilities2EXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SurfaceCapabilities2EXT",
+            self.s_type,
+            StructureType::SURFACE_CAPABILITIES_2_EXT,
+        );
+        v.p_next(
+            StructureType::SURFACE_CAPABILITIES_2_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SurfaceCapabilities2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SurfaceCapabilities2KHR",
+            self.s_type,
+            StructureType::SURFACE_CAPABILITIES_2_KHR,
+        );
+        v.p_next(
+            StructureType::SURFACE_CAPABILITIES_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SurfaceFormat2KHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SurfaceFormat2KHR",
+            self.s_type,
+            StructureType::SURFACE_FORMAT_2_KHR,
+        );
+        v.p_next(
+            StructureType::SURFACE_FORMAT_2_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SwapchainCounterCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SwapchainCounterCreateInfoEXT",
+            self.s_type,
+            StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for SwapchainCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "SwapchainCreateInfoKHR",
+            self.s_type,
+            StructureType::SWAPCHAIN_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::SWAPCHAIN_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for TextureLODGatherFormatPropertiesAMD {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "TextureLODGatherFormatPropertiesAMD",
+            self.s_type,
+            StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD,
+        );
+        v.p_next(
+            StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for ValidationCacheCreateInfoEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ValidationCacheCreateInfoEXT",
+            self.s_type,
+            StructureType::VALIDATION_CACHE_CREATE_INFO_EXT,
+        );
+        v.p_next(
+            StructureType::VALIDATION_CACHE_CREATE_INFO_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "ValidationCacheCreateInfoEXT",
+            "p_initial_data",
+            self.p_initial_data,
+            self.initial_data_size as usize,
+        );
+    }
+}
+impl Validate for ValidationFlagsEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ValidationFlagsEXT",
+            self.s_type,
+            StructureType::VALIDATION_FLAGS_EXT,
+        );
+        v.p_next(
+            StructureType::VALIDATION_FLAGS_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "ValidationFlagsEXT",
+            "p_disabled_validation_checks",
+            self.p_disabled_validation_checks,
+            self.disabled_validation_check_count as usize,
+        );
+    }
+}
+impl Validate for ViSurfaceCreateInfoNN {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "ViSurfaceCreateInfoNN",
+            self.s_type,
+            StructureType::VI_SURFACE_CREATE_INFO_NN,
+        );
+        v.p_next(
+            StructureType::VI_SURFACE_CREATE_INFO_NN,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for WaylandSurfaceCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "WaylandSurfaceCreateInfoKHR",
+            self.s_type,
+            StructureType::WAYLAND_SURFACE_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::WAYLAND_SURFACE_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for Win32KeyedMutexAcquireReleaseInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "Win32KeyedMutexAcquireReleaseInfoKHR",
+            self.s_type,
+            StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoKHR",
+            "p_acquire_syncs",
+            self.p_acquire_syncs,
+            self.acquire_count as usize,
+        );
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoKHR",
+            "p_acquire_keys",
+            self.p_acquire_keys,
+            self.acquire_count as usize,
+        );
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoKHR",
+            "p_acquire_timeouts",
+            self.p_acquire_timeouts,
+            self.acquire_count as usize,
+        );
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoKHR",
+            "p_release_syncs",
+            self.p_release_syncs,
+            self.release_count as usize,
+        );
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoKHR",
+            "p_release_keys",
+            self.p_release_keys,
+            self.release_count as usize,
+        );
+    }
+}
+impl Validate for Win32KeyedMutexAcquireReleaseInfoNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "Win32KeyedMutexAcquireReleaseInfoNV",
+            self.s_type,
+            StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV,
+        );
+        v.p_next(
+            StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoNV",
+            "p_acquire_syncs",
+            self.p_acquire_syncs,
+            self.acquire_count as usize,
+        );
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoNV",
+            "p_acquire_keys",
+            self.p_acquire_keys,
+            self.acquire_count as usize,
+        );
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoNV",
+            "p_acquire_timeout_milliseconds",
+            self.p_acquire_timeout_milliseconds,
+            self.acquire_count as usize,
+        );
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoNV",
+            "p_release_syncs",
+            self.p_release_syncs,
+            self.release_count as usize,
+        );
+        v.array(
+            "Win32KeyedMutexAcquireReleaseInfoNV",
+            "p_release_keys",
+            self.p_release_keys,
+            self.release_count as usize,
+        );
+    }
+}
+impl Validate for Win32SurfaceCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "Win32SurfaceCreateInfoKHR",
+            self.s_type,
+            StructureType::WIN32_SURFACE_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::WIN32_SURFACE_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for WriteDescriptorSet {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "WriteDescriptorSet",
+            self.s_type,
+            StructureType::WRITE_DESCRIPTOR_SET,
+        );
+        v.p_next(
+            StructureType::WRITE_DESCRIPTOR_SET,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        if [
+            DescriptorType::SAMPLER,
+            DescriptorType::COMBINED_IMAGE_SAMPLER,
+            DescriptorType::SAMPLED_IMAGE,
+            DescriptorType::STORAGE_IMAGE,
+            DescriptorType::INPUT_ATTACHMENT,
+        ]
+        .contains(&self.descriptor_type)
+        {
+            v.array(
+                "WriteDescriptorSet",
+                "p_image_info",
+                self.p_image_info,
+                self.descriptor_count as usize,
+            );
+        }
+        if [
+            DescriptorType::UNIFORM_BUFFER,
+            DescriptorType::STORAGE_BUFFER,
+            DescriptorType::UNIFORM_BUFFER_DYNAMIC,
+            DescriptorType::STORAGE_BUFFER_DYNAMIC,
+        ]
+        .contains(&self.descriptor_type)
+        {
+            v.array(
+                "WriteDescriptorSet",
+                "p_buffer_info",
+                self.p_buffer_info,
+                self.descriptor_count as usize,
+            );
+        }
+        if [
+            DescriptorType::UNIFORM_TEXEL_BUFFER,
+            DescriptorType::STORAGE_TEXEL_BUFFER,
+        ]
+        .contains(&self.descriptor_type)
+        {
+            v.array(
+                "WriteDescriptorSet",
+                "p_texel_buffer_view",
+                self.p_texel_buffer_view,
+                self.descriptor_count as usize,
+            );
+        }
+    }
+}
+impl Validate for WriteDescriptorSetAccelerationStructureNV {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "WriteDescriptorSetAccelerationStructureNV",
+            self.s_type,
+            StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV,
+        );
+        v.p_next(
+            StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "WriteDescriptorSetAccelerationStructureNV",
+            "p_acceleration_structures",
+            self.p_acceleration_structures,
+            self.acceleration_structure_count as usize,
+        );
+    }
+}
+impl Validate for WriteDescriptorSetInlineUniformBlockEXT {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "WriteDescriptorSetInlineUniformBlockEXT",
+            self.s_type,
+            StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT,
+        );
+        v.p_next(
+            StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, v: &mut Validator) {
+        v.array(
+            "WriteDescriptorSetInlineUniformBlockEXT",
+            "p_data",
+            self.p_data,
+            self.data_size as usize,
+        );
+    }
+}
+impl Validate for XcbSurfaceCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "XcbSurfaceCreateInfoKHR",
+            self.s_type,
+            StructureType::XCB_SURFACE_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::XCB_SURFACE_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+impl Validate for XlibSurfaceCreateInfoKHR {
+    unsafe fn validate(&self, v: &mut Validator) {
+        v.s_type(
+            "XlibSurfaceCreateInfoKHR",
+            self.s_type,
+            StructureType::XLIB_SURFACE_CREATE_INFO_KHR,
+        );
+        v.p_next(
+            StructureType::XLIB_SURFACE_CREATE_INFO_KHR,
+            self.p_next as *const c_void,
+        );
+        self.validate_members(v);
+    }
+    unsafe fn validate_members(&self, _v: &mut Validator) {}
+}
+pub(crate) unsafe fn validate_structure(p: *const c_void, v: &mut Validator) -> bool {
+    match (*(p as *const BaseInStructure)).s_type {
+        StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_NV => {
+            (*(p as *const AccelerationStructureCreateInfoNV)).validate_members(v)
+        }
+        StructureType::ACCELERATION_STRUCTURE_INFO_NV => {
+            (*(p as *const AccelerationStructureInfoNV)).validate_members(v)
+        }
+        StructureType::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV => {
+            (*(p as *const AccelerationStructureMemoryRequirementsInfoNV)).validate_members(v)
+        }
+        StructureType::ACQUIRE_NEXT_IMAGE_INFO_KHR => {
+            (*(p as *const AcquireNextImageInfoKHR)).validate_members(v)
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID => {
+            (*(p as *const AndroidHardwareBufferFormatPropertiesANDROID)).validate_members(v)
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID => {
+            (*(p as *const AndroidHardwareBufferPropertiesANDROID)).validate_members(v)
+        }
+        StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID => {
+            (*(p as *const AndroidHardwareBufferUsageANDROID)).validate_members(v)
+        }
+        StructureType::ANDROID_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const AndroidSurfaceCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::APPLICATION_INFO => (*(p as *const ApplicationInfo)).validate_members(v),
+        StructureType::ATTACHMENT_DESCRIPTION_2_KHR => {
+            (*(p as *const AttachmentDescription2KHR)).validate_members(v)
+        }
+        StructureType::ATTACHMENT_REFERENCE_2_KHR => {
+            (*(p as *const AttachmentReference2KHR)).validate_members(v)
+        }
+        StructureType::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV => {
+            (*(p as *const BindAccelerationStructureMemoryInfoNV)).validate_members(v)
+        }
+        StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO => {
+            (*(p as *const BindBufferMemoryDeviceGroupInfo)).validate_members(v)
+        }
+        StructureType::BIND_BUFFER_MEMORY_INFO => {
+            (*(p as *const BindBufferMemoryInfo)).validate_members(v)
+        }
+        StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO => {
+            (*(p as *const BindImageMemoryDeviceGroupInfo)).validate_members(v)
+        }
+        StructureType::BIND_IMAGE_MEMORY_INFO => {
+            (*(p as *const BindImageMemoryInfo)).validate_members(v)
+        }
+        StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR => {
+            (*(p as *const BindImageMemorySwapchainInfoKHR)).validate_members(v)
+        }
+        StructureType::BIND_IMAGE_PLANE_MEMORY_INFO => {
+            (*(p as *const BindImagePlaneMemoryInfo)).validate_members(v)
+        }
+        StructureType::BIND_SPARSE_INFO => (*(p as *const BindSparseInfo)).validate_members(v),
+        StructureType::BUFFER_CREATE_INFO => (*(p as *const BufferCreateInfo)).validate_members(v),
+        StructureType::BUFFER_MEMORY_BARRIER => {
+            (*(p as *const BufferMemoryBarrier)).validate_members(v)
+        }
+        StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2 => {
+            (*(p as *const BufferMemoryRequirementsInfo2)).validate_members(v)
+        }
+        StructureType::BUFFER_VIEW_CREATE_INFO => {
+            (*(p as *const BufferViewCreateInfo)).validate_members(v)
+        }
+        StructureType::CALIBRATED_TIMESTAMP_INFO_EXT => {
+            (*(p as *const CalibratedTimestampInfoEXT)).validate_members(v)
+        }
+        StructureType::CHECKPOINT_DATA_NV => (*(p as *const CheckpointDataNV)).validate_members(v),
+        StructureType::CMD_PROCESS_COMMANDS_INFO_NVX => {
+            (*(p as *const CmdProcessCommandsInfoNVX)).validate_members(v)
+        }
+        StructureType::CMD_RESERVE_SPACE_FOR_COMMANDS_INFO_NVX => {
+            (*(p as *const CmdReserveSpaceForCommandsInfoNVX)).validate_members(v)
+        }
+        StructureType::COMMAND_BUFFER_ALLOCATE_INFO => {
+            (*(p as *const CommandBufferAllocateInfo)).validate_members(v)
+        }
+        StructureType::COMMAND_BUFFER_BEGIN_INFO => {
+            (*(p as *const CommandBufferBeginInfo)).validate_members(v)
+        }
+        StructureType::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT => {
+            (*(p as *const CommandBufferInheritanceConditionalRenderingInfoEXT)).validate_members(v)
+        }
+        StructureType::COMMAND_BUFFER_INHERITANCE_INFO => {
+            (*(p as *const CommandBufferInheritanceInfo)).validate_members(v)
+        }
+        StructureType::COMMAND_POOL_CREATE_INFO => {
+            (*(p as *const CommandPoolCreateInfo)).validate_members(v)
+        }
+        StructureType::COMPUTE_PIPELINE_CREATE_INFO => {
+            (*(p as *const ComputePipelineCreateInfo)).validate_members(v)
+        }
+        StructureType::CONDITIONAL_RENDERING_BEGIN_INFO_EXT => {
+            (*(p as *const ConditionalRenderingBeginInfoEXT)).validate_members(v)
+        }
+        StructureType::COPY_DESCRIPTOR_SET => {
+            (*(p as *const CopyDescriptorSet)).validate_members(v)
+        }
+        StructureType::D3D12_FENCE_SUBMIT_INFO_KHR => {
+            (*(p as *const D3D12FenceSubmitInfoKHR)).validate_members(v)
+        }
+        StructureType::DEBUG_MARKER_MARKER_INFO_EXT => {
+            (*(p as *const DebugMarkerMarkerInfoEXT)).validate_members(v)
+        }
+        StructureType::DEBUG_MARKER_OBJECT_NAME_INFO_EXT => {
+            (*(p as *const DebugMarkerObjectNameInfoEXT)).validate_members(v)
+        }
+        StructureType::DEBUG_MARKER_OBJECT_TAG_INFO_EXT => {
+            (*(p as *const DebugMarkerObjectTagInfoEXT)).validate_members(v)
+        }
+        StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT => {
+            (*(p as *const DebugReportCallbackCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::DEBUG_UTILS_LABEL_EXT => {
+            (*(p as *const DebugUtilsLabelEXT)).validate_members(v)
+        }
+        StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT => {
+            (*(p as *const DebugUtilsMessengerCallbackDataEXT)).validate_members(v)
+        }
+        StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT => {
+            (*(p as *const DebugUtilsMessengerCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT => {
+            (*(p as *const DebugUtilsObjectNameInfoEXT)).validate_members(v)
+        }
+        StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT => {
+            (*(p as *const DebugUtilsObjectTagInfoEXT)).validate_members(v)
+        }
+        StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV => {
+            (*(p as *const DedicatedAllocationBufferCreateInfoNV)).validate_members(v)
+        }
+        StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV => {
+            (*(p as *const DedicatedAllocationImageCreateInfoNV)).validate_members(v)
+        }
+        StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV => {
+            (*(p as *const DedicatedAllocationMemoryAllocateInfoNV)).validate_members(v)
+        }
+        StructureType::DESCRIPTOR_POOL_CREATE_INFO => {
+            (*(p as *const DescriptorPoolCreateInfo)).validate_members(v)
+        }
+        StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT => {
+            (*(p as *const DescriptorPoolInlineUniformBlockCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::DESCRIPTOR_SET_ALLOCATE_INFO => {
+            (*(p as *const DescriptorSetAllocateInfo)).validate_members(v)
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT => {
+            (*(p as *const DescriptorSetLayoutBindingFlagsCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO => {
+            (*(p as *const DescriptorSetLayoutCreateInfo)).validate_members(v)
+        }
+        StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT => {
+            (*(p as *const DescriptorSetLayoutSupport)).validate_members(v)
+        }
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT => {
+            (*(p as *const DescriptorSetVariableDescriptorCountAllocateInfoEXT)).validate_members(v)
+        }
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT => (*(p
+            as *const DescriptorSetVariableDescriptorCountLayoutSupportEXT))
+            .validate_members(v),
+        StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO => {
+            (*(p as *const DescriptorUpdateTemplateCreateInfo)).validate_members(v)
+        }
+        StructureType::DEVICE_CREATE_INFO => (*(p as *const DeviceCreateInfo)).validate_members(v),
+        StructureType::DEVICE_EVENT_INFO_EXT => {
+            (*(p as *const DeviceEventInfoEXT)).validate_members(v)
+        }
+        StructureType::DEVICE_GENERATED_COMMANDS_FEATURES_NVX => {
+            (*(p as *const DeviceGeneratedCommandsFeaturesNVX)).validate_members(v)
+        }
+        StructureType::DEVICE_GENERATED_COMMANDS_LIMITS_NVX => {
+            (*(p as *const DeviceGeneratedCommandsLimitsNVX)).validate_members(v)
+        }
+        StructureType::DEVICE_GROUP_BIND_SPARSE_INFO => {
+            (*(p as *const DeviceGroupBindSparseInfo)).validate_members(v)
+        }
+        StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO => {
+            (*(p as *const DeviceGroupCommandBufferBeginInfo)).validate_members(v)
+        }
+        StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO => {
+            (*(p as *const DeviceGroupDeviceCreateInfo)).validate_members(v)
+        }
+        StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR => {
+            (*(p as *const DeviceGroupPresentCapabilitiesKHR)).validate_members(v)
+        }
+        StructureType::DEVICE_GROUP_PRESENT_INFO_KHR => {
+            (*(p as *const DeviceGroupPresentInfoKHR)).validate_members(v)
+        }
+        StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO => {
+            (*(p as *const DeviceGroupRenderPassBeginInfo)).validate_members(v)
+        }
+        StructureType::DEVICE_GROUP_SUBMIT_INFO => {
+            (*(p as *const DeviceGroupSubmitInfo)).validate_members(v)
+        }
+        StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR => {
+            (*(p as *const DeviceGroupSwapchainCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD => {
+            (*(p as *const DeviceMemoryOverallocationCreateInfoAMD)).validate_members(v)
+        }
+        StructureType::DEVICE_QUEUE_CREATE_INFO => {
+            (*(p as *const DeviceQueueCreateInfo)).validate_members(v)
+        }
+        StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT => {
+            (*(p as *const DeviceQueueGlobalPriorityCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::DEVICE_QUEUE_INFO_2 => (*(p as *const DeviceQueueInfo2)).validate_members(v),
+        StructureType::DISPLAY_EVENT_INFO_EXT => {
+            (*(p as *const DisplayEventInfoEXT)).validate_members(v)
+        }
+        StructureType::DISPLAY_MODE_CREATE_INFO_KHR => {
+            (*(p as *const DisplayModeCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::DISPLAY_MODE_PROPERTIES_2_KHR => {
+            (*(p as *const DisplayModeProperties2KHR)).validate_members(v)
+        }
+        StructureType::DISPLAY_PLANE_CAPABILITIES_2_KHR => {
+            (*(p as *const DisplayPlaneCapabilities2KHR)).validate_members(v)
+        }
+        StructureType::DISPLAY_PLANE_INFO_2_KHR => {
+            (*(p as *const DisplayPlaneInfo2KHR)).validate_members(v)
+        }
+        StructureType::DISPLAY_PLANE_PROPERTIES_2_KHR => {
+            (*(p as *const DisplayPlaneProperties2KHR)).validate_members(v)
+        }
+        StructureType::DISPLAY_POWER_INFO_EXT => {
+            (*(p as *const DisplayPowerInfoEXT)).validate_members(v)
+        }
+        StructureType::DISPLAY_PRESENT_INFO_KHR => {
+            (*(p as *const DisplayPresentInfoKHR)).validate_members(v)
+        }
+        StructureType::DISPLAY_PROPERTIES_2_KHR => {
+            (*(p as *const DisplayProperties2KHR)).validate_members(v)
+        }
+        StructureType::DISPLAY_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const DisplaySurfaceCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT => {
+            (*(p as *const DrmFormatModifierPropertiesListEXT)).validate_members(v)
+        }
+        StructureType::EVENT_CREATE_INFO => (*(p as *const EventCreateInfo)).validate_members(v),
+        StructureType::EXPORT_FENCE_CREATE_INFO => {
+            (*(p as *const ExportFenceCreateInfo)).validate_members(v)
+        }
+        StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ExportFenceWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::EXPORT_MEMORY_ALLOCATE_INFO => {
+            (*(p as *const ExportMemoryAllocateInfo)).validate_members(v)
+        }
+        StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV => {
+            (*(p as *const ExportMemoryAllocateInfoNV)).validate_members(v)
+        }
+        StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ExportMemoryWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV => {
+            (*(p as *const ExportMemoryWin32HandleInfoNV)).validate_members(v)
+        }
+        StructureType::EXPORT_SEMAPHORE_CREATE_INFO => {
+            (*(p as *const ExportSemaphoreCreateInfo)).validate_members(v)
+        }
+        StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ExportSemaphoreWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::EXTERNAL_BUFFER_PROPERTIES => {
+            (*(p as *const ExternalBufferProperties)).validate_members(v)
+        }
+        StructureType::EXTERNAL_FENCE_PROPERTIES => {
+            (*(p as *const ExternalFenceProperties)).validate_members(v)
+        }
+        StructureType::EXTERNAL_FORMAT_ANDROID => {
+            (*(p as *const ExternalFormatANDROID)).validate_members(v)
+        }
+        StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES => {
+            (*(p as *const ExternalImageFormatProperties)).validate_members(v)
+        }
+        StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO => {
+            (*(p as *const ExternalMemoryBufferCreateInfo)).validate_members(v)
+        }
+        StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO => {
+            (*(p as *const ExternalMemoryImageCreateInfo)).validate_members(v)
+        }
+        StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV => {
+            (*(p as *const ExternalMemoryImageCreateInfoNV)).validate_members(v)
+        }
+        StructureType::EXTERNAL_SEMAPHORE_PROPERTIES => {
+            (*(p as *const ExternalSemaphoreProperties)).validate_members(v)
+        }
+        StructureType::FENCE_CREATE_INFO => (*(p as *const FenceCreateInfo)).validate_members(v),
+        StructureType::FENCE_GET_FD_INFO_KHR => {
+            (*(p as *const FenceGetFdInfoKHR)).validate_members(v)
+        }
+        StructureType::FENCE_GET_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const FenceGetWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::FORMAT_PROPERTIES_2 => {
+            (*(p as *const FormatProperties2)).validate_members(v)
+        }
+        StructureType::FRAMEBUFFER_CREATE_INFO => {
+            (*(p as *const FramebufferCreateInfo)).validate_members(v)
+        }
+        StructureType::GEOMETRY_AABB_NV => (*(p as *const GeometryAABBNV)).validate_members(v),
+        StructureType::GEOMETRY_NV => (*(p as *const GeometryNV)).validate_members(v),
+        StructureType::GEOMETRY_TRIANGLES_NV => {
+            (*(p as *const GeometryTrianglesNV)).validate_members(v)
+        }
+        StructureType::GRAPHICS_PIPELINE_CREATE_INFO => {
+            (*(p as *const GraphicsPipelineCreateInfo)).validate_members(v)
+        }
+        StructureType::HDR_METADATA_EXT => (*(p as *const HdrMetadataEXT)).validate_members(v),
+        StructureType::IOS_SURFACE_CREATE_INFO_M => {
+            (*(p as *const IOSSurfaceCreateInfoMVK)).validate_members(v)
+        }
+        StructureType::IMAGE_CREATE_INFO => (*(p as *const ImageCreateInfo)).validate_members(v),
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT => {
+            (*(p as *const ImageDrmFormatModifierExplicitCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT => {
+            (*(p as *const ImageDrmFormatModifierListCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT => {
+            (*(p as *const ImageDrmFormatModifierPropertiesEXT)).validate_members(v)
+        }
+        StructureType::IMAGE_FORMAT_LIST_CREATE_INFO_KHR => {
+            (*(p as *const ImageFormatListCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::IMAGE_FORMAT_PROPERTIES_2 => {
+            (*(p as *const ImageFormatProperties2)).validate_members(v)
+        }
+        StructureType::IMAGE_MEMORY_BARRIER => {
+            (*(p as *const ImageMemoryBarrier)).validate_members(v)
+        }
+        StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2 => {
+            (*(p as *const ImageMemoryRequirementsInfo2)).validate_members(v)
+        }
+        StructureType::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA => {
+            (*(p as *const ImagePipeSurfaceCreateInfoFUCHSIA)).validate_members(v)
+        }
+        StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO => {
+            (*(p as *const ImagePlaneMemoryRequirementsInfo)).validate_members(v)
+        }
+        StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2 => {
+            (*(p as *const ImageSparseMemoryRequirementsInfo2)).validate_members(v)
+        }
+        StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR => {
+            (*(p as *const ImageSwapchainCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT => {
+            (*(p as *const ImageViewASTCDecodeModeEXT)).validate_members(v)
+        }
+        StructureType::IMAGE_VIEW_CREATE_INFO => {
+            (*(p as *const ImageViewCreateInfo)).validate_members(v)
+        }
+        StructureType::IMAGE_VIEW_USAGE_CREATE_INFO => {
+            (*(p as *const ImageViewUsageCreateInfo)).validate_members(v)
+        }
+        StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID => {
+            (*(p as *const ImportAndroidHardwareBufferInfoANDROID)).validate_members(v)
+        }
+        StructureType::IMPORT_FENCE_FD_INFO_KHR => {
+            (*(p as *const ImportFenceFdInfoKHR)).validate_members(v)
+        }
+        StructureType::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ImportFenceWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::IMPORT_MEMORY_FD_INFO_KHR => {
+            (*(p as *const ImportMemoryFdInfoKHR)).validate_members(v)
+        }
+        StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT => {
+            (*(p as *const ImportMemoryHostPointerInfoEXT)).validate_members(v)
+        }
+        StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ImportMemoryWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV => {
+            (*(p as *const ImportMemoryWin32HandleInfoNV)).validate_members(v)
+        }
+        StructureType::IMPORT_SEMAPHORE_FD_INFO_KHR => {
+            (*(p as *const ImportSemaphoreFdInfoKHR)).validate_members(v)
+        }
+        StructureType::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const ImportSemaphoreWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NVX => {
+            (*(p as *const IndirectCommandsLayoutCreateInfoNVX)).validate_members(v)
+        }
+        StructureType::INSTANCE_CREATE_INFO => {
+            (*(p as *const InstanceCreateInfo)).validate_members(v)
+        }
+        StructureType::MACOS_SURFACE_CREATE_INFO_M => {
+            (*(p as *const MacOSSurfaceCreateInfoMVK)).validate_members(v)
+        }
+        StructureType::MAPPED_MEMORY_RANGE => {
+            (*(p as *const MappedMemoryRange)).validate_members(v)
+        }
+        StructureType::MEMORY_ALLOCATE_FLAGS_INFO => {
+            (*(p as *const MemoryAllocateFlagsInfo)).validate_members(v)
+        }
+        StructureType::MEMORY_ALLOCATE_INFO => {
+            (*(p as *const MemoryAllocateInfo)).validate_members(v)
+        }
+        StructureType::MEMORY_BARRIER => (*(p as *const MemoryBarrier)).validate_members(v),
+        StructureType::MEMORY_DEDICATED_ALLOCATE_INFO => {
+            (*(p as *const MemoryDedicatedAllocateInfo)).validate_members(v)
+        }
+        StructureType::MEMORY_DEDICATED_REQUIREMENTS => {
+            (*(p as *const MemoryDedicatedRequirements)).validate_members(v)
+        }
+        StructureType::MEMORY_FD_PROPERTIES_KHR => {
+            (*(p as *const MemoryFdPropertiesKHR)).validate_members(v)
+        }
+        StructureType::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID => {
+            (*(p as *const MemoryGetAndroidHardwareBufferInfoANDROID)).validate_members(v)
+        }
+        StructureType::MEMORY_GET_FD_INFO_KHR => {
+            (*(p as *const MemoryGetFdInfoKHR)).validate_members(v)
+        }
+        StructureType::MEMORY_GET_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const MemoryGetWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::MEMORY_HOST_POINTER_PROPERTIES_EXT => {
+            (*(p as *const MemoryHostPointerPropertiesEXT)).validate_members(v)
+        }
+        StructureType::MEMORY_REQUIREMENTS_2 => {
+            (*(p as *const MemoryRequirements2)).validate_members(v)
+        }
+        StructureType::MEMORY_WIN32_HANDLE_PROPERTIES_KHR => {
+            (*(p as *const MemoryWin32HandlePropertiesKHR)).validate_members(v)
+        }
+        StructureType::MULTISAMPLE_PROPERTIES_EXT => {
+            (*(p as *const MultisamplePropertiesEXT)).validate_members(v)
+        }
+        StructureType::NATIVE_BUFFER_ANDROID => {
+            (*(p as *const NativeBufferANDROID)).validate_members(v)
+        }
+        StructureType::OBJECT_TABLE_CREATE_INFO_NVX => {
+            (*(p as *const ObjectTableCreateInfoNVX)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES => {
+            (*(p as *const PhysicalDevice16BitStorageFeatures)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR => {
+            (*(p as *const PhysicalDevice8BitStorageFeaturesKHR)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceASTCDecodeFeaturesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceBlendOperationAdvancedFeaturesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceBlendOperationAdvancedPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceComputeShaderDerivativesFeaturesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceConditionalRenderingFeaturesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT => (*(p
+            as *const PhysicalDeviceConservativeRasterizationPropertiesEXT))
+            .validate_members(v),
+        StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceCornerSampledImageFeaturesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceDescriptorIndexingFeaturesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceDescriptorIndexingPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceDiscardRectanglePropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR => {
+            (*(p as *const PhysicalDeviceDriverPropertiesKHR)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceExclusiveScissorFeaturesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO => {
+            (*(p as *const PhysicalDeviceExternalBufferInfo)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO => {
+            (*(p as *const PhysicalDeviceExternalFenceInfo)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO => {
+            (*(p as *const PhysicalDeviceExternalImageFormatInfo)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceExternalMemoryHostPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO => {
+            (*(p as *const PhysicalDeviceExternalSemaphoreInfo)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_FEATURES_2 => {
+            (*(p as *const PhysicalDeviceFeatures2)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceFragmentShaderBarycentricFeaturesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES => {
+            (*(p as *const PhysicalDeviceGroupProperties)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_ID_PROPERTIES => {
+            (*(p as *const PhysicalDeviceIDProperties)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT => {
+            (*(p as *const PhysicalDeviceImageDrmFormatModifierInfoEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2 => {
+            (*(p as *const PhysicalDeviceImageFormatInfo2)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceInlineUniformBlockFeaturesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceInlineUniformBlockPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES => {
+            (*(p as *const PhysicalDeviceMaintenance3Properties)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2 => {
+            (*(p as *const PhysicalDeviceMemoryProperties2)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceMeshShaderFeaturesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV => {
+            (*(p as *const PhysicalDeviceMeshShaderPropertiesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES => {
+            (*(p as *const PhysicalDeviceMultiviewFeatures)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX => (*(p
+            as *const PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX))
+            .validate_members(v),
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES => {
+            (*(p as *const PhysicalDeviceMultiviewProperties)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDevicePCIBusInfoPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES => {
+            (*(p as *const PhysicalDevicePointClippingProperties)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_PROPERTIES_2 => {
+            (*(p as *const PhysicalDeviceProperties2)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES => {
+            (*(p as *const PhysicalDeviceProtectedMemoryFeatures)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES => {
+            (*(p as *const PhysicalDeviceProtectedMemoryProperties)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR => {
+            (*(p as *const PhysicalDevicePushDescriptorPropertiesKHR)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV => {
+            (*(p as *const PhysicalDeviceRayTracingPropertiesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceRepresentativeFragmentTestFeaturesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceSampleLocationsPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceSamplerFilterMinmaxPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES => {
+            (*(p as *const PhysicalDeviceSamplerYcbcrConversionFeatures)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR => {
+            (*(p as *const PhysicalDeviceShaderAtomicInt64FeaturesKHR)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD => {
+            (*(p as *const PhysicalDeviceShaderCorePropertiesAMD)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES => {
+            (*(p as *const PhysicalDeviceShaderDrawParameterFeatures)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceShaderImageFootprintFeaturesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV => {
+            (*(p as *const PhysicalDeviceShadingRateImageFeaturesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV => {
+            (*(p as *const PhysicalDeviceShadingRateImagePropertiesNV)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2 => {
+            (*(p as *const PhysicalDeviceSparseImageFormatInfo2)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES => {
+            (*(p as *const PhysicalDeviceSubgroupProperties)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR => {
+            (*(p as *const PhysicalDeviceSurfaceInfo2KHR)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceTransformFeedbackFeaturesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceTransformFeedbackPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES => {
+            (*(p as *const PhysicalDeviceVariablePointerFeatures)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT => {
+            (*(p as *const PhysicalDeviceVertexAttributeDivisorFeaturesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT => {
+            (*(p as *const PhysicalDeviceVertexAttributeDivisorPropertiesEXT)).validate_members(v)
+        }
+        StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR => {
+            (*(p as *const PhysicalDeviceVulkanMemoryModelFeaturesKHR)).validate_members(v)
+        }
+        StructureType::PIPELINE_CACHE_CREATE_INFO => {
+            (*(p as *const PipelineCacheCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineColorBlendAdvancedStateCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO => {
+            (*(p as *const PipelineColorBlendStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineCoverageModulationStateCreateInfoNV)).validate_members(v)
+        }
+        StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineCoverageToColorStateCreateInfoNV)).validate_members(v)
+        }
+        StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO => {
+            (*(p as *const PipelineDepthStencilStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineDiscardRectangleStateCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO => {
+            (*(p as *const PipelineDynamicStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO => {
+            (*(p as *const PipelineInputAssemblyStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_LAYOUT_CREATE_INFO => {
+            (*(p as *const PipelineLayoutCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO => {
+            (*(p as *const PipelineMultisampleStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineRasterizationConservativeStateCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO => {
+            (*(p as *const PipelineRasterizationStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD => {
+            (*(p as *const PipelineRasterizationStateRasterizationOrderAMD)).validate_members(v)
+        }
+        StructureType::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT => {
+            (*(p as *const PipelineRasterizationStateStreamCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineRepresentativeFragmentTestStateCreateInfoNV)).validate_members(v)
+        }
+        StructureType::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineSampleLocationsStateCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO => {
+            (*(p as *const PipelineShaderStageCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO => {
+            (*(p as *const PipelineTessellationDomainOriginStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO => {
+            (*(p as *const PipelineTessellationStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT => {
+            (*(p as *const PipelineVertexInputDivisorStateCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO => {
+            (*(p as *const PipelineVertexInputStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportCoarseSampleOrderStateCreateInfoNV)).validate_members(v)
+        }
+        StructureType::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportExclusiveScissorStateCreateInfoNV)).validate_members(v)
+        }
+        StructureType::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportShadingRateImageStateCreateInfoNV)).validate_members(v)
+        }
+        StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO => {
+            (*(p as *const PipelineViewportStateCreateInfo)).validate_members(v)
+        }
+        StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportSwizzleStateCreateInfoNV)).validate_members(v)
+        }
+        StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV => {
+            (*(p as *const PipelineViewportWScalingStateCreateInfoNV)).validate_members(v)
+        }
+        StructureType::PRESENT_INFO_KHR => (*(p as *const PresentInfoKHR)).validate_members(v),
+        StructureType::PRESENT_REGIONS_KHR => {
+            (*(p as *const PresentRegionsKHR)).validate_members(v)
+        }
+        StructureType::PRESENT_TIMES_INFO_GOOGLE => {
+            (*(p as *const PresentTimesInfoGOOGLE)).validate_members(v)
+        }
+        StructureType::PROTECTED_SUBMIT_INFO => {
+            (*(p as *const ProtectedSubmitInfo)).validate_members(v)
+        }
+        StructureType::QUERY_POOL_CREATE_INFO => {
+            (*(p as *const QueryPoolCreateInfo)).validate_members(v)
+        }
+        StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV => {
+            (*(p as *const QueueFamilyCheckpointPropertiesNV)).validate_members(v)
+        }
+        StructureType::QUEUE_FAMILY_PROPERTIES_2 => {
+            (*(p as *const QueueFamilyProperties2)).validate_members(v)
+        }
+        StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_NV => {
+            (*(p as *const RayTracingPipelineCreateInfoNV)).validate_members(v)
+        }
+        StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV => {
+            (*(p as *const RayTracingShaderGroupCreateInfoNV)).validate_members(v)
+        }
+        StructureType::RENDER_PASS_BEGIN_INFO => {
+            (*(p as *const RenderPassBeginInfo)).validate_members(v)
+        }
+        StructureType::RENDER_PASS_CREATE_INFO => {
+            (*(p as *const RenderPassCreateInfo)).validate_members(v)
+        }
+        StructureType::RENDER_PASS_CREATE_INFO_2_KHR => {
+            (*(p as *const RenderPassCreateInfo2KHR)).validate_members(v)
+        }
+        StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO => {
+            (*(p as *const RenderPassInputAttachmentAspectCreateInfo)).validate_members(v)
+        }
+        StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO => {
+            (*(p as *const RenderPassMultiviewCreateInfo)).validate_members(v)
+        }
+        StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT => {
+            (*(p as *const RenderPassSampleLocationsBeginInfoEXT)).validate_members(v)
+        }
+        StructureType::SAMPLE_LOCATIONS_INFO_EXT => {
+            (*(p as *const SampleLocationsInfoEXT)).validate_members(v)
+        }
+        StructureType::SAMPLER_CREATE_INFO => {
+            (*(p as *const SamplerCreateInfo)).validate_members(v)
+        }
+        StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT => {
+            (*(p as *const SamplerReductionModeCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO => {
+            (*(p as *const SamplerYcbcrConversionCreateInfo)).validate_members(v)
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES => {
+            (*(p as *const SamplerYcbcrConversionImageFormatProperties)).validate_members(v)
+        }
+        StructureType::SAMPLER_YCBCR_CONVERSION_INFO => {
+            (*(p as *const SamplerYcbcrConversionInfo)).validate_members(v)
+        }
+        StructureType::SEMAPHORE_CREATE_INFO => {
+            (*(p as *const SemaphoreCreateInfo)).validate_members(v)
+        }
+        StructureType::SEMAPHORE_GET_FD_INFO_KHR => {
+            (*(p as *const SemaphoreGetFdInfoKHR)).validate_members(v)
+        }
+        StructureType::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR => {
+            (*(p as *const SemaphoreGetWin32HandleInfoKHR)).validate_members(v)
+        }
+        StructureType::SHADER_MODULE_CREATE_INFO => {
+            (*(p as *const ShaderModuleCreateInfo)).validate_members(v)
+        }
+        StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT => {
+            (*(p as *const ShaderModuleValidationCacheCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR => {
+            (*(p as *const SharedPresentSurfaceCapabilitiesKHR)).validate_members(v)
+        }
+        StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2 => {
+            (*(p as *const SparseImageFormatProperties2)).validate_members(v)
+        }
+        StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2 => {
+            (*(p as *const SparseImageMemoryRequirements2)).validate_members(v)
+        }
+        StructureType::SUBMIT_INFO => (*(p as *const SubmitInfo)).validate_members(v),
+        StructureType::SUBPASS_BEGIN_INFO_KHR => {
+            (*(p as *const SubpassBeginInfoKHR)).validate_members(v)
+        }
+        StructureType::SUBPASS_DEPENDENCY_2_KHR => {
+            (*(p as *const SubpassDependency2KHR)).validate_members(v)
+        }
+        StructureType::SUBPASS_DESCRIPTION_2_KHR => {
+            (*(p as *const SubpassDescription2KHR)).validate_members(v)
+        }
+        StructureType::SUBPASS_END_INFO_KHR => {
+            (*(p as *const SubpassEndInfoKHR)).validate_members(v)
+        }
+        StructureType::SURFACE_CAPABILITIES_2_EXT => {
+            (*(p as *const SurfaceCapabilities2EXT)).validate_members(v)
+        }
+        StructureType::SURFACE_CAPABILITIES_2_KHR => {
+            (*(p as *const SurfaceCapabilities2KHR)).validate_members(v)
+        }
+        StructureType::SURFACE_FORMAT_2_KHR => {
+            (*(p as *const SurfaceFormat2KHR)).validate_members(v)
+        }
+        StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT => {
+            (*(p as *const SwapchainCounterCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::SWAPCHAIN_CREATE_INFO_KHR => {
+            (*(p as *const SwapchainCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD => {
+            (*(p as *const TextureLODGatherFormatPropertiesAMD)).validate_members(v)
+        }
+        StructureType::VALIDATION_CACHE_CREATE_INFO_EXT => {
+            (*(p as *const ValidationCacheCreateInfoEXT)).validate_members(v)
+        }
+        StructureType::VALIDATION_FLAGS_EXT => {
+            (*(p as *const ValidationFlagsEXT)).validate_members(v)
+        }
+        StructureType::VI_SURFACE_CREATE_INFO_NN => {
+            (*(p as *const ViSurfaceCreateInfoNN)).validate_members(v)
+        }
+        StructureType::WAYLAND_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const WaylandSurfaceCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR => {
+            (*(p as *const Win32KeyedMutexAcquireReleaseInfoKHR)).validate_members(v)
+        }
+        StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV => {
+            (*(p as *const Win32KeyedMutexAcquireReleaseInfoNV)).validate_members(v)
+        }
+        StructureType::WIN32_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const Win32SurfaceCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::WRITE_DESCRIPTOR_SET => {
+            (*(p as *const WriteDescriptorSet)).validate_members(v)
+        }
+        StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV => {
+            (*(p as *const WriteDescriptorSetAccelerationStructureNV)).validate_members(v)
+        }
+        StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT => {
+            (*(p as *const WriteDescriptorSetInlineUniformBlockEXT)).validate_members(v)
+        }
+        StructureType::XCB_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const XcbSurfaceCreateInfoKHR)).validate_members(v)
+        }
+        StructureType::XLIB_SURFACE_CREATE_INFO_KHR => {
+            (*(p as *const XlibSurfaceCreateInfoKHR)).validate_members(v)
+        }
+        _ => return false,
+    }
+    true
+}
+pub(crate) fn extends(base: StructureType, next: StructureType) -> bool {
+    match base {
+        StructureType::BIND_BUFFER_MEMORY_INFO => match next {
+            StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO => true,
+            _ => false,
+        },
+        StructureType::BIND_IMAGE_MEMORY_INFO => match next {
+            StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO
+            | StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR
+            | StructureType::BIND_IMAGE_PLANE_MEMORY_INFO => true,
+            _ => false,
+        },
+        StructureType::BIND_SPARSE_INFO => match next {
+            StructureType::DEVICE_GROUP_BIND_SPARSE_INFO => true,
+            _ => false,
+        },
+        StructureType::BUFFER_CREATE_INFO => match next {
+            StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV
+            | StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO => true,
+            _ => false,
+        },
+        StructureType::COMMAND_BUFFER_BEGIN_INFO => match next {
+            StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO => true,
+            _ => false,
+        },
+        StructureType::DESCRIPTOR_POOL_CREATE_INFO => match next {
+            StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO => match next {
+            StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::DEVICE_CREATE_INFO => match next {
+            StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO
+            | StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD
+            | StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES
+            | StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR
+            | StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_FEATURES_2
+            | StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES
+            | StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES
+            | StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES
+            | StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR
+            | StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES
+            | StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES
+            | StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR => true,
+            _ => false,
+        },
+        StructureType::DEVICE_QUEUE_CREATE_INFO => match next {
+            StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::FENCE_CREATE_INFO => match next {
+            StructureType::EXPORT_FENCE_CREATE_INFO
+            | StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR => true,
+            _ => false,
+        },
+        StructureType::FORMAT_PROPERTIES_2 => match next {
+            StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT => true,
+            _ => false,
+        },
+        StructureType::GRAPHICS_PIPELINE_CREATE_INFO => match next {
+            StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::IMAGE_CREATE_INFO => match next {
+            StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV
+            | StructureType::EXTERNAL_FORMAT_ANDROID
+            | StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO
+            | StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV
+            | StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT
+            | StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT
+            | StructureType::IMAGE_FORMAT_LIST_CREATE_INFO_KHR
+            | StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR => true,
+            _ => false,
+        },
+        StructureType::IMAGE_FORMAT_PROPERTIES_2 => match next {
+            StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID
+            | StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES
+            | StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES
+            | StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD => true,
+            _ => false,
+        },
+        StructureType::IMAGE_MEMORY_BARRIER => match next {
+            StructureType::SAMPLE_LOCATIONS_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2 => match next {
+            StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO => true,
+            _ => false,
+        },
+        StructureType::IMAGE_VIEW_CREATE_INFO => match next {
+            StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT
+            | StructureType::IMAGE_VIEW_USAGE_CREATE_INFO
+            | StructureType::SAMPLER_YCBCR_CONVERSION_INFO => true,
+            _ => false,
+        },
+        StructureType::INSTANCE_CREATE_INFO => match next {
+            StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT
+            | StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT
+            | StructureType::VALIDATION_FLAGS_EXT => true,
+            _ => false,
+        },
+        StructureType::MEMORY_ALLOCATE_INFO => match next {
+            StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV
+            | StructureType::EXPORT_MEMORY_ALLOCATE_INFO
+            | StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV
+            | StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR
+            | StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV
+            | StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID
+            | StructureType::IMPORT_MEMORY_FD_INFO_KHR
+            | StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT
+            | StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR
+            | StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV
+            | StructureType::MEMORY_ALLOCATE_FLAGS_INFO
+            | StructureType::MEMORY_DEDICATED_ALLOCATE_INFO => true,
+            _ => false,
+        },
+        StructureType::MEMORY_REQUIREMENTS_2 => match next {
+            StructureType::MEMORY_DEDICATED_REQUIREMENTS => true,
+            _ => false,
+        },
+        StructureType::PHYSICAL_DEVICE_FEATURES_2 => match next {
+            StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES
+            | StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR
+            | StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES
+            | StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES
+            | StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES
+            | StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR
+            | StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES
+            | StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV
+            | StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES
+            | StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT
+            | StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR => true,
+            _ => false,
+        },
+        StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2 => match next {
+            StructureType::IMAGE_FORMAT_LIST_CREATE_INFO_KHR
+            | StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO
+            | StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::PHYSICAL_DEVICE_PROPERTIES_2 => match next {
+            StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR
+            | StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_ID_PROPERTIES
+            | StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES
+            | StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV
+            | StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES
+            | StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES
+            | StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES
+            | StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR
+            | StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV
+            | StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD
+            | StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV
+            | StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES
+            | StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT
+            | StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT => true,
+            _ => false,
+        },
+        StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO => match next {
+            StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV => true,
+            _ => false,
+        },
+        StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO => match next {
+            StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV
+            | StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV => true,
+            _ => false,
+        },
+        StructureType::PRESENT_INFO_KHR => match next {
+            StructureType::DEVICE_GROUP_PRESENT_INFO_KHR
+            | StructureType::DISPLAY_PRESENT_INFO_KHR
+            | StructureType::PRESENT_REGIONS_KHR
+            | StructureType::PRESENT_TIMES_INFO_GOOGLE => true,
+            _ => false,
+        },
+        StructureType::QUEUE_FAMILY_PROPERTIES_2 => match next {
+            StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV => true,
+            _ => false,
+        },
+        StructureType::RENDER_PASS_BEGIN_INFO => match next {
+            StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO
+            | StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::RENDER_PASS_CREATE_INFO => match next {
+            StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO
+            | StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO => true,
+            _ => false,
+        },
+        StructureType::SAMPLER_CREATE_INFO => match next {
+            StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT
+            | StructureType::SAMPLER_YCBCR_CONVERSION_INFO => true,
+            _ => false,
+        },
+        StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO => match next {
+            StructureType::EXTERNAL_FORMAT_ANDROID => true,
+            _ => false,
+        },
+        StructureType::SEMAPHORE_CREATE_INFO => match next {
+            StructureType::EXPORT_SEMAPHORE_CREATE_INFO
+            | StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => true,
+            _ => false,
+        },
+        StructureType::SHADER_MODULE_CREATE_INFO => match next {
+            StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::SUBMIT_INFO => match next {
+            StructureType::D3D12_FENCE_SUBMIT_INFO_KHR
+            | StructureType::DEVICE_GROUP_SUBMIT_INFO
+            | StructureType::PROTECTED_SUBMIT_INFO
+            | StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR
+            | StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV => true,
+            _ => false,
+        },
+        StructureType::SURFACE_CAPABILITIES_2_KHR => match next {
+            StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR => true,
+            _ => false,
+        },
+        StructureType::SWAPCHAIN_CREATE_INFO_KHR => match next {
+            StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR
+            | StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT => true,
+            _ => false,
+        },
+        StructureType::WRITE_DESCRIPTOR_SET => match next {
+            StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV
+            | StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,213 @@
+//! Structural checks of the arguments of the wrappers in `Device`, `Instance` and
+//! `extensions`, for machines without the validation layers. Only compiled with the
+//! `validation` feature.
+//!
+//! Before calling the driver, the wrappers check
+//!
+//! * the `s_type` of every struct, and of every element of a pNext chain
+//! * that the elements of a pNext chain may extend the struct, as with the `Extends*` traits
+//! * that arrays with a length that is not zero are not null
+//! * that handles that are required are not null
+//! * that queues and command buffers belong to the device that is called
+//!
+//! Violations are reported to the callback set with `set_callback`, and ignored without one.
+//! The call is made either way. Applications decide where violations go, and tests that should
+//! fail on violations can opt into panicking:
+//!
+//! ```no_run
+//! # use ash::validation;
+//! validation::set_callback(|violation| eprintln!("{}", violation));
+//! validation::set_callback(validation::panic_on_violation);
+//! ```
+//!
+//! To find the device of queues and command buffers, their dispatch table pointer is read, so
+//! dispatchable handles that are passed to the wrappers must be valid or null, as they must be
+//! for the driver.
+use std::error::Error;
+use std::fmt;
+use std::os::raw::c_void;
+use std::sync::{Arc, RwLock};
+use vk::{self, Handle};
+
+mod generated;
+
+/// A structural rule that a call breaks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// The command that was called, e.g. `vkCreateBuffer`.
+    pub command: &'static str,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.command, self.message)
+    }
+}
+
+impl Error for Violation {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+lazy_static! {
+    static ref CALLBACK: RwLock<Option<Arc<dyn Fn(&Violation) + Send + Sync>>> = RwLock::new(None);
+}
+
+/// Reports every violation to `callback`.
+pub fn set_callback<F: Fn(&Violation) + Send + Sync + 'static>(callback: F) {
+    *CALLBACK.write().unwrap_or_else(|err| err.into_inner()) = Some(Arc::new(callback));
+}
+
+/// Ignores violations again.
+pub fn clear_callback() {
+    *CALLBACK.write().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// A callback for `set_callback` that panics on every violation.
+pub fn panic_on_violation(violation: &Violation) {
+    panic!("{}", violation)
+}
+
+/// Runs the checks of a wrapper, see `validate!`.
+pub(crate) fn check<F: FnOnce(&mut Validator)>(command: &'static str, checks: F) {
+    checks(&mut Validator { command })
+}
+
+/// A struct whose members can be checked.
+pub(crate) trait Validate {
+    /// Checks the `s_type`, the pNext chain and the members.
+    unsafe fn validate(&self, v: &mut Validator) {
+        self.validate_members(v)
+    }
+
+    unsafe fn validate_members(&self, v: &mut Validator);
+}
+
+/// Checks the arguments of one call.
+pub(crate) struct Validator {
+    command: &'static str,
+}
+
+impl Validator {
+    fn report(&mut self, message: String) {
+        let violation = Violation {
+            command: self.command,
+            message,
+        };
+        // The lock is not held while the callback runs, so that it may call `set_callback`
+        let callback = CALLBACK
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone();
+        if let Some(callback) = callback {
+            callback(&violation);
+        }
+    }
+
+    pub(crate) fn s_type(
+        &mut self,
+        name: &str,
+        actual: vk::StructureType,
+        expected: vk::StructureType,
+    ) {
+        if actual != expected {
+            self.report(format!(
+                "{}.s_type is {}, but must be {}",
+                name, actual, expected
+            ));
+        }
+    }
+
+    /// Checks every element of the pNext chain of a struct of type `base`.
+    pub(crate) unsafe fn p_next(&mut self, base: vk::StructureType, mut p_next: *const c_void) {
+        while !p_next.is_null() {
+            let next = &*(p_next as *const vk::BaseInStructure);
+            if !generated::validate_structure(p_next, self) {
+                self.report(format!(
+                    "the pNext chain of {} contains the unknown structure type {}",
+                    base, next.s_type
+                ));
+            } else if !generated::extends(base, next.s_type) {
+                self.report(format!("{} can not extend {}", next.s_type, base));
+            }
+            p_next = next.p_next as *const c_void;
+        }
+    }
+
+    /// Checks that an array with a length that is not zero is not null.
+    pub(crate) fn array<T>(&mut self, name: &str, member: &str, ptr: *const T, len: usize) {
+        if len > 0 && ptr.is_null() {
+            self.report(format!(
+                "{}.{} is null, but its length is {}",
+                name, member, len
+            ));
+        }
+    }
+
+    /// Checks the structs of an array, which may be null.
+    pub(crate) unsafe fn structures<T: Validate>(&mut self, ptr: *const T, len: usize) {
+        if ptr.is_null() {
+            return;
+        }
+        for i in 0..len {
+            (*ptr.offset(i as isize)).validate(self);
+        }
+    }
+
+    pub(crate) unsafe fn structure<T: Validate>(&mut self, value: &T) {
+        value.validate(self)
+    }
+
+    pub(crate) unsafe fn slice<T: Validate>(&mut self, values: &[T]) {
+        for value in values {
+            value.validate(self);
+        }
+    }
+
+    /// Checks that a required handle is not null.
+    pub(crate) fn handle<H: Handle>(&mut self, name: &str, handle: H) {
+        if handle.as_raw() == 0 {
+            self.report(format!("{} is a null {}", name, H::TYPE));
+        }
+    }
+
+    pub(crate) fn handles<H: Handle + Copy>(&mut self, name: &str, handles: &[H]) {
+        for (i, &handle) in handles.iter().enumerate() {
+            if handle.as_raw() == 0 {
+                self.report(format!("{}[{}] is a null {}", name, i, H::TYPE));
+            }
+        }
+    }
+
+    /// Checks that the dispatchable `handle`, such as a queue or a command buffer, is not null
+    /// and belongs to `parent`. Both share the dispatch table pointer that the loader stores at
+    /// the start of every dispatchable handle.
+    ///
+    /// # Safety
+    ///
+    /// `handle` and `parent` must each be null or a valid dispatchable handle, as the dispatch
+    /// table pointer is read from them.
+    pub(crate) unsafe fn dispatchable<H: Handle + Copy, P: Handle + Copy>(
+        &mut self,
+        name: &str,
+        handle: H,
+        parent: P,
+    ) {
+        if handle.as_raw() == 0 {
+            self.report(format!("{} is a null {}", name, H::TYPE));
+        } else if parent.as_raw() != 0
+            && *(handle.as_raw() as usize as *const usize)
+                != *(parent.as_raw() as usize as *const usize)
+        {
+            self.report(format!(
+                "{} {:?} does not belong to {} {:?}",
+                name,
+                handle.as_raw() as *const c_void,
+                P::TYPE,
+                parent.as_raw() as *const c_void
+            ));
+        }
+    }
+}
@@ -1485,7 +1485,7 @@ pub type PFN_vkCmdSetDepthBias = extern "system" fn(
 ) -> c_void;
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetBlendConstants =
-    extern "system" fn(command_buffer: CommandBuffer, blend_constants: [f32; 4]) -> c_void;
+    extern "system" fn(command_buffer: CommandBuffer, blend_constants: *const [f32; 4]) -> c_void;
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetDepthBounds = extern "system" fn(
     command_buffer: CommandBuffer,
@@ -2189,8 +2189,10 @@ pub struct DeviceFnV1_0 {
         depth_bias_clamp: f32,
         depth_bias_slope_factor: f32,
     ) -> c_void,
-    pub cmd_set_blend_constants:
-        extern "system" fn(command_buffer: CommandBuffer, blend_constants: [f32; 4]) -> c_void,
+    pub cmd_set_blend_constants: extern "system" fn(
+        command_buffer: CommandBuffer,
+        blend_constants: *const [f32; 4],
+    ) -> c_void,
     pub cmd_set_depth_bounds: extern "system" fn(
         command_buffer: CommandBuffer,
         min_depth_bounds: f32,
@@ -4058,7 +4060,7 @@ impl DeviceFnV1_0 {
             cmd_set_blend_constants: unsafe {
                 extern "system" fn cmd_set_blend_constants(
                     _command_buffer: CommandBuffer,
-                    _blend_constants: [f32; 4],
+                    _blend_constants: *const [f32; 4],
                 ) -> c_void {
                     panic!(concat!(
                         "Unable to load ",
@@ -5545,7 +5547,7 @@ impl DeviceFnV1_0 {
     pub unsafe fn cmd_set_blend_constants(
         &self,
         command_buffer: CommandBuffer,
-        blend_constants: [f32; 4],
+        blend_constants: *const [f32; 4],
     ) -> c_void {
         (self.cmd_set_blend_constants)(command_buffer, blend_constants)
     }
@@ -54616,3 +54618,9615 @@ pub type SamplerYcbcrConversionImageFormatPropertiesKHR =
     SamplerYcbcrConversionImageFormatProperties;
 pub type PhysicalDeviceMaintenance3PropertiesKHR = PhysicalDeviceMaintenance3Properties;
 pub type DescriptorSetLayoutSupportKHR = DescriptorSetLayoutSupport;
+pub mod commands {
+    use super::{CommandBufferLevel, QueueFlags, Result};
+    #[doc = "Whether a command may be recorded inside or outside of a render pass instance."]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum RenderPassScope {
+        Inside,
+        Outside,
+        Both,
+    }
+    #[doc = "The `renderpass`, `queues`, `cmdbufferlevel`, `successcodes` and `errorcodes` of a command in vk.xml."]
+    #[derive(Clone, Copy, Debug)]
+    pub struct CommandMetadata {
+        pub name: &'static str,
+        #[doc = "`None` for commands that are not recorded into a command buffer."]
+        pub render_pass: Option<RenderPassScope>,
+        #[doc = "The queue types that support the command, empty if vk.xml does not restrict them."]
+        pub queues: QueueFlags,
+        #[doc = "Empty for commands that are not recorded into a command buffer."]
+        pub command_buffer_levels: &'static [CommandBufferLevel],
+        #[doc = "Empty for commands that do not return a `Result`."]
+        pub success_codes: &'static [Result],
+        pub error_codes: &'static [Result],
+    }
+    impl CommandMetadata {
+        pub fn is_recorded(&self) -> bool {
+            self.render_pass.is_some()
+        }
+        pub fn allowed_inside_render_pass(&self) -> bool {
+            match self.render_pass {
+                Some(RenderPassScope::Inside) | Some(RenderPassScope::Both) => true,
+                _ => false,
+            }
+        }
+        pub fn allowed_outside_render_pass(&self) -> bool {
+            match self.render_pass {
+                Some(RenderPassScope::Outside) | Some(RenderPassScope::Both) => true,
+                _ => false,
+            }
+        }
+        #[doc = "Returns true if a queue with `queue_flags` supports the command."]
+        pub fn supports_queue(&self, queue_flags: QueueFlags) -> bool {
+            self.queues.is_empty() || self.queues.intersects(queue_flags)
+        }
+        pub fn allowed_in(&self, level: CommandBufferLevel) -> bool {
+            self.command_buffer_levels.contains(&level)
+        }
+        pub fn may_return(&self, result: Result) -> bool {
+            self.success_codes.contains(&result) || self.error_codes.contains(&result)
+        }
+    }
+    #[doc = "Returns the metadata of a command, e.g. `vkCmdDraw`."]
+    pub fn metadata(name: &str) -> Option<&'static CommandMetadata> {
+        METADATA
+            .binary_search_by(|metadata| metadata.name.cmp(name))
+            .ok()
+            .map(|index| &METADATA[index])
+    }
+    #[doc = "The metadata of every command, sorted by name."]
+    pub static METADATA: &[CommandMetadata] = &[
+        CommandMetadata {
+            name: "vkAcquireImageANDROID",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkAcquireNextImage2KHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[
+                Result::SUCCESS,
+                Result::TIMEOUT,
+                Result::NOT_READY,
+                Result::SUBOPTIMAL_KHR,
+            ],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_OUT_OF_DATE_KHR,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkAcquireNextImageKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[
+                Result::SUCCESS,
+                Result::TIMEOUT,
+                Result::NOT_READY,
+                Result::SUBOPTIMAL_KHR,
+            ],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_OUT_OF_DATE_KHR,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkAcquireXlibDisplayEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INITIALIZATION_FAILED,
+            ],
+        },
+        CommandMetadata {
+            name: "vkAllocateCommandBuffers",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkAllocateDescriptorSets",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_FRAGMENTED_POOL,
+                Result::ERROR_OUT_OF_POOL_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkAllocateMemory",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkBeginCommandBuffer",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkBindAccelerationStructureMemoryNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkBindBufferMemory",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkBindBufferMemory2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkBindImageMemory",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkBindImageMemory2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCmdBeginConditionalRenderingEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBeginDebugUtilsLabelEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBeginQuery",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBeginQueryIndexedEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBeginRenderPass",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBeginRenderPass2KHR",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBeginTransformFeedbackEXT",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBindDescriptorSets",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBindIndexBuffer",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBindPipeline",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBindShadingRateImageNV",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBindTransformFeedbackBuffersEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBindVertexBuffers",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBlitImage",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdBuildAccelerationStructureNV",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b10),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdClearAttachments",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdClearColorImage",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdClearDepthStencilImage",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdCopyAccelerationStructureNV",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b10),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdCopyBuffer",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdCopyBufferToImage",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdCopyImage",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdCopyImageToBuffer",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdCopyQueryPoolResults",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDebugMarkerBeginEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDebugMarkerEndEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDebugMarkerInsertEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDispatch",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b10),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDispatchBase",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b10),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDispatchIndirect",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b10),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDraw",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawIndexed",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawIndexedIndirect",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawIndexedIndirectCountAMD",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawIndexedIndirectCountKHR",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawIndirect",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawIndirectByteCountEXT",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawIndirectCountAMD",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawIndirectCountKHR",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawMeshTasksIndirectCountNV",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawMeshTasksIndirectNV",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdDrawMeshTasksNV",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdEndConditionalRenderingEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdEndDebugUtilsLabelEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdEndQuery",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdEndQueryIndexedEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdEndRenderPass",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdEndRenderPass2KHR",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdEndTransformFeedbackEXT",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdExecuteCommands",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdFillBuffer",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdInsertDebugUtilsLabelEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdNextSubpass",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdNextSubpass2KHR",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdPipelineBarrier",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdProcessCommandsNVX",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdPushConstants",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdPushDescriptorSetKHR",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdPushDescriptorSetWithTemplateKHR",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdReserveSpaceForCommandsNVX",
+            render_pass: Some(RenderPassScope::Inside),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdResetEvent",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdResetQueryPool",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdResolveImage",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetBlendConstants",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetCheckpointNV",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetCoarseSampleOrderNV",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetDepthBias",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetDepthBounds",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetDeviceMask",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetDiscardRectangleEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetEvent",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetExclusiveScissorNV",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetLineWidth",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetSampleLocationsEXT",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetScissor",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetStencilCompareMask",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetStencilReference",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetStencilWriteMask",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetViewport",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetViewportShadingRatePaletteNV",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdSetViewportWScalingNV",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b1),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdTraceRaysNV",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b10),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdUpdateBuffer",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdWaitEvents",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b11),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdWriteAccelerationStructuresPropertiesNV",
+            render_pass: Some(RenderPassScope::Outside),
+            queues: QueueFlags(0b10),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdWriteBufferMarkerAMD",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCmdWriteTimestamp",
+            render_pass: Some(RenderPassScope::Both),
+            queues: QueueFlags(0b111),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkCompileDeferredNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateAccelerationStructureNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateAndroidSurfaceKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_NATIVE_WINDOW_IN_USE_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateBuffer",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateBufferView",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateCommandPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateComputePipelines",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INVALID_SHADER_NV,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateDebugReportCallbackEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkCreateDebugUtilsMessengerEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkCreateDescriptorPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_FRAGMENTATION_EXT,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateDescriptorSetLayout",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateDescriptorUpdateTemplate",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateDevice",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INITIALIZATION_FAILED,
+                Result::ERROR_EXTENSION_NOT_PRESENT,
+                Result::ERROR_FEATURE_NOT_PRESENT,
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateDisplayModeKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INITIALIZATION_FAILED,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateDisplayPlaneSurfaceKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateEvent",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateFence",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateFramebuffer",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateGraphicsPipelines",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INVALID_SHADER_NV,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateIOSSurfaceMVK",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateImage",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateImagePipeSurfaceFUCHSIA",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateImageView",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateIndirectCommandsLayoutNVX",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateInstance",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INITIALIZATION_FAILED,
+                Result::ERROR_LAYER_NOT_PRESENT,
+                Result::ERROR_EXTENSION_NOT_PRESENT,
+                Result::ERROR_INCOMPATIBLE_DRIVER,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateMacOSSurfaceMVK",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateObjectTableNVX",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreatePipelineCache",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreatePipelineLayout",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateQueryPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateRayTracingPipelinesNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INVALID_SHADER_NV,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateRenderPass",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateRenderPass2KHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateSampler",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateSamplerYcbcrConversion",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateSemaphore",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateShaderModule",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INVALID_SHADER_NV,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateSharedSwapchainsKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INCOMPATIBLE_DISPLAY_KHR,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateSwapchainKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_SURFACE_LOST_KHR,
+                Result::ERROR_NATIVE_WINDOW_IN_USE_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateValidationCacheEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkCreateViSurfaceNN",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateWaylandSurfaceKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateWin32SurfaceKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateXcbSurfaceKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkCreateXlibSurfaceKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkDebugMarkerSetObjectNameEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkDebugMarkerSetObjectTagEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkDebugReportMessageEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyAccelerationStructureNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyBuffer",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyBufferView",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyCommandPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyDebugReportCallbackEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyDebugUtilsMessengerEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyDescriptorPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyDescriptorSetLayout",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyDescriptorUpdateTemplate",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyDevice",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyEvent",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyFence",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyFramebuffer",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyImage",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyImageView",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyIndirectCommandsLayoutNVX",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyInstance",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyObjectTableNVX",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyPipeline",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyPipelineCache",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyPipelineLayout",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyQueryPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyRenderPass",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroySampler",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroySamplerYcbcrConversion",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroySemaphore",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyShaderModule",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroySurfaceKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroySwapchainKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDestroyValidationCacheEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkDeviceWaitIdle",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+        CommandMetadata {
+            name: "vkDisplayPowerControlEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkEndCommandBuffer",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkEnumerateDeviceExtensionProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_LAYER_NOT_PRESENT,
+            ],
+        },
+        CommandMetadata {
+            name: "vkEnumerateDeviceLayerProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkEnumerateInstanceExtensionProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_LAYER_NOT_PRESENT,
+            ],
+        },
+        CommandMetadata {
+            name: "vkEnumerateInstanceLayerProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkEnumerateInstanceVersion",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkEnumeratePhysicalDeviceGroups",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INITIALIZATION_FAILED,
+            ],
+        },
+        CommandMetadata {
+            name: "vkEnumeratePhysicalDevices",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_INITIALIZATION_FAILED,
+            ],
+        },
+        CommandMetadata {
+            name: "vkFlushMappedMemoryRanges",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkFreeCommandBuffers",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkFreeDescriptorSets",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkFreeMemory",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetAccelerationStructureHandleNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetAccelerationStructureMemoryRequirementsNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetAndroidHardwareBufferPropertiesANDROID",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetBufferMemoryRequirements",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetBufferMemoryRequirements2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetCalibratedTimestampsEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetDescriptorSetLayoutSupport",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetDeviceGroupPeerMemoryFeatures",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetDeviceGroupPresentCapabilitiesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetDeviceGroupSurfacePresentModesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetDeviceMemoryCommitment",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetDeviceProcAddr",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetDeviceQueue",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetDeviceQueue2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetDisplayModeProperties2KHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetDisplayModePropertiesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetDisplayPlaneCapabilities2KHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetDisplayPlaneCapabilitiesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetDisplayPlaneSupportedDisplaysKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetEventStatus",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::EVENT_SET, Result::EVENT_RESET],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetFenceFdKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetFenceStatus",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::NOT_READY],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetFenceWin32HandleKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetImageDrmFormatModifierPropertiesEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkGetImageMemoryRequirements",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetImageMemoryRequirements2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetImageSparseMemoryRequirements",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetImageSparseMemoryRequirements2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetImageSubresourceLayout",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetInstanceProcAddr",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetMemoryAndroidHardwareBufferANDROID",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetMemoryFdKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetMemoryFdPropertiesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetMemoryHostPointerPropertiesEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetMemoryWin32HandleKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetMemoryWin32HandleNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetMemoryWin32HandlePropertiesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPastPresentationTimingGOOGLE",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_OUT_OF_DATE_KHR,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceDisplayProperties2KHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceDisplayPropertiesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceExternalBufferProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceExternalFenceProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_FORMAT_NOT_SUPPORTED,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceExternalSemaphoreProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceFeatures",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceFeatures2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceFormatProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceFormatProperties2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceImageFormatProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_FORMAT_NOT_SUPPORTED,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceImageFormatProperties2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_FORMAT_NOT_SUPPORTED,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceMemoryProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceMemoryProperties2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDevicePresentRectanglesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceProperties2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceQueueFamilyProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceQueueFamilyProperties2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSparseImageFormatProperties",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSparseImageFormatProperties2",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSurfaceFormats2KHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSurfaceFormatsKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSurfacePresentModesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceSurfaceSupportKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetPipelineCacheData",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetQueryPoolResults",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::NOT_READY],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetQueueCheckpointDataNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetRandROutputDisplayEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkGetRayTracingShaderGroupHandlesNV",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetRefreshCycleDurationGOOGLE",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetRenderAreaGranularity",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkGetSemaphoreFdKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetSemaphoreWin32HandleKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_TOO_MANY_OBJECTS,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetShaderInfoAMD",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_FEATURE_NOT_PRESENT,
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetSwapchainCounterEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_OUT_OF_DATE_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetSwapchainGrallocUsageANDROID",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetSwapchainImagesKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetSwapchainStatusKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::SUBOPTIMAL_KHR],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_OUT_OF_DATE_KHR,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkGetValidationCacheDataEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::INCOMPLETE],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkImportFenceFdKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkImportFenceWin32HandleKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkImportSemaphoreFdKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkImportSemaphoreWin32HandleKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_INVALID_EXTERNAL_HANDLE,
+            ],
+        },
+        CommandMetadata {
+            name: "vkInvalidateMappedMemoryRanges",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkMapMemory",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_MEMORY_MAP_FAILED,
+            ],
+        },
+        CommandMetadata {
+            name: "vkMergePipelineCaches",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkMergeValidationCachesEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkQueueBeginDebugUtilsLabelEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkQueueBindSparse",
+            render_pass: None,
+            queues: QueueFlags(0b1000),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+        CommandMetadata {
+            name: "vkQueueEndDebugUtilsLabelEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkQueueInsertDebugUtilsLabelEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkQueuePresentKHR",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::SUBOPTIMAL_KHR],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+                Result::ERROR_OUT_OF_DATE_KHR,
+                Result::ERROR_SURFACE_LOST_KHR,
+            ],
+        },
+        CommandMetadata {
+            name: "vkQueueSignalReleaseImageANDROID",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkQueueSubmit",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+        CommandMetadata {
+            name: "vkQueueWaitIdle",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+        CommandMetadata {
+            name: "vkRegisterDeviceEventEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkRegisterDisplayEventEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_HOST_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkRegisterObjectsNVX",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkReleaseDisplayEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkResetCommandBuffer",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_DEVICE_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkResetCommandPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_DEVICE_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkResetDescriptorPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkResetEvent",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkResetFences",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[Result::ERROR_OUT_OF_DEVICE_MEMORY],
+        },
+        CommandMetadata {
+            name: "vkSetDebugUtilsObjectNameEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkSetDebugUtilsObjectTagEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkSetEvent",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkSetHdrMetadataEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkSubmitDebugUtilsMessageEXT",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkTrimCommandPool",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkUnmapMemory",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkUnregisterObjectsNVX",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ],
+        },
+        CommandMetadata {
+            name: "vkUpdateDescriptorSetWithTemplate",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkUpdateDescriptorSets",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[],
+            error_codes: &[],
+        },
+        CommandMetadata {
+            name: "vkWaitForFences",
+            render_pass: None,
+            queues: QueueFlags(0b0),
+            command_buffer_levels: &[],
+            success_codes: &[Result::SUCCESS, Result::TIMEOUT],
+            error_codes: &[
+                Result::ERROR_OUT_OF_HOST_MEMORY,
+                Result::ERROR_OUT_OF_DEVICE_MEMORY,
+                Result::ERROR_DEVICE_LOST,
+            ],
+        },
+    ];
+}
+pub mod formats {
+    use super::{Format, ImageAspectFlags};
+    #[doc = "How the bits of a component are interpreted."]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum NumericType {
+        Unorm,
+        Snorm,
+        Uscaled,
+        Sscaled,
+        Uint,
+        Sint,
+        Ufloat,
+        Sfloat,
+        Srgb,
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum ComponentName {
+        R,
+        G,
+        B,
+        A,
+        D,
+        S,
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum Compression {
+        Bc,
+        Etc2,
+        Eac,
+        Astc,
+        Pvrtc,
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Component {
+        pub name: ComponentName,
+        #[doc = "0 for compressed formats."]
+        pub bits: u32,
+        pub numeric_type: NumericType,
+    }
+    #[doc = "A plane of a multi-planar format, with the format of its image views and the size of the plane relative to the image."]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Plane {
+        pub format: Format,
+        pub width_divisor: u32,
+        pub height_divisor: u32,
+    }
+    #[doc = "The texel block layout of a format, as described in the Formats chapter of the specification."]
+    #[derive(Clone, Copy, Debug)]
+    pub struct FormatInfo {
+        pub format: Format,
+        #[doc = "The size of a texel block in bytes, the sum over all planes for multi-planar formats."]
+        pub block_size: u32,
+        #[doc = "The width, height and depth of a texel block in texels."]
+        pub block_extent: [u32; 3],
+        #[doc = "The components in memory order. Padding bits and the shared exponent are left out."]
+        pub components: &'static [Component],
+        pub aspect_mask: ImageAspectFlags,
+        #[doc = "Empty for formats that are not multi-planar."]
+        pub planes: &'static [Plane],
+        #[doc = "Formats of the same class are compatible, e.g. for image views of `MUTABLE_FORMAT` images."]
+        pub compatibility_class: &'static str,
+        pub compression: Option<Compression>,
+    }
+    impl FormatInfo {
+        #[doc = "The numeric type of the first component. Depth/stencil formats may have different types per component."]
+        pub fn numeric_type(&self) -> NumericType {
+            self.components[0].numeric_type
+        }
+        pub fn component(&self, name: ComponentName) -> Option<&Component> {
+            self.components
+                .iter()
+                .find(|component| component.name == name)
+        }
+        pub fn plane_count(&self) -> u32 {
+            (self.planes.len() as u32).max(1)
+        }
+        pub fn is_compressed(&self) -> bool {
+            self.compression.is_some()
+        }
+        pub fn is_depth_stencil(&self) -> bool {
+            self.aspect_mask
+                .intersects(ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL)
+        }
+        pub fn is_compatible_with(&self, other: &FormatInfo) -> bool {
+            self.compatibility_class == other.compatibility_class
+        }
+    }
+    impl Format {
+        #[doc = "`None` for `UNDEFINED`."]
+        pub fn info(self) -> Option<&'static FormatInfo> {
+            let index = match self {
+                Format::R4G4_UNORM_PACK8 => 0,
+                Format::R4G4B4A4_UNORM_PACK16 => 1,
+                Format::B4G4R4A4_UNORM_PACK16 => 2,
+                Format::R5G6B5_UNORM_PACK16 => 3,
+                Format::B5G6R5_UNORM_PACK16 => 4,
+                Format::R5G5B5A1_UNORM_PACK16 => 5,
+                Format::B5G5R5A1_UNORM_PACK16 => 6,
+                Format::A1R5G5B5_UNORM_PACK16 => 7,
+                Format::R8_UNORM => 8,
+                Format::R8_SNORM => 9,
+                Format::R8_USCALED => 10,
+                Format::R8_SSCALED => 11,
+                Format::R8_UINT => 12,
+                Format::R8_SINT => 13,
+                Format::R8_SRGB => 14,
+                Format::R8G8_UNORM => 15,
+                Format::R8G8_SNORM => 16,
+                Format::R8G8_USCALED => 17,
+                Format::R8G8_SSCALED => 18,
+                Format::R8G8_UINT => 19,
+                Format::R8G8_SINT => 20,
+                Format::R8G8_SRGB => 21,
+                Format::R8G8B8_UNORM => 22,
+                Format::R8G8B8_SNORM => 23,
+                Format::R8G8B8_USCALED => 24,
+                Format::R8G8B8_SSCALED => 25,
+                Format::R8G8B8_UINT => 26,
+                Format::R8G8B8_SINT => 27,
+                Format::R8G8B8_SRGB => 28,
+                Format::B8G8R8_UNORM => 29,
+                Format::B8G8R8_SNORM => 30,
+                Format::B8G8R8_USCALED => 31,
+                Format::B8G8R8_SSCALED => 32,
+                Format::B8G8R8_UINT => 33,
+                Format::B8G8R8_SINT => 34,
+                Format::B8G8R8_SRGB => 35,
+                Format::R8G8B8A8_UNORM => 36,
+                Format::R8G8B8A8_SNORM => 37,
+                Format::R8G8B8A8_USCALED => 38,
+                Format::R8G8B8A8_SSCALED => 39,
+                Format::R8G8B8A8_UINT => 40,
+                Format::R8G8B8A8_SINT => 41,
+                Format::R8G8B8A8_SRGB => 42,
+                Format::B8G8R8A8_UNORM => 43,
+                Format::B8G8R8A8_SNORM => 44,
+                Format::B8G8R8A8_USCALED => 45,
+                Format::B8G8R8A8_SSCALED => 46,
+                Format::B8G8R8A8_UINT => 47,
+                Format::B8G8R8A8_SINT => 48,
+                Format::B8G8R8A8_SRGB => 49,
+                Format::A8B8G8R8_UNORM_PACK32 => 50,
+                Format::A8B8G8R8_SNORM_PACK32 => 51,
+                Format::A8B8G8R8_USCALED_PACK32 => 52,
+                Format::A8B8G8R8_SSCALED_PACK32 => 53,
+                Format::A8B8G8R8_UINT_PACK32 => 54,
+                Format::A8B8G8R8_SINT_PACK32 => 55,
+                Format::A8B8G8R8_SRGB_PACK32 => 56,
+                Format::A2R10G10B10_UNORM_PACK32 => 57,
+                Format::A2R10G10B10_SNORM_PACK32 => 58,
+                Format::A2R10G10B10_USCALED_PACK32 => 59,
+                Format::A2R10G10B10_SSCALED_PACK32 => 60,
+                Format::A2R10G10B10_UINT_PACK32 => 61,
+                Format::A2R10G10B10_SINT_PACK32 => 62,
+                Format::A2B10G10R10_UNORM_PACK32 => 63,
+                Format::A2B10G10R10_SNORM_PACK32 => 64,
+                Format::A2B10G10R10_USCALED_PACK32 => 65,
+                Format::A2B10G10R10_SSCALED_PACK32 => 66,
+                Format::A2B10G10R10_UINT_PACK32 => 67,
+                Format::A2B10G10R10_SINT_PACK32 => 68,
+                Format::R16_UNORM => 69,
+                Format::R16_SNORM => 70,
+                Format::R16_USCALED => 71,
+                Format::R16_SSCALED => 72,
+                Format::R16_UINT => 73,
+                Format::R16_SINT => 74,
+                Format::R16_SFLOAT => 75,
+                Format::R16G16_UNORM => 76,
+                Format::R16G16_SNORM => 77,
+                Format::R16G16_USCALED => 78,
+                Format::R16G16_SSCALED => 79,
+                Format::R16G16_UINT => 80,
+                Format::R16G16_SINT => 81,
+                Format::R16G16_SFLOAT => 82,
+                Format::R16G16B16_UNORM => 83,
+                Format::R16G16B16_SNORM => 84,
+                Format::R16G16B16_USCALED => 85,
+                Format::R16G16B16_SSCALED => 86,
+                Format::R16G16B16_UINT => 87,
+                Format::R16G16B16_SINT => 88,
+                Format::R16G16B16_SFLOAT => 89,
+                Format::R16G16B16A16_UNORM => 90,
+                Format::R16G16B16A16_SNORM => 91,
+                Format::R16G16B16A16_USCALED => 92,
+                Format::R16G16B16A16_SSCALED => 93,
+                Format::R16G16B16A16_UINT => 94,
+                Format::R16G16B16A16_SINT => 95,
+                Format::R16G16B16A16_SFLOAT => 96,
+                Format::R32_UINT => 97,
+                Format::R32_SINT => 98,
+                Format::R32_SFLOAT => 99,
+                Format::R32G32_UINT => 100,
+                Format::R32G32_SINT => 101,
+                Format::R32G32_SFLOAT => 102,
+                Format::R32G32B32_UINT => 103,
+                Format::R32G32B32_SINT => 104,
+                Format::R32G32B32_SFLOAT => 105,
+                Format::R32G32B32A32_UINT => 106,
+                Format::R32G32B32A32_SINT => 107,
+                Format::R32G32B32A32_SFLOAT => 108,
+                Format::R64_UINT => 109,
+                Format::R64_SINT => 110,
+                Format::R64_SFLOAT => 111,
+                Format::R64G64_UINT => 112,
+                Format::R64G64_SINT => 113,
+                Format::R64G64_SFLOAT => 114,
+                Format::R64G64B64_UINT => 115,
+                Format::R64G64B64_SINT => 116,
+                Format::R64G64B64_SFLOAT => 117,
+                Format::R64G64B64A64_UINT => 118,
+                Format::R64G64B64A64_SINT => 119,
+                Format::R64G64B64A64_SFLOAT => 120,
+                Format::B10G11R11_UFLOAT_PACK32 => 121,
+                Format::E5B9G9R9_UFLOAT_PACK32 => 122,
+                Format::D16_UNORM => 123,
+                Format::X8_D24_UNORM_PACK32 => 124,
+                Format::D32_SFLOAT => 125,
+                Format::S8_UINT => 126,
+                Format::D16_UNORM_S8_UINT => 127,
+                Format::D24_UNORM_S8_UINT => 128,
+                Format::D32_SFLOAT_S8_UINT => 129,
+                Format::BC1_RGB_UNORM_BLOCK => 130,
+                Format::BC1_RGB_SRGB_BLOCK => 131,
+                Format::BC1_RGBA_UNORM_BLOCK => 132,
+                Format::BC1_RGBA_SRGB_BLOCK => 133,
+                Format::BC2_UNORM_BLOCK => 134,
+                Format::BC2_SRGB_BLOCK => 135,
+                Format::BC3_UNORM_BLOCK => 136,
+                Format::BC3_SRGB_BLOCK => 137,
+                Format::BC4_UNORM_BLOCK => 138,
+                Format::BC4_SNORM_BLOCK => 139,
+                Format::BC5_UNORM_BLOCK => 140,
+                Format::BC5_SNORM_BLOCK => 141,
+                Format::BC6H_UFLOAT_BLOCK => 142,
+                Format::BC6H_SFLOAT_BLOCK => 143,
+                Format::BC7_UNORM_BLOCK => 144,
+                Format::BC7_SRGB_BLOCK => 145,
+                Format::ETC2_R8G8B8_UNORM_BLOCK => 146,
+                Format::ETC2_R8G8B8_SRGB_BLOCK => 147,
+                Format::ETC2_R8G8B8A1_UNORM_BLOCK => 148,
+                Format::ETC2_R8G8B8A1_SRGB_BLOCK => 149,
+                Format::ETC2_R8G8B8A8_UNORM_BLOCK => 150,
+                Format::ETC2_R8G8B8A8_SRGB_BLOCK => 151,
+                Format::EAC_R11_UNORM_BLOCK => 152,
+                Format::EAC_R11_SNORM_BLOCK => 153,
+                Format::EAC_R11G11_UNORM_BLOCK => 154,
+                Format::EAC_R11G11_SNORM_BLOCK => 155,
+                Format::ASTC_4X4_UNORM_BLOCK => 156,
+                Format::ASTC_4X4_SRGB_BLOCK => 157,
+                Format::ASTC_5X4_UNORM_BLOCK => 158,
+                Format::ASTC_5X4_SRGB_BLOCK => 159,
+                Format::ASTC_5X5_UNORM_BLOCK => 160,
+                Format::ASTC_5X5_SRGB_BLOCK => 161,
+                Format::ASTC_6X5_UNORM_BLOCK => 162,
+                Format::ASTC_6X5_SRGB_BLOCK => 163,
+                Format::ASTC_6X6_UNORM_BLOCK => 164,
+                Format::ASTC_6X6_SRGB_BLOCK => 165,
+                Format::ASTC_8X5_UNORM_BLOCK => 166,
+                Format::ASTC_8X5_SRGB_BLOCK => 167,
+                Format::ASTC_8X6_UNORM_BLOCK => 168,
+                Format::ASTC_8X6_SRGB_BLOCK => 169,
+                Format::ASTC_8X8_UNORM_BLOCK => 170,
+                Format::ASTC_8X8_SRGB_BLOCK => 171,
+                Format::ASTC_10X5_UNORM_BLOCK => 172,
+                Format::ASTC_10X5_SRGB_BLOCK => 173,
+                Format::ASTC_10X6_UNORM_BLOCK => 174,
+                Format::ASTC_10X6_SRGB_BLOCK => 175,
+                Format::ASTC_10X8_UNORM_BLOCK => 176,
+                Format::ASTC_10X8_SRGB_BLOCK => 177,
+                Format::ASTC_10X10_UNORM_BLOCK => 178,
+                Format::ASTC_10X10_SRGB_BLOCK => 179,
+                Format::ASTC_12X10_UNORM_BLOCK => 180,
+                Format::ASTC_12X10_SRGB_BLOCK => 181,
+                Format::ASTC_12X12_UNORM_BLOCK => 182,
+                Format::ASTC_12X12_SRGB_BLOCK => 183,
+                Format::PVRTC1_2BPP_UNORM_BLOCK_IMG => 184,
+                Format::PVRTC1_4BPP_UNORM_BLOCK_IMG => 185,
+                Format::PVRTC2_2BPP_UNORM_BLOCK_IMG => 186,
+                Format::PVRTC2_4BPP_UNORM_BLOCK_IMG => 187,
+                Format::PVRTC1_2BPP_SRGB_BLOCK_IMG => 188,
+                Format::PVRTC1_4BPP_SRGB_BLOCK_IMG => 189,
+                Format::PVRTC2_2BPP_SRGB_BLOCK_IMG => 190,
+                Format::PVRTC2_4BPP_SRGB_BLOCK_IMG => 191,
+                Format::G8B8G8R8_422_UNORM => 192,
+                Format::B8G8R8G8_422_UNORM => 193,
+                Format::G8_B8_R8_3PLANE_420_UNORM => 194,
+                Format::G8_B8R8_2PLANE_420_UNORM => 195,
+                Format::G8_B8_R8_3PLANE_422_UNORM => 196,
+                Format::G8_B8R8_2PLANE_422_UNORM => 197,
+                Format::G8_B8_R8_3PLANE_444_UNORM => 198,
+                Format::R10X6_UNORM_PACK16 => 199,
+                Format::R10X6G10X6_UNORM_2PACK16 => 200,
+                Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16 => 201,
+                Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16 => 202,
+                Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16 => 203,
+                Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16 => 204,
+                Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16 => 205,
+                Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16 => 206,
+                Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16 => 207,
+                Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16 => 208,
+                Format::R12X4_UNORM_PACK16 => 209,
+                Format::R12X4G12X4_UNORM_2PACK16 => 210,
+                Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16 => 211,
+                Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16 => 212,
+                Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16 => 213,
+                Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16 => 214,
+                Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16 => 215,
+                Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16 => 216,
+                Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16 => 217,
+                Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16 => 218,
+                Format::G16B16G16R16_422_UNORM => 219,
+                Format::B16G16R16G16_422_UNORM => 220,
+                Format::G16_B16_R16_3PLANE_420_UNORM => 221,
+                Format::G16_B16R16_2PLANE_420_UNORM => 222,
+                Format::G16_B16_R16_3PLANE_422_UNORM => 223,
+                Format::G16_B16R16_2PLANE_422_UNORM => 224,
+                Format::G16_B16_R16_3PLANE_444_UNORM => 225,
+                _ => return None,
+            };
+            Some(&FORMATS[index])
+        }
+        #[doc = "Whether an image view of `self` may be created for an image of `other`, for `MUTABLE_FORMAT` images. Their compatibility classes have to match."]
+        pub fn is_compatible_with(self, other: Format) -> bool {
+            match (self.info(), other.info()) {
+                (Some(a), Some(b)) => a.is_compatible_with(b),
+                _ => false,
+            }
+        }
+    }
+    #[doc = "The layout of every format except `UNDEFINED`, in declaration order."]
+    pub static FORMATS: &[FormatInfo] = &[
+        FormatInfo {
+            format: Format::R4G4_UNORM_PACK8,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "8-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R4G4B4A4_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B4G4R4A4_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 4,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R5G6B5_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 6,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B5G6R5_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 6,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R5G5B5A1_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 1,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B5G5R5A1_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 1,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A1R5G5B5_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 1,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 5,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8_UNORM,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 8,
+                numeric_type: NumericType::Unorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "8-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8_SNORM,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 8,
+                numeric_type: NumericType::Snorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "8-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8_USCALED,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 8,
+                numeric_type: NumericType::Uscaled,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "8-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8_SSCALED,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 8,
+                numeric_type: NumericType::Sscaled,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "8-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8_UINT,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 8,
+                numeric_type: NumericType::Uint,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "8-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8_SINT,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 8,
+                numeric_type: NumericType::Sint,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "8-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8_SRGB,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 8,
+                numeric_type: NumericType::Srgb,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "8-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8_UNORM,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8_SNORM,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8_USCALED,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8_SSCALED,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8_UINT,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8_SINT,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8_SRGB,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8_UNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8_SNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8_USCALED,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8_SSCALED,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8_UINT,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8_SINT,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8_SRGB,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8_UNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8_SNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8_USCALED,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8_SSCALED,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8_UINT,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8_SINT,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8_SRGB,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "24-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8A8_UNORM,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8A8_SNORM,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8A8_USCALED,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8A8_SSCALED,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8A8_UINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8A8_SINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R8G8B8A8_SRGB,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8A8_UNORM,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8A8_SNORM,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8A8_USCALED,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8A8_SSCALED,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8A8_UINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8A8_SINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8A8_SRGB,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A8B8G8R8_UNORM_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A8B8G8R8_SNORM_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A8B8G8R8_USCALED_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A8B8G8R8_SSCALED_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A8B8G8R8_UINT_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A8B8G8R8_SINT_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A8B8G8R8_SRGB_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Srgb,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2R10G10B10_UNORM_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2R10G10B10_SNORM_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2R10G10B10_USCALED_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2R10G10B10_SSCALED_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2R10G10B10_UINT_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2R10G10B10_SINT_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2B10G10R10_UNORM_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2B10G10R10_SNORM_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2B10G10R10_USCALED_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2B10G10R10_SSCALED_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2B10G10R10_UINT_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::A2B10G10R10_SINT_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::A,
+                    bits: 2,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16_UNORM,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 16,
+                numeric_type: NumericType::Unorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16_SNORM,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 16,
+                numeric_type: NumericType::Snorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16_USCALED,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 16,
+                numeric_type: NumericType::Uscaled,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16_SSCALED,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 16,
+                numeric_type: NumericType::Sscaled,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16_UINT,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 16,
+                numeric_type: NumericType::Uint,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16_SINT,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 16,
+                numeric_type: NumericType::Sint,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16_SFLOAT,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 16,
+                numeric_type: NumericType::Sfloat,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16_UNORM,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16_SNORM,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16_USCALED,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16_SSCALED,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16_UINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16_SINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16_SFLOAT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16_UNORM,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "48-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16_SNORM,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "48-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16_USCALED,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "48-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16_SSCALED,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "48-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16_UINT,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "48-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16_SINT,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "48-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16_SFLOAT,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "48-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16A16_UNORM,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16A16_SNORM,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 16,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16A16_USCALED,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 16,
+                    numeric_type: NumericType::Uscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16A16_SSCALED,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 16,
+                    numeric_type: NumericType::Sscaled,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16A16_UINT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 16,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16A16_SINT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 16,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R16G16B16A16_SFLOAT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 16,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32_UINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 32,
+                numeric_type: NumericType::Uint,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32_SINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 32,
+                numeric_type: NumericType::Sint,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32_SFLOAT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 32,
+                numeric_type: NumericType::Sfloat,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32_UINT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32_SINT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32_SFLOAT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32B32_UINT,
+            block_size: 12,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "96-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32B32_SINT,
+            block_size: 12,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "96-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32B32_SFLOAT,
+            block_size: 12,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "96-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32B32A32_UINT,
+            block_size: 16,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 32,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "128-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32B32A32_SINT,
+            block_size: 16,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 32,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "128-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R32G32B32A32_SFLOAT,
+            block_size: 16,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "128-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64_UINT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 64,
+                numeric_type: NumericType::Uint,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64_SINT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 64,
+                numeric_type: NumericType::Sint,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64_SFLOAT,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 64,
+                numeric_type: NumericType::Sfloat,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64_UINT,
+            block_size: 16,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "128-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64_SINT,
+            block_size: 16,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "128-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64_SFLOAT,
+            block_size: 16,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "128-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64B64_UINT,
+            block_size: 24,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "192-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64B64_SINT,
+            block_size: 24,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "192-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64B64_SFLOAT,
+            block_size: 24,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "192-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64B64A64_UINT,
+            block_size: 32,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 64,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "256-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64B64A64_SINT,
+            block_size: 32,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 64,
+                    numeric_type: NumericType::Sint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "256-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R64G64B64A64_SFLOAT,
+            block_size: 32,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 64,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "256-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B10G11R11_UFLOAT_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Ufloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 11,
+                    numeric_type: NumericType::Ufloat,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 11,
+                    numeric_type: NumericType::Ufloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::E5B9G9R9_UFLOAT_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 9,
+                    numeric_type: NumericType::Ufloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 9,
+                    numeric_type: NumericType::Ufloat,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 9,
+                    numeric_type: NumericType::Ufloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::D16_UNORM,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::D,
+                bits: 16,
+                numeric_type: NumericType::Unorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b10),
+            planes: &[],
+            compatibility_class: "D16",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::X8_D24_UNORM_PACK32,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::D,
+                bits: 24,
+                numeric_type: NumericType::Unorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b10),
+            planes: &[],
+            compatibility_class: "D24",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::D32_SFLOAT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::D,
+                bits: 32,
+                numeric_type: NumericType::Sfloat,
+            }],
+            aspect_mask: ImageAspectFlags(0b10),
+            planes: &[],
+            compatibility_class: "D32",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::S8_UINT,
+            block_size: 1,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::S,
+                bits: 8,
+                numeric_type: NumericType::Uint,
+            }],
+            aspect_mask: ImageAspectFlags(0b100),
+            planes: &[],
+            compatibility_class: "S8",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::D16_UNORM_S8_UINT,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::D,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::S,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110),
+            planes: &[],
+            compatibility_class: "D16S8",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::D24_UNORM_S8_UINT,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::D,
+                    bits: 24,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::S,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110),
+            planes: &[],
+            compatibility_class: "D24S8",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::D32_SFLOAT_S8_UINT,
+            block_size: 5,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::D,
+                    bits: 32,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::S,
+                    bits: 8,
+                    numeric_type: NumericType::Uint,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110),
+            planes: &[],
+            compatibility_class: "D32S8",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::BC1_RGB_UNORM_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC1_RGB",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC1_RGB_SRGB_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC1_RGB",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC1_RGBA_UNORM_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC1_RGBA",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC1_RGBA_SRGB_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC1_RGBA",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC2_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC2",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC2_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC2",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC3_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC3",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC3_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC3",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC4_UNORM_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 0,
+                numeric_type: NumericType::Unorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC4",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC4_SNORM_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 0,
+                numeric_type: NumericType::Snorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC4",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC5_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC5",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC5_SNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC5",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC6H_UFLOAT_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Ufloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Ufloat,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Ufloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC6H",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC6H_SFLOAT_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Sfloat,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Sfloat,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC6H",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC7_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC7",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::BC7_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "BC7",
+            compression: Some(Compression::Bc),
+        },
+        FormatInfo {
+            format: Format::ETC2_R8G8B8_UNORM_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ETC2_RGB",
+            compression: Some(Compression::Etc2),
+        },
+        FormatInfo {
+            format: Format::ETC2_R8G8B8_SRGB_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ETC2_RGB",
+            compression: Some(Compression::Etc2),
+        },
+        FormatInfo {
+            format: Format::ETC2_R8G8B8A1_UNORM_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ETC2_RGBA",
+            compression: Some(Compression::Etc2),
+        },
+        FormatInfo {
+            format: Format::ETC2_R8G8B8A1_SRGB_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ETC2_RGBA",
+            compression: Some(Compression::Etc2),
+        },
+        FormatInfo {
+            format: Format::ETC2_R8G8B8A8_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ETC2_EAC_RGBA",
+            compression: Some(Compression::Etc2),
+        },
+        FormatInfo {
+            format: Format::ETC2_R8G8B8A8_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ETC2_EAC_RGBA",
+            compression: Some(Compression::Etc2),
+        },
+        FormatInfo {
+            format: Format::EAC_R11_UNORM_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 0,
+                numeric_type: NumericType::Unorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "EAC_R",
+            compression: Some(Compression::Eac),
+        },
+        FormatInfo {
+            format: Format::EAC_R11_SNORM_BLOCK,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 0,
+                numeric_type: NumericType::Snorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "EAC_R",
+            compression: Some(Compression::Eac),
+        },
+        FormatInfo {
+            format: Format::EAC_R11G11_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "EAC_RG",
+            compression: Some(Compression::Eac),
+        },
+        FormatInfo {
+            format: Format::EAC_R11G11_SNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Snorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Snorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "EAC_RG",
+            compression: Some(Compression::Eac),
+        },
+        FormatInfo {
+            format: Format::ASTC_4X4_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_4x4",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_4X4_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_4x4",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_5X4_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [5, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_5x4",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_5X4_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [5, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_5x4",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_5X5_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [5, 5, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_5x5",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_5X5_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [5, 5, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_5x5",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_6X5_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [6, 5, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_6x5",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_6X5_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [6, 5, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_6x5",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_6X6_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [6, 6, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_6x6",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_6X6_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [6, 6, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_6x6",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_8X5_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [8, 5, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_8x5",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_8X5_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [8, 5, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_8x5",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_8X6_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [8, 6, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_8x6",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_8X6_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [8, 6, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_8x6",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_8X8_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [8, 8, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_8x8",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_8X8_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [8, 8, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_8x8",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_10X5_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [10, 5, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_10x5",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_10X5_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [10, 5, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_10x5",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_10X6_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [10, 6, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_10x6",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_10X6_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [10, 6, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_10x6",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_10X8_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [10, 8, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_10x8",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_10X8_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [10, 8, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_10x8",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_10X10_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [10, 10, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_10x10",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_10X10_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [10, 10, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_10x10",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_12X10_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [12, 10, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_12x10",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_12X10_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [12, 10, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_12x10",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_12X12_UNORM_BLOCK,
+            block_size: 16,
+            block_extent: [12, 12, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_12x12",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::ASTC_12X12_SRGB_BLOCK,
+            block_size: 16,
+            block_extent: [12, 12, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "ASTC_12x12",
+            compression: Some(Compression::Astc),
+        },
+        FormatInfo {
+            format: Format::PVRTC1_2BPP_UNORM_BLOCK_IMG,
+            block_size: 8,
+            block_extent: [8, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "PVRTC1_2BPP",
+            compression: Some(Compression::Pvrtc),
+        },
+        FormatInfo {
+            format: Format::PVRTC1_4BPP_UNORM_BLOCK_IMG,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "PVRTC1_4BPP",
+            compression: Some(Compression::Pvrtc),
+        },
+        FormatInfo {
+            format: Format::PVRTC2_2BPP_UNORM_BLOCK_IMG,
+            block_size: 8,
+            block_extent: [8, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "PVRTC2_2BPP",
+            compression: Some(Compression::Pvrtc),
+        },
+        FormatInfo {
+            format: Format::PVRTC2_4BPP_UNORM_BLOCK_IMG,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "PVRTC2_4BPP",
+            compression: Some(Compression::Pvrtc),
+        },
+        FormatInfo {
+            format: Format::PVRTC1_2BPP_SRGB_BLOCK_IMG,
+            block_size: 8,
+            block_extent: [8, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "PVRTC1_2BPP",
+            compression: Some(Compression::Pvrtc),
+        },
+        FormatInfo {
+            format: Format::PVRTC1_4BPP_SRGB_BLOCK_IMG,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "PVRTC1_4BPP",
+            compression: Some(Compression::Pvrtc),
+        },
+        FormatInfo {
+            format: Format::PVRTC2_2BPP_SRGB_BLOCK_IMG,
+            block_size: 8,
+            block_extent: [8, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "PVRTC2_2BPP",
+            compression: Some(Compression::Pvrtc),
+        },
+        FormatInfo {
+            format: Format::PVRTC2_4BPP_SRGB_BLOCK_IMG,
+            block_size: 8,
+            block_extent: [4, 4, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 0,
+                    numeric_type: NumericType::Srgb,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 0,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "PVRTC2_4BPP",
+            compression: Some(Compression::Pvrtc),
+        },
+        FormatInfo {
+            format: Format::G8B8G8R8_422_UNORM,
+            block_size: 4,
+            block_extent: [2, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit G8B8G8R8",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B8G8R8G8_422_UNORM,
+            block_size: 4,
+            block_extent: [2, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit B8G8R8G8",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G8_B8_R8_3PLANE_420_UNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+            ],
+            compatibility_class: "8-bit 3-plane 420",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G8_B8R8_2PLANE_420_UNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110001),
+            planes: &[
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R8G8_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+            ],
+            compatibility_class: "8-bit 2-plane 420",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G8_B8_R8_3PLANE_422_UNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "8-bit 3-plane 422",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G8_B8R8_2PLANE_422_UNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110001),
+            planes: &[
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R8G8_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "8-bit 2-plane 422",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G8_B8_R8_3PLANE_444_UNORM,
+            block_size: 3,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 8,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R8_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "8-bit 3-plane 444",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R10X6_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 10,
+                numeric_type: NumericType::Unorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R10X6G10X6_UNORM_2PACK16,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit R10G10B10A10",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16,
+            block_size: 8,
+            block_extent: [2, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit G10B10G10R10",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16,
+            block_size: 8,
+            block_extent: [2, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit B10G10R10G10",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+            ],
+            compatibility_class: "10-bit 3-plane 420",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110001),
+            planes: &[
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R10X6G10X6_UNORM_2PACK16,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+            ],
+            compatibility_class: "10-bit 2-plane 420",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "10-bit 3-plane 422",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110001),
+            planes: &[
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R10X6G10X6_UNORM_2PACK16,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "10-bit 2-plane 422",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 10,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R10X6_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "10-bit 3-plane 444",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R12X4_UNORM_PACK16,
+            block_size: 2,
+            block_extent: [1, 1, 1],
+            components: &[Component {
+                name: ComponentName::R,
+                bits: 12,
+                numeric_type: NumericType::Unorm,
+            }],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "16-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R12X4G12X4_UNORM_2PACK16,
+            block_size: 4,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "32-bit",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16,
+            block_size: 8,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::A,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit R12G12B12A12",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16,
+            block_size: 8,
+            block_extent: [2, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit G12B12G12R12",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16,
+            block_size: 8,
+            block_extent: [2, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit B12G12R12G12",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+            ],
+            compatibility_class: "12-bit 3-plane 420",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110001),
+            planes: &[
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R12X4G12X4_UNORM_2PACK16,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+            ],
+            compatibility_class: "12-bit 2-plane 420",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "12-bit 3-plane 422",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110001),
+            planes: &[
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R12X4G12X4_UNORM_2PACK16,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "12-bit 2-plane 422",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 12,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R12X4_UNORM_PACK16,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "12-bit 3-plane 444",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G16B16G16R16_422_UNORM,
+            block_size: 8,
+            block_extent: [2, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit G16B16G16R16",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::B16G16R16G16_422_UNORM,
+            block_size: 8,
+            block_extent: [2, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1),
+            planes: &[],
+            compatibility_class: "64-bit B16G16R16G16",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G16_B16_R16_3PLANE_420_UNORM,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+            ],
+            compatibility_class: "16-bit 3-plane 420",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G16_B16R16_2PLANE_420_UNORM,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110001),
+            planes: &[
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R16G16_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 2,
+                },
+            ],
+            compatibility_class: "16-bit 2-plane 420",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G16_B16_R16_3PLANE_422_UNORM,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "16-bit 3-plane 422",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G16_B16R16_2PLANE_422_UNORM,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b110001),
+            planes: &[
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R16G16_UNORM,
+                    width_divisor: 2,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "16-bit 2-plane 422",
+            compression: None,
+        },
+        FormatInfo {
+            format: Format::G16_B16_R16_3PLANE_444_UNORM,
+            block_size: 6,
+            block_extent: [1, 1, 1],
+            components: &[
+                Component {
+                    name: ComponentName::G,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::B,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+                Component {
+                    name: ComponentName::R,
+                    bits: 16,
+                    numeric_type: NumericType::Unorm,
+                },
+            ],
+            aspect_mask: ImageAspectFlags(0b1110001),
+            planes: &[
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+                Plane {
+                    format: Format::R16_UNORM,
+                    width_divisor: 1,
+                    height_divisor: 1,
+                },
+            ],
+            compatibility_class: "16-bit 3-plane 444",
+            compression: None,
+        },
+    ];
+}
@@ -0,0 +1,96 @@
+extern crate ash;
+use ash::util::{Align, AlignError};
+use std::os::raw::c_void;
+
+// Backing storage with a known alignment
+#[repr(align(256))]
+struct Storage([u8; 1024]);
+
+#[test]
+fn elements_are_padded_to_the_alignment() {
+    let mut storage = Storage([0; 1024]);
+    {
+        let mut align = Align::<u32>::from_slice(&mut storage.0, 256).unwrap();
+        assert_eq!(align.len(), 4);
+        assert_eq!(align.elem_size(), 256);
+        align.copy_from_slice(&[1, 2, 3, 4, 5]);
+        assert_eq!(align.get(1), Some(&2));
+        assert_eq!(align.get(4), None);
+        *align.get_mut(3).unwrap() = 40;
+        assert_eq!(align.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3, 40]);
+        assert_eq!(align.copy_to_vec(), vec![1, 2, 3, 40]);
+    }
+    assert_eq!(storage.0[512], 3);
+    assert_eq!(storage.0[513], 0);
+}
+
+#[test]
+fn sub_ranges() {
+    let mut storage = Storage([0; 1024]);
+    let mut align = Align::<u64>::from_slice(&mut storage.0, 64).unwrap();
+    assert_eq!(align.len(), 16);
+    {
+        let mut tail = align.sub_range(12..16);
+        assert_eq!(tail.len(), 4);
+        for (i, value) in tail.iter_mut().enumerate() {
+            *value = i as u64 + 1;
+        }
+    }
+    assert_eq!(align.get(11), Some(&0));
+    assert_eq!(align.get(12), Some(&1));
+    assert_eq!(align.get(15), Some(&4));
+    assert!(align.sub_range(16..16).is_empty());
+}
+
+#[test]
+fn packed_elements() {
+    let mut storage = Storage([0; 1024]);
+    let mut align = Align::<[u8; 3]>::from_slice(&mut storage.0[..12], 1).unwrap();
+    assert_eq!(align.len(), 4);
+    align.copy_from_slice(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(&storage.0[..7], &[1, 2, 3, 4, 5, 6, 0]);
+}
+
+#[test]
+fn misaligned_memory_is_rejected() {
+    let mut storage = Storage([0; 1024]);
+    assert_eq!(
+        Align::<u32>::from_slice(&mut storage.0[4..516], 256).err(),
+        Some(AlignError::MisalignedPointer)
+    );
+    assert_eq!(
+        Align::<u32>::from_slice(&mut storage.0[..300], 256).err(),
+        Some(AlignError::UnalignedSize)
+    );
+    assert_eq!(
+        Align::<u32>::from_slice(&mut storage.0, 0).err(),
+        Some(AlignError::InvalidAlignment)
+    );
+    assert_eq!(
+        Align::<u32>::from_slice(&mut storage.0[1..9], 1).err(),
+        Some(AlignError::MisalignedPointer)
+    );
+}
+
+#[test]
+fn new_only_checks_the_size() {
+    let mut storage = Storage([0; 1024]);
+    unsafe {
+        let ptr = storage.0.as_mut_ptr().offset(8) as *mut c_void;
+        assert_eq!(
+            Align::<u32>::try_new(ptr, 64, 128).err(),
+            Some(AlignError::MisalignedPointer)
+        );
+        // The pointer is used as is
+        assert_eq!(Align::<u32>::new(ptr, 64, 128).len(), 2);
+    }
+}
+
+#[test]
+#[should_panic(expected = "size must be aligned")]
+fn new_panics_on_unaligned_sizes() {
+    let mut storage = Storage([0; 1024]);
+    unsafe {
+        Align::<u32>::new(storage.0.as_mut_ptr() as *mut c_void, 64, 100);
+    }
+}
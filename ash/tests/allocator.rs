@@ -0,0 +1,313 @@
+extern crate ash;
+mod common;
+use ash::allocator::{
+    find_memory_type_index, AllocationCreateInfo, Allocator, MemoryUsage, Strategy,
+};
+use ash::vk::{self, Handle};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+const BLOCK_SIZE: u64 = 1 << 20;
+
+extern "system" fn allocate_memory(
+    _: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    unsafe {
+        let info = &*p_allocate_info;
+        // The device local heap only has room for blocks
+        if info.memory_type_index == 0 && info.allocation_size > BLOCK_SIZE {
+            return vk::Result::ERROR_OUT_OF_DEVICE_MEMORY;
+        }
+        // The handle is the address of the memory, which `map_memory` returns
+        let memory = vec![0u8; info.allocation_size as usize].into_boxed_slice();
+        *p_memory = vk::DeviceMemory::from_raw(Box::into_raw(memory) as *mut u8 as u64);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn free_memory(
+    _: vk::Device,
+    _: vk::DeviceMemory,
+    _: *const vk::AllocationCallbacks,
+) -> c_void {
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn map_memory(
+    _: vk::Device,
+    memory: vk::DeviceMemory,
+    _: vk::DeviceSize,
+    _: vk::DeviceSize,
+    _: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    unsafe {
+        *pp_data = memory.as_raw() as *mut c_void;
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn create_buffer(
+    _: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    // The buffer handle is its size
+    unsafe {
+        *p_buffer = vk::Buffer::from_raw((*p_create_info).size);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_buffer_memory_requirements(
+    _: vk::Device,
+    buffer: vk::Buffer,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) -> c_void {
+    unsafe {
+        *p_memory_requirements = vk::MemoryRequirements {
+            size: buffer.as_raw(),
+            alignment: 256,
+            memory_type_bits: 0b111,
+        };
+        mem::zeroed()
+    }
+}
+
+extern "system" fn create_image(
+    _: vk::Device,
+    _: *const vk::ImageCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_image: *mut vk::Image,
+) -> vk::Result {
+    unsafe {
+        *p_image = vk::Image::from_raw(1);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_image_memory_requirements(
+    _: vk::Device,
+    _: vk::Image,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) -> c_void {
+    unsafe {
+        *p_memory_requirements = vk::MemoryRequirements {
+            size: 4096,
+            alignment: 4096,
+            memory_type_bits: 0b111,
+        };
+        mem::zeroed()
+    }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkAllocateMemory" => allocate_memory as *const c_void,
+            b"vkFreeMemory" => free_memory as *const c_void,
+            b"vkMapMemory" => map_memory as *const c_void,
+            b"vkCreateBuffer" => create_buffer as *const c_void,
+            b"vkGetBufferMemoryRequirements" => get_buffer_memory_requirements as *const c_void,
+            b"vkCreateImage" => create_image as *const c_void,
+            b"vkGetImageMemoryRequirements" => get_image_memory_requirements as *const c_void,
+            b"vkBindBufferMemory" | b"vkBindImageMemory" => common::bind_memory as *const c_void,
+            b"vkDestroyBuffer" | b"vkDestroyImage" => common::destroy_handle as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn memory_properties() -> vk::PhysicalDeviceMemoryProperties {
+    let mut properties = vk::PhysicalDeviceMemoryProperties {
+        memory_type_count: 3,
+        memory_heap_count: 2,
+        ..Default::default()
+    };
+    properties.memory_types[0].property_flags = vk::MemoryPropertyFlags::DEVICE_LOCAL;
+    properties.memory_types[1] = vk::MemoryType {
+        property_flags: vk::MemoryPropertyFlags::HOST_VISIBLE
+            | vk::MemoryPropertyFlags::HOST_COHERENT
+            | vk::MemoryPropertyFlags::HOST_CACHED,
+        heap_index: 1,
+    };
+    properties.memory_types[2].property_flags = vk::MemoryPropertyFlags::DEVICE_LOCAL
+        | vk::MemoryPropertyFlags::HOST_VISIBLE
+        | vk::MemoryPropertyFlags::HOST_COHERENT;
+    properties.memory_heaps[0].size = 8 << 30;
+    properties.memory_heaps[1].size = 8 << 30;
+    properties
+}
+
+fn allocator(buffer_image_granularity: u64) -> Allocator {
+    let device = common::load_device(get_device_proc_addr);
+    let limits = vk::PhysicalDeviceLimits {
+        buffer_image_granularity,
+        ..Default::default()
+    };
+    Allocator::new(device, memory_properties(), &limits).block_size(BLOCK_SIZE)
+}
+
+fn buffer_info(size: u64) -> vk::BufferCreateInfo {
+    vk::BufferCreateInfo {
+        size,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn memory_types_are_picked_by_usage() {
+    let properties = memory_properties();
+    let find = |bits, usage| find_memory_type_index(&properties, bits, usage);
+    assert_eq!(find(0b111, MemoryUsage::GpuOnly), Some(0));
+    assert_eq!(find(0b111, MemoryUsage::CpuToGpu), Some(2));
+    assert_eq!(find(0b111, MemoryUsage::GpuToCpu), Some(1));
+    assert_eq!(find(0b111, MemoryUsage::CpuOnly), Some(1));
+    assert_eq!(find(0b110, MemoryUsage::GpuOnly), Some(2));
+    assert_eq!(find(0b001, MemoryUsage::CpuToGpu), None);
+}
+
+#[test]
+fn buddy_blocks_are_shared_and_merged() {
+    let allocator = allocator(1);
+    let info = AllocationCreateInfo::default();
+    unsafe {
+        let (a, a_memory) = allocator.create_buffer(&buffer_info(300), &info).unwrap();
+        let (b, b_memory) = allocator.create_buffer(&buffer_info(300), &info).unwrap();
+        assert_eq!(a_memory.memory(), b_memory.memory());
+        assert_eq!(a_memory.memory_type_index(), 0);
+        assert!(a_memory.mapped_ptr().is_none());
+        // Rounded up to 512 bytes
+        assert_eq!((a_memory.offset(), b_memory.offset()), (0, 512));
+        assert_eq!(allocator.device_memory_count(), 1);
+        allocator.destroy_buffer(a, a_memory);
+        allocator.destroy_buffer(b, b_memory);
+
+        // Both halves of the block are free again
+        let (a, a_memory) = allocator
+            .create_buffer(&buffer_info(BLOCK_SIZE / 2), &info)
+            .unwrap();
+        let (b, b_memory) = allocator
+            .create_buffer(&buffer_info(BLOCK_SIZE / 2), &info)
+            .unwrap();
+        assert!(!a_memory.is_dedicated());
+        assert_eq!(a_memory.memory(), b_memory.memory());
+        assert_eq!(allocator.device_memory_count(), 1);
+
+        // A second block is allocated when the first one is full
+        let (c, c_memory) = allocator.create_buffer(&buffer_info(256), &info).unwrap();
+        assert_ne!(c_memory.memory(), a_memory.memory());
+        assert_eq!(allocator.device_memory_count(), 2);
+        // Empty blocks are freed, except for the last block of a pool
+        allocator.destroy_buffer(c, c_memory);
+        assert_eq!(allocator.device_memory_count(), 1);
+        allocator.destroy_buffer(a, a_memory);
+        allocator.destroy_buffer(b, b_memory);
+        assert_eq!(allocator.device_memory_count(), 1);
+    }
+}
+
+#[test]
+fn linear_blocks_are_reset_when_empty() {
+    let allocator = allocator(1);
+    let info = AllocationCreateInfo {
+        strategy: Strategy::Linear,
+        ..Default::default()
+    };
+    unsafe {
+        let (a, a_memory) = allocator.create_buffer(&buffer_info(300), &info).unwrap();
+        let (b, b_memory) = allocator.create_buffer(&buffer_info(300), &info).unwrap();
+        assert_eq!((a_memory.offset(), b_memory.offset()), (0, 512));
+        allocator.destroy_buffer(a, a_memory);
+        let (c, c_memory) = allocator.create_buffer(&buffer_info(300), &info).unwrap();
+        assert_eq!(c_memory.offset(), 1024);
+        allocator.destroy_buffer(b, b_memory);
+        allocator.destroy_buffer(c, c_memory);
+        let (d, d_memory) = allocator.create_buffer(&buffer_info(300), &info).unwrap();
+        assert_eq!(d_memory.offset(), 0);
+        allocator.destroy_buffer(d, d_memory);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Allocation does not belong to this allocator")]
+fn allocations_of_other_allocators_are_rejected() {
+    let info = AllocationCreateInfo {
+        strategy: Strategy::Linear,
+        ..Default::default()
+    };
+    let (a, b) = (allocator(1), allocator(1));
+    unsafe {
+        let (_, a_memory) = a.create_buffer(&buffer_info(300), &info).unwrap();
+        // Both allocators have a first block in the same pool
+        let (_, _b_memory) = b.create_buffer(&buffer_info(300), &info).unwrap();
+        b.free(a_memory);
+    }
+}
+
+#[test]
+fn buffers_and_optimal_images_are_separated_by_granularity() {
+    let image_info = vk::ImageCreateInfo {
+        tiling: vk::ImageTiling::OPTIMAL,
+        ..Default::default()
+    };
+    let info = AllocationCreateInfo::default();
+    for &(granularity, shared) in &[(1024, false), (1, true)] {
+        let allocator = allocator(granularity);
+        unsafe {
+            let (buffer, buffer_memory) =
+                allocator.create_buffer(&buffer_info(256), &info).unwrap();
+            let (image, image_memory) = allocator.create_image(&image_info, &info).unwrap();
+            assert_eq!(buffer_memory.memory() == image_memory.memory(), shared);
+            assert_eq!(image_memory.offset() % 4096, 0);
+            allocator.destroy_buffer(buffer, buffer_memory);
+            allocator.destroy_image(image, image_memory);
+        }
+    }
+}
+
+#[test]
+fn host_visible_allocations_are_mapped() {
+    let allocator = allocator(1);
+    let info = AllocationCreateInfo {
+        usage: MemoryUsage::CpuToGpu,
+        ..Default::default()
+    };
+    unsafe {
+        let (a, a_memory) = allocator.create_buffer(&buffer_info(256), &info).unwrap();
+        let (b, mut b_memory) = allocator.create_buffer(&buffer_info(256), &info).unwrap();
+        assert_eq!(b_memory.memory_type_index(), 2);
+        let base = b_memory.memory().as_raw() as *mut u8;
+        assert_eq!(a_memory.mapped_ptr(), Some(base as *mut c_void));
+        b_memory.mapped_slice_mut().unwrap()[0] = 42;
+        assert_eq!(*base.offset(256), 42);
+        allocator.destroy_buffer(a, a_memory);
+        allocator.destroy_buffer(b, b_memory);
+    }
+}
+
+#[test]
+fn large_allocations_are_dedicated_and_fall_back_to_other_types() {
+    let allocator = allocator(1);
+    let info = AllocationCreateInfo::default();
+    unsafe {
+        let (buffer, memory) = allocator
+            .create_buffer(&buffer_info(4 * BLOCK_SIZE), &info)
+            .unwrap();
+        assert!(memory.is_dedicated());
+        assert_eq!(memory.offset(), 0);
+        // The device local type is out of memory
+        assert_eq!(memory.memory_type_index(), 2);
+        assert_eq!(allocator.device_memory_count(), 1);
+        allocator.destroy_buffer(buffer, memory);
+        assert_eq!(allocator.device_memory_count(), 0);
+    }
+}
@@ -0,0 +1,505 @@
+extern crate ash;
+mod common;
+use ash::capture::{CaptureSink, Replayer};
+use ash::trace;
+use ash::version::{DeviceV1_0, InstanceV1_0};
+use ash::vk::{self, Handle};
+use ash::Device;
+use common::{dispatchable, INSTANCE};
+use std::cell::Cell;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+// The physical devices of the capture and the replay have different handles, but use the
+// instance's dispatch table
+static CAPTURED_DEVICE: usize = 1;
+static REPLAYED_DEVICE: usize = 1;
+static FORMAT_PROPERTIES_DEVICE: AtomicUsize = AtomicUsize::new(0);
+
+unsafe fn enumerate(
+    physical_device: vk::PhysicalDevice,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    if !p_physical_devices.is_null() {
+        *p_physical_devices = physical_device;
+    }
+    *p_physical_device_count = 1;
+    vk::Result::SUCCESS
+}
+
+extern "system" fn enumerate_captured(
+    _instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    unsafe {
+        enumerate(
+            dispatchable(&CAPTURED_DEVICE),
+            p_physical_device_count,
+            p_physical_devices,
+        )
+    }
+}
+
+extern "system" fn enumerate_replayed(
+    _instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    unsafe {
+        enumerate(
+            dispatchable(&REPLAYED_DEVICE),
+            p_physical_device_count,
+            p_physical_devices,
+        )
+    }
+}
+
+extern "system" fn get_format_properties(
+    physical_device: vk::PhysicalDevice,
+    _format: vk::Format,
+    p_format_properties: *mut vk::FormatProperties,
+) {
+    FORMAT_PROPERTIES_DEVICE.store(physical_device.as_raw() as usize, Ordering::SeqCst);
+    unsafe {
+        (*p_format_properties).optimal_tiling_features = vk::FormatFeatureFlags::SAMPLED_IMAGE;
+    }
+}
+
+fn proc_addr(
+    p_name: *const c_char,
+    enumerate: vk::PFN_vkEnumeratePhysicalDevices,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkEnumeratePhysicalDevices" => enumerate as *const c_void,
+            b"vkGetPhysicalDeviceFormatProperties" => get_format_properties as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+extern "system" fn get_captured_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    proc_addr(p_name, enumerate_captured)
+}
+
+extern "system" fn get_replayed_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    proc_addr(p_name, enumerate_replayed)
+}
+
+#[test]
+fn replay_maps_the_handles_of_the_capture() {
+    let path = std::env::temp_dir().join("ash-capture-test.capture");
+    let capture = CaptureSink::new(File::create(&path).unwrap()).unwrap();
+    let captured_instance = dispatchable(&INSTANCE);
+    let static_fn = vk::StaticFn {
+        get_instance_proc_addr: get_captured_proc_addr,
+    };
+    unsafe {
+        let instance = trace::trace_instance(&static_fn, captured_instance, capture.clone());
+        let physical_devices = instance.enumerate_physical_devices().unwrap();
+        instance.get_physical_device_format_properties(physical_devices[0], vk::Format::R8_UNORM);
+    }
+    capture.flush().unwrap();
+
+    let mut replayer = Replayer::new(&vk::StaticFn {
+        get_instance_proc_addr: get_replayed_proc_addr,
+    });
+    let replayed_instance = vk::Instance::from_raw(1);
+    replayer.map_handle(captured_instance, replayed_instance);
+    let stats = unsafe { replayer.replay(&fs::read(&path).unwrap()) }.unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(stats.calls, 3);
+    assert_eq!(stats.skipped, 0);
+    assert_eq!(stats.result_mismatches, 0);
+    let replayed_device: vk::PhysicalDevice = dispatchable(&REPLAYED_DEVICE);
+    assert_eq!(
+        replayer.handle(dispatchable(&CAPTURED_DEVICE)),
+        Some(replayed_device)
+    );
+    assert_eq!(
+        FORMAT_PROPERTIES_DEVICE.load(Ordering::SeqCst),
+        replayed_device.as_raw() as usize
+    );
+}
+
+// The dispatch tables of the instance, the device and the queue of a round trip. Every test
+// uses its own, as the traced handles are looked up by them.
+static BUFFER_DISPATCH: [usize; 3] = [10, 11, 11];
+static IMAGE_DISPATCH: [usize; 3] = [20, 21, 21];
+static DESCRIPTOR_DISPATCH: [usize; 3] = [30, 31, 31];
+
+thread_local! {
+    static DISPATCH: Cell<&'static [usize; 3]> = Cell::new(&BUFFER_DISPATCH);
+    static NEXT_HANDLE: Cell<u64> = Cell::new(1);
+}
+
+// Memory type 0 is host coherent, 1 is not
+const COHERENT: u32 = 0;
+const NON_COHERENT: u32 = 1;
+
+#[derive(Debug, PartialEq)]
+struct WriteDescriptor {
+    set: u64,
+    buffer: u64,
+    range: vk::DeviceSize,
+}
+
+extern "system" fn create_device(
+    _: vk::PhysicalDevice,
+    _: *const vk::DeviceCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    unsafe {
+        *p_device = DISPATCH.with(|dispatch| dispatchable(&dispatch.get()[1]));
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_device_queue(
+    _: vk::Device,
+    _: u32,
+    _: u32,
+    p_queue: *mut vk::Queue,
+) -> c_void {
+    unsafe {
+        *p_queue = DISPATCH.with(|dispatch| dispatchable(&dispatch.get()[2]));
+        mem::zeroed()
+    }
+}
+
+extern "system" fn get_memory_properties(
+    _: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties,
+) -> c_void {
+    unsafe {
+        let properties = &mut *p_memory_properties;
+        properties.memory_type_count = 2;
+        properties.memory_types[COHERENT as usize].property_flags =
+            vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT;
+        properties.memory_types[NON_COHERENT as usize].property_flags =
+            vk::MemoryPropertyFlags::HOST_VISIBLE;
+        mem::zeroed()
+    }
+}
+
+// Creates any non-dispatchable handle, a different one every time
+extern "system" fn create_handle(
+    _: vk::Device,
+    _: *const c_void,
+    _: *const vk::AllocationCallbacks,
+    p_handle: *mut u64,
+) -> vk::Result {
+    unsafe {
+        *p_handle = NEXT_HANDLE.with(|next| next.replace(next.get() + 1));
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn allocate_descriptor_sets(
+    _: vk::Device,
+    p_allocate_info: *const vk::DescriptorSetAllocateInfo,
+    p_descriptor_sets: *mut vk::DescriptorSet,
+) -> vk::Result {
+    unsafe {
+        for i in 0..(*p_allocate_info).descriptor_set_count as usize {
+            create_handle(
+                vk::Device::null(),
+                ptr::null(),
+                ptr::null(),
+                p_descriptor_sets.add(i) as *mut u64,
+            );
+        }
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn update_descriptor_sets(
+    _: vk::Device,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet,
+    _: u32,
+    _: *const vk::CopyDescriptorSet,
+) -> c_void {
+    unsafe {
+        for write in slice::from_raw_parts(p_descriptor_writes, descriptor_write_count as usize) {
+            let info = &*write.p_buffer_info;
+            common::record(WriteDescriptor {
+                set: write.dst_set.as_raw(),
+                buffer: info.buffer.as_raw(),
+                range: info.range,
+            });
+        }
+        mem::zeroed()
+    }
+}
+
+extern "system" fn succeed(_: vk::Queue) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_round_trip_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    get_round_trip_proc_addr(vk::Instance::null(), p_name)
+}
+
+// Device commands are loaded from the instance during the replay
+extern "system" fn get_round_trip_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkGetDeviceProcAddr" => get_round_trip_device_proc_addr as *const c_void,
+            b"vkGetPhysicalDeviceMemoryProperties" => get_memory_properties as *const c_void,
+            b"vkCreateDevice" => create_device as *const c_void,
+            b"vkGetDeviceQueue" => get_device_queue as *const c_void,
+            b"vkQueueSubmit" => succeed as *const c_void,
+            b"vkCreateBuffer"
+            | b"vkCreateImage"
+            | b"vkCreateDescriptorSetLayout"
+            | b"vkCreateDescriptorPool" => create_handle as *const c_void,
+            b"vkAllocateDescriptorSets" => allocate_descriptor_sets as *const c_void,
+            b"vkUpdateDescriptorSets" => update_descriptor_sets as *const c_void,
+            b"vkAllocateMemory" => common::allocate_memory as *const c_void,
+            b"vkMapMemory" => common::map_memory as *const c_void,
+            b"vkFlushMappedMemoryRanges" => succeed as *const c_void,
+            b"vkBindBufferMemory" | b"vkBindImageMemory" => common::bind_memory as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Captures the calls `record` makes on a traced device, and replays them.
+fn round_trip<F>(dispatch: &'static [usize; 3], record: F) -> Replayer
+where
+    F: FnOnce(&Device),
+{
+    DISPATCH.with(|cell| cell.set(dispatch));
+    let static_fn = vk::StaticFn {
+        get_instance_proc_addr: get_round_trip_proc_addr,
+    };
+    let instance: vk::Instance = dispatchable(&dispatch[0]);
+    let physical_device: vk::PhysicalDevice = dispatchable(&dispatch[0]);
+    let capture = SharedBuffer::default();
+    let sink = CaptureSink::new(capture.clone()).unwrap();
+    unsafe {
+        let traced_instance = trace::trace_instance(&static_fn, instance, sink.clone());
+        traced_instance.get_physical_device_memory_properties(physical_device);
+        let device = traced_instance
+            .create_device(physical_device, &Default::default(), None)
+            .unwrap();
+        record(&device);
+    }
+    sink.flush().unwrap();
+
+    let mut replayer = Replayer::new(&static_fn);
+    replayer.map_handle(instance, instance);
+    replayer.map_handle(physical_device, physical_device);
+    let capture = capture.0.lock().unwrap().clone();
+    let stats = unsafe { replayer.replay(&capture) }.unwrap();
+    assert_eq!(stats.skipped, 0);
+    assert_eq!(stats.result_mismatches, 0);
+    replayer
+}
+
+/// Allocates and maps memory of `memory_type_index`.
+unsafe fn mapped_memory(device: &Device, memory_type_index: u32) -> (vk::DeviceMemory, *mut u8) {
+    let allocate_info = vk::MemoryAllocateInfo {
+        allocation_size: 256,
+        memory_type_index,
+        ..Default::default()
+    };
+    let memory = device.allocate_memory(&allocate_info, None).unwrap();
+    let data = device
+        .map_memory(memory, 0, vk::WHOLE_SIZE, vk::MemoryMapFlags::empty())
+        .unwrap();
+    (memory, data as *mut u8)
+}
+
+unsafe fn submit(device: &Device) {
+    let queue = device.get_device_queue(0, 0);
+    device.queue_submit(queue, &[], vk::Fence::null()).unwrap();
+}
+
+/// The memory the replay mapped last, on this thread.
+fn replayed_memory() -> Vec<u8> {
+    unsafe { slice::from_raw_parts(common::mapped(), 256).to_vec() }
+}
+
+#[test]
+fn buffer_writes_are_replayed() {
+    let mut captured = (vk::Buffer::null(), Vec::new());
+    let replayer = round_trip(&BUFFER_DISPATCH, |device| unsafe {
+        let create_info = vk::BufferCreateInfo {
+            size: 256,
+            usage: vk::BufferUsageFlags::UNIFORM_BUFFER,
+            ..Default::default()
+        };
+        let buffer = device.create_buffer(&create_info, None).unwrap();
+        let (memory, data) = mapped_memory(device, COHERENT);
+        device.bind_buffer_memory(buffer, memory, 0).unwrap();
+        // Host coherent memory is captured without being flushed
+        ptr::copy_nonoverlapping([1u8, 2, 3, 4].as_ptr(), data.add(16), 4);
+        submit(device);
+        ptr::copy_nonoverlapping([5u8, 6].as_ptr(), data.add(200), 2);
+        *data.add(17) = 7;
+        submit(device);
+        captured = (buffer, slice::from_raw_parts(data, 256).to_vec());
+    });
+
+    let (buffer, memory) = captured;
+    let replayed_buffer = replayer.handle(buffer).unwrap();
+    assert_ne!(replayed_buffer, buffer);
+    assert_eq!(replayed_memory(), memory);
+}
+
+#[test]
+fn only_flushed_image_writes_are_replayed() {
+    let mut captured = (vk::Image::null(), Vec::new());
+    let replayer = round_trip(&IMAGE_DISPATCH, |device| unsafe {
+        let create_info = vk::ImageCreateInfo {
+            image_type: vk::ImageType::TYPE_2D,
+            format: vk::Format::R8G8B8A8_UNORM,
+            extent: vk::Extent3D {
+                width: 8,
+                height: 8,
+                depth: 1,
+            },
+            mip_levels: 1,
+            array_layers: 1,
+            samples: vk::SampleCountFlags::TYPE_1,
+            tiling: vk::ImageTiling::LINEAR,
+            usage: vk::ImageUsageFlags::SAMPLED,
+            ..Default::default()
+        };
+        let image = device.create_image(&create_info, None).unwrap();
+        let (memory, data) = mapped_memory(device, NON_COHERENT);
+        device.bind_image_memory(image, memory, 0).unwrap();
+        ptr::copy_nonoverlapping([1u8, 2, 3, 4].as_ptr(), data, 4);
+        ptr::copy_nonoverlapping([5u8, 6, 7, 8].as_ptr(), data.add(64), 4);
+        let range = vk::MappedMemoryRange {
+            memory,
+            offset: 64,
+            size: 64,
+            ..Default::default()
+        };
+        device.flush_mapped_memory_ranges(&[range]).unwrap();
+        submit(device);
+        captured = (image, slice::from_raw_parts(data, 256).to_vec());
+    });
+
+    let (image, memory) = captured;
+    assert_ne!(replayer.handle(image).unwrap(), image);
+    let replayed = replayed_memory();
+    assert_eq!(replayed[64..128], memory[64..128]);
+    // Writes to memory that is not host coherent are only visible to the device once flushed
+    assert_eq!(memory[..4], [1, 2, 3, 4]);
+    assert_eq!(replayed[..4], [0, 0, 0, 0]);
+}
+
+#[test]
+fn descriptor_updates_use_the_replayed_handles() {
+    let mut captured = (vk::DescriptorSet::null(), vk::Buffer::null());
+    let replayer = round_trip(&DESCRIPTOR_DISPATCH, |device| unsafe {
+        let create_info = vk::BufferCreateInfo {
+            size: 256,
+            usage: vk::BufferUsageFlags::UNIFORM_BUFFER,
+            ..Default::default()
+        };
+        let buffer = device.create_buffer(&create_info, None).unwrap();
+        let bindings = [vk::DescriptorSetLayoutBinding {
+            binding: 0,
+            descriptor_type: vk::DescriptorType::UNIFORM_BUFFER,
+            descriptor_count: 1,
+            stage_flags: vk::ShaderStageFlags::VERTEX,
+            ..Default::default()
+        }];
+        let layout = device
+            .create_descriptor_set_layout(
+                &vk::DescriptorSetLayoutCreateInfo::builder().bindings(&bindings),
+                None,
+            )
+            .unwrap();
+        let pool_sizes = [vk::DescriptorPoolSize {
+            ty: vk::DescriptorType::UNIFORM_BUFFER,
+            descriptor_count: 1,
+        }];
+        let pool = device
+            .create_descriptor_pool(
+                &vk::DescriptorPoolCreateInfo::builder()
+                    .max_sets(1)
+                    .pool_sizes(&pool_sizes),
+                None,
+            )
+            .unwrap();
+        let set = device
+            .allocate_descriptor_sets(
+                &vk::DescriptorSetAllocateInfo::builder()
+                    .descriptor_pool(pool)
+                    .set_layouts(&[layout]),
+            )
+            .unwrap()[0];
+        let buffer_info = [vk::DescriptorBufferInfo {
+            buffer,
+            offset: 0,
+            range: 64,
+        }];
+        let write = vk::WriteDescriptorSet::builder()
+            .dst_set(set)
+            .descriptor_type(vk::DescriptorType::UNIFORM_BUFFER)
+            .buffer_info(&buffer_info)
+            .build();
+        device.update_descriptor_sets(&[write], &[]);
+        captured = (set, buffer);
+    });
+
+    let (set, buffer) = captured;
+    let replayed_set = replayer.handle(set).unwrap();
+    let replayed_buffer = replayer.handle(buffer).unwrap();
+    assert_ne!(replayed_set, set);
+    let replayed_write = WriteDescriptor {
+        set: replayed_set.as_raw(),
+        buffer: replayed_buffer.as_raw(),
+        range: 64,
+    };
+    let captured_write = WriteDescriptor {
+        set: set.as_raw(),
+        buffer: buffer.as_raw(),
+        range: 64,
+    };
+    assert_eq!(
+        common::take_calls::<WriteDescriptor>(),
+        [captured_write, replayed_write]
+    );
+}
@@ -0,0 +1,200 @@
+extern crate ash;
+mod common;
+use ash::command_buffer::CommandBuffer;
+use ash::vk::{self, Handle};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    Begin(vk::CommandBufferUsageFlags),
+    End,
+    Reset,
+    BeginRenderPass(vk::SubpassContents),
+    NextSubpass(vk::SubpassContents),
+    EndRenderPass,
+    Draw(u32),
+    Dispatch,
+    ExecuteCommands(u32),
+}
+
+extern "system" fn begin_command_buffer(
+    _: vk::CommandBuffer,
+    p_begin_info: *const vk::CommandBufferBeginInfo,
+) -> vk::Result {
+    common::record(Call::Begin(unsafe { (*p_begin_info).flags }));
+    vk::Result::SUCCESS
+}
+
+extern "system" fn end_command_buffer(_: vk::CommandBuffer) -> vk::Result {
+    common::record(Call::End);
+    vk::Result::SUCCESS
+}
+
+extern "system" fn reset_command_buffer(
+    _: vk::CommandBuffer,
+    _: vk::CommandBufferResetFlags,
+) -> vk::Result {
+    common::record(Call::Reset);
+    vk::Result::SUCCESS
+}
+
+extern "system" fn cmd_begin_render_pass(
+    _: vk::CommandBuffer,
+    _: *const vk::RenderPassBeginInfo,
+    contents: vk::SubpassContents,
+) -> c_void {
+    common::record(Call::BeginRenderPass(contents));
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn cmd_next_subpass(_: vk::CommandBuffer, contents: vk::SubpassContents) -> c_void {
+    common::record(Call::NextSubpass(contents));
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn cmd_end_render_pass(_: vk::CommandBuffer) -> c_void {
+    common::record(Call::EndRenderPass);
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn cmd_draw(
+    _: vk::CommandBuffer,
+    vertex_count: u32,
+    _: u32,
+    _: u32,
+    _: u32,
+) -> c_void {
+    common::record(Call::Draw(vertex_count));
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn cmd_dispatch(_: vk::CommandBuffer, _: u32, _: u32, _: u32) -> c_void {
+    common::record(Call::Dispatch);
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn cmd_execute_commands(
+    _: vk::CommandBuffer,
+    command_buffer_count: u32,
+    _: *const vk::CommandBuffer,
+) -> c_void {
+    common::record(Call::ExecuteCommands(command_buffer_count));
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkBeginCommandBuffer" => begin_command_buffer as *const c_void,
+            b"vkEndCommandBuffer" => end_command_buffer as *const c_void,
+            b"vkResetCommandBuffer" => reset_command_buffer as *const c_void,
+            b"vkCmdBeginRenderPass" => cmd_begin_render_pass as *const c_void,
+            b"vkCmdNextSubpass" => cmd_next_subpass as *const c_void,
+            b"vkCmdEndRenderPass" => cmd_end_render_pass as *const c_void,
+            b"vkCmdDraw" => cmd_draw as *const c_void,
+            b"vkCmdDispatch" => cmd_dispatch as *const c_void,
+            b"vkCmdExecuteCommands" => cmd_execute_commands as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn take_calls() -> Vec<Call> {
+    common::take_calls()
+}
+
+fn render_pass_begin_info() -> vk::RenderPassBeginInfo {
+    vk::RenderPassBeginInfo {
+        render_pass: vk::RenderPass::from_raw(1),
+        framebuffer: vk::Framebuffer::from_raw(1),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn primary_command_buffer_states() {
+    let device = common::load_device(get_device_proc_addr);
+    let secondaries = [common::dispatchable::<vk::CommandBuffer>(
+        &common::COMMAND_BUFFER,
+    )];
+    unsafe {
+        let initial = CommandBuffer::new(&device, common::dispatchable(&common::COMMAND_BUFFER));
+        let recording = initial.begin(&Default::default()).unwrap();
+        recording.cmd_dispatch(1, 1, 1);
+        recording.cmd_execute_commands(&secondaries);
+        let inline = recording.begin_render_pass_inline(&render_pass_begin_info());
+        inline.cmd_draw(3, 1, 0, 0);
+        let secondary = inline.next_subpass_secondary();
+        secondary.cmd_execute_commands(&secondaries);
+        let executable = secondary.end_render_pass().end().unwrap();
+        assert_eq!(executable.handle(), secondaries[0]);
+        assert_eq!(
+            take_calls(),
+            [
+                Call::Begin(vk::CommandBufferUsageFlags::empty()),
+                Call::Dispatch,
+                Call::ExecuteCommands(1),
+                Call::BeginRenderPass(vk::SubpassContents::INLINE),
+                Call::Draw(3),
+                Call::NextSubpass(vk::SubpassContents::SECONDARY_COMMAND_BUFFERS),
+                Call::ExecuteCommands(1),
+                Call::EndRenderPass,
+                Call::End,
+            ]
+        );
+
+        // An executable command buffer can be recorded again, or reset
+        let recording = executable
+            .begin(&vk::CommandBufferBeginInfo {
+                flags: vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT,
+                ..Default::default()
+            })
+            .unwrap();
+        recording
+            .end()
+            .unwrap()
+            .reset(vk::CommandBufferResetFlags::empty())
+            .unwrap();
+        assert_eq!(
+            take_calls(),
+            [
+                Call::Begin(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT),
+                Call::End,
+                Call::Reset,
+            ]
+        );
+    }
+}
+
+#[test]
+fn inherited_render_pass_states() {
+    let device = common::load_device(get_device_proc_addr);
+    let inheritance_info = vk::CommandBufferInheritanceInfo {
+        render_pass: vk::RenderPass::from_raw(1),
+        ..Default::default()
+    };
+    let begin_info = vk::CommandBufferBeginInfo {
+        flags: vk::CommandBufferUsageFlags::RENDER_PASS_CONTINUE,
+        p_inheritance_info: &inheritance_info,
+        ..Default::default()
+    };
+    unsafe {
+        let initial = CommandBuffer::new(&device, common::dispatchable(&common::COMMAND_BUFFER));
+        let inherited = initial.begin_render_pass_continue(&begin_info).unwrap();
+        inherited.cmd_draw(6, 1, 0, 0);
+        inherited.end().unwrap();
+    }
+    assert_eq!(
+        take_calls(),
+        [
+            Call::Begin(vk::CommandBufferUsageFlags::RENDER_PASS_CONTINUE),
+            Call::Draw(6),
+            Call::End,
+        ]
+    );
+}
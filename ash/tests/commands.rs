@@ -0,0 +1,51 @@
+extern crate ash;
+use ash::vk::{self, commands};
+
+#[test]
+fn recorded_commands() {
+    let draw = commands::metadata("vkCmdDraw").unwrap();
+    assert!(draw.is_recorded());
+    assert!(draw.allowed_inside_render_pass());
+    assert!(!draw.allowed_outside_render_pass());
+    assert!(draw.supports_queue(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE));
+    assert!(!draw.supports_queue(vk::QueueFlags::COMPUTE));
+    assert!(draw.allowed_in(vk::CommandBufferLevel::SECONDARY));
+
+    let execute = commands::metadata("vkCmdExecuteCommands").unwrap();
+    assert!(execute.allowed_in(vk::CommandBufferLevel::PRIMARY));
+    assert!(!execute.allowed_in(vk::CommandBufferLevel::SECONDARY));
+
+    let copy = commands::metadata("vkCmdCopyBuffer").unwrap();
+    assert!(!copy.allowed_inside_render_pass());
+    assert!(copy.supports_queue(vk::QueueFlags::TRANSFER));
+}
+
+#[test]
+fn result_codes() {
+    let present = commands::metadata("vkQueuePresentKHR").unwrap();
+    assert!(!present.is_recorded());
+    assert!(present.supports_queue(vk::QueueFlags::TRANSFER));
+    assert!(present.may_return(vk::Result::SUBOPTIMAL_KHR));
+    assert!(present
+        .error_codes
+        .contains(&vk::Result::ERROR_OUT_OF_DATE_KHR));
+    assert!(!present.may_return(vk::Result::TIMEOUT));
+
+    let wait = commands::metadata("vkWaitForFences").unwrap();
+    assert_eq!(
+        wait.success_codes,
+        [vk::Result::SUCCESS, vk::Result::TIMEOUT]
+    );
+    assert!(commands::metadata("vkCmdDraw")
+        .unwrap()
+        .success_codes
+        .is_empty());
+    assert!(commands::metadata("vkDoesNotExist").is_none());
+}
+
+#[test]
+fn metadata_is_sorted() {
+    assert!(commands::METADATA
+        .windows(2)
+        .all(|pair| pair[0].name < pair[1].name));
+}
@@ -0,0 +1,179 @@
+// A fake driver for the tests: every test binary picks the commands it needs and loads a
+// `Device` or `Instance` from them, without a Vulkan implementation.
+#![allow(dead_code)]
+
+use ash::vk::{self, Handle};
+use ash::{Device, Instance};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+// Dispatchable handles start with the loader's dispatch table pointer
+pub static INSTANCE: usize = 1;
+pub static PHYSICAL_DEVICE: usize = 1;
+pub static DEVICE: usize = 1;
+pub static QUEUE: usize = 1;
+pub static COMMAND_BUFFER: usize = 1;
+
+pub fn dispatchable<H: Handle>(dispatch_table: &'static usize) -> H {
+    H::from_raw(dispatch_table as *const usize as u64)
+}
+
+thread_local! {
+    static CALLS: RefCell<Vec<Box<dyn Any>>> = RefCell::new(Vec::new());
+    static MAPPED: Cell<*mut u8> = Cell::new(ptr::null_mut());
+}
+
+/// Records a call of a fake command, to be checked with `take_calls`.
+pub fn record<C: Any>(call: C) {
+    CALLS.with(|calls| calls.borrow_mut().push(Box::new(call)));
+}
+
+/// The calls recorded on this thread since the last `take_calls`, which must all be `C`s.
+pub fn take_calls<C: Any>() -> Vec<C> {
+    CALLS
+        .with(|calls| mem::replace(&mut *calls.borrow_mut(), Vec::new()))
+        .into_iter()
+        .map(|call| {
+            *call
+                .downcast::<C>()
+                .expect("Recorded a call of another type")
+        })
+        .collect()
+}
+
+/// Looks up the fake command called `p_name`; `commands` returns null for the ones that are
+/// not faked.
+pub unsafe fn proc_addr<F>(p_name: *const c_char, commands: F) -> vk::PFN_vkVoidFunction
+where
+    F: FnOnce(&[u8]) -> *const c_void,
+{
+    let f = commands(CStr::from_ptr(p_name).to_bytes());
+    if f.is_null() {
+        None
+    } else {
+        Some(mem::transmute(f))
+    }
+}
+
+pub fn load_device(get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr) -> Device {
+    let instance_fn = vk::InstanceFnV1_0::load(|name| match name.to_bytes() {
+        b"vkGetDeviceProcAddr" => get_device_proc_addr as *const c_void,
+        _ => ptr::null(),
+    });
+    unsafe { Device::load(&instance_fn, dispatchable(&DEVICE)) }
+}
+
+pub fn load_instance(get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr) -> Instance {
+    let static_fn = vk::StaticFn {
+        get_instance_proc_addr,
+    };
+    unsafe { Instance::load(&static_fn, dispatchable(&INSTANCE)) }
+}
+
+// Creates non-dispatchable handles, which are all 1
+pub extern "system" fn create_handle(
+    _: vk::Device,
+    _: *const c_void,
+    _: *const vk::AllocationCallbacks,
+    p_handle: *mut u64,
+) -> vk::Result {
+    unsafe {
+        *p_handle = 1;
+    }
+    vk::Result::SUCCESS
+}
+
+pub extern "system" fn destroy_handle(_: vk::Device, _: u64, _: *const c_void) -> c_void {
+    unsafe { mem::zeroed() }
+}
+
+// The memory handle is the address of a leaked `Vec<u8>`
+pub extern "system" fn allocate_memory(
+    _: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    unsafe {
+        let data = Box::new(vec![0u8; (*p_allocate_info).allocation_size as usize]);
+        *p_memory = vk::DeviceMemory::from_raw(Box::into_raw(data) as u64);
+    }
+    vk::Result::SUCCESS
+}
+
+pub extern "system" fn free_memory(
+    _: vk::Device,
+    memory: vk::DeviceMemory,
+    _: *const vk::AllocationCallbacks,
+) -> c_void {
+    unsafe {
+        drop(Box::from_raw(memory.as_raw() as *mut Vec<u8>));
+        mem::zeroed()
+    }
+}
+
+/// The start of the memory that was mapped last on this thread.
+pub fn mapped() -> *mut u8 {
+    MAPPED.with(|mapped| mapped.get())
+}
+
+pub extern "system" fn map_memory(
+    _: vk::Device,
+    memory: vk::DeviceMemory,
+    _: vk::DeviceSize,
+    _: vk::DeviceSize,
+    _: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    unsafe {
+        let data = &mut *(memory.as_raw() as *mut Vec<u8>);
+        MAPPED.with(|mapped| mapped.set(data.as_mut_ptr()));
+        *pp_data = data.as_mut_ptr() as *mut c_void;
+    }
+    vk::Result::SUCCESS
+}
+
+// Binds buffers and images
+pub extern "system" fn bind_memory(
+    _: vk::Device,
+    _: u64,
+    _: vk::DeviceMemory,
+    _: vk::DeviceSize,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+pub extern "system" fn allocate_command_buffers(
+    _: vk::Device,
+    _: *const vk::CommandBufferAllocateInfo,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    unsafe {
+        *p_command_buffers = dispatchable(&COMMAND_BUFFER);
+    }
+    vk::Result::SUCCESS
+}
+
+pub extern "system" fn free_command_buffers(
+    _: vk::Device,
+    _: vk::CommandPool,
+    _: u32,
+    _: *const vk::CommandBuffer,
+) -> c_void {
+    unsafe { mem::zeroed() }
+}
+
+pub extern "system" fn begin_command_buffer(
+    _: vk::CommandBuffer,
+    _: *const vk::CommandBufferBeginInfo,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+pub extern "system" fn end_command_buffer(_: vk::CommandBuffer) -> vk::Result {
+    vk::Result::SUCCESS
+}
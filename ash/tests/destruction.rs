@@ -0,0 +1,186 @@
+extern crate ash;
+mod common;
+use ash::destruction::{DestroyAfter, DestructionQueue};
+use ash::owned::Owned;
+use ash::vk::{self, Handle};
+use std::cell::RefCell;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    FenceStatus(u64),
+    WaitIdle,
+    DestroyBuffer(u64),
+    DestroySwapchain(u64),
+}
+
+thread_local! {
+    static SIGNALED: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+fn signal(fence: u64) {
+    SIGNALED.with(|signaled| signaled.borrow_mut().push(fence));
+}
+
+extern "system" fn get_fence_status(_: vk::Device, fence: vk::Fence) -> vk::Result {
+    common::record(Call::FenceStatus(fence.as_raw()));
+    if SIGNALED.with(|signaled| signaled.borrow().contains(&fence.as_raw())) {
+        vk::Result::SUCCESS
+    } else {
+        vk::Result::NOT_READY
+    }
+}
+
+extern "system" fn device_wait_idle(_: vk::Device) -> vk::Result {
+    common::record(Call::WaitIdle);
+    vk::Result::SUCCESS
+}
+
+extern "system" fn destroy_buffer(
+    _: vk::Device,
+    buffer: vk::Buffer,
+    _: *const vk::AllocationCallbacks,
+) -> c_void {
+    common::record(Call::DestroyBuffer(buffer.as_raw()));
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkGetFenceStatus" => get_fence_status as *const c_void,
+            b"vkDeviceWaitIdle" => device_wait_idle as *const c_void,
+            b"vkDestroyBuffer" => destroy_buffer as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn queue() -> DestructionQueue {
+    DestructionQueue::new(Arc::new(common::load_device(get_device_proc_addr)))
+}
+
+fn push_buffer(queue: &mut DestructionQueue, raw: u64, after: DestroyAfter) {
+    unsafe { queue.push(vk::Buffer::from_raw(raw), after, None) }
+}
+
+fn take_calls() -> Vec<Call> {
+    common::take_calls()
+}
+
+#[test]
+fn fence_gated_destruction_keeps_the_push_order() {
+    let mut queue = queue();
+    push_buffer(&mut queue, 1, DestroyAfter::Fence(vk::Fence::from_raw(10)));
+    push_buffer(&mut queue, 2, DestroyAfter::Fence(vk::Fence::from_raw(20)));
+    push_buffer(&mut queue, 3, DestroyAfter::Fence(vk::Fence::from_raw(10)));
+    push_buffer(&mut queue, 4, DestroyAfter::Frame(0));
+
+    assert_eq!(unsafe { queue.collect() }, Ok(0));
+    // Every fence is only checked once
+    assert_eq!(take_calls(), [Call::FenceStatus(10), Call::FenceStatus(20)]);
+
+    signal(10);
+    assert_eq!(unsafe { queue.collect() }, Ok(2));
+    assert_eq!(
+        take_calls(),
+        [
+            Call::FenceStatus(10),
+            Call::FenceStatus(20),
+            Call::DestroyBuffer(1),
+            Call::DestroyBuffer(3),
+        ]
+    );
+    assert_eq!(queue.len(), 2);
+
+    signal(20);
+    assert_eq!(unsafe { queue.collect() }, Ok(1));
+    assert_eq!(
+        take_calls(),
+        [Call::FenceStatus(20), Call::DestroyBuffer(2)]
+    );
+    // Frame gated handles are not affected by fences
+    assert_eq!(queue.pending_counts(), [(vk::ObjectType::BUFFER, 1)]);
+}
+
+#[test]
+fn frame_gated_destruction_keeps_the_push_order() {
+    let mut queue = queue();
+    push_buffer(&mut queue, 1, DestroyAfter::Frame(2));
+    push_buffer(&mut queue, 2, DestroyAfter::Frame(1));
+    push_buffer(&mut queue, 3, DestroyAfter::Frame(3));
+    push_buffer(&mut queue, 4, DestroyAfter::Frame(1));
+    push_buffer(&mut queue, 5, DestroyAfter::Fence(vk::Fence::from_raw(10)));
+
+    assert_eq!(unsafe { queue.frame_completed(0) }, 0);
+    assert_eq!(unsafe { queue.frame_completed(1) }, 2);
+    assert_eq!(
+        take_calls(),
+        [Call::DestroyBuffer(2), Call::DestroyBuffer(4)]
+    );
+    // Completing a later frame also completes the skipped ones
+    assert_eq!(unsafe { queue.frame_completed(3) }, 2);
+    assert_eq!(
+        take_calls(),
+        [Call::DestroyBuffer(1), Call::DestroyBuffer(3)]
+    );
+    assert_eq!(queue.len(), 1);
+}
+
+#[test]
+fn any_handle_can_be_destroyed() {
+    let mut queue = queue();
+    unsafe {
+        queue.push_with(
+            vk::SwapchainKHR::from_raw(1),
+            DestroyAfter::Frame(0),
+            None,
+            |_, swapchain, _| common::record(Call::DestroySwapchain(swapchain.as_raw())),
+        );
+    }
+    push_buffer(&mut queue, 2, DestroyAfter::Frame(0));
+    assert_eq!(
+        queue.pending_counts(),
+        [
+            (vk::ObjectType::SWAPCHAIN_KHR, 1),
+            (vk::ObjectType::BUFFER, 1)
+        ]
+    );
+    assert_eq!(unsafe { queue.frame_completed(0) }, 2);
+    assert_eq!(
+        take_calls(),
+        [Call::DestroySwapchain(1), Call::DestroyBuffer(2)]
+    );
+}
+
+#[test]
+fn flushing_waits_for_the_device() {
+    let mut queue = queue();
+    assert_eq!(unsafe { queue.flush_all() }, Ok(()));
+    // Nothing to destroy, so there is no need to wait
+    assert!(take_calls().is_empty());
+
+    let owned = unsafe { Owned::from_raw(queue.device().clone(), vk::Buffer::from_raw(1), None) };
+    queue.push_owned(owned, DestroyAfter::Fence(vk::Fence::from_raw(10)));
+    push_buffer(&mut queue, 2, DestroyAfter::Frame(5));
+    assert_eq!(unsafe { queue.flush_all() }, Ok(()));
+    assert_eq!(
+        take_calls(),
+        [
+            Call::WaitIdle,
+            Call::DestroyBuffer(1),
+            Call::DestroyBuffer(2)
+        ]
+    );
+    assert!(queue.is_empty());
+
+    push_buffer(&mut queue, 3, DestroyAfter::Frame(5));
+    drop(queue);
+    assert_eq!(take_calls(), [Call::WaitIdle, Call::DestroyBuffer(3)]);
+}
@@ -0,0 +1,146 @@
+extern crate ash;
+mod common;
+use ash::encoder::{BindingCache, CachingEncoder};
+use ash::vk::{self, Handle};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+#[derive(Debug, PartialEq)]
+struct BindDescriptorSets(vk::PipelineBindPoint, u64);
+
+extern "system" fn cmd_bind_descriptor_sets(
+    _: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    _: vk::PipelineLayout,
+    _: u32,
+    _: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+    _: u32,
+    _: *const u32,
+) -> c_void {
+    unsafe {
+        common::record(BindDescriptorSets(
+            pipeline_bind_point,
+            (*p_descriptor_sets).as_raw(),
+        ));
+        mem::zeroed()
+    }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkCmdBindDescriptorSets" => cmd_bind_descriptor_sets as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+#[test]
+fn binding_cache_skips_unchanged_state() {
+    let mut cache = BindingCache::new();
+    let graphics = vk::PipelineBindPoint::GRAPHICS;
+    let pipeline = vk::Pipeline::from_raw(1);
+    assert!(cache.bind_pipeline(graphics, pipeline, &[]));
+    assert!(!cache.bind_pipeline(graphics, pipeline, &[]));
+    assert!(cache.bind_pipeline(vk::PipelineBindPoint::COMPUTE, pipeline, &[]));
+
+    let buffers = [
+        vk::Buffer::from_raw(1),
+        vk::Buffer::from_raw(2),
+        vk::Buffer::from_raw(3),
+    ];
+    assert_eq!(
+        cache.bind_vertex_buffers(0, &buffers, &[0, 0, 0]),
+        Some(0..3)
+    );
+    assert_eq!(
+        cache.bind_vertex_buffers(0, &buffers, &[0, 16, 0]),
+        Some(1..2)
+    );
+    assert_eq!(cache.bind_vertex_buffers(1, &buffers[1..], &[16, 0]), None);
+
+    let layout = vk::PipelineLayout::from_raw(1);
+    let sets = [vk::DescriptorSet::from_raw(1)];
+    assert_eq!(
+        cache.bind_descriptor_sets(graphics, layout, 0, &sets, &[]),
+        Some(0..1)
+    );
+    assert_eq!(
+        cache.bind_descriptor_sets(graphics, layout, 0, &sets, &[]),
+        None
+    );
+    assert_eq!(
+        cache.bind_descriptor_sets(graphics, layout, 0, &sets, &[256]),
+        Some(0..1)
+    );
+    let other_layout = vk::PipelineLayout::from_raw(2);
+    assert_eq!(
+        cache.bind_descriptor_sets(graphics, other_layout, 0, &sets, &[256]),
+        Some(0..1)
+    );
+
+    let stages = vk::ShaderStageFlags::VERTEX;
+    assert!(cache.push_constants(layout, stages, 0, &[1, 2, 3, 4]));
+    assert!(!cache.push_constants(layout, stages, 0, &[1, 2, 3, 4]));
+    assert!(cache.push_constants(layout, stages, 2, &[5, 6]));
+    assert!(cache.push_constants(layout, stages, 0, &[1, 2, 3, 4]));
+}
+
+#[test]
+fn binding_a_pipeline_resets_static_viewport_state() {
+    let mut cache = BindingCache::new();
+    let graphics = vk::PipelineBindPoint::GRAPHICS;
+    let viewport = vk::Viewport {
+        x: 0.0,
+        y: 0.0,
+        width: 640.0,
+        height: 480.0,
+        min_depth: 0.0,
+        max_depth: 1.0,
+    };
+    assert_eq!(cache.set_viewports(0, &[viewport]), Some(0..1));
+    assert_eq!(cache.set_viewports(0, &[viewport]), None);
+
+    let dynamic = [vk::DynamicState::VIEWPORT];
+    cache.bind_pipeline(graphics, vk::Pipeline::from_raw(1), &dynamic);
+    assert_eq!(cache.set_viewports(0, &[viewport]), None);
+
+    cache.bind_pipeline(graphics, vk::Pipeline::from_raw(2), &[]);
+    assert_eq!(cache.set_viewports(0, &[viewport]), Some(0..1));
+
+    cache.invalidate();
+    assert_eq!(cache.set_viewports(0, &[viewport]), Some(0..1));
+}
+
+#[test]
+fn bind_points_are_cached_separately() {
+    let device = common::load_device(get_device_proc_addr);
+    let mut encoder = CachingEncoder::new(&device, common::dispatchable(&common::COMMAND_BUFFER));
+    let layout = vk::PipelineLayout::from_raw(1);
+    let sets = [vk::DescriptorSet::from_raw(1)];
+    let bind_points = [
+        vk::PipelineBindPoint::GRAPHICS,
+        vk::PipelineBindPoint::RAY_TRACING_NV,
+        vk::PipelineBindPoint::COMPUTE,
+    ];
+    unsafe {
+        for &bind_point in &bind_points {
+            encoder.cmd_bind_descriptor_sets(bind_point, layout, 0, &sets, &[]);
+            encoder.cmd_bind_descriptor_sets(bind_point, layout, 0, &sets, &[]);
+        }
+    }
+    assert_eq!(
+        common::take_calls::<BindDescriptorSets>(),
+        [
+            BindDescriptorSets(vk::PipelineBindPoint::GRAPHICS, 1),
+            BindDescriptorSets(vk::PipelineBindPoint::RAY_TRACING_NV, 1),
+            BindDescriptorSets(vk::PipelineBindPoint::COMPUTE, 1),
+        ]
+    );
+    assert_eq!(encoder.stats().descriptor_sets.elided, 3);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "typed_errors")]
+extern crate ash;
+mod common;
+use ash::error::{
+    AcquireNextImageError, AllocateDescriptorSetsError, CreateBufferError, TypedErrors,
+};
+use ash::version::DeviceV1_0;
+use ash::vk::{self, Handle};
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+extern "system" fn allocate_descriptor_sets(
+    _: vk::Device,
+    _: *const vk::DescriptorSetAllocateInfo,
+    _: *mut vk::DescriptorSet,
+) -> vk::Result {
+    vk::Result::ERROR_OUT_OF_POOL_MEMORY
+}
+
+extern "system" fn create_buffer(
+    _: vk::Device,
+    _: *const vk::BufferCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    _: *mut vk::Buffer,
+) -> vk::Result {
+    // Not an error code of vkCreateBuffer, but layers may return it anyway
+    vk::Result::ERROR_VALIDATION_FAILED_EXT
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkAllocateDescriptorSets" => allocate_descriptor_sets as *const c_void,
+            b"vkCreateBuffer" => create_buffer as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+#[test]
+fn typed_wrappers_return_the_error_codes_of_the_command() {
+    let device = common::load_device(get_device_proc_addr);
+    let set_layouts = [vk::DescriptorSetLayout::from_raw(1)];
+    let allocate_info = vk::DescriptorSetAllocateInfo {
+        descriptor_pool: vk::DescriptorPool::from_raw(1),
+        descriptor_set_count: 1,
+        p_set_layouts: set_layouts.as_ptr(),
+        ..Default::default()
+    };
+    // The wrappers of the device itself keep returning `vk::Result`
+    let err = unsafe { device.allocate_descriptor_sets(&allocate_info) }.unwrap_err();
+    assert_eq!(err, vk::Result::ERROR_OUT_OF_POOL_MEMORY);
+
+    let err = unsafe { device.typed().allocate_descriptor_sets(&allocate_info) }.unwrap_err();
+    let description = match err {
+        AllocateDescriptorSetsError::OutOfPoolMemory => "out of pool memory",
+        AllocateDescriptorSetsError::FragmentedPool => "fragmented",
+        AllocateDescriptorSetsError::OutOfHostMemory
+        | AllocateDescriptorSetsError::OutOfDeviceMemory => "out of memory",
+        AllocateDescriptorSetsError::Other(_) => "other",
+    };
+    assert_eq!(description, "out of pool memory");
+
+    let err = unsafe { device.typed().create_buffer(&Default::default(), None) }.unwrap_err();
+    assert_eq!(
+        err,
+        CreateBufferError::Other(vk::Result::ERROR_VALIDATION_FAILED_EXT)
+    );
+}
+
+#[test]
+fn errors_convert_into_vk_result() {
+    assert_eq!(
+        AcquireNextImageError::from(vk::Result::ERROR_OUT_OF_DATE_KHR),
+        AcquireNextImageError::OutOfDate
+    );
+    assert_eq!(
+        vk::Result::from(AcquireNextImageError::SurfaceLost),
+        vk::Result::ERROR_SURFACE_LOST_KHR
+    );
+    assert_eq!(
+        AcquireNextImageError::DeviceLost.to_string(),
+        vk::Result::ERROR_DEVICE_LOST.to_string()
+    );
+
+    fn present() -> ash::prelude::VkResult<()> {
+        Err(AcquireNextImageError::OutOfDate)?;
+        Ok(())
+    }
+    assert_eq!(present(), Err(vk::Result::ERROR_OUT_OF_DATE_KHR));
+}
@@ -0,0 +1,182 @@
+extern crate ash;
+mod common;
+use ash::util::FormatTable;
+use ash::vk;
+use ash::Instance;
+use std::cell::Cell;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+thread_local! {
+    static FORMAT_QUERIES: Cell<u32> = Cell::new(0);
+    static IMAGE_FORMAT_QUERIES: Cell<u32> = Cell::new(0);
+}
+
+extern "system" fn get_format_properties(
+    _physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    p_format_properties: *mut vk::FormatProperties,
+) {
+    FORMAT_QUERIES.with(|queries| queries.set(queries.get() + 1));
+    let optimal_tiling_features = match format {
+        vk::Format::D24_UNORM_S8_UINT | vk::Format::D16_UNORM => {
+            vk::FormatFeatureFlags::DEPTH_STENCIL_ATTACHMENT
+        }
+        vk::Format::R8G8B8A8_UNORM => {
+            vk::FormatFeatureFlags::SAMPLED_IMAGE | vk::FormatFeatureFlags::COLOR_ATTACHMENT
+        }
+        _ => vk::FormatFeatureFlags::empty(),
+    };
+    unsafe {
+        *p_format_properties = vk::FormatProperties {
+            linear_tiling_features: vk::FormatFeatureFlags::empty(),
+            optimal_tiling_features,
+            buffer_features: vk::FormatFeatureFlags::VERTEX_BUFFER,
+        };
+    }
+}
+
+extern "system" fn get_image_format_properties(
+    _physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    _typ: vk::ImageType,
+    _tiling: vk::ImageTiling,
+    _usage: vk::ImageUsageFlags,
+    _flags: vk::ImageCreateFlags,
+    p_image_format_properties: *mut vk::ImageFormatProperties,
+) -> vk::Result {
+    IMAGE_FORMAT_QUERIES.with(|queries| queries.set(queries.get() + 1));
+    if format != vk::Format::R8G8B8A8_UNORM {
+        return vk::Result::ERROR_FORMAT_NOT_SUPPORTED;
+    }
+    unsafe {
+        *p_image_format_properties = vk::ImageFormatProperties {
+            max_extent: vk::Extent3D {
+                width: 4096,
+                height: 4096,
+                depth: 1,
+            },
+            max_mip_levels: 13,
+            max_array_layers: 16,
+            sample_counts: vk::SampleCountFlags::TYPE_1 | vk::SampleCountFlags::TYPE_4,
+            max_resource_size: 1 << 31,
+        };
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_instance_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkGetPhysicalDeviceFormatProperties" => get_format_properties as *const c_void,
+            b"vkGetPhysicalDeviceImageFormatProperties" => {
+                get_image_format_properties as *const c_void
+            }
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn format_table() -> FormatTable<Instance> {
+    unsafe {
+        FormatTable::new(
+            common::load_instance(get_instance_proc_addr),
+            common::dispatchable(&common::PHYSICAL_DEVICE),
+        )
+    }
+}
+
+fn image_create_info(format: vk::Format, extent: u32) -> vk::ImageCreateInfo {
+    vk::ImageCreateInfo::builder()
+        .image_type(vk::ImageType::TYPE_2D)
+        .format(format)
+        .extent(vk::Extent3D {
+            width: extent,
+            height: extent,
+            depth: 1,
+        })
+        .mip_levels(1)
+        .array_layers(1)
+        .samples(vk::SampleCountFlags::TYPE_4)
+        .tiling(vk::ImageTiling::OPTIMAL)
+        .usage(vk::ImageUsageFlags::COLOR_ATTACHMENT)
+        .build()
+}
+
+#[test]
+fn finds_the_first_supported_format() {
+    let formats = format_table();
+    assert_eq!(
+        formats.depth_format(false),
+        Some(vk::Format::D24_UNORM_S8_UINT)
+    );
+    assert_eq!(
+        formats.depth_format(true),
+        Some(vk::Format::D24_UNORM_S8_UINT)
+    );
+    assert_eq!(
+        formats.find_supported(
+            &[vk::Format::B8G8R8A8_UNORM, vk::Format::R8G8B8A8_UNORM],
+            vk::ImageTiling::OPTIMAL,
+            vk::FormatFeatureFlags::SAMPLED_IMAGE,
+        ),
+        Some(vk::Format::R8G8B8A8_UNORM)
+    );
+    assert_eq!(
+        formats.find_supported(
+            &[vk::Format::R8G8B8A8_UNORM],
+            vk::ImageTiling::LINEAR,
+            vk::FormatFeatureFlags::SAMPLED_IMAGE,
+        ),
+        None
+    );
+    assert!(formats.supports_buffer(
+        vk::Format::R32G32_SFLOAT,
+        vk::FormatFeatureFlags::VERTEX_BUFFER
+    ));
+}
+
+#[test]
+fn caches_format_properties() {
+    let formats = format_table();
+    FORMAT_QUERIES.with(|queries| queries.set(0));
+    formats.depth_format(false);
+    // D32_SFLOAT, D32_SFLOAT_S8_UINT, D24_UNORM_S8_UINT
+    assert_eq!(FORMAT_QUERIES.with(|queries| queries.get()), 3);
+    formats.depth_format(true);
+    formats.supports(
+        vk::Format::D32_SFLOAT,
+        vk::ImageTiling::LINEAR,
+        vk::FormatFeatureFlags::SAMPLED_IMAGE,
+    );
+    assert_eq!(FORMAT_QUERIES.with(|queries| queries.get()), 3);
+}
+
+#[test]
+fn checks_image_limits() {
+    let formats = format_table();
+    IMAGE_FORMAT_QUERIES.with(|queries| queries.set(0));
+    let create_info = image_create_info(vk::Format::R8G8B8A8_UNORM, 1024);
+    assert_eq!(formats.supports_image(&create_info), Ok(true));
+    let properties = formats.image_format_properties(&create_info).unwrap();
+    assert_eq!(properties.max_mip_levels, 13);
+    assert_eq!(
+        formats.supports_image(&image_create_info(vk::Format::R8G8B8A8_UNORM, 8192)),
+        Ok(false)
+    );
+    let mut create_info = image_create_info(vk::Format::R8G8B8A8_UNORM, 1024);
+    create_info.samples = vk::SampleCountFlags::TYPE_8;
+    assert_eq!(formats.supports_image(&create_info), Ok(false));
+    assert_eq!(IMAGE_FORMAT_QUERIES.with(|queries| queries.get()), 1);
+
+    let unsupported = image_create_info(vk::Format::BC7_UNORM_BLOCK, 1024);
+    assert_eq!(formats.supports_image(&unsupported), Ok(false));
+    assert_eq!(
+        formats.image_format_properties(&unsupported).err(),
+        Some(vk::Result::ERROR_FORMAT_NOT_SUPPORTED)
+    );
+    assert_eq!(IMAGE_FORMAT_QUERIES.with(|queries| queries.get()), 2);
+}
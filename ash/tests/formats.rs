@@ -0,0 +1,102 @@
+extern crate ash;
+use ash::vk;
+use ash::vk::formats::{ComponentName, Compression, NumericType, FORMATS};
+
+#[test]
+fn uncompressed_formats() {
+    let info = vk::Format::R16G16B16A16_SFLOAT.info().unwrap();
+    assert_eq!(info.block_size, 8);
+    assert_eq!(info.block_extent, [1, 1, 1]);
+    assert_eq!(info.numeric_type(), NumericType::Sfloat);
+    assert_eq!(info.components.len(), 4);
+    assert_eq!(info.component(ComponentName::A).unwrap().bits, 16);
+    assert_eq!(info.aspect_mask, vk::ImageAspectFlags::COLOR);
+    assert_eq!(info.compatibility_class, "64-bit");
+
+    let srgb = vk::Format::B8G8R8A8_SRGB.info().unwrap();
+    assert_eq!(srgb.components[0].name, ComponentName::B);
+    assert_eq!(srgb.numeric_type(), NumericType::Srgb);
+    assert_eq!(
+        srgb.component(ComponentName::A).unwrap().numeric_type,
+        NumericType::Unorm
+    );
+    assert!(vk::Format::B8G8R8A8_SRGB.is_compatible_with(vk::Format::R32_UINT));
+    assert!(!vk::Format::B8G8R8A8_SRGB.is_compatible_with(vk::Format::R16_UINT));
+    assert_eq!(vk::Format::UNDEFINED.info().map(|info| info.format), None);
+}
+
+#[test]
+fn compressed_formats() {
+    let bc7 = vk::Format::BC7_UNORM_BLOCK.info().unwrap();
+    assert_eq!(
+        (bc7.block_size, bc7.block_extent, bc7.compression),
+        (16, [4, 4, 1], Some(Compression::Bc))
+    );
+    let astc = vk::Format::ASTC_10X8_SRGB_BLOCK.info().unwrap();
+    assert_eq!(
+        (astc.block_extent, astc.compatibility_class),
+        ([10, 8, 1], "ASTC_10x8")
+    );
+    assert!(astc.is_compressed());
+    assert!(!vk::Format::BC1_RGB_UNORM_BLOCK.is_compatible_with(vk::Format::BC1_RGBA_UNORM_BLOCK));
+}
+
+#[test]
+fn depth_stencil_formats() {
+    let info = vk::Format::D24_UNORM_S8_UINT.info().unwrap();
+    assert_eq!(
+        info.aspect_mask,
+        vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL
+    );
+    assert!(info.is_depth_stencil());
+    assert_eq!(info.numeric_type(), NumericType::Unorm);
+    assert_eq!(
+        info.component(ComponentName::S).unwrap().numeric_type,
+        NumericType::Uint
+    );
+    assert_eq!(vk::Format::D32_SFLOAT_S8_UINT.info().unwrap().block_size, 5);
+    assert_eq!(
+        vk::Format::X8_D24_UNORM_PACK32
+            .info()
+            .unwrap()
+            .components
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn multi_planar_formats() {
+    let info = vk::Format::G8_B8R8_2PLANE_420_UNORM.info().unwrap();
+    assert_eq!(info.plane_count(), 2);
+    assert_eq!(info.planes[0].format, vk::Format::R8_UNORM);
+    assert_eq!(info.planes[1].format, vk::Format::R8G8_UNORM);
+    assert_eq!(
+        (info.planes[1].width_divisor, info.planes[1].height_divisor),
+        (2, 2)
+    );
+    assert!(info
+        .aspect_mask
+        .contains(vk::ImageAspectFlags::PLANE_0 | vk::ImageAspectFlags::PLANE_1));
+    assert_eq!(info.compatibility_class, "8-bit 2-plane 420");
+
+    let packed = vk::Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16
+        .info()
+        .unwrap();
+    assert_eq!(packed.planes[2].format, vk::Format::R10X6_UNORM_PACK16);
+    assert_eq!(packed.block_size, 6);
+    assert_eq!(
+        vk::Format::G8B8G8R8_422_UNORM.info().unwrap().block_extent,
+        [2, 1, 1]
+    );
+}
+
+#[test]
+fn every_format_has_info() {
+    for info in FORMATS {
+        assert_eq!(info.format.info().unwrap().format, info.format);
+        assert!(!info.components.is_empty());
+        let texels: u32 = info.block_extent.iter().product();
+        assert!(info.block_size > 0 && texels > 0);
+    }
+}
@@ -0,0 +1,153 @@
+extern crate ash;
+
+use ash::allocator::host::HostAllocator;
+use ash::vk;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::os::raw::c_void;
+use std::ptr;
+
+unsafe fn allocate(
+    callbacks: &vk::AllocationCallbacks,
+    size: usize,
+    alignment: usize,
+    scope: vk::SystemAllocationScope,
+) -> *mut c_void {
+    (callbacks.pfn_allocation.unwrap())(callbacks.p_user_data, size, alignment, scope)
+}
+
+unsafe fn free(callbacks: &vk::AllocationCallbacks, memory: *mut c_void) {
+    (callbacks.pfn_free.unwrap())(callbacks.p_user_data, memory);
+}
+
+#[test]
+fn allocation_is_aligned() {
+    let allocator = HostAllocator::new(System);
+    let callbacks = allocator.callbacks();
+    unsafe {
+        for &alignment in &[1, 8, 64, 256, 4096] {
+            let memory = allocate(&callbacks, 24, alignment, vk::SystemAllocationScope::OBJECT);
+            assert!(!memory.is_null());
+            assert_eq!(memory as usize % alignment, 0);
+            free(&callbacks, memory);
+        }
+    }
+}
+
+#[test]
+fn stats_per_scope() {
+    let allocator = HostAllocator::new(System);
+    let callbacks = allocator.callbacks();
+    unsafe {
+        let object = allocate(&callbacks, 100, 8, vk::SystemAllocationScope::OBJECT);
+        let command = allocate(&callbacks, 30, 8, vk::SystemAllocationScope::COMMAND);
+        let stats = allocator.stats();
+        let usage = stats.scope(vk::SystemAllocationScope::OBJECT);
+        assert_eq!(usage.live_bytes, 100);
+        assert_eq!(usage.live_count, 1);
+        assert_eq!(
+            stats.scope(vk::SystemAllocationScope::COMMAND).live_bytes,
+            30
+        );
+        assert_eq!(
+            stats.scope(vk::SystemAllocationScope::DEVICE).total_count,
+            0
+        );
+        assert_eq!(stats.total().live_bytes, 130);
+
+        free(&callbacks, object);
+        free(&callbacks, command);
+        let usage = allocator.stats().scope(vk::SystemAllocationScope::OBJECT);
+        assert_eq!(usage.live_bytes, 0);
+        assert_eq!(usage.live_count, 0);
+        assert_eq!(usage.peak_bytes, 100);
+        assert_eq!(usage.total_count, 1);
+
+        // Freeing null is allowed
+        free(&callbacks, ptr::null_mut());
+    }
+}
+
+#[test]
+fn reallocation_keeps_contents() {
+    let allocator = HostAllocator::new(System);
+    let callbacks = allocator.callbacks();
+    let reallocate = callbacks.pfn_reallocation.unwrap();
+    let scope = vk::SystemAllocationScope::CACHE;
+    unsafe {
+        let memory = reallocate(callbacks.p_user_data, ptr::null_mut(), 16, 16, scope) as *mut u8;
+        for i in 0..16 {
+            *memory.add(i) = i as u8;
+        }
+        let memory = reallocate(callbacks.p_user_data, memory as *mut c_void, 64, 128, scope);
+        assert_eq!(memory as usize % 128, 0);
+        let bytes = std::slice::from_raw_parts(memory as *const u8, 16);
+        assert_eq!(bytes, &(0..16).collect::<Vec<u8>>()[..]);
+        assert_eq!(allocator.stats().scope(scope).live_bytes, 64);
+
+        let memory = reallocate(callbacks.p_user_data, memory, 0, 128, scope);
+        assert!(memory.is_null());
+        assert_eq!(allocator.stats().scope(scope).live_count, 0);
+    }
+}
+
+#[test]
+fn internal_notifications() {
+    let allocator = HostAllocator::new(System);
+    let callbacks = allocator.callbacks();
+    let allocation_type = vk::InternalAllocationType::EXECUTABLE;
+    let scope = vk::SystemAllocationScope::DEVICE;
+    unsafe {
+        (callbacks.pfn_internal_allocation.unwrap())(
+            callbacks.p_user_data,
+            4096,
+            allocation_type,
+            scope,
+        );
+        assert_eq!(allocator.stats().internal(allocation_type).live_bytes, 4096);
+        (callbacks.pfn_internal_free.unwrap())(callbacks.p_user_data, 4096, allocation_type, scope);
+    }
+    let stats = allocator.stats();
+    assert_eq!(stats.internal(allocation_type).live_bytes, 0);
+    assert_eq!(stats.internal(allocation_type).total_count, 1);
+    assert_eq!(stats.total().total_count, 0);
+}
+
+#[test]
+fn closures() {
+    let allocations = Cell::new(0);
+    let allocator = HostAllocator::from_fns(
+        |layout: Layout| {
+            allocations.set(allocations.get() + 1);
+            unsafe { System.alloc(layout) }
+        },
+        |ptr, layout| unsafe { System.dealloc(ptr, layout) },
+    );
+    let callbacks = allocator.callbacks();
+    unsafe {
+        let memory = allocate(&callbacks, 8, 32, vk::SystemAllocationScope::INSTANCE);
+        assert_eq!(memory as usize % 32, 0);
+        free(&callbacks, memory);
+    }
+    assert_eq!(allocations.get(), 1);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_default_allocator() {
+    use ash::allocator::host::{DefaultAllocatorCallback, VkAllocation};
+
+    // The implementation allocates on its own
+    assert!(DefaultAllocatorCallback::create_allocation_callback().is_none());
+    unsafe {
+        let memory = DefaultAllocatorCallback::allocation(
+            ptr::null_mut(),
+            16,
+            64,
+            vk::SystemAllocationScope::OBJECT,
+        );
+        assert!(!memory.is_null());
+        assert_eq!(memory as usize % 64, 0);
+        DefaultAllocatorCallback::free(ptr::null_mut(), memory);
+    }
+}
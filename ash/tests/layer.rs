@@ -0,0 +1,231 @@
+#[macro_use]
+extern crate ash;
+mod common;
+use ash::layer::{
+    Commands, InstanceDispatch, Layer, NegotiateLayerInterface, NegotiateLayerStructType,
+};
+use ash::vk;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ENUMERATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// A dispatch table that no instance of the layer uses
+static UNKNOWN_INSTANCE: usize = 2;
+
+struct CountingLayer;
+
+impl Layer for CountingLayer {
+    fn new(_: &vk::InstanceCreateInfo, _: &InstanceDispatch) -> Self {
+        CountingLayer
+    }
+}
+
+impl Commands for CountingLayer {
+    unsafe fn enumerate_physical_devices(
+        &self,
+        next: &InstanceDispatch,
+        instance: vk::Instance,
+        p_physical_device_count: *mut u32,
+        p_physical_devices: *mut vk::PhysicalDevice,
+    ) -> vk::Result {
+        ENUMERATIONS.fetch_add(1, Ordering::SeqCst);
+        next.fp_v1_0().enumerate_physical_devices(
+            instance,
+            p_physical_device_count,
+            p_physical_devices,
+        )
+    }
+
+    unsafe fn get_physical_device_surface_support_khr(
+        &self,
+        _next: &InstanceDispatch,
+        _physical_device: vk::PhysicalDevice,
+        _queue_family_index: u32,
+        _surface: vk::SurfaceKHR,
+        _p_supported: *mut vk::Bool32,
+    ) -> vk::Result {
+        panic!("The layer failed")
+    }
+}
+
+export_layer!(CountingLayer);
+
+// The loader's structs, which a layer finds in the pNext chain of `vkCreateInstance`
+#[repr(C)]
+struct LayerInstanceLink {
+    p_next: *mut LayerInstanceLink,
+    pfn_next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    pfn_next_get_physical_device_proc_addr: vk::PFN_vkVoidFunction,
+}
+
+#[repr(C)]
+struct LayerInstanceCreateInfo {
+    s_type: vk::StructureType,
+    p_next: *const c_void,
+    function: i32,
+    p_layer_info: *mut LayerInstanceLink,
+}
+
+extern "system" fn next_create_instance(
+    _p_create_info: *const vk::InstanceCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    unsafe {
+        *p_instance = common::dispatchable(&common::INSTANCE);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn next_enumerate_physical_devices(
+    _instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    _p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    unsafe {
+        *p_physical_device_count = 2;
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn next_get_physical_device_surface_support_khr(
+    _physical_device: vk::PhysicalDevice,
+    _queue_family_index: u32,
+    _surface: vk::SurfaceKHR,
+    p_supported: *mut vk::Bool32,
+) -> vk::Result {
+    unsafe {
+        *p_supported = vk::TRUE;
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn next_get_instance_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkCreateInstance" => next_create_instance as *const c_void,
+            b"vkEnumeratePhysicalDevices" => next_enumerate_physical_devices as *const c_void,
+            b"vkGetPhysicalDeviceSurfaceSupportKHR" => {
+                next_get_physical_device_surface_support_khr as *const c_void
+            }
+            _ => ptr::null(),
+        })
+    }
+}
+
+#[test]
+fn layer_overrides_commands_and_forwards_the_rest() {
+    let mut interface = NegotiateLayerInterface {
+        s_type: NegotiateLayerStructType::INTERFACE_STRUCT,
+        p_next: ptr::null_mut(),
+        loader_layer_interface_version: 5,
+        pfn_get_instance_proc_addr: None,
+        pfn_get_device_proc_addr: None,
+        pfn_get_physical_device_proc_addr: None,
+    };
+    unsafe {
+        assert_eq!(
+            vkNegotiateLoaderLayerInterfaceVersion(&mut interface),
+            vk::Result::SUCCESS
+        );
+    }
+    assert_eq!(interface.loader_layer_interface_version, 2);
+    let get_instance_proc_addr = interface.pfn_get_instance_proc_addr.unwrap();
+    let get_proc_addr = |instance: vk::Instance, name: &[u8]| {
+        get_instance_proc_addr(instance, name.as_ptr() as *const c_char)
+    };
+
+    let mut link = LayerInstanceLink {
+        p_next: ptr::null_mut(),
+        pfn_next_get_instance_proc_addr: next_get_instance_proc_addr,
+        pfn_next_get_physical_device_proc_addr: None,
+    };
+    let mut layer_info = LayerInstanceCreateInfo {
+        s_type: vk::StructureType::LOADER_INSTANCE_CREATE_INFO,
+        p_next: ptr::null(),
+        function: 0,
+        p_layer_info: &mut link,
+    };
+    let create_info = vk::InstanceCreateInfo {
+        p_next: &mut layer_info as *mut LayerInstanceCreateInfo as *const c_void,
+        ..Default::default()
+    };
+    let mut instance = vk::Instance::null();
+    unsafe {
+        let create_instance: vk::PFN_vkCreateInstance =
+            mem::transmute(get_proc_addr(vk::Instance::null(), b"vkCreateInstance\0").unwrap());
+        assert_eq!(
+            create_instance(&create_info, ptr::null(), &mut instance),
+            vk::Result::SUCCESS
+        );
+        // The chain moved on to the next layer
+        assert!(layer_info.p_layer_info.is_null());
+
+        let enumerate: vk::PFN_vkEnumeratePhysicalDevices =
+            mem::transmute(get_proc_addr(instance, b"vkEnumeratePhysicalDevices\0").unwrap());
+        let mut count = 0;
+        assert_eq!(
+            enumerate(instance, &mut count, ptr::null_mut()),
+            vk::Result::SUCCESS
+        );
+        assert_eq!(count, 2);
+
+        // The override panics, which must not unwind into the application
+        let surface_support: vk::PFN_vkGetPhysicalDeviceSurfaceSupportKHR = mem::transmute(
+            get_proc_addr(instance, b"vkGetPhysicalDeviceSurfaceSupportKHR\0").unwrap(),
+        );
+        let mut supported = vk::FALSE;
+        assert_eq!(
+            surface_support(
+                common::dispatchable(&common::PHYSICAL_DEVICE),
+                0,
+                vk::SurfaceKHR::null(),
+                &mut supported,
+            ),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        assert_eq!(supported, vk::FALSE);
+    }
+    assert_eq!(ENUMERATIONS.load(Ordering::SeqCst), 1);
+    assert!(get_proc_addr(instance, b"vkCreateFooEXT\0").is_none());
+    // Extension commands are only intercepted if the next layer has them
+    assert!(get_proc_addr(instance, b"vkDestroySurfaceKHR\0").is_none());
+    unsafe {
+        assert!(vkGetInstanceProcAddr(instance, ptr::null()).is_none());
+    }
+}
+
+#[test]
+fn unknown_handles_fail_without_unwinding() {
+    let instance: vk::Instance = common::dispatchable(&UNKNOWN_INSTANCE);
+    unsafe {
+        let get_proc_addr =
+            |name: &[u8]| vkGetInstanceProcAddr(instance, name.as_ptr() as *const c_char);
+        assert!(get_proc_addr(b"vkCreateFooEXT\0").is_none());
+        let enumerate: vk::PFN_vkEnumeratePhysicalDevices =
+            mem::transmute(get_proc_addr(b"vkEnumeratePhysicalDevices\0").unwrap());
+        let mut count = 0;
+        assert_eq!(
+            enumerate(instance, &mut count, ptr::null_mut()),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        assert_eq!(
+            enumerate(vk::Instance::null(), &mut count, ptr::null_mut()),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        assert_eq!(count, 0);
+
+        let set_blend_constants: vk::PFN_vkCmdSetBlendConstants =
+            mem::transmute(get_proc_addr(b"vkCmdSetBlendConstants\0").unwrap());
+        set_blend_constants(vk::CommandBuffer::null(), &[0.0; 4]);
+        let destroy_instance: vk::PFN_vkDestroyInstance =
+            mem::transmute(get_proc_addr(b"vkDestroyInstance\0").unwrap());
+        destroy_instance(instance, ptr::null());
+    }
+}
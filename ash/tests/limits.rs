@@ -0,0 +1,157 @@
+extern crate ash;
+use ash::limits::{LimitChecker, LimitError};
+use ash::vk;
+use std::ffi::CStr;
+
+fn checker(features: vk::PhysicalDeviceFeatures, extensions: &[&CStr]) -> LimitChecker {
+    let properties = vk::PhysicalDeviceProperties {
+        // Vulkan 1.0
+        api_version: 1 << 22,
+        limits: vk::PhysicalDeviceLimits {
+            max_image_dimension2_d: 4096,
+            max_image_array_layers: 256,
+            max_sampler_anisotropy: 16.0,
+            max_compute_work_group_count: [65535, 65535, 65535],
+            max_viewports: 1,
+            max_bound_descriptor_sets: 4,
+            max_descriptor_set_samplers: 4,
+            max_per_stage_descriptor_samplers: 4,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    LimitChecker::new(&properties, features, extensions)
+}
+
+#[test]
+fn create_infos_are_checked_against_limits() {
+    let checker = checker(Default::default(), &[]);
+    let image = vk::ImageCreateInfo {
+        image_type: vk::ImageType::TYPE_2D,
+        extent: vk::Extent3D {
+            width: 8192,
+            height: 1024,
+            depth: 1,
+        },
+        array_layers: 1,
+        ..Default::default()
+    };
+    let err = checker.check_image(&image).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ImageCreateInfo.extent.width is 8192, but the max_image_dimension2_d of the device is 4096"
+    );
+
+    assert!(checker.check_dispatch(65535, 1, 1).is_ok());
+    match checker.check_dispatch(1, 65536, 1) {
+        Err(LimitError::Exceeded { limit, .. }) => {
+            assert_eq!(limit, "max_compute_work_group_count[1]")
+        }
+        result => panic!("{:?}", result),
+    }
+
+    let binding = vk::DescriptorSetLayoutBinding {
+        descriptor_type: vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
+        descriptor_count: 5,
+        stage_flags: vk::ShaderStageFlags::FRAGMENT,
+        ..Default::default()
+    };
+    let set_layout = vk::DescriptorSetLayoutCreateInfo {
+        binding_count: 1,
+        p_bindings: &binding,
+        ..Default::default()
+    };
+    let layout = vk::PipelineLayoutCreateInfo {
+        set_layout_count: 1,
+        ..Default::default()
+    };
+    match unsafe { checker.check_pipeline_layout(&[&set_layout], &layout) } {
+        Err(LimitError::Exceeded { limit, value, .. }) => {
+            assert_eq!((limit, value), ("max_descriptor_set_samplers", 5.0))
+        }
+        result => panic!("{:?}", result),
+    }
+
+    // The counts must not wrap around
+    let bindings = [
+        vk::DescriptorSetLayoutBinding {
+            descriptor_count: u32::max_value(),
+            ..binding
+        },
+        binding,
+    ];
+    let set_layout = vk::DescriptorSetLayoutCreateInfo {
+        binding_count: 2,
+        p_bindings: bindings.as_ptr(),
+        ..Default::default()
+    };
+    assert!(unsafe { checker.check_pipeline_layout(&[&set_layout], &layout) }.is_err());
+
+    let pool_size = vk::DescriptorPoolSize {
+        ty: vk::DescriptorType::INLINE_UNIFORM_BLOCK_EXT,
+        descriptor_count: 1,
+    };
+    let pool = vk::DescriptorPoolCreateInfo {
+        pool_size_count: 1,
+        p_pool_sizes: &pool_size,
+        ..Default::default()
+    };
+    assert!(unsafe { checker.check_descriptor_pool(&pool) }.is_err());
+}
+
+#[test]
+fn features_and_extensions_must_be_enabled() {
+    let sampler = vk::SamplerCreateInfo {
+        anisotropy_enable: vk::TRUE,
+        max_anisotropy: 8.0,
+        ..Default::default()
+    };
+    let err = checker(Default::default(), &[])
+        .check_sampler(&sampler)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        LimitError::FeatureNotEnabled {
+            what: "SamplerCreateInfo.anisotropy_enable".to_owned(),
+            feature: "sampler_anisotropy",
+        }
+    );
+    let features = vk::PhysicalDeviceFeatures {
+        sampler_anisotropy: vk::TRUE,
+        ..Default::default()
+    };
+    assert!(checker(features, &[]).check_sampler(&sampler).is_ok());
+
+    // Promoted to core in Vulkan 1.1
+    let image = vk::ImageCreateInfo {
+        flags: vk::ImageCreateFlags::TYPE_2D_ARRAY_COMPATIBLE,
+        image_type: vk::ImageType::TYPE_3D,
+        array_layers: 1,
+        ..Default::default()
+    };
+    let maintenance1 = CStr::from_bytes_with_nul(b"VK_KHR_maintenance1\0").unwrap();
+    assert!(checker(features, &[]).check_image(&image).is_err());
+    assert!(checker(features, &[maintenance1])
+        .check_image(&image)
+        .is_ok());
+    let vulkan_1_1 = (1 << 22) | (1 << 12);
+    assert!(checker(features, &[])
+        .api_version(vulkan_1_1)
+        .check_image(&image)
+        .is_ok());
+
+    let viewport_state = vk::PipelineViewportStateCreateInfo {
+        viewport_count: 2,
+        scissor_count: 2,
+        ..Default::default()
+    };
+    let pipeline = vk::GraphicsPipelineCreateInfo {
+        p_viewport_state: &viewport_state,
+        ..Default::default()
+    };
+    let err = unsafe { checker(features, &[]).check_graphics_pipeline(&pipeline) }.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "PipelineViewportStateCreateInfo.viewport_count above 1 requires the multi_viewport feature, which is not enabled"
+    );
+}
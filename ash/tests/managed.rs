@@ -0,0 +1,162 @@
+extern crate ash;
+extern crate shared_library;
+mod common;
+use ash::managed::{ManagedDevice, ManagedInstance};
+use ash::version::{DeviceV1_0, InstanceV1_0};
+use ash::vk;
+use ash::{Device, Entry, Instance};
+use shared_library::dynamic_library::DynamicLibrary;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    DestroyDevice,
+    DestroyInstance,
+}
+
+extern "system" fn create_instance(
+    _: *const vk::InstanceCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    unsafe {
+        *p_instance = common::dispatchable(&common::INSTANCE);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn destroy_instance(_: vk::Instance, _: *const vk::AllocationCallbacks) -> c_void {
+    common::record(Call::DestroyInstance);
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn create_device(
+    _: vk::PhysicalDevice,
+    _: *const vk::DeviceCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    unsafe {
+        *p_device = common::dispatchable(&common::DEVICE);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn destroy_device(_: vk::Device, _: *const vk::AllocationCallbacks) -> c_void {
+    common::record(Call::DestroyDevice);
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkDestroyDevice" => destroy_device as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+extern "system" fn get_instance_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkCreateInstance" => create_instance as *const c_void,
+            b"vkDestroyInstance" => destroy_instance as *const c_void,
+            b"vkCreateDevice" => create_device as *const c_void,
+            b"vkGetDeviceProcAddr" => get_device_proc_addr as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+// The library is the test binary itself, the commands are looked up in the fakes above
+fn entry() -> Entry {
+    Entry::new_custom(
+        || Ok(Arc::new(DynamicLibrary::open(None).unwrap())),
+        |_, name| match name.to_bytes() {
+            b"vkGetInstanceProcAddr" => get_instance_proc_addr as *const c_void,
+            _ => ptr::null(),
+        },
+    )
+    .unwrap()
+}
+
+fn take_calls() -> Vec<Call> {
+    common::take_calls()
+}
+
+fn managed() -> (Arc<ManagedInstance>, Arc<ManagedDevice>) {
+    unsafe {
+        let instance =
+            ManagedInstance::new(&entry(), &vk::InstanceCreateInfo::default(), None).unwrap();
+        let device = ManagedDevice::new(
+            &instance,
+            common::dispatchable(&common::PHYSICAL_DEVICE),
+            &vk::DeviceCreateInfo::default(),
+            None,
+        )
+        .unwrap();
+        (instance, device)
+    }
+}
+
+#[test]
+fn children_keep_their_parents_alive() {
+    let (instance, device) = managed();
+    // The managed types dereference to the unmanaged ones
+    let _: &Instance = &instance;
+    let _: &Device = &device;
+    assert_eq!(device.handle(), common::dispatchable(&common::DEVICE));
+    assert!(Arc::ptr_eq(device.instance(), &instance));
+
+    drop(instance);
+    assert!(take_calls().is_empty());
+    drop(device);
+    assert_eq!(take_calls(), [Call::DestroyDevice, Call::DestroyInstance]);
+}
+
+#[test]
+fn extensions_keep_their_parents_alive() {
+    let (instance, device) = managed();
+    let device_extension = ManagedDevice::extension(&device, |_, device| device.handle());
+    let instance_extension = ManagedInstance::extension(&instance, |_, instance| instance.handle());
+    assert_eq!(*device_extension, common::dispatchable(&common::DEVICE));
+    assert_eq!(*instance_extension, common::dispatchable(&common::INSTANCE));
+    drop(device);
+    drop(instance);
+    assert!(take_calls().is_empty());
+
+    // The device still holds the instance
+    drop(instance_extension);
+    assert!(take_calls().is_empty());
+    let (loader, device) = device_extension.into_inner();
+    assert_eq!(loader, common::dispatchable(&common::DEVICE));
+    drop(device);
+    assert_eq!(take_calls(), [Call::DestroyDevice, Call::DestroyInstance]);
+}
+
+// Generic code that destroys the device it is given
+unsafe fn destroy<D: DeviceV1_0>(device: &D) {
+    device.destroy_device(None);
+}
+
+#[test]
+fn generic_code_can_not_destroy_managed_devices() {
+    let (instance, device) = managed();
+    drop(instance);
+    unsafe {
+        destroy(&*device);
+        assert_eq!(DeviceV1_0::handle(&*device), device.device().handle());
+    }
+    assert!(take_calls().is_empty());
+    drop(device);
+    assert_eq!(take_calls(), [Call::DestroyDevice, Call::DestroyInstance]);
+}
@@ -0,0 +1,210 @@
+extern crate ash;
+mod common;
+use ash::util::MappedRange;
+use ash::vk::{self, Handle};
+use ash::Device;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::Mutex;
+
+const MEMORY_SIZE: u64 = 1000;
+const ATOM_SIZE: u64 = 64;
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    Map(u64, u64),
+    Unmap,
+    Flush(u64, u64),
+    Invalidate(u64, u64),
+}
+
+// The memory handle is the address of a `Memory`
+struct Memory {
+    data: [u8; MEMORY_SIZE as usize],
+    calls: Mutex<Vec<Call>>,
+}
+
+unsafe fn memory<'a>(handle: vk::DeviceMemory) -> &'a Memory {
+    &*(handle.as_raw() as *const Memory)
+}
+
+extern "system" fn map_memory(
+    _: vk::Device,
+    handle: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    _: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    unsafe {
+        let memory = memory(handle);
+        memory.calls.lock().unwrap().push(Call::Map(offset, size));
+        *pp_data = memory.data.as_ptr().offset(offset as isize) as *mut c_void;
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn unmap_memory(_: vk::Device, handle: vk::DeviceMemory) -> c_void {
+    unsafe {
+        memory(handle).calls.lock().unwrap().push(Call::Unmap);
+        mem::zeroed()
+    }
+}
+
+unsafe fn record_ranges(
+    count: u32,
+    p_ranges: *const vk::MappedMemoryRange,
+    call: fn(u64, u64) -> Call,
+) -> vk::Result {
+    for range in std::slice::from_raw_parts(p_ranges, count as usize) {
+        let calls = &memory(range.memory).calls;
+        calls.lock().unwrap().push(call(range.offset, range.size));
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn flush_mapped_memory_ranges(
+    _: vk::Device,
+    count: u32,
+    p_ranges: *const vk::MappedMemoryRange,
+) -> vk::Result {
+    unsafe { record_ranges(count, p_ranges, Call::Flush) }
+}
+
+extern "system" fn invalidate_mapped_memory_ranges(
+    _: vk::Device,
+    count: u32,
+    p_ranges: *const vk::MappedMemoryRange,
+) -> vk::Result {
+    unsafe { record_ranges(count, p_ranges, Call::Invalidate) }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkMapMemory" => map_memory as *const c_void,
+            b"vkUnmapMemory" => unmap_memory as *const c_void,
+            b"vkFlushMappedMemoryRanges" => flush_mapped_memory_ranges as *const c_void,
+            b"vkInvalidateMappedMemoryRanges" => invalidate_mapped_memory_ranges as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn device() -> Device {
+    common::load_device(get_device_proc_addr)
+}
+
+fn new_memory() -> Box<Memory> {
+    Box::new(Memory {
+        data: [0; MEMORY_SIZE as usize],
+        calls: Mutex::new(Vec::new()),
+    })
+}
+
+fn handle(memory: &Memory) -> vk::DeviceMemory {
+    vk::DeviceMemory::from_raw(memory as *const Memory as u64)
+}
+
+#[test]
+fn non_coherent_ranges_are_aligned_to_atoms() {
+    let device = device();
+    let memory = new_memory();
+    let flags = vk::MemoryPropertyFlags::HOST_VISIBLE;
+    unsafe {
+        let mut mapped = MappedRange::map(
+            &device,
+            handle(&memory),
+            MEMORY_SIZE,
+            flags,
+            ATOM_SIZE,
+            100,
+            200,
+        )
+        .unwrap();
+        assert_eq!(mapped.size(), 200);
+        assert!(!mapped.is_coherent());
+        mapped.write(30, &[1u32, 2]);
+        mapped.commit().unwrap();
+        // Nothing was written since the last commit
+        mapped.commit().unwrap();
+        assert_eq!(&memory.data[130..134], &[1, 0, 0, 0]);
+
+        mapped.as_mut_slice::<u32>()[0] = 7;
+        drop(mapped);
+    }
+    assert_eq!(
+        *memory.calls.lock().unwrap(),
+        vec![
+            Call::Map(64, 256),
+            Call::Invalidate(64, 256),
+            Call::Flush(128, 64),
+            Call::Flush(64, 256),
+            Call::Unmap,
+        ]
+    );
+}
+
+#[test]
+fn ranges_are_clamped_to_the_end_of_the_memory() {
+    let device = device();
+    let memory = new_memory();
+    let flags = vk::MemoryPropertyFlags::HOST_VISIBLE;
+    unsafe {
+        let mut mapped = MappedRange::map(
+            &device,
+            handle(&memory),
+            MEMORY_SIZE,
+            flags,
+            ATOM_SIZE,
+            900,
+            vk::WHOLE_SIZE,
+        )
+        .unwrap();
+        assert_eq!(mapped.size(), 100);
+        mapped.write(99, &[1u8]);
+    }
+    assert_eq!(
+        *memory.calls.lock().unwrap(),
+        vec![
+            Call::Map(896, 104),
+            Call::Invalidate(896, 104),
+            Call::Flush(960, 40),
+            Call::Unmap,
+        ]
+    );
+}
+
+#[test]
+fn coherent_memory_is_not_flushed() {
+    let device = device();
+    let mut memory = new_memory();
+    memory.data[4..8].copy_from_slice(&[5, 0, 0, 0]);
+    let flags = vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT;
+    unsafe {
+        let mut mapped = MappedRange::map(
+            &device,
+            handle(&memory),
+            MEMORY_SIZE,
+            flags,
+            ATOM_SIZE,
+            0,
+            16,
+        )
+        .unwrap();
+        assert_eq!(mapped.as_slice::<u32>(), &[0, 5, 0, 0]);
+        let mut read = [0u32; 2];
+        mapped.read(4, &mut read);
+        assert_eq!(read, [5, 0]);
+        mapped.write(0, &[1u8]);
+        mapped.commit().unwrap();
+    }
+    assert_eq!(
+        *memory.calls.lock().unwrap(),
+        vec![Call::Map(0, 16), Call::Unmap]
+    );
+}
@@ -0,0 +1,154 @@
+extern crate ash;
+mod common;
+use ash::allocator::report::ReportingDevice;
+use ash::allocator::{AllocationCreateInfo, Allocator};
+use ash::version::DeviceV1_0;
+use ash::vk::{self, Handle};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_MEMORY: AtomicUsize = AtomicUsize::new(0x1000);
+
+extern "system" fn allocate_memory(
+    _: vk::Device,
+    _: *const vk::MemoryAllocateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    let raw = NEXT_MEMORY.fetch_add(0x1000, Ordering::SeqCst);
+    unsafe {
+        *p_memory = vk::DeviceMemory::from_raw(raw as u64);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn free_memory(
+    _: vk::Device,
+    _: vk::DeviceMemory,
+    _: *const vk::AllocationCallbacks,
+) -> c_void {
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkAllocateMemory" => allocate_memory as *const c_void,
+            b"vkFreeMemory" => free_memory as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn memory_properties() -> vk::PhysicalDeviceMemoryProperties {
+    let mut properties = vk::PhysicalDeviceMemoryProperties {
+        memory_type_count: 2,
+        memory_heap_count: 2,
+        ..Default::default()
+    };
+    properties.memory_types[0].property_flags = vk::MemoryPropertyFlags::DEVICE_LOCAL;
+    properties.memory_types[1] = vk::MemoryType {
+        property_flags: vk::MemoryPropertyFlags::HOST_VISIBLE
+            | vk::MemoryPropertyFlags::HOST_COHERENT,
+        heap_index: 1,
+    };
+    properties.memory_heaps[0] = vk::MemoryHeap {
+        size: 1 << 30,
+        flags: vk::MemoryHeapFlags::DEVICE_LOCAL,
+    };
+    properties.memory_heaps[1].size = 1 << 30;
+    properties
+}
+
+fn device() -> ReportingDevice {
+    let device = common::load_device(get_device_proc_addr);
+    ReportingDevice::new(device, memory_properties())
+}
+
+fn allocate(device: &ReportingDevice, memory_type_index: u32, size: u64) -> vk::DeviceMemory {
+    let info = vk::MemoryAllocateInfo {
+        allocation_size: size,
+        memory_type_index,
+        ..Default::default()
+    };
+    unsafe { device.allocate_memory(&info, None).unwrap() }
+}
+
+#[test]
+fn device_memory_is_grouped_by_heap_and_tag() {
+    let device = device();
+    let before = device.report();
+    let textures = allocate(&device, 0, 4096);
+    let staging = allocate(&device, 1, 1024);
+    device.set_tag(textures, "textures");
+
+    let report = device.report();
+    assert_eq!(report.heaps[0].totals.allocated, 4096);
+    assert_eq!(report.heaps[1].totals.allocated, 1024);
+    assert_eq!(report.memory_types[1].blocks[0].memory, staging.as_raw());
+    assert!(report.memory_types[1].blocks[0].dedicated);
+    assert_eq!(report.total().device_memory_count, 2);
+    let tag = report.tag("textures").unwrap();
+    assert_eq!((tag.size, tag.allocation_count), (4096, 1));
+
+    let diff = report.diff(&before);
+    assert_eq!(diff.heaps.len(), 2);
+    assert_eq!(diff.heaps[0].1.allocated, 4096);
+    assert_eq!(diff.tags[0].0, "textures");
+    assert_eq!(diff.new_blocks.len(), 2);
+
+    unsafe {
+        device.free_memory(textures, None);
+    }
+    let diff = device.report().diff(&report);
+    assert_eq!(diff.heaps.len(), 1);
+    assert_eq!(diff.heaps[0].1.allocated, -4096);
+    assert_eq!(diff.tags[0].1.size, -4096);
+    assert_eq!(diff.freed_blocks, vec![(textures.as_raw(), 0)]);
+    assert!(device.report().diff(&device.report()).is_empty());
+}
+
+#[test]
+fn allocator_reports_blocks_and_fragmentation() {
+    let allocator =
+        Allocator::new(device(), memory_properties(), &Default::default()).block_size(1 << 20);
+    let requirements = vk::MemoryRequirements {
+        size: 300,
+        alignment: 256,
+        memory_type_bits: 0b1,
+    };
+    let info = AllocationCreateInfo::default();
+    unsafe {
+        let a = allocator.allocate(&requirements, &info).unwrap();
+        let b = allocator.allocate(&requirements, &info).unwrap();
+        allocator.set_tag(&b, "uniforms");
+        allocator.free(a);
+
+        let report = allocator.report();
+        let block = &report.memory_types[0].blocks[0];
+        assert!(!block.dedicated);
+        assert_eq!(block.size, 1 << 20);
+        // Both allocations are rounded up to 512 bytes
+        assert_eq!(block.used, 512);
+        assert_eq!(block.allocation_count, 1);
+        assert_eq!(block.largest_free_region, 1 << 19);
+        assert!(block.fragmentation() > 0.0);
+        assert_eq!(report.tag("uniforms").unwrap().size, 300);
+        // The device only sees the block
+        assert_eq!(allocator.device().report().total().allocated, 1 << 20);
+
+        let json = report.to_json();
+        assert!(json.contains("\"name\": \"uniforms\", \"size\": 300, \"allocation_count\": 1"));
+        assert!(json.contains("\"flags\": \"DEVICE_LOCAL\""));
+
+        allocator.free(b);
+        let diff = allocator.report().diff(&report);
+        assert_eq!(diff.tags[0].1.allocation_count, -1);
+        assert!(diff.to_json().contains("\"name\": \"uniforms\""));
+    }
+}
@@ -0,0 +1,133 @@
+extern crate ash;
+mod common;
+use ash::owned::Owned;
+use ash::prelude::WaitResult;
+use ash::vk::{self, Handle};
+use ash::Device;
+use std::cell::Cell;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    Wait(u64),
+    Destroy(u64),
+}
+
+thread_local! {
+    static SIGNALED: Cell<bool> = Cell::new(false);
+}
+
+fn signal() {
+    SIGNALED.with(|signaled| signaled.set(true));
+}
+
+extern "system" fn get_fence_status(_: vk::Device, _: vk::Fence) -> vk::Result {
+    if SIGNALED.with(|signaled| signaled.get()) {
+        vk::Result::SUCCESS
+    } else {
+        vk::Result::NOT_READY
+    }
+}
+
+// Waits with a zero timeout time out, longer ones last until the fence is signaled
+extern "system" fn wait_for_fences(
+    _: vk::Device,
+    _: u32,
+    _: *const vk::Fence,
+    _: vk::Bool32,
+    timeout: u64,
+) -> vk::Result {
+    common::record(Call::Wait(timeout));
+    if timeout == 0 && !SIGNALED.with(|signaled| signaled.get()) {
+        return vk::Result::TIMEOUT;
+    }
+    signal();
+    vk::Result::SUCCESS
+}
+
+extern "system" fn destroy_buffer(
+    _: vk::Device,
+    buffer: vk::Buffer,
+    _: *const vk::AllocationCallbacks,
+) -> c_void {
+    common::record(Call::Destroy(buffer.as_raw()));
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkGetFenceStatus" => get_fence_status as *const c_void,
+            b"vkWaitForFences" => wait_for_fences as *const c_void,
+            b"vkDestroyBuffer" => destroy_buffer as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn buffer(device: &Arc<Device>, raw: u64) -> Owned<vk::Buffer> {
+    unsafe { Owned::from_raw(device.clone(), vk::Buffer::from_raw(raw), None) }
+}
+
+fn take_calls() -> Vec<Call> {
+    common::take_calls()
+}
+
+#[test]
+fn owned_handles_are_destroyed_on_drop() {
+    let device = Arc::new(common::load_device(get_device_proc_addr));
+    drop(buffer(&device, 1));
+    assert_eq!(take_calls(), [Call::Destroy(1)]);
+    assert_eq!(buffer(&device, 2).into_raw(), vk::Buffer::from_raw(2));
+    assert!(take_calls().is_empty());
+}
+
+#[test]
+fn deferred_destruction_polls_the_fence() {
+    let device = Arc::new(common::load_device(get_device_proc_addr));
+    let fence = vk::Fence::from_raw(1);
+    let mut deferred = buffer(&device, 1).destroy_after(fence);
+    assert_eq!(unsafe { deferred.poll() }, Ok(false));
+    assert!(take_calls().is_empty());
+    signal();
+    assert_eq!(unsafe { deferred.poll() }, Ok(true));
+    assert_eq!(take_calls(), [Call::Destroy(1)]);
+    assert_eq!(unsafe { deferred.poll() }, Ok(true));
+    // The handle is gone, so dropping does not wait
+    drop(deferred);
+    assert!(take_calls().is_empty());
+}
+
+#[test]
+fn deferred_destruction_waits_with_a_timeout() {
+    let device = Arc::new(common::load_device(get_device_proc_addr));
+    let mut deferred = buffer(&device, 1).destroy_after(vk::Fence::from_raw(1));
+    assert_eq!(unsafe { deferred.wait(0) }.ok(), Some(WaitResult::TimedOut));
+    assert_eq!(take_calls(), [Call::Wait(0)]);
+    assert_eq!(
+        unsafe { deferred.wait(1000) }.ok(),
+        Some(WaitResult::Success)
+    );
+    assert_eq!(take_calls(), [Call::Wait(1000), Call::Destroy(1)]);
+}
+
+#[test]
+fn dropping_a_deferred_destruction_blocks_until_the_fence_is_signaled() {
+    let device = Arc::new(common::load_device(get_device_proc_addr));
+    drop(buffer(&device, 1).destroy_after(vk::Fence::from_raw(1)));
+    assert_eq!(
+        take_calls(),
+        [Call::Wait(u64::max_value()), Call::Destroy(1)]
+    );
+
+    let deferred = buffer(&device, 2).destroy_after(vk::Fence::from_raw(1));
+    let owned = deferred.cancel().unwrap();
+    assert!(take_calls().is_empty());
+    assert_eq!(owned.into_raw(), vk::Buffer::from_raw(2));
+}
@@ -0,0 +1,371 @@
+extern crate ash;
+mod common;
+use ash::util::{depth_stencil_texel_size, read_linear_image, Readback, ReadbackRegion};
+use ash::vk::{self, Handle};
+use ash::Device;
+use common::{dispatchable, record, take_calls, QUEUE};
+use std::cell::Cell;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    Barrier(Vec<(vk::ImageLayout, vk::ImageLayout)>, usize),
+    CopyImageToBuffer(vk::ImageLayout, vk::ImageAspectFlags, u32, u32),
+    CopyBuffer(u64, u64),
+    Invalidate,
+    Wait,
+}
+
+thread_local! {
+    static BUFFER_SIZE: Cell<u64> = Cell::new(0);
+}
+
+// The copies write the index of each byte
+unsafe fn fill_staging(len: usize) {
+    for (i, byte) in slice::from_raw_parts_mut(common::mapped(), len)
+        .iter_mut()
+        .enumerate()
+    {
+        *byte = i as u8;
+    }
+}
+
+extern "system" fn create_buffer(
+    _: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    unsafe {
+        BUFFER_SIZE.with(|size| size.set((*p_create_info).size));
+        *p_buffer = vk::Buffer::from_raw(100);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_buffer_memory_requirements(
+    _: vk::Device,
+    _: vk::Buffer,
+    p_requirements: *mut vk::MemoryRequirements,
+) -> c_void {
+    unsafe {
+        *p_requirements = vk::MemoryRequirements {
+            size: BUFFER_SIZE.with(|size| size.get()),
+            alignment: 1,
+            memory_type_bits: 0b111,
+        };
+        mem::zeroed()
+    }
+}
+
+// Readback memory must be host cached
+extern "system" fn allocate_memory(
+    device: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    unsafe {
+        assert_eq!((*p_allocate_info).memory_type_index, 2);
+    }
+    common::allocate_memory(device, p_allocate_info, p_allocator, p_memory)
+}
+
+extern "system" fn invalidate_mapped_memory_ranges(
+    _: vk::Device,
+    _: u32,
+    _: *const vk::MappedMemoryRange,
+) -> vk::Result {
+    record(Call::Invalidate);
+    vk::Result::SUCCESS
+}
+
+extern "system" fn cmd_pipeline_barrier(
+    _: vk::CommandBuffer,
+    _: vk::PipelineStageFlags,
+    _: vk::PipelineStageFlags,
+    _: vk::DependencyFlags,
+    _: u32,
+    _: *const vk::MemoryBarrier,
+    buffer_barrier_count: u32,
+    _: *const vk::BufferMemoryBarrier,
+    image_barrier_count: u32,
+    p_image_barriers: *const vk::ImageMemoryBarrier,
+) -> c_void {
+    unsafe {
+        let image_barriers = slice::from_raw_parts(p_image_barriers, image_barrier_count as _);
+        record(Call::Barrier(
+            image_barriers
+                .iter()
+                .map(|b| (b.old_layout, b.new_layout))
+                .collect(),
+            buffer_barrier_count as usize,
+        ));
+        mem::zeroed()
+    }
+}
+
+// The test images have 4 bytes per texel
+extern "system" fn cmd_copy_image_to_buffer(
+    _: vk::CommandBuffer,
+    _: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    _: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) -> c_void {
+    unsafe {
+        assert_eq!(region_count, 1);
+        let region = *p_regions;
+        let extent = region.image_extent;
+        let layers = region.image_subresource;
+        fill_staging((extent.width * extent.height * extent.depth * layers.layer_count * 4) as _);
+        record(Call::CopyImageToBuffer(
+            src_image_layout,
+            layers.aspect_mask,
+            region.buffer_row_length,
+            region.buffer_image_height,
+        ));
+        mem::zeroed()
+    }
+}
+
+extern "system" fn cmd_copy_buffer(
+    _: vk::CommandBuffer,
+    _: vk::Buffer,
+    _: vk::Buffer,
+    _: u32,
+    p_regions: *const vk::BufferCopy,
+) -> c_void {
+    unsafe {
+        let region = *p_regions;
+        fill_staging(region.size as usize);
+        record(Call::CopyBuffer(region.src_offset, region.size));
+        mem::zeroed()
+    }
+}
+
+extern "system" fn queue_submit(
+    _: vk::Queue,
+    _: u32,
+    _: *const vk::SubmitInfo,
+    _: vk::Fence,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn wait_for_fences(
+    _: vk::Device,
+    _: u32,
+    _: *const vk::Fence,
+    _: vk::Bool32,
+    _: u64,
+) -> vk::Result {
+    record(Call::Wait);
+    vk::Result::SUCCESS
+}
+
+// Rows are 16 bytes and depth slices 64 bytes apart, after 16 bytes of other data
+extern "system" fn get_image_subresource_layout(
+    _: vk::Device,
+    _: vk::Image,
+    _: *const vk::ImageSubresource,
+    p_layout: *mut vk::SubresourceLayout,
+) -> c_void {
+    unsafe {
+        *p_layout = vk::SubresourceLayout {
+            offset: 16,
+            size: 128,
+            row_pitch: 16,
+            array_pitch: 128,
+            depth_pitch: 64,
+        };
+        mem::zeroed()
+    }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkCreateCommandPool" | b"vkCreateFence" => common::create_handle as *const c_void,
+            b"vkDestroyCommandPool" | b"vkDestroyFence" | b"vkDestroyBuffer" => {
+                common::destroy_handle as *const c_void
+            }
+            b"vkCreateBuffer" => create_buffer as *const c_void,
+            b"vkGetBufferMemoryRequirements" => get_buffer_memory_requirements as *const c_void,
+            b"vkAllocateMemory" => allocate_memory as *const c_void,
+            b"vkFreeMemory" => common::free_memory as *const c_void,
+            b"vkBindBufferMemory" => common::bind_memory as *const c_void,
+            b"vkMapMemory" => common::map_memory as *const c_void,
+            b"vkInvalidateMappedMemoryRanges" => invalidate_mapped_memory_ranges as *const c_void,
+            b"vkAllocateCommandBuffers" => common::allocate_command_buffers as *const c_void,
+            b"vkFreeCommandBuffers" => common::free_command_buffers as *const c_void,
+            b"vkBeginCommandBuffer" => common::begin_command_buffer as *const c_void,
+            b"vkEndCommandBuffer" => common::end_command_buffer as *const c_void,
+            b"vkCmdPipelineBarrier" => cmd_pipeline_barrier as *const c_void,
+            b"vkCmdCopyImageToBuffer" => cmd_copy_image_to_buffer as *const c_void,
+            b"vkCmdCopyBuffer" => cmd_copy_buffer as *const c_void,
+            b"vkQueueSubmit" => queue_submit as *const c_void,
+            b"vkWaitForFences" => wait_for_fences as *const c_void,
+            b"vkGetImageSubresourceLayout" => get_image_subresource_layout as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn device() -> Device {
+    common::load_device(get_device_proc_addr)
+}
+
+// The cached memory type is not coherent
+fn readback() -> Readback {
+    let mut memory_properties = vk::PhysicalDeviceMemoryProperties {
+        memory_type_count: 3,
+        memory_heap_count: 1,
+        ..Default::default()
+    };
+    memory_properties.memory_types[0].property_flags = vk::MemoryPropertyFlags::DEVICE_LOCAL;
+    memory_properties.memory_types[1].property_flags =
+        vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT;
+    memory_properties.memory_types[2].property_flags =
+        vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_CACHED;
+    unsafe { Readback::new(device(), memory_properties, dispatchable(&QUEUE), 0).unwrap() }
+}
+
+fn region(aspect_mask: vk::ImageAspectFlags, width: u32, height: u32) -> ReadbackRegion {
+    ReadbackRegion {
+        aspect_mask,
+        mip_level: 0,
+        base_array_layer: 0,
+        layer_count: 1,
+        extent: vk::Extent3D {
+            width,
+            height,
+            depth: 1,
+        },
+        texel_size: 4,
+    }
+}
+
+#[test]
+fn images_are_transitioned_and_restored() {
+    let readback = readback();
+    let image = vk::Image::from_raw(1);
+    let region = region(vk::ImageAspectFlags::COLOR, 3, 2);
+    let pixels = unsafe {
+        readback
+            .read_image(image, vk::ImageLayout::PRESENT_SRC_KHR, &region)
+            .unwrap()
+    };
+    assert_eq!(pixels, (0..24).collect::<Vec<u8>>());
+    assert_eq!(
+        take_calls::<Call>(),
+        vec![
+            Call::Barrier(
+                vec![(
+                    vk::ImageLayout::PRESENT_SRC_KHR,
+                    vk::ImageLayout::TRANSFER_SRC_OPTIMAL
+                )],
+                0
+            ),
+            Call::CopyImageToBuffer(
+                vk::ImageLayout::TRANSFER_SRC_OPTIMAL,
+                vk::ImageAspectFlags::COLOR,
+                3,
+                2
+            ),
+            Call::Barrier(
+                vec![(
+                    vk::ImageLayout::TRANSFER_SRC_OPTIMAL,
+                    vk::ImageLayout::PRESENT_SRC_KHR
+                )],
+                1
+            ),
+            Call::Wait,
+            Call::Invalidate,
+        ]
+    );
+}
+
+#[test]
+fn general_images_are_copied_in_place() {
+    let readback = readback();
+    let image = vk::Image::from_raw(1);
+    let format = vk::Format::D24_UNORM_S8_UINT;
+    let aspect = vk::ImageAspectFlags::DEPTH;
+    assert_eq!(depth_stencil_texel_size(format, aspect), Some(4));
+    assert_eq!(
+        depth_stencil_texel_size(format, vk::ImageAspectFlags::STENCIL),
+        Some(1)
+    );
+    assert_eq!(
+        depth_stencil_texel_size(vk::Format::R8G8B8A8_UNORM, aspect),
+        None
+    );
+    unsafe {
+        readback
+            .read_image(image, vk::ImageLayout::GENERAL, &region(aspect, 1, 1))
+            .unwrap();
+    }
+    let calls = take_calls::<Call>();
+    assert_eq!(
+        calls[..3],
+        [
+            Call::Barrier(
+                vec![(vk::ImageLayout::GENERAL, vk::ImageLayout::GENERAL)],
+                0
+            ),
+            Call::CopyImageToBuffer(vk::ImageLayout::GENERAL, aspect, 1, 1),
+            Call::Barrier(vec![], 1),
+        ]
+    );
+}
+
+#[test]
+fn buffers_are_copied() {
+    let readback = readback();
+    let data = unsafe {
+        readback
+            .read_buffer(vk::Buffer::from_raw(1), 32, 5)
+            .unwrap()
+    };
+    assert_eq!(data, vec![0, 1, 2, 3, 4]);
+    assert_eq!(take_calls::<Call>()[1], Call::CopyBuffer(32, 5));
+}
+
+#[test]
+fn linear_images_skip_row_padding() {
+    let device = device();
+    let memory: Vec<u8> = (0..144).map(|i| i as u8).collect();
+    let subresource = vk::ImageSubresource {
+        aspect_mask: vk::ImageAspectFlags::COLOR,
+        mip_level: 0,
+        array_layer: 0,
+    };
+    let extent = vk::Extent3D {
+        width: 2,
+        height: 2,
+        depth: 2,
+    };
+    let texels = unsafe {
+        read_linear_image(
+            &device,
+            vk::Image::from_raw(1),
+            subresource,
+            extent,
+            4,
+            memory.as_ptr() as *const c_void,
+        )
+    };
+    let rows: Vec<u8> = [16, 32, 80, 96]
+        .iter()
+        .flat_map(|&start| start..start + 8)
+        .collect();
+    assert_eq!(texels, rows);
+}
@@ -0,0 +1,202 @@
+extern crate ash;
+mod common;
+use ash::extensions::khr::Swapchain;
+use ash::prelude::{AcquireResult, FenceStatus, PresentResult, WaitResult};
+use ash::version::{DeviceV1_0, InstanceV1_0};
+use ash::vk::{self, Handle};
+use std::cell::Cell;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+thread_local! {
+    static PHYSICAL_DEVICE_COUNT: Cell<u32> = Cell::new(1);
+    static ENUMERATIONS: Cell<u32> = Cell::new(0);
+}
+
+// Fence 1 is signaled, fence 2 is not and fence 3 belongs to a lost device
+extern "system" fn get_fence_status(_: vk::Device, fence: vk::Fence) -> vk::Result {
+    match fence.as_raw() {
+        1 => vk::Result::SUCCESS,
+        2 => vk::Result::NOT_READY,
+        _ => vk::Result::ERROR_DEVICE_LOST,
+    }
+}
+
+// Waits with a zero timeout time out, all others succeed
+extern "system" fn wait_for_fences(
+    _: vk::Device,
+    _: u32,
+    _: *const vk::Fence,
+    _: vk::Bool32,
+    timeout: u64,
+) -> vk::Result {
+    if timeout == 0 {
+        vk::Result::TIMEOUT
+    } else {
+        vk::Result::SUCCESS
+    }
+}
+
+// The timeout selects the result, and the acquired image is the timeout as well
+extern "system" fn acquire_next_image(
+    _: vk::Device,
+    _: vk::SwapchainKHR,
+    timeout: u64,
+    _: vk::Semaphore,
+    _: vk::Fence,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    unsafe {
+        *p_image_index = timeout as u32;
+    }
+    match timeout {
+        0 => vk::Result::NOT_READY,
+        1 => vk::Result::TIMEOUT,
+        2 => vk::Result::SUCCESS,
+        3 => vk::Result::SUBOPTIMAL_KHR,
+        _ => vk::Result::ERROR_OUT_OF_DATE_KHR,
+    }
+}
+
+// The presented image index selects the result
+extern "system" fn queue_present(
+    _: vk::Queue,
+    p_present_info: *const vk::PresentInfoKHR,
+) -> vk::Result {
+    match unsafe { *(*p_present_info).p_image_indices } {
+        0 => vk::Result::SUCCESS,
+        1 => vk::Result::SUBOPTIMAL_KHR,
+        _ => vk::Result::ERROR_OUT_OF_DATE_KHR,
+    }
+}
+
+// A physical device is added after the count was queried for the first time
+extern "system" fn enumerate_physical_devices(
+    _: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    ENUMERATIONS.with(|enumerations| enumerations.set(enumerations.get() + 1));
+    let available = PHYSICAL_DEVICE_COUNT.with(|count| count.get());
+    unsafe {
+        if p_physical_devices.is_null() {
+            *p_physical_device_count = available;
+            return vk::Result::SUCCESS;
+        }
+        let count = (*p_physical_device_count).min(available);
+        for i in 0..count {
+            *p_physical_devices.offset(i as isize) = vk::PhysicalDevice::from_raw(i as u64 + 1);
+        }
+        *p_physical_device_count = count;
+        PHYSICAL_DEVICE_COUNT.with(|count| count.set(2));
+        if count < 2 {
+            vk::Result::INCOMPLETE
+        } else {
+            vk::Result::SUCCESS
+        }
+    }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkGetFenceStatus" => get_fence_status as *const c_void,
+            b"vkWaitForFences" => wait_for_fences as *const c_void,
+            b"vkAcquireNextImageKHR" => acquire_next_image as *const c_void,
+            b"vkQueuePresentKHR" => queue_present as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+extern "system" fn get_instance_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkEnumeratePhysicalDevices" => enumerate_physical_devices as *const c_void,
+            b"vkGetDeviceProcAddr" => get_device_proc_addr as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+#[test]
+fn fence_status() {
+    let device = common::load_device(get_device_proc_addr);
+    unsafe {
+        let status = |fence| device.get_fence_status(vk::Fence::from_raw(fence));
+        assert_eq!(status(1).ok(), Some(FenceStatus::Signaled));
+        assert_eq!(status(2).ok(), Some(FenceStatus::NotReady));
+        assert!(status(3).is_err());
+
+        let fences = [vk::Fence::from_raw(1)];
+        assert_eq!(
+            device.wait_for_fences(&fences, true, 0).ok(),
+            Some(WaitResult::TimedOut)
+        );
+        assert_eq!(
+            device.wait_for_fences(&fences, true, 1).ok(),
+            Some(WaitResult::Success)
+        );
+    }
+}
+
+#[test]
+fn acquire_and_present_results() {
+    let instance = common::load_instance(get_instance_proc_addr);
+    let device = common::load_device(get_device_proc_addr);
+    let swapchain_loader = Swapchain::new(&instance, &device);
+    let swapchain = vk::SwapchainKHR::from_raw(1);
+    unsafe {
+        let acquire = |timeout| {
+            swapchain_loader
+                .acquire_next_image(swapchain, timeout, vk::Semaphore::null(), vk::Fence::null())
+                .ok()
+        };
+        assert_eq!(acquire(0), Some(AcquireResult::NotReady));
+        assert_eq!(acquire(1), Some(AcquireResult::TimedOut));
+        assert_eq!(acquire(2), Some(AcquireResult::Success(2)));
+        let suboptimal = acquire(3).unwrap();
+        assert_eq!(suboptimal, AcquireResult::Suboptimal(3));
+        assert_eq!(suboptimal.image_index(), Some(3));
+        assert!(suboptimal.is_suboptimal());
+        assert_eq!(AcquireResult::TimedOut.image_index(), None);
+        assert_eq!(acquire(4), None);
+
+        let present = |image_index: u32| {
+            let swapchains = [swapchain];
+            let image_indices = [image_index];
+            let present_info = vk::PresentInfoKHR::builder()
+                .swapchains(&swapchains)
+                .image_indices(&image_indices);
+            swapchain_loader
+                .queue_present(common::dispatchable(&common::QUEUE), &present_info)
+                .ok()
+        };
+        assert_eq!(present(0), Some(PresentResult::Success));
+        assert!(!PresentResult::Success.is_suboptimal());
+        assert_eq!(present(1), Some(PresentResult::Suboptimal));
+        assert!(PresentResult::Suboptimal.is_suboptimal());
+        assert_eq!(present(2), None);
+    }
+}
+
+#[test]
+fn enumeration_is_retried_when_incomplete() {
+    let instance = common::load_instance(get_instance_proc_addr);
+    let physical_devices = unsafe { instance.enumerate_physical_devices() }.unwrap();
+    assert_eq!(
+        physical_devices,
+        [
+            vk::PhysicalDevice::from_raw(1),
+            vk::PhysicalDevice::from_raw(2)
+        ]
+    );
+    // Count and fill twice
+    assert_eq!(ENUMERATIONS.with(|enumerations| enumerations.get()), 4);
+}
@@ -0,0 +1,107 @@
+extern crate ash;
+mod common;
+use ash::util::{RingBuffer, RingBufferError};
+use ash::vk::{self, Handle};
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+// Fences with an odd handle are signaled
+extern "system" fn get_fence_status(_: vk::Device, fence: vk::Fence) -> vk::Result {
+    if fence.as_raw() % 2 == 1 {
+        vk::Result::SUCCESS
+    } else {
+        vk::Result::NOT_READY
+    }
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkGetFenceStatus" => get_fence_status as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn ring(memory: &mut [u8]) -> RingBuffer {
+    let limits = vk::PhysicalDeviceLimits {
+        min_uniform_buffer_offset_alignment: 256,
+        min_storage_buffer_offset_alignment: 64,
+        ..Default::default()
+    };
+    unsafe {
+        let device = common::load_device(get_device_proc_addr);
+        let mapped = memory.as_mut_ptr() as *mut c_void;
+        RingBuffer::new(
+            device,
+            vk::Buffer::from_raw(7),
+            mapped,
+            memory.len() as u64,
+            &limits,
+        )
+    }
+}
+
+#[test]
+fn allocations_are_aligned() {
+    let mut memory = vec![0u8; 1024];
+    let mut ring = ring(&mut memory);
+    {
+        let (buffer, offset, data) = ring.allocate_storage(10).unwrap();
+        assert_eq!(
+            (buffer, offset, data.len()),
+            (vk::Buffer::from_raw(7), 0, 10)
+        );
+        data[0] = 42;
+    }
+    assert_eq!(ring.allocate_uniform(16).unwrap().1, 256);
+    assert_eq!(ring.allocate_storage(16).unwrap().1, 320);
+    assert_eq!(ring.used(), 336);
+    drop(ring);
+    assert_eq!(memory[0], 42);
+}
+
+#[test]
+fn space_is_reclaimed_when_the_fence_is_signaled() {
+    let mut memory = vec![0u8; 1024];
+    let mut ring = ring(&mut memory);
+    let signaled = vk::Fence::from_raw(1);
+    let unsignaled = vk::Fence::from_raw(2);
+
+    ring.allocate_uniform(512).unwrap();
+    ring.end_frame(signaled);
+    ring.allocate_uniform(256).unwrap();
+    ring.end_frame(unsignaled);
+    assert_eq!(ring.allocate_uniform(256).unwrap().1, 768);
+    assert_eq!(ring.frames_in_flight(), 2);
+    // Only the space of the first frame is reused, by wrapping around
+    assert_eq!(ring.allocate_uniform(256).unwrap().1, 0);
+    assert_eq!(ring.frames_in_flight(), 1);
+    assert_eq!(ring.used(), 768);
+
+    match ring.allocate_uniform(512) {
+        Err(RingBufferError::OutOfSpace { size, available }) => {
+            assert_eq!((size, available), (512, 256))
+        }
+        _ => panic!("The second frame is still in flight"),
+    }
+    assert_eq!(ring.allocate_uniform(256).unwrap().1, 256);
+}
+
+#[test]
+fn allocations_do_not_straddle_the_end() {
+    let mut memory = vec![0u8; 1024];
+    let mut ring = ring(&mut memory);
+    ring.allocate(700, 1).unwrap();
+    ring.end_frame(vk::Fence::from_raw(1));
+    unsafe {
+        assert_eq!(ring.reclaim().unwrap(), 1);
+    }
+    // 324 bytes are left at the end, so the allocation starts at 0
+    assert_eq!(ring.allocate(400, 1).unwrap().1, 0);
+    assert_eq!(ring.used(), 724);
+    assert!(ring.allocate(2048, 1).is_err());
+}
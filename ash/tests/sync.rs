@@ -0,0 +1,200 @@
+extern crate ash;
+mod common;
+use ash::sync::{self, Misuse, SyncDevice};
+use ash::version::DeviceV1_0;
+use ash::vk::{self, Handle};
+use common::{dispatchable, QUEUE};
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// Dispatchable handles start with the loader's dispatch table pointer
+static COMMAND_BUFFERS: [usize; 2] = [1, 1];
+
+static SUBMITTING: AtomicBool = AtomicBool::new(false);
+static OVERLAPS: AtomicUsize = AtomicUsize::new(0);
+
+extern "system" fn queue_submit(
+    _: vk::Queue,
+    _: u32,
+    _: *const vk::SubmitInfo,
+    _: vk::Fence,
+) -> vk::Result {
+    if SUBMITTING.swap(true, Ordering::SeqCst) {
+        OVERLAPS.fetch_add(1, Ordering::SeqCst);
+    }
+    thread::sleep(Duration::from_millis(1));
+    SUBMITTING.store(false, Ordering::SeqCst);
+    vk::Result::SUCCESS
+}
+
+extern "system" fn allocate_command_buffers(
+    _: vk::Device,
+    _: *const vk::CommandBufferAllocateInfo,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    unsafe {
+        *p_command_buffers = dispatchable(&COMMAND_BUFFERS[0]);
+        *p_command_buffers.offset(1) = dispatchable(&COMMAND_BUFFERS[1]);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkQueueSubmit" => queue_submit as *const c_void,
+            b"vkAllocateCommandBuffers" => allocate_command_buffers as *const c_void,
+            b"vkBeginCommandBuffer" => common::begin_command_buffer as *const c_void,
+            b"vkEndCommandBuffer" => common::end_command_buffer as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+#[test]
+fn extern_sync_metadata() {
+    let submit = sync::extern_sync("vkQueueSubmit").unwrap();
+    assert_eq!(submit.params, ["queue", "fence"]);
+    assert!(submit.implicit.is_empty());
+    let draw = sync::extern_sync("vkCmdDraw").unwrap();
+    assert_eq!(draw.params, ["commandBuffer"]);
+    assert_eq!(
+        draw.implicit,
+        ["the VkCommandPool that commandBuffer was allocated from"]
+    );
+    assert_eq!(
+        sync::extern_sync("vkFreeDescriptorSets").unwrap().params,
+        ["descriptorPool", "pDescriptorSets[]"]
+    );
+    assert!(sync::extern_sync("vkCreateBuffer").is_none());
+}
+
+#[test]
+fn queue_submits_are_serialized() {
+    let device = Arc::new(SyncDevice::new(common::load_device(get_device_proc_addr)));
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let device = device.clone();
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    unsafe {
+                        device
+                            .queue_submit(dispatchable(&QUEUE), &[], vk::Fence::null())
+                            .unwrap();
+                    }
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(OVERLAPS.load(Ordering::SeqCst), 0);
+}
+
+fn allocate_command_buffers_of_one_pool<D: DeviceV1_0>(device: &D) -> Vec<vk::CommandBuffer> {
+    let allocate_info = vk::CommandBufferAllocateInfo {
+        command_pool: vk::CommandPool::from_raw(1),
+        command_buffer_count: 2,
+        ..Default::default()
+    };
+    unsafe { device.allocate_command_buffers(&allocate_info) }.unwrap()
+}
+
+#[test]
+fn recording_from_the_same_pool_on_two_threads_is_detected() {
+    let misuses = Arc::new(Mutex::new(Vec::new()));
+    let reported = misuses.clone();
+    let device = Arc::new(SyncDevice::detect_misuse(
+        common::load_device(get_device_proc_addr),
+        move |misuse: &Misuse| reported.lock().unwrap().push(misuse.clone()),
+    ));
+    let command_buffers = allocate_command_buffers_of_one_pool(&*device);
+    let begin_info = vk::CommandBufferBeginInfo::default();
+    let recording = unsafe {
+        // Recording two command buffers of a pool on one thread is fine
+        let recording = device
+            .begin_recording(command_buffers[0], &begin_info)
+            .unwrap();
+        device
+            .begin_recording(command_buffers[1], &begin_info)
+            .unwrap()
+            .end()
+            .unwrap();
+        recording
+    };
+    assert!(misuses.lock().unwrap().is_empty());
+
+    let record = {
+        let device = device.clone();
+        let command_buffer = command_buffers[1];
+        thread::spawn(move || unsafe {
+            device
+                .begin_recording(command_buffer, &vk::CommandBufferBeginInfo::default())
+                .unwrap()
+                .end()
+                .unwrap();
+        })
+    };
+    // The other thread reports the misuse, and then waits for the pool
+    while misuses.lock().unwrap().is_empty() {
+        thread::sleep(Duration::from_millis(1));
+    }
+    unsafe { recording.end() }.unwrap();
+    record.join().unwrap();
+
+    let misuses = misuses.lock().unwrap();
+    assert_eq!(
+        *misuses,
+        [Misuse {
+            command: "vkBeginCommandBuffer",
+            param: "commandPool",
+            object_type: vk::ObjectType::COMMAND_POOL,
+            handle: 1,
+            held_by: "vkBeginCommandBuffer",
+        }]
+    );
+    assert!(misuses[0]
+        .to_string()
+        .ends_with("but vkBeginCommandBuffer uses it on another thread"));
+}
+
+#[test]
+fn command_buffer_calls_only_lock_while_they_run() {
+    let misuses = Arc::new(AtomicUsize::new(0));
+    let reported = misuses.clone();
+    let device = Arc::new(SyncDevice::detect_misuse(
+        common::load_device(get_device_proc_addr),
+        move |_: &Misuse| {
+            reported.fetch_add(1, Ordering::SeqCst);
+        },
+    ));
+    let command_buffers = allocate_command_buffers_of_one_pool(&*device);
+    let begin_info = vk::CommandBufferBeginInfo::default();
+    unsafe {
+        device
+            .begin_command_buffer(command_buffers[0], &begin_info)
+            .unwrap();
+    }
+    let other = device.clone();
+    let command_buffer = command_buffers[1];
+    thread::spawn(move || unsafe {
+        other
+            .begin_command_buffer(command_buffer, &vk::CommandBufferBeginInfo::default())
+            .unwrap();
+        other.end_command_buffer(command_buffer).unwrap();
+    })
+    .join()
+    .unwrap();
+    unsafe {
+        device.end_command_buffer(command_buffers[0]).unwrap();
+    }
+    assert_eq!(misuses.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,141 @@
+extern crate ash;
+mod common;
+use ash::trace::{self, Call, TraceSink, Value};
+use ash::version::InstanceV1_0;
+use ash::vk::{self, Handle};
+use ash::Instance;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::{Arc, Mutex};
+
+// A dispatch table that no traced instance uses
+static UNTRACED_INSTANCE: usize = 2;
+// The dispatch table of the instance with a `RecordingSink`
+static RECORDED_INSTANCE: usize = 3;
+
+#[derive(Debug, PartialEq)]
+struct DestroyInstance;
+
+extern "system" fn enumerate_physical_devices(
+    _instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    unsafe {
+        if !p_physical_devices.is_null() {
+            *p_physical_devices = vk::PhysicalDevice::from_raw(42);
+        }
+        *p_physical_device_count = 1;
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn destroy_instance(_: vk::Instance, _: *const vk::AllocationCallbacks) -> c_void {
+    common::record(DestroyInstance);
+    unsafe { mem::zeroed() }
+}
+
+extern "system" fn get_instance_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkEnumeratePhysicalDevices" => enumerate_physical_devices as *const c_void,
+            b"vkDestroyInstance" => destroy_instance as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn traced_instance() -> (Instance, Arc<Mutex<Vec<String>>>) {
+    let instance = common::dispatchable(&common::INSTANCE);
+    let static_fn = vk::StaticFn {
+        get_instance_proc_addr,
+    };
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let sink_calls = calls.clone();
+    let instance = unsafe {
+        trace::trace_instance(&static_fn, instance, move |call: &Call| {
+            sink_calls.lock().unwrap().push(call.to_string())
+        })
+    };
+    (instance, calls)
+}
+
+#[test]
+fn traced_calls_reach_the_driver_and_the_sink() {
+    let (instance, calls) = traced_instance();
+
+    let physical_devices = unsafe { instance.enumerate_physical_devices() }.unwrap();
+    assert_eq!(physical_devices, vec![vk::PhysicalDevice::from_raw(42)]);
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 2);
+    assert!(calls[0].starts_with("vkEnumeratePhysicalDevices(instance: "));
+    assert!(calls[1].contains(") -> Command completed successfully ("));
+}
+
+#[test]
+fn calls_through_untraced_handles_fail_without_unwinding() {
+    let (instance, calls) = traced_instance();
+    let untraced: vk::Instance = common::dispatchable(&UNTRACED_INSTANCE);
+    let fp = instance.fp_v1_0();
+    let mut count = 0;
+    unsafe {
+        assert_eq!(
+            fp.enumerate_physical_devices(untraced, &mut count, ptr::null_mut()),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        assert_eq!(
+            fp.enumerate_physical_devices(vk::Instance::null(), &mut count, ptr::null_mut()),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        // Commands without a result do nothing
+        fp.destroy_instance(untraced, ptr::null());
+    }
+    assert_eq!(count, 0);
+    assert!(common::take_calls::<DestroyInstance>().is_empty());
+    assert!(calls.lock().unwrap().is_empty());
+}
+
+/// Formats the output array of `vkEnumeratePhysicalDevices` before and after the call.
+struct RecordingSink(Arc<Mutex<Vec<String>>>);
+
+impl TraceSink for RecordingSink {
+    fn before_call(&self, _command: &'static str, args: &[(&'static str, &dyn Value)]) {
+        self.0.lock().unwrap().push(format!("{:?}", args[2].1));
+    }
+
+    fn call(&self, call: &Call) {
+        self.0.lock().unwrap().push(format!("{:?}", call.args[2].1));
+    }
+}
+
+#[test]
+fn outputs_are_pending_before_the_call() {
+    let instance = common::dispatchable(&RECORDED_INSTANCE);
+    let static_fn = vk::StaticFn {
+        get_instance_proc_addr,
+    };
+    let formatted = Arc::new(Mutex::new(Vec::new()));
+    let instance =
+        unsafe { trace::trace_instance(&static_fn, instance, RecordingSink(formatted.clone())) };
+    let mut count = 1;
+    let mut physical_device = vk::PhysicalDevice::null();
+    unsafe {
+        instance.fp_v1_0().enumerate_physical_devices(
+            instance.handle(),
+            &mut count,
+            &mut physical_device,
+        );
+    }
+    assert_eq!(
+        *formatted.lock().unwrap(),
+        vec![
+            "<pending>".to_string(),
+            format!("{:?}", [vk::PhysicalDevice::from_raw(42)]),
+        ]
+    );
+}
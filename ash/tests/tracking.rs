@@ -0,0 +1,269 @@
+extern crate ash;
+extern crate shared_library;
+mod common;
+use ash::extensions::ext::DebugUtils;
+use ash::extensions::khr::{DisplaySwapchain, Surface, Swapchain, XlibSurface};
+use ash::tracking::{HandleTracker, TrackingDevice, TrackingInstance};
+use ash::version::{DeviceV1_0, InstanceV1_0};
+use ash::vk::{self, Handle};
+use ash::Entry;
+use shared_library::dynamic_library::DynamicLibrary;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, PartialEq)]
+struct SetObjectName(vk::ObjectType, u64, String);
+
+extern "system" fn destroy_dispatchable(_: u64, _: *const vk::AllocationCallbacks) -> c_void {
+    unsafe { mem::zeroed() }
+}
+
+// The swapchains are numbered from 2, after the one that `create_handle` creates
+extern "system" fn create_shared_swapchains(
+    _: vk::Device,
+    swapchain_count: u32,
+    _: *const vk::SwapchainCreateInfoKHR,
+    _: *const vk::AllocationCallbacks,
+    p_swapchains: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    for i in 0..swapchain_count {
+        unsafe {
+            *p_swapchains.offset(i as isize) = vk::SwapchainKHR::from_raw(u64::from(i) + 2);
+        }
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn set_debug_utils_object_name(
+    _: vk::Device,
+    p_name_info: *const vk::DebugUtilsObjectNameInfoEXT,
+) -> vk::Result {
+    unsafe {
+        let name_info = &*p_name_info;
+        let name = CStr::from_ptr(name_info.p_object_name);
+        common::record(SetObjectName(
+            name_info.object_type,
+            name_info.object_handle,
+            name.to_str().unwrap().to_owned(),
+        ));
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn create_device(
+    _: vk::PhysicalDevice,
+    _: *const vk::DeviceCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    unsafe {
+        *p_device = common::dispatchable(&common::DEVICE);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkDestroyDevice" => destroy_dispatchable as *const c_void,
+            b"vkCreateBuffer" | b"vkCreateSwapchainKHR" => common::create_handle as *const c_void,
+            b"vkDestroyBuffer" | b"vkDestroySwapchainKHR" => {
+                common::destroy_handle as *const c_void
+            }
+            b"vkCreateSharedSwapchainsKHR" => create_shared_swapchains as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+extern "system" fn get_instance_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkGetDeviceProcAddr" => get_device_proc_addr as *const c_void,
+            b"vkCreateDevice" => create_device as *const c_void,
+            b"vkDestroyInstance" => destroy_dispatchable as *const c_void,
+            b"vkCreateXlibSurfaceKHR" | b"vkCreateDebugUtilsMessengerEXT" => {
+                common::create_handle as *const c_void
+            }
+            b"vkDestroySurfaceKHR" | b"vkDestroyDebugUtilsMessengerEXT" => {
+                common::destroy_handle as *const c_void
+            }
+            b"vkSetDebugUtilsObjectNameEXT" => set_debug_utils_object_name as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+// The library is the test binary itself, the commands are looked up in the fakes above
+fn entry() -> Entry {
+    Entry::new_custom(
+        || Ok(Arc::new(DynamicLibrary::open(None).unwrap())),
+        |_, name| match name.to_bytes() {
+            b"vkGetInstanceProcAddr" => get_instance_proc_addr as *const c_void,
+            _ => ptr::null(),
+        },
+    )
+    .unwrap()
+}
+
+fn tracking_device() -> TrackingDevice {
+    TrackingDevice::new(common::load_device(get_device_proc_addr))
+}
+
+fn buffer(device: &TrackingDevice) -> vk::Buffer {
+    unsafe { device.create_buffer(&vk::BufferCreateInfo::default(), None) }.unwrap()
+}
+
+#[test]
+fn leak_report_groups_by_object_type() {
+    let tracker = HandleTracker::new();
+    let pool = vk::CommandPool::from_raw(1);
+    tracker.track(pool);
+    tracker.track(vk::ImageView::from_raw(2));
+    tracker.track(vk::ImageView::from_raw(3));
+    tracker.track_child(vk::CommandBuffer::from_raw(4), pool);
+    tracker.set_object_name(vk::ImageView::from_raw(3), "swapchain view");
+
+    assert!(tracker.untrack(vk::ImageView::from_raw(2)));
+    assert!(!tracker.untrack(vk::ImageView::from_raw(2)));
+
+    let report = tracker.leak_report();
+    assert_eq!(report.len(), 3);
+    assert_eq!(report.count(vk::ObjectType::IMAGE_VIEW), 1);
+    assert_eq!(report.count(vk::ObjectType::COMMAND_BUFFER), 1);
+    assert!(report.to_string().contains("\"swapchain view\""));
+
+    tracker.untrack_children::<vk::CommandBuffer, _>(pool);
+    tracker.untrack(pool);
+    tracker.untrack(vk::ImageView::from_raw(3));
+    assert!(tracker.leak_report().is_empty());
+}
+
+#[test]
+fn leaks_are_only_reported_to_the_callback() {
+    let device = tracking_device();
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = reported.clone();
+    device.set_leak_callback(move |report| sink.lock().unwrap().push(report.len()));
+    buffer(&device);
+    unsafe { device.destroy_device(None) };
+    assert_eq!(*reported.lock().unwrap(), [1]);
+
+    // Nothing is reported if there are no leaks
+    let device = tracking_device();
+    let sink = reported.clone();
+    device.set_leak_callback(move |report| sink.lock().unwrap().push(report.len()));
+    unsafe {
+        device.destroy_buffer(buffer(&device), None);
+        device.destroy_device(None);
+    }
+    assert_eq!(*reported.lock().unwrap(), [1]);
+}
+
+#[test]
+fn devices_inherit_the_leak_callback_of_their_instance() {
+    let instance = TrackingInstance::new(common::load_instance(get_instance_proc_addr));
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = reported.clone();
+    instance.set_leak_callback(move |report| sink.lock().unwrap().push(report.len()));
+    unsafe {
+        let device = instance
+            .create_device(
+                common::dispatchable(&common::PHYSICAL_DEVICE),
+                &Default::default(),
+                None,
+            )
+            .unwrap();
+        buffer(&device);
+        device.destroy_device(None);
+    }
+    assert_eq!(*reported.lock().unwrap(), [1]);
+}
+
+#[test]
+fn destroying_the_device_returns_the_leaks() {
+    let device = tracking_device();
+    buffer(&device);
+    let report = unsafe { device.destroy_device_and_report(None) };
+    assert_eq!(report.len(), 1);
+    assert_eq!(report.count(vk::ObjectType::BUFFER), 1);
+}
+
+#[test]
+fn extension_handles_are_tracked() {
+    let entry = entry();
+    let instance = TrackingInstance::new(common::load_instance(get_instance_proc_addr));
+    let device = tracking_device();
+    let swapchain_loader = Swapchain::new(instance.instance(), device.device());
+    let display_swapchain_loader = DisplaySwapchain::new(instance.instance(), device.device());
+    let create_info = vk::SwapchainCreateInfoKHR {
+        surface: vk::SurfaceKHR::from_raw(1),
+        ..Default::default()
+    };
+    unsafe {
+        let swapchain = device
+            .create_swapchain(&swapchain_loader, &create_info, None)
+            .unwrap();
+        let create_infos = [create_info; 2];
+        let shared = device
+            .create_shared_swapchains(&display_swapchain_loader, &create_infos, None)
+            .unwrap();
+        assert_eq!(device.leak_report().count(vk::ObjectType::SWAPCHAIN_KHR), 3);
+        device.destroy_swapchain(&swapchain_loader, swapchain, None);
+        device.destroy_swapchain(&swapchain_loader, shared[0], None);
+        let report = device.destroy_device_and_report(None);
+        assert_eq!(report.count(vk::ObjectType::SWAPCHAIN_KHR), 1);
+
+        let surface_loader = Surface::new(&entry, instance.instance());
+        let xlib_surface_loader = XlibSurface::new(&entry, instance.instance());
+        let debug_utils = DebugUtils::new(&entry, instance.instance());
+        let surface = instance
+            .create_xlib_surface(&xlib_surface_loader, &Default::default(), None)
+            .unwrap();
+        instance
+            .create_debug_utils_messenger(&debug_utils, &Default::default(), None)
+            .unwrap();
+        let report = instance.leak_report();
+        assert_eq!(report.count(vk::ObjectType::SURFACE_KHR), 1);
+        assert_eq!(report.count(vk::ObjectType::DEBUG_UTILS_MESSENGER_EXT), 1);
+
+        instance.destroy_surface(&surface_loader, surface, None);
+        let report = instance.destroy_instance_and_report(None);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.count(vk::ObjectType::DEBUG_UTILS_MESSENGER_EXT), 1);
+    }
+}
+
+#[test]
+fn object_names_are_passed_to_debug_utils() {
+    let instance = common::load_instance(get_instance_proc_addr);
+    let mut device = tracking_device();
+    let buffer = buffer(&device);
+    unsafe {
+        device.set_object_name(buffer, "vertices").unwrap();
+        assert!(common::take_calls::<SetObjectName>().is_empty());
+
+        device.set_debug_utils(DebugUtils::new(&entry(), &instance));
+        device.set_object_name(buffer, "indices\0ignored").unwrap();
+    }
+    assert_eq!(
+        common::take_calls::<SetObjectName>(),
+        [SetObjectName(
+            vk::ObjectType::BUFFER,
+            buffer.as_raw(),
+            "indices".to_owned()
+        )]
+    );
+    let report = device.leak_report();
+    let name = report.groups[0].1[0].name.as_ref().unwrap();
+    assert_eq!(name, "indices\0ignored");
+}
@@ -0,0 +1,513 @@
+extern crate ash;
+mod common;
+use ash::util::{ImageRegion, UploadBatch, Uploader};
+use ash::vk::{self, Handle};
+use common::{dispatchable, record, take_calls, QUEUE};
+use std::cell::Cell;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+
+const TRANSFER_FAMILY: u32 = 1;
+const GRAPHICS_FAMILY: u32 = 0;
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    Barrier {
+        src_stage: vk::PipelineStageFlags,
+        dst_stage: vk::PipelineStageFlags,
+        buffers: Vec<(vk::Buffer, u32, u32)>,
+        images: Vec<(
+            vk::Image,
+            vk::ImageAspectFlags,
+            vk::ImageLayout,
+            vk::ImageLayout,
+            u32,
+            u32,
+            u32,
+            u32,
+        )>,
+    },
+    CopyBuffer(vk::Buffer, u64, Vec<u8>),
+    CopyBufferToImage(vk::Image, vk::ImageAspectFlags, u32, u32, u32, Vec<u8>),
+    Submit(u32),
+    FreeMemory,
+}
+
+thread_local! {
+    static BUFFER_SIZE: Cell<u64> = Cell::new(0);
+    static COMPLETE: Cell<bool> = Cell::new(false);
+}
+
+unsafe fn staged(offset: u64, len: usize) -> Vec<u8> {
+    slice::from_raw_parts(common::mapped().add(offset as usize), len).to_vec()
+}
+
+extern "system" fn create_buffer(
+    _: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    unsafe {
+        BUFFER_SIZE.with(|size| size.set((*p_create_info).size));
+        *p_buffer = vk::Buffer::from_raw(100);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_buffer_memory_requirements(
+    _: vk::Device,
+    _: vk::Buffer,
+    p_requirements: *mut vk::MemoryRequirements,
+) -> c_void {
+    unsafe {
+        *p_requirements = vk::MemoryRequirements {
+            size: BUFFER_SIZE.with(|size| size.get()),
+            alignment: 1,
+            memory_type_bits: 0b11,
+        };
+        mem::zeroed()
+    }
+}
+
+// Staging memory must be host visible
+extern "system" fn allocate_memory(
+    device: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    unsafe {
+        assert_eq!((*p_allocate_info).memory_type_index, 1);
+    }
+    common::allocate_memory(device, p_allocate_info, p_allocator, p_memory)
+}
+
+extern "system" fn free_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_allocator: *const vk::AllocationCallbacks,
+) -> c_void {
+    record(Call::FreeMemory);
+    common::free_memory(device, memory, p_allocator)
+}
+
+extern "system" fn cmd_pipeline_barrier(
+    _: vk::CommandBuffer,
+    src_stage: vk::PipelineStageFlags,
+    dst_stage: vk::PipelineStageFlags,
+    _: vk::DependencyFlags,
+    _: u32,
+    _: *const vk::MemoryBarrier,
+    buffer_barrier_count: u32,
+    p_buffer_barriers: *const vk::BufferMemoryBarrier,
+    image_barrier_count: u32,
+    p_image_barriers: *const vk::ImageMemoryBarrier,
+) -> c_void {
+    unsafe {
+        let buffer_barriers = slice::from_raw_parts(p_buffer_barriers, buffer_barrier_count as _);
+        let image_barriers = slice::from_raw_parts(p_image_barriers, image_barrier_count as _);
+        record(Call::Barrier {
+            src_stage,
+            dst_stage,
+            buffers: buffer_barriers
+                .iter()
+                .map(|b| (b.buffer, b.src_queue_family_index, b.dst_queue_family_index))
+                .collect(),
+            images: image_barriers
+                .iter()
+                .map(|b| {
+                    let range = b.subresource_range;
+                    (
+                        b.image,
+                        range.aspect_mask,
+                        b.old_layout,
+                        b.new_layout,
+                        b.dst_queue_family_index,
+                        range.base_mip_level,
+                        range.level_count,
+                        range.layer_count,
+                    )
+                })
+                .collect(),
+        });
+        mem::zeroed()
+    }
+}
+
+extern "system" fn cmd_copy_buffer(
+    _: vk::CommandBuffer,
+    _: vk::Buffer,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferCopy,
+) -> c_void {
+    unsafe {
+        for region in slice::from_raw_parts(p_regions, region_count as usize) {
+            let data = staged(region.src_offset, region.size as usize);
+            record(Call::CopyBuffer(dst_buffer, region.dst_offset, data));
+        }
+        mem::zeroed()
+    }
+}
+
+// The test images have 4 bytes per texel
+extern "system" fn cmd_copy_buffer_to_image(
+    _: vk::CommandBuffer,
+    _: vk::Buffer,
+    dst_image: vk::Image,
+    _: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) -> c_void {
+    unsafe {
+        for region in slice::from_raw_parts(p_regions, region_count as usize) {
+            assert_eq!(region.buffer_offset % 4, 0);
+            let layers = region.image_subresource;
+            let extent = region.image_extent;
+            let len = extent.width * extent.height * extent.depth * layers.layer_count * 4;
+            record(Call::CopyBufferToImage(
+                dst_image,
+                layers.aspect_mask,
+                layers.mip_level,
+                layers.base_array_layer,
+                layers.layer_count,
+                staged(region.buffer_offset, len as usize),
+            ));
+        }
+        mem::zeroed()
+    }
+}
+
+extern "system" fn queue_submit(
+    _: vk::Queue,
+    _: u32,
+    p_submits: *const vk::SubmitInfo,
+    _: vk::Fence,
+) -> vk::Result {
+    unsafe {
+        record(Call::Submit((*p_submits).signal_semaphore_count));
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_fence_status(_: vk::Device, _: vk::Fence) -> vk::Result {
+    if COMPLETE.with(|complete| complete.get()) {
+        vk::Result::SUCCESS
+    } else {
+        vk::Result::NOT_READY
+    }
+}
+
+extern "system" fn wait_for_fences(
+    _: vk::Device,
+    _: u32,
+    _: *const vk::Fence,
+    _: vk::Bool32,
+    _: u64,
+) -> vk::Result {
+    COMPLETE.with(|complete| complete.set(true));
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkCreateCommandPool" | b"vkCreateFence" => common::create_handle as *const c_void,
+            b"vkDestroyCommandPool" | b"vkDestroyFence" | b"vkDestroyBuffer" => {
+                common::destroy_handle as *const c_void
+            }
+            b"vkCreateBuffer" => create_buffer as *const c_void,
+            b"vkGetBufferMemoryRequirements" => get_buffer_memory_requirements as *const c_void,
+            b"vkAllocateMemory" => allocate_memory as *const c_void,
+            b"vkFreeMemory" => free_memory as *const c_void,
+            b"vkBindBufferMemory" => common::bind_memory as *const c_void,
+            b"vkMapMemory" => common::map_memory as *const c_void,
+            b"vkAllocateCommandBuffers" => common::allocate_command_buffers as *const c_void,
+            b"vkFreeCommandBuffers" => common::free_command_buffers as *const c_void,
+            b"vkBeginCommandBuffer" => common::begin_command_buffer as *const c_void,
+            b"vkEndCommandBuffer" => common::end_command_buffer as *const c_void,
+            b"vkCmdPipelineBarrier" => cmd_pipeline_barrier as *const c_void,
+            b"vkCmdCopyBuffer" => cmd_copy_buffer as *const c_void,
+            b"vkCmdCopyBufferToImage" => cmd_copy_buffer_to_image as *const c_void,
+            b"vkQueueSubmit" => queue_submit as *const c_void,
+            b"vkGetFenceStatus" => get_fence_status as *const c_void,
+            b"vkWaitForFences" => wait_for_fences as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+fn uploader() -> Uploader {
+    // Only the second memory type is host visible
+    let mut memory_properties = vk::PhysicalDeviceMemoryProperties {
+        memory_type_count: 2,
+        memory_heap_count: 1,
+        ..Default::default()
+    };
+    memory_properties.memory_types[0].property_flags = vk::MemoryPropertyFlags::DEVICE_LOCAL;
+    memory_properties.memory_types[1].property_flags =
+        vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT;
+    unsafe {
+        Uploader::new(
+            common::load_device(get_device_proc_addr),
+            memory_properties,
+            dispatchable(&QUEUE),
+            TRANSFER_FAMILY,
+        )
+        .unwrap()
+    }
+}
+
+fn region<'a>(mip_level: u32, size: u32, layer_count: u32, data: &'a [u8]) -> ImageRegion<'a> {
+    ImageRegion {
+        aspect_mask: vk::ImageAspectFlags::COLOR,
+        mip_level,
+        base_array_layer: 0,
+        layer_count,
+        extent: vk::Extent3D {
+            width: size,
+            height: size,
+            depth: 1,
+        },
+        data,
+    }
+}
+
+#[test]
+fn mips_and_buffers_share_one_staging_buffer() {
+    let mut uploader = uploader();
+    let buffer = vk::Buffer::from_raw(1);
+    let image = vk::Image::from_raw(2);
+    let mip0 = [1u8; 4 * 4 * 4 * 2];
+    let mip2 = [2u8; 4 * 2];
+    let mut batch = UploadBatch::new();
+    batch.buffer(buffer, 8, &[3u16, 4]).image(
+        image,
+        vk::ImageAspectFlags::COLOR,
+        vk::ImageLayout::UNDEFINED,
+        vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+        &[region(0, 4, 2, &mip0), region(2, 1, 2, &mip2)],
+    );
+    unsafe {
+        let token = uploader.submit(&batch, None, None).unwrap();
+        assert!(token.image_acquire_barriers().is_empty());
+        assert!(!uploader.is_complete(&token).unwrap());
+        assert_eq!(
+            take_calls::<Call>(),
+            vec![
+                Call::Barrier {
+                    src_stage: vk::PipelineStageFlags::TOP_OF_PIPE,
+                    dst_stage: vk::PipelineStageFlags::TRANSFER,
+                    buffers: vec![],
+                    images: vec![
+                        (
+                            image,
+                            vk::ImageAspectFlags::COLOR,
+                            vk::ImageLayout::UNDEFINED,
+                            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                            vk::QUEUE_FAMILY_IGNORED,
+                            0,
+                            1,
+                            2,
+                        ),
+                        (
+                            image,
+                            vk::ImageAspectFlags::COLOR,
+                            vk::ImageLayout::UNDEFINED,
+                            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                            vk::QUEUE_FAMILY_IGNORED,
+                            2,
+                            1,
+                            2,
+                        ),
+                    ],
+                },
+                Call::CopyBuffer(buffer, 8, vec![3, 0, 4, 0]),
+                Call::CopyBufferToImage(image, vk::ImageAspectFlags::COLOR, 0, 0, 2, mip0.to_vec()),
+                Call::CopyBufferToImage(image, vk::ImageAspectFlags::COLOR, 2, 0, 2, mip2.to_vec()),
+                Call::Barrier {
+                    src_stage: vk::PipelineStageFlags::TRANSFER,
+                    dst_stage: vk::PipelineStageFlags::ALL_COMMANDS,
+                    buffers: vec![(buffer, vk::QUEUE_FAMILY_IGNORED, vk::QUEUE_FAMILY_IGNORED)],
+                    images: vec![
+                        (
+                            image,
+                            vk::ImageAspectFlags::COLOR,
+                            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                            vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+                            vk::QUEUE_FAMILY_IGNORED,
+                            0,
+                            1,
+                            2,
+                        ),
+                        (
+                            image,
+                            vk::ImageAspectFlags::COLOR,
+                            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                            vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+                            vk::QUEUE_FAMILY_IGNORED,
+                            2,
+                            1,
+                            2,
+                        ),
+                    ],
+                },
+                Call::Submit(0),
+            ]
+        );
+        uploader.wait(token).unwrap();
+    }
+    assert_eq!(take_calls::<Call>(), vec![Call::FreeMemory]);
+}
+
+#[test]
+fn ownership_is_released_to_the_graphics_family() {
+    let mut uploader = uploader();
+    let buffer = vk::Buffer::from_raw(1);
+    let mut batch = UploadBatch::new();
+    batch.buffer(buffer, 0, &[1u32]);
+    unsafe {
+        let token = uploader
+            .submit(
+                &batch,
+                Some(GRAPHICS_FAMILY),
+                Some(vk::Semaphore::from_raw(5)),
+            )
+            .unwrap();
+        let acquire = token.buffer_acquire_barriers();
+        assert_eq!(acquire.len(), 1);
+        assert_eq!(
+            (
+                acquire[0].buffer,
+                acquire[0].src_queue_family_index,
+                acquire[0].dst_queue_family_index,
+                acquire[0].size,
+            ),
+            (buffer, TRANSFER_FAMILY, GRAPHICS_FAMILY, 4)
+        );
+        let calls = take_calls::<Call>();
+        assert_eq!(
+            calls[1],
+            Call::Barrier {
+                src_stage: vk::PipelineStageFlags::TRANSFER,
+                dst_stage: vk::PipelineStageFlags::BOTTOM_OF_PIPE,
+                buffers: vec![(buffer, TRANSFER_FAMILY, GRAPHICS_FAMILY)],
+                images: vec![],
+            }
+        );
+        assert_eq!(calls[2], Call::Submit(1));
+
+        assert_eq!(uploader.collect().unwrap(), 1);
+        COMPLETE.with(|complete| complete.set(true));
+        assert!(uploader.is_complete(&token).unwrap());
+        assert_eq!(uploader.collect().unwrap(), 0);
+    }
+    assert_eq!(take_calls::<Call>(), vec![Call::FreeMemory]);
+}
+
+#[test]
+fn depth_and_stencil_are_copied_separately_and_transitioned_together() {
+    let mut uploader = uploader();
+    let image = vk::Image::from_raw(3);
+    let depth_stencil = vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL;
+    let depth = [1u8; 4 * 2 * 2];
+    let stencil = [2u8; 4 * 2 * 2];
+    let mut batch = UploadBatch::new();
+    batch.image(
+        image,
+        depth_stencil,
+        vk::ImageLayout::UNDEFINED,
+        vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+        &[
+            ImageRegion {
+                aspect_mask: vk::ImageAspectFlags::DEPTH,
+                ..region(0, 2, 1, &depth)
+            },
+            ImageRegion {
+                aspect_mask: vk::ImageAspectFlags::STENCIL,
+                ..region(0, 2, 1, &stencil)
+            },
+        ],
+    );
+    unsafe {
+        let token = uploader.submit(&batch, None, None).unwrap();
+        assert_eq!(
+            take_calls::<Call>(),
+            vec![
+                Call::Barrier {
+                    src_stage: vk::PipelineStageFlags::TOP_OF_PIPE,
+                    dst_stage: vk::PipelineStageFlags::TRANSFER,
+                    buffers: vec![],
+                    images: vec![(
+                        image,
+                        depth_stencil,
+                        vk::ImageLayout::UNDEFINED,
+                        vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                        vk::QUEUE_FAMILY_IGNORED,
+                        0,
+                        1,
+                        1,
+                    )],
+                },
+                Call::CopyBufferToImage(
+                    image,
+                    vk::ImageAspectFlags::DEPTH,
+                    0,
+                    0,
+                    1,
+                    depth.to_vec()
+                ),
+                Call::CopyBufferToImage(
+                    image,
+                    vk::ImageAspectFlags::STENCIL,
+                    0,
+                    0,
+                    1,
+                    stencil.to_vec()
+                ),
+                Call::Barrier {
+                    src_stage: vk::PipelineStageFlags::TRANSFER,
+                    dst_stage: vk::PipelineStageFlags::ALL_COMMANDS,
+                    buffers: vec![],
+                    images: vec![(
+                        image,
+                        depth_stencil,
+                        vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+                        vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+                        vk::QUEUE_FAMILY_IGNORED,
+                        0,
+                        1,
+                        1,
+                    )],
+                },
+                Call::Submit(0),
+            ]
+        );
+        uploader.wait(token).unwrap();
+    }
+    assert_eq!(take_calls::<Call>(), vec![Call::FreeMemory]);
+}
+
+#[test]
+fn empty_buffer_uploads_are_skipped() {
+    let mut uploader = uploader();
+    let empty: &[u32] = &[];
+    let mut batch = UploadBatch::new();
+    batch.buffer(vk::Buffer::from_raw(1), 0, empty);
+    assert!(batch.is_empty());
+    unsafe {
+        let token = uploader
+            .submit(&batch, Some(GRAPHICS_FAMILY), None)
+            .unwrap();
+        assert!(token.buffer_acquire_barriers().is_empty());
+        assert_eq!(take_calls::<Call>(), vec![Call::Submit(0)]);
+        uploader.wait(token).unwrap();
+    }
+    assert_eq!(take_calls::<Call>(), vec![Call::FreeMemory]);
+}
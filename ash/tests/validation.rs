@@ -0,0 +1,136 @@
+#![cfg(feature = "validation")]
+extern crate ash;
+mod common;
+use ash::validation::{self, Violation};
+use ash::version::DeviceV1_0;
+use ash::vk::{self, Handle};
+use common::dispatchable;
+use std::cell::RefCell;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+// The dispatch table pointer of handles of another device
+static OTHER_DISPATCH_TABLE: usize = 2;
+
+extern "system" fn create_buffer(
+    _device: vk::Device,
+    _p_create_info: *const vk::BufferCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    unsafe {
+        *p_buffer = vk::Buffer::from_raw(1);
+    }
+    vk::Result::SUCCESS
+}
+
+extern "system" fn cmd_draw(_: vk::CommandBuffer, _: u32, _: u32, _: u32, _: u32) {}
+
+extern "system" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        common::proc_addr(p_name, |name| match name {
+            b"vkCreateBuffer" => create_buffer as *const c_void,
+            b"vkCreatePipelineLayout" => common::create_handle as *const c_void,
+            b"vkCmdDraw" => cmd_draw as *const c_void,
+            _ => ptr::null(),
+        })
+    }
+}
+
+thread_local! {
+    static VIOLATIONS: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+// The callback is shared by all tests, but the calls are checked on the calling thread
+fn record_violations() {
+    validation::set_callback(|violation: &Violation| {
+        VIOLATIONS.with(|violations| violations.borrow_mut().push(violation.message.clone()))
+    });
+}
+
+fn take_violations() -> Vec<String> {
+    VIOLATIONS.with(|violations| violations.borrow_mut().drain(..).collect())
+}
+
+#[test]
+fn violations_are_reported_before_the_call() {
+    record_violations();
+    let device = common::load_device(get_device_proc_addr);
+
+    let dedicated = vk::MemoryDedicatedAllocateInfo::default();
+    let create_info = vk::BufferCreateInfo {
+        s_type: vk::StructureType::IMAGE_CREATE_INFO,
+        p_next: &dedicated as *const vk::MemoryDedicatedAllocateInfo as *const c_void,
+        // The queue family indices are ignored unless the sharing mode is concurrent
+        queue_family_index_count: 2,
+        ..Default::default()
+    };
+    let buffer = unsafe { device.create_buffer(&create_info, None) }.unwrap();
+    assert_eq!(buffer, vk::Buffer::from_raw(1));
+    assert_eq!(
+        take_violations(),
+        [
+            "BufferCreateInfo.s_type is IMAGE_CREATE_INFO, but must be BUFFER_CREATE_INFO",
+            "MEMORY_DEDICATED_ALLOCATE_INFO can not extend BUFFER_CREATE_INFO",
+        ]
+    );
+
+    unsafe {
+        device.cmd_draw(dispatchable(&common::COMMAND_BUFFER), 3, 1, 0, 0);
+        device.cmd_draw(dispatchable(&OTHER_DISPATCH_TABLE), 3, 1, 0, 0);
+    }
+    let violations = take_violations();
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].starts_with("command_buffer"));
+    assert!(violations[0].contains("does not belong to DEVICE"));
+}
+
+#[test]
+fn valid_calls_are_not_reported() {
+    record_violations();
+    let device = common::load_device(get_device_proc_addr);
+    let queue_family_indices = [0, 1];
+    let create_info = vk::BufferCreateInfo::builder()
+        .size(64)
+        .usage(vk::BufferUsageFlags::VERTEX_BUFFER)
+        .sharing_mode(vk::SharingMode::CONCURRENT)
+        .queue_family_indices(&queue_family_indices);
+    unsafe {
+        device.create_buffer(&create_info, None).unwrap();
+        device.cmd_draw(dispatchable(&common::COMMAND_BUFFER), 3, 1, 0, 0);
+    }
+    assert!(take_violations().is_empty());
+}
+
+#[test]
+fn null_arrays_and_handles_are_reported() {
+    record_violations();
+    let device = common::load_device(get_device_proc_addr);
+    let create_info = vk::PipelineLayoutCreateInfo {
+        set_layout_count: 2,
+        ..Default::default()
+    };
+    unsafe {
+        device.create_pipeline_layout(&create_info, None).unwrap();
+        device.cmd_draw(vk::CommandBuffer::null(), 3, 1, 0, 0);
+    }
+    assert_eq!(
+        take_violations(),
+        [
+            "PipelineLayoutCreateInfo.p_set_layouts is null, but its length is 2",
+            "command_buffer is a null COMMAND_BUFFER",
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "vkCmdDraw: command_buffer is a null COMMAND_BUFFER")]
+fn panicking_is_opt_in() {
+    validation::panic_on_violation(&Violation {
+        command: "vkCmdDraw",
+        message: "command_buffer is a null COMMAND_BUFFER".to_owned(),
+    });
+}
@@ -706,7 +706,7 @@ fn main() {
         let graphic_pipeline = graphics_pipelines[0];
 
         base.render_loop(|| {
-            let (present_index, _) = base
+            let present_index = base
                 .swapchain_loader
                 .acquire_next_image(
                     base.swapchain,
@@ -714,7 +714,9 @@ fn main() {
                     base.present_complete_semaphore,
                     vk::Fence::null(),
                 )
-                .unwrap();
+                .unwrap()
+                .image_index()
+                .expect("No swapchain image was acquired");
             let clear_values = [
                 vk::ClearValue {
                     color: vk::ClearColorValue {
@@ -567,6 +567,8 @@ pub trait FieldExt {
 
     /// Returns the basetype ident and removes the 'Vk' prefix
     fn type_tokens(&self) -> Tokens;
+    /// Like `type_tokens`, but static arrays are passed as a pointer, as in C
+    fn param_type_tokens(&self) -> Tokens;
     fn is_clone(&self) -> bool;
 }
 
@@ -673,6 +675,15 @@ impl FieldExt for vkxml::Field {
         });
         array.unwrap_or(pointer_ty)
     }
+
+    fn param_type_tokens(&self) -> Tokens {
+        let ty = self.type_tokens();
+        if is_static_array(self) {
+            quote! {*const #ty}
+        } else {
+            ty
+        }
+    }
 }
 
 pub type CommandMap<'a> = HashMap<vkxml::Identifier, &'a vkxml::Command>;
@@ -716,7 +727,7 @@ fn generate_function_pointers<'a>(
                 .iter()
                 .map(|field| {
                     let name = field.param_ident();
-                    let ty = field.type_tokens();
+                    let ty = field.param_type_tokens();
                     (name, ty)
                 })
                 .collect();
@@ -779,7 +790,7 @@ fn generate_function_pointers<'a>(
                 .iter()
                 .map(|field| {
                     let name = field.param_ident();
-                    let ty = field.type_tokens();
+                    let ty = field.param_type_tokens();
                     quote! { #name: #ty }
                 })
                 .collect();
@@ -848,6 +859,1680 @@ fn generate_function_pointers<'a>(
         }
     }
 }
+/// Types of the window system and the OS, which are passed through as they are.
+const PLATFORM_TYPES: &[&str] = &[
+    "Display",
+    "Window",
+    "VisualID",
+    "RROutput",
+    "xcb_connection_t",
+    "xcb_window_t",
+    "xcb_visualid_t",
+    "MirConnection",
+    "MirSurface",
+    "HINSTANCE",
+    "HWND",
+    "HANDLE",
+    "DWORD",
+    "LPCWSTR",
+    "SECURITY_ATTRIBUTES",
+    "wl_display",
+    "wl_surface",
+    "zx_handle_t",
+    "ANativeWindow",
+    "AHardwareBuffer",
+];
+
+/// How the trace shims and the capture codecs treat a parameter or a struct member.
+#[derive(Clone)]
+pub enum ParamKind {
+    Value,
+    /// Callbacks and user data, which are not captured.
+    Skip,
+    PNext,
+    Str,
+    StrArray(Tokens),
+    Bytes(Tokens),
+    Array(Tokens),
+    Ptr,
+    UnionPtr,
+    Allocator,
+    Out,
+    OutArray(Tokens),
+    OutBytes(Tokens),
+}
+
+fn is_platform_type(basetype: &str) -> bool {
+    PLATFORM_TYPES.contains(&basetype)
+}
+
+/// The length of `field`, if it is an array whose length is another member.
+fn member_len(_struct: &vkxml::Struct, field: &vkxml::Field) -> Option<Tokens> {
+    let name = field.name.as_ref().map(|s| s.as_str()).unwrap_or("");
+    match (_struct.name.as_str(), name) {
+        ("VkShaderModuleCreateInfo", "pCode") => return Some(quote! {self.code_size / 4}),
+        ("VkPipelineMultisampleStateCreateInfo", "pSampleMask") => {
+            return Some(quote! {(self.rasterization_samples.as_raw() as usize + 31) / 32})
+        }
+        _ => {}
+    }
+    match (&field.array, &field.size) {
+        (Some(vkxml::ArrayType::Dynamic), Some(size)) if !size.starts_with("latexmath") => {
+            let size = Ident::from(size.to_snake_case().as_str());
+            Some(quote! {self.#size as usize})
+        }
+        _ => None,
+    }
+}
+
+pub fn member_kind(_struct: &vkxml::Struct, field: &vkxml::Field) -> ParamKind {
+    let name = field.param_ident().to_string();
+    if name == "p_next" {
+        return ParamKind::PNext;
+    }
+    if field.basetype.starts_with("PFN_") || name == "p_user_data" {
+        return ParamKind::Skip;
+    }
+    let len = member_len(_struct, field);
+    match field.reference {
+        Some(vkxml::ReferenceType::Pointer) if field.basetype == "char" => ParamKind::Str,
+        Some(vkxml::ReferenceType::PointerToConstPointer) if field.basetype == "char" => {
+            ParamKind::StrArray(len.expect("string arrays have a length"))
+        }
+        Some(_) if field.basetype == "void" => match len {
+            Some(len) => ParamKind::Bytes(len),
+            None => ParamKind::Value,
+        },
+        Some(_) if is_platform_type(&field.basetype) => ParamKind::Value,
+        Some(_) => match len {
+            Some(len) => ParamKind::Array(len),
+            None if field.is_const => ParamKind::Ptr,
+            None => ParamKind::Value,
+        },
+        None => ParamKind::Value,
+    }
+}
+
+/// Returns how each parameter of `cmd` is passed.
+pub fn param_kinds(cmd: &vkxml::Command, union_types: &HashSet<&str>) -> Vec<ParamKind> {
+    cmd.param
+        .iter()
+        .map(|field| {
+            // The length of an array parameter, as a value for inputs and as a pointer for
+            // outputs, whose length is only known after the call
+            let len = field.size.as_ref().and_then(|size| {
+                if size.contains("->") {
+                    let mut path = size.split("->");
+                    let info = Ident::from(path.next().unwrap().to_snake_case().as_str());
+                    let member = Ident::from(path.next().unwrap().to_snake_case().as_str());
+                    return Some((quote! {(*#info).#member}, quote! {&(*#info).#member}));
+                }
+                cmd.param
+                    .iter()
+                    .find(|param| param.name.as_ref() == Some(size))
+                    .map(|param| {
+                        let ident = param.param_ident();
+                        if param.reference.is_some() {
+                            (quote! {*#ident}, quote! {#ident})
+                        } else {
+                            (quote! {#ident}, quote! {&#ident})
+                        }
+                    })
+            });
+            match field.reference {
+                Some(vkxml::ReferenceType::Pointer) if field.is_const => {
+                    match field.basetype.as_str() {
+                        "char" => ParamKind::Str,
+                        "VkAllocationCallbacks" => ParamKind::Allocator,
+                        "void" => match len {
+                            Some((len, _)) => ParamKind::Bytes(len),
+                            None => ParamKind::Value,
+                        },
+                        basetype if union_types.contains(basetype) => ParamKind::UnionPtr,
+                        basetype if is_platform_type(basetype) => ParamKind::Value,
+                        _ => match len {
+                            Some((len, _)) => ParamKind::Array(len),
+                            None => ParamKind::Ptr,
+                        },
+                    }
+                }
+                Some(vkxml::ReferenceType::Pointer) => match field.basetype.as_str() {
+                    "Display" | "wl_display" | "xcb_connection_t" => ParamKind::Value,
+                    "void" => ParamKind::OutBytes(len.expect("output buffers have a size").1),
+                    _ => match len {
+                        Some((_, len)) => ParamKind::OutArray(len),
+                        None => ParamKind::Out,
+                    },
+                },
+                Some(vkxml::ReferenceType::PointerToConstPointer) if field.is_const => match len {
+                    Some((len, _)) => ParamKind::Array(len),
+                    None => ParamKind::Ptr,
+                },
+                Some(vkxml::ReferenceType::PointerToPointer) => ParamKind::Out,
+                // A static array parameter is a pointer to the array
+                None if is_static_array(field) => ParamKind::Ptr,
+                _ => ParamKind::Value,
+            }
+        })
+        .collect()
+}
+
+/// Whether `cmd` can be replayed. Commands that take platform objects, or untyped data that only
+/// the driver can interpret, can not be.
+fn is_replayable(cmd: &vkxml::Command) -> bool {
+    if cmd.name.contains("WithTemplate") || cmd.name == "vkGetMemoryHostPointerPropertiesEXT" {
+        return false;
+    }
+    cmd.param.iter().all(|field| match field.reference {
+        Some(vkxml::ReferenceType::Pointer) if field.is_const => match field.basetype.as_str() {
+            // Markers are opaque to the driver
+            "void" => {
+                field.size.is_some() || field.param_ident().to_string() == "p_checkpoint_marker"
+            }
+            basetype => !is_platform_type(basetype),
+        },
+        Some(vkxml::ReferenceType::Pointer) => match field.basetype.as_str() {
+            "Display" | "wl_display" | "xcb_connection_t" => false,
+            _ => true,
+        },
+        _ => true,
+    })
+}
+
+/// Generates the shims of `ash::trace`. Every shim decodes its arguments, forwards the call to
+/// the driver and reports it to the trace sink. `vkGetDeviceProcAddr` and `vkCreateDevice`
+/// have handwritten shims.
+pub fn generate_trace_shims(commands: &[&vkxml::Command], union_types: &HashSet<&str>) -> Tokens {
+    let mut commands: Vec<_> = commands
+        .iter()
+        .filter(|cmd| match cmd.function_type() {
+            FunctionType::Instance | FunctionType::Device => {
+                cmd.name != "vkGetDeviceProcAddr" && cmd.name != "vkCreateDevice"
+            }
+            FunctionType::Static | FunctionType::Entry => false,
+        })
+        .collect();
+    commands.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let shims = commands.iter().map(|cmd| {
+        let ident = cmd.command_ident();
+        let raw_name = cmd.name.as_str();
+        let pfn_name = Ident::from(format!("PFN_{}", raw_name).as_str());
+        let return_ty = cmd.return_type.type_tokens();
+        let params: Vec<_> = cmd
+            .param
+            .iter()
+            .map(|field| {
+                let name = field.param_ident();
+                let ty = field.param_type_tokens();
+                quote! {#name: #ty}
+            })
+            .collect();
+        let param_names: Vec<_> = cmd.param.iter().map(|field| field.param_ident()).collect();
+        let first_param = param_names[0].clone();
+        let arg_names: Vec<_> = param_names.iter().map(|name| format!("{}", name)).collect();
+        let arg_values: Vec<_> = param_names
+            .iter()
+            .zip(param_kinds(cmd, union_types))
+            .map(|(name, kind)| match kind {
+                ParamKind::Str => quote! {Str(#name)},
+                ParamKind::Ptr | ParamKind::Allocator => quote! {Ptr(#name)},
+                ParamKind::UnionPtr => quote! {UnionPtr(#name)},
+                ParamKind::Array(len) => quote! {Array(#name, #len as usize)},
+                ParamKind::Bytes(len) => quote! {Bytes(#name as *const u8, #len as usize)},
+                ParamKind::Out => quote! {Out(#name)},
+                ParamKind::OutArray(len) => quote! {OutArray(#name, #len)},
+                ParamKind::OutBytes(len) => quote! {OutBytes(#name, #len)},
+                _ => quote! {#name},
+            })
+            .collect();
+        let param_names_ref = &param_names;
+        quote! {
+            extern "system" fn #ident(#(#params),*) -> #return_ty {
+                unsafe {
+                    trace_call(
+                        #first_param,
+                        #raw_name,
+                        &[#((#arg_names, &#arg_values)),*],
+                        |fp: #pfn_name| fp(#(#param_names_ref),*),
+                    )
+                }
+            }
+        }
+    });
+    let idents = commands.iter().map(|cmd| cmd.command_ident());
+    let raw_names = commands.iter().map(|cmd| cmd.name.as_str());
+    quote! {
+        use super::{trace_call, Array, Bytes, Out, OutArray, OutBytes, Ptr, Str, UnionPtr};
+        use std::os::raw::*;
+        use vk::*;
+        #(#shims)*
+        pub fn shim(name: &str) -> Option<*const c_void> {
+            match name {
+                #(#raw_names => Some(#idents as *const c_void),)*
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Descriptor types for which a member of a descriptor struct is read.
+fn descriptor_guard(_struct: &vkxml::Struct, field: &vkxml::Field) -> Option<Vec<Ident>> {
+    let types: &[&str] = match (_struct.name.as_str(), field.name.as_ref()?.as_str()) {
+        ("VkWriteDescriptorSet", "pImageInfo") => &[
+            "SAMPLER",
+            "COMBINED_IMAGE_SAMPLER",
+            "SAMPLED_IMAGE",
+            "STORAGE_IMAGE",
+            "INPUT_ATTACHMENT",
+        ],
+        ("VkWriteDescriptorSet", "pBufferInfo") => &[
+            "UNIFORM_BUFFER",
+            "STORAGE_BUFFER",
+            "UNIFORM_BUFFER_DYNAMIC",
+            "STORAGE_BUFFER_DYNAMIC",
+        ],
+        ("VkWriteDescriptorSet", "pTexelBufferView") => {
+            &["UNIFORM_TEXEL_BUFFER", "STORAGE_TEXEL_BUFFER"]
+        }
+        ("VkDescriptorSetLayoutBinding", "pImmutableSamplers") => {
+            &["SAMPLER", "COMBINED_IMAGE_SAMPLER"]
+        }
+        _ => return None,
+    };
+    Some(types.iter().map(|ty| Ident::from(*ty)).collect())
+}
+
+/// Generates the encoding of every struct for `capture::CaptureSink`, and the decoding of
+/// every struct and command for `capture::Replayer`. `raw_types` are the enums and flags.
+pub fn generate_capture_codecs(
+    definitions: &[&vkxml::DefinitionsElement],
+    commands: &[&vkxml::Command],
+    union_types: &HashSet<&str>,
+    raw_types: &[Ident],
+) -> Tokens {
+    let mut structs: Vec<&vkxml::Struct> = definitions
+        .iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Struct(ref _struct) => Some(_struct),
+            _ => None,
+        })
+        .collect();
+    structs.sort_by(|a, b| a.name.cmp(&b.name));
+    let members = |_struct: &'_ vkxml::Struct| -> Vec<&vkxml::Field> {
+        _struct
+            .elements
+            .iter()
+            .filter_map(|elem| match *elem {
+                vkxml::StructElement::Member(ref field) => Some(field),
+                _ => None,
+            })
+            .collect()
+    };
+
+    // Structs that hold callbacks or platform objects can not be replayed, and neither can
+    // the structs that point to them
+    let mut tainted: HashSet<&str> = structs
+        .iter()
+        .filter(|_struct| {
+            members(_struct).iter().any(|field| {
+                field.basetype.starts_with("PFN_") || is_platform_type(&field.basetype)
+            }) || _struct.name == "VkIOSSurfaceCreateInfoMVK"
+                || _struct.name == "VkMacOSSurfaceCreateInfoMVK"
+        })
+        .map(|_struct| _struct.name.as_str())
+        .collect();
+    loop {
+        let before = tainted.len();
+        for _struct in &structs {
+            if members(_struct).iter().any(|field| {
+                field.param_ident().to_string() != "p_next"
+                    && tainted.contains(field.basetype.as_str())
+            }) {
+                tainted.insert(_struct.name.as_str());
+            }
+        }
+        if tainted.len() == before {
+            break;
+        }
+    }
+
+    let codecs = structs.iter().map(|_struct| {
+        let name = name_to_tokens(&_struct.name);
+        // Structs whose members are all skipped neither write nor read anything
+        let skipped = members(_struct)
+            .into_iter()
+            .all(|field| match member_kind(_struct, field) {
+                ParamKind::Skip => true,
+                _ => false,
+            });
+        let (encode, decode): (Vec<_>, Vec<_>) = members(_struct)
+            .into_iter()
+            .map(|field| {
+                let ident = field.param_ident();
+                match member_kind(_struct, field) {
+                    ParamKind::PNext => (
+                        quote! {w.p_next(self.p_next as *const c_void);},
+                        quote! {#ident: ctx.p_next(r)? as _},
+                    ),
+                    ParamKind::Skip => (quote! {}, quote! {#ident: mem::zeroed()}),
+                    ParamKind::Str => (quote! {w.str(self.#ident);}, quote! {#ident: ctx.str(r)?}),
+                    ParamKind::StrArray(len) => (
+                        quote! {w.str_array(self.#ident, #len);},
+                        quote! {#ident: ctx.str_array(r)?},
+                    ),
+                    ParamKind::Bytes(len) => (
+                        quote! {w.bytes(self.#ident as *const u8, #len);},
+                        quote! {#ident: ctx.bytes(r)?},
+                    ),
+                    ParamKind::Ptr => (quote! {w.ptr(self.#ident);}, quote! {#ident: ctx.ptr(r)?}),
+                    ParamKind::Array(len) => {
+                        let encode = match descriptor_guard(_struct, field) {
+                            Some(types) => quote! {
+                                if [#(DescriptorType::#types),*].contains(&self.descriptor_type) {
+                                    w.array(self.#ident, #len)
+                                } else {
+                                    w.null();
+                                }
+                            },
+                            None => quote! {w.array(self.#ident, #len);},
+                        };
+                        let decode = if field.is_const {
+                            quote! {#ident: ctx.array(r)?}
+                        } else {
+                            let ty = name_to_tokens(&field.basetype);
+                            quote! {#ident: ctx.array::<#ty>(r)? as *mut _}
+                        };
+                        (encode, decode)
+                    }
+                    _ => (
+                        quote! {self.#ident.encode(w);},
+                        quote! {#ident: Decode::decode(r, ctx)?},
+                    ),
+                }
+            })
+            .unzip();
+        let (w, r, ctx) = if skipped {
+            (Ident::from("_w"), Ident::from("_r"), Ident::from("_ctx"))
+        } else {
+            (Ident::from("w"), Ident::from("r"), Ident::from("ctx"))
+        };
+        quote! {
+            impl Encode for #name {
+                unsafe fn encode(&self, #w: &mut Writer) {
+                    #(#encode)*
+                }
+            }
+            impl Decode for #name {
+                unsafe fn decode(#r: &mut Reader, #ctx: &mut Context) -> ReplayResult<Self> {
+                    Ok(#name { #(#decode),* })
+                }
+            }
+        }
+    });
+
+    let structure_types: Vec<_> = structs
+        .iter()
+        .filter_map(|_struct| {
+            let s_type = members(_struct)
+                .into_iter()
+                .find(|field| field.basetype == "VkStructureType")?;
+            let variant = s_type.type_enums.as_ref()?.split(',').nth(0)?;
+            Some((*_struct, variant_ident("VkStructureType", variant)))
+        })
+        .collect();
+    let encode_arms = structure_types.iter().map(|&(_struct, ref variant)| {
+        let name = name_to_tokens(&_struct.name);
+        quote! {StructureType::#variant => (*(p as *const #name)).encode(w),}
+    });
+    let decode_arms = structure_types.iter().map(|&(_struct, ref variant)| {
+        let name = name_to_tokens(&_struct.name);
+        let has_callbacks = members(_struct)
+            .iter()
+            .any(|field| field.basetype.starts_with("PFN_"));
+        if has_callbacks {
+            // Callbacks can not be replayed, the struct is left out of the chain
+            quote! {
+                StructureType::#variant => {
+                    let value: #name = Decode::decode(r, ctx)?;
+                    value.p_next
+                }
+            }
+        } else {
+            quote! {StructureType::#variant => ctx.boxed::<#name>(r)? as *const c_void,}
+        }
+    });
+
+    let mut commands: Vec<_> = commands
+        .iter()
+        .filter(|cmd| match cmd.name.as_str() {
+            "vkGetInstanceProcAddr"
+            | "vkGetDeviceProcAddr"
+            | "vkEnumerateInstanceLayerProperties"
+            | "vkEnumerateInstanceExtensionProperties"
+            | "vkEnumerateInstanceVersion"
+            // Replayed by hand, to track the mapping
+            | "vkMapMemory" => false,
+            _ => true,
+        })
+        .collect();
+    commands.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut replay_fns = Vec::new();
+    let replay_arms: Vec<_> = commands
+        .iter()
+        .map(|cmd| {
+            let raw_name = cmd.name.as_str();
+            let kinds = param_kinds(cmd, union_types);
+            let replayable = is_replayable(cmd)
+                && cmd
+                    .param
+                    .iter()
+                    .zip(&kinds)
+                    .all(|(field, kind)| match kind {
+                        ParamKind::Allocator => true,
+                        _ => !tainted.contains(field.basetype.as_str()),
+                    });
+            if !replayable {
+                return quote! {#raw_name => Err(ReplayError::Unsupported(#raw_name)),};
+            }
+            let ident = cmd.command_ident();
+            let pfn_name = Ident::from(format!("PFN_{}", raw_name).as_str());
+            let param_names: Vec<_> = cmd.param.iter().map(|field| field.param_ident()).collect();
+            let decoders = kinds.iter().map(|kind| match kind {
+                ParamKind::Str => quote! {ctx.str(r)?},
+                ParamKind::Allocator => quote! {ctx.allocator(r)?},
+                ParamKind::Ptr | ParamKind::UnionPtr => quote! {ctx.ptr(r)?},
+                ParamKind::Array(_) => quote! {ctx.array(r)?},
+                ParamKind::Bytes(_) => quote! {ctx.bytes(r)?},
+                ParamKind::Out => quote! {ctx.out(r)?},
+                ParamKind::OutArray(_) => quote! {ctx.out_array(r)?},
+                ParamKind::OutBytes(_) => quote! {ctx.out_bytes(r)?},
+                _ => quote! {Decode::decode(r, ctx)?},
+            });
+            let param_names_ref = &param_names;
+            let call = if cmd.return_type.basetype == "void" && cmd.return_type.reference.is_none()
+            {
+                quote! {
+                    let fp: #pfn_name = ctx.fp(#raw_name)?;
+                    fp(#(#param_names_ref),*);
+                    ctx.finish((), ());
+                }
+            } else {
+                quote! {
+                    let captured = Decode::decode(r, ctx)?;
+                    let fp: #pfn_name = ctx.fp(#raw_name)?;
+                    let result = fp(#(#param_names_ref),*);
+                    ctx.finish(result, captured);
+                }
+            };
+            replay_fns.push(quote! {
+                unsafe fn #ident(r: &mut Reader, ctx: &mut Context) -> ReplayResult<()> {
+                    #(let #param_names_ref = #decoders;)*
+                    #call
+                    Ok(())
+                }
+            });
+            quote! {#raw_name => #ident(r, ctx),}
+        })
+        .collect();
+
+    let union_names = union_types.iter().map(|name| name_to_tokens(name));
+    quote! {
+        use super::{Context, Decode, Encode, Reader, ReplayError, ReplayResult, Writer};
+        use std::mem;
+        use std::os::raw::*;
+        use vk::*;
+        codec_raw!(#(#raw_types),*);
+        codec_union!(#(#union_names),*);
+        #(#codecs)*
+        pub(crate) unsafe fn encode_structure(p: *const c_void, w: &mut Writer) -> bool {
+            match (*(p as *const BaseInStructure)).s_type {
+                #(#encode_arms)*
+                _ => return false,
+            }
+            true
+        }
+        pub(crate) unsafe fn decode_structure(
+            s_type: StructureType,
+            r: &mut Reader,
+            ctx: &mut Context,
+        ) -> ReplayResult<*const c_void> {
+            Ok(match s_type {
+                #(#decode_arms)*
+                _ => return Err(ReplayError::Invalid("unknown structure type")),
+            })
+        }
+        #(#replay_fns)*
+        pub(crate) unsafe fn replay_command(
+            name: &str,
+            r: &mut Reader,
+            ctx: &mut Context,
+        ) -> Option<ReplayResult<()>> {
+            Some(match name {
+                #(#replay_arms)*
+                _ => return None,
+            })
+        }
+    }
+}
+/// Generates `ash::layer::Commands`, whose methods forward to the next layer unless a layer
+/// overrides them, and the shims that call them. `instance_commands` and `device_commands`
+/// are the commands of `InstanceFnV1_0` and `DeviceFnV1_0`, `extension_commands` the other
+/// instance and device commands, which are loaded into `InstanceExtensionFn` and
+/// `DeviceExtensionFn`.
+pub fn generate_layer_commands(
+    instance_commands: &[&vkxml::Command],
+    device_commands: &[&vkxml::Command],
+    extension_commands: &[&vkxml::Command],
+) -> Tokens {
+    let levels = [
+        (
+            Ident::from("InstanceDispatch"),
+            Ident::from("instance"),
+            instance_commands,
+        ),
+        (
+            Ident::from("DeviceDispatch"),
+            Ident::from("device"),
+            device_commands,
+        ),
+    ];
+    let mut methods = Vec::new();
+    let mut shims = Vec::new();
+    let mut lookups = Vec::new();
+    for &(ref dispatch, ref level, commands) in &levels {
+        let mut raw_names = Vec::new();
+        let mut idents = Vec::new();
+        for cmd in commands
+            .iter()
+            .filter(|cmd| cmd.name != "vkGetDeviceProcAddr")
+        {
+            let ident = cmd.command_ident();
+            let raw_name = cmd.name.as_str();
+            let params: Vec<_> = cmd
+                .param
+                .iter()
+                .map(|field| {
+                    let name = field.param_ident();
+                    let ty = field.param_type_tokens();
+                    quote! {#name: #ty}
+                })
+                .collect();
+            let param_names: Vec<_> = cmd.param.iter().map(|field| field.param_ident()).collect();
+            let params_ref = &params;
+            let param_names_ref = &param_names;
+            let is_void = cmd.return_type.basetype == "void" && cmd.return_type.reference.is_none();
+            let (return_ty, forward) = if is_void {
+                (
+                    quote! {},
+                    quote! {next.fp_v1_0().#ident(#(#param_names_ref),*);},
+                )
+            } else {
+                let return_ty = cmd.return_type.type_tokens();
+                (
+                    quote! {-> #return_ty},
+                    quote! {next.fp_v1_0().#ident(#(#param_names_ref),*)},
+                )
+            };
+            methods.push(quote! {
+                unsafe fn #ident(&self, next: &#dispatch, #(#params_ref),*) #return_ty {
+                    #forward
+                }
+            });
+            // These register or remove dispatch tables, and are handwritten
+            match raw_name {
+                "vkCreateDevice" | "vkDestroyInstance" | "vkDestroyDevice" => continue,
+                _ => {}
+            }
+            let lookup = Ident::from(format!("{}_data", level).as_str());
+            let layer = if level.as_ref() == "instance" {
+                quote! {this.layer}
+            } else {
+                quote! {this.instance.layer}
+            };
+            let first_param = param_names[0].clone();
+            shims.push(quote! {
+                extern "system" fn #ident<L: Layer>(#(#params_ref),*) #return_ty {
+                    unsafe {
+                        let this = match #lookup::<L, _>(#first_param) {
+                            Some(this) => this,
+                            None => return ShimReturn::unforwarded(),
+                        };
+                        catch(|| #layer.#ident(&this.dispatch, #(#param_names_ref),*))
+                    }
+                }
+            });
+            raw_names.push(raw_name);
+            idents.push(ident);
+        }
+        let shim_fn = Ident::from(format!("{}_shim", level).as_str());
+        lookups.push(quote! {
+            pub(crate) fn #shim_fn<L: Layer>(name: &str) -> Option<*const c_void> {
+                match name {
+                    #(#raw_names => Some(#idents::<L> as *const c_void),)*
+                    _ => None,
+                }
+            }
+        });
+    }
+
+    // The other commands go through the `fp_extensions` of the dispatch, and are only
+    // intercepted if the next layer provides them
+    let mut extension_names = (Vec::new(), Vec::new());
+    let mut extension_idents = (Vec::new(), Vec::new());
+    let mut extension_pfns = (Vec::new(), Vec::new());
+    let mut extension_byte_names = (Vec::new(), Vec::new());
+    for cmd in extension_commands {
+        let ident = cmd.command_ident();
+        let raw_name = cmd.name.as_str();
+        let is_device = match cmd.function_type() {
+            FunctionType::Device => true,
+            _ => false,
+        };
+        let (dispatch, lookup, layer) = if is_device {
+            (
+                quote! {DeviceDispatch},
+                quote! {device_data},
+                quote! {this.instance.layer},
+            )
+        } else {
+            (
+                quote! {InstanceDispatch},
+                quote! {instance_data},
+                quote! {this.layer},
+            )
+        };
+        let params: Vec<_> = cmd
+            .param
+            .iter()
+            .map(|field| {
+                let name = field.param_ident();
+                let ty = field.param_type_tokens();
+                quote! {#name: #ty}
+            })
+            .collect();
+        let param_names: Vec<_> = cmd.param.iter().map(|field| field.param_ident()).collect();
+        let params_ref = &params;
+        let param_names_ref = &param_names;
+        let is_void = cmd.return_type.basetype == "void" && cmd.return_type.reference.is_none();
+        let (return_ty, forward) = if is_void {
+            (
+                quote! {},
+                quote! {
+                    if let Some(fp) = next.fp_extensions().#ident {
+                        fp(#(#param_names_ref),*);
+                    }
+                },
+            )
+        } else {
+            let return_ty = cmd.return_type.type_tokens();
+            (
+                quote! {-> #return_ty},
+                quote! {
+                    match next.fp_extensions().#ident {
+                        Some(fp) => fp(#(#param_names_ref),*),
+                        None => ShimReturn::unforwarded(),
+                    }
+                },
+            )
+        };
+        methods.push(quote! {
+            unsafe fn #ident(&self, next: &#dispatch, #(#params_ref),*) #return_ty {
+                #forward
+            }
+        });
+        let first_param = param_names[0].clone();
+        shims.push(quote! {
+            extern "system" fn #ident<L: Layer>(#(#params_ref),*) #return_ty {
+                unsafe {
+                    let this = match #lookup::<L, _>(#first_param) {
+                        Some(this) => this,
+                        None => return ShimReturn::unforwarded(),
+                    };
+                    catch(|| #layer.#ident(&this.dispatch, #(#param_names_ref),*))
+                }
+            }
+        });
+        let (names, idents, pfns, byte_names) = if is_device {
+            (
+                &mut extension_names.1,
+                &mut extension_idents.1,
+                &mut extension_pfns.1,
+                &mut extension_byte_names.1,
+            )
+        } else {
+            (
+                &mut extension_names.0,
+                &mut extension_idents.0,
+                &mut extension_pfns.0,
+                &mut extension_byte_names.0,
+            )
+        };
+        names.push(raw_name);
+        idents.push(ident);
+        pfns.push(Ident::from(format!("PFN_{}", raw_name).as_str()));
+        byte_names.push(Term::intern(&format!("b\"{}\\0\"", raw_name)));
+    }
+    let (instance_names, device_names) = (&extension_names.0, &extension_names.1);
+    let (instance_idents, device_idents) = (&extension_idents.0, &extension_idents.1);
+    let (instance_idents2, device_idents2) = (&extension_idents.0, &extension_idents.1);
+    let (instance_idents3, device_idents3) = (&extension_idents.0, &extension_idents.1);
+    let (instance_pfns, device_pfns) = (&extension_pfns.0, &extension_pfns.1);
+    let (instance_byte_names, device_byte_names) =
+        (&extension_byte_names.0, &extension_byte_names.1);
+    quote! {
+        use super::{
+            catch, device_data, instance_data, DeviceDispatch, InstanceDispatch, Layer, ShimReturn,
+        };
+        use std::ffi::CStr;
+        use std::mem;
+        use std::os::raw::*;
+        use vk::*;
+        pub trait Commands: Sized + Send + Sync + 'static {
+            #(#methods)*
+        }
+        #(#shims)*
+        #(#lookups)*
+        pub(crate) fn instance_extension_shim<L: Layer>(name: &str) -> Option<*const c_void> {
+            match name {
+                #(#instance_names => Some(#instance_idents::<L> as *const c_void),)*
+                _ => None,
+            }
+        }
+        pub(crate) fn device_extension_shim<L: Layer>(name: &str) -> Option<*const c_void> {
+            match name {
+                #(#device_names => Some(#device_idents::<L> as *const c_void),)*
+                _ => None,
+            }
+        }
+        #[doc = "The instance commands of the next layer that are not part of Vulkan 1.0, `None` if the next layer does not provide them, e.g. because their extension is not enabled."]
+        pub struct InstanceExtensionFn {
+            #(pub #instance_idents2: Option<#instance_pfns>,)*
+        }
+        impl InstanceExtensionFn {
+            pub(crate) unsafe fn load<F: FnMut(&CStr) -> *const c_void>(mut f: F) -> Self {
+                InstanceExtensionFn {
+                    #(#instance_idents3: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(#instance_byte_names))),)*
+                }
+            }
+        }
+        #[doc = "The device commands of the next layer that are not part of Vulkan 1.0, `None` if the next layer does not provide them, e.g. because their extension is not enabled."]
+        pub struct DeviceExtensionFn {
+            #(pub #device_idents2: Option<#device_pfns>,)*
+        }
+        impl DeviceExtensionFn {
+            pub(crate) unsafe fn load<F: FnMut(&CStr) -> *const c_void>(mut f: F) -> Self {
+                DeviceExtensionFn {
+                    #(#device_idents3: mem::transmute(f(CStr::from_bytes_with_nul_unchecked(#device_byte_names))),)*
+                }
+            }
+        }
+    }
+}
+
+/// Arrays that may be null although their length is not zero, as it is ignored in some states.
+const OPTIONAL_ARRAYS: &[(&str, &str)] = &[
+    ("VkBufferCreateInfo", "pQueueFamilyIndices"),
+    ("VkImageCreateInfo", "pQueueFamilyIndices"),
+    ("VkSwapchainCreateInfoKHR", "pQueueFamilyIndices"),
+    (
+        "VkPhysicalDeviceImageDrmFormatModifierInfoEXT",
+        "pQueueFamilyIndices",
+    ),
+    ("VkPipelineViewportStateCreateInfo", "pViewports"),
+    ("VkPipelineViewportStateCreateInfo", "pScissors"),
+    (
+        "VkPipelineViewportWScalingStateCreateInfoNV",
+        "pViewportWScalings",
+    ),
+    (
+        "VkPipelineDiscardRectangleStateCreateInfoEXT",
+        "pDiscardRectangles",
+    ),
+    (
+        "VkPipelineViewportExclusiveScissorStateCreateInfoNV",
+        "pExclusiveScissors",
+    ),
+    (
+        "VkPipelineViewportShadingRateImageStateCreateInfoNV",
+        "pShadingRatePalettes",
+    ),
+    (
+        "VkPipelineCoverageModulationStateCreateInfoNV",
+        "pCoverageModulationTable",
+    ),
+    ("VkPipelineMultisampleStateCreateInfo", "pSampleMask"),
+    ("VkDescriptorSetLayoutBinding", "pImmutableSamplers"),
+    ("VkSubpassDescription", "pResolveAttachments"),
+    ("VkSubpassDescription2KHR", "pResolveAttachments"),
+    ("VkPresentInfoKHR", "pResults"),
+    ("VkPresentRegionsKHR", "pRegions"),
+    ("VkPresentRegionKHR", "pRectangles"),
+    ("VkPresentTimesInfoGOOGLE", "pTimes"),
+    ("VkD3D12FenceSubmitInfoKHR", "pWaitSemaphoreValues"),
+    ("VkD3D12FenceSubmitInfoKHR", "pSignalSemaphoreValues"),
+];
+
+/// Generates the `Validate` impls of `ash::validation`, which check the `s_type`, the pNext
+/// chain and the arrays of every struct, and of the structs it points to.
+pub fn generate_validation(definitions: &[&vkxml::DefinitionsElement]) -> Tokens {
+    let mut structs: Vec<&vkxml::Struct> = definitions
+        .iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Struct(ref _struct) => Some(_struct),
+            _ => None,
+        })
+        .collect();
+    structs.sort_by(|a, b| a.name.cmp(&b.name));
+    fn members(_struct: &vkxml::Struct) -> Vec<&vkxml::Field> {
+        _struct
+            .elements
+            .iter()
+            .filter_map(|elem| match *elem {
+                vkxml::StructElement::Member(ref field) => Some(field),
+                _ => None,
+            })
+            .collect()
+    }
+    fn structure_type(_struct: &vkxml::Struct) -> Option<Ident> {
+        let s_type = members(_struct)
+            .into_iter()
+            .find(|field| field.basetype == "VkStructureType")?;
+        let variant = s_type.type_enums.as_ref()?.split(',').nth(0)?;
+        Some(variant_ident("VkStructureType", variant))
+    }
+
+    // Structs that have anything to check, directly or through the structs they point to
+    let mut checked: HashSet<&str> = structs
+        .iter()
+        .filter(|_struct| {
+            structure_type(_struct).is_some()
+                || members(_struct)
+                    .iter()
+                    .any(|field| member_len(_struct, field).is_some())
+        })
+        .map(|_struct| _struct.name.as_str())
+        .collect();
+    loop {
+        let before = checked.len();
+        for _struct in &structs {
+            if members(_struct)
+                .iter()
+                .any(|field| checked.contains(field.basetype.as_str()))
+            {
+                checked.insert(_struct.name.as_str());
+            }
+        }
+        if checked.len() == before {
+            break;
+        }
+    }
+
+    let impls = structs
+        .iter()
+        .filter(|_struct| checked.contains(_struct.name.as_str()))
+        .map(|_struct| {
+            let name = name_to_tokens(&_struct.name);
+            let name_str = name.to_string();
+            let checks: Vec<_> = members(_struct)
+                .into_iter()
+                .filter_map(|field| {
+                    let ident = field.param_ident();
+                    let member = ident.to_string();
+                    let target = checked.contains(field.basetype.as_str());
+                    match (member_len(_struct, field), &field.reference) {
+                        (Some(len), _) => {
+                            let field_name = field.name.as_ref().map(|s| s.as_str()).unwrap_or("");
+                            let optional = OPTIONAL_ARRAYS.contains(&(_struct.name.as_str(), field_name));
+                            let array = if optional {
+                                quote! {}
+                            } else {
+                                quote! {v.array(#name_str, #member, self.#ident, #len);}
+                            };
+                            let structures = if target {
+                                quote! {v.structures(self.#ident, #len);}
+                            } else {
+                                quote! {}
+                            };
+                            if optional && !target {
+                                return None;
+                            }
+                            Some(match descriptor_guard(_struct, field) {
+                                Some(types) => quote! {
+                                    if [#(DescriptorType::#types),*].contains(&self.descriptor_type) {
+                                        #array
+                                        #structures
+                                    }
+                                },
+                                None => quote! {#array #structures},
+                            })
+                        }
+                        (None, &Some(_)) if target && member != "p_next" => {
+                            Some(quote! {v.structures(self.#ident, 1);})
+                        }
+                        (None, &None) if target => Some(quote! {self.#ident.validate(v);}),
+                        _ => None,
+                    }
+                })
+                .collect();
+            let v = Ident::from(if checks.is_empty() { "_v" } else { "v" });
+            let validate = structure_type(_struct).map(|s_type| {
+                quote! {
+                    unsafe fn validate(&self, v: &mut Validator) {
+                        v.s_type(#name_str, self.s_type, StructureType::#s_type);
+                        v.p_next(StructureType::#s_type, self.p_next as *const c_void);
+                        self.validate_members(v);
+                    }
+                }
+            });
+            quote! {
+                impl Validate for #name {
+                    #validate
+                    unsafe fn validate_members(&self, #v: &mut Validator) {
+                        #(#checks)*
+                    }
+                }
+            }
+        });
+
+    let structure_types: Vec<_> = structs
+        .iter()
+        .filter_map(|_struct| Some((name_to_tokens(&_struct.name), structure_type(_struct)?)))
+        .collect();
+    let validate_arms = structure_types.iter().map(|&(ref name, ref s_type)| {
+        quote! {StructureType::#s_type => (*(p as *const #name)).validate_members(v),}
+    });
+    let mut extended: HashMap<&str, Vec<Ident>> = HashMap::new();
+    for _struct in &structs {
+        let s_type = match structure_type(_struct) {
+            Some(s_type) => s_type,
+            None => continue,
+        };
+        for base in _struct
+            .extends
+            .iter()
+            .flat_map(|extends| extends.split(','))
+        {
+            extended
+                .entry(base)
+                .or_insert_with(Vec::new)
+                .push(s_type.clone());
+        }
+    }
+    let extends_arms = structs.iter().filter_map(|_struct| {
+        let nexts = extended.get(_struct.name.as_str())?;
+        let s_type = structure_type(_struct)?;
+        Some(quote! {
+            StructureType::#s_type => match next {
+                #(StructureType::#nexts)|* => true,
+                _ => false,
+            },
+        })
+    });
+    quote! {
+        use super::{Validate, Validator};
+        use std::os::raw::*;
+        use vk::*;
+        #(#impls)*
+        pub(crate) unsafe fn validate_structure(p: *const c_void, v: &mut Validator) -> bool {
+            match (*(p as *const BaseInStructure)).s_type {
+                #(#validate_arms)*
+                _ => return false,
+            }
+            true
+        }
+        pub(crate) fn extends(base: StructureType, next: StructureType) -> bool {
+            match base {
+                #(#extends_arms)*
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Generates the table of `sync::extern_sync`, with the parameters that vk.xml marks as
+/// `externsync` and the objects it lists in `implicitexternsyncparams`.
+/// The asciidoc macros of the spec, such as `pname:commandBuffer`, which are removed from the
+/// text that ends up in ash.
+const ASCIIDOC_MACROS: &[&str] = &[
+    "sname", "slink", "pname", "fname", "flink", "ename", "elink", "tname", "tlink", "dname",
+    "dlink", "code",
+];
+
+/// Removes the asciidoc macros from `text`, e.g. "the sname:VkCommandPool" becomes "the
+/// VkCommandPool".
+fn strip_asciidoc(text: &str) -> String {
+    text.split(' ')
+        .map(|word| {
+            let start = word.find(|c: char| c.is_alphanumeric()).unwrap_or(0);
+            let (prefix, rest) = word.split_at(start);
+            match rest.find(':') {
+                Some(colon) if ASCIIDOC_MACROS.contains(&&rest[..colon]) => {
+                    format!("{}{}", prefix, &rest[colon + 1..])
+                }
+                _ => word.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn generate_extern_sync(commands: &[&vkxml::Command]) -> Tokens {
+    let mut commands: Vec<_> = commands
+        .iter()
+        .filter(|cmd| cmd.external_sync.is_some() || cmd.param.iter().any(|p| p.sync.is_some()))
+        .collect();
+    commands.sort_by(|a, b| a.name.cmp(&b.name));
+    let entries = commands.iter().map(|cmd| {
+        let name = cmd.name.as_str();
+        let params: Vec<String> = cmd
+            .param
+            .iter()
+            .filter_map(|param| Some((param.name.as_ref()?, param.sync.as_ref()?)))
+            .flat_map(|(param, sync)| {
+                // "true" marks the parameter itself, anything else lists members of it
+                if sync == "true" {
+                    vec![param.clone()]
+                } else {
+                    sync.split(',')
+                        .map(|member| member.trim().to_string())
+                        .collect()
+                }
+            })
+            .collect();
+        let implicit = cmd
+            .external_sync
+            .iter()
+            .map(|sync| strip_asciidoc(&sync.sync));
+        quote! {
+            ExternSync {
+                command: #name,
+                params: &[#(#params),*],
+                implicit: &[#(#implicit),*],
+            },
+        }
+    });
+    quote! {
+        use super::ExternSync;
+        pub(crate) static EXTERN_SYNC: &[ExternSync] = &[#(#entries)*];
+    }
+}
+
+/// Generates `vk::commands`, with the `renderpass`, `queues`, `cmdbufferlevel`,
+/// `successcodes` and `errorcodes` attributes of every command in `fn_cache`.
+pub fn generate_command_metadata(
+    registry: &vk_parse::Registry,
+    fn_cache: &HashSet<&str>,
+) -> Tokens {
+    fn split(attribute: &Option<String>) -> Vec<&str> {
+        attribute
+            .as_ref()
+            .map(|values| values.split(',').collect())
+            .unwrap_or_default()
+    }
+    let mut definitions: Vec<_> = registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Commands(ref cmds) => Some(&cmds.children),
+            _ => None,
+        })
+        .flat_map(|cmds| cmds.iter())
+        .filter_map(|cmd| match cmd {
+            vk_parse::Command::Definition(ref def) => Some(def),
+            _ => None,
+        })
+        .filter(|def| fn_cache.contains(def.proto.name.as_str()))
+        .collect();
+    definitions.sort_by(|a, b| a.proto.name.cmp(&b.proto.name));
+    let entries = definitions.iter().map(|def| {
+        let name = def.proto.name.as_str();
+        let render_pass = match def.renderpass.as_ref().map(|scope| scope.as_str()) {
+            Some("inside") => quote!(Some(RenderPassScope::Inside)),
+            Some("outside") => quote!(Some(RenderPassScope::Outside)),
+            Some("both") => quote!(Some(RenderPassScope::Both)),
+            Some(scope) => panic!("Unknown renderpass scope {}", scope),
+            None => quote!(None),
+        };
+        let queues = split(&def.queues)
+            .into_iter()
+            .map(|queue| match queue {
+                "graphics" => 0b1,
+                "compute" => 0b10,
+                "transfer" => 0b100,
+                "sparse_binding" => 0b1000,
+                queue => panic!("Unknown queue {}", queue),
+            })
+            .fold(0u32, |bits, bit| bits | bit);
+        let levels = split(&def.cmdbufferlevel)
+            .into_iter()
+            .map(|level| Ident::from(level.to_shouty_snake_case()));
+        let success_codes = split(&def.successcodes)
+            .into_iter()
+            .map(|code| variant_ident("VkResult", code));
+        let error_codes = split(&def.errorcodes)
+            .into_iter()
+            .map(|code| variant_ident("VkResult", code));
+        quote! {
+            CommandMetadata {
+                name: #name,
+                render_pass: #render_pass,
+                queues: QueueFlags(#queues),
+                command_buffer_levels: &[#(CommandBufferLevel::#levels),*],
+                success_codes: &[#(Result::#success_codes),*],
+                error_codes: &[#(Result::#error_codes),*],
+            },
+        }
+    });
+    quote! {
+        pub mod commands {
+            use super::{CommandBufferLevel, QueueFlags, Result};
+            #[doc = "Whether a command may be recorded inside or outside of a render pass instance."]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub enum RenderPassScope {
+                Inside,
+                Outside,
+                Both,
+            }
+            #[doc = "The `renderpass`, `queues`, `cmdbufferlevel`, `successcodes` and `errorcodes` of a command in vk.xml."]
+            #[derive(Clone, Copy, Debug)]
+            pub struct CommandMetadata {
+                pub name: &'static str,
+                #[doc = "`None` for commands that are not recorded into a command buffer."]
+                pub render_pass: Option<RenderPassScope>,
+                #[doc = "The queue types that support the command, empty if vk.xml does not restrict them."]
+                pub queues: QueueFlags,
+                #[doc = "Empty for commands that are not recorded into a command buffer."]
+                pub command_buffer_levels: &'static [CommandBufferLevel],
+                #[doc = "Empty for commands that do not return a `Result`."]
+                pub success_codes: &'static [Result],
+                pub error_codes: &'static [Result],
+            }
+            impl CommandMetadata {
+                pub fn is_recorded(&self) -> bool {
+                    self.render_pass.is_some()
+                }
+                pub fn allowed_inside_render_pass(&self) -> bool {
+                    match self.render_pass {
+                        Some(RenderPassScope::Inside) | Some(RenderPassScope::Both) => true,
+                        _ => false,
+                    }
+                }
+                pub fn allowed_outside_render_pass(&self) -> bool {
+                    match self.render_pass {
+                        Some(RenderPassScope::Outside) | Some(RenderPassScope::Both) => true,
+                        _ => false,
+                    }
+                }
+                #[doc = "Returns true if a queue with `queue_flags` supports the command."]
+                pub fn supports_queue(&self, queue_flags: QueueFlags) -> bool {
+                    self.queues.is_empty() || self.queues.intersects(queue_flags)
+                }
+                pub fn allowed_in(&self, level: CommandBufferLevel) -> bool {
+                    self.command_buffer_levels.contains(&level)
+                }
+                pub fn may_return(&self, result: Result) -> bool {
+                    self.success_codes.contains(&result) || self.error_codes.contains(&result)
+                }
+            }
+            #[doc = "Returns the metadata of a command, e.g. `vkCmdDraw`."]
+            pub fn metadata(name: &str) -> Option<&'static CommandMetadata> {
+                METADATA
+                    .binary_search_by(|metadata| metadata.name.cmp(name))
+                    .ok()
+                    .map(|index| &METADATA[index])
+            }
+            #[doc = "The metadata of every command, sorted by name."]
+            pub static METADATA: &[CommandMetadata] = &[#(#entries)*];
+        }
+    }
+}
+
+/// Generates `error::generated`, with a `command_error!` for every command in `names` that
+/// has `errorcodes`. The vendor suffix is dropped from the names of the types and variants, e.g.
+/// `vkAcquireNextImageKHR` has an `AcquireNextImageError` with an `OutOfDate` variant.
+pub fn generate_command_errors(registry: &vk_parse::Registry, names: &[&str]) -> Tokens {
+    fn strip_vendor(name: &str) -> &str {
+        let vendors = ["NVX", "KHR", "EXT", "NV", "AMD", "ANDROID", "GOOGLE"];
+        vendors
+            .iter()
+            .find(|vendor| name.ends_with(*vendor))
+            .map(|vendor| name[..name.len() - vendor.len()].trim_right_matches('_'))
+            .unwrap_or(name)
+    }
+    let mut definitions: Vec<_> = registry
+        .0
+        .iter()
+        .filter_map(|item| match item {
+            vk_parse::RegistryChild::Commands(ref cmds) => Some(&cmds.children),
+            _ => None,
+        })
+        .flat_map(|cmds| cmds.iter())
+        .filter_map(|cmd| match cmd {
+            vk_parse::Command::Definition(ref def) => Some(def),
+            _ => None,
+        })
+        .filter(|def| names.contains(&def.proto.name.as_str()) && def.errorcodes.is_some())
+        .collect();
+    definitions.sort_by(|a, b| a.proto.name.cmp(&b.proto.name));
+    let errors = definitions.iter().map(|def| {
+        let name = def.proto.name.as_str();
+        let doc = format!("Error codes of `{}`.", name);
+        let ident = Ident::from(format!("{}Error", strip_vendor(&name[2..])));
+        let codes = def.errorcodes.as_ref().unwrap().split(',');
+        let variants = codes
+            .clone()
+            .map(|code| Ident::from(strip_vendor(&code["VK_ERROR_".len()..]).to_camel_case()));
+        let codes = codes.map(|code| variant_ident("VkResult", code));
+        quote! {
+            command_error! {
+                #[doc = #doc]
+                #ident {
+                    #(#variants = #codes,)*
+                }
+            }
+        }
+    });
+    quote! {
+        #(#errors)*
+    }
+}
+
+/// The components of a token like `R10X6G10X6`, including padding `X` and exponent `E` bits.
+fn format_components(token: &str) -> Vec<(char, u32)> {
+    let mut components = Vec::new();
+    let mut chars = token.chars().peekable();
+    while let Some(name) = chars.next() {
+        let mut bits = String::new();
+        while let Some(&digit) = chars.peek() {
+            if !digit.is_ascii_digit() {
+                break;
+            }
+            bits.push(digit);
+            chars.next();
+        }
+        components.push((name, bits.parse().expect("Component bits")));
+    }
+    components
+}
+
+fn numeric_type(token: &str) -> Option<&'static str> {
+    Some(match token {
+        "UNORM" => "Unorm",
+        "SNORM" => "Snorm",
+        "USCALED" => "Uscaled",
+        "SSCALED" => "Sscaled",
+        "UINT" => "Uint",
+        "SINT" => "Sint",
+        "UFLOAT" => "Ufloat",
+        "SFLOAT" => "Sfloat",
+        "SRGB" => "Srgb",
+        _ => return None,
+    })
+}
+
+/// The compression, block size, block extent, channels and compatibility class of a
+/// block-compressed format.
+fn compressed_format(name: &str) -> Option<(&'static str, u32, [u32; 3], &'static str, String)> {
+    let tokens: Vec<&str> = name.split('_').collect();
+    if tokens[0] == "ASTC" {
+        let (width, height) = tokens[1].split_at(tokens[1].find('X').expect("ASTC extent"));
+        let extent = [width.parse().unwrap(), height[1..].parse().unwrap(), 1];
+        let class = format!("ASTC_{}x{}", width, &height[1..]);
+        return Some(("Astc", 16, extent, "RGBA", class));
+    }
+    if tokens[0].starts_with("PVRTC") {
+        let extent = if tokens[1] == "2BPP" {
+            [8, 4, 1]
+        } else {
+            [4, 4, 1]
+        };
+        let class = format!("{}_{}", tokens[0], tokens[1]);
+        return Some(("Pvrtc", 8, extent, "RGBA", class));
+    }
+    let table = [
+        ("BC1_RGB", "Bc", 8, "RGB", "BC1_RGB"),
+        ("BC1_RGBA", "Bc", 8, "RGBA", "BC1_RGBA"),
+        ("BC2", "Bc", 16, "RGBA", "BC2"),
+        ("BC3", "Bc", 16, "RGBA", "BC3"),
+        ("BC4", "Bc", 8, "R", "BC4"),
+        ("BC5", "Bc", 16, "RG", "BC5"),
+        ("BC6H", "Bc", 16, "RGB", "BC6H"),
+        ("BC7", "Bc", 16, "RGBA", "BC7"),
+        ("ETC2_R8G8B8", "Etc2", 8, "RGB", "ETC2_RGB"),
+        ("ETC2_R8G8B8A1", "Etc2", 8, "RGBA", "ETC2_RGBA"),
+        ("ETC2_R8G8B8A8", "Etc2", 16, "RGBA", "ETC2_EAC_RGBA"),
+        ("EAC_R11", "Eac", 8, "R", "EAC_R"),
+        ("EAC_R11G11", "Eac", 16, "RG", "EAC_RG"),
+    ];
+    table
+        .iter()
+        .find(|&&(prefix, ..)| {
+            name.starts_with(&format!("{}_", prefix))
+                && name[prefix.len() + 1..]
+                    .split('_')
+                    .next()
+                    .and_then(numeric_type)
+                    .is_some()
+        })
+        .map(|&(_, compression, size, channels, class)| {
+            (compression, size, [4, 4, 1], channels, class.to_string())
+        })
+}
+
+/// Generates `vk::formats` with a `FormatInfo` for every `Format` variant except `UNDEFINED`.
+/// vk.xml does not describe formats, so the layout is derived from the names, e.g.
+/// `G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16` has a 10 bit G plane and a B10R10 plane with
+/// half the width and height, padded to 16 bits per component.
+pub fn generate_format_info(variants: &[Ident]) -> Tokens {
+    let names: Vec<String> = variants
+        .iter()
+        .map(|variant| variant.to_string())
+        .filter(|name| name != "UNDEFINED")
+        .collect();
+    let entries = names.iter().map(|name| {
+        let tokens: Vec<&str> = name
+            .split('_')
+            .filter(|&token| token != "BLOCK" && token != "IMG")
+            .collect();
+        let numeric = tokens
+            .iter()
+            .filter_map(|token| numeric_type(token))
+            .next()
+            .expect("Numeric type");
+        // The alpha of sRGB formats is linear
+        let component_type = |channel: char, numeric: &'static str| {
+            if numeric == "Srgb" && channel == 'A' {
+                "Unorm"
+            } else {
+                numeric
+            }
+        };
+        let (block_size, block_extent, components, aspect_mask, planes, class, compression) =
+            if let Some((compression, size, extent, channels, class)) = compressed_format(name) {
+                let components: Vec<_> = channels
+                    .chars()
+                    .map(|channel| (channel, 0, component_type(channel, numeric)))
+                    .collect();
+                (
+                    size,
+                    extent,
+                    components,
+                    0b1,
+                    Vec::new(),
+                    class,
+                    Some(compression),
+                )
+            } else {
+                // Components followed by their numeric type, e.g. D24 UNORM S8 UINT
+                let mut groups = Vec::new();
+                let mut pending = Vec::new();
+                let mut plane_count = 1;
+                let mut chroma = None;
+                for &token in &tokens {
+                    if let Some(numeric) = numeric_type(token) {
+                        groups.extend(pending.drain(..).map(|token| (token, numeric)));
+                    } else if token.ends_with("PLANE") {
+                        plane_count = token[..1].parse().expect("Plane count");
+                    } else if token == "420" || token == "422" || token == "444" {
+                        chroma = Some(token);
+                    } else if !token.contains("PACK") {
+                        pending.push(token);
+                    }
+                }
+                let mut bits = 0;
+                let mut components = Vec::new();
+                for &(token, numeric) in &groups {
+                    for (channel, channel_bits) in format_components(token) {
+                        bits += channel_bits;
+                        if channel != 'X' && channel != 'E' {
+                            components.push((
+                                channel,
+                                channel_bits,
+                                component_type(channel, numeric),
+                            ));
+                        }
+                    }
+                }
+                let has = |name: char| components.iter().any(|&(channel, ..)| channel == name);
+                let layout = || {
+                    components
+                        .iter()
+                        .map(|&(channel, bits, _)| format!("{}{}", channel, bits))
+                        .collect::<String>()
+                };
+                if has('D') || has('S') {
+                    let aspect = if has('D') { 0b10 } else { 0 } | if has('S') { 0b100 } else { 0 };
+                    (
+                        bits / 8,
+                        [1, 1, 1],
+                        components.clone(),
+                        aspect,
+                        Vec::new(),
+                        layout(),
+                        None,
+                    )
+                } else if plane_count > 1 {
+                    let depth = components[0].1;
+                    let chroma = chroma.expect("Chroma subsampling");
+                    let planes: Vec<_> = groups
+                        .iter()
+                        .enumerate()
+                        .map(|(index, &(token, _))| {
+                            let channels = token
+                                .matches(|c: char| c == 'R' || c == 'G' || c == 'B')
+                                .count();
+                            let format = match (depth, channels) {
+                                (8, 1) | (16, 1) => format!("R{}_UNORM", depth),
+                                (8, 2) | (16, 2) => format!("R{0}G{0}_UNORM", depth),
+                                (_, 1) => format!("R{}X{}_UNORM_PACK16", depth, 16 - depth),
+                                _ => format!("R{0}X{1}G{0}X{1}_UNORM_2PACK16", depth, 16 - depth),
+                            };
+                            let divisors = match (index, chroma) {
+                                (0, _) | (_, "444") => (1u32, 1u32),
+                                (_, "420") => (2, 2),
+                                _ => (2, 1),
+                            };
+                            (format, divisors)
+                        })
+                        .collect();
+                    let aspect = [0b1_0000, 0b10_0000, 0b100_0000][..plane_count]
+                        .iter()
+                        .fold(0b1, |aspect, plane| aspect | plane);
+                    let class = format!("{}-bit {}-plane {}", depth, plane_count, chroma);
+                    (
+                        bits / 8,
+                        [1, 1, 1],
+                        components.clone(),
+                        aspect,
+                        planes,
+                        class,
+                        None,
+                    )
+                } else {
+                    let padded_rgba = components.len() == 4 && bits == 64 && name.contains('X');
+                    let class = if chroma == Some("422") || padded_rgba {
+                        format!("{}-bit {}", bits, layout())
+                    } else {
+                        format!("{}-bit", bits)
+                    };
+                    let extent = if chroma == Some("422") {
+                        [2, 1, 1]
+                    } else {
+                        [1, 1, 1]
+                    };
+                    (
+                        bits / 8,
+                        extent,
+                        components.clone(),
+                        0b1,
+                        Vec::new(),
+                        class,
+                        None,
+                    )
+                }
+            };
+        let format = Ident::from(name.as_str());
+        let (width, height, depth) = (block_extent[0], block_extent[1], block_extent[2]);
+        let components = components.iter().map(|&(channel, bits, numeric)| {
+            let channel = Ident::from(channel.to_string());
+            let numeric = Ident::from(numeric);
+            quote! {
+                Component {
+                    name: ComponentName::#channel,
+                    bits: #bits,
+                    numeric_type: NumericType::#numeric,
+                }
+            }
+        });
+        let planes = planes
+            .iter()
+            .map(|&(ref format, (width_divisor, height_divisor))| {
+                let format = Ident::from(format.as_str());
+                quote! {
+                    Plane {
+                        format: Format::#format,
+                        width_divisor: #width_divisor,
+                        height_divisor: #height_divisor,
+                    }
+                }
+            });
+        let aspect_mask = Term::intern(&format!("0b{:b}", aspect_mask));
+        let compression = match compression {
+            Some(compression) => {
+                let compression = Ident::from(compression);
+                quote!(Some(Compression::#compression))
+            }
+            None => quote!(None),
+        };
+        quote! {
+            FormatInfo {
+                format: Format::#format,
+                block_size: #block_size,
+                block_extent: [#width, #height, #depth],
+                components: &[#(#components),*],
+                aspect_mask: ImageAspectFlags(#aspect_mask),
+                planes: &[#(#planes),*],
+                compatibility_class: #class,
+                compression: #compression,
+            },
+        }
+    });
+    let arms = names.iter().enumerate().map(|(index, name)| {
+        let format = Ident::from(name.as_str());
+        quote!(Format::#format => #index,)
+    });
+    quote! {
+        pub mod formats {
+            use super::{Format, ImageAspectFlags};
+            #[doc = "How the bits of a component are interpreted."]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub enum NumericType {
+                Unorm,
+                Snorm,
+                Uscaled,
+                Sscaled,
+                Uint,
+                Sint,
+                Ufloat,
+                Sfloat,
+                Srgb,
+            }
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub enum ComponentName {
+                R,
+                G,
+                B,
+                A,
+                D,
+                S,
+            }
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub enum Compression {
+                Bc,
+                Etc2,
+                Eac,
+                Astc,
+                Pvrtc,
+            }
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub struct Component {
+                pub name: ComponentName,
+                #[doc = "0 for compressed formats."]
+                pub bits: u32,
+                pub numeric_type: NumericType,
+            }
+            #[doc = "A plane of a multi-planar format, with the format of its image views and the size of the plane relative to the image."]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub struct Plane {
+                pub format: Format,
+                pub width_divisor: u32,
+                pub height_divisor: u32,
+            }
+            #[doc = "The texel block layout of a format, as described in the Formats chapter of the specification."]
+            #[derive(Clone, Copy, Debug)]
+            pub struct FormatInfo {
+                pub format: Format,
+                #[doc = "The size of a texel block in bytes, the sum over all planes for multi-planar formats."]
+                pub block_size: u32,
+                #[doc = "The width, height and depth of a texel block in texels."]
+                pub block_extent: [u32; 3],
+                #[doc = "The components in memory order. Padding bits and the shared exponent are left out."]
+                pub components: &'static [Component],
+                pub aspect_mask: ImageAspectFlags,
+                #[doc = "Empty for formats that are not multi-planar."]
+                pub planes: &'static [Plane],
+                #[doc = "Formats of the same class are compatible, e.g. for image views of `MUTABLE_FORMAT` images."]
+                pub compatibility_class: &'static str,
+                pub compression: Option<Compression>,
+            }
+            impl FormatInfo {
+                #[doc = "The numeric type of the first component. Depth/stencil formats may have different types per component."]
+                pub fn numeric_type(&self) -> NumericType {
+                    self.components[0].numeric_type
+                }
+                pub fn component(&self, name: ComponentName) -> Option<&Component> {
+                    self.components
+                        .iter()
+                        .find(|component| component.name == name)
+                }
+                pub fn plane_count(&self) -> u32 {
+                    (self.planes.len() as u32).max(1)
+                }
+                pub fn is_compressed(&self) -> bool {
+                    self.compression.is_some()
+                }
+                pub fn is_depth_stencil(&self) -> bool {
+                    self.aspect_mask
+                        .intersects(ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL)
+                }
+                pub fn is_compatible_with(&self, other: &FormatInfo) -> bool {
+                    self.compatibility_class == other.compatibility_class
+                }
+            }
+            impl Format {
+                #[doc = "`None` for `UNDEFINED`."]
+                pub fn info(self) -> Option<&'static FormatInfo> {
+                    let index = match self {
+                        #(#arms)*
+                        _ => return None,
+                    };
+                    Some(&FORMATS[index])
+                }
+                #[doc = "Whether an image view of `self` may be created for an image of `other`, for `MUTABLE_FORMAT` images. Their compatibility classes have to match."]
+                pub fn is_compatible_with(self, other: Format) -> bool {
+                    match (self.info(), other.info()) {
+                        (Some(a), Some(b)) => a.is_compatible_with(b),
+                        _ => false,
+                    }
+                }
+            }
+            #[doc = "The layout of every format except `UNDEFINED`, in declaration order."]
+            pub static FORMATS: &[FormatInfo] = &[#(#entries)*];
+        }
+    }
+}
+
 pub struct ExtensionConstant<'a> {
     pub name: &'a str,
     pub constant: Constant,
@@ -1779,12 +3464,12 @@ pub fn generate_definition(
         _ => None,
     }
 }
-pub fn generate_feature<'a>(
+/// The commands that `feature` requires.
+pub fn feature_commands<'a>(
     feature: &vkxml::Feature,
     commands: &CommandMap<'a>,
-    fn_cache: &mut HashSet<&'a str>,
-) -> quote::Tokens {
-    let (static_commands, entry_commands, device_commands, instance_commands) = feature
+) -> Vec<&'a vkxml::Command> {
+    feature
         .elements
         .iter()
         .flat_map(|feature| {
@@ -1804,10 +3489,19 @@ pub fn generate_feature<'a>(
                 vec![]
             }
         })
-        .filter_map(|cmd_ref| commands.get(&cmd_ref.name))
-        .fold(
+        .filter_map(|cmd_ref| commands.get(&cmd_ref.name).cloned())
+        .collect()
+}
+
+pub fn generate_feature<'a>(
+    feature: &vkxml::Feature,
+    commands: &CommandMap<'a>,
+    fn_cache: &mut HashSet<&'a str>,
+) -> quote::Tokens {
+    let (static_commands, entry_commands, device_commands, instance_commands) =
+        feature_commands(feature, commands).into_iter().fold(
             (Vec::new(), Vec::new(), Vec::new(), Vec::new()),
-            |mut acc, &cmd_ref| {
+            |mut acc, cmd_ref| {
                 match cmd_ref.function_type() {
                     FunctionType::Static => {
                         acc.0.push(cmd_ref);
@@ -2122,6 +3816,8 @@ pub fn write_source_code(path: &Path) {
         generate_feature_extension(&spec2, &mut const_cache, &mut const_values);
 
     let const_displays = generate_const_displays(&const_values);
+    let command_metadata = generate_command_metadata(&spec2, &fn_cache);
+    let format_info = generate_format_info(&const_values[&Ident::from("Format")]);
 
     let mut file = File::create("../ash/src/vk.rs").expect("vk");
     let bitflags_macro = vk_bitflags_wrapped_macro();
@@ -2153,6 +3849,98 @@ pub fn write_source_code(path: &Path) {
         #feature_extensions_code
         #const_displays
         #(#aliases)*
+        #command_metadata
+        #format_info
     };
     write!(&mut file, "{}", source_code).expect("Unable to write to file");
+
+    let all_commands: Vec<_> = commands
+        .values()
+        .cloned()
+        .filter(|cmd| fn_cache.contains(cmd.name.as_str()))
+        .collect();
+    let trace_shims = generate_trace_shims(&all_commands, &union_types);
+    let mut file = File::create("../ash/src/trace/shims.rs").expect("shims");
+    write!(&mut file, "{}", trace_shims).expect("Unable to write to file");
+
+    let mut raw_types: Vec<_> = bitflags_cache.iter().cloned().collect();
+    raw_types.extend(
+        enums
+            .iter()
+            .filter(|_enum| !_enum.name.contains("Bit"))
+            .map(|_enum| Ident::from(&_enum.name[2..])),
+    );
+    raw_types.sort_by_key(|ident| ident.to_string());
+    let capture_codecs =
+        generate_capture_codecs(&definitions, &all_commands, &union_types, &raw_types);
+    let mut file = File::create("../ash/src/capture/generated.rs").expect("capture codecs");
+    write!(&mut file, "{}", capture_codecs).expect("Unable to write to file");
+
+    let core = features
+        .iter()
+        .find(|feature| feature.version == 1.0)
+        .expect("Vulkan 1.0");
+    let (device_commands, instance_commands): (Vec<_>, Vec<_>) = feature_commands(core, &commands)
+        .into_iter()
+        .filter(|cmd| match cmd.function_type() {
+            FunctionType::Device | FunctionType::Instance => true,
+            FunctionType::Static | FunctionType::Entry => false,
+        })
+        .partition(|cmd| match cmd.function_type() {
+            FunctionType::Device => true,
+            _ => false,
+        });
+    let mut extension_commands: Vec<_> = all_commands
+        .iter()
+        .cloned()
+        .filter(|cmd| match cmd.function_type() {
+            FunctionType::Device | FunctionType::Instance => true,
+            FunctionType::Static | FunctionType::Entry => false,
+        })
+        .filter(|cmd| {
+            !instance_commands
+                .iter()
+                .chain(&device_commands)
+                .any(|core| core.name == cmd.name)
+        })
+        .collect();
+    extension_commands.sort_by(|a, b| a.name.cmp(&b.name));
+    let layer_commands =
+        generate_layer_commands(&instance_commands, &device_commands, &extension_commands);
+    let mut file = File::create("../ash/src/layer/commands.rs").expect("layer commands");
+    write!(&mut file, "{}", layer_commands).expect("Unable to write to file");
+
+    let validation = generate_validation(&definitions);
+    let mut file = File::create("../ash/src/validation/generated.rs").expect("validation");
+    write!(&mut file, "{}", validation).expect("Unable to write to file");
+
+    let extern_sync = generate_extern_sync(&all_commands);
+    let mut file = File::create("../ash/src/sync/generated.rs").expect("extern sync");
+    write!(&mut file, "{}", extern_sync).expect("Unable to write to file");
+
+    let swapchain = extensions
+        .iter()
+        .find(|ext| ext.name == "VK_KHR_swapchain")
+        .expect("VK_KHR_swapchain");
+    let mut error_commands: Vec<&str> = device_commands
+        .iter()
+        .map(|cmd| cmd.name.as_str())
+        .collect();
+    error_commands.extend(
+        swapchain
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                vk_parse::ExtensionChild::Require { items, .. } => Some(items),
+                _ => None,
+            })
+            .flat_map(|items| items.iter())
+            .filter_map(|item| match item {
+                vk_parse::InterfaceItem::Command { ref name, .. } => Some(name.as_str()),
+                _ => None,
+            }),
+    );
+    let command_errors = generate_command_errors(&spec2, &error_commands);
+    let mut file = File::create("../ash/src/error/generated.rs").expect("command errors");
+    write!(&mut file, "{}", command_errors).expect("Unable to write to file");
 }